@@ -0,0 +1,292 @@
+//! Role-check helpers for an access model in the target language
+//!
+//! A `Role` enum over the model's roles, `implies` (does holding one role
+//! make one the other) and `can` (does a role hold a permission), with the
+//! hierarchy and grants flattened into tables at generation time, so the
+//! checks read `role.implies(Role::Moderator)` instead of equality chains:
+//!
+//! - Rust: `access.rs`
+//! - TypeScript: `access.ts`
+//! - Python: `access.py`
+
+use crate::{AccessError, AccessModel, Permission};
+use crucible_codegen::{GeneratedFile, TargetLanguage};
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+/// The role-check module for `language`
+pub fn role_module(model: &AccessModel, language: TargetLanguage) -> Result<GeneratedFile, AccessError> {
+    if model.roles.is_empty() {
+        return Err(AccessError::NoRoles);
+    }
+    let (path, contents) = match language {
+        TargetLanguage::Rust => ("access.rs", rust(model)),
+        TargetLanguage::TypeScript => ("access.ts", typescript(model)),
+        TargetLanguage::Python => ("access.py", python(model)),
+        other => return Err(AccessError::UnsupportedLanguage(format!("{:?}", other))),
+    };
+    Ok(GeneratedFile {
+        path: path.to_string(),
+        contents,
+    })
+}
+
+/// `team-lead` as a type or variant name, `TeamLead`
+fn pascal(role: &str) -> String {
+    role.split(['-', '_'])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut part = part.to_ascii_lowercase();
+            part[..1].make_ascii_uppercase();
+            part
+        })
+        .collect()
+}
+
+/// `team-lead` as a Python member name, `TEAM_LEAD`
+fn constant(role: &str) -> String {
+    role.replace('-', "_").to_ascii_uppercase()
+}
+
+/// Every permission granted in the model, with the roles holding it
+fn permissions(model: &AccessModel) -> Vec<(&Permission, BTreeSet<&str>)> {
+    let granted: BTreeSet<&Permission> = model.grants.values().flatten().collect();
+    granted
+        .into_iter()
+        .map(|permission| (permission, model.holders(permission)))
+        .collect()
+}
+
+fn rust(model: &AccessModel) -> String {
+    let mut variants = String::new();
+    let mut names = String::new();
+    let mut parse = String::new();
+    let mut implies = String::new();
+    for role in model.roles.keys() {
+        let variant = pascal(role);
+        let _ = writeln!(variants, "    {},", variant);
+        let _ = writeln!(names, "            Role::{} => \"{}\",", variant, role);
+        let _ = writeln!(parse, "            \"{}\" => Some(Role::{}),", role, variant);
+        let juniors: Vec<String> = model
+            .juniors(role)
+            .into_iter()
+            .map(|junior| format!("Role::{}", pascal(junior)))
+            .collect();
+        let _ = writeln!(
+            implies,
+            "            Role::{} => matches!(required, {}),",
+            variant,
+            juniors.join(" | ")
+        );
+    }
+    let mut can = String::new();
+    for (permission, holders) in permissions(model) {
+        let holders: Vec<String> = holders
+            .into_iter()
+            .map(|role| format!("Role::{}", pascal(role)))
+            .collect();
+        let _ = writeln!(
+            can,
+            "            (\"{}\", \"{}\") => matches!(self, {}),",
+            permission.action,
+            permission.resource,
+            holders.join(" | ")
+        );
+    }
+    format!(
+        r#"//! Roles and role checks; the hierarchy and grants are fixed at generation
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {{
+{variants}}}
+
+impl Role {{
+    pub const fn name(self) -> &'static str {{
+        match self {{
+{names}        }}
+    }}
+
+    pub fn parse(name: &str) -> Option<Role> {{
+        match name {{
+{parse}            _ => None,
+        }}
+    }}
+
+    /// Whether holding `self` makes one a `required`
+    pub const fn implies(self, required: Role) -> bool {{
+        match self {{
+{implies}        }}
+    }}
+
+    /// Whether `self` may perform `action` on `resource`
+    pub fn can(self, action: &str, resource: &str) -> bool {{
+        match (action, resource) {{
+{can}            _ => false,
+        }}
+    }}
+}}
+"#
+    )
+}
+
+fn typescript(model: &AccessModel) -> String {
+    let mut members = String::new();
+    let mut implies = String::new();
+    for role in model.roles.keys() {
+        let _ = writeln!(members, "  {} = \"{}\",", pascal(role), role);
+        let juniors: Vec<String> = model
+            .juniors(role)
+            .into_iter()
+            .map(|junior| format!("Role.{}", pascal(junior)))
+            .collect();
+        let _ = writeln!(implies, "  [Role.{}]: [{}],", pascal(role), juniors.join(", "));
+    }
+    let mut grants = String::new();
+    for (permission, holders) in permissions(model) {
+        let holders: Vec<String> = holders
+            .into_iter()
+            .map(|role| format!("Role.{}", pascal(role)))
+            .collect();
+        let _ = writeln!(grants, "  \"{}\": [{}],", permission, holders.join(", "));
+    }
+    format!(
+        r#"// Roles and role checks; the hierarchy and grants are fixed at generation
+
+export enum Role {{
+{members}}}
+
+/** Each role and the roles it implies, itself included */
+const IMPLIES: Record<Role, readonly Role[]> = {{
+{implies}}};
+
+/** Each granted `action:resource` and the roles holding it */
+const GRANTS: Record<string, readonly Role[]> = {{
+{grants}}};
+
+/** Whether holding `role` makes one a `required` */
+export function implies(role: Role, required: Role): boolean {{
+  return IMPLIES[role].includes(required);
+}}
+
+/** Whether `role` may perform `action` on `resource` */
+export function can(role: Role, action: string, resource: string): boolean {{
+  return GRANTS[`${{action}}:${{resource}}`]?.includes(role) ?? false;
+}}
+"#
+    )
+}
+
+fn python(model: &AccessModel) -> String {
+    let mut members = String::new();
+    let mut implies = String::new();
+    for role in model.roles.keys() {
+        let _ = writeln!(members, "    {} = \"{}\"", constant(role), role);
+        let juniors: Vec<String> = model
+            .juniors(role)
+            .into_iter()
+            .map(|junior| format!("Role.{}", constant(junior)))
+            .collect();
+        let _ = writeln!(
+            implies,
+            "    Role.{}: frozenset({{{}}}),",
+            constant(role),
+            juniors.join(", ")
+        );
+    }
+    let mut grants = String::new();
+    for (permission, holders) in permissions(model) {
+        let holders: Vec<String> = holders
+            .into_iter()
+            .map(|role| format!("Role.{}", constant(role)))
+            .collect();
+        let _ = writeln!(
+            grants,
+            "    (\"{}\", \"{}\"): frozenset({{{}}}),",
+            permission.action,
+            permission.resource,
+            holders.join(", ")
+        );
+    }
+    format!(
+        r#""""Roles and role checks; the hierarchy and grants are fixed at generation"""
+
+from enum import Enum
+
+
+class Role(str, Enum):
+{members}
+
+# Each role and the roles it implies, itself included
+_IMPLIES = {{
+{implies}}}
+
+# Each granted (action, resource) and the roles holding it
+_GRANTS = {{
+{grants}}}
+
+
+def implies(role: Role, required: Role) -> bool:
+    """Whether holding `role` makes one a `required`"""
+    return required in _IMPLIES[role]
+
+
+def can(role: Role, action: str, resource: str) -> bool:
+    """Whether `role` may perform `action` on `resource`"""
+    return role in _GRANTS.get((action, resource), frozenset())
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_modules() {
+        let mut model = AccessModel::new();
+        model
+            .add_role("admin")
+            .unwrap()
+            .add_role("team-lead")
+            .unwrap()
+            .add_role("user")
+            .unwrap();
+        model
+            .inherit("admin", "team-lead")
+            .unwrap()
+            .inherit("team-lead", "user")
+            .unwrap();
+        model
+            .grant("team-lead", Permission::parse("approve:expense").unwrap())
+            .unwrap();
+
+        let rust = role_module(&model, TargetLanguage::Rust).unwrap();
+        assert_eq!(rust.path, "access.rs");
+        assert!(rust
+            .contents
+            .contains("pub enum Role {\n    Admin,\n    TeamLead,\n    User,\n}\n"));
+        assert!(rust
+            .contents
+            .contains("Role::TeamLead => matches!(required, Role::TeamLead | Role::User),"));
+        assert!(rust
+            .contents
+            .contains("(\"approve\", \"expense\") => matches!(self, Role::Admin | Role::TeamLead),"));
+
+        let typescript = role_module(&model, TargetLanguage::TypeScript).unwrap().contents;
+        assert!(typescript.contains("  TeamLead = \"team-lead\",\n"));
+        assert!(typescript.contains("  \"approve:expense\": [Role.Admin, Role.TeamLead],\n"));
+
+        let python = role_module(&model, TargetLanguage::Python).unwrap().contents;
+        assert!(python.contains("    TEAM_LEAD = \"team-lead\"\n"));
+        assert!(python.contains("    Role.USER: frozenset({Role.USER}),\n"));
+
+        assert!(matches!(
+            role_module(&model, TargetLanguage::Zig),
+            Err(AccessError::UnsupportedLanguage(_))
+        ));
+        assert_eq!(
+            role_module(&AccessModel::new(), TargetLanguage::Rust),
+            Err(AccessError::NoRoles)
+        );
+    }
+}
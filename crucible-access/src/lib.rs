@@ -0,0 +1,546 @@
+//! Crucible Access - Access-Control Domain Package
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! Access requirements are about roles, and roles are not plain strings:
+//! "admin implies moderator" makes every admin a moderator, and a
+//! permission granted to a moderator is held by every admin too. Written
+//! out as `role == "admin" OR role == "moderator"`, the hierarchy is copied
+//! into each requirement and drifts as roles are added. An `AccessModel`
+//! records the roles, which role inherits which, the permissions
+//! (action on resource) granted to each, and which Schema fields hold a
+//! role, and then
+//!
+//! - `lower` rewrites a constraint tree over those fields for the solver:
+//!   roles become integer codes, `role >= "moderator"` ("at least
+//!   moderator") becomes the roles that imply moderator, and
+//!   `role contains "delete:post"` the roles that hold the permission;
+//! - `smt_lib` encodes the hierarchy for Z3, with its axioms (reflexive,
+//!   transitive, antisymmetric) as queries expected to be `unsat`;
+//! - `codegen::role_module` emits role-check helpers (`implies`, `can`) for
+//!   Rust, TypeScript and Python;
+//! - the `verification` feature adds the `AccessCheck` pass, reporting
+//!   unknown roles and equality chains that spell out a hierarchy check.
+//!
+//! ```
+//! use crucible_access::{AccessModel, Permission};
+//! use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+//!
+//! let mut model = AccessModel::new();
+//! model.add_role("admin").unwrap().add_role("moderator").unwrap().add_role("user").unwrap();
+//! model.inherit("admin", "moderator").unwrap().inherit("moderator", "user").unwrap();
+//! model.grant("moderator", Permission::parse("delete:post").unwrap()).unwrap();
+//! model.add_subject("role");
+//! assert!(model.implies("admin", "user"));
+//!
+//! let tree = CompoundConstraint::Simple(Constraint {
+//!     left_variable: "role".to_string(),
+//!     operator: ConstraintOperator::Contains,
+//!     right_value: "\"delete:post\"".to_string(),
+//! });
+//! // Codes follow role names: admin 0, moderator 1, user 2
+//! assert_eq!(
+//!     model.lower(&tree).unwrap().to_string(),
+//!     "((role == 0 OR role == 1) AND role >= 0 AND role < 3)"
+//! );
+//! ```
+
+pub mod codegen;
+#[cfg(feature = "verification")]
+mod pass;
+
+#[cfg(feature = "verification")]
+pub use pass::{AccessCheck, ROLE_CHAIN, UNKNOWN_ROLE};
+
+use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write as _};
+use thiserror::Error;
+
+/// Errors raised by the access model
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AccessError {
+    #[error("`{0}` is not a role, action or resource name (letters, digits, `_` and `-`)")]
+    InvalidName(String),
+
+    #[error("`{0}` is not a role of the access model")]
+    UnknownRole(String),
+
+    #[error("`{0}` is not a permission (`action:resource`)")]
+    InvalidPermission(String),
+
+    #[error("`{junior}` already inherits `{senior}`; the hierarchy would have a cycle")]
+    Cycle { senior: String, junior: String },
+
+    #[error("the access model has no roles")]
+    NoRoles,
+
+    #[error("no role-check helpers for {0}")]
+    UnsupportedLanguage(String),
+}
+
+/// An action on a resource, written `action:resource`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Permission {
+    pub action: String,
+    pub resource: String,
+}
+
+impl Permission {
+    pub fn new(action: &str, resource: &str) -> Result<Self, AccessError> {
+        Ok(Self {
+            action: name(action)?,
+            resource: name(resource)?,
+        })
+    }
+
+    /// `delete:post`
+    pub fn parse(text: &str) -> Result<Self, AccessError> {
+        let (action, resource) = text
+            .split_once(':')
+            .ok_or_else(|| AccessError::InvalidPermission(text.to_string()))?;
+        Self::new(action, resource)
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.action, self.resource)
+    }
+}
+
+/// Roles, their hierarchy and grants, and the fields that hold a role
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessModel {
+    /// Role -> the roles it inherits directly
+    pub roles: BTreeMap<String, BTreeSet<String>>,
+    /// Role -> the permissions granted to it directly
+    pub grants: BTreeMap<String, BTreeSet<Permission>>,
+    /// Fields whose value is a role
+    pub subjects: BTreeSet<String>,
+}
+
+impl AccessModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_role(&mut self, role: &str) -> Result<&mut Self, AccessError> {
+        self.roles.entry(name(role)?).or_default();
+        Ok(self)
+    }
+
+    /// Every `senior` is also a `junior` and holds its permissions
+    pub fn inherit(&mut self, senior: &str, junior: &str) -> Result<&mut Self, AccessError> {
+        self.role(senior)?;
+        self.role(junior)?;
+        if self.implies(junior, senior) {
+            return Err(AccessError::Cycle {
+                senior: senior.to_string(),
+                junior: junior.to_string(),
+            });
+        }
+        self.roles
+            .entry(senior.to_string())
+            .or_default()
+            .insert(junior.to_string());
+        Ok(self)
+    }
+
+    pub fn grant(&mut self, role: &str, permission: Permission) -> Result<&mut Self, AccessError> {
+        self.role(role)?;
+        self.grants.entry(role.to_string()).or_default().insert(permission);
+        Ok(self)
+    }
+
+    /// Declare `field` a role
+    pub fn add_subject(&mut self, field: &str) -> &mut Self {
+        self.subjects.insert(field.to_string());
+        self
+    }
+
+    /// Whether holding `role` makes one a `required`; every role implies itself
+    pub fn implies(&self, role: &str, required: &str) -> bool {
+        self.juniors(role).contains(required)
+    }
+
+    /// `role` and every role it inherits, directly or not
+    pub fn juniors(&self, role: &str) -> BTreeSet<&str> {
+        let mut reached = BTreeSet::new();
+        let mut pending: Vec<&str> = self
+            .roles
+            .get_key_value(role)
+            .map(|(role, _)| role.as_str())
+            .into_iter()
+            .collect();
+        while let Some(role) = pending.pop() {
+            if reached.insert(role) {
+                pending.extend(self.roles[role].iter().map(String::as_str));
+            }
+        }
+        reached
+    }
+
+    /// `role` and every role that implies it
+    pub fn seniors(&self, role: &str) -> BTreeSet<&str> {
+        self.roles
+            .keys()
+            .map(String::as_str)
+            .filter(|senior| self.implies(senior, role))
+            .collect()
+    }
+
+    /// The permissions `role` holds, its own and inherited
+    pub fn permissions(&self, role: &str) -> BTreeSet<&Permission> {
+        self.juniors(role)
+            .into_iter()
+            .filter_map(|junior| self.grants.get(junior))
+            .flatten()
+            .collect()
+    }
+
+    /// The roles holding `permission`
+    pub fn holders(&self, permission: &Permission) -> BTreeSet<&str> {
+        self.roles
+            .keys()
+            .map(String::as_str)
+            .filter(|role| self.permissions(role).contains(permission))
+            .collect()
+    }
+
+    /// The integer `lower` gives `role`: its position in name order
+    pub fn code(&self, role: &str) -> Option<usize> {
+        self.roles.keys().position(|known| known == role)
+    }
+
+    /// `tree` with every comparison of a role field lowered to integer role
+    /// codes, and each such field bounded to the codes:
+    ///
+    /// - `role == "x"`, `role != "x"`: the code of `x`
+    /// - `role >= "x"` (at least `x`), `role > "x"`: the roles implying `x`
+    /// - `role <= "x"` (at most `x`), `role < "x"`: the roles `x` implies
+    /// - `role contains "action:resource"`: the roles holding the permission
+    ///
+    /// Comparisons between two role fields are left alone. Fails on the
+    /// first unknown role or permission
+    pub fn lower(&self, tree: &CompoundConstraint) -> Result<CompoundConstraint, AccessError> {
+        let mut used = BTreeSet::new();
+        let lowered = self.map_roles(tree, &mut used)?;
+        if used.is_empty() {
+            return Ok(lowered);
+        }
+        let mut parts = vec![lowered];
+        for field in used {
+            parts.push(compare(&field, ConstraintOperator::GreaterThanOrEqual, "0"));
+            parts.push(compare(
+                &field,
+                ConstraintOperator::LessThan,
+                &self.roles.len().to_string(),
+            ));
+        }
+        Ok(CompoundConstraint::And(parts))
+    }
+
+    /// One comparison of a role field, lowered; `None` when `constraint`
+    /// compares no role field with a role or permission
+    pub fn lower_constraint<'a>(
+        &'a self,
+        constraint: &'a Constraint,
+    ) -> Result<Option<CompoundConstraint>, AccessError> {
+        let field = constraint.left_variable.as_str();
+        if !self.subjects.contains(field) || self.subjects.contains(&constraint.right_value) {
+            return Ok(None);
+        }
+        let value = unquote(&constraint.right_value);
+        let codes = |roles: BTreeSet<&str>| roles.into_iter().filter_map(|role| self.code(role)).collect();
+        let strict = |mut roles: BTreeSet<&'a str>| {
+            roles.remove(value);
+            roles
+        };
+        let (codes, negated): (Vec<usize>, bool) = match constraint.operator {
+            ConstraintOperator::Equal => (vec![self.role(value)?], false),
+            ConstraintOperator::NotEqual => (vec![self.role(value)?], true),
+            ConstraintOperator::GreaterThanOrEqual => (codes(self.seniors(self.name(value)?)), false),
+            ConstraintOperator::GreaterThan => (codes(strict(self.seniors(self.name(value)?))), false),
+            ConstraintOperator::LessThanOrEqual => (codes(self.juniors(self.name(value)?)), false),
+            ConstraintOperator::LessThan => (codes(strict(self.juniors(self.name(value)?))), false),
+            ConstraintOperator::Contains => (codes(self.holders(&Permission::parse(value)?)), false),
+            ConstraintOperator::DoesNotContain => (codes(self.holders(&Permission::parse(value)?)), true),
+        };
+        let mut alternatives: Vec<CompoundConstraint> = codes
+            .into_iter()
+            .map(|code| compare(field, ConstraintOperator::Equal, &code.to_string()))
+            .collect();
+        let lowered = match alternatives.len() {
+            1 => alternatives.remove(0),
+            _ => CompoundConstraint::Or(alternatives),
+        };
+        Ok(Some(if negated {
+            CompoundConstraint::Not(Box::new(lowered))
+        } else {
+            lowered
+        }))
+    }
+
+    /// The role an `Or` of equalities on one role field allows, when those
+    /// are exactly the roles implying it: `role == "admin" OR role ==
+    /// "moderator"` is `role >= "moderator"` when only admin implies moderator
+    pub fn as_hierarchy_check(&self, tree: &CompoundConstraint) -> Option<Constraint> {
+        let CompoundConstraint::Or(parts) = tree else {
+            return None;
+        };
+        let mut field = None;
+        let mut roles = BTreeSet::new();
+        for part in parts {
+            let CompoundConstraint::Simple(constraint) = part else {
+                return None;
+            };
+            let value = unquote(&constraint.right_value);
+            let same_field = *field.get_or_insert(constraint.left_variable.as_str()) == constraint.left_variable;
+            let is_role_equality = constraint.operator == ConstraintOperator::Equal
+                && self.subjects.contains(&constraint.left_variable)
+                && self.roles.contains_key(value);
+            if !same_field || !is_role_equality {
+                return None;
+            }
+            roles.insert(value);
+        }
+        let role = roles
+            .iter()
+            .find(|role| self.seniors(role) == roles)
+            .filter(|_| roles.len() > 1)?;
+        Some(Constraint {
+            left_variable: field?.to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: format!("\"{}\"", role),
+        })
+    }
+
+    /// The hierarchy and grants as SMT-LIB: a `Role` datatype, `implies`
+    /// (the reflexive, transitive closure of `inherits`) and `permits`, then
+    /// one query per hierarchy axiom, each expected to be `unsat`
+    pub fn smt_lib(&self) -> String {
+        let mut out = String::from("(set-logic ALL)\n");
+        let constructors: Vec<String> = self.roles.keys().map(|role| format!("({})", role)).collect();
+        let _ = writeln!(out, "(declare-datatypes ((Role 0)) (({})))", constructors.join(" "));
+
+        let pairs = |holds: &dyn Fn(&str, &str) -> bool| {
+            let mut pairs = Vec::new();
+            for senior in self.roles.keys() {
+                for junior in self.roles.keys() {
+                    if holds(senior, junior) {
+                        pairs.push(format!("(and (= a {}) (= b {}))", senior, junior));
+                    }
+                }
+            }
+            disjunction(pairs)
+        };
+        let direct = |senior: &str, junior: &str| self.roles[senior].contains(junior);
+        let closure = |senior: &str, junior: &str| self.implies(senior, junior);
+        let _ = writeln!(out, "(define-fun inherits ((a Role) (b Role)) Bool {})", pairs(&direct));
+        let _ = writeln!(out, "(define-fun implies ((a Role) (b Role)) Bool {})", pairs(&closure));
+
+        let grants = self
+            .grants
+            .iter()
+            .flat_map(|(role, permissions)| {
+                permissions.iter().map(move |permission| {
+                    format!(
+                        "(and (implies r {}) (= action \"{}\") (= resource \"{}\"))",
+                        role, permission.action, permission.resource
+                    )
+                })
+            })
+            .collect();
+        let _ = writeln!(
+            out,
+            "(define-fun permits ((r Role) (action String) (resource String)) Bool {})",
+            disjunction(grants)
+        );
+
+        for (axiom, statement) in [
+            (
+                "inherited",
+                "(forall ((a Role) (b Role)) (=> (inherits a b) (implies a b)))",
+            ),
+            ("reflexive", "(forall ((a Role)) (implies a a))"),
+            (
+                "transitive",
+                "(forall ((a Role) (b Role) (c Role)) (=> (and (implies a b) (implies b c)) (implies a c)))",
+            ),
+            (
+                "antisymmetric",
+                "(forall ((a Role) (b Role)) (=> (and (implies a b) (implies b a)) (= a b)))",
+            ),
+        ] {
+            let _ = writeln!(
+                out,
+                "\n; {} (expect unsat)\n(push 1)\n(assert (not {}))\n(check-sat)\n(pop 1)",
+                axiom, statement
+            );
+        }
+        out
+    }
+
+    fn role(&self, role: &str) -> Result<usize, AccessError> {
+        self.code(role)
+            .ok_or_else(|| AccessError::UnknownRole(role.to_string()))
+    }
+
+    /// `role`, borrowed from the model
+    fn name<'a>(&self, role: &'a str) -> Result<&'a str, AccessError> {
+        self.role(role).map(|_| role)
+    }
+
+    fn map_roles(
+        &self,
+        tree: &CompoundConstraint,
+        used: &mut BTreeSet<String>,
+    ) -> Result<CompoundConstraint, AccessError> {
+        let mut all = |parts: &[CompoundConstraint]| -> Result<Vec<_>, AccessError> {
+            parts.iter().map(|part| self.map_roles(part, used)).collect()
+        };
+        Ok(match tree {
+            CompoundConstraint::Simple(constraint) => match self.lower_constraint(constraint)? {
+                Some(lowered) => {
+                    used.insert(constraint.left_variable.clone());
+                    lowered
+                }
+                None => tree.clone(),
+            },
+            CompoundConstraint::And(parts) => CompoundConstraint::And(all(parts)?),
+            CompoundConstraint::Or(parts) => CompoundConstraint::Or(all(parts)?),
+            CompoundConstraint::ExactlyOne(parts) => CompoundConstraint::ExactlyOne(all(parts)?),
+            CompoundConstraint::AtMostOne(parts) => CompoundConstraint::AtMostOne(all(parts)?),
+            CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(self.map_roles(inner, used)?)),
+            CompoundConstraint::Implies(antecedent, consequent) => CompoundConstraint::Implies(
+                Box::new(self.map_roles(antecedent, used)?),
+                Box::new(self.map_roles(consequent, used)?),
+            ),
+            CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => tree.clone(),
+        })
+    }
+}
+
+/// `name` if it is a valid role, action or resource name
+fn name(name: &str) -> Result<String, AccessError> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(name.to_string())
+    } else {
+        Err(AccessError::InvalidName(name.to_string()))
+    }
+}
+
+/// `"admin"` (a string literal) or `admin`, as `admin`
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+fn compare(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+    CompoundConstraint::Simple(Constraint {
+        left_variable: left.to_string(),
+        operator,
+        right_value: right.to_string(),
+    })
+}
+
+/// `(or ...)`, `false` when empty
+fn disjunction(mut terms: Vec<String>) -> String {
+    match terms.len() {
+        0 => "false".to_string(),
+        1 => terms.remove(0),
+        _ => format!("(or {})", terms.join(" ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> AccessModel {
+        let mut model = AccessModel::new();
+        for role in ["admin", "auditor", "moderator", "user"] {
+            model.add_role(role).unwrap();
+        }
+        model
+            .inherit("admin", "moderator")
+            .unwrap()
+            .inherit("moderator", "user")
+            .unwrap()
+            .inherit("auditor", "user")
+            .unwrap();
+        model.grant("user", Permission::parse("read:post").unwrap()).unwrap();
+        model
+            .grant("moderator", Permission::parse("delete:post").unwrap())
+            .unwrap();
+        model.add_subject("role");
+        model
+    }
+
+    #[test]
+    fn test_hierarchy_and_lowering() {
+        let mut model = model();
+        assert!(model.implies("admin", "user") && !model.implies("auditor", "moderator"));
+        assert_eq!(model.seniors("moderator"), BTreeSet::from(["admin", "moderator"]));
+        assert_eq!(model.permissions("admin").len(), 2);
+        assert_eq!(model.holders(&Permission::parse("read:post").unwrap()).len(), 4);
+        assert!(matches!(model.inherit("user", "admin"), Err(AccessError::Cycle { .. })));
+        assert!(matches!(
+            model.inherit("user", "guest"),
+            Err(AccessError::UnknownRole(_))
+        ));
+        assert!(matches!(
+            Permission::parse("delete"),
+            Err(AccessError::InvalidPermission(_))
+        ));
+        assert!(matches!(model.add_role("super user"), Err(AccessError::InvalidName(_))));
+
+        // admin 0, auditor 1, moderator 2, user 3
+        let lowered = |operator, right: &str| model.lower(&compare("role", operator, right)).unwrap().to_string();
+        assert_eq!(
+            lowered(ConstraintOperator::Equal, "\"auditor\""),
+            "(role == 1 AND role >= 0 AND role < 4)"
+        );
+        assert!(lowered(ConstraintOperator::GreaterThanOrEqual, "moderator").starts_with("((role == 0 OR role == 2)"));
+        assert!(lowered(ConstraintOperator::GreaterThan, "moderator").starts_with("(role == 0 AND"));
+        assert!(lowered(ConstraintOperator::LessThan, "auditor").starts_with("(role == 3 AND"));
+        assert!(lowered(ConstraintOperator::DoesNotContain, "\"delete:post\"").starts_with("(NOT (role == 0 OR"));
+        let count = compare("count", ConstraintOperator::LessThan, "3");
+        assert_eq!(model.lower(&count).unwrap(), count);
+        let unknown = compare("role", ConstraintOperator::Equal, "\"guest\"");
+        assert_eq!(
+            model.lower(&unknown),
+            Err(AccessError::UnknownRole("guest".to_string()))
+        );
+
+        let chain = CompoundConstraint::Or(vec![
+            compare("role", ConstraintOperator::Equal, "\"moderator\""),
+            compare("role", ConstraintOperator::Equal, "\"admin\""),
+        ]);
+        assert_eq!(
+            model.as_hierarchy_check(&chain).unwrap().to_string(),
+            "role >= \"moderator\""
+        );
+        let CompoundConstraint::Or(mut parts) = chain else {
+            unreachable!()
+        };
+        parts.pop();
+        assert!(model.as_hierarchy_check(&CompoundConstraint::Or(parts)).is_none());
+
+        let smt = model.smt_lib();
+        assert!(smt.contains("(declare-datatypes ((Role 0)) (((admin) (auditor) (moderator) (user))))"));
+        assert!(smt.contains("(and (implies r moderator) (= action \"delete\") (= resource \"post\"))"));
+        assert_eq!(smt.matches("(check-sat)").count(), 4);
+    }
+}
@@ -0,0 +1,106 @@
+//! Role checks as a verification pass
+
+use crate::AccessModel;
+use crucible_core::{CompoundConstraint, Diagnostic};
+use crucible_verification::{Level, PassContext, VerificationPass, VerificationResult};
+
+/// A constraint names a role or permission the model does not have
+pub const UNKNOWN_ROLE: &str = "A001";
+/// An `Or` of role equalities spells out `role >= "x"`
+pub const ROLE_CHAIN: &str = "A002";
+
+/// Reports role comparisons `AccessModel::lower` rejects as errors, and
+/// equality chains that are a hierarchy check as notes; register it with
+/// `PassManager::register`
+pub struct AccessCheck {
+    model: AccessModel,
+}
+
+impl AccessCheck {
+    pub fn new(model: AccessModel) -> Self {
+        Self { model }
+    }
+
+    fn visit(&self, tree: &CompoundConstraint, out: &mut Vec<(Level, Diagnostic)>) {
+        if let Some(check) = self.model.as_hierarchy_check(tree) {
+            let message = format!(
+                "`{}` is `{}`; the hierarchy already implies the other roles",
+                tree, check
+            );
+            let diagnostic = Diagnostic::new(ROLE_CHAIN, message)
+                .with_arg("field", &check.left_variable)
+                .with_arg("role", check.right_value.trim_matches('"'));
+            out.push((Level::Note, diagnostic));
+            return;
+        }
+        match tree {
+            CompoundConstraint::Simple(constraint) => {
+                if let Err(error) = self.model.lower_constraint(constraint) {
+                    let diagnostic = Diagnostic::new(UNKNOWN_ROLE, format!("`{}`: {}", constraint, error))
+                        .with_arg("constraint", constraint);
+                    out.push((Level::Error, diagnostic));
+                }
+            }
+            CompoundConstraint::And(parts)
+            | CompoundConstraint::Or(parts)
+            | CompoundConstraint::ExactlyOne(parts)
+            | CompoundConstraint::AtMostOne(parts) => parts.iter().for_each(|part| self.visit(part, out)),
+            CompoundConstraint::Not(inner) => self.visit(inner, out),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                self.visit(antecedent, out);
+                self.visit(consequent, out);
+            }
+            CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => {}
+        }
+    }
+}
+
+impl VerificationPass for AccessCheck {
+    fn name(&self) -> &str {
+        "access-control"
+    }
+
+    fn check(
+        &self,
+        tree: &CompoundConstraint,
+        _context: &PassContext<'_>,
+    ) -> VerificationResult<Vec<(Level, Diagnostic)>> {
+        let mut out = Vec::new();
+        self.visit(tree, &mut out);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
+    use crucible_verification::{PassManager, Z3Verifier};
+
+    fn is(role: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: "role".to_string(),
+            operator: ConstraintOperator::Equal,
+            right_value: format!("\"{}\"", role),
+        })
+    }
+
+    #[test]
+    fn test_access_check() {
+        let mut model = AccessModel::new();
+        model.add_role("admin").unwrap().add_role("moderator").unwrap();
+        model.inherit("admin", "moderator").unwrap();
+        model.add_subject("role");
+        let mut passes = PassManager::new();
+        passes.register(AccessCheck::new(model));
+
+        let tree = CompoundConstraint::And(vec![
+            CompoundConstraint::Or(vec![is("admin"), is("moderator")]),
+            is("guest"),
+        ]);
+        let findings = passes.run(&Z3Verifier::new(), None, &[tree]);
+        let found: Vec<_> = findings.iter().map(|f| (f.level, f.diagnostic.code.as_str())).collect();
+        assert_eq!(found, [(Level::Note, ROLE_CHAIN), (Level::Error, UNKNOWN_ROLE)]);
+        assert_eq!(findings[0].diagnostic.args["role"], "moderator");
+    }
+}
@@ -0,0 +1,135 @@
+//! Error responses
+//!
+//! Every failed request answers with the `ApiResponse` shape and
+//! `success: false`. When the pipeline rejected the input, the body also
+//! carries the `CrucibleError`, so clients can switch on its stage and code
+//! and point at its span:
+//!
+//! ```text
+//! {"success": false, "data": null, "message": "...",
+//!  "error": {"stage": "schema", "code": "S002", "message": "...", "args": {...}}}
+//! ```
+//!
+//! The `localize` middleware renders `message` in the best language of the
+//! request's `Accept-Language`; `error.message` stays English, and clients
+//! with their own catalogue can render `code` with `args` instead.
+
+use axum::{
+    extract::Request,
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use crucible_core::{CrucibleError, Locale};
+use serde::Serialize;
+
+/// A handler's failure: the status, and the pipeline error behind it if any
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    error: Option<CrucibleError>,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    success: bool,
+    data: Option<()>,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a CrucibleError>,
+}
+
+impl ApiError {
+    fn body(&self, locale: Locale) -> ErrorBody<'_> {
+        ErrorBody {
+            success: false,
+            data: None,
+            message: match &self.error {
+                Some(error) => error.localized(locale),
+                None => self.status.canonical_reason().unwrap_or("Request failed").to_string(),
+            },
+            error: self.error.as_ref(),
+        }
+    }
+}
+
+impl From<StatusCode> for ApiError {
+    fn from(status: StatusCode) -> Self {
+        Self { status, error: None }
+    }
+}
+
+impl From<CrucibleError> for ApiError {
+    fn from(error: CrucibleError) -> Self {
+        Self {
+            status: StatusCode::from_u16(error.http_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            error: Some(error),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut response = (self.status, Json(self.body(Locale::En))).into_response();
+        // For `localize` to render the message again
+        if let Some(error) = self.error {
+            response.extensions_mut().insert(error);
+        }
+        response
+    }
+}
+
+/// Render pipeline error messages in the language the client asked for
+pub async fn localize(request: Request, next: Next) -> Response {
+    let locale = request
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map_or(Locale::En, Locale::negotiate);
+    let mut response = next.run(request).await;
+    if locale == Locale::En {
+        return response;
+    }
+    match response.extensions_mut().remove::<CrucibleError>() {
+        Some(error) => {
+            let error = ApiError { status: response.status(), error: Some(error) };
+            (error.status, Json(error.body(locale))).into_response()
+        }
+        None => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::ConstraintError;
+
+    #[test]
+    fn test_error_body() {
+        let error = ApiError::from(CrucibleError::from(ConstraintError::Empty("OR")));
+        assert_eq!(error.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::to_value(error.body(Locale::En)).unwrap(),
+            serde_json::json!({
+                "success": false,
+                "data": null,
+                "message": "schema error [S003] empty OR group has nothing to combine",
+                "error": {
+                    "stage": "schema",
+                    "code": "S003",
+                    "message": "empty OR group has nothing to combine",
+                    "args": {"group": "OR"},
+                },
+            })
+        );
+        let body = error.body(Locale::negotiate("de-DE,de;q=0.9,en;q=0.8"));
+        assert_eq!(body.message, "Schemafehler [S003] leere OR-Gruppe hat nichts zu verknüpfen");
+
+        let error = ApiError::from(StatusCode::NOT_FOUND);
+        assert_eq!(
+            serde_json::to_value(error.body(Locale::De)).unwrap(),
+            serde_json::json!({"success": false, "data": null, "message": "Not Found"})
+        );
+        assert_eq!(error.into_response().status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -6,20 +6,114 @@
 //!
 //! Provisional Patent Application: 63/928,407
 
-use axum::
-    http::StatusCode,
+mod error;
+mod metrics;
+mod tenancy;
+#[cfg(feature = "verification")]
+mod verify;
+
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::{header, StatusCode},
+    middleware,
     response::Json,
     routing::{get, post},
     Router,
 };
-use crucible_core::{IntentAst, Requirement};
+use crucible_core::{
+    normalize_tag, split_tags, ChangeEntry, CrucibleConfig, CrucibleError, DependencyGraph, Impact, IntentAst,
+    LifecycleError, Requirement, RequirementStatus, StageTimings, TagError,
+};
+use crucible_parser::AddParsedRequirement;
+use error::ApiError;
+use metrics::Metrics;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tenancy::{Project, Tenants};
 
-use tower_http::cors::CorsLayer;
+use tower_http::{cors::CorsLayer, trace::TraceLayer};
 
 #[derive(Deserialize)]
 struct RequirementRequest {
+    /// Trailing hashtags (`... #payments`) become tags
     content: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Body of `POST /api/requirements/:id/tags`
+#[derive(Deserialize)]
+struct TagsRequest {
+    #[serde(default)]
+    add: Vec<String>,
+    #[serde(default)]
+    remove: Vec<String>,
+}
+
+/// `?tags=payments,security`: only the requirements carrying one of the tags
+#[derive(Deserialize)]
+struct TagQuery {
+    #[serde(default)]
+    tags: Option<String>,
+}
+
+impl TagQuery {
+    /// The filter's tags, normalized; 400 for one that is not a valid tag
+    fn tags(&self) -> Result<Vec<String>, StatusCode> {
+        self.tags
+            .iter()
+            .flat_map(|tags| tags.split(','))
+            .filter(|tag| !tag.trim().is_empty())
+            .map(|tag| normalize_tag(tag).map_err(|_| StatusCode::BAD_REQUEST))
+            .collect()
+    }
+}
+
+/// Body of `POST /api/requirements/:id/status`
+#[derive(Deserialize)]
+struct StatusRequest {
+    status: RequirementStatus,
+    author: String,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+/// Diagram syntax for `/api/ast/:id/graph`
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum GraphFormat {
+    #[default]
+    Mermaid,
+    Dot,
+}
+
+#[derive(Deserialize)]
+struct GraphQuery {
+    #[serde(default)]
+    format: GraphFormat,
+}
+
+#[derive(Deserialize)]
+struct ImpactQuery {
+    /// Requirement ID or field name
+    target: String,
+}
+
+/// What every tenant shares; each tenant's Intent-AST lives in its `Project`
+#[derive(Clone)]
+struct AppState {
+    metrics: Arc<Metrics>,
+    /// `crucible.toml` and `CRUCIBLE_*` settings, read once at startup
+    config: Arc<CrucibleConfig>,
+    /// Warm Z3 workers shared by the verification handlers
+    #[cfg(feature = "verification")]
+    verifiers: crucible_verification::VerifierPool,
+    /// Signs verification reports when `CRUCIBLE_SIGNING_KEY` names a key file
+    #[cfg(feature = "signing")]
+    signer: Option<Arc<crucible_core::ArtifactSigner>>,
 }
 
 #[derive(Serialize)]
@@ -27,18 +121,71 @@ struct ApiResponse<T> {
     success: bool,
     data: Option<T>,
     message: String,
+    /// Time spent in each stage of the request, in microseconds
+    #[serde(skip_serializing_if = "StageTimings::is_empty")]
+    timings: StageTimings,
+}
+
+/// Log to stderr, filtered by `RUST_LOG` (default `info`); set
+/// `CRUCIBLE_LOG_FORMAT=json` for one JSON object per event
+fn init_tracing() {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter).with_writer(std::io::stderr);
+    if std::env::var("CRUCIBLE_LOG_FORMAT").is_ok_and(|format| format.eq_ignore_ascii_case("json")) {
+        subscriber.json().with_current_span(true).init();
+    } else {
+        subscriber.init();
+    }
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    init_tracing();
     println!("🔥 Crucible Engine - Correct by Design, Not by Debugging");
     println!("🚀 Starting API server on http://localhost:3000");
 
+    let config = Arc::new(CrucibleConfig::load()?);
+    let metrics = Arc::new(Metrics::new()?);
+    let tenants = Arc::new(Tenants::from_config(&config)?);
+    let state = AppState {
+        metrics: metrics.clone(),
+        #[cfg(feature = "verification")]
+        verifiers: verify::verifier_pool(&config)?,
+        #[cfg(feature = "signing")]
+        signer: match std::env::var("CRUCIBLE_SIGNING_KEY") {
+            Ok(path) => Some(Arc::new(crucible_core::ArtifactSigner::load(&path)?)),
+            Err(_) => None,
+        },
+        config,
+    };
+
     let app = Router::new()
-        .route("/", get(health_check))
+        .route("/api/config", get(get_config))
         .route("/api/requirements", post(add_requirement))
+        .route("/api/requirements/:id/status", post(set_requirement_status))
+        .route("/api/requirements/:id/history", get(get_requirement_history))
+        .route("/api/requirements/:id/tags", post(set_requirement_tags))
+        .route("/api/tags", get(get_tags))
         .route("/api/ast", get(get_ast))
-        .layer(CorsLayer::permissive());
+        .route("/api/ast/:id/graph", get(get_ast_graph))
+        .route("/api/ast/:id/dependencies", get(get_dependencies))
+        .route("/api/ast/:id/impact", get(get_impact));
+    #[cfg(feature = "verification")]
+    let app = app
+        .route("/api/ast/:id/verify", post(verify::verify_ast))
+        .route("/api/ast/:id/optimize", post(verify::optimize_variable))
+        .route("/api/scenario", post(verify::check_scenario));
+    // Only the `/api` routes belong to a tenant
+    let app = app
+        .route_layer(middleware::from_fn_with_state(tenants, tenancy::authenticate))
+        .route("/", get(health_check))
+        .route("/metrics", get(get_metrics))
+        .layer(middleware::from_fn_with_state(metrics, metrics::track_requests))
+        .layer(middleware::from_fn(error::localize))
+        .layer(CorsLayer::permissive())
+        .layer(TraceLayer::new_for_http())
+        .with_state(state);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
     axum::serve(listener, app).await?;
@@ -51,30 +198,288 @@ async fn health_check() -> Json<ApiResponse<String>> {
         success: true,
         data: Some("Crucible Engine API".to_string()),
         message: "System operational".to_string(),
+        timings: StageTimings::new(),
+    })
+}
+
+/// The settings the server is running with, without the tenant list
+async fn get_config(State(state): State<AppState>) -> Json<ApiResponse<CrucibleConfig>> {
+    let config = CrucibleConfig {
+        tenants: Vec::new(),
+        ..(*state.config).clone()
+    };
+    Json(ApiResponse {
+        success: true,
+        data: Some(config),
+        message: "Effective configuration".to_string(),
+        timings: StageTimings::new(),
     })
 }
 
+/// Prometheus scrape endpoint
+async fn get_metrics(State(state): State<AppState>) -> ([(header::HeaderName, &'static str); 1], String) {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
+
+/// A stored requirement and how it relates to the ones before it
+#[derive(Serialize)]
+struct AddedRequirement {
+    #[serde(flatten)]
+    requirement: Requirement,
+    /// Why the text was stored without constraints
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_error: Option<CrucibleError>,
+    /// Absent when the new requirement has no constraints
+    #[cfg(feature = "verification")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    consistency: Option<verify::ConsistencyReport>,
+}
+
 async fn add_requirement(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
     Json(req): Json<RequirementRequest>,
-) -> Result<Json<ApiResponse<Requirement>>, StatusCode> {
-    let mut ast = IntentAst::new();
-    ast.add_requirement(req.content);
-    
-    let requirement = ast.requirements.last().unwrap().clone();
-    
+) -> Result<Json<ApiResponse<AddedRequirement>>, ApiError> {
+    let (content, mut tags) = split_tags(&req.content);
+    for tag in &req.tags {
+        tags.push(normalize_tag(tag).map_err(|_| StatusCode::UNPROCESSABLE_ENTITY)?);
+    }
+    let mut timings = StageTimings::new();
+    let mut parse_error = None;
+    let requirement = {
+        let mut ast = project.ast.write().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        project.check_quota(&ast)?;
+        timings.time("parse", || {
+            // Text the grammar cannot read is still recorded, without constraints
+            if let Err(e) = ast.add_requirement_parsed(content) {
+                tracing::debug!(error = %e, "requirement stored unparsed");
+                ast.add_requirement(content.to_string());
+                parse_error = Some(CrucibleError::from(e));
+            }
+        });
+        let id = ast.requirements.last().unwrap().id;
+        ast.tag(id, &tags).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        match &req.author {
+            Some(author) => ast.attribute(id, author).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?.clone(),
+            None => ast.requirements.last().unwrap().clone(),
+        }
+    };
+    #[cfg(feature = "verification")]
+    let consistency = verify::classify_requirement(&state, &project, &requirement, &mut timings).await?;
+    // Only a solver run marks a requirement verified
+    #[cfg(feature = "verification")]
+    let requirement = match &consistency {
+        Some(report) if report.is_consistent() => {
+            let mut ast = project.ast.write().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            let note = Some(report.summary());
+            match ast.transition(requirement.id, RequirementStatus::Verified, None, note) {
+                Ok(verified) => verified.clone(),
+                // Removed or moved on in the meantime
+                Err(_) => requirement,
+            }
+        }
+        _ => requirement,
+    };
+    state.metrics.observe_stages(&timings);
+
+    #[cfg(feature = "verification")]
+    let message = consistency.as_ref().map_or_else(|| "Requirement added".to_string(), |c| c.summary());
+    #[cfg(not(feature = "verification"))]
+    let message = "Requirement added".to_string();
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(AddedRequirement {
+            requirement,
+            parse_error,
+            #[cfg(feature = "verification")]
+            consistency,
+        }),
+        message,
+        timings,
+    }))
+}
+
+/// Move a requirement through its lifecycle (`draft`, `parsed`,
+/// `code_generated`, `deprecated`); 409 for a transition the lifecycle
+/// forbids. Only a solver run makes a requirement `verified`, so asking for it
+/// here is a 422.
+async fn set_requirement_status(
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+    Json(req): Json<StatusRequest>,
+) -> Result<Json<ApiResponse<Requirement>>, ApiError> {
+    if req.status == RequirementStatus::Verified {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY.into());
+    }
+    let id = id.parse().map_err(|_| StatusCode::NOT_FOUND)?;
+    let mut ast = project.ast.write().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let requirement = ast
+        .transition(id, req.status, Some(&req.author), req.note)
+        .map_err(|e| match e {
+            LifecycleError::UnknownRequirement(_) => StatusCode::NOT_FOUND,
+            LifecycleError::InvalidTransition { .. } => StatusCode::CONFLICT,
+        })?
+        .clone();
+
     Ok(Json(ApiResponse {
         success: true,
+        message: format!("Requirement is now {:?}", requirement.status),
         data: Some(requirement),
-        message: "Requirement added".to_string(),
+        timings: StageTimings::new(),
     }))
 }
 
-async fn get_ast() -> Json<ApiResponse<IntentAst>> {
-    let ast = IntentAst::new();
-    
-    Json(ApiResponse {
+/// A requirement's change log, oldest first
+async fn get_requirement_history(
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<ChangeEntry>>>, ApiError> {
+    let ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let requirement = ast.requirements.iter().find(|r| r.id.to_string() == id).ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: format!("{} change(s)", requirement.history.len()),
+        data: Some(requirement.history.clone()),
+        timings: StageTimings::new(),
+    }))
+}
+
+/// Add and remove a requirement's tags; 422 for a malformed tag
+async fn set_requirement_tags(
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+    Json(req): Json<TagsRequest>,
+) -> Result<Json<ApiResponse<Requirement>>, ApiError> {
+    let id = id.parse().map_err(|_| StatusCode::NOT_FOUND)?;
+    let status = |e: TagError| match e {
+        TagError::UnknownRequirement(_) => StatusCode::NOT_FOUND,
+        TagError::InvalidTag(_) => StatusCode::UNPROCESSABLE_ENTITY,
+    };
+    let mut ast = project.ast.write().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // Check both lists before changing anything
+    for tag in req.add.iter().chain(&req.remove) {
+        normalize_tag(tag).map_err(status)?;
+    }
+    ast.tag(id, &req.add).map_err(status)?;
+    let requirement = ast.untag(id, &req.remove).map_err(status)?.clone();
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: format!("{} tag(s)", requirement.tags.len()),
+        data: Some(requirement),
+        timings: StageTimings::new(),
+    }))
+}
+
+/// Every tag in use, with how many requirements carry it
+async fn get_tags(
+    Extension(project): Extension<Arc<Project>>,
+) -> Result<Json<ApiResponse<BTreeMap<String, usize>>>, ApiError> {
+    let ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let counts: BTreeMap<String, usize> =
+        ast.tag_counts().into_iter().map(|(tag, count)| (tag.to_string(), count)).collect();
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: format!("{} tag(s)", counts.len()),
+        data: Some(counts),
+        timings: StageTimings::new(),
+    }))
+}
+
+/// The Intent-AST; with `?tags=`, only the requirements carrying one of them
+async fn get_ast(
+    Extension(project): Extension<Arc<Project>>,
+    Query(query): Query<TagQuery>,
+) -> Result<Json<ApiResponse<IntentAst>>, ApiError> {
+    let tags = query.tags()?;
+    let mut ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?.clone();
+    ast.requirements.retain(|requirement| requirement.matches_tags(&tags));
+
+    Ok(Json(ApiResponse {
         success: true,
         data: Some(ast),
         message: "Intent-AST retrieved".to_string(),
-    })
-}
\ No newline at end of file
+        timings: StageTimings::new(),
+    }))
+}
+
+/// Requirement and constraint structure as a diagram (`?format=mermaid|dot`)
+async fn get_ast_graph(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+    Query(query): Query<GraphQuery>,
+) -> Result<Json<ApiResponse<String>>, ApiError> {
+    let ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if ast.id.to_string() != id {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    let mut timings = StageTimings::new();
+    let graph = timings.time("render", || match query.format {
+        GraphFormat::Mermaid => ast.to_mermaid(),
+        GraphFormat::Dot => ast.to_dot(),
+    });
+    state.metrics.observe_stages(&timings);
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(graph),
+        message: "Intent-AST graph rendered".to_string(),
+        timings,
+    }))
+}
+
+/// Requirement <-> field links of the Intent-AST
+async fn get_dependencies(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<DependencyGraph>>, ApiError> {
+    let ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if ast.id.to_string() != id {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    let mut timings = StageTimings::new();
+    let graph = timings.time("analysis", || ast.dependency_graph());
+    state.metrics.observe_stages(&timings);
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(graph),
+        message: "Dependency graph built".to_string(),
+        timings,
+    }))
+}
+
+/// What to re-verify and regenerate when `?target=` (a requirement ID or a
+/// field name) changes
+async fn get_impact(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+    Query(query): Query<ImpactQuery>,
+) -> Result<Json<ApiResponse<Impact>>, ApiError> {
+    let ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if ast.id.to_string() != id {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    let mut timings = StageTimings::new();
+    let impact = timings.time("analysis", || ast.impact_of_change(&query.target));
+    state.metrics.observe_stages(&timings);
+    Ok(Json(ApiResponse {
+        success: true,
+        message: format!(
+            "{} requirement(s) to re-verify, {} field(s) to regenerate",
+            impact.requirements.len(),
+            impact.fields.len()
+        ),
+        data: Some(impact),
+        timings,
+    }))
+}
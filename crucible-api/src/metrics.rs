@@ -0,0 +1,204 @@
+//! Prometheus metrics for `/metrics`
+//!
+//! Request counts and latencies come from the `track_requests` middleware,
+//! stage latencies from the `StageTimings` the handlers report, and the
+//! verification series from the Z3 endpoint (the `verification` feature).
+//! Z3 allocates inside the server process, so its memory is estimated from
+//! the process's resident set after each verification.
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use crucible_core::StageTimings;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Solver calls range from microseconds to tens of seconds
+const LATENCY_BUCKETS: &[f64] = &[0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0, 30.0];
+
+/// Outcome label of a verification
+#[cfg_attr(not(feature = "verification"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverOutcome {
+    Sat,
+    Unsat,
+    Unknown,
+}
+
+impl SolverOutcome {
+    pub fn label(self) -> &'static str {
+        match self {
+            SolverOutcome::Sat => "sat",
+            SolverOutcome::Unsat => "unsat",
+            SolverOutcome::Unknown => "unknown",
+        }
+    }
+}
+
+/// Every series the API exports, in its own registry
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_duration: HistogramVec,
+    stage_duration: HistogramVec,
+    verification_duration: Histogram,
+    verification_results: IntCounterVec,
+    cache_lookups: IntCounterVec,
+    solver_memory: IntGauge,
+}
+
+impl Metrics {
+    pub fn new() -> prometheus::Result<Self> {
+        let registry = Registry::new_custom(Some("crucible".to_string()), None)?;
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route, method and status"),
+            &["route", "method", "status"],
+        )?;
+        let http_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency by route")
+                .buckets(LATENCY_BUCKETS.to_vec()),
+            &["route"],
+        )?;
+        let stage_duration = HistogramVec::new(
+            HistogramOpts::new("stage_duration_seconds", "Time spent in each pipeline stage")
+                .buckets(LATENCY_BUCKETS.to_vec()),
+            &["stage"],
+        )?;
+        let verification_duration = Histogram::with_opts(
+            HistogramOpts::new("verification_duration_seconds", "Z3 time per verified requirement")
+                .buckets(LATENCY_BUCKETS.to_vec()),
+        )?;
+        let verification_results = IntCounterVec::new(
+            Opts::new("verification_results_total", "Verification outcomes (sat, unsat, unknown)"),
+            &["result"],
+        )?;
+        let cache_lookups = IntCounterVec::new(
+            Opts::new("verification_cache_lookups_total", "Verification cache lookups (hit, miss)"),
+            &["result"],
+        )?;
+        let solver_memory = IntGauge::new(
+            "solver_memory_estimate_bytes",
+            "Resident memory after the last verification; Z3 runs in-process",
+        )?;
+
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(http_duration.clone()))?;
+        registry.register(Box::new(stage_duration.clone()))?;
+        registry.register(Box::new(verification_duration.clone()))?;
+        registry.register(Box::new(verification_results.clone()))?;
+        registry.register(Box::new(cache_lookups.clone()))?;
+        registry.register(Box::new(solver_memory.clone()))?;
+
+        // Export every label combination from the start so rate() and
+        // alerts see zeroes instead of missing series
+        for outcome in [SolverOutcome::Sat, SolverOutcome::Unsat, SolverOutcome::Unknown] {
+            verification_results.with_label_values(&[outcome.label()]);
+        }
+        for result in ["hit", "miss"] {
+            cache_lookups.with_label_values(&[result]);
+        }
+
+        Ok(Self {
+            registry,
+            http_requests,
+            http_duration,
+            stage_duration,
+            verification_duration,
+            verification_results,
+            cache_lookups,
+            solver_memory,
+        })
+    }
+
+    /// Feed the stage durations a handler reports into `stage_duration_seconds`
+    pub fn observe_stages(&self, timings: &StageTimings) {
+        for timing in &timings.stages {
+            self.stage_duration
+                .with_label_values(&[&timing.stage])
+                .observe(timing.micros as f64 / 1e6);
+        }
+    }
+
+    #[cfg_attr(not(feature = "verification"), allow(dead_code))]
+    pub fn record_verification(&self, outcome: SolverOutcome, seconds: f64) {
+        self.verification_results.with_label_values(&[outcome.label()]).inc();
+        self.verification_duration.observe(seconds);
+        if let Some(bytes) = resident_memory_bytes() {
+            self.solver_memory.set(bytes);
+        }
+    }
+
+    #[cfg_attr(not(feature = "verification"), allow(dead_code))]
+    pub fn record_cache_lookup(&self, hit: bool) {
+        self.cache_lookups.with_label_values(&[if hit { "hit" } else { "miss" }]).inc();
+    }
+
+    /// Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        // Encoding into a Vec only fails on malformed metric families,
+        // which the registry rejects at registration
+        let _ = TextEncoder::new().encode(&self.registry.gather(), &mut buffer);
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// Resident set size from `/proc/self/statm`; `None` off Linux
+fn resident_memory_bytes() -> Option<i64> {
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let pages: i64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+    Some(pages * 4096)
+}
+
+/// Count and time every request, labelled by its route template
+/// (`/api/ast/:id/graph`) so IDs do not explode the label space
+pub async fn track_requests(
+    State(metrics): State<Arc<Metrics>>,
+    matched: Option<MatchedPath>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let route = matched.map_or_else(|| "unmatched".to_string(), |path| path.as_str().to_string());
+    let method = request.method().to_string();
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+
+    metrics
+        .http_requests
+        .with_label_values(&[&route, &method, response.status().as_str()])
+        .inc();
+    metrics
+        .http_duration
+        .with_label_values(&[&route])
+        .observe(started.elapsed().as_secs_f64());
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics() {
+        let metrics = Metrics::new().unwrap();
+        let mut timings = StageTimings::new();
+        timings.time("render", || ());
+        metrics.observe_stages(&timings);
+        metrics.record_verification(SolverOutcome::Unsat, 0.25);
+        metrics.record_cache_lookup(true);
+
+        let text = metrics.render();
+        assert!(text.contains("crucible_verification_results_total{result=\"unsat\"} 1"));
+        assert!(text.contains("crucible_verification_results_total{result=\"unknown\"} 0"));
+        assert!(text.contains("crucible_verification_cache_lookups_total{result=\"hit\"} 1"));
+        assert!(text.contains("crucible_stage_duration_seconds_count{stage=\"render\"} 1"));
+        assert!(text.contains("crucible_verification_duration_seconds_bucket{le=\"0.5\"} 1"));
+    }
+}
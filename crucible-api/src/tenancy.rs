@@ -0,0 +1,252 @@
+//! Tenants - one hosted server, many teams
+//!
+//! Each `[[tenants]]` entry of `crucible.toml` gets its own project: an
+//! Intent-AST and solver caches no other tenant can read. Callers name their
+//! tenant with an API key, sent as `Authorization: Bearer <key>` or
+//! `x-api-key: <key>`; the config holds only SHA-256 digests of the keys.
+//!
+//! The `authenticate` middleware guards every `/api` route: 401 for a missing
+//! or unknown key, 429 with `Retry-After` once the tenant has used its
+//! `requests_per_minute`. `add_requirement` answers 507 when the project
+//! already holds `max_requirements`.
+//!
+//! With no tenants configured the server keeps a single open project, as a
+//! local install always has.
+
+#[cfg(feature = "verification")]
+use crate::{metrics::SolverOutcome, verify::VariableRange};
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use crucible_core::{CrucibleConfig, IntentAst};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+/// Tenant of the open project when `crucible.toml` configures none
+pub const DEFAULT_TENANT: &str = "default";
+
+/// Length of a rate-limit window
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// One tenant's Intent-AST and the solver results derived from it
+pub struct Project {
+    pub tenant: String,
+    pub ast: RwLock<IntentAst>,
+    max_requirements: Option<usize>,
+    /// Solver outcomes by canonical constraint hash
+    #[cfg(feature = "verification")]
+    pub verified: Mutex<HashMap<String, SolverOutcome>>,
+    /// Feasible variable ranges by canonical hash of the whole spec
+    #[cfg(feature = "verification")]
+    pub ranges: Mutex<HashMap<String, Vec<VariableRange>>>,
+}
+
+impl Project {
+    pub fn new(tenant: &str, max_requirements: Option<usize>) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+            ast: RwLock::new(IntentAst::new()),
+            max_requirements,
+            #[cfg(feature = "verification")]
+            verified: Default::default(),
+            #[cfg(feature = "verification")]
+            ranges: Default::default(),
+        }
+    }
+
+    /// 507 when `ast` has no room for another requirement
+    pub fn check_quota(&self, ast: &IntentAst) -> Result<(), StatusCode> {
+        match self.max_requirements {
+            Some(max) if ast.requirements.len() >= max => Err(StatusCode::INSUFFICIENT_STORAGE),
+            _ => Ok(()),
+        }
+    }
+}
+
+struct Tenant {
+    project: Arc<Project>,
+    requests_per_minute: Option<u32>,
+    /// Start of the current window and the requests admitted in it
+    window: Mutex<(Instant, u32)>,
+}
+
+impl Tenant {
+    /// Count one request, or say how long until the next window opens
+    fn admit(&self) -> Result<(), Duration> {
+        let Some(limit) = self.requests_per_minute else {
+            return Ok(());
+        };
+        let mut window = self.window.lock().unwrap_or_else(PoisonError::into_inner);
+        if window.0.elapsed() >= WINDOW {
+            *window = (Instant::now(), 0);
+        }
+        if window.1 >= limit {
+            return Err(WINDOW.saturating_sub(window.0.elapsed()));
+        }
+        window.1 += 1;
+        Ok(())
+    }
+}
+
+/// Every tenant and the key digests that identify them, fixed at startup
+pub struct Tenants {
+    tenants: HashMap<String, Tenant>,
+    /// Hex SHA-256 of an API key -> tenant ID
+    keys: HashMap<String, String>,
+    /// No tenants configured: everyone shares the default project
+    open: bool,
+}
+
+impl Tenants {
+    /// The tenants of `config`; an error names a duplicate tenant or a key
+    /// digest listed twice
+    pub fn from_config(config: &CrucibleConfig) -> anyhow::Result<Self> {
+        let mut tenants = HashMap::new();
+        let mut keys = HashMap::new();
+        for tenant in &config.tenants {
+            for digest in &tenant.key_sha256 {
+                let digest = digest.to_ascii_lowercase();
+                if let Some(owner) = keys.insert(digest.clone(), tenant.id.clone()) {
+                    anyhow::bail!("API key {} is listed for both {} and {}", digest, owner, tenant.id);
+                }
+            }
+            let entry = Tenant {
+                project: Arc::new(Project::new(&tenant.id, tenant.max_requirements)),
+                requests_per_minute: tenant.requests_per_minute,
+                window: Mutex::new((Instant::now(), 0)),
+            };
+            if tenants.insert(tenant.id.clone(), entry).is_some() {
+                anyhow::bail!("tenant {} is configured twice", tenant.id);
+            }
+        }
+        let open = tenants.is_empty();
+        if open {
+            tenants.insert(
+                DEFAULT_TENANT.to_string(),
+                Tenant {
+                    project: Arc::new(Project::new(DEFAULT_TENANT, None)),
+                    requests_per_minute: None,
+                    window: Mutex::new((Instant::now(), 0)),
+                },
+            );
+        }
+        Ok(Self { tenants, keys, open })
+    }
+
+    /// The tenant whose key the request carries; 401 without one
+    fn resolve(&self, headers: &HeaderMap) -> Result<&Tenant, StatusCode> {
+        if self.open {
+            return self.tenants.get(DEFAULT_TENANT).ok_or(StatusCode::UNAUTHORIZED);
+        }
+        let key = api_key(headers).ok_or(StatusCode::UNAUTHORIZED)?;
+        let digest = hex::encode(Sha256::digest(key.as_bytes()));
+        let tenant = self.keys.get(&digest).ok_or(StatusCode::UNAUTHORIZED)?;
+        self.tenants.get(tenant).ok_or(StatusCode::UNAUTHORIZED)
+    }
+}
+
+/// The key from `Authorization: Bearer` or `x-api-key`
+fn api_key(headers: &HeaderMap) -> Option<&str> {
+    let bearer = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    bearer
+        .or_else(|| headers.get("x-api-key").and_then(|value| value.to_str().ok()))
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+/// Resolve the caller's tenant, charge its rate limit and hand its project
+/// to the handler as an `Extension<Arc<Project>>`
+pub async fn authenticate(State(tenants): State<Arc<Tenants>>, mut request: Request, next: Next) -> Response {
+    let tenant = match tenants.resolve(request.headers()) {
+        Ok(tenant) => tenant,
+        Err(status) => return status.into_response(),
+    };
+    if let Err(wait) = tenant.admit() {
+        tracing::debug!(tenant = %tenant.project.tenant, "rate limit reached");
+        let retry_after = wait.as_secs_f64().ceil().max(1.0).to_string();
+        return (StatusCode::TOO_MANY_REQUESTS, [(header::RETRY_AFTER, retry_after)]).into_response();
+    }
+    request.extensions_mut().insert(tenant.project.clone());
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::TenantConfig;
+
+    fn digest(key: &str) -> String {
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    #[test]
+    fn test_keys_rate_limits_and_quotas_are_per_tenant() {
+        let config = CrucibleConfig {
+            tenants: vec![
+                TenantConfig {
+                    id: "payments".to_string(),
+                    key_sha256: vec![digest("pay-key")],
+                    requests_per_minute: Some(2),
+                    max_requirements: Some(1),
+                },
+                TenantConfig {
+                    id: "lending".to_string(),
+                    key_sha256: vec![digest("lend-key")],
+                    requests_per_minute: None,
+                    max_requirements: None,
+                },
+            ],
+            ..CrucibleConfig::default()
+        };
+        let tenants = Tenants::from_config(&config).unwrap();
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(tenants.resolve(&headers).err(), Some(StatusCode::UNAUTHORIZED));
+        headers.insert(header::AUTHORIZATION, "Bearer wrong".parse().unwrap());
+        assert_eq!(tenants.resolve(&headers).err(), Some(StatusCode::UNAUTHORIZED));
+        headers.insert(header::AUTHORIZATION, "Bearer pay-key".parse().unwrap());
+        let payments = tenants.resolve(&headers).unwrap();
+        assert_eq!(payments.project.tenant, "payments");
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", "lend-key".parse().unwrap());
+        let lending = tenants.resolve(&headers).unwrap();
+        assert!(!Arc::ptr_eq(&payments.project, &lending.project));
+
+        assert!(payments.admit().is_ok());
+        assert!(payments.admit().is_ok());
+        assert!(payments.admit().unwrap_err() <= WINDOW);
+        assert!((0..10).all(|_| lending.admit().is_ok()));
+
+        let mut ast = payments.project.ast.write().unwrap();
+        assert!(payments.project.check_quota(&ast).is_ok());
+        ast.add_requirement("Amount must not exceed 100".to_string());
+        assert_eq!(payments.project.check_quota(&ast), Err(StatusCode::INSUFFICIENT_STORAGE));
+        assert!(lending.project.ast.read().unwrap().requirements.is_empty());
+    }
+
+    #[test]
+    fn test_without_tenants_the_project_is_open() {
+        let tenants = Tenants::from_config(&CrucibleConfig::default()).unwrap();
+        assert_eq!(tenants.resolve(&HeaderMap::new()).unwrap().project.tenant, DEFAULT_TENANT);
+
+        let twice = TenantConfig {
+            id: "payments".to_string(),
+            key_sha256: vec![digest("key")],
+            requests_per_minute: None,
+            max_requirements: None,
+        };
+        let config = CrucibleConfig {
+            tenants: vec![twice.clone(), TenantConfig { id: "lending".to_string(), ..twice }],
+            ..CrucibleConfig::default()
+        };
+        assert!(Tenants::from_config(&config).is_err());
+    }
+}
@@ -0,0 +1,565 @@
+//! `POST /api/ast/:id/verify` - Z3 verdicts for every requirement, or with
+//! `?tags=` only for the requirements carrying one of the tags
+//! `POST /api/ast/:id/optimize` - least or greatest value of one variable
+//! `POST /api/scenario` - check concrete values against every requirement
+//!
+//! `POST /api/requirements` also calls `classify_requirement` here, so each
+//! new requirement is checked against the ones already stored.
+//!
+//! Verdicts are cached in the tenant's project by the canonical hash of each
+//! requirement's constraints, so re-verifying an unchanged spec, or two
+//! requirements that say the same thing, never reaches the solver twice; the
+//! feasible range of every variable is cached the same way, keyed by the
+//! whole spec. Cache misses run on a worker checked out of the shared
+//! `VerifierPool`; when every worker stays busy for the configured
+//! `solver.checkout_timeout_ms` the request is refused with 503 rather than
+//! queued.
+//!
+//! With the `signing` feature and a key configured, the verify response also
+//! carries ed25519 signatures over the report and the Intent-AST it covers.
+
+use crate::metrics::SolverOutcome;
+use crate::{tenancy::Project, ApiError, ApiResponse, AppState, TagQuery};
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use crucible_core::{CompoundConstraint, CrucibleConfig, CrucibleError, Requirement, RequirementStatus, StageTimings};
+use crucible_verification::{
+    Consistency, FeasibleRange, Objective, PoolError, ScenarioVerdict, VerificationError, VerifierPool,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// `solver.workers` workers, or one per CPU, each with the configured solver timeout
+pub fn verifier_pool(config: &CrucibleConfig) -> Result<VerifierPool, PoolError> {
+    let workers = config
+        .solver
+        .workers
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+    VerifierPool::with_timeout(workers, config.solver_timeout())
+}
+
+/// Solver verdict for one requirement
+#[derive(Serialize)]
+pub struct RequirementVerdict {
+    id: String,
+    /// `sat`, `unsat` or `unknown`
+    result: &'static str,
+    /// Answered from the cache without running Z3
+    cached: bool,
+}
+
+/// Feasible interval of one variable under the whole spec; `null` ends are unbounded
+#[derive(Clone, Serialize)]
+pub struct VariableRange {
+    variable: String,
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+impl From<FeasibleRange> for VariableRange {
+    fn from(range: FeasibleRange) -> Self {
+        Self {
+            variable: range.variable,
+            min: range.min,
+            max: range.max,
+        }
+    }
+}
+
+/// Body of `POST /api/ast/:id/verify`
+#[derive(Serialize)]
+pub struct VerificationReport {
+    requirements: Vec<RequirementVerdict>,
+    /// Empty when the requirements contradict one another
+    ranges: Vec<VariableRange>,
+    #[cfg(feature = "signing")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<ReportSignature>,
+}
+
+/// Signatures over the canonical JSON of the report (without this field)
+/// and of the Intent-AST as it stood after verification
+#[cfg(feature = "signing")]
+#[derive(Serialize)]
+pub struct ReportSignature {
+    public_key: String,
+    fingerprint: String,
+    report: crucible_core::ArtifactSignature,
+    ast: crucible_core::ArtifactSignature,
+}
+
+/// Requirement ID, constraint hash and tree for every requirement with
+/// constraints and one of `tags` (any, for none); `id`, when given, must
+/// name the current AST
+fn spec_constraints(
+    project: &Project,
+    id: Option<&str>,
+    tags: &[String],
+) -> Result<Vec<(String, String, CompoundConstraint)>, ApiError> {
+    let ast = project.ast.read().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if id.is_some_and(|id| ast.id.to_string() != id) {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+    Ok(ast
+        .tagged(tags)
+        .filter_map(|requirement| {
+            let compound = requirement.constraint_tree()?;
+            Some((requirement.id.to_string(), compound.canonical_hash(), compound))
+        })
+        .collect())
+}
+
+/// Every requirement's constraints together
+fn whole_spec(requirements: &[(String, String, CompoundConstraint)]) -> CompoundConstraint {
+    CompoundConstraint::And(requirements.iter().map(|(_, _, compound)| compound.clone()).collect())
+}
+
+fn pool_status(error: PoolError) -> StatusCode {
+    match error {
+        PoolError::Busy { .. } => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// Check every requirement with constraints and mark the satisfiable ones verified
+pub async fn verify_ast(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+    Query(query): Query<TagQuery>,
+) -> Result<Json<ApiResponse<VerificationReport>>, ApiError> {
+    // Nothing is held across the solver
+    let requirements = spec_constraints(&project, Some(&id), &query.tags()?)?;
+    let whole = whole_spec(&requirements);
+    let whole_hash = whole.canonical_hash();
+
+    let mut outcomes: HashMap<String, SolverOutcome> = HashMap::new();
+    let mut misses: Vec<(String, CompoundConstraint)> = Vec::new();
+    {
+        let cache = project.verified.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        for (_, hash, compound) in &requirements {
+            let cached = cache.get(hash).copied();
+            state.metrics.record_cache_lookup(cached.is_some());
+            match cached {
+                Some(outcome) => {
+                    outcomes.insert(hash.clone(), outcome);
+                }
+                None if !misses.iter().any(|(missed, _)| missed == hash) => {
+                    misses.push((hash.clone(), compound.clone()));
+                }
+                None => {}
+            }
+        }
+    }
+    let cached: Vec<bool> = requirements.iter().map(|(_, hash, _)| outcomes.contains_key(hash)).collect();
+    let mut ranges = if requirements.is_empty() {
+        Some(Vec::new())
+    } else {
+        project.ranges.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?.get(&whole_hash).cloned()
+    };
+
+    let mut timings = StageTimings::new();
+    if !misses.is_empty() || ranges.is_none() {
+        let started = Instant::now();
+        let (pool, wait) = (state.verifiers.clone(), state.config.checkout_timeout());
+        let find_ranges = ranges.is_none();
+        let (solved, found) = tokio::task::spawn_blocking(move || {
+            let verifier = pool.checkout(wait)?;
+            verifier.run(move |z3| {
+                let solved = misses
+                    .into_iter()
+                    .map(|(hash, compound)| {
+                        let started = Instant::now();
+                        let outcome = match z3.verify_compound_constraints(&compound) {
+                            Ok(_) => SolverOutcome::Sat,
+                            Err(VerificationError::Unsatisfiable(_)) => SolverOutcome::Unsat,
+                            Err(_) => SolverOutcome::Unknown,
+                        };
+                        (hash, outcome, started.elapsed())
+                    })
+                    .collect::<Vec<_>>();
+                // Only a definite answer is cached; `unknown` is retried next time
+                let found = match find_ranges.then(|| z3.feasible_ranges(&whole)) {
+                    Some(Ok(found)) => Some(found.into_iter().map(VariableRange::from).collect::<Vec<_>>()),
+                    Some(Err(VerificationError::Unsatisfiable(_))) => Some(Vec::new()),
+                    _ => None,
+                };
+                (solved, found)
+            })
+        })
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(pool_status)?;
+        timings.record("verify", started.elapsed());
+
+        let mut cache = project.verified.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        for (hash, outcome, elapsed) in solved {
+            state.metrics.record_verification(outcome, elapsed.as_secs_f64());
+            cache.insert(hash.clone(), outcome);
+            outcomes.insert(hash, outcome);
+        }
+        if let Some(found) = found {
+            let mut cache = project.ranges.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            cache.insert(whole_hash, found.clone());
+            ranges = Some(found);
+        }
+    }
+
+    let verdicts: Vec<RequirementVerdict> = requirements
+        .iter()
+        .zip(cached)
+        .map(|((id, hash, _), cached)| RequirementVerdict {
+            id: id.clone(),
+            result: outcomes[hash].label(),
+            cached,
+        })
+        .collect();
+
+    #[cfg(feature = "signing")]
+    let ast_signature;
+    {
+        let mut ast = project.ast.write().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        if ast.id.to_string() != id {
+            return Err(StatusCode::NOT_FOUND.into());
+        }
+        let satisfied: HashMap<&str, bool> =
+            verdicts.iter().map(|v| (v.id.as_str(), v.result == SolverOutcome::Sat.label())).collect();
+        // A satisfiable requirement becomes Verified; a proved one that no longer is falls back to Parsed
+        let transitions: Vec<_> = ast
+            .requirements
+            .iter()
+            .filter_map(|requirement| {
+                let satisfied = *satisfied.get(requirement.id.to_string().as_str())?;
+                let target = if satisfied { RequirementStatus::Verified } else { RequirementStatus::Parsed };
+                let moves = requirement.status != target && requirement.status.can_become(target);
+                (moves && (satisfied || requirement.status.is_proved())).then_some((requirement.id, target))
+            })
+            .collect();
+        for (requirement, target) in transitions {
+            let note = format!("POST /api/ast/{}/verify", id);
+            ast.transition(requirement, target, None, Some(note))
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        }
+        #[cfg(feature = "signing")]
+        {
+            ast_signature = match &state.signer {
+                Some(signer) => Some(signer.sign_ast(&ast).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?),
+                None => None,
+            };
+        }
+    }
+    state.metrics.observe_stages(&timings);
+
+    let satisfiable = verdicts.iter().filter(|v| v.result == "sat").count();
+    let message = format!("{} of {} requirement(s) satisfiable", satisfiable, verdicts.len());
+    let report = VerificationReport {
+        requirements: verdicts,
+        ranges: ranges.unwrap_or_default(),
+        #[cfg(feature = "signing")]
+        signature: None,
+    };
+    #[cfg(feature = "signing")]
+    let report = sign_report(&state, report, ast_signature)?;
+    Ok(Json(ApiResponse {
+        success: true,
+        message,
+        data: Some(report),
+        timings,
+    }))
+}
+
+/// Attach the report and AST signatures when the server has a key
+#[cfg(feature = "signing")]
+fn sign_report(
+    state: &AppState,
+    mut report: VerificationReport,
+    ast: Option<crucible_core::ArtifactSignature>,
+) -> Result<VerificationReport, ApiError> {
+    if let (Some(signer), Some(ast)) = (&state.signer, ast) {
+        report.signature = Some(ReportSignature {
+            public_key: signer.public_key_hex(),
+            fingerprint: signer.fingerprint(),
+            report: signer.sign_json(&report).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+            ast,
+        });
+    }
+    Ok(report)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Direction {
+    Minimize,
+    Maximize,
+}
+
+#[derive(Deserialize)]
+pub struct OptimizeRequest {
+    variable: String,
+    objective: Direction,
+}
+
+/// Extreme value of a variable and an assignment attaining it
+#[derive(Serialize)]
+pub struct Optimum {
+    variable: String,
+    /// `null` when the spec leaves the variable unbounded that way
+    value: Option<i64>,
+    model: Option<HashMap<String, String>>,
+}
+
+/// Least or greatest value a variable can take under every requirement.
+///
+/// 422 when the requirements contradict one another, 400 when the variable
+/// cannot be translated.
+pub async fn optimize_variable(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
+    Path(id): Path<String>,
+    Json(request): Json<OptimizeRequest>,
+) -> Result<Json<ApiResponse<Optimum>>, ApiError> {
+    let whole = whole_spec(&spec_constraints(&project, Some(&id), &[])?);
+    let objective = match request.objective {
+        Direction::Minimize => Objective::Minimize,
+        Direction::Maximize => Objective::Maximize,
+    };
+
+    let started = Instant::now();
+    let (pool, wait) = (state.verifiers.clone(), state.config.checkout_timeout());
+    let output = tokio::task::spawn_blocking(move || {
+        let verifier = pool.checkout(wait)?;
+        verifier.run(move |z3| z3.optimize(&request.variable, objective, &whole))
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .map_err(pool_status)?
+    .map_err(CrucibleError::from)?;
+    let mut timings = StageTimings::new();
+    timings.record("optimize", started.elapsed());
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: output.to_string(),
+        data: Some(Optimum {
+            variable: output.variable,
+            value: output.value,
+            model: output.model,
+        }),
+        timings,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct ScenarioRequest {
+    /// Field values; JSON strings bind string fields, numbers integer fields
+    values: BTreeMap<String, serde_json::Value>,
+}
+
+/// How one requirement fares under the scenario
+#[derive(Serialize)]
+pub struct ScenarioResult {
+    id: String,
+    /// `satisfied`, `violated` or `undetermined` (depends on fields left out)
+    verdict: &'static str,
+    /// Parts of the requirement the values break
+    violations: Vec<String>,
+}
+
+/// Check an example, such as one transaction, against every requirement
+/// before any code exists. 400 for values that are neither strings nor integers.
+pub async fn check_scenario(
+    State(state): State<AppState>,
+    Extension(project): Extension<Arc<Project>>,
+    Json(request): Json<ScenarioRequest>,
+) -> Result<Json<ApiResponse<Vec<ScenarioResult>>>, ApiError> {
+    let bindings = request
+        .values
+        .into_iter()
+        .map(|(field, value)| match value {
+            serde_json::Value::Number(n) if n.is_i64() => Ok((field, n.to_string())),
+            serde_json::Value::String(text) => Ok((field, format!("\"{}\"", text))),
+            _ => Err(StatusCode::BAD_REQUEST),
+        })
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+    let (ids, constraints): (Vec<String>, Vec<CompoundConstraint>) =
+        spec_constraints(&project, None, &[])?.into_iter().map(|(id, _, compound)| (id, compound)).unzip();
+
+    let started = Instant::now();
+    let (pool, wait) = (state.verifiers.clone(), state.config.checkout_timeout());
+    let checks = tokio::task::spawn_blocking(move || {
+        let verifier = pool.checkout(wait)?;
+        verifier.run(move |z3| z3.check_scenario(&bindings, &constraints))
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .map_err(pool_status)?
+    .map_err(CrucibleError::from)?;
+    let mut timings = StageTimings::new();
+    timings.record("scenario", started.elapsed());
+
+    let results: Vec<ScenarioResult> = checks
+        .into_iter()
+        .map(|check| ScenarioResult {
+            id: ids[check.index].clone(),
+            verdict: match check.verdict {
+                ScenarioVerdict::Satisfied => "satisfied",
+                ScenarioVerdict::Violated => "violated",
+                ScenarioVerdict::Undetermined => "undetermined",
+            },
+            violations: check.violations,
+        })
+        .collect();
+    let violated = results.iter().filter(|r| r.verdict == "violated").count();
+    Ok(Json(ApiResponse {
+        success: true,
+        message: format!("{} of {} requirement(s) violated", violated, results.len()),
+        data: Some(results),
+        timings,
+    }))
+}
+
+/// How a new requirement relates to the stored ones
+#[derive(Serialize)]
+pub struct ConsistencyReport {
+    /// `consistent`, `implied` or `contradicts`
+    status: &'static str,
+    /// IDs of the stored requirements that imply or contradict it; empty
+    /// for `contradicts` when the requirement contradicts itself
+    requirements: Vec<String>,
+}
+
+impl ConsistencyReport {
+    /// Satisfiable together with the stored requirements
+    pub fn is_consistent(&self) -> bool {
+        self.status != "contradicts"
+    }
+
+    pub fn summary(&self) -> String {
+        match (self.status, self.requirements.len()) {
+            ("consistent", _) => "Requirement added; consistent with the existing requirements".to_string(),
+            ("implied", n) => format!("Requirement added; already implied by {} existing requirement(s)", n),
+            (_, 0) => "Requirement added; it contradicts itself".to_string(),
+            (_, n) => format!("Requirement added; contradicts {} existing requirement(s)", n),
+        }
+    }
+}
+
+/// Classify a just-added requirement against every other requirement with
+/// constraints; `None` when it has no constraints of its own
+pub async fn classify_requirement(
+    state: &AppState,
+    project: &Project,
+    requirement: &Requirement,
+    timings: &mut StageTimings,
+) -> Result<Option<ConsistencyReport>, ApiError> {
+    let Some(candidate) = requirement.constraint_tree() else {
+        return Ok(None);
+    };
+    let new_id = requirement.id.to_string();
+    let (ids, existing): (Vec<String>, Vec<CompoundConstraint>) = spec_constraints(project, None, &[])?
+        .into_iter()
+        .filter(|(id, _, _)| *id != new_id)
+        .map(|(id, _, compound)| (id, compound))
+        .unzip();
+
+    let started = Instant::now();
+    let (pool, wait) = (state.verifiers.clone(), state.config.checkout_timeout());
+    let consistency = tokio::task::spawn_blocking(move || {
+        let verifier = pool.checkout(wait)?;
+        verifier.run(move |z3| z3.classify(&existing, &candidate))
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .map_err(pool_status)?
+    .map_err(CrucibleError::from)?;
+    timings.record("classify", started.elapsed());
+
+    let named = |indices: Vec<usize>| indices.into_iter().map(|i| ids[i].clone()).collect();
+    Ok(Some(match consistency {
+        Consistency::Consistent => ConsistencyReport {
+            status: "consistent",
+            requirements: Vec::new(),
+        },
+        Consistency::Implied(premises) => ConsistencyReport {
+            status: "implied",
+            requirements: named(premises),
+        },
+        Consistency::Contradicts(conflict) => ConsistencyReport {
+            status: "contradicts",
+            requirements: named(conflict),
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::Metrics;
+    use crucible_core::{Constraint, ConstraintOperator};
+
+    fn state() -> AppState {
+        let config = CrucibleConfig::default();
+        AppState {
+            metrics: Arc::new(Metrics::new().unwrap()),
+            verifiers: verifier_pool(&config).unwrap(),
+            #[cfg(feature = "signing")]
+            signer: None,
+            config: Arc::new(config),
+        }
+    }
+
+    fn amount(operator: ConstraintOperator, value: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator,
+            right_value: value.to_string(),
+        })
+    }
+
+    async fn verify(state: &AppState, project: &Arc<Project>) -> VerificationReport {
+        let id = project.ast.read().unwrap().id.to_string();
+        let query = Query(TagQuery { tags: None });
+        let Json(response) = verify_ast(State(state.clone()), Extension(project.clone()), Path(id), query)
+            .await
+            .unwrap();
+        response.data.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_requirement_is_answered_from_the_cache() {
+        let (state, project) = (state(), Arc::new(Project::new("test", None)));
+        let positive = amount(ConstraintOperator::GreaterThan, "0");
+        project.ast.write().unwrap().add_constrained_requirement("Amount must be positive".to_string(), Some(positive));
+
+        let first = verify(&state, &project).await;
+        assert_eq!(first.requirements[0].result, "sat");
+        assert!(!first.requirements[0].cached);
+
+        let second = verify(&state, &project).await;
+        assert_eq!(second.requirements[0].result, "sat");
+        assert!(second.requirements[0].cached);
+    }
+
+    #[tokio::test]
+    async fn test_proved_requirement_falls_back_to_parsed_when_unsatisfiable() {
+        let (state, project) = (state(), Arc::new(Project::new("test", None)));
+        let positive = amount(ConstraintOperator::GreaterThan, "0");
+        project.ast.write().unwrap().add_constrained_requirement("Amount must be positive".to_string(), Some(positive));
+
+        verify(&state, &project).await;
+        assert_eq!(project.ast.read().unwrap().requirements[0].status, RequirementStatus::Verified);
+
+        project.ast.write().unwrap().requirements[0].tree = Some(CompoundConstraint::And(vec![
+            amount(ConstraintOperator::GreaterThan, "10"),
+            amount(ConstraintOperator::LessThan, "5"),
+        ]));
+        let report = verify(&state, &project).await;
+        assert_eq!(report.requirements[0].result, "unsat");
+        assert_eq!(project.ast.read().unwrap().requirements[0].status, RequirementStatus::Parsed);
+    }
+}
@@ -0,0 +1,159 @@
+//! `crucible compat` - interface contracts between two components
+//!
+//! Each intent file is one component's side of an interface: its
+//! `Assuming ...` requirements are what it relies on, everything else is
+//! what it guarantees. The producer's guarantees must imply every consumer
+//! assumption; the report lists the ones they do not, each with a producer
+//! output that breaks it, and the command fails when there is any.
+
+use crate::watch::{spec_lines, spec_text};
+use crate::CompatArgs;
+use anyhow::Context;
+use crucible_core::error::codes;
+use crucible_core::{ComponentContract, CrucibleConfig, CrucibleError, Diagnostic};
+use crucible_verification::{CompatibilityOutput, Z3Verifier};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// A component's contract and the source line and text of each assumption
+struct Component {
+    contract: ComponentContract,
+    assumptions: Vec<(Option<usize>, String)>,
+}
+
+pub fn run(args: &CompatArgs, config: &CrucibleConfig) -> anyhow::Result<()> {
+    let producer = load(&args.producer)?;
+    let consumer = load(&args.consumer)?;
+    let verifier = Z3Verifier::with_timeout(config.solver_timeout());
+    let result = verifier
+        .check_compatibility(&producer.contract, &consumer.contract)
+        .map_err(CrucibleError::from)
+        .with_context(|| format!("checking {} against {}", args.producer.display(), args.consumer.display()))?;
+    print!("{}", render(&producer, &consumer, &result));
+    if !result.is_compatible() {
+        let message = format!(
+            "{} of {} assumption(s) of `{}` are not guaranteed by `{}`",
+            result.gaps.len(),
+            result.assumptions_count,
+            consumer.contract.name,
+            producer.contract.name
+        );
+        return Err(CrucibleError::Verification(Diagnostic::new(codes::UNSATISFIABLE, message)).into());
+    }
+    Ok(())
+}
+
+/// The contract in `spec`, named after the file
+fn load(spec: &Path) -> anyhow::Result<Component> {
+    let source = fs::read_to_string(spec).with_context(|| format!("reading {}", spec.display()))?;
+    let lines = spec_lines(spec, &source);
+    let ast = crucible_parser::parse(&spec_text(spec, &source))
+        .map_err(CrucibleError::from)
+        .with_context(|| format!("parsing {}", spec.display()))?;
+    let definitions = ast.definition_table().map_err(CrucibleError::from)?;
+    let name = spec.file_stem().map_or_else(|| spec.display().to_string(), |stem| stem.to_string_lossy().into_owned());
+
+    let mut trees = Vec::new();
+    let mut assumptions = Vec::new();
+    for requirement in &ast.requirements {
+        let Some(compound) = requirement.compound() else {
+            continue;
+        };
+        if requirement.kind == crucible_core::RequirementKind::Assumption {
+            let (line, text) = lines.get(requirement.line.wrapping_sub(1)).copied().unzip();
+            assumptions.push((line, text.unwrap_or_default().trim().to_string()));
+        }
+        trees.push((requirement.kind, definitions.inline(&compound).map_err(CrucibleError::from)?));
+    }
+    let contract = ComponentContract::from_requirements(&name, trees.iter().map(|(kind, tree)| (*kind, tree)));
+    Ok(Component { contract, assumptions })
+}
+
+fn render(producer: &Component, consumer: &Component, result: &CompatibilityOutput) -> String {
+    let (producer_name, consumer_name) = (&producer.contract.name, &consumer.contract.name);
+    let mut out = format!("# {} → {}\n\n", producer_name, consumer_name);
+    if result.is_compatible() {
+        let _ = writeln!(
+            out,
+            "All {} assumption(s) of `{}` follow from the guarantees of `{}`.",
+            result.assumptions_count, consumer_name, producer_name
+        );
+        return out;
+    }
+    let _ = writeln!(
+        out,
+        "{} of {} assumption(s) of `{}` are not guaranteed by `{}`:\n",
+        result.gaps.len(),
+        result.assumptions_count,
+        consumer_name,
+        producer_name
+    );
+    for gap in &result.gaps {
+        let (line, text) = &consumer.assumptions[gap.assumption];
+        match line {
+            Some(line) => {
+                let _ = writeln!(out, "- line {}: {}", line, text);
+            }
+            None => {
+                let _ = writeln!(out, "- {}", text);
+            }
+        }
+        if !gap.unconstrained.is_empty() {
+            let _ = writeln!(out, "  `{}` says nothing about {}", producer_name, gap.unconstrained.join(", "));
+        }
+        if let Some(counterexample) = &gap.counterexample {
+            let _ = writeln!(out, "  counterexample: {}", assignment(counterexample));
+        }
+    }
+    out
+}
+
+/// `amount = 10000, cart_total = 10000`
+pub(crate) fn assignment(values: &HashMap<String, String>) -> String {
+    let mut values: Vec<_> = values.iter().collect();
+    values.sort();
+    values.iter().map(|(name, value)| format!("{} = {}", name, value)).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_verification::InterfaceGap;
+
+    #[test]
+    fn test_render_gaps() {
+        let component = |name: &str, assumptions: Vec<(Option<usize>, String)>| Component {
+            contract: ComponentContract::new(name),
+            assumptions,
+        };
+        let producer = component("checkout", Vec::new());
+        let consumer = component(
+            "payments",
+            vec![
+                (Some(1), "Assuming amount is at least 1".to_string()),
+                (Some(3), "Assuming currency is 978".to_string()),
+            ],
+        );
+        let result = CompatibilityOutput {
+            assumptions_count: 2,
+            gaps: vec![InterfaceGap {
+                assumption: 1,
+                counterexample: Some(HashMap::from([
+                    ("currency".to_string(), "0".to_string()),
+                    ("amount".to_string(), "5".to_string()),
+                ])),
+                unconstrained: vec!["currency".to_string()],
+            }],
+        };
+        assert_eq!(
+            render(&producer, &consumer, &result),
+            "# checkout → payments\n\n\
+             1 of 2 assumption(s) of `payments` are not guaranteed by `checkout`:\n\n\
+             - line 3: Assuming currency is 978\n  \
+             `checkout` says nothing about currency\n  \
+             counterexample: amount = 5, currency = 0\n"
+        );
+    }
+}
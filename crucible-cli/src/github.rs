@@ -0,0 +1,350 @@
+//! `crucible report --format github` - verification results on a pull request
+//!
+//! In a GitHub Actions job the report becomes:
+//!
+//! - workflow commands (`::error file=...,line=...::`) on stdout, which the
+//!   job's check run shows as annotations on the intent files' lines;
+//! - a Markdown summary appended to `$GITHUB_STEP_SUMMARY`;
+//! - with `--post`, a pull request comment, updated in place on later runs.
+//!
+//! ```yaml
+//! - run: crucible report --format github --base origin/${{ github.base_ref }} --post specs/*.md
+//!   env:
+//!     GITHUB_TOKEN: ${{ secrets.GITHUB_TOKEN }}
+//! ```
+//!
+//! Posting reads `GITHUB_TOKEN`, `GITHUB_REPOSITORY` and the pull request
+//! number from `--pr` or the event payload at `GITHUB_EVENT_PATH`.
+
+use crate::compat::assignment;
+use crate::report::{SpecReport, Verdict};
+use anyhow::{anyhow, Context};
+use crucible_verification::Level;
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+/// Marks the comment this command owns, so reruns edit it instead of adding another
+const MARKER: &str = "<!-- crucible-report -->";
+
+/// The pull request comment: a verdict table per spec, contradictions with
+/// their lines, the semantic change against the base and the regenerated code diffs
+pub fn comment(reports: &[SpecReport]) -> String {
+    let failing = reports.iter().filter(|r| r.has_errors()).count();
+    let mut body = format!("{}\n## Crucible verification\n\n", MARKER);
+    if failing == 0 {
+        let _ = writeln!(body, "All {} intent file(s) verified.\n", reports.len());
+    } else {
+        let _ = writeln!(body, "{} of {} intent file(s) need attention.\n", failing, reports.len());
+    }
+
+    for report in reports {
+        let path = report.path.display();
+        let _ = writeln!(body, "### `{}`\n", path);
+        if let Some(error) = &report.error {
+            let _ = writeln!(body, "Could not be checked: {}\n", escape_cell(error));
+            continue;
+        }
+        body.push_str("| Line | Requirement | Status |\n|---:|---|---|\n");
+        for requirement in &report.requirements {
+            let line = requirement.line.map_or_else(|| "-".to_string(), |line| line.to_string());
+            let status = match requirement.verdict {
+                Verdict::Sat if !requirement.contradicts.is_empty() => "contradiction".to_string(),
+                Verdict::Sat if requirement.vacuous => "SAT (vacuous)".to_string(),
+                Verdict::Sat => "SAT".to_string(),
+                verdict => verdict.label().to_string(),
+            };
+            let _ = writeln!(body, "| {} | {} | {} |", line, escape_cell(&requirement.text), status);
+        }
+        body.push('\n');
+
+        let contradictions = report.contradictions();
+        if !contradictions.is_empty() {
+            body.push_str("**Contradictions**\n\n");
+            for (later, earlier) in contradictions {
+                let _ = writeln!(
+                    body,
+                    "- {} contradicts {}",
+                    line_ref(&report.path.display().to_string(), later.line),
+                    line_ref(&report.path.display().to_string(), earlier.line),
+                );
+            }
+            body.push('\n');
+        }
+
+        let findings: Vec<_> =
+            report.requirements.iter().flat_map(|r| r.findings.iter().map(move |f| (r, f))).collect();
+        if !findings.is_empty() {
+            body.push_str("**Findings**\n\n");
+            for (requirement, finding) in findings {
+                let _ = writeln!(
+                    body,
+                    "- {}: {} `{}` {}",
+                    line_ref(&report.path.display().to_string(), requirement.line),
+                    finding.level,
+                    finding.pass,
+                    escape_cell(&finding.diagnostic.to_string()),
+                );
+            }
+            body.push('\n');
+        }
+
+        if !report.ambiguities.is_empty() {
+            body.push_str("**Ambiguities**\n\n");
+            for ambiguity in &report.ambiguities {
+                let _ = writeln!(
+                    body,
+                    "- {}: `{}` could mean {}",
+                    line_ref(&report.path.display().to_string(), ambiguity.line),
+                    ambiguity.span,
+                    ambiguity.interpretations.join(" or "),
+                );
+            }
+            body.push('\n');
+        }
+
+        if !report.monitored.is_empty() {
+            body.push_str("**Monitored properties** (checked at runtime, not by the solver)\n\n");
+            for property in &report.monitored {
+                let path = report.path.display().to_string();
+                let _ = writeln!(body, "- {}: {}", line_ref(&path, Some(property.line)), property.tolerance);
+            }
+            body.push('\n');
+        }
+
+        if let Some(refinement) = &report.refinement {
+            let _ = writeln!(body, "**Semantic change**: {} than the base version", refinement.relation);
+            if let Some(values) = &refinement.newly_rejected {
+                let _ = writeln!(body, "- no longer allowed: {}", assignment(values));
+            }
+            if let Some(values) = &refinement.newly_allowed {
+                let _ = writeln!(body, "- newly allowed: {}", assignment(values));
+            }
+            body.push('\n');
+        }
+
+        if !report.diffs.is_empty() {
+            let _ = writeln!(
+                body,
+                "<details><summary>Regenerated code ({} file(s) changed)</summary>\n\n```diff",
+                report.diffs.len()
+            );
+            for diff in &report.diffs {
+                body.push_str(diff);
+                if !diff.ends_with('\n') {
+                    body.push('\n');
+                }
+            }
+            body.push_str("```\n\n</details>\n\n");
+        }
+    }
+    body
+}
+
+/// Workflow commands annotating the failing lines
+pub fn annotations(reports: &[SpecReport]) -> String {
+    let mut out = String::new();
+    for report in reports {
+        let file = escape_property(&report.path.display().to_string());
+        if let Some(error) = &report.error {
+            let line = report.error_line.map(|line| format!(",line={}", line)).unwrap_or_default();
+            let _ = writeln!(out, "::error file={}{},title=Crucible::{}", file, line, escape_data(error));
+        }
+        for ambiguity in &report.ambiguities {
+            let line = ambiguity.line.map(|line| format!(",line={}", line)).unwrap_or_default();
+            let message = format!("`{}` could mean {}", ambiguity.span, ambiguity.interpretations.join(" or "));
+            let title = "Ambiguous requirement";
+            let _ = writeln!(out, "::warning file={}{},title={}::{}", file, line, title, escape_data(&message));
+        }
+        for requirement in &report.requirements {
+            let location = match requirement.line {
+                Some(line) => format!("file={},line={}", file, line),
+                None => format!("file={}", file),
+            };
+            for finding in &requirement.findings {
+                let level = match finding.level {
+                    Level::Error => "error",
+                    Level::Warning => "warning",
+                    Level::Note => "notice",
+                };
+                let title = escape_property(&finding.pass);
+                let message = escape_data(&finding.diagnostic.to_string());
+                let _ = writeln!(out, "::{} {},title={}::{}", level, location, title, message);
+            }
+            let (level, title, message) = match requirement.verdict {
+                Verdict::Unsat => ("error", "Unsatisfiable requirement", requirement.text.clone()),
+                Verdict::Unknown => ("warning", "Unverified requirement", requirement.text.clone()),
+                Verdict::NoConstraints => ("warning", "No constraints parsed", requirement.text.clone()),
+                Verdict::Sat if requirement.vacuous => {
+                    ("warning", "Vacuous requirement", format!("{} (its condition can never hold)", requirement.text))
+                }
+                Verdict::Sat if requirement.contradicts.is_empty() => continue,
+                Verdict::Sat => {
+                    let lines: Vec<String> = requirement
+                        .contradicts
+                        .iter()
+                        .map(|&i| report.requirements[i].line.map_or_else(|| "?".to_string(), |l| l.to_string()))
+                        .collect();
+                    let message = format!("{} (contradicts line {})", requirement.text, lines.join(", "));
+                    ("error", "Contradiction", message)
+                }
+            };
+            let _ = writeln!(out, "::{} {},title={}::{}", level, location, title, escape_data(&message));
+        }
+    }
+    out
+}
+
+/// Append the comment to the job summary when running in Actions
+pub fn write_step_summary(body: &str) -> anyhow::Result<()> {
+    let Ok(path) = std::env::var("GITHUB_STEP_SUMMARY") else {
+        return Ok(());
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("opening {}", path))?;
+    file.write_all(body.as_bytes())?;
+    Ok(())
+}
+
+/// Create the report comment on the pull request, or update the one an
+/// earlier run left; returns the comment's URL
+pub fn post_comment(body: &str, pr: Option<u64>) -> anyhow::Result<String> {
+    let token = std::env::var("GITHUB_TOKEN").context("GITHUB_TOKEN is not set")?;
+    let repository = std::env::var("GITHUB_REPOSITORY").context("GITHUB_REPOSITORY is not set")?;
+    let api = std::env::var("GITHUB_API_URL").unwrap_or_else(|_| "https://api.github.com".to_string());
+    let pr = match pr {
+        Some(pr) => pr,
+        None => event_pull_request()?,
+    };
+    let authorization = format!("Bearer {}", token);
+    let request = |method: &str, url: &str| {
+        ureq::request(method, url)
+            .set("Authorization", &authorization)
+            .set("Accept", "application/vnd.github+json")
+            .set("User-Agent", concat!("crucible-cli/", env!("CARGO_PKG_VERSION")))
+    };
+
+    let comments_url = format!("{}/repos/{}/issues/{}/comments", api, repository, pr);
+    let existing: Vec<serde_json::Value> = request("GET", &format!("{}?per_page=100", comments_url))
+        .call()
+        .map_err(|e| anyhow!("listing comments on #{}: {}", pr, e))?
+        .into_json()?;
+    let previous = existing
+        .iter()
+        .find(|comment| comment["body"].as_str().is_some_and(|b| b.starts_with(MARKER)))
+        .and_then(|comment| comment["id"].as_u64());
+
+    let payload = serde_json::json!({ "body": body });
+    let response = match previous {
+        Some(id) => {
+            let url = format!("{}/repos/{}/issues/comments/{}", api, repository, id);
+            request("PATCH", &url).send_json(payload)
+        }
+        None => request("POST", &comments_url).send_json(payload),
+    }
+    .map_err(|e| anyhow!("commenting on #{}: {}", pr, e))?;
+    let comment: serde_json::Value = response.into_json()?;
+    Ok(comment["html_url"].as_str().unwrap_or_default().to_string())
+}
+
+/// Pull request number of the `pull_request` event that started the job
+fn event_pull_request() -> anyhow::Result<u64> {
+    let path = std::env::var("GITHUB_EVENT_PATH").context("no --pr given and GITHUB_EVENT_PATH is not set")?;
+    let event: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).with_context(|| format!("reading {}", path))?)?;
+    event["pull_request"]["number"]
+        .as_u64()
+        .ok_or_else(|| anyhow!("the event in {} is not a pull request; pass --pr", path))
+}
+
+/// `path#L12`, or the bare path without a line
+fn line_ref(path: &str, line: Option<usize>) -> String {
+    match line {
+        Some(line) => format!("`{}#L{}`", path, line),
+        None => format!("`{}`", path),
+    }
+}
+
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Message of a workflow command
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Property value of a workflow command
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{MonitoredProperty, RequirementResult};
+    use crucible_core::split_tolerance;
+    use crucible_verification::{Refinement, RefinementOutput};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn requirement(line: usize, text: &str, verdict: Verdict, contradicts: Vec<usize>) -> RequirementResult {
+        RequirementResult {
+            line: Some(line),
+            text: text.to_string(),
+            verdict,
+            detail: None,
+            contradicts,
+            vacuous: false,
+            tree: None,
+            findings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_comment_and_annotations() {
+        let report = SpecReport {
+            path: PathBuf::from("specs/payments.md"),
+            requirements: vec![
+                requirement(3, "Amount must be at most 100", Verdict::Sat, vec![]),
+                requirement(4, "Amount must be greater than 200", Verdict::Sat, vec![0]),
+                requirement(6, "Fees are fair | reasonable", Verdict::NoConstraints, vec![]),
+            ],
+            ambiguities: Vec::new(),
+            monitored: vec![MonitoredProperty {
+                line: 8,
+                tolerance: split_tolerance("latency < 100ms in 99% of cases").1.unwrap(),
+            }],
+            diffs: vec!["--- a/requirement_1.rs\n+++ b/requirement_1.rs\n@@ -1 +1 @@\n-a\n+b\n".to_string()],
+            refinement: Some(RefinementOutput {
+                relation: Refinement::Stronger,
+                newly_rejected: Some(HashMap::from([("amount".to_string(), "150".to_string())])),
+                newly_allowed: None,
+            }),
+            error: None,
+            error_line: None,
+        };
+        assert!(report.has_errors());
+
+        let body = comment(std::slice::from_ref(&report));
+        assert!(body.starts_with(MARKER));
+        assert!(body.contains("1 of 1 intent file(s) need attention."));
+        assert!(body.contains("| 4 | Amount must be greater than 200 | contradiction |"));
+        assert!(body.contains("| 6 | Fees are fair \\| reasonable | no constraints |"));
+        assert!(body.contains("- `specs/payments.md#L4` contradicts `specs/payments.md#L3`"));
+        assert!(body.contains("- `specs/payments.md#L8`: latency < 100ms in 99% of cases\n"));
+        assert!(body.contains(
+            "**Semantic change**: strictly stronger than the base version\n- no longer allowed: amount = 150\n\n"
+        ));
+        assert!(body.contains("```diff\n--- a/requirement_1.rs\n"));
+
+        assert_eq!(
+            annotations(&[report]),
+            "::error file=specs/payments.md,line=4,title=Contradiction::\
+             Amount must be greater than 200 (contradicts line 3)\n\
+             ::warning file=specs/payments.md,line=6,title=No constraints parsed::Fees are fair | reasonable\n"
+        );
+    }
+}
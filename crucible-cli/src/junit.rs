@@ -0,0 +1,221 @@
+//! `crucible report --format junit` - spec health as JUnit XML
+//!
+//! Each intent file is a `<testsuite>` and each requirement a `<testcase>`,
+//! so CI dashboards that already read test results show spec health without
+//! a plugin:
+//!
+//! - SAT: passed (a vacuous requirement or one with findings passes with a
+//!   note in `system-out`)
+//! - UNSAT, contradicting an earlier requirement or with an error-level
+//!   finding from a verification pass: `<failure>`
+//! - undecided by the solver: `<error>`
+//! - no constraints parsed: `<skipped>`
+//! - a tolerance, monitored at runtime: `<skipped>`
+//!
+//! A spec that does not parse is a suite with one erroring `parse` case.
+
+use crate::report::{SpecReport, Verdict};
+use crucible_verification::Level;
+use quick_xml::escape::escape;
+use std::fmt::Write as _;
+
+/// The JUnit XML document of every spec
+pub fn render(reports: &[SpecReport]) -> String {
+    let suites: Vec<Suite> = reports.iter().map(suite).collect();
+    let count = |f: fn(&Suite) -> usize| suites.iter().map(f).sum::<usize>();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"crucible\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+        count(|s| s.cases.len()),
+        count(|s| s.failures),
+        count(|s| s.errors),
+        count(|s| s.skipped),
+    );
+    for suite in &suites {
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            escape(suite.name.as_str()),
+            suite.cases.len(),
+            suite.failures,
+            suite.errors,
+            suite.skipped,
+        );
+        for case in &suite.cases {
+            out.push_str(case);
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+/// One spec's rendered test cases and their tallies
+struct Suite {
+    name: String,
+    cases: Vec<String>,
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+}
+
+fn suite(report: &SpecReport) -> Suite {
+    let name = report.path.to_string_lossy().replace('\\', "/");
+    let mut suite = Suite {
+        name: name.clone(),
+        cases: Vec::new(),
+        failures: 0,
+        errors: 0,
+        skipped: 0,
+    };
+    let open = |case: &str, line: Option<usize>| {
+        let line = line.map(|line| format!(" line=\"{}\"", line)).unwrap_or_default();
+        format!(
+            "    <testcase classname=\"{}\" name=\"{}\" file=\"{}\"{}",
+            escape(name.as_str()),
+            escape(case),
+            escape(name.as_str()),
+            line
+        )
+    };
+
+    if let Some(error) = &report.error {
+        suite.errors += 1;
+        suite.cases.push(format!(
+            "{}>\n      <error type=\"parse\" message=\"{}\"/>\n    </testcase>\n",
+            open("parse", report.error_line),
+            escape(error.as_str())
+        ));
+        return suite;
+    }
+
+    for requirement in &report.requirements {
+        let case_name = match requirement.line {
+            Some(line) => format!("L{}: {}", line, requirement.text),
+            None => requirement.text.clone(),
+        };
+        let case = open(&case_name, requirement.line);
+        let detail = requirement.detail.as_deref().unwrap_or_default();
+        let body = match requirement.verdict {
+            Verdict::Sat if !requirement.contradicts.is_empty() => {
+                suite.failures += 1;
+                let others: Vec<String> = requirement
+                    .contradicts
+                    .iter()
+                    .map(|&i| {
+                        let other = &report.requirements[i];
+                        match other.line {
+                            Some(line) => format!("line {}: {}", line, other.text),
+                            None => other.text.clone(),
+                        }
+                    })
+                    .collect();
+                let message = format!("contradicts {}", others.join("; "));
+                Some(format!("<failure type=\"contradiction\" message=\"{}\"/>", escape(message.as_str())))
+            }
+            Verdict::Sat if requirement.vacuous => {
+                Some("<system-out>vacuous: its condition can never hold</system-out>".to_string())
+            }
+            Verdict::Sat => match requirement.findings.iter().find(|f| f.level == Level::Error) {
+                Some(finding) => {
+                    suite.failures += 1;
+                    Some(format!(
+                        "<failure type=\"{}\" message=\"{}\"/>",
+                        escape(finding.pass.as_str()),
+                        escape(finding.diagnostic.to_string().as_str())
+                    ))
+                }
+                None if !requirement.findings.is_empty() => {
+                    let notes: Vec<String> = requirement
+                        .findings
+                        .iter()
+                        .map(|f| format!("{} [{}] {}", f.level, f.pass, f.diagnostic))
+                        .collect();
+                    Some(format!("<system-out>{}</system-out>", escape(notes.join("\n").as_str())))
+                }
+                None => None,
+            },
+            Verdict::Unsat => {
+                suite.failures += 1;
+                Some(format!("<failure type=\"unsatisfiable\" message=\"{}\"/>", escape(detail)))
+            }
+            Verdict::Unknown => {
+                suite.errors += 1;
+                Some(format!("<error type=\"unknown\" message=\"{}\"/>", escape(detail)))
+            }
+            Verdict::NoConstraints => {
+                suite.skipped += 1;
+                Some("<skipped message=\"no constraints parsed\"/>".to_string())
+            }
+        };
+        suite.cases.push(match body {
+            Some(body) => format!("{}>\n      {}\n    </testcase>\n", case, body),
+            None => format!("{}/>\n", case),
+        });
+    }
+    for property in &report.monitored {
+        suite.skipped += 1;
+        let case = open(&format!("L{}: {}", property.line, property.tolerance), Some(property.line));
+        suite.cases.push(format!("{}>\n      <skipped message=\"monitored at runtime\"/>\n    </testcase>\n", case));
+    }
+    suite
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{MonitoredProperty, RequirementResult};
+    use crucible_core::split_tolerance;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_junit_cases() {
+        let requirement = |line: usize, text: &str, verdict: Verdict, contradicts: Vec<usize>| RequirementResult {
+            line: Some(line),
+            text: text.to_string(),
+            verdict,
+            detail: None,
+            contradicts,
+            vacuous: false,
+            tree: None,
+            findings: Vec::new(),
+        };
+        let report = SpecReport {
+            path: PathBuf::from("specs/payments.md"),
+            requirements: vec![
+                requirement(3, "Amount must be at most 100", Verdict::Sat, vec![]),
+                requirement(4, "Amount must be greater than 200", Verdict::Sat, vec![0]),
+                requirement(6, "Fees are <fair>", Verdict::NoConstraints, vec![]),
+            ],
+            monitored: vec![MonitoredProperty {
+                line: 8,
+                tolerance: split_tolerance("latency < 100ms in 99% of cases").1.unwrap(),
+            }],
+            ..SpecReport::default()
+        };
+        let broken = SpecReport {
+            path: PathBuf::from("specs/broken.md"),
+            error: Some("Unexpected `&&`".to_string()),
+            error_line: Some(2),
+            ..SpecReport::default()
+        };
+
+        let xml = render(&[report, broken]);
+        assert!(xml.contains("<testsuites name=\"crucible\" tests=\"5\" failures=\"1\" errors=\"1\" skipped=\"2\">"));
+        assert!(xml.contains(
+            "    <testcase classname=\"specs/payments.md\" name=\"L3: Amount must be at most 100\" \
+             file=\"specs/payments.md\" line=\"3\"/>\n"
+        ));
+        assert!(xml.contains(
+            "<failure type=\"contradiction\" message=\"contradicts line 3: Amount must be at most 100\"/>"
+        ));
+        assert!(xml.contains("name=\"L6: Fees are &lt;fair&gt;\""));
+        assert!(xml.contains(
+            "name=\"L8: latency &lt; 100ms in 99% of cases\" file=\"specs/payments.md\" line=\"8\">\n      \
+             <skipped message=\"monitored at runtime\"/>"
+        ));
+        assert!(xml.contains("<error type=\"parse\" message=\"Unexpected `&amp;&amp;`\"/>"));
+    }
+}
@@ -0,0 +1,302 @@
+//! Crucible Engine CLI
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+
+mod compat;
+mod github;
+mod junit;
+mod report;
+mod sarif;
+mod tui;
+mod watch;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use crucible_codegen::TargetLanguage;
+use crucible_core::error::codes;
+use crucible_core::{CrucibleConfig, CrucibleError, Diagnostic, Locale};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "crucible", version, about = "Correct by Design, Not by Debugging")]
+struct Cli {
+    /// Engine config (default: `crucible.toml` in the working directory, if present)
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Language of diagnostics (`en`, `de`, `es`; default: `CRUCIBLE_LOCALE`, then `LANG`)
+    #[arg(long, global = true, value_parser = parse_locale)]
+    locale: Option<Locale>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Regenerate validators whenever the intent file changes
+    Watch(WatchArgs),
+    /// Verify intent files and report the results for CI
+    Report(ReportArgs),
+    /// Terminal dashboard: live verification results while editing a spec
+    Tui(TuiArgs),
+    /// Check that a producer's guarantees meet a consumer's assumptions
+    Compat(CompatArgs),
+    /// Run grammar corpus files and report which grammar rules they exercise
+    Corpus(CorpusArgs),
+    /// Write a new ed25519 signing key
+    Keygen {
+        /// File the private key is written to
+        #[arg(default_value = "crucible.key")]
+        path: PathBuf,
+    },
+    /// Check generated files against the signature manifest in a directory
+    VerifySignatures {
+        /// Default: `codegen.out_dir` from the config
+        dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Intent file (plain requirements or Markdown)
+    pub spec: PathBuf,
+    /// Target language (`rust`, `ts`, `python`, `sol`, ...); default: the
+    /// first of `codegen.targets` in the config
+    #[arg(long, value_parser = parse_language)]
+    pub lang: Option<TargetLanguage>,
+    /// Directory the generated files are written to; default: `codegen.out_dir`
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+    /// Regenerate once and exit instead of watching
+    #[arg(long)]
+    pub once: bool,
+    /// Leave timestamps out of generated files, so the same spec always
+    /// produces the same bytes
+    #[arg(long)]
+    pub reproducible: bool,
+    /// Sign generated files with this key (see `crucible keygen`)
+    #[arg(long)]
+    pub sign_key: Option<PathBuf>,
+    /// Write each validator between `<crucible:begin>` and `<crucible:end>`
+    /// markers of its file, keeping the hand-written code around them
+    #[arg(long, conflicts_with = "sign_key")]
+    pub in_place: bool,
+    /// With `--in-place`, overwrite regions that were edited by hand
+    #[arg(long, requires = "in_place")]
+    pub force: bool,
+    /// Only generate requirements carrying this tag; repeat for any of several
+    #[arg(long = "tag", value_parser = parse_tag)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct TuiArgs {
+    /// Intent file (plain requirements or Markdown)
+    pub spec: PathBuf,
+    /// Language `r` regenerates validators in; default: the first of `codegen.targets`
+    #[arg(long, value_parser = parse_language)]
+    pub lang: Option<TargetLanguage>,
+    /// Directory `r` writes the generated files to; default: `codegen.out_dir`
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+    /// Only show requirements carrying this tag; repeat for any of several
+    #[arg(long = "tag", value_parser = parse_tag)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Args)]
+pub struct CompatArgs {
+    /// Intent file of the component sending across the interface
+    pub producer: PathBuf,
+    /// Intent file of the component receiving; its `Assuming ...`
+    /// requirements are checked against the producer's guarantees
+    pub consumer: PathBuf,
+}
+
+#[derive(Args)]
+pub struct CorpusArgs {
+    /// Corpus files, or directories of `.txt` corpus files
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+    /// Fail when the corpus exercises less than this percentage of the grammar's rules
+    #[arg(long, value_name = "PERCENT")]
+    pub min_coverage: Option<f64>,
+}
+
+#[derive(Args)]
+pub struct ReportArgs {
+    /// Intent files to report on, such as the ones a pull request changes
+    #[arg(required = true)]
+    pub specs: Vec<PathBuf>,
+    #[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
+    pub format: ReportFormat,
+    /// Git revision to diff the regenerated code against, such as the pull
+    /// request's base branch
+    #[arg(long)]
+    pub base: Option<String>,
+    /// Language of the regenerated code; default: the first of `codegen.targets`
+    #[arg(long, value_parser = parse_language)]
+    pub lang: Option<TargetLanguage>,
+    /// Post the report as a pull request comment (`github` format)
+    #[arg(long)]
+    pub post: bool,
+    /// Pull request to comment on; default: the one in `GITHUB_EVENT_PATH`
+    #[arg(long)]
+    pub pr: Option<u64>,
+    /// Only verify requirements carrying this tag; repeat for any of several
+    #[arg(long = "tag", value_parser = parse_tag)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ReportFormat {
+    /// The pull request comment, on stdout
+    Markdown,
+    /// Check-run annotations on stdout, plus the job summary and, with
+    /// `--post`, the pull request comment
+    Github,
+    /// A SARIF 2.1.0 log on stdout, for code scanning dashboards
+    Sarif,
+    /// JUnit XML on stdout, one test case per requirement
+    Junit,
+}
+
+fn parse_language(name: &str) -> Result<TargetLanguage, String> {
+    TargetLanguage::from_name(name).ok_or_else(|| format!("unknown target language `{}`", name))
+}
+
+fn parse_locale(tag: &str) -> Result<Locale, String> {
+    Locale::from_tag(tag).ok_or_else(|| format!("unsupported locale `{}`", tag))
+}
+
+fn parse_tag(tag: &str) -> Result<String, String> {
+    crucible_core::normalize_tag(tag).map_err(|e| e.to_string())
+}
+
+/// Exits with `CrucibleError::exit_code` when a pipeline stage failed
+/// (3 parse, 4 schema, 5 verification, 6 codegen), 1 for anything else
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let locale = cli.locale.unwrap_or_else(Locale::from_env);
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", describe(&e, locale));
+            ExitCode::from(exit_code(&e))
+        }
+    }
+}
+
+/// The error and its causes, outermost first, with pipeline errors in `locale`
+fn describe(error: &anyhow::Error, locale: Locale) -> String {
+    let causes: Vec<String> = error
+        .chain()
+        .map(|cause| match cause.downcast_ref::<CrucibleError>() {
+            Some(error) => error.localized(locale),
+            None => cause.to_string(),
+        })
+        .collect();
+    causes.join(": ")
+}
+
+/// The exit code of the first `CrucibleError` in the error's chain
+fn exit_code(error: &anyhow::Error) -> u8 {
+    error.chain().find_map(|cause| cause.downcast_ref::<CrucibleError>()).map_or(1, CrucibleError::exit_code)
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    let config = match &cli.config {
+        Some(path) => CrucibleConfig::load_from(path)?,
+        None => CrucibleConfig::load()?,
+    };
+    match cli.command {
+        Command::Watch(args) => watch::run(&args, config),
+        Command::Report(args) => run_report(&args, &config),
+        Command::Tui(args) => tui::run(&args, config, cli.locale.unwrap_or_else(Locale::from_env)),
+        Command::Compat(args) => compat::run(&args, &config),
+        Command::Corpus(args) => run_corpus(&args),
+        Command::Keygen { path } => {
+            if path.exists() {
+                anyhow::bail!("{} already exists", path.display());
+            }
+            let signer = crucible_core::ArtifactSigner::generate();
+            std::fs::write(&path, signer.seed_hex())?;
+            println!("wrote {} (fingerprint {})", path.display(), signer.fingerprint());
+            println!("public key: {}", signer.public_key_hex());
+            Ok(())
+        }
+        Command::VerifySignatures { dir } => watch::verify_signatures(dir.as_ref().unwrap_or(&config.codegen.out_dir)),
+    }
+}
+
+/// `crucible report`: fails when any spec has an unsatisfiable or
+/// contradicting requirement, so the CI job fails with it. SARIF output
+/// leaves failing to the dashboard, so the upload step still runs
+fn run_report(args: &ReportArgs, config: &CrucibleConfig) -> anyhow::Result<()> {
+    let reports = report::build(args, config)?;
+    let body = github::comment(&reports);
+    match args.format {
+        ReportFormat::Markdown => print!("{}", body),
+        ReportFormat::Github => {
+            print!("{}", github::annotations(&reports));
+            github::write_step_summary(&body)?;
+            if args.post {
+                let url = github::post_comment(&body, args.pr)?;
+                eprintln!("posted {}", url);
+            }
+        }
+        ReportFormat::Junit => print!("{}", junit::render(&reports)),
+        ReportFormat::Sarif => {
+            println!("{}", serde_json::to_string_pretty(&sarif::log(&reports))?);
+            return Ok(());
+        }
+    }
+    let failing = reports.iter().filter(|r| r.has_errors()).count();
+    if failing > 0 {
+        let message = format!("{} of {} intent file(s) failed verification", failing, reports.len());
+        return Err(CrucibleError::Verification(Diagnostic::new(codes::UNSATISFIABLE, message)).into());
+    }
+    Ok(())
+}
+
+/// `crucible corpus`: fails when a case does not parse as expected or the
+/// coverage is below `--min-coverage`
+fn run_corpus(args: &CorpusArgs) -> anyhow::Result<()> {
+    let mut corpus = crucible_parser::Corpus::default();
+    for path in &args.paths {
+        corpus.cases.extend(crucible_parser::Corpus::load(path)?.cases);
+    }
+    let report = corpus.run();
+    print!("{}", report);
+    if !report.is_success() {
+        anyhow::bail!("{} of {} corpus case(s) failed", report.failures.len(), report.cases);
+    }
+    let coverage = report.coverage.ratio() * 100.0;
+    match args.min_coverage {
+        Some(minimum) if coverage < minimum => {
+            anyhow::bail!("grammar coverage {:.1}% is below the required {}%", coverage, minimum)
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_exit_codes() {
+        let undefined = crucible_core::DefinitionError::Undefined("Eligible".to_string());
+        let error = Err::<(), _>(CrucibleError::from(undefined)).context("checking spec.md").unwrap_err();
+        assert_eq!(exit_code(&error), 4);
+        assert_eq!(
+            describe(&error, Locale::Es),
+            "checking spec.md: error de esquema [S005] predicado `Eligible` no definido"
+        );
+        assert_eq!(exit_code(&anyhow::anyhow!("spec.md: no such file")), 1);
+    }
+}
@@ -0,0 +1,360 @@
+//! `crucible report` - verification results of intent files for CI
+//!
+//! Every requirement of each spec is located in its source, checked with Z3
+//! and classified against the requirements above it, so a contradiction
+//! names both lines. A requirement whose `if` condition no state allowed by
+//! the rest of the spec meets is flagged as vacuous: it holds, but only
+//! because it never applies. The parser's ambiguities are carried along.
+//!
+//! With `--base <ref>` the report also carries a unified diff of the
+//! validators the spec generates against the ones its version at `<ref>`
+//! generated, which is what a reviewer of an intent change wants to see,
+//! and the semantic change: whether the new version allows the same values,
+//! fewer, more, or different ones, with a witness for each direction.
+//! Renderers (`github`, `sarif`, `junit`) turn the result into CI output.
+//!
+//! With `--tag`, requirements without one of the tags are left out of both
+//! the verification and the diff.
+//!
+//! Tolerances ("latency < 100ms in 99% of cases") are cut from the text the
+//! solver sees and listed as monitored properties instead.
+//!
+//! The verification passes (`PassManager::with_builtin`) then run over the
+//! constraint trees, and what they find is reported with the requirement;
+//! an error-level finding fails the report like a contradiction does.
+
+use crate::watch::{line_tags, selected, spec_lines, spec_text};
+use crate::ReportArgs;
+use anyhow::Context;
+use crucible_codegen::{CodeGenerator, CodegenOptions, TargetLanguage};
+use crucible_core::{split_tags, split_tolerance, CompoundConstraint, CrucibleConfig, CrucibleError, Tolerance};
+use crucible_parser::AmbiguityKind;
+use crucible_verification::{
+    Consistency, Finding, Level, PassManager, RefinementOutput, VerificationError, Z3Verifier,
+};
+use similar::TextDiff;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Solver verdict for one requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Sat,
+    Unsat,
+    /// The solver gave up or could not translate the constraints
+    Unknown,
+    /// The parser found no constraints in the sentence
+    NoConstraints,
+}
+
+impl Verdict {
+    pub fn label(self) -> &'static str {
+        match self {
+            Verdict::Sat => "SAT",
+            Verdict::Unsat => "UNSAT",
+            Verdict::Unknown => "unknown",
+            Verdict::NoConstraints => "no constraints",
+        }
+    }
+}
+
+/// One requirement of a spec
+#[derive(Debug, Clone)]
+pub struct RequirementResult {
+    /// 1-based line in the spec
+    pub line: Option<usize>,
+    pub text: String,
+    pub verdict: Verdict,
+    /// The solver's reason for anything but `Sat`
+    pub detail: Option<String>,
+    /// Indices of the earlier requirements this one contradicts
+    pub contradicts: Vec<usize>,
+    /// Satisfiable, but its condition can never hold alongside the rest of the spec
+    pub vacuous: bool,
+    /// The constraint tree the solver checked, definitions inlined
+    pub tree: Option<CompoundConstraint>,
+    /// What the verification passes reported about the tree
+    pub findings: Vec<Finding>,
+}
+
+/// A sentence the parser could read more than one way
+#[derive(Debug, Clone)]
+pub struct AmbiguityNote {
+    pub line: Option<usize>,
+    pub kind: AmbiguityKind,
+    /// The text that can be read more than one way
+    pub span: String,
+    /// Every reading, the one the parser chose first
+    pub interpretations: Vec<String>,
+}
+
+/// A tolerance in the spec, monitored at runtime instead of checked by the solver
+#[derive(Debug, Clone)]
+pub struct MonitoredProperty {
+    /// 1-based line in the spec
+    pub line: usize,
+    pub tolerance: Tolerance,
+}
+
+/// Everything `crucible report` found in one spec
+#[derive(Debug, Clone, Default)]
+pub struct SpecReport {
+    pub path: PathBuf,
+    pub requirements: Vec<RequirementResult>,
+    pub ambiguities: Vec<AmbiguityNote>,
+    pub monitored: Vec<MonitoredProperty>,
+    /// Unified diffs of the generated files that differ from `--base`
+    pub diffs: Vec<String>,
+    /// How the spec relates to its version at `--base`, when it had one
+    pub refinement: Option<RefinementOutput>,
+    /// Why the spec could not be read or parsed
+    pub error: Option<String>,
+    /// Line of a syntax error
+    pub error_line: Option<usize>,
+}
+
+impl SpecReport {
+    /// Unreadable, or with a requirement that is unsatisfiable, contradicts
+    /// another or has an error-level finding
+    pub fn has_errors(&self) -> bool {
+        self.error.is_some()
+            || self.requirements.iter().any(|r| {
+                r.verdict == Verdict::Unsat
+                    || !r.contradicts.is_empty()
+                    || r.findings.iter().any(|f| f.level == Level::Error)
+            })
+    }
+
+    /// Requirement lines of the contradiction pairs, as `(later, earlier)`
+    pub fn contradictions(&self) -> Vec<(&RequirementResult, &RequirementResult)> {
+        self.requirements
+            .iter()
+            .flat_map(|r| r.contradicts.iter().map(move |&earlier| (r, &self.requirements[earlier])))
+            .collect()
+    }
+}
+
+/// Verify every spec in `args`, diffing generated code against `args.base`
+pub fn build(args: &ReportArgs, config: &CrucibleConfig) -> anyhow::Result<Vec<SpecReport>> {
+    let language = match args.lang {
+        Some(language) => language,
+        None => *TargetLanguage::configured(config)?.first().context("no target language configured")?,
+    };
+    let options = CodegenOptions {
+        reproducible: true,
+        ..CodegenOptions::from_config(config)
+    };
+    let verifier = Z3Verifier::with_timeout(config.solver_timeout());
+    let passes = PassManager::with_builtin();
+    let mut reports = Vec::new();
+    for spec in &args.specs {
+        let mut report = SpecReport {
+            path: spec.clone(),
+            ..SpecReport::default()
+        };
+        let result = check_spec(&verifier, &passes, spec, &args.tags, &mut report).and_then(|compounds| {
+            let Some(base) = &args.base else {
+                return Ok(());
+            };
+            let generated = generate(&compounds, language, &options, &config.naming.file_prefix)?;
+            let before = match base_source(base, spec)? {
+                Some(source) => {
+                    let before = constraints(spec, &source, &args.tags)?;
+                    // A solver that gives up leaves the relation out; the code diff still stands
+                    report.refinement = verifier.compare_specs(&before, &compounds).ok();
+                    generate(&before, language, &options, &config.naming.file_prefix)?
+                }
+                None => BTreeMap::new(),
+            };
+            report.diffs = diff(&before, &generated);
+            Ok(())
+        });
+        if let Err(e) = result {
+            report.error = Some(format!("{:#}", e));
+        }
+        reports.push(report);
+    }
+    Ok(reports)
+}
+
+/// Fill in the requirements of `spec` that pass the tag filter; returns the
+/// constraint tree of each one that has one, for code generation
+pub(crate) fn check_spec(
+    verifier: &Z3Verifier,
+    passes: &PassManager,
+    spec: &Path,
+    tags: &[String],
+    report: &mut SpecReport,
+) -> anyhow::Result<Vec<CompoundConstraint>> {
+    let source = fs::read_to_string(spec).with_context(|| format!("reading {}", spec.display()))?;
+    let lines = spec_lines(spec, &source);
+    // The parser saw only the requirement lines, numbered from 1
+    let spec_line = |line: usize| lines.get(line.wrapping_sub(1)).copied();
+    let ast = crucible_parser::parse(&spec_text(spec, &source)).map_err(|e| {
+        report.error_line = spec_line(e.line).map(|(line, _)| line);
+        CrucibleError::from(e)
+    })?;
+    let definitions = ast.definition_table().map_err(CrucibleError::from)?;
+    report.ambiguities = ast
+        .ambiguities
+        .iter()
+        .map(|ambiguity| AmbiguityNote {
+            line: spec_line(ambiguity.line).map(|(line, _)| line),
+            kind: ambiguity.kind,
+            span: ambiguity.span.clone(),
+            interpretations: ambiguity.interpretations.iter().map(|i| i.description.clone()).collect(),
+        })
+        .collect();
+    let tolerance = |text: &str| split_tolerance(split_tags(text).0).1;
+    report.monitored = lines
+        .iter()
+        .filter(|(_, text)| selected(tags, &split_tags(text).1))
+        .filter_map(|&(line, text)| Some(MonitoredProperty { line, tolerance: tolerance(text)? }))
+        .collect();
+
+    // Constraint trees of the earlier satisfiable requirements, with their indices
+    let mut earlier: Vec<(usize, CompoundConstraint)> = Vec::new();
+    let mut compounds = Vec::new();
+    // Requirement index of each of `compounds`
+    let mut indices = Vec::new();
+    // Inlined `if` conditions by requirement index
+    let mut conditions: Vec<(usize, CompoundConstraint)> = Vec::new();
+    for requirement in ast.requirements.iter().filter(|r| selected(tags, &line_tags(&lines, r.line))) {
+        let (line, text) = spec_line(requirement.line).unzip();
+        // All it said was its tolerance, which is listed as monitored
+        if requirement.compound().is_none() && text.and_then(tolerance).is_some() {
+            continue;
+        }
+        let mut result = RequirementResult {
+            line,
+            text: split_tags(text.unwrap_or_default()).0.trim().to_string(),
+            verdict: Verdict::NoConstraints,
+            detail: None,
+            contradicts: Vec::new(),
+            vacuous: false,
+            tree: None,
+            findings: Vec::new(),
+        };
+        if let Some(compound) = requirement.compound() {
+            let compound = definitions.inline(&compound).map_err(CrucibleError::from)?;
+            match verifier.verify_compound_constraints(&compound) {
+                Ok(_) => result.verdict = Verdict::Sat,
+                Err(VerificationError::Unsatisfiable(reason)) => {
+                    result.verdict = Verdict::Unsat;
+                    result.detail = Some(reason);
+                }
+                Err(e) => {
+                    result.verdict = Verdict::Unknown;
+                    result.detail = Some(e.to_string());
+                }
+            }
+            if result.verdict == Verdict::Sat {
+                let existing: Vec<CompoundConstraint> = earlier.iter().map(|(_, c)| c.clone()).collect();
+                if let Ok(Consistency::Contradicts(conflict)) = verifier.classify(&existing, &compound) {
+                    result.contradicts = conflict.into_iter().map(|i| earlier[i].0).collect();
+                }
+                earlier.push((report.requirements.len(), compound.clone()));
+                if let Some(condition) = requirement.condition.as_ref().and_then(|c| c.to_compound()) {
+                    conditions.push((report.requirements.len(), definitions.inline(&condition)?));
+                }
+            }
+            result.tree = Some(compound.clone());
+            indices.push(report.requirements.len());
+            compounds.push(compound);
+        }
+        report.requirements.push(result);
+    }
+    for finding in passes.run(verifier, None, &compounds) {
+        report.requirements[indices[finding.requirement]].findings.push(finding);
+    }
+
+    // In a contradictory spec every condition is unreachable; the contradiction is the finding
+    if report.requirements.iter().any(|r| !r.contradicts.is_empty()) {
+        return Ok(compounds);
+    }
+    for (index, condition) in conditions {
+        let mut context: Vec<CompoundConstraint> =
+            earlier.iter().filter(|(other, _)| *other != index).map(|(_, c)| c.clone()).collect();
+        context.push(condition);
+        let reachable = verifier.verify_compound_constraints(&CompoundConstraint::And(context));
+        report.requirements[index].vacuous = matches!(reachable, Err(VerificationError::Unsatisfiable(_)));
+    }
+    Ok(compounds)
+}
+
+/// Inlined constraint trees of a spec's tagged requirements, without verifying them
+fn constraints(spec: &Path, source: &str, tags: &[String]) -> anyhow::Result<Vec<CompoundConstraint>> {
+    let ast = crucible_parser::parse(&spec_text(spec, source)).map_err(CrucibleError::from)?;
+    let definitions = ast.definition_table().map_err(CrucibleError::from)?;
+    let lines = spec_lines(spec, source);
+    let mut compounds = Vec::new();
+    for requirement in ast.requirements.iter().filter(|r| selected(tags, &line_tags(&lines, r.line))) {
+        if let Some(compound) = requirement.compound() {
+            compounds.push(definitions.inline(&compound).map_err(CrucibleError::from)?);
+        }
+    }
+    Ok(compounds)
+}
+
+/// The files `crucible watch` would write for these constraints, by name
+fn generate(
+    compounds: &[CompoundConstraint],
+    language: TargetLanguage,
+    options: &CodegenOptions,
+    file_prefix: &str,
+) -> anyhow::Result<BTreeMap<String, String>> {
+    let mut files = BTreeMap::new();
+    for compound in compounds {
+        let hash = compound.canonical_hash();
+        let options = CodegenOptions {
+            func_name: format!("{}{}", file_prefix, hash),
+            ..options.clone()
+        };
+        let output = CodeGenerator.generate_with_options(compound, language, &options).map_err(CrucibleError::from)?;
+        files.insert(format!("{}{}.{}", file_prefix, hash, language.file_extension()), output.code);
+        for file in output.files {
+            files.insert(file.path, file.contents);
+        }
+    }
+    Ok(files)
+}
+
+/// `spec` as committed at `base`; `None` when it did not exist there
+fn base_source(base: &str, spec: &Path) -> anyhow::Result<Option<String>> {
+    // `./` makes git resolve the path against the working directory
+    let output = Command::new("git")
+        .arg("show")
+        .arg(format!("{}:./{}", base, spec.display()))
+        .output()
+        .context("running git show")?;
+    if !output.status.success() {
+        let verify = Command::new("git").args(["rev-parse", "--verify", "--quiet"]).arg(base).output()?;
+        if !verify.status.success() {
+            anyhow::bail!("unknown git revision {}", base);
+        }
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8(output.stdout).context("spec at base is not UTF-8")?))
+}
+
+/// Unified diffs of every file added, removed or changed
+fn diff(before: &BTreeMap<String, String>, after: &BTreeMap<String, String>) -> Vec<String> {
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let old = before.get(name).map_or("", String::as_str);
+            let new = after.get(name).map_or("", String::as_str);
+            (old != new).then(|| {
+                TextDiff::from_lines(old, new)
+                    .unified_diff()
+                    .header(&format!("a/{}", name), &format!("b/{}", name))
+                    .to_string()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,200 @@
+//! `crucible report --format sarif` - spec diagnostics as SARIF 2.1.0
+//!
+//! Syntax errors, ambiguities, unsatisfiable, contradicting, vacuous and
+//! unverified requirements and the verification passes' findings become
+//! SARIF results against the intent file's lines, so GitHub Code Scanning and other SARIF dashboards list them next
+//! to ordinary static analysis findings:
+//!
+//! ```yaml
+//! - run: crucible report --format sarif specs/*.md > crucible.sarif
+//! - uses: github/codeql-action/upload-sarif@v3
+//!   with:
+//!     sarif_file: crucible.sarif
+//! ```
+//!
+//! Rule IDs are stable across releases, so dismissed alerts stay dismissed.
+
+use crate::report::{SpecReport, Verdict};
+use serde_json::{json, Value};
+
+/// `(id, name, level, description)` of every rule, in `ruleIndex` order
+const RULES: &[(&str, &str, &str, &str)] = &[
+    ("CRU001", "syntax-error", "error", "The intent file does not parse"),
+    ("CRU002", "ambiguous-requirement", "warning", "The requirement can be read more than one way"),
+    ("CRU003", "unsatisfiable-requirement", "error", "No assignment satisfies the requirement"),
+    ("CRU004", "contradictory-requirements", "error", "The requirement contradicts an earlier one"),
+    ("CRU005", "vacuous-requirement", "warning", "The requirement's condition can never hold"),
+    ("CRU006", "unverified-requirement", "note", "The solver could not decide the requirement"),
+    ("CRU007", "unconstrained-requirement", "note", "The parser found no constraints in the requirement"),
+    ("CRU008", "verification-pass", "warning", "A verification pass reported a finding"),
+];
+
+/// The SARIF log of every spec, as one run of the `crucible` tool
+pub fn log(reports: &[SpecReport]) -> Value {
+    let rules: Vec<Value> = RULES
+        .iter()
+        .map(|(id, name, level, description)| {
+            json!({
+                "id": id,
+                "name": name,
+                "shortDescription": { "text": description },
+                "defaultConfiguration": { "level": level },
+            })
+        })
+        .collect();
+    let results: Vec<Value> = reports.iter().flat_map(results).collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "crucible",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+fn results(report: &SpecReport) -> Vec<Value> {
+    let uri = report.path.to_string_lossy().replace('\\', "/");
+    let location = |line: Option<usize>| {
+        let mut physical = json!({ "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" } });
+        if let Some(line) = line {
+            physical["region"] = json!({ "startLine": line });
+        }
+        json!({ "physicalLocation": physical })
+    };
+
+    let mut results = Vec::new();
+    if let Some(error) = &report.error {
+        results.push(result(0, error, location(report.error_line)));
+    }
+    for ambiguity in &report.ambiguities {
+        let message = format!("`{}` could mean {}", ambiguity.span, ambiguity.interpretations.join(" or "));
+        let mut ambiguous = result(1, &message, location(ambiguity.line));
+        ambiguous["properties"] = json!({ "ambiguityKind": ambiguity.kind });
+        results.push(ambiguous);
+    }
+    for requirement in &report.requirements {
+        for finding in &requirement.findings {
+            let mut found = result(7, &finding.diagnostic.to_string(), location(requirement.line));
+            found["level"] = json!(finding.level.to_string());
+            found["properties"] = json!({ "pass": finding.pass, "code": finding.diagnostic.code });
+            results.push(found);
+        }
+        let here = location(requirement.line);
+        let detail = requirement.detail.as_deref().map(|d| format!(": {}", d)).unwrap_or_default();
+        match requirement.verdict {
+            Verdict::Unsat => {
+                results.push(result(2, &format!("Unsatisfiable: {}{}", requirement.text, detail), here));
+            }
+            Verdict::Unknown => {
+                results.push(result(5, &format!("Not verified: {}{}", requirement.text, detail), here));
+            }
+            Verdict::NoConstraints => {
+                results.push(result(6, &format!("No constraints parsed: {}", requirement.text), here));
+            }
+            Verdict::Sat if !requirement.contradicts.is_empty() => {
+                let mut contradiction = result(3, &format!("Contradiction: {}", requirement.text), here);
+                contradiction["relatedLocations"] = requirement
+                    .contradicts
+                    .iter()
+                    .enumerate()
+                    .map(|(id, &other)| {
+                        let other = &report.requirements[other];
+                        let mut related = location(other.line);
+                        related["id"] = json!(id);
+                        related["message"] = json!({ "text": other.text });
+                        related
+                    })
+                    .collect();
+                results.push(contradiction);
+            }
+            Verdict::Sat if requirement.vacuous => {
+                let message = format!("Vacuous: the condition of `{}` can never hold", requirement.text);
+                results.push(result(4, &message, here));
+            }
+            Verdict::Sat => {}
+        }
+    }
+    results
+}
+
+fn result(rule: usize, message: &str, location: Value) -> Value {
+    let (id, _, level, _) = RULES[rule];
+    json!({
+        "ruleId": id,
+        "ruleIndex": rule,
+        "level": level,
+        "message": { "text": message },
+        "locations": [location],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::RequirementResult;
+    use crucible_core::Diagnostic;
+    use crucible_verification::{Finding, Level};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_sarif_results() {
+        let requirement = |line: usize, text: &str, contradicts: Vec<usize>, vacuous: bool| RequirementResult {
+            line: Some(line),
+            text: text.to_string(),
+            verdict: Verdict::Sat,
+            detail: None,
+            contradicts,
+            vacuous,
+            tree: None,
+            findings: Vec::new(),
+        };
+        let mut capped = requirement(3, "Amount must be at most 100", vec![], false);
+        capped.findings.push(Finding {
+            pass: "limits-policy".to_string(),
+            requirement: 0,
+            level: Level::Note,
+            diagnostic: Diagnostic::new("ACME001", "limit below the company minimum"),
+        });
+        let report = SpecReport {
+            path: PathBuf::from("specs\\payments.md"),
+            requirements: vec![
+                capped,
+                requirement(4, "Amount must be greater than 200", vec![0], false),
+                requirement(5, "Refunds need approval if amount > 1000", vec![], true),
+            ],
+            ..SpecReport::default()
+        };
+
+        let log = log(&[report]);
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), RULES.len());
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0]["ruleId"], "CRU008");
+        assert_eq!(results[0]["level"], "note");
+        assert_eq!(results[0]["message"]["text"], "[ACME001] limit below the company minimum");
+        assert_eq!(results[0]["properties"]["pass"], "limits-policy");
+
+        assert_eq!(results[1]["ruleId"], "CRU004");
+        assert_eq!(results[1]["level"], "error");
+        let location = &results[1]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "specs/payments.md");
+        assert_eq!(location["region"]["startLine"], 4);
+        assert_eq!(results[1]["relatedLocations"][0]["physicalLocation"]["region"]["startLine"], 3);
+
+        assert_eq!(results[2]["ruleId"], "CRU005");
+        assert_eq!(results[2]["ruleIndex"], 4);
+        assert_eq!(results[2]["level"], "warning");
+    }
+}
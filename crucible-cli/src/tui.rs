@@ -0,0 +1,374 @@
+//! `crucible tui` - a terminal dashboard for writing a spec
+//!
+//! The left pane lists the spec's requirements with the solver's verdict on
+//! each; the right panes show the selected requirement's constraint tree and
+//! what the solver said about it, verbalized back to a sentence. The spec is
+//! re-verified in the background on every save, so the verdicts follow the
+//! editor; `r` regenerates the validators into `--out` the way `crucible
+//! watch` would. It needs nothing but a terminal, so it works over SSH where
+//! the web frontend is out of reach.
+//!
+//! ```text
+//! ┌ payments.md ─────────────────┐┌ Constraint tree ─────────┐
+//! │ ✔    1  The user may withdraw││ AND                      │
+//! │ ✘    3  The fee must be ...  ││ ├─ balance >= amount     │
+//! │ ·    5  Refunds are logged   ││ └─ amount > 0            │
+//! │                              │└──────────────────────────┘
+//! │                              │┌ SAT ─────────────────────┐
+//! └──────────────────────────────┘└──────────────────────────┘
+//!  2 regenerated, 0 unchanged, 0 removed, 0 failed in 41 ms
+//! ```
+
+use crate::report::{check_spec, RequirementResult, SpecReport, Verdict};
+use crate::watch::{touches_spec, watch_spec, Session, DEBOUNCE};
+use crate::TuiArgs;
+use anyhow::Context;
+use crucible_codegen::TargetLanguage;
+use crucible_core::{CompoundConstraint, CrucibleConfig, Locale, Verbalizer};
+use crucible_verification::{PassManager, Z3Verifier};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::Constraint::{Fill, Length, Percentage};
+use ratatui::layout::Layout;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// How long to wait for a key before looking for new results
+const TICK: Duration = Duration::from_millis(100);
+
+const KEYS: &str = "↑/↓ select  r regenerate  v verify  q quit";
+
+/// What the dashboard shows
+struct App {
+    spec: PathBuf,
+    report: SpecReport,
+    list: ListState,
+    /// A check of the spec is running in the background
+    verifying: bool,
+    /// Outcome of the last regeneration
+    status: Option<Result<String, String>>,
+    locale: Locale,
+}
+
+impl App {
+    fn new(spec: &Path, locale: Locale) -> Self {
+        Self {
+            spec: spec.to_path_buf(),
+            report: SpecReport::default(),
+            list: ListState::default(),
+            verifying: false,
+            status: None,
+            locale,
+        }
+    }
+
+    /// Show a new report, keeping the selection where it was
+    fn update(&mut self, report: SpecReport) {
+        let count = report.requirements.len();
+        self.report = report;
+        self.verifying = false;
+        self.list.select(match (self.list.selected(), count) {
+            (_, 0) => None,
+            (Some(index), _) => Some(index.min(count - 1)),
+            (None, _) => Some(0),
+        });
+    }
+
+    fn selected(&self) -> Option<&RequirementResult> {
+        self.list.selected().and_then(|index| self.report.requirements.get(index))
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        let count = self.report.requirements.len();
+        if count == 0 {
+            return;
+        }
+        let index = self.list.selected().unwrap_or(0);
+        self.list.select(Some(if down { (index + 1).min(count - 1) } else { index.saturating_sub(1) }));
+    }
+}
+
+/// Checks the spec on its own thread whenever asked, so the UI stays
+/// responsive while Z3 works. The verifier is created there, as it cannot
+/// move between threads
+fn spawn_checker(
+    spec: PathBuf,
+    tags: Vec<String>,
+    timeout: Option<Duration>,
+) -> (mpsc::Sender<()>, mpsc::Receiver<SpecReport>) {
+    let (request_tx, request_rx) = mpsc::channel::<()>();
+    let (report_tx, report_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let verifier = Z3Verifier::with_timeout(timeout);
+        let passes = PassManager::with_builtin();
+        while request_rx.recv().is_ok() {
+            // Saves made during a check are covered by the next one
+            while request_rx.try_recv().is_ok() {}
+            let mut report = SpecReport {
+                path: spec.clone(),
+                ..SpecReport::default()
+            };
+            if let Err(e) = check_spec(&verifier, &passes, &spec, &tags, &mut report) {
+                report.error = Some(format!("{:#}", e));
+            }
+            if report_tx.send(report).is_err() {
+                break;
+            }
+        }
+    });
+    (request_tx, report_rx)
+}
+
+/// `crucible tui`: verify the spec, then again on every change to it, until `q`
+pub fn run(args: &TuiArgs, config: CrucibleConfig, locale: Locale) -> anyhow::Result<()> {
+    let language = match args.lang {
+        Some(language) => language,
+        None => *TargetLanguage::configured(&config)?.first().context("no target language configured")?,
+    };
+    let out = args.out.as_ref().unwrap_or(&config.codegen.out_dir);
+    let mut session = Session::new(&args.spec, language, out, &config).with_tags(args.tags.clone());
+    let file_name = args.spec.file_name().context("spec path has no file name")?.to_owned();
+    let (_watcher, changes) = watch_spec(&args.spec)?;
+    let (check, reports) = spawn_checker(args.spec.clone(), args.tags.clone(), config.solver_timeout());
+
+    let mut app = App::new(&args.spec, locale);
+    check.send(())?;
+    app.verifying = true;
+
+    let mut terminal = ratatui::init();
+    let result = (|| -> anyhow::Result<()> {
+        loop {
+            terminal.draw(|frame| draw(frame, &mut app))?;
+            if event::poll(TICK)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Down | KeyCode::Char('j') => app.move_selection(true),
+                        KeyCode::Up | KeyCode::Char('k') => app.move_selection(false),
+                        KeyCode::Char('v') => {
+                            check.send(())?;
+                            app.verifying = true;
+                        }
+                        KeyCode::Char('r') => regenerate(&mut terminal, &mut app, &mut session)?,
+                        _ => {}
+                    }
+                }
+            }
+            if changes.try_iter().any(|event| touches_spec(&event, &file_name)) {
+                while changes.recv_timeout(DEBOUNCE).is_ok() {}
+                check.send(())?;
+                app.verifying = true;
+            }
+            if let Some(report) = reports.try_iter().last() {
+                app.update(report);
+            }
+        }
+    })();
+    ratatui::restore();
+    result
+}
+
+/// Bring `--out` up to date; the solver runs on this thread, so say so first
+fn regenerate(terminal: &mut DefaultTerminal, app: &mut App, session: &mut Session) -> anyhow::Result<()> {
+    app.status = Some(Ok("regenerating…".to_string()));
+    terminal.draw(|frame| draw(frame, app))?;
+    app.status = Some(session.rebuild().map(|report| report.summary()).map_err(|e| format!("{:#}", e)));
+    Ok(())
+}
+
+fn draw(frame: &mut Frame, app: &mut App) {
+    let [main, status, keys] = Layout::vertical([Fill(1), Length(1), Length(1)]).areas(frame.area());
+    let [list, right] = Layout::horizontal([Percentage(45), Fill(1)]).areas(main);
+    let [tree, detail] = Layout::vertical([Percentage(60), Fill(1)]).areas(right);
+
+    let mut title = app.spec.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+    if app.verifying {
+        title.push_str(" - verifying…");
+    }
+    let items: Vec<ListItem> = app
+        .report
+        .requirements
+        .iter()
+        .map(|requirement| {
+            let (icon, color) = icon(requirement);
+            let line = requirement.line.map_or_else(String::new, |line| line.to_string());
+            ListItem::new(Line::from(vec![
+                Span::styled(icon, Style::new().fg(color)),
+                Span::raw(format!(" {:>4}  {}", line, requirement.text)),
+            ]))
+        })
+        .collect();
+    let requirements = List::new(items)
+        .block(Block::new().borders(Borders::ALL).title(title))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(requirements, list, &mut app.list);
+
+    let selected = app.selected();
+    let tree_text: Vec<Line> = match selected.and_then(|r| r.tree.as_ref()) {
+        Some(compound) => tree_lines(compound).into_iter().map(Line::from).collect(),
+        None => Vec::new(),
+    };
+    frame.render_widget(
+        Paragraph::new(tree_text).block(Block::new().borders(Borders::ALL).title("Constraint tree")),
+        tree,
+    );
+
+    let (verdict, detail_text) = match selected {
+        Some(requirement) => (requirement.verdict.label(), details(requirement, &app.report, app.locale)),
+        None => ("", Vec::new()),
+    };
+    frame.render_widget(
+        Paragraph::new(detail_text.into_iter().map(Line::from).collect::<Vec<_>>())
+            .wrap(Wrap { trim: true })
+            .block(Block::new().borders(Borders::ALL).title(verdict)),
+        detail,
+    );
+
+    let status_line = match (&app.report.error, &app.status) {
+        (Some(error), _) => Line::styled(format!("error: {}", error), Style::new().fg(Color::Red)),
+        (None, Some(Err(error))) => {
+            Line::styled(format!("regeneration failed: {}", error), Style::new().fg(Color::Red))
+        }
+        (None, Some(Ok(summary))) => Line::raw(summary.as_str()),
+        (None, None) => Line::raw(""),
+    };
+    frame.render_widget(Paragraph::new(status_line), status);
+    frame.render_widget(Paragraph::new(Line::styled(KEYS, Style::new().fg(Color::DarkGray))), keys);
+}
+
+/// Status icon of a requirement in the list
+fn icon(requirement: &RequirementResult) -> (&'static str, Color) {
+    match requirement.verdict {
+        Verdict::Sat if !requirement.contradicts.is_empty() => ("✘", Color::Red),
+        Verdict::Sat if requirement.vacuous => ("○", Color::Yellow),
+        Verdict::Sat => ("✔", Color::Green),
+        Verdict::Unsat => ("✘", Color::Red),
+        Verdict::Unknown => ("?", Color::Yellow),
+        Verdict::NoConstraints => ("·", Color::DarkGray),
+    }
+}
+
+/// What the solver found about a requirement, then the requirement as the
+/// engine understood it
+fn details(requirement: &RequirementResult, report: &SpecReport, locale: Locale) -> Vec<String> {
+    let mut lines: Vec<String> = requirement.detail.iter().cloned().collect();
+    for &earlier in &requirement.contradicts {
+        if let Some(line) = report.requirements.get(earlier).and_then(|r| r.line) {
+            lines.push(format!("contradicts the requirement on line {}", line));
+        }
+    }
+    if requirement.vacuous {
+        lines.push("vacuous: its condition can never hold".to_string());
+    }
+    for finding in &requirement.findings {
+        lines.push(format!("{} [{}] {}", finding.level, finding.pass, finding.diagnostic));
+    }
+    if let Some(tree) = &requirement.tree {
+        lines.push(String::new());
+        lines.push(Verbalizer::new().with_locale(locale).compound(tree));
+    }
+    lines
+}
+
+/// A constraint tree drawn one node per line, each group's members below it
+fn tree_lines(tree: &CompoundConstraint) -> Vec<String> {
+    let mut lines = Vec::new();
+    push_node(tree, "", "", &mut lines);
+    lines
+}
+
+/// `lead` goes before the node's own line, `indent` before its members'
+fn push_node(node: &CompoundConstraint, lead: &str, indent: &str, lines: &mut Vec<String>) {
+    let (label, members): (String, Vec<&CompoundConstraint>) = match node {
+        CompoundConstraint::And(parts) => ("AND".to_string(), parts.iter().collect()),
+        CompoundConstraint::Or(parts) => ("OR".to_string(), parts.iter().collect()),
+        CompoundConstraint::Not(inner) => ("NOT".to_string(), vec![inner.as_ref()]),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            ("IF … THEN".to_string(), vec![antecedent.as_ref(), consequent.as_ref()])
+        }
+        CompoundConstraint::ExactlyOne(parts) => ("EXACTLY ONE".to_string(), parts.iter().collect()),
+        CompoundConstraint::AtMostOne(parts) => ("AT MOST ONE".to_string(), parts.iter().collect()),
+        CompoundConstraint::Simple(constraint) => (constraint.to_string(), Vec::new()),
+        CompoundConstraint::Named(name) => (name.clone(), Vec::new()),
+        CompoundConstraint::Distinct(_) => (node.to_string(), Vec::new()),
+    };
+    lines.push(format!("{}{}", lead, label));
+    for (index, member) in members.iter().enumerate() {
+        let (branch, rail) = if index + 1 == members.len() { ("└─ ", "   ") } else { ("├─ ", "│  ") };
+        push_node(member, &format!("{}{}", indent, branch), &format!("{}{}", indent, rail), lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn simple(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_dashboard() {
+        let tree = CompoundConstraint::And(vec![
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            CompoundConstraint::Not(Box::new(simple("status", ConstraintOperator::Equal, "2"))),
+        ]);
+        assert_eq!(tree_lines(&tree), ["AND", "├─ balance >= amount", "└─ NOT", "   └─ status == 2"]);
+
+        let requirement = |line: usize, text: &str, verdict: Verdict, tree: Option<CompoundConstraint>| {
+            RequirementResult {
+                line: Some(line),
+                text: text.to_string(),
+                verdict,
+                detail: None,
+                contradicts: Vec::new(),
+                vacuous: false,
+                tree,
+                findings: Vec::new(),
+            }
+        };
+        let mut app = App::new(Path::new("specs/payments.md"), Locale::En);
+        app.update(SpecReport {
+            requirements: vec![
+                requirement(1, "The user may withdraw", Verdict::Sat, Some(tree)),
+                requirement(3, "Refunds are logged", Verdict::NoConstraints, None),
+            ],
+            ..SpecReport::default()
+        });
+        app.move_selection(true);
+        app.move_selection(true);
+        assert_eq!(app.list.selected(), Some(1));
+        app.move_selection(false);
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+        let screen: Vec<String> = terminal
+            .backend()
+            .buffer()
+            .content()
+            .chunks(100)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect();
+        assert!(screen[0].contains("payments.md"));
+        assert!(screen[1].contains("✔    1  The user may withdraw"));
+        assert!(screen[2].contains("·    3  Refunds are logged"));
+        assert!(screen.iter().any(|row| row.contains("├─ balance >= amount")));
+        assert!(screen.iter().any(|row| row.contains("┌SAT")));
+        assert!(screen[15].starts_with(KEYS));
+    }
+}
@@ -0,0 +1,553 @@
+//! `crucible watch` - incremental regeneration for a fast dev loop
+//!
+//! Each requirement's constraint tree is keyed by its canonical hash, which
+//! stays the same when the sentence is reworded without changing its logic
+//! ("a and b" / "b and a"). On every save the spec is re-parsed, and only
+//! requirements whose hash is new are re-verified and regenerated; files of
+//! requirements that disappeared are deleted and everything else is left
+//! untouched, so the build tool watching `--out` only sees real changes.
+//!
+//! ```text
+//! spec.md --parse--> requirements --canonical_hash--> new? --Z3--> codegen --> out/requirement_<hash>.<ext>
+//! ```
+//!
+//! With `--sign-key`, every file written gets a signature header and is
+//! listed, with the Intent-AST, in `out/crucible.sig.json`.
+//!
+//! With `--tag`, only requirements carrying one of the tags (trailing
+//! hashtags in the spec) are verified and generated; `--out` then holds just
+//! their validators.
+//!
+//! With `--in-place`, each validator is written between region markers of
+//! its file instead of replacing it, so code added around the markers
+//! survives regeneration. A region edited by hand is reported and left alone
+//! unless `--force` is given; a removed requirement takes only its region
+//! with it, and the file goes once nothing else is left in it.
+
+use crate::WatchArgs;
+use anyhow::{anyhow, Context};
+use crucible_codegen::{CodeGenerator, CodegenOptions, ProtectedFile, RegionError, TargetLanguage};
+use crucible_core::signing::MANIFEST_FILE;
+use crucible_core::{
+    split_tags, split_tolerance, ArtifactSigner, CompoundConstraint, CrucibleConfig, CrucibleError, OverflowRisk,
+    SignatureManifest,
+};
+use crucible_verification::Z3Verifier;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Editors save in bursts (truncate, write, rename); wait this long for the
+/// burst to end before regenerating
+pub(crate) const DEBOUNCE: Duration = Duration::from_millis(50);
+
+
+/// Outcome of one regeneration pass
+#[derive(Debug, Default)]
+pub struct Report {
+    pub generated: usize,
+    pub unchanged: usize,
+    pub removed: usize,
+    /// Requirements that did not verify, with the solver's reason
+    pub failed: Vec<(String, String)>,
+    /// Arithmetic that can overflow the target's integers, by requirement;
+    /// generated checked or widened where the target has such operations
+    pub overflow: Vec<(String, OverflowRisk)>,
+    pub elapsed: Duration,
+}
+
+impl Report {
+    /// One line: how many requirements were regenerated, kept, removed and failed
+    pub fn summary(&self) -> String {
+        format!(
+            "{} regenerated, {} unchanged, {} removed, {} failed in {} ms",
+            self.generated,
+            self.unchanged,
+            self.removed,
+            self.failed.len(),
+            self.elapsed.as_millis()
+        )
+    }
+}
+
+/// Generated files of one spec, keyed by requirement hash
+pub struct Session {
+    spec: PathBuf,
+    language: TargetLanguage,
+    out: PathBuf,
+    /// Naming and output profiles from the config; `func_name` is set per requirement
+    options: CodegenOptions,
+    file_prefix: String,
+    solver_timeout: Option<Duration>,
+    generated: BTreeMap<String, Vec<PathBuf>>,
+    signing: Option<(ArtifactSigner, SignatureManifest)>,
+    /// Generate only requirements carrying one of these; empty for all
+    tags: Vec<String>,
+    /// Write validators into marked regions; `force` overwrites edited ones
+    in_place: bool,
+    force: bool,
+}
+
+impl Session {
+    /// Start from the files a previous run left in `out`, so restarting the
+    /// watcher does not regenerate everything
+    pub fn new(spec: &Path, language: TargetLanguage, out: &Path, config: &CrucibleConfig) -> Self {
+        let file_prefix = config.naming.file_prefix.clone();
+        let mut generated = BTreeMap::new();
+        let suffix = format!(".{}", language.file_extension());
+        if let Ok(entries) = fs::read_dir(out) {
+            for path in entries.flatten().map(|entry| entry.path()) {
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+                if let Some(hash) = name.strip_prefix(file_prefix.as_str()).and_then(|n| n.strip_suffix(&suffix)) {
+                    generated.insert(hash.to_string(), vec![path.clone()]);
+                }
+            }
+        }
+
+        Self {
+            spec: spec.to_path_buf(),
+            language,
+            out: out.to_path_buf(),
+            options: CodegenOptions {
+                solver_version: Some(Z3Verifier::solver_version()),
+                ..CodegenOptions::from_config(config)
+            },
+            file_prefix,
+            solver_timeout: config.solver_timeout(),
+            generated,
+            signing: None,
+            tags: Vec::new(),
+            in_place: false,
+            force: false,
+        }
+    }
+
+    /// Write validators into the marked regions of their files. Files
+    /// without the requirement's region are not taken as generated
+    pub fn with_regions(mut self, force: bool) -> Self {
+        let (language, prefix) = (self.language, self.file_prefix.clone());
+        self.generated.retain(|hash, paths| {
+            let contents = fs::read_to_string(&paths[0]).unwrap_or_default();
+            let file = ProtectedFile::parse(&contents, language);
+            file.is_ok_and(|file| file.region(&format!("{}{}", prefix, hash)).is_some())
+        });
+        self.in_place = true;
+        self.force = force;
+        self
+    }
+
+    /// Generate only the requirements carrying one of `tags`
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sign everything written from now on. A manifest left in `out` by the
+    /// same key is kept, so files resumed from a previous run stay listed
+    pub fn with_signer(mut self, signer: ArtifactSigner) -> Self {
+        let manifest = fs::read_to_string(self.out.join(MANIFEST_FILE))
+            .ok()
+            .and_then(|json| SignatureManifest::from_json(&json).ok())
+            .filter(|manifest| manifest.fingerprint == signer.fingerprint())
+            .unwrap_or_else(|| signer.manifest());
+        self.signing = Some((signer, manifest));
+        self
+    }
+
+    /// Re-parse the spec and bring `out` up to date
+    pub fn rebuild(&mut self) -> anyhow::Result<Report> {
+        let started = Instant::now();
+        let source = fs::read_to_string(&self.spec).with_context(|| format!("reading {}", self.spec.display()))?;
+        let ast = crucible_parser::parse(&spec_text(&self.spec, &source)).map_err(CrucibleError::from)?;
+        let lines = spec_lines(&self.spec, &source);
+        // Definitions are inlined before hashing, so editing a predicate
+        // regenerates exactly the requirements that use it
+        let definitions = ast.definition_table().map_err(CrucibleError::from)?;
+        let mut current = BTreeMap::new();
+        for requirement in ast.requirements.iter().filter(|r| selected(&self.tags, &line_tags(&lines, r.line))) {
+            if let Some(compound) = requirement.compound() {
+                let inlined = definitions.inline(&compound).map_err(CrucibleError::from)?;
+                current.insert(inlined.canonical_hash(), inlined);
+            }
+        }
+
+        let mut report = Report::default();
+        let stale: Vec<String> = self.generated.keys().filter(|hash| !current.contains_key(*hash)).cloned().collect();
+        for hash in stale {
+            let mut paths = self.generated.remove(&hash).unwrap_or_default();
+            if self.in_place && !paths.is_empty() {
+                match self.remove_region(&hash, &paths[0]) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        paths.remove(0);
+                    }
+                    Err(e) if e.is::<RegionError>() => {
+                        report.failed.push((hash.clone(), region_failure(&e)));
+                        self.generated.insert(hash, paths);
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            for path in paths {
+                fs::remove_file(&path).with_context(|| format!("removing {}", path.display()))?;
+                let name = self.artifact_name(&path);
+                if let Some((_, manifest)) = &mut self.signing {
+                    manifest.artifacts.remove(&name);
+                }
+            }
+            report.removed += 1;
+        }
+
+        let verifier = Z3Verifier::with_timeout(self.solver_timeout);
+        fs::create_dir_all(&self.out).with_context(|| format!("creating {}", self.out.display()))?;
+        for (hash, compound) in &current {
+            if self.generated.contains_key(hash) {
+                report.unchanged += 1;
+                continue;
+            }
+            if let Err(e) = verifier.verify_compound_constraints(compound) {
+                report.failed.push((hash.clone(), e.to_string()));
+                continue;
+            }
+            let widths = self.language.integer_widths(compound, None, &self.options);
+            let risks = match verifier.overflow_risks(compound, &widths) {
+                Ok(risks) => risks,
+                Err(e) => {
+                    report.failed.push((hash.clone(), e.to_string()));
+                    continue;
+                }
+            };
+            report.overflow.extend(risks.iter().map(|risk| (hash.clone(), risk.clone())));
+            let paths = match self.generate(hash, compound, risks) {
+                Err(e) if e.is::<RegionError>() => {
+                    report.failed.push((hash.clone(), region_failure(&e)));
+                    continue;
+                }
+                paths => paths?,
+            };
+            self.sign(&paths)?;
+            self.generated.insert(hash.clone(), paths);
+            report.generated += 1;
+        }
+
+        if let Some((signer, manifest)) = &mut self.signing {
+            manifest.artifacts.insert("intent-ast".to_string(), signer.sign_json(&ast)?);
+            let path = self.out.join(MANIFEST_FILE);
+            fs::write(&path, manifest.to_json()?).with_context(|| format!("writing {}", path.display()))?;
+        }
+
+        report.elapsed = started.elapsed();
+        Ok(report)
+    }
+
+    /// Write the validator for one requirement and its companion files
+    fn generate(
+        &self,
+        hash: &str,
+        compound: &CompoundConstraint,
+        overflow_risks: Vec<OverflowRisk>,
+    ) -> anyhow::Result<Vec<PathBuf>> {
+        let options = CodegenOptions {
+            func_name: format!("{}{}", self.file_prefix, hash),
+            overflow_risks,
+            ..self.options.clone()
+        };
+        // The validator streams straight to disk; only companion files are held in memory
+        let main = self.out.join(format!("{}{}.{}", self.file_prefix, hash, self.language.file_extension()));
+        let output = if self.in_place {
+            let mut code = Vec::new();
+            let output = CodeGenerator
+                .generate_to(compound, self.language, &options, &mut code)
+                .map_err(CrucibleError::from)?;
+            self.write_region(&main, &options.func_name, &String::from_utf8_lossy(&code))?;
+            output
+        } else {
+            let file = File::create(&main).with_context(|| format!("writing {}", main.display()))?;
+            CodeGenerator
+                .generate_to(compound, self.language, &options, &mut BufWriter::new(file))
+                .map_err(CrucibleError::from)?
+        };
+
+        let mut paths = vec![main];
+        for file in output.files {
+            let path = self.out.join(file.path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, file.contents).with_context(|| format!("writing {}", path.display()))?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Put `code` into region `id` of `path`, creating the file if needed
+    fn write_region(&self, path: &Path, id: &str, code: &str) -> anyhow::Result<()> {
+        let contents = match fs::read_to_string(path) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            contents => contents.with_context(|| format!("reading {}", path.display()))?,
+        };
+        let mut file = ProtectedFile::parse(&contents, self.language).with_context(|| path.display().to_string())?;
+        let changed = file.update(id, code, self.force).with_context(|| path.display().to_string())?;
+        if changed {
+            fs::write(path, file.to_string()).with_context(|| format!("writing {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// Take the region of requirement `hash` out of `path`; `Ok(true)` when
+    /// the file holds nothing else and is to be deleted
+    fn remove_region(&self, hash: &str, path: &Path) -> anyhow::Result<bool> {
+        let contents = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let mut file = ProtectedFile::parse(&contents, self.language).with_context(|| path.display().to_string())?;
+        file.remove(&format!("{}{}", self.file_prefix, hash), self.force).with_context(|| path.display().to_string())?;
+        if file.is_generated_only() && file.regions().next().is_none() {
+            return Ok(true);
+        }
+        fs::write(path, file.to_string()).with_context(|| format!("writing {}", path.display()))?;
+        Ok(false)
+    }
+
+    /// Prepend the signature header to freshly written files and list them
+    fn sign(&mut self, paths: &[PathBuf]) -> anyhow::Result<()> {
+        let names: Vec<String> = paths.iter().map(|path| self.artifact_name(path)).collect();
+        let Some((signer, manifest)) = &mut self.signing else {
+            return Ok(());
+        };
+        for (path, name) in paths.iter().zip(names) {
+            let contents = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+            let (signed, signature) = signer.sign_file(&name, &contents);
+            fs::write(path, signed).with_context(|| format!("writing {}", path.display()))?;
+            manifest.artifacts.insert(name, signature);
+        }
+        Ok(())
+    }
+
+    /// Manifest key of a generated file: its path relative to `out`
+    fn artifact_name(&self, path: &Path) -> String {
+        let relative = path.strip_prefix(&self.out).unwrap_or(path);
+        relative.components().map(|c| c.as_os_str().to_string_lossy()).collect::<Vec<_>>().join("/")
+    }
+}
+
+/// `crucible verify-signatures`: check every file listed in a signature manifest
+pub fn verify_signatures(dir: &Path) -> anyhow::Result<()> {
+    let path = dir.join(MANIFEST_FILE);
+    let json = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    let manifest = SignatureManifest::from_json(&json)?;
+    let mut failures = 0;
+    let mut checked = 0;
+    for name in manifest.artifacts.keys().filter(|name| name.as_str() != "intent-ast") {
+        let result = fs::read_to_string(dir.join(name))
+            .map_err(crucible_core::SigningError::from)
+            .and_then(|contents| manifest.verify_file(name, &contents));
+        checked += 1;
+        if let Err(e) = result {
+            eprintln!("  {}: {}", name, e);
+            failures += 1;
+        }
+    }
+    println!("{} of {} files verified with key {}", checked - failures, checked, manifest.fingerprint);
+    if failures > 0 {
+        return Err(anyhow!("{} signed files failed verification", failures));
+    }
+    Ok(())
+}
+
+/// The requirement lines of a spec, without their tags and tolerances.
+/// Markdown specs keep their requirements as paragraphs or list items;
+/// headings and fenced code blocks are skipped
+pub(crate) fn spec_text(path: &Path, source: &str) -> String {
+    let lines: Vec<&str> =
+        spec_lines(path, source).into_iter().map(|(_, line)| split_tolerance(split_tags(line).0).0).collect();
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+fn is_markdown(path: &Path) -> bool {
+    matches!(path.extension().and_then(|e| e.to_str()), Some("md" | "markdown"))
+}
+
+/// Each requirement line of a spec with its 1-based line number in the source
+pub(crate) fn spec_lines<'a>(path: &Path, source: &'a str) -> Vec<(usize, &'a str)> {
+    if !is_markdown(path) {
+        return source.lines().enumerate().map(|(i, line)| (i + 1, line)).collect();
+    }
+
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+    for (i, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with("```") || line.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence || line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")).unwrap_or(line);
+        lines.push((i + 1, line));
+    }
+    lines
+}
+
+/// Tags of the requirement the parser found on line `line` of `spec_text`
+pub(crate) fn line_tags(lines: &[(usize, &str)], line: usize) -> Vec<String> {
+    lines.get(line.wrapping_sub(1)).map(|(_, text)| split_tags(text).1).unwrap_or_default()
+}
+
+/// Whether a requirement with `tags` passes the `--tag` filter
+pub(crate) fn selected(filter: &[String], tags: &[String]) -> bool {
+    filter.is_empty() || filter.iter().any(|tag| tags.contains(tag))
+}
+
+/// The reason a requirement's region was not written, with the way out
+fn region_failure(error: &anyhow::Error) -> String {
+    match error.downcast_ref() {
+        Some(RegionError::Modified(_)) => format!("{:#}; pass --force to overwrite it", error),
+        _ => format!("{:#}", error),
+    }
+}
+
+fn print_report(report: &Report, file_prefix: &str) {
+    for (hash, reason) in &report.failed {
+        eprintln!("  {}{}: {}", file_prefix, hash, reason);
+    }
+    for (hash, risk) in &report.overflow {
+        eprintln!("  {}{}: warning: {}", file_prefix, hash, risk);
+    }
+    println!("{}", report.summary());
+}
+
+/// Events of a watcher on the directory of `spec`, for `touches_spec`. The
+/// directory rather than the file: editors that save by renaming a
+/// temporary file would otherwise detach the watch
+pub(crate) fn watch_spec(spec: &Path) -> anyhow::Result<(RecommendedWatcher, mpsc::Receiver<notify::Result<Event>>)> {
+    let directory = match spec.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+    Ok((watcher, rx))
+}
+
+/// Whether a watcher event changed the file named `file_name`
+pub(crate) fn touches_spec(event: &notify::Result<Event>, file_name: &OsStr) -> bool {
+    match event {
+        Ok(event) => !event.kind.is_access() && event.paths.iter().any(|p| p.file_name() == Some(file_name)),
+        Err(_) => false,
+    }
+}
+
+/// `crucible watch`: regenerate now, then on every change to the spec.
+/// Flags take precedence over the config
+pub fn run(args: &WatchArgs, mut config: CrucibleConfig) -> anyhow::Result<()> {
+    config.codegen.reproducible |= args.reproducible;
+    let language = match args.lang {
+        Some(language) => language,
+        None => *TargetLanguage::configured(&config)?.first().context("no target language configured")?,
+    };
+    let out = args.out.as_ref().unwrap_or(&config.codegen.out_dir);
+    let mut session = Session::new(&args.spec, language, out, &config).with_tags(args.tags.clone());
+    if args.in_place {
+        session = session.with_regions(args.force);
+    }
+    if let Some(key) = &args.sign_key {
+        let signer = ArtifactSigner::load(key).with_context(|| format!("loading signing key {}", key.display()))?;
+        session = session.with_signer(signer);
+    }
+    match session.rebuild() {
+        Ok(report) => print_report(&report, &config.naming.file_prefix),
+        Err(e) if !args.once => eprintln!("error: {:#}", e),
+        Err(e) => return Err(e),
+    }
+    if args.once {
+        return Ok(());
+    }
+
+    let file_name = args.spec.file_name().context("spec path has no file name")?.to_owned();
+    let (_watcher, rx) = watch_spec(&args.spec)?;
+    println!("watching {} ({})", args.spec.display(), language.file_extension());
+
+    while let Ok(event) = rx.recv() {
+        if !touches_spec(&event, &file_name) {
+            continue;
+        }
+        while rx.recv_timeout(DEBOUNCE).is_ok() {}
+        match session.rebuild() {
+            Ok(report) => print_report(&report, &config.naming.file_prefix),
+            Err(e) => eprintln!("error: {:#}", e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_markdown_spec_text() {
+        let source = "# Withdrawals\n\n- User can withdraw if balance >= amount #payments\n\n```rust\nlet x = 1;\n```\n\
+                      * Admin can delete if role == admin\n";
+        assert_eq!(
+            spec_text(Path::new("spec.md"), source),
+            "User can withdraw if balance >= amount\nAdmin can delete if role == admin\n"
+        );
+        let lines = spec_lines(Path::new("spec.md"), source);
+        assert_eq!(line_tags(&lines, 1), vec!["payments"]);
+        assert!(selected(&[], &line_tags(&lines, 2)));
+        assert!(!selected(&["payments".to_string()], &line_tags(&lines, 2)));
+        let plain = "Amount must be at most 100 #payments\n";
+        assert_eq!(spec_text(Path::new("spec.txt"), plain), "Amount must be at most 100\n");
+    }
+
+    #[test]
+    fn test_session_resumes_from_output_directory() {
+        let out = std::env::temp_dir().join(format!("crucible-watch-{}", std::process::id()));
+        fs::create_dir_all(&out).unwrap();
+        fs::write(out.join("requirement_0123456789abcdef.rs"), "").unwrap();
+        fs::write(out.join("requirement_fedcba9876543210.ts"), "").unwrap();
+        fs::write(out.join("mod.rs"), "").unwrap();
+
+        let session = Session::new(Path::new("spec.md"), TargetLanguage::Rust, &out, &CrucibleConfig::default());
+        let hashes: BTreeSet<&str> = session.generated.keys().map(String::as_str).collect();
+        assert_eq!(hashes, BTreeSet::from(["0123456789abcdef"]));
+        fs::remove_dir_all(&out).unwrap();
+    }
+
+    #[test]
+    fn test_in_place_regions() {
+        let out = std::env::temp_dir().join(format!("crucible-regions-{}", std::process::id()));
+        fs::create_dir_all(&out).unwrap();
+        let path = out.join("requirement_0123456789abcdef.rs");
+        fs::write(&path, "use std::fmt;\n").unwrap();
+        fs::write(out.join("requirement_fedcba9876543210.rs"), "fn unmarked() {}\n").unwrap();
+
+        // Only files holding their requirement's region resume as generated
+        let config = CrucibleConfig::default();
+        let session = Session::new(Path::new("spec.md"), TargetLanguage::Rust, &out, &config).with_regions(false);
+        assert!(session.generated.is_empty());
+        session.write_region(&path, "requirement_0123456789abcdef", "fn validate() {}\n").unwrap();
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(written.starts_with("use std::fmt;\n\n// <crucible:begin requirement_0123456789abcdef sha256="));
+
+        fs::write(&path, written.replace("fn validate() {}", "fn validate() { todo!() }")).unwrap();
+        let error = session.write_region(&path, "requirement_0123456789abcdef", "fn validate() {}\n").unwrap_err();
+        assert!(region_failure(&error).ends_with("was edited since it was generated; pass --force to overwrite it"));
+        let forced = Session::new(Path::new("spec.md"), TargetLanguage::Rust, &out, &config).with_regions(true);
+        assert_eq!(forced.generated.keys().collect::<Vec<_>>(), vec!["0123456789abcdef"]);
+        assert!(!forced.remove_region("0123456789abcdef", &path).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "use std::fmt;\n");
+        fs::remove_dir_all(&out).unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+//! Code generation benchmarks
+//!
+//! `codegen/<language>` times one validator for the same mixed tree in every
+//! target, and `codegen_size/<n>` how Rust generation scales with the number
+//! of constraints. Baselines are in `docs/BENCHMARKS.md`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use crucible_codegen::{CodeGenerator, SqlDialect, TargetLanguage};
+use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+use std::hint::black_box;
+
+const LANGUAGES: [(&str, TargetLanguage); 13] = [
+    ("rust", TargetLanguage::Rust),
+    ("typescript", TargetLanguage::TypeScript),
+    ("python", TargetLanguage::Python),
+    ("solidity", TargetLanguage::Solidity),
+    ("spark_ada", TargetLanguage::SparkAda),
+    ("zig", TargetLanguage::Zig),
+    ("elixir", TargetLanguage::Elixir),
+    ("sql_postgres", TargetLanguage::Sql(SqlDialect::Postgres)),
+    ("rego", TargetLanguage::Rego),
+    ("cue", TargetLanguage::Cue),
+    ("cedar", TargetLanguage::Cedar),
+    ("wit", TargetLanguage::Wit),
+    ("ebpf_c", TargetLanguage::EbpfC),
+];
+
+fn simple(left: String, operator: ConstraintOperator, right: String) -> CompoundConstraint {
+    CompoundConstraint::Simple(Constraint {
+        left_variable: left,
+        operator,
+        right_value: right,
+    })
+}
+
+/// `n` range checks over `n / 2` fields, every fourth pair as an alternative
+fn requirement(n: usize) -> CompoundConstraint {
+    let pairs: Vec<CompoundConstraint> = (0..n.div_ceil(2))
+        .map(|i| {
+            CompoundConstraint::And(vec![
+                simple(format!("field_{}", i), ConstraintOperator::GreaterThanOrEqual, i.to_string()),
+                simple(format!("field_{}", i), ConstraintOperator::LessThan, (i + 100).to_string()),
+            ])
+        })
+        .collect();
+    let mut conjuncts = Vec::new();
+    for chunk in pairs.chunks(4) {
+        let (first, rest) = chunk.split_first().unwrap();
+        conjuncts.push(first.clone());
+        if !rest.is_empty() {
+            conjuncts.push(CompoundConstraint::Or(rest.to_vec()));
+        }
+    }
+    CompoundConstraint::And(conjuncts)
+}
+
+fn bench_languages(c: &mut Criterion) {
+    let compound = requirement(32);
+    let mut group = c.benchmark_group("codegen");
+    for (name, language) in LANGUAGES {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &compound, |b, compound| {
+            b.iter(|| CodeGenerator.generate(black_box(compound), language).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_sizes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("codegen_size");
+    for n in [8, 32, 128, 512] {
+        let compound = requirement(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &compound, |b, compound| {
+            b.iter(|| CodeGenerator.generate(black_box(compound), TargetLanguage::Rust).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_languages, bench_sizes);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+//! Field widths for masks and shifts
+//!
+//! Rust, Zig, Solidity and C evaluate `flags & 0x8` and `word << 4` in the
+//! field's own integer type. Python, Elixir and Rego integers are unbounded
+//! and TypeScript numbers are doubles, so those targets compute wider and
+//! wrap the result back to the field's width, read from the Schema; a field
+//! without one is `BitWidth::DEFAULT` wide.
+
+use crucible_core::{BitWidth, Schema};
+use std::collections::HashMap;
+
+/// The width of every integer field of a Schema
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BitWidths(HashMap<String, BitWidth>);
+
+impl BitWidths {
+    pub(crate) fn new(schema: &Schema) -> Self {
+        Self(
+            schema
+                .fields
+                .iter()
+                .filter_map(|(name, dt)| Some((name.clone(), BitWidth::of(dt)?)))
+                .collect(),
+        )
+    }
+
+    pub(crate) fn of(&self, field: &str) -> BitWidth {
+        self.0.get(field).copied().unwrap_or(BitWidth::DEFAULT)
+    }
+}
+
+/// `value` reduced to a two's-complement integer of `width`, given the
+/// target's bitwise AND of two rendered integers. Exact for any integer,
+/// however far outside the width
+pub(crate) fn wrapped(value: &str, width: BitWidth, and: impl Fn(&str, &str) -> String) -> String {
+    let mask = ((1u128 << width.bits) - 1).to_string();
+    if !width.signed {
+        return and(value, &mask);
+    }
+    let half = 1u128 << (width.bits - 1);
+    format!("({} - {})", and(&format!("({} + {})", value, half), &mask), half)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::DataType;
+
+    #[test]
+    fn test_wrapped() {
+        let mut schema = Schema::new("TR-BITS".to_string());
+        schema.add_field("word".to_string(), DataType::Uint32, None);
+        schema.add_field("name".to_string(), DataType::String, None);
+        let widths = BitWidths::new(&schema);
+        assert_eq!(
+            (widths.of("word").bits, widths.of("name"), widths.of("other")),
+            (32, BitWidth::DEFAULT, BitWidth::DEFAULT)
+        );
+
+        let and = |a: &str, b: &str| format!("(({}) & {})", a, b);
+        assert_eq!(
+            wrapped("(word << 4)", widths.of("word"), and),
+            "(((word << 4)) & 4294967295)"
+        );
+        assert_eq!(
+            wrapped("(delta << 1)", BitWidth { bits: 32, signed: true }, and),
+            "(((((delta << 1) + 2147483648)) & 4294967295) - 2147483648)"
+        );
+    }
+}
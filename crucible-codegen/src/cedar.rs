@@ -0,0 +1,240 @@
+//! Cedar Strategy (AWS Cedar Authorization Policies)
+//!
+//! Renders the intent as a `permit` policy whose `when` clause evaluates the
+//! constraint tree over the request `context`. With a Schema, a companion
+//! `.cedarschema` declares the context record for the action.
+
+use super::literal;
+use super::{
+    default_safe_compare, fold_pairs, is_identifier, CodegenStrategy, GeneratedFile, Provenance, VerifiableStrategy,
+};
+use crucible_core::{ArithmeticOperator, ConstraintOperator, DataType, MathFunction, Schema};
+
+pub(crate) struct CedarStrategy;
+
+impl CodegenStrategy for CedarStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        match op {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "==",
+            ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "like",
+            ConstraintOperator::DoesNotContain => "like",
+        }
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        if is_identifier(name) {
+            format!("context.{}", name)
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn format_string(&self, text: &str) -> String {
+        literal::cedar_string(text)
+    }
+
+    /// No built-ins, so `if` expressions; `Long` arithmetic overflow is an
+    /// evaluation error, which denies the request
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min => fold_pairs(args, |a, b| format!("(if {a} <= {b} then {a} else {b})", a = a, b = b)),
+            MathFunction::Max => fold_pairs(args, |a, b| format!("(if {a} >= {b} then {a} else {b})", a = a, b = b)),
+            MathFunction::Abs => format!("(if {x} >= 0 then {x} else -{x})", x = args.join(", ")),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        }
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(format!("(if {} then {} else {})", condition, then_value, else_value))
+    }
+
+    /// Cedar has no bitwise operators
+    fn supports_bitwise(&self) -> bool {
+        false
+    }
+
+    /// Cedar has no substring function; a literal needle becomes a `like`
+    /// wildcard pattern, a string literal with `*` escaped
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        let pattern = needle.strip_prefix('"').and_then(|n| n.strip_suffix('"')).unwrap_or(needle);
+        format!("{} like \"*{}*\"", haystack, pattern.replace('*', "\\*"))
+    }
+
+    fn logical_and(&self) -> &'static str {
+        "&&"
+    }
+
+    fn logical_or(&self) -> &'static str {
+        "||"
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        format!("!({})", expr)
+    }
+
+    fn wrap_verified_function(
+        &self,
+        func_name: &str,
+        _contracts: &str,
+        body: &str,
+        _assertions: &str,
+    ) -> String {
+        format!(
+            r#"// Cedar Generated Code - Authorization Policy
+// Evaluate with the Cedar CLI: `cedar authorize --policies <file>`
+
+@id("{func_name}")
+permit (
+    principal,
+    action == Action::"{func_name}",
+    resource
+)
+when {{ {body} }};
+"#,
+            func_name = func_name,
+            body = body
+        )
+    }
+}
+
+impl VerifiableStrategy for CedarStrategy {
+    fn map_type(&self, dt: &DataType) -> String {
+        match dt {
+            // Cedar has a single signed 64-bit integer type
+            DataType::Uint64 | DataType::Uint32 | DataType::Int64 | DataType::Int32 => "Long".to_string(),
+            DataType::String => "String".to_string(),
+            DataType::Bool => "Bool".to_string(),
+            DataType::Decimal => "decimal".to_string(),
+            DataType::List(element) => format!("Set<{}>", self.map_type(element)),
+            DataType::Custom { range_min, range_max, name } => {
+                if range_min.is_some() || range_max.is_some() {
+                    "Long".to_string()
+                } else {
+                    name.clone()
+                }
+            }
+        }
+    }
+
+    fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
+        format!("// Post-condition: Permitted iff ({})", expression)
+    }
+
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        // Cedar raises an evaluation error on Long overflow, which denies the request
+        format!("{} {} {}", left, op.symbol(), right)
+    }
+
+    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
+        format!("// Context for Action::\"{}\": {}", func_name, self.context_record(schema))
+    }
+
+    fn fn_end(&self) -> String {
+        "};".to_string()
+    }
+
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner("//", &["Cedar Generated Code - Authorization Policy (v0.1.5-alpha)"])
+    }
+
+    fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
+        default_safe_compare(left, op, right, data_type)
+    }
+
+    fn schema_files(&self, func_name: &str, schema: &Schema) -> Vec<GeneratedFile> {
+        let contents = format!(
+            "// Cedar schema - Traceability ID: {}\naction \"{}\" appliesTo {{\n    context: {}\n}};\n",
+            literal::comment(&schema.traceability_id),
+            func_name,
+            self.context_record(schema)
+        );
+
+        vec![GeneratedFile {
+            path: format!("{}.cedarschema", func_name),
+            contents,
+        }]
+    }
+}
+
+impl CedarStrategy {
+    fn context_record(&self, schema: &Schema) -> String {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        let fields: Vec<String> = names
+            .iter()
+            .map(|name| format!("{}: {}", name, self.map_type(&schema.fields[*name])))
+            .collect();
+        format!("{{ {} }}", fields.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, TargetLanguage};
+    use crucible_core::{CompoundConstraint, Constraint};
+
+    fn access() -> CompoundConstraint {
+        CompoundConstraint::Or(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "role".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: "\"admin\"".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "trust_level".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "3".to_string(),
+            }),
+        ])
+    }
+
+    #[test]
+    fn test_cedar_permit_policy() {
+        let output = CodeGenerator.generate(&access(), TargetLanguage::Cedar).unwrap();
+        assert!(output.code.contains("action == Action::\"validate_intent\""));
+        assert!(output
+            .code
+            .contains("when { (context.role == \"admin\" || context.trust_level >= 3) };"));
+    }
+
+    #[test]
+    fn test_cedar_field_right_operand() {
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "limit".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "amount + fee".to_string(),
+        });
+        let output = CodeGenerator.generate(&compound, TargetLanguage::Cedar).unwrap();
+        assert!(output.code.contains("when { context.limit >= context.amount + context.fee };"));
+    }
+
+    #[test]
+    fn test_cedar_schema_file() {
+        let mut schema = Schema::new("cedar-001".to_string());
+        schema.add_field("role".to_string(), DataType::String, None);
+        schema.add_field("trust_level".to_string(), DataType::Uint32, None);
+
+        let output = CodeGenerator
+            .generate_with_schema(&access(), &schema, TargetLanguage::Cedar)
+            .unwrap();
+        assert!(output.code.contains("Traceability ID: cedar-001"));
+        assert_eq!(output.files.len(), 1);
+        assert_eq!(output.files[0].path, "validate_intent.cedarschema");
+        assert!(output.files[0]
+            .contents
+            .contains("context: { role: String, trust_level: Long }"));
+    }
+}
@@ -0,0 +1,262 @@
+//! CUE Strategy (Configuration Constraints)
+//!
+//! Emits a CUE definition whose fields carry the Schema types and whose hidden
+//! `_intent` field unifies `true` with the constraint expression, so `cue vet`
+//! rejects any configuration that violates the verified intent.
+
+use super::{collect_variables, default_safe_compare, is_identifier, CodegenStrategy, Provenance, VerifiableStrategy};
+use crucible_core::{ArithmeticOperator, CompoundConstraint, ConstraintOperator, DataType, MathFunction, Schema};
+
+pub(crate) struct CueStrategy;
+
+/// `validate_intent` -> `ValidateIntent`
+fn definition_name(func_name: &str) -> String {
+    func_name
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+            }
+        })
+        .collect()
+}
+
+impl CodegenStrategy for CueStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        match op {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "==",
+            ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "strings.Contains",
+            ConstraintOperator::DoesNotContain => "strings.Contains",
+        }
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    /// CUE's `mod` is already Euclidean (`rem` is the truncating one)
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("mod({}, {})", dividend, divisor)
+    }
+
+    /// CUE has no bitwise operators
+    fn supports_bitwise(&self) -> bool {
+        false
+    }
+
+    /// `list.Min`/`list.Max` over a list and `math.Abs`, imported on use;
+    /// numbers are arbitrary-precision
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min => format!("list.Min([{}])", args.join(", ")),
+            MathFunction::Max => format!("list.Max([{}])", args.join(", ")),
+            MathFunction::Abs => format!("math.Abs({})", args.join(", ")),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        }
+    }
+
+    /// CUE has no conditional expression, only `if` comprehensions over
+    /// fields; comparisons against a conditional are spelled out as both cases
+    fn format_conditional(&self, _condition: &str, _then_value: &str, _else_value: &str) -> Option<String> {
+        None
+    }
+
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("strings.Contains({}, {})", haystack, needle)
+    }
+
+    fn logical_and(&self) -> &'static str {
+        "&&"
+    }
+
+    fn logical_or(&self) -> &'static str {
+        "||"
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        format!("!({})", expr)
+    }
+
+    /// Without a Schema, every referenced field is declared as a number
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let fields: Vec<String> = collect_variables(compound)
+            .into_iter()
+            .filter(|v| is_identifier(v))
+            .map(|v| format!("\t{}: number", v))
+            .collect();
+        Some(fields.join("\n"))
+    }
+
+    fn wrap_verified_function(
+        &self,
+        func_name: &str,
+        contracts: &str,
+        body: &str,
+        _assertions: &str,
+    ) -> String {
+        let fields = if contracts.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n\n", contracts)
+        };
+
+        // Standard packages the body calls into
+        let packages: Vec<&str> = [("list", "list.M"), ("math", "math.Abs("), ("strings", "strings.")]
+            .iter()
+            .filter(|(_, call)| body.contains(call))
+            .map(|(package, _)| *package)
+            .collect();
+        let imports = match packages.as_slice() {
+            [] => String::new(),
+            [package] => format!("import \"{}\"\n\n", package),
+            _ => {
+                let lines: String = packages.iter().map(|package| format!("\t\"{}\"\n", package)).collect();
+                format!("import (\n{})\n\n", lines)
+            }
+        };
+
+        format!(
+            r#"// CUE Generated Code - Configuration Constraints
+// Validate with `cue vet`
+
+package crucible
+
+{imports}#{definition}: {{
+{fields}	// Unifies only when the intent holds
+	_intent: true & ({body})
+}}
+"#,
+            imports = imports,
+            definition = definition_name(func_name),
+            fields = fields,
+            body = body
+        )
+    }
+}
+
+impl VerifiableStrategy for CueStrategy {
+    fn map_type(&self, dt: &DataType) -> String {
+        match dt {
+            DataType::Uint64 => "uint64".to_string(),
+            DataType::Uint32 => "uint32".to_string(),
+            DataType::Int64 => "int64".to_string(),
+            DataType::Int32 => "int32".to_string(),
+            DataType::String => "string".to_string(),
+            DataType::Bool => "bool".to_string(),
+            DataType::Decimal => "number".to_string(),
+            DataType::List(element) => format!("[...{}]", self.map_type(element)),
+            DataType::Custom { range_min, range_max, .. } => {
+                let mut parts = vec!["int".to_string()];
+                if let Some(min) = range_min {
+                    parts.push(format!(">={}", min));
+                }
+                if let Some(max) = range_max {
+                    parts.push(format!("<={}", max));
+                }
+                parts.join(" & ")
+            }
+        }
+    }
+
+    fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
+        format!("// Post-condition: Unifies iff ({})", expression)
+    }
+
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        // CUE integers are arbitrary precision; bounds come from the field type
+        format!("{} {} {}", left, op.symbol(), right)
+    }
+
+    /// Field declarations for the definition body
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        names
+            .iter()
+            .map(|name| {
+                let doc = schema
+                    .documentation
+                    .get(*name)
+                    .map(|d| format!("\t// {}\n", d))
+                    .unwrap_or_default();
+                format!("{}\t{}: {}", doc, name, self.map_type(&schema.fields[*name]))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn fn_end(&self) -> String {
+        "}".to_string()
+    }
+
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner("//", &["CUE Generated Code - Configuration Constraints (v0.1.5-alpha)"])
+    }
+
+    fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
+        default_safe_compare(left, op, right, data_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, TargetLanguage};
+    use crucible_core::Constraint;
+
+    fn withdraw() -> CompoundConstraint {
+        CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "balance".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "amount".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "0".to_string(),
+            }),
+        ])
+    }
+
+    #[test]
+    fn test_cue_definition_with_inferred_fields() {
+        let output = CodeGenerator.generate(&withdraw(), TargetLanguage::Cue).unwrap();
+        assert!(output.code.contains("#ValidateIntent: {"));
+        assert!(output.code.contains("\tamount: number\n\tbalance: number"));
+        assert!(output.code.contains("_intent: true & ((balance >= amount && amount > 0))"));
+    }
+
+    #[test]
+    fn test_cue_schema_types() {
+        let mut schema = Schema::new("cue-001".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, Some("Account balance".to_string()));
+        schema.add_field(
+            "amount".to_string(),
+            DataType::Custom { name: "Amount".to_string(), range_min: Some(1), range_max: Some(10000) },
+            None,
+        );
+
+        let output = CodeGenerator
+            .generate_with_schema(&withdraw(), &schema, TargetLanguage::Cue)
+            .unwrap();
+        assert!(output.code.contains("Traceability ID: cue-001"));
+        assert!(output.code.contains("\tamount: int & >=1 & <=10000"));
+        assert!(output.code.contains("\t// Account balance\n\tbalance: uint64"));
+        assert!(!output.code.contains(": number"));
+    }
+}
@@ -0,0 +1,145 @@
+//! Custom type mappings applied to a Schema for one target language
+//!
+//! `DataType::Custom` names are renamed to the registry's spelling for the
+//! language before any strategy sees the Schema, so every `map_type` that
+//! prints the name prints the mapped type; the strategies that lower a
+//! custom type to its range (SQL, CUE, WIT, eBPF) are unaffected. The
+//! declarations are rendered once per type, in name order.
+
+use crate::{CodegenError, TargetLanguage};
+use crucible_core::{DataType, Schema, TypeMapping, TypeRegistry};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// The mapping of `name` for `language`, under any of the language's names
+fn mapping<'a>(registry: &'a TypeRegistry, name: &str, language: TargetLanguage) -> Option<&'a TypeMapping> {
+    registry
+        .mappings(name)
+        .find(|(key, _)| TargetLanguage::from_name(key) == Some(language))
+        .map(|(_, mapping)| mapping)
+}
+
+/// The Schema's custom types by name, with their bounds
+fn custom_types(schema: &Schema) -> BTreeMap<&str, (Option<i128>, Option<i128>)> {
+    schema
+        .fields
+        .values()
+        .filter_map(|data_type| match data_type {
+            DataType::Custom { name, range_min, range_max } => Some((name.as_str(), (*range_min, *range_max))),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `schema` with every mapped custom type renamed to its spelling in `language`
+pub(crate) fn apply<'a>(schema: &'a Schema, language: TargetLanguage, registry: &TypeRegistry) -> Cow<'a, Schema> {
+    let mapped = |name: &str| mapping(registry, name, language).filter(|m| m.target != name);
+    if !custom_types(schema).keys().any(|name| mapped(name).is_some()) {
+        return Cow::Borrowed(schema);
+    }
+    let mut schema = schema.clone();
+    for data_type in schema.fields.values_mut() {
+        if let DataType::Custom { name, .. } = data_type {
+            if let Some(mapping) = mapped(name) {
+                *name = mapping.target.clone();
+            }
+        }
+    }
+    Cow::Owned(schema)
+}
+
+/// The declarations of the Schema's custom types in `language`, each on its
+/// own lines and followed by a blank line; empty when none has one
+pub(crate) fn declarations(
+    schema: &Schema,
+    language: TargetLanguage,
+    registry: &TypeRegistry,
+) -> Result<String, CodegenError> {
+    let mut out = String::new();
+    for (name, (min, max)) in custom_types(schema) {
+        let Some(mapping) = mapping(registry, name, language) else {
+            continue;
+        };
+        let Some(template) = &mapping.declaration else {
+            continue;
+        };
+        let mut declaration = template.replace("{type}", &mapping.target).replace("{name}", name);
+        for (placeholder, bound) in [("{min}", min), ("{max}", max)] {
+            if !declaration.contains(placeholder) {
+                continue;
+            }
+            let bound = bound.ok_or_else(|| {
+                CodegenError::GenerationError(format!(
+                    "the declaration of custom type {} uses {} but the type has no such bound",
+                    name, placeholder
+                ))
+            })?;
+            declaration = declaration.replace(placeholder, &bound.to_string());
+        }
+        out.push_str(declaration.trim_end());
+        out.push_str("\n\n");
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, CodegenOptions};
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_custom_type_mappings() {
+        let mut schema = Schema::new("TR-1".to_string());
+        let money = DataType::Custom {
+            name: "Money".to_string(),
+            range_min: Some(0),
+            range_max: Some(1_000_000),
+        };
+        schema.add_field("amount".to_string(), money, None);
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+
+        let cents = TypeMapping::new("Cents").with_declaration("pub type {type} = u64; // {min}..={max}");
+        let python = TypeMapping::new("Money").with_declaration("{type} = int  # {name} < {lower}");
+        let mut types = TypeRegistry::new();
+        types
+            .register("Money", "rust", cents)
+            .register("Money", "typescript", TypeMapping::new("Money"))
+            .register("Money", "python", python);
+        let options = CodegenOptions {
+            types,
+            ..CodegenOptions::default()
+        };
+
+        let rust = CodeGenerator
+            .generate_with_schema_and_options(&compound, &schema, TargetLanguage::Rust, &options)
+            .unwrap()
+            .code;
+        assert!(rust.contains("pub type Cents = u64; // 0..=1000000\n\n"));
+        assert!(rust.contains("pub amount: Cents,"));
+
+        // Registered under an alias; no declaration
+        let typescript = declarations(&schema, TargetLanguage::TypeScript, &options.types).unwrap();
+        assert!(typescript.is_empty());
+        assert!(matches!(apply(&schema, TargetLanguage::TypeScript, &options.types), Cow::Borrowed(_)));
+        // Unknown placeholders are left alone
+        let python = declarations(&schema, TargetLanguage::Python, &options.types).unwrap();
+        assert_eq!(python, "Money = int  # Money < {lower}\n\n");
+        // Unmapped languages keep the Schema name
+        let zig = apply(&schema, TargetLanguage::Zig, &options.types);
+        assert!(matches!(&zig.fields["amount"], DataType::Custom { name, .. } if name == "Money"));
+
+        let mut unbounded = Schema::new("TR-2".to_string());
+        let money = DataType::Custom {
+            name: "Money".to_string(),
+            range_min: Some(0),
+            range_max: None,
+        };
+        unbounded.add_field("amount".to_string(), money, None);
+        assert!(declarations(&unbounded, TargetLanguage::Rust, &options.types).is_err());
+    }
+}
@@ -0,0 +1,425 @@
+//! eBPF C Strategy (Kernel Data-Plane Enforcement)
+//!
+//! Emits a single `static __always_inline` predicate made only of fixed-size
+//! integer comparisons: no loops, no calls, no floating point, so the kernel
+//! verifier accepts it inside XDP/TC/LSM programs. With a Schema, a companion
+//! header fixes the `struct validation_params` layout (explicit offsets and a
+//! size assertion) and declares the map the program reads it from.
+
+use super::literal::{self, Operand};
+use super::{
+    collect_variables, fold_pairs, is_identifier, CodegenStrategy, GeneratedFile, Provenance, VerifiableStrategy,
+};
+use crucible_core::{
+    ArithmeticOperator, Bitwise, BitwiseOperator, Call, CompoundConstraint, Constraint, ConstraintOperator, DataType,
+    MathFunction, Schema,
+};
+
+/// Fixed capacity of string fields, including the NUL terminator
+const STR_LEN: usize = 64;
+
+/// Fixed-point scale for `DataType::Decimal` (eBPF has no floating point)
+const DECIMAL_SCALE: u64 = 1_000_000;
+
+/// Substring search over a fixed-capacity string field; both loops have
+/// constant bounds, so clang unrolls them and the verifier sees no back-edges
+const CONTAINS_HELPER: &str = r#"
+static __always_inline int crucible_contains(const char *haystack, const char *needle, __u32 len)
+{
+#pragma unroll
+    for (__u32 start = 0; start < CRUCIBLE_STR_LEN; start++) {
+        int found = 1;
+#pragma unroll
+        for (__u32 i = 0; i < CRUCIBLE_STR_LEN; i++) {
+            if (i >= len)
+                break;
+            if (start + i >= CRUCIBLE_STR_LEN || haystack[start + i] != needle[i]) {
+                found = 0;
+                break;
+            }
+        }
+        if (found)
+            return 1;
+    }
+    return 0;
+}
+"#;
+
+pub(crate) struct EbpfStrategy;
+
+/// C layout of a Schema type: (declaration type, array suffix, size, alignment)
+fn c_layout(dt: &DataType) -> (&'static str, String, usize, usize) {
+    match dt {
+        DataType::Uint64 => ("__u64", String::new(), 8, 8),
+        DataType::Uint32 => ("__u32", String::new(), 4, 4),
+        DataType::Int64 | DataType::Decimal => ("__s64", String::new(), 8, 8),
+        DataType::Int32 => ("__s32", String::new(), 4, 4),
+        DataType::Bool => ("__u8", String::new(), 1, 1),
+        DataType::String => ("char", "[CRUCIBLE_STR_LEN]".to_string(), STR_LEN, 1),
+        DataType::Custom { range_min: Some(min), .. } if *min >= 0 => ("__u64", String::new(), 8, 8),
+        DataType::Custom { .. } => ("__s64", String::new(), 8, 8),
+        DataType::List(_) => unreachable!("list fields are rejected before generation"),
+    }
+}
+
+impl EbpfStrategy {
+    /// Struct fields ordered by alignment (then name) so the layout has no interior padding
+    fn struct_layout(&self, schema: &Schema) -> (Vec<String>, usize) {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort_by(|a, b| {
+            let (_, _, _, align_a) = c_layout(&schema.fields[*a]);
+            let (_, _, _, align_b) = c_layout(&schema.fields[*b]);
+            align_b.cmp(&align_a).then(a.cmp(b))
+        });
+
+        let mut offset: usize = 0;
+        let mut max_align: usize = 1;
+        let mut lines = Vec::new();
+        for name in names {
+            let (ty, suffix, size, align) = c_layout(&schema.fields[name]);
+            offset = offset.next_multiple_of(align);
+            max_align = max_align.max(align);
+            let doc = schema
+                .documentation
+                .get(name)
+                .map(|d| format!(" - {}", d))
+                .unwrap_or_default();
+            lines.push(format!("    {} {}{}; /* offset {}{} */", ty, name, suffix, offset, doc));
+            offset += size;
+        }
+
+        (lines, offset.next_multiple_of(max_align))
+    }
+
+    /// Decimal literals become compile-time fixed-point constants
+    fn format_literal(&self, value: &str) -> String {
+        if is_identifier(value) {
+            self.format_variable(value)
+        } else if value.contains('.') && value.parse::<f64>().is_ok() {
+            format!("(__s64)({} * CRUCIBLE_DECIMAL_SCALE)", value)
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl CodegenStrategy for EbpfStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        match op {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "==",
+            ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "contains",
+            ConstraintOperator::DoesNotContain => "!contains",
+        }
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        format!("params->{}", name)
+    }
+
+    fn format_string(&self, text: &str) -> String {
+        literal::c_string(text)
+    }
+
+    /// String literals compare with a constant-length memcmp, which clang
+    /// unrolls into plain loads; `sizeof` includes the NUL for an exact match.
+    /// A conditional value is spelled out as both cases, so each branch is
+    /// compared the same way.
+    fn format_comparison(&self, c: &Constraint) -> String {
+        if c.operator.is_membership() {
+            return self.format_membership_comparison(c);
+        }
+        if let Some(conditional) = c.conditional() {
+            return self.format_conditional_cases(c, &conditional);
+        }
+        if let Some(call) = Call::parse(&c.right_value) {
+            return format!(
+                "{} {} {}",
+                self.format_left_operand(c),
+                self.format_operator(&c.operator),
+                self.format_call_expression(&call)
+            );
+        }
+        if let Operand::Text(text) = literal::operand(&c.right_value) {
+            return format!(
+                "__builtin_memcmp({}, {value}, sizeof({value})) {} 0",
+                self.format_left_operand(c),
+                self.format_operator(&c.operator),
+                value = self.format_string(&text)
+            );
+        }
+
+        format!(
+            "{} {} {}",
+            self.format_left_operand(c),
+            self.format_operator(&c.operator),
+            self.format_literal(&c.right_value)
+        )
+    }
+
+    /// C `%` truncates toward zero; shifted by `|divisor|` into `[0, |divisor|)`
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        let abs = format!("({d} < 0 ? -{d} : {d})", d = divisor);
+        format!("((({} % {}) + {abs}) % {abs})", dividend, divisor, abs = abs)
+    }
+
+    /// Left-shifting a negative value is undefined in C, so `<<` shifts the
+    /// value's bits as unsigned and converts back to the field's type
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        match bitwise.operator {
+            BitwiseOperator::ShiftLeft => format!("((__typeof__({o}))((__u64)({o}) << {}))", mask, o = operand),
+            _ => format!("({} {} {})", operand, bitwise.operator.symbol(), mask),
+        }
+    }
+
+    /// Conditionals rather than helper calls; as in C, negating the most
+    /// negative value overflows
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min => fold_pairs(args, |a, b| format!("({a} <= {b} ? {a} : {b})", a = a, b = b)),
+            MathFunction::Max => fold_pairs(args, |a, b| format!("({a} >= {b} ? {a} : {b})", a = a, b = b)),
+            MathFunction::Abs => format!("({x} < 0 ? -{x} : {x})", x = args.join(", ")),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        }
+    }
+
+    /// `sizeof` of a literal needle counts its NUL, which the search excludes
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("crucible_contains({}, {needle}, sizeof({needle}) - 1)", haystack, needle = needle)
+    }
+
+    fn logical_and(&self) -> &'static str {
+        "&&"
+    }
+
+    fn logical_or(&self) -> &'static str {
+        "||"
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        format!("!({})", expr)
+    }
+
+    fn inline_comment(&self, text: &str) -> Option<String> {
+        Some(format!("/* {} */", text))
+    }
+
+    /// Comparisons are `int` 0/1 in C
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        let terms: Vec<String> = parts.iter().map(|p| format!("!!({})", p)).collect();
+        Some(format!("({})", terms.join(" + ")))
+    }
+
+    /// Without a Schema, every referenced field is a signed 64-bit integer
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let fields: Vec<String> = collect_variables(compound)
+            .into_iter()
+            .filter(|v| is_identifier(v))
+            .map(|v| format!("    __s64 {};", v))
+            .collect();
+        Some(format!("struct validation_params {{\n{}\n}};", fields.join("\n")))
+    }
+
+    fn wrap_verified_function(
+        &self,
+        func_name: &str,
+        contracts: &str,
+        body: &str,
+        _assertions: &str,
+    ) -> String {
+        let helpers = if body.contains("crucible_contains(") {
+            CONTAINS_HELPER
+        } else {
+            ""
+        };
+
+        format!(
+            r#"// eBPF C Generated Code - Data-Plane Validator
+// Bounded and loop-free: accepted by the kernel verifier
+
+#include <linux/types.h>
+#include <bpf/bpf_helpers.h>
+
+#ifndef CRUCIBLE_STR_LEN
+#define CRUCIBLE_STR_LEN {str_len}
+#endif
+#ifndef CRUCIBLE_DECIMAL_SCALE
+#define CRUCIBLE_DECIMAL_SCALE {scale}
+#endif
+
+{contracts}
+{helpers}
+/* Returns 1 iff the parameters satisfy the verified intent */
+static __always_inline int {func_name}(const struct validation_params *params)
+{{
+    return {body};
+}}
+"#,
+            str_len = STR_LEN,
+            scale = DECIMAL_SCALE,
+            contracts = contracts,
+            helpers = helpers,
+            func_name = func_name,
+            body = body
+        )
+    }
+}
+
+impl VerifiableStrategy for EbpfStrategy {
+    fn map_type(&self, dt: &DataType) -> String {
+        let (ty, suffix, _, _) = c_layout(dt);
+        format!("{}{}", ty, suffix)
+    }
+
+    fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
+        format!("/* Post-condition: Returns 1 iff ({}) */", expression)
+    }
+
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        // __builtin_*_overflow lowers to plain BPF ALU ops plus a compare
+        match op {
+            ArithmeticOperator::Add => format!("({{ __s64 _r; __builtin_add_overflow({}, {}, &_r) ? 0 : _r; }})", left, right),
+            ArithmeticOperator::Subtract => format!("({{ __s64 _r; __builtin_sub_overflow({}, {}, &_r) ? 0 : _r; }})", left, right),
+            ArithmeticOperator::Multiply => format!("({{ __s64 _r; __builtin_mul_overflow({}, {}, &_r) ? 0 : _r; }})", left, right),
+            ArithmeticOperator::Divide => format!("({} != 0 ? {} / {} : 0)", right, left, right),
+            ArithmeticOperator::Modulo => self.format_modulo(left, right),
+        }
+    }
+
+    /// The struct comes from the companion header
+    fn build_signature(&self, func_name: &str, _schema: &Schema) -> String {
+        format!("#include \"{}.h\"", func_name)
+    }
+
+    fn fn_end(&self) -> String {
+        "}".to_string()
+    }
+
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner("//", &["eBPF C Generated Code - Data-Plane Validator (v0.1.5-alpha)"])
+    }
+
+    fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, _data_type: &DataType) -> String {
+        format!("{} {} {}", left, self.format_operator(op), right)
+    }
+
+    fn schema_files(&self, func_name: &str, schema: &Schema) -> Vec<GeneratedFile> {
+        let (fields, size) = self.struct_layout(schema);
+        let guard = format!("{}_H", func_name.to_uppercase());
+
+        let contents = format!(
+            r#"// eBPF C Generated Header - Validation Parameter Layout
+// Traceability ID: {traceability_id}
+
+#ifndef {guard}
+#define {guard}
+
+#include <linux/types.h>
+#include <bpf/bpf_helpers.h>
+
+#ifndef CRUCIBLE_STR_LEN
+#define CRUCIBLE_STR_LEN {str_len}
+#endif
+#ifndef CRUCIBLE_DECIMAL_SCALE
+#define CRUCIBLE_DECIMAL_SCALE {scale}
+#endif
+
+struct validation_params {{
+{fields}
+}};
+
+_Static_assert(sizeof(struct validation_params) == {size}, "validation_params layout changed");
+
+/* User space writes the current parameters at key 0 */
+struct {{
+    __uint(type, BPF_MAP_TYPE_ARRAY);
+    __uint(max_entries, 1);
+    __type(key, __u32);
+    __type(value, struct validation_params);
+}} {func_name}_params SEC(".maps");
+
+#endif /* {guard} */
+"#,
+            traceability_id = literal::comment(&schema.traceability_id),
+            guard = guard,
+            str_len = STR_LEN,
+            scale = DECIMAL_SCALE,
+            fields = fields.join("\n"),
+            size = size,
+            func_name = func_name
+        );
+
+        vec![GeneratedFile {
+            path: format!("{}.h", func_name),
+            contents,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, TargetLanguage};
+
+    fn packet_policy() -> CompoundConstraint {
+        CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "payload_len".to_string(),
+                operator: ConstraintOperator::LessThanOrEqual,
+                right_value: "mtu".to_string(),
+            }),
+            CompoundConstraint::Not(Box::new(CompoundConstraint::Simple(Constraint {
+                left_variable: "proto".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: "\"icmp\"".to_string(),
+            }))),
+        ])
+    }
+
+    #[test]
+    fn test_ebpf_loop_free_predicate() {
+        let output = CodeGenerator.generate(&packet_policy(), TargetLanguage::EbpfC).unwrap();
+        assert!(output.code.contains("struct validation_params {\n    __s64 mtu;\n    __s64 payload_len;\n    __s64 proto;\n};"));
+        assert!(output
+            .code
+            .contains("static __always_inline int validate_intent(const struct validation_params *params)"));
+        assert!(output.code.contains(
+            "return (params->payload_len <= params->mtu && !(__builtin_memcmp(params->proto, \"icmp\", sizeof(\"icmp\")) == 0));"
+        ));
+        assert!(!output.code.contains("for ("));
+        assert!(!output.code.contains("while"));
+    }
+
+    #[test]
+    fn test_ebpf_header_layout() {
+        let mut schema = Schema::new("ebpf-001".to_string());
+        schema.add_field("payload_len".to_string(), DataType::Uint32, Some("L4 payload bytes".to_string()));
+        schema.add_field("mtu".to_string(), DataType::Uint32, None);
+        schema.add_field("proto".to_string(), DataType::String, None);
+        schema.add_field("flow_id".to_string(), DataType::Uint64, None);
+
+        let output = CodeGenerator
+            .generate_with_schema(&packet_policy(), &schema, TargetLanguage::EbpfC)
+            .unwrap();
+        assert!(output.code.contains("Traceability ID: ebpf-001"));
+        assert!(output.code.contains("#include \"validate_intent.h\""));
+
+        let header = &output.files[0];
+        assert_eq!(header.path, "validate_intent.h");
+        assert!(header.contents.contains(
+            "    __u64 flow_id; /* offset 0 */\n    __u32 mtu; /* offset 8 */\n    __u32 payload_len; /* offset 12 - L4 payload bytes */\n    char proto[CRUCIBLE_STR_LEN]; /* offset 16 */"
+        ));
+        assert!(header.contents.contains("sizeof(struct validation_params) == 80"));
+        assert!(header.contents.contains("} validate_intent_params SEC(\".maps\");"));
+    }
+}
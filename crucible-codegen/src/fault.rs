@@ -0,0 +1,243 @@
+//! Fault-injection tests for a validator
+//!
+//! `generate_fault_tests` turns the verifier's fault cases
+//! (`Z3Verifier::fault_cases`) into negative tests. Each input violates one
+//! top-level constraint and satisfies the others, and the test asserts that
+//! the validator rejects it. A validator that accepts one ignores that
+//! constraint, so together the tests show every constraint is load-bearing.
+//! A constraint the others imply has no case, and the file notes it.
+//!
+//! - Rust: `#[test]` functions in `<func>_faults.rs`, a sibling module of the validator
+//! - TypeScript: Vitest cases in `<func>.faults.test.ts`
+//! - Python: pytest functions in `test_<func>_faults.py`
+
+use super::monitor::conjuncts;
+use super::{
+    collect_variables, inlined_compound, is_identifier, literal, CodeGenerator, CodegenError, CodegenOptions,
+    FieldNames, GeneratedFile, TargetLanguage,
+};
+use crucible_core::{CompoundConstraint, FaultCase, Verbalizer};
+use std::fmt::Write as _;
+
+/// One negative test, its input renamed for the target
+struct Fault {
+    name: String,
+    summary: String,
+    /// `(field, value)` for every field of `ValidationParams`
+    values: Vec<(String, i64)>,
+}
+
+impl CodeGenerator {
+    /// Fault-injection tests for the validator `generate_with_options` emits
+    /// for `compound`, one per case.
+    ///
+    /// Supported for Rust, TypeScript and Python.
+    pub fn generate_fault_tests(
+        &self,
+        compound: &CompoundConstraint,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+        cases: &[FaultCase],
+    ) -> Result<GeneratedFile, CodegenError> {
+        let func_name = options.func_name.as_str();
+        language.check_identifier(func_name)?;
+        if cases.is_empty() {
+            return Err(CodegenError::GenerationError("no fault cases to test".to_string()));
+        }
+        let compound = inlined_compound(compound, options)?;
+        let names = FieldNames::for_compounds(language, [&*compound], None)?;
+        let compound = names.rename(compound);
+        let conjuncts = conjuncts(&compound);
+        let mut fields: Vec<String> = collect_variables(&compound).into_iter().filter(|v| is_identifier(v)).collect();
+        fields.sort();
+        fields.dedup();
+
+        let mut faults = Vec::new();
+        for case in cases {
+            let Some((name, conjunct)) = conjuncts.get(case.constraint) else {
+                return Err(CodegenError::GenerationError(format!(
+                    "fault case for constraint {} of {}",
+                    case.constraint + 1,
+                    conjuncts.len()
+                )));
+            };
+            let values = case.values.iter().map(|(field, value)| (names.get(field), *value)).collect::<Vec<_>>();
+            let value = |field: &str| values.iter().find(|(name, _)| *name == field).map_or(0, |(_, value)| *value);
+            faults.push(Fault {
+                name: name.clone(),
+                summary: literal::comment(&Verbalizer::new().compound(conjunct)).into_owned(),
+                values: fields.iter().map(|field| (field.clone(), value(field))).collect(),
+            });
+        }
+        // Constraints without a case are implied by the others
+        let implied: Vec<&str> = conjuncts
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !cases.iter().any(|case| case.constraint == *index))
+            .map(|(_, (name, _))| name.as_str())
+            .collect();
+
+        let (path, contents) = match language {
+            TargetLanguage::Rust => (format!("{}_faults.rs", func_name), rust(&faults, &implied, func_name)),
+            TargetLanguage::TypeScript => {
+                (format!("{}.faults.test.ts", func_name), typescript(&faults, &implied, func_name, options.zod))
+            }
+            TargetLanguage::Python => (format!("test_{}_faults.py", func_name), python(&faults, &implied, func_name)),
+            other => {
+                return Err(CodegenError::UnsupportedLanguage(format!("{:?} has no fault-injection tests", other)));
+            }
+        };
+        Ok(GeneratedFile { path, contents })
+    }
+}
+
+/// `// `constraint_3` has no fault case: the other constraints imply it`, per line
+fn implied_notes(implied: &[&str], comment: &str) -> String {
+    implied
+        .iter()
+        .map(|name| format!("\n{} `{}` has no fault case: the other constraints imply it\n", comment, name))
+        .collect()
+}
+
+fn rust(faults: &[Fault], implied: &[&str], func_name: &str) -> String {
+    let mut tests = String::new();
+    for fault in faults {
+        let values: Vec<String> = fault.values.iter().map(|(field, value)| format!("{}: {}", field, value)).collect();
+        let _ = write!(
+            tests,
+            "\n/// Violates only `{}`\n#[test]\nfn rejects_without_{}() {{\n    \
+             let params = ValidationParams {{ {} }};\n    assert!(!Validator.{}(&params));\n}}\n",
+            fault.summary,
+            fault.name,
+            values.join(", "),
+            func_name
+        );
+    }
+    format!(
+        r#"//! Fault injection for `{func_name}`: each input violates one constraint
+//! and satisfies the others, so the validator must reject it. Declare next
+//! to the validator: `#[cfg(test)] mod {func_name}_faults;`
+
+use super::{func_name}::{{ValidationParams, Validator}};
+{tests}{notes}"#,
+        notes = implied_notes(implied, "//"),
+    )
+}
+
+fn typescript(faults: &[Fault], implied: &[&str], func_name: &str, zod: bool) -> String {
+    let (import, call) = if zod {
+        (func_name.to_string(), func_name.to_string())
+    } else {
+        ("Validator".to_string(), format!("Validator.{}", func_name))
+    };
+    let mut tests = String::new();
+    for fault in faults {
+        let values: Vec<String> = fault.values.iter().map(|(field, value)| format!("{}: {}", field, value)).collect();
+        let _ = write!(
+            tests,
+            "\n  // Violates only `{}`\n  it(\"rejects without {}\", () => {{\n    \
+             expect({}({{ {} }})).toBe(false);\n  }});\n",
+            fault.summary,
+            fault.name,
+            call,
+            values.join(", ")
+        );
+    }
+    let notes: String = implied_notes(implied, "  //");
+    format!(
+        r#"// Fault injection for `{func_name}`: each input violates one constraint
+// and satisfies the others, so the validator must reject it. Run with `vitest`.
+import {{ describe, expect, it }} from "vitest";
+import {{ {import} }} from "./{func_name}";
+
+describe("{func_name} rejects each violated constraint", () => {{{tests}{notes}}});
+"#
+    )
+}
+
+fn python(faults: &[Fault], implied: &[&str], func_name: &str) -> String {
+    let mut tests = String::new();
+    for fault in faults {
+        let values: Vec<String> =
+            fault.values.iter().map(|(field, value)| format!("{}: {}", literal::json_string(field), value)).collect();
+        let _ = write!(
+            tests,
+            "\n\n# Violates only `{}`\ndef test_rejects_without_{}():\n    assert not Validator.{}({{{}}})\n",
+            fault.summary,
+            fault.name,
+            func_name,
+            values.join(", ")
+        );
+    }
+    format!(
+        r#""""Fault injection for `{func_name}`: each input violates one constraint
+and satisfies the others, so the validator must reject it. Run with `pytest`."""
+
+from {func_name} import Validator
+{tests}{notes}"#,
+        notes = implied_notes(implied, "#"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_fault_tests() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            simple("amount", ConstraintOperator::GreaterThan, "-5"),
+        ]);
+        // What `Z3Verifier::fault_cases` finds; the third constraint is implied by the first
+        let cases = [
+            FaultCase {
+                constraint: 0,
+                values: BTreeMap::from([("amount".to_string(), 0), ("balance".to_string(), 0)]),
+            },
+            FaultCase {
+                constraint: 1,
+                values: BTreeMap::from([("amount".to_string(), 2), ("balance".to_string(), 1)]),
+            },
+        ];
+        let options = CodegenOptions::default();
+        let generate = |language| CodeGenerator.generate_fault_tests(&compound, language, &options, &cases);
+
+        let rust = generate(TargetLanguage::Rust).unwrap();
+        assert_eq!(rust.path, "validate_intent_faults.rs");
+        assert!(rust.contents.contains("use super::validate_intent::{ValidationParams, Validator};\n"));
+        assert!(rust.contents.contains(
+            "#[test]\nfn rejects_without_balance_ge_amount() {\n    \
+             let params = ValidationParams { amount: 2, balance: 1 };\n    \
+             assert!(!Validator.validate_intent(&params));\n}\n"
+        ));
+        assert!(rust.contents.contains("// `amount_gt_5` has no fault case: the other constraints imply it\n"));
+
+        let typescript = generate(TargetLanguage::TypeScript).unwrap();
+        assert!(typescript
+            .contents
+            .contains("    expect(Validator.validate_intent({ amount: 0, balance: 0 })).toBe(false);"));
+
+        let python = generate(TargetLanguage::Python).unwrap();
+        assert_eq!(python.path, "test_validate_intent_faults.py");
+        assert!(python.contents.contains(
+            "def test_rejects_without_amount_gt_0():\n    \
+             assert not Validator.validate_intent({\"amount\": 0, \"balance\": 0})"
+        ));
+
+        assert!(matches!(generate(TargetLanguage::Zig), Err(CodegenError::UnsupportedLanguage(_))));
+        let stray = [FaultCase { constraint: 3, values: BTreeMap::new() }];
+        let stray = CodeGenerator.generate_fault_tests(&compound, TargetLanguage::Rust, &options, &stray);
+        assert!(matches!(stray, Err(CodegenError::GenerationError(_))));
+    }
+}
@@ -0,0 +1,229 @@
+//! Target formatters run over generated code
+//!
+//! Generated code is laid out by string templates, so a change to one
+//! constraint can re-indent its neighbours and regenerated files are hard to
+//! review. With `CodegenOptions::format` enabled, the validator and its
+//! companion sources go through the formatter the target's developers already
+//! use: rustfmt (or `prettyplease` in-process, with the `rustfmt` feature),
+//! prettier, black, gnatpp, `zig fmt`, `mix format`, `forge fmt`, `opa fmt`,
+//! `cue fmt` and clang-format. A formatter that is not installed leaves the
+//! code as generated; one that rejects it does too, with a warning.
+
+use crate::{CodegenError, StreamedOutput, TargetLanguage};
+use crucible_core::FormatConfig;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use uuid::Uuid;
+
+/// Argument replaced by the path of a temporary copy of the code
+const FILE: &str = "{file}";
+
+/// The formatter of `language` when none is configured; empty for targets
+/// without a common one
+fn default_command(language: TargetLanguage) -> &'static [&'static str] {
+    match language {
+        TargetLanguage::Rust => &["rustfmt", "--edition", "2021"],
+        TargetLanguage::TypeScript => &["prettier", "--parser", "typescript"],
+        TargetLanguage::Python => &["black", "--quiet", "-"],
+        TargetLanguage::SparkAda => &["gnatpp", "--pipe", FILE],
+        TargetLanguage::Zig => &["zig", "fmt", "--stdin"],
+        TargetLanguage::Elixir => &["mix", "format", "-"],
+        TargetLanguage::Solidity => &["forge", "fmt", "--raw", "-"],
+        TargetLanguage::Rego => &["opa", "fmt"],
+        TargetLanguage::Cue => &["cue", "fmt", "-"],
+        TargetLanguage::EbpfC => &["clang-format", "--assume-filename=validator.c"],
+        TargetLanguage::Sql(_) | TargetLanguage::Cedar | TargetLanguage::Wit => &[],
+    }
+}
+
+/// The command configured for `language` under any of its names
+fn configured(language: TargetLanguage, config: &FormatConfig) -> Option<&[String]> {
+    config
+        .commands
+        .iter()
+        .find(|(name, _)| TargetLanguage::from_name(name) == Some(language))
+        .map(|(_, command)| command.as_slice())
+}
+
+/// Whether a file with `extension` is `language` source. The combined SPARK
+/// package is left for `gnatchop`; its spec and body files are formatted
+fn formats(language: TargetLanguage, extension: &str) -> bool {
+    match language {
+        TargetLanguage::SparkAda => matches!(extension, "ads" | "adb"),
+        TargetLanguage::EbpfC => matches!(extension, "c" | "h"),
+        _ => extension == language.file_extension(),
+    }
+}
+
+/// `code` run through the formatter of `language`; `None` when there is
+/// none or it is not installed
+pub fn format_code(language: TargetLanguage, code: &str, config: &FormatConfig) -> io::Result<Option<String>> {
+    format_source(language, language.file_extension(), code, config)
+}
+
+fn format_source(
+    language: TargetLanguage,
+    extension: &str,
+    code: &str,
+    config: &FormatConfig,
+) -> io::Result<Option<String>> {
+    let command = configured(language, config);
+    #[cfg(feature = "rustfmt")]
+    if command.is_none() && language == TargetLanguage::Rust {
+        return unparse(code).map(Some);
+    }
+    let command: Vec<String> = match command {
+        Some(command) => command.to_vec(),
+        None => default_command(language).iter().map(|arg| arg.to_string()).collect(),
+    };
+    let Some((program, args)) = command.split_first() else {
+        return Ok(None);
+    };
+    match run(program, args, code, extension) {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            tracing::debug!(program, "formatter not installed");
+            Ok(None)
+        }
+        result => result.map(Some),
+    }
+}
+
+/// Pipe `code` through `program`, or hand it a temporary `.{extension}`
+/// copy for `{file}`; the result is its output, or the copy when it
+/// formats in place and prints nothing
+fn run(program: &str, args: &[String], code: &str, extension: &str) -> io::Result<String> {
+    if !args.iter().any(|arg| arg == FILE) {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        // Written from another thread, so a formatter streaming its output
+        // cannot block on a full stdout pipe; a failed write shows in its status
+        let output = std::thread::scope(|scope| {
+            scope.spawn(move || stdin.write_all(code.as_bytes()));
+            child.wait_with_output()
+        })?;
+        return formatted(program, output, code);
+    }
+
+    let path = std::env::temp_dir().join(format!("crucible-{}.{}", Uuid::new_v4(), extension));
+    std::fs::write(&path, code)?;
+    let args = args.iter().map(|arg| if arg == FILE { path.as_os_str() } else { arg.as_ref() });
+    let result = Command::new(program).args(args).stdin(Stdio::null()).output().and_then(|output| {
+        match output.status.success() && output.stdout.is_empty() {
+            true => std::fs::read_to_string(&path),
+            false => formatted(program, output, code),
+        }
+    });
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+fn formatted(program: &str, output: Output, code: &str) -> io::Result<String> {
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::other(format!("{} {}: {}", program, output.status, stderr.trim())));
+    }
+    if output.stdout.is_empty() && !code.is_empty() {
+        return Err(io::Error::other(format!("{} printed nothing", program)));
+    }
+    String::from_utf8(output.stdout).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Rust laid out by `prettyplease`. `syn` drops line comments, so the leading
+/// attributes and provenance header are kept as written; comments inside
+/// items are lost, and `commands.rust` selects rustfmt instead
+#[cfg(feature = "rustfmt")]
+fn unparse(code: &str) -> io::Result<String> {
+    let head: usize = code
+        .split_inclusive('\n')
+        .take_while(|line| {
+            let line = line.trim();
+            line.is_empty() || line.starts_with("#![") || (line.starts_with("//") && !line.starts_with("///"))
+        })
+        .map(str::len)
+        .sum();
+    let (head, items) = code.split_at(head);
+    let file = syn::parse_file(items).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(format!("{}{}", head, prettyplease::unparse(&file)))
+}
+
+/// Format the streamed validator in `code` and the companion sources of
+/// `output`, write the validator to `out` and record its new size
+pub(crate) fn write_formatted(
+    mut output: StreamedOutput,
+    code: Vec<u8>,
+    config: &FormatConfig,
+    out: &mut impl Write,
+) -> Result<StreamedOutput, CodegenError> {
+    let language = output.language;
+    let reformat = |extension: &str, code: String| {
+        if !formats(language, extension) {
+            return code;
+        }
+        match format_source(language, extension, &code, config) {
+            Ok(formatted) => formatted.unwrap_or(code),
+            Err(error) => {
+                tracing::warn!(?language, %error, "formatter failed; keeping the generated layout");
+                code
+            }
+        }
+    };
+
+    // Every piece written is a `&str`
+    let code = String::from_utf8(code).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+    let code = reformat(language.file_extension(), code);
+    for file in &mut output.files {
+        let extension = Path::new(&file.path).extension().and_then(|e| e.to_str()).unwrap_or_default();
+        file.contents = reformat(extension, std::mem::take(&mut file.contents));
+    }
+    out.write_all(code.as_bytes())?;
+    output.bytes = code.len() as u64;
+    Ok(output)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, CodegenOptions};
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_formatter_hooks() {
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "100".to_string(),
+        });
+        let generate = |language, commands: &[(&str, &[&str])]| {
+            let format = FormatConfig {
+                enabled: !commands.is_empty(),
+                commands: commands
+                    .iter()
+                    .map(|(name, command)| (name.to_string(), command.iter().map(|arg| arg.to_string()).collect()))
+                    .collect(),
+            };
+            let options = CodegenOptions { format, reproducible: true, ..CodegenOptions::default() };
+            CodeGenerator.generate_with_options(&compound, language, &options).unwrap()
+        };
+        // The format settings are part of the input hash; the code after it is compared
+        let body = |code: &str| code.lines().skip(2).collect::<Vec<_>>().join("\n");
+        let plain = body(&generate(TargetLanguage::Python, &[]).code);
+
+        // Piped through stdin, or handed a temporary copy formatted in place
+        let upper = generate(TargetLanguage::Python, &[("py", &["tr", "a-z", "A-Z"])]);
+        assert_eq!(body(&upper.code), plain.to_uppercase());
+        let renamed = generate(TargetLanguage::Python, &[("python", &["sed", "-i", "s/validate_intent/check/", FILE])]);
+        assert!(renamed.code.contains("def check(") && !renamed.code.contains("validate_intent"));
+
+        // Missing, failing and empty formatters keep the generated layout
+        for command in [&["crucible-no-such-formatter"][..], &["false"], &[]] {
+            assert_eq!(body(&generate(TargetLanguage::Python, &[("python", command)]).code), plain);
+        }
+        assert_eq!(format_code(TargetLanguage::Wit, "x", &FormatConfig::default()).unwrap(), None);
+    }
+}
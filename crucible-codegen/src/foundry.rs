@@ -0,0 +1,232 @@
+//! Property tests for the Solidity validator
+//!
+//! Two companion files accompany the Solidity output:
+//!
+//! - `test/Validator.t.sol`, a Foundry test contract whose fuzz tests check
+//!   that the validator agrees with the constraint on random inputs and that
+//!   `enforce` reverts exactly when validation fails, plus one unit test per
+//!   boundary case;
+//! - `test/ValidatorEchidna.sol`, the same agreement as an Echidna assertion.
+//!
+//! The fuzzed arguments are the members of `ValidationParams`, typed as in
+//! the validator. The oracle is the constraint rendered over them, so it
+//! does not go through the struct. Boundary cases come from the
+//! verifier (`Z3Verifier::boundary_cases`), which places inputs on and next
+//! to the edge of every comparison.
+
+use super::{
+    canonical_compound, inlined_compound, BareVariables, CodeGenerator, CodegenOptions, FieldNames, GeneratedFile,
+    SolidityStrategy, TargetLanguage,
+};
+use crucible_core::{BoundaryCase, CompoundConstraint};
+
+impl CodeGenerator {
+    /// Foundry and Echidna test contracts for the Solidity validator, with a
+    /// unit test for each of `boundary_cases`.
+    pub fn generate_solidity_property_tests(
+        &self,
+        compound: &CompoundConstraint,
+        options: &CodegenOptions,
+        boundary_cases: &[BoundaryCase],
+    ) -> Vec<GeneratedFile> {
+        // Unresolved references are reported by `generate`; test what resolves
+        let compound = inlined_compound(compound, options).unwrap_or_else(|_| canonical_compound(compound, options));
+        let names = FieldNames::for_compounds(TargetLanguage::Solidity, [&*compound], None).unwrap_or_default();
+        let boundary_cases: Vec<BoundaryCase> = boundary_cases
+            .iter()
+            .map(|case| BoundaryCase {
+                values: case.values.iter().map(|(field, value)| (names.get(field).to_string(), *value)).collect(),
+                expected: case.expected,
+            })
+            .collect();
+        let compound = names.rename(compound);
+        let members = SolidityStrategy::inferred_members(&compound);
+        property_test_files(&compound, &options.func_name, &members, &boundary_cases)
+    }
+}
+
+/// The test contracts for a validator whose `ValidationParams` has
+/// `members`, each a field name and its Solidity type
+pub(crate) fn property_test_files(
+    compound: &CompoundConstraint,
+    func_name: &str,
+    members: &[(String, String)],
+    boundary_cases: &[BoundaryCase],
+) -> Vec<GeneratedFile> {
+    let arguments = members.iter().map(|(name, ty)| argument(name, ty)).collect::<Vec<_>>().join(", ");
+    let params = format!(
+        "ValidationParams memory params = ValidationParams({{{}}});",
+        members.iter().map(|(name, _)| format!("{0}: {0}", name)).collect::<Vec<_>>().join(", ")
+    );
+    let strategy = SolidityStrategy::with_members(members);
+    let oracle = CodeGenerator.build_expression(compound, &BareVariables(&strategy));
+
+    let boundary_tests: String = boundary_cases
+        .iter()
+        .filter_map(|case| {
+            members
+                .iter()
+                .map(|(name, ty)| Some(format!("{}: {}", name, boundary_value(ty, case.values.get(name).copied())?)))
+                .collect::<Option<Vec<_>>>()
+                .map(|values| (values.join(", "), case.expected))
+        })
+        .enumerate()
+        .map(|(index, (values, expected))| {
+            let check = if expected { "assertTrue" } else { "assertFalse" };
+            format!(
+                r#"
+    function test_boundary_{index}() public view {{
+        ValidationParams memory params = ValidationParams({{{values}}});
+        {check}(validator.{func_name}(params));
+    }}
+"#
+            )
+        })
+        .collect();
+
+    let foundry = format!(
+        r#"// SPDX-License-Identifier: MIT
+// Foundry property tests - run with `forge test`
+pragma solidity ^0.8.27;
+
+import {{Test}} from "forge-std/Test.sol";
+import "../src/Validator.sol";
+
+contract ValidatorTest is Test {{
+    Validator validator = new Validator();
+
+    /// @notice The validator agrees with the constraint on random inputs
+    function testFuzz_{func_name}({arguments}) public view {{
+        {params}
+        assertEq(validator.{func_name}(params), {oracle});
+    }}
+
+    /// @notice `enforce` reverts exactly when validation fails
+    function testFuzz_enforce({arguments}) public {{
+        {params}
+        if (!({oracle})) {{
+            vm.expectRevert();
+        }}
+        validator.enforce(params);
+    }}
+{boundary_tests}}}
+"#
+    );
+
+    let echidna = format!(
+        r#"// SPDX-License-Identifier: MIT
+// Echidna properties - run with
+// `echidna test/ValidatorEchidna.sol --contract ValidatorEchidna --test-mode assertion`
+pragma solidity ^0.8.27;
+
+import "../src/Validator.sol";
+
+contract ValidatorEchidna {{
+    Validator validator = new Validator();
+
+    function check_{func_name}({arguments}) public view {{
+        {params}
+        assert(validator.{func_name}(params) == ({oracle}));
+    }}
+}}
+"#
+    );
+
+    vec![
+        GeneratedFile {
+            path: "test/Validator.t.sol".to_string(),
+            contents: foundry,
+        },
+        GeneratedFile {
+            path: "test/ValidatorEchidna.sol".to_string(),
+            contents: echidna,
+        },
+    ]
+}
+
+/// A fuzzed argument; strings and arrays live in `memory`
+fn argument(name: &str, ty: &str) -> String {
+    match ty == "string" || ty.ends_with(']') {
+        true => format!("{} memory {}", ty, name),
+        false => format!("{} {}", ty, name),
+    }
+}
+
+/// A boundary case's value for a member of type `ty` (`0` where the case
+/// leaves the field free); `None` when a negative value cannot be unsigned
+fn boundary_value(ty: &str, value: Option<i64>) -> Option<String> {
+    let value = value.unwrap_or(0);
+    match ty {
+        "string" => Some("\"\"".to_string()),
+        "bool" => Some((value != 0).to_string()),
+        _ if ty.ends_with("[]") => Some(format!("new {}(0)", ty)),
+        _ if ty.starts_with("uint") && value < 0 => None,
+        _ => Some(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator, DataType, Schema};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_foundry_fuzz_and_boundary_tests() {
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "amount".to_string(),
+        });
+        let boundary = BoundaryCase {
+            values: BTreeMap::from([("amount".to_string(), 5), ("balance".to_string(), 4)]),
+            expected: false,
+        };
+
+        let files = CodeGenerator.generate_solidity_property_tests(&compound, &CodegenOptions::default(), &[boundary]);
+        let foundry = &files[0].contents;
+        assert!(foundry.contains("function testFuzz_validate_intent(uint256 amount, uint256 balance) public view"));
+        assert!(foundry.contains("ValidationParams({amount: amount, balance: balance})"));
+        assert!(foundry.contains("assertEq(validator.validate_intent(params), balance >= amount);"));
+        assert!(foundry.contains("ValidationParams({amount: 5, balance: 4});\n        assertFalse("));
+
+        assert_eq!(files[1].path, "test/ValidatorEchidna.sol");
+        assert!(files[1].contents.contains("assert(validator.validate_intent(params) == (balance >= amount));"));
+    }
+
+    #[test]
+    fn test_fuzz_arguments_follow_the_schema() {
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "delta".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "-5".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "role".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: "\"admin\"".to_string(),
+            }),
+        ]);
+        let mut schema = Schema::new("foundry-001".to_string());
+        schema.add_field("delta".to_string(), DataType::Int64, None);
+        schema.add_field("role".to_string(), DataType::String, None);
+        schema.add_field("active".to_string(), DataType::Bool, None);
+
+        let output = CodeGenerator
+            .generate_with_schema(&compound, &schema, TargetLanguage::Solidity)
+            .unwrap();
+        let foundry = &output.files.iter().find(|f| f.path == "test/Validator.t.sol").unwrap().contents;
+        assert!(foundry.contains("function testFuzz_validate_intent(bool active, int256 delta, string memory role)"));
+        assert!(foundry.contains("ValidationParams({active: active, delta: delta, role: role})"));
+        assert!(foundry.contains("keccak256(bytes(role)) == keccak256(bytes(\"admin\"))"));
+
+        let members = [("delta".to_string(), "int256".to_string()), ("role".to_string(), "string".to_string())];
+        let boundary = BoundaryCase {
+            values: BTreeMap::from([("delta".to_string(), -5)]),
+            expected: false,
+        };
+        let files = property_test_files(&compound, "validate_intent", &members, &[boundary]);
+        assert!(files[0].contents.contains("ValidationParams({delta: -5, role: \"\"});\n        assertFalse("));
+    }
+}
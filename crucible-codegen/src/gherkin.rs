@@ -0,0 +1,442 @@
+//! Gherkin/BDD Feature Export
+//!
+//! Renders each requirement of an Intent-AST as a Gherkin scenario so QA teams
+//! can run the verified intents as executable acceptance tests in existing
+//! Cucumber/Behave suites:
+//!
+//! - `Given` lines declare the Schema fields the requirement touches
+//! - `When` supplies a table of example values chosen to satisfy the constraints
+//! - `Then` asserts the result the validator gives on them: the row is
+//!   evaluated against the constraint tree, since the example solver cannot
+//!   satisfy every combination of constraints
+
+use crucible_core::bitwise::parse_integer;
+use crucible_core::{
+    BitwiseOperator, Call, CompoundConstraint, Constraint, ConstraintOperator, IntentAst, Requirement, Schema,
+};
+use crate::{is_identifier, literal};
+use std::collections::BTreeMap;
+
+/// Exports an Intent-AST as a Gherkin `.feature` document
+pub struct GherkinExporter {
+    /// Name used for the `Feature:` line
+    pub feature_name: String,
+    /// Name of the validator function referenced in `Then` steps
+    pub func_name: String,
+}
+
+impl GherkinExporter {
+    /// Create an exporter for the given feature name
+    pub fn new(feature_name: &str) -> Self {
+        Self {
+            feature_name: feature_name.to_string(),
+            func_name: "validate_intent".to_string(),
+        }
+    }
+
+    /// Render the whole Intent-AST as a feature file
+    pub fn export(&self, ast: &IntentAst, schema: &Schema) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("# Traceability ID: {}\n", literal::comment(&schema.traceability_id)));
+        out.push_str(&format!("Feature: {}\n", self.feature_name));
+
+        for requirement in &ast.requirements {
+            out.push('\n');
+            out.push_str(&self.export_scenario(requirement, schema));
+        }
+
+        out
+    }
+
+    /// Render a single requirement as a scenario
+    pub fn export_scenario(&self, requirement: &Requirement, schema: &Schema) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("  @requirement-{}\n", requirement.id));
+        out.push_str(&format!("  Scenario: {}\n", requirement.content.trim()));
+
+        let Some(tree) = requirement.constraint_tree() else {
+            out.push_str("    # No formal constraints attached to this requirement\n");
+            out.push_str(&format!("    Then {} is defined\n", self.func_name));
+            return out;
+        };
+
+        let bindings = example_bindings(&example_constraints(&tree));
+
+        let mut first = true;
+        for name in bindings.keys() {
+            let keyword = if first { "Given" } else { "And" };
+            out.push_str(&format!(
+                "    {} a field \"{}\" of type {:?}\n",
+                keyword,
+                name,
+                schema.get_type(name)
+            ));
+            first = false;
+        }
+
+        out.push_str(&format!("    When {} is called with:\n", self.func_name));
+        out.push_str("      | field | value |\n");
+        for (name, value) in &bindings {
+            out.push_str(&format!("      | {} | {} |\n", name, table_cell(value)));
+        }
+
+        match holds(&tree, &bindings) {
+            Some(expected) => out.push_str(&format!("    Then {} returns {}\n", self.func_name, expected)),
+            None => {
+                out.push_str("    # The expected result depends on a predicate the example cannot evaluate\n");
+                out.push_str(&format!("    Then {} is defined\n", self.func_name));
+            }
+        }
+        out
+    }
+}
+
+/// Whether the example row satisfies `compound`; `None` when part of it
+/// (a named predicate, a call, a conditional) cannot be evaluated here
+fn holds(compound: &CompoundConstraint, row: &BTreeMap<String, String>) -> Option<bool> {
+    let all = |constraints: &[CompoundConstraint]| -> Option<Vec<bool>> {
+        constraints.iter().map(|c| holds(c, row)).collect()
+    };
+    match compound {
+        CompoundConstraint::Simple(c) => constraint_holds(c, row),
+        // A known `false` settles a conjunction (and a known `true` a
+        // disjunction) even when another branch cannot be evaluated
+        CompoundConstraint::And(constraints) => {
+            let results: Vec<_> = constraints.iter().map(|c| holds(c, row)).collect();
+            match results.contains(&Some(false)) {
+                true => Some(false),
+                false => results.into_iter().collect::<Option<Vec<_>>>().map(|_| true),
+            }
+        }
+        CompoundConstraint::Or(constraints) => {
+            let results: Vec<_> = constraints.iter().map(|c| holds(c, row)).collect();
+            match results.contains(&Some(true)) {
+                true => Some(true),
+                false => results.into_iter().collect::<Option<Vec<_>>>().map(|_| false),
+            }
+        }
+        CompoundConstraint::Not(inner) => holds(inner, row).map(|result| !result),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            match (holds(antecedent, row), holds(consequent, row)) {
+                (Some(false), _) | (_, Some(true)) => Some(true),
+                (Some(true), Some(false)) => Some(false),
+                _ => None,
+            }
+        }
+        CompoundConstraint::ExactlyOne(constraints) => {
+            all(constraints).map(|results| results.iter().filter(|r| **r).count() == 1)
+        }
+        CompoundConstraint::AtMostOne(constraints) => {
+            all(constraints).map(|results| results.iter().filter(|r| **r).count() <= 1)
+        }
+        CompoundConstraint::Distinct(_) => holds(&compound.pairwise_encoding()?, row),
+        CompoundConstraint::Named(_) => None,
+    }
+}
+
+/// Whether the example row satisfies a single constraint
+fn constraint_holds(c: &Constraint, row: &BTreeMap<String, String>) -> Option<bool> {
+    let integer = |term: &str| parse_integer(term).or_else(|| row.get(term).and_then(|v| parse_integer(v)));
+    let text = |value: &str| literal::unquote(value).unwrap_or_else(|| value.to_string());
+
+    if c.operator.is_membership() {
+        let haystack = text(row.get(&c.left_variable)?);
+        let needle = text(&c.right_value);
+        return Some(haystack.contains(&needle) == (c.operator == ConstraintOperator::Contains));
+    }
+
+    let left = if let Some(m) = c.modulo() {
+        integer(&m.dividend)?.checked_rem_euclid(integer(&m.divisor)?)?
+    } else if let Some(bitwise) = c.bitwise() {
+        let (operand, mask) = (integer(&bitwise.operand)?, integer(&bitwise.mask)?);
+        match bitwise.operator {
+            BitwiseOperator::And => operand & mask,
+            BitwiseOperator::Or => operand | mask,
+            BitwiseOperator::Xor => operand ^ mask,
+            BitwiseOperator::ShiftLeft => operand.checked_shl(u32::try_from(mask).ok()?)?,
+            BitwiseOperator::ShiftRight => operand.checked_shr(u32::try_from(mask).ok()?)?,
+        }
+    } else if Call::parse(&c.left_variable).is_some() || c.conditional().is_some() {
+        return None;
+    } else if let Some(value) = integer(&c.left_variable) {
+        value
+    } else {
+        // A string field compared for (in)equality with a literal or another field
+        let left = text(row.get(&c.left_variable)?);
+        let right = text(row.get(&c.right_value).unwrap_or(&c.right_value));
+        return match c.operator {
+            ConstraintOperator::Equal => Some(left == right),
+            ConstraintOperator::NotEqual => Some(left != right),
+            _ => None,
+        };
+    };
+
+    let right = integer(&c.right_value)?;
+    Some(match c.operator {
+        ConstraintOperator::GreaterThanOrEqual => left >= right,
+        ConstraintOperator::GreaterThan => left > right,
+        ConstraintOperator::LessThanOrEqual => left <= right,
+        ConstraintOperator::LessThan => left < right,
+        ConstraintOperator::Equal => left == right,
+        ConstraintOperator::NotEqual => left != right,
+        ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => return None,
+    })
+}
+
+/// A data table cell: `\\`, `|` and line breaks are escaped so text cannot
+/// end the cell or the row
+fn table_cell(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('|', "\\|").replace('\n', "\\n")
+}
+
+/// Pick example values that satisfy every constraint of a requirement.
+///
+/// Numeric bounds are applied first, then variable-to-variable comparisons are
+/// resolved relative to whatever the other side was assigned.
+pub(crate) fn example_bindings(constraints: &[Constraint]) -> BTreeMap<String, String> {
+    let mut bindings: BTreeMap<String, i64> = BTreeMap::new();
+    let mut literals: BTreeMap<String, String> = BTreeMap::new();
+
+    for c in constraints {
+        if c.operator.is_membership() {
+            // The needle itself contains the needle; the empty string contains nothing
+            let example = match c.operator {
+                ConstraintOperator::Contains => c.right_value.clone(),
+                _ => "\"\"".to_string(),
+            };
+            literals.insert(c.left_variable.clone(), example);
+            continue;
+        }
+        if let Some(m) = c.modulo() {
+            // Settled after the bounds, below
+            bindings.entry(m.dividend).or_insert(0);
+            continue;
+        }
+        if let Some(bitwise) = c.bitwise() {
+            // Masked bits are settled after the bounds too
+            for field in bitwise.variables() {
+                bindings.entry(field).or_insert(0);
+            }
+            continue;
+        }
+        // The fields a call or conditional reads start at zero; only bounds on plain fields are solved
+        if let Some(call) = Call::parse(&c.left_variable) {
+            for field in call.variables() {
+                bindings.entry(field).or_insert(0);
+            }
+            continue;
+        }
+        if let Some(conditional) = c.conditional() {
+            for field in conditional.variables() {
+                bindings.entry(field).or_insert(0);
+            }
+            bindings.entry(c.left_variable.clone()).or_insert(0);
+            continue;
+        }
+        match c.right_value.parse::<i64>() {
+            Ok(bound) => {
+                let current = bindings.get(&c.left_variable).copied();
+                bindings.insert(c.left_variable.clone(), satisfy(current, c.operator, bound));
+            }
+            Err(_) if is_identifier(&c.right_value) => {
+                bindings.entry(c.left_variable.clone()).or_insert(0);
+                bindings.entry(c.right_value.clone()).or_insert(0);
+            }
+            Err(_) => {
+                literals.insert(c.left_variable.clone(), c.right_value.clone());
+            }
+        }
+    }
+
+    // Two passes settle chains such as `balance >= amount` and `amount > 0`
+    for _ in 0..2 {
+        for c in constraints {
+            if c.operator.is_membership()
+                || c.modulo().is_some()
+                || c.bitwise().is_some()
+                || Call::parse(&c.left_variable).is_some()
+                || c.right_value.parse::<i64>().is_ok()
+                || !is_identifier(&c.right_value)
+            {
+                continue;
+            }
+            let other = bindings[&c.right_value];
+            let current = bindings.get(&c.left_variable).copied();
+            bindings.insert(c.left_variable.clone(), satisfy(current, c.operator, other));
+        }
+    }
+
+    // Round each dividend up to the required remainder, which keeps its lower bounds
+    for c in constraints {
+        let Some(m) = c.modulo() else { continue };
+        let (Ok(divisor), Ok(remainder)) = (m.divisor.parse::<i64>(), c.right_value.parse::<i64>()) else {
+            continue;
+        };
+        if divisor == 0 {
+            continue;
+        }
+        let current = bindings[&m.dividend];
+        let value = match c.operator {
+            ConstraintOperator::Equal => current + (remainder - current).rem_euclid(divisor.abs()),
+            ConstraintOperator::NotEqual if current.rem_euclid(divisor.abs()) == remainder => current + 1,
+            _ => current,
+        };
+        bindings.insert(m.dividend, value);
+    }
+
+    // Set the masked bits to the required pattern, keeping the others
+    for c in constraints {
+        let Some(bitwise) = c.bitwise().filter(|bitwise| bitwise.operator == BitwiseOperator::And) else { continue };
+        let (Some(Ok(mask)), Ok(pattern)) = (bitwise.mask_value().map(i64::try_from), c.right_value.parse::<i64>())
+        else {
+            continue;
+        };
+        let current = bindings[&bitwise.operand];
+        let value = match c.operator {
+            ConstraintOperator::Equal => (current & !mask) | (pattern & mask),
+            ConstraintOperator::NotEqual if current & mask == pattern => current ^ (mask & mask.wrapping_neg()),
+            _ => current,
+        };
+        bindings.insert(bitwise.operand, value);
+    }
+
+    let mut result: BTreeMap<String, String> =
+        bindings.into_iter().map(|(k, v)| (k, v.to_string())).collect();
+    result.extend(literals);
+    result
+}
+
+/// Pick the constraints an accepting example has to satisfy: every conjunct,
+/// the first branch of each disjunction or exactly-one choice, the consequent
+/// of an implication (which then holds whatever the antecedent), `a != b` for
+/// each pair of distinct fields, and nothing under a negation, an at-most-one
+/// choice or an (uninlined) named predicate.
+pub(crate) fn example_constraints(compound: &CompoundConstraint) -> Vec<Constraint> {
+    match compound {
+        CompoundConstraint::Simple(c) => vec![c.clone()],
+        CompoundConstraint::And(constraints) => {
+            constraints.iter().flat_map(example_constraints).collect()
+        }
+        CompoundConstraint::Or(constraints) | CompoundConstraint::ExactlyOne(constraints) => {
+            constraints.first().map(example_constraints).unwrap_or_default()
+        }
+        CompoundConstraint::Implies(_, consequent) => example_constraints(consequent),
+        CompoundConstraint::Distinct(_) => {
+            compound.pairwise_encoding().map(|pairs| example_constraints(&pairs)).unwrap_or_default()
+        }
+        CompoundConstraint::Not(_) | CompoundConstraint::AtMostOne(_) | CompoundConstraint::Named(_) => Vec::new(),
+    }
+}
+
+/// Adjust `current` (if any) so that `current op bound` holds
+fn satisfy(current: Option<i64>, op: ConstraintOperator, bound: i64) -> i64 {
+    let value = current.unwrap_or(bound);
+    match op {
+        ConstraintOperator::GreaterThanOrEqual => value.max(bound),
+        ConstraintOperator::GreaterThan => value.max(bound.saturating_add(1)),
+        ConstraintOperator::LessThanOrEqual => value.min(bound),
+        ConstraintOperator::LessThan => value.min(bound.saturating_sub(1)),
+        ConstraintOperator::Equal => bound,
+        ConstraintOperator::NotEqual => {
+            if value == bound {
+                bound.saturating_sub(1)
+            } else {
+                value
+            }
+        }
+        // Handled as string literals by `example_bindings`
+        ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::DataType;
+
+    fn withdraw_ast() -> IntentAst {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("User can withdraw money if balance >= amount".to_string());
+        ast.requirements[0].constraints = vec![
+            Constraint {
+                left_variable: "balance".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "amount".to_string(),
+            },
+            Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "0".to_string(),
+            },
+        ];
+        ast
+    }
+
+    #[test]
+    fn test_gherkin_scenario_structure() {
+        let mut schema = Schema::new("gherkin-001".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, None);
+        schema.add_field("amount".to_string(), DataType::Uint64, None);
+
+        let feature = GherkinExporter::new("Withdrawals").export(&withdraw_ast(), &schema);
+        assert!(feature.contains("Feature: Withdrawals"));
+        assert!(feature.contains("Scenario: User can withdraw money"));
+        assert!(feature.contains("Given a field \"amount\" of type Uint64"));
+        assert!(feature.contains("| amount | 1 |"));
+        assert!(feature.contains("| balance | 1 |"));
+        assert!(feature.contains("Then validate_intent returns true"));
+    }
+
+    #[test]
+    fn test_example_bindings_satisfy_bounds() {
+        let bindings = example_bindings(&[
+            Constraint {
+                left_variable: "x".to_string(),
+                operator: ConstraintOperator::LessThan,
+                right_value: "10".to_string(),
+            },
+            Constraint {
+                left_variable: "x".to_string(),
+                operator: ConstraintOperator::NotEqual,
+                right_value: "9".to_string(),
+            },
+        ]);
+        assert_eq!(bindings["x"], "8");
+    }
+
+    #[test]
+    fn test_expected_result_follows_the_example_row() {
+        let atom = |left: &str, operator, right: &str| Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        };
+        let mut schema = Schema::new("gherkin-002".to_string());
+        schema.add_field("x".to_string(), DataType::Int64, None);
+        schema.add_field("role".to_string(), DataType::String, None);
+        let exporter = GherkinExporter::new("Examples");
+
+        // No value is above 5 and below 3, so the row the solver picks is rejected
+        let mut ast = IntentAst::new();
+        ast.add_requirement("x is above 5 and below 3".to_string());
+        ast.requirements[0].constraints = vec![
+            atom("x", ConstraintOperator::GreaterThan, "5"),
+            atom("x", ConstraintOperator::LessThan, "3"),
+        ];
+        let scenario = exporter.export_scenario(&ast.requirements[0], &schema);
+        assert!(scenario.contains("| x | 2 |"));
+        assert!(scenario.contains("Then validate_intent returns false"));
+
+        ast.requirements[0].constraints = vec![
+            atom("role", ConstraintOperator::Equal, "\"admin\""),
+            atom("x", ConstraintOperator::NotEqual, "0"),
+        ];
+        let scenario = exporter.export_scenario(&ast.requirements[0], &schema);
+        assert!(scenario.contains("Then validate_intent returns true"));
+
+        ast.requirements[0].tree = Some(CompoundConstraint::Named("eligible".to_string()));
+        let scenario = exporter.export_scenario(&ast.requirements[0], &schema);
+        assert!(scenario.contains("Then validate_intent is defined"));
+        assert!(!scenario.contains("returns"));
+    }
+}
@@ -0,0 +1,566 @@
+//! JSON Schema Output
+//!
+//! Renders a Schema plus its constraint tree as a JSON Schema (draft 2020-12)
+//! document so the same intent can be enforced at the API gateway with
+//! off-the-shelf validators.
+//!
+//! Field-to-literal comparisons map onto `minimum`/`maximum`/`const`/`enum`
+//! (substring tests onto `pattern`), and the AND/OR/NOT structure onto `allOf`/`anyOf`/`not`. Comparisons
+//! between two fields cannot be expressed in JSON Schema; they are listed
+//! under `x-crucible-unenforced` so callers know to check them elsewhere.
+//! Where such a comparison sits under `not`, `if`, `anyOf` or `oneOf`, the whole
+//! enclosing rule is listed instead, since leaving out the comparison there
+//! would reject valid documents.
+//! Named predicates become `$defs` entries referenced with `$ref`.
+
+use crate::literal;
+use crucible_core::{
+    Call, CompoundConstraint, Constraint, ConstraintOperator, DataType, Definitions, Modulo, Schema,
+};
+use serde_json::{json, Map, Value};
+
+/// JSON Schema dialect emitted by the exporter
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Exports Schema + constraints as a JSON Schema document
+pub struct JsonSchemaExporter {
+    /// Used as the document `title`
+    pub title: String,
+    /// Named predicates, exported under `$defs`
+    pub definitions: Definitions,
+}
+
+impl JsonSchemaExporter {
+    /// Create an exporter with the given document title
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            definitions: Definitions::new(),
+        }
+    }
+
+    /// Resolve `CompoundConstraint::Named` references against `definitions`
+    pub fn with_definitions(mut self, definitions: Definitions) -> Self {
+        self.definitions = definitions;
+        self
+    }
+
+    /// Build the JSON Schema document
+    pub fn export(&self, compound: &CompoundConstraint, schema: &Schema) -> Value {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        let mut properties = Map::new();
+        for name in &names {
+            let mut property = type_schema(&schema.fields[*name]);
+            if let (Some(doc), Value::Object(obj)) = (schema.documentation.get(*name), &mut property) {
+                obj.insert("description".to_string(), json!(doc));
+            }
+            properties.insert((*name).clone(), property);
+        }
+
+        let mut unenforced = Vec::new();
+        let constraint_schema = self.translate(compound, schema, &mut unenforced, false).unwrap_or_else(|| json!({}));
+
+        let mut document = json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "title": self.title,
+            "type": "object",
+            "properties": properties,
+            "required": names,
+            "allOf": [constraint_schema],
+            "x-crucible-traceability-id": schema.traceability_id,
+        });
+
+        if !self.definitions.is_empty() {
+            let defs: Map<String, Value> = self
+                .definitions
+                .predicates
+                .iter()
+                .map(|(name, predicate)| {
+                    let value = self.translate(predicate, schema, &mut unenforced, false);
+                    (name.clone(), value.unwrap_or_else(|| json!({})))
+                })
+                .collect();
+            document["$defs"] = Value::Object(defs);
+        }
+
+        if !unenforced.is_empty() {
+            document["x-crucible-unenforced"] = json!(unenforced);
+        }
+
+        document
+    }
+
+    /// Build the document and render it as pretty-printed JSON
+    pub fn export_string(&self, compound: &CompoundConstraint, schema: &Schema) -> String {
+        serde_json::to_string_pretty(&self.export(compound, schema)).unwrap_or_default()
+    }
+
+    /// Translate `compound`, or `None` when part of it has no keyword.
+    ///
+    /// Where a rule is asserted as-is (`allOf`, `then`, `else`) a missing
+    /// part is left out as `{}`, which only loosens the rule. Under `not`,
+    /// `if`, `anyOf` and `oneOf` a looser part would reject valid documents, so those
+    /// translate their operands with `exact` set and, failing that, drop out
+    /// whole and are listed in `unenforced` in place of their operands.
+    fn translate(
+        &self,
+        compound: &CompoundConstraint,
+        schema: &Schema,
+        unenforced: &mut Vec<String>,
+        exact: bool,
+    ) -> Option<Value> {
+        match compound {
+            CompoundConstraint::Simple(c) => self.translate_simple(c, schema, unenforced, exact),
+            CompoundConstraint::Named(name) => {
+                if exact && !self.translates_exactly(compound, schema) {
+                    unenforced.push(name.clone());
+                    return None;
+                }
+                if self.definitions.get(name).is_none() {
+                    unenforced.push(format!("undefined predicate {}", name));
+                }
+                Some(json!({ "$ref": format!("#/$defs/{}", name) }))
+            }
+            CompoundConstraint::And(constraints) => {
+                let parts = constraints
+                    .iter()
+                    .map(|c| or_unconstrained(self.translate(c, schema, unenforced, exact), exact))
+                    .collect::<Option<Vec<Value>>>()?;
+                Some(json!({ "allOf": parts }))
+            }
+            CompoundConstraint::Or(constraints) => {
+                if let Some(value) = enum_shortcut(constraints, schema) {
+                    return Some(value);
+                }
+                let mark = unenforced.len();
+                match self.translate_all(constraints, schema, unenforced) {
+                    Some(parts) => Some(json!({ "anyOf": parts })),
+                    None => unenforceable(compound.to_string(), mark, unenforced),
+                }
+            }
+            CompoundConstraint::Not(inner) => {
+                let mark = unenforced.len();
+                match self.translate(inner, schema, unenforced, true) {
+                    Some(value) => Some(json!({ "not": value })),
+                    None => unenforceable(compound.to_string(), mark, unenforced),
+                }
+            }
+            CompoundConstraint::ExactlyOne(constraints) | CompoundConstraint::AtMostOne(constraints) => {
+                let mark = unenforced.len();
+                let Some(parts) = self.translate_all(constraints, schema, unenforced) else {
+                    return unenforceable(compound.to_string(), mark, unenforced);
+                };
+                Some(match compound {
+                    CompoundConstraint::ExactlyOne(_) => json!({ "oneOf": parts }),
+                    // One of them, or none at all
+                    _ => json!({ "anyOf": [{ "oneOf": parts }, { "not": { "anyOf": parts } }] }),
+                })
+            }
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                let mark = unenforced.len();
+                let Some(antecedent) = self.translate(antecedent, schema, unenforced, true) else {
+                    return unenforceable(compound.to_string(), mark, unenforced);
+                };
+                let consequent = or_unconstrained(self.translate(consequent, schema, unenforced, exact), exact)?;
+                Some(json!({ "if": antecedent, "then": consequent }))
+            }
+            // A list's elements are `uniqueItems`; no keyword compares fields
+            CompoundConstraint::Distinct(fields) => match fields.as_slice() {
+                [list] => Some(json!({
+                    "properties": { list.clone(): { "uniqueItems": true } },
+                    "required": [list],
+                })),
+                _ => {
+                    unenforced.push(compound.to_string());
+                    None
+                }
+            },
+        }
+    }
+
+    /// Translate every operand exactly, or `None` if any has no keyword
+    fn translate_all(
+        &self,
+        constraints: &[CompoundConstraint],
+        schema: &Schema,
+        unenforced: &mut Vec<String>,
+    ) -> Option<Vec<Value>> {
+        constraints.iter().map(|c| self.translate(c, schema, unenforced, true)).collect()
+    }
+
+    /// Whether a named predicate's `$defs` entry is its exact translation
+    fn translates_exactly(&self, named: &CompoundConstraint, schema: &Schema) -> bool {
+        self.definitions
+            .inline(named)
+            .ok()
+            .and_then(|predicate| self.translate(&predicate, schema, &mut Vec::new(), true))
+            .is_some()
+    }
+
+    fn translate_simple(
+        &self,
+        c: &Constraint,
+        schema: &Schema,
+        unenforced: &mut Vec<String>,
+        exact: bool,
+    ) -> Option<Value> {
+        if let Some(m) = c.modulo() {
+            return translate_modulo(c, &m, unenforced);
+        }
+        // `fee == 0 if member else 5` is `if`/`then`/`else` over the two branches
+        if let Some(conditional) = c.conditional() {
+            let branch = |value: &str| Constraint { right_value: value.to_string(), ..c.clone() };
+            let mark = unenforced.len();
+            let Some(condition) = self.translate_simple(&conditional.condition, schema, unenforced, true) else {
+                return unenforceable(c.to_string(), mark, unenforced);
+            };
+            let then = self.translate_simple(&branch(&conditional.then_value), schema, unenforced, exact);
+            let then = or_unconstrained(then, exact)?;
+            let otherwise = self.translate_simple(&branch(&conditional.else_value), schema, unenforced, exact);
+            let otherwise = or_unconstrained(otherwise, exact)?;
+            return Some(json!({ "if": condition, "then": then, "else": otherwise }));
+        }
+        // No keyword constrains a computed value or a field's bits
+        if Call::parse(&c.left_variable).is_some() || c.bitwise().is_some() {
+            unenforced.push(format!("{} {} {}", c.left_variable, operator_symbol(&c.operator), c.right_value));
+            return None;
+        }
+        let Some(literal) = literal_value(&c.right_value, schema) else {
+            unenforced.push(format!(
+                "{} {} {}",
+                c.left_variable,
+                operator_symbol(&c.operator),
+                c.right_value
+            ));
+            return None;
+        };
+
+        let keyword = match (&c.operator, &literal) {
+            (ConstraintOperator::GreaterThanOrEqual, Value::Number(_)) => json!({ "minimum": literal }),
+            (ConstraintOperator::GreaterThan, Value::Number(_)) => json!({ "exclusiveMinimum": literal }),
+            (ConstraintOperator::LessThanOrEqual, Value::Number(_)) => json!({ "maximum": literal }),
+            (ConstraintOperator::LessThan, Value::Number(_)) => json!({ "exclusiveMaximum": literal }),
+            (ConstraintOperator::Equal, _) => json!({ "const": literal }),
+            (ConstraintOperator::NotEqual, _) => json!({ "not": { "const": literal } }),
+            (ConstraintOperator::Contains, Value::String(s)) => json!({ "pattern": escape_pattern(s) }),
+            (ConstraintOperator::DoesNotContain, Value::String(s)) => {
+                json!({ "not": { "pattern": escape_pattern(s) } })
+            }
+            // Ordering against a non-numeric literal has no JSON Schema keyword
+            _ => {
+                unenforced.push(format!(
+                    "{} {} {}",
+                    c.left_variable,
+                    operator_symbol(&c.operator),
+                    c.right_value
+                ));
+                return None;
+            }
+        };
+
+        Some(json!({
+            "properties": { c.left_variable.clone(): keyword },
+            "required": [c.left_variable],
+        }))
+    }
+}
+
+/// A part with no keyword, left out as `{}` unless it must be `exact`
+fn or_unconstrained(value: Option<Value>, exact: bool) -> Option<Value> {
+    match value {
+        None if !exact => Some(json!({})),
+        _ => value,
+    }
+}
+
+/// Drop a construct whose operands cannot all be translated, listing it in
+/// place of whatever its operands recorded since `mark`
+fn unenforceable(rule: String, mark: usize, unenforced: &mut Vec<String>) -> Option<Value> {
+    unenforced.truncate(mark);
+    unenforced.push(rule);
+    None
+}
+
+/// `x % n == 0` (or `!= 0`) with a literal divisor becomes `multipleOf`;
+/// other remainders have no keyword
+fn translate_modulo(c: &Constraint, m: &Modulo, unenforced: &mut Vec<String>) -> Option<Value> {
+    let divisor = m.divisor.parse::<i64>().ok().filter(|d| *d != 0).map(i64::unsigned_abs);
+    let keyword = match (&c.operator, divisor, c.right_value.trim()) {
+        (ConstraintOperator::Equal, Some(d), "0") => json!({ "multipleOf": d }),
+        (ConstraintOperator::NotEqual, Some(d), "0") => json!({ "not": { "multipleOf": d } }),
+        _ => {
+            unenforced.push(format!("{} {} {}", c.left_variable, operator_symbol(&c.operator), c.right_value));
+            return None;
+        }
+    };
+
+    Some(json!({
+        "properties": { m.dividend.clone(): keyword },
+        "required": [m.dividend],
+    }))
+}
+
+/// `x == a || x == b || ...` on the same field becomes `enum: [a, b, ...]`
+fn enum_shortcut(constraints: &[CompoundConstraint], schema: &Schema) -> Option<Value> {
+    let mut field: Option<&str> = None;
+    let mut values = Vec::new();
+
+    for c in constraints {
+        let CompoundConstraint::Simple(simple) = c else { return None };
+        if simple.operator != ConstraintOperator::Equal
+            || simple.modulo().is_some()
+            || Call::parse(&simple.left_variable).is_some()
+            || simple.bitwise().is_some()
+        {
+            return None;
+        }
+        if field.is_some_and(|f| f != simple.left_variable) {
+            return None;
+        }
+        field = Some(&simple.left_variable);
+        values.push(literal_value(&simple.right_value, schema)?);
+    }
+
+    let field = field?;
+    Some(json!({
+        "properties": { field: { "enum": values } },
+        "required": [field],
+    }))
+}
+
+/// Interpret a right-hand value as a JSON literal, or `None` for a field reference
+fn literal_value(value: &str, schema: &Schema) -> Option<Value> {
+    if let Ok(i) = value.parse::<i64>() {
+        return Some(json!(i));
+    }
+    if let Ok(f) = value.parse::<f64>() {
+        return Some(json!(f));
+    }
+    match value {
+        "true" => return Some(json!(true)),
+        "false" => return Some(json!(false)),
+        _ => {}
+    }
+    if let Some(text) = literal::unquote(value) {
+        return Some(json!(text));
+    }
+    if schema.fields.contains_key(value) {
+        return None;
+    }
+    // Bare words that are not schema fields are enum-style string literals
+    Some(json!(value))
+}
+
+fn type_schema(dt: &DataType) -> Value {
+    match dt {
+        DataType::Uint64 => json!({ "type": "integer", "minimum": 0, "maximum": u64::MAX }),
+        DataType::Uint32 => json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX }),
+        DataType::Int64 => json!({ "type": "integer", "minimum": i64::MIN, "maximum": i64::MAX }),
+        DataType::Int32 => json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX }),
+        DataType::String => json!({ "type": "string" }),
+        DataType::Bool => json!({ "type": "boolean" }),
+        DataType::Decimal => json!({ "type": "number" }),
+        DataType::List(element) => json!({ "type": "array", "items": type_schema(element) }),
+        DataType::Custom { name, range_min, range_max } => {
+            let mut obj = Map::new();
+            obj.insert("title".to_string(), json!(name));
+            if range_min.is_some() || range_max.is_some() {
+                obj.insert("type".to_string(), json!("integer"));
+            }
+            // JSON numbers beyond i64/u64 are not portable, so clamp i128 bounds
+            if let Some(min) = range_min {
+                obj.insert("minimum".to_string(), json!((*min).clamp(i64::MIN as i128, i64::MAX as i128) as i64));
+            }
+            if let Some(max) = range_max {
+                obj.insert("maximum".to_string(), json!((*max).clamp(i64::MIN as i128, i64::MAX as i128) as i64));
+            }
+            Value::Object(obj)
+        }
+    }
+}
+
+/// ECMA-262 regex matching `literal` anywhere in a string
+fn escape_pattern(literal: &str) -> String {
+    let mut pattern = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if "\\^$.|?*+()[]{}/".contains(c) {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern
+}
+
+fn operator_symbol(op: &ConstraintOperator) -> &'static str {
+    match op {
+        ConstraintOperator::GreaterThanOrEqual => ">=",
+        ConstraintOperator::LessThanOrEqual => "<=",
+        ConstraintOperator::GreaterThan => ">",
+        ConstraintOperator::LessThan => "<",
+        ConstraintOperator::Equal => "==",
+        ConstraintOperator::NotEqual => "!=",
+        ConstraintOperator::Contains => "contains",
+        ConstraintOperator::DoesNotContain => "does not contain",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    fn sample_schema() -> Schema {
+        let mut schema = Schema::new("json-001".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, Some("Account balance".to_string()));
+        schema.add_field("amount".to_string(), DataType::Uint32, None);
+        schema.add_field("role".to_string(), DataType::String, None);
+        schema
+    }
+
+    #[test]
+    fn test_json_schema_bounds_and_types() {
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            simple("amount", ConstraintOperator::LessThanOrEqual, "10000"),
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+        ]);
+
+        let doc = JsonSchemaExporter::new("Withdrawal").export(&compound, &sample_schema());
+        assert_eq!(doc["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(doc["properties"]["balance"]["type"], "integer");
+        assert_eq!(doc["properties"]["balance"]["description"], "Account balance");
+        assert_eq!(doc["allOf"][0]["allOf"][0]["properties"]["amount"]["exclusiveMinimum"], 0);
+        assert_eq!(doc["allOf"][0]["allOf"][1]["properties"]["amount"]["maximum"], 10000);
+        assert_eq!(doc["x-crucible-unenforced"][0], "balance >= amount");
+    }
+
+    #[test]
+    fn test_json_schema_enum_and_not() {
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Or(vec![
+                simple("role", ConstraintOperator::Equal, "\"admin\""),
+                simple("role", ConstraintOperator::Equal, "moderator"),
+            ]),
+            CompoundConstraint::Not(Box::new(simple("amount", ConstraintOperator::Equal, "13"))),
+        ]);
+
+        let doc = JsonSchemaExporter::new("Access").export(&compound, &sample_schema());
+        let parts = &doc["allOf"][0]["allOf"];
+        assert_eq!(parts[0]["properties"]["role"]["enum"], json!(["admin", "moderator"]));
+        assert_eq!(parts[1]["not"]["properties"]["amount"]["const"], 13);
+        assert!(doc.get("x-crucible-unenforced").is_none());
+    }
+
+    #[test]
+    fn test_json_schema_named_predicates() {
+        let mut definitions = Definitions::new();
+        definitions.define("positive_amount", simple("amount", ConstraintOperator::GreaterThan, "0")).unwrap();
+        let compound = CompoundConstraint::Not(Box::new(CompoundConstraint::Named("positive_amount".to_string())));
+
+        let doc = JsonSchemaExporter::new("Amount").with_definitions(definitions).export(&compound, &sample_schema());
+        assert_eq!(doc["allOf"][0]["not"]["$ref"], "#/$defs/positive_amount");
+        assert_eq!(doc["$defs"]["positive_amount"]["properties"]["amount"]["exclusiveMinimum"], 0);
+        assert!(doc.get("x-crucible-unenforced").is_none());
+    }
+
+    #[test]
+    fn test_json_schema_substring_pattern() {
+        let compound = CompoundConstraint::And(vec![
+            simple("role", ConstraintOperator::Contains, "\"ops.\""),
+            simple("role", ConstraintOperator::DoesNotContain, "\"guest\""),
+        ]);
+
+        let doc = JsonSchemaExporter::new("Role").export(&compound, &sample_schema());
+        let parts = &doc["allOf"][0]["allOf"];
+        assert_eq!(parts[0]["properties"]["role"]["pattern"], "ops\\.");
+        assert_eq!(parts[1]["properties"]["role"]["not"]["pattern"], "guest");
+    }
+
+    #[test]
+    fn test_json_schema_multiple_of() {
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint::multiple_of("amount", "-100")),
+            simple("amount % 7", ConstraintOperator::Equal, "3"),
+        ]);
+
+        let doc = JsonSchemaExporter::new("Amount").export(&compound, &sample_schema());
+        let parts = &doc["allOf"][0]["allOf"];
+        assert_eq!(parts[0]["properties"]["amount"]["multipleOf"], 100);
+        assert_eq!(parts[1], json!({}));
+    }
+
+    #[test]
+    fn test_json_schema_negated_cross_field() {
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            CompoundConstraint::Not(Box::new(simple("amount", ConstraintOperator::GreaterThan, "balance"))),
+            CompoundConstraint::Implies(
+                Box::new(simple("balance", ConstraintOperator::LessThan, "amount")),
+                Box::new(simple("role", ConstraintOperator::Equal, "admin")),
+            ),
+        ]);
+
+        let doc = JsonSchemaExporter::new("Withdrawal").export(&compound, &sample_schema());
+        let parts = &doc["allOf"][0]["allOf"];
+        assert_eq!(parts[0]["properties"]["amount"]["exclusiveMinimum"], 0);
+        // `not {}` would reject every document, so the whole rule is left out
+        assert_eq!(parts[1], json!({}));
+        assert_eq!(parts[2], json!({}));
+        assert_eq!(
+            doc["x-crucible-unenforced"],
+            json!(["NOT (amount > balance)", "(IF balance < amount THEN role == admin)"])
+        );
+    }
+
+    #[test]
+    fn test_json_schema_cardinality_cross_field() {
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::ExactlyOne(vec![
+                simple("amount", ConstraintOperator::GreaterThan, "balance"),
+                simple("role", ConstraintOperator::Equal, "admin"),
+            ]),
+            CompoundConstraint::AtMostOne(vec![
+                simple("amount", ConstraintOperator::Equal, "balance"),
+                simple("balance", ConstraintOperator::LessThan, "amount"),
+            ]),
+            CompoundConstraint::ExactlyOne(vec![
+                simple("role", ConstraintOperator::Equal, "admin"),
+                simple("amount", ConstraintOperator::Equal, "0"),
+            ]),
+        ]);
+
+        let doc = JsonSchemaExporter::new("Payment").export(&compound, &sample_schema());
+        let parts = &doc["allOf"][0]["allOf"];
+        assert_eq!(parts[0], json!({}));
+        assert_eq!(parts[1], json!({}));
+        assert_eq!(parts[2]["oneOf"][1]["properties"]["amount"]["const"], 0);
+        assert_eq!(
+            doc["x-crucible-unenforced"],
+            json!([
+                "exactly one of (amount > balance, role == admin)",
+                "at most one of (amount == balance, balance < amount)",
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_schema_conditional_value() {
+        let compound = simple("amount", ConstraintOperator::LessThanOrEqual, "100 if role == \"admin\" else 10");
+
+        let doc = JsonSchemaExporter::new("Limit").export(&compound, &sample_schema());
+        let rule = &doc["allOf"][0];
+        assert_eq!(rule["if"]["properties"]["role"]["const"], "admin");
+        assert_eq!(rule["then"]["properties"]["amount"]["maximum"], 100);
+        assert_eq!(rule["else"]["properties"]["amount"]["maximum"], 10);
+        assert!(doc.get("x-crucible-unenforced").is_none());
+    }
+}
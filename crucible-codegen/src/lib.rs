@@ -7,20 +7,69 @@
 //! Provisional Patent Application: 63/928,407
 //!
 //! Multi-language code generation with formal verification contracts.
-//! Supporting: Rust, TypeScript, Python, Solidity, SPARK/Ada, Zig, Elixir.
+//! Supporting: Rust, TypeScript, Python, Solidity, SPARK/Ada, Zig, Elixir,
+//! plus policy/data-plane targets (SQL, Rego, CUE, Cedar, WIT, eBPF C).
 //!
 //! ## Strategy-Based Model (v0.1.5)
 //! This module implements a `VerifiableStrategy` trait for each supported language.
 //! Every language must define how it expresses mathematical truths and runtime assertions.
 //! This ensures contract-first generation with formal proof traceability.
 
+use crucible_core::error::codes;
 use crucible_core::{
-    ArithmeticOperator, Constraint, ConstraintOperator, CompoundConstraint, DataType, Schema,
+    ArithmeticOperator, ArithmeticTerm, Between, Bitwise, BitwiseOperator, Call, Conditional, Constraint,
+    ConstraintArena, ConstraintError, ConstraintOperator, CompoundConstraint, CrucibleConfig, CrucibleError, DataType,
+    DefinitionError, Definitions, Diagnostic, FormatConfig, Glossary, HeaderPolicy, MathFunction, Node, NodeId,
+    OverflowRisk, Schema, MachineTransition, StateMachine, TypeRegistry, Verbalizer,
 };
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io::{self, Write as _};
 use serde::{Deserialize, Serialize};
+use bitwise::{wrapped, BitWidths};
+use literal::Operand;
+use stream::ArtifactWriter;
 use thiserror::Error;
 use uuid::Uuid;
 
+pub mod gherkin;
+pub mod json_schema;
+mod bitwise;
+mod cedar;
+mod cue;
+mod custom_types;
+mod ebpf;
+mod fault;
+mod foundry;
+mod format;
+mod literal;
+mod metadata;
+mod monitor;
+pub mod naming;
+mod overflow;
+pub mod provenance;
+mod regions;
+mod rego;
+mod slo;
+mod sql;
+mod state_machine;
+mod stream;
+mod telemetry;
+mod transition;
+mod warnings;
+mod wit;
+
+pub use format::format_code;
+pub use gherkin::GherkinExporter;
+pub use json_schema::JsonSchemaExporter;
+pub use metadata::{CodegenMetadata, GeneratedFunction};
+pub use naming::FieldNames;
+pub use provenance::Provenance;
+pub use regions::{ProtectedFile, Region, RegionError};
+pub use sql::SqlDialect;
+pub use warnings::{CodegenWarning, TypeLoss};
+pub use wit::WIT_PATH;
+
 /// Errors that can occur during code generation
 #[derive(Debug, Error)]
 pub enum CodegenError {
@@ -32,6 +81,58 @@ pub enum CodegenError {
 
     #[error("Generation error: {0}")]
     GenerationError(String),
+
+    #[error("Definition error: {0}")]
+    Definition(#[from] DefinitionError),
+
+    #[error("Invalid constraint: {0}")]
+    InvalidConstraint(#[from] ConstraintError),
+
+    #[error("fields `{}` and `{}` both become `{name}` in {language}; rename one in the Schema", fields.0, fields.1)]
+    FieldNameCollision {
+        language: String,
+        name: String,
+        fields: (String, String),
+    },
+
+    #[error("`{name}` is not a valid identifier in {language}: {reason}")]
+    InvalidIdentifier {
+        language: String,
+        name: String,
+        reason: String,
+    },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+impl From<CodegenError> for CrucibleError {
+    fn from(error: CodegenError) -> Self {
+        let diagnostic = |code| Diagnostic::new(code, error.to_string());
+        CrucibleError::Codegen(match &error {
+            CodegenError::Definition(e) => return e.clone().into(),
+            CodegenError::InvalidConstraint(e) => return e.clone().into(),
+            CodegenError::UnsupportedLanguage(language) => {
+                diagnostic(codes::UNSUPPORTED_LANGUAGE).with_arg("language", language)
+            }
+            CodegenError::MissingContract(language) => {
+                diagnostic(codes::MISSING_CONTRACT).with_arg("language", language)
+            }
+            CodegenError::FieldNameCollision { language, name, fields } => diagnostic(codes::IDENTIFIER)
+                .with_arg("problem", "collision")
+                .with_arg("language", language)
+                .with_arg("name", name)
+                .with_arg("first", &fields.0)
+                .with_arg("second", &fields.1),
+            CodegenError::InvalidIdentifier { language, name, reason } => diagnostic(codes::IDENTIFIER)
+                .with_arg("problem", "invalid")
+                .with_arg("language", language)
+                .with_arg("name", name)
+                .with_arg("reason", reason),
+            CodegenError::GenerationError(detail) => diagnostic(codes::GENERATION).with_arg("detail", detail),
+            CodegenError::Io(e) => diagnostic(codes::IO).with_arg("detail", e),
+        })
+    }
 }
 
 /// Supported output languages
@@ -44,6 +145,66 @@ pub enum TargetLanguage {
     SparkAda, // High-integrity formal verification (MIL-SPEC)
     Zig,      // Memory-safe systems programming
     Elixir,   // Fault-tolerant distributed logic
+    Sql(SqlDialect), // Database CHECK constraints
+    Rego,     // Open Policy Agent policies
+    Cue,      // CUE configuration constraints
+    Cedar,    // AWS Cedar authorization policies
+    Wit,      // WebAssembly Component Model validators
+    EbpfC,    // Loop-free C for the eBPF verifier
+}
+
+impl TargetLanguage {
+    /// Look up a language by its command-line name (`rust`, `ts`, `sql-postgres`, ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => TargetLanguage::Rust,
+            "typescript" | "ts" => TargetLanguage::TypeScript,
+            "python" | "py" => TargetLanguage::Python,
+            "solidity" | "sol" => TargetLanguage::Solidity,
+            "spark" | "ada" | "spark-ada" => TargetLanguage::SparkAda,
+            "zig" => TargetLanguage::Zig,
+            "elixir" | "ex" => TargetLanguage::Elixir,
+            "sql" | "postgres" | "sql-postgres" => TargetLanguage::Sql(SqlDialect::Postgres),
+            "mysql" | "sql-mysql" => TargetLanguage::Sql(SqlDialect::MySql),
+            "sqlite" | "sql-sqlite" => TargetLanguage::Sql(SqlDialect::Sqlite),
+            "rego" | "opa" => TargetLanguage::Rego,
+            "cue" => TargetLanguage::Cue,
+            "cedar" => TargetLanguage::Cedar,
+            "wit" => TargetLanguage::Wit,
+            "ebpf" | "ebpf-c" => TargetLanguage::EbpfC,
+            _ => return None,
+        })
+    }
+
+    /// The config's target languages, in order
+    pub fn configured(config: &CrucibleConfig) -> Result<Vec<TargetLanguage>, CodegenError> {
+        config
+            .codegen
+            .targets
+            .iter()
+            .map(|name| Self::from_name(name).ok_or_else(|| CodegenError::UnsupportedLanguage(name.clone())))
+            .collect()
+    }
+
+    /// Extension of the main generated file
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            TargetLanguage::Rust => "rs",
+            TargetLanguage::TypeScript => "ts",
+            TargetLanguage::Python => "py",
+            TargetLanguage::Solidity => "sol",
+            // Spec and body together, for `gnatchop`
+            TargetLanguage::SparkAda => "ada",
+            TargetLanguage::Zig => "zig",
+            TargetLanguage::Elixir => "ex",
+            TargetLanguage::Sql(_) => "sql",
+            TargetLanguage::Rego => "rego",
+            TargetLanguage::Cue => "cue",
+            TargetLanguage::Cedar => "cedar",
+            TargetLanguage::Wit => "wit",
+            TargetLanguage::EbpfC => "c",
+        }
+    }
 }
 
 /// Code generation result
@@ -52,6 +213,169 @@ pub struct CodegenOutput {
     pub language: TargetLanguage,
     pub code: String,
     pub constraints_count: usize,
+    /// Companion artifacts (test suites, project files) emitted alongside `code`
+    #[serde(default)]
+    pub files: Vec<GeneratedFile>,
+    /// Schema fields the target represents with different semantics
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<CodegenWarning>,
+    /// Functions, field types and per-constraint code of `code`
+    #[serde(default)]
+    pub metadata: CodegenMetadata,
+    /// Input hash, solver and crate versions, also written at the top of `code`
+    #[serde(flatten)]
+    pub provenance: Provenance,
+}
+
+/// Result of `generate_to`: a `CodegenOutput` whose code went to the writer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamedOutput {
+    pub language: TargetLanguage,
+    /// Size of the code written
+    pub bytes: u64,
+    pub constraints_count: usize,
+    #[serde(default)]
+    pub files: Vec<GeneratedFile>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<CodegenWarning>,
+    #[serde(default)]
+    pub metadata: CodegenMetadata,
+    #[serde(flatten)]
+    pub provenance: Provenance,
+}
+
+impl StreamedOutput {
+    /// The in-memory output, given the code that was written
+    fn with_code(self, code: Vec<u8>) -> CodegenOutput {
+        CodegenOutput {
+            language: self.language,
+            // Every piece written is a `&str`
+            code: String::from_utf8(code).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()),
+            constraints_count: self.constraints_count,
+            files: self.files,
+            warnings: self.warnings,
+            metadata: self.metadata,
+            provenance: self.provenance,
+        }
+    }
+}
+
+/// An additional generated file, addressed by a path relative to the output directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedFile {
+    pub path: String,
+    pub contents: String,
+}
+
+/// Naming and output options shared by all strategies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CodegenOptions {
+    /// Name of the generated validator function (or policy rule)
+    pub func_name: String,
+    /// Package/module name for languages that have one (e.g. Rego `package`)
+    pub package_name: Option<String>,
+    /// Project glossary; variables are renamed to their canonical terms
+    #[serde(default)]
+    pub glossary: Option<Glossary>,
+    /// Rust: emit `#![no_std]` code for embedded targets (core only, no
+    /// `String`, `const fn` validators where the field types allow it)
+    #[serde(default)]
+    pub no_std: bool,
+    /// Python: emit a Pydantic v2 model whose field and model validators
+    /// enforce the constraints
+    #[serde(default)]
+    pub pydantic: bool,
+    /// TypeScript: emit a Zod schema (`z.object(...).refine(...)`) and its
+    /// inferred type instead of a `Validator` class
+    #[serde(default)]
+    pub zod: bool,
+    /// TypeScript: type 64-bit integer fields as `bigint` (exact beyond
+    /// 2^53) and parse them from JSON decimal strings
+    #[serde(default)]
+    pub bigint: bool,
+    /// Rust, TypeScript, Python: follow the validator with a `monitor_`
+    /// wrapper that counts and reports each violated constraint at runtime
+    #[serde(default)]
+    pub monitor: bool,
+    /// Rust, TypeScript, Python: follow the validator with a `traced_`
+    /// wrapper that checks the constraints inside an OpenTelemetry span
+    #[serde(default)]
+    pub telemetry: bool,
+    /// ID of the requirement the constraints come from, naming the
+    /// telemetry span; the tree's canonical hash when unset
+    #[serde(default)]
+    pub requirement_id: Option<String>,
+    /// Named predicates the constraints may reference; emitted as helper
+    /// functions where the target has them, inlined everywhere else
+    #[serde(default)]
+    pub definitions: Option<Definitions>,
+    /// Solver that verified the input (e.g. `Z3Verifier::solver_version()`),
+    /// recorded in the provenance header
+    #[serde(default)]
+    pub solver_version: Option<String>,
+    /// Leave the timestamp out of the provenance, so identical input gives
+    /// byte-identical output
+    #[serde(default)]
+    pub reproducible: bool,
+    /// Target spellings and declarations of the Schema's custom types
+    #[serde(default, skip_serializing_if = "TypeRegistry::is_empty")]
+    pub types: TypeRegistry,
+    /// Terms that can overflow the target's integers (from
+    /// `Z3Verifier::overflow_risks`); Rust checks them and TypeScript widens
+    /// them to `BigInt`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overflow_risks: Vec<OverflowRisk>,
+    /// Run the generated code through the target's formatter
+    #[serde(default, skip_serializing_if = "FormatConfig::is_default")]
+    pub format: FormatConfig,
+    /// Banner opening the generated code, in place of the built-in one
+    #[serde(default, skip_serializing_if = "HeaderPolicy::is_default")]
+    pub header: HeaderPolicy,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            func_name: "validate_intent".to_string(),
+            package_name: None,
+            glossary: None,
+            no_std: false,
+            pydantic: false,
+            zod: false,
+            bigint: false,
+            monitor: false,
+            telemetry: false,
+            requirement_id: None,
+            definitions: None,
+            solver_version: None,
+            reproducible: false,
+            types: TypeRegistry::default(),
+            overflow_risks: Vec::new(),
+            format: FormatConfig::default(),
+            header: HeaderPolicy::Default,
+        }
+    }
+}
+
+impl CodegenOptions {
+    /// Naming, output profiles and reproducibility from the engine config
+    pub fn from_config(config: &CrucibleConfig) -> Self {
+        Self {
+            func_name: config.naming.function.clone(),
+            package_name: config.naming.package.clone(),
+            no_std: config.features.no_std,
+            pydantic: config.features.pydantic,
+            zod: config.features.zod,
+            bigint: config.features.bigint,
+            monitor: config.features.monitor,
+            telemetry: config.features.telemetry,
+            reproducible: config.codegen.reproducible,
+            types: config.codegen.types.clone(),
+            format: config.codegen.format.clone(),
+            header: config.codegen.header.clone(),
+            ..Self::default()
+        }
+    }
 }
 
 /// Information about a constraint for contract generation
@@ -73,6 +397,269 @@ trait CodegenStrategy {
     fn logical_or(&self) -> &'static str;
     fn logical_not(&self, expr: &str) -> String;
 
+    /// Render a single comparison; override when operands need per-language treatment
+    fn format_comparison(&self, constraint: &Constraint) -> String {
+        if constraint.operator.is_membership() {
+            return self.format_membership_comparison(constraint);
+        }
+        if let Some(conditional) = constraint.conditional() {
+            if self.format_conditional_expression(&conditional).is_none() {
+                return self.format_conditional_cases(constraint, &conditional);
+            }
+        }
+        let checked = ArithmeticTerm::parse(&constraint.right_value)
+            .and_then(|term| self.format_overflow_checked(constraint, &term));
+        if let Some(checked) = checked {
+            return checked;
+        }
+        self.format_relation(
+            constraint,
+            &self.format_left_operand(constraint),
+            &self.format_right_operand(&constraint.right_value),
+        )
+    }
+
+    /// `left op right` over rendered operands; override where the operator
+    /// depends on the operand types
+    fn format_relation(&self, constraint: &Constraint, left: &str, right: &str) -> String {
+        format!("{} {} {}", left, self.format_operator(&constraint.operator), right)
+    }
+
+    /// A comparison with an `a op b` right side that can overflow the
+    /// target's integers (`CodegenOptions::overflow_risks`), computed with a
+    /// checked or widened operation; `None` keeps the plain one
+    fn format_overflow_checked(&self, _constraint: &Constraint, _term: &ArithmeticTerm) -> Option<String> {
+        None
+    }
+
+    /// Right side of a comparison: a conditional or a call, code with its
+    /// fields through `format_variable`, text as a string literal
+    fn format_right_operand(&self, value: &str) -> String {
+        if let Some(expression) = Conditional::parse(value).and_then(|c| self.format_conditional_expression(&c)) {
+            return expression;
+        }
+        if let Some(call) = Call::parse(value) {
+            return self.format_call_expression(&call);
+        }
+        match literal::operand(value) {
+            Operand::Code(code) => literal::map_fields(code, |name| self.format_variable(name)),
+            Operand::Text(text) => self.format_string(&text),
+        }
+    }
+
+    /// A string literal holding exactly `text`; the default is a JSON string
+    fn format_string(&self, text: &str) -> String {
+        literal::json_string(text)
+    }
+
+    /// Left side of a comparison: the variable, a `dividend % divisor` or
+    /// `operand & mask` term or a call
+    fn format_left_operand(&self, constraint: &Constraint) -> String {
+        let operand = |value: &str| match is_identifier(value) {
+            true => self.format_variable(value),
+            false => value.to_string(),
+        };
+        match (constraint.modulo(), constraint.bitwise()) {
+            (Some(m), _) => self.format_modulo(&operand(&m.dividend), &operand(&m.divisor)),
+            (None, Some(b)) => self.format_bitwise(&b, &operand(&b.operand), &operand(&b.mask)),
+            (None, None) => match Call::parse(&constraint.left_variable) {
+                Some(call) => self.format_call_expression(&call),
+                None => self.format_variable(&constraint.left_variable),
+            },
+        }
+    }
+
+    /// Euclidean remainder (never negative); override where `%` truncates
+    /// toward zero and so follows the sign of the dividend
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("({} % {})", dividend, divisor)
+    }
+
+    /// `bitwise` over its rendered operand and mask. The default is the
+    /// C-family operator, exact where fields have their Schema types; see
+    /// `bitwise` for the targets that wrap the result to the field's width
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        format!("({} {} {})", operand, bitwise.operator.symbol(), mask)
+    }
+
+    /// Whether the target has bitwise operators for `format_bitwise`;
+    /// generation rejects masks and shifts where it has not
+    fn supports_bitwise(&self) -> bool {
+        true
+    }
+
+    /// `call` with each argument rendered by `format_term`
+    fn format_call_expression(&self, call: &Call) -> String {
+        let args: Vec<String> = call.args.iter().map(|arg| self.format_term(arg)).collect();
+        self.format_call(call.function, &args)
+    }
+
+    /// A call argument or conditional branch: fields through
+    /// `format_variable`, `a op b` parenthesized, calls recursively
+    fn format_term(&self, term: &str) -> String {
+        let operand = |value: &str| match is_identifier(value) {
+            true => self.format_variable(value),
+            false => value.to_string(),
+        };
+        if let Some(call) = Call::parse(term) {
+            return self.format_call_expression(&call);
+        }
+        match term.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [left, op, right] => match ArithmeticOperator::from_symbol(op) {
+                Some(ArithmeticOperator::Modulo) => self.format_modulo(&operand(left), &operand(right)),
+                Some(op) => format!("({} {} {})", operand(left), op.symbol(), operand(right)),
+                None => term.to_string(),
+            },
+            _ => operand(term),
+        }
+    }
+
+    /// `min`/`max` over two or more rendered arguments, `abs` and the
+    /// aggregates over one. The default calls functions of the same name,
+    /// nested beyond two arguments: `max(max(a, b), c)`
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min | MathFunction::Max => {
+                fold_pairs(args, |a, b| format!("{}({}, {})", function.name(), a, b))
+            }
+            _ => format!("{}({})", function.name(), args.join(", ")),
+        }
+    }
+
+    /// Whether the target has list fields for `sum`, `count` and `average` to
+    /// reduce; generation rejects both where it has not
+    fn supports_lists(&self) -> bool {
+        false
+    }
+
+    /// `distinct` over rendered fields, or over the elements of one list
+    /// field when `values` has one entry. `None` compares each pair of
+    /// fields with `!=` instead
+    fn format_distinct(&self, _values: &[String]) -> Option<String> {
+        None
+    }
+
+    /// `conditional` with its condition through `format_comparison` and its
+    /// branches through `format_term` (text as a string literal, a nested
+    /// conditional recursively); `None` where `format_conditional` is
+    fn format_conditional_expression(&self, conditional: &Conditional) -> Option<String> {
+        let branch = |value: &str| match Conditional::parse(value) {
+            Some(nested) => self.format_conditional_expression(&nested),
+            None if literal::unquote(value).is_some() => Some(self.format_right_operand(value)),
+            None => Some(self.format_term(value)),
+        };
+        let condition = self.format_comparison(&conditional.condition);
+        self.format_conditional(&condition, &branch(&conditional.then_value)?, &branch(&conditional.else_value)?)
+    }
+
+    /// `then_value` when `condition` holds, `else_value` otherwise. The
+    /// default is C's `?:`; `None` where the target has no conditional
+    /// expression, and comparisons go through `format_conditional_cases`
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(format!("({} ? {} : {})", condition, then_value, else_value))
+    }
+
+    /// `left op (then if condition else otherwise)` spelled out as
+    /// `Conditional::cases`: `(condition and left op then) or (not condition and left op otherwise)`
+    fn format_conditional_cases(&self, constraint: &Constraint, conditional: &Conditional) -> String {
+        let condition = self.format_comparison(&conditional.condition);
+        let case = |value: &String| {
+            self.format_comparison(&Constraint { right_value: value.clone(), ..constraint.clone() })
+        };
+        format!(
+            "(({} {and} {}) {} ({} {and} {}))",
+            condition,
+            case(&conditional.then_value),
+            self.logical_or(),
+            self.logical_not(&condition),
+            case(&conditional.else_value),
+            and = self.logical_and()
+        )
+    }
+
+    /// Substring test: does the string `haystack` contain `needle`
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("{}.contains({})", haystack, needle)
+    }
+
+    /// `Contains`/`DoesNotContain` through `format_membership`, negated with `logical_not`
+    fn format_membership_comparison(&self, constraint: &Constraint) -> String {
+        let test = self.format_membership(
+            &self.format_variable(&constraint.left_variable),
+            &self.format_right_operand(&constraint.right_value),
+        );
+        match constraint.operator {
+            ConstraintOperator::DoesNotContain => self.logical_not(&test),
+            _ => test,
+        }
+    }
+
+    /// `if antecedent then consequent`, spelled `!antecedent || consequent`
+    /// (marked with `inline_comment`) where the language has no implication
+    fn format_implication(&self, antecedent: &str, consequent: &str) -> String {
+        let expression = format!("({} {} {})", self.logical_not(antecedent), self.logical_or(), consequent);
+        match self.inline_comment("implication") {
+            Some(comment) => format!("{} {}", comment, expression),
+            None => expression,
+        }
+    }
+
+    /// How many of the rendered conditions hold, where the language can
+    /// count booleans; `None` falls back to a pairwise encoding
+    fn format_count(&self, _parts: &[String]) -> Option<String> {
+        None
+    }
+
+    /// `ExactlyOne` (when `exact`) or `AtMostOne` over rendered conditions:
+    /// a count compared with 1, or else "no two hold" (plus "one holds")
+    fn format_cardinality(&self, parts: &[String], exact: bool) -> String {
+        if let Some(count) = self.format_count(parts) {
+            let operator = if exact { ConstraintOperator::Equal } else { ConstraintOperator::LessThanOrEqual };
+            return format!("({} {} 1)", count, self.format_operator(&operator));
+        }
+
+        let and = format!(" {} ", self.logical_and());
+        let mut clauses = Vec::new();
+        if exact {
+            clauses.push(format!("({})", parts.join(&format!(" {} ", self.logical_or()))));
+        }
+        for (i, first) in parts.iter().enumerate() {
+            for second in &parts[i + 1..] {
+                clauses.push(self.logical_not(&format!("{}{}{}", first, and, second)));
+            }
+        }
+        format!("({})", clauses.join(&and))
+    }
+
+    /// A comment that can sit inside an expression; `None` for languages
+    /// with line comments only
+    fn inline_comment(&self, _text: &str) -> Option<String> {
+        None
+    }
+
+    /// A reference to the named predicate `name`, calling its helper
+    fn format_predicate_call(&self, name: &str) -> String {
+        format!("{}(params)", name)
+    }
+
+    /// Helper function evaluating a named predicate (`body` is the rendered
+    /// definition), emitted once after the validator; `None` inlines the
+    /// definition at every reference instead
+    fn predicate_helper(&self, _name: &str, _body: &str) -> Option<String> {
+        None
+    }
+
+    /// Render a `Between` range; override where the language has range syntax
+    fn format_range(&self, range: &Between) -> String {
+        let (lower, upper) = range.bounds();
+        format!(
+            "({} {} {})",
+            self.format_comparison(&lower),
+            self.logical_and(),
+            self.format_comparison(&upper)
+        )
+    }
+
     /// Formal Verification Hook: How the language handles "Assertions" or "Contracts"
     fn wrap_assertion(&self, condition: &str) -> String {
         format!("assert({});", condition)
@@ -111,7 +698,7 @@ trait CodegenStrategy {
     }
 
     /// Generate guard-compatible expression (for languages like Elixir)
-    fn to_guard_expression(&self, compound: &CompoundConstraint) -> Option<String> {
+    fn to_guard_expression(&self, _compound: &CompoundConstraint) -> Option<String> {
         None
     }
 
@@ -119,6 +706,118 @@ trait CodegenStrategy {
     fn compile_error(&self, message: &str) -> String {
         format!("@compileError(\"{}\");", message)
     }
+
+    /// Post-state variable of a transition (`balance'`)
+    fn format_next_variable(&self, name: &str) -> String {
+        format!("next.{}", name)
+    }
+
+    /// State-update function for a transition: check `guard`, apply the
+    /// `(field, value)` assignments to a copy of the state, check `post_check`.
+    /// `None` for targets that only validate.
+    fn wrap_state_update(
+        &self,
+        _func_name: &str,
+        _guard: &str,
+        _assignments: &[(String, String)],
+        _post_check: Option<&str>,
+    ) -> Option<String> {
+        None
+    }
+
+    /// State machine over the lifecycle of a subject; `guards` holds the
+    /// rendered guard of each transition, in order. `None` for targets
+    /// without a state-machine idiom.
+    fn emit_state_machine(&self, _machine: &StateMachine, _guards: &[Option<String>]) -> Option<String> {
+        None
+    }
+
+    /// Companion files (e.g. test suites) generated next to the main artifact
+    fn companion_files(&self, _compound: &CompoundConstraint, _func_name: &str) -> Vec<GeneratedFile> {
+        Vec::new()
+    }
+}
+
+/// Renders variables as bare names (`balance` rather than `params.balance`),
+/// for reference expressions over destructured or fuzzed arguments
+pub(crate) struct BareVariables<'a>(pub(crate) &'a dyn CodegenStrategy);
+
+impl CodegenStrategy for BareVariables<'_> {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.0.wrap_in_function(body, func_name)
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        self.0.format_operator(op)
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        self.0.format_membership(haystack, needle)
+    }
+
+    fn format_relation(&self, constraint: &Constraint, left: &str, right: &str) -> String {
+        self.0.format_relation(constraint, left, right)
+    }
+
+    fn format_string(&self, text: &str) -> String {
+        self.0.format_string(text)
+    }
+
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        self.0.format_modulo(dividend, divisor)
+    }
+
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        self.0.format_bitwise(bitwise, operand, mask)
+    }
+
+    fn supports_bitwise(&self) -> bool {
+        self.0.supports_bitwise()
+    }
+
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        self.0.format_call(function, args)
+    }
+
+    fn supports_lists(&self) -> bool {
+        self.0.supports_lists()
+    }
+
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        self.0.format_distinct(values)
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        self.0.format_conditional(condition, then_value, else_value)
+    }
+
+    fn format_implication(&self, antecedent: &str, consequent: &str) -> String {
+        self.0.format_implication(antecedent, consequent)
+    }
+
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        self.0.format_count(parts)
+    }
+
+    fn logical_and(&self) -> &'static str {
+        self.0.logical_and()
+    }
+
+    fn logical_or(&self) -> &'static str {
+        self.0.logical_or()
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        self.0.logical_not(expr)
+    }
+
+    fn wrap_verified_function(&self, func_name: &str, contracts: &str, body: &str, assertions: &str) -> String {
+        self.0.wrap_verified_function(func_name, contracts, body, assertions)
+    }
 }
 
 // =============================================================================
@@ -143,28 +842,43 @@ trait VerifiableStrategy {
     /// Emit the end of a verified function
     fn fn_end(&self) -> String;
 
-    /// Generate license header with traceability ID
-    fn license_header(&self, traceability_id: &str) -> String;
+    /// The banner opening the Schema-typed artifact, from `provenance`
+    fn license_header(&self, provenance: &Provenance) -> String;
 
     /// Generate overflow-safe comparison for integer types
     fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String;
+
+    /// Companion files derived from the Schema (e.g. type declarations, schemas)
+    fn schema_files(&self, _func_name: &str, _schema: &Schema) -> Vec<GeneratedFile> {
+        Vec::new()
+    }
 }
 
 /// Default implementation for safe comparison
 fn default_safe_compare(left: &str, op: &ConstraintOperator, right: &str, _data_type: &DataType) -> String {
-    format!("{} {} {}", left, match op {
+    let symbol = match op {
         ConstraintOperator::GreaterThanOrEqual => ">=",
         ConstraintOperator::LessThanOrEqual => "<=",
         ConstraintOperator::GreaterThan => ">",
         ConstraintOperator::LessThan => "<",
         ConstraintOperator::Equal => "==",
         ConstraintOperator::NotEqual => "!=",
-    }, right)
+        ConstraintOperator::Contains => return format!("{}.contains({})", left, right),
+        ConstraintOperator::DoesNotContain => return format!("!{}.contains({})", left, right),
+    };
+    format!("{} {} {}", left, symbol, right)
 }
 
 // --- SPARK/Ada Strategy (MIL-SPEC Formal Verification) ---
 
-struct SparkAdaStrategy;
+/// SPARK output is a package: a spec with the record type and the
+/// contracts, and a body with the implementation
+struct SparkAdaStrategy {
+    /// Ada package name (e.g. `Validator`)
+    package: String,
+    /// Ada function name (e.g. `Validate_Intent`)
+    func_name: String,
+}
 
 impl CodegenStrategy for SparkAdaStrategy {
     fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
@@ -191,6 +905,8 @@ end {func_name};"#,
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "=",
             ConstraintOperator::NotEqual => "/=",
+            ConstraintOperator::Contains => "Index /= 0",
+            ConstraintOperator::DoesNotContain => "Index = 0",
         }
     }
 
@@ -198,6 +914,54 @@ end {func_name};"#,
         format!("Params.{}", to_ada_case(name))
     }
 
+    /// Ada has no escape sequences; see `literal::ada_string`
+    fn format_string(&self, text: &str) -> String {
+        literal::ada_string(text)
+    }
+
+    /// Ada `mod` takes the sign of the divisor
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("({} mod abs ({}))", dividend, divisor)
+    }
+
+    /// `and`, `or` and `xor` apply to modular types only, and the
+    /// parameters are signed integers
+    fn supports_bitwise(&self) -> bool {
+        false
+    }
+
+    /// `abs Integer'First` raises `Constraint_Error`, which the SPARK proof
+    /// either rules out from the preconditions or reports
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min => fold_pairs(args, |a, b| format!("Integer'Min ({}, {})", a, b)),
+            MathFunction::Max => fold_pairs(args, |a, b| format!("Integer'Max ({}, {})", a, b)),
+            MathFunction::Abs => format!("(abs {})", args.join(", ")),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        }
+    }
+
+    /// Ada 2012 conditional expression
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(format!("(if {} then {} else {})", condition, then_value, else_value))
+    }
+
+    /// Needs `with Ada.Strings.Fixed;`, which `package_source` adds on use
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("Ada.Strings.Fixed.Index ({}, {}) /= 0", haystack, needle)
+    }
+
+    /// Inclusive ranges use a membership test: `Params.Amount in 1 .. 100`
+    fn format_range(&self, range: &Between) -> String {
+        if !range.inclusive {
+            let (lower, upper) = range.bounds();
+            return format!("({} and then {})", self.format_comparison(&lower), self.format_comparison(&upper));
+        }
+        format!("({} in {} .. {})", self.format_variable(&range.variable), range.low, range.high)
+    }
+
     fn logical_and(&self) -> &'static str {
         "and then"
     }
@@ -210,6 +974,16 @@ end {func_name};"#,
         format!("not ({})", expr)
     }
 
+    /// Ada 2012 conditional expression: `(if A then B)` is True when A is False
+    fn format_implication(&self, antecedent: &str, consequent: &str) -> String {
+        format!("(if {} then {})", antecedent, consequent)
+    }
+
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        let terms: Vec<String> = parts.iter().map(|p| format!("Boolean'Pos ({})", p)).collect();
+        Some(format!("({})", terms.join(" + ")))
+    }
+
     fn wrap_assertion(&self, condition: &str) -> String {
         format!("pragma Assert ({});", condition)
     }
@@ -222,117 +996,202 @@ end {func_name};"#,
         Some(format!("Post => {}", condition))
     }
 
+    /// Spec declarations: the record type (every field `Integer` without a
+    /// Schema) and the function with its `Pre`/`Post` aspects
     fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
-        let preconditions = self.extract_preconditions(compound);
-        let postcondition = self.build_postcondition(compound);
-
-        if preconditions.is_empty() && postcondition.is_none() {
-            return None;
-        }
-
-        let mut contracts = String::new();
-        contracts.push_str("   with\n");
-
-        let mut first = true;
-        for pre in &preconditions {
-            if !first {
-                contracts.push_str(",\n");
-            }
-            contracts.push_str(&format!("        {}", pre));
-            first = false;
-        }
-
-        if let Some(post) = postcondition {
-            if !first {
-                contracts.push_str(",\n");
-            }
-            contracts.push_str(&format!("        {}", post));
-        }
-
-        Some(contracts)
+        let fields: Vec<(String, String)> = inferred_fields(compound)
+            .into_iter()
+            .map(|(v, dt)| match dt {
+                DataType::String => (v, self.map_type(&dt)),
+                _ => (v, "Integer".to_string()),
+            })
+            .collect();
+        Some(format!("{}\n{}", self.record_type(&fields), self.function_declaration(compound)))
     }
 
+    /// Without a precondition, asserting each conjunct in the body would
+    /// fail on the inputs the validator rejects, so `_assertions` are left out
     fn wrap_verified_function(
         &self,
-        func_name: &str,
+        _func_name: &str,
         contracts: &str,
         body: &str,
-        assertions: &str,
+        _assertions: &str,
     ) -> String {
-        let assertions_block = if !assertions.is_empty() {
-            format!("   -- Runtime assertion checks\n   {}\n", assertions)
-        } else {
-            String::new()
-        };
-
         format!(
             r#"-- SPARK/Ada Generated Code - Formally Verifiable
+-- Use GNATprove for mathematical verification: `gnatprove -P {project}.gpr --level=4`
+-- Spec and body follow each other; `gnatchop` splits them into {project}.ads/.adb
+
+{package}"#,
+            project = self.file_stem(),
+            package = self.package_source(contracts, body)
+        )
+    }
+
+    fn format_next_variable(&self, name: &str) -> String {
+        format!("Next.{}", to_ada_case(name))
+    }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: String = assignments
+            .iter()
+            .map(|(field, value)| format!("   Next.{} := {};\n", to_ada_case(field), value))
+            .collect();
+        let post = post_check
+            .map(|check| format!("   pragma Assert ({});\n", check))
+            .unwrap_or_default();
+
+        Some(format!(
+            r#"-- SPARK/Ada Generated Code - State Update
 -- Use GNATprove for mathematical verification: `gnatprove -P<project> --level=4`
 
-function {func_name} (Params : Validation_Params) return Boolean
-   with SPARK_Mode => On{contracts}
+function {func_name} (Params : Validation_Params) return Validation_Params
+   with SPARK_Mode => On,
+        Pre => {guard}
 is
- begin
-{assertions_block}   return {body};
- end {func_name};"#,
-            func_name = func_name,
-            contracts = contracts,
-            body = body,
-            assertions_block = assertions_block.trim()
-        )
+   Next : Validation_Params := Params;
+begin
+{updates}{post}   return Next;
+end {func_name};"#
+        ))
+    }
+
+    fn companion_files(&self, compound: &CompoundConstraint, func_name: &str) -> Vec<GeneratedFile> {
+        let code = self.wrap_verified_function(
+            func_name,
+            &self.emit_contracts(compound).unwrap_or_default(),
+            &CodeGenerator.build_expression(compound, self),
+            &build_assertions(compound, self),
+        );
+        self.package_files(&code)
     }
 }
 
 impl SparkAdaStrategy {
-    fn extract_preconditions(&self, compound: &CompoundConstraint) -> Vec<String> {
-        let mut preconditions = Vec::new();
-        self.collect_preconditions(compound, &mut preconditions);
-        preconditions
+    fn new(options: &CodegenOptions) -> Self {
+        Self {
+            package: to_ada_case(options.package_name.as_deref().unwrap_or("validator")),
+            func_name: to_ada_case(&options.func_name),
+        }
     }
 
-    fn collect_preconditions(&self, compound: &CompoundConstraint, preconditions: &mut Vec<String>) {
-        match compound {
-            CompoundConstraint::Simple(c) => {
-                // Extract meaningful preconditions from simple constraints
-                let var = self.format_variable(&c.left_variable);
-                let op = self.format_operator(&c.operator);
-                let val = &c.right_value;
-                preconditions.push(format!("{} {} {}", var, op, val));
-            }
-            CompoundConstraint::And(constraints) => {
-                for c in constraints {
-                    self.collect_preconditions(c, preconditions);
-                }
-            }
-            CompoundConstraint::Or(_) | CompoundConstraint::Not(_) => {
-                // OR/NOT constraints typically become part of postcondition or body
-            }
+    /// GNAT file name of the package: `Payment_Rules` -> `payment_rules`
+    fn file_stem(&self) -> String {
+        self.package.to_lowercase()
+    }
+
+    fn record_type(&self, fields: &[(String, String)]) -> String {
+        let components: String = fields
+            .iter()
+            .map(|(name, ty)| format!("      {} : {};\n", to_ada_case(name), ty))
+            .collect();
+        if components.is_empty() {
+            return "   type Validation_Params is null record;\n".to_string();
         }
+        format!("   type Validation_Params is record\n{}   end record;\n", components)
+    }
+
+    fn function_declaration(&self, compound: &CompoundConstraint) -> String {
+        // No precondition: the validator is called on invalid inputs too and
+        // answers `False`, so the constraint is only in the body and `Post`
+        format!(
+            "   function {} (Params : Validation_Params) return Boolean\n     with\n        {};\n",
+            self.func_name,
+            self.build_postcondition(compound)
+        )
+    }
+
+    /// Spec followed by body, in one compilation file
+    fn package_source(&self, declarations: &str, body: &str) -> String {
+        let context = if declarations.contains("Ada.Strings.Fixed.") || body.contains("Ada.Strings.Fixed.") {
+            "with Ada.Strings.Fixed;\n\n"
+        } else {
+            ""
+        };
+        format!(
+            r#"{context}package {package}
+   with SPARK_Mode => On
+is
+{declarations}end {package};
+
+package body {package}
+   with SPARK_Mode => On
+is
+   function {func_name} (Params : Validation_Params) return Boolean is
+   begin
+      return {body};
+   end {func_name};
+end {package};
+"#,
+            package = self.package,
+            func_name = self.func_name,
+        )
     }
 
-    fn build_postcondition(&self, compound: &CompoundConstraint) -> Option<String> {
+    /// `.ads`, `.adb` and a GNAT project file, split out of the combined source
+    fn package_files(&self, code: &str) -> Vec<GeneratedFile> {
+        let stem = self.file_stem();
+        let (spec, body) = code
+            .split_once(&format!("\npackage body {}", self.package))
+            .map(|(spec, body)| (spec.trim_end().to_string() + "\n", format!("package body {}{}", self.package, body)))
+            .unwrap_or_else(|| (code.to_string(), String::new()));
+
+        let project = format!(
+            r#"project {package} is
+   for Source_Dirs use ("src");
+   for Object_Dir use "obj";
+
+   package Prove is
+      for Proof_Switches ("Ada") use ("--level=4");
+   end Prove;
+end {package};
+"#,
+            package = self.package
+        );
+
+        vec![
+            GeneratedFile {
+                path: format!("src/{}.ads", stem),
+                contents: spec,
+            },
+            GeneratedFile {
+                path: format!("src/{}.adb", stem),
+                contents: body,
+            },
+            GeneratedFile {
+                path: format!("{}.gpr", stem),
+                contents: project,
+            },
+        ]
+    }
+
+    fn build_postcondition(&self, compound: &CompoundConstraint) -> String {
         let expr = self.build_expression_body(compound);
         // Relate 'Result directly to inputs for stronger GNATprove verification
-        Some(format!("Post => ({}'Result = {})", "validate_intent", expr))
+        format!("Post => ({}'Result = {})", self.func_name, expr)
     }
 
     fn build_expression_body(&self, compound: &CompoundConstraint) -> String {
         match compound {
-            CompoundConstraint::Simple(c) => {
-                format!(
-                    "{} {} {}",
-                    self.format_variable(&c.left_variable),
-                    self.format_operator(&c.operator),
-                    c.right_value
-                )
-            }
-            CompoundConstraint::And(constraints) => {
-                let parts: Vec<String> = constraints
-                    .iter()
-                    .map(|c| self.build_expression_body(c))
-                    .collect();
-                format!("({})", parts.join(" and then "))
-            }
+            CompoundConstraint::Simple(c) => self.format_comparison(c),
+            CompoundConstraint::Named(name) => self.format_predicate_call(name),
+            CompoundConstraint::And(constraints) => match compound.as_between() {
+                Some(range) => self.format_range(&range),
+                None => {
+                    let parts: Vec<String> = constraints
+                        .iter()
+                        .map(|c| self.build_expression_body(c))
+                        .collect();
+                    format!("({})", parts.join(" and then "))
+                }
+            },
             CompoundConstraint::Or(constraints) => {
                 let parts: Vec<String> = constraints
                     .iter()
@@ -343,6 +1202,17 @@ impl SparkAdaStrategy {
             CompoundConstraint::Not(inner) => {
                 self.logical_not(&self.build_expression_body(inner))
             }
+            CompoundConstraint::Implies(antecedent, consequent) => self.format_implication(
+                &self.build_expression_body(antecedent),
+                &self.build_expression_body(consequent),
+            ),
+            CompoundConstraint::ExactlyOne(parts) | CompoundConstraint::AtMostOne(parts) => {
+                let rendered: Vec<String> = parts.iter().map(|c| self.build_expression_body(c)).collect();
+                self.format_cardinality(&rendered, matches!(compound, CompoundConstraint::ExactlyOne(_)))
+            }
+            CompoundConstraint::Distinct(_) => {
+                compound.pairwise_encoding().map(|c| self.build_expression_body(&c)).unwrap_or_default()
+            }
         }
     }
 }
@@ -359,13 +1229,14 @@ impl VerifiableStrategy for SparkAdaStrategy {
             DataType::String => "String".to_string(),
             DataType::Bool => "Boolean".to_string(),
             DataType::Decimal => "Long_Float".to_string(),
+            DataType::List(element) => format!("array (Positive range <>) of {}", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
 
     fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
         // SPARK/Ada: Relate 'Result directly to the expression for GNATprove
-        format!("Post => (validate_intent'Result = ({}))", expression)
+        format!("Post => ({}'Result = ({}))", self.func_name, expression)
     }
 
     fn safe_op(&self, left: &str, _op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
@@ -374,39 +1245,28 @@ impl VerifiableStrategy for SparkAdaStrategy {
         format!("{} >= {}", left, right)
     }
 
-    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let params: Vec<String> = schema
+    /// The Schema-typed `Validation_Params` record
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        let mut fields: Vec<(String, String)> = schema
             .fields
             .iter()
-            .map(|(name, dt)| {
-                let ada_name = to_ada_case(name);
-                let ada_type = self.map_type(dt);
-                format!("{} : {}", ada_name, ada_type)
-            })
+            .map(|(name, dt)| (name.clone(), self.map_type(dt)))
             .collect();
-        
-        let params_str = if params.is_empty() {
-            "".to_string()
-        } else {
-            format!(" ({})", params.join("; "))
-        };
-        
-        format!("function {}{} return Boolean", func_name, params_str)
+        fields.sort();
+        self.record_type(&fields)
     }
 
     fn fn_end(&self) -> String {
         ";".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"-- SPARK/Ada Generated Code - Formally Verifiable (v0.1.5-alpha)
--- Use GNATprove for mathematical verification: `gnatprove -P<project> --level=4`
--- Patent Application: 63/928,407
--- Traceability ID: {}
--- Correct by Design, Verified by Construction
-"#,
-            traceability_id
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner(
+            "--",
+            &[
+                "SPARK/Ada Generated Code - Formally Verifiable (v0.1.5-alpha)",
+                "Use GNATprove for mathematical verification: `gnatprove -P<project> --level=4`",
+            ],
         )
     }
 
@@ -418,38 +1278,18 @@ impl VerifiableStrategy for SparkAdaStrategy {
 
 // --- Zig Strategy (Memory-Safe Systems Programming) ---
 
-struct ZigStrategy;
+/// First line of the generated test section; `emit_contracts` output is
+/// split here so the tests follow the validator they exercise
+const ZIG_TESTS: &str = "\ntest \"";
+
+struct ZigStrategy {
+    /// Validator function the generated tests call
+    func_name: String,
+}
 
 impl CodegenStrategy for ZigStrategy {
     fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
-        format!(
-            r#"// Zig Generated Code - Memory Safe Systems Programming
-// Compile-time verification via comptime blocks
-
-const std = @import("std");
-
-pub const ValidationParams = struct {{
-    // Define your validation parameters here
-}};
-
-pub fn {func_name}(params: ValidationParams) bool {{
-    // Compile-time contract check (when possible)
-    comptime {{
-        // Static assertions can be added here
-    }}
-
-    return {body};
-}}
-
-test "{func_name}" {{
-    // Property-based test harness
-    const params = ValidationParams{{}};
-    const result = {func_name}(params);
-    try std.testing.expect(result == true or result == false);
-}}"#,
-            func_name = func_name,
-            body = body
-        )
+        self.wrap_verified_function(func_name, "", body, "")
     }
 
     fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
@@ -460,6 +1300,8 @@ test "{func_name}" {{
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "==",
             ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "indexOf != null",
+            ConstraintOperator::DoesNotContain => "indexOf == null",
         }
     }
 
@@ -467,6 +1309,38 @@ test "{func_name}" {{
         format!("params.{}", name)
     }
 
+    fn format_string(&self, text: &str) -> String {
+        literal::zig_string(text)
+    }
+
+    /// A zero or negative divisor is an error, so the input does not validate
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("(std.math.mod(@TypeOf({d}), {d}, {}) catch return false)", divisor, d = dividend)
+    }
+
+    /// `@abs` returns the unsigned type of the same width, so the most
+    /// negative value has an absolute value too
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        format!("@{}({})", function.name(), args.join(", "))
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(format!("(if ({}) {} else {})", condition, then_value, else_value))
+    }
+
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("(std.mem.indexOf(u8, {}, {}) != null)", haystack, needle)
+    }
+
+    /// Slices have no `==`; a string is compared with `std.mem.eql`
+    fn format_relation(&self, constraint: &Constraint, left: &str, right: &str) -> String {
+        match (compares_with_text(constraint), &constraint.operator) {
+            (true, ConstraintOperator::Equal) => format!("std.mem.eql(u8, {}, {})", left, right),
+            (true, ConstraintOperator::NotEqual) => format!("!std.mem.eql(u8, {}, {})", left, right),
+            _ => format!("{} {} {}", left, self.format_operator(&constraint.operator), right),
+        }
+    }
+
     fn logical_and(&self) -> &'static str {
         "and"
     }
@@ -479,6 +1353,11 @@ test "{func_name}" {{
         format!("!({})", expr)
     }
 
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        let terms: Vec<String> = parts.iter().map(|p| format!("@as(u32, @intFromBool({}))", p)).collect();
+        Some(format!("({})", terms.join(" + ")))
+    }
+
     fn wrap_assertion(&self, condition: &str) -> String {
         format!("std.debug.assert({});", condition)
     }
@@ -488,75 +1367,359 @@ test "{func_name}" {{
         self.is_static_constraint(compound)
     }
 
+    /// The `ValidationParams` struct (every field `i64` without a Schema),
+    /// its compile-time checks and the test section
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let fields: Vec<(String, String)> = inferred_fields(compound)
+            .into_iter()
+            .map(|(v, dt)| match dt {
+                DataType::String => (v, self.map_type(&dt)),
+                _ => (v, "i64".to_string()),
+            })
+            .collect();
+        Some(format!(
+            "{}\n{}{}",
+            self.params_struct(&fields),
+            self.comptime_checks(compound, &fields),
+            self.tests(compound, &fields)
+        ))
+    }
+
+    /// The validator returns whether the constraint holds; asserting each
+    /// conjunct would trap on the inputs it should reject, so `_assertions`
+    /// are left out
     fn wrap_verified_function(
         &self,
         func_name: &str,
         contracts: &str,
         body: &str,
-        assertions: &str,
+        _assertions: &str,
     ) -> String {
-        let comptime_block = if !contracts.is_empty() {
-            format!(
-                r#"    comptime {{
-        // Compile-time contract validation
-        {contracts}
-    }}
-"#
-            )
-        } else {
+        // The tests go after the validator they call
+        let (declarations, tests) = contracts.split_once(ZIG_TESTS).unwrap_or((contracts, ""));
+        let tests = if tests.is_empty() {
             String::new()
-        };
-
-        let runtime_assertions = if !assertions.is_empty() {
-            format!(
-                r#"    // Runtime assertion checks
-    {assertions}
-"#
-            )
         } else {
-            String::new()
+            format!("{}{}", ZIG_TESTS, tests)
         };
 
         format!(
             r#"// Zig Generated Code - Memory Safe Systems Programming
-// Compile-time and runtime verification
+// Compile-time verification via comptime blocks
 
 const std = @import("std");
 
-pub const ValidationParams = struct {{
-    // Define your validation parameters here
-}};
+{declarations}
 
 pub fn {func_name}(params: ValidationParams) bool {{
-{comptime_block}{runtime_assertions}    return {body};
+    return {body};
 }}
-
-test "{func_name}" {{
-    const params = ValidationParams{{}};
-    const result = {func_name}(params);
-    try std.testing.expect(result);
-}}"#,
+{tests}"#,
+            declarations = declarations.trim_end(),
             func_name = func_name,
-            comptime_block = comptime_block,
-            runtime_assertions = runtime_assertions,
-            body = body
+            body = body,
+            tests = tests
         )
     }
 
     fn compile_error(&self, message: &str) -> String {
         format!("@compileError(\"{}\");", message)
     }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: String = assignments
+            .iter()
+            .map(|(field, value)| format!("    next.{} = {};\n", field, value))
+            .collect();
+        let post = post_check
+            .map(|check| format!("    if (!({})) return error.PostconditionViolated;\n", check))
+            .unwrap_or_default();
+
+        Some(format!(
+            r#"// Zig Generated Code - State Update
+
+pub fn {func_name}(params: ValidationParams) error{{ PreconditionViolated, PostconditionViolated }}!ValidationParams {{
+    if (!({guard})) return error.PreconditionViolated;
+    var next = params;
+{updates}{post}    return next;
+}}"#
+        ))
+    }
 }
 
 impl ZigStrategy {
+    fn new(options: &CodegenOptions) -> Self {
+        Self {
+            func_name: options.func_name.clone(),
+        }
+    }
+
+    /// A constraint is static when every comparison is against a literal,
+    /// so its bounds are known at compile time
     fn is_static_constraint(&self, compound: &CompoundConstraint) -> bool {
         match compound {
-            CompoundConstraint::Simple(_) => true, // Simple constraints can be comptime
+            CompoundConstraint::Simple(c) => !is_identifier(&c.right_value),
+            CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => false,
             CompoundConstraint::And(constraints) => constraints.iter().all(|c| self.is_static_constraint(c)),
-            CompoundConstraint::Or(constraints) => constraints.iter().all(|c| self.is_static_constraint(c)),
+            CompoundConstraint::Or(constraints)
+            | CompoundConstraint::ExactlyOne(constraints)
+            | CompoundConstraint::AtMostOne(constraints) => constraints.iter().all(|c| self.is_static_constraint(c)),
             CompoundConstraint::Not(inner) => self.is_static_constraint(inner),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                self.is_static_constraint(antecedent) && self.is_static_constraint(consequent)
+            }
+        }
+    }
+
+    fn params_struct(&self, fields: &[(String, String)]) -> String {
+        let members: String = fields
+            .iter()
+            .map(|(name, ty)| format!("    {}: {},\n", name, ty))
+            .collect();
+        format!("/// Validation parameters structure\npub const ValidationParams = struct {{\n{}}};\n", members)
+    }
+
+    /// Compile-time checks: every literal bound is representable in its
+    /// field's type, and the validator accepts a satisfying input and
+    /// rejects a violating one. `members` are the fields of `ValidationParams`
+    /// with their Zig types.
+    fn comptime_checks(&self, compound: &CompoundConstraint, members: &[(String, String)]) -> String {
+        let mut checks = Vec::new();
+        for (field, literal) in literal_bounds(compound) {
+            let check = format!("    _ = @as(@FieldType(ValidationParams, \"{}\"), {});\n", field, literal);
+            if !checks.contains(&check) {
+                checks.push(check);
+            }
+        }
+        if let Some(values) = satisfying_values(compound) {
+            checks.push(format!("    std.debug.assert({}({}));\n", self.func_name, params_literal(&values, members)));
+        }
+        if let Some(values) = violating_values(compound) {
+            checks.push(format!("    std.debug.assert(!{}({}));\n", self.func_name, params_literal(&values, members)));
+        }
+        if checks.is_empty() {
+            return String::new();
+        }
+        format!("comptime {{\n{}}}\n", checks.concat())
+    }
+
+    /// Tests calling the validator on a satisfying input, evaluated at
+    /// compile time as well when the constraint is static, and on a
+    /// violating one
+    fn tests(&self, compound: &CompoundConstraint, members: &[(String, String)]) -> String {
+        let Some(values) = satisfying_values(compound) else {
+            return String::new();
+        };
+        let func_name = &self.func_name;
+        let comptime_check = if self.is_comptime_capable(compound) {
+            format!("\n    comptime std.debug.assert({func_name}(params));")
+        } else {
+            String::new()
+        };
+        let mut tests = format!(
+            r#"{tests}{func_name} accepts satisfying params" {{
+    const params = {params};
+    try std.testing.expect({func_name}(params));{comptime_check}
+}}
+"#,
+            tests = ZIG_TESTS.trim_start(),
+            params = params_literal(&values, members)
+        );
+        if let Some(values) = violating_values(compound) {
+            tests.push_str(&format!(
+                r#"{tests}{func_name} rejects violating params" {{
+    try std.testing.expect(!{func_name}({params}));
+}}
+"#,
+                tests = ZIG_TESTS,
+                params = params_literal(&values, members)
+            ));
+        }
+        tests
+    }
+}
+
+/// `ValidationParams{ .amount = 1, .balance = 1 }`; a member the values
+/// leave free is the zero of its type, since Zig has no default fields
+fn params_literal(values: &BTreeMap<String, i128>, members: &[(String, String)]) -> String {
+    let initializer: Vec<String> = members
+        .iter()
+        .map(|(field, ty)| {
+            let value = match (values.get(field), ty.as_str()) {
+                (Some(value), _) => value.to_string(),
+                (None, "[]const u8") => "\"\"".to_string(),
+                (None, ty) if ty.starts_with("[]") => "&.{}".to_string(),
+                (None, "bool") => "false".to_string(),
+                (None, _) => "0".to_string(),
+            };
+            format!(".{} = {}", field, value)
+        })
+        .collect();
+    format!("ValidationParams{{ {} }}", initializer.join(", "))
+}
+
+/// `(field, literal)` for every comparison of a field against an integer or
+/// boolean literal
+fn literal_bounds(compound: &CompoundConstraint) -> Vec<(String, String)> {
+    match compound {
+        CompoundConstraint::Simple(c)
+            if c.operator.is_membership()
+                || c.modulo().is_some()
+                || c.bitwise().is_some()
+                || Call::parse(&c.left_variable).is_some() =>
+        {
+            Vec::new()
+        }
+        CompoundConstraint::Simple(c) => {
+            let literal = c.right_value.trim();
+            if literal.parse::<i128>().is_ok() || literal == "true" || literal == "false" {
+                vec![(c.left_variable.clone(), literal.to_string())]
+            } else {
+                Vec::new()
+            }
+        }
+        CompoundConstraint::And(constraints)
+        | CompoundConstraint::Or(constraints)
+        | CompoundConstraint::ExactlyOne(constraints)
+        | CompoundConstraint::AtMostOne(constraints) => constraints.iter().flat_map(literal_bounds).collect(),
+        CompoundConstraint::Not(inner) => literal_bounds(inner),
+        CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => Vec::new(),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            let mut bounds = literal_bounds(antecedent);
+            bounds.extend(literal_bounds(consequent));
+            bounds
+        }
+    }
+}
+
+/// A field's value in `values`, or an integer literal
+fn example_value(operand: &str, values: &BTreeMap<String, i128>) -> Option<i128> {
+    values.get(operand).copied().or_else(|| operand.trim().parse().ok())
+}
+
+/// Whether `values` satisfy `compound`; `None` when it reads something
+/// other than integers
+fn example_holds(compound: &CompoundConstraint, values: &BTreeMap<String, i128>) -> Option<bool> {
+    match compound {
+        CompoundConstraint::Simple(c) => {
+            let left = match c.modulo() {
+                Some(m) => {
+                    example_value(&m.dividend, values)?.checked_rem_euclid(example_value(&m.divisor, values)?)?
+                }
+                None => example_value(&c.left_variable, values)?,
+            };
+            let right = example_value(&c.right_value, values)?;
+            Some(match c.operator {
+                ConstraintOperator::GreaterThanOrEqual => left >= right,
+                ConstraintOperator::LessThanOrEqual => left <= right,
+                ConstraintOperator::GreaterThan => left > right,
+                ConstraintOperator::LessThan => left < right,
+                ConstraintOperator::Equal => left == right,
+                ConstraintOperator::NotEqual => left != right,
+                ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => return None,
+            })
+        }
+        CompoundConstraint::And(constraints) => {
+            constraints.iter().try_fold(true, |acc, c| Some(acc & example_holds(c, values)?))
+        }
+        CompoundConstraint::Or(constraints) => {
+            constraints.iter().try_fold(false, |acc, c| Some(acc | example_holds(c, values)?))
+        }
+        CompoundConstraint::Not(inner) => example_holds(inner, values).map(|held| !held),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            Some(!example_holds(antecedent, values)? || example_holds(consequent, values)?)
+        }
+        CompoundConstraint::ExactlyOne(constraints) | CompoundConstraint::AtMostOne(constraints) => {
+            let held =
+                constraints.iter().try_fold(0, |count, c| Some(count + usize::from(example_holds(c, values)?)))?;
+            Some(match compound {
+                CompoundConstraint::ExactlyOne(_) => held == 1,
+                _ => held <= 1,
+            })
+        }
+        CompoundConstraint::Distinct(_) => example_holds(&compound.pairwise_encoding()?, values),
+        CompoundConstraint::Named(_) => None,
+    }
+}
+
+/// Integer values satisfying `compound`, found by repairing violated
+/// comparisons from an all-zero start. `None` for negations, non-integer
+/// literals, or when a few rounds of repair do not converge.
+fn satisfying_values(compound: &CompoundConstraint) -> Option<BTreeMap<String, i128>> {
+    fn repair(compound: &CompoundConstraint, values: &mut BTreeMap<String, i128>) -> Option<()> {
+        if example_holds(compound, values)? {
+            return Some(());
+        }
+        match compound {
+            CompoundConstraint::Simple(c) if c.modulo().is_some() || c.bitwise().is_some() => None,
+            CompoundConstraint::Simple(c) => {
+                let right = example_value(&c.right_value, values)?;
+                let left = match c.operator {
+                    ConstraintOperator::GreaterThan | ConstraintOperator::NotEqual => right + 1,
+                    ConstraintOperator::LessThan => right - 1,
+                    _ => right,
+                };
+                values.insert(c.left_variable.clone(), left);
+                Some(())
+            }
+            CompoundConstraint::And(constraints) => {
+                constraints.iter().try_for_each(|c| repair(c, values))
+            }
+            CompoundConstraint::Or(constraints) => repair(constraints.first()?, values),
+            // The antecedent holds here, so the consequent has to
+            CompoundConstraint::Implies(_, consequent) => repair(consequent, values),
+            CompoundConstraint::Distinct(_) => repair(&compound.pairwise_encoding()?, values),
+            CompoundConstraint::Not(_)
+            | CompoundConstraint::ExactlyOne(_)
+            | CompoundConstraint::AtMostOne(_)
+            | CompoundConstraint::Named(_) => None,
+        }
+    }
+
+    let mut values: BTreeMap<String, i128> = collect_variables(compound)
+        .into_iter()
+        .filter(|v| is_identifier(v))
+        .map(|v| (v, 0))
+        .collect();
+    for _ in 0..8 {
+        if example_holds(compound, &values)? {
+            return Some(values);
+        }
+        repair(compound, &mut values)?;
+    }
+    example_holds(compound, &values)?.then_some(values)
+}
+
+/// Values that violate `compound`: the satisfying values with one field
+/// compared in a top-level conjunct moved just across its bound. Values stay
+/// non-negative, so they fit unsigned fields too.
+fn violating_values(compound: &CompoundConstraint) -> Option<BTreeMap<String, i128>> {
+    let satisfying = satisfying_values(compound)?;
+    let conjuncts: Vec<&CompoundConstraint> = match compound {
+        CompoundConstraint::And(parts) => parts.iter().collect(),
+        other => vec![other],
+    };
+    for conjunct in conjuncts {
+        let CompoundConstraint::Simple(c) = conjunct else { continue };
+        if !satisfying.contains_key(&c.left_variable) {
+            continue;
+        }
+        let Some(bound) = example_value(&c.right_value, &satisfying) else { continue };
+        for candidate in [bound - 1, bound, bound + 1].into_iter().filter(|v| *v >= 0) {
+            let mut values = satisfying.clone();
+            values.insert(c.left_variable.clone(), candidate);
+            if example_holds(compound, &values) == Some(false) {
+                return Some(values);
+            }
         }
     }
+    None
 }
 
 // --- Zig VerifiableStrategy Implementation ---
@@ -571,6 +1734,7 @@ impl VerifiableStrategy for ZigStrategy {
             DataType::String => "[]const u8".to_string(),
             DataType::Bool => "bool".to_string(),
             DataType::Decimal => "f64".to_string(),
+            DataType::List(element) => format!("[]const {}", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
@@ -581,56 +1745,42 @@ impl VerifiableStrategy for ZigStrategy {
     }
 
     fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
-        // Zig: Use overflow-safe intrinsics for arithmetic operations
-        match op {
-            ArithmeticOperator::Subtract => {
-                format!("@subWithOverflow({}, {}).*[0]", left, right)
-            }
-            ArithmeticOperator::Add => {
-                format!("@addWithOverflow({}, {}).*[0]", left, right)
-            }
-            ArithmeticOperator::Multiply => {
-                format!("@mulWithOverflow({}, {}).*[0]", left, right)
-            }
-            ArithmeticOperator::Divide => {
-                // Division overflow only possible for MIN / -1, handle with panic
-                format!("{}{}{}", left, op.rust_symbol(), right)
-            }
-        }
+        // `std.math` reports overflow (and division by zero) as an error;
+        // an input that overflows does not validate
+        let function = match op {
+            ArithmeticOperator::Add => "add",
+            ArithmeticOperator::Subtract => "sub",
+            ArithmeticOperator::Multiply => "mul",
+            ArithmeticOperator::Divide => "divTrunc",
+            ArithmeticOperator::Modulo => "mod",
+        };
+        format!("(std.math.{}(@TypeOf({}), {}, {}) catch return false)", function, left, left, right)
     }
 
-    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let fields: Vec<String> = schema
+    /// The schema-typed `ValidationParams` struct
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        let mut fields: Vec<(String, String)> = schema
             .fields
             .iter()
-            .map(|(name, dt)| {
-                format!("{}: {}", name, self.map_type(dt))
-            })
+            .map(|(name, dt)| (name.clone(), self.map_type(dt)))
             .collect();
-        
-        let fields_str = if fields.is_empty() {
-            "{}".to_string()
-        } else {
-            format!("{{ {} }}", fields.join(", "))
-        };
-        
-        format!("pub fn {}(params: {})", func_name, fields_str)
+        fields.sort();
+        self.params_struct(&fields)
     }
 
     fn fn_end(&self) -> String {
         "}".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"// Zig Generated Code - Memory Safe Systems Programming (v0.1.5-alpha)
-// Compile-time verification via comptime blocks
-// Patent Application: 63/928,407
-// Traceability ID: {}
-// Correct by Design, Verified by Construction
-"#,
-            traceability_id
-        )
+    fn license_header(&self, provenance: &Provenance) -> String {
+        let banner = provenance.banner(
+            "//",
+            &[
+                "Zig Generated Code - Memory Safe Systems Programming (v0.1.5-alpha)",
+                "Compile-time verification via comptime blocks",
+            ],
+        );
+        format!("{}const std = @import(\"std\");\n\n", banner)
     }
 
     fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
@@ -641,7 +1791,14 @@ impl VerifiableStrategy for ZigStrategy {
 
 // --- Elixir Strategy (Fault-Tolerant Distributed Logic) ---
 
-struct ElixirStrategy;
+struct ElixirStrategy {
+    /// Validator function the clauses and tests define
+    func_name: String,
+    /// Field widths `<<` wraps to
+    widths: BitWidths,
+    /// Fields of the params map, by the identifier each is bound to
+    keys: FieldNames,
+}
 
 impl CodegenStrategy for ElixirStrategy {
     fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
@@ -650,14 +1807,14 @@ impl CodegenStrategy for ElixirStrategy {
 # Use with ExUnit for property-based testing
 
 defmodule Validator do
-  @moduledoc \"\"\"
+  @moduledoc """
   Auto-generated validation module from Crucible Intent specification.
-  \"\"\"
+  """
 
-  @doc \"\"\"
+  @doc """
   Validates the given parameters against the intent constraints.
   Returns true if all constraints are satisfied.
-  \"\"\"
+  """
   @spec {func_name}?(map()) :: boolean()
   def {func_name}?(params) when is_map(params) do
     {body}
@@ -678,11 +1835,67 @@ end"#,
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "==",
             ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "String.contains?",
+            ConstraintOperator::DoesNotContain => "not String.contains?",
         }
     }
 
     fn format_variable(&self, name: &str) -> String {
-        format!("params[:{}]", name)
+        format!("params[:{}]", self.key(name))
+    }
+
+    /// `#{` would interpolate inside a double-quoted string
+    fn format_string(&self, text: &str) -> String {
+        literal::elixir_string(text)
+    }
+
+    /// `rem` truncates; shifted by `abs` so the result stays guard-safe
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("rem(rem({}, {}) + abs({d}), abs({d}))", dividend, divisor, d = divisor)
+    }
+
+    /// `Bitwise` is guard-safe; integers are unbounded, so `bsl` is wrapped
+    /// to the field's width
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        let function = match bitwise.operator {
+            BitwiseOperator::And => "band",
+            BitwiseOperator::Or => "bor",
+            BitwiseOperator::Xor => "bxor",
+            BitwiseOperator::ShiftLeft => "bsl",
+            BitwiseOperator::ShiftRight => "bsr",
+        };
+        let bits = format!("Bitwise.{}({}, {})", function, operand, mask);
+        match bitwise.operator {
+            BitwiseOperator::ShiftLeft => {
+                wrapped(&bits, self.widths.of(&bitwise.operand), |a, b| format!("Bitwise.band({}, {})", a, b))
+            }
+            _ => bits,
+        }
+    }
+
+    /// `Kernel.min/2` is not allowed in guards; `(a + b -/+ |a - b|) / 2` is,
+    /// and is exact on integers, which never overflow
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min => fold_pairs(args, |a, b| format!("div({a} + {b} - abs({a} - {b}), 2)", a = a, b = b)),
+            MathFunction::Max => fold_pairs(args, |a, b| format!("div({a} + {b} + abs({a} - {b}), 2)", a = a, b = b)),
+            MathFunction::Abs => format!("abs({})", args.join(", ")),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        }
+    }
+
+    /// `if` is a macro, which guards cannot call; comparisons against a
+    /// conditional are spelled out as both cases
+    fn format_conditional(&self, _condition: &str, _then_value: &str, _else_value: &str) -> Option<String> {
+        None
+    }
+
+    /// Not allowed in guards, so a validator with substring tests checks its
+    /// constraints in the clause body
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("String.contains?({}, {})", haystack, needle)
     }
 
     fn logical_and(&self) -> &'static str {
@@ -702,24 +1915,33 @@ end"#,
     }
 
     fn to_guard_expression(&self, compound: &CompoundConstraint) -> Option<String> {
+        if has_membership(compound) {
+            return None;
+        }
         Some(self.build_guard_expression(compound))
     }
 
+    /// `@type params`, the documented `@spec` and the guarded clauses, with
+    /// every field `integer()` without a Schema
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let fields = inferred_fields(compound);
+        Some(self.validator(compound, &self.params_type(&fields), &fields))
+    }
+
     fn wrap_verified_function(
         &self,
         func_name: &str,
         contracts: &str,
         body: &str,
-        assertions: &str,
+        _assertions: &str,
     ) -> String {
-        let assertions_code = if !assertions.is_empty() {
+        // The guards carry the constraints; without them, evaluate the body
+        let clauses = if contracts.is_empty() {
             format!(
-                r#"
-  # Assertion checks
-  {assertions}"#
+                "  def {func_name}?(params) when is_map(params) do\n    if {body}, do: {{:ok, true}}, else: {{:error, :validation_failed}}\n  end\n\n  def {func_name}?(_), do: {{:error, :invalid_type}}"
             )
         } else {
-            String::new()
+            contracts.trim_end().to_string()
         };
 
         format!(
@@ -727,35 +1949,126 @@ end"#,
 # Guard clauses for compile-time pattern matching
 
 defmodule Validator do
-  @moduledoc \"\"\"
+  @moduledoc """
   Auto-generated validation module from Crucible Intent specification.
-  \"\"\"
-
-  @doc \"\"\"
-  Validates the given parameters against the intent constraints.
-  Returns {{:ok, true}} on success, {{:error, reason}} on failure.
-  \"\"\"
-  @spec {func_name}?(map()) :: {{:ok, true}} | {{:error, atom()}}
-{contracts}
-
-  def {func_name}?(params) when is_map(params) and is_integer(params[:amount]) and params[:amount] >= 0 do
-{body}{assertions_code}
-  end
+  """
 
-  def {func_name}?(params) when not is_map(params), do: {{:error, :invalid_type}}
-  def {func_name}?(params) when not is_integer(params[:amount]), do: {{:error, :invalid_amount_type}}
-  def {func_name}?(params) when params[:amount] < 0, do: {{:error, :negative_amount}}
-  def {func_name}?(_), do: {{:error, :validation_failed}}
-end"#,
-            func_name = func_name,
-            contracts = contracts,
-            body = body,
-            assertions_code = assertions_code.trim()
+{clauses}
+end"#
         )
     }
-}
 
-// --- Elixir VerifiableStrategy Implementation ---
+    fn companion_files(&self, compound: &CompoundConstraint, _func_name: &str) -> Vec<GeneratedFile> {
+        let fields = inferred_fields(compound);
+        vec![self.property_tests(compound, &fields)]
+    }
+
+    fn format_next_variable(&self, name: &str) -> String {
+        format!("next[:{}]", self.key(name))
+    }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: Vec<String> = assignments
+            .iter()
+            .map(|(field, value)| format!("{}: {}", self.key(field), value))
+            .collect();
+        let result = match post_check {
+            Some(check) => format!(
+                "if {}, do: {{:ok, next}}, else: {{:error, :postcondition_violated}}",
+                check
+            ),
+            None => "{:ok, next}".to_string(),
+        };
+
+        Some(format!(
+            r#"# Elixir Generated Code - State Update
+defmodule Validator do
+  @doc "Applies `{func_name}` when its precondition holds"
+  def {func_name}(params) do
+    if {guard} do
+      next = %{{params | {updates}}}
+      {result}
+    else
+      {{:error, :precondition_violated}}
+    end
+  end
+end"#,
+            updates = updates.join(", ")
+        ))
+    }
+
+    fn emit_state_machine(&self, machine: &StateMachine, guards: &[Option<String>]) -> Option<String> {
+        let mut handlers = String::new();
+        for state in &machine.states {
+            let outgoing: Vec<(usize, &MachineTransition)> =
+                machine.transitions.iter().enumerate().filter(|(_, t)| &t.from == state).collect();
+            let mut actions: Vec<&str> = Vec::new();
+            for (_, transition) in &outgoing {
+                if !actions.contains(&transition.action.as_str()) {
+                    actions.push(&transition.action);
+                }
+            }
+
+            for action in actions {
+                // Access syntax is not allowed in guards, so conditions are checked in the body
+                let branches: String = outgoing
+                    .iter()
+                    .filter(|(_, t)| t.action == action)
+                    .map(|(i, t)| {
+                        format!(
+                            "      {} -> {{:next_state, :{}, params, [{{:reply, from, :ok}}]}}\n",
+                            guards[*i].as_deref().unwrap_or("true"),
+                            t.to
+                        )
+                    })
+                    .collect();
+                handlers.push_str(&format!(
+                    r#"  def {state}({{:call, from}}, :{action}, params) do
+    cond do
+{branches}      true -> {{:keep_state_and_data, [{{:reply, from, {{:error, :guard_failed}}}}]}}
+    end
+  end
+
+"#
+                ));
+            }
+            handlers.push_str(&format!(
+                r#"  def {state}({{:call, from}}, _event, _params) do
+    {{:keep_state_and_data, [{{:reply, from, {{:error, :invalid_transition}}}}]}}
+  end
+
+"#
+            ));
+        }
+
+        Some(format!(
+            r#"# Elixir Generated Code - State Machine
+defmodule {module}Machine do
+  @moduledoc "Lifecycle of {subject}: {states}"
+  use GenStateMachine, callback_mode: :state_functions
+
+  def start_link(params), do: GenStateMachine.start_link(__MODULE__, params)
+
+  @impl true
+  def init(params), do: {{:ok, :{initial}, params}}
+
+{handlers}end"#,
+            module = to_ada_case(&machine.subject).replace('_', ""),
+            subject = machine.subject,
+            states = machine.states.join(", "),
+            initial = machine.initial,
+            handlers = handlers.trim_end_matches('\n').to_string() + "\n"
+        ))
+    }
+}
+
+// --- Elixir VerifiableStrategy Implementation ---
 
 impl VerifiableStrategy for ElixirStrategy {
     fn map_type(&self, dt: &DataType) -> String {
@@ -765,6 +2078,7 @@ impl VerifiableStrategy for ElixirStrategy {
             DataType::String => "String.t()".to_string(),
             DataType::Bool => "boolean()".to_string(),
             DataType::Decimal => "Decimal.t()".to_string(),
+            DataType::List(element) => format!("[{}]", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
@@ -779,35 +2093,21 @@ impl VerifiableStrategy for ElixirStrategy {
             ArithmeticOperator::Add => format!("{}_{}_plus_{}", left, op.symbol(), right),
             ArithmeticOperator::Multiply => format!("{}_{}_times_{}", left, op.symbol(), right),
             ArithmeticOperator::Divide => format!("{}{}{}", left, op.rust_symbol(), right),
+            ArithmeticOperator::Modulo => self.format_modulo(left, right),
         }
     }
 
-    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let fields: Vec<String> = schema
-            .fields
-            .iter()
-            .map(|(name, dt)| {
-                format!("{}: {}", name, self.map_type(dt))
-            })
-            .collect();
-        
-        format!("@spec {}_params() :: map()\n  def {}_params(), do: %{{{}}}", func_name, func_name, fields.join(", "))
+    /// `@type params` over the Schema fields
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        self.params_type(&sorted_fields(schema))
     }
 
     fn fn_end(&self) -> String {
         "end".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"# Elixir Generated Code - Fault-Tolerant Distributed Logic (v0.1.5-alpha)
-# Patent Application: 63/928,407
-# Traceability ID: {}
-# Correct by Design, Verified by Construction
-
-"#,
-            traceability_id
-        )
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner("#", &["Elixir Generated Code - Fault-Tolerant Distributed Logic (v0.1.5-alpha)"])
     }
 
     fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
@@ -816,16 +2116,57 @@ impl VerifiableStrategy for ElixirStrategy {
 }
 
 impl ElixirStrategy {
+    fn new(options: &CodegenOptions) -> Self {
+        Self {
+            func_name: options.func_name.clone(),
+            widths: BitWidths::default(),
+            keys: FieldNames::default(),
+        }
+    }
+
+    /// Wraps shifts to the widths of `schema`'s fields
+    fn with_schema(mut self, schema: &Schema) -> Self {
+        self.widths = BitWidths::new(schema);
+        self
+    }
+
+    /// Keys the params map by each field's own name rather than the
+    /// identifier `names` binds it to: `end` is `%{end: end_}`
+    fn with_names(mut self, names: &FieldNames) -> Self {
+        self.keys = names.clone();
+        self
+    }
+
+    /// The atom key of the field bound to `name`, quoted when it is not a
+    /// bare atom (`"user-id"`)
+    fn key(&self, name: &str) -> String {
+        let field = self.keys.field(name);
+        let mut chars = field.chars();
+        let bare = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_');
+        if bare {
+            field.to_string()
+        } else {
+            literal::elixir_string(field)
+        }
+    }
+
+    /// Guard over the bound field variables; access syntax (`params[:x]`)
+    /// is not allowed in guards
     fn build_guard_expression(&self, compound: &CompoundConstraint) -> String {
         match compound {
-            CompoundConstraint::Simple(c) => {
-                format!(
+            CompoundConstraint::Simple(c) if c.operator.is_membership() => {
+                BareVariables(self).format_membership_comparison(c)
+            }
+            CompoundConstraint::Simple(c) => match c.conditional() {
+                Some(conditional) => self.build_guard_expression(&conditional.cases(c)),
+                None => format!(
                     "{} {} {}",
-                    self.format_variable(&c.left_variable),
+                    BareVariables(self).format_left_operand(c),
                     self.format_operator(&c.operator),
-                    self.format_value(&c.right_value)
-                )
-            }
+                    BareVariables(self).format_right_operand(&c.right_value)
+                ),
+            },
             CompoundConstraint::And(constraints) => {
                 let parts: Vec<String> = constraints
                     .iter()
@@ -843,64 +2184,171 @@ impl ElixirStrategy {
             CompoundConstraint::Not(inner) => {
                 format!("not ({})", self.build_guard_expression(inner))
             }
+            CompoundConstraint::Implies(antecedent, consequent) => format!(
+                "(not ({}) or {})",
+                self.build_guard_expression(antecedent),
+                self.build_guard_expression(consequent)
+            ),
+            // Guards cannot count booleans or build sets
+            CompoundConstraint::ExactlyOne(_) | CompoundConstraint::AtMostOne(_) | CompoundConstraint::Distinct(_) => {
+                compound.pairwise_encoding().map(|c| self.build_guard_expression(&c)).unwrap_or_default()
+            }
+            // Guards cannot call functions; definitions are inlined before this point
+            CompoundConstraint::Named(name) => name.clone(),
         }
     }
 
-    fn format_value(&self, value: &str) -> String {
-        // Try to parse as integer first
-        if value.parse::<i64>().is_ok() {
-            value.to_string()
-        } else {
-            // Keep as atom/reference for guard compatibility
-            format!("params[:{}]", value)
-        }
+    /// `@type params :: %{amount: integer(), ...}`
+    fn params_type(&self, fields: &[(String, DataType)]) -> String {
+        let members: Vec<String> = fields
+            .iter()
+            .map(|(name, dt)| format!("{}: {}", self.key(name), self.map_type(dt)))
+            .collect();
+        format!("  @type params :: %{{{}}}\n", members.join(", "))
     }
-}
 
-// --- Rust Strategy (with Kani proof harness support) ---
+    /// Guard checking the runtime type of a bound field
+    fn type_guard(name: &str, dt: &DataType) -> Option<String> {
+        match dt {
+            DataType::Uint64 | DataType::Uint32 => Some(format!("is_integer({0}) and {0} >= 0", name)),
+            DataType::Int64 | DataType::Int32 => Some(format!("is_integer({})", name)),
+            DataType::String => Some(format!("is_binary({})", name)),
+            DataType::Bool => Some(format!("is_boolean({})", name)),
+            DataType::Decimal => Some(format!("is_struct({}, Decimal)", name)),
+            DataType::List(_) => Some(format!("is_list({})", name)),
+            DataType::Custom { range_min, range_max, .. } => {
+                let mut guards = vec![format!("is_integer({})", name)];
+                guards.extend(range_min.map(|min| format!("{} >= {}", name, min)));
+                guards.extend(range_max.map(|max| format!("{} <= {}", name, max)));
+                Some(guards.join(" and "))
+            }
+        }
+    }
 
-struct RustStrategy;
+    /// The validator clauses: well-typed params satisfying the constraints,
+    /// well-typed params violating them, maps missing fields, non-maps
+    fn validator(&self, compound: &CompoundConstraint, type_decl: &str, fields: &[(String, DataType)]) -> String {
+        let func_name = &self.func_name;
+        let bound: Vec<&(String, DataType)> = collect_variables(compound)
+            .iter()
+            .filter_map(|v| fields.iter().find(|(name, _)| name == v))
+            .collect();
+        let pattern = bound
+            .iter()
+            .map(|(name, _)| format!("{}: {}", self.key(name), name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let types = bound
+            .iter()
+            .filter_map(|(name, dt)| Self::type_guard(name, dt))
+            .collect::<Vec<_>>()
+            .join(" and ");
+        let typed = if types.is_empty() { "true".to_string() } else { types };
+        // `String.contains?/2` is not allowed in guards, so it is checked in the body
+        let typed_clauses = match self.to_guard_expression(compound) {
+            None => format!(
+                r#"  def {func_name}?(%{{{pattern}}}) when {typed} do
+    if {guard}, do: {{:ok, true}}, else: {{:error, :validation_failed}}
+  end
+"#,
+                guard = self.build_guard_expression(compound)
+            ),
+            Some(guard) => format!(
+                r#"  def {func_name}?(%{{{pattern}}})
+      when {typed} and ({guard}),
+      do: {{:ok, true}}
+
+  def {func_name}?(%{{{pattern}}}) when {typed}, do: {{:error, :validation_failed}}
+"#
+            ),
+        };
 
-impl CodegenStrategy for RustStrategy {
-    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
         format!(
-            r#"//! Rust Generated Code - Memory Safe with Formal Verification
-//! Use with Kani for bounded model checking
+            r#"{type_decl}
+  @doc """
+  Validates the given parameters against the intent constraints.
+  Returns {{:ok, true}} on success, {{:error, reason}} on failure.
+  """
+  @spec {func_name}?(params() | term()) :: {{:ok, true}} | {{:error, atom()}}
+{typed_clauses}  def {func_name}?(params) when is_map(params), do: {{:error, :invalid_params}}
+  def {func_name}?(_), do: {{:error, :invalid_type}}
+"#
+        )
+    }
 
-/// Validation parameters structure
-#[derive(Debug, Clone)]
-pub struct ValidationParams {{
-    // Define your validation parameters here
-}}
+    /// ExUnit + StreamData property test: the validator accepts exactly the
+    /// generated params that satisfy the constraints
+    fn property_tests(&self, compound: &CompoundConstraint, fields: &[(String, DataType)]) -> GeneratedFile {
+        let func_name = &self.func_name;
+        let bound: Vec<&(String, DataType)> = collect_variables(compound)
+            .iter()
+            .filter_map(|v| fields.iter().find(|(name, _)| name == v))
+            .collect();
+        let generators = bound
+            .iter()
+            .map(|(name, dt)| format!("{} <- {}", name, Self::generator(dt)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let params = bound
+            .iter()
+            .map(|(name, _)| format!("{}: {}", self.key(name), name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let oracle = self.build_guard_expression(compound);
+
+        GeneratedFile {
+            path: "test/validator_test.exs".to_string(),
+            contents: format!(
+                r#"# ExUnit + StreamData property tests - run with `mix test`
+defmodule ValidatorTest do
+  use ExUnit.Case, async: true
+  use ExUnitProperties
+
+  property "{func_name}? accepts exactly the params satisfying the constraints" do
+    check all {generators} do
+      expected = if {oracle}, do: {{:ok, true}}, else: {{:error, :validation_failed}}
+      assert Validator.{func_name}?(%{{{params}}}) == expected
+    end
+  end
 
-impl Validator {{
-    /// Validates the given parameters against the intent constraints.
-    ///
-    /// # Returns
-    /// `true` if all constraints are satisfied, `false` otherwise.
-    #[inline]
-    pub fn {func_name}(&self, params: &ValidationParams) -> bool {{
-        {body}
-    }}
-}}
+  test "{func_name}? rejects non-map input" do
+    assert Validator.{func_name}?(nil) == {{:error, :invalid_type}}
+  end
+end
+"#
+            ),
+        }
+    }
 
-#[cfg(kani)]
-mod verification {{
-    use super::*;
+    /// StreamData generator producing values that pass `type_guard`
+    fn generator(dt: &DataType) -> String {
+        match dt {
+            DataType::Uint64 | DataType::Uint32 => "non_negative_integer()".to_string(),
+            DataType::Int64 | DataType::Int32 => "integer()".to_string(),
+            DataType::String => "string(:printable)".to_string(),
+            DataType::Bool => "boolean()".to_string(),
+            DataType::Decimal => "map(integer(), &Decimal.new/1)".to_string(),
+            DataType::List(element) => format!("list_of({})", Self::generator(element)),
+            DataType::Custom { range_min: Some(min), range_max: Some(max), .. } => format!("integer({}..{})", min, max),
+            DataType::Custom { .. } => "integer()".to_string(),
+        }
+    }
+}
 
-    #[kani::proof]
-    fn verify_{func_name}() {{
-        let validator = Validator;
-        let params = kani::any::<ValidationParams>();
-        let result = validator.{func_name}(&params);
-        // Kani will verify this function terminates and doesn't panic
-        kani::cover!(result == true);
-        kani::cover!(result == false);
-    }}
-}}"#,
-            func_name = func_name,
-            body = body
-        )
+// --- Rust Strategy (with Kani proof harness support) ---
+
+struct RustStrategy {
+    /// Validator method the Kani harnesses call
+    func_name: String,
+    /// Embedded profile: `#![no_std]`, `&'static str` for strings, `const fn`
+    no_std: bool,
+    /// Terms at risk of overflow, computed with `checked_*`
+    checked: Vec<String>,
+}
+
+impl CodegenStrategy for RustStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
     }
 
     fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
@@ -911,6 +2359,8 @@ mod verification {{
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "==",
             ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "contains",
+            ConstraintOperator::DoesNotContain => "!contains",
         }
     }
 
@@ -918,6 +2368,73 @@ mod verification {{
         format!("params.{}", name)
     }
 
+    fn format_string(&self, text: &str) -> String {
+        literal::rust_string(text)
+    }
+
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("{}.rem_euclid({})", dividend, divisor)
+    }
+
+    /// `matches!(a.checked_add(b), Some(value) if left == value)`: a term
+    /// that overflows fails the validation instead of wrapping or panicking
+    fn format_overflow_checked(&self, constraint: &Constraint, term: &ArithmeticTerm) -> Option<String> {
+        if !self.checked.contains(&term.to_string()) {
+            return None;
+        }
+        // A literal has no type to call the method on until it is an argument
+        let (receiver, argument) = match (is_identifier(&term.left), term.operator) {
+            (true, _) => (&term.left, &term.right),
+            (false, ArithmeticOperator::Add | ArithmeticOperator::Multiply) => (&term.right, &term.left),
+            (false, _) => return None,
+        };
+        let method = match term.operator {
+            ArithmeticOperator::Add => "checked_add",
+            ArithmeticOperator::Subtract => "checked_sub",
+            ArithmeticOperator::Multiply => "checked_mul",
+            ArithmeticOperator::Divide => "checked_div",
+            ArithmeticOperator::Modulo => "checked_rem_euclid",
+        };
+        let operand = |value: &str| match is_identifier(value) {
+            true => self.format_variable(value),
+            false => value.to_string(),
+        };
+        Some(format!(
+            "matches!({}.{}({}), Some(value) if {} {} value)",
+            operand(receiver),
+            method,
+            operand(argument),
+            self.format_left_operand(constraint),
+            self.format_operator(&constraint.operator)
+        ))
+    }
+
+    /// `abs` saturates: `abs(i64::MIN)` is `i64::MAX` rather than a panic
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        rust_call(function, args)
+    }
+
+    fn supports_lists(&self) -> bool {
+        true
+    }
+
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        rust_distinct(values, self.no_std)
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(rust_conditional(condition, then_value, else_value))
+    }
+
+    /// Inclusive ranges as `(1..=100).contains(&params.amount)`
+    fn format_range(&self, range: &Between) -> String {
+        if !range.inclusive {
+            let (lower, upper) = range.bounds();
+            return format!("({} && {})", self.format_comparison(&lower), self.format_comparison(&upper));
+        }
+        format!("({}..={}).contains(&{})", range.low, range.high, self.format_variable(&range.variable))
+    }
+
     fn logical_and(&self) -> &'static str {
         "&&"
     }
@@ -930,10 +2447,44 @@ mod verification {{
         format!("!({})", expr)
     }
 
+    fn inline_comment(&self, text: &str) -> Option<String> {
+        Some(format!("/* {} */", text))
+    }
+
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        Some(format!("[{}].iter().filter(|&&held| held).count()", parts.join(", ")))
+    }
+
+    /// Inlined in the embedded profile, where a `const fn` validator could
+    /// not call them
+    fn predicate_helper(&self, name: &str, body: &str) -> Option<String> {
+        if self.no_std {
+            return None;
+        }
+        Some(format!(
+            "/// Named predicate `{name}`\n#[inline]\npub fn {name}(params: &ValidationParams) -> bool {{\n    {body}\n}}",
+            name = name,
+            body = body
+        ))
+    }
+
     fn wrap_assertion(&self, condition: &str) -> String {
         format!("debug_assert!({});", condition)
     }
 
+    /// The `ValidationParams` struct (every field `i64` without a Schema)
+    /// followed by the Kani harness module
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let fields: Vec<(String, String)> = inferred_fields(compound)
+            .into_iter()
+            .map(|(v, dt)| match dt {
+                DataType::String | DataType::List(_) => (v, self.map_type(&dt)),
+                _ => (v, "i64".to_string()),
+            })
+            .collect();
+        Some(format!("{}\n{}", self.params_struct(&fields), self.harnesses(compound)))
+    }
+
     fn wrap_verified_function(
         &self,
         func_name: &str,
@@ -950,16 +2501,27 @@ mod verification {{
         } else {
             String::new()
         };
+        // The harness module goes after the validator it proves
+        let (declarations, harnesses) = contracts.split_once(KANI_MODULE).unwrap_or((contracts, ""));
+        let harnesses = if harnesses.is_empty() {
+            String::new()
+        } else {
+            format!("\n{}{}", KANI_MODULE.trim_start(), harnesses)
+        };
+
+        // Without a Schema every field is `i64`
+        let const_items = self.const_items(&[]);
+        let qualifier = if const_items.is_some() { "const fn" } else { "fn" };
 
         format!(
-            r#"//! Rust Generated Code - Memory Safe with Formal Verification
-//! Use with Kani for bounded model checking
+            r#"{attributes}//! Rust Generated Code - Memory Safe with Formal Verification
+//! Use with Kani for bounded model checking: `cargo kani`
 
-/// Validation parameters structure
-#[derive(Debug, Clone)]
-pub struct ValidationParams {{
-    // Define your validation parameters here
-}}{contracts}
+{declarations}
+
+/// Entry point for the generated validators
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Validator;
 
 impl Validator {{
     /// Validates the given parameters against the intent constraints.
@@ -967,33 +2529,304 @@ impl Validator {{
     /// # Returns
     /// `true` if all constraints are satisfied, `false` otherwise.
     #[inline]
-    pub fn {func_name}(&self, params: &ValidationParams) -> bool {{
+    pub {qualifier} {func_name}(&self, params: &ValidationParams) -> bool {{
 {assertions_code}
         {body}
     }}
 }}
+{const_items}{harnesses}"#,
+            attributes = self.crate_attributes(),
+            const_items = const_items.unwrap_or_default(),
+            declarations = declarations.trim_end(),
+            func_name = func_name,
+            body = body,
+            assertions_code = assertions_code.trim(),
+            harnesses = harnesses.trim_end()
+        )
+    }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: String = assignments
+            .iter()
+            .map(|(field, value)| format!("    next.{} = {};\n", field, value))
+            .collect();
+        let post = post_check
+            .map(|check| format!("    if !({}) {{\n        return Err(\"postcondition violated\");\n    }}\n", check))
+            .unwrap_or_default();
+
+        Some(format!(
+            r#"// Rust Generated Code - State Update
+
+/// Applies `{func_name}`: checks the precondition, updates the state, checks the postcondition
+pub fn {func_name}(params: &ValidationParams) -> Result<ValidationParams, &'static str> {{
+    if !({guard}) {{
+        return Err("precondition violated");
+    }}
+    let mut next = params.clone();
+{updates}{post}    Ok(next)
+}}"#
+        ))
+    }
+
+    fn emit_state_machine(&self, machine: &StateMachine, guards: &[Option<String>]) -> Option<String> {
+        let subject = to_ada_case(&machine.subject).replace('_', "");
+        let state_type = |state: &str| to_ada_case(state).replace('_', "");
+
+        let markers: String = machine
+            .states
+            .iter()
+            .map(|state| format!("/// `{}` is in state `{}`\npub struct {};\n", subject, state, state_type(state)))
+            .collect();
+
+        let mut impls = String::new();
+        for state in &machine.states {
+            let outgoing: Vec<(usize, &MachineTransition)> =
+                machine.transitions.iter().enumerate().filter(|(_, t)| &t.from == state).collect();
+            if outgoing.is_empty() {
+                continue;
+            }
+
+            let methods: Vec<String> = outgoing
+                .iter()
+                .map(|(i, t)| {
+                    // The same action may lead to different states under different guards
+                    let name = if outgoing.iter().filter(|(_, o)| o.action == t.action).count() > 1 {
+                        format!("{}_to_{}", t.action, t.to)
+                    } else {
+                        t.action.clone()
+                    };
+                    let check = guards[*i]
+                        .as_deref()
+                        .map(|guard| {
+                            format!(
+                                "        let params = &self.params;\n        if !({}) {{\n            return Err(self);\n        }}\n",
+                                guard
+                            )
+                        })
+                        .unwrap_or_default();
+                    format!(
+                        r#"    /// `{action}`: {from} -> {to}
+    pub fn {name}(self) -> Result<{subject}<{to_type}>, Self> {{
+{check}        Ok({subject} {{ params: self.params, _state: PhantomData }})
+    }}"#,
+                        action = t.action,
+                        from = t.from,
+                        to = t.to,
+                        to_type = state_type(&t.to),
+                    )
+                })
+                .collect();
+            impls.push_str(&format!(
+                "\nimpl {}<{}> {{\n{}\n}}\n",
+                subject,
+                state_type(state),
+                methods.join("\n\n")
+            ));
+        }
+
+        let variants: String = machine
+            .states
+            .iter()
+            .map(|state| format!("    {0}({1}<{0}>),\n", state_type(state), subject))
+            .collect();
+
+        Some(format!(
+            r#"// Rust Generated Code - Typestate for {subject}
+
+use core::marker::PhantomData;
+
+{markers}
+/// `{subject}` whose lifecycle state is tracked in the type
+pub struct {subject}<S> {{
+    pub params: ValidationParams,
+    _state: PhantomData<S>,
+}}
+
+impl {subject}<{initial}> {{
+    pub fn new(params: ValidationParams) -> Self {{
+        {subject} {{ params, _state: PhantomData }}
+    }}
+}}
+{impls}
+/// Current state when it is only known at runtime
+pub enum {subject}State {{
+{variants}}}"#,
+            initial = state_type(&machine.initial),
+        ))
+    }
+}
+
+/// Start of the Kani harness module emitted by `RustStrategy::harnesses`
+const KANI_MODULE: &str = "\n#[cfg(kani)]\nmod verification {";
+
+impl RustStrategy {
+    fn new(options: &CodegenOptions) -> Self {
+        Self {
+            func_name: options.func_name.clone(),
+            no_std: options.no_std,
+            checked: options.overflow_risks.iter().map(|risk| risk.term.clone()).collect(),
+        }
+    }
+
+    /// `ValidationParams`, arbitrary under Kani so harnesses can quantify over it
+    fn params_struct(&self, fields: &[(String, String)]) -> String {
+        let members: String = fields
+            .iter()
+            .map(|(name, ty)| format!("    pub {}: {},\n", name, ty))
+            .collect();
+        let derives = if self.no_std && fields.iter().all(|(_, ty)| is_const_comparable(ty)) {
+            "Debug, Clone, Copy, PartialEq"
+        } else {
+            "Debug, Clone, PartialEq"
+        };
+        format!(
+            "/// Validation parameters structure\n#[derive({})]\n#[cfg_attr(kani, derive(kani::Arbitrary))]\npub struct ValidationParams {{\n{}}}\n",
+            derives, members
+        )
+    }
+
+    /// In the embedded profile the validator is a `const fn` when every field
+    /// can be compared in const context, with a macro that checks a
+    /// configuration at compile time
+    fn const_items(&self, field_types: &[String]) -> Option<String> {
+        if !self.no_std || !field_types.iter().all(|ty| is_const_comparable(ty)) {
+            return None;
+        }
+        Some(format!(
+            r#"
+/// Fails the build unless `$params` (a const `ValidationParams`) is valid
+#[macro_export]
+macro_rules! assert_valid_params {{
+    ($params:expr) => {{
+        const _: () = assert!(
+            $crate::Validator.{func_name}(&$params),
+            "ValidationParams violate the intent constraints"
+        );
+    }};
+}}
+"#,
+            func_name = self.func_name
+        ))
+    }
+
+    /// `#![no_std]` header line for the embedded profile
+    fn crate_attributes(&self) -> &'static str {
+        if self.no_std {
+            "#![no_std]\n"
+        } else {
+            ""
+        }
+    }
+
+    /// Kani harnesses: one checking the validator against the reference
+    /// expression, and one per top-level constraint checking that an
+    /// accepted input satisfies it
+    fn harnesses(&self, compound: &CompoundConstraint) -> String {
+        let func_name = &self.func_name;
+        // Destructure only the fields an expression reads, so no binding is unused
+        let bindings_for = |compound: &CompoundConstraint| {
+            let fields: Vec<String> = collect_variables(compound).into_iter().filter(|v| is_identifier(v)).collect();
+            if fields.is_empty() {
+                String::new()
+            } else {
+                format!("let ValidationParams {{ {}, .. }} = params.clone();", fields.join(", "))
+            }
+        };
+        let bindings = bindings_for(compound);
+        let reference = CodeGenerator.build_expression(compound, &BareVariables(self));
+
+        let conjuncts: Vec<&CompoundConstraint> = match compound {
+            CompoundConstraint::And(parts) => parts.iter().collect(),
+            other => vec![other],
+        };
+        let mut names: Vec<String> = Vec::new();
+        let mut checks = String::new();
+        for (index, conjunct) in conjuncts.iter().enumerate() {
+            let mut name = match conjunct {
+                CompoundConstraint::Simple(c) => harness_name(c),
+                _ => format!("constraint_{}", index + 1),
+            };
+            if names.contains(&name) {
+                name = format!("{}_{}", name, index + 1);
+            }
+            checks.push_str(&format!(
+                r#"
+    /// Accepted inputs satisfy `{summary}`
+    #[kani::proof]
+    fn check_{name}() {{
+        let params: ValidationParams = kani::any();
+        {bindings}
+        if Validator.{func_name}(&params) {{
+            assert!({condition});
+        }}
+    }}
+"#,
+                summary = literal::comment(&Verbalizer::new().compound(conjunct)),
+                bindings = bindings_for(conjunct),
+                condition = CodeGenerator.build_expression(conjunct, &BareVariables(self)),
+            ));
+            names.push(name);
+        }
 
-#[cfg(kani)]
-mod verification {{
+        format!(
+            r#"{module}
     use super::*;
 
+    /// The validator computes exactly the reference expression
     #[kani::proof]
     fn verify_{func_name}() {{
-        let validator = Validator;
-        let params = kani::any::<ValidationParams>();
-        let result = validator.{func_name}(&params);
-        kani::cover!(result == true);
-        kani::cover!(result == false);
+        let params: ValidationParams = kani::any();
+        let result = Validator.{func_name}(&params);
+        {bindings}
+        assert_eq!(result, {reference});
+        kani::cover!(result);
+        kani::cover!(!result);
     }}
-}}"#,
-            func_name = func_name,
-            contracts = contracts,
-            body = body,
-            assertions_code = assertions_code.trim()
+{checks}}}
+"#,
+            module = KANI_MODULE.trim_start(),
         )
     }
 }
 
+/// Integer and boolean fields compare with built-in operators, so a
+/// validator over them can be a `const fn`
+fn is_const_comparable(rust_type: &str) -> bool {
+    matches!(
+        rust_type,
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "bool"
+    )
+}
+
+/// `balance >= amount` -> `balance_ge_amount`
+fn harness_name(constraint: &Constraint) -> String {
+    let relation = match constraint.operator {
+        ConstraintOperator::GreaterThanOrEqual => "ge",
+        ConstraintOperator::LessThanOrEqual => "le",
+        ConstraintOperator::GreaterThan => "gt",
+        ConstraintOperator::LessThan => "lt",
+        ConstraintOperator::Equal => "eq",
+        ConstraintOperator::NotEqual => "ne",
+        ConstraintOperator::Contains => "contains",
+        ConstraintOperator::DoesNotContain => "not_contains",
+    };
+    let snake = |text: &str| -> String {
+        text.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join("_")
+            .to_lowercase()
+    };
+    let left = constraint.left_variable.replace('%', " mod ");
+    format!("{}_{}_{}", snake(&left), relation, snake(&constraint.right_value))
+}
+
 // --- Rust VerifiableStrategy Implementation ---
 
 impl VerifiableStrategy for RustStrategy {
@@ -1003,9 +2836,12 @@ impl VerifiableStrategy for RustStrategy {
             DataType::Uint32 => "u32".to_string(),
             DataType::Int64 => "i64".to_string(),
             DataType::Int32 => "i32".to_string(),
+            DataType::String if self.no_std => "&'static str".to_string(),
             DataType::String => "String".to_string(),
             DataType::Bool => "bool".to_string(),
             DataType::Decimal => "f64".to_string(),
+            DataType::List(element) if self.no_std => format!("&'static [{}]", self.map_type(element)),
+            DataType::List(element) => format!("Vec<{}>", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
@@ -1028,41 +2864,32 @@ impl VerifiableStrategy for RustStrategy {
             ArithmeticOperator::Divide => {
                 format!("{}{}{}", left, op.rust_symbol(), right)
             }
+            ArithmeticOperator::Modulo => self.format_modulo(left, right),
         }
     }
 
-    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let fields: Vec<String> = schema
+    /// The Schema-typed `ValidationParams` struct
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        let mut fields: Vec<(String, String)> = schema
             .fields
             .iter()
-            .map(|(name, dt)| {
-                format!("pub {}: {}", name, self.map_type(dt))
-            })
+            .map(|(name, dt)| (name.clone(), self.map_type(dt)))
             .collect();
-        
-        let fields_str = if fields.is_empty() {
-            "".to_string()
-        } else {
-            format!("\n    {}", fields.join(",\n    "))
-        };
-        
-        format!("pub struct ValidationParams {{ {}}}", fields_str)
+        fields.sort();
+        self.params_struct(&fields)
     }
 
     fn fn_end(&self) -> String {
         "}".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"//! Rust Generated Code - Memory Safe with Formal Verification (v0.1.5-alpha)
-//! Use with Kani for bounded model checking
-//! Patent Application: 63/928,407
-//! Traceability ID: {}
-//! Correct by Design, Verified by Construction
-
-"#,
-            traceability_id
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner(
+            "//!",
+            &[
+                "Rust Generated Code - Memory Safe with Formal Verification (v0.1.5-alpha)",
+                "Use with Kani for bounded model checking",
+            ],
         )
     }
 
@@ -1073,7 +2900,19 @@ impl VerifiableStrategy for RustStrategy {
 
 // --- TypeScript Strategy ---
 
-struct TypeScriptStrategy;
+struct TypeScriptStrategy {
+    /// Zod schema output instead of a `Validator` class
+    zod: bool,
+    /// `bigint` for 64-bit integer fields
+    bigint: bool,
+    /// Fields the Schema types as something other than a 64-bit integer;
+    /// every field is `bigint` without a Schema
+    number_fields: Vec<String>,
+    /// Field widths masks and shifts are computed at
+    widths: BitWidths,
+    /// Terms at risk of leaving the doubles' exact integers, computed in `BigInt`
+    widened: Vec<String>,
+}
 
 impl CodegenStrategy for TypeScriptStrategy {
     fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
@@ -1108,6 +2947,8 @@ export class Validator {{
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "===",
             ConstraintOperator::NotEqual => "!==",
+            ConstraintOperator::Contains => "includes",
+            ConstraintOperator::DoesNotContain => "!includes",
         }
     }
 
@@ -1115,6 +2956,91 @@ export class Validator {{
         format!("params.{}", name)
     }
 
+    /// `%` follows the sign of the dividend; shifted into `[0, |divisor|)`
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("((({} % {d}) + Math.abs({d})) % Math.abs({d}))", dividend, d = divisor)
+    }
+
+    /// Sums, differences and products past 2^53 compared exactly in
+    /// `BigInt`; a quotient or remainder stays a number, which it fits
+    fn format_overflow_checked(&self, constraint: &Constraint, term: &ArithmeticTerm) -> Option<String> {
+        if matches!(term.operator, ArithmeticOperator::Divide | ArithmeticOperator::Modulo)
+            || !self.widened.contains(&term.to_string())
+        {
+            return None;
+        }
+        let widen = |value: &str| match is_identifier(value) {
+            true => Some(format!("BigInt({})", self.format_variable(value))),
+            false => value.parse::<i128>().ok().map(|literal| format!("{}n", literal)),
+        };
+        Some(format!(
+            "{} {} {} {} {}",
+            widen(&constraint.left_variable)?,
+            self.format_operator(&constraint.operator),
+            widen(&term.left)?,
+            term.operator.symbol(),
+            widen(&term.right)?
+        ))
+    }
+
+    /// Number operators work on 32-bit signed integers, so a 32-bit field
+    /// uses them directly (`>>> 0` reads the bits unsigned) and a 64-bit
+    /// one goes through `BigInt` and back
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        let width = self.widths.of(&bitwise.operand);
+        let symbol = bitwise.operator.symbol();
+        let bigint = self.is_bigint_field(&bitwise.operand);
+        if width.bits == 32 && !bigint {
+            return match (bitwise.operator, width.signed) {
+                (BitwiseOperator::ShiftRight, false) => format!("({} >>> {})", operand, mask),
+                (_, false) => format!("(({} {} {}) >>> 0)", operand, symbol, mask),
+                (_, true) => format!("({} {} {})", operand, symbol, mask),
+            };
+        }
+        let function = if width.signed { "asIntN" } else { "asUintN" };
+        let bits = format!("BigInt.{}({}, BigInt({}) {} BigInt({}))", function, width.bits, operand, symbol, mask);
+        match bigint {
+            true => bits,
+            false => format!("Number({})", bits),
+        }
+    }
+
+    /// Numbers are doubles, so `Math.abs` cannot overflow; `Math` rejects
+    /// `bigint`, which gets conditionals instead
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match (function, self.bigint) {
+            (MathFunction::Min, true) => fold_pairs(args, |a, b| format!("({a} <= {b} ? {a} : {b})", a = a, b = b)),
+            (MathFunction::Max, true) => fold_pairs(args, |a, b| format!("({a} >= {b} ? {a} : {b})", a = a, b = b)),
+            (MathFunction::Abs, true) => format!("({x} < 0 ? -{x} : {x})", x = args.join(", ")),
+            (MathFunction::Sum, _) => typescript_sum(&args.join(", ")),
+            (MathFunction::Count, _) => format!("{}.length", args.join(", ")),
+            (MathFunction::Average, _) => format!(
+                "({list}.length === 0 ? 0 : Math.floor({sum} / {list}.length))",
+                list = args.join(", "),
+                sum = typescript_sum(&args.join(", "))
+            ),
+            _ => format!("Math.{}({})", function.name(), args.join(", ")),
+        }
+    }
+
+    /// Not with `bigint`, where a list's element type would decide between
+    /// `number` and `bigint` arithmetic
+    fn supports_lists(&self) -> bool {
+        !self.bigint
+    }
+
+    /// A `Set` drops repeats, so its size falls short of the count
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        Some(match values {
+            [list] => format!("new Set({list}).size === {list}.length", list = list),
+            _ => format!("new Set([{}]).size === {}", values.join(", "), values.len()),
+        })
+    }
+
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("{}.includes({})", haystack, needle)
+    }
+
     fn logical_and(&self) -> &'static str {
         "&&"
     }
@@ -1127,6 +3053,68 @@ export class Validator {{
         format!("!({})", expr)
     }
 
+    fn inline_comment(&self, text: &str) -> Option<String> {
+        Some(format!("/* {} */", text))
+    }
+
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        Some(format!("[{}].filter(Boolean).length", parts.join(", ")))
+    }
+
+    /// Function declarations are hoisted, so the helpers can follow the
+    /// class; Zod schemas and bigint literals are inlined instead
+    fn predicate_helper(&self, name: &str, body: &str) -> Option<String> {
+        if self.zod || self.bigint {
+            return None;
+        }
+        Some(format!(
+            "/** Named predicate `{name}` */\nexport function {name}(params: any): boolean {{\n  return {body};\n}}",
+            name = name,
+            body = body
+        ))
+    }
+
+    /// Integer literals compared with a `bigint` field take the `n` suffix
+    fn format_comparison(&self, constraint: &Constraint) -> String {
+        if constraint.operator.is_membership() {
+            return self.format_membership_comparison(constraint);
+        }
+        let widened = ArithmeticTerm::parse(&constraint.right_value)
+            .and_then(|term| self.format_overflow_checked(constraint, &term));
+        if let Some(widened) = widened {
+            return widened;
+        }
+        let left = constraint.bitwise().map_or_else(|| constraint.left_variable.clone(), |bitwise| bitwise.operand);
+        let right = if self.is_bigint_field(&left) && constraint.right_value.parse::<i128>().is_ok() {
+            format!("{}n", constraint.right_value)
+        } else {
+            self.format_right_operand(&constraint.right_value)
+        };
+        format!(
+            "{} {} {}",
+            self.format_left_operand(constraint),
+            self.format_operator(&constraint.operator),
+            right
+        )
+    }
+
+    /// The Zod schema in Zod mode; in bigint mode the interface members
+    /// followed by the JSON parser (every field an integer without a Schema)
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        if !self.zod && !self.bigint {
+            return None;
+        }
+        let fields = inferred_fields(compound);
+        if self.zod {
+            return Some(self.zod_schema(compound, &fields));
+        }
+        let members: String = fields
+            .iter()
+            .map(|(name, dt)| format!("  {}: {};\n", name, self.map_type(dt)))
+            .collect();
+        Some(format!("{}{}", members, self.bigint_parser("ValidationParams", &fields)))
+    }
+
     fn wrap_verified_function(
         &self,
         func_name: &str,
@@ -1134,6 +3122,17 @@ export class Validator {{
         body: &str,
         assertions: &str,
     ) -> String {
+        if self.zod {
+            return self.zod_module(func_name, contracts);
+        }
+        // The JSON parser follows the interface it builds
+        let (contracts, parser) = contracts.split_once(TS_BIGINT_PARSER).unwrap_or((contracts, ""));
+        let parser = if parser.is_empty() {
+            String::new()
+        } else {
+            format!("\n{}{}", TS_BIGINT_PARSER, parser.trim_end())
+        };
+
         let assertions_code = if !assertions.is_empty() {
             format!(
                 r#"
@@ -1163,13 +3162,205 @@ export class Validator {{
 {assertions_code}
     return {body};
   }}
-}}"#,
+}}{parser}"#,
             func_name = func_name,
-            contracts = contracts,
+            contracts = contracts.trim_end(),
             body = body,
             assertions_code = assertions_code.trim()
         )
     }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: String = assignments
+            .iter()
+            .map(|(field, value)| format!("  next.{} = {};\n", field, value))
+            .collect();
+        let post = post_check
+            .map(|check| format!("  if (!({})) {{\n    throw new Error(\"postcondition violated\");\n  }}\n", check))
+            .unwrap_or_default();
+
+        Some(format!(
+            r#"// TypeScript Generated Code - State Update
+
+/** Applies `{func_name}`: checks the precondition, updates the state, checks the postcondition */
+export function {func_name}(params: ValidationParams): ValidationParams {{
+  if (!({guard})) {{
+    throw new Error("precondition violated");
+  }}
+  const next: ValidationParams = {{ ...params }};
+{updates}{post}  return next;
+}}"#
+        ))
+    }
+}
+
+/// First line of the bigint JSON parser; `emit_contracts` output is split
+/// here so the parser follows the interface
+const TS_BIGINT_PARSER: &str = "\n/** Converts a JSON value";
+
+impl TypeScriptStrategy {
+    fn new(options: &CodegenOptions) -> Self {
+        Self {
+            zod: options.zod,
+            bigint: options.bigint,
+            number_fields: Vec::new(),
+            widths: BitWidths::default(),
+            widened: options.overflow_risks.iter().map(|risk| risk.term.clone()).collect(),
+        }
+    }
+
+    /// Types fields from `schema`: only its 64-bit integer fields are `bigint`
+    fn with_schema(mut self, schema: &Schema) -> Self {
+        self.number_fields = schema
+            .fields
+            .iter()
+            .filter(|(_, dt)| !matches!(dt, DataType::Uint64 | DataType::Int64))
+            .map(|(name, _)| name.clone())
+            .collect();
+        self.widths = BitWidths::new(schema);
+        self
+    }
+
+    fn is_bigint_field(&self, name: &str) -> bool {
+        self.bigint && !self.number_fields.iter().any(|field| field == name)
+    }
+
+    /// `parseValidationParams`: builds `ValidationParams` from parsed JSON,
+    /// where 64-bit integers arrive as decimal strings (or safe integers)
+    fn bigint_parser(&self, type_name: &str, fields: &[(String, DataType)]) -> String {
+        let members: String = fields
+            .iter()
+            .map(|(name, dt)| {
+                if self.bigint && matches!(dt, DataType::Uint64 | DataType::Int64) {
+                    format!("    {0}: toBigInt(json.{0}, \"{0}\"),\n", name)
+                } else {
+                    format!("    {}: json.{} as {},\n", name, name, self.map_type(dt))
+                }
+            })
+            .collect();
+        format!(
+            r#"{marker}: a decimal string, a safe integer or a bigint */
+function toBigInt(value: unknown, field: string): bigint {{
+  if (typeof value === "bigint") return value;
+  if (typeof value === "string" && /^-?\d+$/.test(value)) return BigInt(value);
+  if (typeof value === "number" && Number.isSafeInteger(value)) return BigInt(value);
+  throw new TypeError(`${{field}}: expected an integer or a decimal string`);
+}}
+
+/** Builds `{type_name}` from parsed JSON; 64-bit integers may be decimal strings */
+export function parseValidationParams(json: Record<string, unknown>): {type_name} {{
+  return {{
+{members}  }};
+}}
+"#,
+            marker = TS_BIGINT_PARSER.trim_start()
+        )
+    }
+
+    /// `ValidationParamsSchema`: numeric comparisons against literals become
+    /// Zod checks on the field, every other conjunct a `.refine` over the
+    /// parsed object
+    fn zod_schema(&self, compound: &CompoundConstraint, fields: &[(String, DataType)]) -> String {
+        let (bounds, cross_field) = field_bounds(compound);
+
+        let mut members = String::new();
+        for (name, dt) in fields {
+            let mut checks: Vec<(&str, String)> = bounds.get(name.as_str()).cloned().unwrap_or_default();
+            let has = |checks: &Vec<(&str, String)>, key: &str| checks.iter().any(|(k, _)| *k == key);
+            let is_bigint = self.bigint && matches!(dt, DataType::Uint64 | DataType::Int64);
+            let array;
+            let base = match dt {
+                // JSON carries 64-bit integers as strings; coerce them
+                DataType::Uint64 | DataType::Int64 if is_bigint => {
+                    if *dt == DataType::Uint64 && !has(&checks, "ge") && !has(&checks, "gt") {
+                        checks.push(("ge", "0".to_string()));
+                    }
+                    "z.coerce.bigint()"
+                }
+                DataType::Uint64 | DataType::Uint32 => {
+                    if !has(&checks, "ge") && !has(&checks, "gt") {
+                        checks.push(("ge", "0".to_string()));
+                    }
+                    "z.number().int()"
+                }
+                DataType::Int64 | DataType::Int32 => "z.number().int()",
+                DataType::String => "z.string()",
+                DataType::Bool => "z.boolean()",
+                DataType::Decimal => "z.number()",
+                DataType::List(element) => {
+                    array = zod_array(element);
+                    array.as_str()
+                }
+                DataType::Custom { range_min, range_max, .. } => {
+                    if let (Some(min), false) = (range_min, has(&checks, "ge")) {
+                        checks.push(("ge", min.to_string()));
+                    }
+                    if let (Some(max), false) = (range_max, has(&checks, "le")) {
+                        checks.push(("le", max.to_string()));
+                    }
+                    "z.number().int()"
+                }
+            };
+            let checks: String = checks
+                .iter()
+                .map(|(key, value)| {
+                    let method = match *key {
+                        "ge" => "gte",
+                        "le" => "lte",
+                        other => other,
+                    };
+                    let suffix = if is_bigint { "n" } else { "" };
+                    format!(".{}({}{})", method, value, suffix)
+                })
+                .collect();
+            members.push_str(&format!("    {}: {}{},\n", name, base, checks));
+        }
+
+        let refinements: String = cross_field
+            .iter()
+            .map(|conjunct| {
+                let used: Vec<String> = collect_variables(conjunct).into_iter().filter(|v| is_identifier(v)).collect();
+                let path = match conjunct {
+                    CompoundConstraint::Simple(c) => format!(", path: [\"{}\"]", c.left_variable),
+                    _ => String::new(),
+                };
+                format!(
+                    "\n  .refine(({{ {} }}) => {}, {{ message: {}{} }})",
+                    used.join(", "),
+                    CodeGenerator.build_expression(conjunct, &BareVariables(self)),
+                    self.format_string(&Verbalizer::new().compound(conjunct)),
+                    path
+                )
+            })
+            .collect();
+
+        format!("export const ValidationParamsSchema = z\n  .object({{\n{}  }}){};\n", members, refinements)
+    }
+
+    /// The schema, its inferred type and a type guard over untrusted input
+    fn zod_module(&self, func_name: &str, schema: &str) -> String {
+        format!(
+            r#"// TypeScript Generated Code - Zod schema
+// Parse untrusted input with `ValidationParamsSchema.safeParse(...)`
+
+import {{ z }} from "zod";
+
+{schema}
+export type ValidationParams = z.infer<typeof ValidationParamsSchema>;
+
+/** `true` if `params` satisfies the intent constraints */
+export function {func_name}(params: unknown): params is ValidationParams {{
+  return ValidationParamsSchema.safeParse(params).success;
+}}
+"#
+        )
+    }
 }
 
 // --- TypeScript VerifiableStrategy Implementation ---
@@ -1177,11 +3368,13 @@ export class Validator {{
 impl VerifiableStrategy for TypeScriptStrategy {
     fn map_type(&self, dt: &DataType) -> String {
         match dt {
+            DataType::Uint64 | DataType::Int64 if self.bigint => "bigint".to_string(),
             DataType::Uint64 | DataType::Uint32 => "number".to_string(),
             DataType::Int64 | DataType::Int32 => "number".to_string(),
             DataType::String => "string".to_string(),
             DataType::Bool => "boolean".to_string(),
             DataType::Decimal => "number".to_string(),
+            DataType::List(element) => format!("{}[]", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
@@ -1205,12 +3398,12 @@ impl VerifiableStrategy for TypeScriptStrategy {
             ArithmeticOperator::Divide => {
                 format!("{}{}{}", left, op.rust_symbol(), right)
             }
+            ArithmeticOperator::Modulo => self.format_modulo(left, right),
         }
     }
 
     fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let fields: Vec<String> = schema
-            .fields
+        let fields: Vec<String> = sorted_fields(schema)
             .iter()
             .map(|(name, dt)| {
                 format!("{}: {}", name, self.map_type(dt))
@@ -1230,16 +3423,13 @@ impl VerifiableStrategy for TypeScriptStrategy {
         "}".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"// TypeScript Generated Code (v0.1.5-alpha)
-// Use with ts-auto-guard for runtime type checking
-// Patent Application: 63/928,407
-// Traceability ID: {}
-// Correct by Design, Verified by Construction
-
-"#,
-            traceability_id
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner(
+            "//",
+            &[
+                "TypeScript Generated Code (v0.1.5-alpha)",
+                "Use with ts-auto-guard for runtime type checking",
+            ],
         )
     }
 
@@ -1250,7 +3440,12 @@ impl VerifiableStrategy for TypeScriptStrategy {
 
 // --- Python Strategy ---
 
-struct PythonStrategy;
+struct PythonStrategy {
+    /// Pydantic v2 model output instead of a plain validator
+    pydantic: bool,
+    /// Field widths `<<` wraps to
+    widths: BitWidths,
+}
 
 impl CodegenStrategy for PythonStrategy {
     fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
@@ -1304,6 +3499,8 @@ class Validator:
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "==",
             ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "in",
+            ConstraintOperator::DoesNotContain => "not in",
         }
     }
 
@@ -1311,6 +3508,58 @@ class Validator:
         format!("params['{}']", name)
     }
 
+    /// Python's `%` takes the sign of the divisor
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("({} % abs({}))", dividend, divisor)
+    }
+
+    /// Integers are unbounded two's complement, so only `<<` leaves the
+    /// field's width and is wrapped back to it
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        let bits = format!("({} {} {})", operand, bitwise.operator.symbol(), mask);
+        match bitwise.operator {
+            BitwiseOperator::ShiftLeft => {
+                wrapped(&bits, self.widths.of(&bitwise.operand), |a, b| format!("({} & {})", a, b))
+            }
+            _ => bits,
+        }
+    }
+
+    /// Integers are unbounded, so `abs` cannot overflow; `sum` is the
+    /// builtin, `count` is `len`
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Count => format!("len({})", args.join(", ")),
+            MathFunction::Average => format!("(sum({list}) // len({list}) if {list} else 0)", list = args.join(", ")),
+            _ => format!("{}({})", function.name(), args.join(", ")),
+        }
+    }
+
+    fn supports_lists(&self) -> bool {
+        true
+    }
+
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        Some(match values {
+            [list] => format!("len(set({list})) == len({list})", list = list),
+            _ => format!("len({{{}}}) == {}", values.join(", "), values.len()),
+        })
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(format!("({} if {} else {})", then_value, condition, else_value))
+    }
+
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("{} in {}", needle, haystack)
+    }
+
+    /// Chained comparison: `(1 <= params['amount'] <= 100)`
+    fn format_range(&self, range: &Between) -> String {
+        let op = if range.inclusive { "<=" } else { "<" };
+        format!("({} {op} {} {op} {})", range.low, self.format_variable(&range.variable), range.high)
+    }
+
     fn logical_and(&self) -> &'static str {
         "and"
     }
@@ -1323,6 +3572,38 @@ class Validator:
         format!("not ({})", expr)
     }
 
+    /// A conditional expression reads as the implication it is
+    fn format_implication(&self, antecedent: &str, consequent: &str) -> String {
+        format!("({} if {} else True)", consequent, antecedent)
+    }
+
+    /// `True` counts as 1
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        Some(format!("sum([{}])", parts.join(", ")))
+    }
+
+    /// Module-level functions, resolved when the validator runs; Pydantic
+    /// validators inline the definitions
+    fn predicate_helper(&self, name: &str, body: &str) -> Option<String> {
+        if self.pydantic {
+            return None;
+        }
+        Some(format!(
+            "def {name}(params: Dict[str, Any]) -> bool:\n    \"\"\"Named predicate `{name}`.\"\"\"\n    return {body}",
+            name = name,
+            body = body
+        ))
+    }
+
+    /// The Pydantic model, in Pydantic mode (every field `int` without a Schema)
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        if !self.pydantic {
+            return None;
+        }
+        let fields = inferred_fields(compound);
+        Some(self.pydantic_model(compound, &fields))
+    }
+
     fn wrap_verified_function(
         &self,
         func_name: &str,
@@ -1330,6 +3611,10 @@ class Validator:
         body: &str,
         assertions: &str,
     ) -> String {
+        if self.pydantic {
+            return self.pydantic_module(func_name, contracts);
+        }
+
         let assertions_code = if !assertions.is_empty() {
             format!(
                 r#"
@@ -1385,6 +3670,167 @@ class Validator:
             assertions_code = assertions_code.trim()
         )
     }
+
+    fn format_next_variable(&self, name: &str) -> String {
+        format!("next_state['{}']", name)
+    }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: String = assignments
+            .iter()
+            .map(|(field, value)| format!("    next_state['{}'] = {}\n", field, value))
+            .collect();
+        let post = post_check
+            .map(|check| format!("    if not ({}):\n        raise ValueError(\"postcondition violated\")\n", check))
+            .unwrap_or_default();
+
+        Some(format!(
+            r#"# Python Generated Code - State Update
+from typing import Any, Dict
+
+
+def {func_name}(params: Dict[str, Any]) -> Dict[str, Any]:
+    """Applies `{func_name}`: checks the precondition, updates the state, checks the postcondition."""
+    if not ({guard}):
+        raise ValueError("precondition violated")
+    next_state = dict(params)
+{updates}{post}    return next_state"#
+        ))
+    }
+}
+
+impl PythonStrategy {
+    fn new(options: &CodegenOptions) -> Self {
+        Self {
+            pydantic: options.pydantic,
+            widths: BitWidths::default(),
+        }
+    }
+
+    /// Wraps shifts to the widths of `schema`'s fields
+    fn with_schema(mut self, schema: &Schema) -> Self {
+        self.widths = BitWidths::new(schema);
+        self
+    }
+
+    /// `ValidationParams` as a Pydantic v2 model. A top-level comparison of
+    /// a field against a number becomes a `Field` bound; every other
+    /// conjunct becomes a `model_validator` over the whole model.
+    fn pydantic_model(&self, compound: &CompoundConstraint, fields: &[(String, DataType)]) -> String {
+        let (bounds, cross_field) = field_bounds(compound);
+
+        let mut members = String::new();
+        for (name, dt) in fields {
+            let mut kwargs: Vec<(&str, String)> = bounds.get(name.as_str()).cloned().unwrap_or_default();
+            let has = |kwargs: &Vec<(&str, String)>, key: &str| kwargs.iter().any(|(k, _)| *k == key);
+            let type_name = match dt {
+                DataType::Uint64 | DataType::Uint32 => {
+                    if !has(&kwargs, "ge") && !has(&kwargs, "gt") {
+                        kwargs.push(("ge", "0".to_string()));
+                    }
+                    "int".to_string()
+                }
+                DataType::Custom { range_min, range_max, .. } => {
+                    if let (Some(min), false) = (range_min, has(&kwargs, "ge")) {
+                        kwargs.push(("ge", min.to_string()));
+                    }
+                    if let (Some(max), false) = (range_max, has(&kwargs, "le")) {
+                        kwargs.push(("le", max.to_string()));
+                    }
+                    "int".to_string()
+                }
+                other => self.map_type(other),
+            };
+            if kwargs.is_empty() {
+                members.push_str(&format!("    {}: {}\n", name, type_name));
+            } else {
+                let kwargs: Vec<String> = kwargs.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+                members.push_str(&format!("    {}: {} = Field({})\n", name, type_name, kwargs.join(", ")));
+            }
+        }
+
+        let mut validators = String::new();
+        let mut names: Vec<String> = Vec::new();
+        for (index, conjunct) in cross_field.iter().enumerate() {
+            let mut name = match conjunct {
+                CompoundConstraint::Simple(c) => harness_name(c),
+                _ => format!("constraint_{}", index + 1),
+            };
+            if names.contains(&name) {
+                name = format!("{}_{}", name, index + 1);
+            }
+            let used: Vec<String> = collect_variables(conjunct).into_iter().filter(|v| is_identifier(v)).collect();
+            let bindings = if used.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "        {} = {}\n",
+                    used.join(", "),
+                    used.iter().map(|v| format!("self.{}", v)).collect::<Vec<_>>().join(", ")
+                )
+            };
+            validators.push_str(&format!(
+                r#"
+    @model_validator(mode="after")
+    def check_{name}(self) -> "ValidationParams":
+{bindings}        if not ({condition}):
+            raise ValueError({message})
+        return self
+"#,
+                condition = CodeGenerator.build_expression(conjunct, &BareVariables(self)),
+                message = self.format_string(&format!("constraint violated: {}", Verbalizer::new().compound(conjunct))),
+            ));
+            names.push(name);
+        }
+
+        let imports = if fields.iter().any(|(_, dt)| *dt == DataType::Decimal) {
+            "from decimal import Decimal\n"
+        } else {
+            ""
+        };
+        format!(
+            r#"{imports}from typing import Any, Dict
+
+from pydantic import BaseModel, Field, ValidationError, model_validator
+
+
+class ValidationParams(BaseModel):
+    """Validation parameters, checked against the intent constraints on construction."""
+
+{members}{validators}"#
+        )
+    }
+
+    /// The model followed by a boolean validator over untrusted input
+    fn pydantic_module(&self, func_name: &str, model: &str) -> String {
+        format!(
+            r#"# Python Generated Code - Pydantic v2 model
+# Invalid input raises pydantic.ValidationError on construction
+
+{model}
+
+
+class Validator:
+    """Auto-generated validator from Crucible Intent specification."""
+
+    @staticmethod
+    def {func_name}(params: Dict[str, Any]) -> bool:
+        """True if `params` is a valid `ValidationParams`."""
+        try:
+            ValidationParams.model_validate(params)
+        except ValidationError:
+            return False
+        return True
+"#,
+            model = model.trim_end()
+        )
+    }
 }
 
 // --- Python VerifiableStrategy Implementation ---
@@ -1397,6 +3843,7 @@ impl VerifiableStrategy for PythonStrategy {
             DataType::String => "str".to_string(),
             DataType::Bool => "bool".to_string(),
             DataType::Decimal => "Decimal".to_string(),
+            DataType::List(element) => format!("list[{}]", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
@@ -1411,12 +3858,12 @@ impl VerifiableStrategy for PythonStrategy {
             ArithmeticOperator::Add => format!("{}_add({}, {}", left, right, ")"),
             ArithmeticOperator::Multiply => format!("{}_multiply({}, {}", left, right, ")"),
             ArithmeticOperator::Divide => format!("{}{}{}", left, op.rust_symbol(), right),
+            ArithmeticOperator::Modulo => self.format_modulo(left, right),
         }
     }
 
     fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let fields: Vec<String> = schema
-            .fields
+        let fields: Vec<String> = sorted_fields(schema)
             .iter()
             .map(|(name, dt)| {
                 format!("{}: {}", name, self.map_type(dt))
@@ -1436,16 +3883,13 @@ impl VerifiableStrategy for PythonStrategy {
         "".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"# Python Generated Code (v0.1.5-alpha)
-# Use with hypothesis for property-based testing
-# Patent Application: 63/928,407
-# Traceability ID: {}
-# Correct by Design, Verified by Construction
-
-"#,
-            traceability_id
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner(
+            "#",
+            &[
+                "Python Generated Code (v0.1.5-alpha)",
+                "Use with hypothesis for property-based testing",
+            ],
         )
     }
 
@@ -1456,7 +3900,120 @@ impl VerifiableStrategy for PythonStrategy {
 
 // --- Solidity Strategy (Smart Contract Verification) ---
 
-struct SolidityStrategy;
+#[derive(Default)]
+struct SolidityStrategy {
+    /// Fields of Schema type `String`, compared by hash
+    string_fields: Vec<String>,
+}
+
+/// `require(cond, CustomError())` needs 0.8.27 outside via-IR
+const SOLIDITY_PRAGMA: &str = "pragma solidity ^0.8.27;";
+
+/// Byte-wise substring search backing `Contains`/`DoesNotContain`
+const SOLIDITY_CONTAINS: &str = r#"/// @notice Whether `needle` occurs in `haystack`
+function contains(string memory haystack, string memory needle) pure returns (bool) {
+    bytes memory h = bytes(haystack);
+    bytes memory n = bytes(needle);
+    if (n.length > h.length) return false;
+    for (uint256 start = 0; start <= h.length - n.length; start++) {
+        uint256 i = 0;
+        while (i < n.length && h[start + i] == n[i]) i++;
+        if (i == n.length) return true;
+    }
+    return false;
+}
+
+"#;
+
+impl SolidityStrategy {
+    fn with_schema(schema: &Schema) -> Self {
+        Self::with_members(&Self::schema_members(schema))
+    }
+
+    /// For a `ValidationParams` with `members`, each a field name and its type
+    fn with_members(members: &[(String, String)]) -> Self {
+        let string_fields = members.iter().filter(|(_, ty)| ty == "string").map(|(name, _)| name.clone());
+        Self {
+            string_fields: string_fields.collect(),
+        }
+    }
+
+    /// The `ValidationParams` members for a Schema, sorted by name
+    fn schema_members(schema: &Schema) -> Vec<(String, String)> {
+        sorted_fields(schema).into_iter().map(|(name, dt)| (name, Self::default().map_type(&dt))).collect()
+    }
+
+    /// The `ValidationParams` members without a Schema: strings where
+    /// `inferred_fields` finds them, `uint256` for every other field
+    fn inferred_members(compound: &CompoundConstraint) -> Vec<(String, String)> {
+        inferred_fields(compound)
+            .into_iter()
+            .map(|(name, dt)| match dt {
+                DataType::String => (name, Self::default().map_type(&dt)),
+                _ => (name, "uint256".to_string()),
+            })
+            .collect()
+    }
+
+    /// `==`/`!=` between strings: a field against a string literal, or two
+    /// fields one of which the Schema types as a string
+    fn is_string_equality(&self, constraint: &Constraint) -> bool {
+        let is_string_field = |name: &str| self.string_fields.iter().any(|field| field == name);
+        matches!(constraint.operator, ConstraintOperator::Equal | ConstraintOperator::NotEqual)
+            && is_identifier(&constraint.left_variable)
+            && (compares_with_text(constraint)
+                || is_string_field(&constraint.left_variable)
+                || is_string_field(&constraint.right_value))
+    }
+
+    /// Struct declaration for the validated parameters
+    fn params_struct(&self, fields: &[(String, String)]) -> String {
+        let members: String = fields
+            .iter()
+            .map(|(name, ty)| format!("    {} {};\n", ty, name))
+            .collect();
+        format!("/// @notice Inputs checked by {{Validator}}\nstruct ValidationParams {{\n{}}}\n", members)
+    }
+
+    /// One custom error per top-level conjunct, and a free function that
+    /// reverts with the error of the first violated one. Conjuncts that are
+    /// themselves `or`/`not` groups are checked as a whole.
+    fn checks(&self, compound: &CompoundConstraint) -> String {
+        let conjuncts: Vec<&CompoundConstraint> = match compound {
+            CompoundConstraint::And(parts) => parts.iter().collect(),
+            other => vec![other],
+        };
+
+        let mut names: Vec<String> = Vec::new();
+        let mut errors = String::new();
+        let mut requires = String::new();
+        for (index, conjunct) in conjuncts.iter().enumerate() {
+            let mut name = match conjunct {
+                CompoundConstraint::Simple(c) => solidity_error_name(c),
+                _ => format!("IntentConstraint{}", index + 1),
+            };
+            if names.contains(&name) {
+                name = format!("{}{}", name, index + 1);
+            }
+            errors.push_str(&format!(
+                "/// @notice Reverted unless {}\nerror {}();\n",
+                literal::comment(&Verbalizer::new().compound(conjunct)),
+                name
+            ));
+            requires.push_str(&format!(
+                "    require({}, {}());\n",
+                CodeGenerator.build_expression(conjunct, self),
+                name
+            ));
+            names.push(name);
+        }
+
+        let helper = if has_membership(compound) { SOLIDITY_CONTAINS } else { "" };
+        format!(
+            "{helper}{errors}\n/// @notice Reverts with the custom error of the first violated constraint\nfunction requireValidationParams(ValidationParams memory params) pure {{\n{requires}}}\n"
+        )
+    }
+}
 
 impl CodegenStrategy for SolidityStrategy {
     fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
@@ -1464,22 +4021,16 @@ impl CodegenStrategy for SolidityStrategy {
             r#"// SPDX-License-Identifier: MIT
 // Solidity Generated Code - Smart Contract Verification
 // Use with Slither for security analysis, Echidna for property testing
+{pragma}
 
-struct ValidationParams {{
-    // Define your validation parameters here
-}}
-
+/// @title Validator
 contract Validator {{
-    /// Validates the given parameters against the intent constraints.
+    /// @notice Validates the given parameters against the intent constraints
     function {func_name}(ValidationParams memory params) public pure returns (bool) {{
         return {body};
     }}
-
-    /// View function for external validation queries
-    function validate(ValidationParams memory params) external view returns (bool) {{
-        return this.{func_name}(params);
-    }}
 }}"#,
+            pragma = SOLIDITY_PRAGMA,
             func_name = func_name,
             body = body
         )
@@ -1493,6 +4044,8 @@ contract Validator {{
             ConstraintOperator::LessThan => "<",
             ConstraintOperator::Equal => "==",
             ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "contains",
+            ConstraintOperator::DoesNotContain => "!contains",
         }
     }
 
@@ -1500,6 +4053,45 @@ contract Validator {{
         format!("params.{}", name)
     }
 
+    fn format_string(&self, text: &str) -> String {
+        literal::solidity_string(text)
+    }
+
+    /// `%` on `int256` follows the sign of the dividend; shifting by the
+    /// divisor makes the remainder non-negative for a positive divisor and
+    /// stays valid for unsigned fields, where unary minus does not compile
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("((({} % {d}) + {d}) % {d})", dividend, d = divisor)
+    }
+
+    /// No built-ins, so conditionals; `0 - x` compiles for unsigned fields,
+    /// and checked arithmetic reverts on `abs(type(int256).min)`
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Min => fold_pairs(args, |a, b| format!("({a} <= {b} ? {a} : {b})", a = a, b = b)),
+            MathFunction::Max => fold_pairs(args, |a, b| format!("({a} >= {b} ? {a} : {b})", a = a, b = b)),
+            MathFunction::Abs => format!("({x} >= 0 ? {x} : 0 - {x})", x = args.join(", ")),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        }
+    }
+
+    /// Strings have no `==`; they are equal when their hashes are
+    fn format_relation(&self, constraint: &Constraint, left: &str, right: &str) -> String {
+        let operator = self.format_operator(&constraint.operator);
+        match self.is_string_equality(constraint) {
+            true => format!("keccak256(bytes({})) {} keccak256(bytes({}))", left, operator, right),
+            false => format!("{} {} {}", left, operator, right),
+        }
+    }
+
+    /// Solidity strings have no substring search; `contains` is a free
+    /// function emitted next to the validator
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("contains({}, {})", haystack, needle)
+    }
+
     fn logical_and(&self) -> &'static str {
         "&&"
     }
@@ -1512,50 +4104,122 @@ contract Validator {{
         format!("!({})", expr)
     }
 
+    fn inline_comment(&self, text: &str) -> Option<String> {
+        Some(format!("/* {} */", text))
+    }
+
     fn wrap_assertion(&self, condition: &str) -> String {
         format!("require({});", condition)
     }
 
+    /// Struct, custom errors and the reverting checker, all at file level
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let members = Self::inferred_members(compound);
+        Some(format!("{}\n{}", self.params_struct(&members), self.checks(compound)))
+    }
+
     fn wrap_verified_function(
         &self,
         func_name: &str,
         contracts: &str,
         body: &str,
-        assertions: &str,
+        _assertions: &str,
     ) -> String {
-        let assertions_code = if !assertions.is_empty() {
-            format!(
-                r#"
-        // Runtime assertion checks
-        {assertions}"#
-            )
-        } else {
-            String::new()
-        };
-
+        // Per-leaf assertions would reject valid `or` branches; the checker
+        // in `contracts` reverts per top-level conjunct instead
         format!(
             r#"// SPDX-License-Identifier: MIT
 // Solidity Generated Code - Smart Contract Verification
 // Use with Slither for security analysis, Echidna for property testing
+{pragma}
+
 {contracts}
+/// @title Validator
+/// @notice Checks parameters against the intent constraints
 contract Validator {{
-    /// Validation modifier for reentrancy protection
-    modifier validate {{
-        _;
-        // Post-validation checks can go here
-    }}
-
+    /// @notice Whether `params` satisfies every constraint
     function {func_name}(ValidationParams memory params) public pure returns (bool) {{
-{assertions_code}
         return {body};
     }}
+
+    /// @notice Reverts with a custom error naming the violated constraint
+    function enforce(ValidationParams memory params) external pure {{
+        requireValidationParams(params);
+    }}
 }}"#,
-            contracts = format!("// Contracts: {}", contracts.trim().lines().next().unwrap_or("none")),
+            pragma = SOLIDITY_PRAGMA,
+            contracts = contracts,
             func_name = func_name,
             body = body,
-            assertions_code = assertions_code.trim()
         )
     }
+
+    fn wrap_state_update(
+        &self,
+        func_name: &str,
+        guard: &str,
+        assignments: &[(String, String)],
+        post_check: Option<&str>,
+    ) -> Option<String> {
+        let updates: String = assignments
+            .iter()
+            .map(|(field, value)| format!("        next.{} = {};\n", field, value))
+            .collect();
+        let post = post_check
+            .map(|check| format!("        require({}, PostconditionViolated());\n", check))
+            .unwrap_or_default();
+
+        // Memory structs alias on assignment; round-trip through ABI encoding for a copy
+        Some(format!(
+            r#"// SPDX-License-Identifier: MIT
+// Solidity Generated Code - State Update
+{pragma}
+
+/// @notice The precondition of the action does not hold
+error PreconditionViolated();
+/// @notice The new state breaks the postcondition
+error PostconditionViolated();
+
+/// @title Validator
+contract Validator {{
+    /// @notice Applies `{func_name}` to a copy of the state
+    /// @dev Arithmetic is checked (0.8); an overflowing update reverts
+    function {func_name}(ValidationParams memory params) public pure returns (ValidationParams memory) {{
+        require({guard}, PreconditionViolated());
+        ValidationParams memory next = abi.decode(abi.encode(params), (ValidationParams));
+{updates}{post}        return next;
+    }}
+}}"#,
+            pragma = SOLIDITY_PRAGMA,
+        ))
+    }
+
+    /// Foundry fuzz tests and Echidna properties (no boundary cases without the verifier)
+    fn companion_files(&self, compound: &CompoundConstraint, func_name: &str) -> Vec<GeneratedFile> {
+        foundry::property_test_files(compound, func_name, &Self::inferred_members(compound), &[])
+    }
+}
+
+/// `balance >= amount` -> `BalanceAtLeastAmount`
+fn solidity_error_name(constraint: &Constraint) -> String {
+    let relation = match constraint.operator {
+        ConstraintOperator::GreaterThanOrEqual => "AtLeast",
+        ConstraintOperator::LessThanOrEqual => "AtMost",
+        ConstraintOperator::GreaterThan => "GreaterThan",
+        ConstraintOperator::LessThan => "LessThan",
+        ConstraintOperator::Equal => "Equals",
+        ConstraintOperator::NotEqual => "NotEquals",
+        ConstraintOperator::Contains => "Contains",
+        ConstraintOperator::DoesNotContain => "DoesNotContain",
+    };
+    let pascal = |text: &str| -> String {
+        text.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| to_ada_case(word).replace('_', ""))
+            .collect()
+    };
+    let left = constraint.left_variable.replace('%', " mod ");
+    format!("{}{}{}", pascal(&left), relation, pascal(&constraint.right_value))
 }
 
 // --- Solidity VerifiableStrategy Implementation ---
@@ -1570,67 +4234,43 @@ impl VerifiableStrategy for SolidityStrategy {
             DataType::String => "string".to_string(),
             DataType::Bool => "bool".to_string(),
             DataType::Decimal => "int256".to_string(), // Use fixed-point via int256
+            DataType::List(element) => format!("{}[]", self.map_type(element)),
             DataType::Custom { name, .. } => name.clone(),
         }
     }
 
     fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
-        format!("// Post-condition: Validated iff ({})", expression)
+        format!("/// @dev Post-condition: returns true iff {}", expression)
     }
 
-    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, schema: &Schema) -> String {
-        // Solidity 0.8+ has built-in overflow checks
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        // Solidity 0.8+ reverts on overflow natively; `unchecked { }` is
+        // left to hand-written code that has ruled overflow out
         match op {
-            ArithmeticOperator::Subtract => {
-                // Use checked subtraction pattern
-                format!("{}.sub({})", left, right)
-            }
-            ArithmeticOperator::Add => {
-                format!("{}.add({})", left, right)
-            }
-            ArithmeticOperator::Multiply => {
-                format!("{}.mul({})", left, right)
-            }
-            ArithmeticOperator::Divide => {
-                format!("{}{}{}", left, op.rust_symbol(), right)
-            }
+            ArithmeticOperator::Modulo => self.format_modulo(left, right),
+            _ => format!("({} {} {})", left, op.symbol(), right),
         }
     }
 
-    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
-        let fields: Vec<String> = schema
-            .fields
-            .iter()
-            .map(|(name, dt)| {
-                format!("{} {}", self.map_type(dt), name)
-            })
-            .collect();
-        
-        let fields_str = if fields.is_empty() {
-            "".to_string()
-        } else {
-            format!(" ({})", fields.join(", "))
-        };
-        
-        format!("function {}{}", func_name, fields_str)
+    /// The schema-typed `ValidationParams` struct
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        self.params_struct(&Self::schema_members(schema))
     }
 
     fn fn_end(&self) -> String {
         "}".to_string()
     }
 
-    fn license_header(&self, traceability_id: &str) -> String {
-        format!(
-            r#"// SPDX-License-Identifier: MIT
-// Solidity Generated Code - Smart Contract Verification (v0.1.5-alpha)
-// Use with Slither for security analysis, Echidna for property testing
-// Patent Application: 63/928,407
-// Traceability ID: {}
-// Correct by Design, Verified by Construction
-
-"#,
-            traceability_id
-        )
+    fn license_header(&self, provenance: &Provenance) -> String {
+        let banner = provenance.banner(
+            "//",
+            &[
+                "SPDX-License-Identifier: MIT",
+                "Solidity Generated Code - Smart Contract Verification (v0.1.5-alpha)",
+                "Use with Slither for security analysis, Echidna for property testing",
+            ],
+        );
+        format!("{}{}\n\n", banner, SOLIDITY_PRAGMA)
     }
 
     fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
@@ -1654,6 +4294,334 @@ fn to_ada_case(name: &str) -> String {
         .join("_")
 }
 
+/// `core::cmp::min(a, b)` (a literal receiver of `.min` would not infer its
+/// type) and `x.saturating_abs()`, shared by the Rust and WIT targets
+///
+/// The aggregates fold, so the sum and count take the type of what they are
+/// compared with; `average` divides with `div_euclid` to round down.
+pub(crate) fn rust_call(function: MathFunction, args: &[String]) -> String {
+    let sum = |list: &str| format!("{}.iter().fold(0, |total, item| total + item)", list);
+    let count = |list: &str| format!("{}.iter().fold(0, |count, _| count + 1)", list);
+    match function {
+        MathFunction::Abs => format!("{}.saturating_abs()", args.join(", ")),
+        MathFunction::Sum => sum(&args.join(", ")),
+        MathFunction::Count => count(&args.join(", ")),
+        MathFunction::Average => {
+            let list = args.join(", ");
+            format!("(if {list}.is_empty() {{ 0 }} else {{ {}.div_euclid({}) }})", sum(&list), count(&list))
+        }
+        _ => fold_pairs(args, |a, b| format!("core::cmp::{}({}, {})", function.name(), a, b)),
+    }
+}
+
+/// A `HashSet` that every value must be new to, shared by the Rust and WIT
+/// targets. `core` has no set: a list's elements are each checked against
+/// the ones before, and fields are compared pairwise
+pub(crate) fn rust_distinct(values: &[String], no_std: bool) -> Option<String> {
+    let set = "let mut seen = std::collections::HashSet::new();";
+    match (values, no_std) {
+        ([list], false) => Some(format!("{{ {} {}.iter().all(|item| seen.insert(item)) }}", set, list)),
+        ([list], true) => {
+            Some(format!("{list}.iter().enumerate().all(|(index, item)| !{list}[..index].contains(item))", list = list))
+        }
+        (_, false) => {
+            let values: Vec<String> = values.iter().map(|value| format!("&{}", value)).collect();
+            Some(format!("{{ {} [{}].into_iter().all(|value| seen.insert(value)) }}", set, values.join(", ")))
+        }
+        (_, true) => None,
+    }
+}
+
+/// `(if condition { a } else { b })`, shared by the Rust and WIT targets
+pub(crate) fn rust_conditional(condition: &str, then_value: &str, else_value: &str) -> String {
+    format!("(if {} {{ {} }} else {{ {} }})", condition, then_value, else_value)
+}
+
+/// `z.array(...)` of a list field; bounds only apply to the field itself
+fn zod_array(element: &DataType) -> String {
+    let element = match element {
+        DataType::Uint64 | DataType::Uint32 => "z.number().int().nonnegative()".to_string(),
+        DataType::Int64 | DataType::Int32 | DataType::Custom { .. } => "z.number().int()".to_string(),
+        DataType::String => "z.string()".to_string(),
+        DataType::Bool => "z.boolean()".to_string(),
+        DataType::Decimal => "z.number()".to_string(),
+        DataType::List(inner) => zod_array(inner),
+    };
+    format!("z.array({})", element)
+}
+
+/// `list.reduce((total, item) => total + item, 0)`
+fn typescript_sum(list: &str) -> String {
+    format!("{}.reduce((total, item) => total + item, 0)", list)
+}
+
+/// `pair(pair(a, b), c)` over the arguments of a `min` or `max` call
+pub(crate) fn fold_pairs(args: &[String], pair: impl Fn(&str, &str) -> String) -> String {
+    match args {
+        [first, rest @ ..] => rest.iter().fold(first.clone(), |folded, arg| pair(&folded, arg)),
+        [] => String::new(),
+    }
+}
+
+/// True if `value` is a bare identifier (a field reference rather than a literal)
+pub(crate) fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && value != "true"
+        && value != "false"
+}
+
+/// Field name -> `(bound kind, literal)`, e.g. `amount -> [("gt", "0")]`
+type FieldBounds<'a> = BTreeMap<&'a str, Vec<(&'static str, String)>>;
+
+/// Splits the top-level conjuncts into per-field numeric bounds (`ge`,
+/// `gt`, `le`, `lt` against a literal, at most one of each per field) and
+/// the remaining constraints, for targets that declare bounds on fields
+fn field_bounds(compound: &CompoundConstraint) -> (FieldBounds<'_>, Vec<&CompoundConstraint>) {
+    let conjuncts: Vec<&CompoundConstraint> = match compound {
+        CompoundConstraint::And(parts) => parts.iter().collect(),
+        other => vec![other],
+    };
+
+    let mut bounds = FieldBounds::new();
+    let mut rest: Vec<&CompoundConstraint> = Vec::new();
+    for conjunct in conjuncts {
+        let bound = match conjunct {
+            CompoundConstraint::Simple(c) if c.right_value.trim().parse::<f64>().is_ok() => match c.operator {
+                ConstraintOperator::GreaterThanOrEqual => Some((c, "ge")),
+                ConstraintOperator::GreaterThan => Some((c, "gt")),
+                ConstraintOperator::LessThanOrEqual => Some((c, "le")),
+                ConstraintOperator::LessThan => Some((c, "lt")),
+                _ => None,
+            },
+            _ => None,
+        };
+        match bound {
+            Some((c, key))
+                if !bounds
+                    .get(c.left_variable.as_str())
+                    .is_some_and(|b| b.iter().any(|(k, _)| *k == key)) =>
+            {
+                bounds
+                    .entry(c.left_variable.as_str())
+                    .or_default()
+                    .push((key, c.right_value.trim().to_string()));
+            }
+            _ => rest.push(conjunct),
+        }
+    }
+    (bounds, rest)
+}
+
+/// Schema fields sorted by name (`Schema.fields` is unordered)
+fn sorted_fields(schema: &Schema) -> Vec<(String, DataType)> {
+    let mut fields: Vec<(String, DataType)> =
+        schema.fields.iter().map(|(name, dt)| (name.clone(), dt.clone())).collect();
+    fields.sort_by(|a, b| a.0.cmp(&b.0));
+    fields
+}
+
+/// Constrained fields with their Schema types (`Int32` for fields the
+/// Schema does not declare)
+fn schema_fields(compound: &CompoundConstraint, schema: &Schema) -> Vec<(String, DataType)> {
+    collect_variables(compound)
+        .into_iter()
+        .filter(|v| is_identifier(v))
+        .map(|v| {
+            let data_type = schema.get_type(&v);
+            (v, data_type)
+        })
+        .collect()
+}
+
+/// Collect every field referenced by a constraint tree, sorted and de-duplicated
+pub(crate) fn collect_variables(compound: &CompoundConstraint) -> Vec<String> {
+    fn walk(compound: &CompoundConstraint, out: &mut Vec<String>) {
+        match compound {
+            CompoundConstraint::Simple(c) => {
+                match c.modulo() {
+                    Some(m) => {
+                        out.push(m.dividend);
+                        if is_identifier(&m.divisor) {
+                            out.push(m.divisor);
+                        }
+                    }
+                    None => match (Call::parse(&c.left_variable), c.bitwise()) {
+                        (Some(call), _) => out.extend(call.variables()),
+                        (None, Some(bitwise)) => out.extend(bitwise.variables()),
+                        (None, None) => out.push(c.left_variable.clone()),
+                    },
+                }
+                if is_identifier(&c.right_value) {
+                    out.push(c.right_value.clone());
+                } else if let Some(call) = Call::parse(&c.right_value) {
+                    out.extend(call.variables());
+                } else if let Some(conditional) = c.conditional() {
+                    out.extend(conditional.variables());
+                } else if let Some(term) = ArithmeticTerm::parse(&c.right_value) {
+                    out.extend(term.fields().into_iter().map(String::from));
+                }
+            }
+            CompoundConstraint::And(constraints)
+            | CompoundConstraint::Or(constraints)
+            | CompoundConstraint::ExactlyOne(constraints)
+            | CompoundConstraint::AtMostOne(constraints) => {
+                for c in constraints {
+                    walk(c, out);
+                }
+            }
+            CompoundConstraint::Not(inner) => walk(inner, out),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                walk(antecedent, out);
+                walk(consequent, out);
+            }
+            CompoundConstraint::Distinct(fields) => out.extend(fields.iter().cloned()),
+            // Fields come from the inlined definition
+            CompoundConstraint::Named(_) => {}
+        }
+    }
+
+    let mut variables = Vec::new();
+    walk(compound, &mut variables);
+    variables.sort();
+    variables.dedup();
+    variables
+}
+
+/// Whether any comparison in `compound` is a substring test
+pub(crate) fn has_membership(compound: &CompoundConstraint) -> bool {
+    match compound {
+        CompoundConstraint::Simple(c) => c.operator.is_membership(),
+        CompoundConstraint::And(constraints)
+        | CompoundConstraint::Or(constraints)
+        | CompoundConstraint::ExactlyOne(constraints)
+        | CompoundConstraint::AtMostOne(constraints) => constraints.iter().any(has_membership),
+        CompoundConstraint::Not(inner) => has_membership(inner),
+        CompoundConstraint::Implies(antecedent, consequent) => has_membership(antecedent) || has_membership(consequent),
+        CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => false,
+    }
+}
+
+/// Whether any comparison in `compound`, or the condition of a conditional
+/// value, masks or shifts a field
+pub(crate) fn has_bitwise(compound: &CompoundConstraint) -> bool {
+    fn comparison(c: &Constraint) -> bool {
+        c.bitwise().is_some() || c.conditional().is_some_and(|conditional| comparison(&conditional.condition))
+    }
+    match compound {
+        CompoundConstraint::Simple(c) => comparison(c),
+        CompoundConstraint::And(constraints)
+        | CompoundConstraint::Or(constraints)
+        | CompoundConstraint::ExactlyOne(constraints)
+        | CompoundConstraint::AtMostOne(constraints) => constraints.iter().any(has_bitwise),
+        CompoundConstraint::Not(inner) => has_bitwise(inner),
+        CompoundConstraint::Implies(antecedent, consequent) => has_bitwise(antecedent) || has_bitwise(consequent),
+        CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => false,
+    }
+}
+
+/// Fields `compound` reads as lists, sorted: through `sum`, `count` or
+/// `average`, or as the one field of a `Distinct`
+pub(crate) fn list_fields(compound: &CompoundConstraint) -> Vec<String> {
+    fn operand(value: &str, out: &mut Vec<String>) {
+        if let Some(conditional) = Conditional::parse(value) {
+            operand(&conditional.condition.left_variable, out);
+            operand(&conditional.then_value, out);
+            operand(&conditional.else_value, out);
+        } else if let Some(call) = Call::parse(value) {
+            out.extend(call.aggregates().into_iter().flat_map(|aggregate| aggregate.args));
+        }
+    }
+
+    fn walk(compound: &CompoundConstraint, out: &mut Vec<String>) {
+        match compound {
+            CompoundConstraint::Simple(c) => {
+                operand(&c.left_variable, out);
+                operand(&c.right_value, out);
+            }
+            CompoundConstraint::Distinct(fields) if fields.len() == 1 => out.extend(fields.iter().cloned()),
+            CompoundConstraint::Distinct(_) | CompoundConstraint::Named(_) => {}
+            CompoundConstraint::And(constraints)
+            | CompoundConstraint::Or(constraints)
+            | CompoundConstraint::ExactlyOne(constraints)
+            | CompoundConstraint::AtMostOne(constraints) => {
+                for c in constraints {
+                    walk(c, out);
+                }
+            }
+            CompoundConstraint::Not(inner) => walk(inner, out),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                walk(antecedent, out);
+                walk(consequent, out);
+            }
+        }
+    }
+
+    let mut lists = Vec::new();
+    walk(compound, &mut lists);
+    lists.sort();
+    lists.dedup();
+    lists
+}
+
+/// Referenced fields with the type assumed when there is no Schema: strings
+/// for both sides of a substring test and for a field compared with a string
+/// literal, lists of 64-bit signed integers for
+/// `list_fields`, 64-bit signed integers otherwise
+/// A comparison of a field with a string literal (`role == "admin"`)
+fn compares_with_text(constraint: &Constraint) -> bool {
+    let right = &constraint.right_value;
+    is_identifier(&constraint.left_variable)
+        && matches!(literal::operand(right), Operand::Text(_))
+        && Call::parse(right).is_none()
+        && Conditional::parse(right).is_none()
+}
+
+pub(crate) fn inferred_fields(compound: &CompoundConstraint) -> Vec<(String, DataType)> {
+    fn strings(compound: &CompoundConstraint, out: &mut Vec<String>) {
+        match compound {
+            CompoundConstraint::Simple(c) if c.operator.is_membership() => {
+                out.push(c.left_variable.clone());
+                out.push(c.right_value.clone());
+            }
+            CompoundConstraint::Simple(c) if compares_with_text(c) => out.push(c.left_variable.clone()),
+            CompoundConstraint::Simple(_) | CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => {}
+            CompoundConstraint::And(constraints)
+            | CompoundConstraint::Or(constraints)
+            | CompoundConstraint::ExactlyOne(constraints)
+            | CompoundConstraint::AtMostOne(constraints) => {
+                for c in constraints {
+                    strings(c, out);
+                }
+            }
+            CompoundConstraint::Not(inner) => strings(inner, out),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                strings(antecedent, out);
+                strings(consequent, out);
+            }
+        }
+    }
+
+    let mut string_fields = Vec::new();
+    strings(compound, &mut string_fields);
+    let lists = list_fields(compound);
+    collect_variables(compound)
+        .into_iter()
+        .filter(|v| is_identifier(v))
+        .map(|v| {
+            let dt = if string_fields.contains(&v) {
+                DataType::String
+            } else if lists.contains(&v) {
+                DataType::List(Box::new(DataType::Int64))
+            } else {
+                DataType::Int64
+            };
+            (v, dt)
+        })
+        .collect()
+}
+
 /// Build assertions for all simple constraints in a compound constraint
 fn build_assertions(compound: &CompoundConstraint, strategy: &dyn CodegenStrategy) -> String {
     let mut assertions = Vec::new();
@@ -1668,13 +4636,7 @@ fn collect_assertions(
 ) {
     match compound {
         CompoundConstraint::Simple(c) => {
-            let expr = format!(
-                "{} {} {}",
-                strategy.format_variable(&c.left_variable),
-                strategy.format_operator(&c.operator),
-                c.right_value
-            );
-            assertions.push(strategy.wrap_assertion(&expr));
+            assertions.push(strategy.wrap_assertion(&strategy.format_comparison(c)));
         }
         CompoundConstraint::And(constraints) | CompoundConstraint::Or(constraints) => {
             for c in constraints {
@@ -1684,32 +4646,261 @@ fn collect_assertions(
         CompoundConstraint::Not(inner) => {
             collect_assertions(inner, strategy, assertions);
         }
+        // No part holds on its own; assert the node as a whole
+        CompoundConstraint::Implies(..)
+        | CompoundConstraint::ExactlyOne(_)
+        | CompoundConstraint::AtMostOne(_)
+        | CompoundConstraint::Distinct(_)
+        | CompoundConstraint::Named(_) => {
+            assertions.push(strategy.wrap_assertion(&CodeGenerator.build_expression(compound, strategy)));
+        }
     }
 }
 
 // --- Main Engine ---
 
+/// Apply the glossary (if any) so every synonym generates the same identifier
+fn canonical_compound<'a>(compound: &'a CompoundConstraint, options: &CodegenOptions) -> Cow<'a, CompoundConstraint> {
+    match &options.glossary {
+        Some(glossary) => Cow::Owned(glossary.normalize_compound(compound)),
+        None => Cow::Borrowed(compound),
+    }
+}
+
+/// `canonical_compound` with every named predicate replaced by its definition
+fn inlined_compound<'a>(
+    compound: &'a CompoundConstraint,
+    options: &CodegenOptions,
+) -> Result<Cow<'a, CompoundConstraint>, CodegenError> {
+    if compound.references().is_empty() {
+        return Ok(canonical_compound(compound, options));
+    }
+    let empty = Definitions::new();
+    let inlined = options.definitions.as_ref().unwrap_or(&empty).inline(compound)?;
+    Ok(Cow::Owned(canonical_compound(&inlined, options).into_owned()))
+}
+
+/// A constraint tree with its named predicates resolved for one strategy
+struct ResolvedPredicates<'a> {
+    /// The tree as given, its fields under their identifiers in the target
+    compound: Cow<'a, CompoundConstraint>,
+    /// Every reference replaced by its definition, for code that inspects fields
+    inlined: Cow<'a, CompoundConstraint>,
+    /// Helper functions, each after the ones it calls; empty when inlined
+    helpers: Vec<String>,
+    /// The name and fields of each helper
+    functions: Vec<GeneratedFunction>,
+    /// The identifier of every field in the target
+    names: FieldNames,
+}
+
+/// The `monitor_` and `traced_` wrappers `options` asks for and the target
+/// has, each with its name
+fn wrappers(
+    compound: &CompoundConstraint,
+    language: TargetLanguage,
+    strategy: &dyn CodegenStrategy,
+    options: &CodegenOptions,
+    requirement_id: &str,
+    params_type: &str,
+) -> Vec<(String, String)> {
+    let func_name = options.func_name.as_str();
+    let monitor = options.monitor.then(|| monitor::monitor(compound, language, strategy, func_name, params_type));
+    let traced = options
+        .telemetry
+        .then(|| telemetry::traced(compound, language, strategy, func_name, requirement_id, params_type));
+    [("monitor", monitor.flatten()), ("traced", traced.flatten())]
+        .into_iter()
+        .filter_map(|(prefix, code)| Some((format!("{}_{}", prefix, func_name), code?)))
+        .collect()
+}
+
+/// Instantiate the expression strategy for a target language
+fn codegen_strategy(language: TargetLanguage, options: &CodegenOptions) -> Box<dyn CodegenStrategy> {
+    match language {
+        TargetLanguage::Rust => Box::new(RustStrategy::new(options)),
+        TargetLanguage::TypeScript => Box::new(TypeScriptStrategy::new(options)),
+        TargetLanguage::Python => Box::new(PythonStrategy::new(options)),
+        TargetLanguage::SparkAda => Box::new(SparkAdaStrategy::new(options)),
+        TargetLanguage::Zig => Box::new(ZigStrategy::new(options)),
+        TargetLanguage::Elixir => Box::new(ElixirStrategy::new(options)),
+        TargetLanguage::Solidity => Box::new(SolidityStrategy::default()),
+        TargetLanguage::Sql(dialect) => Box::new(sql::SqlStrategy { dialect }),
+        TargetLanguage::Rego => Box::new(rego::RegoStrategy::new(options)),
+        TargetLanguage::Cue => Box::new(cue::CueStrategy),
+        TargetLanguage::Cedar => Box::new(cedar::CedarStrategy),
+        TargetLanguage::Wit => Box::new(wit::WitStrategy::new(options)),
+        TargetLanguage::EbpfC => Box::new(ebpf::EbpfStrategy),
+    }
+}
+
+/// `codegen_strategy` knowing the field each identifier names, for the
+/// targets that look fields up by name
+fn named_strategy(language: TargetLanguage, options: &CodegenOptions, names: &FieldNames) -> Box<dyn CodegenStrategy> {
+    match language {
+        TargetLanguage::Elixir => Box::new(ElixirStrategy::new(options).with_names(names)),
+        _ => codegen_strategy(language, options),
+    }
+}
+
+/// `named_strategy` with the widths of `schema`'s fields, for the targets
+/// whose integers are not their fields' types
+fn schema_strategy(
+    language: TargetLanguage,
+    options: &CodegenOptions,
+    schema: &Schema,
+    names: &FieldNames,
+) -> Box<dyn CodegenStrategy> {
+    match language {
+        TargetLanguage::TypeScript => Box::new(TypeScriptStrategy::new(options).with_schema(schema)),
+        TargetLanguage::Python => Box::new(PythonStrategy::new(options).with_schema(schema)),
+        TargetLanguage::Elixir => Box::new(ElixirStrategy::new(options).with_schema(schema).with_names(names)),
+        TargetLanguage::Rego => Box::new(rego::RegoStrategy::new(options).with_schema(schema)),
+        TargetLanguage::Solidity => Box::new(SolidityStrategy::with_schema(schema)),
+        _ => named_strategy(language, options, names),
+    }
+}
+
+/// Instantiate the type-aware strategy for a target language
+fn verifiable_strategy(language: TargetLanguage, options: &CodegenOptions) -> Box<dyn VerifiableStrategy> {
+    match language {
+        TargetLanguage::Rust => Box::new(RustStrategy::new(options)),
+        TargetLanguage::TypeScript => Box::new(TypeScriptStrategy::new(options)),
+        TargetLanguage::Python => Box::new(PythonStrategy::new(options)),
+        TargetLanguage::SparkAda => Box::new(SparkAdaStrategy::new(options)),
+        TargetLanguage::Zig => Box::new(ZigStrategy::new(options)),
+        TargetLanguage::Elixir => Box::new(ElixirStrategy::new(options)),
+        TargetLanguage::Solidity => Box::new(SolidityStrategy::default()),
+        TargetLanguage::Sql(dialect) => Box::new(sql::SqlStrategy { dialect }),
+        TargetLanguage::Rego => Box::new(rego::RegoStrategy::new(options)),
+        TargetLanguage::Cue => Box::new(cue::CueStrategy),
+        TargetLanguage::Cedar => Box::new(cedar::CedarStrategy),
+        TargetLanguage::Wit => Box::new(wit::WitStrategy::new(options)),
+        TargetLanguage::EbpfC => Box::new(ebpf::EbpfStrategy),
+    }
+}
+
 pub struct CodeGenerator;
 
 impl CodeGenerator {
+    /// Resolve the named predicates `compound` references against
+    /// `options.definitions`, rendering a helper per definition when the
+    /// strategy emits them, and rename its fields for `language`. The inlined
+    /// tree is validated first, against `schema` when there is one
+    fn resolve_predicates<'a>(
+        &self,
+        compound: &'a CompoundConstraint,
+        strategy: &dyn CodegenStrategy,
+        options: &CodegenOptions,
+        language: TargetLanguage,
+        schema: Option<&Schema>,
+    ) -> Result<ResolvedPredicates<'a>, CodegenError> {
+        let inlined = inlined_compound(compound, options)?;
+        match schema {
+            Some(schema) => inlined.validate(schema)?,
+            None => inlined.validate_structure()?,
+        }
+        let lists = schema.is_some_and(|schema| schema.fields.values().any(|dt| matches!(dt, DataType::List(_))));
+        if !strategy.supports_lists() && (lists || !list_fields(&inlined).is_empty()) {
+            return Err(CodegenError::UnsupportedLanguage(format!("{:?} has no list fields", language)));
+        }
+        if !strategy.supports_bitwise() && has_bitwise(&inlined) {
+            return Err(CodegenError::UnsupportedLanguage(format!("{:?} has no bitwise operators", language)));
+        }
+        let names = FieldNames::for_compounds(language, [&*inlined], schema)?;
+        let helpers = match &options.definitions {
+            Some(definitions) => definitions.used_by(compound)?,
+            None => Vec::new(),
+        };
+        let helpers = helpers
+            .into_iter()
+            .map(|(name, body)| {
+                let body = names.rename(canonical_compound(body, options));
+                strategy
+                    .predicate_helper(name, &self.build_expression(&body, strategy))
+                    .map(|helper| (name, helper, GeneratedFunction::reading(name, &body)))
+            })
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+        let (helpers, functions) = helpers
+            .into_iter()
+            .map(|(name, helper, function)| language.check_identifier(name).map(|()| (helper, function)))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        Ok(ResolvedPredicates {
+            compound: names.rename(Cow::Borrowed(compound)),
+            inlined: names.rename(inlined),
+            helpers,
+            functions,
+            names,
+        })
+    }
+
     /// Generate code for the given compound constraint in the target language.
     pub fn generate(
         &self,
         compound: &CompoundConstraint,
         language: TargetLanguage,
     ) -> Result<CodegenOutput, CodegenError> {
-        let strategy: Box<dyn CodegenStrategy> = match language {
-            TargetLanguage::Rust => Box::new(RustStrategy),
-            TargetLanguage::TypeScript => Box::new(TypeScriptStrategy),
-            TargetLanguage::Python => Box::new(PythonStrategy),
-            TargetLanguage::SparkAda => Box::new(SparkAdaStrategy),
-            TargetLanguage::Zig => Box::new(ZigStrategy),
-            TargetLanguage::Elixir => Box::new(ElixirStrategy),
-            TargetLanguage::Solidity => Box::new(SolidityStrategy),
-        };
+        self.generate_with_options(compound, language, &CodegenOptions::default())
+    }
+
+    /// Generate code with explicit naming and output options.
+    pub fn generate_with_options(
+        &self,
+        compound: &CompoundConstraint,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+    ) -> Result<CodegenOutput, CodegenError> {
+        let mut code = Vec::new();
+        let output = self.generate_to(compound, language, options, &mut code)?;
+        Ok(output.with_code(code))
+    }
+
+    /// `generate_with_options`, writing the validator to `out` as it is
+    /// produced instead of returning it. Companion files are returned as usual.
+    /// With `options.format` enabled the validator is buffered for the formatter.
+    pub fn generate_to(
+        &self,
+        compound: &CompoundConstraint,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+        out: &mut impl io::Write,
+    ) -> Result<StreamedOutput, CodegenError> {
+        if !options.format.enabled {
+            return self.stream_to(compound, language, options, out);
+        }
+        let mut code = Vec::new();
+        let output = self.stream_to(compound, language, options, &mut code)?;
+        format::write_formatted(output, code, &options.format, out)
+    }
 
-        // Build the main expression
-        let expression = self.build_expression(compound, &*strategy);
+    #[tracing::instrument(skip_all, fields(?language, func = %options.func_name, constraints = compound.count_constraints()))]
+    fn stream_to(
+        &self,
+        compound: &CompoundConstraint,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+        out: &mut impl io::Write,
+    ) -> Result<StreamedOutput, CodegenError> {
+        let strategy = codegen_strategy(language, options);
+        let func_name = options.func_name.as_str();
+        language.check_identifier(func_name)?;
+        let provenance = Provenance::new(compound, None, language, options);
+        let requirement_id = options.requirement_id.clone().unwrap_or_else(|| compound.canonical_hash());
+        let compound = &*canonical_compound(compound, options);
+        let predicates = self.resolve_predicates(compound, &*strategy, options, language, None)?;
+        let strategy = named_strategy(language, options, &predicates.names);
+
+        // Build the main expression, calling the predicate helpers if there are any
+        let expression = if predicates.helpers.is_empty() {
+            self.build_expression(&predicates.inlined, &*strategy)
+        } else {
+            self.build_expression(&predicates.compound, &*strategy)
+        };
+        let compound = &*predicates.inlined;
 
         // Build assertions for runtime checking
         let assertions = build_assertions(compound, &*strategy);
@@ -1718,17 +4909,35 @@ impl CodeGenerator {
         let contracts = strategy.emit_contracts(compound).unwrap_or_default();
 
         // Generate the verified function with contracts and assertions
-        let code = strategy.wrap_verified_function(
-            "validate_intent",
-            &contracts,
-            &expression,
-            &assertions,
+        let mut writer = ArtifactWriter::new(out);
+        let artifact = strategy.wrap_verified_function(func_name, &contracts, &expression, &assertions);
+        writer.write_all(provenance.stamp(language, &artifact).as_bytes())?;
+        let mut helpers = predicates.helpers.clone();
+        let wrappers = wrappers(compound, language, &*strategy, options, &requirement_id, "ValidationParams");
+        helpers.extend(wrappers.iter().map(|(_, code)| code.clone()));
+        let bytes = writer.finish(&helpers)?;
+        let mut files = strategy.companion_files(compound, func_name);
+        provenance.license_files(&mut files);
+        let vstrategy = verifiable_strategy(language, options);
+        let field_types = inferred_fields(compound).into_iter().map(|(name, dt)| (name, vstrategy.map_type(&dt)));
+        let metadata = CodegenMetadata::new(
+            compound,
+            &*strategy,
+            func_name,
+            wrappers.into_iter().map(|(name, _)| name).collect(),
+            predicates.functions,
+            field_types.collect(),
         );
+        tracing::debug!(bytes, files = files.len(), "generated");
 
-        Ok(CodegenOutput {
+        Ok(StreamedOutput {
             language,
-            code,
+            bytes,
             constraints_count: compound.count_constraints(),
+            files,
+            warnings: Vec::new(),
+            metadata,
+            provenance,
         })
     }
 
@@ -1745,119 +4954,304 @@ impl CodeGenerator {
         schema: &Schema,
         language: TargetLanguage,
     ) -> Result<CodegenOutput, CodegenError> {
-        let traceability_id = schema.traceability_id.clone();
+        self.generate_with_schema_and_options(compound, schema, language, &CodegenOptions::default())
+    }
+
+    /// Type-aware generation with explicit naming and output options.
+    pub fn generate_with_schema_and_options(
+        &self,
+        compound: &CompoundConstraint,
+        schema: &Schema,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+    ) -> Result<CodegenOutput, CodegenError> {
+        let mut code = Vec::new();
+        let output = self.generate_with_schema_to(compound, schema, language, options, &mut code)?;
+        Ok(output.with_code(code))
+    }
+
+    /// `generate_with_schema_and_options`, writing the validator to `out`
+    /// piece by piece, so the Schema's field declarations are never copied
+    /// into one assembled artifact. Companion files are returned as usual.
+    /// With `options.format` enabled the validator is buffered for the formatter.
+    pub fn generate_with_schema_to(
+        &self,
+        compound: &CompoundConstraint,
+        schema: &Schema,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+        out: &mut impl io::Write,
+    ) -> Result<StreamedOutput, CodegenError> {
+        if !options.format.enabled {
+            return self.stream_with_schema_to(compound, schema, language, options, out);
+        }
+        let mut code = Vec::new();
+        let output = self.stream_with_schema_to(compound, schema, language, options, &mut code)?;
+        format::write_formatted(output, code, &options.format, out)
+    }
+
+    #[tracing::instrument(skip_all, fields(?language, func = %options.func_name, constraints = compound.count_constraints()))]
+    fn stream_with_schema_to(
+        &self,
+        compound: &CompoundConstraint,
+        schema: &Schema,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+        out: &mut impl io::Write,
+    ) -> Result<StreamedOutput, CodegenError> {
+        // Every target prints the ID in a comment
+        let traceability_id = literal::comment(&schema.traceability_id).into_owned();
+        let func_name = options.func_name.as_str();
+        language.check_identifier(func_name)?;
+        let provenance = Provenance::new(compound, Some(schema), language, options);
+        let requirement_id = options.requirement_id.clone().unwrap_or_else(|| compound.canonical_hash());
+        let compound = &*canonical_compound(compound, options);
         
         // Get the strategy based on language
-        let strategy: Box<dyn CodegenStrategy> = match language {
-            TargetLanguage::Rust => Box::new(RustStrategy),
-            TargetLanguage::TypeScript => Box::new(TypeScriptStrategy),
-            TargetLanguage::Python => Box::new(PythonStrategy),
-            TargetLanguage::SparkAda => Box::new(SparkAdaStrategy),
-            TargetLanguage::Zig => Box::new(ZigStrategy),
-            TargetLanguage::Elixir => Box::new(ElixirStrategy),
-            TargetLanguage::Solidity => Box::new(SolidityStrategy),
-        };
+        let strategy = codegen_strategy(language, options);
         
         // Cast to VerifiableStrategy for type-aware generation
-        let vstrategy: Box<dyn VerifiableStrategy> = match language {
-            TargetLanguage::Rust => Box::new(RustStrategy),
-            TargetLanguage::TypeScript => Box::new(TypeScriptStrategy),
-            TargetLanguage::Python => Box::new(PythonStrategy),
-            TargetLanguage::SparkAda => Box::new(SparkAdaStrategy),
-            TargetLanguage::Zig => Box::new(ZigStrategy),
-            TargetLanguage::Elixir => Box::new(ElixirStrategy),
-            TargetLanguage::Solidity => Box::new(SolidityStrategy),
-        };
+        let vstrategy = verifiable_strategy(language, options);
+        let predicates = self.resolve_predicates(compound, &*strategy, options, language, Some(schema))?;
+        let schema = &*predicates.names.rename_schema(schema);
+        let strategy = schema_strategy(language, options, schema, &predicates.names);
+        let declarations = custom_types::declarations(schema, language, &options.types)?;
+        let schema = &*custom_types::apply(schema, language, &options.types);
         
         // 1. Generate the core logic expression
-        let logic_expr = self.build_expression(compound, &*strategy);
+        let logic_expr = if predicates.helpers.is_empty() {
+            self.build_expression(&predicates.inlined, &*strategy)
+        } else {
+            self.build_expression(&predicates.compound, &*strategy)
+        };
+        let compound = &*predicates.inlined;
         
-        // 2. Build the function signature using Schema metadata
-        let signature = vstrategy.build_signature("validate_intent", schema);
+        // 2. Build the function signature using Schema metadata, after the
+        // declarations of the custom types it names
+        let signature = format!("{}{}", declarations, vstrategy.build_signature(func_name, schema));
         
         // 3. Attach formal contracts (Pre/Post)
         let postcondition = vstrategy.emit_postcondition(&logic_expr, schema);
         
         // 4. Generate license header with traceability
-        let header = vstrategy.license_header(&traceability_id);
+        let header = provenance.stamp(language, &vstrategy.license_header(&provenance));
         
         // 5. Build assertions for runtime checking
         let assertions = build_assertions(compound, &*strategy);
         
-        // 6. Combine into final artifact based on language
-        let code = match language {
+        // 6. Write the final artifact based on language. `package_files` splits
+        // the finished SPARK package into spec and body, so that one target is
+        // assembled in memory first
+        let mut spark_source = Vec::new();
+        let sink: &mut dyn io::Write = match language {
+            TargetLanguage::SparkAda => &mut spark_source,
+            _ => &mut *out,
+        };
+        let mut writer = ArtifactWriter::new(sink);
+        match language {
             TargetLanguage::SparkAda => {
-                // SPARK/Ada has special contract syntax
-                let contracts = strategy.emit_contracts(compound).unwrap_or_default();
-                format!("{}{}\n   with {}\n{}\nis\nbegin\n    {}\n    return {}\n{}",
-                    header, signature, contracts, postcondition, assertions, logic_expr, vstrategy.fn_end())
+                // The Schema-typed record replaces the inferred one; contracts live in the spec
+                let spark = SparkAdaStrategy::new(options);
+                let declarations = format!("{}\n{}", signature, spark.function_declaration(compound));
+                write!(writer, "{}\n{}", header, spark.package_source(&declarations, &logic_expr))
             }
             TargetLanguage::Zig => {
-                format!("{}{}\n{}\n    {}\n    return {}\n{}",
-                    header, signature, postcondition, assertions, logic_expr, vstrategy.fn_end())
+                let zig = ZigStrategy::new(options);
+                let members: Vec<(String, String)> =
+                    sorted_fields(schema).into_iter().map(|(name, dt)| (name, zig.map_type(&dt))).collect();
+                write!(writer, "{}{}\n{}\n{}\npub fn {}(params: ValidationParams) bool {{\n    return {};\n{}\n\n{}",
+                    header, signature, zig.comptime_checks(compound, &members), postcondition, func_name, logic_expr,
+                    vstrategy.fn_end(), zig.tests(compound, &members))
             }
             TargetLanguage::Rust => {
-                format!("{}{}\n{}\nimpl Validator {{ \n    pub fn validate_intent(&self, params: &ValidationParams) -> bool {{ \n        {}\n        {}\n    }}\n}}",
-                    header, signature, postcondition, assertions, logic_expr)
+                let rust = RustStrategy::new(options);
+                let field_types: Vec<String> = schema.fields.values().map(|dt| rust.map_type(dt)).collect();
+                let const_items = rust.const_items(&field_types);
+                let qualifier = if const_items.is_some() { "const fn" } else { "fn" };
+                write!(writer, "{}{}{}\n#[derive(Debug, Clone, Copy, Default)]\npub struct Validator;\n\nimpl Validator {{\n    {}\n    pub {} {}(&self, params: &ValidationParams) -> bool {{\n        {}\n        {}\n    }}\n}}\n{}{}",
+                    rust.crate_attributes(), header, signature, postcondition, qualifier, func_name, assertions, logic_expr,
+                    const_items.unwrap_or_default(), rust.harnesses(compound))
+            }
+            TargetLanguage::Solidity => {
+                // The struct and errors precede the contract; NatSpec carries the traceability ID
+                let checks = SolidityStrategy::with_schema(schema).checks(compound);
+                write!(writer, "{}{}\n{}\n/// @title Validator\n/// @custom:traceability {}\ncontract Validator {{\n    {}\n    function {}(ValidationParams memory params) public pure returns (bool) {{\n        return {};\n    }}\n\n    /// @notice Reverts with a custom error naming the violated constraint\n    function enforce(ValidationParams memory params) external pure {{\n        requireValidationParams(params);\n    }}\n}}",
+                    header, signature, checks, traceability_id, postcondition, func_name, logic_expr)
+            }
+            TargetLanguage::Python if options.pydantic => {
+                let python = PythonStrategy::new(options).with_schema(schema);
+                let model = python.pydantic_model(compound, &sorted_fields(schema));
+                write!(writer, "{}{}{}", header, declarations, python.pydantic_module(func_name, &model))
+            }
+            TargetLanguage::Python => {
+                write!(writer, "{}{}\n\nclass Validator:\n    @staticmethod\n    def {}(params) -> bool:\n        {}\n        {}\n        return {}",
+                    header, signature, func_name, postcondition, assertions, logic_expr)
+            }
+            TargetLanguage::TypeScript if options.zod => {
+                let typescript = TypeScriptStrategy::new(options).with_schema(schema);
+                let zod = typescript.zod_schema(compound, &sorted_fields(schema));
+                write!(writer, "{}{}{}", header, declarations, typescript.zod_module(func_name, &zod))
+            }
+            TargetLanguage::TypeScript if options.bigint => {
+                // Only the Schema's 64-bit fields are bigint, so literals are re-rendered against it
+                let typescript = TypeScriptStrategy::new(options).with_schema(schema);
+                let logic_expr = self.build_expression(compound, &typescript);
+                let assertions = build_assertions(compound, &typescript);
+                let postcondition = typescript.emit_postcondition(&logic_expr, schema);
+                write!(writer, "{}{}\n\nexport class Validator {{ \n    static {}(params: {}_Params): boolean {{ \n        {}\n        {}\n        return {}\n    }}\n}}\n\n{}",
+                    header, signature, func_name, func_name, postcondition, assertions, logic_expr,
+                    typescript.bigint_parser(&format!("{}_Params", func_name), &sorted_fields(schema)).trim_start())
+            }
+            TargetLanguage::TypeScript => {
+                write!(writer, "{}{}\n\nexport class Validator {{ \n    static {}(params: any): boolean {{ \n        {}\n        {}\n        return {}\n    }}\n}}",
+                    header, signature, func_name, postcondition, assertions, logic_expr)
+            }
+            TargetLanguage::Elixir => {
+                // Clause heads bind the fields; guards check their Schema types
+                let elixir = ElixirStrategy::new(options).with_schema(schema).with_names(&predicates.names);
+                let fields = schema_fields(compound, schema);
+                let signature = format!("{}{}", declarations, elixir.build_signature(func_name, schema));
+                let contracts = format!("  {}\n{}", postcondition, elixir.validator(compound, &signature, &fields));
+                write!(writer, "{}{}", header, strategy.wrap_verified_function(func_name, &contracts, &logic_expr, ""))
+            }
+            TargetLanguage::Sql(dialect) => {
+                // SQL enforces the whole expression as one CHECK; per-constraint
+                // assertions would over-constrain OR branches
+                let table = sql::render_table(&sql::SqlStrategy { dialect }, &header, &signature,
+                    &postcondition, &logic_expr, schema, compound)?;
+                writer.write_all(table.as_bytes())
+            }
+            TargetLanguage::Rego => {
+                // Rego rules are assembled from the tree, not the inline expression
+                let contracts = strategy.emit_contracts(compound).unwrap_or_default();
+                write!(writer, "{}{}\n{}",
+                    header, signature, strategy.wrap_verified_function(func_name, &contracts, &logic_expr, ""))
+            }
+            TargetLanguage::Cue => {
+                // The Schema-derived field declarations replace the inferred ones
+                write!(writer, "{}{}", header, strategy.wrap_verified_function(func_name, &signature, &logic_expr, ""))
+            }
+            TargetLanguage::Cedar => {
+                write!(writer, "{}{}\n{}", header, signature, strategy.wrap_verified_function(func_name, "", &logic_expr, ""))
             }
-            TargetLanguage::Solidity => {
-                format!("{}\ncontract Validator {{ \n    {}\n    {}\n    {}\n        return {}\n    }}\n}}",
-                    header, signature, postcondition, assertions, logic_expr)
+            TargetLanguage::Wit => {
+                // Schema-typed record fields replace the inferred `s64` ones
+                write!(writer, "{}{}", header, strategy.wrap_verified_function(func_name, &signature, &logic_expr, ""))
             }
-            TargetLanguage::Python => {
-                format!("{}{}\n\nclass Validator:\n    @staticmethod\n    def validate_intent(params) -> bool:\n        {}\n        {}\n        return {}",
-                    header, signature, postcondition, assertions, logic_expr)
+            TargetLanguage::EbpfC => {
+                // The struct lives in the companion header; the predicate stays loop-free
+                let prelude = format!("{}\n\n{}", signature, postcondition);
+                write!(writer, "{}{}", header, strategy.wrap_verified_function(func_name, &prelude, &logic_expr, ""))
             }
-            TargetLanguage::TypeScript => {
-                format!("{}{}\n\nexport class Validator {{ \n    static validate_intent(params: any): boolean {{ \n        {}\n        {}\n        return {}\n    }}\n}}",
-                    header, signature, postcondition, assertions, logic_expr)
+        }?;
+        let mut helpers = predicates.helpers.clone();
+        let params_type = if options.zod { "ValidationParams".to_string() } else { format!("{}_Params", func_name) };
+        let wrappers = wrappers(compound, language, &*strategy, options, &requirement_id, &params_type);
+        helpers.extend(wrappers.iter().map(|(_, code)| code.clone()));
+        let bytes = writer.finish(&helpers)?;
+
+        let mut files = match language {
+            // Split the Schema-typed package rather than re-deriving an untyped one
+            TargetLanguage::SparkAda => {
+                out.write_all(&spark_source)?;
+                SparkAdaStrategy::new(options).package_files(&String::from_utf8_lossy(&spark_source))
             }
             TargetLanguage::Elixir => {
-                format!("{}{}\n\ndefmodule Validator do\n    {}\n    def validate_intent?(params) do\n        {}\n        {}\n        {}\n    end\nend",
-                    header, signature, postcondition, assertions, logic_expr, vstrategy.fn_end())
+                let elixir = ElixirStrategy::new(options).with_names(&predicates.names);
+                vec![elixir.property_tests(compound, &schema_fields(compound, schema))]
             }
+            TargetLanguage::Solidity => {
+                foundry::property_test_files(compound, func_name, &SolidityStrategy::schema_members(schema), &[])
+            }
+            _ => strategy.companion_files(compound, func_name),
         };
-        
-        Ok(CodegenOutput {
+        files.extend(vstrategy.schema_files(func_name, schema));
+        provenance.license_files(&mut files);
+        let warnings = warnings::lossy_fields(language, schema, options, |data_type| vstrategy.map_type(data_type));
+        let field_types = schema.fields.iter().map(|(name, dt)| (name.clone(), vstrategy.map_type(dt)));
+        let metadata = CodegenMetadata::new(
+            compound,
+            &*strategy,
+            func_name,
+            wrappers.into_iter().map(|(name, _)| name).collect(),
+            predicates.functions,
+            field_types.collect(),
+        );
+        tracing::debug!(bytes, files = files.len(), warnings = warnings.len(), "generated");
+
+        Ok(StreamedOutput {
             language,
-            code,
+            bytes,
             constraints_count: compound.count_constraints(),
+            files,
+            warnings,
+            metadata,
+            provenance,
         })
     }
 
-    /// Recursively build the boolean expression from compound constraints.
+    /// Build the boolean expression for a constraint tree. The tree is
+    /// interned into a `ConstraintArena` first, so a subtree repeated across
+    /// the spec (a shared guard) is rendered once.
     fn build_expression(
         &self,
         compound: &CompoundConstraint,
         strategy: &dyn CodegenStrategy,
     ) -> String {
-        match compound {
-            CompoundConstraint::Simple(c) => {
-                format!(
-                    "{} {} {}",
-                    strategy.format_variable(&c.left_variable),
-                    strategy.format_operator(&c.operator),
-                    c.right_value
-                )
-            }
-            CompoundConstraint::And(constraints) => {
-                let parts: Vec<String> = constraints
-                    .iter()
-                    .map(|c| self.build_expression(c, strategy))
-                    .collect();
-                format!("({})", parts.join(&format!(" {} ", strategy.logical_and())))
-            }
-            CompoundConstraint::Or(constraints) => {
-                let parts: Vec<String> = constraints
-                    .iter()
-                    .map(|c| self.build_expression(c, strategy))
-                    .collect();
-                format!("({})", parts.join(&format!(" {} ", strategy.logical_or())))
+        let (arena, root) = ConstraintArena::from_tree(compound);
+        let mut rendered = vec![None; arena.len()];
+        self.build_node(&arena, root, strategy, &mut rendered)
+    }
+
+    /// Recursively render one arena node, memoized by `NodeId`
+    fn build_node(
+        &self,
+        arena: &ConstraintArena,
+        id: NodeId,
+        strategy: &dyn CodegenStrategy,
+        rendered: &mut Vec<Option<String>>,
+    ) -> String {
+        if let Some(expression) = &rendered[id.index()] {
+            return expression.clone();
+        }
+        let list = |children, rendered: &mut Vec<Option<String>>| -> Vec<String> {
+            arena
+                .children(children)
+                .iter()
+                .map(|&child| self.build_node(arena, child, strategy, rendered))
+                .collect()
+        };
+        let expression = match arena.node(id) {
+            Node::Simple(leaf) => strategy.format_comparison(&arena.constraint(leaf)),
+            Node::Named(name) => strategy.format_predicate_call(arena.resolve(name)),
+            Node::And(children) => match arena.as_between(id) {
+                Some(range) => strategy.format_range(&range),
+                None => format!("({})", list(children, rendered).join(&format!(" {} ", strategy.logical_and()))),
+            },
+            Node::Or(children) => {
+                format!("({})", list(children, rendered).join(&format!(" {} ", strategy.logical_or())))
             }
-            CompoundConstraint::Not(inner) => {
-                strategy.logical_not(&self.build_expression(inner, strategy))
+            Node::Not(inner) => strategy.logical_not(&self.build_node(arena, inner, strategy, rendered)),
+            Node::Implies(antecedent, consequent) => strategy.format_implication(
+                &self.build_node(arena, antecedent, strategy, rendered),
+                &self.build_node(arena, consequent, strategy, rendered),
+            ),
+            Node::ExactlyOne(children) => strategy.format_cardinality(&list(children, rendered), true),
+            Node::AtMostOne(children) => strategy.format_cardinality(&list(children, rendered), false),
+            Node::Distinct(fields) => {
+                let fields = arena.fields(fields);
+                let values: Vec<String> = fields.iter().map(|field| strategy.format_variable(field)).collect();
+                match strategy.format_distinct(&values) {
+                    Some(expression) => expression,
+                    None => match CompoundConstraint::Distinct(fields).pairwise_encoding() {
+                        Some(pairs) => self.build_expression(&pairs, strategy),
+                        None => unreachable!("list fields are rejected before generation"),
+                    },
+                }
             }
-        }
+        };
+        rendered[id.index()] = Some(expression.clone());
+        expression
     }
 }
 
@@ -1887,22 +5281,103 @@ mod tests {
         let result = generator.generate(&sample_compound(), TargetLanguage::Rust);
         assert!(result.is_ok());
         let output = result.unwrap();
-        assert!(output.code.contains("params.balance >= amount"));
+        assert!(output.code.contains("params.balance >= params.amount"));
         assert!(output.code.contains("params.amount > 0"));
         assert!(output.code.contains("#[kani::proof]"));
     }
 
+    #[test]
+    fn test_rust_kani_harnesses() {
+        let output = CodeGenerator.generate(&sample_compound(), TargetLanguage::Rust).unwrap();
+        let code = &output.code;
+        assert!(code.contains("#[cfg_attr(kani, derive(kani::Arbitrary))]\npub struct ValidationParams {\n    pub amount: i64,\n    pub balance: i64,\n}"));
+        assert!(code.contains("pub struct Validator;"));
+        assert!(code.find("impl Validator").unwrap() < code.find("mod verification").unwrap());
+        assert!(code.contains("assert_eq!(result, (balance >= amount && amount > 0));"));
+        assert!(code.contains("fn check_balance_ge_amount()"));
+        assert!(code.contains("fn check_amount_gt_0()"));
+        assert!(code.contains("if Validator.validate_intent(&params) {\n            assert!(amount > 0);"));
+
+        let typed = CodeGenerator
+            .generate_with_schema(&sample_compound(), &sample_schema(), TargetLanguage::Rust)
+            .unwrap();
+        assert!(typed.code.contains("#[cfg_attr(kani, derive(kani::Arbitrary))]"));
+        assert!(typed.code.contains("fn check_balance_ge_amount()"));
+    }
+
+    #[test]
+    fn test_rust_validator_body_reads_params() {
+        let code = CodeGenerator
+            .generate_with_schema(&sample_compound(), &sample_schema(), TargetLanguage::Rust)
+            .unwrap()
+            .code;
+        let body = &code[code.find("pub fn validate_intent").unwrap()..code.find("#[cfg(kani)]").unwrap()];
+        assert!(body.contains("debug_assert!(params.balance >= params.amount);"));
+        assert!(body.contains("(params.balance >= params.amount && params.amount > 0)"));
+        // Only the harnesses destructure; the validator has no bare field in scope
+        let mut words = body.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'));
+        assert!(words.all(|word| !matches!(word, "balance" | "amount")), "{}", body);
+    }
+
+    #[test]
+    fn test_rust_no_std_profile() {
+        let options = CodegenOptions {
+            no_std: true,
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_options(&sample_compound(), TargetLanguage::Rust, &options)
+            .unwrap();
+        assert!(output.code.starts_with("#![no_std]\n"));
+        assert!(output.code.contains("#[derive(Debug, Clone, Copy, PartialEq)]"));
+        assert!(output.code.contains("pub const fn validate_intent(&self, params: &ValidationParams) -> bool"));
+        assert!(output.code.contains("macro_rules! assert_valid_params"));
+
+        let mut schema = sample_schema();
+        schema.add_field("memo".to_string(), DataType::String, None);
+        let typed = CodeGenerator
+            .generate_with_schema_and_options(&sample_compound(), &schema, TargetLanguage::Rust, &options)
+            .unwrap();
+        assert!(typed.code.contains("pub memo: &'static str,"));
+        assert!(!typed.code.contains("String"));
+        assert!(typed.code.contains("pub fn validate_intent"));
+        assert!(!typed.code.contains("assert_valid_params"));
+    }
+
     #[test]
     fn test_spark_ada_generation() {
         let generator = CodeGenerator;
         let result = generator.generate(&sample_compound(), TargetLanguage::SparkAda);
         assert!(result.is_ok());
         let output = result.unwrap();
-        assert!(output.code.contains("Params.Balance >= amount"));
+        assert!(output.code.contains("Params.Balance >= Params.Amount"));
         assert!(output.code.contains("and then"));
         assert!(output.code.contains("SPARK_Mode => On"));
         assert!(output.code.contains("Post =>"));
-        assert!(output.code.contains("pragma Assert"));
+        assert!(!output.code.contains("pragma Assert"));
+    }
+
+    #[test]
+    fn test_spark_ada_package_files() {
+        let options = CodegenOptions {
+            func_name: "can_withdraw".to_string(),
+            package_name: Some("payment_rules".to_string()),
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_schema_and_options(&sample_compound(), &sample_schema(), TargetLanguage::SparkAda, &options)
+            .unwrap();
+        assert!(output.code.contains("Post => (Can_Withdraw'Result = "));
+        assert!(!output.code.contains("validate_intent"));
+
+        let paths: Vec<&str> = output.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/payment_rules.ads", "src/payment_rules.adb", "payment_rules.gpr"]);
+        let spec = &output.files[0].contents;
+        assert!(spec.contains("package Payment_Rules\n   with SPARK_Mode => On"));
+        assert!(spec.contains("   type Validation_Params is record\n      Amount : Natural;\n      Balance : Natural;\n   end record;"));
+        assert!(!spec.contains("package body"));
+        assert!(output.files[1].contents.starts_with("package body Payment_Rules"));
+        assert!(output.files[2].contents.contains("project Payment_Rules is"));
     }
 
     #[test]
@@ -1911,7 +5386,7 @@ mod tests {
         let result = generator.generate(&sample_compound(), TargetLanguage::Zig);
         assert!(result.is_ok());
         let output = result.unwrap();
-        assert!(output.code.contains("params.balance >= amount"));
+        assert!(output.code.contains("params.balance >= params.amount"));
         assert!(output.code.contains("comptime"));
         assert!(output.code.contains("std.debug.assert"));
     }
@@ -1922,9 +5397,650 @@ mod tests {
         let result = generator.generate(&sample_compound(), TargetLanguage::Elixir);
         assert!(result.is_ok());
         let output = result.unwrap();
-        assert!(output.code.contains("params[:balance] >= amount"));
+        assert!(output.code.contains("when is_integer(amount) and is_integer(balance) and (balance >= amount and amount > 0)"));
         assert!(output.code.contains("def validate_intent?"));
         assert!(output.code.contains("when is_map(params)"));
+        assert!(!output.code.contains("params[:amount]"));
+    }
+
+    #[test]
+    fn test_python_pydantic_model() {
+        let options = CodegenOptions {
+            pydantic: true,
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_options(&sample_compound(), TargetLanguage::Python, &options)
+            .unwrap();
+        let code = &output.code;
+        assert!(code.contains("class ValidationParams(BaseModel):"));
+        assert!(code.contains("    amount: int = Field(gt=0)\n    balance: int\n"));
+        assert!(code.contains("    @model_validator(mode=\"after\")\n    def check_balance_ge_amount(self) -> \"ValidationParams\":\n        amount, balance = self.amount, self.balance\n        if not (balance >= amount):"));
+        assert!(code.contains("ValidationParams.model_validate(params)"));
+
+        let mut schema = sample_schema();
+        schema.add_field("price".to_string(), DataType::Decimal, None);
+        let typed = CodeGenerator
+            .generate_with_schema_and_options(&sample_compound(), &schema, TargetLanguage::Python, &options)
+            .unwrap();
+        assert!(typed.code.contains("test-traceability-123"));
+        assert!(typed.code.contains("from decimal import Decimal"));
+        assert!(typed.code.contains("    amount: int = Field(gt=0)\n    balance: int = Field(ge=0)\n    price: Decimal\n"));
+    }
+
+    #[test]
+    fn test_typescript_zod_schema() {
+        let options = CodegenOptions {
+            zod: true,
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_options(&sample_compound(), TargetLanguage::TypeScript, &options)
+            .unwrap();
+        let code = &output.code;
+        assert!(code.contains("import { z } from \"zod\";"));
+        assert!(code.contains("  .object({\n    amount: z.number().int().gt(0),\n    balance: z.number().int(),\n  })"));
+        assert!(code.contains("  .refine(({ amount, balance }) => balance >= amount, { message: \"the balance is at least the amount\", path: [\"balance\"] });"));
+        assert!(code.contains("export type ValidationParams = z.infer<typeof ValidationParamsSchema>;"));
+        assert!(code.contains("export function validate_intent(params: unknown): params is ValidationParams {"));
+
+        let mut schema = sample_schema();
+        schema.add_field("memo".to_string(), DataType::String, None);
+        let typed = CodeGenerator
+            .generate_with_schema_and_options(&sample_compound(), &schema, TargetLanguage::TypeScript, &options)
+            .unwrap();
+        assert!(typed.code.contains("    balance: z.number().int().gte(0),\n    memo: z.string(),\n"));
+    }
+
+    #[test]
+    fn test_membership_operators() {
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "email".to_string(),
+                operator: ConstraintOperator::Contains,
+                right_value: "\"@\"".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "username".to_string(),
+                operator: ConstraintOperator::DoesNotContain,
+                right_value: "\"admin\"".to_string(),
+            }),
+        ]);
+        let generate = |lang| CodeGenerator.generate(&compound, lang).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("    pub email: String,\n    pub username: String,"));
+        assert!(rust.contains("(params.email.contains(\"@\") && !(params.username.contains(\"admin\")))"));
+        assert!(generate(TargetLanguage::Python).contains("(\"@\" in params['email'] and not (\"admin\" in params['username']))"));
+        assert!(generate(TargetLanguage::TypeScript).contains("params.email.includes(\"@\") && !(params.username.includes(\"admin\"))"));
+        assert!(generate(TargetLanguage::Zig).contains("(std.mem.indexOf(u8, params.email, \"@\") != null)"));
+
+        let spark = generate(TargetLanguage::SparkAda);
+        assert!(spark.contains("with Ada.Strings.Fixed;\n\npackage Validator"));
+        assert!(spark.contains("Post => (Validate_Intent'Result = (Ada.Strings.Fixed.Index (Params.Email, \"@\") /= 0 and then not (Ada.Strings.Fixed.Index (Params.Username, \"admin\") /= 0)))"));
+
+        // Not guard-safe, so checked in the clause body
+        let elixir = generate(TargetLanguage::Elixir);
+        assert!(elixir.contains("when is_binary(email) and is_binary(username) do\n    if String.contains?(email, \"@\") and not (String.contains?(username, \"admin\")), do: {:ok, true}, else: {:error, :validation_failed}"));
+
+        let solidity = generate(TargetLanguage::Solidity);
+        assert!(solidity.contains("function contains(string memory haystack, string memory needle) pure returns (bool)"));
+        assert!(solidity.contains("require(!(contains(params.username, \"admin\")), UsernameDoesNotContainAdmin());"));
+    }
+
+    #[test]
+    fn test_string_literals_resist_injection() {
+        let text = "x\"); drop('y'); /* #{evil} */ -- \\\n";
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "memo".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: literal::json_string(text),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "note".to_string(),
+                operator: ConstraintOperator::Contains,
+                right_value: literal::json_string(text),
+            }),
+        ]);
+        let languages = [
+            TargetLanguage::Rust,
+            TargetLanguage::TypeScript,
+            TargetLanguage::Python,
+            TargetLanguage::Solidity,
+            TargetLanguage::SparkAda,
+            TargetLanguage::Zig,
+            TargetLanguage::Elixir,
+            TargetLanguage::Sql(SqlDialect::Postgres),
+            TargetLanguage::Sql(SqlDialect::MySql),
+            TargetLanguage::Sql(SqlDialect::Sqlite),
+            TargetLanguage::Rego,
+            TargetLanguage::Cue,
+            TargetLanguage::Cedar,
+            TargetLanguage::Wit,
+            TargetLanguage::EbpfC,
+        ];
+        for language in languages {
+            let code = CodeGenerator.generate(&compound, language).unwrap().code;
+            let expected = match language {
+                TargetLanguage::Rust | TargetLanguage::Wit => literal::rust_string(text),
+                TargetLanguage::Solidity => literal::solidity_string(text),
+                TargetLanguage::SparkAda => literal::ada_string(text),
+                TargetLanguage::Zig => literal::zig_string(text),
+                TargetLanguage::Elixir => literal::elixir_string(text),
+                TargetLanguage::Cedar => literal::cedar_string(text),
+                TargetLanguage::EbpfC => literal::c_string(text),
+                TargetLanguage::Sql(SqlDialect::Postgres) => r#"('x"); drop(''y''); /* #{evil} */ -- \' || CHR(10))"#.into(),
+                TargetLanguage::Sql(SqlDialect::Sqlite) => r#"('x"); drop(''y''); /* #{evil} */ -- \' || CHAR(10))"#.into(),
+                TargetLanguage::Sql(SqlDialect::MySql) => {
+                    r#"CONCAT('x"); drop(''y''); /* #{evil} */ -- \\', CONVERT(X'0A' USING utf8mb4))"#.into()
+                }
+                _ => literal::json_string(text),
+            };
+            assert!(code.contains(&expected), "{:?}:\n{}", language, code);
+            assert!(!code.contains("-- \\\n"), "{:?}:\n{}", language, code);
+        }
+    }
+
+    #[test]
+    fn test_traceability_id_stays_in_comments() {
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+        let mut schema = Schema::new("TR-1\n*/ evil();\r".to_string());
+        schema.add_field("amount".to_string(), DataType::Uint64, None);
+        let languages = [
+            TargetLanguage::Rust,
+            TargetLanguage::TypeScript,
+            TargetLanguage::Python,
+            TargetLanguage::Solidity,
+            TargetLanguage::SparkAda,
+            TargetLanguage::Zig,
+            TargetLanguage::Elixir,
+            TargetLanguage::Sql(SqlDialect::Postgres),
+            TargetLanguage::Rego,
+            TargetLanguage::Cue,
+            TargetLanguage::Cedar,
+            TargetLanguage::Wit,
+            TargetLanguage::EbpfC,
+        ];
+        for language in languages {
+            let output = CodeGenerator.generate_with_schema(&compound, &schema, language).unwrap();
+            assert!(output.code.contains("TR-1\\n*\\/ evil();\\r"), "{:?}:\n{}", language, output.code);
+            for code in std::iter::once(&output.code).chain(output.files.iter().map(|file| &file.contents)) {
+                assert!(!code.contains("*/ evil") && !code.contains('\r'), "{:?}:\n{}", language, code);
+            }
+        }
+        let ast = crucible_core::IntentAst::new();
+        let feature = gherkin::GherkinExporter::new("Payments").export(&ast, &schema);
+        assert!(feature.starts_with("# Traceability ID: TR-1\\n*\\/ evil();\\r\nFeature: Payments\n"), "{}", feature);
+    }
+
+    #[test]
+    fn test_between_range_checks() {
+        let compound = CompoundConstraint::And(vec![
+            Between::new("amount", "1", "100", true).into(),
+            Between::new("fee", "0", "5", false).into(),
+        ]);
+        let generate = |lang| CodeGenerator.generate(&compound, lang).unwrap().code;
+
+        assert!(generate(TargetLanguage::Rust).contains("((1..=100).contains(&params.amount) && (params.fee > 0 && params.fee < 5))"));
+        assert!(generate(TargetLanguage::Python).contains("((1 <= params['amount'] <= 100) and (0 < params['fee'] < 5))"));
+        assert!(generate(TargetLanguage::TypeScript).contains("((params.amount >= 1 && params.amount <= 100) && (params.fee > 0 && params.fee < 5))"));
+        assert!(generate(TargetLanguage::Sql(SqlDialect::Postgres)).contains("CHECK (((amount BETWEEN 1 AND 100) AND (fee > 0 AND fee < 5)))"));
+
+        let spark = generate(TargetLanguage::SparkAda);
+        assert!(spark.contains("return ((Params.Amount in 1 .. 100) and then (Params.Fee > 0 and then Params.Fee < 5));"));
+    }
+
+    #[test]
+    fn test_cardinality_rendering() {
+        let method = |value: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "method".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: value.to_string(),
+            })
+        };
+        let payment = CompoundConstraint::ExactlyOne(vec![method("1"), method("2")]);
+        let generate = |compound: &CompoundConstraint, lang| CodeGenerator.generate(compound, lang).unwrap().code;
+
+        assert!(generate(&payment, TargetLanguage::Rust)
+            .contains("([params.method == 1, params.method == 2].iter().filter(|&&held| held).count() == 1)"));
+        assert!(generate(&payment, TargetLanguage::Python).contains("(sum([params['method'] == 1, params['method'] == 2]) == 1)"));
+        assert!(generate(&payment, TargetLanguage::TypeScript)
+            .contains("([params.method === 1, params.method === 2].filter(Boolean).length === 1)"));
+        assert!(generate(&payment, TargetLanguage::SparkAda)
+            .contains("((Boolean'Pos (Params.Method = 1) + Boolean'Pos (Params.Method = 2)) = 1)"));
+        // Elixir guards cannot count, so they get the pairwise encoding
+        assert!(generate(&payment, TargetLanguage::Elixir).contains("(method == 1 or method == 2) and not (method == 1 and method == 2)"));
+
+        let at_most_one = CompoundConstraint::AtMostOne(vec![method("1"), method("2"), method("3")]);
+        assert!(generate(&at_most_one, TargetLanguage::Solidity).contains(
+            "(!(params.method == 1 && params.method == 2) && !(params.method == 1 && params.method == 3) && !(params.method == 2 && params.method == 3))"
+        ));
+    }
+
+    #[test]
+    fn test_language_names() {
+        assert_eq!(TargetLanguage::from_name("Rust"), Some(TargetLanguage::Rust));
+        assert_eq!(TargetLanguage::from_name("sql-sqlite"), Some(TargetLanguage::Sql(SqlDialect::Sqlite)));
+        assert_eq!(TargetLanguage::from_name("cobol"), None);
+        assert_eq!(TargetLanguage::Python.file_extension(), "py");
+    }
+
+    #[test]
+    fn test_named_predicates() {
+        let mut definitions = Definitions::new();
+        definitions
+            .define(
+                "sufficient_funds",
+                CompoundConstraint::Simple(Constraint {
+                    left_variable: "balance".to_string(),
+                    operator: ConstraintOperator::GreaterThanOrEqual,
+                    right_value: "amount".to_string(),
+                }),
+            )
+            .unwrap();
+        let withdraw = CompoundConstraint::And(vec![
+            CompoundConstraint::Named("sufficient_funds".to_string()),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "0".to_string(),
+            }),
+        ]);
+        let options = CodegenOptions { definitions: Some(definitions), ..CodegenOptions::default() };
+        let generate = |lang| CodeGenerator.generate_with_options(&withdraw, lang, &options).unwrap().code;
+
+        // Helper functions where the language has them, called from the validator
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("(sufficient_funds(params) && params.amount > 0)"));
+        assert!(rust.contains(
+            "pub fn sufficient_funds(params: &ValidationParams) -> bool {\n    params.balance >= params.amount\n}"
+        ));
+        assert!(rust.contains("pub balance: i64"));
+        let python = generate(TargetLanguage::Python);
+        assert!(python.contains("def sufficient_funds(params: Dict[str, Any]) -> bool:"));
+        assert!(generate(TargetLanguage::TypeScript).contains("export function sufficient_funds(params: any): boolean {"));
+
+        // Inlined everywhere else
+        let spark = generate(TargetLanguage::SparkAda);
+        assert!(!spark.contains("sufficient_funds"));
+        assert!(spark.contains("Params.Balance >= Params.Amount"));
+
+        let undefined = CodeGenerator.generate(&withdraw, TargetLanguage::Rust);
+        assert!(matches!(undefined, Err(CodegenError::Definition(DefinitionError::Undefined(name))) if name == "sufficient_funds"));
+    }
+
+    #[test]
+    fn test_spark_inlines_predicates_over_params() {
+        let mut definitions = Definitions::new();
+        definitions
+            .define(
+                "sufficient_funds",
+                CompoundConstraint::Simple(Constraint {
+                    left_variable: "balance".to_string(),
+                    operator: ConstraintOperator::GreaterThanOrEqual,
+                    right_value: "amount + fee".to_string(),
+                }),
+            )
+            .unwrap();
+        let withdraw = CompoundConstraint::Named("sufficient_funds".to_string());
+        let options = CodegenOptions { definitions: Some(definitions), ..CodegenOptions::default() };
+        let output = CodeGenerator.generate_with_options(&withdraw, TargetLanguage::SparkAda, &options).unwrap();
+
+        let inlined = "Params.Balance >= Params.Amount + Params.Fee";
+        assert!(output.code.contains(&format!("Post => (Validate_Intent'Result = {})", inlined)));
+        let body = &output.files.iter().find(|f| f.path == "src/validator.adb").unwrap().contents;
+        assert!(body.contains(&format!("return {};", inlined)));
+    }
+
+    #[test]
+    fn test_implication_rendering() {
+        let approval = CompoundConstraint::implies(
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "1000".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "approvals".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "2".to_string(),
+            }),
+        );
+        let generate = |lang| CodeGenerator.generate(&approval, lang).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("/* implication */ (!(params.amount > 1000) || params.approvals >= 2)"));
+        assert!(rust.contains("debug_assert!(/* implication */ (!(params.amount > 1000) || params.approvals >= 2));"));
+        assert!(generate(TargetLanguage::SparkAda).contains("(if Params.Amount > 1000 then Params.Approvals >= 2)"));
+        assert!(generate(TargetLanguage::Python).contains("(params['approvals'] >= 2 if params['amount'] > 1000 else True)"));
+        assert!(generate(TargetLanguage::Elixir).contains("(not (amount > 1000) or approvals >= 2)"));
+    }
+
+    #[test]
+    fn test_modulo_is_euclidean() {
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint::multiple_of("amount", "100")),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "id % shards".to_string(),
+                operator: ConstraintOperator::NotEqual,
+                right_value: "3".to_string(),
+            }),
+        ]);
+        let generate = |lang| CodeGenerator.generate(&compound, lang).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("(params.amount.rem_euclid(100) == 0 && params.id.rem_euclid(params.shards) != 3)"));
+        assert!(rust.contains("pub shards: i64"));
+        assert!(generate(TargetLanguage::Python).contains("(params['amount'] % abs(100)) == 0"));
+        assert!(generate(TargetLanguage::TypeScript)
+            .contains("(((params.amount % 100) + Math.abs(100)) % Math.abs(100)) === 0"));
+        assert!(generate(TargetLanguage::SparkAda).contains("(Params.Amount mod abs (100)) = 0"));
+        assert!(generate(TargetLanguage::Sql(SqlDialect::Postgres)).contains("(((amount % 100) + ABS(100)) % ABS(100)) = 0"));
+    }
+
+    #[test]
+    fn test_min_max_abs_calls() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("fee", ConstraintOperator::Equal, "max(min_fee, amount * rate)"),
+            simple("abs(delta)", ConstraintOperator::LessThanOrEqual, "min(limit, 5, cap)"),
+        ]);
+        let generate = |lang| CodeGenerator.generate(&compound, lang).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("params.fee == core::cmp::max(params.min_fee, (params.amount * params.rate))"));
+        assert!(rust.contains(
+            "params.delta.saturating_abs() <= core::cmp::min(core::cmp::min(params.limit, 5), params.cap)"
+        ));
+        assert!(rust.contains("pub rate: i64"));
+        assert!(generate(TargetLanguage::TypeScript)
+            .contains("Math.abs(params.delta) <= Math.min(params.limit, 5, params.cap)"));
+        assert!(generate(TargetLanguage::Python)
+            .contains("abs(params['delta']) <= min(params['limit'], 5, params['cap'])"));
+        // Guard-safe: `Kernel.max/2` is not allowed in a `when` clause
+        assert!(generate(TargetLanguage::Elixir)
+            .contains("fee == div(min_fee + (amount * rate) + abs(min_fee - (amount * rate)), 2) and abs(delta) <= "));
+        assert!(generate(TargetLanguage::Solidity).contains("(params.delta >= 0 ? params.delta : 0 - params.delta)"));
+        assert!(generate(TargetLanguage::Zig).contains("@abs(params.delta) <= @min(params.limit, 5, params.cap)"));
+        assert!(generate(TargetLanguage::SparkAda)
+            .contains("Integer'Max (Params.Min_Fee, (Params.Amount * Params.Rate))"));
+        assert!(generate(TargetLanguage::Sql(SqlDialect::Postgres)).contains("ABS(delta) <= LEAST(limit_, 5, cap)"));
+        assert!(generate(TargetLanguage::Sql(SqlDialect::Sqlite)).contains("fee = MAX(min_fee, (amount * rate))"));
+    }
+
+    #[test]
+    fn test_conditional_values() {
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "fee".to_string(),
+            operator: ConstraintOperator::Equal,
+            right_value: "0 if member else 2 if tier >= 3 else base + 1".to_string(),
+        });
+        let generate = |lang| CodeGenerator.generate(&compound, lang).unwrap().code;
+
+        assert!(generate(TargetLanguage::Rust).contains(
+            "params.fee == (if params.member == true { 0 } else { (if params.tier >= 3 { 2 } else { (params.base + 1) "
+        ));
+        assert!(generate(TargetLanguage::TypeScript)
+            .contains("params.fee === (params.member === true ? 0 : (params.tier >= 3 ? 2 : (params.base + 1)))"));
+        assert!(generate(TargetLanguage::Python).contains("params['fee'] == (0 if params['member'] == true else (2"));
+        assert!(generate(TargetLanguage::SparkAda)
+            .contains("Params.Fee = (if Params.Member = true then 0 else (if Params.Tier >= 3 then 2 else "));
+        assert!(generate(TargetLanguage::Zig).contains("params.fee == (if (params.member == true) 0 else (if ("));
+        assert!(generate(TargetLanguage::Sql(SqlDialect::Postgres))
+            .contains("fee = CASE WHEN member = true THEN 0 ELSE CASE WHEN tier >= 3 THEN 2 ELSE (base + 1) END END"));
+        // Guard-safe: no conditional expression in a `when` clause
+        assert!(generate(TargetLanguage::Elixir).contains(
+            "((member == true and fee == 0 or not (member == true) and (tier >= 3 and fee == 2 or not (tier >= 3) and "
+        ));
+    }
+
+    #[test]
+    fn test_list_aggregates() {
+        let mut schema = Schema::new("orders".to_string());
+        schema.add_field("line_amounts".to_string(), DataType::List(Box::new(DataType::Uint64)), None);
+        schema.add_field("total".to_string(), DataType::Uint64, None);
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "total".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: "sum(line_amounts)".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "count(line_amounts)".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "average(line_amounts)".to_string(),
+            }),
+        ]);
+        let generate = |lang| CodeGenerator.generate_with_schema(&compound, &schema, lang);
+
+        let rust = generate(TargetLanguage::Rust).unwrap().code;
+        assert!(rust.contains("    pub line_amounts: Vec<u64>,\n"));
+        assert!(rust.contains("(params.total == params.line_amounts.iter().fold(0, |total, item| total + item) && "));
+        assert!(rust.contains(
+            "params.line_amounts.iter().fold(0, |count, _| count + 1) > \
+             (if params.line_amounts.is_empty() { 0 } else { "
+        ));
+        let typescript = generate(TargetLanguage::TypeScript).unwrap().code;
+        assert!(typescript.contains("line_amounts: number[];"));
+        assert!(typescript
+            .contains("params.line_amounts.length > (params.line_amounts.length === 0 ? 0 : Math.floor("));
+        let python = generate(TargetLanguage::Python).unwrap().code;
+        assert!(python.contains("params['total'] == sum(params['line_amounts']) and len(params['line_amounts']) > "));
+        assert!(python
+            .contains("(sum(params['line_amounts']) // len(params['line_amounts']) if params['line_amounts'] else 0)"));
+
+        // Without a Schema an aggregated field is a list of `i64`
+        let inferred = CodeGenerator.generate(&compound, TargetLanguage::Rust).unwrap().code;
+        assert!(inferred.contains("    pub line_amounts: Vec<i64>,\n"));
+        for lang in [TargetLanguage::Zig, TargetLanguage::Sql(SqlDialect::Postgres), TargetLanguage::Solidity] {
+            assert!(matches!(generate(lang), Err(CodegenError::UnsupportedLanguage(_))), "{:?}", lang);
+        }
+        let bigint = CodegenOptions { bigint: true, ..Default::default() };
+        let bigint = CodeGenerator.generate_with_options(&compound, TargetLanguage::TypeScript, &bigint);
+        assert!(matches!(bigint, Err(CodegenError::UnsupportedLanguage(_))));
+    }
+
+    #[test]
+    fn test_distinct() {
+        let distinct = |fields: &[&str]| CompoundConstraint::Distinct(fields.iter().map(|f| f.to_string()).collect());
+        let compound = CompoundConstraint::And(vec![distinct(&["sender", "receiver"]), distinct(&["ids"])]);
+        let generate = |lang| CodeGenerator.generate(&compound, lang).map(|output| output.code);
+
+        let rust = generate(TargetLanguage::Rust).unwrap();
+        assert!(rust.contains("    pub ids: Vec<i64>,\n"));
+        assert!(rust.contains(
+            "({ let mut seen = std::collections::HashSet::new(); [&params.sender, &params.receiver].into_iter()\
+             .all(|value| seen.insert(value)) } && { let mut seen = std::collections::HashSet::new(); \
+             params.ids.iter().all(|item| seen.insert(item)) })"
+        ));
+        let options = CodegenOptions { no_std: true, ..Default::default() };
+        let no_std = CodeGenerator.generate_with_options(&compound, TargetLanguage::Rust, &options).unwrap().code;
+        assert!(no_std.contains(
+            "((params.sender != params.receiver) && \
+             params.ids.iter().enumerate().all(|(index, item)| !params.ids[..index].contains(item)))"
+        ));
+        let typescript = generate(TargetLanguage::TypeScript).unwrap();
+        assert!(typescript.contains(
+            "(new Set([params.sender, params.receiver]).size === 2 && new Set(params.ids).size === params.ids.length)"
+        ));
+        let python = generate(TargetLanguage::Python).unwrap();
+        assert!(python.contains(
+            "(len({params['sender'], params['receiver']}) == 2 and len(set(params['ids'])) == len(params['ids']))"
+        ));
+
+        // Targets without sets compare each pair; a list needs list fields
+        let fields = distinct(&["sender", "receiver", "escrow"]);
+        let zig = CodeGenerator.generate(&fields, TargetLanguage::Zig).unwrap().code;
+        assert!(zig.contains(
+            "return (params.sender != params.receiver and params.sender != params.escrow and \
+             params.receiver != params.escrow);"
+        ));
+        assert!(zig.contains("const params = ValidationParams{ .escrow = 0, .receiver = 1, .sender = 2 };"));
+        assert!(matches!(generate(TargetLanguage::Zig), Err(CodegenError::UnsupportedLanguage(_))));
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint::bit_set("flags", 3)),
+            simple("mode & 0x0F", ConstraintOperator::Equal, "2"),
+            simple("word << 4", ConstraintOperator::NotEqual, "0"),
+        ]);
+        let mut schema = Schema::new("TR-BITS".to_string());
+        schema.add_field("flags".to_string(), DataType::Int64, None);
+        schema.add_field("mode".to_string(), DataType::Int64, None);
+        schema.add_field("word".to_string(), DataType::Uint32, None);
+        let generate = |lang| CodeGenerator.generate_with_schema(&compound, &schema, lang).map(|output| output.code);
+
+        // Rust computes in the field's type; the others wrap `<<` back to 32 bits
+        assert!(generate(TargetLanguage::Rust)
+            .unwrap()
+            .contains("((params.flags & 0x8) != 0 && (params.mode & 0x0F) == 2 && (params.word << 4) != 0)"));
+        let typescript = generate(TargetLanguage::TypeScript).unwrap();
+        assert!(typescript.contains("Number(BigInt.asIntN(64, BigInt(params.mode) & BigInt(0x0F))) === 2"));
+        assert!(typescript.contains("((params.word << 4) >>> 0) !== 0"));
+        assert!(generate(TargetLanguage::Python).unwrap().contains("((params['word'] << 4) & 4294967295) != 0"));
+        assert!(generate(TargetLanguage::Elixir)
+            .unwrap()
+            .contains("Bitwise.band(flags, 0x8) != 0 and Bitwise.band(mode, 0x0F) == 2"));
+        assert!(generate(TargetLanguage::Rego).unwrap().contains("bits.and(bits.lsh(input.word, 4), 4294967295) != 0"));
+
+        // Without a Schema a field is a signed 64-bit integer
+        let python = CodeGenerator.generate(&compound, TargetLanguage::Python).unwrap().code;
+        assert!(python.contains(
+            "((((params['word'] << 4) + 9223372036854775808) & 18446744073709551615) - 9223372036854775808) != 0"
+        ));
+        for lang in [TargetLanguage::SparkAda, TargetLanguage::Sql(SqlDialect::Postgres), TargetLanguage::Cue] {
+            assert!(matches!(generate(lang), Err(CodegenError::UnsupportedLanguage(_))));
+        }
+    }
+
+    #[test]
+    fn test_overflow_checked_arithmetic() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("total", ConstraintOperator::Equal, "amount + fee"),
+            simple("limit", ConstraintOperator::GreaterThanOrEqual, "2 * amount"),
+            simple("share", ConstraintOperator::LessThanOrEqual, "amount / 2"),
+        ]);
+        let risk = |term: &str, constraint: &str| OverflowRisk {
+            term: term.to_string(),
+            constraint: constraint.to_string(),
+            width: crucible_core::BitWidth::DEFAULT,
+            witness: BTreeMap::new(),
+        };
+        let options = CodegenOptions {
+            overflow_risks: vec![
+                risk("amount + fee", "total == amount + fee"),
+                risk("2 * amount", "limit >= 2 * amount"),
+                risk("amount / 2", "share <= amount / 2"),
+            ],
+            ..CodegenOptions::default()
+        };
+        let generate = |lang| CodeGenerator.generate_with_options(&compound, lang, &options).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("matches!(params.amount.checked_add(params.fee), Some(value) if params.total == value)"));
+        assert!(rust.contains("matches!(params.amount.checked_mul(2), Some(value) if params.limit >= value)"));
+        assert!(rust.contains("matches!(params.amount.checked_div(2), Some(value) if params.share <= value)"));
+        assert!(rust.contains("pub fee: i64"));
+
+        // A quotient fits a double; sums and products are compared in `BigInt`
+        let typescript = generate(TargetLanguage::TypeScript);
+        assert!(typescript.contains("BigInt(params.total) === BigInt(params.amount) + BigInt(params.fee)"));
+        assert!(typescript.contains("BigInt(params.limit) >= 2n * BigInt(params.amount)"));
+        assert!(typescript.contains("params.share <= params.amount / 2"));
+
+        // Without risks the plain operation stays
+        let plain = CodeGenerator.generate(&compound, TargetLanguage::Rust).unwrap().code;
+        assert!(!plain.contains("checked_add"));
+    }
+
+    #[test]
+    fn test_typescript_bigint_mode() {
+        let options = CodegenOptions {
+            bigint: true,
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_options(&sample_compound(), TargetLanguage::TypeScript, &options)
+            .unwrap();
+        let code = &output.code;
+        assert!(code.contains("export interface ValidationParams {\n  // Define your validation parameters here\n  amount: bigint;\n  balance: bigint;\n}"));
+        assert!(code.contains("params.amount > 0n"));
+        assert!(code.contains("export function parseValidationParams(json: Record<string, unknown>): ValidationParams {"));
+        assert!(code.contains("    amount: toBigInt(json.amount, \"amount\"),"));
+        // The parser follows the validator
+        assert!(code.find("export class Validator").unwrap() < code.find("function toBigInt").unwrap());
+
+        let mut schema = sample_schema();
+        schema.add_field("count".to_string(), DataType::Uint32, None);
+        let compound = CompoundConstraint::And(vec![
+            sample_compound(),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "count".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: "1".to_string(),
+            }),
+        ]);
+        let typed = CodeGenerator
+            .generate_with_schema_and_options(&compound, &schema, TargetLanguage::TypeScript, &options)
+            .unwrap();
+        assert!(typed.code.contains("params.amount > 0n"));
+        assert!(typed.code.contains("params.count === 1)"));
+        assert!(typed.code.contains("    count: json.count as number,"));
+
+        let zod = CodegenOptions {
+            zod: true,
+            bigint: true,
+            ..Default::default()
+        };
+        let typed = CodeGenerator
+            .generate_with_schema_and_options(&sample_compound(), &sample_schema(), TargetLanguage::TypeScript, &zod)
+            .unwrap();
+        assert!(typed.code.contains("    amount: z.coerce.bigint().gt(0n),\n    balance: z.coerce.bigint().gte(0n),\n"));
+    }
+
+    #[test]
+    fn test_elixir_typespecs_and_property_tests() {
+        let mut schema = sample_schema();
+        schema.add_field("memo".to_string(), DataType::String, None);
+        let output = CodeGenerator
+            .generate_with_schema(&sample_compound(), &schema, TargetLanguage::Elixir)
+            .unwrap();
+        assert!(output.code.contains("@type params :: %{amount: integer(), balance: integer(), memo: String.t()}"));
+        assert!(output.code.contains("@spec validate_intent?(params() | term()) :: {:ok, true} | {:error, atom()}"));
+        assert!(output.code.contains("def validate_intent?(%{amount: amount, balance: balance})\n      when is_integer(amount) and amount >= 0 and is_integer(balance) and balance >= 0 and (balance >= amount and amount > 0),"));
+
+        let tests = &output.files[0];
+        assert_eq!(tests.path, "test/validator_test.exs");
+        assert!(tests.contents.contains("check all amount <- non_negative_integer(), balance <- non_negative_integer() do"));
+        assert!(tests.contents.contains("assert Validator.validate_intent?(%{amount: amount, balance: balance}) == expected"));
     }
 
     #[test]
@@ -1956,7 +6072,7 @@ mod tests {
         let result = generator.generate(&sample_compound(), TargetLanguage::TypeScript);
         assert!(result.is_ok());
         let output = result.unwrap();
-        assert!(output.code.contains("params.balance >= amount"));
+        assert!(output.code.contains("params.balance >= params.amount"));
         assert!(output.code.contains("&&"));
     }
 
@@ -1997,25 +6113,78 @@ mod tests {
             }),
         ]);
 
-        let strategy = SparkAdaStrategy;
+        let strategy = SparkAdaStrategy::new(&CodegenOptions::default());
         let contracts = strategy.emit_contracts(&compound);
         assert!(contracts.is_some());
         let contracts_str = contracts.unwrap();
-        assert!(contracts_str.contains("Pre  =>"));
-        assert!(contracts_str.contains("Post =>"));
+        assert!(!contracts_str.contains("Pre  =>"));
+        assert!(contracts_str
+            .contains("Post => (Validate_Intent'Result = (Params.Amount >= 0 and then Params.Balance >= Params.Amount))"));
     }
 
     #[test]
     fn test_zig_comptime_capable() {
+        let strategy = ZigStrategy::new(&CodegenOptions::default());
+        // `balance >= amount` depends on two runtime fields
+        assert!(!strategy.is_comptime_capable(&sample_compound()));
+        let bounded = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+        assert!(strategy.is_comptime_capable(&bounded));
+    }
+
+    #[test]
+    fn test_zig_params_and_tests() {
         let compound = sample_compound();
-        let strategy = ZigStrategy;
-        assert!(strategy.is_comptime_capable(&compound));
+        let output = CodeGenerator.generate(&compound, TargetLanguage::Zig).unwrap();
+        assert!(output.code.contains("pub const ValidationParams = struct {\n    amount: i64,\n    balance: i64,\n};"));
+        assert!(!output.code.contains("@hasField"));
+        let accepts = "std.debug.assert(validate_intent(ValidationParams{ .amount = 1, .balance = 1 }));";
+        let rejects = "std.debug.assert(!validate_intent(ValidationParams{ .amount = 1, .balance = 0 }));";
+        assert!(output.code.contains(accepts) && output.code.contains(rejects));
+        assert!(output.code.contains("_ = @as(@FieldType(ValidationParams, \"amount\"), 0);"));
+        assert!(output.code.contains("const params = ValidationParams{ .amount = 1, .balance = 1 };"));
+        // The tests follow the validator they call
+        assert!(output.code.find("pub fn validate_intent").unwrap() < output.code.find("test \"").unwrap());
+
+        let typed = CodeGenerator.generate_with_schema(&compound, &sample_schema(), TargetLanguage::Zig).unwrap();
+        assert!(typed.code.contains("    amount: u64,\n    balance: u64,"));
+        assert!(typed.code.contains("pub fn validate_intent(params: ValidationParams) bool {"));
+        assert!(typed.code.contains("return (params.balance >= params.amount and params.amount > 0);"));
+
+        let sum = ZigStrategy::new(&CodegenOptions::default()).safe_op(
+            "params.balance",
+            ArithmeticOperator::Add,
+            "params.amount",
+            &sample_schema(),
+        );
+        assert_eq!(sum, "(std.math.add(@TypeOf(params.balance), params.balance, params.amount) catch return false)");
+    }
+
+    #[test]
+    fn test_zig_validator_rejects_violating_params() {
+        let mut schema = sample_schema();
+        schema.add_field("role".to_string(), DataType::String, None);
+        let code = CodeGenerator.generate_with_schema(&sample_compound(), &schema, TargetLanguage::Zig).unwrap().code;
+        // Violations make the validator return false rather than trap
+        assert!(!code.contains("std.debug.assert(params."));
+        assert!(code.contains("bool {\n    return (params.balance >= params.amount and params.amount > 0);\n}"));
+
+        let violating = "validate_intent(ValidationParams{ .amount = 1, .balance = 0, .role = \"\" })";
+        assert!(code.contains("comptime {\n    _ = @as(@FieldType(ValidationParams, \"amount\"), 0);\n"));
+        assert!(code.contains(&format!("    std.debug.assert(!{});\n}}", violating)));
+        assert!(code.contains(&format!(
+            "test \"validate_intent rejects violating params\" {{\n    try std.testing.expect(!{});\n}}",
+            violating
+        )));
     }
 
     #[test]
     fn test_elixir_guard_expression() {
         let compound = sample_compound();
-        let strategy = ElixirStrategy;
+        let strategy = ElixirStrategy::new(&CodegenOptions::default());
         let guard = strategy.to_guard_expression(&compound);
         assert!(guard.is_some());
         let guard_str = guard.unwrap();
@@ -2028,11 +6197,65 @@ mod tests {
         let result = generator.generate(&sample_compound(), TargetLanguage::Solidity);
         assert!(result.is_ok());
         let output = result.unwrap();
-        assert!(output.code.contains("params.balance >= amount"));
+        assert!(output.code.contains("params.balance >= params.amount"));
         assert!(output.code.contains("require("));
         assert!(output.code.contains("// SPDX-License-Identifier: MIT"));
     }
 
+    #[test]
+    fn test_solidity_custom_errors_and_pragma() {
+        let output = CodeGenerator.generate(&sample_compound(), TargetLanguage::Solidity).unwrap();
+        let code = &output.code;
+        assert!(code.starts_with("// SPDX-License-Identifier: MIT"));
+        assert!(code.contains("pragma solidity ^0.8.27;"));
+        assert!(code.find("struct ValidationParams").unwrap() < code.find("contract Validator").unwrap());
+        assert!(code.contains("    uint256 balance;"));
+        assert!(code.contains("error BalanceAtLeastAmount();"));
+        assert!(code.contains("require(params.amount > 0, AmountGreaterThan0());"));
+        assert!(code.contains("/// @notice Reverted unless the balance is at least the amount"));
+        assert!(!code.contains(".sub("));
+
+        let typed = CodeGenerator
+            .generate_with_schema(&sample_compound(), &sample_schema(), TargetLanguage::Solidity)
+            .unwrap();
+        assert!(typed.code.contains("/// @custom:traceability test-traceability-123"));
+        assert!(typed.code.contains("struct ValidationParams {\n    uint256 amount;\n    uint256 balance;\n}"));
+    }
+
+    #[test]
+    fn test_solidity_field_operands_and_string_equality() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount + fee"),
+            simple("role", ConstraintOperator::Equal, "\"admin\""),
+            simple("sender", ConstraintOperator::NotEqual, "receiver"),
+        ]);
+        let mut schema = sample_schema();
+        schema.add_field("fee".to_string(), DataType::Uint64, None);
+        for field in ["role", "sender", "receiver"] {
+            schema.add_field(field.to_string(), DataType::String, None);
+        }
+
+        let code = CodeGenerator.generate_with_schema(&compound, &schema, TargetLanguage::Solidity).unwrap().code;
+        assert!(code.contains("require(params.balance >= params.amount + params.fee, BalanceAtLeastAmountFee());"));
+        assert!(code.contains(
+            "require(keccak256(bytes(params.role)) == keccak256(bytes(\"admin\")), RoleEqualsAdmin());"
+        ));
+        assert!(code.contains("keccak256(bytes(params.sender)) != keccak256(bytes(params.receiver))"));
+        assert!(!code.contains("params.role == "));
+
+        // Without a Schema the field compared with a string literal is a string
+        let untyped = CodeGenerator.generate(&compound, TargetLanguage::Solidity).unwrap().code;
+        assert!(untyped.contains("    string role;"));
+        assert!(untyped.contains("keccak256(bytes(params.role)) == keccak256(bytes(\"admin\"))"));
+    }
+
     // === Type-Aware Generation Tests (v0.1.5-alpha) ===
 
     fn sample_schema() -> Schema {
@@ -2042,6 +6265,44 @@ mod tests {
         schema
     }
 
+    #[test]
+    fn test_invalid_constraints_are_rejected() {
+        let mut schema = sample_schema();
+        schema.add_field("memo".to_string(), DataType::String, None);
+        let generate = |compound: CompoundConstraint| {
+            CodeGenerator.generate_with_schema(&compound, &schema, TargetLanguage::Rust).map(|output| output.code)
+        };
+        let mut unknown = sample_compound();
+        if let CompoundConstraint::And(parts) = &mut unknown {
+            parts.push(CompoundConstraint::Simple(Constraint::from("fee")));
+        }
+        assert!(matches!(
+            generate(unknown),
+            Err(CodegenError::InvalidConstraint(ConstraintError::UnknownVariable { name, .. })) if name == "fee"
+        ));
+        let mismatch = CompoundConstraint::Simple(Constraint::from("memo"));
+        assert_eq!(
+            generate(mismatch).unwrap_err().to_string(),
+            "Invalid constraint: `memo >= 0` compares a string with a number"
+        );
+        let error = CrucibleError::from(generate(CompoundConstraint::Simple(Constraint::from("memo"))).unwrap_err());
+        assert!(matches!(&error, CrucibleError::Schema(d) if d.code == codes::TYPE_MISMATCH));
+        let unsupported = CrucibleError::from(CodegenError::UnsupportedLanguage("COBOL".to_string()));
+        assert_eq!(unsupported.code(), "G001");
+        assert_eq!(
+            unsupported.localized(crucible_core::Locale::Es),
+            "error de generación de código [G001] Lenguaje de destino no compatible: COBOL"
+        );
+        assert!(generate(sample_compound()).is_ok());
+
+        // Without a Schema only the shape is checked
+        let empty = CompoundConstraint::Or(Vec::new());
+        assert!(matches!(
+            CodeGenerator.generate(&empty, TargetLanguage::Python),
+            Err(CodegenError::InvalidConstraint(ConstraintError::Empty("OR")))
+        ));
+    }
+
     #[test]
     fn test_schema_creation() {
         let schema = sample_schema();
@@ -2051,6 +6312,39 @@ mod tests {
         assert!(schema.requires_overflow_protection("balance"));
     }
 
+    #[test]
+    fn test_generate_to_streams_pieces() {
+        /// Records the size of each write
+        struct Chunks(Vec<usize>);
+        impl io::Write for Chunks {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.push(buf.len());
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let compound = sample_compound();
+        let schema = sample_schema();
+        let options = CodegenOptions::default();
+        for language in [TargetLanguage::Rust, TargetLanguage::SparkAda] {
+            let mut chunks = Chunks(Vec::new());
+            let streamed = CodeGenerator
+                .generate_with_schema_to(&compound, &schema, language, &options, &mut chunks)
+                .unwrap();
+            let output = CodeGenerator.generate_with_schema_and_options(&compound, &schema, language, &options).unwrap();
+            assert_eq!(streamed.bytes as usize, output.code.len());
+            assert_eq!(chunks.0.iter().sum::<usize>(), output.code.len());
+            assert_eq!(streamed.files.len(), output.files.len());
+            // Rust arrives in pieces rather than as one assembled string
+            if language == TargetLanguage::Rust {
+                assert!(chunks.0.len() > 1);
+            }
+        }
+    }
+
     #[test]
     fn test_spark_ada_type_aware_generation() {
         let generator = CodeGenerator;
@@ -2178,4 +6472,26 @@ mod tests {
             range_max: Some(1000) 
         });
     }
+
+    #[test]
+    fn test_glossary_canonical_naming() {
+        let mut glossary = Glossary::new();
+        glossary.add_term("balance", &["account balance"], Some(DataType::Uint64));
+
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "account_balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "0".to_string(),
+        });
+        let options = CodegenOptions {
+            glossary: Some(glossary),
+            ..Default::default()
+        };
+
+        let output = CodeGenerator
+            .generate_with_options(&compound, TargetLanguage::Rust, &options)
+            .unwrap();
+        assert!(output.code.contains("params.balance >= 0"));
+        assert!(!output.code.contains("account_balance"));
+    }
 }
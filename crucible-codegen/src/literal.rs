@@ -0,0 +1,289 @@
+//! String literals in generated code
+//!
+//! Right-hand values come from natural-language text, so none of them is
+//! pasted into a template as written. A value is either code the strategies
+//! can emit as is (a number, a boolean, a field, or arithmetic over those)
+//! or text: a double-quoted literal as the grammar writes it (`"say \"hi\""`,
+//! with `\n`, `\t`, `\"`, `\\` and `\/` escapes) or anything else, taken
+//! verbatim. Text is re-encoded by the target's `format_string`, so a value
+//! such as `"); drop(` is a string in every language rather than code.
+
+use std::borrow::Cow;
+
+/// A right-hand value, classified for rendering
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Operand<'a> {
+    /// Emitted as written: numbers, booleans, fields and arithmetic over them
+    Code(&'a str),
+    /// The contents of a string
+    Text(Cow<'a, str>),
+}
+
+/// Classify a right-hand value
+pub(crate) fn operand(value: &str) -> Operand<'_> {
+    let trimmed = value.trim();
+    if let Some(text) = unquote(trimmed) {
+        return Operand::Text(Cow::Owned(text));
+    }
+    if !trimmed.is_empty() && is_code(trimmed) {
+        return Operand::Code(trimmed);
+    }
+    Operand::Text(Cow::Borrowed(value))
+}
+
+/// The contents of a double-quoted literal, escapes decoded; `None` when
+/// `value` is not one. An unknown escape keeps its backslash.
+pub(crate) fn unquote(value: &str) -> Option<String> {
+    let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some(escaped @ ('"' | '\\' | '/')) => text.push(escaped),
+            Some(other) => {
+                text.push('\\');
+                text.push(other);
+            }
+            None => text.push('\\'),
+        }
+    }
+    Some(text)
+}
+
+/// An arithmetic expression over numbers, booleans and fields (`balance'`
+/// primed, `a.b` dotted): operands alternate with `+ - * / %`, optionally
+/// negated or parenthesized. Anything else (a second word, a quote, `//` or
+/// `--`) could change the program around it, so it is text.
+fn is_code(value: &str) -> bool {
+    if ["//", "/*", "*/", "--"].iter().any(|comment| value.contains(comment)) {
+        return false;
+    }
+    let mut tokens = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c == ' ' {
+            chars.next();
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let mut atom = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.')) {
+                atom.push(c);
+                chars.next();
+            }
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+            }
+            if atom.ends_with('.') || atom.contains("..") {
+                return false;
+            }
+            tokens.push('a');
+        } else if matches!(c, '+' | '-' | '*' | '/' | '%' | '(' | ')') {
+            tokens.push(c);
+            chars.next();
+        } else {
+            return false;
+        }
+    }
+    let mut tokens = tokens.into_iter().peekable();
+    expression(&mut tokens) && tokens.next().is_none()
+}
+
+/// `term (op term)*` over the tokens of `is_code`, `a` standing for an operand
+fn expression(tokens: &mut std::iter::Peekable<impl Iterator<Item = char>>) -> bool {
+    loop {
+        tokens.next_if_eq(&'-');
+        let term = match tokens.next() {
+            Some('a') => true,
+            Some('(') => expression(tokens) && tokens.next() == Some(')'),
+            _ => false,
+        };
+        if !term {
+            return false;
+        }
+        if tokens.next_if(|t| matches!(t, '+' | '-' | '*' | '/' | '%')).is_none() {
+            return true;
+        }
+    }
+}
+
+/// An `Operand::Code` value with each field (primed or dotted) replaced by
+/// `field(name)`; numbers, booleans and operators are kept as written
+pub(crate) fn map_fields(code: &str, mut field: impl FnMut(&str) -> String) -> String {
+    let mut out = String::with_capacity(code.len());
+    let mut chars = code.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !(c.is_ascii_alphanumeric() || c == '_') {
+            out.push(c);
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some((i, c)) = chars.next_if(|(_, c)| c.is_ascii_alphanumeric() || matches!(c, '_' | '.')) {
+            end = i + c.len_utf8();
+        }
+        let atom = &code[start..end];
+        if c.is_ascii_digit() || matches!(atom, "true" | "false") {
+            out.push_str(atom);
+        } else if chars.next_if(|(_, c)| *c == '\'').is_some() {
+            out.push_str(&field(&code[start..=end]));
+        } else {
+            out.push_str(&field(atom));
+        }
+    }
+    out
+}
+
+/// A JSON string, which is also a valid TypeScript, Python, Rego and CUE literal
+pub(crate) fn json_string(text: &str) -> String {
+    serde_json::Value::from(text).to_string()
+}
+
+/// A Rust string literal
+pub(crate) fn rust_string(text: &str) -> String {
+    format!("\"{}\"", text.escape_debug())
+}
+
+/// A double-quoted literal with `\\`, `\"`, `\n`, `\r` and `\t`, and
+/// `escape` for the remaining characters that need one
+pub(crate) fn escaped_string(text: &str, escape: impl Fn(char) -> Option<String>) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => match escape(c) {
+                Some(escaped) => out.push_str(&escaped),
+                None => out.push(c),
+            },
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// A C string literal: octal escapes for control and non-ASCII bytes, and
+/// `\?` so no `??` trigraph forms
+pub(crate) fn c_string(text: &str) -> String {
+    escaped_string(text, |c| match c {
+        '?' => Some("\\?".to_string()),
+        c if c.is_ascii() && !c.is_ascii_control() => None,
+        c => Some(c.to_string().bytes().map(|b| format!("\\{:03o}", b)).collect()),
+    })
+}
+
+/// An Ada string expression. Ada has no escapes: `"` is doubled and every
+/// other byte outside printable ASCII is a concatenated `Character'Val`
+pub(crate) fn ada_string(text: &str) -> String {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    for byte in text.bytes() {
+        if byte.is_ascii_graphic() || byte == b' ' {
+            if byte == b'"' {
+                literal.push('"');
+            }
+            literal.push(byte as char);
+        } else {
+            if !literal.is_empty() {
+                parts.push(format!("\"{}\"", std::mem::take(&mut literal)));
+            }
+            parts.push(format!("Character'Val ({})", byte));
+        }
+    }
+    if !literal.is_empty() || parts.is_empty() {
+        parts.push(format!("\"{}\"", literal));
+    }
+    match parts.len() {
+        1 => parts.remove(0),
+        _ => format!("({})", parts.join(" & ")),
+    }
+}
+
+/// A Zig string literal
+pub(crate) fn zig_string(text: &str) -> String {
+    escaped_string(text, |c| match c {
+        c if c.is_ascii_control() => Some(format!("\\x{:02x}", c as u32)),
+        c if needs_escape(c) => Some(format!("\\u{{{:x}}}", c as u32)),
+        _ => None,
+    })
+}
+
+/// A Solidity string literal; plain literals only hold printable ASCII, so
+/// anything else is written as its UTF-8 bytes
+pub(crate) fn solidity_string(text: &str) -> String {
+    escaped_string(text, |c| match c {
+        c if c.is_ascii() && !c.is_ascii_control() => None,
+        c => Some(c.to_string().bytes().map(|b| format!("\\x{:02x}", b)).collect()),
+    })
+}
+
+/// A Cedar string literal
+pub(crate) fn cedar_string(text: &str) -> String {
+    escaped_string(text, |c| needs_escape(c).then(|| format!("\\u{{{:x}}}", c as u32)))
+}
+
+/// An Elixir string literal: a JSON string with `#` escaped, so `#{...}`
+/// is not interpolated
+pub(crate) fn elixir_string(text: &str) -> String {
+    json_string(text).replace('#', "\\#")
+}
+
+/// `text` for a line, doc or block comment: line breaks and other control
+/// characters are escaped and `*/` is broken up, so the comment cannot end
+/// early
+pub(crate) fn comment(text: &str) -> Cow<'_, str> {
+    if text.chars().any(needs_escape) || text.contains("*/") {
+        let escape = |c: char| if needs_escape(c) { c.escape_default().to_string() } else { c.to_string() };
+        Cow::Owned(text.chars().map(escape).collect::<String>().replace("*/", "*\\/"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Control characters and the Unicode line and paragraph separators
+pub(crate) fn needs_escape(c: char) -> bool {
+    c.is_control() || matches!(c, '\u{2028}' | '\u{2029}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(operand("100"), Operand::Code("100"));
+        assert_eq!(operand("balance - fee"), Operand::Code("balance - fee"));
+        assert_eq!(operand("balance'"), Operand::Code("balance'"));
+        assert_eq!(operand("-(a.b + 2.5) % n"), Operand::Code("-(a.b + 2.5) % n"));
+        for text in ["admin or true", "1 // x", "0 --", "f(x)", "a'b", "x; y", "a.", "(a", "a +"] {
+            assert_eq!(operand(text), Operand::Text(text.into()), "{text}");
+        }
+        assert_eq!(operand(r#""say \"hi\"\n""#), Operand::Text("say \"hi\"\n".into()));
+        assert_eq!(operand(r#""a"); drop(""#), Operand::Text("a\"); drop(".into()));
+        assert_eq!(operand("x'; DROP TABLE t; --"), Operand::Text("x'; DROP TABLE t; --".into()));
+        assert_eq!(operand("#{System.halt()}"), Operand::Text("#{System.halt()}".into()));
+        let qualify = |name: &str| format!("params.{}", name);
+        assert_eq!(map_fields("-(a.b + 2.5) % n", qualify), "-(params.a.b + 2.5) % params.n");
+        assert_eq!(map_fields("balance' - fee2 * true", qualify), "params.balance' - params.fee2 * true");
+
+        let text = "\"); }\n\\ \u{2028}";
+        assert_eq!(json_string(text), "\"\\\"); }\\n\\\\ \u{2028}\"");
+        assert_eq!(rust_string(text), r#""\"); }\n\\ \u{2028}""#);
+        assert_eq!(elixir_string("#{x}"), r#""\#{x}""#);
+        assert_eq!(c_string("é??\0"), r#""\303\251\?\?\000""#);
+        assert_eq!(ada_string("say \"hi\"\n"), r#"("say ""hi""" & Character'Val (10))"#);
+        assert_eq!(ada_string(""), r#""""#);
+        assert_eq!(zig_string("\u{7}\u{2028}"), r#""\x07\u{2028}""#);
+        assert_eq!(solidity_string("é\""), r#""\xc3\xa9\"""#);
+        assert_eq!(cedar_string("\u{0}*"), r#""\u{0}*""#);
+        assert_eq!(comment("a\nb"), "a\\nb");
+        assert_eq!(comment("*/ x"), "*\\/ x");
+    }
+}
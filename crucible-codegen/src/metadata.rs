@@ -0,0 +1,133 @@
+//! What a generated artifact defines, for tools that link back to the spec
+//!
+//! Docs, IDE integrations and trace reports need the functions an output
+//! defines, the fields they read, each field's type in the target and the
+//! code generated for each constraint. `CodegenOutput::metadata` records all
+//! of it, so none of them has to parse the emitted code.
+
+use crate::{collect_variables, is_identifier, CodegenStrategy};
+use crucible_core::CompoundConstraint;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A function of the generated code, with the fields it reads
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratedFunction {
+    pub name: String,
+    /// Field identifiers in the target, sorted
+    pub parameters: Vec<String>,
+}
+
+impl GeneratedFunction {
+    /// A function reading the fields of `compound`
+    pub(crate) fn reading(name: &str, compound: &CompoundConstraint) -> Self {
+        let mut parameters: Vec<String> =
+            collect_variables(compound).into_iter().filter(|v| is_identifier(v)).collect();
+        parameters.sort();
+        parameters.dedup();
+        Self {
+            name: name.to_string(),
+            parameters,
+        }
+    }
+}
+
+/// Symbols and per-constraint code of one generated artifact
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodegenMetadata {
+    /// The validator first, then its monitor and traced wrappers and the
+    /// named predicate helpers, where the target has them
+    pub functions: Vec<GeneratedFunction>,
+    /// Each field's type in the target, keyed by its identifier there
+    pub field_types: BTreeMap<String, String>,
+    /// The code checking each simple constraint, indexed like
+    /// `CompoundConstraint::leaves`
+    pub expressions: Vec<String>,
+}
+
+impl CodegenMetadata {
+    /// The validator `func_name` reads every field of `field_types`; the
+    /// wrappers named in `wrappers` take the same parameters
+    pub(crate) fn new(
+        compound: &CompoundConstraint,
+        strategy: &dyn CodegenStrategy,
+        func_name: &str,
+        wrappers: Vec<String>,
+        helpers: Vec<GeneratedFunction>,
+        field_types: BTreeMap<String, String>,
+    ) -> Self {
+        let parameters: Vec<String> = field_types.keys().cloned().collect();
+        let mut functions: Vec<GeneratedFunction> = std::iter::once(func_name.to_string())
+            .chain(wrappers)
+            .map(|name| GeneratedFunction {
+                name,
+                parameters: parameters.clone(),
+            })
+            .collect();
+        functions.extend(helpers);
+        Self {
+            functions,
+            field_types,
+            expressions: compound.leaves().into_iter().map(|leaf| strategy.format_comparison(leaf)).collect(),
+        }
+    }
+
+    /// The generated function called `name`
+    pub fn function(&self, name: &str) -> Option<&GeneratedFunction> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeGenerator, CodegenOptions, TargetLanguage};
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator, DataType, Definitions, Schema};
+
+    #[test]
+    fn test_codegen_metadata() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "100"),
+            CompoundConstraint::Not(Box::new(simple("status", ConstraintOperator::Equal, "2"))),
+        ]);
+        let mut schema = Schema::new("TR-META".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, None);
+        schema.add_field("amount".to_string(), DataType::Uint32, None);
+        schema.add_field("status".to_string(), DataType::Int32, None);
+
+        let options = CodegenOptions { monitor: true, ..CodegenOptions::default() };
+        let rust = CodeGenerator
+            .generate_with_schema_and_options(&compound, &schema, TargetLanguage::Rust, &options)
+            .unwrap()
+            .metadata;
+        assert_eq!(rust.expressions, vec!["params.balance >= 100", "params.status == 2"]);
+        assert_eq!(
+            rust.field_types.iter().map(|(f, t)| format!("{}: {}", f, t)).collect::<Vec<_>>(),
+            vec!["amount: u32", "balance: u64", "status: i32"]
+        );
+        let names: Vec<&str> = rust.functions.iter().map(|function| function.name.as_str()).collect();
+        assert_eq!(names, vec!["validate_intent", "monitor_validate_intent"]);
+        assert_eq!(rust.function("validate_intent").unwrap().parameters, vec!["amount", "balance", "status"]);
+
+        // Without a Schema every field is inferred; helpers read their own fields
+        let mut definitions = Definitions::new();
+        definitions
+            .define("sufficient_funds", simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"))
+            .unwrap();
+        let named = CompoundConstraint::And(vec![
+            CompoundConstraint::Named("sufficient_funds".to_string()),
+            simple("status", ConstraintOperator::NotEqual, "2"),
+        ]);
+        let options = CodegenOptions { definitions: Some(definitions), ..CodegenOptions::default() };
+        let python = CodeGenerator.generate_with_options(&named, TargetLanguage::Python, &options).unwrap().metadata;
+        assert_eq!(python.function("sufficient_funds").unwrap().parameters, vec!["amount", "balance"]);
+        assert_eq!(python.field_types["status"], "int");
+        assert_eq!(python.expressions.len(), 2);
+    }
+}
@@ -0,0 +1,291 @@
+//! Runtime monitors: intent violations as production telemetry
+//!
+//! With `CodegenOptions::monitor`, Rust, TypeScript and Python validators are
+//! followed by a `monitor_<func>` wrapper. It evaluates each top-level
+//! constraint on its own against the live values, counts the violations of
+//! each, and reports every failure in the target's idiom:
+//!
+//! - Rust: `AtomicU64` counters and a `tracing::warn!` event, which
+//!   `tracing-opentelemetry` attaches to the current span
+//! - TypeScript: a JSON line on `console.warn` and an event on the active
+//!   `@opentelemetry/api` span
+//! - Python: a `logging` record with the details in `extra`, and a span event
+//!   when `opentelemetry` is installed
+//!
+//! The wrapper returns what the validator would, so it can stand in for it.
+
+use super::{
+    collect_variables, harness_name, is_identifier, literal, BareVariables, CodeGenerator, CodegenStrategy,
+    TargetLanguage,
+};
+use crucible_core::{CompoundConstraint, Verbalizer};
+use std::fmt::Write as _;
+
+/// One top-level constraint, as the monitor checks it
+pub(crate) struct Check {
+    /// Counter key and `constraint` attribute, e.g. `balance_ge_amount`
+    pub(crate) name: String,
+    /// Target string literal of the verbalized constraint
+    pub(crate) description: String,
+    /// The constraint over the bound field names
+    pub(crate) condition: String,
+}
+
+/// The monitor for `compound`, or `None` for targets without one.
+/// `params_type` is the parameter type of the TypeScript validator.
+pub(crate) fn monitor(
+    compound: &CompoundConstraint,
+    language: TargetLanguage,
+    strategy: &dyn CodegenStrategy,
+    func_name: &str,
+    params_type: &str,
+) -> Option<String> {
+    let checks = checks(compound, strategy);
+    let bindings = bindings(compound, language, strategy);
+    match language {
+        TargetLanguage::Rust => Some(rust(&checks, &bindings, func_name)),
+        TargetLanguage::TypeScript => Some(typescript(&checks, &bindings, func_name, params_type)),
+        TargetLanguage::Python => Some(python(&checks, &bindings, func_name)),
+        _ => None,
+    }
+}
+
+/// Statements binding each field the checks read to its bare name, each on
+/// a new line indented for a function body; empty for other targets
+pub(crate) fn bindings(
+    compound: &CompoundConstraint,
+    language: TargetLanguage,
+    strategy: &dyn CodegenStrategy,
+) -> String {
+    let mut fields: Vec<String> = collect_variables(compound).into_iter().filter(|v| is_identifier(v)).collect();
+    fields.sort();
+    fields.dedup();
+    if fields.is_empty() {
+        return String::new();
+    }
+    match language {
+        TargetLanguage::Rust => format!("\n    let ValidationParams {{ {}, .. }} = params.clone();", fields.join(", ")),
+        TargetLanguage::TypeScript => format!("\n  const {{ {} }} = params;", fields.join(", ")),
+        TargetLanguage::Python => {
+            fields.iter().map(|field| format!("\n    {} = {}", field, strategy.format_variable(field))).collect()
+        }
+        _ => String::new(),
+    }
+}
+
+/// The top-level conjuncts of `compound`, named like the Kani harnesses:
+/// `amount_gt_0` for a comparison, `constraint_2` for anything else
+pub(crate) fn conjuncts(compound: &CompoundConstraint) -> Vec<(String, &CompoundConstraint)> {
+    let parts: Vec<&CompoundConstraint> = match compound {
+        CompoundConstraint::And(parts) => parts.iter().collect(),
+        other => vec![other],
+    };
+    let mut named: Vec<(String, &CompoundConstraint)> = Vec::new();
+    for (index, conjunct) in parts.into_iter().enumerate() {
+        let mut name = match conjunct {
+            CompoundConstraint::Simple(c) => harness_name(c),
+            _ => format!("constraint_{}", index + 1),
+        };
+        if named.iter().any(|(other, _)| *other == name) {
+            name = format!("{}_{}", name, index + 1);
+        }
+        named.push((name, conjunct));
+    }
+    named
+}
+
+/// One check per top-level conjunct
+pub(crate) fn checks(compound: &CompoundConstraint, strategy: &dyn CodegenStrategy) -> Vec<Check> {
+    conjuncts(compound)
+        .into_iter()
+        .map(|(name, conjunct)| Check {
+            name,
+            description: strategy.format_string(&literal::comment(&Verbalizer::new().compound(conjunct))),
+            condition: CodeGenerator.build_expression(conjunct, &BareVariables(strategy)),
+        })
+        .collect()
+}
+
+fn rust(checks: &[Check], bindings: &str, func_name: &str) -> String {
+    let upper = func_name.to_ascii_uppercase();
+    let counters = vec!["AtomicU64::new(0)"; checks.len()].join(", ");
+    let mut table = String::new();
+    for check in checks {
+        let _ = writeln!(table, "    (\"{}\", {}),", check.name, check.description);
+    }
+    let results: Vec<&str> = checks.iter().map(|check| check.condition.as_str()).collect();
+    format!(
+        r#"use core::sync::atomic::{{AtomicU64, Ordering}};
+
+/// Violations of each of `{upper}_CHECKS`, since the process started
+pub static {upper}_VIOLATIONS: [AtomicU64; {count}] = [{counters}];
+
+/// Name and description of each constraint `monitor_{func_name}` checks
+pub const {upper}_CHECKS: [(&str, &str); {count}] = [
+{table}];
+
+/// `{func_name}` for production: every violated constraint is counted and
+/// reported as a `tracing` warning
+pub fn monitor_{func_name}(params: &ValidationParams) -> bool {{{bindings}
+    let results = [{results}];
+    let mut valid = true;
+    for (index, holds) in results.into_iter().enumerate() {{
+        if holds {{
+            continue;
+        }}
+        valid = false;
+        let (constraint, description) = {upper}_CHECKS[index];
+        let violations = {upper}_VIOLATIONS[index].fetch_add(1, Ordering::Relaxed) + 1;
+        tracing::warn!(
+            target: "crucible::intent",
+            validator = "{func_name}",
+            constraint,
+            description,
+            violations,
+            "intent violated"
+        );
+    }}
+    valid
+}}"#,
+        count = checks.len(),
+        results = results.join(", "),
+    )
+}
+
+fn typescript(checks: &[Check], bindings: &str, func_name: &str, params_type: &str) -> String {
+    let upper = func_name.to_ascii_uppercase();
+    let mut table = String::new();
+    for check in checks {
+        let _ = writeln!(table, "  {{ constraint: \"{}\", description: {} }},", check.name, check.description);
+    }
+    let counters: Vec<String> = checks.iter().map(|check| format!("{}: 0", check.name)).collect();
+    let results: Vec<&str> = checks.iter().map(|check| check.condition.as_str()).collect();
+    format!(
+        r#"import {{ trace }} from "@opentelemetry/api";
+
+/** Name and description of each constraint `monitor_{func_name}` checks */
+export const {upper}_CHECKS = [
+{table}] as const;
+
+/** Violations of each constraint since the process started, by name */
+export const {func_name}_violations: Record<string, number> = {{ {counters} }};
+
+/**
+ * `{func_name}` for production: every violated constraint is counted,
+ * logged as JSON and added to the active span as an event
+ */
+export function monitor_{func_name}(params: {params_type}): boolean {{{bindings}
+  const results = [{results}];
+  let valid = true;
+  results.forEach((holds, index) => {{
+    if (holds) {{
+      return;
+    }}
+    valid = false;
+    const {{ constraint, description }} = {upper}_CHECKS[index];
+    const violations = ++{func_name}_violations[constraint];
+    const event = {{ validator: "{func_name}", constraint, description, violations }};
+    console.warn(JSON.stringify({{ message: "intent violated", ...event }}));
+    trace.getActiveSpan()?.addEvent("intent.violated", event);
+  }});
+  return valid;
+}}"#,
+        counters = counters.join(", "),
+        results = results.join(", "),
+    )
+}
+
+fn python(checks: &[Check], bindings: &str, func_name: &str) -> String {
+    let upper = func_name.to_ascii_uppercase();
+    let mut table = String::new();
+    for check in checks {
+        let _ = writeln!(table, "    (\"{}\", {}),", check.name, check.description);
+    }
+    let results: Vec<&str> = checks.iter().map(|check| check.condition.as_str()).collect();
+    format!(
+        r#"import logging
+
+try:
+    from opentelemetry import trace as _trace
+except ImportError:  # telemetry is optional; the log record is always written
+    _trace = None
+
+_intent_log = logging.getLogger("crucible.intent")
+
+# Name and description of each constraint `monitor_{func_name}` checks
+{upper}_CHECKS = [
+{table}]
+
+# Violations of each constraint since the process started, by name
+{upper}_VIOLATIONS = {{name: 0 for name, _ in {upper}_CHECKS}}
+
+
+def monitor_{func_name}(params) -> bool:
+    """`{func_name}` for production: every violated constraint is counted,
+    logged and added to the current span as an event."""{bindings}
+    results = [{results}]
+    valid = True
+    for (constraint, description), holds in zip({upper}_CHECKS, results):
+        if holds:
+            continue
+        valid = False
+        {upper}_VIOLATIONS[constraint] += 1
+        event = {{
+            "validator": "{func_name}",
+            "constraint": constraint,
+            "description": description,
+            "violations": {upper}_VIOLATIONS[constraint],
+        }}
+        _intent_log.warning("intent violated: %s", constraint, extra=event)
+        if _trace is not None:
+            _trace.get_current_span().add_event("intent.violated", event)
+    return valid"#,
+        results = results.join(", "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeGenerator, CodegenOptions, TargetLanguage};
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_monitor() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            CompoundConstraint::Or(vec![
+                simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+                simple("tier", ConstraintOperator::Equal, "3"),
+            ]),
+        ]);
+        let options = CodegenOptions { monitor: true, ..CodegenOptions::default() };
+        let generate = |language| CodeGenerator.generate_with_options(&compound, language, &options).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("pub static VALIDATE_INTENT_VIOLATIONS: [AtomicU64; 2] = [AtomicU64::new(0), "));
+        assert!(rust.contains("    let ValidationParams { amount, balance, tier, .. } = params.clone();\n"));
+        assert!(rust.contains("    let results = [amount > 0, (balance >= amount || tier == 3)];\n"));
+        assert!(rust.contains("    (\"amount_gt_0\", \""));
+        assert!(rust.contains("    (\"constraint_2\", \""));
+
+        let typescript = generate(TargetLanguage::TypeScript);
+        assert!(typescript.contains("export function monitor_validate_intent(params: ValidationParams): boolean {"));
+        assert!(typescript.contains("validate_intent_violations: Record<string, number> = { amount_gt_0: 0, "));
+
+        let python = generate(TargetLanguage::Python);
+        assert!(python.contains("    amount = params['amount']\n"));
+        assert!(python.contains("def monitor_validate_intent(params) -> bool:"));
+
+        let plain = CodeGenerator.generate(&compound, TargetLanguage::Rust).unwrap().code;
+        assert!(!plain.contains("monitor_"));
+        let zig = CodeGenerator.generate_with_options(&compound, TargetLanguage::Zig, &options).unwrap().code;
+        assert!(!zig.contains("monitor_"));
+    }
+}
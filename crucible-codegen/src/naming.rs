@@ -0,0 +1,578 @@
+//! Field names in each target language
+//!
+//! Before a strategy renders anything, every field is renamed to its
+//! identifier in the target: the Schema's override for that language if it
+//! has one, otherwise the name in the language's case convention (the
+//! Schema's choice, or the default: Ada_Case for SPARK, the spec's spelling
+//! everywhere else) with reserved words escaped. `type` is `type_` in Rust,
+//! `end` is `end_` in Elixir and `End_Field` in SPARK. Names that break a
+//! language's identifier rules are respelled where there is an obvious fix
+//! (`user-id` is `user_id`, `line_2` is `line2` in WIT); anything else, and
+//! any override that is not a valid identifier, is an
+//! `InvalidIdentifier` error. Two fields that end up with the same identifier
+//! are an error rather than broken code.
+
+use super::{CodegenError, TargetLanguage};
+use crucible_core::{CompoundConstraint, Conditional, Constraint, FieldNaming, NamingConvention, Schema};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+const RUST: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "fn", "for",
+    "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "type", "unsafe", "use", "where", "while", "abstract", "become", "box",
+    "do", "final", "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+const TYPESCRIPT: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete", "do", "else",
+    "enum", "export", "extends", "false", "finally", "for", "function", "if", "implements", "import", "in",
+    "instanceof", "interface", "let", "new", "null", "package", "private", "protected", "public", "return", "static",
+    "super", "switch", "this", "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+const PYTHON: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+    "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "nonlocal",
+    "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+const SOLIDITY: &[&str] = &[
+    "abstract", "address", "after", "alias", "anonymous", "apply", "assembly", "auto", "bool", "break", "byte",
+    "bytes", "calldata", "case", "catch", "constant", "constructor", "continue", "contract", "copyof", "days",
+    "default", "define", "delete", "do", "else", "emit", "enum", "ether", "event", "external", "fallback", "final",
+    "for", "function", "gwei", "hours", "if", "immutable", "implements", "import", "in", "indexed", "inline", "int",
+    "interface", "internal", "is", "let", "library", "macro", "mapping", "match", "memory", "minutes", "modifier",
+    "mutable", "new", "null", "of", "override", "partial", "payable", "pragma", "private", "promise", "public",
+    "pure", "receive", "reference", "relocatable", "return", "returns", "sealed", "seconds", "sizeof", "static",
+    "storage", "string", "struct", "supports", "switch", "try", "type", "typedef", "typeof", "uint", "unchecked",
+    "using", "view", "virtual", "weeks", "wei", "while", "years",
+];
+
+const ADA: &[&str] = &[
+    "abort", "abs", "abstract", "accept", "access", "aliased", "all", "and", "array", "at", "begin", "body", "case",
+    "constant", "declare", "delay", "delta", "digits", "do", "else", "elsif", "end", "entry", "exception", "exit",
+    "for", "function", "generic", "goto", "if", "in", "interface", "is", "limited", "loop", "mod", "new", "not",
+    "null", "of", "or", "others", "out", "overriding", "package", "parallel", "pragma", "private", "procedure",
+    "protected", "raise", "range", "record", "rem", "renames", "requeue", "return", "reverse", "select", "separate",
+    "some", "subtype", "synchronized", "tagged", "task", "terminate", "then", "type", "until", "use", "when",
+    "while", "with", "xor",
+];
+
+const ZIG: &[&str] = &[
+    "addrspace", "align", "allowzero", "and", "anyframe", "anytype", "asm", "async", "await", "break", "callconv",
+    "catch", "comptime", "const", "continue", "defer", "else", "enum", "errdefer", "error", "export", "extern", "fn",
+    "for", "if", "inline", "linksection", "noalias", "noinline", "nosuspend", "opaque", "or", "orelse", "packed",
+    "pub", "resume", "return", "struct", "suspend", "switch", "test", "threadlocal", "try", "union", "unreachable",
+    "usingnamespace", "var", "volatile", "while",
+];
+
+const ELIXIR: &[&str] = &[
+    "after", "and", "catch", "do", "else", "end", "false", "fn", "in", "nil", "not", "or", "rescue", "true", "when",
+];
+
+const SQL: &[&str] = &[
+    "all", "and", "any", "as", "asc", "between", "by", "case", "check", "column", "constraint", "create", "default",
+    "delete", "desc", "distinct", "drop", "else", "end", "from", "group", "having", "in", "index", "insert", "is",
+    "join", "key", "like", "limit", "not", "null", "offset", "on", "or", "order", "primary", "references", "select",
+    "table", "then", "union", "unique", "update", "user", "values", "when", "where",
+];
+
+const REGO: &[&str] = &[
+    "as", "contains", "default", "else", "every", "false", "if", "import", "in", "not", "null", "package", "some",
+    "true", "with",
+];
+
+const CUE: &[&str] = &["false", "for", "if", "import", "in", "let", "null", "package", "true"];
+
+const CEDAR: &[&str] = &["else", "false", "has", "if", "in", "is", "like", "then", "true"];
+
+const WIT: &[&str] = &[
+    "as", "bool", "borrow", "char", "constructor", "enum", "export", "f32", "f64", "flags", "from", "func", "import",
+    "include", "interface", "list", "option", "own", "package", "record", "resource", "result", "s16", "s32", "s64",
+    "s8", "static", "string", "tuple", "type", "u16", "u32", "u64", "u8", "use", "variant", "with", "world",
+];
+
+const C: &[&str] = &[
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else", "enum", "extern",
+    "float", "for", "goto", "if", "inline", "int", "long", "register", "restrict", "return", "short", "signed",
+    "sizeof", "static", "struct", "switch", "typedef", "union", "unsigned", "void", "volatile", "while",
+];
+
+impl TargetLanguage {
+    /// Case convention for field names when the Schema does not choose one
+    pub fn default_naming_convention(&self) -> NamingConvention {
+        match self {
+            TargetLanguage::SparkAda => NamingConvention::AdaCase,
+            _ => NamingConvention::Preserve,
+        }
+    }
+
+    /// Words that cannot name a field in generated code
+    pub fn reserved_words(&self) -> &'static [&'static str] {
+        match self {
+            TargetLanguage::Rust => RUST,
+            TargetLanguage::TypeScript => TYPESCRIPT,
+            TargetLanguage::Python => PYTHON,
+            TargetLanguage::Solidity => SOLIDITY,
+            TargetLanguage::SparkAda => ADA,
+            TargetLanguage::Zig => ZIG,
+            TargetLanguage::Elixir => ELIXIR,
+            TargetLanguage::Sql(_) => SQL,
+            TargetLanguage::Rego => REGO,
+            TargetLanguage::Cue => CUE,
+            TargetLanguage::Cedar => CEDAR,
+            TargetLanguage::Wit => WIT,
+            TargetLanguage::EbpfC => C,
+        }
+    }
+
+    /// Ada and SQL identifiers ignore case
+    fn case_insensitive(&self) -> bool {
+        matches!(self, TargetLanguage::SparkAda | TargetLanguage::Sql(_))
+    }
+
+    /// Whether `name` is a reserved word
+    pub fn is_reserved(&self, name: &str) -> bool {
+        let words = self.reserved_words();
+        if self.case_insensitive() {
+            words.iter().any(|word| word.eq_ignore_ascii_case(name))
+        } else {
+            words.contains(&name)
+        }
+    }
+
+    /// Check that `name` can name a field, function or type in this language
+    pub fn check_identifier(&self, name: &str) -> Result<(), CodegenError> {
+        match self.identifier_error(name) {
+            Some(reason) => Err(CodegenError::InvalidIdentifier {
+                language: format!("{:?}", self),
+                name: name.to_string(),
+                reason: reason.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Why `name` breaks this language's identifier rules, if it does
+    fn identifier_error(&self, name: &str) -> Option<&'static str> {
+        let mut chars = name.chars();
+        let well_formed = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !well_formed {
+            return Some("identifiers are ASCII letters, digits and `_`, not starting with a digit");
+        }
+        if name.chars().all(|c| c == '_') {
+            return Some("`_` alone is not a name");
+        }
+        if self.is_reserved(name) {
+            return Some("it is a reserved word");
+        }
+        let capital = |word: &str| word.starts_with(|c: char| c.is_ascii_uppercase());
+        let reserved_in_c = name.strip_prefix('_').is_some_and(|rest| rest.starts_with('_') || capital(rest));
+        match self {
+            TargetLanguage::SparkAda | TargetLanguage::Wit
+                if name.starts_with('_') || name.ends_with('_') || name.contains("__") =>
+            {
+                Some("`_` may only separate words")
+            }
+            TargetLanguage::Wit if name.split('_').any(|word| word.starts_with(|c: char| c.is_ascii_digit())) => {
+                Some("every word must start with a letter")
+            }
+            TargetLanguage::Elixir if capital(name) => Some("a capitalized name is a module alias"),
+            TargetLanguage::EbpfC if reserved_in_c => {
+                Some("names starting with `__` or `_` and a capital letter are reserved")
+            }
+            TargetLanguage::Cue if name.starts_with('_') => Some("fields starting with `_` are hidden"),
+            TargetLanguage::Sql(_) if name.len() > 63 => Some("identifiers are at most 63 characters"),
+            _ => None,
+        }
+    }
+
+    /// `name` respelled to follow the identifier rules where there is an
+    /// obvious way to; reserved words are escaped separately
+    fn sanitize(&self, name: &str) -> String {
+        let name = name.replace(['-', ' '], "_");
+        match self {
+            TargetLanguage::SparkAda | TargetLanguage::Wit => {
+                let mut out = String::new();
+                for word in name.split('_').filter(|word| !word.is_empty()) {
+                    // WIT words start with a letter, so `line_2` is `line2`
+                    let joined = *self == TargetLanguage::Wit && word.starts_with(|c: char| c.is_ascii_digit());
+                    if !out.is_empty() && !joined {
+                        out.push('_');
+                    }
+                    out.push_str(word);
+                }
+                out
+            }
+            TargetLanguage::Elixir => {
+                let mut chars = name.chars();
+                chars.next().map(|c| c.to_ascii_lowercase()).into_iter().chain(chars).collect()
+            }
+            TargetLanguage::EbpfC | TargetLanguage::Cue => name.trim_start_matches('_').to_string(),
+            _ => name,
+        }
+    }
+
+    /// Whether a language key of a `FieldNaming` (`ts`, `sql`) names this language
+    fn is_named(&self, key: &str) -> bool {
+        match (TargetLanguage::from_name(key), self) {
+            // The naming rules are the same for every dialect
+            (Some(TargetLanguage::Sql(_)), TargetLanguage::Sql(_)) => true,
+            (language, _) => language == Some(*self),
+        }
+    }
+}
+
+/// Field name -> identifier in one target language, for the fields whose
+/// identifier differs from their name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldNames {
+    names: BTreeMap<String, String>,
+}
+
+impl FieldNames {
+    /// Identifiers of `fields` in `language` under `naming`
+    pub fn resolve<'a>(
+        language: TargetLanguage,
+        naming: &FieldNaming,
+        fields: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, CodegenError> {
+        let convention = naming
+            .conventions
+            .iter()
+            .find(|(key, _)| language.is_named(key))
+            .map_or(language.default_naming_convention(), |(_, convention)| *convention);
+        let overrides: BTreeMap<&str, &str> = naming
+            .overrides
+            .iter()
+            .filter(|(key, _)| language.is_named(key))
+            .flat_map(|(_, names)| names.iter().map(|(field, name)| (field.as_str(), name.as_str())))
+            .collect();
+
+        let mut fields: Vec<&str> = fields.into_iter().collect();
+        fields.sort_unstable();
+        fields.dedup();
+        let mut names = BTreeMap::new();
+        // Identifier (lowercased where case does not count) -> the field that has it
+        let mut taken: BTreeMap<String, &str> = BTreeMap::new();
+        for field in fields {
+            let name = match overrides.get(field) {
+                Some(name) => name.to_string(),
+                None => {
+                    let name = language.sanitize(&convention.apply(field));
+                    match language {
+                        _ if !language.is_reserved(&name) => name,
+                        // Neither allows a trailing underscore (WIT's becomes a dash)
+                        TargetLanguage::SparkAda => format!("{}_Field", name),
+                        TargetLanguage::Wit => format!("{}_field", name),
+                        _ => format!("{}_", name),
+                    }
+                }
+            };
+            language.check_identifier(&name)?;
+            let key = if language.case_insensitive() { name.to_lowercase() } else { name.clone() };
+            if let Some(other) = taken.insert(key, field) {
+                return Err(CodegenError::FieldNameCollision {
+                    language: format!("{:?}", language),
+                    fields: (other.to_string(), field.to_string()),
+                    name,
+                });
+            }
+            if name != field {
+                names.insert(field.to_string(), name);
+            }
+        }
+        Ok(Self { names })
+    }
+
+    /// Identifiers of every field the constraint trees or the Schema mention
+    pub fn for_compounds<'a>(
+        language: TargetLanguage,
+        compounds: impl IntoIterator<Item = &'a CompoundConstraint>,
+        schema: Option<&'a Schema>,
+    ) -> Result<Self, CodegenError> {
+        let mut fields = Vec::new();
+        for compound in compounds {
+            compound_fields(compound, &mut fields);
+        }
+        let default = FieldNaming::default();
+        let naming = schema.map_or(&default, |schema| &schema.naming);
+        let declared = schema.into_iter().flat_map(|schema| schema.fields.keys().map(String::as_str));
+        Self::resolve(language, naming, fields.into_iter().chain(declared))
+    }
+
+    /// The identifier of `field`
+    pub fn get<'a>(&'a self, field: &'a str) -> &'a str {
+        self.names.get(field).map_or(field, String::as_str)
+    }
+
+    /// The field whose identifier is `identifier`
+    pub fn field<'a>(&'a self, identifier: &'a str) -> &'a str {
+        self.names.iter().find(|(_, name)| *name == identifier).map_or(identifier, |(field, _)| field)
+    }
+
+    /// `compound` with every field under its identifier
+    pub fn rename<'a>(&self, compound: Cow<'a, CompoundConstraint>) -> Cow<'a, CompoundConstraint> {
+        if self.names.is_empty() {
+            return compound;
+        }
+        Cow::Owned(self.rename_compound(&compound))
+    }
+
+    fn rename_compound(&self, compound: &CompoundConstraint) -> CompoundConstraint {
+        let all = |parts: &[CompoundConstraint]| parts.iter().map(|part| self.rename_compound(part)).collect();
+        match compound {
+            CompoundConstraint::Simple(c) => CompoundConstraint::Simple(Constraint {
+                left_variable: self.rename_expression(&c.left_variable),
+                operator: c.operator,
+                right_value: self.rename_expression(&c.right_value),
+            }),
+            CompoundConstraint::And(parts) => CompoundConstraint::And(all(parts)),
+            CompoundConstraint::Or(parts) => CompoundConstraint::Or(all(parts)),
+            CompoundConstraint::ExactlyOne(parts) => CompoundConstraint::ExactlyOne(all(parts)),
+            CompoundConstraint::AtMostOne(parts) => CompoundConstraint::AtMostOne(all(parts)),
+            CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(self.rename_compound(inner))),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                CompoundConstraint::implies(self.rename_compound(antecedent), self.rename_compound(consequent))
+            }
+            CompoundConstraint::Distinct(fields) => {
+                CompoundConstraint::Distinct(fields.iter().map(|field| self.get(field).to_string()).collect())
+            }
+            CompoundConstraint::Named(name) => CompoundConstraint::Named(name.clone()),
+        }
+    }
+
+    /// `schema` with its fields and their documentation under their identifiers
+    pub fn rename_schema<'a>(&self, schema: &'a Schema) -> Cow<'a, Schema> {
+        if self.names.is_empty() {
+            return Cow::Borrowed(schema);
+        }
+        let mut renamed = schema.clone();
+        renamed.fields = schema.fields.iter().map(|(f, dt)| (self.get(f).to_string(), dt.clone())).collect();
+        renamed.documentation =
+            schema.documentation.iter().map(|(f, doc)| (self.get(f).to_string(), doc.clone())).collect();
+        Cow::Owned(renamed)
+    }
+
+    fn rename_expression(&self, expression: &str) -> String {
+        let mut out = String::with_capacity(expression.len());
+        for_each_identifier(expression, |token, is_field| {
+            out.push_str(if is_field { self.get(token) } else { token });
+        });
+        out
+    }
+}
+
+/// Every field named in the operands of `compound`
+fn compound_fields<'a>(compound: &'a CompoundConstraint, out: &mut Vec<&'a str>) {
+    match compound {
+        CompoundConstraint::Simple(c) => {
+            for operand in [&c.left_variable, &c.right_value] {
+                for_each_identifier(operand, |token, is_field| {
+                    if is_field {
+                        out.push(token);
+                    }
+                });
+            }
+        }
+        CompoundConstraint::And(parts)
+        | CompoundConstraint::Or(parts)
+        | CompoundConstraint::ExactlyOne(parts)
+        | CompoundConstraint::AtMostOne(parts) => parts.iter().for_each(|part| compound_fields(part, out)),
+        CompoundConstraint::Not(inner) => compound_fields(inner, out),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            compound_fields(antecedent, out);
+            compound_fields(consequent, out);
+        }
+        CompoundConstraint::Distinct(fields) => out.extend(fields.iter().map(String::as_str)),
+        CompoundConstraint::Named(_) => {}
+    }
+}
+
+/// Split an operand (`balance`, `id % 10`, `"ok"`, `count'`, `max(a, b)`,
+/// `0 if member else 5`) into pieces, flagging the field names: identifiers
+/// outside string literals that are neither boolean literals, members after
+/// a `.`, called, nor the keywords of a conditional
+fn for_each_identifier<'a>(operand: &'a str, mut visit: impl FnMut(&'a str, bool)) {
+    let conditional = Conditional::parse(operand).is_some();
+    let bytes = operand.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i];
+        if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            let token = &operand[start..i];
+            let member = operand[..start].ends_with('.');
+            let called = operand[i..].starts_with('(');
+            let keyword = conditional && matches!(token, "if" | "else");
+            visit(token, !member && !called && !keyword && !matches!(token, "true" | "false"));
+            continue;
+        }
+        if c.is_ascii_digit() {
+            // `1e5` and `0x1F` are numbers, not a number and a field
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_' || bytes[i] == b'.') {
+                i += 1;
+            }
+        } else if c == b'"' || (c == b'\'' && !operand[..start].ends_with(|p: char| p.is_alphanumeric() || p == '_')) {
+            // A quote after an identifier is a prime (`count'`), not a string
+            i += 1;
+            while i < bytes.len() && bytes[i] != c {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(bytes.len());
+        } else {
+            i += operand[i..].chars().next().map_or(1, char::len_utf8);
+        }
+        visit(&operand[start..i], false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, CodegenOptions};
+    use crucible_core::{ConstraintOperator, DataType, StateTransition};
+
+    fn constraint(left: &str, op: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator: op,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_field_names_per_language() {
+        let compound = CompoundConstraint::And(vec![
+            constraint("type", ConstraintOperator::Equal, "\"end\""),
+            constraint("end % 7", ConstraintOperator::Equal, "0"),
+            constraint("user_id", ConstraintOperator::LessThan, "match"),
+            constraint("abs(end)", ConstraintOperator::LessThan, "max(match, 3)"),
+        ]);
+        let names = |language| FieldNames::for_compounds(language, [&compound], None).unwrap();
+
+        let rust = names(TargetLanguage::Rust);
+        assert_eq!((rust.get("type"), rust.get("match"), rust.get("end")), ("type_", "match_", "end"));
+        let renamed = rust.rename(Cow::Borrowed(&compound));
+        assert_eq!(
+            *renamed,
+            CompoundConstraint::And(vec![
+                constraint("type_", ConstraintOperator::Equal, "\"end\""),
+                constraint("end % 7", ConstraintOperator::Equal, "0"),
+                constraint("user_id", ConstraintOperator::LessThan, "match_"),
+                constraint("abs(end)", ConstraintOperator::LessThan, "max(match_, 3)"),
+            ])
+        );
+        assert_eq!(names(TargetLanguage::Elixir).get("end"), "end_");
+        assert_eq!(names(TargetLanguage::SparkAda).get("end"), "End_Field");
+        assert_eq!(names(TargetLanguage::SparkAda).get("user_id"), "User_Id");
+        // Function names are not fields, even where `abs` is reserved
+        assert_eq!(names(TargetLanguage::SparkAda).get("abs"), "abs");
+        // Nor are the keywords of a conditional
+        let conditional = constraint("end", ConstraintOperator::Equal, "0 if match else 1");
+        let rust = FieldNames::for_compounds(TargetLanguage::Rust, [&conditional], None).unwrap();
+        let renamed = rust.rename(Cow::Borrowed(&conditional));
+        assert_eq!(*renamed, constraint("end", ConstraintOperator::Equal, "0 if match_ else 1"));
+        assert_eq!(names(TargetLanguage::TypeScript), FieldNames::default());
+
+        // The Schema's convention and overrides, matched by any name of the language
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("user_id".to_string(), DataType::Uint64, Some("Account owner".to_string()));
+        schema.add_field("match".to_string(), DataType::Uint64, None);
+        schema.add_field("end".to_string(), DataType::Int64, None);
+        schema.add_field("type".to_string(), DataType::String, None);
+        schema.set_naming_convention("ts", NamingConvention::CamelCase);
+        schema.rename_field("python", "type", "kind");
+        let typescript = FieldNames::for_compounds(TargetLanguage::TypeScript, [&compound], Some(&schema)).unwrap();
+        assert_eq!(typescript.get("user_id"), "userId");
+        assert_eq!(typescript.rename_schema(&schema).documentation["userId"], "Account owner");
+        let python = FieldNames::for_compounds(TargetLanguage::Python, [&compound], Some(&schema)).unwrap();
+        assert_eq!(python.get("type"), "kind");
+
+        // Every part of the generated code uses the escaped names
+        let rust = CodeGenerator.generate_with_schema(&compound, &schema, TargetLanguage::Rust).unwrap().code;
+        assert!(rust.contains(
+            "(params.type_ == \"end\" && params.end.rem_euclid(7) == 0 && params.user_id < params.match_ && "
+        ));
+        assert!(rust.contains("let ValidationParams { end, match_, type_, user_id, .. } = params.clone();"));
+        let spark = CodeGenerator.generate_with_schema(&compound, &schema, TargetLanguage::SparkAda).unwrap().code;
+        assert!(spark.contains("(Params.Type_Field = \"end\" and then "));
+        assert!(spark.contains("      User_Id : Natural;"));
+
+        // `userId` and `user_id` are one identifier in snake_case
+        let clash = constraint("userId", ConstraintOperator::Equal, "user_id");
+        schema.set_naming_convention("rust", NamingConvention::SnakeCase);
+        let error = FieldNames::for_compounds(TargetLanguage::Rust, [&clash], Some(&schema)).unwrap_err();
+        assert!(error.to_string().contains("`userId` and `user_id` both become `user_id`"), "{}", error);
+    }
+
+    #[test]
+    fn test_identifier_rules() {
+        let fields = |language, names: &[&str]| {
+            let names = FieldNames::resolve(language, &FieldNaming::default(), names.iter().copied()).unwrap();
+            move |field: &'static str| names.get(field).to_string()
+        };
+        assert_eq!(fields(TargetLanguage::Python, &["user-id"])("user-id"), "user_id");
+        assert_eq!(fields(TargetLanguage::Wit, &["line_2"])("line_2"), "line2");
+        assert_eq!(fields(TargetLanguage::Elixir, &["Amount"])("Amount"), "amount");
+        assert_eq!(fields(TargetLanguage::Cue, &["_secret"])("_secret"), "secret");
+        assert_eq!(fields(TargetLanguage::SparkAda, &["__max__retries_"])("__max__retries_"), "Max_Retries");
+        assert_eq!(fields(TargetLanguage::EbpfC, &["return"])("return"), "return_");
+
+        // Function names and overrides are the caller's; they are checked, not respelled
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+        let generate = |func_name: &str, language| {
+            let options = CodegenOptions { func_name: func_name.to_string(), ..CodegenOptions::default() };
+            CodeGenerator.generate_with_options(&compound, language, &options)
+        };
+        let error = generate("return", TargetLanguage::Rust).unwrap_err();
+        assert_eq!(error.to_string(), "`return` is not a valid identifier in Rust: it is a reserved word");
+        assert!(matches!(
+            generate("validate-intent", TargetLanguage::Python),
+            Err(CodegenError::InvalidIdentifier { name, .. }) if name == "validate-intent"
+        ));
+        assert!(generate("Validate", TargetLanguage::Elixir).is_err());
+        assert!(generate("validate", TargetLanguage::Elixir).is_ok());
+
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("amount".to_string(), DataType::Uint64, None);
+        schema.rename_field("rust", "amount", "2fast");
+        let invalid = CodeGenerator.generate_with_schema(&compound, &schema, TargetLanguage::Rust);
+        assert!(matches!(invalid, Err(CodegenError::InvalidIdentifier { name, .. }) if name == "2fast"));
+    }
+
+    #[test]
+    fn test_elixir_keys_keep_the_field_names() {
+        let compound = constraint("end", ConstraintOperator::GreaterThan, "start");
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("end".to_string(), DataType::Int64, None);
+        schema.add_field("start".to_string(), DataType::Int64, None);
+        schema.add_field("user-id".to_string(), DataType::Uint64, None);
+
+        // Only the bound variables are renamed; the params map is keyed by the spec's names
+        let output = CodeGenerator.generate_with_schema(&compound, &schema, TargetLanguage::Elixir).unwrap();
+        assert!(output.code.contains("def validate_intent?(%{end: end_, start: start})"), "{}", output.code);
+        assert!(output.code.contains("@type params :: %{end: integer(), start: integer(), \"user-id\": integer()}"));
+        assert!(output.code.contains("(params[:end] > params[:start])"));
+        assert!(!output.code.contains("end_:"));
+        assert!(output.files[0].contents.contains("Validator.validate_intent?(%{end: end_, start: start})"));
+        let output = CodeGenerator.generate(&compound, TargetLanguage::Elixir).unwrap();
+        assert!(output.code.contains("def validate_intent?(%{end: end_, start: start})"), "{}", output.code);
+
+        let post = constraint("end'", ConstraintOperator::Equal, "end + 1");
+        let transition = StateTransition::new("close", compound, post);
+        let options = CodegenOptions::default();
+        let update = CodeGenerator.generate_transition(&transition, TargetLanguage::Elixir, &options).unwrap().code;
+        assert!(update.contains("if params[:end] > params[:start] do"), "{}", update);
+        assert!(update.contains("%{params | end: params[:end] + 1}"), "{}", update);
+    }
+}
@@ -0,0 +1,97 @@
+//! The integer types each target computes arithmetic in
+//!
+//! `Z3Verifier::overflow_risks` asks whether `amount + fee` can leave the
+//! type the target adds in: the field's own type in Rust, Zig, WIT and C,
+//! `uint256` for a `Uint64` in Solidity, `Natural` in SPARK and a double in
+//! TypeScript (exact up to 2^53). Python, Elixir, Rego and CUE integers are
+//! unbounded. The risks come back in `CodegenOptions::overflow_risks`, and
+//! Rust renders each risky term with a checked operation and TypeScript with
+//! `BigInt`s; Solidity, Zig, SPARK, SQL and Cedar already fail on overflow.
+
+use crate::{inferred_fields, CodegenOptions, SqlDialect, TargetLanguage};
+use crucible_core::{BitWidth, CompoundConstraint, DataType, Schema};
+use std::collections::HashMap;
+
+impl TargetLanguage {
+    /// The type a field of `data_type` computes in; `None` where its
+    /// arithmetic is unbounded or the type is not an integer
+    pub fn integer_width(&self, data_type: &DataType, options: &CodegenOptions) -> Option<BitWidth> {
+        let width = BitWidth::of(data_type)?;
+        let wide = width.bits == 64;
+        let (bits, signed) = match self {
+            TargetLanguage::Rust | TargetLanguage::Zig | TargetLanguage::Wit | TargetLanguage::EbpfC => {
+                return Some(width)
+            }
+            TargetLanguage::Solidity if wide => (256, width.signed),
+            TargetLanguage::Solidity => (32, width.signed),
+            // `Natural` and `Integer`, 32-bit in GNAT
+            TargetLanguage::SparkAda if width.signed => (32, true),
+            TargetLanguage::SparkAda => (31, false),
+            TargetLanguage::TypeScript if options.bigint && wide => return None,
+            TargetLanguage::TypeScript => (54, true),
+            TargetLanguage::Cedar => (64, true),
+            TargetLanguage::Sql(SqlDialect::Postgres) if wide && !width.signed => return None,
+            // A `Uint32` column is a `BIGINT`
+            TargetLanguage::Sql(SqlDialect::Postgres) if !width.signed => (64, true),
+            TargetLanguage::Sql(SqlDialect::Postgres | SqlDialect::MySql) => return Some(width),
+            TargetLanguage::Sql(SqlDialect::Sqlite) => (64, true),
+            TargetLanguage::Python | TargetLanguage::Elixir | TargetLanguage::Rego | TargetLanguage::Cue => {
+                return None
+            }
+        };
+        Some(BitWidth { bits, signed })
+    }
+
+    /// `integer_width` of every field of `compound`, typed by `schema` and
+    /// `Int64` without one, for `Z3Verifier::overflow_risks`
+    pub fn integer_widths(
+        &self,
+        compound: &CompoundConstraint,
+        schema: Option<&Schema>,
+        options: &CodegenOptions,
+    ) -> HashMap<String, BitWidth> {
+        let mut fields: HashMap<String, DataType> = inferred_fields(compound).into_iter().collect();
+        if let Some(schema) = schema {
+            fields.extend(schema.fields.iter().map(|(name, data_type)| (name.clone(), data_type.clone())));
+        }
+        fields
+            .into_iter()
+            .filter_map(|(name, data_type)| Some((name, self.integer_width(&data_type, options)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_integer_widths() {
+        let options = CodegenOptions::default();
+        let width = |language: TargetLanguage, data_type: DataType| language.integer_width(&data_type, &options);
+        let bits = |bits, signed| Some(BitWidth { bits, signed });
+        assert_eq!(width(TargetLanguage::Rust, DataType::Uint32), bits(32, false));
+        assert_eq!(width(TargetLanguage::Solidity, DataType::Uint64), bits(256, false));
+        assert_eq!(width(TargetLanguage::SparkAda, DataType::Uint64), bits(31, false));
+        assert_eq!(width(TargetLanguage::TypeScript, DataType::Int64), bits(54, true));
+        assert_eq!(width(TargetLanguage::Sql(SqlDialect::Postgres), DataType::Uint64), None);
+        assert_eq!(width(TargetLanguage::Python, DataType::Int64), None);
+        assert_eq!(width(TargetLanguage::Rust, DataType::Decimal), None);
+        let bigint = CodegenOptions { bigint: true, ..CodegenOptions::default() };
+        assert_eq!(TargetLanguage::TypeScript.integer_width(&DataType::Int64, &bigint), None);
+
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "total".to_string(),
+            operator: ConstraintOperator::Equal,
+            right_value: "amount + fee".to_string(),
+        });
+        let mut schema = Schema::new("TR-OVERFLOW".to_string());
+        schema.add_field("amount".to_string(), DataType::Uint32, None);
+        let widths = TargetLanguage::Rust.integer_widths(&compound, Some(&schema), &options);
+        assert_eq!(
+            (widths["amount"], widths["fee"], widths["total"]),
+            (bits(32, false).unwrap(), BitWidth::DEFAULT, BitWidth::DEFAULT)
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! Provenance of generated artifacts, for SBOM and reproducible-build tooling
+//!
+//! Every `CodegenOutput` records what it was generated from (a SHA-256 of the
+//! constraints, schema, target and options), the solver that verified the
+//! input, the version of this crate and when it ran. The same facts open the
+//! artifact as comment lines, after anything the target requires first
+//! (`#![no_std]`, an SPDX line):
+//!
+//! ```text
+//! // Generated by crucible-codegen 0.1.0
+//! // Input hash: sha256:5d41402abc4b2a76b9719d911017c592...
+//! // Solver: Z3 4.12.2.0
+//! // Generated at: 2026-10-18T09:12:44Z
+//! ```
+//!
+//! With `CodegenOptions::reproducible` the timestamp is left out, so the same
+//! input and toolchain give byte-identical output.
+//!
+//! Above them each target prints a banner naming the engine, its license and
+//! patent application. `CodegenOptions::header` swaps it for an SPDX line, a
+//! project template or nothing, the same way for every target and on every
+//! companion file; the traceability ID the banner carried stays in
+//! `Provenance::traceability_id`.
+
+use crate::{literal, CodegenOptions, GeneratedFile, TargetLanguage};
+use chrono::{DateTime, SecondsFormat, Utc};
+use crucible_core::{HeaderPolicy, Schema};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Where an artifact came from
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// Hex SHA-256 of the generator's input
+    #[serde(default)]
+    pub input_hash: String,
+    /// Solver that verified the constraints, as given in `CodegenOptions`
+    #[serde(default)]
+    pub solver_version: Option<String>,
+    /// Version of `crucible-codegen`
+    #[serde(default)]
+    pub crate_version: String,
+    /// `None` for reproducible output
+    #[serde(default)]
+    pub generated_at: Option<DateTime<Utc>>,
+    /// The Schema's traceability ID, when generated from one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traceability_id: Option<String>,
+    /// Banner `stamp` writes; a setting, not part of the record
+    #[serde(skip)]
+    header: HeaderPolicy,
+}
+
+impl Provenance {
+    /// Provenance of generating from `input` (a constraint tree, a state
+    /// machine) and `schema`, if any, for `language`
+    pub fn new(
+        input: &impl Serialize,
+        schema: Option<&Schema>,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+    ) -> Self {
+        Self {
+            input_hash: input_hash(input, schema, language, options),
+            solver_version: options.solver_version.clone(),
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at: (!options.reproducible).then(Utc::now),
+            traceability_id: schema.map(|schema| schema.traceability_id.clone()),
+            header: options.header.clone(),
+        }
+    }
+
+    /// The provenance as line comments of `language`, ending in a blank line
+    pub fn header(&self, language: TargetLanguage) -> String {
+        let prefix = comment_prefix(language);
+        let mut lines = vec![
+            format!("{}{}", GENERATED_BY, self.crate_version),
+            format!("Input hash: sha256:{}", self.input_hash),
+        ];
+        if let Some(solver) = &self.solver_version {
+            lines.push(format!("Solver: {}", solver));
+        }
+        if let Some(at) = self.generated_at {
+            lines.push(format!("Generated at: {}", at.to_rfc3339_opts(SecondsFormat::Secs, true)));
+        }
+        let mut header: String = lines.iter().map(|line| format!("{} {}\n", prefix, line)).collect();
+        header.push('\n');
+        header
+    }
+
+    /// `code` with the header inserted after its leading crate attributes,
+    /// shebang and SPDX lines, which have to stay on top, under the banner
+    /// `CodegenOptions::header` asks for
+    pub(crate) fn stamp(&self, language: TargetLanguage, code: &str) -> String {
+        let code = &self.licensed(comment_prefix(language), code);
+        let mut split = 0;
+        for line in code.split_inclusive('\n') {
+            let pinned = line.starts_with("#!") || line.contains(SPDX);
+            if !pinned {
+                break;
+            }
+            split += line.len();
+        }
+        format!("{}{}{}", &code[..split], self.header(language), &code[split..])
+    }
+
+    /// The banner a target opens its Schema-typed artifact with, as `prefix`
+    /// comment lines ending in a blank line: its `title`, the patent
+    /// application and the traceability ID. Empty under any other
+    /// `CodegenOptions::header`, whose banner `stamp` adds
+    pub(crate) fn banner(&self, prefix: &str, title: &[&str]) -> String {
+        if !self.header.is_default() {
+            return String::new();
+        }
+        let traceability_id = self.traceability_id.as_deref().map(literal::comment);
+        let mut lines: Vec<String> = title.iter().map(|line| line.to_string()).collect();
+        lines.push(PATENT.to_string());
+        lines.extend(traceability_id.map(|id| format!("Traceability ID: {}", id)));
+        lines.push("Correct by Design, Verified by Construction".to_string());
+        let mut banner: String = lines.iter().map(|line| format!("{} {}\n", prefix, line)).collect();
+        banner.push('\n');
+        banner
+    }
+}
+
+impl Provenance {
+    /// Companion files under the banner of `CodegenOptions::header`, in the
+    /// comment syntax of each
+    pub(crate) fn license_files(&self, files: &mut [GeneratedFile]) {
+        for file in files.iter_mut() {
+            file.contents = self.licensed(file_comment_prefix(&file.path), &file.contents);
+        }
+    }
+
+    /// `code` under the banner `CodegenOptions::header` asks for: the comment
+    /// lines it opens with, after any crate attributes or shebang and up to
+    /// the provenance lines, replaced by the chosen SPDX line or template.
+    /// Doc comments belong to the item below them and stay. By default `code`
+    /// keeps its own lines
+    fn licensed(&self, prefix: &str, code: &str) -> String {
+        let lines = match &self.header {
+            HeaderPolicy::Default => return code.to_string(),
+            HeaderPolicy::None => Vec::new(),
+            HeaderPolicy::Spdx(id) => vec![format!("{}{}", SPDX, literal::comment(id))],
+            HeaderPolicy::Template(template) => {
+                let traceability_id = literal::comment(self.traceability_id.as_deref().unwrap_or_default());
+                let template = template.replace("{traceability_id}", &traceability_id);
+                template.lines().map(|line| literal::comment(line).into_owned()).collect()
+            }
+        };
+        let (mut pinned, mut end) = (0, 0);
+        for line in code.split_inclusive('\n') {
+            let trimmed = line.trim();
+            let doc = trimmed.starts_with("///") || trimmed.starts_with("/**");
+            if end == pinned && trimmed.starts_with("#!") {
+                pinned += line.len();
+            } else if !(trimmed.is_empty() || trimmed.starts_with(prefix) && !doc) || trimmed.contains(GENERATED_BY) {
+                break;
+            }
+            end += line.len();
+        }
+        let mut banner: String = lines
+            .iter()
+            .map(|line| format!("{} {}", prefix, line).trim_end().to_string() + "\n")
+            .collect();
+        // SPDX lines move above the provenance lines, which end in a blank line
+        if !matches!(self.header, HeaderPolicy::Spdx(_)) && !banner.is_empty() {
+            banner.push('\n');
+        }
+        // A file split from a stamped artifact carries the banner already
+        if code[end..].contains(banner.trim_end()) {
+            banner.clear();
+        }
+        format!("{}{}{}", &code[..pinned], banner, &code[end..])
+    }
+}
+
+/// SHA-256 of the key-sorted JSON of everything that shapes the output. The
+/// provenance options themselves are left out: they change the header, not
+/// what was generated
+fn input_hash(
+    input: &impl Serialize,
+    schema: Option<&Schema>,
+    language: TargetLanguage,
+    options: &CodegenOptions,
+) -> String {
+    let options = CodegenOptions {
+        solver_version: None,
+        reproducible: false,
+        ..options.clone()
+    };
+    let input = serde_json::json!({
+        "input": input,
+        "schema": schema,
+        "language": language,
+        "options": options,
+    });
+    // `Value` objects keep their keys sorted, so schema maps hash the same every run
+    hex::encode(Sha256::digest(input.to_string().as_bytes()))
+}
+
+/// Opens an SPDX license line
+const SPDX: &str = "SPDX-License-Identifier: ";
+
+/// Opens the provenance lines
+const GENERATED_BY: &str = "Generated by crucible-codegen ";
+
+/// The default banner's patent line
+const PATENT: &str = "Patent Application: 63/928,407";
+
+/// Line-comment syntax of the main artifact
+pub(crate) fn comment_prefix(language: TargetLanguage) -> &'static str {
+    match language {
+        TargetLanguage::Python | TargetLanguage::Elixir | TargetLanguage::Rego => "#",
+        TargetLanguage::SparkAda | TargetLanguage::Sql(_) => "--",
+        TargetLanguage::Rust
+        | TargetLanguage::TypeScript
+        | TargetLanguage::Solidity
+        | TargetLanguage::Zig
+        | TargetLanguage::Cue
+        | TargetLanguage::Cedar
+        | TargetLanguage::Wit
+        | TargetLanguage::EbpfC => "//",
+    }
+}
+
+/// Line-comment syntax of a companion file
+fn file_comment_prefix(path: &str) -> &'static str {
+    match path.rsplit_once('.').map(|(_, extension)| extension) {
+        Some("ads" | "adb" | "gpr" | "sql") => "--",
+        Some("exs" | "ex" | "rego" | "toml" | "py") => "#",
+        _ => "//",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeGenerator, CodegenOptions, TargetLanguage};
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator, HeaderPolicy, Schema};
+
+    fn limit(value: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::LessThanOrEqual,
+            right_value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_reproducible_output_is_identical() {
+        let options = CodegenOptions {
+            solver_version: Some("Z3 4.12.2.0".to_string()),
+            reproducible: true,
+            no_std: true,
+            ..CodegenOptions::default()
+        };
+        let first = CodeGenerator.generate_with_options(&limit("100"), TargetLanguage::Rust, &options).unwrap();
+        let second = CodeGenerator.generate_with_options(&limit("100"), TargetLanguage::Rust, &options).unwrap();
+        assert_eq!(first.code, second.code);
+        assert_eq!(first.provenance, second.provenance);
+        assert!(first.provenance.generated_at.is_none());
+        assert!(first.code.starts_with(&format!(
+            "#![no_std]\n// Generated by crucible-codegen {}\n// Input hash: sha256:{}\n// Solver: Z3 4.12.2.0\n\n",
+            env!("CARGO_PKG_VERSION"),
+            first.provenance.input_hash
+        )));
+
+        let other = CodeGenerator.generate_with_options(&limit("200"), TargetLanguage::Rust, &options).unwrap();
+        assert_ne!(other.provenance.input_hash, first.provenance.input_hash);
+        // The provenance options do not change the input hash
+        let timestamped = CodegenOptions {
+            no_std: true,
+            ..CodegenOptions::default()
+        };
+        let stamped = CodeGenerator.generate_with_options(&limit("100"), TargetLanguage::Rust, &timestamped).unwrap();
+        assert_eq!(stamped.provenance.input_hash, first.provenance.input_hash);
+        assert!(stamped.provenance.generated_at.is_some());
+        assert!(stamped.code.contains("\n// Generated at: "));
+
+        let sql = TargetLanguage::Sql(crate::SqlDialect::Postgres);
+        let sql = CodeGenerator.generate_with_options(&limit("100"), sql, &options).unwrap();
+        assert!(sql.code.contains("-- Input hash: sha256:"));
+    }
+
+    #[test]
+    fn test_header_policy() {
+        let generate = |language, header| {
+            let options = CodegenOptions { header, reproducible: true, ..CodegenOptions::default() };
+            let mut schema = Schema::new("TR-42".to_string());
+            schema.add_field("amount".to_string(), crucible_core::DataType::Uint32, None);
+            CodeGenerator.generate_with_schema_and_options(&limit("100"), &schema, language, &options).unwrap()
+        };
+        let default = generate(TargetLanguage::Rust, HeaderPolicy::Default);
+        assert!(default.code.contains("Patent Application"));
+        assert_eq!(default.provenance.traceability_id.as_deref(), Some("TR-42"));
+
+        // The SPDX line stays on top; Solidity keeps its pragma without the banner
+        let spdx = generate(TargetLanguage::Solidity, HeaderPolicy::Spdx("Apache-2.0".to_string()));
+        let tests = spdx.files.iter().find(|file| file.path.ends_with(".t.sol")).unwrap();
+        assert!(tests.contents.starts_with("// SPDX-License-Identifier: Apache-2.0\n"));
+        assert!(spdx.code.starts_with("// SPDX-License-Identifier: Apache-2.0\n// Generated by crucible-codegen"));
+        assert!(!spdx.code.contains("Patent") && !spdx.code.contains("MIT"));
+        assert!(spdx.code.contains("\n\npragma solidity"));
+        let none = generate(TargetLanguage::Python, HeaderPolicy::None);
+        assert!(!none.code.contains("Patent") && !none.code.contains("Python Generated Code (v"));
+        assert_eq!(none.provenance.traceability_id.as_deref(), Some("TR-42"));
+
+        let template = HeaderPolicy::Template("Copyright Example Corp\nTrace: {traceability_id}".to_string());
+        let custom = generate(TargetLanguage::SparkAda, template);
+        assert!(custom.code.contains("\n\n-- Copyright Example Corp\n-- Trace: TR-42\n"));
+        assert!(!custom.code.contains("Patent"));
+        let options = CodegenOptions { header: HeaderPolicy::None, no_std: true, ..CodegenOptions::default() };
+        let rust = CodeGenerator.generate_with_options(&limit("100"), TargetLanguage::Rust, &options).unwrap();
+        assert!(rust.code.starts_with("#![no_std]\n// Generated by crucible-codegen"));
+        assert!(!rust.code.contains("//! Rust Generated Code"));
+    }
+
+    #[test]
+    fn test_header_policy_covers_every_file() {
+        let mut schema = Schema::new("TR-42\n*/".to_string());
+        schema.add_field("amount".to_string(), crucible_core::DataType::Uint32, None);
+        let generate = |language, header, typed: bool| {
+            let options = CodegenOptions { header, reproducible: true, ..CodegenOptions::default() };
+            let output = match typed {
+                true => CodeGenerator.generate_with_schema_and_options(&limit("100"), &schema, language, &options),
+                false => CodeGenerator.generate_with_options(&limit("100"), language, &options),
+            };
+            let output = output.unwrap();
+            let files = output.files.into_iter().map(|file| (file.path, file.contents));
+            std::iter::once(("main".to_string(), output.code)).chain(files).collect::<Vec<_>>()
+        };
+        let languages = [
+            TargetLanguage::Rust,
+            TargetLanguage::TypeScript,
+            TargetLanguage::Python,
+            TargetLanguage::Solidity,
+            TargetLanguage::SparkAda,
+            TargetLanguage::Zig,
+            TargetLanguage::Elixir,
+            TargetLanguage::Sql(crate::SqlDialect::Postgres),
+            TargetLanguage::Rego,
+            TargetLanguage::Cue,
+            TargetLanguage::Cedar,
+            TargetLanguage::Wit,
+            TargetLanguage::EbpfC,
+        ];
+        for language in languages {
+            assert!(generate(language, HeaderPolicy::Default, true)[0].1.contains("Patent Application"));
+            for (path, contents) in generate(language, HeaderPolicy::None, true) {
+                let banner = contents.contains("Patent") || contents.contains("SPDX");
+                assert!(!banner, "{:?} {}:\n{}", language, path, contents);
+            }
+        }
+
+        // The SPARK spec, body and project all carry the template, once
+        let template = HeaderPolicy::Template("Copyright Example Corp\nTrace: {traceability_id}".to_string());
+        for (path, contents) in generate(TargetLanguage::SparkAda, template.clone(), true) {
+            assert_eq!(contents.matches("-- Copyright Example Corp\n-- Trace: TR-42\\n*\\/\n").count(), 1, "{}", path);
+        }
+        for (path, contents) in generate(TargetLanguage::SparkAda, template, false) {
+            assert!(!contents.contains("Formally Verifiable"), "{}:\n{}", path, contents);
+            assert_eq!(contents.matches("-- Copyright Example Corp\n-- Trace:\n").count(), 1, "{}", path);
+        }
+        let spdx = HeaderPolicy::Spdx("Apache-2.0".to_string());
+        for (path, contents) in generate(TargetLanguage::SparkAda, spdx, false) {
+            let spdx = if path == "main" { "-- SPDX-License-Identifier: Apache-2.0\n-- Generated" } else { "-- SPDX" };
+            assert!(contents.starts_with(spdx), "{}:\n{}", path, contents);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Generated code inside hand-written files
+//!
+//! Overwriting whole files makes incremental adoption painful: a validator
+//! cannot share a file with the code around it. A `ProtectedFile` keeps each
+//! piece of generated code between markers in the target's line comments
+//!
+//! ```text
+//! // <crucible:begin requirement_5d41402abc4b2a76 sha256=9f86d081884c7d65>
+//! pub struct Validator;
+//! // <crucible:end>
+//! ```
+//!
+//! and leaves every line outside them as it is. The begin marker records a
+//! checksum of the code written, so a region edited since is only
+//! overwritten when forced, and writing the same code again changes nothing.
+
+use crate::provenance::comment_prefix;
+use crate::TargetLanguage;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Markers that do not pair up, or a region that may not be overwritten
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RegionError {
+    #[error("region `{0}` was edited since it was generated")]
+    Modified(String),
+
+    #[error("line {line}: region `{id}` has no end marker")]
+    Unterminated { id: String, line: usize },
+
+    #[error("line {line}: end marker outside any region")]
+    UnmatchedEnd { line: usize },
+
+    #[error("line {line}: region `{id}` begins inside region `{outer}`")]
+    Nested { id: String, outer: String, line: usize },
+
+    #[error("line {line}: region `{id}` appears twice")]
+    Duplicate { id: String, line: usize },
+}
+
+/// Generated code between a pair of markers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: String,
+    /// Checksum of the code as generated; none in a region marked by hand
+    pub checksum: Option<String>,
+    /// The lines between the markers
+    pub code: String,
+}
+
+impl Region {
+    /// Whether the code differs from what was generated into it; a region
+    /// marked by hand counts as edited unless it is empty
+    pub fn is_modified(&self) -> bool {
+        match &self.checksum {
+            Some(checksum) => *checksum != checksum_of(&self.code),
+            None => !self.code.trim().is_empty(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Region(Region),
+}
+
+/// A source file as hand-written text and generated regions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtectedFile {
+    prefix: &'static str,
+    segments: Vec<Segment>,
+}
+
+enum Marker {
+    Begin { id: String, checksum: Option<String> },
+    End,
+}
+
+/// The marker on `line`, if it is one
+fn marker(line: &str, prefix: &str) -> Option<Marker> {
+    let tag = line.trim().strip_prefix(prefix)?.trim().strip_prefix("<crucible:")?.strip_suffix('>')?;
+    if tag == "end" {
+        return Some(Marker::End);
+    }
+    let mut words = tag.strip_prefix("begin ")?.split_whitespace();
+    let id = words.next()?.to_string();
+    let checksum = words.next().and_then(|word| word.strip_prefix("sha256=")).map(str::to_string);
+    Some(Marker::Begin { id, checksum })
+}
+
+fn checksum_of(code: &str) -> String {
+    hex::encode(&Sha256::digest(code.as_bytes())[..8])
+}
+
+impl ProtectedFile {
+    /// Split `text`, a `language` source file, at its region markers
+    pub fn parse(text: &str, language: TargetLanguage) -> Result<Self, RegionError> {
+        let prefix = comment_prefix(language);
+        let mut file = Self { prefix, segments: Vec::new() };
+        let mut plain = String::new();
+        // The region being read, with the line it began on
+        let mut open: Option<(Region, usize)> = None;
+        for (index, line) in text.split_inclusive('\n').enumerate() {
+            let line_number = index + 1;
+            match (marker(line, prefix), &mut open) {
+                (Some(Marker::Begin { id, .. }), Some((outer, _))) => {
+                    return Err(RegionError::Nested { id, outer: outer.id.clone(), line: line_number });
+                }
+                (Some(Marker::Begin { id, checksum }), None) => {
+                    if file.region(&id).is_some() {
+                        return Err(RegionError::Duplicate { id, line: line_number });
+                    }
+                    file.push_text(std::mem::take(&mut plain));
+                    open = Some((Region { id, checksum, code: String::new() }, line_number));
+                }
+                (Some(Marker::End), Some(_)) => {
+                    file.segments.extend(open.take().map(|(region, _)| Segment::Region(region)));
+                }
+                (Some(Marker::End), None) => return Err(RegionError::UnmatchedEnd { line: line_number }),
+                (None, Some((region, _))) => region.code.push_str(line),
+                (None, None) => plain.push_str(line),
+            }
+        }
+        if let Some((region, line)) = open {
+            return Err(RegionError::Unterminated { id: region.id, line });
+        }
+        file.push_text(plain);
+        Ok(file)
+    }
+
+    fn push_text(&mut self, text: String) {
+        if !text.is_empty() {
+            self.segments.push(Segment::Text(text));
+        }
+    }
+
+    /// The regions, in file order
+    pub fn regions(&self) -> impl Iterator<Item = &Region> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Region(region) => Some(region),
+            Segment::Text(_) => None,
+        })
+    }
+
+    pub fn region(&self, id: &str) -> Option<&Region> {
+        self.regions().find(|region| region.id == id)
+    }
+
+    /// Whether everything outside the regions is blank
+    pub fn is_generated_only(&self) -> bool {
+        self.segments.iter().all(|segment| match segment {
+            Segment::Text(text) => text.trim().is_empty(),
+            Segment::Region(_) => true,
+        })
+    }
+
+    /// Put `code` into region `id`, appending the region when the file has
+    /// none; `Ok(false)` when it already holds exactly that code. A region
+    /// edited since it was generated is only overwritten with `force`
+    pub fn update(&mut self, id: &str, code: &str, force: bool) -> Result<bool, RegionError> {
+        let mut code = code.to_string();
+        if !code.ends_with('\n') {
+            code.push('\n');
+        }
+        let checksum = checksum_of(&code);
+        let Some(region) = self.segments.iter_mut().find_map(|segment| match segment {
+            Segment::Region(region) if region.id == id => Some(region),
+            _ => None,
+        }) else {
+            // Separated from the text before by a blank line
+            let separator = match self.segments.last() {
+                None => "",
+                Some(Segment::Text(text)) if text.ends_with("\n\n") => "",
+                Some(Segment::Text(text)) if !text.ends_with('\n') => "\n\n",
+                Some(_) => "\n",
+            };
+            match self.segments.last_mut() {
+                Some(Segment::Text(text)) => text.push_str(separator),
+                _ => self.push_text(separator.to_string()),
+            }
+            self.segments.push(Segment::Region(Region { id: id.to_string(), checksum: Some(checksum), code }));
+            return Ok(true);
+        };
+        if region.code == code && region.checksum.as_ref() == Some(&checksum) {
+            return Ok(false);
+        }
+        if region.is_modified() && !force {
+            return Err(RegionError::Modified(id.to_string()));
+        }
+        region.code = code;
+        region.checksum = Some(checksum);
+        Ok(true)
+    }
+
+    /// Drop region `id` with its markers; `Ok(false)` when there is none.
+    /// A region edited since it was generated is only dropped with `force`
+    pub fn remove(&mut self, id: &str, force: bool) -> Result<bool, RegionError> {
+        let index = self.segments.iter().position(|segment| match segment {
+            Segment::Region(region) => region.id == id,
+            Segment::Text(_) => false,
+        });
+        let Some(index) = index else {
+            return Ok(false);
+        };
+        if let Segment::Region(region) = &self.segments[index] {
+            if region.is_modified() && !force {
+                return Err(RegionError::Modified(id.to_string()));
+            }
+        }
+        self.segments.remove(index);
+        // Take back the blank line `update` put before an appended region
+        if index == self.segments.len() {
+            if let Some(Segment::Text(text)) = self.segments.last_mut() {
+                if text.ends_with("\n\n") {
+                    text.pop();
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl std::fmt::Display for ProtectedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => f.write_str(text)?,
+                Segment::Region(region) => {
+                    write!(f, "{} <crucible:begin {}", self.prefix, region.id)?;
+                    if let Some(checksum) = &region.checksum {
+                        write!(f, " sha256={}", checksum)?;
+                    }
+                    write!(f, ">\n{}{} <crucible:end>\n", region.code, self.prefix)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protected_regions() {
+        let hand_written = "use crate::Account;\n\nfn helper() {}\n";
+        let mut file = ProtectedFile::parse(hand_written, TargetLanguage::Rust).unwrap();
+        assert!(file.update("requirement_1", "fn validate() {}", false).unwrap());
+        let written = file.to_string();
+        let mut removed = file.clone();
+        assert!(removed.remove("requirement_1", false).unwrap());
+        assert_eq!(removed.to_string(), hand_written);
+        let region = written.strip_prefix(hand_written).unwrap();
+        assert!(region.starts_with("\n// <crucible:begin requirement_1 sha256="));
+        assert!(region.ends_with(">\nfn validate() {}\n// <crucible:end>\n"));
+
+        // Regenerating the same code is a no-op; new code replaces the region only
+        let mut file = ProtectedFile::parse(&written, TargetLanguage::Rust).unwrap();
+        assert!(!file.update("requirement_1", "fn validate() {}\n", false).unwrap());
+        assert_eq!(file.to_string(), written);
+        assert!(file.update("requirement_1", "fn validate() -> bool { true }", false).unwrap());
+        assert!(file.to_string().starts_with(hand_written));
+        assert!(!file.is_generated_only());
+
+        // Edits inside a region need `force`
+        let edited = written.replace("fn validate() {}", "fn validate() { todo!() }");
+        let mut file = ProtectedFile::parse(&edited, TargetLanguage::Rust).unwrap();
+        assert!(file.region("requirement_1").unwrap().is_modified());
+        assert_eq!(
+            file.update("requirement_1", "fn validate() {}", false),
+            Err(RegionError::Modified("requirement_1".to_string()))
+        );
+        assert_eq!(file.remove("requirement_1", false), Err(RegionError::Modified("requirement_1".to_string())));
+        assert!(file.update("requirement_1", "fn validate() {}", true).unwrap());
+        assert!(file.remove("requirement_1", false).unwrap());
+        assert_eq!(file.to_string(), hand_written);
+
+        // A region marked by hand is filled when empty; markers use the target's comments
+        let marked = "x = 1\n# <crucible:begin check>\n# <crucible:end>\n";
+        let mut file = ProtectedFile::parse(marked, TargetLanguage::Python).unwrap();
+        assert!(file.update("check", "def check(): pass", false).unwrap());
+        assert!(file.to_string().starts_with("x = 1\n# <crucible:begin check sha256="));
+
+        assert_eq!(
+            ProtectedFile::parse("// <crucible:begin a>\n", TargetLanguage::Rust),
+            Err(RegionError::Unterminated { id: "a".to_string(), line: 1 })
+        );
+        assert_eq!(
+            ProtectedFile::parse("\n// <crucible:end>\n", TargetLanguage::Rust),
+            Err(RegionError::UnmatchedEnd { line: 2 })
+        );
+    }
+}
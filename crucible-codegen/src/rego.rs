@@ -0,0 +1,412 @@
+//! Rego Strategy (Open Policy Agent)
+//!
+//! Rego rule bodies are implicit conjunctions and disjunction is expressed by
+//! defining the same rule several times, so the constraint tree is compiled
+//! into one helper rule per node instead of an inline boolean expression.
+
+use super::gherkin::{example_bindings, example_constraints};
+use super::literal::{self, Operand};
+use super::{default_safe_compare, CodegenOptions, CodegenStrategy, GeneratedFile, Provenance, VerifiableStrategy};
+use super::bitwise::{wrapped, BitWidths};
+use crucible_core::{
+    ArithmeticOperator, Bitwise, BitwiseOperator, Call, CompoundConstraint, ConstraintOperator, DataType, MathFunction,
+    Schema,
+};
+
+/// Package used when `CodegenOptions::package_name` is not set
+const DEFAULT_PACKAGE: &str = "crucible";
+
+pub(crate) struct RegoStrategy {
+    package: String,
+    /// Field widths `bits.lsh` wraps to
+    widths: BitWidths,
+}
+
+impl RegoStrategy {
+    pub(crate) fn new(options: &CodegenOptions) -> Self {
+        Self {
+            package: options
+                .package_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PACKAGE.to_string()),
+            widths: BitWidths::default(),
+        }
+    }
+
+    /// Wraps shifts to the widths of `schema`'s fields
+    pub(crate) fn with_schema(mut self, schema: &Schema) -> Self {
+        self.widths = BitWidths::new(schema);
+        self
+    }
+
+    /// Emit the rules for `compound` and return the name of its root rule
+    fn compile_rules(&self, compound: &CompoundConstraint, rules: &mut Vec<String>) -> String {
+        let name = format!("intent_{}", rules.len());
+        rules.push(String::new());
+        let index = rules.len() - 1;
+
+        let rule = match compound {
+            CompoundConstraint::Simple(c) if c.operator.is_membership() => {
+                format!("{} if {{\n    {}\n}}", name, self.format_membership_comparison(c))
+            }
+            CompoundConstraint::Simple(c) => match c.conditional() {
+                Some(conditional) => {
+                    format!("{} if {{\n    {}\n}}", name, self.compile_rules(&conditional.cases(c), rules))
+                }
+                None => format!(
+                    "{} if {{\n    {} {} {}\n}}",
+                    name,
+                    self.format_left_operand(c),
+                    self.format_operator(&c.operator),
+                    self.format_right_operand(&c.right_value)
+                ),
+            },
+            CompoundConstraint::And(constraints) => {
+                let body: Vec<String> = constraints
+                    .iter()
+                    .map(|c| format!("    {}", self.compile_rules(c, rules)))
+                    .collect();
+                if body.is_empty() {
+                    format!("{} := true", name)
+                } else {
+                    format!("{} if {{\n{}\n}}", name, body.join("\n"))
+                }
+            }
+            CompoundConstraint::Or(constraints) => {
+                // Each definition of the same rule is an alternative
+                let alternatives: Vec<String> = constraints
+                    .iter()
+                    .map(|c| format!("{} if {{\n    {}\n}}", name, self.compile_rules(c, rules)))
+                    .collect();
+                if alternatives.is_empty() {
+                    format!("{} := false", name)
+                } else {
+                    alternatives.join("\n\n")
+                }
+            }
+            CompoundConstraint::Not(inner) => {
+                format!("{} if {{\n    not {}\n}}", name, self.compile_rules(inner, rules))
+            }
+            // Definitions are inlined before rule compilation; a leftover
+            // reference names a rule defined elsewhere in the package
+            CompoundConstraint::Named(predicate) => format!("{} if {{\n    {}\n}}", name, predicate),
+            CompoundConstraint::ExactlyOne(_) | CompoundConstraint::AtMostOne(_) => {
+                let encoded = match compound.pairwise_encoding() {
+                    Some(encoding) => self.compile_rules(&encoding, rules),
+                    None => unreachable!("cardinality nodes have a pairwise encoding"),
+                };
+                format!("{} if {{\n    {}\n}}", name, encoded)
+            }
+            CompoundConstraint::Distinct(fields) => {
+                let values: Vec<String> = fields.iter().map(|field| self.format_variable(field)).collect();
+                format!("{} if {{\n    {}\n}}", name, self.format_distinct(&values).unwrap_or_default())
+            }
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                // Holds when the antecedent fails or the consequent holds
+                let antecedent = self.compile_rules(antecedent, rules);
+                let consequent = self.compile_rules(consequent, rules);
+                format!(
+                    "{name} if {{\n    not {}\n}}\n\n{name} if {{\n    {}\n}}",
+                    antecedent,
+                    consequent,
+                    name = name
+                )
+            }
+        };
+
+        rules[index] = rule;
+        name
+    }
+}
+
+impl CodegenStrategy for RegoStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        match op {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "==",
+            ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "contains",
+            ConstraintOperator::DoesNotContain => "contains",
+        }
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        format!("input.{}", name)
+    }
+
+    /// Rego's `%` truncates toward zero; shifted into `[0, |divisor|)`
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("((({} % {d}) + abs({d})) % abs({d}))", dividend, d = divisor)
+    }
+
+    /// The `bits` built-ins over arbitrary-precision integers, `bits.lsh`
+    /// wrapped to the field's width; numbers are JSON, so masks are decimal
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        let function = match bitwise.operator {
+            BitwiseOperator::And => "and",
+            BitwiseOperator::Or => "or",
+            BitwiseOperator::Xor => "xor",
+            BitwiseOperator::ShiftLeft => "lsh",
+            BitwiseOperator::ShiftRight => "rsh",
+        };
+        let mask = bitwise.mask_value().map_or_else(|| mask.to_string(), |value| value.to_string());
+        let bits = format!("bits.{}({}, {})", function, operand, mask);
+        match bitwise.operator {
+            BitwiseOperator::ShiftLeft => {
+                wrapped(&bits, self.widths.of(&bitwise.operand), |a, b| format!("bits.and({}, {})", a, b))
+            }
+            _ => bits,
+        }
+    }
+
+    /// `min`/`max` take a collection; numbers are arbitrary-precision
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        match function {
+            MathFunction::Abs => format!("abs({})", args.join(", ")),
+            _ => format!("{}([{}])", function.name(), args.join(", ")),
+        }
+    }
+
+    /// A set literal drops repeats; list fields are rejected before generation
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        match values {
+            [_] => None,
+            _ => Some(format!("count({{{}}}) == {}", values.join(", "), values.len())),
+        }
+    }
+
+    /// Rego has no conditional expression; `compile_rules` gives each case a
+    /// rule body of its own
+    fn format_conditional(&self, _condition: &str, _then_value: &str, _else_value: &str) -> Option<String> {
+        None
+    }
+
+    // Only used for the `# Intent:` summary line; rules are built by `compile_rules`
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        format!("contains({}, {})", haystack, needle)
+    }
+
+    /// Right-hand values: numbers and booleans stay literal, text becomes a
+    /// string and a field reference reads another input field
+    fn format_right_operand(&self, value: &str) -> String {
+        if let Some(call) = Call::parse(value) {
+            return self.format_call_expression(&call);
+        }
+        match literal::operand(value) {
+            Operand::Text(text) => self.format_string(&text),
+            Operand::Code(code) if is_field(code) => self.format_variable(code),
+            Operand::Code(code) => code.to_string(),
+        }
+    }
+
+    fn logical_and(&self) -> &'static str {
+        "and"
+    }
+
+    fn logical_or(&self) -> &'static str {
+        "or"
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        format!("not {}", expr)
+    }
+
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let mut rules = Vec::new();
+        self.compile_rules(compound, &mut rules);
+        Some(rules.join("\n\n"))
+    }
+
+    fn wrap_verified_function(
+        &self,
+        func_name: &str,
+        contracts: &str,
+        body: &str,
+        _assertions: &str,
+    ) -> String {
+        let rules = if contracts.is_empty() {
+            String::new()
+        } else {
+            format!("\n\n{}", contracts)
+        };
+
+        format!(
+            r#"# Rego Generated Code - Open Policy Agent Policy
+# Intent: {body}
+
+package {package}
+
+import rego.v1
+
+default {func_name} := false
+
+{func_name} if {{
+    intent_0
+}}{rules}
+"#,
+            body = body,
+            package = self.package,
+            func_name = func_name,
+            rules = rules
+        )
+    }
+
+    fn companion_files(&self, compound: &CompoundConstraint, func_name: &str) -> Vec<GeneratedFile> {
+        let bindings = example_bindings(&example_constraints(compound));
+        let fields: Vec<String> = bindings
+            .iter()
+            .map(|(name, value)| format!("\"{}\": {}", name, json_value(value)))
+            .collect();
+
+        let contents = format!(
+            r#"# Rego Generated Tests - run with `opa test .`
+
+package {package}_test
+
+import rego.v1
+
+test_{func_name}_accepts_example if {{
+    data.{package}.{func_name} with input as {{{fields}}}
+}}
+
+test_{func_name}_rejects_empty_input if {{
+    not data.{package}.{func_name} with input as {{}}
+}}
+"#,
+            package = self.package,
+            func_name = func_name,
+            fields = fields.join(", ")
+        );
+
+        vec![GeneratedFile {
+            path: format!("{}_test.rego", func_name),
+            contents,
+        }]
+    }
+}
+
+fn json_value(value: &str) -> String {
+    match literal::operand(value) {
+        Operand::Code(code) if !is_field(code) => code.to_string(),
+        Operand::Code(text) => literal::json_string(text),
+        Operand::Text(text) => literal::json_string(&text),
+    }
+}
+
+/// A bare name other than `true`/`false`
+fn is_field(value: &str) -> bool {
+    value.parse::<f64>().is_err() && value != "true" && value != "false"
+}
+
+impl VerifiableStrategy for RegoStrategy {
+    fn map_type(&self, dt: &DataType) -> String {
+        match dt {
+            DataType::Uint64 | DataType::Uint32 | DataType::Int64 | DataType::Int32 => "number".to_string(),
+            DataType::Decimal => "number".to_string(),
+            DataType::String => "string".to_string(),
+            DataType::Bool => "boolean".to_string(),
+            DataType::List(_) => "array".to_string(),
+            DataType::Custom { name, .. } => name.clone(),
+        }
+    }
+
+    fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
+        format!("# Post-condition: Policy allows iff ({})", expression)
+    }
+
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        // Rego numbers are arbitrary precision, so arithmetic cannot overflow
+        format!("{} {} {}", left, op.symbol(), right)
+    }
+
+    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        let fields: Vec<String> = names
+            .iter()
+            .map(|name| format!("#   {}: {}", name, self.map_type(&schema.fields[*name])))
+            .collect();
+
+        format!("# Input document for {}:\n{}", func_name, fields.join("\n"))
+    }
+
+    fn fn_end(&self) -> String {
+        "}".to_string()
+    }
+
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner("#", &["Rego Generated Code - Open Policy Agent Policy (v0.1.5-alpha)"])
+    }
+
+    fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
+        default_safe_compare(left, op, right, data_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, TargetLanguage};
+    use crucible_core::Constraint;
+
+    fn role_check() -> CompoundConstraint {
+        CompoundConstraint::And(vec![
+            CompoundConstraint::Or(vec![
+                CompoundConstraint::Simple(Constraint {
+                    left_variable: "role".to_string(),
+                    operator: ConstraintOperator::Equal,
+                    right_value: "\"admin\"".to_string(),
+                }),
+                CompoundConstraint::Simple(Constraint {
+                    left_variable: "role".to_string(),
+                    operator: ConstraintOperator::Equal,
+                    right_value: "\"moderator\"".to_string(),
+                }),
+            ]),
+            CompoundConstraint::Not(Box::new(CompoundConstraint::Simple(Constraint {
+                left_variable: "suspended".to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: "true".to_string(),
+            }))),
+        ])
+    }
+
+    #[test]
+    fn test_rego_rules_from_tree() {
+        let output = CodeGenerator.generate(&role_check(), TargetLanguage::Rego).unwrap();
+        assert!(output.code.contains("package crucible"));
+        assert!(output.code.contains("default validate_intent := false"));
+        assert!(output.code.contains("intent_0 if {\n    intent_1\n    intent_4\n}"));
+        assert!(output.code.contains("intent_1 if {\n    intent_2\n}\n\nintent_1 if {\n    intent_3\n}"));
+        assert!(output.code.contains("input.role == \"admin\""));
+        assert!(output.code.contains("intent_4 if {\n    not intent_5\n}"));
+    }
+
+    #[test]
+    fn test_rego_naming_options_and_test_file() {
+        let options = CodegenOptions {
+            func_name: "allow".to_string(),
+            package_name: Some("payments.authz".to_string()),
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_options(&role_check(), TargetLanguage::Rego, &options)
+            .unwrap();
+        assert!(output.code.contains("package payments.authz"));
+        assert!(output.code.contains("default allow := false"));
+
+        assert_eq!(output.files.len(), 1);
+        let test = &output.files[0];
+        assert_eq!(test.path, "allow_test.rego");
+        assert!(test.contents.contains("package payments.authz_test"));
+        assert!(test.contents.contains("data.payments.authz.allow with input as {\"role\": \"admin\"}"));
+    }
+}
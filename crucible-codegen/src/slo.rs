@@ -0,0 +1,260 @@
+//! SLO assertions for a spec's tolerances
+//!
+//! `generate_slo_assertions` turns each `Tolerance` ("latency < 100ms in 99%
+//! of cases") into a constant `Slo` with a `holds` check over observed
+//! samples. Thresholds and quantiles are the spec's defaults; deployments
+//! override them without regenerating:
+//!
+//! - Rust: `LATENCY_P99.with_threshold(150.0)`
+//! - TypeScript: `{ ...LATENCY_P99, threshold: 150 }`
+//! - Python: `dataclasses.replace(LATENCY_P99, threshold=150)`
+
+use super::{CodeGenerator, CodegenError, CodegenMetadata, CodegenOptions, CodegenOutput, Provenance, TargetLanguage};
+use crucible_core::Tolerance;
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+impl CodeGenerator {
+    /// Generate one SLO assertion per tolerance.
+    ///
+    /// Supported for Rust, TypeScript and Python.
+    pub fn generate_slo_assertions(
+        &self,
+        tolerances: &[Tolerance],
+        language: TargetLanguage,
+        options: &CodegenOptions,
+    ) -> Result<CodegenOutput, CodegenError> {
+        if tolerances.is_empty() {
+            return Err(CodegenError::GenerationError("no tolerances to assert".to_string()));
+        }
+        let mut names = BTreeSet::new();
+        for tolerance in tolerances {
+            if !names.insert(constant(tolerance)) {
+                return Err(CodegenError::GenerationError(format!(
+                    "more than one tolerance on the {}% of `{}`",
+                    tolerance.percent, tolerance.metric
+                )));
+            }
+        }
+        let code = match language {
+            TargetLanguage::Rust => rust(tolerances),
+            TargetLanguage::TypeScript => typescript(tolerances),
+            TargetLanguage::Python => python(tolerances),
+            other => {
+                return Err(CodegenError::UnsupportedLanguage(format!("{:?} has no SLO assertions", other)));
+            }
+        };
+        let provenance = Provenance::new(&tolerances, None, language, options);
+        Ok(CodegenOutput {
+            language,
+            code: provenance.stamp(language, &code),
+            constraints_count: tolerances.len(),
+            files: Vec::new(),
+            warnings: Vec::new(),
+            metadata: CodegenMetadata::default(),
+            provenance,
+        })
+    }
+}
+
+/// `latency` in 99.9% of cases is `LATENCY_P99_9`
+fn constant(tolerance: &Tolerance) -> String {
+    let metric: String = tolerance
+        .metric
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("{}_P{}", metric, tolerance.percent.to_string().replace('.', "_"))
+}
+
+/// A float literal every target reads: `100.0`, `0.5`
+fn number(value: f64) -> String {
+    format!("{:?}", value)
+}
+
+/// The threshold as a float literal; `Tolerance` only holds parseable ones
+fn threshold(tolerance: &Tolerance) -> String {
+    number(tolerance.threshold.parse().unwrap_or_default())
+}
+
+/// `percent` as a fraction, without the float noise of dividing by 100
+fn quantile(tolerance: &Tolerance) -> String {
+    number((tolerance.percent * 1e6).round() / 1e8)
+}
+
+fn rust(tolerances: &[Tolerance]) -> String {
+    let mut constants = String::new();
+    for tolerance in tolerances {
+        let _ = writeln!(
+            constants,
+            "/// `{}`\npub const {}: Slo = Slo {{\n    metric: \"{}\",\n    threshold: {},\n    quantile: {},\n    \
+             meets: |sample, threshold| sample {} threshold,\n}};\n",
+            tolerance,
+            constant(tolerance),
+            tolerance.metric,
+            threshold(tolerance),
+            quantile(tolerance),
+            tolerance.operator.symbol()
+        );
+    }
+    let all: Vec<String> = tolerances.iter().map(constant).collect();
+    format!(
+        r#"//! Service-level objectives from the spec's tolerances; thresholds and
+//! quantiles are defaults, override them per deployment
+
+/// A share of observations that must meet a threshold
+#[derive(Debug, Clone, Copy)]
+pub struct Slo {{
+    pub metric: &'static str,
+    pub threshold: f64,
+    /// Fraction of observations that must meet the threshold
+    pub quantile: f64,
+    meets: fn(f64, f64) -> bool,
+}}
+
+impl Slo {{
+    pub const fn with_threshold(self, threshold: f64) -> Self {{
+        Slo {{ threshold, ..self }}
+    }}
+
+    pub const fn with_quantile(self, quantile: f64) -> Self {{
+        Slo {{ quantile, ..self }}
+    }}
+
+    /// Whether at least `quantile` of `samples` meet the threshold; true for no samples
+    pub fn holds(&self, samples: &[f64]) -> bool {{
+        let met = samples.iter().filter(|&&sample| (self.meets)(sample, self.threshold)).count();
+        met as f64 >= self.quantile * samples.len() as f64
+    }}
+}}
+
+{constants}pub const SLOS: &[Slo] = &[{all}];
+"#,
+        all = all.join(", ")
+    )
+}
+
+fn typescript(tolerances: &[Tolerance]) -> String {
+    let mut constants = String::new();
+    for tolerance in tolerances {
+        let _ = writeln!(
+            constants,
+            "/** `{}` */\nexport const {}: Slo = {{\n  metric: \"{}\",\n  threshold: {},\n  quantile: {},\n  \
+             meets: (sample, threshold) => sample {} threshold,\n}};\n",
+            tolerance,
+            constant(tolerance),
+            tolerance.metric,
+            threshold(tolerance),
+            quantile(tolerance),
+            tolerance.operator.symbol()
+        );
+    }
+    let all: Vec<String> = tolerances.iter().map(constant).collect();
+    format!(
+        r#"// Service-level objectives from the spec's tolerances; thresholds and
+// quantiles are defaults, override them per deployment
+
+/** A share of observations that must meet a threshold */
+export interface Slo {{
+  readonly metric: string;
+  readonly threshold: number;
+  /** Fraction of observations that must meet the threshold */
+  readonly quantile: number;
+  readonly meets: (sample: number, threshold: number) => boolean;
+}}
+
+{constants}export const SLOS: readonly Slo[] = [{all}];
+
+/** Whether at least `slo.quantile` of `samples` meet the threshold; true for no samples */
+export function holds(slo: Slo, samples: readonly number[]): boolean {{
+  const met = samples.filter((sample) => slo.meets(sample, slo.threshold)).length;
+  return met >= slo.quantile * samples.length;
+}}
+"#,
+        all = all.join(", ")
+    )
+}
+
+fn python(tolerances: &[Tolerance]) -> String {
+    let mut constants = String::new();
+    for tolerance in tolerances {
+        let _ = writeln!(
+            constants,
+            "\n# {}\n{} = Slo(\n    metric=\"{}\",\n    threshold={},\n    quantile={},\n    \
+             meets=lambda sample, threshold: sample {} threshold,\n)",
+            tolerance,
+            constant(tolerance),
+            tolerance.metric,
+            threshold(tolerance),
+            quantile(tolerance),
+            tolerance.operator.symbol()
+        );
+    }
+    let all: Vec<String> = tolerances.iter().map(constant).collect();
+    format!(
+        r#""""Service-level objectives from the spec's tolerances; thresholds and
+quantiles are defaults, override them per deployment"""
+
+from dataclasses import dataclass
+from typing import Callable, Sequence
+
+
+@dataclass(frozen=True)
+class Slo:
+    """A share of observations that must meet a threshold"""
+
+    metric: str
+    threshold: float
+    # Fraction of observations that must meet the threshold
+    quantile: float
+    meets: Callable[[float, float], bool]
+
+    def holds(self, samples: Sequence[float]) -> bool:
+        """Whether at least `quantile` of `samples` meet the threshold; true for no samples"""
+        met = sum(1 for sample in samples if self.meets(sample, self.threshold))
+        return met >= self.quantile * len(samples)
+
+{constants}
+SLOS = [{all}]
+"#,
+        all = all.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::split_tolerance;
+
+    #[test]
+    fn test_slo_assertions() {
+        let tolerances: Vec<Tolerance> =
+            ["latency < 100ms in 99% of cases", "error_rate <= 0.5 99.9% of the time"]
+                .iter()
+                .map(|line| split_tolerance(line).1.unwrap())
+                .collect();
+        let options = CodegenOptions::default();
+
+        let rust = CodeGenerator.generate_slo_assertions(&tolerances, TargetLanguage::Rust, &options).unwrap();
+        assert_eq!(rust.constraints_count, 2);
+        assert!(rust.code.contains(
+            "/// `latency < 100ms in 99% of cases`\npub const LATENCY_P99: Slo = Slo {\n    metric: \"latency\",\n    \
+             threshold: 100.0,\n    quantile: 0.99,\n    meets: |sample, threshold| sample < threshold,\n};\n"
+        ));
+        assert!(rust.code.contains("quantile: 0.999,"));
+        assert!(rust.code.contains("pub const SLOS: &[Slo] = &[LATENCY_P99, ERROR_RATE_P99_9];"));
+
+        let typescript =
+            CodeGenerator.generate_slo_assertions(&tolerances, TargetLanguage::TypeScript, &options).unwrap();
+        assert!(typescript.code.contains("  meets: (sample, threshold) => sample <= threshold,\n"));
+
+        let python = CodeGenerator.generate_slo_assertions(&tolerances, TargetLanguage::Python, &options).unwrap();
+        assert!(python.code.contains("\n# latency < 100ms in 99% of cases\nLATENCY_P99 = Slo(\n"));
+
+        let zig = CodeGenerator.generate_slo_assertions(&tolerances, TargetLanguage::Zig, &options);
+        assert!(matches!(zig, Err(CodegenError::UnsupportedLanguage(_))));
+        let twice = [tolerances[0].clone(), tolerances[0].clone()];
+        let duplicate = CodeGenerator.generate_slo_assertions(&twice, TargetLanguage::Rust, &options);
+        assert!(matches!(duplicate, Err(CodegenError::GenerationError(_))));
+    }
+}
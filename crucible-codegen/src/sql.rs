@@ -0,0 +1,401 @@
+//! SQL Strategy (Database CHECK Constraints)
+//!
+//! Renders constraints as `CHECK` clauses and, when a Schema is available, a
+//! `CREATE TABLE` skeleton whose column types and range checks mirror the
+//! verified intent, so data-layer invariants match the spec exactly.
+
+use super::{default_safe_compare, CodegenError, CodegenStrategy, Provenance, VerifiableStrategy};
+use crucible_core::{
+    ArithmeticOperator, Between, CompoundConstraint, ConstraintOperator, DataType, MathFunction, Schema,
+};
+use serde::{Deserialize, Serialize};
+
+/// SQL dialects supported by the SQL target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SqlDialect {
+    Postgres,
+    MySql,
+    Sqlite,
+}
+
+impl SqlDialect {
+    fn display_name(&self) -> &'static str {
+        match self {
+            SqlDialect::Postgres => "PostgreSQL",
+            SqlDialect::MySql => "MySQL",
+            SqlDialect::Sqlite => "SQLite",
+        }
+    }
+
+    /// A string literal: `'` doubled (and `\\` in MySQL, where it escapes),
+    /// control characters concatenated by code so the literal stays on one line
+    fn string_literal(&self, text: &str) -> String {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        for c in text.chars() {
+            if c.is_control() {
+                if !literal.is_empty() {
+                    parts.push(format!("'{}'", std::mem::take(&mut literal)));
+                }
+                parts.push(match self {
+                    SqlDialect::Postgres => format!("CHR({})", c as u32),
+                    SqlDialect::MySql => {
+                        let hex: String = c.to_string().bytes().map(|b| format!("{:02X}", b)).collect();
+                        format!("CONVERT(X'{}' USING utf8mb4)", hex)
+                    }
+                    SqlDialect::Sqlite => format!("CHAR({})", c as u32),
+                });
+                continue;
+            }
+            match c {
+                '\'' => literal.push_str("''"),
+                '\\' if *self == SqlDialect::MySql => literal.push_str("\\\\"),
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() || parts.is_empty() {
+            parts.push(format!("'{}'", literal));
+        }
+        match (parts.len(), self) {
+            (1, _) => parts.remove(0),
+            (_, SqlDialect::MySql) => format!("CONCAT({})", parts.join(", ")),
+            _ => format!("({})", parts.join(" || ")),
+        }
+    }
+}
+
+pub(crate) struct SqlStrategy {
+    pub(crate) dialect: SqlDialect,
+}
+
+impl CodegenStrategy for SqlStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        match op {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "=",
+            ConstraintOperator::NotEqual => "<>",
+            ConstraintOperator::Contains => "LIKE",
+            ConstraintOperator::DoesNotContain => "NOT LIKE",
+        }
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    fn format_string(&self, text: &str) -> String {
+        self.dialect.string_literal(text)
+    }
+
+    /// The substring function differs per dialect
+    fn format_membership(&self, haystack: &str, needle: &str) -> String {
+        match self.dialect {
+            SqlDialect::Postgres => format!("POSITION({} IN {}) > 0", needle, haystack),
+            SqlDialect::MySql => format!("LOCATE({}, {}) > 0", needle, haystack),
+            SqlDialect::Sqlite => format!("INSTR({}, {}) > 0", haystack, needle),
+        }
+    }
+
+    /// `%` truncates toward zero in all three dialects; shifted into `[0, |divisor|)`
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("((({} % {d}) + ABS({d})) % ABS({d}))", dividend, d = divisor)
+    }
+
+    /// Exclusive or is `#` in PostgreSQL, `^` in MySQL and missing in SQLite,
+    /// and no dialect fixes the width of a shift, so masks are not checked here
+    fn supports_bitwise(&self) -> bool {
+        false
+    }
+
+    /// SQLite's multi-argument `MIN`/`MAX` are its `LEAST`/`GREATEST`; `ABS`
+    /// of the most negative `BIGINT` is an out-of-range error in all three
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        let name = match (function, self.dialect) {
+            (MathFunction::Abs, _) => "ABS",
+            (MathFunction::Min, SqlDialect::Sqlite) => "MIN",
+            (MathFunction::Max, SqlDialect::Sqlite) => "MAX",
+            (MathFunction::Min, _) => "LEAST",
+            (MathFunction::Max, _) => "GREATEST",
+            (MathFunction::Sum | MathFunction::Count | MathFunction::Average, _) => {
+                unreachable!("aggregates are rejected before generation")
+            }
+        };
+        format!("{}({})", name, args.join(", "))
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(format!("CASE WHEN {} THEN {} ELSE {} END", condition, then_value, else_value))
+    }
+
+    /// `BETWEEN` is inclusive; exclusive ranges keep both comparisons
+    fn format_range(&self, range: &Between) -> String {
+        let (lower, upper) = range.bounds();
+        if !range.inclusive {
+            return format!("({} AND {})", self.format_comparison(&lower), self.format_comparison(&upper));
+        }
+        format!("({} BETWEEN {} AND {})", range.variable, range.low, range.high)
+    }
+
+    fn logical_and(&self) -> &'static str {
+        "AND"
+    }
+
+    fn logical_or(&self) -> &'static str {
+        "OR"
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        format!("NOT ({})", expr)
+    }
+
+    fn inline_comment(&self, text: &str) -> Option<String> {
+        Some(format!("/* {} */", text))
+    }
+
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        let terms: Vec<String> = parts.iter().map(|p| format!("CASE WHEN {} THEN 1 ELSE 0 END", p)).collect();
+        Some(format!("({})", terms.join(" + ")))
+    }
+
+    fn wrap_assertion(&self, condition: &str) -> String {
+        format!("CHECK ({})", condition)
+    }
+
+    fn wrap_verified_function(
+        &self,
+        func_name: &str,
+        _contracts: &str,
+        body: &str,
+        _assertions: &str,
+    ) -> String {
+        let statement = match self.dialect {
+            // SQLite cannot add constraints to an existing table
+            SqlDialect::Sqlite => format!(
+                "-- Add to the CREATE TABLE definition of {func_name}_params:\n--     CONSTRAINT {func_name} CHECK ({body})"
+            ),
+            SqlDialect::Postgres | SqlDialect::MySql => format!(
+                "ALTER TABLE {func_name}_params\n    ADD CONSTRAINT {func_name} CHECK ({body});"
+            ),
+        };
+
+        format!(
+            r#"-- SQL Generated Code ({dialect}) - Database CHECK Constraints
+-- Enforces the verified intent at the data layer
+
+{statement}"#,
+            dialect = self.dialect.display_name(),
+            statement = statement
+        )
+    }
+}
+
+impl SqlStrategy {
+    /// Range checks implied by the column type itself (e.g. unsigned, custom ranges)
+    pub(crate) fn type_checks(&self, schema: &Schema) -> Vec<String> {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        let mut checks = Vec::new();
+        for name in names {
+            match &schema.fields[name] {
+                DataType::Uint64 if self.dialect != SqlDialect::MySql => {
+                    checks.push(format!("{} BETWEEN 0 AND {}", name, u64::MAX));
+                }
+                DataType::Uint32 if self.dialect != SqlDialect::MySql => {
+                    checks.push(format!("{} BETWEEN 0 AND {}", name, u32::MAX));
+                }
+                DataType::Custom { range_min, range_max, .. } => match (range_min, range_max) {
+                    (Some(min), Some(max)) => checks.push(format!("{} BETWEEN {} AND {}", name, min, max)),
+                    (Some(min), None) => checks.push(format!("{} >= {}", name, min)),
+                    (None, Some(max)) => checks.push(format!("{} <= {}", name, max)),
+                    (None, None) => {}
+                },
+                _ => {}
+            }
+        }
+        checks
+    }
+}
+
+impl VerifiableStrategy for SqlStrategy {
+    fn map_type(&self, dt: &DataType) -> String {
+        match (self.dialect, dt) {
+            (SqlDialect::Postgres, DataType::Uint64) => "NUMERIC(20, 0)".to_string(),
+            (SqlDialect::Postgres, DataType::Uint32) => "BIGINT".to_string(),
+            (SqlDialect::Postgres, DataType::Int64) => "BIGINT".to_string(),
+            (SqlDialect::Postgres, DataType::Int32) => "INTEGER".to_string(),
+            (SqlDialect::Postgres, DataType::String) => "TEXT".to_string(),
+            (SqlDialect::Postgres, DataType::Bool) => "BOOLEAN".to_string(),
+            (SqlDialect::Postgres, DataType::Decimal) => "NUMERIC(38, 18)".to_string(),
+            (SqlDialect::Postgres, DataType::List(element)) => format!("{}[]", self.map_type(element)),
+            (SqlDialect::MySql, DataType::Uint64) => "BIGINT UNSIGNED".to_string(),
+            (SqlDialect::MySql, DataType::Uint32) => "INT UNSIGNED".to_string(),
+            (SqlDialect::MySql, DataType::Int64) => "BIGINT".to_string(),
+            (SqlDialect::MySql, DataType::Int32) => "INT".to_string(),
+            (SqlDialect::MySql, DataType::String) => "TEXT".to_string(),
+            (SqlDialect::MySql, DataType::Bool) => "BOOLEAN".to_string(),
+            (SqlDialect::MySql, DataType::Decimal) => "DECIMAL(38, 18)".to_string(),
+            (SqlDialect::MySql, DataType::List(_)) => "JSON".to_string(),
+            (SqlDialect::Sqlite, DataType::String | DataType::List(_)) => "TEXT".to_string(),
+            (SqlDialect::Sqlite, DataType::Decimal) => "NUMERIC".to_string(),
+            (SqlDialect::Sqlite, DataType::Custom { .. }) => "NUMERIC".to_string(),
+            (SqlDialect::Sqlite, _) => "INTEGER".to_string(),
+            (_, DataType::Custom { range_min, range_max, .. }) => {
+                // Ranged custom types are stored as the narrowest exact numeric
+                if range_min.is_some() || range_max.is_some() {
+                    "NUMERIC(39, 0)".to_string()
+                } else {
+                    "TEXT".to_string()
+                }
+            }
+        }
+    }
+
+    fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
+        format!("-- Post-condition: Row is accepted iff ({})", expression)
+    }
+
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        // SQL arithmetic on exact numerics raises on overflow instead of wrapping
+        format!("({} {} {})", left, op.symbol(), right)
+    }
+
+    fn build_signature(&self, func_name: &str, schema: &Schema) -> String {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        let columns: Vec<String> = names
+            .iter()
+            .map(|name| format!("    {} {} NOT NULL", name, self.map_type(&schema.fields[*name])))
+            .collect();
+
+        format!("CREATE TABLE {}_params (\n{}", func_name, columns.join(",\n"))
+    }
+
+    fn fn_end(&self) -> String {
+        ");".to_string()
+    }
+
+    fn license_header(&self, provenance: &Provenance) -> String {
+        let title = format!(
+            "SQL Generated Code ({}) - Database CHECK Constraints (v0.1.5-alpha)",
+            self.dialect.display_name()
+        );
+        provenance.banner("--", &[&title])
+    }
+
+    fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
+        default_safe_compare(left, op, right, data_type)
+    }
+}
+
+/// Build the full `CREATE TABLE` artifact for the SQL target; a table
+/// needs at least one column, so the Schema must declare a field
+pub(crate) fn render_table(
+    strategy: &SqlStrategy,
+    header: &str,
+    signature: &str,
+    postcondition: &str,
+    logic_expr: &str,
+    schema: &Schema,
+    compound: &CompoundConstraint,
+) -> Result<String, CodegenError> {
+    if schema.fields.is_empty() {
+        return Err(CodegenError::GenerationError(
+            "the SQL target needs at least one Schema field to declare as a column".to_string(),
+        ));
+    }
+    let mut clauses = Vec::new();
+    for (i, check) in strategy.type_checks(schema).iter().enumerate() {
+        clauses.push(format!("    CONSTRAINT validate_intent_type_{} CHECK ({})", i + 1, check));
+    }
+    if compound.count_constraints() > 0 {
+        clauses.push(format!("    CONSTRAINT validate_intent CHECK ({})", logic_expr));
+    }
+
+    let separator = if clauses.is_empty() { "" } else { ",\n" };
+    Ok(format!(
+        "{}{}\n{}{}{}\n{}",
+        header,
+        postcondition,
+        signature,
+        separator,
+        clauses.join(",\n"),
+        strategy.fn_end()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, TargetLanguage};
+    use crucible_core::Constraint;
+
+    fn withdraw() -> CompoundConstraint {
+        CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "balance".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "amount".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::NotEqual,
+                right_value: "0".to_string(),
+            }),
+        ])
+    }
+
+    #[test]
+    fn test_sql_check_constraint() {
+        let output = CodeGenerator
+            .generate(&withdraw(), TargetLanguage::Sql(SqlDialect::Postgres))
+            .unwrap();
+        assert!(output.code.contains("ALTER TABLE validate_intent_params"));
+        assert!(output.code.contains("CHECK ((balance >= amount AND amount <> 0))"));
+    }
+
+    #[test]
+    fn test_sql_create_table_per_dialect() {
+        let mut schema = Schema::new("sql-001".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, None);
+        schema.add_field("amount".to_string(), DataType::Uint32, None);
+
+        let pg = CodeGenerator
+            .generate_with_schema(&withdraw(), &schema, TargetLanguage::Sql(SqlDialect::Postgres))
+            .unwrap();
+        assert!(pg.code.contains("CREATE TABLE validate_intent_params ("));
+        assert!(pg.code.contains("balance NUMERIC(20, 0) NOT NULL"));
+        assert!(pg.code.contains("CHECK (amount BETWEEN 0 AND 4294967295)"));
+        assert!(pg.code.contains("CONSTRAINT validate_intent CHECK ("));
+        assert!(pg.code.trim_end().ends_with(");"));
+
+        let mysql = CodeGenerator
+            .generate_with_schema(&withdraw(), &schema, TargetLanguage::Sql(SqlDialect::MySql))
+            .unwrap();
+        assert!(mysql.code.contains("balance BIGINT UNSIGNED NOT NULL"));
+        assert!(!mysql.code.contains("BETWEEN 0 AND"));
+
+        let sqlite = CodeGenerator
+            .generate_with_schema(&withdraw(), &schema, TargetLanguage::Sql(SqlDialect::Sqlite))
+            .unwrap();
+        assert!(sqlite.code.contains("amount INTEGER NOT NULL"));
+        assert!(sqlite.code.contains("Traceability ID: sql-001"));
+    }
+
+    #[test]
+    fn test_sql_empty_schema_rejected() {
+        let schema = Schema::new("sql-002".to_string());
+        let strategy = SqlStrategy { dialect: SqlDialect::Sqlite };
+        let signature = strategy.build_signature("validate_intent", &schema);
+        let result = render_table(&strategy, "", &signature, "", "TRUE", &schema, &withdraw());
+        assert!(matches!(result, Err(CodegenError::GenerationError(_))));
+    }
+}
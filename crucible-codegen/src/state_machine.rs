@@ -0,0 +1,111 @@
+//! State machines over requirement lifecycles
+//!
+//! `generate_state_machine` renders a `StateMachine` in the idiom of the
+//! target: a typestate in Rust, where an illegal transition does not compile,
+//! and a `GenStateMachine` in Elixir. Guards are rendered with the target's
+//! ordinary expression builder over the subject's `params`.
+
+use super::{
+    inlined_compound, CodeGenerator, CodegenError, CodegenMetadata, CodegenOptions, CodegenOutput, FieldNames,
+    Provenance, TargetLanguage,
+};
+use crucible_core::StateMachine;
+
+impl CodeGenerator {
+    /// Generate the state machine for `machine`.
+    ///
+    /// Supported for Rust and Elixir.
+    pub fn generate_state_machine(
+        &self,
+        machine: &StateMachine,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+    ) -> Result<CodegenOutput, CodegenError> {
+        // Actions are functions everywhere; Elixir also names a function after each state
+        let states = machine.states.iter().filter(|_| language == TargetLanguage::Elixir);
+        for name in machine.transitions.iter().map(|t| &t.action).chain(states) {
+            language.check_identifier(name)?;
+        }
+        let guards = machine
+            .transitions
+            .iter()
+            .map(|t| t.guard.as_ref().map(|guard| inlined_compound(guard, options)).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+        let names = FieldNames::for_compounds(language, guards.iter().flatten().map(|guard| &**guard), None)?;
+        let strategy = super::named_strategy(language, options, &names);
+        let guards: Vec<Option<String>> = guards
+            .into_iter()
+            .map(|guard| guard.map(|guard| self.build_expression(&names.rename(guard), &*strategy)))
+            .collect();
+
+        let code = strategy.emit_state_machine(machine, &guards).ok_or_else(|| {
+            CodegenError::UnsupportedLanguage(format!("{:?} has no state-machine output", language))
+        })?;
+
+        let provenance = Provenance::new(machine, None, language, options);
+        Ok(CodegenOutput {
+            language,
+            code: provenance.stamp(language, &code),
+            constraints_count: machine
+                .transitions
+                .iter()
+                .filter_map(|t| t.guard.as_ref())
+                .map(|g| g.count_constraints())
+                .sum(),
+            files: Vec::new(),
+            warnings: Vec::new(),
+            metadata: CodegenMetadata::default(),
+            provenance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    fn session() -> StateMachine {
+        let mut machine = StateMachine::new("user", "initial");
+        machine.add_transition("initial", "registered", "register", None);
+        machine.add_transition(
+            "registered",
+            "logged_in",
+            "login",
+            Some(CompoundConstraint::Simple(Constraint {
+                left_variable: "attempts".to_string(),
+                operator: ConstraintOperator::LessThan,
+                right_value: "3".to_string(),
+            })),
+        );
+        machine.add_transition("logged_in", "registered", "logout", None);
+        machine
+    }
+
+    #[test]
+    fn test_rust_typestate() {
+        let output = CodeGenerator
+            .generate_state_machine(&session(), TargetLanguage::Rust, &CodegenOptions::default())
+            .unwrap();
+        assert!(output.code.contains("pub struct LoggedIn;"));
+        assert!(output.code.contains("impl User<Initial> {\n    pub fn new(params: ValidationParams) -> Self"));
+        assert!(output.code.contains("pub fn login(self) -> Result<User<LoggedIn>, Self>"));
+        assert!(output.code.contains("if !(params.attempts < 3)"));
+        assert!(output.code.contains("    LoggedIn(User<LoggedIn>),"));
+        assert_eq!(output.constraints_count, 1);
+    }
+
+    #[test]
+    fn test_elixir_gen_state_machine() {
+        let output = CodeGenerator
+            .generate_state_machine(&session(), TargetLanguage::Elixir, &CodegenOptions::default())
+            .unwrap();
+        assert!(output.code.contains("defmodule UserMachine do"));
+        assert!(output.code.contains("def init(params), do: {:ok, :initial, params}"));
+        assert!(output.code.contains("def registered({:call, from}, :login, params) do"));
+        assert!(output.code.contains("params[:attempts] < 3 -> {:next_state, :logged_in, params, [{:reply, from, :ok}]}"));
+
+        let python = CodeGenerator.generate_state_machine(&session(), TargetLanguage::Python, &CodegenOptions::default());
+        assert!(matches!(python, Err(CodegenError::UnsupportedLanguage(_))));
+    }
+}
@@ -0,0 +1,95 @@
+//! Streaming output for `generate_to`
+//!
+//! Generated artifacts used to be assembled with one `format!` per target and
+//! returned as a single `String`, so a Schema with thousands of fields was
+//! held in memory several times over (the pieces, the assembled validator,
+//! the validator with its predicate helpers). `ArtifactWriter` sends each
+//! piece to the destination as it is produced instead.
+
+use std::io::{self, Write};
+
+/// Writes one artifact, counting its bytes and appending the predicate helpers.
+///
+/// The helpers follow the validator after a blank line, with the validator's
+/// trailing whitespace dropped, so trailing whitespace is held back until
+/// either more code or the end of the artifact shows whether it is trailing.
+pub(crate) struct ArtifactWriter<'a> {
+    inner: &'a mut dyn Write,
+    pending: Vec<u8>,
+    bytes: u64,
+}
+
+impl<'a> ArtifactWriter<'a> {
+    pub(crate) fn new(inner: &'a mut dyn Write) -> Self {
+        Self {
+            inner,
+            pending: Vec::new(),
+            bytes: 0,
+        }
+    }
+
+    fn emit(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf)?;
+        self.bytes += buf.len() as u64;
+        Ok(())
+    }
+
+    /// Append the helpers and return the size of the artifact
+    pub(crate) fn finish(mut self, helpers: &[String]) -> io::Result<u64> {
+        if helpers.is_empty() {
+            let pending = std::mem::take(&mut self.pending);
+            self.emit(&pending)?;
+        } else {
+            self.emit(b"\n\n")?;
+            self.emit(helpers.join("\n\n").as_bytes())?;
+            self.emit(b"\n")?;
+        }
+        self.inner.flush()?;
+        Ok(self.bytes)
+    }
+}
+
+impl Write for ArtifactWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match buf.iter().rposition(|b| !b.is_ascii_whitespace()) {
+            None => self.pending.extend_from_slice(buf),
+            Some(last) => {
+                let pending = std::mem::take(&mut self.pending);
+                self.emit(&pending)?;
+                self.emit(&buf[..=last])?;
+                self.pending.extend_from_slice(&buf[last + 1..]);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn artifact(chunks: &[&str], helpers: &[String]) -> (String, u64) {
+        let mut out = Vec::new();
+        let mut writer = ArtifactWriter::new(&mut out);
+        for chunk in chunks {
+            writer.write_all(chunk.as_bytes()).unwrap();
+        }
+        let bytes = writer.finish(helpers).unwrap();
+        (String::from_utf8(out).unwrap(), bytes)
+    }
+
+    #[test]
+    fn test_trailing_whitespace_only_dropped_before_helpers() {
+        let chunks = ["fn a() {}\n", "\n  ", "fn b() {}\n\n"];
+        assert_eq!(artifact(&chunks, &[]), ("fn a() {}\n\n  fn b() {}\n\n".to_string(), 24));
+
+        let helpers = vec!["fn p() {}".to_string(), "fn q() {}".to_string()];
+        let (code, bytes) = artifact(&chunks, &helpers);
+        assert_eq!(code, "fn a() {}\n\n  fn b() {}\n\nfn p() {}\n\nfn q() {}\n");
+        assert_eq!(bytes, code.len() as u64);
+    }
+}
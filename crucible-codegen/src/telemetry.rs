@@ -0,0 +1,191 @@
+//! OpenTelemetry spans around intent checks
+//!
+//! With `CodegenOptions::telemetry`, Rust, TypeScript and Python validators
+//! are followed by a `traced_<func>` wrapper that checks the top-level
+//! constraints inside a span named after the requirement ID, through the
+//! target's own OpenTelemetry API:
+//!
+//! - Rust: `opentelemetry::global::tracer`
+//! - TypeScript: `trace.getTracer(..).startActiveSpan` from `@opentelemetry/api`
+//! - Python: `trace.get_tracer(..).start_as_current_span` from `opentelemetry`
+//!
+//! Every span carries the same attributes, so one query covers all services:
+//! `crucible.requirement.id`, `crucible.validator`, `crucible.valid` and
+//! `crucible.failed_constraints` (the names of the failed checks, as in
+//! `monitor`). A failed check also sets the span status to error.
+
+use super::monitor::{bindings, checks};
+use super::{literal, CodegenStrategy, TargetLanguage};
+use crucible_core::CompoundConstraint;
+use std::borrow::Cow;
+
+/// The traced wrapper for `compound`, or `None` for targets without one.
+/// `params_type` is the parameter type of the TypeScript validator.
+pub(crate) fn traced(
+    compound: &CompoundConstraint,
+    language: TargetLanguage,
+    strategy: &dyn CodegenStrategy,
+    func_name: &str,
+    requirement_id: &str,
+    params_type: &str,
+) -> Option<String> {
+    let pairs: Vec<String> = checks(compound, strategy)
+        .into_iter()
+        .map(|check| match language {
+            TargetLanguage::TypeScript => format!("[\"{}\", {}]", check.name, check.condition),
+            _ => format!("(\"{}\", {})", check.name, check.condition),
+        })
+        .collect();
+    let span = Span {
+        bindings: bindings(compound, language, strategy),
+        checks: pairs.join(", "),
+        func_name,
+        id: strategy.format_string(requirement_id),
+        summary: literal::comment(requirement_id),
+    };
+    match language {
+        TargetLanguage::Rust => Some(span.rust()),
+        TargetLanguage::TypeScript => Some(span.typescript(params_type)),
+        TargetLanguage::Python => Some(span.python()),
+        _ => None,
+    }
+}
+
+/// What every target's wrapper is assembled from
+struct Span<'a> {
+    bindings: String,
+    /// `(name, holds)` pairs in the target's syntax, comma-separated
+    checks: String,
+    func_name: &'a str,
+    /// The requirement ID as a target string literal
+    id: String,
+    /// The requirement ID, safe inside a doc comment
+    summary: Cow<'a, str>,
+}
+
+impl Span<'_> {
+    fn rust(&self) -> String {
+        let Span { bindings, checks, func_name, id, summary } = self;
+        format!(
+            r#"use opentelemetry::trace::{{Span as _, Status, Tracer as _}};
+use opentelemetry::{{global, Array, KeyValue, StringValue, Value}};
+
+/// `{func_name}` inside a span named after requirement `{summary}`, recording
+/// which constraints failed
+pub fn traced_{func_name}(params: &ValidationParams) -> bool {{
+    let mut span = global::tracer("crucible").start({id});{bindings}
+    let checks = [{checks}];
+    let failed: Vec<StringValue> =
+        checks.into_iter().filter(|(_, holds)| !holds).map(|(name, _)| name.into()).collect();
+    let valid = failed.is_empty();
+    span.set_attribute(KeyValue::new("crucible.requirement.id", {id}));
+    span.set_attribute(KeyValue::new("crucible.validator", "{func_name}"));
+    span.set_attribute(KeyValue::new("crucible.valid", valid));
+    span.set_attribute(KeyValue::new("crucible.failed_constraints", Value::Array(Array::String(failed))));
+    if !valid {{
+        span.set_status(Status::error("intent violated"));
+    }}
+    span.end();
+    valid
+}}"#
+        )
+    }
+
+    fn typescript(&self, params_type: &str) -> String {
+        let Span { bindings, checks, func_name, id, summary } = self;
+        format!(
+            r#"import * as otel from "@opentelemetry/api";
+
+/**
+ * `{func_name}` inside a span named after requirement `{summary}`,
+ * recording which constraints failed
+ */
+export function traced_{func_name}(params: {params_type}): boolean {{{bindings}
+  return otel.trace.getTracer("crucible").startActiveSpan({id}, (span) => {{
+    const checks: [string, boolean][] = [{checks}];
+    const failed = checks.filter(([, holds]) => !holds).map(([name]) => name);
+    span.setAttributes({{
+      "crucible.requirement.id": {id},
+      "crucible.validator": "{func_name}",
+      "crucible.valid": failed.length === 0,
+      "crucible.failed_constraints": failed,
+    }});
+    if (failed.length > 0) {{
+      span.setStatus({{ code: otel.SpanStatusCode.ERROR, message: "intent violated" }});
+    }}
+    span.end();
+    return failed.length === 0;
+  }});
+}}"#
+        )
+    }
+
+    fn python(&self) -> String {
+        let Span { bindings, checks, func_name, id, summary } = self;
+        format!(
+            r#"from opentelemetry import trace
+from opentelemetry.trace import Status, StatusCode
+
+_intent_tracer = trace.get_tracer("crucible")
+
+
+def traced_{func_name}(params) -> bool:
+    """`{func_name}` inside a span named after requirement `{summary}`,
+    recording which constraints failed."""{bindings}
+    with _intent_tracer.start_as_current_span({id}) as span:
+        checks = [{checks}]
+        failed = [name for name, holds in checks if not holds]
+        span.set_attributes({{
+            "crucible.requirement.id": {id},
+            "crucible.validator": "{func_name}",
+            "crucible.valid": not failed,
+            "crucible.failed_constraints": failed,
+        }})
+        if failed:
+            span.set_status(Status(StatusCode.ERROR, "intent violated"))
+        return not failed"#
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeGenerator, CodegenOptions, TargetLanguage};
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_traced_validators() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+        ]);
+        let options =
+            CodegenOptions { telemetry: true, requirement_id: Some("REQ-7".to_string()), ..CodegenOptions::default() };
+        let generate = |language| CodeGenerator.generate_with_options(&compound, language, &options).unwrap().code;
+
+        let rust = generate(TargetLanguage::Rust);
+        assert!(rust.contains("pub fn traced_validate_intent(params: &ValidationParams) -> bool {\n"));
+        assert!(rust.contains("    let mut span = global::tracer(\"crucible\").start(\"REQ-7\");\n"));
+        assert!(rust
+            .contains("    let checks = [(\"amount_gt_0\", amount > 0), (\"balance_ge_amount\", balance >= amount)];"));
+
+        let typescript = generate(TargetLanguage::TypeScript);
+        assert!(typescript.contains("  const { amount, balance } = params;\n  return otel.trace.getTracer"));
+        assert!(typescript.contains("[[\"amount_gt_0\", amount > 0], [\"balance_ge_amount\", balance >= amount]]"));
+
+        let python = generate(TargetLanguage::Python);
+        assert!(python.contains("    with _intent_tracer.start_as_current_span(\"REQ-7\") as span:\n"));
+
+        // Without an ID the span is named after the tree's canonical hash
+        let options = CodegenOptions { telemetry: true, ..CodegenOptions::default() };
+        let rust = CodeGenerator.generate_with_options(&compound, TargetLanguage::Rust, &options).unwrap().code;
+        assert!(rust.contains(&format!(".start(\"{}\");", compound.canonical_hash())));
+    }
+}
@@ -0,0 +1,194 @@
+//! State-update functions for pre/postcondition transitions
+//!
+//! Where `generate` emits a boolean validator, `generate_transition` emits a
+//! function that checks the precondition, applies the `x' == expr` updates
+//! to a copy of the state and checks the remaining postconditions on the
+//! result. Primed variables in those checks refer to the new state.
+
+use super::{
+    inlined_compound, is_identifier, CodeGenerator, CodegenError, CodegenMetadata, CodegenOptions, CodegenOutput,
+    CodegenStrategy, FieldNames, Provenance, TargetLanguage,
+};
+use crucible_core::transition::unprimed;
+use crucible_core::{Bitwise, Constraint, ConstraintOperator, MathFunction, StateTransition, UpdateExpr};
+
+/// Renders primed variables as post-state fields of the wrapped strategy
+struct NextState<'a>(&'a dyn CodegenStrategy);
+
+impl CodegenStrategy for NextState<'_> {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.0.wrap_in_function(body, func_name)
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        self.0.format_operator(op)
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        match unprimed(name) {
+            Some(name) => self.0.format_next_variable(name),
+            None => self.0.format_variable(name),
+        }
+    }
+
+    fn format_relation(&self, constraint: &Constraint, left: &str, right: &str) -> String {
+        self.0.format_relation(constraint, left, right)
+    }
+
+    fn format_string(&self, text: &str) -> String {
+        self.0.format_string(text)
+    }
+
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        self.0.format_bitwise(bitwise, operand, mask)
+    }
+
+    fn supports_bitwise(&self) -> bool {
+        self.0.supports_bitwise()
+    }
+
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        self.0.format_call(function, args)
+    }
+
+    fn supports_lists(&self) -> bool {
+        self.0.supports_lists()
+    }
+
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        self.0.format_distinct(values)
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        self.0.format_conditional(condition, then_value, else_value)
+    }
+
+    fn logical_and(&self) -> &'static str {
+        self.0.logical_and()
+    }
+
+    fn logical_or(&self) -> &'static str {
+        self.0.logical_or()
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        self.0.logical_not(expr)
+    }
+
+    fn wrap_verified_function(&self, func_name: &str, contracts: &str, body: &str, assertions: &str) -> String {
+        self.0.wrap_verified_function(func_name, contracts, body, assertions)
+    }
+}
+
+impl CodeGenerator {
+    /// Generate a state-update function for `transition`, named after its action.
+    ///
+    /// Supported for the general-purpose targets (Rust, TypeScript, Python,
+    /// Solidity, SPARK/Ada, Zig, Elixir); policy targets only validate.
+    pub fn generate_transition(
+        &self,
+        transition: &StateTransition,
+        language: TargetLanguage,
+        options: &CodegenOptions,
+    ) -> Result<CodegenOutput, CodegenError> {
+        language.check_identifier(&transition.action)?;
+        let provenance = Provenance::new(transition, None, language, options);
+        let pre = inlined_compound(&transition.pre, options)?;
+        let post = inlined_compound(&transition.post, options)?;
+        let names = FieldNames::for_compounds(language, [&*pre, &*post], None)?;
+        let strategy = super::named_strategy(language, options, &names);
+        let (pre, post) = (names.rename(pre), names.rename(post));
+        let transition = StateTransition::new(&transition.action, pre.into_owned(), post.into_owned());
+
+        let updates = transition
+            .updates()
+            .map_err(|e| CodegenError::GenerationError(e.to_string()))?;
+        let assignments: Vec<(String, String)> = updates
+            .iter()
+            .map(|u| (u.variable.clone(), render_update(&u.expression, &*strategy)))
+            .collect();
+
+        let guard = self.build_expression(&transition.pre, &*strategy);
+        let post_check = transition
+            .post_checks()
+            .map(|checks| self.build_expression(&checks, &NextState(&*strategy)));
+
+        let code = strategy
+            .wrap_state_update(&transition.action, &guard, &assignments, post_check.as_deref())
+            .ok_or_else(|| {
+                CodegenError::UnsupportedLanguage(format!("{:?} has no state-update functions", language))
+            })?;
+
+        Ok(CodegenOutput {
+            language,
+            code: provenance.stamp(language, &code),
+            constraints_count: transition.pre.count_constraints() + transition.post.count_constraints(),
+            files: Vec::new(),
+            warnings: Vec::new(),
+            metadata: CodegenMetadata::default(),
+            provenance,
+        })
+    }
+}
+
+/// `balance - amount` -> `params.balance - params.amount`
+fn render_update(expression: &UpdateExpr, strategy: &dyn CodegenStrategy) -> String {
+    let operand = |value: &str| {
+        if is_identifier(value) {
+            strategy.format_variable(value)
+        } else {
+            value.to_string()
+        }
+    };
+
+    match &expression.operation {
+        Some((op, right)) => format!("{} {} {}", operand(&expression.left), op.symbol(), operand(right)),
+        None => operand(&expression.left),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{CompoundConstraint, Constraint};
+
+    fn withdraw() -> StateTransition {
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        StateTransition::new(
+            "withdraw",
+            atom("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            CompoundConstraint::And(vec![
+                atom("balance'", ConstraintOperator::Equal, "balance - amount"),
+                atom("balance'", ConstraintOperator::GreaterThanOrEqual, "0"),
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_rust_and_python_state_updates() {
+        let generator = CodeGenerator;
+        let options = CodegenOptions::default();
+
+        let rust = generator.generate_transition(&withdraw(), TargetLanguage::Rust, &options).unwrap();
+        assert!(rust.code.contains("pub fn withdraw(params: &ValidationParams) -> Result<ValidationParams, &'static str>"));
+        assert!(rust.code.contains("if !(params.balance >= params.amount)"));
+        assert!(rust.code.contains("next.balance = params.balance - params.amount;"));
+        assert!(rust.code.contains("if !(next.balance >= 0)"));
+
+        let python = generator.generate_transition(&withdraw(), TargetLanguage::Python, &options).unwrap();
+        assert!(python.code.contains("next_state['balance'] = params['balance'] - params['amount']"));
+        assert!(python.code.contains("if not (next_state['balance'] >= 0):"));
+    }
+
+    #[test]
+    fn test_policy_targets_reject_transitions() {
+        let result = CodeGenerator.generate_transition(&withdraw(), TargetLanguage::Rego, &CodegenOptions::default());
+        assert!(matches!(result, Err(CodegenError::UnsupportedLanguage(_))));
+    }
+}
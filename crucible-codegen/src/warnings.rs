@@ -0,0 +1,135 @@
+//! Schema types a target cannot represent exactly
+//!
+//! A `Uint64` is a `number` in TypeScript, exact only up to 2^53; a `Decimal`
+//! is an `f64` in Rust; a `Uint64` is a `uint256` in Solidity and a `Natural`
+//! in SPARK. The validator still compiles, but it accepts or rejects
+//! different values than the Schema describes, so `generate_with_schema`
+//! reports each such field in `CodegenOutput::warnings`.
+
+use crate::{CodegenOptions, SqlDialect, TargetLanguage};
+use crucible_core::{DataType, Schema};
+use serde::{Deserialize, Serialize};
+
+/// How a target type differs from the Schema type it stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeLoss {
+    /// Some values are rounded (doubles, fixed fractional digits)
+    Precision,
+    /// The target type also holds values outside the Schema type
+    Widened,
+    /// Some values of the Schema type do not fit
+    Narrowed,
+}
+
+impl TypeLoss {
+    fn describe(&self) -> &'static str {
+        match self {
+            TypeLoss::Precision => "which rounds some of its values",
+            TypeLoss::Widened => "which also holds values outside it",
+            TypeLoss::Narrowed => "which cannot hold all of its values",
+        }
+    }
+}
+
+/// A Schema field whose type maps lossily into the target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodegenWarning {
+    pub field: String,
+    pub data_type: DataType,
+    /// The target's spelling of the type, e.g. `number`
+    pub target_type: String,
+    pub loss: TypeLoss,
+}
+
+impl std::fmt::Display for CodegenWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` ({:?}) is generated as `{}`, {}",
+            self.field,
+            self.data_type,
+            self.target_type,
+            self.loss.describe()
+        )
+    }
+}
+
+/// How `language` represents `data_type`; `None` when exactly
+pub(crate) fn type_loss(language: TargetLanguage, data_type: &DataType, options: &CodegenOptions) -> Option<TypeLoss> {
+    let wide = matches!(data_type, DataType::Uint64 | DataType::Int64);
+    match (language, data_type) {
+        (_, DataType::List(element)) => type_loss(language, element, options),
+        // Exact decimals and unbounded integers
+        (TargetLanguage::Python | TargetLanguage::Elixir | TargetLanguage::Rego | TargetLanguage::Cue, _) => None,
+        (_, DataType::Decimal) => Some(TypeLoss::Precision),
+        (TargetLanguage::TypeScript, _) if wide && !options.bigint => Some(TypeLoss::Precision),
+        (TargetLanguage::Solidity, _) if wide => Some(TypeLoss::Widened),
+        // `Natural` and `Integer` are 32-bit
+        (TargetLanguage::SparkAda, DataType::Uint64 | DataType::Uint32 | DataType::Int64) => Some(TypeLoss::Narrowed),
+        // A signed 64-bit integer
+        (TargetLanguage::Cedar | TargetLanguage::Sql(SqlDialect::Sqlite), DataType::Uint64) => Some(TypeLoss::Narrowed),
+        _ => None,
+    }
+}
+
+/// A warning for every field of `schema` that `language` maps lossily,
+/// in field order; `map_type` spells the target type
+pub(crate) fn lossy_fields(
+    language: TargetLanguage,
+    schema: &Schema,
+    options: &CodegenOptions,
+    map_type: impl Fn(&DataType) -> String,
+) -> Vec<CodegenWarning> {
+    let mut fields: Vec<(&String, &DataType)> = schema.fields.iter().collect();
+    fields.sort_by_key(|(field, _)| *field);
+    fields
+        .into_iter()
+        .filter_map(|(field, data_type)| {
+            Some(CodegenWarning {
+                loss: type_loss(language, data_type, options)?,
+                field: field.clone(),
+                data_type: data_type.clone(),
+                target_type: map_type(data_type),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CodeGenerator;
+    use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_lossy_type_warnings() {
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "price".to_string(),
+        });
+        let mut schema = Schema::new("TR-TYPES".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, None);
+        schema.add_field("count".to_string(), DataType::Uint32, None);
+        schema.add_field("price".to_string(), DataType::Decimal, None);
+        let warnings = |language| CodeGenerator.generate_with_schema(&compound, &schema, language).unwrap().warnings;
+
+        let typescript = warnings(TargetLanguage::TypeScript);
+        assert_eq!(
+            typescript.iter().map(|w| (w.field.as_str(), w.target_type.as_str(), w.loss)).collect::<Vec<_>>(),
+            vec![("balance", "number", TypeLoss::Precision), ("price", "number", TypeLoss::Precision)]
+        );
+        assert_eq!(
+            typescript[0].to_string(),
+            "`balance` (Uint64) is generated as `number`, which rounds some of its values"
+        );
+        let solidity = warnings(TargetLanguage::Solidity);
+        assert_eq!((solidity[0].target_type.as_str(), solidity[0].loss), ("uint256", TypeLoss::Widened));
+        assert!(warnings(TargetLanguage::Python).is_empty());
+
+        let bigint = CodegenOptions { bigint: true, ..CodegenOptions::default() };
+        assert_eq!(type_loss(TargetLanguage::TypeScript, &DataType::Uint64, &bigint), None);
+        let list = DataType::List(Box::new(DataType::Int64));
+        assert_eq!(type_loss(TargetLanguage::SparkAda, &list, &bigint), Some(TypeLoss::Narrowed));
+    }
+}
@@ -0,0 +1,469 @@
+//! WIT Strategy (WebAssembly Component Model)
+//!
+//! The main artifact is a WIT package exporting a `policy` interface whose
+//! single function takes a `validation-params` record and returns whether the
+//! intent holds. Companion files provide a `cargo component` crate
+//! implementing that interface, so the validator can run as a sandboxed
+//! module inside any component host (Envoy, wasmCloud, wasmtime).
+
+use super::literal::{self, Operand};
+use super::{
+    collect_variables, default_safe_compare, is_identifier, rust_call, rust_conditional, rust_distinct,
+    CodegenOptions, CodegenStrategy, GeneratedFile, Provenance, VerifiableStrategy,
+};
+use crucible_core::{
+    ArithmeticOperator, Bitwise, Call, CompoundConstraint, Conditional, ConstraintOperator, DataType, MathFunction,
+    Schema,
+};
+
+/// Package used when `CodegenOptions::package_name` is not set
+const DEFAULT_PACKAGE: &str = "crucible:validator";
+
+/// Path at which `CodegenOutput::code` belongs inside the generated component crate
+pub const WIT_PATH: &str = "wit/validator.wit";
+
+pub(crate) struct WitStrategy {
+    package: String,
+}
+
+/// WIT identifiers are kebab-case: `validate_intent` -> `validate-intent`
+fn kebab_case(name: &str) -> String {
+    name.to_lowercase().replace('_', "-")
+}
+
+impl WitStrategy {
+    pub(crate) fn new(options: &CodegenOptions) -> Self {
+        let package = match &options.package_name {
+            Some(name) if name.contains(':') => name.clone(),
+            Some(name) => format!("crucible:{}", kebab_case(name)),
+            None => DEFAULT_PACKAGE.to_string(),
+        };
+        Self { package }
+    }
+
+    /// A field reference reads from the `params` record, text becomes a
+    /// string literal and other literals stay as written
+    fn param_value(&self, value: &str) -> String {
+        if let Some(conditional) = Conditional::parse(value) {
+            return ParamsRecord(self).format_conditional_expression(&conditional).unwrap_or_default();
+        }
+        if let Some(call) = Call::parse(value) {
+            return ParamsRecord(self).format_call_expression(&call);
+        }
+        match literal::operand(value) {
+            Operand::Text(text) => self.format_string(&text),
+            Operand::Code(code) if is_identifier(code) => format!("params.{}", code),
+            Operand::Code(code) => code.to_string(),
+        }
+    }
+
+    /// Rust expression evaluated by the component; field references on both
+    /// sides of a comparison read from the `params` record
+    fn rust_expression(&self, compound: &CompoundConstraint) -> String {
+        match compound {
+            CompoundConstraint::Simple(c) if c.operator.is_membership() => {
+                let test = format!("params.{}.contains({})", c.left_variable, self.param_value(&c.right_value));
+                match c.operator {
+                    ConstraintOperator::DoesNotContain => format!("!{}", test),
+                    _ => test,
+                }
+            }
+            CompoundConstraint::Simple(c) => {
+                let right = self.param_value(&c.right_value);
+                let left = match c.modulo() {
+                    Some(m) => format!("params.{}.rem_euclid({})", m.dividend, self.param_value(&m.divisor)),
+                    None if c.bitwise().is_some() => ParamsRecord(self).format_left_operand(c),
+                    None => match Call::parse(&c.left_variable) {
+                        Some(call) => ParamsRecord(self).format_call_expression(&call),
+                        None => format!("params.{}", c.left_variable),
+                    },
+                };
+                format!("{} {} {}", left, self.format_operator(&c.operator), right)
+            }
+            CompoundConstraint::And(constraints) if constraints.is_empty() => "true".to_string(),
+            CompoundConstraint::Or(constraints) if constraints.is_empty() => "false".to_string(),
+            CompoundConstraint::And(constraints) => {
+                let parts: Vec<String> = constraints.iter().map(|c| self.rust_expression(c)).collect();
+                format!("({})", parts.join(" && "))
+            }
+            CompoundConstraint::Or(constraints) => {
+                let parts: Vec<String> = constraints.iter().map(|c| self.rust_expression(c)).collect();
+                format!("({})", parts.join(" || "))
+            }
+            CompoundConstraint::Not(inner) => format!("!({})", self.rust_expression(inner)),
+            CompoundConstraint::Named(name) => format!("{}(&params)", name),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                self.format_implication(&self.rust_expression(antecedent), &self.rust_expression(consequent))
+            }
+            CompoundConstraint::ExactlyOne(parts) | CompoundConstraint::AtMostOne(parts) => {
+                let rendered: Vec<String> = parts.iter().map(|c| self.rust_expression(c)).collect();
+                self.format_cardinality(&rendered, matches!(compound, CompoundConstraint::ExactlyOne(_)))
+            }
+            CompoundConstraint::Distinct(fields) => {
+                let values: Vec<String> = fields.iter().map(|field| format!("params.{}", field)).collect();
+                self.format_distinct(&values).unwrap_or_default()
+            }
+        }
+    }
+
+    /// Record fields for the WIT `validation-params` type
+    fn record_fields(&self, fields: &[(String, String)]) -> String {
+        fields
+            .iter()
+            .map(|(name, ty)| format!("        {}: {},", kebab_case(name), ty))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Renders fields as reads from the `params` record, for calls, masks and
+/// conditionals in the component body
+struct ParamsRecord<'a>(&'a WitStrategy);
+
+impl CodegenStrategy for ParamsRecord<'_> {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.0.wrap_in_function(body, func_name)
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        self.0.format_operator(op)
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        format!("params.{}", name)
+    }
+
+    fn format_modulo(&self, dividend: &str, divisor: &str) -> String {
+        format!("{}.rem_euclid({})", dividend, divisor)
+    }
+
+    fn format_bitwise(&self, bitwise: &Bitwise, operand: &str, mask: &str) -> String {
+        self.0.format_bitwise(bitwise, operand, mask)
+    }
+
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        self.0.format_call(function, args)
+    }
+
+    fn supports_lists(&self) -> bool {
+        self.0.supports_lists()
+    }
+
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        self.0.format_distinct(values)
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        self.0.format_conditional(condition, then_value, else_value)
+    }
+
+    fn format_right_operand(&self, value: &str) -> String {
+        self.0.param_value(value)
+    }
+
+    fn logical_and(&self) -> &'static str {
+        self.0.logical_and()
+    }
+
+    fn logical_or(&self) -> &'static str {
+        self.0.logical_or()
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        self.0.logical_not(expr)
+    }
+
+    fn wrap_verified_function(&self, func_name: &str, contracts: &str, body: &str, assertions: &str) -> String {
+        self.0.wrap_verified_function(func_name, contracts, body, assertions)
+    }
+}
+
+impl CodegenStrategy for WitStrategy {
+    fn wrap_in_function(&self, body: &str, func_name: &str) -> String {
+        self.wrap_verified_function(func_name, "", body, "")
+    }
+
+    fn format_operator(&self, op: &ConstraintOperator) -> &'static str {
+        match op {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "==",
+            ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "contains",
+            ConstraintOperator::DoesNotContain => "contains",
+        }
+    }
+
+    fn format_variable(&self, name: &str) -> String {
+        name.to_string()
+    }
+
+    /// The component body is Rust
+    fn format_string(&self, text: &str) -> String {
+        literal::rust_string(text)
+    }
+
+    fn format_call(&self, function: MathFunction, args: &[String]) -> String {
+        rust_call(function, args)
+    }
+
+    fn supports_lists(&self) -> bool {
+        true
+    }
+
+    fn format_distinct(&self, values: &[String]) -> Option<String> {
+        rust_distinct(values, false)
+    }
+
+    fn format_conditional(&self, condition: &str, then_value: &str, else_value: &str) -> Option<String> {
+        Some(rust_conditional(condition, then_value, else_value))
+    }
+
+    // Only used for the `// Intent:` summary line; the component body is built by `rust_expression`
+    fn logical_and(&self) -> &'static str {
+        "&&"
+    }
+
+    fn logical_or(&self) -> &'static str {
+        "||"
+    }
+
+    fn logical_not(&self, expr: &str) -> String {
+        format!("!({})", expr)
+    }
+
+    fn inline_comment(&self, text: &str) -> Option<String> {
+        Some(format!("/* {} */", text))
+    }
+
+    fn format_count(&self, parts: &[String]) -> Option<String> {
+        Some(format!("[{}].iter().filter(|&&held| held).count()", parts.join(", ")))
+    }
+
+    /// Without a Schema, every referenced field is declared as a signed 64-bit integer
+    fn emit_contracts(&self, compound: &CompoundConstraint) -> Option<String> {
+        let fields: Vec<(String, String)> = collect_variables(compound)
+            .into_iter()
+            .filter(|v| is_identifier(v))
+            .map(|v| (v, "s64".to_string()))
+            .collect();
+        Some(self.record_fields(&fields))
+    }
+
+    fn wrap_verified_function(
+        &self,
+        func_name: &str,
+        contracts: &str,
+        body: &str,
+        _assertions: &str,
+    ) -> String {
+        format!(
+            r#"// WIT Generated Code - WebAssembly Component Validator
+// Intent: {body}
+
+package {package};
+
+interface policy {{
+    record validation-params {{
+{fields}
+    }}
+
+    /// Returns true iff the parameters satisfy the verified intent
+    {func}: func(params: validation-params) -> bool;
+}}
+
+world validator {{
+    export policy;
+}}
+"#,
+            body = body,
+            package = self.package,
+            fields = contracts,
+            func = kebab_case(func_name)
+        )
+    }
+
+    fn companion_files(&self, compound: &CompoundConstraint, func_name: &str) -> Vec<GeneratedFile> {
+        let (namespace, name) = self.package.split_once(':').unwrap_or(("crucible", "validator"));
+        let crate_name = format!("{}-{}", namespace, name);
+
+        let manifest = format!(
+            r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+wit-bindgen = "0.36"
+
+[package.metadata.component]
+package = "{package}"
+"#,
+            crate_name = crate_name,
+            package = self.package
+        );
+
+        let module_path = format!(
+            "{}::{}",
+            kebab_case(namespace).replace('-', "_"),
+            kebab_case(name).replace('-', "_")
+        );
+        let implementation = format!(
+            r#"//! Rust Generated Code - WebAssembly Component Validator
+//! Build with `cargo component build --release`
+
+wit_bindgen::generate!({{
+    world: "validator",
+    path: "wit",
+}});
+
+use exports::{module_path}::policy::{{Guest, ValidationParams}};
+
+struct Component;
+
+impl Guest for Component {{
+    fn {func_name}(params: ValidationParams) -> bool {{
+        {body}
+    }}
+}}
+
+export!(Component);
+"#,
+            module_path = module_path,
+            func_name = func_name,
+            body = self.rust_expression(compound)
+        );
+
+        vec![
+            GeneratedFile {
+                path: "Cargo.toml".to_string(),
+                contents: manifest,
+            },
+            GeneratedFile {
+                path: "src/lib.rs".to_string(),
+                contents: implementation,
+            },
+        ]
+    }
+}
+
+impl VerifiableStrategy for WitStrategy {
+    fn map_type(&self, dt: &DataType) -> String {
+        match dt {
+            DataType::Uint64 => "u64".to_string(),
+            DataType::Uint32 => "u32".to_string(),
+            DataType::Int64 => "s64".to_string(),
+            DataType::Int32 => "s32".to_string(),
+            DataType::String => "string".to_string(),
+            DataType::Bool => "bool".to_string(),
+            DataType::Decimal => "f64".to_string(),
+            DataType::List(element) => format!("list<{}>", self.map_type(element)),
+            // WIT has no refinement types; ranges are enforced by the intent itself
+            DataType::Custom { range_min: Some(min), .. } if *min >= 0 => "u64".to_string(),
+            DataType::Custom { .. } => "s64".to_string(),
+        }
+    }
+
+    fn emit_postcondition(&self, expression: &str, _schema: &Schema) -> String {
+        format!("// Post-condition: Returns true iff ({})", expression)
+    }
+
+    fn safe_op(&self, left: &str, op: ArithmeticOperator, right: &str, _schema: &Schema) -> String {
+        match op {
+            ArithmeticOperator::Subtract => format!("{}.checked_sub({}).unwrap_or(0)", left, right),
+            ArithmeticOperator::Add => format!("{}.checked_add({}).unwrap_or(0)", left, right),
+            ArithmeticOperator::Multiply => format!("{}.checked_mul({}).unwrap_or(0)", left, right),
+            ArithmeticOperator::Divide => format!("{}{}{}", left, op.rust_symbol(), right),
+            ArithmeticOperator::Modulo => format!("{}.rem_euclid({})", left, right),
+        }
+    }
+
+    /// Record fields for `validation-params`, in place of the inferred ones
+    fn build_signature(&self, _func_name: &str, schema: &Schema) -> String {
+        let mut names: Vec<&String> = schema.fields.keys().collect();
+        names.sort();
+
+        let fields: Vec<(String, String)> = names
+            .iter()
+            .map(|name| ((*name).clone(), self.map_type(&schema.fields[*name])))
+            .collect();
+        self.record_fields(&fields)
+    }
+
+    fn fn_end(&self) -> String {
+        "}".to_string()
+    }
+
+    fn license_header(&self, provenance: &Provenance) -> String {
+        provenance.banner("//", &["WIT Generated Code - WebAssembly Component Validator (v0.1.5-alpha)"])
+    }
+
+    fn safe_compare(&self, left: &str, op: &ConstraintOperator, right: &str, data_type: &DataType) -> String {
+        default_safe_compare(left, op, right, data_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CodeGenerator, TargetLanguage};
+    use crucible_core::Constraint;
+
+    fn withdraw() -> CompoundConstraint {
+        CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "account_balance".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "amount".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "0".to_string(),
+            }),
+        ])
+    }
+
+    #[test]
+    fn test_wit_interface_and_component() {
+        let output = CodeGenerator.generate(&withdraw(), TargetLanguage::Wit).unwrap();
+        assert!(output.code.contains("package crucible:validator;"));
+        assert!(output.code.contains("        account-balance: s64,\n        amount: s64,"));
+        assert!(output
+            .code
+            .contains("validate-intent: func(params: validation-params) -> bool;"));
+
+        let paths: Vec<&str> = output.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["Cargo.toml", "src/lib.rs"]);
+        assert!(output.files[0].contents.contains("package = \"crucible:validator\""));
+        let implementation = &output.files[1].contents;
+        assert!(implementation.contains("use exports::crucible::validator::policy::{Guest, ValidationParams};"));
+        assert!(implementation.contains("(params.account_balance >= params.amount && params.amount > 0)"));
+    }
+
+    #[test]
+    fn test_wit_schema_types_and_package() {
+        let mut schema = Schema::new("wit-001".to_string());
+        schema.add_field("account_balance".to_string(), DataType::Uint64, None);
+        schema.add_field("amount".to_string(), DataType::Uint32, None);
+
+        let options = CodegenOptions {
+            func_name: "allow_withdrawal".to_string(),
+            package_name: Some("acme:payments".to_string()),
+            ..Default::default()
+        };
+        let output = CodeGenerator
+            .generate_with_schema_and_options(&withdraw(), &schema, TargetLanguage::Wit, &options)
+            .unwrap();
+        assert!(output.code.contains("Traceability ID: wit-001"));
+        assert!(output.code.contains("package acme:payments;"));
+        assert!(output.code.contains("account-balance: u64,\n        amount: u32,"));
+        assert!(output.code.contains("allow-withdrawal: func("));
+        assert!(output.files[1].contents.contains("fn allow_withdrawal(params: ValidationParams) -> bool"));
+    }
+}
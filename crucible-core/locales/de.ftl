@@ -0,0 +1,107 @@
+# Crucible Engine messages, German
+
+## Diagnostics, by stage and error code
+
+stage-parse = Syntaxfehler
+stage-schema = Schemafehler
+stage-verification = Verifikationsfehler
+stage-codegen = Codegenerierungsfehler
+position = Zeile { $line }, Spalte { $column }
+
+P001 = { $problem ->
+        [expected] { $token } erwartet
+       *[unexpected] Unerwartetes `{ $token }`
+    }
+P002 = Interner Parserfehler: { $detail }
+P003 = Ungültiges JSON: { $detail }
+
+S001 = `{ $name }` in `{ $constraint }` ist kein Feld des Schemas
+S002 = `{ $constraint }` vergleicht { $left ->
+        [number] eine Zahl
+        [string] eine Zeichenkette
+       *[boolean] einen Wahrheitswert
+    } mit { $right ->
+        [number] einer Zahl
+        [string] einer Zeichenkette
+       *[boolean] einem Wahrheitswert
+    }
+S003 = leere { $group }-Gruppe hat nichts zu verknüpfen
+S004 = Bedingungen sind tiefer als { $limit } Ebenen verschachtelt
+S005 = { $problem ->
+        [undefined] Prädikat `{ $name }` ist nicht definiert
+        [duplicate] Prädikat `{ $name }` ist bereits definiert
+       *[cyclic] Prädikat `{ $name }` verweist über { $path } auf sich selbst
+    }
+S006 = { $problem ->
+        [duplicate] `{ $variable }'` wird mehr als einmal zugewiesen
+        [primed] die Aktualisierung von `{ $variable }'` liest die Nachzustandsvariable `{ $operand }`
+       *[expression] Aktualisierungsausdruck `{ $expression }` ist nicht lesbar
+    }
+S007 = { $problem ->
+        [integer] `{ $name }` in `{ $constraint }` ist kein ganzzahliges Feld und hat daher keine Bits
+       *[width] `{ $value }` in `{ $constraint }` passt nicht in das { $bits }-Bit-Feld `{ $name }`
+    }
+
+V001 = Widersprüchliche Bedingungen: { $detail }
+V002 = Fehler des Z3-Solvers: { $detail }
+V003 = Bedingung nicht übersetzbar: { $detail }
+
+G001 = Nicht unterstützte Zielsprache: { $language }
+G002 = Sprache { $language } verlangt formale Verträge, die fehlen
+G003 = { $problem ->
+        [collision] die Felder `{ $first }` und `{ $second }` werden in { $language } beide zu `{ $name }`; eines im Schema umbenennen
+       *[invalid] `{ $name }` ist kein gültiger Bezeichner in { $language }: { $reason }
+    }
+G004 = Generierungsfehler: { $detail }
+G005 = E/A-Fehler: { $detail }
+
+## Verbalizer: constraint trees as sentences
+
+verbalize-requirement = { $subject } { $modal } { $action } nur, wenn { $condition }.
+verbalize-field = { $name }
+verbalize-zero = null
+verbalize-and = und
+verbalize-or = oder
+verbalize-both = sowohl { $clause }
+verbalize-either = entweder { $clause }
+verbalize-not = es trifft nicht zu, dass { $clause }
+verbalize-implies = wenn { $antecedent }, dann { $consequent }
+verbalize-exactly-one = genau eines von: { $clauses }
+verbalize-at-most-one = höchstens eines von: { $clauses }
+verbalize-distinct = { $values } sind alle verschieden
+verbalize-unique = { $list } sind eindeutig
+verbalize-flag = das Flag { $flag } ist { $state ->
+        [set] gesetzt
+       *[unset] nicht gesetzt
+    }
+verbalize-multiple = { $subject } ist ein Vielfaches von { $divisor }
+verbalize-not-multiple = { $subject } ist kein Vielfaches von { $divisor }
+verbalize-remainder = der Rest von { $dividend } geteilt durch { $divisor }
+verbalize-bit = Bit { $bit } von { $subject } ist { $state ->
+        [set] gesetzt
+       *[clear] nicht gesetzt
+    }
+verbalize-bitwise = { $operator ->
+        [and] { $operand } maskiert mit { $mask }
+        [or] { $operand } mit den gesetzten Bits von { $mask }
+        [xor] { $operand } mit den umgekehrten Bits von { $mask }
+        [left] { $operand } um { $mask } Bits nach links verschoben
+       *[right] { $operand } um { $mask } Bits nach rechts verschoben
+    }
+verbalize-min = das Minimum von { $values }
+verbalize-max = das Maximum von { $values }
+verbalize-abs = der Betrag von { $value }
+verbalize-sum = die Summe von { $value }
+verbalize-count = die Anzahl von { $value }
+verbalize-average = der Durchschnitt von { $value }
+verbalize-conditional = { $then }, falls { $condition }, sonst { $otherwise }
+verbalize-at-least = { $subject } ist mindestens { $value }
+verbalize-at-most = { $subject } ist höchstens { $value }
+verbalize-greater = { $subject } ist größer als { $value }
+verbalize-less = { $subject } ist kleiner als { $value }
+verbalize-equal = { $subject } ist { $value }
+verbalize-not-equal = { $subject } ist nicht { $value }
+verbalize-contains = { $subject } enthält { $value }
+verbalize-not-contains = { $subject } enthält nicht { $value }
+verbalize-between = { $subject } liegt zwischen { $low } und { $high }
+verbalize-strictly-between = { $subject } liegt echt zwischen { $low } und { $high }
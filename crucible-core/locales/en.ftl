@@ -0,0 +1,107 @@
+# Crucible Engine messages, English (the fallback for every other locale)
+
+## Diagnostics, by stage and error code
+
+stage-parse = parse error
+stage-schema = schema error
+stage-verification = verification error
+stage-codegen = codegen error
+position = line { $line }, column { $column }
+
+P001 = { $problem ->
+        [expected] Expected { $token }
+       *[unexpected] Unexpected `{ $token }`
+    }
+P002 = { $detail }
+P003 = { $detail }
+
+S001 = `{ $name }` in `{ $constraint }` is not a field of the Schema
+S002 = `{ $constraint }` compares { $left ->
+        [number] a number
+        [string] a string
+       *[boolean] a boolean
+    } with { $right ->
+        [number] a number
+        [string] a string
+       *[boolean] a boolean
+    }
+S003 = empty { $group } group has nothing to combine
+S004 = constraints nest more than { $limit } levels deep
+S005 = { $problem ->
+        [undefined] undefined predicate `{ $name }`
+        [duplicate] predicate `{ $name }` is already defined
+       *[cyclic] predicate `{ $name }` refers to itself through { $path }
+    }
+S006 = { $problem ->
+        [duplicate] `{ $variable }'` is assigned more than once
+        [primed] the update of `{ $variable }'` reads the post-state variable `{ $operand }`
+       *[expression] cannot parse update expression `{ $expression }`
+    }
+S007 = { $problem ->
+        [integer] `{ $name }` in `{ $constraint }` is not an integer field, so it has no bits
+       *[width] `{ $value }` in `{ $constraint }` does not fit the { $bits }-bit field `{ $name }`
+    }
+
+V001 = Unsatisfiable constraints: { $detail }
+V002 = Z3 solver error: { $detail }
+V003 = Constraint translation error: { $detail }
+
+G001 = Unsupported target language: { $language }
+G002 = Language { $language } requires formal contracts not provided
+G003 = { $problem ->
+        [collision] fields `{ $first }` and `{ $second }` both become `{ $name }` in { $language }; rename one in the Schema
+       *[invalid] `{ $name }` is not a valid identifier in { $language }: { $reason }
+    }
+G004 = Generation error: { $detail }
+G005 = I/O error: { $detail }
+
+## Verbalizer: constraint trees as sentences
+
+verbalize-requirement = { $subject } { $modal } { $action } only if { $condition }.
+verbalize-field = the { $name }
+verbalize-zero = zero
+verbalize-and = and
+verbalize-or = or
+verbalize-both = both { $clause }
+verbalize-either = either { $clause }
+verbalize-not = it is not the case that { $clause }
+verbalize-implies = if { $antecedent }, then { $consequent }
+verbalize-exactly-one = exactly one of { $clauses }
+verbalize-at-most-one = at most one of { $clauses }
+verbalize-distinct = { $values } are all different
+verbalize-unique = { $list } are unique
+verbalize-flag = the { $flag } flag is { $state ->
+        [set] set
+       *[unset] not set
+    }
+verbalize-multiple = { $subject } is a multiple of { $divisor }
+verbalize-not-multiple = { $subject } is not a multiple of { $divisor }
+verbalize-remainder = the remainder of { $dividend } divided by { $divisor }
+verbalize-bit = bit { $bit } of { $subject } is { $state ->
+        [set] set
+       *[clear] clear
+    }
+verbalize-bitwise = { $operator ->
+        [and] { $operand } masked with { $mask }
+        [or] { $operand } with the bits of { $mask } set
+        [xor] { $operand } with the bits of { $mask } flipped
+        [left] { $operand } shifted left by { $mask } bits
+       *[right] { $operand } shifted right by { $mask } bits
+    }
+verbalize-min = the lesser of { $values }
+verbalize-max = the greater of { $values }
+verbalize-abs = the absolute value of { $value }
+verbalize-sum = the sum of { $value }
+verbalize-count = the number of { $value }
+verbalize-average = the average of { $value }
+verbalize-conditional = { $then } if { $condition }, otherwise { $otherwise }
+verbalize-at-least = { $subject } is at least { $value }
+verbalize-at-most = { $subject } is at most { $value }
+verbalize-greater = { $subject } is greater than { $value }
+verbalize-less = { $subject } is less than { $value }
+verbalize-equal = { $subject } is { $value }
+verbalize-not-equal = { $subject } is not { $value }
+verbalize-contains = { $subject } contains { $value }
+verbalize-not-contains = { $subject } does not contain { $value }
+verbalize-between = { $subject } is between { $low } and { $high }
+verbalize-strictly-between = { $subject } is strictly between { $low } and { $high }
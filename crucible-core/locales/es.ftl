@@ -0,0 +1,107 @@
+# Crucible Engine messages, Spanish
+
+## Diagnostics, by stage and error code
+
+stage-parse = error de sintaxis
+stage-schema = error de esquema
+stage-verification = error de verificación
+stage-codegen = error de generación de código
+position = línea { $line }, columna { $column }
+
+P001 = { $problem ->
+        [expected] Se esperaba { $token }
+       *[unexpected] `{ $token }` inesperado
+    }
+P002 = Error interno del analizador: { $detail }
+P003 = JSON no válido: { $detail }
+
+S001 = `{ $name }` en `{ $constraint }` no es un campo del esquema
+S002 = `{ $constraint }` compara { $left ->
+        [number] un número
+        [string] una cadena
+       *[boolean] un booleano
+    } con { $right ->
+        [number] un número
+        [string] una cadena
+       *[boolean] un booleano
+    }
+S003 = el grupo { $group } vacío no tiene nada que combinar
+S004 = las restricciones se anidan más de { $limit } niveles
+S005 = { $problem ->
+        [undefined] predicado `{ $name }` no definido
+        [duplicate] el predicado `{ $name }` ya está definido
+       *[cyclic] el predicado `{ $name }` se refiere a sí mismo a través de { $path }
+    }
+S006 = { $problem ->
+        [duplicate] `{ $variable }'` se asigna más de una vez
+        [primed] la actualización de `{ $variable }'` lee la variable de post-estado `{ $operand }`
+       *[expression] no se puede analizar la expresión de actualización `{ $expression }`
+    }
+S007 = { $problem ->
+        [integer] `{ $name }` en `{ $constraint }` no es un campo entero, así que no tiene bits
+       *[width] `{ $value }` en `{ $constraint }` no cabe en el campo de { $bits } bits `{ $name }`
+    }
+
+V001 = Restricciones insatisfacibles: { $detail }
+V002 = Error del solucionador Z3: { $detail }
+V003 = Error al traducir la restricción: { $detail }
+
+G001 = Lenguaje de destino no compatible: { $language }
+G002 = El lenguaje { $language } requiere contratos formales que faltan
+G003 = { $problem ->
+        [collision] los campos `{ $first }` y `{ $second }` se convierten ambos en `{ $name }` en { $language }; renombre uno en el esquema
+       *[invalid] `{ $name }` no es un identificador válido en { $language }: { $reason }
+    }
+G004 = Error de generación: { $detail }
+G005 = Error de E/S: { $detail }
+
+## Verbalizer: constraint trees as sentences
+
+verbalize-requirement = { $subject } { $modal } { $action } solo si { $condition }.
+verbalize-field = { $name }
+verbalize-zero = cero
+verbalize-and = y
+verbalize-or = o
+verbalize-both = a la vez { $clause }
+verbalize-either = o bien { $clause }
+verbalize-not = no es cierto que { $clause }
+verbalize-implies = si { $antecedent }, entonces { $consequent }
+verbalize-exactly-one = exactamente uno de: { $clauses }
+verbalize-at-most-one = como máximo uno de: { $clauses }
+verbalize-distinct = { $values } son todos distintos
+verbalize-unique = { $list } son únicos
+verbalize-flag = el indicador { $flag } { $state ->
+        [set] está activado
+       *[unset] no está activado
+    }
+verbalize-multiple = { $subject } es múltiplo de { $divisor }
+verbalize-not-multiple = { $subject } no es múltiplo de { $divisor }
+verbalize-remainder = el resto de { $dividend } dividido entre { $divisor }
+verbalize-bit = el bit { $bit } de { $subject } está { $state ->
+        [set] activado
+       *[clear] desactivado
+    }
+verbalize-bitwise = { $operator ->
+        [and] { $operand } enmascarado con { $mask }
+        [or] { $operand } con los bits de { $mask } activados
+        [xor] { $operand } con los bits de { $mask } invertidos
+        [left] { $operand } desplazado { $mask } bits a la izquierda
+       *[right] { $operand } desplazado { $mask } bits a la derecha
+    }
+verbalize-min = el mínimo de { $values }
+verbalize-max = el máximo de { $values }
+verbalize-abs = el valor absoluto de { $value }
+verbalize-sum = la suma de { $value }
+verbalize-count = el número de { $value }
+verbalize-average = el promedio de { $value }
+verbalize-conditional = { $then } si { $condition }, de lo contrario { $otherwise }
+verbalize-at-least = { $subject } es al menos { $value }
+verbalize-at-most = { $subject } es como máximo { $value }
+verbalize-greater = { $subject } es mayor que { $value }
+verbalize-less = { $subject } es menor que { $value }
+verbalize-equal = { $subject } es { $value }
+verbalize-not-equal = { $subject } no es { $value }
+verbalize-contains = { $subject } contiene { $value }
+verbalize-not-contains = { $subject } no contiene { $value }
+verbalize-between = { $subject } está entre { $low } y { $high }
+verbalize-strictly-between = { $subject } está estrictamente entre { $low } y { $high }
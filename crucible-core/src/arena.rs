@@ -0,0 +1,377 @@
+//! Arena IR - compact, shared storage for large constraint trees
+//!
+//! `CompoundConstraint` is the serde-friendly form: every node owns its
+//! children and every comparison owns its variable names. Specs with tens of
+//! thousands of constraints repeat the same names and the same subtrees
+//! (one guard in front of every requirement) over and over, so walking them
+//! allocates and clones far more than it computes.
+//!
+//! `ConstraintArena` stores a tree as flat vectors instead:
+//!
+//! - every string is interned once and referred to by `Symbol`
+//! - every node lives in `nodes` and is referred to by `NodeId`
+//! - identical subtrees are stored once (hash-consing), so a pass that
+//!   memoizes by `NodeId` visits each distinct subtree once
+//!
+//! ```text
+//! And[Simple(a >= 0), Or[Simple(a >= 0), Named(p)]]
+//!
+//! nodes: 0 Simple(a >= 0)   1 Named(p)   2 Or[0, 1]   3 And[0, 2]
+//! ```
+//!
+//! Convert with `ConstraintArena::insert` / `to_compound`; the tree is the
+//! exchange format and the arena the working one.
+
+use crate::{Between, CompoundConstraint, Constraint, ConstraintOperator};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::mem::discriminant;
+use std::sync::Arc;
+
+/// Interned string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+/// Index of a node in its `ConstraintArena`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    /// Position in the arena, for side tables indexed by node
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// The children of an n-ary node, a range of the arena's edge list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Children {
+    start: u32,
+    len: u32,
+}
+
+/// A comparison with interned operands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Leaf {
+    pub left: Symbol,
+    pub operator: ConstraintOperator,
+    pub right: Symbol,
+}
+
+/// One node; mirrors `CompoundConstraint` with indices in place of owned children
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Node {
+    And(Children),
+    Or(Children),
+    Not(NodeId),
+    Implies(NodeId, NodeId),
+    ExactlyOne(Children),
+    AtMostOne(Children),
+    Simple(Leaf),
+    Named(Symbol),
+    /// The fields, joined by `", "` into one string
+    Distinct(Symbol),
+}
+
+impl Node {
+    fn list(&self) -> Option<Children> {
+        match self {
+            Node::And(children) | Node::Or(children) | Node::ExactlyOne(children) | Node::AtMostOne(children) => {
+                Some(*children)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// String table; each distinct string is stored once
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    ids: HashMap<Arc<str>, Symbol>,
+    strings: Vec<Arc<str>>,
+}
+
+impl Interner {
+    pub fn intern(&mut self, value: &str) -> Symbol {
+        if let Some(&symbol) = self.ids.get(value) {
+            return symbol;
+        }
+        let symbol = Symbol(self.strings.len() as u32);
+        let value: Arc<str> = Arc::from(value);
+        self.strings.push(value.clone());
+        self.ids.insert(value, symbol);
+        symbol
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.0 as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+/// Interned, hash-consed constraint trees
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintArena {
+    nodes: Vec<Node>,
+    edges: Vec<NodeId>,
+    strings: Interner,
+    /// Content hash -> nodes with that hash, to find an existing copy of a subtree
+    shared: HashMap<u64, Vec<NodeId>>,
+}
+
+impl ConstraintArena {
+    /// Create an empty arena
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An arena holding just `compound`, and its root
+    pub fn from_tree(compound: &CompoundConstraint) -> (Self, NodeId) {
+        let mut arena = Self::new();
+        let root = arena.insert(compound);
+        (arena, root)
+    }
+
+    /// Add a tree, reusing any subtree the arena already holds
+    pub fn insert(&mut self, compound: &CompoundConstraint) -> NodeId {
+        let list = |arena: &mut Self, parts: &[CompoundConstraint], node: fn(Children) -> Node| {
+            let ids: Vec<NodeId> = parts.iter().map(|part| arena.insert(part)).collect();
+            arena.share(node(Children { start: 0, len: 0 }), &ids)
+        };
+        match compound {
+            CompoundConstraint::And(parts) => list(self, parts, Node::And),
+            CompoundConstraint::Or(parts) => list(self, parts, Node::Or),
+            CompoundConstraint::ExactlyOne(parts) => list(self, parts, Node::ExactlyOne),
+            CompoundConstraint::AtMostOne(parts) => list(self, parts, Node::AtMostOne),
+            CompoundConstraint::Not(inner) => {
+                let inner = self.insert(inner);
+                self.share(Node::Not(inner), &[])
+            }
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                let antecedent = self.insert(antecedent);
+                let consequent = self.insert(consequent);
+                self.share(Node::Implies(antecedent, consequent), &[])
+            }
+            CompoundConstraint::Simple(constraint) => {
+                let leaf = Leaf {
+                    left: self.strings.intern(&constraint.left_variable),
+                    operator: constraint.operator,
+                    right: self.strings.intern(&constraint.right_value),
+                };
+                self.share(Node::Simple(leaf), &[])
+            }
+            CompoundConstraint::Named(name) => {
+                let name = self.strings.intern(name);
+                self.share(Node::Named(name), &[])
+            }
+            CompoundConstraint::Distinct(fields) => {
+                let fields = self.strings.intern(&fields.join(", "));
+                self.share(Node::Distinct(fields), &[])
+            }
+        }
+    }
+
+    /// The id of an existing identical node, or of `node` newly added.
+    /// List nodes are compared by kind and `children`; their span is assigned here
+    fn share(&mut self, node: Node, children: &[NodeId]) -> NodeId {
+        let mut hasher = DefaultHasher::new();
+        match node.list() {
+            Some(_) => (discriminant(&node), children).hash(&mut hasher),
+            None => node.hash(&mut hasher),
+        }
+        let hash = hasher.finish();
+
+        let same = |candidate: &NodeId| {
+            let existing = self.nodes[candidate.index()];
+            match existing.list() {
+                Some(list) => discriminant(&existing) == discriminant(&node) && self.children(list) == children,
+                None => existing == node,
+            }
+        };
+        if let Some(&id) = self.shared.get(&hash).and_then(|ids| ids.iter().find(|id| same(id))) {
+            return id;
+        }
+
+        let span = Children {
+            start: self.edges.len() as u32,
+            len: children.len() as u32,
+        };
+        self.edges.extend_from_slice(children);
+        let node = match node {
+            Node::And(_) => Node::And(span),
+            Node::Or(_) => Node::Or(span),
+            Node::ExactlyOne(_) => Node::ExactlyOne(span),
+            Node::AtMostOne(_) => Node::AtMostOne(span),
+            other => other,
+        };
+        let id = NodeId(self.nodes.len() as u32);
+        self.nodes.push(node);
+        self.shared.entry(hash).or_default().push(id);
+        id
+    }
+
+    pub fn node(&self, id: NodeId) -> Node {
+        self.nodes[id.index()]
+    }
+
+    pub fn children(&self, children: Children) -> &[NodeId] {
+        &self.edges[children.start as usize..(children.start + children.len) as usize]
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        self.strings.resolve(symbol)
+    }
+
+    /// Number of distinct nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Number of distinct strings
+    pub fn symbols(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// A leaf as an owned `Constraint`, for APIs that take one
+    pub fn constraint(&self, leaf: Leaf) -> Constraint {
+        Constraint {
+            left_variable: self.resolve(leaf.left).to_string(),
+            operator: leaf.operator,
+            right_value: self.resolve(leaf.right).to_string(),
+        }
+    }
+
+    /// The fields of a `Distinct` node
+    pub fn fields(&self, fields: Symbol) -> Vec<String> {
+        self.resolve(fields).split(", ").map(|field| field.to_string()).collect()
+    }
+
+    /// The subtree at `id` as a `CompoundConstraint`
+    pub fn to_compound(&self, id: NodeId) -> CompoundConstraint {
+        let list = |children: Children| self.children(children).iter().map(|&c| self.to_compound(c)).collect();
+        match self.node(id) {
+            Node::And(children) => CompoundConstraint::And(list(children)),
+            Node::Or(children) => CompoundConstraint::Or(list(children)),
+            Node::ExactlyOne(children) => CompoundConstraint::ExactlyOne(list(children)),
+            Node::AtMostOne(children) => CompoundConstraint::AtMostOne(list(children)),
+            Node::Not(inner) => CompoundConstraint::Not(Box::new(self.to_compound(inner))),
+            Node::Implies(antecedent, consequent) => CompoundConstraint::Implies(
+                Box::new(self.to_compound(antecedent)),
+                Box::new(self.to_compound(consequent)),
+            ),
+            Node::Simple(leaf) => CompoundConstraint::Simple(self.constraint(leaf)),
+            Node::Named(name) => CompoundConstraint::Named(self.resolve(name).to_string()),
+            Node::Distinct(fields) => CompoundConstraint::Distinct(self.fields(fields)),
+        }
+    }
+
+    /// `CompoundConstraint::count_constraints` for the subtree at `id`
+    pub fn count_constraints(&self, id: NodeId) -> usize {
+        match self.node(id) {
+            Node::And(children) | Node::Or(children) | Node::ExactlyOne(children) | Node::AtMostOne(children) => {
+                self.children(children).iter().map(|&c| self.count_constraints(c)).sum()
+            }
+            Node::Not(inner) => self.count_constraints(inner),
+            Node::Implies(antecedent, consequent) => {
+                self.count_constraints(antecedent) + self.count_constraints(consequent)
+            }
+            Node::Simple(_) | Node::Named(_) | Node::Distinct(_) => 1,
+        }
+    }
+
+    /// `CompoundConstraint::as_between` for the node at `id`
+    pub fn as_between(&self, id: NodeId) -> Option<Between> {
+        let Node::And(children) = self.node(id) else { return None };
+        let &[lower, upper] = self.children(children) else { return None };
+        let (Node::Simple(lower), Node::Simple(upper)) = (self.node(lower), self.node(upper)) else {
+            return None;
+        };
+        if lower.left != upper.left {
+            return None;
+        }
+        let inclusive = match (lower.operator, upper.operator) {
+            (ConstraintOperator::GreaterThanOrEqual, ConstraintOperator::LessThanOrEqual) => true,
+            (ConstraintOperator::GreaterThan, ConstraintOperator::LessThan) => false,
+            _ => return None,
+        };
+        Some(Between::new(
+            self.resolve(lower.left),
+            self.resolve(lower.right),
+            self.resolve(upper.right),
+            inclusive,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_round_trip_shares_subtrees() {
+        let guard = CompoundConstraint::And(vec![
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+        ]);
+        let tree = CompoundConstraint::Or(vec![
+            CompoundConstraint::Implies(Box::new(guard.clone()), Box::new(CompoundConstraint::Named("p".into()))),
+            CompoundConstraint::Not(Box::new(guard.clone())),
+            CompoundConstraint::ExactlyOne(vec![guard.clone(), CompoundConstraint::Named("p".into())]),
+        ]);
+
+        let (mut arena, root) = ConstraintArena::from_tree(&tree);
+        assert_eq!(arena.to_compound(root), tree);
+        assert_eq!(arena.count_constraints(root), tree.count_constraints());
+        // two leaves, the guard, `p`, implies, not, exactly-one, or
+        assert_eq!(arena.len(), 8);
+        assert_eq!(arena.symbols(), 4);
+
+        // The same tree again adds nothing
+        assert_eq!(arena.insert(&tree), root);
+        assert_eq!(arena.len(), 8);
+    }
+
+    #[test]
+    fn test_lists_compare_by_kind_and_children() {
+        let a = simple("a", ConstraintOperator::Equal, "1");
+        let b = simple("b", ConstraintOperator::Equal, "2");
+        let mut arena = ConstraintArena::new();
+        let and = arena.insert(&CompoundConstraint::And(vec![a.clone(), b.clone()]));
+        let or = arena.insert(&CompoundConstraint::Or(vec![a.clone(), b.clone()]));
+        let reversed = arena.insert(&CompoundConstraint::And(vec![b, a]));
+        assert_ne!(and, or);
+        assert_ne!(and, reversed);
+        assert_eq!(arena.len(), 5);
+    }
+
+    #[test]
+    fn test_as_between_matches_tree() {
+        let range = CompoundConstraint::And(vec![
+            simple("age", ConstraintOperator::GreaterThanOrEqual, "18"),
+            simple("age", ConstraintOperator::LessThanOrEqual, "65"),
+        ]);
+        let (arena, root) = ConstraintArena::from_tree(&range);
+        assert_eq!(arena.as_between(root), range.as_between());
+        assert!(arena.as_between(root).is_some());
+    }
+}
@@ -0,0 +1,210 @@
+//! Bit masks and shifts inside constraint expressions
+//!
+//! `flags & 0x8 != 0`, `mode & 0x0F == 2`, `granted | required == granted`:
+//! like a remainder, a bitwise term is the left-hand side of a comparison,
+//! kept as text in `Constraint::left_variable` and parsed on demand. The
+//! operand is an integer field; the mask is a field or an integer literal
+//! (decimal, `0x` hex or `0b` binary), and a shift amount is a literal.
+//!
+//! Values are two's-complement integers of their field's width
+//! (`BitWidth::of`): `&`, `|` and `^` stay within it, `>>` is arithmetic on
+//! signed fields and logical on unsigned ones, and `<<` drops the bits
+//! shifted past the top, so `x << 1` on a `Uint32` field is `x * 2 mod 2^32`
+//! in every target.
+
+use crate::DataType;
+use serde::{Deserialize, Serialize};
+
+/// An operator combining the bits of two integers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BitwiseOperator {
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BitwiseOperator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BitwiseOperator::And => "&",
+            BitwiseOperator::Or => "|",
+            BitwiseOperator::Xor => "^",
+            BitwiseOperator::ShiftLeft => "<<",
+            BitwiseOperator::ShiftRight => ">>",
+        }
+    }
+
+    /// Parse `&`, `|`, `^`, `<<` or `>>`
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "&" => Some(BitwiseOperator::And),
+            "|" => Some(BitwiseOperator::Or),
+            "^" => Some(BitwiseOperator::Xor),
+            "<<" => Some(BitwiseOperator::ShiftLeft),
+            ">>" => Some(BitwiseOperator::ShiftRight),
+            _ => None,
+        }
+    }
+
+    pub fn is_shift(&self) -> bool {
+        matches!(self, BitwiseOperator::ShiftLeft | BitwiseOperator::ShiftRight)
+    }
+}
+
+/// The width and signedness of an integer field's two's-complement bits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BitWidth {
+    pub bits: u32,
+    pub signed: bool,
+}
+
+impl BitWidth {
+    /// A field without a Schema type: a signed 64-bit integer
+    pub const DEFAULT: BitWidth = BitWidth { bits: 64, signed: true };
+
+    /// The width of an integer type; `None` for every other type
+    pub fn of(data_type: &DataType) -> Option<Self> {
+        match data_type {
+            DataType::Uint64 => Some(BitWidth {
+                bits: 64,
+                signed: false,
+            }),
+            DataType::Uint32 => Some(BitWidth {
+                bits: 32,
+                signed: false,
+            }),
+            DataType::Int64 => Some(BitWidth { bits: 64, signed: true }),
+            DataType::Int32 => Some(BitWidth { bits: 32, signed: true }),
+            _ => None,
+        }
+    }
+
+    pub fn min(&self) -> i128 {
+        match self.signed {
+            true => -(1i128 << (self.bits - 1)),
+            false => 0,
+        }
+    }
+
+    pub fn max(&self) -> i128 {
+        match self.signed {
+            true => (1i128 << (self.bits - 1)) - 1,
+            false => (1i128 << self.bits) - 1,
+        }
+    }
+
+    /// Whether `value` is a value of the width
+    pub fn contains(&self, value: i128) -> bool {
+        (self.min()..=self.max()).contains(&value)
+    }
+}
+
+/// `operand op mask`, written `"flags & 0x8"` in `Constraint::left_variable`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bitwise {
+    pub operand: String,
+    pub operator: BitwiseOperator,
+    /// The mask or shift amount as written, e.g. `0x0F`
+    pub mask: String,
+}
+
+impl Bitwise {
+    pub fn new(operand: &str, operator: BitwiseOperator, mask: &str) -> Self {
+        Self {
+            operand: operand.to_string(),
+            operator,
+            mask: mask.to_string(),
+        }
+    }
+
+    /// Parse `flags & 0x8`, `mode ^ expected` or `word >> 4`; `None` for
+    /// anything else, including a shift by a field
+    pub fn parse(text: &str) -> Option<Self> {
+        let [operand, op, mask] = text.split_whitespace().collect::<Vec<_>>()[..] else {
+            return None;
+        };
+        let operator = BitwiseOperator::from_symbol(op)?;
+        let mask_ok = match operator.is_shift() {
+            true => parse_integer(mask).is_some(),
+            false => parse_integer(mask).is_some() || names_field(mask),
+        };
+        (names_field(operand) && mask_ok).then(|| Self::new(operand, operator, mask))
+    }
+
+    /// The mask or shift amount when it is a literal
+    pub fn mask_value(&self) -> Option<i128> {
+        parse_integer(&self.mask)
+    }
+
+    /// The operand, then the mask when it names a field
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables = vec![self.operand.clone()];
+        if names_field(&self.mask) {
+            variables.push(self.mask.clone());
+        }
+        variables
+    }
+}
+
+impl std::fmt::Display for Bitwise {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.operand, self.operator.symbol(), self.mask)
+    }
+}
+
+/// An integer literal: decimal, `0x` hex or `0b` binary, optionally negative
+pub fn parse_integer(text: &str) -> Option<i128> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let magnitude = if let Some(hex) = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        i128::from_str_radix(hex, 16).ok()?
+    } else if let Some(binary) = digits.strip_prefix("0b").or_else(|| digits.strip_prefix("0B")) {
+        i128::from_str_radix(binary, 2).ok()?
+    } else if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()?
+    } else {
+        return None;
+    };
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+fn names_field(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+        && !matches!(text, "true" | "false")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bitwise() {
+        let mode = Bitwise::parse("mode & 0x0F").unwrap();
+        assert_eq!(mode, Bitwise::new("mode", BitwiseOperator::And, "0x0F"));
+        assert_eq!(
+            (mode.mask_value(), mode.to_string()),
+            (Some(15), "mode & 0x0F".to_string())
+        );
+        assert_eq!(
+            Bitwise::parse("granted | required").unwrap().variables(),
+            vec!["granted", "required"]
+        );
+        assert_eq!(Bitwise::parse("word >> 0b100").unwrap().mask_value(), Some(4));
+        assert_eq!(Bitwise::parse("word << shift"), None);
+        assert_eq!(Bitwise::parse("0x0F & mode"), None);
+        assert_eq!(Bitwise::parse("id % 10"), None);
+
+        let word = BitWidth::of(&DataType::Uint32).unwrap();
+        assert!(word.contains(0xFFFF_FFFF) && !word.contains(-1));
+        assert_eq!(
+            (BitWidth::DEFAULT.min(), BitWidth::DEFAULT.max()),
+            (i64::MIN as i128, i64::MAX as i128)
+        );
+        assert_eq!(BitWidth::of(&DataType::String), None);
+    }
+}
@@ -0,0 +1,198 @@
+//! `min`, `max` and `abs` inside constraint expressions
+//!
+//! A call stands wherever an operand can, on either side of a comparison:
+//! `fee == max(min_fee, amount * rate)`, `abs(delta) <= 5`. Like a remainder
+//! it is kept as text in `Constraint::left_variable` / `right_value` and
+//! parsed on demand. Each argument is a number, a field, `a op b`, or
+//! another call.
+//!
+//! `sum`, `count` and `average` aggregate a list field (`DataType::List`)
+//! instead: `total == sum(line_amounts)`, `count(approvers) >= 2`. Their one
+//! argument is the field itself.
+
+use crate::ArithmeticOperator;
+use serde::{Deserialize, Serialize};
+
+/// A function a constraint expression can call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MathFunction {
+    /// The least of two or more arguments
+    Min,
+    /// The greatest of two or more arguments
+    Max,
+    /// The absolute value of one argument
+    Abs,
+    /// The sum of a list's elements; 0 for an empty list
+    Sum,
+    /// The number of elements of a list
+    Count,
+    /// The mean of a list's elements, rounded down; 0 for an empty list
+    Average,
+}
+
+impl MathFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            MathFunction::Min => "min",
+            MathFunction::Max => "max",
+            MathFunction::Abs => "abs",
+            MathFunction::Sum => "sum",
+            MathFunction::Count => "count",
+            MathFunction::Average => "average",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "min" => Some(MathFunction::Min),
+            "max" => Some(MathFunction::Max),
+            "abs" => Some(MathFunction::Abs),
+            "sum" => Some(MathFunction::Sum),
+            "count" => Some(MathFunction::Count),
+            "average" => Some(MathFunction::Average),
+            _ => None,
+        }
+    }
+
+    /// Whether the function takes `count` arguments
+    pub fn accepts(&self, count: usize) -> bool {
+        match self {
+            MathFunction::Min | MathFunction::Max => count >= 2,
+            MathFunction::Abs | MathFunction::Sum | MathFunction::Count | MathFunction::Average => count == 1,
+        }
+    }
+
+    /// Whether the function reduces a list field rather than numbers
+    pub fn is_aggregate(&self) -> bool {
+        matches!(self, MathFunction::Sum | MathFunction::Count | MathFunction::Average)
+    }
+}
+
+/// `function(arg, ...)`, written `"max(min_fee, amount * rate)"` in a constraint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Call {
+    pub function: MathFunction,
+    /// Each argument as written, trimmed
+    pub args: Vec<String>,
+}
+
+impl Call {
+    pub fn new(function: MathFunction, args: &[&str]) -> Self {
+        Self {
+            function,
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+
+    /// Parse `max(a, b * 2)`, `abs(min(x, 0))` or `sum(items)`; `None` for
+    /// anything else, including a call with the wrong number of arguments and
+    /// an aggregate of anything but a field
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let (name, rest) = text.split_once('(')?;
+        let function = MathFunction::from_name(name.trim())?;
+        let inner = rest.strip_suffix(')')?;
+
+        // Split on the commas outside nested calls
+        let mut args = Vec::new();
+        let (mut depth, mut start) = (0usize, 0);
+        for (index, c) in inner.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.checked_sub(1)?,
+                ',' if depth == 0 => {
+                    args.push(inner[start..index].trim().to_string());
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return None;
+        }
+        args.push(inner[start..].trim().to_string());
+
+        let operands = match function.is_aggregate() {
+            true => args.iter().all(|arg| names_field(arg)),
+            false => args.iter().all(|arg| is_argument(arg)),
+        };
+        if !function.accepts(args.len()) || !operands {
+            return None;
+        }
+        Some(Self { function, args })
+    }
+
+    /// Fields the arguments read, in order, nested calls included
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables = Vec::new();
+        for arg in &self.args {
+            match Call::parse(arg) {
+                Some(call) => variables.extend(call.variables()),
+                None => variables.extend(
+                    arg.split_whitespace().filter(|token| names_field(token)).map(|token| token.to_string()),
+                ),
+            }
+        }
+        variables
+    }
+
+    /// The aggregates among the call and its arguments, outermost first
+    pub fn aggregates(&self) -> Vec<Call> {
+        if self.function.is_aggregate() {
+            return vec![self.clone()];
+        }
+        self.args.iter().filter_map(|arg| Call::parse(arg)).flat_map(|call| call.aggregates()).collect()
+    }
+}
+
+impl std::fmt::Display for Call {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.function.name(), self.args.join(", "))
+    }
+}
+
+/// A number, a field, `a op b` over those, or a call
+pub(crate) fn is_argument(arg: &str) -> bool {
+    if Call::parse(arg).is_some() {
+        return true;
+    }
+    let operand = |token: &str| token.parse::<f64>().is_ok() || names_field(token);
+    match arg.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [single] => operand(single),
+        [left, op, right] => operand(left) && ArithmeticOperator::from_symbol(op).is_some() && operand(right),
+        _ => false,
+    }
+}
+
+pub(crate) fn names_field(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && token.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '\''))
+        && !matches!(token, "true" | "false" | "mod")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_call() {
+        let call = Call::parse("max(min_fee,  amount * rate)").unwrap();
+        assert_eq!(call, Call::new(MathFunction::Max, &["min_fee", "amount * rate"]));
+        assert_eq!(call.to_string(), "max(min_fee, amount * rate)");
+        assert_eq!(call.variables(), ["min_fee", "amount", "rate"]);
+
+        let nested = Call::parse("abs(min(delta, limit - 1))").unwrap();
+        assert_eq!(nested.args, ["min(delta, limit - 1)"]);
+        assert_eq!(nested.variables(), ["delta", "limit"]);
+
+        let aggregate = Call::parse("max(sum(items), count(approvers), 1)").unwrap();
+        assert_eq!(aggregate.variables(), ["items", "approvers"]);
+        let aggregates: Vec<String> = aggregate.aggregates().iter().map(|call| call.to_string()).collect();
+        assert_eq!(aggregates, ["sum(items)", "count(approvers)"]);
+
+        let invalid = ["abs(a, b)", "min(a)", "max(a, b", "sqrt(a)", "max(a, \"b\")", "max(a, (b)", "amount"];
+        for text in invalid.into_iter().chain(["sum(a + b)", "count(3)"]) {
+            assert_eq!(Call::parse(text), None, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Components - assume-guarantee contracts at a service boundary
+//!
+//! Two teams each write intents for their side of an interface: the
+//! producer guarantees what it sends ("amount is at most 10000"), the
+//! consumer assumes what it receives ("assuming amount is positive"). A
+//! `ComponentContract` holds one side; the verifier's compatibility check
+//! proves that whatever the producer may send meets every consumer
+//! assumption, and names the assumptions it cannot prove.
+
+use crate::{CompoundConstraint, RequirementKind};
+use serde::{Deserialize, Serialize};
+
+/// One component's side of an interface
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentContract {
+    /// Component name, e.g. `payments`
+    pub name: String,
+    /// What the component relies on from its environment
+    pub assumptions: Vec<CompoundConstraint>,
+    /// What the component delivers when its assumptions hold
+    pub guarantees: Vec<CompoundConstraint>,
+}
+
+impl ComponentContract {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// A contract from classified requirements; invariants are guarantees
+    pub fn from_requirements<'a>(
+        name: &str,
+        requirements: impl IntoIterator<Item = (RequirementKind, &'a CompoundConstraint)>,
+    ) -> Self {
+        let mut contract = Self::new(name);
+        for (kind, tree) in requirements {
+            match kind {
+                RequirementKind::Assumption => contract.assume(tree.clone()),
+                RequirementKind::Guarantee | RequirementKind::Invariant => contract.guarantee(tree.clone()),
+            };
+        }
+        contract
+    }
+
+    pub fn assume(&mut self, tree: CompoundConstraint) -> &mut Self {
+        self.assumptions.push(tree);
+        self
+    }
+
+    pub fn guarantee(&mut self, tree: CompoundConstraint) -> &mut Self {
+        self.guarantees.push(tree);
+        self
+    }
+
+    /// Fields the contract mentions, sorted
+    pub fn fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self
+            .assumptions
+            .iter()
+            .chain(&self.guarantees)
+            .flat_map(|tree| tree.leaves())
+            .flat_map(|constraint| constraint.variables())
+            .collect();
+        fields.sort();
+        fields.dedup();
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_contract_from_requirements() {
+        let positive = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+        let capped = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::LessThanOrEqual,
+            right_value: "limit".to_string(),
+        });
+        let contract = ComponentContract::from_requirements(
+            "ledger",
+            [
+                (RequirementKind::Assumption, &positive),
+                (RequirementKind::Invariant, &capped),
+                (RequirementKind::Guarantee, &positive),
+            ],
+        );
+        assert_eq!(contract.guarantees, [capped, positive.clone()]);
+        assert_eq!(contract.assumptions, [positive]);
+        assert_eq!(contract.fields(), ["amount", "limit"]);
+    }
+}
@@ -0,0 +1,175 @@
+//! If-then-else values on the right of a comparison
+//!
+//! `fee == 0 if member else 5` compares `fee` with 0 when `member` holds and
+//! with 5 otherwise. Like a call, the value is kept as text in
+//! `Constraint::right_value` and parsed on demand. The condition is a
+//! comparison or a bare boolean field; each branch is an operand (number,
+//! string, field, `a op b` or call), and the `else` branch may be another
+//! conditional: `0 if member else 2 if tier >= 3 else 5`.
+
+use crate::call::{is_argument, names_field};
+use crate::{Bitwise, Call, CompoundConstraint, Constraint, ConstraintOperator, Modulo};
+use serde::{Deserialize, Serialize};
+
+/// `then_value if condition else else_value`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conditional {
+    /// A bare boolean field `member` is held as `member == true`
+    pub condition: Constraint,
+    pub then_value: String,
+    pub else_value: String,
+}
+
+impl Conditional {
+    pub fn new(condition: Constraint, then_value: &str, else_value: &str) -> Self {
+        Self { condition, then_value: then_value.to_string(), else_value: else_value.to_string() }
+    }
+
+    /// Parse `0 if member else 5` or `0 if tier >= 3 else 5`; `None` for
+    /// anything else
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let at_if = top_level(text, " if ")?;
+        let (then_value, rest) = (text[..at_if].trim(), &text[at_if + 4..]);
+        let at_else = top_level(rest, " else ")?;
+        let (condition, else_value) = (rest[..at_else].trim(), rest[at_else + 6..].trim());
+
+        if !is_value(then_value) || !(is_value(else_value) || Conditional::parse(else_value).is_some()) {
+            return None;
+        }
+        Some(Self::new(parse_condition(condition)?, then_value, else_value))
+    }
+
+    /// Fields the condition and both branches read, nested conditionals included
+    pub fn variables(&self) -> Vec<String> {
+        let mut variables = self.condition.variables();
+        variables.extend(value_variables(&self.then_value));
+        match Conditional::parse(&self.else_value) {
+            Some(nested) => variables.extend(nested.variables()),
+            None => variables.extend(value_variables(&self.else_value)),
+        }
+        variables
+    }
+
+    /// `comparison` (whose right value is this conditional) as two plain
+    /// cases, for targets without a conditional expression:
+    /// `(condition and left op then) or (not condition and left op else)`.
+    /// A nested conditional stays in the `else` case.
+    pub fn cases(&self, comparison: &Constraint) -> CompoundConstraint {
+        let branch = |value: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: comparison.left_variable.clone(),
+                operator: comparison.operator,
+                right_value: value.to_string(),
+            })
+        };
+        let condition = CompoundConstraint::Simple(self.condition.clone());
+        CompoundConstraint::Or(vec![
+            CompoundConstraint::And(vec![condition.clone(), branch(&self.then_value)]),
+            CompoundConstraint::And(vec![CompoundConstraint::Not(Box::new(condition)), branch(&self.else_value)]),
+        ])
+    }
+}
+
+impl std::fmt::Display for Conditional {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let condition = &self.condition;
+        if condition.operator == ConstraintOperator::Equal && condition.right_value == "true" {
+            write!(f, "{} if {} else {}", self.then_value, condition.left_variable, self.else_value)
+        } else {
+            write!(f, "{} if {} else {}", self.then_value, condition, self.else_value)
+        }
+    }
+}
+
+/// A branch: a string literal or anything a call takes as an argument
+fn is_value(value: &str) -> bool {
+    let quoted =
+        value.len() >= 2 && value.starts_with('"') && value.ends_with('"') && !value[1..value.len() - 1].contains('"');
+    quoted || is_argument(value)
+}
+
+fn value_variables(value: &str) -> Vec<String> {
+    match Call::parse(value) {
+        Some(call) => call.variables(),
+        None if value.starts_with('"') => Vec::new(),
+        None => value.split_whitespace().filter(|token| names_field(token)).map(|token| token.to_string()).collect(),
+    }
+}
+
+/// `tier >= 3`, or a bare field `member` as `member == true`
+fn parse_condition(text: &str) -> Option<Constraint> {
+    if names_field(text) {
+        return Some(Constraint {
+            left_variable: text.to_string(),
+            operator: ConstraintOperator::Equal,
+            right_value: "true".to_string(),
+        });
+    }
+    let operators = [
+        ConstraintOperator::GreaterThanOrEqual,
+        ConstraintOperator::LessThanOrEqual,
+        ConstraintOperator::Equal,
+        ConstraintOperator::NotEqual,
+        ConstraintOperator::GreaterThan,
+        ConstraintOperator::LessThan,
+    ];
+    operators.into_iter().find_map(|operator| {
+        let at = top_level(text, &format!(" {} ", operator.symbol()))?;
+        let (left, right) = (text[..at].trim(), text[at + operator.symbol().len() + 2..].trim());
+        let operand = names_field(left)
+            || Modulo::parse(left).is_some()
+            || Bitwise::parse(left).is_some()
+            || Call::parse(left).is_some();
+        (operand && is_value(right)).then(|| Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    })
+}
+
+/// Byte offset of the first `pattern` outside parentheses and string literals
+fn top_level(text: &str, pattern: &str) -> Option<usize> {
+    let (mut depth, mut quoted) = (0usize, false);
+    for (index, c) in text.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth = depth.saturating_sub(1),
+            _ if !quoted && depth == 0 && text[index..].starts_with(pattern) => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conditional() {
+        let member = Conditional::parse("0 if member else 5").unwrap();
+        assert_eq!(member.condition.to_string(), "member == true");
+        assert_eq!((member.then_value.as_str(), member.else_value.as_str()), ("0", "5"));
+        assert_eq!(member.to_string(), "0 if member else 5");
+
+        let tiered = Conditional::parse("max(fee, 1) if tier >= 3 else amount * rate if vip else \"std\"").unwrap();
+        assert_eq!(tiered.condition.to_string(), "tier >= 3");
+        assert_eq!(tiered.then_value, "max(fee, 1)");
+        assert_eq!(tiered.else_value, "amount * rate if vip else \"std\"");
+        assert_eq!(tiered.variables(), ["tier", "fee", "vip", "amount", "rate"]);
+
+        let cases = member.cases(&Constraint {
+            left_variable: "fee".to_string(),
+            operator: ConstraintOperator::Equal,
+            right_value: member.to_string(),
+        });
+        assert_eq!(cases.to_string(), "((member == true AND fee == 0) OR (NOT (member == true) AND fee == 5))");
+
+        for text in ["0 if member", "0 else 5", "0 if tier >> 3 else 5", "a b if member else 5", "0 if \"x\" else 5"] {
+            assert_eq!(Conditional::parse(text), None, "{}", text);
+        }
+    }
+}
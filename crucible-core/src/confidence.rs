@@ -0,0 +1,137 @@
+//! Requirement Confidence Model
+//!
+//! Scores how far each requirement can be trusted as a formal input: how much
+//! of its text the requirements grammar recognizes, whether it produced any
+//! constraints, and whether those constraints have been verified. The
+//! breakdown is kept on the requirement so authors can see *why* a
+//! requirement scored low, not just that it did.
+
+use crate::Requirement;
+use serde::{Deserialize, Serialize};
+
+/// Weight of grammar coverage in the final score (out of 100)
+const COVERAGE_WEIGHT: f64 = 40.0;
+/// Weight of having at least one extracted constraint
+const CONSTRAINT_WEIGHT: f64 = 30.0;
+/// Weight of a successful verification
+const VERIFIED_WEIGHT: f64 = 30.0;
+
+/// Vocabulary recognized by the requirements grammar (see `crucible-parser/grammar.js`)
+const VOCABULARY: &[&str] = &[
+    // subjects
+    "user", "system", "admin", "application", "service", "api",
+    // modal verbs
+    "can", "must", "should", "shall", "will", "may",
+    // verbs
+    "create", "read", "update", "delete", "validate", "verify", "authenticate", "authorize",
+    "encrypt", "decrypt", "send", "receive", "store", "retrieve", "process", "calculate",
+    "generate", "export", "import", "withdraw", "deposit", "transfer", "login", "logout", "register",
+    // clause keywords and connectives
+    "if", "where", "and", "or", "not", "given", "when", "then", "be", "is", "are",
+    // prepositions and determiners
+    "from", "to", "in", "on", "at", "by", "with", "without", "for", "into", "onto", "through",
+    "during", "before", "after", "the", "a", "an",
+    // word operators
+    "equals", "not_equals", "greater_than", "less_than", "at_least", "at_most", "is_set",
+    "is_not_set", "contains", "does_not_contain",
+];
+
+const SYMBOL_OPERATORS: &[&str] = &["==", "!=", ">", "<", ">=", "<=", "+", "-", "*", "/", "%"];
+
+/// Per-requirement confidence and the signals behind it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceBreakdown {
+    /// Fraction of tokens recognized by the grammar or bound to a constraint (0.0 - 1.0)
+    pub grammar_coverage: f64,
+    /// Tokens the grammar does not know and no constraint explains
+    pub unrecognized_tokens: Vec<String>,
+    /// At least one constraint was extracted
+    pub has_constraints: bool,
+    /// The constraints have been checked by the verifier
+    pub verified: bool,
+    /// Weighted total (0 - 100)
+    pub score: f64,
+}
+
+impl ConfidenceBreakdown {
+    /// Score a requirement from its text, constraints and verification status
+    pub fn assess(requirement: &Requirement) -> Self {
+        let tokens: Vec<String> = requirement
+            .content
+            .split_whitespace()
+            .map(|t| t.trim_matches(|c: char| matches!(c, '.' | ',' | ';' | ':' | '(' | ')' | '!' | '?')))
+            .filter(|t| !t.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        let unrecognized_tokens: Vec<String> = tokens
+            .iter()
+            .filter(|t| !is_recognized(t, requirement))
+            .cloned()
+            .collect();
+
+        let grammar_coverage = if tokens.is_empty() {
+            0.0
+        } else {
+            (tokens.len() - unrecognized_tokens.len()) as f64 / tokens.len() as f64
+        };
+        let has_constraints = !requirement.constraints.is_empty();
+        let verified = requirement.verified;
+
+        let score = COVERAGE_WEIGHT * grammar_coverage
+            + if has_constraints { CONSTRAINT_WEIGHT } else { 0.0 }
+            + if verified { VERIFIED_WEIGHT } else { 0.0 };
+
+        Self {
+            grammar_coverage,
+            unrecognized_tokens,
+            has_constraints,
+            verified,
+            score,
+        }
+    }
+}
+
+fn is_recognized(token: &str, requirement: &Requirement) -> bool {
+    VOCABULARY.contains(&token)
+        || SYMBOL_OPERATORS.contains(&token)
+        || token.parse::<f64>().is_ok()
+        || (token.len() >= 2 && token.starts_with('"') && token.ends_with('"'))
+        || requirement
+            .constraints
+            .iter()
+            .any(|c| c.left_variable.eq_ignore_ascii_case(token) || c.right_value.eq_ignore_ascii_case(token))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Constraint, ConstraintOperator, IntentAst};
+
+    #[test]
+    fn test_breakdown_signals() {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("User can withdraw money if balance >= amount".to_string());
+        ast.add_requirement("Thingy should frobnicate gizmos".to_string());
+
+        ast.requirements[0].constraints.push(Constraint {
+            left_variable: "balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "amount".to_string(),
+        });
+        ast.requirements[0].verified = true;
+        ast.refresh_scores();
+
+        let strong = &ast.requirements[0].confidence;
+        assert_eq!(strong.unrecognized_tokens, vec!["money".to_string()]);
+        assert!(strong.has_constraints && strong.verified);
+        assert!((strong.score - (40.0 * 7.0 / 8.0 + 60.0)).abs() < 1e-9);
+
+        let weak = &ast.requirements[1].confidence;
+        assert_eq!(weak.unrecognized_tokens.len(), 3);
+        assert_eq!(weak.score, 40.0 * 0.25);
+
+        assert!((ast.correctness_score - (strong.score + weak.score) / 2.0).abs() < 1e-9);
+        let flagged: Vec<_> = ast.needs_attention(50.0).map(|r| r.content.as_str()).collect();
+        assert_eq!(flagged, vec!["Thingy should frobnicate gizmos"]);
+    }
+}
@@ -0,0 +1,458 @@
+//! Engine Configuration - `crucible.toml` layered with environment variables
+//!
+//! One `CrucibleConfig` is shared by the CLI, the API server and library
+//! callers. Values are resolved in three layers, later ones winning:
+//!
+//! ```text
+//! built-in defaults -> crucible.toml -> CRUCIBLE_<SECTION>_<KEY> env vars
+//! ```
+//!
+//! ```toml
+//! [codegen]
+//! targets = ["rust", "ts"]
+//! out_dir = "src/generated"
+//! reproducible = true
+//!
+//! [codegen.types.Money.rust]
+//! target = "Money"
+//!
+//! [codegen.header]
+//! spdx = "Apache-2.0"
+//!
+//! [codegen.format]
+//! enabled = true
+//! commands.python = ["ruff", "format", "-"]
+//!
+//! [solver]
+//! timeout_ms = 10000
+//! workers = 4
+//!
+//! [naming]
+//! function = "validate_order"
+//!
+//! [features]
+//! pydantic = true
+//!
+//! [[tenants]]
+//! id = "payments"
+//! key_sha256 = ["9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"]
+//! requests_per_minute = 600
+//! max_requirements = 500
+//! ```
+//!
+//! Every key outside `[[tenants]]` and `[codegen.types]` can be overridden from the environment by
+//! upper-casing its section and name: `CRUCIBLE_SOLVER_TIMEOUT_MS=2000`,
+//! `CRUCIBLE_CODEGEN_TARGETS=rust,python`; `CRUCIBLE_CODEGEN_FORMAT=on`
+//! switches formatting on, while its commands are only read from the file,
+//! and `CRUCIBLE_CODEGEN_HEADER` takes `default`, `none` or `spdx:<id>`.
+//! Target languages are kept as names here; `crucible-codegen` resolves them.
+
+use crate::TypeRegistry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// File name looked up in the working directory
+pub const CONFIG_FILE: &str = "crucible.toml";
+
+/// Errors raised while loading configuration
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("cannot read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("invalid {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+
+    #[error("{var}={value:?} is not a valid {expected}")]
+    InvalidEnv {
+        var: String,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+/// Engine settings; every section falls back to its defaults when missing
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CrucibleConfig {
+    pub codegen: CodegenConfig,
+    pub solver: SolverConfig,
+    pub naming: NamingConfig,
+    pub features: FeatureConfig,
+    /// Teams sharing one API server; none means a single open project
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tenants: Vec<TenantConfig>,
+}
+
+/// Where and for which languages validators are generated
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CodegenConfig {
+    /// Target language names (`rust`, `ts`, `python`, ...); the first is
+    /// the default where one language is expected
+    pub targets: Vec<String>,
+    pub out_dir: PathBuf,
+    /// Leave timestamps out of generated files
+    pub reproducible: bool,
+    /// Target spellings and declarations of the Schema's custom types
+    #[serde(skip_serializing_if = "TypeRegistry::is_empty")]
+    pub types: TypeRegistry,
+    /// Formatters the generated code is run through
+    #[serde(skip_serializing_if = "FormatConfig::is_default")]
+    pub format: FormatConfig,
+    /// Banner opening each generated file
+    #[serde(skip_serializing_if = "HeaderPolicy::is_default")]
+    pub header: HeaderPolicy,
+}
+
+/// The banner opening generated files. The built-in one names the engine,
+/// its license and patent application; the provenance lines are written
+/// whichever is chosen
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderPolicy {
+    #[default]
+    Default,
+    /// No banner
+    None,
+    /// `SPDX-License-Identifier: <id>` alone, e.g. `Apache-2.0`
+    Spdx(String),
+    /// Project text, one comment line per line; `{traceability_id}` is
+    /// replaced by the Schema's traceability ID
+    Template(String),
+}
+
+impl HeaderPolicy {
+    pub fn is_default(&self) -> bool {
+        *self == Self::Default
+    }
+}
+
+/// Post-processing of generated code by the target's formatter, so that
+/// regenerated files diff cleanly; off by default
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FormatConfig {
+    pub enabled: bool,
+    /// Formatter command per target name, replacing the built-in one. The
+    /// code is piped through stdin and stdout unless an argument is
+    /// `{file}`, which names a temporary copy formatted in place; an empty
+    /// command leaves the target unformatted
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub commands: BTreeMap<String, Vec<String>>,
+}
+
+impl FormatConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Z3 limits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SolverConfig {
+    /// Per-check limit; a check that runs out answers `unknown`
+    pub timeout_ms: Option<u64>,
+    /// Verifier pool size; one per CPU when unset
+    pub workers: Option<usize>,
+    /// How long an API request waits for a free verifier
+    pub checkout_timeout_ms: u64,
+}
+
+/// Names given to generated code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NamingConfig {
+    /// Validator function (or policy rule) name
+    pub function: String,
+    /// Package/module name for languages that have one
+    pub package: Option<String>,
+    /// Prefix of per-requirement files written by `crucible watch`
+    pub file_prefix: String,
+}
+
+/// Optional output profiles, all off by default
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeatureConfig {
+    /// Rust: `#![no_std]` validators
+    pub no_std: bool,
+    /// Python: Pydantic v2 models
+    pub pydantic: bool,
+    /// TypeScript: Zod schemas
+    pub zod: bool,
+    /// TypeScript: `bigint` for 64-bit fields
+    pub bigint: bool,
+    /// Rust, TypeScript, Python: runtime monitors after the validator
+    pub monitor: bool,
+    /// Rust, TypeScript, Python: OpenTelemetry spans around the checks
+    pub telemetry: bool,
+}
+
+/// One team on a shared API server, with its own project, keys and limits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TenantConfig {
+    pub id: String,
+    /// Hex SHA-256 of each API key the tenant may use; the keys themselves
+    /// never appear in the config
+    #[serde(default)]
+    pub key_sha256: Vec<String>,
+    /// Unlimited when unset
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+    /// Requirements the tenant's project may hold; unlimited when unset
+    #[serde(default)]
+    pub max_requirements: Option<usize>,
+}
+
+impl Default for CodegenConfig {
+    fn default() -> Self {
+        Self {
+            targets: vec!["rust".to_string()],
+            out_dir: PathBuf::from("src/generated"),
+            reproducible: false,
+            types: TypeRegistry::default(),
+            format: FormatConfig::default(),
+            header: HeaderPolicy::Default,
+        }
+    }
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: None,
+            workers: None,
+            checkout_timeout_ms: 5000,
+        }
+    }
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        Self {
+            function: "validate_intent".to_string(),
+            package: None,
+            file_prefix: "requirement_".to_string(),
+        }
+    }
+}
+
+impl CrucibleConfig {
+    /// `crucible.toml` from the working directory, if there is one, then the
+    /// process environment
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = Path::new(CONFIG_FILE);
+        let mut config = if path.exists() { Self::from_file(path)? } else { Self::default() };
+        config.apply_env(|var| std::env::var(var).ok())?;
+        Ok(config)
+    }
+
+    /// `path` (which must exist), then the process environment
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let mut config = Self::from_file(path.as_ref())?;
+        config.apply_env(|var| std::env::var(var).ok())?;
+        Ok(config)
+    }
+
+    /// A config file alone, without environment overrides
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml(&text).map_err(|e| match e {
+            ConfigError::Parse { message, .. } => ConfigError::Parse {
+                path: path.to_path_buf(),
+                message,
+            },
+            other => other,
+        })
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, ConfigError> {
+        toml::from_str(text).map_err(|e| ConfigError::Parse {
+            path: PathBuf::from(CONFIG_FILE),
+            message: e.message().to_string(),
+        })
+    }
+
+    /// Override settings from `CRUCIBLE_*` variables, read through `var`.
+    /// `CRUCIBLE_VERIFIER_WORKERS` is still honoured for the pool size
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        let env = Env(var);
+        if let Some(targets) = env.string("CRUCIBLE_CODEGEN_TARGETS") {
+            self.codegen.targets = targets.split(',').map(|t| t.trim().to_string()).filter(|t| !t.is_empty()).collect();
+        }
+        if let Some(out_dir) = env.string("CRUCIBLE_CODEGEN_OUT_DIR") {
+            self.codegen.out_dir = PathBuf::from(out_dir);
+        }
+        env.set_bool("CRUCIBLE_CODEGEN_REPRODUCIBLE", &mut self.codegen.reproducible)?;
+        env.set_bool("CRUCIBLE_CODEGEN_FORMAT", &mut self.codegen.format.enabled)?;
+        if let Some(header) = env.string("CRUCIBLE_CODEGEN_HEADER") {
+            self.codegen.header = match header.as_str() {
+                "default" => HeaderPolicy::Default,
+                "none" => HeaderPolicy::None,
+                _ => match header.strip_prefix("spdx:").map(str::trim) {
+                    Some(id) if !id.is_empty() => HeaderPolicy::Spdx(id.to_string()),
+                    _ => {
+                        return Err(ConfigError::InvalidEnv {
+                            var: "CRUCIBLE_CODEGEN_HEADER".to_string(),
+                            value: header,
+                            expected: "header (`default`, `none` or `spdx:<id>`)",
+                        })
+                    }
+                },
+            };
+        }
+
+        if let Some(timeout) = env.parse("CRUCIBLE_SOLVER_TIMEOUT_MS", "number of milliseconds")? {
+            self.solver.timeout_ms = Some(timeout);
+        }
+        for name in ["CRUCIBLE_VERIFIER_WORKERS", "CRUCIBLE_SOLVER_WORKERS"] {
+            if let Some(workers) = env.parse(name, "worker count")? {
+                self.solver.workers = Some(workers);
+            }
+        }
+        if let Some(timeout) = env.parse("CRUCIBLE_SOLVER_CHECKOUT_TIMEOUT_MS", "number of milliseconds")? {
+            self.solver.checkout_timeout_ms = timeout;
+        }
+
+        if let Some(function) = env.string("CRUCIBLE_NAMING_FUNCTION") {
+            self.naming.function = function;
+        }
+        if let Some(package) = env.string("CRUCIBLE_NAMING_PACKAGE") {
+            self.naming.package = Some(package);
+        }
+        if let Some(prefix) = env.string("CRUCIBLE_NAMING_FILE_PREFIX") {
+            self.naming.file_prefix = prefix;
+        }
+
+        env.set_bool("CRUCIBLE_FEATURES_NO_STD", &mut self.features.no_std)?;
+        env.set_bool("CRUCIBLE_FEATURES_PYDANTIC", &mut self.features.pydantic)?;
+        env.set_bool("CRUCIBLE_FEATURES_ZOD", &mut self.features.zod)?;
+        env.set_bool("CRUCIBLE_FEATURES_BIGINT", &mut self.features.bigint)?;
+        env.set_bool("CRUCIBLE_FEATURES_MONITOR", &mut self.features.monitor)?;
+        env.set_bool("CRUCIBLE_FEATURES_TELEMETRY", &mut self.features.telemetry)?;
+        Ok(())
+    }
+
+    /// The first configured target language name
+    pub fn default_target(&self) -> Option<&str> {
+        self.codegen.targets.first().map(String::as_str)
+    }
+
+    pub fn solver_timeout(&self) -> Option<Duration> {
+        self.solver.timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn checkout_timeout(&self) -> Duration {
+        Duration::from_millis(self.solver.checkout_timeout_ms)
+    }
+}
+
+/// Typed reads of environment variables
+struct Env<F>(F);
+
+impl<F: Fn(&str) -> Option<String>> Env<F> {
+    fn string(&self, name: &str) -> Option<String> {
+        (self.0)(name).filter(|value| !value.is_empty())
+    }
+
+    fn parse<T: std::str::FromStr>(&self, name: &str, expected: &'static str) -> Result<Option<T>, ConfigError> {
+        self.string(name)
+            .map(|value| {
+                value.trim().parse().map_err(|_| ConfigError::InvalidEnv {
+                    var: name.to_string(),
+                    value,
+                    expected,
+                })
+            })
+            .transpose()
+    }
+
+    fn set_bool(&self, name: &str, target: &mut bool) -> Result<(), ConfigError> {
+        let Some(value) = self.string(name) else {
+            return Ok(());
+        };
+        *target = match value.to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => true,
+            "0" | "false" | "no" | "off" => false,
+            _ => {
+                return Err(ConfigError::InvalidEnv {
+                    var: name.to_string(),
+                    value,
+                    expected: "boolean",
+                })
+            }
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_file_then_environment() {
+        let mut config = CrucibleConfig::from_toml(
+            r#"
+            [codegen]
+            targets = ["ts", "python"]
+
+            [codegen.header]
+            template = "Copyright Example Corp\nTrace: {traceability_id}"
+
+            [codegen.format]
+            commands.python = ["ruff", "format", "-"]
+
+            [solver]
+            timeout_ms = 10000
+
+            [features]
+            zod = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.default_target(), Some("ts"));
+        assert!(matches!(&config.codegen.header, HeaderPolicy::Template(text) if text.starts_with("Copyright")));
+        assert_eq!(config.codegen.out_dir, PathBuf::from("src/generated"));
+        assert_eq!(config.naming.function, "validate_intent");
+
+        let env: HashMap<&str, &str> = HashMap::from([
+            ("CRUCIBLE_SOLVER_TIMEOUT_MS", "2000"),
+            ("CRUCIBLE_VERIFIER_WORKERS", "3"),
+            ("CRUCIBLE_CODEGEN_TARGETS", "rust, sol"),
+            ("CRUCIBLE_FEATURES_ZOD", "off"),
+            ("CRUCIBLE_CODEGEN_FORMAT", "on"),
+            ("CRUCIBLE_CODEGEN_HEADER", "spdx:Apache-2.0"),
+        ]);
+        config.apply_env(|var| env.get(var).map(|v| v.to_string())).unwrap();
+        assert_eq!(config.solver_timeout(), Some(Duration::from_millis(2000)));
+        assert_eq!(config.solver.workers, Some(3));
+        assert_eq!(config.codegen.targets, vec!["rust", "sol"]);
+        assert!(!config.features.zod);
+        assert!(config.codegen.format.enabled);
+        assert_eq!(config.codegen.header, HeaderPolicy::Spdx("Apache-2.0".to_string()));
+        assert_eq!(config.codegen.format.commands["python"], vec!["ruff", "format", "-"]);
+
+        let error = config
+            .apply_env(|var| (var == "CRUCIBLE_CODEGEN_REPRODUCIBLE").then(|| "maybe".to_string()))
+            .unwrap_err();
+        assert!(matches!(error, ConfigError::InvalidEnv { expected: "boolean", .. }));
+        assert!(matches!(
+            CrucibleConfig::from_toml("[solver]\ntimeout = 5"),
+            Err(ConfigError::Parse { .. })
+        ));
+    }
+}
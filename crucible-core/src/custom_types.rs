@@ -0,0 +1,122 @@
+//! Custom Types - target-language spellings of `DataType::Custom` names
+//!
+//! A Schema can type a field as a domain type (`Money`, `AccountId`,
+//! `Percentage`), but the name means nothing to the generated code unless
+//! the project declares it. The registry says, per custom type and target
+//! language, what to write instead and, optionally, the declaration to emit
+//! ahead of the validator:
+//!
+//! ```toml
+//! [codegen.types.Money.rust]
+//! target = "Money"
+//! declaration = "pub type {type} = u64; // {min}..={max} cents"
+//!
+//! [codegen.types.Money.ts]
+//! target = "Money"
+//! declaration = "export type {type} = number & { readonly __brand: \"{name}\" };"
+//!
+//! [codegen.types.AccountId.python]
+//! target = "accounts.AccountId"
+//! ```
+//!
+//! Languages are kept as names (any alias `crucible-codegen` accepts);
+//! types without a mapping for a language keep their Schema name there.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How one target language spells a custom type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeMapping {
+    /// The type in the target, e.g. `Money` or `crate::money::Money`
+    pub target: String,
+    /// Emitted before the validator, with `{type}` (the target spelling),
+    /// `{name}` (the Schema name), `{min}` and `{max}` (the type's bounds)
+    /// filled in; leave unset when the project already declares the type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declaration: Option<String>,
+}
+
+impl TypeMapping {
+    /// A mapping that emits no declaration
+    pub fn new(target: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            declaration: None,
+        }
+    }
+
+    /// Emit `template` ahead of the validator
+    pub fn with_declaration(mut self, template: impl Into<String>) -> Self {
+        self.declaration = Some(template.into());
+        self
+    }
+}
+
+/// Custom type name -> language name -> mapping
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TypeRegistry {
+    types: BTreeMap<String, BTreeMap<String, TypeMapping>>,
+}
+
+impl TypeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map the custom type `name` for `language`, replacing an earlier mapping
+    pub fn register(&mut self, name: &str, language: &str, mapping: TypeMapping) -> &mut Self {
+        self.types.entry(name.to_string()).or_default().insert(language.to_string(), mapping);
+        self
+    }
+
+    /// The mappings of `name` by language name
+    pub fn mappings(&self, name: &str) -> impl Iterator<Item = (&str, &TypeMapping)> {
+        self.types.get(name).into_iter().flatten().map(|(language, mapping)| (language.as_str(), mapping))
+    }
+
+    /// Custom type names with at least one mapping, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.types.keys().map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.values().all(BTreeMap::is_empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CrucibleConfig;
+
+    #[test]
+    fn test_registry_from_config() {
+        let config = CrucibleConfig::from_toml(
+            r#"
+            [codegen.types.Money.rust]
+            target = "Money"
+            declaration = "pub type {type} = u64;"
+
+            [codegen.types.Money.ts]
+            target = "Money"
+            "#,
+        )
+        .unwrap();
+        let registry = &config.codegen.types;
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["Money"]);
+        let mappings: Vec<(&str, &TypeMapping)> = registry.mappings("Money").collect();
+        assert_eq!(mappings[0], ("rust", &TypeMapping::new("Money").with_declaration("pub type {type} = u64;")));
+        assert_eq!(mappings[1], ("ts", &TypeMapping::new("Money")));
+        assert_eq!(registry.mappings("AccountId").count(), 0);
+
+        let mut built = TypeRegistry::new();
+        built.register("Money", "ts", TypeMapping::new("Money"));
+        assert!(!built.is_empty() && CrucibleConfig::default().codegen.types.is_empty());
+
+        let typo = CrucibleConfig::from_toml("[codegen.types.Money.rust]\ntarget = \"Money\"\ndecl = \"\"\n");
+        assert!(typo.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+//! Named Predicates - define a condition once, reference it everywhere
+//!
+//! Specs repeat the same condition across requirements ("the balance covers
+//! the amount" guards withdrawals, transfers and payments alike). A definition
+//! names it once:
+//!
+//! ```text
+//! sufficient_funds := balance >= amount
+//! ```
+//!
+//! and requirements refer to it as `CompoundConstraint::Named`. Z3 has no
+//! notion of a reference, so verification inlines every definition first;
+//! codegen emits one helper function per definition where the target has
+//! functions and inlines elsewhere, so the logic is never written out twice.
+
+use crate::CompoundConstraint;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors raised while defining or resolving named predicates
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DefinitionError {
+    #[error("undefined predicate `{0}`")]
+    Undefined(String),
+
+    #[error("predicate `{0}` is already defined")]
+    Duplicate(String),
+
+    #[error("predicate `{name}` refers to itself through {}", .path.join(" -> "))]
+    Cyclic { name: String, path: Vec<String> },
+}
+
+/// Project-wide name -> predicate table
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Definitions {
+    pub predicates: BTreeMap<String, CompoundConstraint>,
+}
+
+impl Definitions {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse definitions from their JSON form (`{"predicates": {...}}`)
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Load the project definitions from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(Self::from_json(&json)?)
+    }
+
+    /// Define `name`; redefining an existing predicate is an error
+    pub fn define(&mut self, name: &str, predicate: CompoundConstraint) -> Result<(), DefinitionError> {
+        if self.predicates.contains_key(name) {
+            return Err(DefinitionError::Duplicate(name.to_string()));
+        }
+        self.predicates.insert(name.to_string(), predicate);
+        Ok(())
+    }
+
+    /// The predicate behind `name`, if defined
+    pub fn get(&self, name: &str) -> Option<&CompoundConstraint> {
+        self.predicates.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.predicates.is_empty()
+    }
+
+    /// `compound` with every reference replaced by its definition, recursively
+    pub fn inline(&self, compound: &CompoundConstraint) -> Result<CompoundConstraint, DefinitionError> {
+        self.inline_with(compound, &mut Vec::new())
+    }
+
+    /// The definitions `compound` depends on, directly or through other
+    /// definitions, each listed after everything it refers to
+    pub fn used_by(&self, compound: &CompoundConstraint) -> Result<Vec<(&str, &CompoundConstraint)>, DefinitionError> {
+        let mut ordered = Vec::new();
+        let mut visited = BTreeSet::new();
+        for name in compound.references() {
+            self.visit(name, &mut Vec::new(), &mut visited, &mut ordered)?;
+        }
+        Ok(ordered)
+    }
+
+    /// Every definition must resolve without cycles or dangling references
+    pub fn validate(&self) -> Result<(), DefinitionError> {
+        for predicate in self.predicates.values() {
+            self.inline(predicate)?;
+        }
+        Ok(())
+    }
+
+    fn inline_with(&self, compound: &CompoundConstraint, path: &mut Vec<String>) -> Result<CompoundConstraint, DefinitionError> {
+        let inline_all = |parts: &[CompoundConstraint], path: &mut Vec<String>| {
+            parts.iter().map(|part| self.inline_with(part, path)).collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match compound {
+            CompoundConstraint::Named(name) => {
+                let predicate = self.lookup(name, path)?;
+                path.push(name.clone());
+                let inlined = self.inline_with(predicate, path)?;
+                path.pop();
+                inlined
+            }
+            CompoundConstraint::Simple(_) | CompoundConstraint::Distinct(_) => compound.clone(),
+            CompoundConstraint::And(parts) => CompoundConstraint::And(inline_all(parts, path)?),
+            CompoundConstraint::Or(parts) => CompoundConstraint::Or(inline_all(parts, path)?),
+            CompoundConstraint::ExactlyOne(parts) => CompoundConstraint::ExactlyOne(inline_all(parts, path)?),
+            CompoundConstraint::AtMostOne(parts) => CompoundConstraint::AtMostOne(inline_all(parts, path)?),
+            CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(self.inline_with(inner, path)?)),
+            CompoundConstraint::Implies(antecedent, consequent) => CompoundConstraint::implies(
+                self.inline_with(antecedent, path)?,
+                self.inline_with(consequent, path)?,
+            ),
+        })
+    }
+
+    fn visit<'a>(
+        &'a self,
+        name: &str,
+        path: &mut Vec<String>,
+        visited: &mut BTreeSet<String>,
+        ordered: &mut Vec<(&'a str, &'a CompoundConstraint)>,
+    ) -> Result<(), DefinitionError> {
+        if visited.contains(name) {
+            return Ok(());
+        }
+        let predicate = self.lookup(name, path)?;
+        path.push(name.to_string());
+        for dependency in predicate.references() {
+            self.visit(dependency, path, visited, ordered)?;
+        }
+        path.pop();
+
+        visited.insert(name.to_string());
+        let (key, predicate) = self.predicates.get_key_value(name).expect("looked up above");
+        ordered.push((key.as_str(), predicate));
+        Ok(())
+    }
+
+    /// Resolve `name` while `path` is being expanded, rejecting cycles
+    fn lookup(&self, name: &str, path: &[String]) -> Result<&CompoundConstraint, DefinitionError> {
+        if let Some(start) = path.iter().position(|p| p == name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(name.to_string());
+            return Err(DefinitionError::Cyclic { name: name.to_string(), path: cycle });
+        }
+        self.get(name).ok_or_else(|| DefinitionError::Undefined(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Constraint, ConstraintOperator};
+
+    fn c(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    fn named(name: &str) -> CompoundConstraint {
+        CompoundConstraint::Named(name.to_string())
+    }
+
+    #[test]
+    fn test_inline_and_dependency_order() {
+        let mut definitions = Definitions::new();
+        definitions
+            .define("sufficient_funds", c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"))
+            .unwrap();
+        definitions
+            .define(
+                "can_withdraw",
+                CompoundConstraint::And(vec![named("sufficient_funds"), c("amount", ConstraintOperator::GreaterThan, "0")]),
+            )
+            .unwrap();
+        assert_eq!(
+            definitions.define("can_withdraw", named("sufficient_funds")),
+            Err(DefinitionError::Duplicate("can_withdraw".to_string()))
+        );
+
+        let requirement = CompoundConstraint::Or(vec![named("can_withdraw"), named("sufficient_funds")]);
+        assert_eq!(
+            definitions.inline(&requirement).unwrap(),
+            CompoundConstraint::Or(vec![
+                CompoundConstraint::And(vec![
+                    c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+                    c("amount", ConstraintOperator::GreaterThan, "0"),
+                ]),
+                c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            ])
+        );
+
+        let order: Vec<&str> = definitions.used_by(&requirement).unwrap().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(order, vec!["sufficient_funds", "can_withdraw"]);
+    }
+
+    #[test]
+    fn test_undefined_and_cyclic_references() {
+        let mut definitions = Definitions::new();
+        assert_eq!(definitions.inline(&named("missing")), Err(DefinitionError::Undefined("missing".to_string())));
+
+        definitions.define("a", CompoundConstraint::Not(Box::new(named("b")))).unwrap();
+        definitions.define("b", named("a")).unwrap();
+        assert_eq!(
+            definitions.inline(&named("a")),
+            Err(DefinitionError::Cyclic {
+                name: "a".to_string(),
+                path: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+            })
+        );
+        assert!(definitions.used_by(&named("b")).is_err());
+        assert!(definitions.validate().is_err());
+    }
+}
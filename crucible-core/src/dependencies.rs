@@ -0,0 +1,176 @@
+//! Dependency Graph - which requirements and fields affect which
+//!
+//! Requirements interact through the variables they share: a change to the
+//! bound on `amount` can make a withdrawal rule and a transfer limit
+//! contradict each other even though neither rule mentions the other. The
+//! graph links every requirement to the fields its constraints read, and
+//! `impact_of_change` follows those links to everything that has to be
+//! re-verified (the requirements reachable through shared fields) and
+//! regenerated (the fields those requirements constrain).
+
+use crate::IntentAst;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+/// Requirement <-> field links of an Intent-AST
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    /// Fields each requirement's constraints read
+    pub requirement_fields: BTreeMap<Uuid, BTreeSet<String>>,
+    /// Requirements reading each field
+    pub field_requirements: BTreeMap<String, BTreeSet<Uuid>>,
+}
+
+/// Everything a change reaches
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Impact {
+    /// Requirements to re-verify: the changed one (or the field's readers) and
+    /// every requirement connected to them through shared fields
+    pub requirements: Vec<Uuid>,
+    /// Fields whose generated validation code has to be regenerated
+    pub fields: Vec<String>,
+}
+
+impl Impact {
+    pub fn is_empty(&self) -> bool {
+        self.requirements.is_empty() && self.fields.is_empty()
+    }
+}
+
+impl DependencyGraph {
+    /// Link every requirement of `ast` to the fields its constraints read
+    pub fn build(ast: &IntentAst) -> Self {
+        let mut graph = Self::default();
+        for requirement in &ast.requirements {
+            let fields: BTreeSet<String> = requirement.constraints.iter().flat_map(|c| c.variables()).collect();
+            for field in &fields {
+                graph.field_requirements.entry(field.clone()).or_default().insert(requirement.id);
+            }
+            graph.requirement_fields.insert(requirement.id, fields);
+        }
+        graph
+    }
+
+    /// Fields read by `requirement`
+    pub fn fields_of(&self, requirement: Uuid) -> Vec<&str> {
+        self.requirement_fields
+            .get(&requirement)
+            .map(|fields| fields.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Requirements reading `field`
+    pub fn requirements_using(&self, field: &str) -> Vec<Uuid> {
+        self.field_requirements
+            .get(field)
+            .map(|ids| ids.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Other requirements sharing at least one field with `requirement`
+    pub fn shares_variables_with(&self, requirement: Uuid) -> Vec<Uuid> {
+        let shared: BTreeSet<Uuid> = self
+            .fields_of(requirement)
+            .into_iter()
+            .flat_map(|field| self.requirements_using(field))
+            .filter(|id| *id != requirement)
+            .collect();
+        shared.into_iter().collect()
+    }
+
+    /// What must be re-verified and regenerated when `field_or_requirement`
+    /// (a requirement ID or a field name) changes. Unknown targets reach nothing
+    pub fn impact_of_change(&self, field_or_requirement: &str) -> Impact {
+        let mut pending: Vec<Uuid> = match Uuid::parse_str(field_or_requirement) {
+            Ok(id) if self.requirement_fields.contains_key(&id) => vec![id],
+            _ => self.requirements_using(field_or_requirement),
+        };
+
+        let mut requirements = BTreeSet::new();
+        let mut fields = BTreeSet::new();
+        while let Some(id) = pending.pop() {
+            if !requirements.insert(id) {
+                continue;
+            }
+            for field in self.fields_of(id) {
+                if fields.insert(field.to_string()) {
+                    pending.extend(self.requirements_using(field));
+                }
+            }
+        }
+
+        Impact {
+            requirements: requirements.into_iter().collect(),
+            fields: fields.into_iter().collect(),
+        }
+    }
+}
+
+impl IntentAst {
+    /// Requirement <-> field dependency graph
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        DependencyGraph::build(self)
+    }
+
+    /// Shorthand for `dependency_graph().impact_of_change(...)`
+    pub fn impact_of_change(&self, field_or_requirement: &str) -> Impact {
+        self.dependency_graph().impact_of_change(field_or_requirement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Constraint, ConstraintOperator};
+
+    fn constraint(left: &str, operator: ConstraintOperator, right: &str) -> Constraint {
+        Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        }
+    }
+
+    fn sample_ast() -> IntentAst {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("User can withdraw if balance >= amount".to_string());
+        ast.add_requirement("User can transfer if amount <= daily_limit".to_string());
+        ast.add_requirement("Admin can delete if role == admin".to_string());
+        ast.requirements[0].constraints = vec![constraint("balance", ConstraintOperator::GreaterThanOrEqual, "amount")];
+        ast.requirements[1].constraints = vec![constraint("amount", ConstraintOperator::LessThanOrEqual, "daily_limit")];
+        ast.requirements[2].constraints = vec![constraint("role", ConstraintOperator::Equal, "\"admin\"")];
+        ast
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        let ast = sample_ast();
+        let graph = ast.dependency_graph();
+        let [withdraw, transfer, delete] = [0, 1, 2].map(|i| ast.requirements[i].id);
+
+        assert_eq!(graph.fields_of(withdraw), vec!["amount", "balance"]);
+        assert_eq!(graph.fields_of(delete), vec!["role"]);
+        assert_eq!(graph.shares_variables_with(withdraw), vec![transfer]);
+        assert!(graph.shares_variables_with(delete).is_empty());
+    }
+
+    #[test]
+    fn test_impact_of_change() {
+        let ast = sample_ast();
+        let [withdraw, transfer, delete] = [0, 1, 2].map(|i| ast.requirements[i].id);
+        let mut linked = vec![withdraw, transfer];
+        linked.sort();
+
+        // `balance` reaches the transfer rule through the shared `amount`
+        let impact = ast.impact_of_change("balance");
+        assert_eq!(impact.requirements, linked);
+        assert_eq!(impact.fields, vec!["amount", "balance", "daily_limit"]);
+
+        let impact = ast.impact_of_change(&delete.to_string());
+        assert_eq!(impact.requirements, vec![delete]);
+        assert_eq!(impact.fields, vec!["role"]);
+
+        assert!(ast.impact_of_change("unknown_field").is_empty());
+    }
+}
@@ -0,0 +1,253 @@
+//! Entity Resolution - lightweight coreference across requirements
+//!
+//! Requirements are often written as a short discourse: "User can withdraw
+//! money. The amount must be positive." Taken one sentence at a time, the
+//! second sentence has no subject and `amount` belongs to nothing. This pass
+//! walks the sentences in order, tracks the current discourse subject,
+//! resolves pronouns and definite noun phrases against it, and binds every
+//! variable to an entity so the constraint set stays connected.
+
+use crate::{Constraint, ConstraintOperator, IntentAst};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+const MODAL_VERBS: &[&str] = &["can", "must", "should", "shall", "will", "may", "is", "are"];
+const DETERMINERS: &[&str] = &["the", "a", "an"];
+const PRONOUNS: &[&str] = &["it", "they", "he", "she", "this", "that"];
+const POSSESSIVE_PRONOUNS: &[&str] = &["its", "their", "his", "her"];
+
+/// Predicate adjectives that imply a comparison against zero
+const PREDICATES: &[(&str, ConstraintOperator)] = &[
+    ("positive", ConstraintOperator::GreaterThan),
+    ("negative", ConstraintOperator::LessThan),
+    ("non-negative", ConstraintOperator::GreaterThanOrEqual),
+    ("nonnegative", ConstraintOperator::GreaterThanOrEqual),
+    ("non-zero", ConstraintOperator::NotEqual),
+    ("nonzero", ConstraintOperator::NotEqual),
+];
+
+/// Entities discovered in a spec and the variables bound to each
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityGlossary {
+    /// Entity name -> variables that describe it
+    pub entities: BTreeMap<String, BTreeSet<String>>,
+    /// Resolved (possibly implicit) subject of each requirement
+    pub subjects: BTreeMap<Uuid, String>,
+}
+
+impl EntityGlossary {
+    /// The entity a variable is bound to, if any
+    pub fn entity_of(&self, variable: &str) -> Option<&str> {
+        self.entities
+            .iter()
+            .find(|(_, attributes)| attributes.contains(variable))
+            .map(|(entity, _)| entity.as_str())
+    }
+
+    fn bind(&mut self, entity: &str, variable: &str) {
+        // A variable belongs to the first entity that mentions it
+        if self.entity_of(variable).is_none() {
+            self.entities
+                .entry(entity.to_string())
+                .or_default()
+                .insert(variable.to_string());
+        }
+    }
+}
+
+/// Resolve subjects and variable ownership across all requirements of `ast`.
+///
+/// Sentences such as "The amount must be positive" also contribute the
+/// implied constraint (`amount > 0`) to their requirement.
+pub fn resolve_entities(ast: &mut IntentAst) -> EntityGlossary {
+    let mut glossary = EntityGlossary::default();
+    let mut current: Option<String> = None;
+
+    for requirement in &mut ast.requirements {
+        for sentence in split_sentences(&requirement.content) {
+            let Some(clause) = analyze_sentence(&sentence, current.as_deref(), &glossary) else {
+                continue;
+            };
+
+            if let Some(entity) = &clause.subject {
+                glossary.entities.entry(entity.clone()).or_default();
+                current = Some(entity.clone());
+            }
+
+            if let Some(variable) = &clause.variable {
+                if let Some(owner) = clause.owner.as_ref().or(current.as_ref()) {
+                    glossary.bind(owner, variable);
+                }
+            }
+
+            if let Some(constraint) = clause.implied {
+                if !requirement.constraints.contains(&constraint) {
+                    requirement.constraints.push(constraint);
+                }
+            }
+        }
+
+        if let Some(subject) = &current {
+            glossary.subjects.insert(requirement.id, subject.clone());
+            for constraint in &requirement.constraints {
+                glossary.bind(subject, &constraint.left_variable);
+                if is_variable(&constraint.right_value) {
+                    glossary.bind(subject, &constraint.right_value);
+                }
+            }
+        }
+    }
+
+    // Implied constraints change the confidence of their requirements
+    ast.refresh_scores();
+    glossary
+}
+
+/// What one sentence contributes to the discourse
+struct Clause {
+    /// A new explicit subject
+    subject: Option<String>,
+    /// A variable mentioned as the sentence topic
+    variable: Option<String>,
+    /// Explicit owner of `variable` (possessives); defaults to the current subject
+    owner: Option<String>,
+    implied: Option<Constraint>,
+}
+
+fn analyze_sentence(sentence: &str, current: Option<&str>, glossary: &EntityGlossary) -> Option<Clause> {
+    let words: Vec<&str> = sentence
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| matches!(c, ',' | ';' | ':')))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let lower: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
+
+    let modal = lower.iter().position(|w| MODAL_VERBS.contains(&w.as_str()))?;
+    let definite = lower.first().is_some_and(|w| DETERMINERS.contains(&w.as_str()));
+    let start = usize::from(definite);
+    if start >= modal {
+        return None;
+    }
+    let phrase = &lower[start..modal];
+
+    let mut clause = Clause {
+        subject: None,
+        variable: None,
+        owner: None,
+        implied: None,
+    };
+
+    if phrase.len() == 1 && PRONOUNS.contains(&phrase[0].as_str()) {
+        // "It must ..." continues the current subject
+        clause.subject = current.map(str::to_string);
+    } else if let Some(owner) = phrase[0].strip_suffix("'s") {
+        // "the user's balance" -> user.balance
+        clause.owner = Some(owner.to_string());
+        clause.variable = Some(phrase[1..].join("_")).filter(|v| !v.is_empty());
+    } else if POSSESSIVE_PRONOUNS.contains(&phrase[0].as_str()) {
+        // "Its balance" -> <current>.balance
+        clause.variable = Some(phrase[1..].join("_")).filter(|v| !v.is_empty());
+    } else {
+        let name = phrase.join("_");
+        let capitalized = words[start].chars().next().is_some_and(char::is_uppercase);
+        let is_entity = glossary.entities.contains_key(&name)
+            || (phrase.len() == 1 && capitalized && !definite && glossary.entity_of(&name).is_none());
+
+        if is_entity {
+            clause.subject = Some(name);
+        } else {
+            clause.variable = Some(name);
+        }
+    }
+
+    // "<variable> must be positive"
+    if let Some(variable) = &clause.variable {
+        let rest = &lower[modal + 1..];
+        let predicate = match rest {
+            [be, word, ..] if be == "be" => Some(word.as_str()),
+            [word, ..] if matches!(lower[modal].as_str(), "is" | "are") => Some(word.as_str()),
+            _ => None,
+        };
+        if let Some((_, operator)) = predicate.and_then(|p| PREDICATES.iter().find(|(name, _)| *name == p)) {
+            clause.implied = Some(Constraint {
+                left_variable: variable.clone(),
+                operator: *operator,
+                right_value: "0".to_string(),
+            });
+        }
+    }
+
+    Some(clause)
+}
+
+/// Split on sentence-ending punctuation, keeping decimals like `2.5` intact
+fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let ends = matches!(c, '.' | '!' | '?') && chars.peek().is_none_or(|next| next.is_whitespace());
+        if ends {
+            sentences.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    sentences.push(current);
+
+    sentences
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn is_variable(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && value != "true"
+        && value != "false"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_implicit_subject_and_implied_constraint() {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("User can withdraw money. The amount must be positive.".to_string());
+
+        let glossary = resolve_entities(&mut ast);
+        let id = ast.requirements[0].id;
+        assert_eq!(glossary.subjects.get(&id).map(String::as_str), Some("user"));
+        assert_eq!(glossary.entity_of("amount"), Some("user"));
+        assert_eq!(
+            ast.requirements[0].constraints,
+            vec![Constraint {
+                left_variable: "amount".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "0".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_pronouns_and_possessives_across_requirements() {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("Account must be open".to_string());
+        ast.add_requirement("Its balance must be non-negative".to_string());
+        ast.add_requirement("The user's limit must be positive".to_string());
+        ast.add_requirement("It should reject overdrafts".to_string());
+
+        let glossary = resolve_entities(&mut ast);
+        assert_eq!(glossary.entity_of("balance"), Some("account"));
+        assert_eq!(glossary.entity_of("limit"), Some("user"));
+        assert_eq!(ast.requirements[1].constraints[0].operator, ConstraintOperator::GreaterThanOrEqual);
+        // "It" still refers to the account: possessives do not change the subject
+        assert_eq!(glossary.subjects.get(&ast.requirements[3].id).map(String::as_str), Some("account"));
+    }
+}
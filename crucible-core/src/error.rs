@@ -0,0 +1,327 @@
+//! Pipeline Errors - one taxonomy from parsing to code generation
+//!
+//! Each crate keeps its own error enum for the detail it needs; at a crate
+//! boundary (an API response, a CLI exit, a WASM export) they convert into
+//! `CrucibleError`, which says which stage failed, carries a stable code
+//! and, when the input text is to blame, where. The message is English;
+//! the code and its arguments render it in another locale:
+//!
+//! ```
+//! use crucible_core::{ConstraintError, CrucibleError, Locale};
+//!
+//! let error = CrucibleError::from(ConstraintError::TooDeep { limit: 64 });
+//! assert_eq!(error.code(), "S004");
+//! assert_eq!(
+//!     serde_json::to_value(&error).unwrap(),
+//!     serde_json::json!({
+//!         "stage": "schema",
+//!         "code": "S004",
+//!         "message": "constraints nest more than 64 levels deep",
+//!         "args": {"limit": "64"}
+//!     })
+//! );
+//! assert_eq!(
+//!     error.localized(Locale::Es),
+//!     "error de esquema [S004] las restricciones se anidan más de 64 niveles"
+//! );
+//! ```
+
+use crate::{ConstraintError, DefinitionError, Locale, TransitionError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use thiserror::Error;
+
+/// Stable error codes; the letter names the stage, the number the problem
+pub mod codes {
+    /// The requirement text does not follow the grammar
+    pub const SYNTAX: &str = "P001";
+    /// The parser could not be set up or produced no tree
+    pub const PARSER: &str = "P002";
+    /// A JSON document (constraint tree, Schema, definitions) is malformed
+    pub const INVALID_JSON: &str = "P003";
+
+    /// A constraint names a field the Schema does not have
+    pub const UNKNOWN_FIELD: &str = "S001";
+    /// The two sides of a comparison have different types
+    pub const TYPE_MISMATCH: &str = "S002";
+    /// An AND/OR or cardinality group has no members
+    pub const EMPTY_GROUP: &str = "S003";
+    /// Constraints nest beyond `MAX_NESTING_DEPTH`
+    pub const TOO_DEEP: &str = "S004";
+    /// A named predicate is undefined, defined twice or cyclic
+    pub const PREDICATE: &str = "S005";
+    /// A transition's updates are malformed
+    pub const TRANSITION: &str = "S006";
+    /// A bitwise term reads a non-integer field or a mask wider than its field
+    pub const BITS: &str = "S007";
+
+    /// The constraints contradict each other
+    pub const UNSATISFIABLE: &str = "V001";
+    /// The solver failed or gave up
+    pub const SOLVER: &str = "V002";
+    /// A constraint could not be translated for the solver
+    pub const TRANSLATION: &str = "V003";
+
+    /// The target language is not supported
+    pub const UNSUPPORTED_LANGUAGE: &str = "G001";
+    /// The target needs contracts the specification does not provide
+    pub const MISSING_CONTRACT: &str = "G002";
+    /// Two fields or a keyword clash in the target's identifiers
+    pub const IDENTIFIER: &str = "G003";
+    /// Generation failed
+    pub const GENERATION: &str = "G004";
+    /// Reading or writing generated files failed
+    pub const IO: &str = "G005";
+}
+
+/// 1-based position in the input text; column 0 when only the line is known
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// What went wrong, independent of the stage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub code: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
+    /// Arguments of the code's message in the `i18n` catalogues
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, String>,
+}
+
+impl Diagnostic {
+    /// A diagnostic without a position
+    pub fn new(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+            span: None,
+            args: BTreeMap::new(),
+        }
+    }
+
+    /// Set an argument of the localized message
+    pub fn with_arg(mut self, name: &str, value: impl ToString) -> Self {
+        self.args.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Attach the position the problem was found at
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.span = Some(SourceSpan { line, column });
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] ", self.code)?;
+        if let Some(span) = self.span {
+            write!(f, "line {}, column {}: ", span.line, span.column)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// An error from any stage of the pipeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum CrucibleError {
+    #[error("parse error {0}")]
+    Parse(Diagnostic),
+
+    #[error("schema error {0}")]
+    Schema(Diagnostic),
+
+    #[error("verification error {0}")]
+    Verification(Diagnostic),
+
+    #[error("codegen error {0}")]
+    Codegen(Diagnostic),
+}
+
+impl CrucibleError {
+    /// Code, message and span, whatever the stage
+    pub fn diagnostic(&self) -> &Diagnostic {
+        match self {
+            CrucibleError::Parse(d)
+            | CrucibleError::Schema(d)
+            | CrucibleError::Verification(d)
+            | CrucibleError::Codegen(d) => d,
+        }
+    }
+
+    /// The stable code, e.g. `P001`
+    pub fn code(&self) -> &str {
+        &self.diagnostic().code
+    }
+
+    /// Where in the input the problem is, if known
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.diagnostic().span
+    }
+
+    /// The error in `locale`, like `Display`; the English message stands in
+    /// when the catalogue cannot render the code
+    pub fn localized(&self, locale: Locale) -> String {
+        let diagnostic = self.diagnostic();
+        let args: Vec<(&str, &str)> = diagnostic.args.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        let stage = match self {
+            CrucibleError::Parse(_) => "stage-parse",
+            CrucibleError::Schema(_) => "stage-schema",
+            CrucibleError::Verification(_) => "stage-verification",
+            CrucibleError::Codegen(_) => "stage-codegen",
+        };
+        let mut text = format!("{} [{}] ", locale.message(stage, &[]).unwrap_or_default(), diagnostic.code);
+        if let Some(span) = diagnostic.span {
+            let (line, column) = (span.line.to_string(), span.column.to_string());
+            let position = locale.message("position", &[("line", &line), ("column", &column)]);
+            text.push_str(&position.unwrap_or_default());
+            text.push_str(": ");
+        }
+        text.push_str(&locale.message(&diagnostic.code, &args).unwrap_or_else(|| diagnostic.message.clone()));
+        text
+    }
+
+    /// Process exit code: 3 parse, 4 schema, 5 verification, 6 codegen
+    /// (1 is left for failures outside the pipeline, 2 for usage errors)
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CrucibleError::Parse(_) => 3,
+            CrucibleError::Schema(_) => 4,
+            CrucibleError::Verification(_) => 5,
+            CrucibleError::Codegen(_) => 6,
+        }
+    }
+
+    /// HTTP status: the input's fault is 400 (unreadable or untranslatable)
+    /// or 422 (readable but wrong, including contradictory constraints); the
+    /// rest is 500
+    pub fn http_status(&self) -> u16 {
+        match (self, self.code()) {
+            (CrucibleError::Parse(_), codes::PARSER) => 500,
+            (CrucibleError::Parse(_), _) | (_, codes::TRANSLATION) => 400,
+            (CrucibleError::Schema(_), _) => 422,
+            (_, codes::UNSATISFIABLE | codes::UNSUPPORTED_LANGUAGE | codes::MISSING_CONTRACT | codes::IDENTIFIER) => {
+                422
+            }
+            _ => 500,
+        }
+    }
+}
+
+impl From<ConstraintError> for CrucibleError {
+    fn from(error: ConstraintError) -> Self {
+        // "a number" -> "number"
+        let kind = |description: &str| description.rsplit(' ').next().unwrap_or_default().to_string();
+        let diagnostic = |code| Diagnostic::new(code, error.to_string());
+        CrucibleError::Schema(match &error {
+            ConstraintError::UnknownVariable { name, constraint } => {
+                diagnostic(codes::UNKNOWN_FIELD).with_arg("name", name).with_arg("constraint", constraint)
+            }
+            ConstraintError::TypeMismatch { constraint, left, right } => diagnostic(codes::TYPE_MISMATCH)
+                .with_arg("constraint", constraint)
+                .with_arg("left", kind(left))
+                .with_arg("right", kind(right)),
+            ConstraintError::Empty(group) => diagnostic(codes::EMPTY_GROUP).with_arg("group", group),
+            ConstraintError::TooDeep { limit } => diagnostic(codes::TOO_DEEP).with_arg("limit", limit),
+            ConstraintError::NotInteger { name, constraint } => diagnostic(codes::BITS)
+                .with_arg("problem", "integer")
+                .with_arg("name", name)
+                .with_arg("constraint", constraint),
+            ConstraintError::OutOfWidth { value, field, bits, constraint } => diagnostic(codes::BITS)
+                .with_arg("problem", "width")
+                .with_arg("value", value)
+                .with_arg("name", field)
+                .with_arg("bits", bits)
+                .with_arg("constraint", constraint),
+        })
+    }
+}
+
+impl From<DefinitionError> for CrucibleError {
+    fn from(error: DefinitionError) -> Self {
+        let diagnostic = Diagnostic::new(codes::PREDICATE, error.to_string());
+        CrucibleError::Schema(match &error {
+            DefinitionError::Undefined(name) => diagnostic.with_arg("problem", "undefined").with_arg("name", name),
+            DefinitionError::Duplicate(name) => diagnostic.with_arg("problem", "duplicate").with_arg("name", name),
+            DefinitionError::Cyclic { name, path } => diagnostic
+                .with_arg("problem", "cyclic")
+                .with_arg("name", name)
+                .with_arg("path", path.join(" -> ")),
+        })
+    }
+}
+
+impl From<TransitionError> for CrucibleError {
+    fn from(error: TransitionError) -> Self {
+        let diagnostic = Diagnostic::new(codes::TRANSITION, error.to_string());
+        CrucibleError::Schema(match &error {
+            TransitionError::DuplicateUpdate(variable) => {
+                diagnostic.with_arg("problem", "duplicate").with_arg("variable", variable)
+            }
+            TransitionError::PrimedOperand { variable, operand } => diagnostic
+                .with_arg("problem", "primed")
+                .with_arg("variable", variable)
+                .with_arg("operand", operand),
+            TransitionError::InvalidExpression(expression) => {
+                diagnostic.with_arg("problem", "expression").with_arg("expression", expression)
+            }
+        })
+    }
+}
+
+impl From<serde_json::Error> for CrucibleError {
+    fn from(error: serde_json::Error) -> Self {
+        let diagnostic = Diagnostic::new(codes::INVALID_JSON, error.to_string()).with_arg("detail", &error);
+        CrucibleError::Parse(match error.line() {
+            0 => diagnostic,
+            line => diagnostic.at(line, error.column()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crucible_error() {
+        let error = CrucibleError::from(serde_json::from_str::<serde_json::Value>("{\n  \"a\": }").unwrap_err());
+        assert_eq!(error.code(), codes::INVALID_JSON);
+        assert_eq!(error.span(), Some(SourceSpan { line: 2, column: 8 }));
+        assert_eq!(error.exit_code(), 3);
+        assert_eq!(error.http_status(), 400);
+        assert!(error.to_string().starts_with("parse error [P003] line 2, column 8: "));
+
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(serde_json::from_str::<CrucibleError>(&json).unwrap(), error);
+
+        let error = CrucibleError::from(DefinitionError::Undefined("Eligible".to_string()));
+        assert_eq!((error.code(), error.exit_code(), error.http_status()), ("S005", 4, 422));
+        assert_eq!(error.to_string(), "schema error [S005] undefined predicate `Eligible`");
+        assert_eq!(error.localized(Locale::En), error.to_string());
+        assert_eq!(error.localized(Locale::De), "Schemafehler [S005] Prädikat `Eligible` ist nicht definiert");
+
+        let error = CrucibleError::from(ConstraintError::TypeMismatch {
+            constraint: "a > 1".to_string(),
+            left: "a string",
+            right: "a number",
+        });
+        assert_eq!(error.localized(Locale::En), error.to_string());
+        assert_eq!(
+            error.localized(Locale::De),
+            "Schemafehler [S002] `a > 1` vergleicht eine Zeichenkette mit einer Zahl"
+        );
+
+        // Without the arguments the catalogue needs, the English message stands in
+        let error = CrucibleError::Verification(Diagnostic::new(codes::SOLVER, "timeout"));
+        assert_eq!((error.exit_code(), error.http_status()), (5, 500));
+        assert_eq!(error.localized(Locale::De), "Verifikationsfehler [V002] timeout");
+    }
+}
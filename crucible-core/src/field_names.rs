@@ -0,0 +1,149 @@
+//! Per-Language Field Names - how a Schema field is spelled in each target
+//!
+//! A field is written once in the spec (`user_id`), but each target language
+//! has its own conventions and reserved words. A `FieldNaming` on the Schema
+//! picks a case convention per language and pins individual names:
+//!
+//! ```
+//! use crucible_core::{NamingConvention, Schema};
+//!
+//! let mut schema = Schema::new("TR-1".to_string());
+//! schema.set_naming_convention("typescript", NamingConvention::CamelCase);
+//! schema.rename_field("rust", "type", "kind");
+//! assert_eq!(schema.naming.overrides["rust"]["type"], "kind");
+//! ```
+//!
+//! Languages are named as on the command line (`rust`, `ts`, `spark-ada`);
+//! code generation applies the conventions and escapes reserved words.
+
+use crate::Schema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Case convention for generated identifiers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NamingConvention {
+    /// The name as written in the spec
+    #[default]
+    Preserve,
+    /// `user_id`
+    SnakeCase,
+    /// `userId`
+    CamelCase,
+    /// `UserId`
+    PascalCase,
+    /// `User_Id`
+    AdaCase,
+}
+
+impl NamingConvention {
+    /// `name` in this convention; word breaks are underscores, dashes and case changes
+    pub fn apply(self, name: &str) -> String {
+        let words = words(name);
+        match self {
+            NamingConvention::Preserve => name.to_string(),
+            NamingConvention::SnakeCase => {
+                words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_")
+            }
+            NamingConvention::CamelCase => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize(w) })
+                .collect(),
+            NamingConvention::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+            NamingConvention::AdaCase => words.iter().map(|w| capitalize(w)).collect::<Vec<_>>().join("_"),
+        }
+    }
+}
+
+/// Per-language case conventions and name overrides of a Schema's fields
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldNaming {
+    /// Language -> convention, replacing the language's default
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub conventions: BTreeMap<String, NamingConvention>,
+    /// Language -> field -> identifier; used verbatim, ahead of any convention
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overrides: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Schema {
+    /// Spell `field` as `name` in the code generated for `language`
+    pub fn rename_field(&mut self, language: &str, field: &str, name: &str) {
+        self.naming
+            .overrides
+            .entry(language.to_ascii_lowercase())
+            .or_default()
+            .insert(field.to_string(), name.to_string());
+    }
+
+    /// Convert every field name to `convention` in the code generated for `language`
+    pub fn set_naming_convention(&mut self, language: &str, convention: NamingConvention) {
+        self.naming.conventions.insert(language.to_ascii_lowercase(), convention);
+    }
+}
+
+/// `"userID_v2"` -> `["user", "ID", "v2"]`
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            words.push(std::mem::take(&mut word));
+            continue;
+        }
+        let previous = i.checked_sub(1).map(|p| chars[p]);
+        let next = chars.get(i + 1);
+        // `aB` and the `S` of `HTTPServer` start a word
+        let boundary = c.is_uppercase()
+            && previous.is_some_and(|p| {
+                p.is_lowercase() || p.is_ascii_digit() || (p.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+            });
+        if boundary {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push(c);
+    }
+    words.push(word);
+    words.retain(|w| !w.is_empty());
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_naming_conventions() {
+        let convert = |convention: NamingConvention, name: &str| convention.apply(name);
+        assert_eq!(convert(NamingConvention::SnakeCase, "userID"), "user_id");
+        assert_eq!(convert(NamingConvention::SnakeCase, "HTTPServer2Port"), "http_server2_port");
+        assert_eq!(convert(NamingConvention::CamelCase, "account_balance"), "accountBalance");
+        assert_eq!(convert(NamingConvention::PascalCase, "max-retry_count"), "MaxRetryCount");
+        assert_eq!(convert(NamingConvention::AdaCase, "accountBalance"), "Account_Balance");
+        assert_eq!(convert(NamingConvention::AdaCase, "amount"), "Amount");
+        assert_eq!(convert(NamingConvention::Preserve, "userID"), "userID");
+
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.set_naming_convention("TS", NamingConvention::CamelCase);
+        schema.rename_field("Rust", "type", "kind");
+        assert_eq!(schema.naming.conventions["ts"], NamingConvention::CamelCase);
+        assert_eq!(schema.naming.overrides["rust"]["type"], "kind");
+
+        let json = serde_json::to_string(&schema).unwrap();
+        assert!(json.contains(r#""conventions":{"ts":"camel_case"}"#));
+        let json = r#"{"fields":{},"documentation":{},"traceability_id":"x"}"#;
+        let without: Schema = serde_json::from_str(json).unwrap();
+        assert_eq!(without.naming, FieldNaming::default());
+    }
+}
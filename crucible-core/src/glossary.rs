@@ -0,0 +1,208 @@
+//! Domain Glossary - one canonical variable per concept
+//!
+//! Specs name the same concept in many ways ("balance", "account balance",
+//! "the user's balance"). Without a glossary each phrasing becomes its own
+//! Z3 variable and the constraints never interact. A project glossary maps
+//! every term and synonym to one canonical variable name, optionally with its
+//! data type, and is shared by the parser (normalization) and codegen (naming).
+
+use crate::{CompoundConstraint, Constraint, DataType, IntentAst, Schema};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// One domain concept
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlossaryTerm {
+    /// Variable name used in constraints and generated code
+    pub canonical: String,
+    /// Other phrasings that refer to the same concept
+    #[serde(default)]
+    pub synonyms: Vec<String>,
+    /// Data type, contributed to the Schema when known
+    #[serde(default)]
+    pub data_type: Option<DataType>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Project-wide term -> canonical variable mapping
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Glossary {
+    pub terms: Vec<GlossaryTerm>,
+}
+
+impl Glossary {
+    /// Create an empty glossary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a glossary from its JSON form (`{"terms": [...]}`)
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Load the project glossary from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(Self::from_json(&json)?)
+    }
+
+    /// Register a term and its synonyms
+    pub fn add_term(&mut self, canonical: &str, synonyms: &[&str], data_type: Option<DataType>) {
+        self.terms.push(GlossaryTerm {
+            canonical: canonical.to_string(),
+            synonyms: synonyms.iter().map(|s| s.to_string()).collect(),
+            data_type,
+            description: None,
+        });
+    }
+
+    /// Canonical variable for a term, synonym or variable-style phrase
+    pub fn canonicalize(&self, phrase: &str) -> Option<&str> {
+        let key = normalize_phrase(phrase);
+        self.terms
+            .iter()
+            .find(|term| {
+                normalize_phrase(&term.canonical) == key
+                    || term.synonyms.iter().any(|s| normalize_phrase(s) == key)
+            })
+            .map(|term| term.canonical.as_str())
+    }
+
+    /// Rewrite a constraint's variables to their canonical names
+    pub fn normalize_constraint(&self, constraint: &Constraint) -> Constraint {
+        let right_value = if is_variable(&constraint.right_value) {
+            self.canonical_or_self(&constraint.right_value)
+        } else {
+            constraint.right_value.clone()
+        };
+
+        Constraint {
+            left_variable: self.canonical_or_self(&constraint.left_variable),
+            operator: constraint.operator,
+            right_value,
+        }
+    }
+
+    /// Rewrite every variable in a constraint tree to its canonical name
+    pub fn normalize_compound(&self, compound: &CompoundConstraint) -> CompoundConstraint {
+        match compound {
+            CompoundConstraint::Simple(c) => CompoundConstraint::Simple(self.normalize_constraint(c)),
+            CompoundConstraint::Named(name) => CompoundConstraint::Named(name.clone()),
+            CompoundConstraint::Distinct(fields) => {
+                CompoundConstraint::Distinct(fields.iter().map(|field| self.canonical_or_self(field)).collect())
+            }
+            CompoundConstraint::And(constraints) => {
+                CompoundConstraint::And(constraints.iter().map(|c| self.normalize_compound(c)).collect())
+            }
+            CompoundConstraint::Or(constraints) => {
+                CompoundConstraint::Or(constraints.iter().map(|c| self.normalize_compound(c)).collect())
+            }
+            CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(self.normalize_compound(inner))),
+            CompoundConstraint::ExactlyOne(parts) => {
+                CompoundConstraint::ExactlyOne(parts.iter().map(|c| self.normalize_compound(c)).collect())
+            }
+            CompoundConstraint::AtMostOne(parts) => {
+                CompoundConstraint::AtMostOne(parts.iter().map(|c| self.normalize_compound(c)).collect())
+            }
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                CompoundConstraint::implies(self.normalize_compound(antecedent), self.normalize_compound(consequent))
+            }
+        }
+    }
+
+    /// Normalize the constraints of every requirement in place
+    pub fn normalize_ast(&self, ast: &mut IntentAst) {
+        for requirement in &mut ast.requirements {
+            for constraint in &mut requirement.constraints {
+                *constraint = self.normalize_constraint(constraint);
+            }
+        }
+    }
+
+    /// Schema with every typed term, documented by its description
+    pub fn to_schema(&self, traceability_id: String) -> Schema {
+        let mut schema = Schema::new(traceability_id);
+        for term in &self.terms {
+            if let Some(data_type) = &term.data_type {
+                schema.add_field(term.canonical.clone(), data_type.clone(), term.description.clone());
+            }
+        }
+        schema
+    }
+
+    fn canonical_or_self(&self, name: &str) -> String {
+        self.canonicalize(name).unwrap_or(name).to_string()
+    }
+}
+
+/// "The user's Account Balance" -> "user_account_balance"
+fn normalize_phrase(phrase: &str) -> String {
+    phrase
+        .split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .filter(|w| !matches!(w.as_str(), "the" | "a" | "an"))
+        .map(|w| w.trim_end_matches("'s").to_string())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn is_variable(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && value != "true"
+        && value != "false"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstraintOperator;
+
+    fn banking() -> Glossary {
+        let mut glossary = Glossary::new();
+        glossary.add_term("balance", &["account balance", "the user's balance"], Some(DataType::Uint64));
+        glossary.add_term("amount", &["withdrawal amount"], None);
+        glossary
+    }
+
+    #[test]
+    fn test_synonyms_collapse_to_one_variable() {
+        let glossary = banking();
+        assert_eq!(glossary.canonicalize("balance"), Some("balance"));
+        assert_eq!(glossary.canonicalize("Account Balance"), Some("balance"));
+        assert_eq!(glossary.canonicalize("account_balance"), Some("balance"));
+        assert_eq!(glossary.canonicalize("user's balance"), Some("balance"));
+        assert_eq!(glossary.canonicalize("limit"), None);
+
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "account_balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "withdrawal_amount".to_string(),
+        });
+        let normalized = glossary.normalize_compound(&compound);
+        assert_eq!(
+            normalized,
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "balance".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "amount".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip_and_schema() {
+        let glossary = Glossary::from_json(
+            r#"{"terms": [{"canonical": "balance", "synonyms": ["account balance"], "data_type": "Uint64"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(glossary.terms[0].synonyms, vec!["account balance".to_string()]);
+
+        let schema = glossary.to_schema("glossary-001".to_string());
+        assert_eq!(schema.get_type("balance"), DataType::Uint64);
+    }
+}
@@ -0,0 +1,408 @@
+//! Graph Export - constraint trees as Graphviz and Mermaid diagrams
+//!
+//! A large spec is easier to review as a picture of its logical structure
+//! than as nested text. Operator nodes (AND/OR/NOT, implications, cardinality)
+//! become ellipses, comparisons become boxes and named predicates become
+//! subroutine shapes; `IntentAst` graphs hang each requirement's tree under a
+//! node carrying its source sentence.
+//!
+//! ```text
+//! CompoundConstraint::to_dot()      -> digraph for `dot -Tsvg`
+//! CompoundConstraint::to_mermaid()  -> flowchart for Markdown renderers
+//! CompoundConstraint::to_svg()      -> self-contained SVG, laid out here
+//! ```
+
+use crate::{CompoundConstraint, Constraint, IntentAst};
+
+/// Node shape, mapped onto each format's vocabulary
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Operator,
+    Comparison,
+    Reference,
+    Requirement,
+}
+
+/// A format-independent diagram: labelled nodes and optionally labelled edges
+#[derive(Debug, Default)]
+struct Graph {
+    nodes: Vec<(String, Shape)>,
+    edges: Vec<(usize, usize, Option<&'static str>)>,
+}
+
+impl Graph {
+    fn node(&mut self, label: String, shape: Shape) -> usize {
+        self.nodes.push((label, shape));
+        self.nodes.len() - 1
+    }
+
+    /// Add `compound` below `parent` (if any) and return its node
+    fn add_tree(&mut self, compound: &CompoundConstraint, parent: Option<usize>, edge: Option<&'static str>) -> usize {
+        let id = match compound {
+            CompoundConstraint::Simple(c) => self.node(comparison_label(c), Shape::Comparison),
+            CompoundConstraint::Named(name) => self.node(name.clone(), Shape::Reference),
+            CompoundConstraint::Distinct(_) => self.node(compound.to_string(), Shape::Comparison),
+            CompoundConstraint::And(parts) => match compound.as_between() {
+                Some(range) => {
+                    let op = if range.inclusive { "<=" } else { "<" };
+                    let label = format!("{} {op} {} {op} {}", range.low, range.variable, range.high);
+                    self.node(label, Shape::Comparison)
+                }
+                None => self.add_children("AND", parts),
+            },
+            CompoundConstraint::Or(parts) => self.add_children("OR", parts),
+            CompoundConstraint::ExactlyOne(parts) => self.add_children("EXACTLY ONE", parts),
+            CompoundConstraint::AtMostOne(parts) => self.add_children("AT MOST ONE", parts),
+            CompoundConstraint::Not(inner) => {
+                let id = self.node("NOT".to_string(), Shape::Operator);
+                self.add_tree(inner, Some(id), None);
+                id
+            }
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                let id = self.node("IMPLIES".to_string(), Shape::Operator);
+                self.add_tree(antecedent, Some(id), Some("if"));
+                self.add_tree(consequent, Some(id), Some("then"));
+                id
+            }
+        };
+        if let Some(parent) = parent {
+            self.edges.push((parent, id, edge));
+        }
+        id
+    }
+
+    fn add_children(&mut self, label: &str, parts: &[CompoundConstraint]) -> usize {
+        let id = self.node(label.to_string(), Shape::Operator);
+        for part in parts {
+            self.add_tree(part, Some(id), None);
+        }
+        id
+    }
+
+    fn from_ast(ast: &IntentAst) -> Self {
+        let mut graph = Graph::default();
+        let root = graph.node(format!("Intent-AST {}", ast.id), Shape::Requirement);
+        for (index, requirement) in ast.requirements.iter().enumerate() {
+            let label = format!("R{}: {}", index + 1, requirement.content);
+            let id = graph.node(label, Shape::Requirement);
+            graph.edges.push((root, id, None));
+            match requirement.constraints.as_slice() {
+                [] => {}
+                [only] => {
+                    graph.add_tree(&CompoundConstraint::Simple(only.clone()), Some(id), None);
+                }
+                all => {
+                    let tree = CompoundConstraint::And(all.iter().cloned().map(CompoundConstraint::Simple).collect());
+                    graph.add_tree(&tree, Some(id), None);
+                }
+            }
+        }
+        graph
+    }
+
+    fn to_dot(&self) -> String {
+        let mut out = String::from("digraph constraints {\n  rankdir=TB;\n  node [fontname=\"Helvetica\"];\n");
+        for (id, (label, shape)) in self.nodes.iter().enumerate() {
+            let shape = match shape {
+                Shape::Operator => "ellipse",
+                Shape::Comparison => "box",
+                Shape::Reference => "component",
+                Shape::Requirement => "note",
+            };
+            out.push_str(&format!("  n{} [label=\"{}\", shape={}];\n", id, dot_escape(label), shape));
+        }
+        for (from, to, label) in &self.edges {
+            match label {
+                Some(label) => out.push_str(&format!("  n{} -> n{} [label=\"{}\"];\n", from, to, label)),
+                None => out.push_str(&format!("  n{} -> n{};\n", from, to)),
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut out = String::from("flowchart TD\n");
+        for (id, (label, shape)) in self.nodes.iter().enumerate() {
+            let label = mermaid_escape(label);
+            let node = match shape {
+                Shape::Operator => format!("n{}((\"{}\"))", id, label),
+                Shape::Comparison => format!("n{}[\"{}\"]", id, label),
+                Shape::Reference => format!("n{}[[\"{}\"]]", id, label),
+                Shape::Requirement => format!("n{}>\"{}\"]", id, label),
+            };
+            out.push_str(&format!("  {}\n", node));
+        }
+        for (from, to, label) in &self.edges {
+            match label {
+                Some(label) => out.push_str(&format!("  n{} -->|{}| n{}\n", from, label, to)),
+                None => out.push_str(&format!("  n{} --> n{}\n", from, to)),
+            }
+        }
+        out
+    }
+}
+
+/// Layout of `to_svg`, in pixels
+const SVG_CHAR_WIDTH: usize = 7;
+const SVG_NODE_HEIGHT: usize = 28;
+const SVG_LEVEL_HEIGHT: usize = 64;
+const SVG_GAP: usize = 16;
+
+impl Graph {
+    /// Top-down tidy layout of a tree: leaves side by side in order, each
+    /// parent centred over its children
+    fn to_svg(&self) -> String {
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        let mut is_child = vec![false; self.nodes.len()];
+        for &(from, to, _) in &self.edges {
+            children[from].push(to);
+            is_child[to] = true;
+        }
+        let node_width = |id: usize| self.nodes[id].0.chars().count() * SVG_CHAR_WIDTH + 20;
+        let mut widths: Vec<usize> = (0..self.nodes.len()).map(node_width).collect();
+        // Children are always added after their parent, so a reverse sweep sees them first
+        for id in (0..self.nodes.len()).rev() {
+            let below: usize = children[id].iter().map(|&child| widths[child]).sum();
+            let gaps = children[id].len().saturating_sub(1) * SVG_GAP;
+            widths[id] = widths[id].max(below + gaps);
+        }
+
+        /// Centre `x` and depth of every node of the subtree starting at `left`
+        fn place(
+            id: usize,
+            left: usize,
+            depth: usize,
+            children: &[Vec<usize>],
+            widths: &[usize],
+            at: &mut [(usize, usize)],
+        ) {
+            let kids = &children[id];
+            if kids.is_empty() {
+                at[id] = (left + widths[id] / 2, depth);
+                return;
+            }
+            let span = kids.iter().map(|&child| widths[child]).sum::<usize>() + (kids.len() - 1) * SVG_GAP;
+            let mut x = left + (widths[id] - span) / 2;
+            for &child in kids {
+                place(child, x, depth + 1, children, widths, at);
+                x += widths[child] + SVG_GAP;
+            }
+            at[id] = ((at[kids[0]].0 + at[kids[kids.len() - 1]].0) / 2, depth);
+        }
+
+        let mut positions = vec![(0, 0); self.nodes.len()];
+        let mut left = SVG_GAP;
+        for root in (0..self.nodes.len()).filter(|&id| !is_child[id]) {
+            place(root, left, 0, &children, &widths, &mut positions);
+            left += widths[root] + SVG_GAP;
+        }
+        let depth = positions.iter().map(|&(_, depth)| depth).max().unwrap_or(0);
+        let width = left;
+        let height = depth * SVG_LEVEL_HEIGHT + SVG_NODE_HEIGHT + 2 * SVG_GAP;
+        let y = |depth: usize| SVG_GAP + depth * SVG_LEVEL_HEIGHT + SVG_NODE_HEIGHT / 2;
+
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" \
+             font-family=\"Helvetica, Arial, sans-serif\" font-size=\"12\">\n",
+            w = width,
+            h = height
+        );
+        for &(from, to, label) in &self.edges {
+            let (x1, d1) = positions[from];
+            let (x2, d2) = positions[to];
+            let (y1, y2) = (y(d1) + SVG_NODE_HEIGHT / 2, y(d2) - SVG_NODE_HEIGHT / 2);
+            out.push_str(&format!(
+                "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#666\"/>\n",
+                x1, y1, x2, y2
+            ));
+            if let Some(label) = label {
+                out.push_str(&format!(
+                    "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" fill=\"#666\">{}</text>\n",
+                    (x1 + x2) / 2,
+                    (y1 + y2) / 2,
+                    label
+                ));
+            }
+        }
+        for (id, (label, shape)) in self.nodes.iter().enumerate() {
+            let (x, depth) = positions[id];
+            let (w, h, cy) = (node_width(id), SVG_NODE_HEIGHT, y(depth));
+            let outline = match shape {
+                Shape::Operator => format!(
+                    "<ellipse cx=\"{}\" cy=\"{}\" rx=\"{}\" ry=\"{}\" fill=\"#eef\" stroke=\"#446\"/>",
+                    x,
+                    cy,
+                    w / 2,
+                    h / 2
+                ),
+                Shape::Comparison | Shape::Reference | Shape::Requirement => {
+                    let (radius, dash) = match shape {
+                        Shape::Requirement => (6, ""),
+                        Shape::Reference => (0, " stroke-dasharray=\"4 2\""),
+                        _ => (0, ""),
+                    };
+                    format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\" \
+                         fill=\"#fff\" stroke=\"#446\"{}/>",
+                        x - w / 2,
+                        cy - h / 2,
+                        w,
+                        h,
+                        radius,
+                        dash
+                    )
+                }
+            };
+            out.push_str(&format!(
+                "  {}\n  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n",
+                outline,
+                x,
+                cy,
+                xml_escape(label)
+            ));
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+fn comparison_label(c: &Constraint) -> String {
+    format!("{} {} {}", c.left_variable, c.operator.symbol(), c.right_value)
+}
+
+/// Quotes and backslashes inside a Graphviz `label="..."`
+fn dot_escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Text content of an SVG element
+fn xml_escape(label: &str) -> String {
+    label.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Mermaid labels take HTML entities; quotes would end the label
+fn mermaid_escape(label: &str) -> String {
+    label.replace('"', "#quot;")
+}
+
+impl CompoundConstraint {
+    /// Graphviz `digraph` of the tree, for `dot -Tsvg`
+    pub fn to_dot(&self) -> String {
+        let mut graph = Graph::default();
+        graph.add_tree(self, None, None);
+        graph.to_dot()
+    }
+
+    /// Mermaid `flowchart` of the tree, for Markdown renderers
+    pub fn to_mermaid(&self) -> String {
+        let mut graph = Graph::default();
+        graph.add_tree(self, None, None);
+        graph.to_mermaid()
+    }
+
+    /// The tree as a standalone SVG image, for documents that must not load
+    /// a renderer
+    pub fn to_svg(&self) -> String {
+        let mut graph = Graph::default();
+        graph.add_tree(self, None, None);
+        graph.to_svg()
+    }
+}
+
+impl IntentAst {
+    /// Graphviz `digraph` with one node per requirement, labelled with its
+    /// source text, above the requirement's constraints
+    pub fn to_dot(&self) -> String {
+        Graph::from_ast(self).to_dot()
+    }
+
+    /// Mermaid `flowchart` counterpart of `to_dot`
+    pub fn to_mermaid(&self) -> String {
+        Graph::from_ast(self).to_mermaid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstraintOperator;
+
+    fn c(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    fn withdrawal() -> CompoundConstraint {
+        CompoundConstraint::Or(vec![
+            c("role", ConstraintOperator::Equal, "\"admin\""),
+            CompoundConstraint::implies(
+                c("amount", ConstraintOperator::GreaterThan, "1000"),
+                CompoundConstraint::Named("approved".to_string()),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_dot_export() {
+        assert_eq!(
+            withdrawal().to_dot(),
+            "digraph constraints {\n  rankdir=TB;\n  node [fontname=\"Helvetica\"];\n\
+             \x20 n0 [label=\"OR\", shape=ellipse];\n\
+             \x20 n1 [label=\"role == \\\"admin\\\"\", shape=box];\n\
+             \x20 n2 [label=\"IMPLIES\", shape=ellipse];\n\
+             \x20 n3 [label=\"amount > 1000\", shape=box];\n\
+             \x20 n4 [label=\"approved\", shape=component];\n\
+             \x20 n0 -> n1;\n\
+             \x20 n2 -> n3 [label=\"if\"];\n\
+             \x20 n2 -> n4 [label=\"then\"];\n\
+             \x20 n0 -> n2;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_svg_export() {
+        let svg = withdrawal().to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<ellipse").count(), 2);
+        assert_eq!(svg.matches("<line").count(), 4);
+        assert!(svg.contains(">role == \"admin\"</text>"));
+        assert!(svg.contains(">then</text>"));
+        assert!(svg.contains("stroke-dasharray"));
+
+        // Siblings never overlap, and a parent sits above its children
+        let xs: Vec<usize> = svg
+            .lines()
+            .filter(|line| line.contains("dominant-baseline"))
+            .map(|line| line.split("x=\"").nth(1).unwrap().split('"').next().unwrap().parse().unwrap())
+            .collect();
+        let (or, role, implies, amount, approved) = (xs[0], xs[1], xs[2], xs[3], xs[4]);
+        assert!(role < implies && amount < approved);
+        assert!(role < or && or < implies);
+        assert!(amount < implies && implies < approved);
+    }
+
+    #[test]
+    fn test_mermaid_export_with_requirements() {
+        let mermaid = withdrawal().to_mermaid();
+        assert!(mermaid.starts_with("flowchart TD\n  n0((\"OR\"))\n"));
+        assert!(mermaid.contains("  n1[\"role == #quot;admin#quot;\"]\n"));
+        assert!(mermaid.contains("  n4[[\"approved\"]]\n"));
+        assert!(mermaid.contains("  n2 -->|then| n4\n"));
+
+        let mut ast = IntentAst::new();
+        ast.add_requirement("User can withdraw if balance >= amount".to_string());
+        ast.requirements[0].constraints = vec![Constraint {
+            left_variable: "balance".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "amount".to_string(),
+        }];
+        let mermaid = ast.to_mermaid();
+        assert!(mermaid.contains("  n1>\"R1: User can withdraw if balance >= amount\"]\n"));
+        assert!(mermaid.contains("  n2[\"balance >= amount\"]\n  n0 --> n1\n  n1 --> n2\n"));
+    }
+}
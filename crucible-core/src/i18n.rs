@@ -0,0 +1,223 @@
+//! Message Catalogues - user-facing text in the reader's language
+//!
+//! Diagnostics and verbalized constraints are looked up by message ID in a
+//! per-locale catalogue (`locales/<locale>.ftl`, compiled in) instead of
+//! being formatted in English. The files use Fluent syntax: `id = pattern`,
+//! `{ $name }` for arguments and select expressions for wording that
+//! depends on an argument:
+//!
+//! ```text
+//! S005 = { $problem ->
+//!         [undefined] undefined predicate `{ $name }`
+//!        *[duplicate] predicate `{ $name }` is already defined
+//!     }
+//! ```
+//!
+//! A message missing from a locale falls back to English.
+//!
+//! ```
+//! use crucible_core::Locale;
+//!
+//! let locale = Locale::from_tag("de-CH").unwrap();
+//! assert_eq!(
+//!     locale.message("S004", &[("limit", "64")]).unwrap(),
+//!     "Bedingungen sind tiefer als 64 Ebenen verschachtelt"
+//! );
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// A language user-facing text can be rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Es,
+}
+
+const SOURCES: [(Locale, &str); 3] = [
+    (Locale::En, include_str!("../locales/en.ftl")),
+    (Locale::De, include_str!("../locales/de.ftl")),
+    (Locale::Es, include_str!("../locales/es.ftl")),
+];
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::De, Locale::Es];
+
+    /// The locale of a BCP 47 tag or POSIX locale name (`de`, `es-MX`,
+    /// `de_DE.UTF-8`); only the language is considered
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.trim().split(['-', '_', '.', '@']).next()?;
+        Self::ALL.into_iter().find(|locale| locale.tag().eq_ignore_ascii_case(language))
+    }
+
+    /// The best supported locale of an `Accept-Language` header, English if none is
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut ranges: Vec<(f32, Locale)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let locale = Self::from_tag(parts.next()?)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse().ok())?;
+                Some((quality, locale))
+            })
+            .filter(|(quality, _)| *quality > 0.0)
+            .collect();
+        // Stable, so equal weights keep the client's order
+        ranges.sort_by(|a, b| b.0.total_cmp(&a.0));
+        ranges.first().map_or(Locale::En, |(_, locale)| *locale)
+    }
+
+    /// `CRUCIBLE_LOCALE`, else the POSIX `LC_ALL`, `LC_MESSAGES` and `LANG`;
+    /// English when none names a supported language
+    pub fn from_env() -> Self {
+        ["CRUCIBLE_LOCALE", "LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|name| std::env::var(name).ok().filter(|value| !value.is_empty()))
+            .find_map(|value| Self::from_tag(&value))
+            .unwrap_or_default()
+    }
+
+    /// The language subtag: `en`, `de`, `es`
+    pub fn tag(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Message `id` with `args` filled in, from this locale's catalogue or
+    /// else the English one; `None` when neither has `id` or an argument it
+    /// uses is missing
+    pub fn message(self, id: &str, args: &[(&str, &str)]) -> Option<String> {
+        let pattern = catalogue(self).get(id).or_else(|| catalogue(Locale::En).get(id))?;
+        render(pattern, args)
+    }
+
+    /// IDs of the messages this locale's catalogue defines
+    pub fn message_ids(self) -> impl Iterator<Item = &'static str> {
+        catalogue(self).keys().copied()
+    }
+}
+
+fn catalogue(locale: Locale) -> &'static HashMap<&'static str, String> {
+    static CATALOGUES: OnceLock<HashMap<Locale, HashMap<&'static str, String>>> = OnceLock::new();
+    &CATALOGUES.get_or_init(|| SOURCES.iter().map(|(locale, source)| (*locale, parse(source))).collect())[&locale]
+}
+
+/// Messages of a `.ftl` file; indented lines continue the message above
+fn parse(source: &'static str) -> HashMap<&'static str, String> {
+    let mut messages = HashMap::new();
+    let mut current: Option<(&str, String)> = None;
+    for line in source.lines() {
+        if line.starts_with(' ') {
+            if let Some((_, pattern)) = &mut current {
+                pattern.push('\n');
+                pattern.push_str(line.trim());
+            }
+            continue;
+        }
+        messages.extend(current.take());
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        if let Some((id, pattern)) = line.split_once('=') {
+            current = Some((id.trim(), pattern.trim().to_string()));
+        }
+    }
+    messages.extend(current);
+    messages
+}
+
+/// Fill in `{ $name }` placeables and pick the variants of select expressions
+fn render(pattern: &str, args: &[(&str, &str)]) -> Option<String> {
+    let arg = |name: &str| args.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+    let mut out = String::new();
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let end = start + closing_brace(&rest[start..])?;
+        let placeable = rest[start + 1..end].trim();
+        match placeable.split_once("->") {
+            Some((selector, variants)) => {
+                let value = arg(selector.trim().strip_prefix('$')?)?;
+                out.push_str(&render(select(variants, value)?, args)?);
+            }
+            None => out.push_str(arg(placeable.strip_prefix('$')?)?),
+        }
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// Offset of the `}` closing the `{` that `text` starts with
+fn closing_brace(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (offset, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return Some(offset),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The variant keyed `value`, or the `*` default; one variant per line
+fn select<'a>(variants: &'a str, value: &str) -> Option<&'a str> {
+    let mut default = None;
+    for line in variants.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (is_default, line) = match line.strip_prefix('*') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+        let (key, text) = line.strip_prefix('[')?.split_once(']')?;
+        if key.trim() == value {
+            return Some(text.trim());
+        }
+        if is_default {
+            default = Some(text.trim());
+        }
+    }
+    default
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_catalogues() {
+        let english: BTreeSet<&str> = Locale::En.message_ids().collect();
+        for locale in Locale::ALL {
+            assert_eq!(locale.message_ids().collect::<BTreeSet<_>>(), english, "{:?}", locale);
+        }
+
+        let args = [("problem", "cyclic"), ("name", "Eligible"), ("path", "Eligible -> Adult -> Eligible")];
+        assert_eq!(
+            Locale::En.message("S005", &args).unwrap(),
+            "predicate `Eligible` refers to itself through Eligible -> Adult -> Eligible"
+        );
+        assert_eq!(
+            Locale::Es.message("S005", &[("problem", "undefined"), ("name", "Apto")]).unwrap(),
+            "predicado `Apto` no definido"
+        );
+        assert_eq!(Locale::De.message("S001", &[("name", "fee")]), None);
+        assert_eq!(Locale::De.message("no-such-message", &[]), None);
+
+        assert_eq!(Locale::from_tag("de_AT.UTF-8"), Some(Locale::De));
+        assert_eq!(Locale::from_tag("fr"), None);
+        assert_eq!(Locale::negotiate("fr-CH, fr;q=0.9, es;q=0.5, de;q=0.7"), Locale::De);
+        assert_eq!(Locale::negotiate("*"), Locale::En);
+    }
+}
@@ -5,11 +5,76 @@
 //!
 //! Provisional Patent Application: 63/928,407
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use uuid::Uuid;
 
+pub mod arena;
+pub mod bitwise;
+pub mod call;
+pub mod confidence;
+pub mod components;
+pub mod conditional;
+pub mod config;
+pub mod custom_types;
+pub mod definitions;
+pub mod dependencies;
+pub mod entities;
+pub mod error;
+pub mod field_names;
+pub mod glossary;
+pub mod graph;
+pub mod i18n;
+pub mod lifecycle;
+pub mod overflow;
+mod pdf;
+pub mod presolve;
+pub mod report;
+#[cfg(feature = "signing")]
+pub mod signing;
+pub mod state_machine;
+pub mod tags;
+pub mod templates;
+pub mod timing;
+pub mod tolerance;
+pub mod transition;
+pub mod validate;
+pub mod verbalize;
+
+pub use arena::{ConstraintArena, Node, NodeId};
+pub use bitwise::{BitWidth, Bitwise, BitwiseOperator};
+pub use call::{Call, MathFunction};
+pub use components::ComponentContract;
+pub use conditional::Conditional;
+pub use confidence::ConfidenceBreakdown;
+pub use config::{ConfigError, CrucibleConfig, FormatConfig, HeaderPolicy, TenantConfig};
+pub use custom_types::{TypeMapping, TypeRegistry};
+pub use definitions::{DefinitionError, Definitions};
+pub use dependencies::{DependencyGraph, Impact};
+
+pub use entities::{resolve_entities, EntityGlossary};
+pub use error::{CrucibleError, Diagnostic, SourceSpan};
+pub use field_names::{FieldNaming, NamingConvention};
+pub use glossary::{Glossary, GlossaryTerm};
+pub use i18n::Locale;
+pub use lifecycle::{ChangeEntry, LifecycleError, RequirementStatus};
+pub use overflow::{ArithmeticTerm, OverflowRisk};
+pub use presolve::{presolve, Interval, Presolved};
+pub use report::{ReportBuilder, SolverVerdict};
+#[cfg(feature = "signing")]
+pub use signing::{ArtifactSignature, ArtifactSigner, SignatureManifest, SigningError};
+pub use state_machine::{MachineTransition, StateMachine};
+pub use tags::{normalize_tag, split_tags, TagError};
+pub use templates::{RequirementTemplate, TemplateError, TemplateLibrary};
+pub use timing::{StageTiming, StageTimings};
+pub use tolerance::{split_tolerance, Tolerance};
+pub use transition::{prime_state, StateTransition, StateUpdate, TransitionError, UpdateExpr};
+pub use validate::{ConstraintError, MAX_NESTING_DEPTH};
+pub use verbalize::Verbalizer;
+
 /// Operators for constraint expressions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConstraintOperator {
     GreaterThanOrEqual,
     LessThanOrEqual,
@@ -17,6 +82,31 @@ pub enum ConstraintOperator {
     LessThan,
     Equal,
     NotEqual,
+    /// The string `left` contains `right` as a substring
+    Contains,
+    DoesNotContain,
+}
+
+impl ConstraintOperator {
+    /// `Contains`/`DoesNotContain`, which targets render as a membership
+    /// test rather than an infix comparison
+    pub fn is_membership(&self) -> bool {
+        matches!(self, ConstraintOperator::Contains | ConstraintOperator::DoesNotContain)
+    }
+
+    /// Infix spelling, as written in requirements (`>=`, `contains`)
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "==",
+            ConstraintOperator::NotEqual => "!=",
+            ConstraintOperator::Contains => "contains",
+            ConstraintOperator::DoesNotContain => "does not contain",
+        }
+    }
 }
 
 /// A simple constraint expression: `left_variable operator right_value`
@@ -27,6 +117,119 @@ pub struct Constraint {
     pub right_value: String,
 }
 
+impl Constraint {
+    /// `variable % divisor == 0`
+    pub fn multiple_of(variable: &str, divisor: &str) -> Self {
+        Self {
+            left_variable: Modulo::new(variable, divisor).to_string(),
+            operator: ConstraintOperator::Equal,
+            right_value: "0".to_string(),
+        }
+    }
+
+    /// The remainder on the left-hand side, for `id % 10 == 0`
+    pub fn modulo(&self) -> Option<Modulo> {
+        Modulo::parse(&self.left_variable)
+    }
+
+    /// `field & (1 << bit) != 0`: `field` has bit `bit` (counted from 0) set
+    pub fn bit_set(field: &str, bit: u32) -> Self {
+        Self {
+            left_variable: Bitwise::new(field, BitwiseOperator::And, &format!("{:#x}", 1u128 << bit)).to_string(),
+            operator: ConstraintOperator::NotEqual,
+            right_value: "0".to_string(),
+        }
+    }
+
+    /// `field & (1 << bit) == 0`: `field` has bit `bit` clear
+    pub fn bit_clear(field: &str, bit: u32) -> Self {
+        Self {
+            operator: ConstraintOperator::Equal,
+            ..Self::bit_set(field, bit)
+        }
+    }
+
+    /// The mask or shift on the left-hand side, for `flags & 0x8 != 0`
+    pub fn bitwise(&self) -> Option<Bitwise> {
+        Bitwise::parse(&self.left_variable)
+    }
+
+    /// The if-then-else value on the right, for `fee == 0 if member else 5`
+    pub fn conditional(&self) -> Option<Conditional> {
+        Conditional::parse(&self.right_value)
+    }
+
+    /// Fields the constraint reads: the left operand (both sides of a
+    /// remainder or mask, the arguments of a call) and the right value when it
+    /// names a field, is a call or is a conditional
+    pub fn variables(&self) -> Vec<String> {
+        let names_field = |value: &str| {
+            value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+                && !matches!(value, "true" | "false")
+        };
+        let mut variables = match (self.modulo(), Call::parse(&self.left_variable)) {
+            (Some(m), _) => {
+                let mut operands = vec![m.dividend];
+                if names_field(&m.divisor) {
+                    operands.push(m.divisor);
+                }
+                operands
+            }
+            (None, Some(call)) => call.variables(),
+            (None, None) => match self.bitwise() {
+                Some(bitwise) => bitwise.variables(),
+                None => vec![self.left_variable.clone()],
+            },
+        };
+        if names_field(&self.right_value) {
+            variables.push(self.right_value.clone());
+        } else if let Some(call) = Call::parse(&self.right_value) {
+            variables.extend(call.variables());
+        } else if let Some(conditional) = self.conditional() {
+            variables.extend(conditional.variables());
+        }
+        variables
+    }
+}
+
+/// `dividend % divisor` as the left-hand side of a constraint, written
+/// `"id % 10"` in `Constraint::left_variable`. The remainder is Euclidean
+/// (`0 <= r < |divisor|`), so `-7 % 10` is 3 in every target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Modulo {
+    pub dividend: String,
+    pub divisor: String,
+}
+
+impl Modulo {
+    pub fn new(dividend: &str, divisor: &str) -> Self {
+        Self {
+            dividend: dividend.to_string(),
+            divisor: divisor.to_string(),
+        }
+    }
+
+    /// Parse `id % 10` or `id mod 10`
+    pub fn parse(text: &str) -> Option<Self> {
+        if text.contains('(') {
+            return None;
+        }
+        match text.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [dividend, op, divisor] if ArithmeticOperator::from_symbol(op) == Some(ArithmeticOperator::Modulo) => {
+                Some(Self::new(dividend, divisor))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Modulo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} % {}", self.dividend, self.divisor)
+    }
+}
+
 impl From<&str> for Constraint {
     fn from(s: &str) -> Self {
         Self {
@@ -47,26 +250,270 @@ impl From<String> for Constraint {
     }
 }
 
-/// A constraint that can be simple or compound (AND/OR/NOT tree)
+impl std::fmt::Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.left_variable, self.operator.symbol(), self.right_value)
+    }
+}
+
+/// A constraint that can be simple or compound (AND/OR/NOT/IMPLIES tree,
+/// plus cardinality over a list of conditions and uniqueness over fields)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CompoundConstraint {
     And(Vec<CompoundConstraint>),
     Or(Vec<CompoundConstraint>),
     Not(Box<CompoundConstraint>),
+    /// `if antecedent then consequent`; holds whenever the antecedent does not
+    Implies(Box<CompoundConstraint>, Box<CompoundConstraint>),
+    /// Exactly one of the conditions holds ("card or bank transfer, not both")
+    ExactlyOne(Vec<CompoundConstraint>),
+    /// No two of the conditions hold together
+    AtMostOne(Vec<CompoundConstraint>),
+    /// No two of the fields are equal ("sender and receiver differ"); a
+    /// single list field (`DataType::List`) has no two equal elements
+    Distinct(Vec<String>),
     Simple(Constraint),
+    /// Reference to a predicate in the `Definitions` table ("sufficient_funds")
+    Named(String),
+}
+
+/// Requirement-style text, e.g. `(amount > 0 AND NOT (status == 2))`
+impl std::fmt::Display for CompoundConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |parts: &[CompoundConstraint], separator: &str| {
+            parts.iter().map(|part| part.to_string()).collect::<Vec<_>>().join(separator)
+        };
+        match self {
+            CompoundConstraint::And(parts) => write!(f, "({})", join(parts, " AND ")),
+            CompoundConstraint::Or(parts) => write!(f, "({})", join(parts, " OR ")),
+            CompoundConstraint::Not(inner) => match inner.as_ref() {
+                CompoundConstraint::Simple(_) => write!(f, "NOT ({})", inner),
+                _ => write!(f, "NOT {}", inner),
+            },
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                write!(f, "(IF {} THEN {})", antecedent, consequent)
+            }
+            CompoundConstraint::ExactlyOne(parts) => write!(f, "exactly one of ({})", join(parts, ", ")),
+            CompoundConstraint::AtMostOne(parts) => write!(f, "at most one of ({})", join(parts, ", ")),
+            CompoundConstraint::Distinct(fields) => write!(f, "distinct({})", fields.join(", ")),
+            CompoundConstraint::Simple(constraint) => write!(f, "{}", constraint),
+            CompoundConstraint::Named(name) => f.write_str(name),
+        }
+    }
 }
 
 impl CompoundConstraint {
     /// Count the number of simple constraints in the tree
     pub fn count_constraints(&self) -> usize {
         match self {
-            CompoundConstraint::And(constraints) | CompoundConstraint::Or(constraints) => {
-                constraints.iter().map(|c| c.count_constraints()).sum()
-            }
+            CompoundConstraint::And(constraints)
+            | CompoundConstraint::Or(constraints)
+            | CompoundConstraint::ExactlyOne(constraints)
+            | CompoundConstraint::AtMostOne(constraints) => constraints.iter().map(|c| c.count_constraints()).sum(),
             CompoundConstraint::Not(constraint) => constraint.count_constraints(),
-            CompoundConstraint::Simple(_) => 1,
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                antecedent.count_constraints() + consequent.count_constraints()
+            }
+            CompoundConstraint::Simple(_) | CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => 1,
+        }
+    }
+
+    /// Names of the predicates referenced directly in the tree, in order of appearance
+    pub fn references(&self) -> Vec<&str> {
+        match self {
+            CompoundConstraint::Named(name) => vec![name.as_str()],
+            CompoundConstraint::Simple(_) | CompoundConstraint::Distinct(_) => Vec::new(),
+            CompoundConstraint::And(parts)
+            | CompoundConstraint::Or(parts)
+            | CompoundConstraint::ExactlyOne(parts)
+            | CompoundConstraint::AtMostOne(parts) => parts.iter().flat_map(|part| part.references()).collect(),
+            CompoundConstraint::Not(inner) => inner.references(),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                antecedent.references().into_iter().chain(consequent.references()).collect()
+            }
+        }
+    }
+
+    /// The simple constraints of the tree, in order of appearance
+    pub fn leaves(&self) -> Vec<&Constraint> {
+        match self {
+            CompoundConstraint::Simple(constraint) => vec![constraint],
+            CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => Vec::new(),
+            CompoundConstraint::And(parts)
+            | CompoundConstraint::Or(parts)
+            | CompoundConstraint::ExactlyOne(parts)
+            | CompoundConstraint::AtMostOne(parts) => parts.iter().flat_map(|part| part.leaves()).collect(),
+            CompoundConstraint::Not(inner) => inner.leaves(),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                antecedent.leaves().into_iter().chain(consequent.leaves()).collect()
+            }
         }
     }
+
+    /// The tree with the operands of AND/OR and the cardinality nodes in a
+    /// fixed order and nested ANDs/ORs flattened, so that equivalent phrasings
+    /// ("a and b" / "b and a") compare equal
+    pub fn canonical(&self) -> CompoundConstraint {
+        fn sorted(parts: Vec<CompoundConstraint>) -> Vec<CompoundConstraint> {
+            let mut keyed: Vec<(String, CompoundConstraint)> = parts
+                .into_iter()
+                .map(|part| (serde_json::to_string(&part).unwrap_or_default(), part))
+                .collect();
+            keyed.sort_by(|a, b| a.0.cmp(&b.0));
+            keyed.into_iter().map(|(_, part)| part).collect()
+        }
+        fn flatten(parts: &[CompoundConstraint], and: bool) -> Vec<CompoundConstraint> {
+            parts
+                .iter()
+                .map(CompoundConstraint::canonical)
+                .flat_map(|part| match part {
+                    CompoundConstraint::And(inner) if and => inner,
+                    CompoundConstraint::Or(inner) if !and => inner,
+                    other => vec![other],
+                })
+                .collect()
+        }
+
+        match self {
+            CompoundConstraint::And(parts) => CompoundConstraint::And(sorted(flatten(parts, true))),
+            CompoundConstraint::Or(parts) => CompoundConstraint::Or(sorted(flatten(parts, false))),
+            CompoundConstraint::ExactlyOne(parts) => {
+                CompoundConstraint::ExactlyOne(sorted(parts.iter().map(|p| p.canonical()).collect()))
+            }
+            CompoundConstraint::AtMostOne(parts) => {
+                CompoundConstraint::AtMostOne(sorted(parts.iter().map(|p| p.canonical()).collect()))
+            }
+            CompoundConstraint::Distinct(fields) => {
+                let mut fields = fields.clone();
+                fields.sort();
+                CompoundConstraint::Distinct(fields)
+            }
+            CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(inner.canonical())),
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                CompoundConstraint::implies(antecedent.canonical(), consequent.canonical())
+            }
+            CompoundConstraint::Simple(_) | CompoundConstraint::Named(_) => self.clone(),
+        }
+    }
+
+    /// Stable 64-bit FNV-1a hash of the canonical tree, as 16 hex digits;
+    /// equal for equivalent phrasings and across runs and platforms
+    pub fn canonical_hash(&self) -> String {
+        let json = serde_json::to_string(&self.canonical()).unwrap_or_default();
+        let hash = json.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        format!("{:016x}", hash)
+    }
+
+    /// `if antecedent then consequent`
+    pub fn implies(antecedent: CompoundConstraint, consequent: CompoundConstraint) -> Self {
+        CompoundConstraint::Implies(Box::new(antecedent), Box::new(consequent))
+    }
+
+    /// A cardinality node as plain AND/OR/NOT: "at most one" is the negation
+    /// of every pair, "exactly one" adds the disjunction. `Distinct` over
+    /// fields is `a != b` for every pair. `None` for other nodes, and for
+    /// `Distinct` over the elements of a list
+    pub fn pairwise_encoding(&self) -> Option<CompoundConstraint> {
+        let (parts, exact) = match self {
+            CompoundConstraint::ExactlyOne(parts) => (parts, true),
+            CompoundConstraint::AtMostOne(parts) => (parts, false),
+            CompoundConstraint::Distinct(fields) if fields.len() > 1 => {
+                let mut pairs = Vec::new();
+                for (i, first) in fields.iter().enumerate() {
+                    for second in &fields[i + 1..] {
+                        pairs.push(CompoundConstraint::Simple(Constraint {
+                            left_variable: first.clone(),
+                            operator: ConstraintOperator::NotEqual,
+                            right_value: second.clone(),
+                        }));
+                    }
+                }
+                return Some(CompoundConstraint::And(pairs));
+            }
+            _ => return None,
+        };
+        let mut exclusions = Vec::new();
+        for (i, first) in parts.iter().enumerate() {
+            for second in &parts[i + 1..] {
+                let both = CompoundConstraint::And(vec![first.clone(), second.clone()]);
+                exclusions.push(CompoundConstraint::Not(Box::new(both)));
+            }
+        }
+        if exact {
+            exclusions.insert(0, CompoundConstraint::Or(parts.clone()));
+        }
+        Some(CompoundConstraint::And(exclusions))
+    }
+
+    /// The range this node spells out, if it is exactly a lower and an upper
+    /// bound of the same strictness on one variable, in that order
+    pub fn as_between(&self) -> Option<Between> {
+        let CompoundConstraint::And(parts) = self else { return None };
+        let [CompoundConstraint::Simple(lower), CompoundConstraint::Simple(upper)] = parts.as_slice() else {
+            return None;
+        };
+        if lower.left_variable != upper.left_variable {
+            return None;
+        }
+        let inclusive = match (lower.operator, upper.operator) {
+            (ConstraintOperator::GreaterThanOrEqual, ConstraintOperator::LessThanOrEqual) => true,
+            (ConstraintOperator::GreaterThan, ConstraintOperator::LessThan) => false,
+            _ => return None,
+        };
+        Some(Between::new(&lower.left_variable, &lower.right_value, &upper.right_value, inclusive))
+    }
+}
+
+/// `variable` between `low` and `high`: sugar for the conjunction of a lower
+/// and an upper bound, which is how it is stored in constraint trees
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Between {
+    pub variable: String,
+    pub low: String,
+    pub high: String,
+    /// `low <= variable <= high` when set, `low < variable < high` otherwise
+    pub inclusive: bool,
+}
+
+impl Between {
+    pub fn new(variable: &str, low: &str, high: &str, inclusive: bool) -> Self {
+        Self {
+            variable: variable.to_string(),
+            low: low.to_string(),
+            high: high.to_string(),
+            inclusive,
+        }
+    }
+
+    /// The lower and upper bound as plain comparisons
+    pub fn bounds(&self) -> (Constraint, Constraint) {
+        let (lower, upper) = if self.inclusive {
+            (ConstraintOperator::GreaterThanOrEqual, ConstraintOperator::LessThanOrEqual)
+        } else {
+            (ConstraintOperator::GreaterThan, ConstraintOperator::LessThan)
+        };
+        (
+            Constraint {
+                left_variable: self.variable.clone(),
+                operator: lower,
+                right_value: self.low.clone(),
+            },
+            Constraint {
+                left_variable: self.variable.clone(),
+                operator: upper,
+                right_value: self.high.clone(),
+            },
+        )
+    }
+}
+
+impl From<Between> for CompoundConstraint {
+    fn from(range: Between) -> Self {
+        let (lower, upper) = range.bounds();
+        CompoundConstraint::And(vec![CompoundConstraint::Simple(lower), CompoundConstraint::Simple(upper)])
+    }
 }
 
 impl From<Constraint> for CompoundConstraint {
@@ -75,18 +522,167 @@ impl From<Constraint> for CompoundConstraint {
     }
 }
 
+/// An input on or next to the edge of a constraint, with the verdict the
+/// validator must give for it (used to seed generated property tests)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoundaryCase {
+    pub values: std::collections::BTreeMap<String, i64>,
+    pub expected: bool,
+}
+
+/// An input that violates one top-level constraint and satisfies all the
+/// others, so a validator that accepts it ignores that constraint (used to
+/// seed generated fault-injection tests)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FaultCase {
+    /// Index of the violated constraint among the top-level conjuncts
+    pub constraint: usize,
+    pub values: std::collections::BTreeMap<String, i64>,
+}
+
+/// How strictly a requirement must hold, from its modal verb
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum Severity {
+    /// "must", "shall", "will", "can": violations make the spec inconsistent
+    #[default]
+    MustHold,
+    /// "should": conflicts are warnings the solver may relax
+    ShouldHold,
+    /// "may": optional; relaxed first
+    MayHold,
+}
+
+impl Severity {
+    /// Severity implied by a modal verb; unknown words are treated as hard
+    pub fn from_modal(verb: &str) -> Self {
+        match verb.to_lowercase().as_str() {
+            "should" => Severity::ShouldHold,
+            "may" => Severity::MayHold,
+            _ => Severity::MustHold,
+        }
+    }
+
+    /// Severity of the first modal verb in a sentence
+    pub fn from_text(text: &str) -> Self {
+        text.split_whitespace()
+            .map(str::to_lowercase)
+            .find(|w| matches!(w.as_str(), "must" | "shall" | "will" | "can" | "should" | "may"))
+            .map_or(Severity::MustHold, |w| Self::from_modal(&w))
+    }
+
+    /// Hard constraints must be satisfiable; soft ones can be relaxed
+    pub fn is_hard(&self) -> bool {
+        *self == Severity::MustHold
+    }
+
+    /// MaxSAT weight of a soft constraint (higher is relaxed later); `None` for hard ones
+    pub fn soft_weight(&self) -> Option<u32> {
+        match self {
+            Severity::MustHold => None,
+            Severity::ShouldHold => Some(10),
+            Severity::MayHold => Some(1),
+        }
+    }
+}
+
+/// The role a requirement plays in an assume-guarantee contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RequirementKind {
+    /// What the environment promises ("Assuming ...")
+    Assumption,
+    /// What the system must deliver when the assumptions hold
+    #[default]
+    Guarantee,
+    /// Holds in every state ("Always ...", "Invariant: ...")
+    Invariant,
+}
+
+impl RequirementKind {
+    /// Infer the kind from a requirement's phrasing
+    pub fn from_text(text: &str) -> Self {
+        let lower = text.trim_start().to_lowercase();
+        if lower.starts_with("assuming") || lower.starts_with("assume") || lower.contains("provided that") {
+            RequirementKind::Assumption
+        } else if lower.starts_with("always") || lower.starts_with("invariant") || lower.contains("at all times") {
+            RequirementKind::Invariant
+        } else {
+            RequirementKind::Guarantee
+        }
+    }
+}
+
+/// Outcome of running an external prover on the code generated for a requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProofStatus {
+    /// Every proof obligation was discharged
+    Proved,
+    /// At least one obligation has a counterexample or could not be proved
+    Failed,
+    /// The prover gave no verdict (timeout, unsupported construct, no obligations)
+    Inconclusive,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Requirement {
     pub id: Uuid,
     pub content: String,
     pub verified: bool,
+    /// Comparisons that must each hold: the top-level conjuncts of `tree`
     pub constraints: Vec<Constraint>,
+    /// Full constraint tree, when it is more than the conjunction of `constraints`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tree: Option<CompoundConstraint>,
+    /// Why this requirement can (or cannot) be trusted; see `IntentAst::refresh_scores`
+    #[serde(default)]
+    pub confidence: ConfidenceBreakdown,
+    /// Derived from the modal verb; see `Severity::from_text`
+    #[serde(default)]
+    pub severity: Severity,
+    /// Assumption, guarantee or invariant; see `RequirementKind::from_text`
+    #[serde(default)]
+    pub kind: RequirementKind,
+    /// Prover verdict on the generated code, once a prover has run
+    #[serde(default)]
+    pub proof: Option<ProofStatus>,
+    /// Lifecycle state; change it with `IntentAst::transition`
+    #[serde(default)]
+    pub status: RequirementStatus,
+    /// Who wrote the requirement, when known
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Labels for filtering verification and code generation; see `tags`
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tags: BTreeSet<String>,
+    /// Statistical tolerance, monitored instead of proved; see `tolerance`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<Tolerance>,
+    #[serde(default)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub updated_at: DateTime<Utc>,
+    /// Append-only log of status changes, oldest first
+    #[serde(default)]
+    pub history: Vec<ChangeEntry>,
+}
+
+impl Requirement {
+    /// Everything the requirement constrains, as one tree; `None` when unconstrained
+    pub fn constraint_tree(&self) -> Option<CompoundConstraint> {
+        match (&self.tree, self.constraints.as_slice()) {
+            (Some(tree), _) => Some(tree.clone()),
+            (None, []) => None,
+            (None, constraints) => Some(CompoundConstraint::And(
+                constraints.iter().cloned().map(CompoundConstraint::Simple).collect(),
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntentAst {
     pub id: Uuid,
     pub requirements: Vec<Requirement>,
+    /// Mean per-requirement confidence score (0 - 100)
     pub correctness_score: f64,
 }
 
@@ -100,24 +696,95 @@ impl IntentAst {
     }
 
     pub fn add_requirement(&mut self, content: String) {
+        self.add_constrained_requirement(content, None);
+    }
+
+    /// Add a requirement with the constraint tree parsed from it. Top-level
+    /// comparisons become `constraints`; the tree is kept whole only when it
+    /// says more than they do. It starts out Parsed, or Draft without a tree;
+    /// `verified` starts out false either way: only a solver run may set it.
+    pub fn add_constrained_requirement(&mut self, content: String, tree: Option<CompoundConstraint>) -> &Requirement {
+        let mut constraints = Vec::new();
+        let mut rest = 0;
+        let mut pending: Vec<&CompoundConstraint> = tree.iter().collect();
+        while let Some(node) = pending.pop() {
+            match node {
+                CompoundConstraint::And(parts) => pending.extend(parts.iter().rev()),
+                CompoundConstraint::Simple(constraint) => constraints.push(constraint.clone()),
+                _ => rest += 1,
+            }
+        }
+
+        let status = match tree {
+            Some(_) => RequirementStatus::Parsed,
+            None => RequirementStatus::Draft,
+        };
+        let now = Utc::now();
         let req = Requirement {
             id: Uuid::new_v4(),
+            severity: Severity::from_text(&content),
+            kind: RequirementKind::from_text(&content),
             content,
             verified: false,
-            constraints: Vec::new(),
+            constraints,
+            tree: tree.filter(|_| rest > 0),
+            confidence: ConfidenceBreakdown::default(),
+            proof: None,
+            status,
+            author: None,
+            tags: BTreeSet::new(),
+            tolerance: None,
+            created_at: now,
+            updated_at: now,
+            history: vec![ChangeEntry {
+                at: now,
+                author: None,
+                from: None,
+                to: status,
+                note: None,
+            }],
         };
+        tracing::debug!(
+            id = %req.id,
+            severity = ?req.severity,
+            kind = ?req.kind,
+            constraints = req.constraints.len(),
+            "requirement added"
+        );
         self.requirements.push(req);
-        self.update_score();
+        self.refresh_scores();
+        self.requirements.last().unwrap()
     }
 
-    fn update_score(&mut self) {
+    /// Recompute every requirement's confidence and the overall score.
+    /// Call after changing a requirement's constraints or verification status.
+    pub fn refresh_scores(&mut self) {
+        for requirement in &mut self.requirements {
+            requirement.confidence = ConfidenceBreakdown::assess(requirement);
+        }
+
         if self.requirements.is_empty() {
             self.correctness_score = 0.0;
             return;
         }
-        
-        let verified = self.requirements.iter().filter(|r| r.verified).count();
-        self.correctness_score = (verified as f64 / self.requirements.len() as f64) * 100.0;
+
+        let total: f64 = self.requirements.iter().map(|r| r.confidence.score).sum();
+        self.correctness_score = total / self.requirements.len() as f64;
+    }
+
+    /// Requirements scoring below `threshold`, in source order
+    pub fn needs_attention(&self, threshold: f64) -> impl Iterator<Item = &Requirement> {
+        self.requirements.iter().filter(move |r| r.confidence.score < threshold)
+    }
+
+    /// What the spec actually demands: the trees of its hard requirements,
+    /// skipping deprecated ones. Two versions of a spec are compared on these.
+    pub fn binding_constraints(&self) -> Vec<CompoundConstraint> {
+        self.requirements
+            .iter()
+            .filter(|r| r.severity.is_hard() && r.status != RequirementStatus::Deprecated)
+            .filter_map(Requirement::constraint_tree)
+            .collect()
     }
 }
 
@@ -154,6 +821,8 @@ pub enum DataType {
         range_min: Option<i128>,
         range_max: Option<i128>,
     },
+    /// List of values of one type, read through `sum`, `count` and `average`
+    List(Box<DataType>),
 }
 
 /// Maps a variable name to its data type for overflow-safe code generation
@@ -165,6 +834,9 @@ pub struct Schema {
     pub documentation: std::collections::HashMap<String, String>,
     /// Traceability ID linking to Z3 SMT solver run
     pub traceability_id: String,
+    /// Per-target-language case conventions and field name overrides
+    #[serde(default)]
+    pub naming: FieldNaming,
 }
 
 impl Schema {
@@ -174,6 +846,7 @@ impl Schema {
             fields: std::collections::HashMap::new(),
             documentation: std::collections::HashMap::new(),
             traceability_id,
+            naming: FieldNaming::default(),
         }
     }
 
@@ -206,6 +879,8 @@ pub enum ArithmeticOperator {
     Subtract,
     Multiply,
     Divide,
+    /// Euclidean remainder: never negative, whatever the operand signs (Z3 `mod`)
+    Modulo,
 }
 
 impl ArithmeticOperator {
@@ -216,6 +891,19 @@ impl ArithmeticOperator {
             ArithmeticOperator::Subtract => "-",
             ArithmeticOperator::Multiply => "*",
             ArithmeticOperator::Divide => "/",
+            ArithmeticOperator::Modulo => "%",
+        }
+    }
+
+    /// Parse `+`, `-`, `*`, `/`, `%` (or `mod`)
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "+" => Some(ArithmeticOperator::Add),
+            "-" => Some(ArithmeticOperator::Subtract),
+            "*" => Some(ArithmeticOperator::Multiply),
+            "/" => Some(ArithmeticOperator::Divide),
+            "%" | "mod" => Some(ArithmeticOperator::Modulo),
+            _ => None,
         }
     }
 
@@ -223,4 +911,189 @@ impl ArithmeticOperator {
     pub fn symbol(&self) -> &'static str {
         self.rust_symbol()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_from_modal_verb() {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("System shall encrypt data".to_string());
+        ast.add_requirement("User should verify email".to_string());
+        ast.add_requirement("Admin may export reports".to_string());
+
+        let severities: Vec<Severity> = ast.requirements.iter().map(|r| r.severity).collect();
+        assert_eq!(severities, vec![Severity::MustHold, Severity::ShouldHold, Severity::MayHold]);
+        assert!(Severity::MustHold.is_hard() && Severity::MustHold.soft_weight().is_none());
+    }
+
+    #[test]
+    fn test_compound_display() {
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            CompoundConstraint::Not(Box::new(simple("status", ConstraintOperator::Equal, "2"))),
+            CompoundConstraint::implies(
+                CompoundConstraint::Named("is_vip".to_string()),
+                CompoundConstraint::Or(vec![
+                    simple("tier", ConstraintOperator::GreaterThanOrEqual, "3"),
+                    simple("note", ConstraintOperator::Contains, "\"vip\""),
+                ]),
+            ),
+        ]);
+        assert_eq!(
+            compound.to_string(),
+            "(amount > 0 AND NOT (status == 2) AND (IF is_vip THEN (tier >= 3 OR note contains \"vip\")))"
+        );
+    }
+
+    #[test]
+    fn test_constrained_requirement_splits_top_level_comparisons() {
+        let simple = |left: &str, operator, right: &str| Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        };
+        let amount = simple("amount", ConstraintOperator::GreaterThan, "0");
+        let balance = simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount");
+        let vip = CompoundConstraint::Named("vip".to_string());
+        let mut ast = IntentAst::new();
+
+        let plain = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(amount.clone()),
+            CompoundConstraint::And(vec![CompoundConstraint::Simple(balance.clone())]),
+        ]);
+        let added = ast.add_constrained_requirement("User can withdraw money".to_string(), Some(plain));
+        assert_eq!(added.constraints, vec![amount.clone(), balance.clone()]);
+        assert!(added.tree.is_none() && !added.verified);
+
+        let guarded = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(amount.clone()),
+            CompoundConstraint::Or(vec![vip, CompoundConstraint::Simple(balance)]),
+        ]);
+        let added = ast.add_constrained_requirement("User can withdraw money".to_string(), Some(guarded.clone()));
+        assert_eq!(added.constraints, vec![amount]);
+        assert_eq!(added.constraint_tree(), Some(guarded));
+    }
+
+    #[test]
+    fn test_between_round_trip() {
+        let range = Between::new("amount", "1", "100", true);
+        let compound = CompoundConstraint::from(range.clone());
+        assert_eq!(compound.count_constraints(), 2);
+        assert_eq!(compound.as_between(), Some(range));
+
+        let strict = CompoundConstraint::from(Between::new("score", "0", "10", false));
+        assert!(!strict.as_between().unwrap().inclusive);
+
+        // Mixed strictness is two separate bounds, not a range
+        let (lower, _) = Between::new("amount", "1", "100", true).bounds();
+        let (_, upper) = Between::new("amount", "1", "100", false).bounds();
+        let mixed = CompoundConstraint::And(vec![lower.into(), upper.into()]);
+        assert_eq!(mixed.as_between(), None);
+    }
+
+    #[test]
+    fn test_pairwise_encoding() {
+        let card = CompoundConstraint::Simple(Constraint::from("card"));
+        let transfer = CompoundConstraint::Simple(Constraint::from("transfer"));
+        let both = CompoundConstraint::Not(Box::new(CompoundConstraint::And(vec![card.clone(), transfer.clone()])));
+
+        let exactly_one = CompoundConstraint::ExactlyOne(vec![card.clone(), transfer.clone()]);
+        assert_eq!(
+            exactly_one.pairwise_encoding(),
+            Some(CompoundConstraint::And(vec![CompoundConstraint::Or(vec![card.clone(), transfer.clone()]), both.clone()]))
+        );
+        assert_eq!(exactly_one.count_constraints(), 2);
+
+        let at_most_one = CompoundConstraint::AtMostOne(vec![card.clone(), transfer]);
+        assert_eq!(at_most_one.pairwise_encoding(), Some(CompoundConstraint::And(vec![both])));
+        assert_eq!(card.pairwise_encoding(), None);
+
+        let parties = CompoundConstraint::Distinct(vec!["sender".into(), "receiver".into(), "approver".into()]);
+        assert_eq!(parties.to_string(), "distinct(sender, receiver, approver)");
+        assert_eq!(
+            parties.pairwise_encoding().unwrap().to_string(),
+            "(sender != receiver AND sender != approver AND receiver != approver)"
+        );
+        assert_eq!(CompoundConstraint::Distinct(vec!["transaction_ids".into()]).pairwise_encoding(), None);
+    }
+
+    #[test]
+    fn test_canonical_hash() {
+        let c = |left: &str, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: right.to_string(),
+            })
+        };
+        let written = CompoundConstraint::And(vec![
+            c("balance", "amount"),
+            CompoundConstraint::And(vec![c("amount", "0"), c("fee", "1")]),
+        ]);
+        let reordered = CompoundConstraint::And(vec![c("fee", "1"), c("balance", "amount"), c("amount", "0")]);
+
+        assert_eq!(written.canonical(), reordered.canonical());
+        assert_eq!(written.canonical_hash(), reordered.canonical_hash());
+        assert_eq!(written.canonical_hash().len(), 16);
+        assert_ne!(written.canonical_hash(), CompoundConstraint::Or(vec![c("balance", "amount")]).canonical_hash());
+    }
+
+    #[test]
+    fn test_modulo_left_operand() {
+        let multiple = Constraint::multiple_of("amount", "100");
+        assert_eq!(multiple.left_variable, "amount % 100");
+        assert_eq!(multiple.modulo(), Some(Modulo::new("amount", "100")));
+        assert_eq!(Modulo::parse("id mod 10"), Some(Modulo::new("id", "10")));
+        assert_eq!(Modulo::parse("balance - amount"), None);
+        assert_eq!(Constraint::from("amount").modulo(), None);
+    }
+
+    #[test]
+    fn test_kind_from_phrasing() {
+        let kinds: Vec<RequirementKind> = [
+            "Assuming amount > 0 the user can withdraw money",
+            "System is guaranteed to process payment",
+            "Always balance >= 0",
+            "Invariant: total supply is constant",
+        ]
+        .iter()
+        .map(|text| RequirementKind::from_text(text))
+        .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                RequirementKind::Assumption,
+                RequirementKind::Guarantee,
+                RequirementKind::Invariant,
+                RequirementKind::Invariant,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_binding_constraints() {
+        let positive = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+        let mut ast = IntentAst::new();
+        ast.add_constrained_requirement("Amount must be positive".to_string(), Some(positive.clone()));
+        ast.add_constrained_requirement("Amount should be positive".to_string(), Some(positive.clone()));
+        ast.add_constrained_requirement("Amount shall be positive".to_string(), Some(positive.clone()));
+        ast.add_requirement("System shall log transfers".to_string());
+        ast.requirements[2].status = RequirementStatus::Deprecated;
+
+        assert_eq!(ast.binding_constraints(), [CompoundConstraint::And(vec![positive])]);
+    }
+}
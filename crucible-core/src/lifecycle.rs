@@ -0,0 +1,213 @@
+//! Requirement Lifecycle - status, authorship and an append-only change log
+//!
+//! A requirement moves through
+//!
+//! ```text
+//! Draft -> Parsed -> Verified -> CodeGenerated
+//!            ^          |             |
+//!            +----------+-------------+   (re-parsed, or the proof no longer holds)
+//! ```
+//!
+//! and can be Deprecated from any other state. Every transition is appended
+//! to `Requirement::history` with who made it and when; entries are never
+//! rewritten, so the log is the audit trail of who changed a requirement and
+//! when its proof was last valid.
+
+use crate::{IntentAst, Requirement};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Where a requirement is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequirementStatus {
+    /// Text only; no constraints extracted yet
+    #[default]
+    Draft,
+    /// Constraints extracted, not yet checked by the solver
+    Parsed,
+    /// The solver found the constraints consistent
+    Verified,
+    /// Validators were generated from the verified constraints
+    CodeGenerated,
+    /// Withdrawn; kept for the record
+    Deprecated,
+}
+
+impl RequirementStatus {
+    /// Whether a requirement may move from `self` to `to`
+    pub fn can_become(self, to: RequirementStatus) -> bool {
+        use RequirementStatus::*;
+        matches!(
+            (self, to),
+            (Draft, Parsed)
+                | (Parsed, Draft)
+                | (Parsed, Verified)
+                | (Verified, CodeGenerated)
+                | (Verified | CodeGenerated, Parsed)
+                | (Draft | Parsed | Verified | CodeGenerated, Deprecated)
+        )
+    }
+
+    /// Whether a requirement in this state rests on a valid proof
+    pub fn is_proved(self) -> bool {
+        matches!(self, RequirementStatus::Verified | RequirementStatus::CodeGenerated)
+    }
+}
+
+/// One entry of a requirement's change log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeEntry {
+    pub at: DateTime<Utc>,
+    /// `None` for changes Crucible made itself (parser, solver)
+    pub author: Option<String>,
+    /// `None` for the entry recording the requirement's creation
+    pub from: Option<RequirementStatus>,
+    pub to: RequirementStatus,
+    pub note: Option<String>,
+}
+
+/// Errors raised by lifecycle transitions
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LifecycleError {
+    #[error("no requirement with id {0}")]
+    UnknownRequirement(Uuid),
+
+    #[error("a requirement cannot move from {from:?} to {to:?}")]
+    InvalidTransition {
+        from: RequirementStatus,
+        to: RequirementStatus,
+    },
+}
+
+impl Requirement {
+    /// When the requirement last became Verified, if that proof still stands
+    pub fn last_verified(&self) -> Option<DateTime<Utc>> {
+        if !self.status.is_proved() {
+            return None;
+        }
+        self.history
+            .iter()
+            .rev()
+            .find(|entry| entry.to == RequirementStatus::Verified)
+            .map(|entry| entry.at)
+    }
+}
+
+impl IntentAst {
+    /// Record who wrote a requirement that was just added, including on the
+    /// entry logging its creation; a requirement with later changes keeps its
+    /// log as it is
+    pub fn attribute(&mut self, id: Uuid, author: &str) -> Result<&Requirement, LifecycleError> {
+        let requirement = self
+            .requirements
+            .iter_mut()
+            .find(|r| r.id == id)
+            .ok_or(LifecycleError::UnknownRequirement(id))?;
+        requirement.author = Some(author.to_string());
+        if let [created] = requirement.history.as_mut_slice() {
+            created.author = Some(author.to_string());
+        }
+        Ok(requirement)
+    }
+
+    /// Move a requirement to `to` and log who did it.
+    ///
+    /// `verified` follows the status: set on entering Verified, cleared on
+    /// falling back to Parsed or Draft.
+    pub fn transition(
+        &mut self,
+        id: Uuid,
+        to: RequirementStatus,
+        author: Option<&str>,
+        note: Option<String>,
+    ) -> Result<&Requirement, LifecycleError> {
+        let index = self
+            .requirements
+            .iter()
+            .position(|r| r.id == id)
+            .ok_or(LifecycleError::UnknownRequirement(id))?;
+        let requirement = &mut self.requirements[index];
+        let from = requirement.status;
+        if !from.can_become(to) {
+            return Err(LifecycleError::InvalidTransition { from, to });
+        }
+
+        let at = Utc::now();
+        requirement.history.push(ChangeEntry {
+            at,
+            author: author.map(str::to_string),
+            from: Some(from),
+            to,
+            note,
+        });
+        requirement.status = to;
+        requirement.updated_at = at;
+        match to {
+            RequirementStatus::Verified => requirement.verified = true,
+            RequirementStatus::Draft | RequirementStatus::Parsed => requirement.verified = false,
+            RequirementStatus::CodeGenerated | RequirementStatus::Deprecated => {}
+        }
+        tracing::info!(%id, ?from, ?to, author = author.unwrap_or("crucible"), "requirement status changed");
+
+        self.refresh_scores();
+        Ok(&self.requirements[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_transitions_are_checked_and_logged() {
+        let mut ast = IntentAst::new();
+        let tree = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "0".to_string(),
+        });
+        let id = ast.add_constrained_requirement("Amount must be positive".to_string(), Some(tree)).id;
+        assert_eq!(ast.requirements[0].status, RequirementStatus::Parsed);
+
+        let error = ast.transition(id, RequirementStatus::CodeGenerated, Some("ana"), None).unwrap_err();
+        assert_eq!(
+            error,
+            LifecycleError::InvalidTransition {
+                from: RequirementStatus::Parsed,
+                to: RequirementStatus::CodeGenerated
+            }
+        );
+
+        let verified = ast.transition(id, RequirementStatus::Verified, None, None).unwrap();
+        assert!(verified.verified);
+        let proved_at = verified.last_verified().unwrap();
+        ast.transition(id, RequirementStatus::CodeGenerated, Some("ana"), Some("rust validator".to_string()))
+            .unwrap();
+        assert_eq!(ast.requirements[0].last_verified(), Some(proved_at));
+
+        let reopened = ast.transition(id, RequirementStatus::Parsed, Some("ben"), None).unwrap();
+        assert!(!reopened.verified && reopened.last_verified().is_none());
+        let log: Vec<(Option<RequirementStatus>, RequirementStatus)> =
+            reopened.history.iter().map(|entry| (entry.from, entry.to)).collect();
+        assert_eq!(
+            log,
+            vec![
+                (None, RequirementStatus::Parsed),
+                (Some(RequirementStatus::Parsed), RequirementStatus::Verified),
+                (Some(RequirementStatus::Verified), RequirementStatus::CodeGenerated),
+                (Some(RequirementStatus::CodeGenerated), RequirementStatus::Parsed),
+            ]
+        );
+        assert_eq!(reopened.history[3].author.as_deref(), Some("ben"));
+
+        let unknown = Uuid::new_v4();
+        assert_eq!(
+            ast.transition(unknown, RequirementStatus::Deprecated, None, None).unwrap_err(),
+            LifecycleError::UnknownRequirement(unknown)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Arithmetic that may not fit the target language's integer type
+//!
+//! `total == amount + fee` is checked over unbounded integers, but the
+//! generated validator adds two `u32`s, two JavaScript numbers or two
+//! `uint256`s. An `OverflowRisk` records an `a op b` term whose value leaves
+//! the width of the type it is computed in, for some input that reaches it,
+//! so that generation can pick a checked or widened operation for it.
+
+use crate::call::names_field;
+use crate::{ArithmeticOperator, BitWidth, Call, Constraint};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `left op right` over a field and a field or decimal integer, as written
+/// in a right-hand value (`amount + fee`) or a call argument (`abs(x - y)`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArithmeticTerm {
+    pub left: String,
+    pub operator: ArithmeticOperator,
+    pub right: String,
+}
+
+impl ArithmeticTerm {
+    /// Parse `amount + fee` or `price * 2`; `None` for anything else,
+    /// including a term over two literals
+    pub fn parse(text: &str) -> Option<Self> {
+        let [left, op, right] = text.split_whitespace().collect::<Vec<_>>()[..] else {
+            return None;
+        };
+        let operator = ArithmeticOperator::from_symbol(op)?;
+        let operand = |token: &str| names_field(token) || token.parse::<i64>().is_ok();
+        (operand(left) && operand(right) && (names_field(left) || names_field(right))).then(|| Self {
+            left: left.to_string(),
+            operator,
+            right: right.to_string(),
+        })
+    }
+
+    /// The operands that name fields, in source order
+    pub fn fields(&self) -> Vec<&str> {
+        [self.left.as_str(), self.right.as_str()]
+            .into_iter()
+            .filter(|operand| names_field(operand))
+            .collect()
+    }
+
+    /// The terms `constraint` computes: its right value, the branches of a
+    /// conditional right value and the arguments of a call on either side
+    pub fn in_constraint(constraint: &Constraint) -> Vec<Self> {
+        let mut texts = vec![constraint.right_value.clone()];
+        if let Some(conditional) = constraint.conditional() {
+            texts.extend([conditional.then_value, conditional.else_value]);
+        }
+        for side in [&constraint.left_variable, &constraint.right_value] {
+            if let Some(call) = Call::parse(side) {
+                texts.extend(call.args);
+            }
+        }
+        texts.iter().filter_map(|text| Self::parse(text)).collect()
+    }
+}
+
+impl std::fmt::Display for ArithmeticTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.left, self.operator.symbol(), self.right)
+    }
+}
+
+/// A term that can leave `width` for an input satisfying the path to it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OverflowRisk {
+    /// The term as written, e.g. `amount + fee`
+    pub term: String,
+    /// The constraint computing it, e.g. `total == amount + fee`
+    pub constraint: String,
+    /// The type the target computes the term in
+    pub width: BitWidth,
+    /// An input under which the term overflows, in Z3's rendering
+    pub witness: BTreeMap<String, String>,
+}
+
+impl OverflowRisk {
+    pub fn arithmetic(&self) -> Option<ArithmeticTerm> {
+        ArithmeticTerm::parse(&self.term)
+    }
+}
+
+impl std::fmt::Display for OverflowRisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.width.signed { "i" } else { "u" };
+        write!(
+            f,
+            "`{}` in `{}` overflows {}{}",
+            self.term, self.constraint, sign, self.width.bits
+        )?;
+        if !self.witness.is_empty() {
+            let values: Vec<String> = self
+                .witness
+                .iter()
+                .map(|(field, value)| format!("{} = {}", field, value))
+                .collect();
+            write!(f, " when {}", values.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstraintOperator;
+
+    #[test]
+    fn test_arithmetic_terms() {
+        let sum = ArithmeticTerm::parse("amount + fee").unwrap();
+        assert_eq!(
+            (sum.operator, sum.fields()),
+            (ArithmeticOperator::Add, vec!["amount", "fee"])
+        );
+        assert_eq!(ArithmeticTerm::parse("price * 16").unwrap().fields(), vec!["price"]);
+        assert_eq!(ArithmeticTerm::parse("2 + 3"), None);
+        assert_eq!(ArithmeticTerm::parse("flags & 8"), None);
+
+        let constraint = Constraint {
+            left_variable: "abs(x - y)".to_string(),
+            operator: ConstraintOperator::LessThanOrEqual,
+            right_value: "limit * 2 if strict else limit + 1".to_string(),
+        };
+        let terms: Vec<String> = ArithmeticTerm::in_constraint(&constraint)
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(terms, vec!["limit * 2", "limit + 1", "x - y"]);
+
+        let risk = OverflowRisk {
+            term: sum.to_string(),
+            constraint: "total == amount + fee".to_string(),
+            width: BitWidth {
+                bits: 32,
+                signed: false,
+            },
+            witness: [("amount", "4294967295"), ("fee", "1")]
+                .into_iter()
+                .map(|(field, value)| (field.to_string(), value.to_string()))
+                .collect(),
+        };
+        assert_eq!(
+            risk.to_string(),
+            "`amount + fee` in `total == amount + fee` overflows u32 when amount = 4294967295, fee = 1"
+        );
+        assert_eq!(risk.arithmetic(), Some(sum));
+    }
+}
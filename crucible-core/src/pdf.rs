@@ -0,0 +1,344 @@
+//! Minimal PDF/A-2b writer for the audit report
+//!
+//! Archival PDF must embed every font it uses and pin its colours, and an
+//! audit copy must come out byte-identical from the same Intent-AST. Both
+//! rule out system fonts, so text is set in a built-in 5x7 dot-matrix Type 3
+//! font covering printable ASCII (anything else prints as `?`), with a
+//! `ToUnicode` map so it stays searchable, in a calibrated gray. Nothing is
+//! compressed, and the document ID is a hash of the file's contents.
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+
+/// A4 in points
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const MARGIN: u32 = 56;
+/// Body text size; a glyph cell is 0.6 of the size wide
+pub(crate) const BODY_SIZE: u32 = 9;
+/// Characters of body text that fit between the margins
+pub(crate) const COLUMNS: usize = ((PAGE_WIDTH - 2 * MARGIN) * 10 / (BODY_SIZE * 6)) as usize;
+const LEADING: u32 = 12;
+/// Room kept under the text for the page number
+const FOOTER: u32 = 24;
+
+/// Column bitmaps of the glyphs `' '` to `'~'`, least significant bit on top
+const GLYPHS: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x5f, 0x00, 0x00],
+    [0x00, 0x07, 0x00, 0x07, 0x00],
+    [0x14, 0x7f, 0x14, 0x7f, 0x14],
+    [0x24, 0x2a, 0x7f, 0x2a, 0x12],
+    [0x23, 0x13, 0x08, 0x64, 0x62],
+    [0x36, 0x49, 0x55, 0x22, 0x50],
+    [0x00, 0x00, 0x07, 0x00, 0x00],
+    [0x00, 0x1c, 0x22, 0x41, 0x00],
+    [0x00, 0x41, 0x22, 0x1c, 0x00],
+    [0x14, 0x08, 0x3e, 0x08, 0x14],
+    [0x08, 0x08, 0x3e, 0x08, 0x08],
+    [0x00, 0x50, 0x30, 0x00, 0x00],
+    [0x08, 0x08, 0x08, 0x08, 0x08],
+    [0x00, 0x60, 0x60, 0x00, 0x00],
+    [0x20, 0x10, 0x08, 0x04, 0x02],
+    [0x3e, 0x51, 0x49, 0x45, 0x3e],
+    [0x00, 0x42, 0x7f, 0x40, 0x00],
+    [0x42, 0x61, 0x51, 0x49, 0x46],
+    [0x21, 0x41, 0x45, 0x4b, 0x31],
+    [0x18, 0x14, 0x12, 0x7f, 0x10],
+    [0x27, 0x45, 0x45, 0x45, 0x39],
+    [0x3c, 0x4a, 0x49, 0x49, 0x30],
+    [0x01, 0x71, 0x09, 0x05, 0x03],
+    [0x36, 0x49, 0x49, 0x49, 0x36],
+    [0x06, 0x49, 0x49, 0x29, 0x1e],
+    [0x00, 0x36, 0x36, 0x00, 0x00],
+    [0x00, 0x56, 0x36, 0x00, 0x00],
+    [0x08, 0x14, 0x22, 0x41, 0x00],
+    [0x14, 0x14, 0x14, 0x14, 0x14],
+    [0x00, 0x41, 0x22, 0x14, 0x08],
+    [0x02, 0x01, 0x51, 0x09, 0x06],
+    [0x32, 0x49, 0x79, 0x41, 0x3e],
+    [0x7e, 0x11, 0x11, 0x11, 0x7e],
+    [0x7f, 0x49, 0x49, 0x49, 0x36],
+    [0x3e, 0x41, 0x41, 0x41, 0x22],
+    [0x7f, 0x41, 0x41, 0x22, 0x1c],
+    [0x7f, 0x49, 0x49, 0x49, 0x41],
+    [0x7f, 0x09, 0x09, 0x09, 0x01],
+    [0x3e, 0x41, 0x49, 0x49, 0x7a],
+    [0x7f, 0x08, 0x08, 0x08, 0x7f],
+    [0x00, 0x41, 0x7f, 0x41, 0x00],
+    [0x20, 0x40, 0x41, 0x3f, 0x01],
+    [0x7f, 0x08, 0x14, 0x22, 0x41],
+    [0x7f, 0x40, 0x40, 0x40, 0x40],
+    [0x7f, 0x02, 0x0c, 0x02, 0x7f],
+    [0x7f, 0x04, 0x08, 0x10, 0x7f],
+    [0x3e, 0x41, 0x41, 0x41, 0x3e],
+    [0x7f, 0x09, 0x09, 0x09, 0x06],
+    [0x3e, 0x41, 0x51, 0x21, 0x5e],
+    [0x7f, 0x09, 0x19, 0x29, 0x46],
+    [0x46, 0x49, 0x49, 0x49, 0x31],
+    [0x01, 0x01, 0x7f, 0x01, 0x01],
+    [0x3f, 0x40, 0x40, 0x40, 0x3f],
+    [0x1f, 0x20, 0x40, 0x20, 0x1f],
+    [0x3f, 0x40, 0x38, 0x40, 0x3f],
+    [0x63, 0x14, 0x08, 0x14, 0x63],
+    [0x07, 0x08, 0x70, 0x08, 0x07],
+    [0x61, 0x51, 0x49, 0x45, 0x43],
+    [0x00, 0x7f, 0x41, 0x41, 0x00],
+    [0x02, 0x04, 0x08, 0x10, 0x20],
+    [0x00, 0x41, 0x41, 0x7f, 0x00],
+    [0x04, 0x02, 0x01, 0x02, 0x04],
+    [0x40, 0x40, 0x40, 0x40, 0x40],
+    [0x00, 0x01, 0x02, 0x04, 0x00],
+    [0x20, 0x54, 0x54, 0x54, 0x78],
+    [0x7f, 0x48, 0x44, 0x44, 0x38],
+    [0x38, 0x44, 0x44, 0x44, 0x20],
+    [0x38, 0x44, 0x44, 0x48, 0x7f],
+    [0x38, 0x54, 0x54, 0x54, 0x18],
+    [0x08, 0x7e, 0x09, 0x01, 0x02],
+    [0x0c, 0x52, 0x52, 0x52, 0x3e],
+    [0x7f, 0x08, 0x04, 0x04, 0x78],
+    [0x00, 0x44, 0x7d, 0x40, 0x00],
+    [0x20, 0x40, 0x44, 0x3d, 0x00],
+    [0x7f, 0x10, 0x28, 0x44, 0x00],
+    [0x00, 0x41, 0x7f, 0x40, 0x00],
+    [0x7c, 0x04, 0x18, 0x04, 0x78],
+    [0x7c, 0x08, 0x04, 0x04, 0x78],
+    [0x38, 0x44, 0x44, 0x44, 0x38],
+    [0x7c, 0x14, 0x14, 0x14, 0x08],
+    [0x08, 0x14, 0x14, 0x18, 0x7c],
+    [0x7c, 0x08, 0x04, 0x04, 0x08],
+    [0x48, 0x54, 0x54, 0x54, 0x20],
+    [0x04, 0x3f, 0x44, 0x40, 0x20],
+    [0x3c, 0x40, 0x40, 0x20, 0x7c],
+    [0x1c, 0x20, 0x40, 0x20, 0x1c],
+    [0x3c, 0x40, 0x30, 0x40, 0x3c],
+    [0x44, 0x28, 0x10, 0x28, 0x44],
+    [0x0c, 0x50, 0x50, 0x50, 0x3c],
+    [0x44, 0x64, 0x54, 0x4c, 0x44],
+    [0x00, 0x08, 0x36, 0x41, 0x00],
+    [0x00, 0x00, 0x7f, 0x00, 0x00],
+    [0x00, 0x41, 0x36, 0x08, 0x00],
+    [0x10, 0x08, 0x08, 0x10, 0x08],
+];
+
+/// Text laid out onto A4 pages, top to bottom
+pub(crate) struct PdfText {
+    pages: Vec<Vec<(u32, String)>>,
+    /// Height already used on the last page
+    used: u32,
+}
+
+impl PdfText {
+    pub(crate) fn new() -> Self {
+        Self {
+            pages: vec![Vec::new()],
+            used: 0,
+        }
+    }
+
+    /// One line at `size` points, starting a page when this one is full
+    pub(crate) fn line(&mut self, size: u32, text: &str) {
+        let height = size.max(BODY_SIZE) * LEADING / BODY_SIZE;
+        if self.used + height > PAGE_HEIGHT - 2 * MARGIN - FOOTER {
+            self.pages.push(Vec::new());
+            self.used = 0;
+        }
+        self.used += height;
+        self.pages.last_mut().expect("at least one page").push((size, text.to_string()));
+    }
+
+    /// Body text wrapped at word boundaries, every line indented by `indent` columns
+    pub(crate) fn paragraph(&mut self, indent: usize, text: &str) {
+        let width = COLUMNS.saturating_sub(indent).max(1);
+        for line in wrap(text, width) {
+            self.line(BODY_SIZE, &format!("{:indent$}{}", "", line, indent = indent));
+        }
+    }
+
+    pub(crate) fn blank(&mut self) {
+        self.line(BODY_SIZE, "");
+    }
+
+    /// The PDF/A-2b file; `created` is an XMP date, left out for byte-stable output
+    pub(crate) fn finish(self, title: &str, created: Option<&str>) -> Vec<u8> {
+        // Objects 1-5 are fixed; the glyph procedures follow, then each page and its contents
+        let glyphs = 6;
+        let first_page = glyphs + GLYPHS.len();
+        let count = self.pages.len();
+        let mut objects: Vec<Vec<u8>> = Vec::new();
+
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R /Metadata 5 0 R /Lang (en) >>".to_vec());
+        let kids: Vec<String> = (0..count).map(|page| format!("{} 0 R", first_page + 2 * page)).collect();
+        objects.push(format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), count).into_bytes());
+
+        let mut names = String::new();
+        let mut procs = String::new();
+        for index in 0..GLYPHS.len() {
+            let _ = write!(names, " /g{}", index + 32);
+            let _ = write!(procs, " /g{} {} 0 R", index + 32, glyphs + index);
+        }
+        let widths = vec!["600"; GLYPHS.len()].join(" ");
+        objects.push(
+            format!(
+                "<< /Type /Font /Subtype /Type3 /FontBBox [0 0 500 700] /FontMatrix [0.001 0 0 0.001 0 0] \
+                 /CharProcs <<{}>> /Encoding << /Type /Encoding /Differences [32{}] >> /FirstChar 32 /LastChar 126 \
+                 /Widths [{}] /ToUnicode 4 0 R /Resources << >> >>",
+                procs, names, widths
+            )
+            .into_bytes(),
+        );
+        objects.push(stream("", TO_UNICODE.as_bytes()));
+        objects.push(stream("/Type /Metadata /Subtype /XML", xmp(title, created).as_bytes()));
+        for glyph in &GLYPHS {
+            objects.push(stream("", glyph_procedure(glyph).as_bytes()));
+        }
+
+        for (number, lines) in self.pages.iter().enumerate() {
+            let contents = first_page + 2 * number + 1;
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Contents {} 0 R \
+                     /Resources << /Font << /F1 3 0 R >> \
+                     /ColorSpace << /CS0 [/CalGray << /WhitePoint [0.9505 1 1.089] /Gamma 2.2 >>] >> >> >>",
+                    PAGE_WIDTH, PAGE_HEIGHT, contents
+                )
+                .into_bytes(),
+            );
+            objects.push(stream("", page_contents(lines, number + 1, count).as_bytes()));
+        }
+
+        let mut pdf = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::new();
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            pdf.extend_from_slice(object);
+            pdf.extend_from_slice(b"\nendobj\n");
+        }
+        let xref = pdf.len();
+        let id = hex::encode(&Sha256::digest(&pdf)[..16]);
+        let mut tail = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(tail, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            tail,
+            "trailer\n<< /Size {} /Root 1 0 R /ID [<{}> <{}>] >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            id,
+            id,
+            xref
+        );
+        pdf.extend_from_slice(tail.as_bytes());
+        pdf
+    }
+}
+
+/// Greedy word wrap; words longer than `width` are split
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        if !line.is_empty() && line.chars().count() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        while word.len() > width {
+            lines.push(word.drain(..width).collect());
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+fn stream(entries: &str, data: &[u8]) -> Vec<u8> {
+    let separator = if entries.is_empty() { "" } else { " " };
+    let mut object = format!("<< {}{}/Length {} >>\nstream\n", entries, separator, data.len()).into_bytes();
+    object.extend_from_slice(data);
+    object.extend_from_slice(b"\nendstream");
+    object
+}
+
+/// Glyph drawing: one rectangle per horizontal run of set pixels, 100 units a pixel
+fn glyph_procedure(columns: &[u8; 5]) -> String {
+    let mut procedure = String::from("600 0 0 0 500 700 d1\n");
+    for row in 0..7 {
+        let mut column = 0;
+        while column < columns.len() {
+            if columns[column] >> row & 1 == 0 {
+                column += 1;
+                continue;
+            }
+            let start = column;
+            while column < columns.len() && columns[column] >> row & 1 == 1 {
+                column += 1;
+            }
+            let _ = writeln!(procedure, "{} {} {} 100 re", start * 100, (6 - row) * 100, (column - start) * 100);
+        }
+    }
+    if procedure.contains("re") {
+        procedure.push_str("f\n");
+    }
+    procedure
+}
+
+fn page_contents(lines: &[(u32, String)], number: usize, count: usize) -> String {
+    let mut contents = String::from("/CS0 cs 0 sc\n");
+    let mut y = PAGE_HEIGHT - MARGIN;
+    for (size, text) in lines {
+        y -= (*size).max(BODY_SIZE) * LEADING / BODY_SIZE;
+        if !text.is_empty() {
+            let _ = writeln!(contents, "BT /F1 {} Tf {} {} Td ({}) Tj ET", size, MARGIN, y, literal(text));
+        }
+    }
+    let footer = format!("Page {} of {}", number, count);
+    let x = PAGE_WIDTH - MARGIN - (footer.len() as u32 * BODY_SIZE * 6).div_ceil(10);
+    let _ = writeln!(contents, "BT /F1 {} Tf {} {} Td ({}) Tj ET", BODY_SIZE, x, MARGIN, footer);
+    contents
+}
+
+/// PDF string literal of the text, with characters outside the font as `?`
+fn literal(text: &str) -> String {
+    text.chars()
+        .map(|c| if (' '..='~').contains(&c) { c } else { '?' })
+        .fold(String::new(), |mut out, c| {
+            if matches!(c, '(' | ')' | '\\') {
+                out.push('\\');
+            }
+            out.push(c);
+            out
+        })
+}
+
+fn xmp(title: &str, created: Option<&str>) -> String {
+    let escape = |text: &str| text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    let created = created.map(|at| format!("<xmp:CreateDate>{}</xmp:CreateDate>\n", at)).unwrap_or_default();
+    format!(
+        "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n\
+         <x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n\
+         <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n\
+         <rdf:Description rdf:about=\"\" xmlns:pdfaid=\"http://www.aiim.org/pdfa/ns/id/\" \
+         xmlns:dc=\"http://purl.org/dc/elements/1.1/\" xmlns:pdf=\"http://ns.adobe.com/pdf/1.3/\" \
+         xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\">\n\
+         <pdfaid:part>2</pdfaid:part>\n<pdfaid:conformance>B</pdfaid:conformance>\n\
+         <dc:title><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:title>\n\
+         <pdf:Producer>crucible-core {}</pdf:Producer>\n{}\
+         </rdf:Description>\n</rdf:RDF>\n</x:xmpmeta>\n<?xpacket end=\"w\"?>",
+        escape(title),
+        env!("CARGO_PKG_VERSION"),
+        created
+    )
+}
+
+const TO_UNICODE: &str = "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n\
+/CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+/CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n\
+1 begincodespacerange\n<00> <FF>\nendcodespacerange\n\
+1 beginbfrange\n<20> <7E> <0020>\nendbfrange\n\
+endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend";
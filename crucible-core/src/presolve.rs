@@ -0,0 +1,324 @@
+//! Interval Pre-solver - settle the easy part of a spec before Z3 sees it
+//!
+//! Most requirements bound single fields by constants (`amount > 0`,
+//! `age between 18 and 65`, `status != 3`). A conjunction of such bounds is
+//! satisfiable exactly when every field's interval, minus its excluded
+//! points, is non-empty, which takes a few comparisons rather than a solver
+//! call. `presolve` splits the top-level conjunction:
+//!
+//! - bounds on a field that nothing else mentions are decided here, with a
+//!   witness value for the model
+//! - any empty interval makes the whole conjunction unsatisfiable
+//! - everything else (disjunctions, field-to-field comparisons, arithmetic,
+//!   remainders, strings) is the residual core, returned for Z3 together with
+//!   the bounds of the fields it mentions
+//!
+//! Bounds are over the integers, matching Z3's `Int` sort for comparisons, so
+//! `x > 3 and x < 4` is unsatisfiable.
+
+use crate::{CompoundConstraint, Constraint, ConstraintOperator};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The integers a field may take: `low..=high` minus `excluded`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Interval {
+    /// Inclusive lower bound; unbounded when `None`
+    pub low: Option<i64>,
+    /// Inclusive upper bound; unbounded when `None`
+    pub high: Option<i64>,
+    pub excluded: BTreeSet<i64>,
+}
+
+impl Interval {
+    /// Narrow the interval by `field <operator> value`; `false` for operators
+    /// that are not bounds. Bounds that overflow `i64` make the interval empty
+    fn restrict(&mut self, operator: ConstraintOperator, value: i64) -> bool {
+        let raise = |low: &mut Option<i64>, bound: i64| *low = Some(low.map_or(bound, |low| low.max(bound)));
+        let lower = |high: &mut Option<i64>, bound: i64| *high = Some(high.map_or(bound, |high| high.min(bound)));
+        match operator {
+            ConstraintOperator::GreaterThanOrEqual => raise(&mut self.low, value),
+            ConstraintOperator::GreaterThan => match value.checked_add(1) {
+                Some(bound) => raise(&mut self.low, bound),
+                None => self.make_empty(),
+            },
+            ConstraintOperator::LessThanOrEqual => lower(&mut self.high, value),
+            ConstraintOperator::LessThan => match value.checked_sub(1) {
+                Some(bound) => lower(&mut self.high, bound),
+                None => self.make_empty(),
+            },
+            ConstraintOperator::Equal => {
+                raise(&mut self.low, value);
+                lower(&mut self.high, value);
+            }
+            ConstraintOperator::NotEqual => {
+                self.excluded.insert(value);
+            }
+            ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => return false,
+        }
+        true
+    }
+
+    fn make_empty(&mut self) {
+        self.low = Some(1);
+        self.high = Some(0);
+    }
+
+    /// A value in the interval, closest to zero within the bounds; `None` when empty
+    pub fn witness(&self) -> Option<i64> {
+        let start = match (self.low, self.high) {
+            (Some(low), Some(high)) if low > high => return None,
+            (Some(low), _) if low > 0 => low,
+            (_, Some(high)) if high < 0 => high,
+            _ => 0,
+        };
+        // Any run of `excluded.len() + 1` values holds a free one, so look that
+        // far upwards, then downwards in case the upper bound cut the run short
+        let run = self.excluded.len() + 1;
+        let up = (0..)
+            .map_while(|step| start.checked_add(step))
+            .take_while(|v| self.high.is_none_or(|high| *v <= high))
+            .take(run);
+        let down = (1..)
+            .map_while(|step| start.checked_sub(step))
+            .take_while(|v| self.low.is_none_or(|low| *v >= low))
+            .take(run);
+        up.chain(down).find(|v| !self.excluded.contains(v))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.witness().is_none()
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bound = |b: Option<i64>, infinite: &str| b.map_or_else(|| infinite.to_string(), |b| b.to_string());
+        write!(f, "[{}, {}]", bound(self.low, "-inf"), bound(self.high, "+inf"))?;
+        if !self.excluded.is_empty() {
+            let points: Vec<String> = self.excluded.iter().map(i64::to_string).collect();
+            write!(f, " \\ {{{}}}", points.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// What the pre-solver could decide
+#[derive(Debug, Clone, PartialEq)]
+pub enum Presolved {
+    /// Satisfiable without a solver; one value per bounded field
+    Sat(BTreeMap<String, i64>),
+    /// Unsatisfiable without a solver, with the field and bounds that conflict
+    Unsat(String),
+    /// The part Z3 still has to decide, and witnesses for the fields decided
+    /// here, whose bounds were dropped from `core`
+    Residual {
+        core: CompoundConstraint,
+        decided: BTreeMap<String, i64>,
+    },
+}
+
+/// `field <op> integer` on a plain field, the only shape the intervals handle
+fn as_bound(constraint: &Constraint) -> Option<(&str, ConstraintOperator, i64)> {
+    let field = constraint.left_variable.as_str();
+    let plain = field.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+    if !plain || constraint.operator.is_membership() {
+        return None;
+    }
+    let value = constraint.right_value.trim().parse().ok()?;
+    Some((field, constraint.operator, value))
+}
+
+/// Every identifier in the subtree, over-approximating the fields it reads
+fn mentioned(compound: &CompoundConstraint, names: &mut BTreeSet<String>) {
+    match compound {
+        CompoundConstraint::Simple(constraint) => {
+            for side in [&constraint.left_variable, &constraint.right_value] {
+                let words = side.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'));
+                names.extend(
+                    words
+                        .filter(|w| w.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_'))
+                        .map(str::to_string),
+                );
+            }
+        }
+        CompoundConstraint::And(parts)
+        | CompoundConstraint::Or(parts)
+        | CompoundConstraint::ExactlyOne(parts)
+        | CompoundConstraint::AtMostOne(parts) => parts.iter().for_each(|p| mentioned(p, names)),
+        CompoundConstraint::Not(inner) => mentioned(inner, names),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            mentioned(antecedent, names);
+            mentioned(consequent, names);
+        }
+        CompoundConstraint::Distinct(fields) => names.extend(fields.iter().cloned()),
+        CompoundConstraint::Named(_) => {}
+    }
+}
+
+/// The top-level conjuncts, with nested ANDs flattened
+fn conjuncts<'a>(compound: &'a CompoundConstraint, out: &mut Vec<&'a CompoundConstraint>) {
+    match compound {
+        CompoundConstraint::And(parts) => parts.iter().for_each(|p| conjuncts(p, out)),
+        other => out.push(other),
+    }
+}
+
+/// Decide the interval part of `compound` and return what is left for Z3
+pub fn presolve(compound: &CompoundConstraint) -> Presolved {
+    let mut parts = Vec::new();
+    conjuncts(compound, &mut parts);
+
+    // A named predicate may read any field, so nothing can be set aside
+    if parts.iter().any(|p| !p.references().is_empty()) {
+        return Presolved::Residual {
+            core: compound.clone(),
+            decided: BTreeMap::new(),
+        };
+    }
+
+    let mut intervals: BTreeMap<&str, Interval> = BTreeMap::new();
+    let mut bounds: BTreeMap<&str, Vec<&CompoundConstraint>> = BTreeMap::new();
+    let mut hard = Vec::new();
+    for part in parts {
+        let bound = match part {
+            CompoundConstraint::Simple(constraint) => as_bound(constraint),
+            _ => None,
+        };
+        match bound {
+            Some((field, operator, value)) if intervals.entry(field).or_default().restrict(operator, value) => {
+                bounds.entry(field).or_default().push(part);
+            }
+            _ => hard.push(part),
+        }
+    }
+
+    for (field, interval) in &intervals {
+        if interval.is_empty() {
+            return Presolved::Unsat(format!("no integer `{}` lies in {}", field, interval));
+        }
+    }
+
+    let mut coupled = BTreeSet::new();
+    for part in &hard {
+        mentioned(part, &mut coupled);
+    }
+    let decided: BTreeMap<String, i64> = intervals
+        .iter()
+        .filter(|(field, _)| !coupled.contains(**field))
+        .filter_map(|(field, interval)| Some((field.to_string(), interval.witness()?)))
+        .collect();
+    if hard.is_empty() {
+        return Presolved::Sat(decided);
+    }
+
+    // Bounds on fields the core reads stay with it
+    let mut residual: Vec<CompoundConstraint> = hard.into_iter().cloned().collect();
+    for (field, constraints) in bounds {
+        if coupled.contains(field) {
+            residual.extend(constraints.into_iter().cloned());
+        }
+    }
+    let core = match residual.len() {
+        1 => residual.pop().unwrap(),
+        _ => CompoundConstraint::And(residual),
+    };
+    Presolved::Residual { core, decided }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simple(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_independent_bounds_are_decided() {
+        let spec = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            simple("amount", ConstraintOperator::LessThanOrEqual, "100"),
+            simple("amount", ConstraintOperator::NotEqual, "1"),
+            CompoundConstraint::And(vec![simple("age", ConstraintOperator::LessThan, "-3")]),
+        ]);
+        assert_eq!(
+            presolve(&spec),
+            Presolved::Sat(BTreeMap::from([("age".to_string(), -4), ("amount".to_string(), 2)]))
+        );
+
+        let empty = CompoundConstraint::And(vec![
+            simple("x", ConstraintOperator::GreaterThan, "3"),
+            simple("x", ConstraintOperator::LessThan, "5"),
+            simple("x", ConstraintOperator::NotEqual, "4"),
+        ]);
+        assert_eq!(presolve(&empty), Presolved::Unsat("no integer `x` lies in [4, 4] \\ {4}".to_string()));
+    }
+
+    #[test]
+    fn test_residual_keeps_bounds_of_coupled_fields() {
+        let spec = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            simple("fee", ConstraintOperator::GreaterThanOrEqual, "0"),
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount + fee"),
+            simple("age", ConstraintOperator::GreaterThanOrEqual, "18"),
+        ]);
+        let Presolved::Residual {
+            core: CompoundConstraint::And(residual),
+            decided,
+        } = presolve(&spec)
+        else {
+            panic!("expected a residual conjunction");
+        };
+        assert_eq!(decided, BTreeMap::from([("age".to_string(), 18)]));
+        assert_eq!(
+            residual,
+            vec![
+                simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount + fee"),
+                simple("amount", ConstraintOperator::GreaterThan, "0"),
+                simple("fee", ConstraintOperator::GreaterThanOrEqual, "0"),
+            ]
+        );
+
+        // An empty interval settles it even with a residual core
+        let spec = CompoundConstraint::And(vec![
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            simple("amount", ConstraintOperator::LessThan, &i64::MIN.to_string()),
+        ]);
+        assert!(matches!(presolve(&spec), Presolved::Unsat(_)));
+
+        let named = CompoundConstraint::And(vec![
+            simple("age", ConstraintOperator::GreaterThanOrEqual, "18"),
+            CompoundConstraint::Named("adult".to_string()),
+        ]);
+        assert_eq!(
+            presolve(&named),
+            Presolved::Residual {
+                core: named.clone(),
+                decided: BTreeMap::new()
+            }
+        );
+    }
+
+    #[test]
+    fn test_witness_avoids_excluded_points() {
+        let interval = Interval {
+            low: None,
+            high: Some(1),
+            excluded: BTreeSet::from([0, 1, -1]),
+        };
+        assert_eq!(interval.witness(), Some(-2));
+        let interval = Interval {
+            low: Some(0),
+            high: Some(100),
+            excluded: BTreeSet::from([0, 1, 2, 3]),
+        };
+        assert_eq!(interval.witness(), Some(4));
+        assert_eq!(Interval::default().witness(), Some(0));
+    }
+}
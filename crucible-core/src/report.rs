@@ -0,0 +1,405 @@
+//! HTML and PDF/A Reports - one self-contained file for a compliance audit package
+//!
+//! `ReportBuilder` renders an Intent-AST as a single HTML page that loads
+//! nothing (no scripts, fonts or images): every requirement with its parsed
+//! constraints, lifecycle status, solver verdict, counterexample and prover
+//! result, an inline SVG of its constraint tree, and a coverage matrix of
+//! requirements against the fields they read.
+//!
+//! For certification submissions (DO-178C, IEC 61508) `build_pdf` renders
+//! the same traceability data as a PDF/A-2b document, plus the SHA-256 of
+//! every generated artifact handed to `artifact`. It is written in-process
+//! and, without `generated_at`, byte-identical across runs.
+//!
+//! The core crate runs no solver, so verdicts and counterexamples are handed
+//! in by whoever ran one:
+//!
+//! ```
+//! use crucible_core::{IntentAst, ReportBuilder, SolverVerdict};
+//!
+//! let mut ast = IntentAst::new();
+//! ast.add_requirement("Amount must not exceed 100".to_string());
+//! let id = ast.requirements[0].id;
+//! let html = ReportBuilder::new(&ast)
+//!     .title("Payments")
+//!     .verdict(id, SolverVerdict::Satisfiable)
+//!     .build();
+//! assert!(html.starts_with("<!DOCTYPE html>"));
+//! ```
+
+use crate::pdf::{PdfText, BODY_SIZE};
+use crate::{IntentAst, ProofStatus, Requirement, RequirementStatus};
+use chrono::{DateTime, SecondsFormat, Utc};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use uuid::Uuid;
+
+/// What the solver said about one requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverVerdict {
+    Satisfiable,
+    Unsatisfiable,
+    /// Timed out or could not be translated
+    Unknown,
+}
+
+/// Builds the single-file HTML report of an Intent-AST
+pub struct ReportBuilder<'a> {
+    ast: &'a IntentAst,
+    title: String,
+    verdicts: HashMap<Uuid, SolverVerdict>,
+    counterexamples: HashMap<Uuid, BTreeMap<String, String>>,
+    /// SHA-256 of each generated artifact, by name
+    artifacts: BTreeMap<String, String>,
+    generated_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> ReportBuilder<'a> {
+    pub fn new(ast: &'a IntentAst) -> Self {
+        Self {
+            ast,
+            title: "Crucible verification report".to_string(),
+            verdicts: HashMap::new(),
+            counterexamples: HashMap::new(),
+            artifacts: BTreeMap::new(),
+            generated_at: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// The solver's verdict on a requirement; without one the report falls
+    /// back to the requirement's lifecycle status
+    pub fn verdict(mut self, requirement: Uuid, verdict: SolverVerdict) -> Self {
+        self.verdicts.insert(requirement, verdict);
+        self
+    }
+
+    /// Field values that violate a requirement, such as a prover's model
+    pub fn counterexample(mut self, requirement: Uuid, values: BTreeMap<String, String>) -> Self {
+        self.counterexamples.insert(requirement, values);
+        self
+    }
+
+    /// A file generated from the Intent-AST, listed with its SHA-256
+    pub fn artifact(mut self, name: impl Into<String>, contents: &[u8]) -> Self {
+        self.artifacts.insert(name.into(), hex::encode(Sha256::digest(contents)));
+        self
+    }
+
+    /// Stamp the report; left out, the same inputs give the same bytes
+    pub fn generated_at(mut self, at: DateTime<Utc>) -> Self {
+        self.generated_at = Some(at);
+        self
+    }
+
+    pub fn build(&self) -> String {
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             <style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+            escape(&self.title),
+            STYLE,
+            escape(&self.title)
+        );
+        self.summary(&mut html);
+        html.push_str("<h2>Requirements</h2>\n");
+        for (index, requirement) in self.ast.requirements.iter().enumerate() {
+            self.requirement(&mut html, index, requirement);
+        }
+        self.coverage(&mut html);
+        if !self.artifacts.is_empty() {
+            html.push_str("<h2>Generated artifacts</h2>\n<table>\n<tr><th>File</th><th>SHA-256</th></tr>\n");
+            for (name, sha256) in &self.artifacts {
+                let _ = writeln!(
+                    html,
+                    "<tr><td><code>{}</code></td><td><code>{}</code></td></tr>",
+                    escape(name),
+                    sha256
+                );
+            }
+            html.push_str("</table>\n");
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// The traceability report as a PDF/A-2b document
+    pub fn build_pdf(&self) -> Vec<u8> {
+        let mut pdf = PdfText::new();
+        pdf.line(16, &self.title);
+        pdf.blank();
+        let generated = self.generated_at.map(|at| at.to_rfc3339_opts(SecondsFormat::Secs, true));
+        pdf.paragraph(0, &format!("Intent-AST: {}", self.ast.id));
+        if let Some(at) = &generated {
+            pdf.paragraph(0, &format!("Generated:  {}", at));
+        }
+        pdf.paragraph(0, &format!("Requirements: {}", self.ast.requirements.len()));
+        for (label, count) in self.counts() {
+            pdf.paragraph(2, &format!("{}: {}", label, count));
+        }
+
+        for (index, requirement) in self.ast.requirements.iter().enumerate() {
+            pdf.blank();
+            pdf.line(12, &format!("R{}  [{}]", index + 1, self.verdict_of(requirement).0));
+            pdf.paragraph(2, &format!("ID: {}", requirement.id));
+            pdf.paragraph(2, &format!("\"{}\"", requirement.content));
+            pdf.paragraph(
+                2,
+                &format!(
+                    "Status: {}; Severity: {:?}; Kind: {:?}; Prover: {}",
+                    status_label(requirement.status),
+                    requirement.severity,
+                    requirement.kind,
+                    proof_label(requirement.proof)
+                ),
+            );
+            match requirement.constraint_tree() {
+                Some(tree) => {
+                    pdf.paragraph(2, &format!("Constraints: {}", tree));
+                    pdf.paragraph(2, &format!("Canonical hash: {}", tree.canonical_hash()));
+                }
+                None => pdf.paragraph(2, "Constraints: none parsed"),
+            }
+            if let Some(values) = self.counterexamples.get(&requirement.id) {
+                pdf.paragraph(2, "Counterexample:");
+                for (field, value) in values {
+                    pdf.paragraph(4, &format!("{} = {}", field, value));
+                }
+            }
+        }
+
+        if !self.artifacts.is_empty() {
+            pdf.blank();
+            pdf.line(12, "Generated artifacts (SHA-256)");
+            for (name, sha256) in &self.artifacts {
+                pdf.paragraph(2, name);
+                pdf.line(BODY_SIZE, &format!("    {}", sha256));
+            }
+        }
+        pdf.finish(&self.title, generated.as_deref())
+    }
+
+    fn verdict_of(&self, requirement: &Requirement) -> (&'static str, &'static str) {
+        match self.verdicts.get(&requirement.id) {
+            Some(SolverVerdict::Satisfiable) => ("SAT", "pass"),
+            Some(SolverVerdict::Unsatisfiable) => ("UNSAT", "fail"),
+            Some(SolverVerdict::Unknown) => ("unknown", "warn"),
+            None if requirement.status.is_proved() => ("verified", "pass"),
+            None => ("not run", "none"),
+        }
+    }
+
+    /// How many requirements got each verdict
+    fn counts(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for requirement in &self.ast.requirements {
+            *counts.entry(self.verdict_of(requirement).0).or_default() += 1;
+        }
+        counts
+    }
+
+    fn summary(&self, html: &mut String) {
+        html.push_str("<table class=\"summary\">\n");
+        let _ = writeln!(html, "<tr><th>Intent-AST</th><td><code>{}</code></td></tr>", self.ast.id);
+        if let Some(at) = self.generated_at {
+            let _ = writeln!(
+                html,
+                "<tr><th>Generated</th><td>{}</td></tr>",
+                at.to_rfc3339_opts(SecondsFormat::Secs, true)
+            );
+        }
+        let _ = writeln!(html, "<tr><th>Requirements</th><td>{}</td></tr>", self.ast.requirements.len());
+        for (label, count) in self.counts() {
+            let _ = writeln!(html, "<tr><th>{}</th><td>{}</td></tr>", label, count);
+        }
+        html.push_str("</table>\n");
+    }
+
+    fn requirement(&self, html: &mut String, index: usize, requirement: &Requirement) {
+        let (verdict, class) = self.verdict_of(requirement);
+        let _ = writeln!(
+            html,
+            "<section class=\"requirement\" id=\"R{n}\">\n<h3>R{n} <span class=\"badge {}\">{}</span></h3>\n\
+             <blockquote>{}</blockquote>\n<dl>",
+            class,
+            verdict,
+            escape(&requirement.content),
+            n = index + 1
+        );
+        let fields = [
+            ("Status", status_label(requirement.status).to_string()),
+            ("Severity", format!("{:?}", requirement.severity)),
+            ("Kind", format!("{:?}", requirement.kind)),
+            ("Prover", proof_label(requirement.proof).to_string()),
+            ("Author", requirement.author.clone().unwrap_or_else(|| "-".to_string())),
+        ];
+        for (name, value) in fields {
+            let _ = writeln!(html, "<dt>{}</dt><dd>{}</dd>", name, escape(&value));
+        }
+        let tree = requirement.constraint_tree();
+        let constraints = tree.as_ref().map_or_else(|| "none parsed".to_string(), |tree| tree.to_string());
+        let _ = writeln!(html, "<dt>Constraints</dt><dd><code>{}</code></dd>\n</dl>", escape(&constraints));
+
+        if let Some(values) = self.counterexamples.get(&requirement.id) {
+            html.push_str("<table class=\"counterexample\">\n<caption>Counterexample</caption>\n");
+            for (field, value) in values {
+                let _ = writeln!(html, "<tr><th>{}</th><td><code>{}</code></td></tr>", escape(field), escape(value));
+            }
+            html.push_str("</table>\n");
+        }
+        if let Some(tree) = tree {
+            let _ = write!(html, "<figure>\n{}<figcaption>Constraint tree</figcaption>\n</figure>\n", tree.to_svg());
+        }
+        html.push_str("</section>\n");
+    }
+
+    /// Requirements down, fields across; a mark where the requirement reads the field
+    fn coverage(&self, html: &mut String) {
+        let graph = self.ast.dependency_graph();
+        html.push_str("<h2>Coverage</h2>\n");
+        if graph.field_requirements.is_empty() {
+            html.push_str("<p>No requirement constrains a field.</p>\n");
+            return;
+        }
+        html.push_str("<table class=\"coverage\">\n<tr><th></th>");
+        for field in graph.field_requirements.keys() {
+            let _ = write!(html, "<th><code>{}</code></th>", escape(field));
+        }
+        html.push_str("</tr>\n");
+        for (index, requirement) in self.ast.requirements.iter().enumerate() {
+            let _ = write!(html, "<tr><th><a href=\"#R{n}\">R{n}</a></th>", n = index + 1);
+            for readers in graph.field_requirements.values() {
+                let mark = if readers.contains(&requirement.id) { "&#9679;" } else { "" };
+                let _ = write!(html, "<td>{}</td>", mark);
+            }
+            html.push_str("</tr>\n");
+        }
+        let _ = write!(html, "<tr><th>Requirements</th>");
+        for readers in graph.field_requirements.values() {
+            let _ = write!(html, "<td>{}</td>", readers.len());
+        }
+        html.push_str("</tr>\n</table>\n");
+    }
+}
+
+fn status_label(status: RequirementStatus) -> &'static str {
+    match status {
+        RequirementStatus::Draft => "draft",
+        RequirementStatus::Parsed => "parsed",
+        RequirementStatus::Verified => "verified",
+        RequirementStatus::CodeGenerated => "code generated",
+        RequirementStatus::Deprecated => "deprecated",
+    }
+}
+
+fn proof_label(proof: Option<ProofStatus>) -> &'static str {
+    match proof {
+        Some(ProofStatus::Proved) => "proved",
+        Some(ProofStatus::Failed) => "failed",
+        Some(ProofStatus::Inconclusive) => "inconclusive",
+        None => "not run",
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Print-friendly styling, inlined so the file stands alone
+const STYLE: &str = "body{font-family:Helvetica,Arial,sans-serif;max-width:60em;margin:2em auto;color:#222}\
+table{border-collapse:collapse;margin:1em 0}th,td{border:1px solid #ccc;padding:.3em .6em;text-align:left}\
+.coverage td{text-align:center}.requirement{border-top:1px solid #ccc;padding-top:.5em;page-break-inside:avoid}\
+blockquote{margin:.5em 0;font-style:italic}dl{display:grid;grid-template-columns:8em auto;gap:.2em}\
+dt{font-weight:bold}dd{margin:0}figure svg{max-width:100%;height:auto}\
+.badge{font-size:.8em;padding:.1em .5em;border-radius:.3em;color:#fff;background:#888}\
+.pass{background:#2a7d3a}.fail{background:#b3261e}.warn{background:#b26a00}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CompoundConstraint, Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_html_report() {
+        let mut ast = IntentAst::new();
+        let limit = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::LessThanOrEqual,
+            right_value: "100".to_string(),
+        });
+        let first = ast.add_constrained_requirement("Amount must be <= 100".to_string(), Some(limit)).id;
+        ast.add_constrained_requirement("Notes are free text".to_string(), None);
+        let counterexample = BTreeMap::from([("amount".to_string(), "101".to_string())]);
+        let at = DateTime::parse_from_rfc3339("2026-10-18T09:00:00Z").unwrap().with_timezone(&Utc);
+
+        let html = ReportBuilder::new(&ast)
+            .title("Payments & refunds")
+            .verdict(first, SolverVerdict::Satisfiable)
+            .counterexample(first, counterexample)
+            .generated_at(at)
+            .build();
+        assert!(html.contains("<title>Payments &amp; refunds</title>"));
+        assert!(html.contains("<tr><th>Generated</th><td>2026-10-18T09:00:00Z</td></tr>"));
+        assert!(html.contains("<span class=\"badge pass\">SAT</span></h3>\n<blockquote>Amount must be &lt;= 100"));
+        assert!(html.contains("<h3>R2 <span class=\"badge none\">not run</span></h3>"));
+        assert!(html.contains("<dt>Constraints</dt><dd><code>(amount &lt;= 100)</code></dd>"));
+        assert!(html.contains("<caption>Counterexample</caption>\n<tr><th>amount</th><td><code>101</code>"));
+        assert_eq!(html.matches("<svg").count(), 1);
+        assert!(html.contains("</th></tr>\n<tr><th><a href=\"#R1\">R1</a></th><td>&#9679;</td>"));
+        assert!(!html.contains("<script") && !html.contains("src=") && !html.contains("http-equiv"));
+
+        // Without a timestamp the report is reproducible
+        assert_eq!(ReportBuilder::new(&ast).build(), ReportBuilder::new(&ast).build());
+    }
+
+    #[test]
+    fn test_pdf_report() {
+        let mut ast = IntentAst::new();
+        let limit = CompoundConstraint::Simple(Constraint {
+            left_variable: "amount".to_string(),
+            operator: ConstraintOperator::LessThanOrEqual,
+            right_value: "100".to_string(),
+        });
+        let first = ast.add_constrained_requirement("Amount (in EUR) must be <= 100".to_string(), Some(limit)).id;
+        for n in 0..20 {
+            ast.add_requirement(format!("Note {} is free text", n));
+        }
+        let build = || {
+            ReportBuilder::new(&ast)
+                .title("Payments")
+                .verdict(first, SolverVerdict::Satisfiable)
+                .artifact("validator.rs", b"pub fn validate() {}")
+                .build_pdf()
+        };
+
+        let pdf = build();
+        assert_eq!(pdf, build());
+        assert!(pdf.starts_with(b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n"));
+        // Past the binary marker line the file is UTF-8
+        let text = std::str::from_utf8(&pdf[15..]).unwrap();
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("<pdfaid:part>2</pdfaid:part>"));
+        assert!(text.contains("(R1  [SAT]) Tj"));
+        assert!(text.contains("(  \"Amount \\(in EUR\\) must be <= 100\") Tj"));
+        assert!(text.contains("(  Constraints: \\(amount <= 100\\)) Tj"));
+        assert!(text.contains(&format!("(    {}) Tj", hex::encode(Sha256::digest(b"pub fn validate() {}")))));
+        assert!(text.contains("/Type /Pages /Kids [101 0 R 103 0 R 105 0 R] /Count 3"));
+        assert!(text.contains("(Page 3 of 3) Tj"));
+
+        // Every cross-reference entry points at its object
+        let xref = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse::<usize>().unwrap() - 15;
+        for (number, entry) in text[xref..].lines().skip(3).take_while(|l| l.ends_with(" n ")).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(text[offset - 15..].starts_with(&format!("{} 0 obj\n", number + 1)));
+        }
+    }
+}
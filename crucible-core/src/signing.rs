@@ -0,0 +1,302 @@
+//! Artifact Signing - ed25519 signatures over the Intent-AST, verification
+//! reports and generated files (feature `signing`)
+//!
+//! A signature proves an artifact is byte-for-byte what Crucible produced
+//! after verification. Structured artifacts (the Intent-AST, a report) are
+//! signed over their canonical JSON: compact, with object keys sorted, so the
+//! signature does not depend on field order. A generated file is signed over
+//! its contents and gets the signature in a first-line comment:
+//!
+//! ```text
+//! // crucible-signature: ed25519 fingerprint=3f9c2a1b7d0e4c85 sig=9a1f...
+//! pub fn validate(...) { ... }
+//! ```
+//!
+//! Files whose format has no comments (JSON, for one) are covered only by the
+//! detached `SignatureManifest`, which lists every artifact's SHA-256 and
+//! signature next to the public key that checks them.
+
+use crate::IntentAst;
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+
+/// File name of the detached manifest written next to signed artifacts
+pub const MANIFEST_FILE: &str = "crucible.sig.json";
+
+const HEADER_MARKER: &str = "crucible-signature: ed25519";
+
+/// Errors raised while signing or checking artifacts
+#[derive(Debug, Error)]
+pub enum SigningError {
+    #[error("invalid signing key: {0}")]
+    InvalidKey(String),
+
+    #[error("`{0}` is not listed in the signature manifest")]
+    Unlisted(String),
+
+    #[error("`{0}` was modified after it was signed")]
+    Tampered(String),
+
+    #[error("`{path}` was signed by key {found}, not {expected}")]
+    WrongKey {
+        path: String,
+        expected: String,
+        found: String,
+    },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// Digest and signature of one artifact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactSignature {
+    /// Hex SHA-256 of the signed bytes
+    pub sha256: String,
+    /// Hex ed25519 signature of the signed bytes
+    pub signature: String,
+}
+
+/// Detached record of every artifact signed with one key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureManifest {
+    pub algorithm: String,
+    /// Hex ed25519 public key
+    pub public_key: String,
+    pub fingerprint: String,
+    /// Artifacts by path (or name, for the AST and reports)
+    pub artifacts: BTreeMap<String, ArtifactSignature>,
+}
+
+/// Holds the private key; everything it signs can be checked with the
+/// public key alone
+pub struct ArtifactSigner {
+    key: SigningKey,
+}
+
+impl ArtifactSigner {
+    /// A fresh random key
+    pub fn generate() -> Self {
+        Self {
+            key: SigningKey::generate(&mut rand_core::OsRng),
+        }
+    }
+
+    /// Key from its 32-byte seed, written as 64 hex digits
+    pub fn from_seed_hex(seed: &str) -> Result<Self, SigningError> {
+        let bytes = hex::decode(seed.trim()).map_err(|e| SigningError::InvalidKey(e.to_string()))?;
+        let seed: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| SigningError::InvalidKey("expected 32 bytes (64 hex digits)".to_string()))?;
+        Ok(Self {
+            key: SigningKey::from_bytes(&seed),
+        })
+    }
+
+    /// Read a key file written by `seed_hex`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SigningError> {
+        Self::from_seed_hex(&std::fs::read_to_string(path)?)
+    }
+
+    /// The private seed, for storing the key; keep it secret
+    pub fn seed_hex(&self) -> String {
+        hex::encode(self.key.to_bytes())
+    }
+
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key.verifying_key().to_bytes())
+    }
+
+    /// Short identifier of the public key: the first 8 bytes of its SHA-256
+    pub fn fingerprint(&self) -> String {
+        fingerprint(&self.key.verifying_key())
+    }
+
+    /// Sign raw bytes
+    pub fn sign(&self, bytes: &[u8]) -> ArtifactSignature {
+        ArtifactSignature {
+            sha256: hex::encode(Sha256::digest(bytes)),
+            signature: hex::encode(self.key.sign(bytes).to_bytes()),
+        }
+    }
+
+    /// Sign the canonical JSON of a structured artifact, such as a report
+    pub fn sign_json<T: Serialize>(&self, value: &T) -> Result<ArtifactSignature, SigningError> {
+        Ok(self.sign(&canonical_json(value)?))
+    }
+
+    pub fn sign_ast(&self, ast: &IntentAst) -> Result<ArtifactSignature, SigningError> {
+        self.sign_json(ast)
+    }
+
+    /// Sign a generated file, returning its contents with the signature
+    /// header prepended where the format has line comments
+    pub fn sign_file(&self, path: &str, contents: &str) -> (String, ArtifactSignature) {
+        let signature = self.sign(contents.as_bytes());
+        let signed = match comment_prefix(path) {
+            Some(prefix) => format!(
+                "{} {} fingerprint={} sig={}\n{}",
+                prefix,
+                HEADER_MARKER,
+                self.fingerprint(),
+                signature.signature,
+                contents
+            ),
+            None => contents.to_string(),
+        };
+        (signed, signature)
+    }
+
+    /// An empty manifest for this key
+    pub fn manifest(&self) -> SignatureManifest {
+        SignatureManifest {
+            algorithm: "ed25519".to_string(),
+            public_key: self.public_key_hex(),
+            fingerprint: self.fingerprint(),
+            artifacts: BTreeMap::new(),
+        }
+    }
+}
+
+impl SignatureManifest {
+    pub fn from_json(json: &str) -> Result<Self, SigningError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, SigningError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Check a file as read from disk, signature header and all
+    pub fn verify_file(&self, path: &str, contents: &str) -> Result<(), SigningError> {
+        let (header, body) = split_header(contents);
+        if let Some(found) = header.and_then(|h| h.split_whitespace().find_map(|f| f.strip_prefix("fingerprint="))) {
+            if found != self.fingerprint {
+                return Err(SigningError::WrongKey {
+                    path: path.to_string(),
+                    expected: self.fingerprint.clone(),
+                    found: found.to_string(),
+                });
+            }
+        }
+        self.verify_bytes(path, body.as_bytes())
+    }
+
+    /// Check a structured artifact listed under `name`
+    pub fn verify_json<T: Serialize>(&self, name: &str, value: &T) -> Result<(), SigningError> {
+        self.verify_bytes(name, &canonical_json(value)?)
+    }
+
+    fn verify_bytes(&self, name: &str, bytes: &[u8]) -> Result<(), SigningError> {
+        let entry = self.artifacts.get(name).ok_or_else(|| SigningError::Unlisted(name.to_string()))?;
+        match verify_signature(&self.public_key, bytes, entry)? {
+            true => Ok(()),
+            false => Err(SigningError::Tampered(name.to_string())),
+        }
+    }
+}
+
+/// Whether `signature` over `bytes` checks out against a hex public key
+pub fn verify_signature(public_key: &str, bytes: &[u8], signature: &ArtifactSignature) -> Result<bool, SigningError> {
+    let key: [u8; 32] = hex::decode(public_key)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| SigningError::InvalidKey("public key must be 32 bytes of hex".to_string()))?;
+    let key = VerifyingKey::from_bytes(&key).map_err(|e| SigningError::InvalidKey(e.to_string()))?;
+    let Some(signature) = hex::decode(&signature.signature).ok().and_then(|b| <[u8; 64]>::try_from(b).ok()) else {
+        return Ok(false);
+    };
+    Ok(key.verify(bytes, &ed25519_dalek::Signature::from_bytes(&signature)).is_ok())
+}
+
+/// Compact JSON with every object's keys sorted
+pub fn canonical_json<T: Serialize>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    fn sorted(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let entries: BTreeMap<String, serde_json::Value> =
+                    map.into_iter().map(|(k, v)| (k, sorted(v))).collect();
+                serde_json::Value::Object(entries.into_iter().collect())
+            }
+            serde_json::Value::Array(items) => serde_json::Value::Array(items.into_iter().map(sorted).collect()),
+            other => other,
+        }
+    }
+    serde_json::to_vec(&sorted(serde_json::to_value(value)?))
+}
+
+fn fingerprint(key: &VerifyingKey) -> String {
+    hex::encode(&Sha256::digest(key.to_bytes())[..8])
+}
+
+/// Line-comment syntax of a generated file, by extension
+fn comment_prefix(path: &str) -> Option<&'static str> {
+    let extension = Path::new(path).extension()?.to_str()?;
+    match extension {
+        "rs" | "ts" | "sol" | "zig" | "c" | "h" | "cue" | "cedar" | "cedarschema" | "wit" => Some("//"),
+        "py" | "ex" | "exs" | "rego" | "toml" | "yaml" | "yml" | "feature" => Some("#"),
+        "ada" | "ads" | "adb" | "sql" => Some("--"),
+        _ => None,
+    }
+}
+
+/// The signature header line, if present, and the signed body after it
+fn split_header(contents: &str) -> (Option<&str>, &str) {
+    match contents.split_once('\n') {
+        Some((first, body)) if first.contains(HEADER_MARKER) => (Some(first), body),
+        _ => (None, contents),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_files_and_ast_verify_until_modified() {
+        let signer = ArtifactSigner::generate();
+        let mut manifest = signer.manifest();
+
+        let (signed, signature) = signer.sign_file("validator.rs", "pub fn validate() -> bool { true }\n");
+        assert!(signed.starts_with(&format!("// {} fingerprint={}", HEADER_MARKER, signer.fingerprint())));
+        manifest.artifacts.insert("validator.rs".to_string(), signature);
+        let (schema, signature) = signer.sign_file("schema.json", "{}");
+        assert_eq!(schema, "{}");
+        manifest.artifacts.insert("schema.json".to_string(), signature);
+
+        let mut ast = IntentAst::new();
+        ast.add_requirement("Amount must be positive".to_string());
+        manifest.artifacts.insert("intent-ast".to_string(), signer.sign_ast(&ast).unwrap());
+
+        let manifest = SignatureManifest::from_json(&manifest.to_json().unwrap()).unwrap();
+        manifest.verify_file("validator.rs", &signed).unwrap();
+        manifest.verify_file("schema.json", &schema).unwrap();
+        manifest.verify_json("intent-ast", &ast).unwrap();
+
+        let edited = signed.replace("true", "false");
+        assert!(matches!(manifest.verify_file("validator.rs", &edited), Err(SigningError::Tampered(_))));
+        ast.requirements[0].content.push('!');
+        assert!(matches!(manifest.verify_json("intent-ast", &ast), Err(SigningError::Tampered(_))));
+        assert!(matches!(manifest.verify_file("other.rs", &signed), Err(SigningError::Unlisted(_))));
+
+        let other = ArtifactSigner::from_seed_hex(&ArtifactSigner::generate().seed_hex()).unwrap();
+        let (foreign, _) = other.sign_file("validator.rs", "pub fn validate() -> bool { true }\n");
+        assert!(matches!(manifest.verify_file("validator.rs", &foreign), Err(SigningError::WrongKey { .. })));
+    }
+
+    #[test]
+    fn test_canonical_json_sorts_keys() {
+        let value = serde_json::json!({"b": 1, "a": {"d": [2, {"f": 3, "e": 4}], "c": null}});
+        assert_eq!(
+            String::from_utf8(canonical_json(&value).unwrap()).unwrap(),
+            r#"{"a":{"c":null,"d":[2,{"e":4,"f":3}]},"b":1}"#
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! State Machines - lifecycle of a subject across action requirements
+//!
+//! Requirements about one subject ("User can register ...", "User can login
+//! if ...", "User can logout ...") together describe a lifecycle. A
+//! `StateMachine` makes it explicit: named states, an initial state and
+//! guarded transitions labelled by the action that fires them. The parser
+//! builds machines from requirements; the verifier checks that every state is
+//! reachable and that no reachable state can get stuck.
+
+use crate::CompoundConstraint;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
+
+/// One guarded edge of a state machine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MachineTransition {
+    pub from: String,
+    pub to: String,
+    /// Action that fires the transition (e.g. `login`)
+    pub action: String,
+    /// Data condition besides the source state; `None` means always enabled
+    pub guard: Option<CompoundConstraint>,
+}
+
+/// States and guarded transitions of one subject
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateMachine {
+    /// Subject the machine belongs to (e.g. `User`)
+    pub subject: String,
+    /// All states, initial state first
+    pub states: Vec<String>,
+    pub initial: String,
+    pub transitions: Vec<MachineTransition>,
+    /// States where stopping is intended (never reported as deadlocks)
+    #[serde(default)]
+    pub final_states: Vec<String>,
+}
+
+impl StateMachine {
+    pub fn new(subject: &str, initial: &str) -> Self {
+        Self {
+            subject: subject.to_string(),
+            states: vec![initial.to_string()],
+            initial: initial.to_string(),
+            transitions: Vec::new(),
+            final_states: Vec::new(),
+        }
+    }
+
+    /// Add a transition, registering unseen states in order of appearance
+    pub fn add_transition(&mut self, from: &str, to: &str, action: &str, guard: Option<CompoundConstraint>) {
+        for state in [from, to] {
+            if !self.states.iter().any(|s| s == state) {
+                self.states.push(state.to_string());
+            }
+        }
+        self.transitions.push(MachineTransition {
+            from: from.to_string(),
+            to: to.to_string(),
+            action: action.to_string(),
+            guard,
+        });
+    }
+
+    /// Transitions leaving `state`
+    pub fn outgoing<'a>(&'a self, state: &'a str) -> impl Iterator<Item = &'a MachineTransition> {
+        self.transitions.iter().filter(move |t| t.from == state)
+    }
+
+    /// States reachable from the initial state over transitions accepted by `enabled`
+    /// (the verifier passes a guard-satisfiability check; `|_| true` ignores guards)
+    pub fn reachable_states(&self, enabled: impl Fn(&MachineTransition) -> bool) -> BTreeSet<String> {
+        let mut reached = BTreeSet::from([self.initial.clone()]);
+        let mut queue = VecDeque::from([self.initial.as_str()]);
+
+        while let Some(state) = queue.pop_front() {
+            for transition in self.outgoing(state).filter(|t| enabled(t)) {
+                if reached.insert(transition.to.clone()) {
+                    queue.push_back(&transition.to);
+                }
+            }
+        }
+        reached
+    }
+
+    /// States that can never be entered
+    pub fn unreachable_states(&self, enabled: impl Fn(&MachineTransition) -> bool) -> Vec<String> {
+        let reached = self.reachable_states(enabled);
+        self.states.iter().filter(|s| !reached.contains(*s)).cloned().collect()
+    }
+
+    /// Distinct action names, in order of first appearance
+    pub fn actions(&self) -> Vec<&str> {
+        let mut actions: Vec<&str> = Vec::new();
+        for transition in &self.transitions {
+            if !actions.contains(&transition.action.as_str()) {
+                actions.push(&transition.action);
+            }
+        }
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reachability_over_transitions() {
+        let mut machine = StateMachine::new("User", "initial");
+        machine.add_transition("initial", "registered", "register", None);
+        machine.add_transition("registered", "logged_in", "login", None);
+        machine.add_transition("logged_in", "registered", "logout", None);
+        machine.add_transition("suspended", "registered", "reinstate", None);
+
+        assert_eq!(machine.states, vec!["initial", "registered", "logged_in", "suspended"]);
+        assert_eq!(machine.unreachable_states(|_| true), vec!["suspended".to_string()]);
+
+        // With `login` disabled, `logged_in` cannot be reached either
+        let unreachable = machine.unreachable_states(|t| t.action != "login");
+        assert_eq!(unreachable, vec!["logged_in".to_string(), "suspended".to_string()]);
+        assert_eq!(machine.actions(), vec!["register", "login", "logout", "reinstate"]);
+    }
+}
@@ -0,0 +1,143 @@
+//! Requirement Tags - labels for verifying or generating a subset of a spec
+//!
+//! A requirement carries any number of tags (`security`, `payments`, `mvp`).
+//! In intent files they are trailing hashtags, stripped before parsing:
+//!
+//! ```text
+//! The amount must be at most 10000 #payments #mvp
+//! ```
+//!
+//! Tags are lowercase letters, digits, `-`, `_` and `.`; `MVP` and `#mvp`
+//! name the same tag. A tag filter selects the requirements carrying any of
+//! its tags, and an empty filter selects every requirement.
+
+use crate::{IntentAst, Requirement};
+use std::collections::BTreeMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors raised while tagging requirements
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TagError {
+    #[error("no requirement with id {0}")]
+    UnknownRequirement(Uuid),
+
+    #[error("`{0}` is not a valid tag (letters, digits, `-`, `_` and `.` only)")]
+    InvalidTag(String),
+}
+
+/// The canonical form of a tag: without a leading `#`, lowercased
+pub fn normalize_tag(tag: &str) -> Result<String, TagError> {
+    let name = tag.trim().strip_prefix('#').unwrap_or(tag.trim()).to_lowercase();
+    let valid = name.chars().next().is_some_and(char::is_alphanumeric)
+        && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(TagError::InvalidTag(tag.to_string()));
+    }
+    Ok(name)
+}
+
+/// Split the trailing hashtags off a requirement line: `"x < 5 #a #b"` is
+/// `("x < 5", ["a", "b"])`. A `#` word followed by other text is not a tag.
+pub fn split_tags(line: &str) -> (&str, Vec<String>) {
+    let mut text = line.trim_end();
+    let mut tags = Vec::new();
+    while let Some((rest, word)) = text.rsplit_once(char::is_whitespace) {
+        match word.starts_with('#').then(|| normalize_tag(word)) {
+            Some(Ok(tag)) => tags.push(tag),
+            _ => break,
+        }
+        text = rest.trim_end();
+    }
+    tags.reverse();
+    let mut unique = Vec::new();
+    for tag in tags {
+        if !unique.contains(&tag) {
+            unique.push(tag);
+        }
+    }
+    (text, unique)
+}
+
+impl Requirement {
+    /// Whether the requirement carries any of `tags`; always true for no tags
+    pub fn matches_tags(&self, tags: &[String]) -> bool {
+        tags.is_empty() || tags.iter().any(|tag| self.tags.contains(tag))
+    }
+}
+
+impl IntentAst {
+    /// Add tags to a requirement
+    pub fn tag<S: AsRef<str>>(&mut self, id: Uuid, tags: &[S]) -> Result<&Requirement, TagError> {
+        let tags = tags.iter().map(|tag| normalize_tag(tag.as_ref())).collect::<Result<Vec<_>, _>>()?;
+        let requirement = self.requirement_mut(id)?;
+        requirement.tags.extend(tags);
+        Ok(requirement)
+    }
+
+    /// Remove tags from a requirement; tags it does not carry are ignored
+    pub fn untag<S: AsRef<str>>(&mut self, id: Uuid, tags: &[S]) -> Result<&Requirement, TagError> {
+        let tags = tags.iter().map(|tag| normalize_tag(tag.as_ref())).collect::<Result<Vec<_>, _>>()?;
+        let requirement = self.requirement_mut(id)?;
+        requirement.tags.retain(|tag| !tags.contains(tag));
+        Ok(requirement)
+    }
+
+    /// Requirements carrying any of `tags`, in source order; all of them for no tags
+    pub fn tagged<'a>(&'a self, tags: &'a [String]) -> impl Iterator<Item = &'a Requirement> {
+        self.requirements.iter().filter(move |r| r.matches_tags(tags))
+    }
+
+    /// Every tag in use, with how many requirements carry it
+    pub fn tag_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        for tag in self.requirements.iter().flat_map(|r| &r.tags) {
+            *counts.entry(tag.as_str()).or_default() += 1;
+        }
+        counts
+    }
+
+    fn requirement_mut(&mut self, id: Uuid) -> Result<&mut Requirement, TagError> {
+        self.requirements
+            .iter_mut()
+            .find(|r| r.id == id)
+            .ok_or(TagError::UnknownRequirement(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_and_filters() {
+        assert_eq!(
+            split_tags("Amount must be at most 100 #Payments #mvp #payments"),
+            ("Amount must be at most 100", vec!["payments".to_string(), "mvp".to_string()])
+        );
+        assert_eq!(split_tags("Order #5 must ship"), ("Order #5 must ship", vec![]));
+        assert_eq!(split_tags("Fee is 2% #"), ("Fee is 2% #", vec![]));
+        assert_eq!(normalize_tag("#pci-dss.v4"), Ok("pci-dss.v4".to_string()));
+        assert_eq!(normalize_tag("a b"), Err(TagError::InvalidTag("a b".to_string())));
+
+        let mut ast = IntentAst::new();
+        ast.add_requirement("Amount must be at most 100".to_string());
+        ast.add_requirement("Password length must be at least 12".to_string());
+        ast.add_requirement("Notes are free text".to_string());
+        let (amount, password) = (ast.requirements[0].id, ast.requirements[1].id);
+        ast.tag(amount, &["payments", "MVP"]).unwrap();
+        ast.tag(password, &["#security"]).unwrap();
+        assert!(matches!(ast.tag(password, &["no spaces"]), Err(TagError::InvalidTag(_))));
+        assert!(matches!(ast.tag(Uuid::new_v4(), &["x"]), Err(TagError::UnknownRequirement(_))));
+
+        let payments = ["payments".to_string()];
+        assert_eq!(ast.tagged(&payments).map(|r| r.id).collect::<Vec<_>>(), vec![amount]);
+        let either = ["security".to_string(), "mvp".to_string()];
+        assert_eq!(ast.tagged(&either).count(), 2);
+        assert_eq!(ast.tagged(&[]).count(), 3);
+        assert_eq!(ast.tag_counts(), BTreeMap::from([("mvp", 1), ("payments", 1), ("security", 1)]));
+
+        ast.untag(amount, &["mvp", "unknown"]).unwrap();
+        assert_eq!(ast.requirements[0].tags.iter().collect::<Vec<_>>(), vec!["payments"]);
+    }
+}
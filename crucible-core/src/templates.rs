@@ -0,0 +1,246 @@
+//! Requirement Templates - parameterized requirement patterns
+//!
+//! Compliance specs repeat the same sentence shapes hundreds of times. A
+//! template names the shape once, with `<<parameter>>` placeholders:
+//!
+//! ```text
+//! <<actor>> can <<action>> if <<field>> >= <<threshold>>
+//! ```
+//!
+//! and a requirements document instantiates it with one line per use:
+//!
+//! ```text
+//! @min_balance(actor = User, action = withdraw, field = balance, threshold = 100)
+//! ```
+//!
+//! `TemplateLibrary::expand` replaces those lines with the instantiated text
+//! before parsing and verification, so the rest of the pipeline only ever sees
+//! plain requirements.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+
+/// Errors raised while instantiating templates
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    #[error("line {line}: unknown template `{name}`")]
+    UnknownTemplate { name: String, line: usize },
+
+    #[error("template `{template}` is missing a binding for `{parameter}`")]
+    MissingParameter { template: String, parameter: String },
+
+    #[error("template `{template}` has no parameter `{parameter}`")]
+    UnknownParameter { template: String, parameter: String },
+
+    #[error("line {line}: malformed template invocation: {reason}")]
+    Malformed { line: usize, reason: String },
+}
+
+/// A named requirement pattern with `<<parameter>>` placeholders
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequirementTemplate {
+    pub name: String,
+    pub body: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl RequirementTemplate {
+    pub fn new(name: &str, body: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            body: body.to_string(),
+            description: None,
+        }
+    }
+
+    /// Placeholder names in order of first appearance
+    pub fn parameters(&self) -> Vec<&str> {
+        let mut parameters = Vec::new();
+        let mut rest = self.body.as_str();
+        while let Some(start) = rest.find("<<") {
+            let Some(len) = rest[start + 2..].find(">>") else {
+                break;
+            };
+            let name = rest[start + 2..start + 2 + len].trim();
+            if !parameters.contains(&name) {
+                parameters.push(name);
+            }
+            rest = &rest[start + 2 + len + 2..];
+        }
+        parameters
+    }
+
+    /// Substitute every placeholder; all parameters must be bound and no extra bindings given
+    pub fn instantiate(&self, bindings: &BTreeMap<String, String>) -> Result<String, TemplateError> {
+        let parameters = self.parameters();
+        if let Some(extra) = bindings.keys().find(|k| !parameters.contains(&k.as_str())) {
+            return Err(TemplateError::UnknownParameter {
+                template: self.name.clone(),
+                parameter: extra.clone(),
+            });
+        }
+
+        let mut text = self.body.clone();
+        for parameter in parameters {
+            let value = bindings.get(parameter).ok_or_else(|| TemplateError::MissingParameter {
+                template: self.name.clone(),
+                parameter: parameter.to_string(),
+            })?;
+            // Placeholders may be written with inner spacing: `<< actor >>`
+            text = text
+                .replace(&format!("<<{}>>", parameter), value)
+                .replace(&format!("<< {} >>", parameter), value);
+        }
+        Ok(text)
+    }
+}
+
+/// The project's template collection
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateLibrary {
+    pub templates: Vec<RequirementTemplate>,
+}
+
+impl TemplateLibrary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a library from its JSON form (`{"templates": [...]}`)
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Load the project template library from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(Self::from_json(&json)?)
+    }
+
+    /// Register a template, replacing any existing one of the same name
+    pub fn add(&mut self, template: RequirementTemplate) {
+        self.templates.retain(|t| t.name != template.name);
+        self.templates.push(template);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&RequirementTemplate> {
+        self.templates.iter().find(|t| t.name == name)
+    }
+
+    /// Replace every `@name(key = value, ...)` line of `text` with its instantiation
+    pub fn expand(&self, text: &str) -> Result<String, TemplateError> {
+        let mut out: Vec<String> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line_no = index + 1;
+            let Some(invocation) = line.trim().strip_prefix('@') else {
+                out.push(line.to_string());
+                continue;
+            };
+
+            let (name, bindings) = parse_invocation(invocation, line_no)?;
+            let template = self.get(name).ok_or_else(|| TemplateError::UnknownTemplate {
+                name: name.to_string(),
+                line: line_no,
+            })?;
+            out.push(template.instantiate(&bindings)?);
+        }
+
+        if text.ends_with('\n') {
+            out.push(String::new());
+        }
+        Ok(out.join("\n"))
+    }
+}
+
+/// `name(key = value, key = "quoted, value")` -> (name, bindings)
+fn parse_invocation(invocation: &str, line: usize) -> Result<(&str, BTreeMap<String, String>), TemplateError> {
+    let malformed = |reason: &str| TemplateError::Malformed {
+        line,
+        reason: reason.to_string(),
+    };
+
+    let open = invocation.find('(').ok_or_else(|| malformed("expected `(` after the template name"))?;
+    let args = invocation[open + 1..]
+        .strip_suffix(')')
+        .ok_or_else(|| malformed("expected a closing `)`"))?;
+    let name = invocation[..open].trim();
+
+    // Split on commas outside quotes; quoted values keep their quotes for the grammar
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for c in args.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            ',' if !in_quotes => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(malformed("unterminated string"));
+    }
+    parts.push(current);
+
+    let mut bindings = BTreeMap::new();
+    for part in parts.iter().filter(|p| !p.trim().is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| malformed("bindings are written `parameter = value`"))?;
+        bindings.insert(key.trim().to_string(), value.trim().to_string());
+    }
+    Ok((name, bindings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn library() -> TemplateLibrary {
+        let mut library = TemplateLibrary::new();
+        library.add(RequirementTemplate::new(
+            "min_balance",
+            "<<actor>> can <<action>> money if <<field>> >= <<threshold>>",
+        ));
+        library
+    }
+
+    #[test]
+    fn test_expand_multiple_instantiations() {
+        let text = "@min_balance(actor = User, action = withdraw, field = balance, threshold = 100)\n\
+                    System must validate input\n\
+                    @min_balance(actor = Admin, action = transfer, field = limit, threshold = 5000)\n";
+
+        assert_eq!(
+            library().expand(text).unwrap(),
+            "User can withdraw money if balance >= 100\n\
+             System must validate input\n\
+             Admin can transfer money if limit >= 5000\n"
+        );
+    }
+
+    #[test]
+    fn test_binding_errors() {
+        let library = library();
+        assert_eq!(
+            library.expand("@min_balance(actor = User, action = withdraw, field = balance)"),
+            Err(TemplateError::MissingParameter {
+                template: "min_balance".to_string(),
+                parameter: "threshold".to_string(),
+            })
+        );
+        assert!(matches!(
+            library.expand("ok\n@kyc(actor = User)"),
+            Err(TemplateError::UnknownTemplate { line: 2, .. })
+        ));
+        assert!(matches!(
+            library.expand("@min_balance(actor = \"User, Admin)"),
+            Err(TemplateError::Malformed { line: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+//! Stage Timings - where a request spent its time
+//!
+//! Each pipeline stage (parse, verify, codegen, ...) runs inside
+//! `StageTimings::time`, which opens a `tracing` span for it and records its
+//! wall-clock duration, so slow requests can be explained from the response
+//! itself as well as from the logs.
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Wall-clock duration of one stage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTiming {
+    pub stage: String,
+    pub micros: u64,
+}
+
+/// Stage durations in the order the stages ran
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StageTimings {
+    pub stages: Vec<StageTiming>,
+}
+
+impl StageTimings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` as `stage`, inside a span of that name
+    pub fn time<T>(&mut self, stage: &str, f: impl FnOnce() -> T) -> T {
+        let span = tracing::info_span!("stage", stage);
+        let _entered = span.enter();
+        let started = Instant::now();
+        let result = f();
+        self.record(stage, started.elapsed());
+        result
+    }
+
+    /// Record a stage timed by the caller, for work that spans an `.await`
+    pub fn record(&mut self, stage: &str, elapsed: Duration) {
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        tracing::debug!(stage, micros, "stage finished");
+        self.stages.push(StageTiming {
+            stage: stage.to_string(),
+            micros,
+        });
+    }
+
+    /// Time spent in `stage`, summed over its runs
+    pub fn get(&self, stage: &str) -> Option<u64> {
+        let mut runs = self.stages.iter().filter(|t| t.stage == stage).peekable();
+        runs.peek()?;
+        Some(runs.map(|t| t.micros).sum())
+    }
+
+    pub fn total_micros(&self) -> u64 {
+        self.stages.iter().map(|t| t.micros).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_timings() {
+        let mut timings = StageTimings::new();
+        assert_eq!(timings.time("parse", || 2 + 2), 4);
+        timings.time("verify", || std::thread::sleep(std::time::Duration::from_millis(2)));
+        timings.time("parse", || ());
+
+        assert_eq!(timings.stages.iter().map(|t| t.stage.as_str()).collect::<Vec<_>>(), ["parse", "verify", "parse"]);
+        assert!(timings.get("verify").unwrap() >= 2_000);
+        assert!(timings.total_micros() >= timings.get("verify").unwrap());
+        assert_eq!(timings.get("codegen"), None);
+        assert!(serde_json::to_string(&timings).unwrap().starts_with("[{\"stage\":\"parse\",\"micros\":"));
+    }
+}
@@ -0,0 +1,140 @@
+//! Tolerances - statistical requirements, monitored rather than proved
+//!
+//! Some requirements only have to hold most of the time:
+//!
+//! ```text
+//! latency < 100ms in 99% of cases
+//! System must process payment where error_rate <= 1% 99.9% of the time
+//! ```
+//!
+//! The trailing clause is a `Tolerance`, split off the line before parsing
+//! like a tag. It is metadata on the requirement: the solver never sees it,
+//! code generation turns it into an SLO assertion over observed samples, and
+//! reports list it as a monitored property.
+
+use crate::ConstraintOperator;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// `latency < 100ms in 99% of cases`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tolerance {
+    /// What is observed, e.g. `latency`
+    pub metric: String,
+    /// How each observation compares to the threshold
+    pub operator: ConstraintOperator,
+    /// Threshold as written, without its unit
+    pub threshold: String,
+    /// Unit written after the threshold, e.g. `ms`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Share of observations that must meet the threshold, in percent
+    pub percent: f64,
+}
+
+impl Tolerance {
+    /// `percent` as a fraction: 0.99 for 99%
+    pub fn quantile(&self) -> f64 {
+        self.percent / 100.0
+    }
+
+    /// Parse `<metric> <op> <threshold>[unit]` with the share of cases
+    fn parse(metric: &str, operator: &str, threshold: &str, percent: &str) -> Option<Self> {
+        let valid_metric = metric.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && metric.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '.'));
+        // Observations are numbers: only the ordering comparisons make sense
+        let operator = match operator {
+            "<" => ConstraintOperator::LessThan,
+            "<=" => ConstraintOperator::LessThanOrEqual,
+            ">" => ConstraintOperator::GreaterThan,
+            ">=" => ConstraintOperator::GreaterThanOrEqual,
+            _ => return None,
+        };
+        let split = threshold
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+            .unwrap_or(threshold.len());
+        let (value, unit) = threshold.split_at(split);
+        let percent: f64 = percent.strip_suffix('%')?.parse().ok()?;
+        if !valid_metric || value.parse::<f64>().is_err() || !(percent > 0.0 && percent <= 100.0) {
+            return None;
+        }
+        Some(Self {
+            metric: metric.to_string(),
+            operator,
+            threshold: value.to_string(),
+            unit: (!unit.is_empty()).then(|| unit.to_string()),
+            percent,
+        })
+    }
+}
+
+impl fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}{} in {}% of cases",
+            self.metric,
+            self.operator.symbol(),
+            self.threshold,
+            self.unit.as_deref().unwrap_or_default(),
+            self.percent
+        )
+    }
+}
+
+/// Split a trailing tolerance off a requirement line, with the `where` or
+/// `and` that introduced it: `"User can pay where latency < 100ms in 99% of
+/// cases"` is `("User can pay", Some(..))`. Lines without one come back whole.
+pub fn split_tolerance(line: &str) -> (&str, Option<Tolerance>) {
+    let text = line.trim_end();
+    let words: Vec<(usize, &str)> = text
+        .split_whitespace()
+        .map(|word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+        .collect();
+    let tail: Vec<&str> = words.iter().map(|(_, word)| *word).collect();
+    // `<metric> <op> <threshold>`, then `in 99% of cases` or `99% of the time`
+    let (start, tolerance) = match tail.as_slice() {
+        [.., metric, operator, threshold, "in", percent, "of", _]
+        | [.., metric, operator, threshold, percent, "of", "the", "time"] => {
+            (tail.len() - 7, Tolerance::parse(metric, operator, threshold, percent))
+        }
+        _ => return (line, None),
+    };
+    let Some(tolerance) = tolerance else {
+        return (line, None);
+    };
+    let start = match start.checked_sub(1).map(|i| tail[i]) {
+        Some("where" | "and") => start - 1,
+        _ => start,
+    };
+    let offset = words.get(start).map_or(0, |(offset, _)| *offset);
+    (text[..offset].trim_end(), Some(tolerance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_tolerance() {
+        let (text, tolerance) = split_tolerance("User can process payment where latency < 100ms in 99% of cases");
+        assert_eq!(text, "User can process payment");
+        let tolerance = tolerance.unwrap();
+        assert_eq!(tolerance.metric, "latency");
+        assert_eq!(tolerance.operator, ConstraintOperator::LessThan);
+        assert_eq!((tolerance.threshold.as_str(), tolerance.unit.as_deref()), ("100", Some("ms")));
+        assert_eq!(tolerance.quantile(), 0.99);
+        assert_eq!(tolerance.to_string(), "latency < 100ms in 99% of cases");
+
+        let (text, tolerance) = split_tolerance("error_rate <= 0.5 99.9% of the time");
+        assert_eq!(text, "");
+        assert_eq!(tolerance.unwrap().percent, 99.9);
+
+        let (text, tolerance) = split_tolerance("User can pay where amount > 0 and retries <= 3 in 95% of requests");
+        assert_eq!((text, tolerance.unwrap().unit), ("User can pay where amount > 0", None));
+
+        for line in ["User can pay where amount > 0", "latency < 100ms in 120% of cases", "p in 99% of cases"] {
+            assert_eq!(split_tolerance(line), (line, None));
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! State Transitions - pre/postconditions for state-changing actions
+//!
+//! Validators answer "may this happen?". Actions such as Withdraw or Transfer
+//! also change state, and the spec has to say how. A transition pairs the
+//! precondition with a postcondition over *primed* variables, where
+//! `balance'` is the value of `balance` after the action:
+//!
+//! ```text
+//! pre:  balance >= amount
+//! post: balance' == balance - amount
+//! ```
+//!
+//! Equalities `x' == <expr over unprimed variables>` are the updates; every
+//! other postcondition is a check on the new state. State variables that no
+//! update mentions keep their value (the frame condition).
+
+use crate::{ArithmeticOperator, CompoundConstraint, Constraint, ConstraintOperator};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Marks a post-state variable (`balance'`)
+pub const PRIME: char = '\'';
+
+/// Errors in the shape of a transition
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TransitionError {
+    #[error("`{0}'` is assigned more than once")]
+    DuplicateUpdate(String),
+
+    #[error("the update of `{variable}'` reads the post-state variable `{operand}`")]
+    PrimedOperand { variable: String, operand: String },
+
+    #[error("cannot parse update expression `{0}`")]
+    InvalidExpression(String),
+}
+
+/// Post-state name of a variable: `balance` -> `balance'`
+pub fn primed(name: &str) -> String {
+    format!("{}{}", name, PRIME)
+}
+
+/// Pre-state name of a primed variable, or `None` if it is not primed
+pub fn unprimed(name: &str) -> Option<&str> {
+    name.strip_suffix(PRIME)
+}
+
+/// `tree` over the post-state: every state variable it mentions, on either
+/// side or inside `a % b` and `a - b`, is primed, so the invariant
+/// `balance >= limit` reads `balance' >= limit'`
+pub fn prime_state(tree: &CompoundConstraint, state_variables: &[String]) -> CompoundConstraint {
+    let prime = |text: &str| {
+        text.split_whitespace()
+            .map(|token| {
+                if state_variables.iter().any(|v| v == token) {
+                    primed(token)
+                } else {
+                    token.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let all = |parts: &[CompoundConstraint]| parts.iter().map(|part| prime_state(part, state_variables)).collect();
+    match tree {
+        CompoundConstraint::Simple(constraint) => CompoundConstraint::Simple(Constraint {
+            left_variable: prime(&constraint.left_variable),
+            operator: constraint.operator,
+            right_value: prime(&constraint.right_value),
+        }),
+        CompoundConstraint::And(parts) => CompoundConstraint::And(all(parts)),
+        CompoundConstraint::Or(parts) => CompoundConstraint::Or(all(parts)),
+        CompoundConstraint::ExactlyOne(parts) => CompoundConstraint::ExactlyOne(all(parts)),
+        CompoundConstraint::AtMostOne(parts) => CompoundConstraint::AtMostOne(all(parts)),
+        CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(prime_state(inner, state_variables))),
+        CompoundConstraint::Implies(antecedent, consequent) => CompoundConstraint::Implies(
+            Box::new(prime_state(antecedent, state_variables)),
+            Box::new(prime_state(consequent, state_variables)),
+        ),
+        CompoundConstraint::Distinct(fields) => CompoundConstraint::Distinct(fields.iter().map(|f| prime(f)).collect()),
+        CompoundConstraint::Named(name) => CompoundConstraint::Named(name.clone()),
+    }
+}
+
+/// Right-hand side of an update: an operand or `operand op operand`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateExpr {
+    pub left: String,
+    pub operation: Option<(ArithmeticOperator, String)>,
+}
+
+impl UpdateExpr {
+    /// Parse `amount`, `42` or `balance - amount`
+    pub fn parse(text: &str) -> Result<Self, TransitionError> {
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let invalid = || TransitionError::InvalidExpression(text.to_string());
+        match tokens.as_slice() {
+            [operand] => Ok(Self {
+                left: operand.to_string(),
+                operation: None,
+            }),
+            [left, op, right] => {
+                let op = ArithmeticOperator::from_symbol(op).ok_or_else(invalid)?;
+                Ok(Self {
+                    left: left.to_string(),
+                    operation: Some((op, right.to_string())),
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Operands in source order
+    pub fn operands(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.left.as_str()).chain(self.operation.as_ref().map(|(_, r)| r.as_str()))
+    }
+}
+
+impl std::fmt::Display for UpdateExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.operation {
+            Some((op, right)) => write!(f, "{} {} {}", self.left, op.symbol(), right),
+            None => write!(f, "{}", self.left),
+        }
+    }
+}
+
+/// `variable' := expression`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateUpdate {
+    /// Unprimed name of the updated variable
+    pub variable: String,
+    pub expression: UpdateExpr,
+}
+
+/// A state-changing action with its pre- and postcondition
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTransition {
+    /// Action name, used for the generated update function
+    pub action: String,
+    pub pre: CompoundConstraint,
+    /// Conjunction over primed and unprimed variables
+    pub post: CompoundConstraint,
+}
+
+impl StateTransition {
+    pub fn new(action: &str, pre: CompoundConstraint, post: CompoundConstraint) -> Self {
+        Self {
+            action: action.to_string(),
+            pre,
+            post,
+        }
+    }
+
+    /// The `x' == expr` conjuncts of the postcondition, in order
+    pub fn updates(&self) -> Result<Vec<StateUpdate>, TransitionError> {
+        let mut updates: Vec<StateUpdate> = Vec::new();
+        for constraint in self.post_conjuncts() {
+            let Some(variable) = update_target(constraint) else {
+                continue;
+            };
+            if updates.iter().any(|u| u.variable == variable) {
+                return Err(TransitionError::DuplicateUpdate(variable.to_string()));
+            }
+
+            let expression = UpdateExpr::parse(&constraint.right_value)?;
+            if let Some(operand) = expression.operands().find(|o| unprimed(o).is_some()) {
+                return Err(TransitionError::PrimedOperand {
+                    variable: variable.to_string(),
+                    operand: operand.to_string(),
+                });
+            }
+            updates.push(StateUpdate {
+                variable: variable.to_string(),
+                expression,
+            });
+        }
+        Ok(updates)
+    }
+
+    /// Postconditions that are checks on the new state rather than updates
+    pub fn post_checks(&self) -> Option<CompoundConstraint> {
+        let checks: Vec<CompoundConstraint> = match &self.post {
+            CompoundConstraint::And(parts) => parts
+                .iter()
+                .filter(|p| !matches!(p, CompoundConstraint::Simple(c) if update_target(c).is_some()))
+                .cloned()
+                .collect(),
+            CompoundConstraint::Simple(c) if update_target(c).is_some() => Vec::new(),
+            other => vec![other.clone()],
+        };
+
+        match checks.len() {
+            0 => None,
+            1 => checks.into_iter().next(),
+            _ => Some(CompoundConstraint::And(checks)),
+        }
+    }
+
+    /// `x' == x` for every state variable the transition does not update
+    pub fn frame(&self, state_variables: &[String]) -> Result<Vec<Constraint>, TransitionError> {
+        let updated: Vec<String> = self.updates()?.into_iter().map(|u| u.variable).collect();
+        Ok(state_variables
+            .iter()
+            .filter(|v| !updated.contains(v))
+            .map(|v| Constraint {
+                left_variable: primed(v),
+                operator: ConstraintOperator::Equal,
+                right_value: v.clone(),
+            })
+            .collect())
+    }
+
+    fn post_conjuncts(&self) -> Vec<&Constraint> {
+        match &self.post {
+            CompoundConstraint::Simple(c) => vec![c],
+            CompoundConstraint::And(parts) => parts
+                .iter()
+                .filter_map(|p| match p {
+                    CompoundConstraint::Simple(c) => Some(c),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `x' == ...` assigns `x`
+fn update_target(constraint: &Constraint) -> Option<&str> {
+    if constraint.operator == ConstraintOperator::Equal {
+        unprimed(&constraint.left_variable)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    fn withdraw() -> StateTransition {
+        StateTransition::new(
+            "withdraw",
+            c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            CompoundConstraint::And(vec![
+                c("balance'", ConstraintOperator::Equal, "balance - amount"),
+                c("balance'", ConstraintOperator::GreaterThanOrEqual, "0"),
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_updates_checks_and_frame() {
+        let transition = withdraw();
+        let updates = transition.updates().unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].variable, "balance");
+        assert_eq!(
+            updates[0].expression.operation,
+            Some((ArithmeticOperator::Subtract, "amount".to_string()))
+        );
+
+        assert_eq!(
+            transition.post_checks(),
+            Some(c("balance'", ConstraintOperator::GreaterThanOrEqual, "0"))
+        );
+
+        let frame = transition.frame(&["balance".to_string(), "limit".to_string()]).unwrap();
+        assert_eq!(frame.len(), 1);
+        assert_eq!(frame[0].left_variable, "limit'");
+        assert_eq!(frame[0].right_value, "limit");
+
+        let state = ["balance".to_string(), "limit".to_string()];
+        let invariant = CompoundConstraint::And(vec![
+            c("balance", ConstraintOperator::LessThanOrEqual, "limit"),
+            c("balance % 10", ConstraintOperator::Equal, "amount - fee"),
+        ]);
+        assert_eq!(
+            prime_state(&invariant, &state).to_string(),
+            "(balance' <= limit' AND balance' % 10 == amount - fee)"
+        );
+    }
+
+    #[test]
+    fn test_malformed_updates() {
+        let mut transition = withdraw();
+        transition.post = CompoundConstraint::And(vec![
+            c("balance'", ConstraintOperator::Equal, "balance - amount"),
+            c("balance'", ConstraintOperator::Equal, "0"),
+        ]);
+        assert_eq!(transition.updates(), Err(TransitionError::DuplicateUpdate("balance".to_string())));
+
+        transition.post = c("fee'", ConstraintOperator::Equal, "balance' / 100");
+        assert!(matches!(transition.updates(), Err(TransitionError::PrimedOperand { .. })));
+    }
+}
@@ -0,0 +1,438 @@
+//! Constraint Validation - reject malformed trees before generation and verification
+//!
+//! A tree that names a field the Schema does not have, compares a string
+//! field with a number or nests without bound would still translate, into
+//! code that does not compile or a solver query that answers the wrong
+//! question. `CompoundConstraint::validate` finds these up front:
+//!
+//! ```
+//! use crucible_core::{CompoundConstraint, Constraint, ConstraintError, ConstraintOperator, DataType, Schema};
+//!
+//! let mut schema = Schema::new("TR-1".to_string());
+//! schema.add_field("name".to_string(), DataType::String, None);
+//! let compound = CompoundConstraint::Simple(Constraint {
+//!     left_variable: "name".to_string(),
+//!     operator: ConstraintOperator::GreaterThan,
+//!     right_value: "5".to_string(),
+//! });
+//! assert!(matches!(compound.validate(&schema), Err(ConstraintError::TypeMismatch { .. })));
+//! ```
+
+use crate::transition::unprimed;
+use crate::{BitWidth, Bitwise, Call, CompoundConstraint, Constraint, DataType, MathFunction, Schema, UpdateExpr};
+use thiserror::Error;
+
+/// Deepest nesting of AND/OR/NOT/IMPLIES and cardinality nodes accepted
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+/// Problems that make a constraint tree unfit for generation or verification
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConstraintError {
+    #[error("`{name}` in `{constraint}` is not a field of the Schema")]
+    UnknownVariable { name: String, constraint: String },
+
+    #[error("`{constraint}` compares {left} with {right}")]
+    TypeMismatch {
+        constraint: String,
+        left: &'static str,
+        right: &'static str,
+    },
+
+    #[error("`{name}` in `{constraint}` is not an integer field, so it has no bits")]
+    NotInteger { name: String, constraint: String },
+
+    #[error("`{value}` in `{constraint}` does not fit the {bits}-bit field `{field}`")]
+    OutOfWidth {
+        value: String,
+        field: String,
+        bits: u32,
+        constraint: String,
+    },
+
+    #[error("empty {0} group has nothing to combine")]
+    Empty(&'static str),
+
+    #[error("constraints nest more than {limit} levels deep")]
+    TooDeep { limit: usize },
+}
+
+/// What a value is, as far as comparisons are concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Number,
+    Text,
+    Bool,
+    List,
+}
+
+impl Kind {
+    fn of(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::String => Kind::Text,
+            DataType::Bool => Kind::Bool,
+            DataType::List(_) => Kind::List,
+            _ => Kind::Number,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Kind::Number => "a number",
+            Kind::Text => "a string",
+            Kind::Bool => "a boolean",
+            Kind::List => "a list",
+        }
+    }
+}
+
+impl CompoundConstraint {
+    /// Check the tree against `schema`: every field exists, both sides of a
+    /// comparison have the same kind (number, string or boolean), `Distinct`
+    /// is over one list or fields of one kind, no AND/OR, cardinality or
+    /// `Distinct` group is empty and nesting stays within
+    /// `MAX_NESTING_DEPTH`. Reports the first problem in reading order.
+    pub fn validate(&self, schema: &Schema) -> Result<(), ConstraintError> {
+        self.walk(Some(schema))
+    }
+
+    /// The checks of `validate` that need no Schema: empty groups and nesting depth
+    pub fn validate_structure(&self) -> Result<(), ConstraintError> {
+        self.walk(None)
+    }
+
+    /// Iterative, so that a tree nested far beyond the limit is rejected
+    /// rather than overflowing the stack
+    fn walk(&self, schema: Option<&Schema>) -> Result<(), ConstraintError> {
+        let mut pending = vec![(self, 1)];
+        while let Some((node, depth)) = pending.pop() {
+            if depth > MAX_NESTING_DEPTH {
+                return Err(ConstraintError::TooDeep { limit: MAX_NESTING_DEPTH });
+            }
+            let children: Vec<&CompoundConstraint> = match node {
+                CompoundConstraint::And(parts)
+                | CompoundConstraint::Or(parts)
+                | CompoundConstraint::ExactlyOne(parts)
+                | CompoundConstraint::AtMostOne(parts) => {
+                    if parts.is_empty() {
+                        return Err(ConstraintError::Empty(group_name(node)));
+                    }
+                    parts.iter().collect()
+                }
+                CompoundConstraint::Not(inner) => vec![inner],
+                CompoundConstraint::Implies(antecedent, consequent) => vec![antecedent, consequent],
+                CompoundConstraint::Simple(constraint) => {
+                    if let Some(schema) = schema {
+                        check_comparison(constraint, schema)?;
+                    }
+                    Vec::new()
+                }
+                CompoundConstraint::Distinct(fields) => {
+                    if fields.is_empty() {
+                        return Err(ConstraintError::Empty(group_name(node)));
+                    }
+                    if let Some(schema) = schema {
+                        check_distinct(node, fields, schema)?;
+                    }
+                    Vec::new()
+                }
+                // Checked where the definition itself is validated
+                CompoundConstraint::Named(_) => Vec::new(),
+            };
+            pending.extend(children.into_iter().rev().map(|child| (child, depth + 1)));
+        }
+        Ok(())
+    }
+}
+
+fn group_name(node: &CompoundConstraint) -> &'static str {
+    match node {
+        CompoundConstraint::And(_) => "AND",
+        CompoundConstraint::Or(_) => "OR",
+        CompoundConstraint::ExactlyOne(_) => "exactly-one",
+        CompoundConstraint::Distinct(_) => "distinct",
+        _ => "at-most-one",
+    }
+}
+
+/// One list field, or several fields of one kind other than a list
+fn check_distinct(node: &CompoundConstraint, fields: &[String], schema: &Schema) -> Result<(), ConstraintError> {
+    let mismatch = |left: Kind, right: Kind| ConstraintError::TypeMismatch {
+        constraint: node.to_string(),
+        left: left.describe(),
+        right: right.describe(),
+    };
+    let mut kinds = Vec::new();
+    for name in fields {
+        match schema.fields.get(unprimed(name).unwrap_or(name)) {
+            Some(data_type) => kinds.push(Kind::of(data_type)),
+            None => {
+                return Err(ConstraintError::UnknownVariable { name: name.clone(), constraint: node.to_string() })
+            }
+        }
+    }
+    match kinds.as_slice() {
+        [Kind::List] => Ok(()),
+        [only] => Err(mismatch(*only, Kind::List)),
+        [first, rest @ ..] => match rest.iter().find(|kind| *kind != first || *first == Kind::List) {
+            Some(other) => Err(mismatch(*first, *other)),
+            None => Ok(()),
+        },
+        [] => Ok(()),
+    }
+}
+
+fn check_comparison(constraint: &Constraint, schema: &Schema) -> Result<(), ConstraintError> {
+    // The condition on its own, and each branch as a comparison with the left side
+    if let Some(conditional) = constraint.conditional() {
+        check_comparison(&conditional.condition, schema)?;
+        for value in [conditional.then_value, conditional.else_value] {
+            check_comparison(&Constraint { right_value: value, ..constraint.clone() }, schema)?;
+        }
+        return Ok(());
+    }
+    let unknown = |name: &str| ConstraintError::UnknownVariable {
+        name: name.to_string(),
+        constraint: constraint.to_string(),
+    };
+    let mismatch = |left: Kind, right: Kind| ConstraintError::TypeMismatch {
+        constraint: constraint.to_string(),
+        left: left.describe(),
+        right: right.describe(),
+    };
+    let field = |name: &str| schema.fields.get(unprimed(name).unwrap_or(name)).map(Kind::of);
+    let number = |value: &str| value.parse::<f64>().is_ok();
+    // Every field a call reads must be numeric, but for the list an aggregate
+    // reduces, whose elements must be numeric unless they are only counted
+    let numeric_call = |call: &Call| {
+        let aggregates = call.aggregates();
+        for aggregate in &aggregates {
+            let list = &aggregate.args[0];
+            match schema.fields.get(unprimed(list).unwrap_or(list)) {
+                Some(DataType::List(element)) => match Kind::of(element) {
+                    Kind::Number => {}
+                    _ if aggregate.function == MathFunction::Count => {}
+                    kind => return Err(mismatch(kind, Kind::Number)),
+                },
+                Some(data_type) => return Err(mismatch(Kind::of(data_type), Kind::List)),
+                None => return Err(unknown(list)),
+            }
+        }
+        for operand in call.variables() {
+            if aggregates.iter().any(|aggregate| aggregate.args[0] == operand) {
+                continue;
+            }
+            match field(&operand) {
+                Some(Kind::Number) => {}
+                Some(kind) => return Err(mismatch(kind, Kind::Number)),
+                None => return Err(unknown(&operand)),
+            }
+        }
+        Ok(Kind::Number)
+    };
+
+    let left = match constraint.modulo() {
+        Some(m) => {
+            for operand in [&m.dividend, &m.divisor] {
+                match field(operand) {
+                    Some(Kind::Number) => {}
+                    Some(kind) => return Err(mismatch(kind, Kind::Number)),
+                    None if number(operand) => {}
+                    None => return Err(unknown(operand)),
+                }
+            }
+            Kind::Number
+        }
+        None => match (Call::parse(&constraint.left_variable), constraint.bitwise()) {
+            (Some(call), _) => numeric_call(&call)?,
+            (None, Some(bitwise)) => check_bitwise(constraint, &bitwise, schema).map(|()| Kind::Number)?,
+            (None, None) => field(&constraint.left_variable).ok_or_else(|| unknown(&constraint.left_variable))?,
+        },
+    };
+
+    let value = constraint.right_value.trim();
+    let right = if number(value) {
+        Kind::Number
+    } else if matches!(value, "true" | "false") {
+        Kind::Bool
+    } else if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        Kind::Text
+    } else if let Some(kind) = field(value) {
+        kind
+    } else if let Some(call) = Call::parse(value) {
+        numeric_call(&call)?
+    } else if let Some(expression) = UpdateExpr::parse(value).ok().filter(|e| e.operation.is_some()) {
+        for operand in expression.operands() {
+            match field(operand) {
+                Some(Kind::Number) => {}
+                Some(kind) => return Err(mismatch(Kind::Number, kind)),
+                None if number(operand) => {}
+                None => return Err(unknown(operand)),
+            }
+        }
+        Kind::Number
+    } else if left == Kind::Text {
+        // Bare words compared with a string field are enum-style literals
+        Kind::Text
+    } else if constraint.variables().iter().any(|v| v == value) {
+        return Err(unknown(value));
+    } else {
+        Kind::Text
+    };
+
+    if constraint.operator.is_membership() && left != Kind::Text {
+        return Err(mismatch(left, Kind::Text));
+    }
+    if left != right {
+        return Err(mismatch(left, right));
+    }
+    Ok(())
+}
+
+/// Both operands of a mask are integer fields or literals of the operand's
+/// width, and a shift moves by fewer bits than the width
+fn check_bitwise(constraint: &Constraint, bitwise: &Bitwise, schema: &Schema) -> Result<(), ConstraintError> {
+    let width = |name: &str| match schema.fields.get(unprimed(name).unwrap_or(name)) {
+        Some(data_type) => BitWidth::of(data_type).ok_or_else(|| ConstraintError::NotInteger {
+            name: name.to_string(),
+            constraint: constraint.to_string(),
+        }),
+        None => Err(ConstraintError::UnknownVariable { name: name.to_string(), constraint: constraint.to_string() }),
+    };
+    let operand = width(&bitwise.operand)?;
+    let fits = match bitwise.mask_value() {
+        Some(shift) if bitwise.operator.is_shift() => (0..operand.bits as i128).contains(&shift),
+        Some(mask) => operand.contains(mask),
+        None => {
+            let mask = width(&bitwise.mask)?;
+            operand.contains(mask.min()) && operand.contains(mask.max())
+        }
+    };
+    match fits {
+        true => Ok(()),
+        false => Err(ConstraintError::OutOfWidth {
+            value: bitwise.mask.clone(),
+            field: bitwise.operand.clone(),
+            bits: operand.bits,
+            constraint: constraint.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstraintOperator;
+
+    fn simple(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("balance".to_string(), DataType::Uint64, None);
+        schema.add_field("amount".to_string(), DataType::Uint64, None);
+        schema.add_field("email".to_string(), DataType::String, None);
+        schema.add_field("active".to_string(), DataType::Bool, None);
+        schema.add_field("items".to_string(), DataType::List(Box::new(DataType::Uint64)), None);
+        schema.add_field("tags".to_string(), DataType::List(Box::new(DataType::String)), None);
+        schema.add_field("flags".to_string(), DataType::Uint32, None);
+
+        let valid = CompoundConstraint::And(vec![
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount + 10"),
+            simple("balance % 10", ConstraintOperator::Equal, "0"),
+            simple("balance'", ConstraintOperator::Equal, "balance - amount"),
+            simple("email", ConstraintOperator::Contains, "\"@\""),
+            simple("email", ConstraintOperator::NotEqual, "admin"),
+            simple("active", ConstraintOperator::Equal, "true"),
+            simple("abs(balance - amount)", ConstraintOperator::LessThanOrEqual, "max(10, amount * 2)"),
+            simple("amount", ConstraintOperator::LessThanOrEqual, "balance if active else 0 if email == \"x\" else 5"),
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "sum(items)"),
+            simple("count(tags)", ConstraintOperator::LessThanOrEqual, "max(average(items), 3)"),
+            simple("flags & 0xFFFFFFFF", ConstraintOperator::Equal, "2"),
+            simple("balance ^ flags", ConstraintOperator::NotEqual, "0"),
+        ]);
+        assert_eq!(valid.validate(&schema), Ok(()));
+
+        let error = |compound: CompoundConstraint| compound.validate(&schema).unwrap_err();
+        assert_eq!(
+            error(simple("fee", ConstraintOperator::LessThan, "10")),
+            ConstraintError::UnknownVariable { name: "fee".to_string(), constraint: "fee < 10".to_string() }
+        );
+        assert!(matches!(
+            error(simple("balance", ConstraintOperator::LessThan, "fee")),
+            ConstraintError::UnknownVariable { name, .. } if name == "fee"
+        ));
+        assert_eq!(
+            error(simple("email", ConstraintOperator::GreaterThan, "5")).to_string(),
+            "`email > 5` compares a string with a number"
+        );
+        assert!(matches!(
+            error(simple("balance", ConstraintOperator::Contains, "\"1\"")),
+            ConstraintError::TypeMismatch { left: "a number", right: "a string", .. }
+        ));
+        assert!(matches!(error(simple("active", ConstraintOperator::Equal, "1")), ConstraintError::TypeMismatch { .. }));
+        assert!(matches!(
+            error(simple("balance", ConstraintOperator::Equal, "min(amount, email)")),
+            ConstraintError::TypeMismatch { left: "a string", right: "a number", .. }
+        ));
+        assert!(matches!(
+            error(simple("max(fee, 1)", ConstraintOperator::Equal, "amount")),
+            ConstraintError::UnknownVariable { name, .. } if name == "fee"
+        ));
+        assert!(matches!(
+            error(simple("amount", ConstraintOperator::Equal, "0 if active else \"none\"")),
+            ConstraintError::TypeMismatch { left: "a number", right: "a string", .. }
+        ));
+        assert!(matches!(
+            error(simple("amount", ConstraintOperator::Equal, "0 if member else 5")),
+            ConstraintError::UnknownVariable { name, .. } if name == "member"
+        ));
+        assert!(matches!(
+            error(simple("sum(tags)", ConstraintOperator::GreaterThan, "0")),
+            ConstraintError::TypeMismatch { left: "a string", right: "a number", .. }
+        ));
+        assert!(matches!(
+            error(simple("amount", ConstraintOperator::Equal, "count(balance)")),
+            ConstraintError::TypeMismatch { left: "a number", right: "a list", .. }
+        ));
+        assert!(matches!(
+            error(simple("email & 1", ConstraintOperator::Equal, "0")),
+            ConstraintError::NotInteger { name, .. } if name == "email"
+        ));
+        assert_eq!(
+            error(simple("flags << 32", ConstraintOperator::Equal, "0")).to_string(),
+            "`32` in `flags << 32 == 0` does not fit the 32-bit field `flags`"
+        );
+        assert!(matches!(
+            error(simple("flags | balance", ConstraintOperator::Equal, "0")),
+            ConstraintError::OutOfWidth { .. }
+        ));
+        let compared = error(simple("items", ConstraintOperator::GreaterThan, "3"));
+        assert!(matches!(compared, ConstraintError::TypeMismatch { .. }));
+        assert_eq!(error(CompoundConstraint::Or(Vec::new())), ConstraintError::Empty("OR"));
+
+        let distinct = |fields: &[&str]| CompoundConstraint::Distinct(fields.iter().map(|f| f.to_string()).collect());
+        assert_eq!(distinct(&["balance", "amount"]).validate(&schema), Ok(()));
+        assert_eq!(distinct(&["tags"]).validate(&schema), Ok(()));
+        assert_eq!(
+            error(distinct(&["email", "amount"])).to_string(),
+            "`distinct(email, amount)` compares a string with a number"
+        );
+        assert!(matches!(error(distinct(&["amount"])), ConstraintError::TypeMismatch { right: "a list", .. }));
+        assert!(matches!(error(distinct(&["items", "tags"])), ConstraintError::TypeMismatch { left: "a list", .. }));
+        assert_eq!(error(distinct(&[])), ConstraintError::Empty("distinct"));
+
+        let mut deep = simple("balance", ConstraintOperator::GreaterThan, "0");
+        for _ in 0..MAX_NESTING_DEPTH {
+            deep = CompoundConstraint::Not(Box::new(deep));
+        }
+        assert_eq!(deep.validate_structure(), Err(ConstraintError::TooDeep { limit: MAX_NESTING_DEPTH }));
+        let CompoundConstraint::Not(within) = deep else { unreachable!() };
+        assert_eq!(within.validate(&schema), Ok(()));
+    }
+}
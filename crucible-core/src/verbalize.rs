@@ -0,0 +1,644 @@
+//! Verbalizer - constraint trees back to controlled natural language
+//!
+//! The round trip of the parser: a `CompoundConstraint` is rendered as the
+//! sentence an author would have written, so review screens can show what the
+//! machine understood ("the balance is at least the amount and the amount is
+//! greater than zero") next to what the author typed. `read` goes the other
+//! way, so tests can check that nothing is lost in the rendering.
+//! `with_locale` renders the sentences from another language's catalogue.
+
+use crate::{
+    Between, Bitwise, BitwiseOperator, Call, CompoundConstraint, Conditional, Constraint, ConstraintOperator, DataType,
+    Locale, MathFunction, Modulo, Schema,
+};
+
+/// Renders constraint trees as controlled natural language, English unless
+/// another locale is chosen
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Verbalizer<'a> {
+    schema: Option<&'a Schema>,
+    locale: Locale,
+}
+
+impl<'a> Verbalizer<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use field types to phrase booleans and strings naturally
+    pub fn with_schema(schema: &'a Schema) -> Self {
+        Self {
+            schema: Some(schema),
+            ..Self::default()
+        }
+    }
+
+    /// Render sentences from the `locale` message catalogue
+    pub fn with_locale(self, locale: Locale) -> Self {
+        Self { locale, ..self }
+    }
+
+    /// "The user may withdraw only if <conditions>."
+    pub fn requirement(&self, subject: &str, modal_verb: &str, action: &str, condition: &CompoundConstraint) -> String {
+        let sentence = self.text(
+            "verbalize-requirement",
+            &[
+                ("subject", &self.noun_phrase(subject)),
+                ("modal", modal_verb),
+                ("action", action),
+                ("condition", &self.compound(condition)),
+            ],
+        );
+        let mut chars = sentence.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => sentence,
+        }
+    }
+
+    /// Render a constraint tree as one clause
+    pub fn compound(&self, compound: &CompoundConstraint) -> String {
+        match compound {
+            CompoundConstraint::Simple(c) => self.constraint(c),
+            CompoundConstraint::Named(name) => name.replace('_', " "),
+            CompoundConstraint::And(parts) => match compound.as_between() {
+                Some(range) => self.between(&range),
+                None => self.list(parts, "and"),
+            },
+            CompoundConstraint::Or(parts) => self.list(parts, "or"),
+            CompoundConstraint::Not(inner) => match inner.as_ref() {
+                CompoundConstraint::Simple(c) => self.constraint(&negate(c)),
+                CompoundConstraint::Not(c) => self.compound(c),
+                other => self.text("verbalize-not", &[("clause", &self.compound(other))]),
+            },
+            CompoundConstraint::Implies(antecedent, consequent) => self.text(
+                "verbalize-implies",
+                &[("antecedent", &self.compound(antecedent)), ("consequent", &self.compound(consequent))],
+            ),
+            CompoundConstraint::ExactlyOne(parts) => {
+                self.text("verbalize-exactly-one", &[("clauses", &self.list(parts, "or"))])
+            }
+            CompoundConstraint::AtMostOne(parts) => {
+                self.text("verbalize-at-most-one", &[("clauses", &self.list(parts, "or"))])
+            }
+            CompoundConstraint::Distinct(fields) => match fields.as_slice() {
+                [list] => self.text("verbalize-unique", &[("list", &self.noun_phrase(list))]),
+                [init @ .., last] => {
+                    let init: Vec<String> = init.iter().map(|field| self.noun_phrase(field)).collect();
+                    let and = self.text("verbalize-and", &[]);
+                    let values = format!("{} {} {}", init.join(", "), and, self.noun_phrase(last));
+                    self.text("verbalize-distinct", &[("values", &values)])
+                }
+                [] => String::new(),
+            },
+        }
+    }
+
+    /// Render `left op right`, e.g. "the balance is at least the amount"
+    pub fn constraint(&self, constraint: &Constraint) -> String {
+        let remainder = |m: &Modulo| {
+            let (dividend, divisor) = (self.noun_phrase(&m.dividend), self.value_phrase(&m.divisor));
+            self.text("verbalize-remainder", &[("dividend", &dividend), ("divisor", &divisor)])
+        };
+        if let Some(bitwise) = constraint.bitwise() {
+            // `flags & 0x8 != 0` reads as "bit 3 of the flags is set"
+            let single = bitwise.mask_value().filter(|mask| *mask > 0 && mask.count_ones() == 1);
+            let state = match constraint.operator {
+                ConstraintOperator::NotEqual => Some("set"),
+                ConstraintOperator::Equal => Some("clear"),
+                _ => None,
+            };
+            if let (BitwiseOperator::And, Some(mask), Some(state), "0") =
+                (bitwise.operator, single, state, constraint.right_value.as_str())
+            {
+                let (bit, subject) = (mask.trailing_zeros().to_string(), self.noun_phrase(&bitwise.operand));
+                return self.text("verbalize-bit", &[("bit", &bit), ("subject", &subject), ("state", state)]);
+            }
+        }
+        let subject = match constraint.modulo() {
+            Some(m)
+                if constraint.right_value == "0"
+                    && matches!(constraint.operator, ConstraintOperator::Equal | ConstraintOperator::NotEqual) =>
+            {
+                let id = match constraint.operator {
+                    ConstraintOperator::Equal => "verbalize-multiple",
+                    _ => "verbalize-not-multiple",
+                };
+                let (subject, divisor) = (self.noun_phrase(&m.dividend), self.value_phrase(&m.divisor));
+                return self.text(id, &[("subject", &subject), ("divisor", &divisor)]);
+            }
+            Some(m) => remainder(&m),
+            None => match (Call::parse(&constraint.left_variable), constraint.bitwise()) {
+                (Some(call), _) => self.call_phrase(&call),
+                (None, Some(bitwise)) => self.bitwise_phrase(&bitwise),
+                (None, None) => self.noun_phrase(&constraint.left_variable),
+            },
+        };
+        let data_type = self.schema.map(|s| s.get_type(&constraint.left_variable));
+
+        // `verified == true` reads as "the verified flag is set"
+        if data_type == Some(DataType::Bool) || matches!(constraint.right_value.as_str(), "true" | "false") {
+            let positive = (constraint.right_value == "true") == (constraint.operator == ConstraintOperator::Equal);
+            if matches!(constraint.operator, ConstraintOperator::Equal | ConstraintOperator::NotEqual) {
+                let state = if positive { "set" } else { "unset" };
+                let flag = constraint.left_variable.replace('_', " ");
+                return self.text("verbalize-flag", &[("flag", &flag), ("state", state)]);
+            }
+        }
+
+        let id = match constraint.operator {
+            ConstraintOperator::GreaterThanOrEqual => "verbalize-at-least",
+            ConstraintOperator::LessThanOrEqual => "verbalize-at-most",
+            ConstraintOperator::GreaterThan => "verbalize-greater",
+            ConstraintOperator::LessThan => "verbalize-less",
+            ConstraintOperator::Equal => "verbalize-equal",
+            ConstraintOperator::NotEqual => "verbalize-not-equal",
+            ConstraintOperator::Contains => "verbalize-contains",
+            ConstraintOperator::DoesNotContain => "verbalize-not-contains",
+        };
+        self.text(id, &[("subject", &subject), ("value", &self.value_phrase(&constraint.right_value))])
+    }
+
+    /// "the amount is between 1 and 100", "the score is strictly between zero and 10"
+    pub fn between(&self, range: &Between) -> String {
+        let id = if range.inclusive { "verbalize-between" } else { "verbalize-strictly-between" };
+        self.text(
+            id,
+            &[
+                ("subject", &self.noun_phrase(&range.variable)),
+                ("low", &self.value_phrase(&range.low)),
+                ("high", &self.value_phrase(&range.high)),
+            ],
+        )
+    }
+
+    /// Read a clause rendered by `compound` back into a constraint tree.
+    ///
+    /// Covers comparisons, flags, ranges, multiples and remainders, flat
+    /// `and`/`or` lists, implications, cardinality, uniqueness and "it is not
+    /// the case that". `None` for nested "both"/"either" groups and for anything the
+    /// verbalizer does not produce. Names come back lowercase with `_`
+    /// between the words, so `daily_total` survives but `dailyTotal` does not.
+    /// Only English clauses are read, whatever the locale.
+    pub fn read(&self, clause: &str) -> Option<CompoundConstraint> {
+        let tokens = tokenize(clause.trim().trim_end_matches('.'));
+        let words: Vec<&str> = tokens.iter().map(String::as_str).collect();
+        read_clause(&words)
+    }
+
+    /// "a and b", "a, b and c"; nested groups get "both"/"either" to keep grouping visible
+    fn list(&self, parts: &[CompoundConstraint], conjunction: &str) -> String {
+        let rendered: Vec<String> = parts
+            .iter()
+            .map(|part| match part {
+                CompoundConstraint::And(inner) if conjunction != "and" && part.as_between().is_none() => {
+                    self.text("verbalize-both", &[("clause", &self.list(inner, "and"))])
+                }
+                CompoundConstraint::Or(inner) if conjunction != "or" => {
+                    self.text("verbalize-either", &[("clause", &self.list(inner, "or"))])
+                }
+                other => self.compound(other),
+            })
+            .collect();
+
+        match rendered.as_slice() {
+            [] => String::new(),
+            [only] => only.clone(),
+            [init @ .., last] => {
+                let conjunction = self.text(&format!("verbalize-{}", conjunction), &[]);
+                format!("{} {} {}", init.join(", "), conjunction, last)
+            }
+        }
+    }
+
+    /// "account_balance" -> "the account balance"
+    fn noun_phrase(&self, variable: &str) -> String {
+        self.text("verbalize-field", &[("name", &variable.replace('_', " ").to_lowercase())])
+    }
+
+    /// "the mode masked with 0x0F", "the word shifted right by 4 bits"
+    fn bitwise_phrase(&self, bitwise: &Bitwise) -> String {
+        let operator = match bitwise.operator {
+            BitwiseOperator::And => "and",
+            BitwiseOperator::Or => "or",
+            BitwiseOperator::Xor => "xor",
+            BitwiseOperator::ShiftLeft => "left",
+            BitwiseOperator::ShiftRight => "right",
+        };
+        let mask = match bitwise.mask_value() {
+            Some(_) => bitwise.mask.clone(),
+            None => self.noun_phrase(&bitwise.mask),
+        };
+        let operand = self.noun_phrase(&bitwise.operand);
+        self.text("verbalize-bitwise", &[("operand", &operand), ("operator", operator), ("mask", &mask)])
+    }
+
+    /// Numbers and quoted strings stay literal, variables become noun phrases
+    fn value_phrase(&self, value: &str) -> String {
+        if let Some(conditional) = Conditional::parse(value) {
+            return self.conditional_phrase(&conditional);
+        }
+        match value {
+            "0" => self.text("verbalize-zero", &[]),
+            v if v.parse::<f64>().is_ok() || v.starts_with('"') => v.to_string(),
+            v => match Call::parse(v) {
+                Some(call) => self.call_phrase(&call),
+                None => self.noun_phrase(v),
+            },
+        }
+    }
+
+    /// "zero if the member flag is set, otherwise 5"; a nested conditional
+    /// continues the chain of "otherwise" clauses
+    fn conditional_phrase(&self, conditional: &Conditional) -> String {
+        self.text(
+            "verbalize-conditional",
+            &[
+                ("then", &self.value_phrase(&conditional.then_value)),
+                ("condition", &self.constraint(&conditional.condition)),
+                ("otherwise", &self.value_phrase(&conditional.else_value)),
+            ],
+        )
+    }
+
+    /// "the greater of the minimum fee and the fee floor", "the absolute value of the delta",
+    /// "the sum of the line amounts"
+    fn call_phrase(&self, call: &Call) -> String {
+        let values: Vec<String> = call.args.iter().map(|arg| self.value_phrase(arg)).collect();
+        match values.as_slice() {
+            [value] if call.function == MathFunction::Abs || call.function.is_aggregate() => {
+                self.text(&format!("verbalize-{}", call.function.name()), &[("value", value)])
+            }
+            [init @ .., last] => {
+                let values = format!("{} {} {}", init.join(", "), self.text("verbalize-and", &[]), last);
+                self.text(&format!("verbalize-{}", call.function.name()), &[("values", &values)])
+            }
+            [] => String::new(),
+        }
+    }
+
+    /// A verbalizer message; every one is in the English catalogue, which
+    /// the other locales fall back to
+    fn text(&self, id: &str, args: &[(&str, &str)]) -> String {
+        self.locale.message(id, args).unwrap_or_default()
+    }
+}
+
+fn negate(constraint: &Constraint) -> Constraint {
+    let operator = match constraint.operator {
+        ConstraintOperator::GreaterThanOrEqual => ConstraintOperator::LessThan,
+        ConstraintOperator::LessThanOrEqual => ConstraintOperator::GreaterThan,
+        ConstraintOperator::GreaterThan => ConstraintOperator::LessThanOrEqual,
+        ConstraintOperator::LessThan => ConstraintOperator::GreaterThanOrEqual,
+        ConstraintOperator::Equal => ConstraintOperator::NotEqual,
+        ConstraintOperator::NotEqual => ConstraintOperator::Equal,
+        ConstraintOperator::Contains => ConstraintOperator::DoesNotContain,
+        ConstraintOperator::DoesNotContain => ConstraintOperator::Contains,
+    };
+    Constraint {
+        left_variable: constraint.left_variable.clone(),
+        operator,
+        right_value: constraint.right_value.clone(),
+    }
+}
+
+/// Words with quoted strings kept whole and commas as separate tokens
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in text.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                current.push(c);
+            }
+            ',' if !quoted => {
+                tokens.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+                tokens.push(",".to_string());
+            }
+            c if c.is_whitespace() && !quoted => {
+                tokens.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+            }
+            c => current.push(c),
+        }
+    }
+    tokens.extend((!current.is_empty()).then_some(current));
+    tokens
+}
+
+fn read_clause(words: &[&str]) -> Option<CompoundConstraint> {
+    match words {
+        ["if", rest @ ..] => {
+            let split = rest.windows(2).position(|pair| pair == [",", "then"])?;
+            Some(CompoundConstraint::implies(read_clause(&rest[..split])?, read_clause(&rest[split + 2..])?))
+        }
+        ["it", "is", "not", "the", "case", "that", rest @ ..] => {
+            Some(CompoundConstraint::Not(Box::new(read_clause(rest)?)))
+        }
+        ["exactly", "one", "of", rest @ ..] => Some(CompoundConstraint::ExactlyOne(read_list(rest, "or")?)),
+        ["at", "most", "one", "of", rest @ ..] => Some(CompoundConstraint::AtMostOne(read_list(rest, "or")?)),
+        ["the", list @ .., "are", "unique"] if !list.is_empty() => {
+            Some(CompoundConstraint::Distinct(vec![list.join("_")]))
+        }
+        [fields @ .., "are", "all", "different"] => {
+            let (Some("and"), segments) = split_list(fields)? else { return None };
+            let fields = segments.iter().map(|segment| match segment.as_slice() {
+                ["the", name @ ..] if !name.is_empty() => Some(name.join("_")),
+                _ => None,
+            });
+            Some(CompoundConstraint::Distinct(fields.collect::<Option<Vec<_>>>()?))
+        }
+        _ => {
+            let (conjunction, segments) = split_list(words)?;
+            match conjunction {
+                None => read_comparison(segments.first()?),
+                Some("and") => Some(CompoundConstraint::And(read_list(words, "and")?)),
+                Some(_) => Some(CompoundConstraint::Or(read_list(words, "or")?)),
+            }
+        }
+    }
+}
+
+/// The items of "a, b and c"; every separator must be `conjunction` or a comma
+fn read_list(words: &[&str], conjunction: &str) -> Option<Vec<CompoundConstraint>> {
+    let (found, segments) = split_list(words)?;
+    if found.is_some_and(|found| found != conjunction) {
+        return None;
+    }
+    segments.iter().map(|segment| read_comparison(segment)).collect()
+}
+
+/// Split at commas and `and`/`or`, leaving the `and` of "between 1 and 100"
+/// inside its item. `None` when both conjunctions appear at the top level
+fn split_list<'w>(words: &[&'w str]) -> Option<(Option<&'w str>, Vec<Vec<&'w str>>)> {
+    let mut conjunction = None;
+    let mut segments = vec![Vec::new()];
+    let mut open_range = false;
+    for &word in words {
+        match word {
+            "between" => {
+                open_range = true;
+                segments.last_mut()?.push(word);
+            }
+            "and" if open_range => {
+                open_range = false;
+                segments.last_mut()?.push(word);
+            }
+            "and" | "or" => {
+                if conjunction.is_some_and(|c| c != word) {
+                    return None;
+                }
+                conjunction = Some(word);
+                segments.push(Vec::new());
+            }
+            "," => segments.push(Vec::new()),
+            _ => segments.last_mut()?.push(word),
+        }
+    }
+    segments.retain(|segment| !segment.is_empty());
+    Some((conjunction, segments))
+}
+
+/// Verb phrases after the subject, longest first
+const PHRASES: &[(&[&str], Option<ConstraintOperator>)] = &[
+    (&["is", "not", "a", "multiple", "of"], Some(ConstraintOperator::NotEqual)),
+    (&["is", "a", "multiple", "of"], Some(ConstraintOperator::Equal)),
+    (&["is", "strictly", "between"], None),
+    (&["is", "between"], None),
+    (&["is", "at", "least"], Some(ConstraintOperator::GreaterThanOrEqual)),
+    (&["is", "at", "most"], Some(ConstraintOperator::LessThanOrEqual)),
+    (&["is", "greater", "than"], Some(ConstraintOperator::GreaterThan)),
+    (&["is", "less", "than"], Some(ConstraintOperator::LessThan)),
+    (&["does", "not", "contain"], Some(ConstraintOperator::DoesNotContain)),
+    (&["is", "not"], Some(ConstraintOperator::NotEqual)),
+    (&["contains"], Some(ConstraintOperator::Contains)),
+    (&["is"], Some(ConstraintOperator::Equal)),
+];
+
+/// One comparison, flag, range or multiple
+fn read_comparison(words: &[&str]) -> Option<CompoundConstraint> {
+    let flag = |name: &[&str], value: &str| {
+        Some(CompoundConstraint::Simple(Constraint {
+            left_variable: name.join("_"),
+            operator: ConstraintOperator::Equal,
+            right_value: value.to_string(),
+        }))
+    };
+    match words {
+        ["the", name @ .., "flag", "is", "set"] if !name.is_empty() => return flag(name, "true"),
+        ["the", name @ .., "flag", "is", "not", "set"] if !name.is_empty() => return flag(name, "false"),
+        _ => {}
+    }
+
+    let (at, phrase, operator) = (1..words.len()).find_map(|at| {
+        PHRASES
+            .iter()
+            .find(|(phrase, _)| words[at..].starts_with(phrase))
+            .map(|(phrase, operator)| (at, phrase.len(), *operator))
+    })?;
+    let (subject, object) = (&words[..at], &words[at + phrase..]);
+
+    let Some(operator) = operator else {
+        let split = object.iter().position(|w| *w == "and")?;
+        let inclusive = words[at + 1] != "strictly";
+        let (low, high) = (read_value(&object[..split])?, read_value(&object[split + 1..])?);
+        return Some(Between::new(&read_subject(subject)?, &low, &high, inclusive).into());
+    };
+    if words[at + phrase - 1] == "of" {
+        let multiple = Constraint::multiple_of(&read_noun(subject)?, &read_value(object)?);
+        return Some(CompoundConstraint::Simple(Constraint { operator, ..multiple }));
+    }
+    Some(CompoundConstraint::Simple(Constraint {
+        left_variable: read_subject(subject)?,
+        operator,
+        right_value: read_value(object)?,
+    }))
+}
+
+/// "the balance", or "the remainder of the id divided by 10" -> `id % 10`
+fn read_subject(words: &[&str]) -> Option<String> {
+    match words {
+        ["the", "remainder", "of", rest @ ..] => {
+            let split = rest.windows(2).position(|pair| pair == ["divided", "by"])?;
+            let modulo = Modulo::new(&read_noun(&rest[..split])?, &read_value(&rest[split + 2..])?);
+            Some(modulo.to_string())
+        }
+        _ => read_noun(words),
+    }
+}
+
+/// "the daily total" -> `daily_total`
+fn read_noun(words: &[&str]) -> Option<String> {
+    match words {
+        ["the", name @ ..] if !name.is_empty() => Some(name.join("_")),
+        _ => None,
+    }
+}
+
+/// Inverse of `value_phrase`
+fn read_value(words: &[&str]) -> Option<String> {
+    match words {
+        ["zero"] => Some("0".to_string()),
+        [literal] if literal.parse::<f64>().is_ok() || literal.starts_with('"') => Some(literal.to_string()),
+        _ => read_noun(words),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_withdrawal_round_trip() {
+        let condition = CompoundConstraint::And(vec![
+            c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            c("amount", ConstraintOperator::GreaterThan, "0"),
+        ]);
+
+        assert_eq!(
+            Verbalizer::new().requirement("user", "may", "withdraw", &condition),
+            "The user may withdraw only if the balance is at least the amount and the amount is greater than zero."
+        );
+    }
+
+    #[test]
+    fn test_grouping_negation_and_schema_types() {
+        let mut schema = Schema::new("verbalize-001".to_string());
+        schema.add_field("verified".to_string(), DataType::Bool, None);
+        let verbalizer = Verbalizer::with_schema(&schema);
+
+        let tree = CompoundConstraint::Or(vec![
+            CompoundConstraint::And(vec![
+                c("verified", ConstraintOperator::Equal, "true"),
+                c("daily_total", ConstraintOperator::LessThanOrEqual, "5000"),
+            ]),
+            CompoundConstraint::Not(Box::new(c("role", ConstraintOperator::NotEqual, "\"admin\""))),
+        ]);
+
+        assert_eq!(
+            verbalizer.compound(&tree),
+            "both the verified flag is set and the daily total is at most 5000 or the role is \"admin\""
+        );
+
+        let excluded = CompoundConstraint::Not(Box::new(c("email", ConstraintOperator::Contains, "\"+\"")));
+        assert_eq!(Verbalizer::new().compound(&excluded), "the email does not contain \"+\"");
+
+        let approval = CompoundConstraint::implies(
+            c("amount", ConstraintOperator::GreaterThan, "1000"),
+            c("approved", ConstraintOperator::Equal, "true"),
+        );
+        assert_eq!(
+            verbalizer.compound(&approval),
+            "if the amount is greater than 1000, then the approved flag is set"
+        );
+
+        let payment = CompoundConstraint::ExactlyOne(vec![
+            c("method", ConstraintOperator::Equal, "\"card\""),
+            c("method", ConstraintOperator::Equal, "\"bank_transfer\""),
+        ]);
+        assert_eq!(
+            Verbalizer::new().compound(&payment),
+            "exactly one of the method is \"card\" or the method is \"bank_transfer\""
+        );
+
+        let multiple = CompoundConstraint::Not(Box::new(CompoundConstraint::Simple(Constraint::multiple_of("amount", "100"))));
+        assert_eq!(Verbalizer::new().compound(&multiple), "the amount is not a multiple of 100");
+
+        let fee = c("fee", ConstraintOperator::Equal, "max(min_fee, abs(adjustment))");
+        assert_eq!(
+            Verbalizer::new().compound(&fee),
+            "the fee is the greater of the min fee and the absolute value of the adjustment"
+        );
+        let tiered = c("fee", ConstraintOperator::Equal, "0 if member else 2 if tier >= 3 else base_fee");
+        assert_eq!(
+            Verbalizer::new().compound(&tiered),
+            "the fee is zero if the member flag is set, otherwise 2 if the tier is at least 3, otherwise the base fee"
+        );
+        let flags = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint::bit_set("flags", 3)),
+            c("mode & 0x0F", ConstraintOperator::Equal, "2"),
+        ]);
+        assert_eq!(
+            Verbalizer::new().compound(&flags),
+            "bit 3 of the flags is set and the mode masked with 0x0F is 2"
+        );
+        let total = c("total", ConstraintOperator::Equal, "sum(line_amounts)");
+        assert_eq!(Verbalizer::new().compound(&total), "the total is the sum of the line amounts");
+
+        let ranges = CompoundConstraint::Or(vec![
+            Between::new("amount", "1", "100", true).into(),
+            Between::new("score", "0", "10", false).into(),
+        ]);
+        assert_eq!(
+            Verbalizer::new().compound(&ranges),
+            "the amount is between 1 and 100 or the score is strictly between zero and 10"
+        );
+    }
+
+    #[test]
+    fn test_locales() {
+        let condition = CompoundConstraint::And(vec![
+            c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            c("verified", ConstraintOperator::Equal, "true"),
+        ]);
+        assert_eq!(
+            Verbalizer::new().with_locale(Locale::De).requirement("Kunde", "darf", "abheben", &condition),
+            "Kunde darf abheben nur, wenn balance ist mindestens amount und das Flag verified ist gesetzt."
+        );
+        let range = CompoundConstraint::Not(Box::new(Between::new("score", "0", "10", false).into()));
+        assert_eq!(
+            Verbalizer::new().with_locale(Locale::Es).compound(&range),
+            "no es cierto que score está estrictamente entre cero y 10"
+        );
+    }
+
+    #[test]
+    fn test_read_round_trip() {
+        let verbalizer = Verbalizer::new();
+        let trees = [
+            CompoundConstraint::And(vec![
+                c("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+                c("amount", ConstraintOperator::GreaterThan, "0"),
+                c("daily_total", ConstraintOperator::LessThanOrEqual, "5000"),
+            ]),
+            CompoundConstraint::Or(vec![
+                Between::new("amount", "1", "100", true).into(),
+                Between::new("score", "0", "10", false).into(),
+                c("method", ConstraintOperator::Equal, "\"bank transfer\""),
+            ]),
+            CompoundConstraint::implies(
+                c("amount", ConstraintOperator::GreaterThan, "1000"),
+                c("approved", ConstraintOperator::Equal, "true"),
+            ),
+            CompoundConstraint::ExactlyOne(vec![
+                CompoundConstraint::Simple(Constraint::multiple_of("amount", "100")),
+                c("id % 7", ConstraintOperator::LessThan, "3"),
+            ]),
+            CompoundConstraint::Not(Box::new(CompoundConstraint::Or(vec![
+                c("email", ConstraintOperator::Contains, "\"+\""),
+                c("role", ConstraintOperator::NotEqual, "owner"),
+            ]))),
+            CompoundConstraint::Distinct(vec!["sender".into(), "receiver".into(), "approver_id".into()]),
+            CompoundConstraint::Distinct(vec!["transaction_ids".into()]),
+        ];
+        for tree in trees {
+            assert_eq!(verbalizer.read(&verbalizer.compound(&tree)), Some(tree));
+        }
+
+        let grouped = CompoundConstraint::Or(vec![
+            CompoundConstraint::And(vec![c("a", ConstraintOperator::Equal, "1"), c("b", ConstraintOperator::Equal, "2")]),
+            c("d", ConstraintOperator::Equal, "3"),
+        ]);
+        assert_eq!(verbalizer.read(&verbalizer.compound(&grouped)), None);
+        assert_eq!(verbalizer.read("the moon is made of cheese"), None);
+    }
+}
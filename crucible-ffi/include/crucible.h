@@ -0,0 +1,59 @@
+/*
+ * Crucible Engine - C ABI
+ * "Correct by Design, Not by Debugging"
+ *
+ * Licensed under the Crucible Engine License v2.0
+ * See LICENSE file for full terms
+ *
+ * Every call takes a JSON request and returns a JSON response, so hosts
+ * (C++, Swift, Java via JNI/FFM, ...) need nothing but a JSON library, and
+ * new options are new request fields rather than new symbols.
+ *
+ *   request:  {"spec": "User can withdraw money if balance >= amount",
+ *              "language": "rust", "schema": { ...Schema JSON... }}
+ *   response: {"result": ...}
+ *         or: {"error": {"stage": "parse", "code": "P001", "message": "...",
+ *                        "span": {"line": 1, "column": 5}, "args": {...}}}
+ *
+ * Responses are UTF-8, NUL-terminated and owned by the caller, who must
+ * release each one with crucible_free. NULL is returned only when the
+ * response could not be allocated. All functions are thread-safe.
+ */
+
+#ifndef CRUCIBLE_H
+#define CRUCIBLE_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Incremented when a function's signature or a response's shape changes incompatibly */
+#define CRUCIBLE_ABI_VERSION 1
+
+/* The value of CRUCIBLE_ABI_VERSION the library was built with */
+unsigned int crucible_abi_version(void);
+
+/* Engine version, e.g. "0.1.0"; static, do not free */
+const char *crucible_version(void);
+
+/* {"spec"} -> {"result": [{"line", "subject", "modal_verb", "action",
+ * "object", "severity", "kind", "constraint", "tree"}, ...]} */
+char *crucible_parse(const char *request_json);
+
+/* {"spec", "schema"?} -> {"result": [{"line", "subject", "verdict",
+ * "detail", "model"}, ...]}; verdict is "sat", "unsat", "invalid" (does
+ * not fit the schema), "unknown" or "none" (no constraints). A library
+ * built without the verification feature answers with a V002 error. */
+char *crucible_verify(const char *request_json);
+
+/* {"spec", "language", "schema"?} -> {"result": {"<file name>": "<contents>", ...}} */
+char *crucible_generate(const char *request_json);
+
+/* Release a response; NULL is ignored */
+void crucible_free(char *response);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CRUCIBLE_H */
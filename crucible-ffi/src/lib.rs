@@ -0,0 +1,250 @@
+//! Crucible FFI - a C ABI for embedding the engine
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! For hosts that cannot take a Rust dependency: `include/crucible.h`
+//! declares parse, verify and generate as JSON-in/JSON-out calls plus
+//! `crucible_free` for their responses. A request is one JSON object
+//! (`spec`, `language`, `schema`), a response is `{"result": ...}` or
+//! `{"error": <CrucibleError>}`. Panics are caught at the boundary and
+//! never unwind into the host.
+
+use crucible_codegen::{CodeGenerator, CodegenError, CodegenOptions, TargetLanguage};
+use crucible_core::error::codes;
+use crucible_core::{CompoundConstraint, CrucibleConfig, CrucibleError, Diagnostic, Schema};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::ffi::{c_char, c_uint, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// `CRUCIBLE_ABI_VERSION` in the header
+pub const ABI_VERSION: c_uint = 1;
+
+/// The fields any call may take; each reads the ones it needs
+#[derive(Debug, Deserialize)]
+struct Request {
+    spec: String,
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    schema: Option<Schema>,
+}
+
+/// The request behind a C string
+///
+/// # Safety
+/// `request` is null or points to a NUL-terminated string
+unsafe fn read(request: *const c_char) -> Result<Request, CrucibleError> {
+    let invalid = |message: &str| CrucibleError::Parse(Diagnostic::new(codes::INVALID_JSON, message));
+    if request.is_null() {
+        return Err(invalid("request is null"));
+    }
+    let text = CStr::from_ptr(request).to_str().map_err(|_| invalid("request is not UTF-8"))?;
+    Ok(serde_json::from_str(text)?)
+}
+
+/// `result` as a response the caller frees with `crucible_free`
+fn respond(result: Result<Value, CrucibleError>) -> *mut c_char {
+    let response = match result {
+        Ok(result) => json!({ "result": result }),
+        Err(error) => json!({ "error": error }),
+    };
+    // `serde_json` escapes NUL, so the conversion cannot fail
+    CString::new(response.to_string()).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Run `handler` on the request, answering a panic with an error rather
+/// than unwinding into the host
+///
+/// # Safety
+/// As for `read`
+unsafe fn call(request: *const c_char, handler: fn(Request) -> Result<Value, CrucibleError>) -> *mut c_char {
+    match panic::catch_unwind(AssertUnwindSafe(|| read(request).and_then(handler))) {
+        Ok(result) => respond(result),
+        Err(_) => CString::new(json!({"error": {"message": "internal error"}}).to_string())
+            .map_or(ptr::null_mut(), CString::into_raw),
+    }
+}
+
+/// Each requirement of a spec with its constraint tree, definitions inlined
+fn requirements(spec: &str) -> Result<Vec<(crucible_parser::Requirement, Option<CompoundConstraint>)>, CrucibleError> {
+    let ast = crucible_parser::parse(spec)?;
+    let definitions = ast.definition_table()?;
+    ast.requirements
+        .into_iter()
+        .map(|requirement| {
+            let tree = match requirement.compound() {
+                Some(compound) => Some(definitions.inline(&compound)?),
+                None => None,
+            };
+            Ok((requirement, tree))
+        })
+        .collect()
+}
+
+fn parse(request: Request) -> Result<Value, CrucibleError> {
+    let records: Vec<Value> = requirements(&request.spec)?
+        .into_iter()
+        .map(|(requirement, tree)| {
+            json!({
+                "line": requirement.line,
+                "subject": requirement.subject,
+                "modal_verb": requirement.modal_verb,
+                "action": requirement.action.verb.to_string(),
+                "object": requirement.action.object,
+                "severity": requirement.severity,
+                "kind": requirement.kind,
+                "constraint": tree.as_ref().map(ToString::to_string),
+                "tree": tree,
+            })
+        })
+        .collect();
+    Ok(Value::from(records))
+}
+
+#[cfg(feature = "verification")]
+fn verify(request: Request) -> Result<Value, CrucibleError> {
+    use crucible_verification::{VerificationError, Z3Verifier};
+
+    let verifier = Z3Verifier::new();
+    let records: Vec<Value> = requirements(&request.spec)?
+        .into_iter()
+        .map(|(requirement, tree)| {
+            let result = tree.map(|tree| match &request.schema {
+                Some(schema) => verifier.verify_with_schema(&tree, schema),
+                None => verifier.verify_compound_constraints(&tree),
+            });
+            let (verdict, detail, model) = match result {
+                None => ("none", None, None),
+                Some(Ok(output)) => ("sat", None, output.model),
+                Some(Err(VerificationError::Unsatisfiable(reason))) => ("unsat", Some(reason), None),
+                Some(Err(e @ VerificationError::InvalidConstraint(_))) => ("invalid", Some(e.to_string()), None),
+                Some(Err(e)) => ("unknown", Some(e.to_string()), None),
+            };
+            json!({
+                "line": requirement.line,
+                "subject": requirement.subject,
+                "verdict": verdict,
+                "detail": detail,
+                "model": model,
+            })
+        })
+        .collect();
+    Ok(Value::from(records))
+}
+
+#[cfg(not(feature = "verification"))]
+fn verify(_: Request) -> Result<Value, CrucibleError> {
+    let message = "built without the verification feature";
+    Err(CrucibleError::Verification(Diagnostic::new(codes::SOLVER, message).with_arg("detail", message)))
+}
+
+fn generate(request: Request) -> Result<Value, CrucibleError> {
+    let name = request.language.unwrap_or_default();
+    let language = TargetLanguage::from_name(&name).ok_or(CodegenError::UnsupportedLanguage(name))?;
+    let config = CrucibleConfig::default();
+    let mut files = BTreeMap::new();
+    for tree in requirements(&request.spec)?.into_iter().filter_map(|(_, tree)| tree) {
+        let options = CodegenOptions {
+            func_name: format!("{}{}", config.naming.file_prefix, tree.canonical_hash()),
+            ..CodegenOptions::from_config(&config)
+        };
+        let output = match &request.schema {
+            Some(schema) => CodeGenerator.generate_with_schema_and_options(&tree, schema, language, &options)?,
+            None => CodeGenerator.generate_with_options(&tree, language, &options)?,
+        };
+        files.insert(format!("{}.{}", options.func_name, language.file_extension()), output.code);
+        files.extend(output.files.into_iter().map(|file| (file.path, file.contents)));
+    }
+    Ok(json!(files))
+}
+
+#[no_mangle]
+pub extern "C" fn crucible_abi_version() -> c_uint {
+    ABI_VERSION
+}
+
+#[no_mangle]
+pub extern "C" fn crucible_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+/// # Safety
+/// `request_json` is null or a NUL-terminated string
+#[no_mangle]
+pub unsafe extern "C" fn crucible_parse(request_json: *const c_char) -> *mut c_char {
+    call(request_json, parse)
+}
+
+/// # Safety
+/// `request_json` is null or a NUL-terminated string
+#[no_mangle]
+pub unsafe extern "C" fn crucible_verify(request_json: *const c_char) -> *mut c_char {
+    call(request_json, verify)
+}
+
+/// # Safety
+/// `request_json` is null or a NUL-terminated string
+#[no_mangle]
+pub unsafe extern "C" fn crucible_generate(request_json: *const c_char) -> *mut c_char {
+    call(request_json, generate)
+}
+
+/// # Safety
+/// `response` is null or was returned by one of the calls above and not yet freed
+#[no_mangle]
+pub unsafe extern "C" fn crucible_free(response: *mut c_char) {
+    if !response.is_null() {
+        drop(CString::from_raw(response));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call_json(function: unsafe extern "C" fn(*const c_char) -> *mut c_char, request: &Value) -> Value {
+        let request = CString::new(request.to_string()).unwrap();
+        unsafe {
+            let response = function(request.as_ptr());
+            let json = serde_json::from_str(CStr::from_ptr(response).to_str().unwrap()).unwrap();
+            crucible_free(response);
+            json
+        }
+    }
+
+    #[test]
+    fn test_json_calls() {
+        let spec = "User can withdraw money if balance >= amount\n";
+        let parsed = call_json(crucible_parse, &json!({"spec": spec}));
+        assert_eq!(parsed["result"][0]["constraint"], "balance >= amount");
+
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("balance".to_string(), crucible_core::DataType::Uint64, None);
+        let request = json!({"spec": spec, "language": "rust", "schema": schema});
+        assert_eq!(call_json(crucible_generate, &request)["error"]["code"], "S001");
+        let generated = call_json(crucible_generate, &json!({"spec": spec, "language": "rust"}));
+        let files = generated["result"].as_object().unwrap();
+        assert!(files.len() == 1 && files.keys().all(|name| name.ends_with(".rs")));
+        assert_eq!(call_json(crucible_generate, &json!({"spec": spec, "language": "cobol"}))["error"]["code"], "G001");
+
+        assert_eq!(call_json(crucible_parse, &json!({"text": spec}))["error"]["code"], "P003");
+        let response = unsafe { crucible_parse(ptr::null()) };
+        let error: Value = serde_json::from_str(unsafe { CStr::from_ptr(response) }.to_str().unwrap()).unwrap();
+        assert_eq!(error["error"]["message"], "request is null");
+        unsafe { crucible_free(response) };
+
+        // The header declares every exported function
+        let header = include_str!("../include/crucible.h");
+        assert!(header.contains(&format!("#define CRUCIBLE_ABI_VERSION {}", ABI_VERSION)));
+        for function in ["abi_version", "version", "parse", "verify", "generate", "free"] {
+            assert!(header.contains(&format!("crucible_{}(", function)), "{}", function);
+        }
+    }
+}
@@ -9,7 +9,7 @@
 //! WebAssembly frontend components for the Crucible Engine.
 
 use wasm_bindgen::prelude::*;
-use crucible_core::{Constraint, ConstraintOperator, CompoundConstraint};
+use crucible_core::{CompoundConstraint, CrucibleError, Locale, Schema, Verbalizer};
 
 /// WebAssembly wrapper for constraint validation
 #[wasm_bindgen]
@@ -74,6 +74,48 @@ pub fn get_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Check a constraint tree against a Schema, both in their JSON form.
+/// Throws the `CrucibleError` as an object (`{stage, code, message, span?,
+/// args?}`) with `localized`, the whole error in `locale` (`en`, `de`, `es`;
+/// English when absent or unsupported). Malformed JSON is a `P003` parse
+/// error with the line and column.
+#[wasm_bindgen]
+pub fn validate_constraints(constraint_json: &str, schema_json: &str, locale: Option<String>) -> Result<(), JsValue> {
+    check_constraints(constraint_json, schema_json).map_err(|error| {
+        let json = error_json(&error, locale_of(locale.as_deref())).to_string();
+        js_sys::JSON::parse(&json).unwrap_or_else(|_| JsValue::from_str(&json))
+    })
+}
+
+/// A constraint tree (JSON) as a sentence in `locale`, for review screens
+#[wasm_bindgen]
+pub fn verbalize(constraint_json: &str, locale: Option<String>) -> Result<String, JsValue> {
+    let locale = locale_of(locale.as_deref());
+    let compound: CompoundConstraint = serde_json::from_str(constraint_json).map_err(|e| {
+        let json = error_json(&CrucibleError::from(e), locale).to_string();
+        js_sys::JSON::parse(&json).unwrap_or_else(|_| JsValue::from_str(&json))
+    })?;
+    Ok(Verbalizer::new().with_locale(locale).compound(&compound))
+}
+
+fn locale_of(tag: Option<&str>) -> Locale {
+    tag.and_then(Locale::from_tag).unwrap_or_default()
+}
+
+fn error_json(error: &CrucibleError, locale: Locale) -> serde_json::Value {
+    let mut json = serde_json::to_value(error).unwrap_or_default();
+    if let Some(object) = json.as_object_mut() {
+        object.insert("localized".to_string(), error.localized(locale).into());
+    }
+    json
+}
+
+fn check_constraints(constraint_json: &str, schema_json: &str) -> Result<(), CrucibleError> {
+    let compound: CompoundConstraint = serde_json::from_str(constraint_json)?;
+    let schema: Schema = serde_json::from_str(schema_json)?;
+    Ok(compound.validate(&schema)?)
+}
+
 /// Convert constraint operator to string
 #[wasm_bindgen]
 pub fn operator_to_string(op: i32) -> String {
@@ -91,6 +133,7 @@ pub fn operator_to_string(op: i32) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
 
     #[test]
     fn test_greater_equal_validation() {
@@ -100,6 +143,32 @@ mod tests {
         assert!(!validator.validate_greater_equal(5, 10));
     }
 
+    #[test]
+    fn test_check_constraints() {
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("balance".to_string(), crucible_core::DataType::Uint64, None);
+        let schema_json = serde_json::to_string(&schema).unwrap();
+        let compound = |left: &str| {
+            let constraint = Constraint {
+                left_variable: left.to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "0".to_string(),
+            };
+            serde_json::to_string(&CompoundConstraint::Simple(constraint)).unwrap()
+        };
+
+        assert_eq!(check_constraints(&compound("balance"), &schema_json), Ok(()));
+        assert_eq!(check_constraints(&compound("fee"), &schema_json).unwrap_err().code(), "S001");
+        let error = check_constraints("{\"Simple\": }", &schema_json).unwrap_err();
+        assert!(matches!(error, CrucibleError::Parse(_)));
+        assert_eq!(error.span().map(|span| span.line), Some(1));
+
+        let error = check_constraints(&compound("fee"), &schema_json).unwrap_err();
+        let json = error_json(&error, locale_of(Some("es-MX")));
+        assert_eq!(json["code"], "S001");
+        assert_eq!(json["localized"], "error de esquema [S001] `fee` en `fee >= 0` no es un campo del esquema");
+    }
+
     #[test]
     fn test_version() {
         let version = get_version();
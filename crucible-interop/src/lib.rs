@@ -0,0 +1,385 @@
+//! Crucible Interop - Requirements-Management Interchange
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! Import and export of requirements in ReqIF (OMG Requirements Interchange
+//! Format), the exchange format spoken by DOORS, Polarion and friends.
+//! Foreign object identifiers are preserved so that verification status can be
+//! round-tripped back into the originating tool.
+
+use crucible_core::IntentAst;
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Standard ReqIF attribute carrying the requirement text
+pub const REQIF_TEXT: &str = "ReqIF.Text";
+/// Standard ReqIF attribute carrying the requirement name
+pub const REQIF_NAME: &str = "ReqIF.Name";
+/// Attribute written on export with the Crucible verification status
+pub const CRUCIBLE_STATUS: &str = "Crucible.VerificationStatus";
+/// Attribute written on export with the Crucible requirement ID
+pub const CRUCIBLE_ID: &str = "Crucible.RequirementId";
+
+/// Errors that can occur during requirements interchange
+#[derive(Debug, Error)]
+pub enum InteropError {
+    #[error("Malformed XML: {0}")]
+    Xml(String),
+
+    #[error("Document is not ReqIF: {0}")]
+    NotReqIf(String),
+
+    #[error("SPEC-OBJECT without IDENTIFIER")]
+    MissingIdentifier,
+}
+
+/// A ReqIF SPEC-OBJECT linked to the Intent-AST requirement it was imported as
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReqIfObject {
+    /// The foreign IDENTIFIER from the source tool
+    pub identifier: String,
+    /// The Intent-AST requirement created for this object
+    pub requirement_id: Uuid,
+    /// Attribute long name (or definition ID) -> value
+    pub attributes: BTreeMap<String, String>,
+}
+
+/// The ReqIF side of an import, kept for round-tripping
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReqIfDocument {
+    /// Title from REQ-IF-HEADER, if present
+    pub title: Option<String>,
+    pub objects: Vec<ReqIfObject>,
+}
+
+impl ReqIfDocument {
+    /// Look up the foreign identifier for a requirement
+    pub fn foreign_id(&self, requirement_id: Uuid) -> Option<&str> {
+        self.objects
+            .iter()
+            .find(|o| o.requirement_id == requirement_id)
+            .map(|o| o.identifier.as_str())
+    }
+}
+
+/// Import ReqIF XML into a fresh Intent-AST.
+///
+/// Requirement text is taken from `ReqIF.Text`, falling back to `ReqIF.Name`
+/// and then to the first attribute value of the object.
+pub fn import_reqif(xml: &str) -> Result<(IntentAst, ReqIfDocument), InteropError> {
+    let raw = read_spec_objects(xml)?;
+
+    let mut ast = IntentAst::new();
+    let mut objects = Vec::new();
+
+    for object in raw.objects {
+        let attributes: BTreeMap<String, String> = object
+            .values
+            .into_iter()
+            .map(|(def, value)| (raw.definitions.get(&def).cloned().unwrap_or(def), value))
+            .collect();
+
+        let text = attributes
+            .get(REQIF_TEXT)
+            .or_else(|| attributes.get(REQIF_NAME))
+            .or_else(|| attributes.values().next())
+            .cloned()
+            .unwrap_or_default();
+
+        ast.add_requirement(text);
+        let requirement_id = ast.requirements.last().map(|r| r.id).unwrap_or_default();
+
+        objects.push(ReqIfObject {
+            identifier: object.identifier,
+            requirement_id,
+            attributes,
+        });
+    }
+
+    Ok((ast, ReqIfDocument { title: raw.title, objects }))
+}
+
+/// Export the Intent-AST as ReqIF, writing the verification status of every
+/// requirement back under its original foreign identifier.
+///
+/// Requirements that were not imported get a `crucible-<uuid>` identifier.
+pub fn export_reqif(ast: &IntentAst, document: &ReqIfDocument) -> String {
+    let mut rows: Vec<(String, BTreeMap<String, String>)> = Vec::new();
+
+    for requirement in &ast.requirements {
+        let original = document
+            .objects
+            .iter()
+            .find(|o| o.requirement_id == requirement.id);
+
+        let identifier = original
+            .map(|o| o.identifier.clone())
+            .unwrap_or_else(|| format!("crucible-{}", requirement.id));
+
+        let mut attributes = original.map(|o| o.attributes.clone()).unwrap_or_default();
+        attributes.insert(REQIF_TEXT.to_string(), requirement.content.clone());
+        attributes.insert(CRUCIBLE_ID.to_string(), requirement.id.to_string());
+        attributes.insert(
+            CRUCIBLE_STATUS.to_string(),
+            if requirement.verified { "verified" } else { "unverified" }.to_string(),
+        );
+
+        rows.push((identifier, attributes));
+    }
+
+    let mut definitions: Vec<&String> = rows.iter().flat_map(|(_, a)| a.keys()).collect();
+    definitions.sort();
+    definitions.dedup();
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<REQ-IF xmlns=\"http://www.omg.org/spec/ReqIF/20110401/reqif.xsd\">\n");
+    out.push_str("  <THE-HEADER>\n");
+    out.push_str(&format!(
+        "    <REQ-IF-HEADER IDENTIFIER=\"crucible-{}\">\n",
+        ast.id
+    ));
+    out.push_str("      <REQ-IF-TOOL-ID>Crucible Engine</REQ-IF-TOOL-ID>\n");
+    if let Some(title) = &document.title {
+        out.push_str(&format!("      <TITLE>{}</TITLE>\n", escape(title.as_str())));
+    }
+    out.push_str("    </REQ-IF-HEADER>\n");
+    out.push_str("  </THE-HEADER>\n");
+    out.push_str("  <CORE-CONTENT>\n");
+    out.push_str("    <REQ-IF-CONTENT>\n");
+
+    out.push_str("      <DATATYPES>\n");
+    out.push_str("        <DATATYPE-DEFINITION-STRING IDENTIFIER=\"crucible-string\" LONG-NAME=\"String\" MAX-LENGTH=\"65535\"/>\n");
+    out.push_str("      </DATATYPES>\n");
+
+    out.push_str("      <SPEC-TYPES>\n");
+    out.push_str("        <SPEC-OBJECT-TYPE IDENTIFIER=\"crucible-requirement-type\" LONG-NAME=\"Requirement\">\n");
+    out.push_str("          <SPEC-ATTRIBUTES>\n");
+    for name in &definitions {
+        out.push_str(&format!(
+            "            <ATTRIBUTE-DEFINITION-STRING IDENTIFIER=\"{id}\" LONG-NAME=\"{id}\">\n              <TYPE><DATATYPE-DEFINITION-STRING-REF>crucible-string</DATATYPE-DEFINITION-STRING-REF></TYPE>\n            </ATTRIBUTE-DEFINITION-STRING>\n",
+            id = escape(name.as_str())
+        ));
+    }
+    out.push_str("          </SPEC-ATTRIBUTES>\n");
+    out.push_str("        </SPEC-OBJECT-TYPE>\n");
+    out.push_str("      </SPEC-TYPES>\n");
+
+    out.push_str("      <SPEC-OBJECTS>\n");
+    for (identifier, attributes) in &rows {
+        out.push_str(&format!(
+            "        <SPEC-OBJECT IDENTIFIER=\"{}\">\n",
+            escape(identifier.as_str())
+        ));
+        out.push_str("          <TYPE><SPEC-OBJECT-TYPE-REF>crucible-requirement-type</SPEC-OBJECT-TYPE-REF></TYPE>\n");
+        out.push_str("          <VALUES>\n");
+        for (name, value) in attributes {
+            out.push_str(&format!(
+                "            <ATTRIBUTE-VALUE-STRING THE-VALUE=\"{}\">\n              <DEFINITION><ATTRIBUTE-DEFINITION-STRING-REF>{}</ATTRIBUTE-DEFINITION-STRING-REF></DEFINITION>\n            </ATTRIBUTE-VALUE-STRING>\n",
+                escape(value.as_str()),
+                escape(name.as_str())
+            ));
+        }
+        out.push_str("          </VALUES>\n");
+        out.push_str("        </SPEC-OBJECT>\n");
+    }
+    out.push_str("      </SPEC-OBJECTS>\n");
+
+    out.push_str("    </REQ-IF-CONTENT>\n");
+    out.push_str("  </CORE-CONTENT>\n");
+    out.push_str("</REQ-IF>\n");
+    out
+}
+
+// --- ReqIF Reader ---
+
+struct RawObject {
+    identifier: String,
+    values: Vec<(String, String)>,
+}
+
+struct RawDocument {
+    title: Option<String>,
+    /// Attribute definition IDENTIFIER -> LONG-NAME
+    definitions: BTreeMap<String, String>,
+    objects: Vec<RawObject>,
+}
+
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, InteropError> {
+    for attr in element.attributes() {
+        let attr = attr.map_err(|e| InteropError::Xml(e.to_string()))?;
+        if attr.key.local_name().as_ref() == name.as_bytes() {
+            let value = attr
+                .unescape_value()
+                .map_err(|e| InteropError::Xml(e.to_string()))?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+fn read_spec_objects(xml: &str) -> Result<RawDocument, InteropError> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut document = RawDocument {
+        title: None,
+        definitions: BTreeMap::new(),
+        objects: Vec::new(),
+    };
+
+    let mut seen_root = false;
+    let mut stack: Vec<String> = Vec::new();
+    let mut current: Option<RawObject> = None;
+    let mut value: Option<String> = None;
+    let mut definition: Option<String> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| InteropError::Xml(e.to_string()))?;
+
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+
+                if name == "REQ-IF" {
+                    seen_root = true;
+                } else if name.starts_with("ATTRIBUTE-DEFINITION-") && !name.ends_with("-REF") {
+                    if let (Some(id), Some(long_name)) =
+                        (attribute(e, "IDENTIFIER")?, attribute(e, "LONG-NAME")?)
+                    {
+                        document.definitions.insert(id, long_name);
+                    }
+                } else if name == "SPEC-OBJECT" {
+                    let identifier =
+                        attribute(e, "IDENTIFIER")?.ok_or(InteropError::MissingIdentifier)?;
+                    current = Some(RawObject {
+                        identifier,
+                        values: Vec::new(),
+                    });
+                } else if name.starts_with("ATTRIBUTE-VALUE-") && current.is_some() {
+                    value = attribute(e, "THE-VALUE")?;
+                    definition = None;
+                }
+
+                if !is_empty {
+                    stack.push(name);
+                }
+            }
+            Event::Text(ref t) => {
+                let text = t
+                    .unescape()
+                    .map_err(|e| InteropError::Xml(e.to_string()))?
+                    .into_owned();
+                let Some(top) = stack.last() else { continue };
+
+                if top == "TITLE" {
+                    document.title = Some(text);
+                } else if top.starts_with("ATTRIBUTE-DEFINITION-") && top.ends_with("-REF") {
+                    definition = Some(text);
+                } else if stack.iter().any(|s| s == "THE-VALUE") {
+                    // XHTML values: keep the text content, drop the markup
+                    let joined = match value.take() {
+                        Some(prev) if !prev.is_empty() => format!("{} {}", prev, text),
+                        _ => text,
+                    };
+                    value = Some(joined);
+                }
+            }
+            Event::End(ref e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                stack.pop();
+
+                if name.starts_with("ATTRIBUTE-VALUE-") {
+                    if let (Some(object), Some(def)) = (current.as_mut(), definition.take()) {
+                        object.values.push((def, value.take().unwrap_or_default()));
+                    }
+                } else if name == "SPEC-OBJECT" {
+                    if let Some(object) = current.take() {
+                        document.objects.push(object);
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if !seen_root {
+        return Err(InteropError::NotReqIf("missing REQ-IF root element".to_string()));
+    }
+
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOORS_EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<REQ-IF xmlns="http://www.omg.org/spec/ReqIF/20110401/reqif.xsd" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+  <THE-HEADER><REQ-IF-HEADER IDENTIFIER="hdr-1"><TITLE>Payments</TITLE></REQ-IF-HEADER></THE-HEADER>
+  <CORE-CONTENT><REQ-IF-CONTENT>
+    <SPEC-TYPES><SPEC-OBJECT-TYPE IDENTIFIER="t1"><SPEC-ATTRIBUTES>
+      <ATTRIBUTE-DEFINITION-STRING IDENTIFIER="_a1" LONG-NAME="ReqIF.Text"/>
+      <ATTRIBUTE-DEFINITION-XHTML IDENTIFIER="_a2" LONG-NAME="Rationale"/>
+    </SPEC-ATTRIBUTES></SPEC-OBJECT-TYPE></SPEC-TYPES>
+    <SPEC-OBJECTS>
+      <SPEC-OBJECT IDENTIFIER="DOORS-42">
+        <VALUES>
+          <ATTRIBUTE-VALUE-STRING THE-VALUE="User can withdraw money if balance &gt;= amount">
+            <DEFINITION><ATTRIBUTE-DEFINITION-STRING-REF>_a1</ATTRIBUTE-DEFINITION-STRING-REF></DEFINITION>
+          </ATTRIBUTE-VALUE-STRING>
+          <ATTRIBUTE-VALUE-XHTML>
+            <DEFINITION><ATTRIBUTE-DEFINITION-XHTML-REF>_a2</ATTRIBUTE-DEFINITION-XHTML-REF></DEFINITION>
+            <THE-VALUE><xhtml:div>No overdraft</xhtml:div></THE-VALUE>
+          </ATTRIBUTE-VALUE-XHTML>
+        </VALUES>
+      </SPEC-OBJECT>
+    </SPEC-OBJECTS>
+  </REQ-IF-CONTENT></CORE-CONTENT>
+</REQ-IF>"#;
+
+    #[test]
+    fn test_import_reqif_objects() {
+        let (ast, document) = import_reqif(DOORS_EXPORT).unwrap();
+        assert_eq!(ast.requirements.len(), 1);
+        assert_eq!(ast.requirements[0].content, "User can withdraw money if balance >= amount");
+        assert_eq!(document.title.as_deref(), Some("Payments"));
+        assert_eq!(document.objects[0].identifier, "DOORS-42");
+        assert_eq!(document.objects[0].attributes["Rationale"], "No overdraft");
+        assert_eq!(document.foreign_id(ast.requirements[0].id), Some("DOORS-42"));
+    }
+
+    #[test]
+    fn test_export_round_trip_preserves_foreign_ids() {
+        let (mut ast, document) = import_reqif(DOORS_EXPORT).unwrap();
+        ast.requirements[0].verified = true;
+
+        let xml = export_reqif(&ast, &document);
+        assert!(xml.contains("IDENTIFIER=\"DOORS-42\""));
+        assert!(xml.contains("THE-VALUE=\"verified\""));
+
+        let (reimported, redoc) = import_reqif(&xml).unwrap();
+        assert_eq!(reimported.requirements[0].content, ast.requirements[0].content);
+        assert_eq!(redoc.objects[0].identifier, "DOORS-42");
+        assert_eq!(redoc.objects[0].attributes[CRUCIBLE_STATUS], "verified");
+        assert_eq!(redoc.objects[0].attributes["Rationale"], "No overdraft");
+    }
+
+    #[test]
+    fn test_rejects_non_reqif() {
+        assert!(matches!(import_reqif("<html/>"), Err(InteropError::NotReqIf(_))));
+    }
+}
@@ -0,0 +1,329 @@
+//! The `Money` type of a money model in the target language
+//!
+//! Validators compare amounts; the project still needs a type that keeps
+//! each amount with its currency, rounds it to the currency's minor units
+//! and refuses to add or compare amounts in different currencies. One
+//! module per language, covering the model's currencies:
+//!
+//! - Rust: `money.rs`, a `Currency` enum and `Money` over `rust_decimal`
+//! - TypeScript: `money.ts`, a `Currency` enum and `Money` over `bignumber.js`
+//! - SPARK/Ada: `money.ads`, one decimal fixed-point type per currency, so
+//!   the compiler rejects mixed-currency arithmetic outright
+//!
+//! Rounding follows the model's `RoundingMode`. Ada rounds half away from
+//! zero (`'Round`) or truncates (conversion), so only `HalfUp` and `Down`
+//! are available there.
+
+use crate::{Currency, MoneyError, MoneyModel, RoundingMode};
+use crucible_codegen::{GeneratedFile, TargetLanguage};
+use std::fmt::Write as _;
+
+/// The Money module for `language`
+pub fn money_module(model: &MoneyModel, language: TargetLanguage) -> Result<GeneratedFile, MoneyError> {
+    let currencies = model.currencies();
+    if currencies.is_empty() {
+        return Err(MoneyError::NoFields);
+    }
+    let (path, contents) = match language {
+        TargetLanguage::Rust => ("money.rs", rust(&currencies, model.rounding)),
+        TargetLanguage::TypeScript => ("money.ts", typescript(&currencies, model.rounding)),
+        TargetLanguage::SparkAda => ("money.ads", ada(&currencies, model.rounding)?),
+        other => return Err(MoneyError::UnsupportedLanguage(format!("{:?}", other))),
+    };
+    Ok(GeneratedFile {
+        path: path.to_string(),
+        contents,
+    })
+}
+
+/// `USD` as a Rust variant, `Usd`
+fn variant(currency: &Currency) -> String {
+    let mut code = currency.code.to_ascii_lowercase();
+    code[..1].make_ascii_uppercase();
+    code
+}
+
+fn rust(currencies: &[&Currency], rounding: RoundingMode) -> String {
+    let strategy = match rounding {
+        RoundingMode::HalfEven => "MidpointNearestEven",
+        RoundingMode::HalfUp => "MidpointAwayFromZero",
+        RoundingMode::Down => "ToZero",
+        RoundingMode::Up => "AwayFromZero",
+        RoundingMode::Floor => "ToNegativeInfinity",
+        RoundingMode::Ceiling => "ToPositiveInfinity",
+    };
+    let mut variants = String::new();
+    let mut codes = String::new();
+    let mut minor_units = String::new();
+    for currency in currencies {
+        let variant = variant(currency);
+        let _ = writeln!(variants, "    {},", variant);
+        let _ = writeln!(codes, "            Currency::{} => \"{}\",", variant, currency.code);
+        let _ = writeln!(minor_units, "            Currency::{} => {},", variant, currency.minor_units);
+    }
+    format!(
+        r#"//! Money with a currency; every amount is rounded to its currency's minor
+//! units ({rounding}) when it is made
+
+use rust_decimal::{{Decimal, RoundingStrategy}};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// How an amount between two minor units is rounded
+pub const ROUNDING: RoundingStrategy = RoundingStrategy::{strategy};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {{
+{variants}}}
+
+impl Currency {{
+    /// ISO 4217 code
+    pub const fn code(self) -> &'static str {{
+        match self {{
+{codes}        }}
+    }}
+
+    /// Digits after the decimal point
+    pub const fn minor_units(self) -> u32 {{
+        match self {{
+{minor_units}        }}
+    }}
+}}
+
+/// Amounts in two different currencies were combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyMismatch {{
+    pub left: Currency,
+    pub right: Currency,
+}}
+
+impl fmt::Display for CurrencyMismatch {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        write!(f, "{{}} and {{}} amounts cannot be combined", self.left.code(), self.right.code())
+    }}
+}}
+
+impl std::error::Error for CurrencyMismatch {{}}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {{
+    amount: Decimal,
+    currency: Currency,
+}}
+
+impl Money {{
+    pub fn new(amount: Decimal, currency: Currency) -> Self {{
+        Self {{
+            amount: amount.round_dp_with_strategy(currency.minor_units(), ROUNDING),
+            currency,
+        }}
+    }}
+
+    pub fn amount(&self) -> Decimal {{
+        self.amount
+    }}
+
+    pub fn currency(&self) -> Currency {{
+        self.currency
+    }}
+
+    pub fn checked_add(self, other: Money) -> Result<Money, CurrencyMismatch> {{
+        self.same_currency(&other)?;
+        Ok(Money::new(self.amount + other.amount, self.currency))
+    }}
+
+    pub fn checked_sub(self, other: Money) -> Result<Money, CurrencyMismatch> {{
+        self.same_currency(&other)?;
+        Ok(Money::new(self.amount - other.amount, self.currency))
+    }}
+
+    /// `self` times `factor` (a rate, a fee percentage), rounded
+    pub fn scale(self, factor: Decimal) -> Money {{
+        Money::new(self.amount * factor, self.currency)
+    }}
+
+    pub fn checked_cmp(&self, other: &Money) -> Result<Ordering, CurrencyMismatch> {{
+        self.same_currency(other)?;
+        Ok(self.amount.cmp(&other.amount))
+    }}
+
+    fn same_currency(&self, other: &Money) -> Result<(), CurrencyMismatch> {{
+        if self.currency == other.currency {{
+            Ok(())
+        }} else {{
+            Err(CurrencyMismatch {{
+                left: self.currency,
+                right: other.currency,
+            }})
+        }}
+    }}
+}}
+
+impl fmt::Display for Money {{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
+        write!(f, "{{}} {{}}", self.amount, self.currency.code())
+    }}
+}}
+"#
+    )
+}
+
+fn typescript(currencies: &[&Currency], rounding: RoundingMode) -> String {
+    let mode = match rounding {
+        RoundingMode::HalfEven => "ROUND_HALF_EVEN",
+        RoundingMode::HalfUp => "ROUND_HALF_UP",
+        RoundingMode::Down => "ROUND_DOWN",
+        RoundingMode::Up => "ROUND_UP",
+        RoundingMode::Floor => "ROUND_FLOOR",
+        RoundingMode::Ceiling => "ROUND_CEIL",
+    };
+    let mut members = String::new();
+    let mut minor_units = String::new();
+    for currency in currencies {
+        let _ = writeln!(members, "  {0} = \"{0}\",", currency.code);
+        let _ = writeln!(minor_units, "  [Currency.{}]: {},", currency.code, currency.minor_units);
+    }
+    format!(
+        r#"// Money with a currency; every amount is rounded to its currency's minor
+// units ({rounding}) when it is made
+
+import BigNumber from "bignumber.js";
+
+/** How an amount between two minor units is rounded */
+export const ROUNDING = BigNumber.{mode};
+
+export enum Currency {{
+{members}}}
+
+/** Digits after the decimal point */
+export const MINOR_UNITS: Record<Currency, number> = {{
+{minor_units}}};
+
+export class CurrencyMismatchError extends Error {{
+  constructor(readonly left: Currency, readonly right: Currency) {{
+    super(`${{left}} and ${{right}} amounts cannot be combined`);
+    this.name = "CurrencyMismatchError";
+  }}
+}}
+
+export class Money {{
+  readonly amount: BigNumber;
+
+  constructor(amount: BigNumber.Value, readonly currency: Currency) {{
+    this.amount = new BigNumber(amount).decimalPlaces(MINOR_UNITS[currency], ROUNDING);
+  }}
+
+  plus(other: Money): Money {{
+    this.sameCurrency(other);
+    return new Money(this.amount.plus(other.amount), this.currency);
+  }}
+
+  minus(other: Money): Money {{
+    this.sameCurrency(other);
+    return new Money(this.amount.minus(other.amount), this.currency);
+  }}
+
+  /** `this` times `factor` (a rate, a fee percentage), rounded */
+  times(factor: BigNumber.Value): Money {{
+    return new Money(this.amount.times(factor), this.currency);
+  }}
+
+  compare(other: Money): number {{
+    this.sameCurrency(other);
+    return this.amount.comparedTo(other.amount);
+  }}
+
+  toString(): string {{
+    return `${{this.amount.toFixed(MINOR_UNITS[this.currency])}} ${{this.currency}}`;
+  }}
+
+  private sameCurrency(other: Money): void {{
+    if (other.currency !== this.currency) {{
+      throw new CurrencyMismatchError(this.currency, other.currency);
+    }}
+  }}
+}}
+"#
+    )
+}
+
+fn ada(currencies: &[&Currency], rounding: RoundingMode) -> Result<String, MoneyError> {
+    let convert = match rounding {
+        RoundingMode::HalfUp => "Round with <Code>_Amount'Round (X), which rounds half away from zero",
+        RoundingMode::Down => "Convert with <Code>_Amount (X), which truncates",
+        mode => {
+            return Err(MoneyError::UnsupportedRounding {
+                language: "SPARK/Ada".to_string(),
+                mode,
+            })
+        }
+    };
+    let codes: Vec<&str> = currencies.iter().map(|currency| currency.code.as_str()).collect();
+    let mut minor_units = String::new();
+    let mut types = String::new();
+    for (index, currency) in currencies.iter().enumerate() {
+        let separator = if index + 1 == currencies.len() { "" } else { "," };
+        let _ = write!(minor_units, "\n         when {} => {}{}", currency.code, currency.minor_units, separator);
+        let delta = match currency.minor_units {
+            0 => "1.0".to_string(),
+            digits => format!("0.{}1", "0".repeat(digits as usize - 1)),
+        };
+        let _ = writeln!(types, "   type {}_Amount is delta {} digits 18;", currency.code, delta);
+    }
+    Ok(format!(
+        r#"--  Money as decimal fixed-point types, one per currency, so amounts in
+--  different currencies cannot be mixed without an explicit conversion.
+--  {convert}.
+
+package Money with SPARK_Mode is
+
+   type Currency is ({codes});
+
+   --  Digits after the decimal point
+   function Minor_Units (C : Currency) return Natural is
+     (case C is{minor_units});
+
+{types}
+end Money;
+"#,
+        codes = codes.join(", "),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_money_modules() {
+        let mut model = MoneyModel::new(RoundingMode::HalfEven);
+        model
+            .add_field("amount", Currency::iso("USD").unwrap())
+            .add_field("balance", Currency::iso("USD").unwrap())
+            .add_field("fee", Currency::iso("JPY").unwrap());
+
+        let rust = money_module(&model, TargetLanguage::Rust).unwrap();
+        assert_eq!(rust.path, "money.rs");
+        assert!(rust.contents.contains("pub enum Currency {\n    Jpy,\n    Usd,\n}\n"));
+        assert!(rust.contents.contains("            Currency::Jpy => 0,\n"));
+        assert!(rust.contents.contains("RoundingStrategy::MidpointNearestEven;"));
+
+        let typescript = money_module(&model, TargetLanguage::TypeScript).unwrap();
+        assert!(typescript.contents.contains("  USD = \"USD\",\n"));
+        assert!(typescript.contents.contains("export const ROUNDING = BigNumber.ROUND_HALF_EVEN;"));
+
+        assert!(matches!(
+            money_module(&model, TargetLanguage::SparkAda),
+            Err(MoneyError::UnsupportedRounding { mode: RoundingMode::HalfEven, .. })
+        ));
+        model.rounding = RoundingMode::HalfUp;
+        let ada = money_module(&model, TargetLanguage::SparkAda).unwrap().contents;
+        assert!(ada.contains("   type Currency is (JPY, USD);\n"));
+        assert!(ada.contains("(case C is\n         when JPY => 0,\n         when USD => 2);"));
+        assert!(ada.contains("   type JPY_Amount is delta 1.0 digits 18;\n"));
+        assert!(ada.contains("   type USD_Amount is delta 0.01 digits 18;\n"));
+
+        assert!(matches!(money_module(&model, TargetLanguage::Zig), Err(MoneyError::UnsupportedLanguage(_))));
+        assert_eq!(money_module(&MoneyModel::default(), TargetLanguage::Rust), Err(MoneyError::NoFields));
+    }
+}
@@ -0,0 +1,433 @@
+//! Crucible Money - Money and Currency Domain Package
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! Financial requirements compare amounts of money, and amounts are not
+//! plain numbers: each has a currency, a fixed number of minor units (cents)
+//! and a rule for rounding values that fall between two of them. A
+//! `MoneyModel` records which Schema fields are money in which currency and
+//! the rounding mode, and then
+//!
+//! - `lower` rewrites a constraint tree over those fields into exact integer
+//!   minor units for the solver, rounding each literal the way the generated
+//!   `Money` type rounds it, so verification and runtime agree;
+//! - `mismatches` finds comparisons between amounts in different currencies
+//!   (the `verification` feature runs it as the `CurrencyCheck` pass);
+//! - `codegen::money_module` emits the `Money` type for Rust (`rust_decimal`
+//!   and a `Currency` enum), TypeScript (`bignumber.js`) and SPARK/Ada
+//!   (decimal fixed-point types).
+//!
+//! ```
+//! use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+//! use crucible_money::{Currency, MoneyModel, RoundingMode};
+//!
+//! let mut model = MoneyModel::new(RoundingMode::HalfEven);
+//! model.add_field("amount", Currency::iso("USD").unwrap());
+//! let tree = CompoundConstraint::Simple(Constraint {
+//!     left_variable: "amount".to_string(),
+//!     operator: ConstraintOperator::GreaterThanOrEqual,
+//!     right_value: "10.005".to_string(),
+//! });
+//! // 10.005 rounds half-to-even to 10.00, which is 1000 cents
+//! assert_eq!(model.lower(&tree).unwrap().to_string(), "amount >= 1000");
+//! ```
+
+pub mod codegen;
+#[cfg(feature = "verification")]
+mod pass;
+
+#[cfg(feature = "verification")]
+pub use pass::{CurrencyCheck, CURRENCY_MISMATCH};
+
+use crucible_core::{CompoundConstraint, Constraint, Modulo};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+use thiserror::Error;
+
+/// Digits an amount may have in all, so its minor units fit an `i128`
+const MAX_DIGITS: usize = 30;
+
+/// ISO 4217 codes with their minor units
+const ISO_4217: &[(&str, u32)] = &[
+    ("AUD", 2),
+    ("BHD", 3),
+    ("BRL", 2),
+    ("CAD", 2),
+    ("CHF", 2),
+    ("CNY", 2),
+    ("EUR", 2),
+    ("GBP", 2),
+    ("HKD", 2),
+    ("INR", 2),
+    ("JPY", 0),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("MXN", 2),
+    ("NOK", 2),
+    ("NZD", 2),
+    ("SEK", 2),
+    ("SGD", 2),
+    ("USD", 2),
+    ("ZAR", 2),
+];
+
+/// Errors raised by the money model
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MoneyError {
+    #[error("`{0}` is not a currency code (three letters)")]
+    InvalidCurrency(String),
+
+    #[error("`{0}` is not an ISO 4217 currency this package knows; use Currency::new")]
+    UnknownCurrency(String),
+
+    #[error("`{0}` is not an amount")]
+    InvalidAmount(String),
+
+    #[error("{0}")]
+    CurrencyMismatch(CurrencyMismatch),
+
+    #[error("the money model has no fields")]
+    NoFields,
+
+    #[error("{language} has no {mode} rounding")]
+    UnsupportedRounding { language: String, mode: RoundingMode },
+
+    #[error("no Money type for {0}")]
+    UnsupportedLanguage(String),
+}
+
+/// A currency and the number of digits after its decimal point
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Currency {
+    /// ISO 4217 code, upper case
+    pub code: String,
+    pub minor_units: u32,
+}
+
+impl Currency {
+    /// A currency with its ISO 4217 minor units
+    pub fn iso(code: &str) -> Result<Self, MoneyError> {
+        let code = code.to_ascii_uppercase();
+        let (_, minor_units) = ISO_4217
+            .iter()
+            .find(|(iso, _)| *iso == code)
+            .ok_or_else(|| MoneyError::UnknownCurrency(code.clone()))?;
+        Self::new(&code, *minor_units)
+    }
+
+    /// Any three-letter currency, e.g. an in-house unit
+    pub fn new(code: &str, minor_units: u32) -> Result<Self, MoneyError> {
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) || minor_units > 8 {
+            return Err(MoneyError::InvalidCurrency(code.to_string()));
+        }
+        Ok(Self {
+            code: code.to_ascii_uppercase(),
+            minor_units,
+        })
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.code)
+    }
+}
+
+/// How a value between two minor units is rounded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RoundingMode {
+    /// To the nearest, ties to the even neighbour (banker's rounding)
+    #[default]
+    HalfEven,
+    /// To the nearest, ties away from zero
+    HalfUp,
+    /// Toward zero (truncation)
+    Down,
+    /// Away from zero
+    Up,
+    /// Toward negative infinity
+    Floor,
+    /// Toward positive infinity
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// `numerator / denominator` rounded to an integer; `denominator` is positive
+    pub fn divide(self, numerator: i128, denominator: i128) -> i128 {
+        let (quotient, remainder) = (numerator / denominator, numerator % denominator);
+        if remainder == 0 {
+            return quotient;
+        }
+        let away = quotient + numerator.signum();
+        match self {
+            RoundingMode::Down => quotient,
+            RoundingMode::Up => away,
+            RoundingMode::Floor => quotient.min(away),
+            RoundingMode::Ceiling => quotient.max(away),
+            RoundingMode::HalfUp | RoundingMode::HalfEven => match (2 * remainder.abs()).cmp(&denominator) {
+                Ordering::Less => quotient,
+                Ordering::Greater => away,
+                Ordering::Equal if self == RoundingMode::HalfUp || quotient % 2 != 0 => away,
+                Ordering::Equal => quotient,
+            },
+        }
+    }
+}
+
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RoundingMode::HalfEven => "half-even",
+            RoundingMode::HalfUp => "half-up",
+            RoundingMode::Down => "down",
+            RoundingMode::Up => "up",
+            RoundingMode::Floor => "floor",
+            RoundingMode::Ceiling => "ceiling",
+        })
+    }
+}
+
+/// A constraint comparing amounts in two different currencies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrencyMismatch {
+    /// The constraint as text
+    pub constraint: String,
+    pub left: String,
+    pub left_currency: String,
+    pub right: String,
+    pub right_currency: String,
+}
+
+impl fmt::Display for CurrencyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` compares {} ({}) with {} ({})",
+            self.constraint, self.left, self.left_currency, self.right, self.right_currency
+        )
+    }
+}
+
+/// Which fields are money, in which currency, and how amounts round
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoneyModel {
+    /// Money field -> its currency
+    pub fields: BTreeMap<String, Currency>,
+    pub rounding: RoundingMode,
+}
+
+impl MoneyModel {
+    pub fn new(rounding: RoundingMode) -> Self {
+        Self {
+            fields: BTreeMap::new(),
+            rounding,
+        }
+    }
+
+    /// Declare `field` an amount of `currency`
+    pub fn add_field(&mut self, field: &str, currency: Currency) -> &mut Self {
+        self.fields.insert(field.to_string(), currency);
+        self
+    }
+
+    pub fn currency(&self, field: &str) -> Option<&Currency> {
+        self.fields.get(field)
+    }
+
+    /// Every currency a field is in, by code
+    pub fn currencies(&self) -> Vec<&Currency> {
+        let mut currencies: Vec<&Currency> = self.fields.values().collect();
+        currencies.sort();
+        currencies.dedup();
+        currencies
+    }
+
+    /// `value` (`12.345`, `-3`) in minor units of `currency`, rounded with
+    /// the model's rounding mode
+    pub fn minor_units(&self, currency: &Currency, value: &str) -> Result<i128, MoneyError> {
+        let invalid = || MoneyError::InvalidAmount(value.to_string());
+        let (mantissa, scale) = parse_amount(value).ok_or_else(invalid)?;
+        match scale.checked_sub(currency.minor_units) {
+            Some(extra) => Ok(self.rounding.divide(mantissa, 10i128.pow(extra))),
+            None => mantissa.checked_mul(10i128.pow(currency.minor_units - scale)).ok_or_else(invalid),
+        }
+    }
+
+    /// Every constraint of `tree` comparing amounts in different currencies
+    pub fn mismatches(&self, tree: &CompoundConstraint) -> Vec<CurrencyMismatch> {
+        let mut mismatches = Vec::new();
+        for_each_simple(tree, &mut |constraint| {
+            let left = money_operand(constraint);
+            let left_currency = self.currency(left);
+            let right_currency = self.currency(&constraint.right_value);
+            if let (Some(left_currency), Some(right_currency)) = (left_currency, right_currency) {
+                if left_currency != right_currency {
+                    mismatches.push(CurrencyMismatch {
+                        constraint: constraint.to_string(),
+                        left: left.to_string(),
+                        left_currency: left_currency.code.clone(),
+                        right: constraint.right_value.clone(),
+                        right_currency: right_currency.code.clone(),
+                    });
+                }
+            }
+        });
+        mismatches
+    }
+
+    /// `tree` over integer minor units: every amount literal compared with a
+    /// money field (and the divisor of `amount % 5`) is scaled to the
+    /// field's minor units and rounded as the generated `Money` type rounds
+    /// it. Fails on the first currency mismatch
+    pub fn lower(&self, tree: &CompoundConstraint) -> Result<CompoundConstraint, MoneyError> {
+        if let Some(mismatch) = self.mismatches(tree).into_iter().next() {
+            return Err(MoneyError::CurrencyMismatch(mismatch));
+        }
+        map_simple(tree, &|constraint| {
+            let Some(currency) = self.currency(money_operand(constraint)) else {
+                return Ok(constraint.clone());
+            };
+            let scale = |value: &str| -> Result<String, MoneyError> {
+                match parse_amount(value) {
+                    Some(_) => Ok(self.minor_units(currency, value)?.to_string()),
+                    // Another field
+                    None => Ok(value.to_string()),
+                }
+            };
+            let left_variable = match constraint.modulo() {
+                Some(modulo) => Modulo::new(&modulo.dividend, &scale(&modulo.divisor)?).to_string(),
+                None => constraint.left_variable.clone(),
+            };
+            Ok(Constraint {
+                left_variable,
+                operator: constraint.operator,
+                right_value: scale(&constraint.right_value)?,
+            })
+        })
+    }
+}
+
+/// The field on the left of a constraint: `amount` in `amount >= 10` and
+/// in `amount % 5 == 0`
+fn money_operand(constraint: &Constraint) -> &str {
+    match constraint.left_variable.split_once(['%', ' ']) {
+        Some((dividend, _)) => dividend.trim(),
+        None => &constraint.left_variable,
+    }
+}
+
+/// `-12.345` as `(-12345, 3)`; `None` for anything but a plain decimal
+fn parse_amount(value: &str) -> Option<(i128, u32)> {
+    let value = value.trim();
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let valid = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() || !valid(whole) || !valid(fraction) || whole.len() + fraction.len() > MAX_DIGITS {
+        return None;
+    }
+    let mantissa: i128 = format!("{}{}", whole, fraction).parse().ok()?;
+    Some((if negative { -mantissa } else { mantissa }, fraction.len() as u32))
+}
+
+fn for_each_simple(tree: &CompoundConstraint, visit: &mut impl FnMut(&Constraint)) {
+    match tree {
+        CompoundConstraint::Simple(constraint) => visit(constraint),
+        CompoundConstraint::And(parts)
+        | CompoundConstraint::Or(parts)
+        | CompoundConstraint::ExactlyOne(parts)
+        | CompoundConstraint::AtMostOne(parts) => parts.iter().for_each(|part| for_each_simple(part, visit)),
+        CompoundConstraint::Not(inner) => for_each_simple(inner, visit),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            for_each_simple(antecedent, visit);
+            for_each_simple(consequent, visit);
+        }
+        CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => {}
+    }
+}
+
+fn map_simple(
+    tree: &CompoundConstraint,
+    f: &impl Fn(&Constraint) -> Result<Constraint, MoneyError>,
+) -> Result<CompoundConstraint, MoneyError> {
+    let all = |parts: &[CompoundConstraint]| -> Result<Vec<_>, MoneyError> {
+        parts.iter().map(|part| map_simple(part, f)).collect()
+    };
+    Ok(match tree {
+        CompoundConstraint::Simple(constraint) => CompoundConstraint::Simple(f(constraint)?),
+        CompoundConstraint::And(parts) => CompoundConstraint::And(all(parts)?),
+        CompoundConstraint::Or(parts) => CompoundConstraint::Or(all(parts)?),
+        CompoundConstraint::ExactlyOne(parts) => CompoundConstraint::ExactlyOne(all(parts)?),
+        CompoundConstraint::AtMostOne(parts) => CompoundConstraint::AtMostOne(all(parts)?),
+        CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(map_simple(inner, f)?)),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            CompoundConstraint::Implies(Box::new(map_simple(antecedent, f)?), Box::new(map_simple(consequent, f)?))
+        }
+        CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => tree.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::ConstraintOperator;
+
+    fn compare(left: &str, operator: ConstraintOperator, right: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: left.to_string(),
+            operator,
+            right_value: right.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_rounding_and_lowering() {
+        let ties = [25, 35, -25];
+        let rounded = |mode: RoundingMode| ties.map(|tenths| mode.divide(tenths, 10));
+        assert_eq!(rounded(RoundingMode::HalfEven), [2, 4, -2]);
+        assert_eq!(rounded(RoundingMode::HalfUp), [3, 4, -3]);
+        assert_eq!(rounded(RoundingMode::Down), [2, 3, -2]);
+        assert_eq!(rounded(RoundingMode::Up), [3, 4, -3]);
+        assert_eq!(rounded(RoundingMode::Floor), [2, 3, -3]);
+        assert_eq!(rounded(RoundingMode::Ceiling), [3, 4, -2]);
+        assert_eq!(RoundingMode::HalfEven.divide(26, 10), 3);
+
+        let usd = Currency::iso("usd").unwrap();
+        let jpy = Currency::iso("JPY").unwrap();
+        assert_eq!(jpy.minor_units, 0);
+        assert!(matches!(Currency::iso("XYZ"), Err(MoneyError::UnknownCurrency(_))));
+        assert!(Currency::new("PTS", 0).is_ok() && Currency::new("POINTS", 0).is_err());
+
+        let mut model = MoneyModel::new(RoundingMode::HalfUp);
+        model.add_field("amount", usd.clone()).add_field("balance", usd.clone()).add_field("fee", jpy);
+        assert_eq!(model.minor_units(&usd, "10.005"), Ok(1001));
+        assert_eq!(model.minor_units(&usd, "-3"), Ok(-300));
+        assert!(model.minor_units(&usd, "ten").is_err());
+
+        let tree = CompoundConstraint::And(vec![
+            compare("amount", ConstraintOperator::GreaterThan, "0.5"),
+            compare("amount", ConstraintOperator::LessThanOrEqual, "balance"),
+            compare("amount % 0.05", ConstraintOperator::Equal, "0"),
+            compare("count", ConstraintOperator::LessThan, "3"),
+        ]);
+        assert_eq!(
+            model.lower(&tree).unwrap().to_string(),
+            "(amount > 50 AND amount <= balance AND amount % 5 == 0 AND count < 3)"
+        );
+
+        let mixed = compare("fee", ConstraintOperator::LessThan, "amount");
+        let mismatches = model.mismatches(&CompoundConstraint::Not(Box::new(mixed.clone())));
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!((mismatches[0].left_currency.as_str(), mismatches[0].right_currency.as_str()), ("JPY", "USD"));
+        assert!(matches!(model.lower(&mixed), Err(MoneyError::CurrencyMismatch(_))));
+    }
+}
@@ -0,0 +1,72 @@
+//! Currency mismatches as a verification pass
+
+use crate::MoneyModel;
+use crucible_core::{CompoundConstraint, Diagnostic};
+use crucible_verification::{Level, PassContext, VerificationPass, VerificationResult};
+
+/// A constraint compares amounts in different currencies
+pub const CURRENCY_MISMATCH: &str = "M001";
+
+/// Reports every comparison between amounts in different currencies as an
+/// error; register it with `PassManager::register`
+pub struct CurrencyCheck {
+    model: MoneyModel,
+}
+
+impl CurrencyCheck {
+    pub fn new(model: MoneyModel) -> Self {
+        Self { model }
+    }
+}
+
+impl VerificationPass for CurrencyCheck {
+    fn name(&self) -> &str {
+        "currency-mismatch"
+    }
+
+    fn check(
+        &self,
+        tree: &CompoundConstraint,
+        _context: &PassContext<'_>,
+    ) -> VerificationResult<Vec<(Level, Diagnostic)>> {
+        Ok(self
+            .model
+            .mismatches(tree)
+            .into_iter()
+            .map(|mismatch| {
+                let diagnostic = Diagnostic::new(CURRENCY_MISMATCH, mismatch.to_string())
+                    .with_arg("left", &mismatch.left)
+                    .with_arg("left_currency", &mismatch.left_currency)
+                    .with_arg("right", &mismatch.right)
+                    .with_arg("right_currency", &mismatch.right_currency);
+                (Level::Error, diagnostic)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Currency, RoundingMode};
+    use crucible_core::{Constraint, ConstraintOperator};
+    use crucible_verification::{PassManager, Z3Verifier};
+
+    #[test]
+    fn test_currency_check() {
+        let mut model = MoneyModel::new(RoundingMode::HalfEven);
+        model.add_field("price", Currency::iso("EUR").unwrap()).add_field("budget", Currency::iso("USD").unwrap());
+        let mut passes = PassManager::new();
+        passes.register(CurrencyCheck::new(model));
+
+        let tree = CompoundConstraint::Simple(Constraint {
+            left_variable: "price".to_string(),
+            operator: ConstraintOperator::LessThanOrEqual,
+            right_value: "budget".to_string(),
+        });
+        let findings = passes.run(&Z3Verifier::new(), None, &[tree]);
+        assert_eq!(findings.len(), 1);
+        assert_eq!((findings[0].level, findings[0].diagnostic.code.as_str()), (Level::Error, CURRENCY_MISMATCH));
+        assert_eq!(findings[0].diagnostic.args["right_currency"], "USD");
+    }
+}
@@ -0,0 +1,281 @@
+//! Crucible Node - native bindings for server-side JavaScript
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! The parser, verifier and code generator as a Node addon, for backends
+//! that would otherwise run the WebAssembly build: native code, no wasm
+//! memory limit, and verification and generation run on the libuv thread
+//! pool, so they return promises and never block the event loop. `napi
+//! build` writes the TypeScript declarations for the result objects.
+//!
+//! ```text
+//! const crucible = require("@crucible-engine/node");
+//!
+//! crucible.parse(spec);                           // Requirement[]
+//! await crucible.verify(spec, schemaJson);        // VerificationResult[]
+//! await crucible.generate(spec, "ts");            // GeneratedFile[]
+//! ```
+//!
+//! A spec the pipeline rejects throws an `Error` with the `stage`, `code`,
+//! `span` and `args` of the `CrucibleError`. `verify` needs the
+//! `verification` feature, which the package build enables.
+
+use crucible_codegen::{CodeGenerator, CodegenOptions, TargetLanguage};
+use crucible_core::{CompoundConstraint, CrucibleConfig, CrucibleError, Schema};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+#[cfg(feature = "verification")]
+use std::collections::HashMap;
+
+/// One requirement of a spec
+#[napi(object)]
+pub struct Requirement {
+    /// 1-based line in the spec
+    pub line: u32,
+    pub subject: String,
+    pub modal_verb: String,
+    pub action: String,
+    pub object: String,
+    /// The constraints as text, e.g. `(balance >= amount AND amount > 0)`
+    pub constraint: Option<String>,
+    /// The constraint tree in its JSON form, definitions inlined
+    pub tree: Option<serde_json::Value>,
+}
+
+/// What Z3 found about a requirement
+#[cfg(feature = "verification")]
+#[napi(string_enum = "lowercase")]
+pub enum Verdict {
+    Sat,
+    Unsat,
+    /// Does not fit the schema
+    Invalid,
+    /// The solver gave up or could not translate the constraints
+    Unknown,
+    /// The requirement has no constraints
+    None,
+}
+
+#[cfg(feature = "verification")]
+#[napi(object)]
+pub struct VerificationResult {
+    pub line: u32,
+    pub subject: String,
+    pub verdict: Verdict,
+    /// The solver's reason for anything but `sat`
+    pub detail: Option<String>,
+    /// Values satisfying the constraints, for `sat`
+    pub model: Option<HashMap<String, String>>,
+}
+
+/// A file `crucible watch` would write for a spec
+#[napi(object)]
+pub struct GeneratedFile {
+    pub path: String,
+    pub contents: String,
+}
+
+/// `error` as a JS `Error` carrying its stage, code, span and arguments
+fn throw(env: &Env, error: &CrucibleError) -> Error {
+    let build = || -> Result<Error> {
+        let mut object = env.create_error(Error::from_reason(error.to_string()))?;
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(error) {
+            // `message` stays the JS error's own, with the stage and code in front
+            for (name, value) in fields.into_iter().filter(|(name, _)| name != "message") {
+                object.set_named_property(&name, value)?;
+            }
+        }
+        Ok(Error::from(object.into_unknown()))
+    };
+    build().unwrap_or_else(|e| e)
+}
+
+/// Each requirement of a spec with its constraint tree, definitions inlined
+fn requirements(
+    text: &str,
+) -> std::result::Result<Vec<(crucible_parser::Requirement, Option<CompoundConstraint>)>, CrucibleError> {
+    let ast = crucible_parser::parse(text)?;
+    let definitions = ast.definition_table()?;
+    ast.requirements
+        .into_iter()
+        .map(|requirement| {
+            let tree = match requirement.compound() {
+                Some(compound) => Some(definitions.inline(&compound)?),
+                None => None,
+            };
+            Ok((requirement, tree))
+        })
+        .collect()
+}
+
+fn schema_of(schema_json: Option<&str>) -> std::result::Result<Option<Schema>, CrucibleError> {
+    Ok(schema_json.map(serde_json::from_str).transpose()?)
+}
+
+/// The requirements of a spec
+#[napi]
+pub fn parse(env: Env, text: String) -> Result<Vec<Requirement>> {
+    let requirements = requirements(&text).map_err(|e| throw(&env, &e))?;
+    Ok(requirements
+        .into_iter()
+        .map(|(requirement, tree)| Requirement {
+            line: requirement.line as u32,
+            subject: requirement.subject,
+            modal_verb: requirement.modal_verb,
+            action: requirement.action.verb.to_string(),
+            object: requirement.action.object,
+            constraint: tree.as_ref().map(ToString::to_string),
+            tree: tree.and_then(|tree| serde_json::to_value(tree).ok()),
+        })
+        .collect())
+}
+
+/// Checks a spec with Z3 on the thread pool
+#[cfg(feature = "verification")]
+pub struct Verify {
+    text: String,
+    schema: Option<Schema>,
+}
+
+#[cfg(feature = "verification")]
+impl Task for Verify {
+    type Output = std::result::Result<Vec<VerificationResult>, CrucibleError>;
+    type JsValue = Vec<VerificationResult>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        use crucible_verification::{VerificationError, Z3Verifier};
+
+        let requirements = match requirements(&self.text) {
+            Ok(requirements) => requirements,
+            Err(e) => return Ok(Err(e)),
+        };
+        // One per task: the verifier stays on the thread that made it
+        let verifier = Z3Verifier::new();
+        Ok(Ok(requirements
+            .into_iter()
+            .map(|(requirement, tree)| {
+                let result = tree.map(|tree| match &self.schema {
+                    Some(schema) => verifier.verify_with_schema(&tree, schema),
+                    None => verifier.verify_compound_constraints(&tree),
+                });
+                let (verdict, detail, model) = match result {
+                    None => (Verdict::None, None, None),
+                    Some(Ok(output)) => (Verdict::Sat, None, output.model),
+                    Some(Err(VerificationError::Unsatisfiable(reason))) => (Verdict::Unsat, Some(reason), None),
+                    Some(Err(e @ VerificationError::InvalidConstraint(_))) => {
+                        (Verdict::Invalid, Some(e.to_string()), None)
+                    }
+                    Some(Err(e)) => (Verdict::Unknown, Some(e.to_string()), None),
+                };
+                VerificationResult {
+                    line: requirement.line as u32,
+                    subject: requirement.subject,
+                    verdict,
+                    detail,
+                    model,
+                }
+            })
+            .collect()))
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        output.map_err(|e| throw(&env, &e))
+    }
+}
+
+/// Check every requirement of a spec with Z3, against a Schema (JSON) if given
+#[cfg(feature = "verification")]
+#[napi(ts_return_type = "Promise<VerificationResult[]>")]
+pub fn verify(env: Env, text: String, schema_json: Option<String>) -> Result<AsyncTask<Verify>> {
+    let schema = schema_of(schema_json.as_deref()).map_err(|e| throw(&env, &e))?;
+    Ok(AsyncTask::new(Verify { text, schema }))
+}
+
+/// Generates validators on the thread pool
+pub struct Generate {
+    text: String,
+    language: TargetLanguage,
+    schema: Option<Schema>,
+}
+
+impl Generate {
+    fn files(&self) -> std::result::Result<Vec<GeneratedFile>, CrucibleError> {
+        let config = CrucibleConfig::default();
+        let mut files = Vec::new();
+        for tree in requirements(&self.text)?.into_iter().filter_map(|(_, tree)| tree) {
+            let options = CodegenOptions {
+                func_name: format!("{}{}", config.naming.file_prefix, tree.canonical_hash()),
+                ..CodegenOptions::from_config(&config)
+            };
+            let output = match &self.schema {
+                Some(schema) => {
+                    CodeGenerator.generate_with_schema_and_options(&tree, schema, self.language, &options)?
+                }
+                None => CodeGenerator.generate_with_options(&tree, self.language, &options)?,
+            };
+            files.push(GeneratedFile {
+                path: format!("{}.{}", options.func_name, self.language.file_extension()),
+                contents: output.code,
+            });
+            files.extend(output.files.into_iter().map(|file| GeneratedFile {
+                path: file.path,
+                contents: file.contents,
+            }));
+        }
+        Ok(files)
+    }
+}
+
+impl Task for Generate {
+    type Output = std::result::Result<Vec<GeneratedFile>, CrucibleError>;
+    type JsValue = Vec<GeneratedFile>;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Ok(self.files())
+    }
+
+    fn resolve(&mut self, env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        output.map_err(|e| throw(&env, &e))
+    }
+}
+
+/// Validators for every requirement of a spec in `language` (`rust`, `ts`,
+/// `python`, `sol`, ...), typed by a Schema (JSON) if given
+#[napi(ts_return_type = "Promise<GeneratedFile[]>")]
+pub fn generate(env: Env, text: String, language: String, schema_json: Option<String>) -> Result<AsyncTask<Generate>> {
+    let language = TargetLanguage::from_name(&language)
+        .ok_or_else(|| Error::new(Status::InvalidArg, format!("unknown language {}", language)))?;
+    let schema = schema_of(schema_json.as_deref()).map_err(|e| throw(&env, &e))?;
+    Ok(AsyncTask::new(Generate { text, language, schema }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_task() {
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("balance".to_string(), crucible_core::DataType::Uint64, None);
+        let task = |schema: &Schema| Generate {
+            text: "User can withdraw money if balance >= amount\n".to_string(),
+            language: TargetLanguage::TypeScript,
+            schema: Some(schema.clone()),
+        };
+
+        let error = task(&schema).files().err().unwrap();
+        assert_eq!(error.code(), "S001");
+        schema.add_field("amount".to_string(), crucible_core::DataType::Uint64, None);
+        let files = task(&schema).files().unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].path.starts_with("requirement_") && files[0].path.ends_with(".ts"));
+
+        let schema_json = serde_json::to_string(&schema).unwrap();
+        assert_eq!(schema_of(Some(&schema_json)).unwrap().unwrap().fields.len(), 2);
+        assert_eq!(schema_of(Some("{")).unwrap_err().code(), "P003");
+    }
+}
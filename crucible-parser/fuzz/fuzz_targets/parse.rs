@@ -0,0 +1,34 @@
+//! Fuzz target: `crucible_parser::parse` on arbitrary text
+//!
+//! Requirements reach the parser straight from API requests, so no input may
+//! panic it or make it hang. Besides English, every input also goes through
+//! the German and Spanish lexicons, which rewrite the text before parsing.
+//!
+//! ```text
+//! cargo +nightly fuzz run parse fuzz/corpus/parse -- -timeout=2
+//! ```
+
+#![no_main]
+
+use crucible_parser::{extract_state_machines, parse, parse_with_language, Lang};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(ast) = parse(text) {
+        // Everything derived from a successful parse must be total too
+        let _ = ast.definition_table();
+        let _ = extract_state_machines(&ast);
+        for requirement in &ast.requirements {
+            let _ = requirement.transition();
+            if let Some(constraint) = &requirement.constraint {
+                let _ = constraint.to_compound();
+            }
+        }
+    }
+    let _ = parse_with_language(text, Lang::De);
+    let _ = parse_with_language(text, Lang::Es);
+});
@@ -10,16 +10,33 @@ module.exports = grammar({
   ],
   
   word: $ => $.identifier,
+
+  // `id % 10 == 0` vs the arithmetic expression `id % 10`;
+  // `x is a multiple of 3` vs `x is 0 if member else 5`
+  conflicts: $ => [
+    [$.left_expression, $.modulo_term],
+    [$.left_expression, $.divisibility],
+  ],
   
   rules: {
     // Root: A requirements file contains one or more requirements
     source_file: $ => seq(
-      repeat($.requirement),
+      repeat(choice($.definition, $.requirement, $.bdd_requirement)),
       optional($.newline)
     ),
+
+    // Named predicate, referenced by name from later requirements:
+    //   sufficient_funds := balance >= amount
+    definition: $ => seq(
+      field('name', $.identifier),
+      ':=',
+      $.constraint_expression,
+      $.newline
+    ),
     
     // A single requirement line
     requirement: $ => seq(
+      optional($.kind_marker),
       $.subject,
       $.modal_verb,
       $.action,
@@ -28,6 +45,47 @@ module.exports = grammar({
       $.newline
     ),
     
+    // Given/When/Then requirement (BDD phrasing), one clause per line:
+    //   Given <precondition> [And <precondition>]...
+    //   When <subject> <action>
+    //   Then <postcondition> [And <postcondition>]...
+    bdd_requirement: $ => seq(
+      $.given_clause,
+      $.when_clause,
+      $.then_clause
+    ),
+
+    // Given: Preconditions that hold before the action
+    given_clause: $ => seq(
+      'Given',
+      $.constraint_expression,
+      repeat($.and_clause),
+      $.newline
+    ),
+
+    // When: The triggering action
+    when_clause: $ => seq(
+      'When',
+      $.subject,
+      $.action,
+      $.newline
+    ),
+
+    // Then: Postconditions that must hold after the action
+    then_clause: $ => seq(
+      'Then',
+      $.constraint_expression,
+      repeat($.and_clause),
+      $.newline
+    ),
+
+    // And: Continuation of the preceding Given/Then clause on the next line
+    and_clause: $ => seq(
+      $.newline,
+      'And',
+      $.constraint_expression
+    ),
+
     // Subject: Who or what the requirement applies to
     subject: $ => choice(
       'User',
@@ -39,6 +97,14 @@ module.exports = grammar({
       $.identifier
     ),
     
+    // Kind marker: separates environment assumptions and invariants from guarantees
+    kind_marker: $ => choice(
+      'Assuming',
+      'Assume',
+      'Invariant:',
+      'Always'
+    ),
+
     // Modal verb: Indicates obligation or capability
     modal_verb: $ => choice(
       'can',
@@ -46,13 +112,14 @@ module.exports = grammar({
       'should',
       'shall',
       'will',
-      'may'
+      'may',
+      seq('is', 'guaranteed', 'to')
     ),
     
-    // Action: What the subject can/must do
+    // Action: What the subject can/must do; intransitive verbs (`User can pay`) have no object
     action: $ => seq(
       $.verb,
-      $.object,
+      optional($.object),
       optional($.preposition_phrase)
     ),
     
@@ -117,8 +184,15 @@ module.exports = grammar({
     // Constraint expression: Can be comparison or logical expression
     constraint_expression: $ => choice(
       $.comparison,
+      $.conditional_comparison,
+      $.range_comparison,
+      $.divisibility,
+      $.bit_test,
+      $.uniqueness,
       $.logical_expression,
-      $.arithmetic_expression
+      $.arithmetic_expression,
+      // A bare name refers to a definition: `if sufficient_funds`
+      $.variable
     ),
     
     // Comparison: Binary comparison operations
@@ -128,12 +202,73 @@ module.exports = grammar({
       $.right_expression
     ),
     
-    // Logical expression: AND/OR combinations
+    // Comparison with a conditional value: `fee is 0 if member else 5`,
+    // `fee == 0 if tier >= 3 else 2 if vip else 5`
+    conditional_comparison: $ => prec(3, seq(
+      $.left_expression,
+      choice($.comparison_operator, 'is'),
+      $.conditional_value
+    )),
+    conditional_value: $ => prec.right(seq(
+      field('then', $.right_expression),
+      'if',
+      field('condition', choice($.comparison, $.variable)),
+      'else',
+      field('else', choice($.conditional_value, $.right_expression))
+    )),
+    
+    // Range: `amount between 1 and 100`, `score strictly between 0 and 10`;
+    // binds tighter than the logical `and`
+    range_comparison: $ => prec(2, seq(
+      $.left_expression,
+      optional('strictly'),
+      'between',
+      $.right_expression,
+      'and',
+      $.right_expression
+    )),
+    
+    // Divisibility: `amount is a multiple of 100`, `id is not a multiple of batch`
+    divisibility: $ => seq(
+      $.variable,
+      'is',
+      optional('not'),
+      'a',
+      'multiple',
+      'of',
+      choice($.variable, $.number)
+    ),
+    
+    // Single bit: `flags has bit 3 set`, `flags has bit 0 clear`
+    bit_test: $ => seq(
+      $.variable,
+      'has',
+      'bit',
+      $.number,
+      choice('set', 'clear')
+    ),
+    
+    // Uniqueness: `transaction_ids are unique` (the elements of a list),
+    // `sender, receiver are distinct` (the fields)
+    uniqueness: $ => seq(
+      field('field', $.variable),
+      repeat(seq(',', field('field', $.variable))),
+      'are',
+      choice('unique', 'distinct')
+    ),
+    
+    // Logical expression: AND/OR combinations, negation and implication
+    // (`where if amount > 1000 then approvals >= 2`); `not` binds tightest,
+    // then `and`, then `or`, and chains group to the left
     logical_expression: $ => choice(
-      seq($.expression, 'and', $.expression),
-      seq($.expression, 'or', $.expression),
-      seq('not', $.expression)
+      prec.left(2, seq($.expression, 'and', $.expression)),
+      prec.left(1, seq($.expression, 'or', $.expression)),
+      prec(3, seq('not', $.expression)),
+      prec.right(seq('if', $.expression, 'then', $.expression))
     ),
+
+    // Explicit grouping: `(a > 0 or b > 0) and c > 0`
+    parenthesized_expression: $ => seq('(', $.expression, ')'),
     
     // Arithmetic expression: Basic math operations
     arithmetic_expression: $ => seq(
@@ -143,11 +278,51 @@ module.exports = grammar({
     ),
     
     // Left/right expressions for comparisons
-    left_expression: $ => $.variable,
-    right_expression: $ => choice($.variable, $.number, $.string),
+    left_expression: $ => choice($.variable, $.modulo_term, $.bitwise_term, $.function_call, $.aggregate),
+    right_expression: $ => choice($.variable, $.number, $.string, $.arithmetic_term, $.function_call, $.aggregate),
+
+    // Call of a built-in on either side: `fee == max(min_fee, amount * rate)`, `abs(delta) <= 5`
+    function_call: $ => seq(
+      field('function', choice('min', 'max', 'abs', 'sum', 'count', 'average')),
+      '(',
+      $.call_argument,
+      repeat(seq(',', $.call_argument)),
+      ')'
+    ),
+    call_argument: $ => choice($.variable, $.number, $.arithmetic_term, $.function_call, $.aggregate),
+
+    // Aggregate of a list field, spelled out: `total == sum of line_amounts`
+    aggregate: $ => seq(
+      field('function', choice('sum', 'count', 'average')),
+      'of',
+      field('list', $.variable)
+    ),
+
+    // Arithmetic term on the right of a comparison: `balance - amount`
+    arithmetic_term: $ => prec.left(1, seq(
+      choice($.variable, $.number),
+      $.arithmetic_operator,
+      choice($.variable, $.number)
+    )),
     
-    // Variable: A named variable in the constraint
-    variable: $ => $.identifier,
+    // Remainder on the left of a comparison: `id % 10 == 0`, `id mod 10 == 0`
+    modulo_term: $ => seq(
+      $.variable,
+      choice('%', 'mod'),
+      choice($.variable, $.number)
+    ),
+
+    // Mask or shift on the left of a comparison: `flags & 0x8 != 0`,
+    // `mode & 0b1111 == 2`, `word >> 4 == 1`
+    bitwise_term: $ => seq(
+      $.variable,
+      choice('&', '|', '^', '<<', '>>'),
+      choice($.variable, $.number, $.bit_pattern)
+    ),
+
+    // Variable: A named variable in the constraint; a trailing prime
+    // (`balance'`) names its value after the action
+    variable: $ => seq($.identifier, optional("'")),
     
     // Comparison operators
     comparison_operator: $ => choice(
@@ -170,7 +345,7 @@ module.exports = grammar({
     ),
     
     // Arithmetic operators
-    arithmetic_operator: $ => choice('+', '-', '*', '/', '%'),
+    arithmetic_operator: $ => choice('+', '-', '*', '/', '%', 'mod'),
     
     // Preposition phrase: Additional context
     preposition_phrase: $ => seq(
@@ -223,6 +398,9 @@ module.exports = grammar({
     
     number: $ => /[0-9]+/,
     
+    // Hex or binary integer, written in masks: `0x0F`, `0b1000`
+    bit_pattern: $ => /0[xX][0-9a-fA-F]+|0[bB][01]+/,
+    
     whitespace: $ => /\s+/,
     
     newline: $ => '\n',
@@ -235,6 +413,13 @@ module.exports = grammar({
     // Expression used in logical expressions
     expression: $ => choice(
       $.comparison,
+      $.conditional_comparison,
+      $.range_comparison,
+      $.divisibility,
+      $.bit_test,
+      $.uniqueness,
+      $.logical_expression,
+      $.parenthesized_expression,
       $.variable,
       $.number
     )
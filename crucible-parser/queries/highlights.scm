@@ -0,0 +1,41 @@
+; Syntax highlighting for Crucible requirement files (*.req)
+
+(comment) @comment
+
+(subject) @type
+(kind_marker) @attribute
+(modal_verb) @keyword
+(verb) @function
+(preposition) @keyword
+
+(definition
+  name: (identifier) @function)
+
+[
+  "Given"
+  "When"
+  "Then"
+  "And"
+] @keyword
+
+[
+  "if"
+  "where"
+  "then"
+  "between"
+] @keyword.control
+
+[
+  "and"
+  "or"
+  "not"
+] @keyword.operator
+
+(comparison_operator) @operator
+(arithmetic_operator) @operator
+":=" @operator
+
+(variable (identifier) @variable)
+(number) @number
+(string) @string
+(escape_sequence) @string.escape
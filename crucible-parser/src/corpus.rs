@@ -0,0 +1,451 @@
+//! Corpus tests for the requirements grammar
+//!
+//! A corpus file holds sentences with what their Intent-AST must contain, in
+//! the layout of tree-sitter's own corpus tests:
+//!
+//! ```text
+//! ==================
+//! Withdraw guarded by the balance
+//! ==================
+//!
+//! User can withdraw money if balance >= amount
+//!
+//! ---
+//!
+//! requirements.0.subject = "User"
+//! requirements.0.condition.Atomic = {"left_variable": "balance", "operator": "GreaterEqual"}
+//! ```
+//!
+//! Each expectation is a dotted path into the AST as serialized to JSON
+//! (array elements by index) and a JSON snippet the value there must match;
+//! objects in the snippet match when the fields they list do. Attribute lines
+//! under the case name change how it runs: `:error` expects a syntax error
+//! and matches the snippets against `message`, `line` and `column`,
+//! `:lang de` parses German (or `es` Spanish) input and `:skip` parks a case.
+//!
+//! Running a corpus also records which grammar rules its parse trees use, so
+//! a grammar change can be checked against sentences for every rule it adds.
+
+use crate::{build_ast, check_syntax, get_language, syntax_tree, translate_to_canonical, Lang};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// A corpus file that cannot be read or does not follow the format
+#[derive(Debug, Error)]
+pub enum CorpusError {
+    #[error("{}: {source}", path.display())]
+    Io { path: PathBuf, source: std::io::Error },
+
+    #[error("{}:{line}: {message}", file.display())]
+    Syntax { file: PathBuf, line: usize, message: String },
+}
+
+/// `path = snippet` below a case's `---`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    pub path: String,
+    pub value: Value,
+    /// 1-based line in the corpus file
+    pub line: usize,
+}
+
+/// One input sentence, or several lines parsed together, and its expectations
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorpusCase {
+    pub name: String,
+    pub file: PathBuf,
+    /// 1-based line of the case header
+    pub line: usize,
+    pub input: String,
+    pub lang: Lang,
+    /// The input must be rejected
+    pub error: bool,
+    pub skip: bool,
+    pub expectations: Vec<Expectation>,
+}
+
+/// Corpus cases, in file order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Corpus {
+    pub cases: Vec<CorpusCase>,
+}
+
+/// A case that did not parse as expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseFailure {
+    pub name: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub problems: Vec<String>,
+}
+
+/// Outcome of running a corpus
+#[derive(Debug, Clone)]
+pub struct CorpusReport {
+    /// Cases run; skipped ones are not counted
+    pub cases: usize,
+    pub skipped: usize,
+    pub failures: Vec<CaseFailure>,
+    pub coverage: GrammarCoverage,
+}
+
+/// How often each named grammar rule occurs in the parse trees seen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrammarCoverage {
+    hits: BTreeMap<&'static str, usize>,
+}
+
+/// A line of three or more `mark`s separating the parts of a case
+fn is_rule(line: &str, mark: char) -> bool {
+    let line = line.trim_end();
+    line.len() >= 3 && line.chars().all(|c| c == mark)
+}
+
+impl Corpus {
+    /// The cases in `path`: a corpus file, or every `.txt` file of a directory
+    pub fn load(path: &Path) -> Result<Self, CorpusError> {
+        let io = |source| CorpusError::Io { path: path.to_path_buf(), source };
+        if !path.is_dir() {
+            let text = std::fs::read_to_string(path).map_err(io)?;
+            return Self::parse(&text, path);
+        }
+        let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+            .map_err(io)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()
+            .map_err(io)?;
+        files.retain(|file| file.extension().is_some_and(|extension| extension == "txt"));
+        files.sort();
+        let mut corpus = Self::default();
+        for file in files {
+            corpus.cases.extend(Self::load(&file)?.cases);
+        }
+        Ok(corpus)
+    }
+
+    /// The cases in `text`, the contents of corpus file `file`
+    pub fn parse(text: &str, file: impl Into<PathBuf>) -> Result<Self, CorpusError> {
+        let file = file.into();
+        let syntax = |line: usize, message: String| CorpusError::Syntax { file: file.clone(), line, message };
+        let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line)).peekable();
+        let mut cases = Vec::new();
+        while let Some((line, header)) = lines.find(|(_, line)| !line.trim().is_empty()) {
+            if !is_rule(header, '=') {
+                return Err(syntax(line, format!("expected a `===` case header, found `{}`", header.trim())));
+            }
+            let mut case = CorpusCase {
+                name: String::new(),
+                file: file.clone(),
+                line,
+                input: String::new(),
+                lang: Lang::En,
+                error: false,
+                skip: false,
+                expectations: Vec::new(),
+            };
+
+            let mut names = Vec::new();
+            loop {
+                let Some((number, text)) = lines.next() else {
+                    return Err(syntax(line, "case header is not closed by a `===` line".to_string()));
+                };
+                if is_rule(text, '=') {
+                    break;
+                }
+                match text.trim() {
+                    ":error" => case.error = true,
+                    ":skip" => case.skip = true,
+                    attribute if attribute.starts_with(":lang") => {
+                        case.lang = match attribute[":lang".len()..].trim() {
+                            "en" => Lang::En,
+                            "de" => Lang::De,
+                            "es" => Lang::Es,
+                            other => return Err(syntax(number, format!("unknown language `{}`", other))),
+                        };
+                    }
+                    attribute if attribute.starts_with(':') => {
+                        return Err(syntax(number, format!("unknown attribute `{}`", attribute)));
+                    }
+                    "" => {}
+                    name => names.push(name),
+                }
+            }
+            if names.is_empty() {
+                return Err(syntax(line, "case has no name".to_string()));
+            }
+            case.name = names.join(" ");
+
+            let mut input = Vec::new();
+            loop {
+                let Some((_, text)) = lines.next() else {
+                    return Err(syntax(line, format!("case `{}` has no `---` line", case.name)));
+                };
+                if is_rule(text, '-') {
+                    break;
+                }
+                input.push(text);
+            }
+            // Every requirement ends at a newline, the last one included
+            case.input = format!("{}\n", input.join("\n").trim());
+            if case.input.trim().is_empty() {
+                return Err(syntax(line, format!("case `{}` has no input", case.name)));
+            }
+
+            while let Some((number, text)) = lines.next_if(|(_, line)| !is_rule(line, '=')) {
+                if text.trim().is_empty() {
+                    continue;
+                }
+                let Some((path, value)) = text.split_once('=') else {
+                    return Err(syntax(number, format!("expected `path = snippet`, found `{}`", text.trim())));
+                };
+                let value = serde_json::from_str(value.trim())
+                    .map_err(|e| syntax(number, format!("snippet is not JSON: {}", e)))?;
+                case.expectations.push(Expectation { path: path.trim().to_string(), value, line: number });
+            }
+            cases.push(case);
+        }
+        Ok(Self { cases })
+    }
+
+    /// Run every case that is not skipped
+    pub fn run(&self) -> CorpusReport {
+        let mut report = CorpusReport { cases: 0, skipped: 0, failures: Vec::new(), coverage: GrammarCoverage::new() };
+        for case in &self.cases {
+            if case.skip {
+                report.skipped += 1;
+                continue;
+            }
+            report.cases += 1;
+            let problems = case.check(&mut report.coverage);
+            if !problems.is_empty() {
+                report.failures.push(CaseFailure {
+                    name: case.name.clone(),
+                    file: case.file.clone(),
+                    line: case.line,
+                    problems,
+                });
+            }
+        }
+        report
+    }
+}
+
+impl CorpusCase {
+    /// Parse the input, recording the rules it uses in `coverage`; the
+    /// expectations it does not meet
+    pub fn check(&self, coverage: &mut GrammarCoverage) -> Vec<String> {
+        let text = translate_to_canonical(&self.input, self.lang);
+        let tree = match syntax_tree(&text) {
+            Ok(tree) => tree,
+            Err(error) => return vec![error.to_string()],
+        };
+        coverage.record(tree.root_node());
+        let actual = match (check_syntax(&tree, &text), self.error) {
+            (Ok(()), false) => {
+                let mut ast = build_ast(&tree, &text);
+                ast.source_text = self.input.clone();
+                serde_json::to_value(&ast).expect("the Intent-AST serializes to JSON")
+            }
+            (Err(error), true) => {
+                serde_json::json!({ "message": error.message, "line": error.line, "column": error.column })
+            }
+            (Ok(()), true) => return vec!["parsed, but a syntax error was expected".to_string()],
+            (Err(error), false) => return vec![error.to_string()],
+        };
+        self.expectations.iter().filter_map(|expectation| expectation.check(&actual)).collect()
+    }
+}
+
+impl Expectation {
+    /// Why `actual` does not match, if it does not
+    fn check(&self, actual: &Value) -> Option<String> {
+        let found = self.path.split('.').try_fold(actual, |value, key| match value {
+            Value::Object(fields) => fields.get(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|index| items.get(index)),
+            _ => None,
+        });
+        match found {
+            None => Some(format!("line {}: `{}` is not in the AST", self.line, self.path)),
+            Some(found) if !matches(&self.value, found) => {
+                Some(format!("line {}: `{}` is {}, expected {}", self.line, self.path, found, self.value))
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+/// Whether `actual` has everything in `expected`; arrays match element by element
+fn matches(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            expected.iter().all(|(key, value)| actual.get(key).is_some_and(|found| matches(value, found)))
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len() && expected.iter().zip(actual).all(|(value, found)| matches(value, found))
+        }
+        _ => expected == actual,
+    }
+}
+
+impl CorpusReport {
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for CaseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "FAIL {}:{} {}", self.file.display(), self.line, self.name)?;
+        for problem in &self.problems {
+            writeln!(f, "  {}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for CorpusReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for failure in &self.failures {
+            write!(f, "{}", failure)?;
+        }
+        write!(f, "{} of {} case(s) passed", self.cases - self.failures.len(), self.cases)?;
+        if self.skipped > 0 {
+            write!(f, ", {} skipped", self.skipped)?;
+        }
+        writeln!(f)?;
+        write!(f, "{}", self.coverage)
+    }
+}
+
+impl GrammarCoverage {
+    /// Every named rule of the compiled grammar, none of them used yet
+    pub fn new() -> Self {
+        let language = get_language();
+        let hits = (0..language.node_kind_count())
+            .filter_map(|id| u16::try_from(id).ok())
+            .filter(|&id| language.node_kind_is_named(id) && language.node_kind_is_visible(id))
+            .filter_map(|id| language.node_kind_for_id(id))
+            .map(|rule| (rule, 0))
+            .collect();
+        Self { hits }
+    }
+
+    /// Count the rules of `node` and its descendants
+    pub fn record(&mut self, node: tree_sitter::Node) {
+        if let Some(hits) = self.hits.get_mut(node.kind()) {
+            *hits += 1;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.record(child);
+        }
+    }
+
+    /// Each rule with the number of nodes it produced
+    pub fn hits(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+        self.hits.iter().map(|(rule, hits)| (*rule, *hits))
+    }
+
+    /// Rules no parse tree used
+    pub fn missed(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.hits().filter(|(_, hits)| *hits == 0).map(|(rule, _)| rule)
+    }
+
+    /// Share of the rules used, from 0 to 1
+    pub fn ratio(&self) -> f64 {
+        match self.hits.len() {
+            0 => 1.0,
+            rules => (rules - self.missed().count()) as f64 / rules as f64,
+        }
+    }
+}
+
+impl Default for GrammarCoverage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for GrammarCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let missed: Vec<&str> = self.missed().collect();
+        let rules = self.hits.len();
+        writeln!(
+            f,
+            "grammar coverage: {} of {} rules ({:.1}%)",
+            rules - missed.len(),
+            rules,
+            self.ratio() * 100.0
+        )?;
+        if !missed.is_empty() {
+            writeln!(f, "not exercised: {}", missed.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corpus_format() {
+        let text = "\
+==========
+Guarded withdrawal
+==========
+
+User can withdraw money if balance >= amount
+
+---
+
+requirements.0.subject = \"User\"
+requirements.0.action = {\"verb\": \"Withdraw\"}
+
+==========
+Missing action
+:error
+==========
+User can
+---
+line = 1
+";
+        let corpus = Corpus::parse(text, "basic.txt").unwrap();
+        assert_eq!(corpus.cases.len(), 2);
+        let guarded = &corpus.cases[0];
+        assert_eq!((guarded.name.as_str(), guarded.line), ("Guarded withdrawal", 1));
+        assert_eq!(guarded.input, "User can withdraw money if balance >= amount\n");
+        assert_eq!(guarded.expectations[1].path, "requirements.0.action");
+        assert_eq!(guarded.expectations[1].line, 10);
+        assert!(corpus.cases[1].error && corpus.cases[1].expectations.len() == 1);
+
+        let invalid = Corpus::parse("===\nName\n===\nUser can\n---\nsubject: \"User\"\n", "bad.txt");
+        assert_eq!(invalid.unwrap_err().to_string(), "bad.txt:6: expected `path = snippet`, found `subject: \"User\"`");
+
+        // Snippets match the fields they list; paths index arrays
+        let ast = serde_json::json!({ "requirements": [{ "subject": "User", "modal_verb": "can" }] });
+        let expect = |path: &str, value: Value| Expectation { path: path.to_string(), value, line: 3 };
+        assert_eq!(expect("requirements.0", serde_json::json!({ "subject": "User" })).check(&ast), None);
+        assert_eq!(
+            expect("requirements.0.subject", serde_json::json!("Admin")).check(&ast),
+            Some("line 3: `requirements.0.subject` is \"User\", expected \"Admin\"".to_string())
+        );
+        assert!(expect("requirements.1", Value::Null).check(&ast).unwrap().contains("is not in the AST"));
+
+        let coverage = GrammarCoverage::new();
+        assert!(coverage.hits().any(|(rule, _)| rule == "requirement"));
+        assert_eq!(coverage.ratio(), 0.0);
+    }
+
+    /// The shipped corpus, `crucible-parser/corpus`
+    #[test]
+    fn test_grammar_corpus() {
+        let corpus = Corpus::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus")).unwrap();
+        assert!(!corpus.cases.is_empty());
+        let report = corpus.run();
+        assert!(report.is_success(), "{}", report);
+    }
+}
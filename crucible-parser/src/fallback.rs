@@ -0,0 +1,195 @@
+//! Fallback parsing for sentences outside the grammar
+//!
+//! The tree-sitter grammar stays the primary, deterministic parser. Lines it
+//! rejects can be handed to a `FallbackParser`, which proposes a rewrite in
+//! the controlled requirement syntax. The proposal is never trusted as-is:
+//! it is re-parsed by the grammar, every variable it mentions must occur in
+//! the original sentence, and the resulting requirement is marked
+//! `Provenance::MachineSuggested` so reviewers can tell it apart.
+//!
+//! An LLM-backed implementation is available behind the `llm-fallback` feature.
+
+use crate::{parse, IntentAst, ParseError, ParseResult, ParsedConstraint, Provenance};
+use thiserror::Error;
+
+/// Errors raised by a fallback parser
+#[derive(Debug, Error)]
+pub enum FallbackError {
+    #[error("Fallback request failed: {0}")]
+    Request(String),
+
+    #[error("Fallback returned no usable suggestion")]
+    NoSuggestion,
+}
+
+/// Proposes a grammar-conformant rewrite for a sentence the grammar rejected
+pub trait FallbackParser {
+    /// Rewrite `sentence` as one requirement line in the controlled syntax,
+    /// e.g. `User can withdraw money if balance >= amount`
+    fn suggest(&self, sentence: &str) -> Result<String, FallbackError>;
+
+    /// Identifies the suggester in `Provenance::MachineSuggested`
+    fn name(&self) -> String;
+}
+
+/// Parse `input` line by line, consulting `fallback` for lines the grammar rejects.
+///
+/// Fails with the line number of the first line that neither the grammar nor
+/// a verified suggestion can handle.
+pub fn parse_with_fallback(input: &str, fallback: &dyn FallbackParser) -> ParseResult {
+    let mut ast = IntentAst {
+        requirements: Vec::new(),
+        source_text: input.to_string(),
+        ambiguities: Vec::new(),
+        definitions: Vec::new(),
+    };
+
+    for (index, line) in input.lines().enumerate() {
+        let line_no = index + 1;
+        if line.trim().is_empty() || line.trim_start().starts_with("//") {
+            continue;
+        }
+
+        if let Ok(parsed) = parse(&format!("{}\n", line)) {
+            append(&mut ast, parsed);
+            continue;
+        }
+
+        let rejected = |message: String| ParseError {
+            message,
+            line: line_no,
+            column: 0,
+        };
+
+        let suggestion = fallback.suggest(line).map_err(|e| rejected(e.to_string()))?;
+        let suggestion = suggestion.trim();
+        let mut parsed = parse(&format!("{}\n", suggestion))
+            .map_err(|_| rejected(format!("Suggestion is not valid requirement syntax: {}", suggestion)))?;
+
+        for requirement in &mut parsed.requirements {
+            for constraint in [&requirement.condition, &requirement.constraint, &requirement.postcondition]
+                .into_iter()
+                .flatten()
+            {
+                if let Some(variable) = ungrounded_variable(constraint, line) {
+                    return Err(rejected(format!(
+                        "Suggestion introduces `{}`, which the sentence does not mention",
+                        variable
+                    )));
+                }
+            }
+            requirement.provenance = Provenance::MachineSuggested {
+                suggester: fallback.name(),
+                original: line.to_string(),
+            };
+        }
+        append(&mut ast, parsed);
+    }
+
+    Ok(ast)
+}
+
+fn append(ast: &mut IntentAst, parsed: IntentAst) {
+    let offset = ast.requirements.len();
+    ast.requirements.extend(parsed.requirements);
+    ast.definitions.extend(parsed.definitions);
+    ast.ambiguities.extend(parsed.ambiguities.into_iter().map(|mut a| {
+        a.requirement_index += offset;
+        a
+    }));
+}
+
+/// A variable in the candidate tree that never appears in the source sentence
+fn ungrounded_variable(constraint: &ParsedConstraint, sentence: &str) -> Option<String> {
+    let sentence = sentence.to_lowercase();
+    let grounded = |name: &str| {
+        name.parse::<f64>().is_ok()
+            || name
+                .split('_')
+                .all(|part| part.is_empty() || sentence.contains(&part.to_lowercase()))
+    };
+
+    match constraint {
+        ParsedConstraint::Atomic(c) => [&c.left_variable, &c.right_value]
+            .into_iter()
+            .find(|name| !grounded(name))
+            .cloned(),
+        ParsedConstraint::Reference(name) => Some(name.clone()).filter(|name| !grounded(name)),
+        ParsedConstraint::Distinct(fields) => fields.iter().find(|name| !grounded(name)).cloned(),
+        ParsedConstraint::Compound { left, right, .. } => ungrounded_variable(left, &sentence)
+            .or_else(|| right.as_ref().and_then(|r| ungrounded_variable(r, &sentence))),
+    }
+}
+
+#[cfg(feature = "llm-fallback")]
+pub use llm::LlmFallbackParser;
+
+#[cfg(feature = "llm-fallback")]
+mod llm {
+    use super::{FallbackError, FallbackParser};
+    use std::time::Duration;
+
+    const SYSTEM_PROMPT: &str = "Rewrite the user's requirement as ONE line in this grammar: \
+<Subject> <can|must|should|shall|will|may> <verb> <object> [<preposition> <noun>] \
+[if <comparison>] [where <comparison>], where a comparison is `variable op value` \
+joined by and/or. Use only words from the requirement. Reply with the line only.";
+
+    /// Calls an OpenAI-compatible chat completions endpoint
+    pub struct LlmFallbackParser {
+        /// Full URL, e.g. `https://api.openai.com/v1/chat/completions`
+        pub endpoint: String,
+        pub model: String,
+        pub api_key: Option<String>,
+        pub timeout: Duration,
+    }
+
+    impl LlmFallbackParser {
+        pub fn new(endpoint: &str, model: &str) -> Self {
+            Self {
+                endpoint: endpoint.to_string(),
+                model: model.to_string(),
+                api_key: None,
+                timeout: Duration::from_secs(30),
+            }
+        }
+
+        pub fn with_api_key(mut self, api_key: &str) -> Self {
+            self.api_key = Some(api_key.to_string());
+            self
+        }
+    }
+
+    impl FallbackParser for LlmFallbackParser {
+        fn suggest(&self, sentence: &str) -> Result<String, FallbackError> {
+            let body = serde_json::json!({
+                "model": self.model,
+                "temperature": 0,
+                "messages": [
+                    { "role": "system", "content": SYSTEM_PROMPT },
+                    { "role": "user", "content": sentence },
+                ],
+            });
+
+            let mut request = ureq::post(&self.endpoint).timeout(self.timeout);
+            if let Some(key) = &self.api_key {
+                request = request.set("Authorization", &format!("Bearer {}", key));
+            }
+
+            let response: serde_json::Value = request
+                .send_json(body)
+                .map_err(|e| FallbackError::Request(e.to_string()))?
+                .into_json()
+                .map_err(|e| FallbackError::Request(e.to_string()))?;
+
+            response["choices"][0]["message"]["content"]
+                .as_str()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .ok_or(FallbackError::NoSuggestion)
+        }
+
+        fn name(&self) -> String {
+            format!("llm:{}", self.model)
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Multi-language requirement input
+//!
+//! The tree-sitter grammar is English. German and Spanish requirements are
+//! mapped onto it by a per-language lexicon (subjects, modal verbs, verbs,
+//! prepositions, clause keywords, word operators) plus the word-order rules
+//! that differ from English, so `parse_with_language(text, Lang::De)` yields
+//! the same Intent-AST as the English sentence.
+//!
+//! Tokens that are not in the lexicon are domain variables; they are kept
+//! and folded to ASCII (`Größe` -> `Groesse`) to satisfy the identifier rule.
+
+use crucible_core::Locale;
+use serde::{Deserialize, Serialize};
+
+/// Input language of a requirements document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Lang {
+    #[default]
+    En,
+    De,
+    Es,
+}
+
+/// Diagnostics and verbalized constraints for a document come in its own language
+impl From<Lang> for Locale {
+    fn from(lang: Lang) -> Self {
+        match lang {
+            Lang::En => Locale::En,
+            Lang::De => Locale::De,
+            Lang::Es => Locale::Es,
+        }
+    }
+}
+
+/// Word list for one language; multi-word entries are matched before single words
+struct Lexicon {
+    /// Source phrase (lowercase) -> English grammar token; an empty target drops the phrase
+    entries: &'static [(&'static str, &'static str)],
+    /// Case-sensitive entries checked first (e.g. German `Wenn` = When vs `wenn` = if)
+    keywords: &'static [(&'static str, &'static str)],
+    /// The main verb closes the clause (German: "Benutzer kann Geld abheben")
+    verb_final: bool,
+}
+
+const ENGLISH_VERBS: &[&str] = &[
+    "create", "read", "update", "delete", "validate", "verify", "authenticate", "authorize", "encrypt",
+    "decrypt", "send", "receive", "store", "retrieve", "process", "calculate", "generate", "export",
+    "import", "withdraw", "deposit", "transfer", "login", "logout", "register",
+];
+
+const CLAUSE_KEYWORDS: &[&str] = &["if", "where"];
+
+const GERMAN: Lexicon = Lexicon {
+    keywords: &[("Angenommen", "Given"), ("Gegeben", "Given"), ("Wenn", "When"), ("Dann", "Then"), ("Und", "And")],
+    entries: &[
+        // subjects
+        ("benutzer", "User"), ("nutzer", "User"), ("system", "System"), ("administrator", "Admin"),
+        ("admin", "Admin"), ("anwendung", "Application"), ("dienst", "Service"), ("api", "API"),
+        // modal verbs
+        ("kann", "can"), ("muss", "must"), ("soll", "should"), ("sollte", "should"), ("wird", "will"),
+        ("darf", "may"),
+        // verbs
+        ("erstellen", "create"), ("lesen", "read"), ("aktualisieren", "update"), ("löschen", "delete"),
+        ("validieren", "validate"), ("prüfen", "validate"), ("verifizieren", "verify"),
+        ("authentifizieren", "authenticate"), ("autorisieren", "authorize"), ("verschlüsseln", "encrypt"),
+        ("entschlüsseln", "decrypt"), ("senden", "send"), ("empfangen", "receive"), ("speichern", "store"),
+        ("abrufen", "retrieve"), ("verarbeiten", "process"), ("berechnen", "calculate"),
+        ("generieren", "generate"), ("exportieren", "export"), ("importieren", "import"),
+        ("abheben", "withdraw"), ("einzahlen", "deposit"), ("überweisen", "transfer"),
+        ("anmelden", "login"), ("abmelden", "logout"), ("registrieren", "register"),
+        // clause keywords and connectives
+        ("wenn", "if"), ("falls", "if"), ("wobei", "where"), ("sofern", "where"), ("und", "and"),
+        ("oder", "or"), ("nicht", "not"),
+        // prepositions (with contracted articles)
+        ("von", "from"), ("vom", "from"), ("aus", "from"), ("zu", "to"), ("zum", "to"), ("zur", "to"),
+        ("an", "to"), ("in", "in"), ("im", "in"), ("auf", "on"), ("bei", "at"), ("durch", "by"),
+        ("mit", "with"), ("ohne", "without"), ("für", "for"), ("während", "during"), ("vor", "before"),
+        ("nach", "after"),
+        // word operators
+        ("größer als", "greater_than"), ("kleiner als", "less_than"), ("gleich", "equals"),
+        ("ungleich", "not_equals"), ("mindestens", "at_least"), ("höchstens", "at_most"),
+        ("enthält nicht", "does_not_contain"), ("enthält", "contains"), ("ist gesetzt", "is_set"),
+        ("echt zwischen", "strictly between"), ("zwischen", "between"),
+        ("ist nicht gesetzt", "is_not_set"),
+        // articles carry no meaning for the grammar
+        ("der", ""), ("die", ""), ("das", ""), ("den", ""), ("dem", ""), ("des", ""), ("ein", ""),
+        ("eine", ""), ("einen", ""), ("einem", ""),
+    ],
+    verb_final: true,
+};
+
+const SPANISH: Lexicon = Lexicon {
+    keywords: &[("Dado", "Given"), ("Dada", "Given"), ("Cuando", "When"), ("Entonces", "Then"), ("Y", "And")],
+    entries: &[
+        // subjects
+        ("usuario", "User"), ("sistema", "System"), ("administrador", "Admin"), ("aplicación", "Application"),
+        ("servicio", "Service"), ("api", "API"),
+        // modal verbs
+        ("puede", "can"), ("debe", "must"), ("debería", "should"), ("deberá", "shall"), ("podrá", "may"),
+        // verbs
+        ("crear", "create"), ("leer", "read"), ("actualizar", "update"), ("eliminar", "delete"),
+        ("borrar", "delete"), ("validar", "validate"), ("verificar", "verify"), ("autenticar", "authenticate"),
+        ("autorizar", "authorize"), ("cifrar", "encrypt"), ("descifrar", "decrypt"), ("enviar", "send"),
+        ("recibir", "receive"), ("almacenar", "store"), ("guardar", "store"), ("recuperar", "retrieve"),
+        ("procesar", "process"), ("calcular", "calculate"), ("generar", "generate"), ("exportar", "export"),
+        ("importar", "import"), ("retirar", "withdraw"), ("depositar", "deposit"), ("transferir", "transfer"),
+        ("iniciar sesión", "login"), ("cerrar sesión", "logout"), ("registrar", "register"),
+        // clause keywords and connectives
+        ("si", "if"), ("donde", "where"), ("cuando", "if"), ("y", "and"), ("o", "or"), ("no", "not"),
+        // prepositions (with contracted articles)
+        ("de", "from"), ("del", "from"), ("desde", "from"), ("a", "to"), ("al", "to"), ("hacia", "to"),
+        ("en", "in"), ("sobre", "on"), ("por", "by"), ("con", "with"), ("sin", "without"), ("para", "for"),
+        ("durante", "during"), ("antes de", "before"), ("después de", "after"),
+        // word operators
+        ("mayor que", "greater_than"), ("menor que", "less_than"), ("igual a", "equals"),
+        ("distinto de", "not_equals"), ("al menos", "at_least"), ("como máximo", "at_most"),
+        ("no contiene", "does_not_contain"), ("contiene", "contains"), ("está definido", "is_set"),
+        ("estrictamente entre", "strictly between"), ("entre", "between"),
+        ("no está definido", "is_not_set"),
+        // articles
+        ("el", ""), ("la", ""), ("los", ""), ("las", ""), ("un", ""), ("una", ""),
+    ],
+    verb_final: false,
+};
+
+fn lexicon(lang: Lang) -> Option<&'static Lexicon> {
+    match lang {
+        Lang::En => None,
+        Lang::De => Some(&GERMAN),
+        Lang::Es => Some(&SPANISH),
+    }
+}
+
+/// Rewrite `text` into the English phrasing the grammar accepts, line by line
+pub fn translate_to_canonical(text: &str, lang: Lang) -> String {
+    let Some(lexicon) = lexicon(lang) else {
+        return text.to_string();
+    };
+
+    let mut out: Vec<String> = text.lines().map(|line| translate_line(line, lexicon)).collect();
+    if text.ends_with('\n') {
+        out.push(String::new());
+    }
+    out.join("\n")
+}
+
+fn translate_line(line: &str, lexicon: &Lexicon) -> String {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut i = 0;
+
+    'tokens: while i < tokens.len() {
+        // Quoted strings, numbers and symbolic operators pass through untouched
+        let token = tokens[i];
+        if token.starts_with('"') || token.parse::<f64>().is_ok() || token.chars().all(|c| !c.is_alphabetic()) {
+            words.push(token.to_string());
+            i += 1;
+            continue;
+        }
+
+        // Scenario keywords only open a line
+        if i == 0 {
+            if let Some((_, target)) = lexicon.keywords.iter().find(|(source, _)| *source == token) {
+                words.push(target.to_string());
+                i += 1;
+                continue;
+            }
+        }
+
+        // Longest phrase first (up to three words)
+        for len in (1..=3.min(tokens.len() - i)).rev() {
+            let phrase = tokens[i..i + len].join(" ").to_lowercase();
+            if let Some((_, target)) = lexicon.entries.iter().find(|(source, _)| *source == phrase) {
+                if !target.is_empty() {
+                    words.push(target.to_string());
+                }
+                i += len;
+                continue 'tokens;
+            }
+        }
+
+        words.push(ascii_fold(token));
+        i += 1;
+    }
+
+    if lexicon.verb_final {
+        move_verb_after_modal(&mut words);
+    }
+    words.join(" ")
+}
+
+/// "User can Geld from Konto withdraw if ..." -> "User can withdraw Geld from Konto if ..."
+/// (in a `When` clause the verb moves right after the subject instead)
+fn move_verb_after_modal(words: &mut Vec<String>) {
+    const MODALS: &[&str] = &["can", "must", "should", "shall", "will", "may"];
+
+    let modal = match words.iter().position(|w| MODALS.contains(&w.as_str())) {
+        Some(modal) => modal,
+        None if words.len() > 2 && words[0] == "When" => 1,
+        None => return,
+    };
+    let clause_end = words
+        .iter()
+        .skip(modal + 1)
+        .position(|w| CLAUSE_KEYWORDS.contains(&w.as_str()))
+        .map_or(words.len(), |p| p + modal + 1);
+
+    if let Some(verb) = (modal + 1..clause_end).rev().find(|&i| ENGLISH_VERBS.contains(&words[i].as_str())) {
+        let verb = words.remove(verb);
+        words.insert(modal + 1, verb);
+    }
+}
+
+/// Fold non-ASCII letters so domain words remain valid identifiers
+fn ascii_fold(word: &str) -> String {
+    let mut out = String::with_capacity(word.len());
+    for c in word.chars() {
+        match c {
+            'ä' => out.push_str("ae"),
+            'ö' => out.push_str("oe"),
+            'ü' => out.push_str("ue"),
+            'Ä' => out.push_str("Ae"),
+            'Ö' => out.push_str("Oe"),
+            'Ü' => out.push_str("Ue"),
+            'ß' => out.push_str("ss"),
+            'á' | 'à' => out.push('a'),
+            'é' | 'è' => out.push('e'),
+            'í' => out.push('i'),
+            'ó' => out.push('o'),
+            'ú' => out.push('u'),
+            'ñ' => out.push('n'),
+            'Á' => out.push('A'),
+            'É' => out.push('E'),
+            'Í' => out.push('I'),
+            'Ó' => out.push('O'),
+            'Ú' => out.push('U'),
+            'Ñ' => out.push('N'),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_german_word_order_and_lexicon() {
+        let text = "Benutzer kann Geld vom Konto abheben wenn Kontostand >= Betrag";
+        assert_eq!(
+            translate_to_canonical(text, Lang::De),
+            "User can withdraw Geld from Konto if Kontostand >= Betrag"
+        );
+
+        let bdd = "Angenommen Größe mindestens 1\nWenn Benutzer Daten speichern\nDann Größe höchstens 10\n";
+        assert_eq!(
+            translate_to_canonical(bdd, Lang::De),
+            "Given Groesse at_least 1\nWhen User store Daten\nThen Groesse at_most 10\n"
+        );
+    }
+
+    #[test]
+    fn test_spanish_lexicon() {
+        let text = "Usuario puede retirar dinero de la cuenta si saldo mayor que 0 y monto <= saldo";
+        assert_eq!(
+            translate_to_canonical(text, Lang::Es),
+            "User can withdraw dinero from cuenta if saldo greater_than 0 and monto <= saldo"
+        );
+        assert_eq!(translate_to_canonical(text, Lang::En), text);
+    }
+}
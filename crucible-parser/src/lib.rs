@@ -9,17 +9,38 @@
 //! This module provides parsing functionality for natural language requirements,
 //! transforming them into an Intent-AST (Abstract Syntax Tree) for formal verification.
 
-use anyhow::{Context, Result, bail};
+use crucible_core::error::codes;
+use crucible_core::{CrucibleError, Diagnostic};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-/// Language binding for the Tree-Sitter requirements grammar
-mod language {
-    include!("src/tree_sitter/alloc.rs");
-    include!("src/tree_sitter/array.rs");
-    include!("src/tree_sitter/parser.rs");
+extern "C" {
+    fn tree_sitter_requirements() -> *const ();
 }
 
+/// The Tree-Sitter requirements grammar, compiled from `src/parser.c` by
+/// `build.rs`. Convert with `.into()` where a `tree_sitter::Language` is needed
+pub const LANGUAGE: tree_sitter_language::LanguageFn =
+    unsafe { tree_sitter_language::LanguageFn::from_raw(tree_sitter_requirements) };
+
+/// Syntax highlighting queries for editors (`queries/highlights.scm`)
+pub const HIGHLIGHTS_QUERY: &str = include_str!("../queries/highlights.scm");
+
+/// Node type metadata generated alongside the parser
+pub const NODE_TYPES: &str = include_str!("node-types.json");
+
+mod corpus;
+mod fallback;
+mod lang;
+mod state_machine;
+
+pub use corpus::{CaseFailure, Corpus, CorpusCase, CorpusError, CorpusReport, Expectation, GrammarCoverage};
+pub use fallback::{parse_with_fallback, FallbackError, FallbackParser};
+#[cfg(feature = "llm-fallback")]
+pub use fallback::LlmFallbackParser;
+pub use lang::{translate_to_canonical, Lang};
+pub use state_machine::extract_state_machines;
+
 /// Represents the type of action in a requirement
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ActionType {
@@ -84,6 +105,39 @@ impl fmt::Display for ActionType {
     }
 }
 
+impl From<&str> for ActionType {
+    fn from(verb: &str) -> Self {
+        match verb {
+            "create" => ActionType::Create,
+            "read" => ActionType::Read,
+            "update" => ActionType::Update,
+            "delete" => ActionType::Delete,
+            "validate" => ActionType::Validate,
+            "verify" => ActionType::Verify,
+            "authenticate" => ActionType::Authenticate,
+            "authorize" => ActionType::Authorize,
+            "encrypt" => ActionType::Encrypt,
+            "decrypt" => ActionType::Decrypt,
+            "send" => ActionType::Send,
+            "receive" => ActionType::Receive,
+            "store" => ActionType::Store,
+            "retrieve" => ActionType::Retrieve,
+            "process" => ActionType::Process,
+            "calculate" => ActionType::Calculate,
+            "generate" => ActionType::Generate,
+            "export" => ActionType::Export,
+            "import" => ActionType::Import,
+            "withdraw" => ActionType::Withdraw,
+            "deposit" => ActionType::Deposit,
+            "transfer" => ActionType::Transfer,
+            "login" => ActionType::Login,
+            "logout" => ActionType::Logout,
+            "register" => ActionType::Register,
+            other => ActionType::Other(other.to_string()),
+        }
+    }
+}
+
 /// Represents a constraint operator
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConstraintOperator {
@@ -105,12 +159,18 @@ pub enum LogicalOperator {
     And,
     Or,
     Not,
+    /// `if left then right`
+    Implies,
 }
 
 /// Represents a parsed constraint (atomic or compound)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ParsedConstraint {
     Atomic(Constraint),
+    /// A named predicate introduced by a `name := ...` definition
+    Reference(String),
+    /// `sender, receiver are distinct`; one field is a list whose elements are
+    Distinct(Vec<String>),
     Compound {
         operator: LogicalOperator,
         left: Box<ParsedConstraint>,
@@ -120,6 +180,7 @@ pub enum ParsedConstraint {
 
 impl ConstraintOperator {
     /// Convert from string representation
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         match s {
             "==" | "equals" => ConstraintOperator::Equal,
@@ -137,6 +198,62 @@ impl ConstraintOperator {
     }
 }
 
+impl ParsedConstraint {
+    /// Convert to the core constraint tree used by the verifier and codegen.
+    ///
+    /// `None` when the tree uses an operator the core model lacks
+    /// (`is_set`, `is_not_set`).
+    pub fn to_compound(&self) -> Option<crucible_core::CompoundConstraint> {
+        use crucible_core::CompoundConstraint;
+
+        match self {
+            ParsedConstraint::Atomic(c) => {
+                let operator = match c.operator {
+                    ConstraintOperator::Equal => crucible_core::ConstraintOperator::Equal,
+                    ConstraintOperator::NotEqual => crucible_core::ConstraintOperator::NotEqual,
+                    ConstraintOperator::GreaterThan => crucible_core::ConstraintOperator::GreaterThan,
+                    ConstraintOperator::LessThan => crucible_core::ConstraintOperator::LessThan,
+                    ConstraintOperator::GreaterEqual => crucible_core::ConstraintOperator::GreaterThanOrEqual,
+                    ConstraintOperator::LessEqual => crucible_core::ConstraintOperator::LessThanOrEqual,
+                    ConstraintOperator::Contains => crucible_core::ConstraintOperator::Contains,
+                    ConstraintOperator::DoesNotContain => crucible_core::ConstraintOperator::DoesNotContain,
+                    _ => return None,
+                };
+                Some(CompoundConstraint::Simple(crucible_core::Constraint {
+                    left_variable: c.left_variable.clone(),
+                    operator,
+                    right_value: c.right_value.clone(),
+                }))
+            }
+            ParsedConstraint::Reference(name) => Some(CompoundConstraint::Named(name.clone())),
+            ParsedConstraint::Distinct(fields) => Some(CompoundConstraint::Distinct(fields.clone())),
+            ParsedConstraint::Compound { operator, left, right } => {
+                let left = left.to_compound()?;
+                match (operator, right) {
+                    (LogicalOperator::Not, _) => Some(CompoundConstraint::Not(Box::new(left))),
+                    (LogicalOperator::And, Some(right)) => {
+                        Some(CompoundConstraint::And(vec![left, right.to_compound()?]))
+                    }
+                    (LogicalOperator::Or, Some(right)) => Some(CompoundConstraint::Or(vec![left, right.to_compound()?])),
+                    (LogicalOperator::Implies, Some(right)) => Some(CompoundConstraint::implies(left, right.to_compound()?)),
+                    (_, None) => Some(left),
+                }
+            }
+        }
+    }
+
+    fn mentions_primed(&self) -> bool {
+        match self {
+            ParsedConstraint::Atomic(c) => c.left_variable.ends_with('\'') || c.right_value.contains('\''),
+            ParsedConstraint::Reference(_) => false,
+            ParsedConstraint::Distinct(fields) => fields.iter().any(|field| field.ends_with('\'')),
+            ParsedConstraint::Compound { left, right, .. } => {
+                left.mentions_primed() || right.as_ref().is_some_and(|r| r.mentions_primed())
+            }
+        }
+    }
+}
+
 /// Represents a parsed constraint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Constraint {
@@ -154,14 +271,137 @@ pub struct Action {
     pub target: Option<String>,
 }
 
+/// How a requirement was phrased in the source text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RequirementStyle {
+    /// `<Subject> <modal> <action> [if ...] [where ...]`
+    #[default]
+    NaturalLanguage,
+    /// `Given ... When ... Then ...`
+    GivenWhenThen,
+}
+
+/// Where a requirement's structure came from
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Provenance {
+    /// Parsed deterministically by the grammar
+    #[default]
+    Grammar,
+    /// Proposed by a `FallbackParser`, then re-parsed and grounded against the sentence
+    MachineSuggested { suggester: String, original: String },
+}
+
 /// Represents a parsed requirement
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Requirement {
     pub subject: String,
     pub modal_verb: String,
     pub action: Action,
+    /// Precondition (`if ...`, or the `Given` clauses)
     pub condition: Option<ParsedConstraint>,
     pub constraint: Option<ParsedConstraint>,
+    /// Postcondition that must hold after the action (the `Then` clauses)
+    #[serde(default)]
+    pub postcondition: Option<ParsedConstraint>,
+    #[serde(default)]
+    pub style: RequirementStyle,
+    #[serde(default)]
+    pub provenance: Provenance,
+    /// Hard or soft, from the modal verb (`shall`/`should`/`may`)
+    #[serde(default)]
+    pub severity: crucible_core::Severity,
+    /// Assumption, guarantee or invariant, from the kind marker or "is guaranteed to"
+    #[serde(default)]
+    pub kind: crucible_core::RequirementKind,
+    /// 1-based line the requirement starts on in the parsed text
+    #[serde(default)]
+    pub line: usize,
+}
+
+impl Requirement {
+    /// Precondition and constraint as one tree; `None` when neither converts
+    /// to the core model
+    pub fn compound(&self) -> Option<crucible_core::CompoundConstraint> {
+        let mut parts: Vec<crucible_core::CompoundConstraint> = [&self.condition, &self.constraint]
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.to_compound())
+            .collect();
+        match parts.len() {
+            0 => None,
+            1 => parts.pop(),
+            _ => Some(crucible_core::CompoundConstraint::And(parts)),
+        }
+    }
+
+    /// The state transition this requirement describes, if its postcondition
+    /// (`Then ...`, or `where ...` in the sentence form) uses primed variables
+    /// such as `balance' == balance - amount`.
+    pub fn transition(&self) -> Option<crucible_core::StateTransition> {
+        let post = self
+            .postcondition
+            .as_ref()
+            .or(self.constraint.as_ref())
+            .filter(|p| p.mentions_primed())?;
+        let pre = match &self.condition {
+            Some(condition) => condition.to_compound()?,
+            None => crucible_core::CompoundConstraint::And(Vec::new()),
+        };
+
+        Some(crucible_core::StateTransition::new(
+            &self.action.verb.to_string(),
+            pre,
+            flatten_and(post.to_compound()?),
+        ))
+    }
+}
+
+/// `And(a, And(b, c))` -> `And(a, b, c)`, so every update is a top-level conjunct
+fn flatten_and(compound: crucible_core::CompoundConstraint) -> crucible_core::CompoundConstraint {
+    use crucible_core::CompoundConstraint;
+
+    match compound {
+        CompoundConstraint::And(parts) => CompoundConstraint::And(
+            parts
+                .into_iter()
+                .flat_map(|part| match flatten_and(part) {
+                    CompoundConstraint::And(inner) => inner,
+                    other => vec![other],
+                })
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Why a requirement admits more than one reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AmbiguityKind {
+    /// `if ...` may guard the whole requirement or only the preposition target
+    ConditionAttachment,
+    /// Mixed `and`/`or` without parentheses
+    OperatorPrecedence,
+    /// A comparison operand names the action's object: the argument or a stored value?
+    ComparisonTarget,
+}
+
+/// One candidate reading of an ambiguous requirement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interpretation {
+    pub description: String,
+    pub constraint: ParsedConstraint,
+}
+
+/// Diagnostic asking the author to choose between competing interpretations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ambiguity {
+    pub kind: AmbiguityKind,
+    /// Index into `IntentAst::requirements`
+    pub requirement_index: usize,
+    pub line: usize,
+    /// The source text that can be read more than one way
+    pub span: String,
+    pub interpretations: Vec<Interpretation>,
 }
 
 /// Represents the Intent-AST (Abstract Syntax Tree) for requirements
@@ -169,6 +409,66 @@ pub struct Requirement {
 pub struct IntentAst {
     pub requirements: Vec<Requirement>,
     pub source_text: String,
+    /// Readings the parser could not decide between; the first interpretation
+    /// of each is the one stored on the requirement
+    #[serde(default)]
+    pub ambiguities: Vec<Ambiguity>,
+    /// Named predicates (`name := ...`), in source order
+    #[serde(default)]
+    pub definitions: Vec<Definition>,
+}
+
+/// A named predicate: `sufficient_funds := balance >= amount`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Definition {
+    pub name: String,
+    pub constraint: ParsedConstraint,
+    /// 1-based source line
+    pub line: usize,
+}
+
+impl IntentAst {
+    /// The definitions as the core table that `CompoundConstraint::Named`
+    /// references resolve against. Definitions using an operator the core
+    /// model lacks are left out, so references to them stay undefined
+    pub fn definition_table(&self) -> Result<crucible_core::Definitions, crucible_core::DefinitionError> {
+        let mut table = crucible_core::Definitions::new();
+        for definition in &self.definitions {
+            if let Some(predicate) = definition.constraint.to_compound() {
+                table.define(&definition.name, predicate)?;
+            }
+        }
+        Ok(table)
+    }
+}
+
+/// Parse-and-store for the core Intent-AST, which cannot depend on the parser
+pub trait AddParsedRequirement {
+    /// Parse `text` and store it as one requirement with its constraint tree
+    /// attached. A trailing tolerance is stored as metadata and left out of
+    /// the tree. Nothing is stored when the text does not parse.
+    fn add_requirement_parsed(&mut self, text: &str) -> Result<&crucible_core::Requirement, ParseError>;
+}
+
+impl AddParsedRequirement for crucible_core::IntentAst {
+    fn add_requirement_parsed(&mut self, text: &str) -> Result<&crucible_core::Requirement, ParseError> {
+        let (hard, tolerance) = crucible_core::split_tolerance(text);
+        let parsed = match tolerance {
+            // A requirement ends at its newline; keep the one the clause was cut from
+            Some(_) => parse(&format!("{}{}", hard, &text[text.trim_end().len()..]))?,
+            None => parse(text)?,
+        };
+        let mut trees: Vec<crucible_core::CompoundConstraint> =
+            parsed.requirements.iter().filter_map(Requirement::compound).collect();
+        let tree = match trees.len() {
+            0 | 1 => trees.pop(),
+            _ => Some(crucible_core::CompoundConstraint::And(trees)),
+        };
+        self.add_constrained_requirement(text.to_string(), tree);
+        let requirement = self.requirements.last_mut().unwrap();
+        requirement.tolerance = tolerance;
+        Ok(requirement)
+    }
 }
 
 /// Represents parsing errors
@@ -185,6 +485,26 @@ impl fmt::Display for ParseError {
     }
 }
 
+impl From<ParseError> for CrucibleError {
+    /// Line 0 marks a failure of the parser itself rather than of the input
+    fn from(error: ParseError) -> Self {
+        let message = &error.message;
+        if error.line == 0 {
+            return CrucibleError::Parse(Diagnostic::new(codes::PARSER, message).with_arg("detail", message));
+        }
+        let diagnostic = Diagnostic::new(codes::SYNTAX, message).at(error.line, error.column);
+        // Syntax errors from the grammar can be localized; other messages stay as written
+        let syntax = match message.strip_prefix("Expected ") {
+            Some(token) => Some(("expected", token)),
+            None => message.strip_prefix("Unexpected `").and_then(|m| m.strip_suffix('`')).map(|t| ("unexpected", t)),
+        };
+        CrucibleError::Parse(match syntax {
+            Some((problem, token)) => diagnostic.with_arg("problem", problem).with_arg("token", token),
+            None => diagnostic,
+        })
+    }
+}
+
 /// Result type for parsing operations
 pub type ParseResult = Result<IntentAst, ParseError>;
 
@@ -207,44 +527,380 @@ pub type ParseResult = Result<IntentAst, ParseError>;
 /// let result = parse(input);
 /// assert!(result.is_ok());
 /// ```
+#[tracing::instrument(skip_all, fields(bytes = input.len()))]
 pub fn parse(input: &str) -> ParseResult {
-    use tree_sitter::{Parser, Tree};
+    let text = terminated(input);
+    let tree = syntax_tree(&text)?;
+    check_syntax(&tree, &text)?;
+    let mut ast = build_ast(&tree, &text);
+    ast.source_text = input.to_string();
+    Ok(ast)
+}
+
+/// `input` with the newline the grammar ends every requirement with; the
+/// last line of a file need not have one
+fn terminated(input: &str) -> std::borrow::Cow<'_, str> {
+    match input.is_empty() || input.ends_with('\n') {
+        true => input.into(),
+        false => format!("{}\n", input).into(),
+    }
+}
+
+/// The concrete syntax tree of `input`, syntax errors included
+fn syntax_tree(input: &str) -> Result<tree_sitter::Tree, ParseError> {
+    use tree_sitter::Parser;
     
     // Create a new parser
     let mut parser = Parser::new();
     
     // Set the language to our requirements grammar
-    parser.set_language(language::LANGUAGE)
-        .context("Failed to set language for parser")?;
+    parser.set_language(&LANGUAGE.into()).map_err(|e| ParseError {
+        message: format!("Failed to set language for parser: {}", e),
+        line: 0,
+        column: 0,
+    })?;
     
     // Parse the input
-    let tree = parser.parse(input.as_bytes(), None)
-        .context("Failed to parse input")?;
-    
-    // Check for errors
-    if tree.root_node().has_error() {
-        bail!("Parse error in input");
-    }
-    
-    // Extract requirements from the tree
-    let requirements = extract_requirements(&tree, input);
+    parser.parse(input.as_bytes(), None).ok_or_else(|| ParseError {
+        message: "Failed to parse input".to_string(),
+        line: 0,
+        column: 0,
+    })
+}
+
+/// The first syntax error in `tree`, if any
+fn check_syntax(tree: &tree_sitter::Tree, input: &str) -> Result<(), ParseError> {
+    let Some(error) = first_error(tree.root_node()) else {
+        return Ok(());
+    };
+    let position = error.start_position();
+    let message = if error.is_missing() {
+        format!("Expected {}", error.kind())
+    } else {
+        format!("Unexpected `{}`", &input[error.byte_range()])
+    };
+    tracing::debug!(line = position.row + 1, column = position.column + 1, %message, "syntax error");
+    Err(ParseError {
+        message,
+        line: position.row + 1,
+        column: position.column + 1,
+    })
+}
+
+/// The Intent-AST of a tree without syntax errors
+fn build_ast(tree: &tree_sitter::Tree, input: &str) -> IntentAst {
+    let requirements = extract_requirements(tree, input);
+    let ambiguities = detect_ambiguities(tree, input, &requirements);
+    let definitions = extract_definitions(tree, input);
+    tracing::debug!(
+        requirements = requirements.len(),
+        ambiguities = ambiguities.len(),
+        definitions = definitions.len(),
+        "parsed"
+    );
     
-    Ok(IntentAst {
+    IntentAst {
         requirements,
         source_text: input.to_string(),
-    })
+        ambiguities,
+        definitions,
+    }
+}
+
+/// Find requirements whose text supports more than one constraint tree
+fn detect_ambiguities(tree: &tree_sitter::Tree, source: &str, requirements: &[Requirement]) -> Vec<Ambiguity> {
+    let root = tree.root_node();
+    let mut ambiguities = Vec::new();
+    let mut index = 0;
+
+    for i in 0..root.child_count() {
+        let Some(node) = root.child(i) else { continue };
+        if node.kind() != "requirement" && node.kind() != "bdd_requirement" {
+            continue;
+        }
+        let Some(requirement) = requirements.get(index) else { break };
+        let line = node.start_position().row + 1;
+
+        for j in 0..node.child_count() {
+            let Some(child) = node.child(j) else { continue };
+            if child.kind() == "condition" || child.kind() == "constraint" {
+                let text = &source[child.byte_range()];
+                if let Some(interpretations) = precedence_interpretations(text) {
+                    ambiguities.push(Ambiguity {
+                        kind: AmbiguityKind::OperatorPrecedence,
+                        requirement_index: index,
+                        line,
+                        span: text.to_string(),
+                        interpretations,
+                    });
+                }
+            }
+        }
+
+        if let (Some(target), Some(condition)) = (&requirement.action.target, &requirement.condition) {
+            let qualified = qualify_variables(condition, target);
+            ambiguities.push(Ambiguity {
+                kind: AmbiguityKind::ConditionAttachment,
+                requirement_index: index,
+                line,
+                span: source[node.byte_range()].trim_end().to_string(),
+                interpretations: vec![
+                    Interpretation {
+                        description: "The condition guards the whole requirement".to_string(),
+                        constraint: condition.clone(),
+                    },
+                    Interpretation {
+                        description: format!("The condition restricts only \"{}\"", target),
+                        constraint: qualified,
+                    },
+                ],
+            });
+        }
+
+        for constraint in [&requirement.condition, &requirement.constraint].into_iter().flatten() {
+            let object = requirement.action.object.as_str();
+            if mentions_value(constraint, object) {
+                ambiguities.push(Ambiguity {
+                    kind: AmbiguityKind::ComparisonTarget,
+                    requirement_index: index,
+                    line,
+                    span: object.to_string(),
+                    interpretations: vec![
+                        Interpretation {
+                            description: format!("\"{}\" is a stored value", object),
+                            constraint: constraint.clone(),
+                        },
+                        Interpretation {
+                            description: format!("\"{}\" is the argument of \"{}\"", object, requirement.action.verb),
+                            constraint: rename_value(constraint, object, &format!("action.{}", object)),
+                        },
+                    ],
+                });
+            }
+        }
+
+        index += 1;
+    }
+
+    ambiguities
+}
+
+/// For `a and b or c` (no parentheses) return both groupings when they differ
+fn precedence_interpretations(text: &str) -> Option<Vec<Interpretation>> {
+    if text.contains('(') {
+        return None;
+    }
+
+    let tokens: Vec<&str> = text.split_whitespace().skip(1).collect(); // skip `if`/`where`
+    let mut operands = Vec::new();
+    for chunk in tokens.split(|t| *t == "and" || *t == "or") {
+        if chunk.len() != 3 {
+            return None;
+        }
+        operands.push(ParsedConstraint::Atomic(Constraint {
+            left_variable: chunk[0].to_string(),
+            operator: ConstraintOperator::from_str(chunk[1]),
+            right_value: chunk[2].to_string(),
+        }));
+    }
+    let connectives: Vec<LogicalOperator> = tokens
+        .iter()
+        .filter_map(|t| match *t {
+            "and" => Some(LogicalOperator::And),
+            "or" => Some(LogicalOperator::Or),
+            _ => None,
+        })
+        .collect();
+    if !(connectives.contains(&LogicalOperator::And) && connectives.contains(&LogicalOperator::Or)) {
+        return None;
+    }
+
+    let combine = |operator: LogicalOperator, left: ParsedConstraint, right: ParsedConstraint| ParsedConstraint::Compound {
+        operator,
+        left: Box::new(left),
+        right: Some(Box::new(right)),
+    };
+
+    // Reading 1: `and` binds tighter than `or`
+    let mut disjuncts = Vec::new();
+    let mut current = operands[0].clone();
+    for (op, operand) in connectives.iter().zip(operands.iter().skip(1)) {
+        if *op == LogicalOperator::And {
+            current = combine(LogicalOperator::And, current, operand.clone());
+        } else {
+            disjuncts.push(std::mem::replace(&mut current, operand.clone()));
+        }
+    }
+    disjuncts.push(current);
+    let mut conventional = disjuncts.remove(0);
+    for d in disjuncts {
+        conventional = combine(LogicalOperator::Or, conventional, d);
+    }
+
+    // Reading 2: strictly left to right, as the sentence is read
+    let mut sequential = operands[0].clone();
+    for (op, operand) in connectives.iter().zip(operands.iter().skip(1)) {
+        sequential = combine(op.clone(), sequential, operand.clone());
+    }
+
+    Some(vec![
+        Interpretation {
+            description: "`and` binds tighter than `or`".to_string(),
+            constraint: conventional,
+        },
+        Interpretation {
+            description: "Left to right, as written".to_string(),
+            constraint: sequential,
+        },
+    ])
+}
+
+/// Prefix every left-hand variable with the target noun (`balance` -> `account.balance`)
+fn qualify_variables(constraint: &ParsedConstraint, target: &str) -> ParsedConstraint {
+    let prefix = target.split_whitespace().filter(|w| *w != "the").collect::<Vec<_>>().join("_");
+    match constraint {
+        ParsedConstraint::Atomic(c) => ParsedConstraint::Atomic(Constraint {
+            left_variable: format!("{}.{}", prefix, c.left_variable),
+            ..c.clone()
+        }),
+        ParsedConstraint::Reference(_) => constraint.clone(),
+        ParsedConstraint::Distinct(fields) => {
+            ParsedConstraint::Distinct(fields.iter().map(|field| format!("{}.{}", prefix, field)).collect())
+        }
+        ParsedConstraint::Compound { operator, left, right } => ParsedConstraint::Compound {
+            operator: operator.clone(),
+            left: Box::new(qualify_variables(left, target)),
+            right: right.as_ref().map(|r| Box::new(qualify_variables(r, target))),
+        },
+    }
+}
+
+fn mentions_value(constraint: &ParsedConstraint, value: &str) -> bool {
+    match constraint {
+        ParsedConstraint::Atomic(c) => !value.is_empty() && c.right_value == value,
+        ParsedConstraint::Reference(_) | ParsedConstraint::Distinct(_) => false,
+        ParsedConstraint::Compound { left, right, .. } => {
+            mentions_value(left, value) || right.as_ref().is_some_and(|r| mentions_value(r, value))
+        }
+    }
+}
+
+fn rename_value(constraint: &ParsedConstraint, from: &str, to: &str) -> ParsedConstraint {
+    match constraint {
+        ParsedConstraint::Atomic(c) if c.right_value == from => ParsedConstraint::Atomic(Constraint {
+            right_value: to.to_string(),
+            ..c.clone()
+        }),
+        ParsedConstraint::Atomic(_) | ParsedConstraint::Reference(_) | ParsedConstraint::Distinct(_) => {
+            constraint.clone()
+        }
+        ParsedConstraint::Compound { operator, left, right } => ParsedConstraint::Compound {
+            operator: operator.clone(),
+            left: Box::new(rename_value(left, from, to)),
+            right: right.as_ref().map(|r| Box::new(rename_value(r, from, to))),
+        },
+    }
+}
+
+/// Parse requirements written in `lang`
+///
+/// Non-English input is mapped onto the English grammar through the language
+/// lexicon, so German or Spanish specs produce the same Intent-AST as their
+/// English equivalent. `source_text` keeps the original input.
+///
+/// # Example
+///
+/// ```
+/// use crucible_parser::{parse_with_language, Lang};
+///
+/// let ast = parse_with_language("Benutzer kann Geld abheben wenn Kontostand >= Betrag", Lang::De).unwrap();
+/// assert_eq!(ast.requirements[0].subject, "User");
+/// ```
+pub fn parse_with_language(input: &str, lang: Lang) -> ParseResult {
+    let canonical = translate_to_canonical(input, lang);
+    let mut ast = parse(&canonical)?;
+    ast.source_text = input.to_string();
+    Ok(ast)
+}
+
+/// Parse requirements and normalize every variable through the project glossary
+///
+/// "balance", "account_balance" and "users_balance" collapse to the glossary's
+/// canonical name, so the verifier sees one variable per concept.
+pub fn parse_with_glossary(input: &str, glossary: &crucible_core::Glossary) -> ParseResult {
+    let mut ast = parse(input)?;
+    for requirement in &mut ast.requirements {
+        for constraint in [
+            requirement.condition.as_mut(),
+            requirement.constraint.as_mut(),
+            requirement.postcondition.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            normalize_parsed_constraint(constraint, glossary);
+        }
+    }
+    for definition in &mut ast.definitions {
+        normalize_parsed_constraint(&mut definition.constraint, glossary);
+    }
+    Ok(ast)
+}
+
+/// Expand `@template(...)` lines from the project library, then parse.
+///
+/// Line numbers in errors refer to the expanded text only when a template
+/// body spans several lines; single-line templates keep them aligned.
+pub fn parse_with_templates(input: &str, templates: &crucible_core::TemplateLibrary) -> ParseResult {
+    let expanded = templates.expand(input).map_err(|e| ParseError {
+        message: e.to_string(),
+        line: match e {
+            crucible_core::TemplateError::UnknownTemplate { line, .. }
+            | crucible_core::TemplateError::Malformed { line, .. } => line,
+            _ => 0,
+        },
+        column: 0,
+    })?;
+    parse(&expanded)
+}
+
+/// Rename the variables of a parsed constraint tree to their canonical terms
+fn normalize_parsed_constraint(constraint: &mut ParsedConstraint, glossary: &crucible_core::Glossary) {
+    match constraint {
+        ParsedConstraint::Atomic(c) => {
+            if let Some(canonical) = glossary.canonicalize(&c.left_variable) {
+                c.left_variable = canonical.to_string();
+            }
+            if c.right_value.parse::<f64>().is_err() {
+                if let Some(canonical) = glossary.canonicalize(&c.right_value) {
+                    c.right_value = canonical.to_string();
+                }
+            }
+        }
+        ParsedConstraint::Reference(_) => {}
+        ParsedConstraint::Distinct(fields) => {
+            for field in fields {
+                if let Some(canonical) = glossary.canonicalize(field) {
+                    *field = canonical.to_string();
+                }
+            }
+        }
+        ParsedConstraint::Compound { left, right, .. } => {
+            normalize_parsed_constraint(left, glossary);
+            if let Some(right) = right {
+                normalize_parsed_constraint(right, glossary);
+            }
+        }
+    }
 }
 
 /// Extract requirements from the parse tree
-fn extract_requirements(tree: &Tree, source: &str) -> Vec<Requirement> {
+fn extract_requirements(tree: &tree_sitter::Tree, source: &str) -> Vec<Requirement> {
     let mut requirements = Vec::new();
     
     // Get the root node
     let root = tree.root_node();
     
-    // Iterate through all requirement nodes
-    let mut cursor = tree.walk();
-    
     // Process each requirement
     for i in 0..root.child_count() {
         if let Some(child) = root.child(i) {
@@ -252,6 +908,10 @@ fn extract_requirements(tree: &Tree, source: &str) -> Vec<Requirement> {
                 if let Some(req) = parse_requirement_node(child, source) {
                     requirements.push(req);
                 }
+            } else if child.kind() == "bdd_requirement" {
+                if let Some(req) = parse_bdd_requirement_node(child, source) {
+                    requirements.push(req);
+                }
             }
         }
     }
@@ -259,6 +919,23 @@ fn extract_requirements(tree: &Tree, source: &str) -> Vec<Requirement> {
     requirements
 }
 
+/// Extract `name := constraint` definitions from the parse tree
+fn extract_definitions(tree: &tree_sitter::Tree, source: &str) -> Vec<Definition> {
+    let root = tree.root_node();
+    (0..root.child_count())
+        .filter_map(|i| root.child(i))
+        .filter(|node| node.kind() == "definition")
+        .filter_map(|node| {
+            let name = node.child_by_field_name("name")?;
+            Some(Definition {
+                name: source[name.byte_range()].to_string(),
+                constraint: parse_constraint_expression(node, source)?,
+                line: node.start_position().row + 1,
+            })
+        })
+        .collect()
+}
+
 /// Parse a single requirement node
 fn parse_requirement_node(node: tree_sitter::Node, source: &str) -> Option<Requirement> {
     // This is a simplified parser - a full implementation would recursively
@@ -269,6 +946,8 @@ fn parse_requirement_node(node: tree_sitter::Node, source: &str) -> Option<Requi
     let action = extract_action(node, source)?;
     let condition = extract_condition(node, source);
     let constraint = extract_constraint(node, source);
+    let severity = crucible_core::Severity::from_modal(&modal_verb);
+    let kind = crucible_core::RequirementKind::from_text(&source[node.byte_range()]);
     
     Some(Requirement {
         subject,
@@ -276,9 +955,86 @@ fn parse_requirement_node(node: tree_sitter::Node, source: &str) -> Option<Requi
         action,
         condition,
         constraint,
+        postcondition: None,
+        style: RequirementStyle::NaturalLanguage,
+        provenance: Provenance::Grammar,
+        severity,
+        kind,
+        line: node.start_position().row + 1,
+    })
+}
+
+/// Parse a Given/When/Then requirement node
+///
+/// Given → precondition (`condition`), When → subject + action,
+/// Then → `postcondition`, so both phrasings produce the same Intent-AST shape.
+fn parse_bdd_requirement_node(node: tree_sitter::Node, source: &str) -> Option<Requirement> {
+    let mut subject = None;
+    let mut action = None;
+    let mut condition = None;
+    let mut postcondition = None;
+
+    for i in 0..node.child_count() {
+        if let Some(child) = node.child(i) {
+            match child.kind() {
+                "given_clause" => condition = parse_bdd_clause(child, source),
+                "when_clause" => {
+                    subject = extract_subject(child, source);
+                    action = extract_action(child, source);
+                }
+                "then_clause" => postcondition = parse_bdd_clause(child, source),
+                _ => {}
+            }
+        }
+    }
+
+    Some(Requirement {
+        subject: subject?,
+        // A scenario states required behaviour
+        modal_verb: "shall".to_string(),
+        action: action?,
+        condition,
+        constraint: None,
+        postcondition,
+        style: RequirementStyle::GivenWhenThen,
+        provenance: Provenance::Grammar,
+        severity: crucible_core::Severity::MustHold,
+        kind: crucible_core::RequirementKind::Guarantee,
+        line: node.start_position().row + 1,
     })
 }
 
+/// Parse a Given/Then clause, folding its `And` continuations into a conjunction
+fn parse_bdd_clause(node: tree_sitter::Node, source: &str) -> Option<ParsedConstraint> {
+    let mut result: Option<ParsedConstraint> = None;
+
+    for i in 0..node.child_count() {
+        if let Some(child) = node.child(i) {
+            let parsed = match child.kind() {
+                "constraint_expression" => parse_constraint_expression(child, source),
+                "and_clause" => (0..child.child_count())
+                    .filter_map(|j| child.child(j))
+                    .find(|c| c.kind() == "constraint_expression")
+                    .and_then(|expression| parse_constraint_expression(expression, source)),
+                _ => None,
+            };
+
+            if let Some(parsed) = parsed {
+                result = Some(match result {
+                    None => parsed,
+                    Some(previous) => ParsedConstraint::Compound {
+                        operator: LogicalOperator::And,
+                        left: Box::new(previous),
+                        right: Some(Box::new(parsed)),
+                    },
+                });
+            }
+        }
+    }
+
+    result
+}
+
 /// Extract the subject from a requirement node
 fn extract_subject(node: tree_sitter::Node, source: &str) -> Option<String> {
     for i in 0..node.child_count() {
@@ -327,7 +1083,7 @@ fn parse_action_node(node: tree_sitter::Node, source: &str) -> Option<Action> {
             match child.kind() {
                 "verb" => {
                     let verb_str = source[child.byte_range()].to_string();
-                    verb = Some(ActionType::from_str(&verb_str));
+                    verb = Some(ActionType::from(verb_str.as_str()));
                 }
                 "object" => {
                     object = Some(source[child.byte_range()].to_string());
@@ -394,12 +1150,30 @@ fn parse_constraint_expression(node: tree_sitter::Node, source: &str) -> Option<
                 "comparison" => {
                     return parse_comparison_node(child, source).map(ParsedConstraint::Atomic);
                 }
+                "conditional_comparison" => {
+                    return parse_conditional_comparison_node(child, source).map(ParsedConstraint::Atomic);
+                }
+                "range_comparison" => {
+                    return parse_range_node(child, source);
+                }
+                "divisibility" => {
+                    return parse_divisibility_node(child, source).map(ParsedConstraint::Atomic);
+                }
+                "bit_test" => {
+                    return parse_bit_test_node(child, source).map(ParsedConstraint::Atomic);
+                }
+                "uniqueness" => {
+                    return Some(parse_uniqueness_node(child, source));
+                }
                 "logical_expression" => {
                     return parse_logical_expression_node(child, source);
                 }
                 "arithmetic_expression" => {
                     return parse_arithmetic_node(child, source).map(ParsedConstraint::Atomic);
                 }
+                "variable" => {
+                    return Some(ParsedConstraint::Reference(source[child.byte_range()].trim().to_string()));
+                }
                 _ => {}
             }
         }
@@ -416,28 +1190,12 @@ fn parse_comparison_node(node: tree_sitter::Node, source: &str) -> Option<Constr
     for k in 0..node.child_count() {
         if let Some(ggchild) = node.child(k) {
             match ggchild.kind() {
-                "left_expression" => {
-                    for l in 0..ggchild.child_count() {
-                        if let Some(gggchild) = ggchild.child(l) {
-                            if gggchild.kind() == "variable" {
-                                left_var = Some(source[gggchild.byte_range()].to_string());
-                            }
-                        }
-                    }
-                }
+                "left_expression" => left_var = parse_left_expression(ggchild, source),
                 "comparison_operator" => {
                     let op_str = source[ggchild.byte_range()].to_string();
-                    operator = Some(ConstraintOperator::from_str(&op_str.trim()));
-                }
-                "right_expression" => {
-                    for l in 0..ggchild.child_count() {
-                        if let Some(gggchild) = ggchild.child(l) {
-                            if gggchild.kind() == "variable" || gggchild.kind() == "number" {
-                                right_val = Some(source[gggchild.byte_range()].to_string());
-                            }
-                        }
-                    }
+                    operator = Some(ConstraintOperator::from_str(op_str.trim()));
                 }
+                "right_expression" => right_val = parse_right_expression(ggchild, source),
                 _ => {}
             }
         }
@@ -453,7 +1211,228 @@ fn parse_comparison_node(node: tree_sitter::Node, source: &str) -> Option<Constr
     }
 }
 
-/// Parse a logical expression node (and/or/not)
+/// The left operand of a comparison: a field, a remainder, a mask or a call
+fn parse_left_expression(node: tree_sitter::Node, source: &str) -> Option<String> {
+    let mut left_var = None;
+    for l in 0..node.child_count() {
+        if let Some(child) = node.child(l) {
+            match child.kind() {
+                "variable" => left_var = Some(source[child.byte_range()].to_string()),
+                // `id mod 10` is kept as `id % 10`
+                "modulo_term" => {
+                    left_var = crucible_core::Modulo::parse(&source[child.byte_range()]).map(|m| m.to_string());
+                }
+                // `flags&0x8` is kept as `flags & 0x8`
+                "bitwise_term" => {
+                    let parts: Vec<_> = (0..child.child_count())
+                        .filter_map(|k| child.child(k))
+                        .map(|part| source[part.byte_range()].trim())
+                        .collect();
+                    left_var = crucible_core::Bitwise::parse(&parts.join(" ")).map(|b| b.to_string());
+                }
+                "function_call" => left_var = parse_call_node(child, source),
+                "aggregate" => left_var = parse_aggregate_node(child, source),
+                _ => {}
+            }
+        }
+    }
+    left_var
+}
+
+/// The right operand of a comparison: a field, a number, `a op b` or a call
+fn parse_right_expression(node: tree_sitter::Node, source: &str) -> Option<String> {
+    let mut right_val = None;
+    for l in 0..node.child_count() {
+        if let Some(child) = node.child(l) {
+            if matches!(child.kind(), "variable" | "number" | "string" | "arithmetic_term") {
+                right_val = Some(source[child.byte_range()].to_string());
+            } else if child.kind() == "function_call" {
+                right_val = parse_call_node(child, source);
+            } else if child.kind() == "aggregate" {
+                right_val = parse_aggregate_node(child, source);
+            }
+        }
+    }
+    right_val
+}
+
+/// Parse `fee is 0 if member else 5`; `is` compares for equality
+fn parse_conditional_comparison_node(node: tree_sitter::Node, source: &str) -> Option<Constraint> {
+    let mut left_var = None;
+    let mut operator = None;
+    let mut right_val = None;
+
+    for k in 0..node.child_count() {
+        if let Some(child) = node.child(k) {
+            match child.kind() {
+                "left_expression" => left_var = parse_left_expression(child, source),
+                "comparison_operator" => {
+                    operator = Some(ConstraintOperator::from_str(source[child.byte_range()].trim()));
+                }
+                "is" => operator = Some(ConstraintOperator::Equal),
+                "conditional_value" => right_val = parse_conditional_node(child, source),
+                _ => {}
+            }
+        }
+    }
+
+    Some(Constraint {
+        left_variable: left_var?,
+        operator: operator?,
+        right_value: right_val?,
+    })
+}
+
+/// Parse `0 if tier>=3 else 5` into the text `crucible_core::Conditional`
+/// reads back, `0 if tier >= 3 else 5`
+fn parse_conditional_node(node: tree_sitter::Node, source: &str) -> Option<String> {
+    let then_value = parse_right_expression(node.child_by_field_name("then")?, source)?;
+    let condition = node.child_by_field_name("condition")?;
+    let condition = match condition.kind() {
+        "comparison" => ParsedConstraint::Atomic(parse_comparison_node(condition, source)?).to_compound()?.to_string(),
+        _ => source[condition.byte_range()].trim().to_string(),
+    };
+    let otherwise = node.child_by_field_name("else")?;
+    let else_value = match otherwise.kind() {
+        "conditional_value" => parse_conditional_node(otherwise, source)?,
+        _ => parse_right_expression(otherwise, source)?,
+    };
+    let text = format!("{} if {} else {}", then_value, condition, else_value);
+    crucible_core::Conditional::parse(&text).map(|conditional| conditional.to_string())
+}
+
+/// Parse `max(min_fee, amount*rate)` into the text `crucible_core::Call`
+/// reads back, `max(min_fee, amount * rate)`; `None` for a wrong arity
+fn parse_call_node(node: tree_sitter::Node, source: &str) -> Option<String> {
+    let function = source[node.child_by_field_name("function")?.byte_range()].to_string();
+    let mut args = Vec::new();
+    for k in 0..node.child_count() {
+        let Some(argument) = node.child(k).filter(|child| child.kind() == "call_argument") else {
+            continue;
+        };
+        let inner = argument.child(0)?;
+        args.push(match inner.kind() {
+            "function_call" => parse_call_node(inner, source)?,
+            "aggregate" => parse_aggregate_node(inner, source)?,
+            // Operands and operator spaced out, whatever the source did
+            "arithmetic_term" => (0..inner.child_count())
+                .filter_map(|l| inner.child(l))
+                .map(|part| source[part.byte_range()].trim())
+                .collect::<Vec<_>>()
+                .join(" "),
+            _ => source[inner.byte_range()].trim().to_string(),
+        });
+    }
+    crucible_core::Call::parse(&format!("{}({})", function, args.join(", "))).map(|call| call.to_string())
+}
+
+/// Parse `sum of line_amounts` into the call `sum(line_amounts)`
+fn parse_aggregate_node(node: tree_sitter::Node, source: &str) -> Option<String> {
+    let function = &source[node.child_by_field_name("function")?.byte_range()];
+    let list = source[node.child_by_field_name("list")?.byte_range()].trim();
+    crucible_core::Call::parse(&format!("{}({})", function, list)).map(|call| call.to_string())
+}
+
+/// Parse `x is [not] a multiple of n` into `x % n == 0` (or `!= 0`)
+fn parse_divisibility_node(node: tree_sitter::Node, source: &str) -> Option<Constraint> {
+    let mut operands = Vec::new();
+    let mut negated = false;
+
+    for k in 0..node.child_count() {
+        if let Some(child) = node.child(k) {
+            match child.kind() {
+                "variable" | "number" => operands.push(source[child.byte_range()].trim().to_string()),
+                "not" => negated = true,
+                _ => {}
+            }
+        }
+    }
+
+    let [variable, divisor] = <[String; 2]>::try_from(operands).ok()?;
+    Some(Constraint {
+        left_variable: crucible_core::Modulo::new(&variable, &divisor).to_string(),
+        operator: if negated { ConstraintOperator::NotEqual } else { ConstraintOperator::Equal },
+        right_value: "0".to_string(),
+    })
+}
+
+/// Parse `flags has bit 3 set` (or `clear`) into `flags & 0x8 != 0` (or `== 0`)
+fn parse_bit_test_node(node: tree_sitter::Node, source: &str) -> Option<Constraint> {
+    let mut field = None;
+    let mut bit = None;
+    let mut set = false;
+
+    for k in 0..node.child_count() {
+        if let Some(child) = node.child(k) {
+            match child.kind() {
+                "variable" => field = Some(source[child.byte_range()].trim()),
+                "number" => bit = source[child.byte_range()].trim().parse::<u32>().ok(),
+                "set" => set = true,
+                _ => {}
+            }
+        }
+    }
+
+    let bit = bit.filter(|bit| *bit < 64)?;
+    let mask = format!("{:#x}", 1u64 << bit);
+    Some(Constraint {
+        left_variable: crucible_core::Bitwise::new(field?, crucible_core::BitwiseOperator::And, &mask).to_string(),
+        operator: if set { ConstraintOperator::NotEqual } else { ConstraintOperator::Equal },
+        right_value: "0".to_string(),
+    })
+}
+
+/// Parse `ids are unique` or `sender, receiver are distinct` into the fields
+fn parse_uniqueness_node(node: tree_sitter::Node, source: &str) -> ParsedConstraint {
+    let mut cursor = node.walk();
+    let fields = node
+        .children_by_field_name("field", &mut cursor)
+        .map(|field| source[field.byte_range()].trim().to_string())
+        .collect();
+    ParsedConstraint::Distinct(fields)
+}
+
+/// Parse `x [strictly] between low and high` into its lower and upper bound,
+/// the shape `crucible_core::CompoundConstraint::as_between` recognizes
+fn parse_range_node(node: tree_sitter::Node, source: &str) -> Option<ParsedConstraint> {
+    let mut variable = None;
+    let mut bounds = Vec::new();
+    let mut strict = false;
+
+    for k in 0..node.child_count() {
+        if let Some(child) = node.child(k) {
+            match child.kind() {
+                "left_expression" => variable = Some(source[child.byte_range()].trim().to_string()),
+                "right_expression" => bounds.push(source[child.byte_range()].trim().to_string()),
+                "strictly" => strict = true,
+                _ => {}
+            }
+        }
+    }
+
+    let variable = variable?;
+    let [low, high] = <[String; 2]>::try_from(bounds).ok()?;
+    let (lower, upper) = if strict {
+        (ConstraintOperator::GreaterThan, ConstraintOperator::LessThan)
+    } else {
+        (ConstraintOperator::GreaterEqual, ConstraintOperator::LessEqual)
+    };
+    let bound = |operator, value| {
+        Box::new(ParsedConstraint::Atomic(Constraint {
+            left_variable: variable.clone(),
+            operator,
+            right_value: value,
+        }))
+    };
+
+    Some(ParsedConstraint::Compound {
+        operator: LogicalOperator::And,
+        left: bound(lower, low),
+        right: Some(bound(upper, high)),
+    })
+}
+
+/// Parse a logical expression node (and/or/not/if-then)
 fn parse_logical_expression_node(node: tree_sitter::Node, source: &str) -> Option<ParsedConstraint> {
     let mut operator = None;
     let mut left_expr = None;
@@ -468,18 +1447,16 @@ fn parse_logical_expression_node(node: tree_sitter::Node, source: &str) -> Optio
                 "not" => {
                     operator = Some(LogicalOperator::Not);
                 }
+                "if" => {
+                    operator = Some(LogicalOperator::Implies);
+                }
                 "expression" => {
                     // This is a nested expression
-                    for j in 0..child.child_count() {
-                        if let Some(expr_child) = child.child(j) {
-                            if expr_child.kind() == "comparison" {
-                                if left_expr.is_none() {
-                                    left_expr = parse_comparison_node(expr_child, source).map(ParsedConstraint::Atomic);
-                                } else {
-                                    right_expr = parse_comparison_node(expr_child, source).map(ParsedConstraint::Atomic);
-                                }
-                            }
-                        }
+                    let operand = parse_operand(child, source);
+                    if left_expr.is_none() {
+                        left_expr = operand;
+                    } else {
+                        right_expr = operand;
                     }
                 }
                 "comparison" => {
@@ -513,6 +1490,30 @@ fn parse_logical_expression_node(node: tree_sitter::Node, source: &str) -> Optio
     }
 }
 
+/// The constraint an `expression` node holds: an operand of a logical
+/// expression, itself possibly logical or parenthesized
+fn parse_operand(node: tree_sitter::Node, source: &str) -> Option<ParsedConstraint> {
+    let mut cursor = node.walk();
+    let inner = node.named_children(&mut cursor).find(|child| !child.is_extra())?;
+    match inner.kind() {
+        "comparison" => parse_comparison_node(inner, source).map(ParsedConstraint::Atomic),
+        "conditional_comparison" => parse_conditional_comparison_node(inner, source).map(ParsedConstraint::Atomic),
+        "range_comparison" => parse_range_node(inner, source),
+        "divisibility" => parse_divisibility_node(inner, source).map(ParsedConstraint::Atomic),
+        "bit_test" => parse_bit_test_node(inner, source).map(ParsedConstraint::Atomic),
+        "uniqueness" => Some(parse_uniqueness_node(inner, source)),
+        "logical_expression" => parse_logical_expression_node(inner, source),
+        // `(a > 0 or b > 0)`: the expression between the parentheses
+        "parenthesized_expression" => {
+            let mut cursor = inner.walk();
+            let grouped = inner.named_children(&mut cursor).find(|child| child.kind() == "expression")?;
+            parse_operand(grouped, source)
+        }
+        "variable" => Some(ParsedConstraint::Reference(source[inner.byte_range()].trim().to_string())),
+        _ => None,
+    }
+}
+
 /// Parse an arithmetic expression node
 fn parse_arithmetic_node(node: tree_sitter::Node, source: &str) -> Option<Constraint> {
     // For arithmetic expressions like "a + b", we create a constraint where
@@ -565,19 +1566,40 @@ fn parse_arithmetic_node(node: tree_sitter::Node, source: &str) -> Option<Constr
 }
 
 /// Legacy parser for simple constraints (kept for backward compatibility)
+#[allow(dead_code)]
 fn parse_constraint_node(node: tree_sitter::Node, source: &str) -> Option<ParsedConstraint> {
     parse_constraint_expression(node, source)
 }
 
+/// The first syntax error in `node`, innermost first: an `ERROR` node or a
+/// token the parser had to insert
+fn first_error(node: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    if !node.has_error() {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let inner = node.children(&mut cursor).find_map(first_error);
+    inner.or_else(|| (node.is_error() || node.is_missing()).then_some(node))
+}
+
 /// Get the Tree-Sitter language for this parser
 pub fn get_language() -> tree_sitter::Language {
-    language::LANGUAGE
+    LANGUAGE.into()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_language_loads() {
+        let mut parser = tree_sitter::Parser::new();
+        assert!(parser.set_language(&LANGUAGE.into()).is_ok());
+        assert!(get_language().node_kind_count() > 0);
+        assert!(HIGHLIGHTS_QUERY.contains("(modal_verb) @keyword"));
+        assert!(NODE_TYPES.starts_with('['));
+    }
+
     #[test]
     fn test_parse_simple_requirement() {
         let input = "User can withdraw money from account if balance >= amount";
@@ -628,13 +1650,117 @@ mod tests {
                 ParsedConstraint::Compound { operator, left: _, right: _ } => {
                     assert_eq!(*operator, LogicalOperator::And);
                 }
-                ParsedConstraint::Atomic(_) => {
+                ParsedConstraint::Atomic(_) | ParsedConstraint::Reference(_) | ParsedConstraint::Distinct(_) => {
                     // Single constraint - still valid
                 }
             }
         }
     }
     
+    #[test]
+    fn test_parse_between_range() {
+        let ast = parse("User can transfer money if amount between 1 and 100 and fee strictly between 0 and 5").unwrap();
+        let condition = ast.requirements[0].condition.as_ref().unwrap().to_compound().unwrap();
+
+        let crucible_core::CompoundConstraint::And(parts) = &condition else {
+            panic!("expected a conjunction of ranges, got {:?}", condition);
+        };
+        assert_eq!(parts[0].as_between(), Some(crucible_core::Between::new("amount", "1", "100", true)));
+        assert_eq!(parts[1].as_between(), Some(crucible_core::Between::new("fee", "0", "5", false)));
+    }
+
+    #[test]
+    fn test_parse_modulo_constraints() {
+        let ast = parse("User can create batch if amount is a multiple of 100 and id mod 10 == 0").unwrap();
+        let condition = ast.requirements[0].condition.as_ref().unwrap().to_compound().unwrap();
+
+        let crucible_core::CompoundConstraint::And(parts) = &condition else {
+            panic!("expected a conjunction, got {:?}", condition);
+        };
+        assert_eq!(parts[0], crucible_core::CompoundConstraint::Simple(crucible_core::Constraint::multiple_of("amount", "100")));
+        let crucible_core::CompoundConstraint::Simple(id) = &parts[1] else {
+            panic!("expected a comparison, got {:?}", parts[1]);
+        };
+        assert_eq!(id.modulo(), Some(crucible_core::Modulo::new("id", "10")));
+        assert_eq!(id.right_value, "0");
+    }
+
+    #[test]
+    fn test_parse_function_calls() {
+        let ast = parse("User can pay if fee == max(min_fee, amount*rate) and abs(delta) <= 5").unwrap();
+        let condition = ast.requirements[0].condition.as_ref().unwrap().to_compound().unwrap();
+
+        let crucible_core::CompoundConstraint::And(parts) = &condition else {
+            panic!("expected a conjunction, got {:?}", condition);
+        };
+        let calls: Vec<String> = parts.iter().map(|part| part.to_string()).collect();
+        assert_eq!(calls, ["fee == max(min_fee, amount * rate)", "abs(delta) <= 5"]);
+    }
+
+    #[test]
+    fn test_parse_aggregates() {
+        let ast = parse("User can pay where total == sum of line_amounts and count(approvers) >= 2").unwrap();
+        let constraint = ast.requirements[0].constraint.as_ref().unwrap().to_compound().unwrap();
+        assert_eq!(constraint.to_string(), "(total == sum(line_amounts) AND count(approvers) >= 2)");
+    }
+
+    #[test]
+    fn test_parse_uniqueness() {
+        let requirement = "User can transfer money where transaction_ids are unique and sender, receiver are distinct";
+        let ast = parse(requirement).unwrap();
+        let constraint = ast.requirements[0].constraint.as_ref().unwrap().to_compound().unwrap();
+        assert_eq!(constraint.to_string(), "(distinct(transaction_ids) AND distinct(sender, receiver))");
+    }
+
+    #[test]
+    fn test_parse_bitwise() {
+        let requirement = "User can write file where flags has bit 3 set and mode&0b1111 == 2 and word >> 4 != 0";
+        let ast = parse(requirement).unwrap();
+        let constraint = ast.requirements[0].constraint.as_ref().unwrap().to_compound().unwrap();
+        assert_eq!(constraint.to_string(), "((flags & 0x8 != 0 AND mode & 0b1111 == 2) AND word >> 4 != 0)");
+    }
+
+    #[test]
+    fn test_parse_conditional_value() {
+        let ast = parse("User can pay where fee is 0 if member else 2 if tier>=3 else base + 1").unwrap();
+        let constraint = ast.requirements[0].constraint.as_ref().unwrap().to_compound().unwrap();
+        assert_eq!(constraint.to_string(), "fee == 0 if member else 2 if tier >= 3 else base + 1");
+    }
+
+    #[test]
+    fn test_parse_implication() {
+        let ast = parse("System must validate transfer where if amount > 1000 then approvals >= 2").unwrap();
+        let constraint = ast.requirements[0].constraint.as_ref().unwrap();
+        match constraint {
+            ParsedConstraint::Compound { operator, .. } => assert_eq!(*operator, LogicalOperator::Implies),
+            other => panic!("expected an implication, got {:?}", other),
+        }
+
+        let crucible_core::CompoundConstraint::Implies(antecedent, consequent) = constraint.to_compound().unwrap() else {
+            panic!("expected CompoundConstraint::Implies");
+        };
+        assert_eq!(antecedent.count_constraints(), 1);
+        assert_eq!(consequent.count_constraints(), 1);
+    }
+
+    #[test]
+    fn test_parse_definitions() {
+        let input = "sufficient_funds := balance >= amount\nUser can withdraw money if sufficient_funds and amount > 0\n";
+        let ast = parse(input).unwrap();
+        assert_eq!(ast.definitions.len(), 1);
+        assert_eq!(ast.definitions[0].name, "sufficient_funds");
+        assert_eq!(ast.definitions[0].line, 1);
+
+        let condition = ast.requirements[0].condition.as_ref().unwrap().to_compound().unwrap();
+        let crucible_core::CompoundConstraint::And(parts) = &condition else {
+            panic!("expected a conjunction, got {:?}", condition);
+        };
+        assert_eq!(parts[0], crucible_core::CompoundConstraint::Named("sufficient_funds".to_string()));
+
+        let table = ast.definition_table().unwrap();
+        assert_eq!(table.inline(&condition).unwrap().count_constraints(), 2);
+    }
+
     #[test]
     fn test_parse_logical_or_constraint() {
         let input = "Admin can delete record if role == admin or role == superuser";
@@ -646,6 +1772,254 @@ mod tests {
         assert_eq!(ast.requirements[0].subject, "Admin");
     }
     
+    #[test]
+    fn test_parse_given_when_then() {
+        let input = "Given balance >= amount\nAnd amount > 0\nWhen User withdraw money\nThen balance >= 0\n";
+        let result = parse(input);
+        assert!(result.is_ok());
+
+        let ast = result.unwrap();
+        assert_eq!(ast.requirements.len(), 1);
+        let req = &ast.requirements[0];
+        assert_eq!(req.style, RequirementStyle::GivenWhenThen);
+        assert_eq!(req.subject, "User");
+        assert_eq!(req.action.verb, ActionType::Withdraw);
+        match req.condition {
+            Some(ParsedConstraint::Compound { ref operator, .. }) => assert_eq!(*operator, LogicalOperator::And),
+            _ => panic!("Given clauses should fold into a conjunction"),
+        }
+        match req.postcondition {
+            Some(ParsedConstraint::Atomic(ref c)) => {
+                assert_eq!(c.left_variable, "balance");
+                assert_eq!(c.operator, ConstraintOperator::GreaterEqual);
+            }
+            _ => panic!("Then clause should be the postcondition"),
+        }
+    }
+
+    #[test]
+    fn test_parse_then_with_and_continuations() {
+        let input = "Given amount > 0\nWhen User withdraw money\nThen balance >= 0\nAnd fee <= 5\nAnd status == 2\n";
+        let ast = parse(input).unwrap();
+        let req = &ast.requirements[0];
+
+        let mut atoms = Vec::new();
+        let mut next = req.postcondition.as_ref();
+        while let Some(constraint) = next {
+            match constraint {
+                ParsedConstraint::Compound { operator, left, right } => {
+                    assert_eq!(*operator, LogicalOperator::And);
+                    match right.as_deref() {
+                        Some(ParsedConstraint::Atomic(c)) => atoms.push(c.clone()),
+                        other => panic!("each And step should add one constraint, got {:?}", other),
+                    }
+                    next = Some(left);
+                }
+                ParsedConstraint::Atomic(c) => {
+                    atoms.push(c.clone());
+                    next = None;
+                }
+                other => panic!("unexpected postcondition {:?}", other),
+            }
+        }
+        atoms.reverse();
+        let rendered: Vec<_> = atoms
+            .iter()
+            .map(|c| (c.left_variable.as_str(), c.operator.clone(), c.right_value.as_str()))
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                ("balance", ConstraintOperator::GreaterEqual, "0"),
+                ("fee", ConstraintOperator::LessEqual, "5"),
+                ("status", ConstraintOperator::Equal, "2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_glossary() {
+        let mut glossary = crucible_core::Glossary::new();
+        glossary.add_term("balance", &["account balance"], None);
+
+        let input = "User can withdraw money if account_balance >= amount";
+        let ast = parse_with_glossary(input, &glossary).unwrap();
+        match ast.requirements[0].condition {
+            Some(ParsedConstraint::Atomic(ref c)) => assert_eq!(c.left_variable, "balance"),
+            _ => panic!("expected an atomic condition"),
+        }
+    }
+
+    #[test]
+    fn test_severity_from_modal_verb() {
+        let ast = parse("User should verify email\nAdmin may export reports\n").unwrap();
+        assert_eq!(ast.requirements[0].severity, crucible_core::Severity::ShouldHold);
+        assert_eq!(ast.requirements[1].severity, crucible_core::Severity::MayHold);
+    }
+
+    #[test]
+    fn test_requirement_kind_markers() {
+        let input = "Assuming System will validate input where amount > 0\n\
+                     System is guaranteed to process payment where total >= amount\n\
+                     Always System must store data where balance >= 0\n";
+        let ast = parse(input).unwrap();
+        let kinds: Vec<_> = ast.requirements.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                crucible_core::RequirementKind::Assumption,
+                crucible_core::RequirementKind::Guarantee,
+                crucible_core::RequirementKind::Invariant,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_primed_postcondition_becomes_transition() {
+        let input = "User can withdraw money if balance >= amount where balance' == balance - amount\n";
+        let ast = parse(input).unwrap();
+
+        let transition = ast.requirements[0].transition().unwrap();
+        assert_eq!(transition.action, "withdraw");
+        let updates = transition.updates().unwrap();
+        assert_eq!(updates[0].variable, "balance");
+        assert_eq!(updates[0].expression.to_string(), "balance - amount");
+    }
+
+    #[test]
+    fn test_extract_state_machines() {
+        let input = "User can register account\n\
+                     User can login account if attempts < 3\n\
+                     User can logout account\n\
+                     Order can ship package if status == \"paid\" and weight > 0 where status' == \"shipped\"\n\
+                     Order can deliver package if status == \"shipped\" where status' == \"delivered\"\n";
+        let ast = parse(input).unwrap();
+        let machines = extract_state_machines(&ast);
+        assert_eq!(machines.len(), 2);
+
+        let user = &machines[0];
+        assert_eq!(user.states, vec!["initial", "registered", "logged_in", "logged_out"]);
+        assert!(user.unreachable_states(|_| true).is_empty());
+
+        let order = &machines[1];
+        assert_eq!(order.initial, "paid");
+        assert_eq!(order.states, vec!["paid", "shipped", "delivered"]);
+        let ship = &order.transitions[0];
+        assert_eq!((ship.from.as_str(), ship.to.as_str()), ("paid", "shipped"));
+        assert_eq!(ship.guard.as_ref().map(|g| g.count_constraints()), Some(1));
+    }
+
+    #[test]
+    fn test_parse_with_templates() {
+        let mut templates = crucible_core::TemplateLibrary::new();
+        templates.add(crucible_core::RequirementTemplate::new(
+            "threshold",
+            "<<actor>> can <<action>> money if <<field>> >= <<threshold>>",
+        ));
+
+        let input = "@threshold(actor = User, action = withdraw, field = balance, threshold = 100)\n\
+                     @threshold(actor = Admin, action = transfer, field = limit, threshold = 5000)\n";
+        let ast = parse_with_templates(input, &templates).unwrap();
+        assert_eq!(ast.requirements.len(), 2);
+        assert_eq!(ast.requirements[1].subject, "Admin");
+    }
+
+    #[test]
+    fn test_ambiguity_condition_attachment() {
+        let input = "User can withdraw money from account if balance >= limit";
+        let ast = parse(input).unwrap();
+
+        let ambiguity = ast
+            .ambiguities
+            .iter()
+            .find(|a| a.kind == AmbiguityKind::ConditionAttachment)
+            .expect("condition after a preposition phrase is ambiguous");
+        assert_eq!(ambiguity.interpretations.len(), 2);
+        match &ambiguity.interpretations[1].constraint {
+            ParsedConstraint::Atomic(c) => assert_eq!(c.left_variable, "account.balance"),
+            _ => panic!("expected an atomic constraint"),
+        }
+    }
+
+    #[test]
+    fn test_ambiguity_operator_precedence() {
+        let readings = precedence_interpretations("where a > 0 or b > 0 and c > 0").unwrap();
+        match &readings[0].constraint {
+            ParsedConstraint::Compound { operator, .. } => assert_eq!(*operator, LogicalOperator::Or),
+            _ => panic!("expected a compound constraint"),
+        }
+        match &readings[1].constraint {
+            ParsedConstraint::Compound { operator, .. } => assert_eq!(*operator, LogicalOperator::And),
+            _ => panic!("expected a compound constraint"),
+        }
+        assert!(precedence_interpretations("where (a > 0 or b > 0) and c > 0").is_none());
+        assert!(precedence_interpretations("where a > 0 and b > 0").is_none());
+    }
+
+    #[test]
+    fn test_parse_german_matches_english() {
+        let english = parse("User can withdraw money from account if balance >= amount").unwrap();
+        let german = parse_with_language("Benutzer kann money vom account abheben wenn balance >= amount", Lang::De).unwrap();
+
+        assert_eq!(german.requirements.len(), 1);
+        assert_eq!(german.requirements[0].subject, english.requirements[0].subject);
+        assert_eq!(german.requirements[0].modal_verb, english.requirements[0].modal_verb);
+        assert_eq!(german.requirements[0].action.verb, english.requirements[0].action.verb);
+        assert!(german.source_text.starts_with("Benutzer"));
+    }
+
+    struct CannedFallback(&'static str);
+
+    impl FallbackParser for CannedFallback {
+        fn suggest(&self, _sentence: &str) -> Result<String, FallbackError> {
+            Ok(self.0.to_string())
+        }
+
+        fn name(&self) -> String {
+            "canned".to_string()
+        }
+    }
+
+    #[test]
+    fn test_fallback_marks_machine_suggested() {
+        let input = "Withdrawals are only allowed while the balance covers the amount\n";
+        let fallback = CannedFallback("User can withdraw money if balance >= amount");
+
+        let ast = parse_with_fallback(input, &fallback).unwrap();
+        assert_eq!(ast.requirements.len(), 1);
+        assert!(matches!(
+            ast.requirements[0].provenance,
+            Provenance::MachineSuggested { ref suggester, .. } if suggester == "canned"
+        ));
+    }
+
+    #[test]
+    fn test_fallback_rejects_ungrounded_variables() {
+        let input = "Withdrawals are only allowed while the balance covers the amount\n";
+        let fallback = CannedFallback("User can withdraw money if credit_score >= 700");
+
+        let err = parse_with_fallback(input, &fallback).unwrap_err();
+        assert_eq!(err.line, 1);
+        assert!(err.message.contains("credit_score"));
+    }
+
+    #[test]
+    fn test_parse_error_into_crucible_error() {
+        use crucible_core::Locale;
+
+        let syntax = ParseError { message: "Unexpected `>`".to_string(), line: 2, column: 14 };
+        let error = CrucibleError::from(syntax);
+        assert_eq!(error.code(), codes::SYNTAX);
+        assert_eq!(error.span(), Some(crucible_core::SourceSpan { line: 2, column: 14 }));
+        assert_eq!(error.http_status(), 400);
+        assert_eq!(error.localized(Locale::De), "Syntaxfehler [P001] Zeile 2, Spalte 14: Unerwartetes `>`");
+        assert_eq!(Locale::from(Lang::Es), Locale::Es);
+
+        let internal = ParseError { message: "Failed to parse input".to_string(), line: 0, column: 0 };
+        let error = CrucibleError::from(internal);
+        assert_eq!((error.code(), error.span(), error.http_status()), (codes::PARSER, None, 500));
+    }
+
     #[test]
     fn test_parse_nested_logical_constraint() {
         let input = "System shall validate input where (length > 0) and (width > 0) or (is_default == true)";
@@ -656,4 +2030,90 @@ mod tests {
         assert_eq!(ast.requirements.len(), 1);
         assert_eq!(ast.requirements[0].subject, "System");
     }
+
+    #[test]
+    fn test_tolerance_stays_out_of_the_tree() {
+        let mut ast = crucible_core::IntentAst::new();
+        let text = "System must process payment where amount > 0 and latency < 100ms in 99% of cases\n";
+        let requirement = ast.add_requirement_parsed(text).unwrap();
+        assert_eq!(requirement.content, text);
+        assert_eq!(requirement.constraints.len(), 1);
+        assert_eq!(requirement.constraints[0].left_variable, "amount");
+        assert_eq!(requirement.tolerance.as_ref().unwrap().metric, "latency");
+
+        let requirement = ast.add_requirement_parsed("latency < 250ms 99.9% of the time\n").unwrap();
+        assert!(requirement.constraint_tree().is_none());
+        assert_eq!(requirement.tolerance.as_ref().unwrap().percent, 99.9);
+    }
+
+    mod round_trip {
+        use super::*;
+        use crucible_core::{CompoundConstraint as Tree, ConstraintOperator as Op, Verbalizer};
+        use proptest::prelude::*;
+        use proptest::sample::select;
+
+        const NAMES: &[&str] = &["balance", "amount", "daily_limit", "fee", "score"];
+        const OPERATORS: &[&str] = &[">=", "<=", ">", "<", "==", "!="];
+
+        fn comparison() -> impl Strategy<Value = String> {
+            let value = prop_oneof![select(NAMES).prop_map(str::to_string), (0u32..10_000).prop_map(|n| n.to_string())];
+            (select(NAMES), select(OPERATORS), value).prop_map(|(left, op, right)| format!("{} {} {}", left, op, right))
+        }
+
+        fn expression() -> impl Strategy<Value = String> {
+            prop_oneof![
+                comparison(),
+                prop::collection::vec(comparison(), 2..4).prop_map(|parts| parts.join(" and ")),
+                prop::collection::vec(comparison(), 2..4).prop_map(|parts| parts.join(" or ")),
+                comparison().prop_map(|c| format!("not {}", c)),
+                (comparison(), comparison()).prop_map(|(a, b)| format!("if {} then {}", a, b)),
+            ]
+        }
+
+        /// The verbalizer states `not a >= b` as `a < b` and flattens nested
+        /// lists; compare trees up to those rewrites
+        fn normalize(tree: &Tree) -> Tree {
+            let normalized = match tree {
+                Tree::Not(inner) => match inner.as_ref() {
+                    Tree::Simple(c) => {
+                        let operator = match c.operator {
+                            Op::GreaterThanOrEqual => Op::LessThan,
+                            Op::LessThanOrEqual => Op::GreaterThan,
+                            Op::GreaterThan => Op::LessThanOrEqual,
+                            Op::LessThan => Op::GreaterThanOrEqual,
+                            Op::Equal => Op::NotEqual,
+                            Op::NotEqual => Op::Equal,
+                            Op::Contains => Op::DoesNotContain,
+                            Op::DoesNotContain => Op::Contains,
+                        };
+                        Tree::Simple(crucible_core::Constraint { operator, ..c.clone() })
+                    }
+                    Tree::Not(twice) => normalize(twice),
+                    other => Tree::Not(Box::new(normalize(other))),
+                },
+                Tree::And(parts) => Tree::And(parts.iter().map(normalize).collect()),
+                Tree::Or(parts) => Tree::Or(parts.iter().map(normalize).collect()),
+                Tree::Implies(antecedent, consequent) => Tree::implies(normalize(antecedent), normalize(consequent)),
+                other => other.clone(),
+            };
+            normalized.canonical()
+        }
+
+        proptest! {
+            #[test]
+            fn parsed_constraints_survive_verbalization(expression in expression()) {
+                let input = format!("System must validate input where {}\n", expression);
+                let verbalizer = Verbalizer::new();
+                // Every generated expression is in the grammar
+                let ast = parse(&input);
+                prop_assert!(ast.is_ok(), "{:?}", ast.err());
+                let tree = ast.unwrap().requirements[0].constraint.as_ref().and_then(ParsedConstraint::to_compound);
+                prop_assert!(tree.is_some(), "no constraint tree for {:?}", input);
+                let tree = tree.unwrap();
+                let sentence = verbalizer.compound(&tree);
+                let read = verbalizer.read(&sentence);
+                prop_assert_eq!(read.as_ref().map(normalize), Some(normalize(&tree)), "{}", sentence);
+            }
+        }
+    }
 }
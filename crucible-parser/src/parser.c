@@ -1,5 +1,3 @@
-/* Automatically @generated by tree-sitter */
-
 #include "tree_sitter/parser.h"
 
 #if defined(__GNUC__) || defined(__clang__)
@@ -7,164 +5,239 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 66
+#define STATE_COUNT 253
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 129
+#define SYMBOL_COUNT 194
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 101
+#define TOKEN_COUNT 143
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 0
-#define MAX_ALIAS_SEQUENCE_LENGTH 6
-#define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 1
-#define SUPERTYPE_COUNT 0
+#define FIELD_COUNT 7
+#define MAX_ALIAS_SEQUENCE_LENGTH 7
+#define PRODUCTION_ID_COUNT 9
 
 enum ts_symbol_identifiers {
   sym_identifier = 1,
-  anon_sym_User = 2,
-  anon_sym_System = 3,
-  anon_sym_Admin = 4,
-  anon_sym_Application = 5,
-  anon_sym_Service = 6,
-  anon_sym_API = 7,
-  anon_sym_can = 8,
-  anon_sym_must = 9,
-  anon_sym_should = 10,
-  anon_sym_shall = 11,
-  anon_sym_will = 12,
-  anon_sym_may = 13,
-  anon_sym_create = 14,
-  anon_sym_read = 15,
-  anon_sym_update = 16,
-  anon_sym_delete = 17,
-  anon_sym_validate = 18,
-  anon_sym_verify = 19,
-  anon_sym_authenticate = 20,
-  anon_sym_authorize = 21,
-  anon_sym_encrypt = 22,
-  anon_sym_decrypt = 23,
-  anon_sym_send = 24,
-  anon_sym_receive = 25,
-  anon_sym_store = 26,
-  anon_sym_retrieve = 27,
-  anon_sym_process = 28,
-  anon_sym_calculate = 29,
-  anon_sym_generate = 30,
-  anon_sym_export = 31,
-  anon_sym_import = 32,
-  anon_sym_withdraw = 33,
-  anon_sym_deposit = 34,
-  anon_sym_transfer = 35,
-  anon_sym_login = 36,
-  anon_sym_logout = 37,
-  anon_sym_register = 38,
-  anon_sym_data_record = 39,
-  anon_sym_user_data = 40,
-  anon_sym_transaction = 41,
-  anon_sym_account_data = 42,
-  anon_sym_password_data = 43,
-  anon_sym_token_data = 44,
-  anon_sym_certificate_data = 45,
-  anon_sym_file_data = 46,
-  anon_sym_document_data = 47,
-  anon_sym_message_data = 48,
-  anon_sym_if = 49,
-  anon_sym_where = 50,
-  anon_sym_and = 51,
-  anon_sym_or = 52,
-  anon_sym_not = 53,
-  anon_sym_EQ_EQ = 54,
-  anon_sym_BANG_EQ = 55,
-  anon_sym_GT = 56,
-  anon_sym_LT = 57,
-  anon_sym_GT_EQ = 58,
-  anon_sym_LT_EQ = 59,
-  anon_sym_equals = 60,
-  anon_sym_not_equals = 61,
-  anon_sym_greater_than = 62,
-  anon_sym_less_than = 63,
-  anon_sym_at_least = 64,
-  anon_sym_at_most = 65,
-  anon_sym_is_set = 66,
-  anon_sym_is_not_set = 67,
-  anon_sym_contains = 68,
-  anon_sym_does_not_contain = 69,
-  anon_sym_PLUS = 70,
-  anon_sym_DASH = 71,
-  anon_sym_STAR = 72,
-  anon_sym_SLASH = 73,
-  anon_sym_PERCENT = 74,
-  anon_sym_from = 75,
-  anon_sym_to = 76,
-  anon_sym_in = 77,
-  anon_sym_on = 78,
-  anon_sym_at = 79,
-  anon_sym_by = 80,
-  anon_sym_with = 81,
-  anon_sym_without = 82,
-  anon_sym_for = 83,
-  anon_sym_into = 84,
-  anon_sym_onto = 85,
-  anon_sym_through = 86,
-  anon_sym_during = 87,
-  anon_sym_before = 88,
-  anon_sym_after = 89,
-  anon_sym_the = 90,
-  anon_sym_DQUOTE = 91,
-  aux_sym_string_token1 = 92,
-  anon_sym_BSLASH = 93,
-  anon_sym_n = 94,
-  anon_sym_t = 95,
-  sym_number = 96,
-  sym_whitespace = 97,
-  sym_newline = 98,
-  anon_sym_SLASH_SLASH = 99,
-  aux_sym_comment_token1 = 100,
-  sym_source_file = 101,
-  sym_requirement = 102,
-  sym_subject = 103,
-  sym_modal_verb = 104,
-  sym_action = 105,
-  sym_verb = 106,
-  sym_object = 107,
-  sym_condition = 108,
-  sym_constraint = 109,
-  sym_constraint_expression = 110,
-  sym_comparison = 111,
-  sym_logical_expression = 112,
-  sym_arithmetic_expression = 113,
-  sym_left_expression = 114,
-  sym_right_expression = 115,
-  sym_variable = 116,
-  sym_comparison_operator = 117,
-  sym_arithmetic_operator = 118,
-  sym_preposition_phrase = 119,
-  sym_preposition = 120,
-  sym_noun_phrase = 121,
-  sym_string = 122,
-  sym_escape_sequence = 123,
-  sym_comment = 124,
-  sym_expression = 125,
-  aux_sym_source_file_repeat1 = 126,
-  aux_sym_noun_phrase_repeat1 = 127,
-  aux_sym_string_repeat1 = 128,
+  anon_sym_COLON_EQ = 2,
+  anon_sym_Given = 3,
+  anon_sym_When = 4,
+  anon_sym_Then = 5,
+  anon_sym_And = 6,
+  anon_sym_User = 7,
+  anon_sym_System = 8,
+  anon_sym_Admin = 9,
+  anon_sym_Application = 10,
+  anon_sym_Service = 11,
+  anon_sym_API = 12,
+  anon_sym_Assuming = 13,
+  anon_sym_Assume = 14,
+  anon_sym_Invariant_COLON = 15,
+  anon_sym_Always = 16,
+  anon_sym_can = 17,
+  anon_sym_must = 18,
+  anon_sym_should = 19,
+  anon_sym_shall = 20,
+  anon_sym_will = 21,
+  anon_sym_may = 22,
+  anon_sym_is = 23,
+  anon_sym_guaranteed = 24,
+  anon_sym_to = 25,
+  anon_sym_create = 26,
+  anon_sym_read = 27,
+  anon_sym_update = 28,
+  anon_sym_delete = 29,
+  anon_sym_validate = 30,
+  anon_sym_verify = 31,
+  anon_sym_authenticate = 32,
+  anon_sym_authorize = 33,
+  anon_sym_encrypt = 34,
+  anon_sym_decrypt = 35,
+  anon_sym_send = 36,
+  anon_sym_receive = 37,
+  anon_sym_store = 38,
+  anon_sym_retrieve = 39,
+  anon_sym_process = 40,
+  anon_sym_calculate = 41,
+  anon_sym_generate = 42,
+  anon_sym_export = 43,
+  anon_sym_import = 44,
+  anon_sym_withdraw = 45,
+  anon_sym_deposit = 46,
+  anon_sym_transfer = 47,
+  anon_sym_login = 48,
+  anon_sym_logout = 49,
+  anon_sym_register = 50,
+  anon_sym_data_record = 51,
+  anon_sym_user_data = 52,
+  anon_sym_transaction = 53,
+  anon_sym_account_data = 54,
+  anon_sym_password_data = 55,
+  anon_sym_token_data = 56,
+  anon_sym_certificate_data = 57,
+  anon_sym_file_data = 58,
+  anon_sym_document_data = 59,
+  anon_sym_message_data = 60,
+  anon_sym_if = 61,
+  anon_sym_where = 62,
+  anon_sym_else = 63,
+  anon_sym_strictly = 64,
+  anon_sym_between = 65,
+  anon_sym_and = 66,
+  anon_sym_not = 67,
+  anon_sym_a = 68,
+  anon_sym_multiple = 69,
+  anon_sym_of = 70,
+  anon_sym_has = 71,
+  anon_sym_bit = 72,
+  anon_sym_set = 73,
+  anon_sym_clear = 74,
+  anon_sym_COMMA = 75,
+  anon_sym_are = 76,
+  anon_sym_unique = 77,
+  anon_sym_distinct = 78,
+  anon_sym_or = 79,
+  anon_sym_then = 80,
+  anon_sym_LPAREN = 81,
+  anon_sym_RPAREN = 82,
+  anon_sym_min = 83,
+  anon_sym_max = 84,
+  anon_sym_abs = 85,
+  anon_sym_sum = 86,
+  anon_sym_count = 87,
+  anon_sym_average = 88,
+  anon_sym_PERCENT = 89,
+  anon_sym_mod = 90,
+  anon_sym_AMP = 91,
+  anon_sym_PIPE = 92,
+  anon_sym_CARET = 93,
+  anon_sym_LT_LT = 94,
+  anon_sym_GT_GT = 95,
+  anon_sym_SQUOTE = 96,
+  anon_sym_EQ_EQ = 97,
+  anon_sym_BANG_EQ = 98,
+  anon_sym_GT = 99,
+  anon_sym_LT = 100,
+  anon_sym_GT_EQ = 101,
+  anon_sym_LT_EQ = 102,
+  anon_sym_equals = 103,
+  anon_sym_not_equals = 104,
+  anon_sym_greater_than = 105,
+  anon_sym_less_than = 106,
+  anon_sym_at_least = 107,
+  anon_sym_at_most = 108,
+  anon_sym_is_set = 109,
+  anon_sym_is_not_set = 110,
+  anon_sym_contains = 111,
+  anon_sym_does_not_contain = 112,
+  anon_sym_PLUS = 113,
+  anon_sym_DASH = 114,
+  anon_sym_STAR = 115,
+  anon_sym_SLASH = 116,
+  anon_sym_from = 117,
+  anon_sym_in = 118,
+  anon_sym_on = 119,
+  anon_sym_at = 120,
+  anon_sym_by = 121,
+  anon_sym_with = 122,
+  anon_sym_without = 123,
+  anon_sym_for = 124,
+  anon_sym_into = 125,
+  anon_sym_onto = 126,
+  anon_sym_through = 127,
+  anon_sym_during = 128,
+  anon_sym_before = 129,
+  anon_sym_after = 130,
+  anon_sym_the = 131,
+  anon_sym_DQUOTE = 132,
+  aux_sym_string_token1 = 133,
+  anon_sym_BSLASH = 134,
+  anon_sym_n = 135,
+  anon_sym_t = 136,
+  sym_number = 137,
+  sym_bit_pattern = 138,
+  sym_whitespace = 139,
+  sym_newline = 140,
+  anon_sym_SLASH_SLASH = 141,
+  aux_sym_comment_token1 = 142,
+  sym_source_file = 143,
+  sym_definition = 144,
+  sym_requirement = 145,
+  sym_bdd_requirement = 146,
+  sym_given_clause = 147,
+  sym_when_clause = 148,
+  sym_then_clause = 149,
+  sym_and_clause = 150,
+  sym_subject = 151,
+  sym_kind_marker = 152,
+  sym_modal_verb = 153,
+  sym_action = 154,
+  sym_verb = 155,
+  sym_object = 156,
+  sym_condition = 157,
+  sym_constraint = 158,
+  sym_constraint_expression = 159,
+  sym_comparison = 160,
+  sym_conditional_comparison = 161,
+  sym_conditional_value = 162,
+  sym_range_comparison = 163,
+  sym_divisibility = 164,
+  sym_bit_test = 165,
+  sym_uniqueness = 166,
+  sym_logical_expression = 167,
+  sym_parenthesized_expression = 168,
+  sym_arithmetic_expression = 169,
+  sym_left_expression = 170,
+  sym_right_expression = 171,
+  sym_function_call = 172,
+  sym_call_argument = 173,
+  sym_aggregate = 174,
+  sym_arithmetic_term = 175,
+  sym_modulo_term = 176,
+  sym_bitwise_term = 177,
+  sym_variable = 178,
+  sym_comparison_operator = 179,
+  sym_arithmetic_operator = 180,
+  sym_preposition_phrase = 181,
+  sym_preposition = 182,
+  sym_noun_phrase = 183,
+  sym_string = 184,
+  sym_escape_sequence = 185,
+  sym_comment = 186,
+  sym_expression = 187,
+  aux_sym_source_file_repeat1 = 188,
+  aux_sym_given_clause_repeat1 = 189,
+  aux_sym_uniqueness_repeat1 = 190,
+  aux_sym_function_call_repeat1 = 191,
+  aux_sym_noun_phrase_repeat1 = 192,
+  aux_sym_string_repeat1 = 193,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
   [sym_identifier] = "identifier",
+  [anon_sym_COLON_EQ] = ":=",
+  [anon_sym_Given] = "Given",
+  [anon_sym_When] = "When",
+  [anon_sym_Then] = "Then",
+  [anon_sym_And] = "And",
   [anon_sym_User] = "User",
   [anon_sym_System] = "System",
   [anon_sym_Admin] = "Admin",
   [anon_sym_Application] = "Application",
   [anon_sym_Service] = "Service",
   [anon_sym_API] = "API",
+  [anon_sym_Assuming] = "Assuming",
+  [anon_sym_Assume] = "Assume",
+  [anon_sym_Invariant_COLON] = "Invariant:",
+  [anon_sym_Always] = "Always",
   [anon_sym_can] = "can",
   [anon_sym_must] = "must",
   [anon_sym_should] = "should",
   [anon_sym_shall] = "shall",
   [anon_sym_will] = "will",
   [anon_sym_may] = "may",
+  [anon_sym_is] = "is",
+  [anon_sym_guaranteed] = "guaranteed",
+  [anon_sym_to] = "to",
   [anon_sym_create] = "create",
   [anon_sym_read] = "read",
   [anon_sym_update] = "update",
@@ -202,9 +275,40 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_message_data] = "message_data",
   [anon_sym_if] = "if",
   [anon_sym_where] = "where",
+  [anon_sym_else] = "else",
+  [anon_sym_strictly] = "strictly",
+  [anon_sym_between] = "between",
   [anon_sym_and] = "and",
-  [anon_sym_or] = "or",
   [anon_sym_not] = "not",
+  [anon_sym_a] = "a",
+  [anon_sym_multiple] = "multiple",
+  [anon_sym_of] = "of",
+  [anon_sym_has] = "has",
+  [anon_sym_bit] = "bit",
+  [anon_sym_set] = "set",
+  [anon_sym_clear] = "clear",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_are] = "are",
+  [anon_sym_unique] = "unique",
+  [anon_sym_distinct] = "distinct",
+  [anon_sym_or] = "or",
+  [anon_sym_then] = "then",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_RPAREN] = ")",
+  [anon_sym_min] = "min",
+  [anon_sym_max] = "max",
+  [anon_sym_abs] = "abs",
+  [anon_sym_sum] = "sum",
+  [anon_sym_count] = "count",
+  [anon_sym_average] = "average",
+  [anon_sym_PERCENT] = "%",
+  [anon_sym_mod] = "mod",
+  [anon_sym_AMP] = "&",
+  [anon_sym_PIPE] = "|",
+  [anon_sym_CARET] = "^",
+  [anon_sym_LT_LT] = "<<",
+  [anon_sym_GT_GT] = ">>",
+  [anon_sym_SQUOTE] = "'",
   [anon_sym_EQ_EQ] = "==",
   [anon_sym_BANG_EQ] = "!=",
   [anon_sym_GT] = ">",
@@ -225,9 +329,7 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_DASH] = "-",
   [anon_sym_STAR] = "*",
   [anon_sym_SLASH] = "/",
-  [anon_sym_PERCENT] = "%",
   [anon_sym_from] = "from",
-  [anon_sym_to] = "to",
   [anon_sym_in] = "in",
   [anon_sym_on] = "on",
   [anon_sym_at] = "at",
@@ -248,13 +350,21 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_n] = "n",
   [anon_sym_t] = "t",
   [sym_number] = "number",
+  [sym_bit_pattern] = "bit_pattern",
   [sym_whitespace] = "whitespace",
   [sym_newline] = "newline",
   [anon_sym_SLASH_SLASH] = "//",
   [aux_sym_comment_token1] = "comment_token1",
   [sym_source_file] = "source_file",
+  [sym_definition] = "definition",
   [sym_requirement] = "requirement",
+  [sym_bdd_requirement] = "bdd_requirement",
+  [sym_given_clause] = "given_clause",
+  [sym_when_clause] = "when_clause",
+  [sym_then_clause] = "then_clause",
+  [sym_and_clause] = "and_clause",
   [sym_subject] = "subject",
+  [sym_kind_marker] = "kind_marker",
   [sym_modal_verb] = "modal_verb",
   [sym_action] = "action",
   [sym_verb] = "verb",
@@ -263,10 +373,23 @@ static const char * const ts_symbol_names[] = {
   [sym_constraint] = "constraint",
   [sym_constraint_expression] = "constraint_expression",
   [sym_comparison] = "comparison",
+  [sym_conditional_comparison] = "conditional_comparison",
+  [sym_conditional_value] = "conditional_value",
+  [sym_range_comparison] = "range_comparison",
+  [sym_divisibility] = "divisibility",
+  [sym_bit_test] = "bit_test",
+  [sym_uniqueness] = "uniqueness",
   [sym_logical_expression] = "logical_expression",
+  [sym_parenthesized_expression] = "parenthesized_expression",
   [sym_arithmetic_expression] = "arithmetic_expression",
   [sym_left_expression] = "left_expression",
   [sym_right_expression] = "right_expression",
+  [sym_function_call] = "function_call",
+  [sym_call_argument] = "call_argument",
+  [sym_aggregate] = "aggregate",
+  [sym_arithmetic_term] = "arithmetic_term",
+  [sym_modulo_term] = "modulo_term",
+  [sym_bitwise_term] = "bitwise_term",
   [sym_variable] = "variable",
   [sym_comparison_operator] = "comparison_operator",
   [sym_arithmetic_operator] = "arithmetic_operator",
@@ -278,6 +401,9 @@ static const char * const ts_symbol_names[] = {
   [sym_comment] = "comment",
   [sym_expression] = "expression",
   [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_given_clause_repeat1] = "given_clause_repeat1",
+  [aux_sym_uniqueness_repeat1] = "uniqueness_repeat1",
+  [aux_sym_function_call_repeat1] = "function_call_repeat1",
   [aux_sym_noun_phrase_repeat1] = "noun_phrase_repeat1",
   [aux_sym_string_repeat1] = "string_repeat1",
 };
@@ -285,18 +411,30 @@ static const char * const ts_symbol_names[] = {
 static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
   [sym_identifier] = sym_identifier,
+  [anon_sym_COLON_EQ] = anon_sym_COLON_EQ,
+  [anon_sym_Given] = anon_sym_Given,
+  [anon_sym_When] = anon_sym_When,
+  [anon_sym_Then] = anon_sym_Then,
+  [anon_sym_And] = anon_sym_And,
   [anon_sym_User] = anon_sym_User,
   [anon_sym_System] = anon_sym_System,
   [anon_sym_Admin] = anon_sym_Admin,
   [anon_sym_Application] = anon_sym_Application,
   [anon_sym_Service] = anon_sym_Service,
   [anon_sym_API] = anon_sym_API,
+  [anon_sym_Assuming] = anon_sym_Assuming,
+  [anon_sym_Assume] = anon_sym_Assume,
+  [anon_sym_Invariant_COLON] = anon_sym_Invariant_COLON,
+  [anon_sym_Always] = anon_sym_Always,
   [anon_sym_can] = anon_sym_can,
   [anon_sym_must] = anon_sym_must,
   [anon_sym_should] = anon_sym_should,
   [anon_sym_shall] = anon_sym_shall,
   [anon_sym_will] = anon_sym_will,
   [anon_sym_may] = anon_sym_may,
+  [anon_sym_is] = anon_sym_is,
+  [anon_sym_guaranteed] = anon_sym_guaranteed,
+  [anon_sym_to] = anon_sym_to,
   [anon_sym_create] = anon_sym_create,
   [anon_sym_read] = anon_sym_read,
   [anon_sym_update] = anon_sym_update,
@@ -334,9 +472,40 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_message_data] = anon_sym_message_data,
   [anon_sym_if] = anon_sym_if,
   [anon_sym_where] = anon_sym_where,
+  [anon_sym_else] = anon_sym_else,
+  [anon_sym_strictly] = anon_sym_strictly,
+  [anon_sym_between] = anon_sym_between,
   [anon_sym_and] = anon_sym_and,
-  [anon_sym_or] = anon_sym_or,
   [anon_sym_not] = anon_sym_not,
+  [anon_sym_a] = anon_sym_a,
+  [anon_sym_multiple] = anon_sym_multiple,
+  [anon_sym_of] = anon_sym_of,
+  [anon_sym_has] = anon_sym_has,
+  [anon_sym_bit] = anon_sym_bit,
+  [anon_sym_set] = anon_sym_set,
+  [anon_sym_clear] = anon_sym_clear,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_are] = anon_sym_are,
+  [anon_sym_unique] = anon_sym_unique,
+  [anon_sym_distinct] = anon_sym_distinct,
+  [anon_sym_or] = anon_sym_or,
+  [anon_sym_then] = anon_sym_then,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_min] = anon_sym_min,
+  [anon_sym_max] = anon_sym_max,
+  [anon_sym_abs] = anon_sym_abs,
+  [anon_sym_sum] = anon_sym_sum,
+  [anon_sym_count] = anon_sym_count,
+  [anon_sym_average] = anon_sym_average,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
+  [anon_sym_mod] = anon_sym_mod,
+  [anon_sym_AMP] = anon_sym_AMP,
+  [anon_sym_PIPE] = anon_sym_PIPE,
+  [anon_sym_CARET] = anon_sym_CARET,
+  [anon_sym_LT_LT] = anon_sym_LT_LT,
+  [anon_sym_GT_GT] = anon_sym_GT_GT,
+  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
   [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
   [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
   [anon_sym_GT] = anon_sym_GT,
@@ -357,9 +526,7 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_DASH] = anon_sym_DASH,
   [anon_sym_STAR] = anon_sym_STAR,
   [anon_sym_SLASH] = anon_sym_SLASH,
-  [anon_sym_PERCENT] = anon_sym_PERCENT,
   [anon_sym_from] = anon_sym_from,
-  [anon_sym_to] = anon_sym_to,
   [anon_sym_in] = anon_sym_in,
   [anon_sym_on] = anon_sym_on,
   [anon_sym_at] = anon_sym_at,
@@ -380,13 +547,21 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_n] = anon_sym_n,
   [anon_sym_t] = anon_sym_t,
   [sym_number] = sym_number,
+  [sym_bit_pattern] = sym_bit_pattern,
   [sym_whitespace] = sym_whitespace,
   [sym_newline] = sym_newline,
   [anon_sym_SLASH_SLASH] = anon_sym_SLASH_SLASH,
   [aux_sym_comment_token1] = aux_sym_comment_token1,
   [sym_source_file] = sym_source_file,
+  [sym_definition] = sym_definition,
   [sym_requirement] = sym_requirement,
+  [sym_bdd_requirement] = sym_bdd_requirement,
+  [sym_given_clause] = sym_given_clause,
+  [sym_when_clause] = sym_when_clause,
+  [sym_then_clause] = sym_then_clause,
+  [sym_and_clause] = sym_and_clause,
   [sym_subject] = sym_subject,
+  [sym_kind_marker] = sym_kind_marker,
   [sym_modal_verb] = sym_modal_verb,
   [sym_action] = sym_action,
   [sym_verb] = sym_verb,
@@ -395,10 +570,23 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_constraint] = sym_constraint,
   [sym_constraint_expression] = sym_constraint_expression,
   [sym_comparison] = sym_comparison,
+  [sym_conditional_comparison] = sym_conditional_comparison,
+  [sym_conditional_value] = sym_conditional_value,
+  [sym_range_comparison] = sym_range_comparison,
+  [sym_divisibility] = sym_divisibility,
+  [sym_bit_test] = sym_bit_test,
+  [sym_uniqueness] = sym_uniqueness,
   [sym_logical_expression] = sym_logical_expression,
+  [sym_parenthesized_expression] = sym_parenthesized_expression,
   [sym_arithmetic_expression] = sym_arithmetic_expression,
   [sym_left_expression] = sym_left_expression,
   [sym_right_expression] = sym_right_expression,
+  [sym_function_call] = sym_function_call,
+  [sym_call_argument] = sym_call_argument,
+  [sym_aggregate] = sym_aggregate,
+  [sym_arithmetic_term] = sym_arithmetic_term,
+  [sym_modulo_term] = sym_modulo_term,
+  [sym_bitwise_term] = sym_bitwise_term,
   [sym_variable] = sym_variable,
   [sym_comparison_operator] = sym_comparison_operator,
   [sym_arithmetic_operator] = sym_arithmetic_operator,
@@ -410,6 +598,9 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_comment] = sym_comment,
   [sym_expression] = sym_expression,
   [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_given_clause_repeat1] = aux_sym_given_clause_repeat1,
+  [aux_sym_uniqueness_repeat1] = aux_sym_uniqueness_repeat1,
+  [aux_sym_function_call_repeat1] = aux_sym_function_call_repeat1,
   [aux_sym_noun_phrase_repeat1] = aux_sym_noun_phrase_repeat1,
   [aux_sym_string_repeat1] = aux_sym_string_repeat1,
 };
@@ -423,6 +614,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [anon_sym_COLON_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Given] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_When] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Then] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_And] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_User] = {
     .visible = true,
     .named = false,
@@ -447,6 +658,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_Assuming] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Assume] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Invariant_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Always] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_can] = {
     .visible = true,
     .named = false,
@@ -471,6 +698,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_is] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_guaranteed] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_to] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_create] = {
     .visible = true,
     .named = false,
@@ -619,15 +858,139 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_else] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_strictly] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_between] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_and] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_not] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_a] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_multiple] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_of] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_has] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_bit] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_set] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_clear] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_are] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_unique] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_distinct] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_or] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_not] = {
+  [anon_sym_then] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_min] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_max] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_abs] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_sum] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_count] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_average] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_mod] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_AMP] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_PIPE] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_CARET] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LT_LT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_GT_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SQUOTE] = {
     .visible = true,
     .named = false,
   },
@@ -711,18 +1074,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_PERCENT] = {
-    .visible = true,
-    .named = false,
-  },
   [anon_sym_from] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_to] = {
-    .visible = true,
-    .named = false,
-  },
   [anon_sym_in] = {
     .visible = true,
     .named = false,
@@ -803,6 +1158,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_bit_pattern] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_whitespace] = {
     .visible = true,
     .named = true,
@@ -823,14 +1182,42 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_definition] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_requirement] = {
     .visible = true,
     .named = true,
   },
+  [sym_bdd_requirement] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_given_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_when_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_then_clause] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_and_clause] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_subject] = {
     .visible = true,
     .named = true,
   },
+  [sym_kind_marker] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_modal_verb] = {
     .visible = true,
     .named = true,
@@ -863,10 +1250,38 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_conditional_comparison] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_conditional_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_range_comparison] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_divisibility] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bit_test] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_uniqueness] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_logical_expression] = {
     .visible = true,
     .named = true,
   },
+  [sym_parenthesized_expression] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_arithmetic_expression] = {
     .visible = true,
     .named = true,
@@ -879,6 +1294,30 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_function_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_call_argument] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_aggregate] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_arithmetic_term] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_modulo_term] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bitwise_term] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_variable] = {
     .visible = true,
     .named = true,
@@ -923,6 +1362,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_given_clause_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_uniqueness_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_function_call_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_noun_phrase_repeat1] = {
     .visible = false,
     .named = false,
@@ -933,6 +1384,62 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
   },
 };
 
+enum ts_field_identifiers {
+  field_condition = 1,
+  field_else = 2,
+  field_field = 3,
+  field_function = 4,
+  field_list = 5,
+  field_name = 6,
+  field_then = 7,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_condition] = "condition",
+  [field_else] = "else",
+  [field_field] = "field",
+  [field_function] = "function",
+  [field_list] = "list",
+  [field_name] = "name",
+  [field_then] = "then",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 2},
+  [2] = {.index = 2, .length = 1},
+  [3] = {.index = 3, .length = 1},
+  [4] = {.index = 4, .length = 2},
+  [5] = {.index = 6, .length = 1},
+  [6] = {.index = 7, .length = 1},
+  [7] = {.index = 8, .length = 2},
+  [8] = {.index = 10, .length = 3},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_function, 0},
+    {field_list, 2},
+  [2] =
+    {field_field, 1},
+  [3] =
+    {field_field, 0},
+  [4] =
+    {field_field, 0, .inherited = true},
+    {field_field, 1, .inherited = true},
+  [6] =
+    {field_name, 0},
+  [7] =
+    {field_function, 0},
+  [8] =
+    {field_field, 0},
+    {field_field, 1, .inherited = true},
+  [10] =
+    {field_condition, 2},
+    {field_else, 4},
+    {field_then, 0},
+};
+
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
   [0] = {0},
 };
@@ -946,15 +1453,15 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [1] = 1,
   [2] = 2,
   [3] = 3,
-  [4] = 4,
-  [5] = 5,
+  [4] = 2,
+  [5] = 3,
   [6] = 6,
   [7] = 7,
   [8] = 8,
-  [9] = 9,
-  [10] = 7,
+  [9] = 8,
+  [10] = 10,
   [11] = 11,
-  [12] = 8,
+  [12] = 12,
   [13] = 13,
   [14] = 14,
   [15] = 15,
@@ -974,40 +1481,227 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [29] = 29,
   [30] = 30,
   [31] = 31,
-  [32] = 32,
+  [32] = 29,
   [33] = 33,
-  [34] = 34,
-  [35] = 35,
-  [36] = 36,
-  [37] = 37,
-  [38] = 38,
+  [34] = 27,
+  [35] = 26,
+  [36] = 33,
+  [37] = 31,
+  [38] = 30,
   [39] = 39,
   [40] = 40,
   [41] = 41,
   [42] = 42,
   [43] = 43,
   [44] = 44,
-  [45] = 45,
+  [45] = 42,
   [46] = 46,
   [47] = 47,
   [48] = 48,
   [49] = 49,
-  [50] = 50,
+  [50] = 48,
   [51] = 51,
   [52] = 52,
   [53] = 53,
   [54] = 54,
-  [55] = 55,
+  [55] = 53,
   [56] = 56,
-  [57] = 53,
-  [58] = 58,
+  [57] = 54,
+  [58] = 56,
   [59] = 59,
   [60] = 60,
   [61] = 61,
   [62] = 62,
   [63] = 63,
   [64] = 64,
-  [65] = 65,
+  [65] = 60,
+  [66] = 66,
+  [67] = 66,
+  [68] = 68,
+  [69] = 59,
+  [70] = 62,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 72,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 82,
+  [84] = 80,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 43,
+  [100] = 44,
+  [101] = 101,
+  [102] = 11,
+  [103] = 101,
+  [104] = 13,
+  [105] = 105,
+  [106] = 106,
+  [107] = 95,
+  [108] = 12,
+  [109] = 106,
+  [110] = 110,
+  [111] = 111,
+  [112] = 111,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 116,
+  [118] = 114,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 119,
+  [133] = 120,
+  [134] = 124,
+  [135] = 135,
+  [136] = 130,
+  [137] = 137,
+  [138] = 137,
+  [139] = 139,
+  [140] = 140,
+  [141] = 135,
+  [142] = 121,
+  [143] = 122,
+  [144] = 139,
+  [145] = 140,
+  [146] = 126,
+  [147] = 127,
+  [148] = 128,
+  [149] = 129,
+  [150] = 131,
+  [151] = 151,
+  [152] = 152,
+  [153] = 125,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 155,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 158,
+  [171] = 171,
+  [172] = 172,
+  [173] = 160,
+  [174] = 174,
+  [175] = 175,
+  [176] = 168,
+  [177] = 174,
+  [178] = 178,
+  [179] = 156,
+  [180] = 157,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 154,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 186,
+  [200] = 198,
+  [201] = 201,
+  [202] = 202,
+  [203] = 192,
+  [204] = 185,
+  [205] = 201,
+  [206] = 206,
+  [207] = 202,
+  [208] = 208,
+  [209] = 209,
+  [210] = 209,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 212,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 230,
+  [237] = 226,
+  [238] = 238,
+  [239] = 239,
+  [240] = 235,
+  [241] = 229,
+  [242] = 215,
+  [243] = 243,
+  [244] = 233,
+  [245] = 232,
+  [246] = 238,
+  [247] = 247,
+  [248] = 219,
+  [249] = 213,
+  [250] = 243,
+  [251] = 251,
+  [252] = 252,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1015,190 +1709,385 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(5);
+      if (eof) ADVANCE(11);
       ADVANCE_MAP(
-        '\n', 26,
-        '!', 2,
-        '"', 18,
-        '%', 16,
-        '*', 14,
-        '+', 12,
-        '-', 13,
-        '/', 15,
-        '<', 9,
-        '=', 3,
-        '>', 8,
-        '\\', 22,
+        '\n', 55,
+        '!', 5,
+        '"', 44,
+        '%', 17,
+        '&', 18,
+        '\'', 23,
+        '(', 15,
+        ')', 16,
+        '*', 32,
+        '+', 30,
+        ',', 14,
+        '-', 31,
+        '/', 33,
+        '0', 49,
+        ':', 6,
+        '<', 27,
+        '=', 7,
+        '>', 26,
+        'I', 38,
+        '\\', 48,
+        '^', 20,
+        '|', 19,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(25);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(23);
+          lookahead == ' ') ADVANCE(54);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(50);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(17);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
       END_STATE();
     case 1:
-      if (lookahead == '"') ADVANCE(18);
-      if (lookahead == '/') ADVANCE(19);
-      if (lookahead == '\\') ADVANCE(22);
+      ADVANCE_MAP(
+        '\n', 55,
+        '!', 5,
+        '"', 44,
+        '%', 17,
+        '&', 18,
+        '\'', 23,
+        '*', 32,
+        '+', 30,
+        ',', 14,
+        '-', 31,
+        '/', 33,
+        '<', 27,
+        '=', 7,
+        '>', 26,
+        '^', 20,
+        '|', 19,
+      );
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(20);
-      if (lookahead != 0) ADVANCE(21);
+          lookahead == ' ') ADVANCE(54);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
       END_STATE();
     case 2:
-      if (lookahead == '=') ADVANCE(7);
+      if (lookahead == '"') ADVANCE(44);
+      if (lookahead == '/') ADVANCE(45);
+      if (lookahead == '\\') ADVANCE(48);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(46);
+      if (lookahead != 0) ADVANCE(47);
       END_STATE();
     case 3:
-      if (lookahead == '=') ADVANCE(6);
+      if (lookahead == '/') ADVANCE(56);
       END_STATE();
     case 4:
-      if (eof) ADVANCE(5);
-      ADVANCE_MAP(
-        '!', 2,
-        '"', 18,
-        '%', 16,
-        '*', 14,
-        '+', 12,
-        '-', 13,
-        '/', 15,
-        '<', 9,
-        '=', 3,
-        '>', 8,
-        '\\', 22,
-      );
+      if (lookahead == '/') ADVANCE(3);
+      if (lookahead == '0') ADVANCE(49);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(25);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(23);
+          lookahead == ' ') ADVANCE(54);
+      if (('1' <= lookahead && lookahead <= '9')) ADVANCE(50);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(17);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
       END_STATE();
     case 5:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == '=') ADVANCE(25);
       END_STATE();
     case 6:
-      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      if (lookahead == '=') ADVANCE(12);
       END_STATE();
     case 7:
-      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      if (lookahead == '=') ADVANCE(24);
       END_STATE();
     case 8:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(10);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(51);
       END_STATE();
     case 9:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(11);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(52);
       END_STATE();
     case 10:
-      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      if (eof) ADVANCE(11);
+      ADVANCE_MAP(
+        '!', 5,
+        '"', 44,
+        '%', 17,
+        '&', 18,
+        '\'', 23,
+        '(', 15,
+        ')', 16,
+        '*', 32,
+        '+', 30,
+        ',', 14,
+        '-', 31,
+        '/', 33,
+        ':', 6,
+        '<', 27,
+        '=', 7,
+        '>', 26,
+        '\\', 48,
+        '^', 20,
+        '|', 19,
+      );
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(54);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
       END_STATE();
     case 11:
-      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 12:
-      ACCEPT_TOKEN(anon_sym_PLUS);
+      ACCEPT_TOKEN(anon_sym_COLON_EQ);
       END_STATE();
     case 13:
-      ACCEPT_TOKEN(anon_sym_DASH);
+      ACCEPT_TOKEN(anon_sym_Invariant_COLON);
       END_STATE();
     case 14:
-      ACCEPT_TOKEN(anon_sym_STAR);
+      ACCEPT_TOKEN(anon_sym_COMMA);
       END_STATE();
     case 15:
-      ACCEPT_TOKEN(anon_sym_SLASH);
-      if (lookahead == '/') ADVANCE(27);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(anon_sym_PERCENT);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 17:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      END_STATE();
+    case 18:
+      ACCEPT_TOKEN(anon_sym_AMP);
+      END_STATE();
+    case 19:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      END_STATE();
+    case 20:
+      ACCEPT_TOKEN(anon_sym_CARET);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(anon_sym_LT_LT);
+      END_STATE();
+    case 22:
+      ACCEPT_TOKEN(anon_sym_GT_GT);
+      END_STATE();
+    case 23:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(28);
+      if (lookahead == '>') ADVANCE(22);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '<') ADVANCE(21);
+      if (lookahead == '=') ADVANCE(29);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '/') ADVANCE(56);
+      END_STATE();
+    case 34:
       ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == ':') ADVANCE(13);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(17);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
       END_STATE();
-    case 18:
+    case 35:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(40);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(39);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(36);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(41);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(37);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(34);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'v') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(43);
+      END_STATE();
+    case 44:
       ACCEPT_TOKEN(anon_sym_DQUOTE);
       END_STATE();
-    case 19:
+    case 45:
       ACCEPT_TOKEN(aux_sym_string_token1);
-      if (lookahead == '/') ADVANCE(28);
+      if (lookahead == '/') ADVANCE(57);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '\\') ADVANCE(21);
+          lookahead != '\\') ADVANCE(47);
       END_STATE();
-    case 20:
+    case 46:
       ACCEPT_TOKEN(aux_sym_string_token1);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(20);
+          lookahead == ' ') ADVANCE(46);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '\\') ADVANCE(21);
+          lookahead != '\\') ADVANCE(47);
       END_STATE();
-    case 21:
+    case 47:
       ACCEPT_TOKEN(aux_sym_string_token1);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '\\') ADVANCE(21);
+          lookahead != '\\') ADVANCE(47);
       END_STATE();
-    case 22:
+    case 48:
       ACCEPT_TOKEN(anon_sym_BSLASH);
       END_STATE();
-    case 23:
+    case 49:
       ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(23);
+      if (lookahead == 'B' ||
+          lookahead == 'b') ADVANCE(8);
+      if (lookahead == 'X' ||
+          lookahead == 'x') ADVANCE(9);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
       END_STATE();
-    case 24:
+    case 50:
+      ACCEPT_TOKEN(sym_number);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(sym_bit_pattern);
+      if (lookahead == '0' ||
+          lookahead == '1') ADVANCE(51);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(sym_bit_pattern);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(52);
+      END_STATE();
+    case 53:
       ACCEPT_TOKEN(sym_whitespace);
-      if (lookahead == '\n') ADVANCE(25);
+      if (lookahead == '\n') ADVANCE(54);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(24);
-      if (lookahead != 0) ADVANCE(32);
+          lookahead == ' ') ADVANCE(53);
+      if (lookahead != 0) ADVANCE(61);
       END_STATE();
-    case 25:
+    case 54:
       ACCEPT_TOKEN(sym_whitespace);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(25);
+          lookahead == ' ') ADVANCE(54);
       END_STATE();
-    case 26:
+    case 55:
       ACCEPT_TOKEN(sym_newline);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(25);
+          lookahead == ' ') ADVANCE(54);
       END_STATE();
-    case 27:
+    case 56:
       ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
       END_STATE();
-    case 28:
+    case 57:
       ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '\\') ADVANCE(21);
+          lookahead != '\\') ADVANCE(47);
       END_STATE();
-    case 29:
+    case 58:
       ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(32);
+          lookahead != '\n') ADVANCE(61);
       END_STATE();
-    case 30:
+    case 59:
       ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '\n') ADVANCE(25);
-      if (lookahead == '/') ADVANCE(31);
+      if (lookahead == '\n') ADVANCE(54);
+      if (lookahead == '/') ADVANCE(60);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(24);
-      if (lookahead != 0) ADVANCE(32);
+          lookahead == ' ') ADVANCE(53);
+      if (lookahead != 0) ADVANCE(61);
       END_STATE();
-    case 31:
+    case 60:
       ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '/') ADVANCE(29);
+      if (lookahead == '/') ADVANCE(58);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(32);
+          lookahead != '\n') ADVANCE(61);
       END_STATE();
-    case 32:
+    case 61:
       ACCEPT_TOKEN(aux_sym_comment_token1);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(32);
+          lookahead != '\n') ADVANCE(61);
       END_STATE();
     default:
       return false;
@@ -1212,1372 +2101,1707 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
     case 0:
       ADVANCE_MAP(
         'A', 1,
-        'S', 2,
-        'U', 3,
-        'a', 4,
-        'b', 5,
-        'c', 6,
-        'd', 7,
-        'e', 8,
-        'f', 9,
-        'g', 10,
-        'i', 11,
-        'l', 12,
-        'm', 13,
-        'n', 14,
-        'o', 15,
-        'p', 16,
-        'r', 17,
-        's', 18,
-        't', 19,
-        'u', 20,
-        'v', 21,
-        'w', 22,
+        'G', 2,
+        'S', 3,
+        'T', 4,
+        'U', 5,
+        'W', 6,
+        'a', 7,
+        'b', 8,
+        'c', 9,
+        'd', 10,
+        'e', 11,
+        'f', 12,
+        'g', 13,
+        'h', 14,
+        'i', 15,
+        'l', 16,
+        'm', 17,
+        'n', 18,
+        'o', 19,
+        'p', 20,
+        'r', 21,
+        's', 22,
+        't', 23,
+        'u', 24,
+        'v', 25,
+        'w', 26,
       );
       END_STATE();
     case 1:
-      if (lookahead == 'P') ADVANCE(23);
-      if (lookahead == 'd') ADVANCE(24);
-      if (lookahead == 'p') ADVANCE(25);
+      if (lookahead == 'P') ADVANCE(27);
+      if (lookahead == 'd') ADVANCE(28);
+      if (lookahead == 'l') ADVANCE(29);
+      if (lookahead == 'n') ADVANCE(30);
+      if (lookahead == 'p') ADVANCE(31);
+      if (lookahead == 's') ADVANCE(32);
       END_STATE();
     case 2:
-      if (lookahead == 'e') ADVANCE(26);
-      if (lookahead == 'y') ADVANCE(27);
+      if (lookahead == 'i') ADVANCE(33);
       END_STATE();
     case 3:
-      if (lookahead == 's') ADVANCE(28);
+      if (lookahead == 'e') ADVANCE(34);
+      if (lookahead == 'y') ADVANCE(35);
       END_STATE();
     case 4:
-      if (lookahead == 'c') ADVANCE(29);
-      if (lookahead == 'f') ADVANCE(30);
-      if (lookahead == 'n') ADVANCE(31);
-      if (lookahead == 't') ADVANCE(32);
-      if (lookahead == 'u') ADVANCE(33);
+      if (lookahead == 'h') ADVANCE(36);
       END_STATE();
     case 5:
-      if (lookahead == 'e') ADVANCE(34);
-      if (lookahead == 'y') ADVANCE(35);
+      if (lookahead == 's') ADVANCE(37);
       END_STATE();
     case 6:
-      if (lookahead == 'a') ADVANCE(36);
-      if (lookahead == 'e') ADVANCE(37);
-      if (lookahead == 'o') ADVANCE(38);
-      if (lookahead == 'r') ADVANCE(39);
+      if (lookahead == 'h') ADVANCE(38);
       END_STATE();
     case 7:
-      if (lookahead == 'a') ADVANCE(40);
-      if (lookahead == 'e') ADVANCE(41);
-      if (lookahead == 'o') ADVANCE(42);
-      if (lookahead == 'u') ADVANCE(43);
+      ACCEPT_TOKEN(anon_sym_a);
+      ADVANCE_MAP(
+        'b', 39,
+        'c', 40,
+        'f', 41,
+        'n', 42,
+        'r', 43,
+        't', 44,
+        'u', 45,
+        'v', 46,
+      );
       END_STATE();
     case 8:
-      if (lookahead == 'n') ADVANCE(44);
-      if (lookahead == 'q') ADVANCE(45);
-      if (lookahead == 'x') ADVANCE(46);
+      if (lookahead == 'e') ADVANCE(47);
+      if (lookahead == 'i') ADVANCE(48);
+      if (lookahead == 'y') ADVANCE(49);
       END_STATE();
     case 9:
-      if (lookahead == 'i') ADVANCE(47);
-      if (lookahead == 'o') ADVANCE(48);
-      if (lookahead == 'r') ADVANCE(49);
+      if (lookahead == 'a') ADVANCE(50);
+      if (lookahead == 'e') ADVANCE(51);
+      if (lookahead == 'l') ADVANCE(52);
+      if (lookahead == 'o') ADVANCE(53);
+      if (lookahead == 'r') ADVANCE(54);
       END_STATE();
     case 10:
-      if (lookahead == 'e') ADVANCE(50);
-      if (lookahead == 'r') ADVANCE(51);
+      if (lookahead == 'a') ADVANCE(55);
+      if (lookahead == 'e') ADVANCE(56);
+      if (lookahead == 'i') ADVANCE(57);
+      if (lookahead == 'o') ADVANCE(58);
+      if (lookahead == 'u') ADVANCE(59);
       END_STATE();
     case 11:
-      if (lookahead == 'f') ADVANCE(52);
-      if (lookahead == 'm') ADVANCE(53);
-      if (lookahead == 'n') ADVANCE(54);
-      if (lookahead == 's') ADVANCE(55);
+      if (lookahead == 'l') ADVANCE(60);
+      if (lookahead == 'n') ADVANCE(61);
+      if (lookahead == 'q') ADVANCE(62);
+      if (lookahead == 'x') ADVANCE(63);
       END_STATE();
     case 12:
-      if (lookahead == 'e') ADVANCE(56);
-      if (lookahead == 'o') ADVANCE(57);
+      if (lookahead == 'i') ADVANCE(64);
+      if (lookahead == 'o') ADVANCE(65);
+      if (lookahead == 'r') ADVANCE(66);
       END_STATE();
     case 13:
-      if (lookahead == 'a') ADVANCE(58);
-      if (lookahead == 'e') ADVANCE(59);
-      if (lookahead == 'u') ADVANCE(60);
+      if (lookahead == 'e') ADVANCE(67);
+      if (lookahead == 'r') ADVANCE(68);
+      if (lookahead == 'u') ADVANCE(69);
       END_STATE();
     case 14:
-      ACCEPT_TOKEN(anon_sym_n);
-      if (lookahead == 'o') ADVANCE(61);
+      if (lookahead == 'a') ADVANCE(70);
       END_STATE();
     case 15:
-      if (lookahead == 'n') ADVANCE(62);
-      if (lookahead == 'r') ADVANCE(63);
+      if (lookahead == 'f') ADVANCE(71);
+      if (lookahead == 'm') ADVANCE(72);
+      if (lookahead == 'n') ADVANCE(73);
+      if (lookahead == 's') ADVANCE(74);
       END_STATE();
     case 16:
-      if (lookahead == 'a') ADVANCE(64);
-      if (lookahead == 'r') ADVANCE(65);
+      if (lookahead == 'e') ADVANCE(75);
+      if (lookahead == 'o') ADVANCE(76);
       END_STATE();
     case 17:
-      if (lookahead == 'e') ADVANCE(66);
+      if (lookahead == 'a') ADVANCE(77);
+      if (lookahead == 'e') ADVANCE(78);
+      if (lookahead == 'i') ADVANCE(79);
+      if (lookahead == 'o') ADVANCE(80);
+      if (lookahead == 'u') ADVANCE(81);
       END_STATE();
     case 18:
-      if (lookahead == 'e') ADVANCE(67);
-      if (lookahead == 'h') ADVANCE(68);
-      if (lookahead == 't') ADVANCE(69);
+      ACCEPT_TOKEN(anon_sym_n);
+      if (lookahead == 'o') ADVANCE(82);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(anon_sym_t);
-      if (lookahead == 'h') ADVANCE(70);
-      if (lookahead == 'o') ADVANCE(71);
-      if (lookahead == 'r') ADVANCE(72);
+      if (lookahead == 'f') ADVANCE(83);
+      if (lookahead == 'n') ADVANCE(84);
+      if (lookahead == 'r') ADVANCE(85);
       END_STATE();
     case 20:
-      if (lookahead == 'p') ADVANCE(73);
-      if (lookahead == 's') ADVANCE(74);
+      if (lookahead == 'a') ADVANCE(86);
+      if (lookahead == 'r') ADVANCE(87);
       END_STATE();
     case 21:
-      if (lookahead == 'a') ADVANCE(75);
-      if (lookahead == 'e') ADVANCE(76);
+      if (lookahead == 'e') ADVANCE(88);
       END_STATE();
     case 22:
-      if (lookahead == 'h') ADVANCE(77);
-      if (lookahead == 'i') ADVANCE(78);
+      if (lookahead == 'e') ADVANCE(89);
+      if (lookahead == 'h') ADVANCE(90);
+      if (lookahead == 't') ADVANCE(91);
+      if (lookahead == 'u') ADVANCE(92);
       END_STATE();
     case 23:
-      if (lookahead == 'I') ADVANCE(79);
+      ACCEPT_TOKEN(anon_sym_t);
+      if (lookahead == 'h') ADVANCE(93);
+      if (lookahead == 'o') ADVANCE(94);
+      if (lookahead == 'r') ADVANCE(95);
       END_STATE();
     case 24:
-      if (lookahead == 'm') ADVANCE(80);
+      if (lookahead == 'n') ADVANCE(96);
+      if (lookahead == 'p') ADVANCE(97);
+      if (lookahead == 's') ADVANCE(98);
       END_STATE();
     case 25:
-      if (lookahead == 'p') ADVANCE(81);
+      if (lookahead == 'a') ADVANCE(99);
+      if (lookahead == 'e') ADVANCE(100);
       END_STATE();
     case 26:
-      if (lookahead == 'r') ADVANCE(82);
+      if (lookahead == 'h') ADVANCE(101);
+      if (lookahead == 'i') ADVANCE(102);
       END_STATE();
     case 27:
-      if (lookahead == 's') ADVANCE(83);
+      if (lookahead == 'I') ADVANCE(103);
       END_STATE();
     case 28:
-      if (lookahead == 'e') ADVANCE(84);
+      if (lookahead == 'm') ADVANCE(104);
       END_STATE();
     case 29:
-      if (lookahead == 'c') ADVANCE(85);
+      if (lookahead == 'w') ADVANCE(105);
       END_STATE();
     case 30:
-      if (lookahead == 't') ADVANCE(86);
+      if (lookahead == 'd') ADVANCE(106);
       END_STATE();
     case 31:
-      if (lookahead == 'd') ADVANCE(87);
+      if (lookahead == 'p') ADVANCE(107);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(anon_sym_at);
-      if (lookahead == '_') ADVANCE(88);
+      if (lookahead == 's') ADVANCE(108);
       END_STATE();
     case 33:
-      if (lookahead == 't') ADVANCE(89);
+      if (lookahead == 'v') ADVANCE(109);
       END_STATE();
     case 34:
-      if (lookahead == 'f') ADVANCE(90);
+      if (lookahead == 'r') ADVANCE(110);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_by);
+      if (lookahead == 's') ADVANCE(111);
       END_STATE();
     case 36:
-      if (lookahead == 'l') ADVANCE(91);
-      if (lookahead == 'n') ADVANCE(92);
+      if (lookahead == 'e') ADVANCE(112);
       END_STATE();
     case 37:
-      if (lookahead == 'r') ADVANCE(93);
+      if (lookahead == 'e') ADVANCE(113);
       END_STATE();
     case 38:
-      if (lookahead == 'n') ADVANCE(94);
+      if (lookahead == 'e') ADVANCE(114);
       END_STATE();
     case 39:
-      if (lookahead == 'e') ADVANCE(95);
+      if (lookahead == 's') ADVANCE(115);
       END_STATE();
     case 40:
-      if (lookahead == 't') ADVANCE(96);
+      if (lookahead == 'c') ADVANCE(116);
       END_STATE();
     case 41:
-      if (lookahead == 'c') ADVANCE(97);
-      if (lookahead == 'l') ADVANCE(98);
-      if (lookahead == 'p') ADVANCE(99);
+      if (lookahead == 't') ADVANCE(117);
       END_STATE();
     case 42:
-      if (lookahead == 'c') ADVANCE(100);
-      if (lookahead == 'e') ADVANCE(101);
+      if (lookahead == 'd') ADVANCE(118);
       END_STATE();
     case 43:
-      if (lookahead == 'r') ADVANCE(102);
+      if (lookahead == 'e') ADVANCE(119);
       END_STATE();
     case 44:
-      if (lookahead == 'c') ADVANCE(103);
+      ACCEPT_TOKEN(anon_sym_at);
+      if (lookahead == '_') ADVANCE(120);
       END_STATE();
     case 45:
-      if (lookahead == 'u') ADVANCE(104);
+      if (lookahead == 't') ADVANCE(121);
       END_STATE();
     case 46:
-      if (lookahead == 'p') ADVANCE(105);
+      if (lookahead == 'e') ADVANCE(122);
       END_STATE();
     case 47:
-      if (lookahead == 'l') ADVANCE(106);
+      if (lookahead == 'f') ADVANCE(123);
+      if (lookahead == 't') ADVANCE(124);
       END_STATE();
     case 48:
-      if (lookahead == 'r') ADVANCE(107);
+      if (lookahead == 't') ADVANCE(125);
       END_STATE();
     case 49:
-      if (lookahead == 'o') ADVANCE(108);
+      ACCEPT_TOKEN(anon_sym_by);
       END_STATE();
     case 50:
-      if (lookahead == 'n') ADVANCE(109);
+      if (lookahead == 'l') ADVANCE(126);
+      if (lookahead == 'n') ADVANCE(127);
       END_STATE();
     case 51:
-      if (lookahead == 'e') ADVANCE(110);
+      if (lookahead == 'r') ADVANCE(128);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(anon_sym_if);
+      if (lookahead == 'e') ADVANCE(129);
       END_STATE();
     case 53:
-      if (lookahead == 'p') ADVANCE(111);
+      if (lookahead == 'n') ADVANCE(130);
+      if (lookahead == 'u') ADVANCE(131);
       END_STATE();
     case 54:
-      ACCEPT_TOKEN(anon_sym_in);
-      if (lookahead == 't') ADVANCE(112);
+      if (lookahead == 'e') ADVANCE(132);
       END_STATE();
     case 55:
-      if (lookahead == '_') ADVANCE(113);
+      if (lookahead == 't') ADVANCE(133);
       END_STATE();
     case 56:
-      if (lookahead == 's') ADVANCE(114);
+      if (lookahead == 'c') ADVANCE(134);
+      if (lookahead == 'l') ADVANCE(135);
+      if (lookahead == 'p') ADVANCE(136);
       END_STATE();
     case 57:
-      if (lookahead == 'g') ADVANCE(115);
+      if (lookahead == 's') ADVANCE(137);
       END_STATE();
     case 58:
-      if (lookahead == 'y') ADVANCE(116);
+      if (lookahead == 'c') ADVANCE(138);
+      if (lookahead == 'e') ADVANCE(139);
       END_STATE();
     case 59:
-      if (lookahead == 's') ADVANCE(117);
+      if (lookahead == 'r') ADVANCE(140);
       END_STATE();
     case 60:
-      if (lookahead == 's') ADVANCE(118);
+      if (lookahead == 's') ADVANCE(141);
       END_STATE();
     case 61:
-      if (lookahead == 't') ADVANCE(119);
+      if (lookahead == 'c') ADVANCE(142);
       END_STATE();
     case 62:
-      ACCEPT_TOKEN(anon_sym_on);
-      if (lookahead == 't') ADVANCE(120);
+      if (lookahead == 'u') ADVANCE(143);
       END_STATE();
     case 63:
-      ACCEPT_TOKEN(anon_sym_or);
+      if (lookahead == 'p') ADVANCE(144);
       END_STATE();
     case 64:
-      if (lookahead == 's') ADVANCE(121);
+      if (lookahead == 'l') ADVANCE(145);
       END_STATE();
     case 65:
-      if (lookahead == 'o') ADVANCE(122);
+      if (lookahead == 'r') ADVANCE(146);
       END_STATE();
     case 66:
-      if (lookahead == 'a') ADVANCE(123);
-      if (lookahead == 'c') ADVANCE(124);
-      if (lookahead == 'g') ADVANCE(125);
-      if (lookahead == 't') ADVANCE(126);
+      if (lookahead == 'o') ADVANCE(147);
       END_STATE();
     case 67:
-      if (lookahead == 'n') ADVANCE(127);
+      if (lookahead == 'n') ADVANCE(148);
       END_STATE();
     case 68:
-      if (lookahead == 'a') ADVANCE(128);
-      if (lookahead == 'o') ADVANCE(129);
+      if (lookahead == 'e') ADVANCE(149);
       END_STATE();
     case 69:
-      if (lookahead == 'o') ADVANCE(130);
+      if (lookahead == 'a') ADVANCE(150);
       END_STATE();
     case 70:
-      if (lookahead == 'e') ADVANCE(131);
-      if (lookahead == 'r') ADVANCE(132);
+      if (lookahead == 's') ADVANCE(151);
       END_STATE();
     case 71:
-      ACCEPT_TOKEN(anon_sym_to);
-      if (lookahead == 'k') ADVANCE(133);
+      ACCEPT_TOKEN(anon_sym_if);
       END_STATE();
     case 72:
-      if (lookahead == 'a') ADVANCE(134);
+      if (lookahead == 'p') ADVANCE(152);
       END_STATE();
     case 73:
-      if (lookahead == 'd') ADVANCE(135);
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 't') ADVANCE(153);
       END_STATE();
     case 74:
-      if (lookahead == 'e') ADVANCE(136);
+      ACCEPT_TOKEN(anon_sym_is);
+      if (lookahead == '_') ADVANCE(154);
       END_STATE();
     case 75:
-      if (lookahead == 'l') ADVANCE(137);
+      if (lookahead == 's') ADVANCE(155);
       END_STATE();
     case 76:
-      if (lookahead == 'r') ADVANCE(138);
+      if (lookahead == 'g') ADVANCE(156);
       END_STATE();
     case 77:
-      if (lookahead == 'e') ADVANCE(139);
+      if (lookahead == 'x') ADVANCE(157);
+      if (lookahead == 'y') ADVANCE(158);
       END_STATE();
     case 78:
-      if (lookahead == 'l') ADVANCE(140);
-      if (lookahead == 't') ADVANCE(141);
+      if (lookahead == 's') ADVANCE(159);
       END_STATE();
     case 79:
-      ACCEPT_TOKEN(anon_sym_API);
+      if (lookahead == 'n') ADVANCE(160);
       END_STATE();
     case 80:
-      if (lookahead == 'i') ADVANCE(142);
+      if (lookahead == 'd') ADVANCE(161);
       END_STATE();
     case 81:
-      if (lookahead == 'l') ADVANCE(143);
+      if (lookahead == 'l') ADVANCE(162);
+      if (lookahead == 's') ADVANCE(163);
       END_STATE();
     case 82:
-      if (lookahead == 'v') ADVANCE(144);
+      if (lookahead == 't') ADVANCE(164);
       END_STATE();
     case 83:
-      if (lookahead == 't') ADVANCE(145);
+      ACCEPT_TOKEN(anon_sym_of);
       END_STATE();
     case 84:
-      if (lookahead == 'r') ADVANCE(146);
+      ACCEPT_TOKEN(anon_sym_on);
+      if (lookahead == 't') ADVANCE(165);
       END_STATE();
     case 85:
-      if (lookahead == 'o') ADVANCE(147);
+      ACCEPT_TOKEN(anon_sym_or);
       END_STATE();
     case 86:
-      if (lookahead == 'e') ADVANCE(148);
+      if (lookahead == 's') ADVANCE(166);
       END_STATE();
     case 87:
-      ACCEPT_TOKEN(anon_sym_and);
+      if (lookahead == 'o') ADVANCE(167);
       END_STATE();
     case 88:
-      if (lookahead == 'l') ADVANCE(149);
-      if (lookahead == 'm') ADVANCE(150);
+      if (lookahead == 'a') ADVANCE(168);
+      if (lookahead == 'c') ADVANCE(169);
+      if (lookahead == 'g') ADVANCE(170);
+      if (lookahead == 't') ADVANCE(171);
       END_STATE();
     case 89:
-      if (lookahead == 'h') ADVANCE(151);
+      if (lookahead == 'n') ADVANCE(172);
+      if (lookahead == 't') ADVANCE(173);
       END_STATE();
     case 90:
-      if (lookahead == 'o') ADVANCE(152);
+      if (lookahead == 'a') ADVANCE(174);
+      if (lookahead == 'o') ADVANCE(175);
       END_STATE();
     case 91:
-      if (lookahead == 'c') ADVANCE(153);
+      if (lookahead == 'o') ADVANCE(176);
+      if (lookahead == 'r') ADVANCE(177);
       END_STATE();
     case 92:
-      ACCEPT_TOKEN(anon_sym_can);
+      if (lookahead == 'm') ADVANCE(178);
       END_STATE();
     case 93:
-      if (lookahead == 't') ADVANCE(154);
+      if (lookahead == 'e') ADVANCE(179);
+      if (lookahead == 'r') ADVANCE(180);
       END_STATE();
     case 94:
-      if (lookahead == 't') ADVANCE(155);
+      ACCEPT_TOKEN(anon_sym_to);
+      if (lookahead == 'k') ADVANCE(181);
       END_STATE();
     case 95:
-      if (lookahead == 'a') ADVANCE(156);
+      if (lookahead == 'a') ADVANCE(182);
       END_STATE();
     case 96:
-      if (lookahead == 'a') ADVANCE(157);
+      if (lookahead == 'i') ADVANCE(183);
       END_STATE();
     case 97:
-      if (lookahead == 'r') ADVANCE(158);
+      if (lookahead == 'd') ADVANCE(184);
       END_STATE();
     case 98:
-      if (lookahead == 'e') ADVANCE(159);
+      if (lookahead == 'e') ADVANCE(185);
       END_STATE();
     case 99:
-      if (lookahead == 'o') ADVANCE(160);
+      if (lookahead == 'l') ADVANCE(186);
       END_STATE();
     case 100:
-      if (lookahead == 'u') ADVANCE(161);
+      if (lookahead == 'r') ADVANCE(187);
       END_STATE();
     case 101:
-      if (lookahead == 's') ADVANCE(162);
+      if (lookahead == 'e') ADVANCE(188);
       END_STATE();
     case 102:
-      if (lookahead == 'i') ADVANCE(163);
+      if (lookahead == 'l') ADVANCE(189);
+      if (lookahead == 't') ADVANCE(190);
       END_STATE();
     case 103:
-      if (lookahead == 'r') ADVANCE(164);
+      ACCEPT_TOKEN(anon_sym_API);
       END_STATE();
     case 104:
-      if (lookahead == 'a') ADVANCE(165);
+      if (lookahead == 'i') ADVANCE(191);
       END_STATE();
     case 105:
-      if (lookahead == 'o') ADVANCE(166);
+      if (lookahead == 'a') ADVANCE(192);
       END_STATE();
     case 106:
-      if (lookahead == 'e') ADVANCE(167);
+      ACCEPT_TOKEN(anon_sym_And);
       END_STATE();
     case 107:
-      ACCEPT_TOKEN(anon_sym_for);
+      if (lookahead == 'l') ADVANCE(193);
       END_STATE();
     case 108:
-      if (lookahead == 'm') ADVANCE(168);
+      if (lookahead == 'u') ADVANCE(194);
       END_STATE();
     case 109:
-      if (lookahead == 'e') ADVANCE(169);
+      if (lookahead == 'e') ADVANCE(195);
       END_STATE();
     case 110:
-      if (lookahead == 'a') ADVANCE(170);
+      if (lookahead == 'v') ADVANCE(196);
       END_STATE();
     case 111:
-      if (lookahead == 'o') ADVANCE(171);
+      if (lookahead == 't') ADVANCE(197);
       END_STATE();
     case 112:
-      if (lookahead == 'o') ADVANCE(172);
+      if (lookahead == 'n') ADVANCE(198);
       END_STATE();
     case 113:
-      if (lookahead == 'n') ADVANCE(173);
-      if (lookahead == 's') ADVANCE(174);
+      if (lookahead == 'r') ADVANCE(199);
       END_STATE();
     case 114:
-      if (lookahead == 's') ADVANCE(175);
+      if (lookahead == 'n') ADVANCE(200);
       END_STATE();
     case 115:
-      if (lookahead == 'i') ADVANCE(176);
-      if (lookahead == 'o') ADVANCE(177);
+      ACCEPT_TOKEN(anon_sym_abs);
       END_STATE();
     case 116:
-      ACCEPT_TOKEN(anon_sym_may);
+      if (lookahead == 'o') ADVANCE(201);
       END_STATE();
     case 117:
-      if (lookahead == 's') ADVANCE(178);
+      if (lookahead == 'e') ADVANCE(202);
       END_STATE();
     case 118:
-      if (lookahead == 't') ADVANCE(179);
+      ACCEPT_TOKEN(anon_sym_and);
       END_STATE();
     case 119:
-      ACCEPT_TOKEN(anon_sym_not);
-      if (lookahead == '_') ADVANCE(180);
+      ACCEPT_TOKEN(anon_sym_are);
       END_STATE();
     case 120:
-      if (lookahead == 'o') ADVANCE(181);
+      if (lookahead == 'l') ADVANCE(203);
+      if (lookahead == 'm') ADVANCE(204);
       END_STATE();
     case 121:
-      if (lookahead == 's') ADVANCE(182);
+      if (lookahead == 'h') ADVANCE(205);
       END_STATE();
     case 122:
-      if (lookahead == 'c') ADVANCE(183);
+      if (lookahead == 'r') ADVANCE(206);
       END_STATE();
     case 123:
-      if (lookahead == 'd') ADVANCE(184);
+      if (lookahead == 'o') ADVANCE(207);
       END_STATE();
     case 124:
-      if (lookahead == 'e') ADVANCE(185);
+      if (lookahead == 'w') ADVANCE(208);
       END_STATE();
     case 125:
-      if (lookahead == 'i') ADVANCE(186);
+      ACCEPT_TOKEN(anon_sym_bit);
       END_STATE();
     case 126:
-      if (lookahead == 'r') ADVANCE(187);
+      if (lookahead == 'c') ADVANCE(209);
       END_STATE();
     case 127:
-      if (lookahead == 'd') ADVANCE(188);
+      ACCEPT_TOKEN(anon_sym_can);
       END_STATE();
     case 128:
-      if (lookahead == 'l') ADVANCE(189);
+      if (lookahead == 't') ADVANCE(210);
       END_STATE();
     case 129:
-      if (lookahead == 'u') ADVANCE(190);
+      if (lookahead == 'a') ADVANCE(211);
       END_STATE();
     case 130:
-      if (lookahead == 'r') ADVANCE(191);
+      if (lookahead == 't') ADVANCE(212);
       END_STATE();
     case 131:
-      ACCEPT_TOKEN(anon_sym_the);
+      if (lookahead == 'n') ADVANCE(213);
       END_STATE();
     case 132:
-      if (lookahead == 'o') ADVANCE(192);
+      if (lookahead == 'a') ADVANCE(214);
       END_STATE();
     case 133:
-      if (lookahead == 'e') ADVANCE(193);
+      if (lookahead == 'a') ADVANCE(215);
       END_STATE();
     case 134:
-      if (lookahead == 'n') ADVANCE(194);
+      if (lookahead == 'r') ADVANCE(216);
       END_STATE();
     case 135:
-      if (lookahead == 'a') ADVANCE(195);
+      if (lookahead == 'e') ADVANCE(217);
       END_STATE();
     case 136:
-      if (lookahead == 'r') ADVANCE(196);
+      if (lookahead == 'o') ADVANCE(218);
       END_STATE();
     case 137:
-      if (lookahead == 'i') ADVANCE(197);
+      if (lookahead == 't') ADVANCE(219);
       END_STATE();
     case 138:
-      if (lookahead == 'i') ADVANCE(198);
+      if (lookahead == 'u') ADVANCE(220);
       END_STATE();
     case 139:
-      if (lookahead == 'r') ADVANCE(199);
+      if (lookahead == 's') ADVANCE(221);
       END_STATE();
     case 140:
-      if (lookahead == 'l') ADVANCE(200);
+      if (lookahead == 'i') ADVANCE(222);
       END_STATE();
     case 141:
-      if (lookahead == 'h') ADVANCE(201);
+      if (lookahead == 'e') ADVANCE(223);
       END_STATE();
     case 142:
-      if (lookahead == 'n') ADVANCE(202);
+      if (lookahead == 'r') ADVANCE(224);
       END_STATE();
     case 143:
-      if (lookahead == 'i') ADVANCE(203);
+      if (lookahead == 'a') ADVANCE(225);
       END_STATE();
     case 144:
-      if (lookahead == 'i') ADVANCE(204);
+      if (lookahead == 'o') ADVANCE(226);
       END_STATE();
     case 145:
-      if (lookahead == 'e') ADVANCE(205);
+      if (lookahead == 'e') ADVANCE(227);
       END_STATE();
     case 146:
-      ACCEPT_TOKEN(anon_sym_User);
+      ACCEPT_TOKEN(anon_sym_for);
       END_STATE();
     case 147:
-      if (lookahead == 'u') ADVANCE(206);
+      if (lookahead == 'm') ADVANCE(228);
       END_STATE();
     case 148:
-      if (lookahead == 'r') ADVANCE(207);
+      if (lookahead == 'e') ADVANCE(229);
       END_STATE();
     case 149:
-      if (lookahead == 'e') ADVANCE(208);
+      if (lookahead == 'a') ADVANCE(230);
       END_STATE();
     case 150:
-      if (lookahead == 'o') ADVANCE(209);
+      if (lookahead == 'r') ADVANCE(231);
       END_STATE();
     case 151:
-      if (lookahead == 'e') ADVANCE(210);
-      if (lookahead == 'o') ADVANCE(211);
+      ACCEPT_TOKEN(anon_sym_has);
       END_STATE();
     case 152:
-      if (lookahead == 'r') ADVANCE(212);
+      if (lookahead == 'o') ADVANCE(232);
       END_STATE();
     case 153:
-      if (lookahead == 'u') ADVANCE(213);
+      if (lookahead == 'o') ADVANCE(233);
       END_STATE();
     case 154:
-      if (lookahead == 'i') ADVANCE(214);
+      if (lookahead == 'n') ADVANCE(234);
+      if (lookahead == 's') ADVANCE(235);
       END_STATE();
     case 155:
-      if (lookahead == 'a') ADVANCE(215);
+      if (lookahead == 's') ADVANCE(236);
       END_STATE();
     case 156:
-      if (lookahead == 't') ADVANCE(216);
+      if (lookahead == 'i') ADVANCE(237);
+      if (lookahead == 'o') ADVANCE(238);
       END_STATE();
     case 157:
-      if (lookahead == '_') ADVANCE(217);
+      ACCEPT_TOKEN(anon_sym_max);
       END_STATE();
     case 158:
-      if (lookahead == 'y') ADVANCE(218);
+      ACCEPT_TOKEN(anon_sym_may);
       END_STATE();
     case 159:
-      if (lookahead == 't') ADVANCE(219);
+      if (lookahead == 's') ADVANCE(239);
       END_STATE();
     case 160:
-      if (lookahead == 's') ADVANCE(220);
+      ACCEPT_TOKEN(anon_sym_min);
       END_STATE();
     case 161:
-      if (lookahead == 'm') ADVANCE(221);
+      ACCEPT_TOKEN(anon_sym_mod);
       END_STATE();
     case 162:
-      if (lookahead == '_') ADVANCE(222);
+      if (lookahead == 't') ADVANCE(240);
       END_STATE();
     case 163:
-      if (lookahead == 'n') ADVANCE(223);
+      if (lookahead == 't') ADVANCE(241);
       END_STATE();
     case 164:
-      if (lookahead == 'y') ADVANCE(224);
+      ACCEPT_TOKEN(anon_sym_not);
+      if (lookahead == '_') ADVANCE(242);
       END_STATE();
     case 165:
-      if (lookahead == 'l') ADVANCE(225);
+      if (lookahead == 'o') ADVANCE(243);
       END_STATE();
     case 166:
-      if (lookahead == 'r') ADVANCE(226);
+      if (lookahead == 's') ADVANCE(244);
       END_STATE();
     case 167:
-      if (lookahead == '_') ADVANCE(227);
+      if (lookahead == 'c') ADVANCE(245);
       END_STATE();
     case 168:
-      ACCEPT_TOKEN(anon_sym_from);
+      if (lookahead == 'd') ADVANCE(246);
       END_STATE();
     case 169:
-      if (lookahead == 'r') ADVANCE(228);
+      if (lookahead == 'e') ADVANCE(247);
       END_STATE();
     case 170:
-      if (lookahead == 't') ADVANCE(229);
+      if (lookahead == 'i') ADVANCE(248);
       END_STATE();
     case 171:
-      if (lookahead == 'r') ADVANCE(230);
+      if (lookahead == 'r') ADVANCE(249);
       END_STATE();
     case 172:
-      ACCEPT_TOKEN(anon_sym_into);
+      if (lookahead == 'd') ADVANCE(250);
       END_STATE();
     case 173:
-      if (lookahead == 'o') ADVANCE(231);
+      ACCEPT_TOKEN(anon_sym_set);
       END_STATE();
     case 174:
-      if (lookahead == 'e') ADVANCE(232);
+      if (lookahead == 'l') ADVANCE(251);
       END_STATE();
     case 175:
-      if (lookahead == '_') ADVANCE(233);
+      if (lookahead == 'u') ADVANCE(252);
       END_STATE();
     case 176:
-      if (lookahead == 'n') ADVANCE(234);
+      if (lookahead == 'r') ADVANCE(253);
       END_STATE();
     case 177:
-      if (lookahead == 'u') ADVANCE(235);
+      if (lookahead == 'i') ADVANCE(254);
       END_STATE();
     case 178:
-      if (lookahead == 'a') ADVANCE(236);
+      ACCEPT_TOKEN(anon_sym_sum);
       END_STATE();
     case 179:
-      ACCEPT_TOKEN(anon_sym_must);
+      ACCEPT_TOKEN(anon_sym_the);
+      if (lookahead == 'n') ADVANCE(255);
       END_STATE();
     case 180:
-      if (lookahead == 'e') ADVANCE(237);
+      if (lookahead == 'o') ADVANCE(256);
       END_STATE();
     case 181:
-      ACCEPT_TOKEN(anon_sym_onto);
+      if (lookahead == 'e') ADVANCE(257);
       END_STATE();
     case 182:
-      if (lookahead == 'w') ADVANCE(238);
+      if (lookahead == 'n') ADVANCE(258);
       END_STATE();
     case 183:
-      if (lookahead == 'e') ADVANCE(239);
+      if (lookahead == 'q') ADVANCE(259);
       END_STATE();
     case 184:
-      ACCEPT_TOKEN(anon_sym_read);
+      if (lookahead == 'a') ADVANCE(260);
       END_STATE();
     case 185:
-      if (lookahead == 'i') ADVANCE(240);
+      if (lookahead == 'r') ADVANCE(261);
       END_STATE();
     case 186:
-      if (lookahead == 's') ADVANCE(241);
+      if (lookahead == 'i') ADVANCE(262);
       END_STATE();
     case 187:
-      if (lookahead == 'i') ADVANCE(242);
+      if (lookahead == 'i') ADVANCE(263);
       END_STATE();
     case 188:
-      ACCEPT_TOKEN(anon_sym_send);
+      if (lookahead == 'r') ADVANCE(264);
       END_STATE();
     case 189:
-      if (lookahead == 'l') ADVANCE(243);
+      if (lookahead == 'l') ADVANCE(265);
       END_STATE();
     case 190:
-      if (lookahead == 'l') ADVANCE(244);
+      if (lookahead == 'h') ADVANCE(266);
       END_STATE();
     case 191:
-      if (lookahead == 'e') ADVANCE(245);
+      if (lookahead == 'n') ADVANCE(267);
       END_STATE();
     case 192:
-      if (lookahead == 'u') ADVANCE(246);
+      if (lookahead == 'y') ADVANCE(268);
       END_STATE();
     case 193:
-      if (lookahead == 'n') ADVANCE(247);
+      if (lookahead == 'i') ADVANCE(269);
       END_STATE();
     case 194:
-      if (lookahead == 's') ADVANCE(248);
+      if (lookahead == 'm') ADVANCE(270);
       END_STATE();
     case 195:
-      if (lookahead == 't') ADVANCE(249);
+      if (lookahead == 'n') ADVANCE(271);
       END_STATE();
     case 196:
-      if (lookahead == '_') ADVANCE(250);
+      if (lookahead == 'i') ADVANCE(272);
       END_STATE();
     case 197:
-      if (lookahead == 'd') ADVANCE(251);
+      if (lookahead == 'e') ADVANCE(273);
       END_STATE();
     case 198:
-      if (lookahead == 'f') ADVANCE(252);
+      ACCEPT_TOKEN(anon_sym_Then);
       END_STATE();
     case 199:
-      if (lookahead == 'e') ADVANCE(253);
+      ACCEPT_TOKEN(anon_sym_User);
       END_STATE();
     case 200:
-      ACCEPT_TOKEN(anon_sym_will);
+      ACCEPT_TOKEN(anon_sym_When);
       END_STATE();
     case 201:
-      ACCEPT_TOKEN(anon_sym_with);
-      if (lookahead == 'd') ADVANCE(254);
-      if (lookahead == 'o') ADVANCE(255);
+      if (lookahead == 'u') ADVANCE(274);
       END_STATE();
     case 202:
-      ACCEPT_TOKEN(anon_sym_Admin);
+      if (lookahead == 'r') ADVANCE(275);
       END_STATE();
     case 203:
-      if (lookahead == 'c') ADVANCE(256);
+      if (lookahead == 'e') ADVANCE(276);
       END_STATE();
     case 204:
-      if (lookahead == 'c') ADVANCE(257);
+      if (lookahead == 'o') ADVANCE(277);
       END_STATE();
     case 205:
-      if (lookahead == 'm') ADVANCE(258);
+      if (lookahead == 'e') ADVANCE(278);
+      if (lookahead == 'o') ADVANCE(279);
       END_STATE();
     case 206:
-      if (lookahead == 'n') ADVANCE(259);
+      if (lookahead == 'a') ADVANCE(280);
       END_STATE();
     case 207:
-      ACCEPT_TOKEN(anon_sym_after);
+      if (lookahead == 'r') ADVANCE(281);
       END_STATE();
     case 208:
-      if (lookahead == 'a') ADVANCE(260);
+      if (lookahead == 'e') ADVANCE(282);
       END_STATE();
     case 209:
-      if (lookahead == 's') ADVANCE(261);
+      if (lookahead == 'u') ADVANCE(283);
       END_STATE();
     case 210:
-      if (lookahead == 'n') ADVANCE(262);
+      if (lookahead == 'i') ADVANCE(284);
       END_STATE();
     case 211:
-      if (lookahead == 'r') ADVANCE(263);
+      if (lookahead == 'r') ADVANCE(285);
       END_STATE();
     case 212:
-      if (lookahead == 'e') ADVANCE(264);
+      if (lookahead == 'a') ADVANCE(286);
       END_STATE();
     case 213:
-      if (lookahead == 'l') ADVANCE(265);
+      if (lookahead == 't') ADVANCE(287);
       END_STATE();
     case 214:
-      if (lookahead == 'f') ADVANCE(266);
+      if (lookahead == 't') ADVANCE(288);
       END_STATE();
     case 215:
-      if (lookahead == 'i') ADVANCE(267);
+      if (lookahead == '_') ADVANCE(289);
       END_STATE();
     case 216:
-      if (lookahead == 'e') ADVANCE(268);
+      if (lookahead == 'y') ADVANCE(290);
       END_STATE();
     case 217:
-      if (lookahead == 'r') ADVANCE(269);
+      if (lookahead == 't') ADVANCE(291);
       END_STATE();
     case 218:
-      if (lookahead == 'p') ADVANCE(270);
+      if (lookahead == 's') ADVANCE(292);
       END_STATE();
     case 219:
-      if (lookahead == 'e') ADVANCE(271);
+      if (lookahead == 'i') ADVANCE(293);
       END_STATE();
     case 220:
-      if (lookahead == 'i') ADVANCE(272);
+      if (lookahead == 'm') ADVANCE(294);
       END_STATE();
     case 221:
-      if (lookahead == 'e') ADVANCE(273);
+      if (lookahead == '_') ADVANCE(295);
       END_STATE();
     case 222:
-      if (lookahead == 'n') ADVANCE(274);
+      if (lookahead == 'n') ADVANCE(296);
       END_STATE();
     case 223:
-      if (lookahead == 'g') ADVANCE(275);
+      ACCEPT_TOKEN(anon_sym_else);
       END_STATE();
     case 224:
-      if (lookahead == 'p') ADVANCE(276);
+      if (lookahead == 'y') ADVANCE(297);
       END_STATE();
     case 225:
-      if (lookahead == 's') ADVANCE(277);
+      if (lookahead == 'l') ADVANCE(298);
       END_STATE();
     case 226:
-      if (lookahead == 't') ADVANCE(278);
+      if (lookahead == 'r') ADVANCE(299);
       END_STATE();
     case 227:
-      if (lookahead == 'd') ADVANCE(279);
+      if (lookahead == '_') ADVANCE(300);
       END_STATE();
     case 228:
-      if (lookahead == 'a') ADVANCE(280);
+      ACCEPT_TOKEN(anon_sym_from);
       END_STATE();
     case 229:
-      if (lookahead == 'e') ADVANCE(281);
+      if (lookahead == 'r') ADVANCE(301);
       END_STATE();
     case 230:
-      if (lookahead == 't') ADVANCE(282);
+      if (lookahead == 't') ADVANCE(302);
       END_STATE();
     case 231:
-      if (lookahead == 't') ADVANCE(283);
+      if (lookahead == 'a') ADVANCE(303);
       END_STATE();
     case 232:
-      if (lookahead == 't') ADVANCE(284);
+      if (lookahead == 'r') ADVANCE(304);
       END_STATE();
     case 233:
-      if (lookahead == 't') ADVANCE(285);
+      ACCEPT_TOKEN(anon_sym_into);
       END_STATE();
     case 234:
-      ACCEPT_TOKEN(anon_sym_login);
+      if (lookahead == 'o') ADVANCE(305);
       END_STATE();
     case 235:
-      if (lookahead == 't') ADVANCE(286);
+      if (lookahead == 'e') ADVANCE(306);
       END_STATE();
     case 236:
-      if (lookahead == 'g') ADVANCE(287);
+      if (lookahead == '_') ADVANCE(307);
       END_STATE();
     case 237:
-      if (lookahead == 'q') ADVANCE(288);
+      if (lookahead == 'n') ADVANCE(308);
       END_STATE();
     case 238:
-      if (lookahead == 'o') ADVANCE(289);
+      if (lookahead == 'u') ADVANCE(309);
       END_STATE();
     case 239:
-      if (lookahead == 's') ADVANCE(290);
+      if (lookahead == 'a') ADVANCE(310);
       END_STATE();
     case 240:
-      if (lookahead == 'v') ADVANCE(291);
+      if (lookahead == 'i') ADVANCE(311);
       END_STATE();
     case 241:
-      if (lookahead == 't') ADVANCE(292);
+      ACCEPT_TOKEN(anon_sym_must);
       END_STATE();
     case 242:
-      if (lookahead == 'e') ADVANCE(293);
+      if (lookahead == 'e') ADVANCE(312);
       END_STATE();
     case 243:
-      ACCEPT_TOKEN(anon_sym_shall);
+      ACCEPT_TOKEN(anon_sym_onto);
       END_STATE();
     case 244:
-      if (lookahead == 'd') ADVANCE(294);
+      if (lookahead == 'w') ADVANCE(313);
       END_STATE();
     case 245:
-      ACCEPT_TOKEN(anon_sym_store);
+      if (lookahead == 'e') ADVANCE(314);
       END_STATE();
     case 246:
-      if (lookahead == 'g') ADVANCE(295);
+      ACCEPT_TOKEN(anon_sym_read);
       END_STATE();
     case 247:
-      if (lookahead == '_') ADVANCE(296);
+      if (lookahead == 'i') ADVANCE(315);
       END_STATE();
     case 248:
-      if (lookahead == 'a') ADVANCE(297);
-      if (lookahead == 'f') ADVANCE(298);
+      if (lookahead == 's') ADVANCE(316);
       END_STATE();
     case 249:
-      if (lookahead == 'e') ADVANCE(299);
+      if (lookahead == 'i') ADVANCE(317);
       END_STATE();
     case 250:
-      if (lookahead == 'd') ADVANCE(300);
+      ACCEPT_TOKEN(anon_sym_send);
       END_STATE();
     case 251:
-      if (lookahead == 'a') ADVANCE(301);
+      if (lookahead == 'l') ADVANCE(318);
       END_STATE();
     case 252:
-      if (lookahead == 'y') ADVANCE(302);
+      if (lookahead == 'l') ADVANCE(319);
       END_STATE();
     case 253:
-      ACCEPT_TOKEN(anon_sym_where);
+      if (lookahead == 'e') ADVANCE(320);
       END_STATE();
     case 254:
-      if (lookahead == 'r') ADVANCE(303);
+      if (lookahead == 'c') ADVANCE(321);
       END_STATE();
     case 255:
-      if (lookahead == 'u') ADVANCE(304);
+      ACCEPT_TOKEN(anon_sym_then);
       END_STATE();
     case 256:
-      if (lookahead == 'a') ADVANCE(305);
+      if (lookahead == 'u') ADVANCE(322);
       END_STATE();
     case 257:
-      if (lookahead == 'e') ADVANCE(306);
+      if (lookahead == 'n') ADVANCE(323);
       END_STATE();
     case 258:
-      ACCEPT_TOKEN(anon_sym_System);
+      if (lookahead == 's') ADVANCE(324);
       END_STATE();
     case 259:
-      if (lookahead == 't') ADVANCE(307);
+      if (lookahead == 'u') ADVANCE(325);
       END_STATE();
     case 260:
-      if (lookahead == 's') ADVANCE(308);
+      if (lookahead == 't') ADVANCE(326);
       END_STATE();
     case 261:
-      if (lookahead == 't') ADVANCE(309);
+      if (lookahead == '_') ADVANCE(327);
       END_STATE();
     case 262:
-      if (lookahead == 't') ADVANCE(310);
+      if (lookahead == 'd') ADVANCE(328);
       END_STATE();
     case 263:
-      if (lookahead == 'i') ADVANCE(311);
+      if (lookahead == 'f') ADVANCE(329);
       END_STATE();
     case 264:
-      ACCEPT_TOKEN(anon_sym_before);
+      if (lookahead == 'e') ADVANCE(330);
       END_STATE();
     case 265:
-      if (lookahead == 'a') ADVANCE(312);
+      ACCEPT_TOKEN(anon_sym_will);
       END_STATE();
     case 266:
-      if (lookahead == 'i') ADVANCE(313);
+      ACCEPT_TOKEN(anon_sym_with);
+      if (lookahead == 'd') ADVANCE(331);
+      if (lookahead == 'o') ADVANCE(332);
       END_STATE();
     case 267:
-      if (lookahead == 'n') ADVANCE(314);
+      ACCEPT_TOKEN(anon_sym_Admin);
       END_STATE();
     case 268:
-      ACCEPT_TOKEN(anon_sym_create);
+      if (lookahead == 's') ADVANCE(333);
       END_STATE();
     case 269:
-      if (lookahead == 'e') ADVANCE(315);
+      if (lookahead == 'c') ADVANCE(334);
       END_STATE();
     case 270:
-      if (lookahead == 't') ADVANCE(316);
+      if (lookahead == 'e') ADVANCE(335);
+      if (lookahead == 'i') ADVANCE(336);
       END_STATE();
     case 271:
-      ACCEPT_TOKEN(anon_sym_delete);
+      ACCEPT_TOKEN(anon_sym_Given);
       END_STATE();
     case 272:
-      if (lookahead == 't') ADVANCE(317);
+      if (lookahead == 'c') ADVANCE(337);
       END_STATE();
     case 273:
-      if (lookahead == 'n') ADVANCE(318);
+      if (lookahead == 'm') ADVANCE(338);
       END_STATE();
     case 274:
-      if (lookahead == 'o') ADVANCE(319);
+      if (lookahead == 'n') ADVANCE(339);
       END_STATE();
     case 275:
-      ACCEPT_TOKEN(anon_sym_during);
+      ACCEPT_TOKEN(anon_sym_after);
       END_STATE();
     case 276:
-      if (lookahead == 't') ADVANCE(320);
+      if (lookahead == 'a') ADVANCE(340);
       END_STATE();
     case 277:
-      ACCEPT_TOKEN(anon_sym_equals);
+      if (lookahead == 's') ADVANCE(341);
       END_STATE();
     case 278:
-      ACCEPT_TOKEN(anon_sym_export);
+      if (lookahead == 'n') ADVANCE(342);
       END_STATE();
     case 279:
-      if (lookahead == 'a') ADVANCE(321);
+      if (lookahead == 'r') ADVANCE(343);
       END_STATE();
     case 280:
-      if (lookahead == 't') ADVANCE(322);
+      if (lookahead == 'g') ADVANCE(344);
       END_STATE();
     case 281:
-      if (lookahead == 'r') ADVANCE(323);
+      if (lookahead == 'e') ADVANCE(345);
       END_STATE();
     case 282:
-      ACCEPT_TOKEN(anon_sym_import);
+      if (lookahead == 'e') ADVANCE(346);
       END_STATE();
     case 283:
-      if (lookahead == '_') ADVANCE(324);
+      if (lookahead == 'l') ADVANCE(347);
       END_STATE();
     case 284:
-      ACCEPT_TOKEN(anon_sym_is_set);
+      if (lookahead == 'f') ADVANCE(348);
       END_STATE();
     case 285:
-      if (lookahead == 'h') ADVANCE(325);
+      ACCEPT_TOKEN(anon_sym_clear);
       END_STATE();
     case 286:
-      ACCEPT_TOKEN(anon_sym_logout);
+      if (lookahead == 'i') ADVANCE(349);
       END_STATE();
     case 287:
-      if (lookahead == 'e') ADVANCE(326);
+      ACCEPT_TOKEN(anon_sym_count);
       END_STATE();
     case 288:
-      if (lookahead == 'u') ADVANCE(327);
+      if (lookahead == 'e') ADVANCE(350);
       END_STATE();
     case 289:
-      if (lookahead == 'r') ADVANCE(328);
+      if (lookahead == 'r') ADVANCE(351);
       END_STATE();
     case 290:
-      if (lookahead == 's') ADVANCE(329);
+      if (lookahead == 'p') ADVANCE(352);
       END_STATE();
     case 291:
-      if (lookahead == 'e') ADVANCE(330);
+      if (lookahead == 'e') ADVANCE(353);
       END_STATE();
     case 292:
-      if (lookahead == 'e') ADVANCE(331);
+      if (lookahead == 'i') ADVANCE(354);
       END_STATE();
     case 293:
-      if (lookahead == 'v') ADVANCE(332);
+      if (lookahead == 'n') ADVANCE(355);
       END_STATE();
     case 294:
-      ACCEPT_TOKEN(anon_sym_should);
+      if (lookahead == 'e') ADVANCE(356);
       END_STATE();
     case 295:
-      if (lookahead == 'h') ADVANCE(333);
+      if (lookahead == 'n') ADVANCE(357);
       END_STATE();
     case 296:
-      if (lookahead == 'd') ADVANCE(334);
+      if (lookahead == 'g') ADVANCE(358);
       END_STATE();
     case 297:
-      if (lookahead == 'c') ADVANCE(335);
+      if (lookahead == 'p') ADVANCE(359);
       END_STATE();
     case 298:
-      if (lookahead == 'e') ADVANCE(336);
+      if (lookahead == 's') ADVANCE(360);
       END_STATE();
     case 299:
-      ACCEPT_TOKEN(anon_sym_update);
+      if (lookahead == 't') ADVANCE(361);
       END_STATE();
     case 300:
-      if (lookahead == 'a') ADVANCE(337);
+      if (lookahead == 'd') ADVANCE(362);
       END_STATE();
     case 301:
-      if (lookahead == 't') ADVANCE(338);
+      if (lookahead == 'a') ADVANCE(363);
       END_STATE();
     case 302:
-      ACCEPT_TOKEN(anon_sym_verify);
+      if (lookahead == 'e') ADVANCE(364);
       END_STATE();
     case 303:
-      if (lookahead == 'a') ADVANCE(339);
+      if (lookahead == 'n') ADVANCE(365);
       END_STATE();
     case 304:
-      if (lookahead == 't') ADVANCE(340);
+      if (lookahead == 't') ADVANCE(366);
       END_STATE();
     case 305:
-      if (lookahead == 't') ADVANCE(341);
+      if (lookahead == 't') ADVANCE(367);
       END_STATE();
     case 306:
-      ACCEPT_TOKEN(anon_sym_Service);
+      if (lookahead == 't') ADVANCE(368);
       END_STATE();
     case 307:
-      if (lookahead == '_') ADVANCE(342);
+      if (lookahead == 't') ADVANCE(369);
       END_STATE();
     case 308:
-      if (lookahead == 't') ADVANCE(343);
+      ACCEPT_TOKEN(anon_sym_login);
       END_STATE();
     case 309:
-      ACCEPT_TOKEN(anon_sym_at_most);
+      if (lookahead == 't') ADVANCE(370);
       END_STATE();
     case 310:
-      if (lookahead == 'i') ADVANCE(344);
+      if (lookahead == 'g') ADVANCE(371);
       END_STATE();
     case 311:
-      if (lookahead == 'z') ADVANCE(345);
+      if (lookahead == 'p') ADVANCE(372);
       END_STATE();
     case 312:
-      if (lookahead == 't') ADVANCE(346);
+      if (lookahead == 'q') ADVANCE(373);
       END_STATE();
     case 313:
-      if (lookahead == 'c') ADVANCE(347);
+      if (lookahead == 'o') ADVANCE(374);
       END_STATE();
     case 314:
-      if (lookahead == 's') ADVANCE(348);
+      if (lookahead == 's') ADVANCE(375);
       END_STATE();
     case 315:
-      if (lookahead == 'c') ADVANCE(349);
+      if (lookahead == 'v') ADVANCE(376);
       END_STATE();
     case 316:
-      ACCEPT_TOKEN(anon_sym_decrypt);
+      if (lookahead == 't') ADVANCE(377);
       END_STATE();
     case 317:
-      ACCEPT_TOKEN(anon_sym_deposit);
+      if (lookahead == 'e') ADVANCE(378);
       END_STATE();
     case 318:
-      if (lookahead == 't') ADVANCE(350);
+      ACCEPT_TOKEN(anon_sym_shall);
       END_STATE();
     case 319:
-      if (lookahead == 't') ADVANCE(351);
+      if (lookahead == 'd') ADVANCE(379);
       END_STATE();
     case 320:
-      ACCEPT_TOKEN(anon_sym_encrypt);
+      ACCEPT_TOKEN(anon_sym_store);
       END_STATE();
     case 321:
-      if (lookahead == 't') ADVANCE(352);
+      if (lookahead == 't') ADVANCE(380);
       END_STATE();
     case 322:
-      if (lookahead == 'e') ADVANCE(353);
+      if (lookahead == 'g') ADVANCE(381);
       END_STATE();
     case 323:
-      if (lookahead == '_') ADVANCE(354);
+      if (lookahead == '_') ADVANCE(382);
       END_STATE();
     case 324:
-      if (lookahead == 's') ADVANCE(355);
+      if (lookahead == 'a') ADVANCE(383);
+      if (lookahead == 'f') ADVANCE(384);
       END_STATE();
     case 325:
-      if (lookahead == 'a') ADVANCE(356);
+      if (lookahead == 'e') ADVANCE(385);
       END_STATE();
     case 326:
-      if (lookahead == '_') ADVANCE(357);
+      if (lookahead == 'e') ADVANCE(386);
       END_STATE();
     case 327:
-      if (lookahead == 'a') ADVANCE(358);
+      if (lookahead == 'd') ADVANCE(387);
       END_STATE();
     case 328:
-      if (lookahead == 'd') ADVANCE(359);
+      if (lookahead == 'a') ADVANCE(388);
       END_STATE();
     case 329:
-      ACCEPT_TOKEN(anon_sym_process);
+      if (lookahead == 'y') ADVANCE(389);
       END_STATE();
     case 330:
-      ACCEPT_TOKEN(anon_sym_receive);
+      ACCEPT_TOKEN(anon_sym_where);
       END_STATE();
     case 331:
-      if (lookahead == 'r') ADVANCE(360);
+      if (lookahead == 'r') ADVANCE(390);
       END_STATE();
     case 332:
-      if (lookahead == 'e') ADVANCE(361);
+      if (lookahead == 'u') ADVANCE(391);
       END_STATE();
     case 333:
-      ACCEPT_TOKEN(anon_sym_through);
+      ACCEPT_TOKEN(anon_sym_Always);
       END_STATE();
     case 334:
-      if (lookahead == 'a') ADVANCE(362);
+      if (lookahead == 'a') ADVANCE(392);
       END_STATE();
     case 335:
-      if (lookahead == 't') ADVANCE(363);
+      ACCEPT_TOKEN(anon_sym_Assume);
       END_STATE();
     case 336:
-      if (lookahead == 'r') ADVANCE(364);
+      if (lookahead == 'n') ADVANCE(393);
       END_STATE();
     case 337:
-      if (lookahead == 't') ADVANCE(365);
+      if (lookahead == 'e') ADVANCE(394);
       END_STATE();
     case 338:
-      if (lookahead == 'e') ADVANCE(366);
+      ACCEPT_TOKEN(anon_sym_System);
       END_STATE();
     case 339:
-      if (lookahead == 'w') ADVANCE(367);
+      if (lookahead == 't') ADVANCE(395);
       END_STATE();
     case 340:
-      ACCEPT_TOKEN(anon_sym_without);
+      if (lookahead == 's') ADVANCE(396);
       END_STATE();
     case 341:
-      if (lookahead == 'i') ADVANCE(368);
+      if (lookahead == 't') ADVANCE(397);
       END_STATE();
     case 342:
-      if (lookahead == 'd') ADVANCE(369);
+      if (lookahead == 't') ADVANCE(398);
       END_STATE();
     case 343:
-      ACCEPT_TOKEN(anon_sym_at_least);
+      if (lookahead == 'i') ADVANCE(399);
       END_STATE();
     case 344:
-      if (lookahead == 'c') ADVANCE(370);
+      if (lookahead == 'e') ADVANCE(400);
       END_STATE();
     case 345:
-      if (lookahead == 'e') ADVANCE(371);
+      ACCEPT_TOKEN(anon_sym_before);
       END_STATE();
     case 346:
-      if (lookahead == 'e') ADVANCE(372);
+      if (lookahead == 'n') ADVANCE(401);
       END_STATE();
     case 347:
-      if (lookahead == 'a') ADVANCE(373);
+      if (lookahead == 'a') ADVANCE(402);
       END_STATE();
     case 348:
-      ACCEPT_TOKEN(anon_sym_contains);
+      if (lookahead == 'i') ADVANCE(403);
       END_STATE();
     case 349:
-      if (lookahead == 'o') ADVANCE(374);
+      if (lookahead == 'n') ADVANCE(404);
       END_STATE();
     case 350:
-      if (lookahead == '_') ADVANCE(375);
+      ACCEPT_TOKEN(anon_sym_create);
       END_STATE();
     case 351:
-      if (lookahead == '_') ADVANCE(376);
+      if (lookahead == 'e') ADVANCE(405);
       END_STATE();
     case 352:
-      if (lookahead == 'a') ADVANCE(377);
+      if (lookahead == 't') ADVANCE(406);
       END_STATE();
     case 353:
-      ACCEPT_TOKEN(anon_sym_generate);
+      ACCEPT_TOKEN(anon_sym_delete);
       END_STATE();
     case 354:
-      if (lookahead == 't') ADVANCE(378);
+      if (lookahead == 't') ADVANCE(407);
       END_STATE();
     case 355:
-      if (lookahead == 'e') ADVANCE(379);
+      if (lookahead == 'c') ADVANCE(408);
       END_STATE();
     case 356:
-      if (lookahead == 'n') ADVANCE(380);
+      if (lookahead == 'n') ADVANCE(409);
       END_STATE();
     case 357:
-      if (lookahead == 'd') ADVANCE(381);
+      if (lookahead == 'o') ADVANCE(410);
       END_STATE();
     case 358:
-      if (lookahead == 'l') ADVANCE(382);
+      ACCEPT_TOKEN(anon_sym_during);
       END_STATE();
     case 359:
-      if (lookahead == '_') ADVANCE(383);
+      if (lookahead == 't') ADVANCE(411);
       END_STATE();
     case 360:
-      ACCEPT_TOKEN(anon_sym_register);
+      ACCEPT_TOKEN(anon_sym_equals);
       END_STATE();
     case 361:
-      ACCEPT_TOKEN(anon_sym_retrieve);
+      ACCEPT_TOKEN(anon_sym_export);
       END_STATE();
     case 362:
-      if (lookahead == 't') ADVANCE(384);
+      if (lookahead == 'a') ADVANCE(412);
       END_STATE();
     case 363:
-      if (lookahead == 'i') ADVANCE(385);
+      if (lookahead == 't') ADVANCE(413);
       END_STATE();
     case 364:
-      ACCEPT_TOKEN(anon_sym_transfer);
+      if (lookahead == 'r') ADVANCE(414);
       END_STATE();
     case 365:
-      if (lookahead == 'a') ADVANCE(386);
+      if (lookahead == 't') ADVANCE(415);
       END_STATE();
     case 366:
-      ACCEPT_TOKEN(anon_sym_validate);
+      ACCEPT_TOKEN(anon_sym_import);
       END_STATE();
     case 367:
-      ACCEPT_TOKEN(anon_sym_withdraw);
+      if (lookahead == '_') ADVANCE(416);
       END_STATE();
     case 368:
-      if (lookahead == 'o') ADVANCE(387);
+      ACCEPT_TOKEN(anon_sym_is_set);
       END_STATE();
     case 369:
-      if (lookahead == 'a') ADVANCE(388);
+      if (lookahead == 'h') ADVANCE(417);
       END_STATE();
     case 370:
-      if (lookahead == 'a') ADVANCE(389);
+      ACCEPT_TOKEN(anon_sym_logout);
       END_STATE();
     case 371:
-      ACCEPT_TOKEN(anon_sym_authorize);
+      if (lookahead == 'e') ADVANCE(418);
       END_STATE();
     case 372:
-      ACCEPT_TOKEN(anon_sym_calculate);
+      if (lookahead == 'l') ADVANCE(419);
       END_STATE();
     case 373:
-      if (lookahead == 't') ADVANCE(390);
+      if (lookahead == 'u') ADVANCE(420);
       END_STATE();
     case 374:
-      if (lookahead == 'r') ADVANCE(391);
+      if (lookahead == 'r') ADVANCE(421);
       END_STATE();
     case 375:
-      if (lookahead == 'd') ADVANCE(392);
+      if (lookahead == 's') ADVANCE(422);
       END_STATE();
     case 376:
-      if (lookahead == 'c') ADVANCE(393);
+      if (lookahead == 'e') ADVANCE(423);
       END_STATE();
     case 377:
-      ACCEPT_TOKEN(anon_sym_file_data);
+      if (lookahead == 'e') ADVANCE(424);
       END_STATE();
     case 378:
-      if (lookahead == 'h') ADVANCE(394);
+      if (lookahead == 'v') ADVANCE(425);
       END_STATE();
     case 379:
-      if (lookahead == 't') ADVANCE(395);
+      ACCEPT_TOKEN(anon_sym_should);
       END_STATE();
     case 380:
-      ACCEPT_TOKEN(anon_sym_less_than);
+      if (lookahead == 'l') ADVANCE(426);
       END_STATE();
     case 381:
-      if (lookahead == 'a') ADVANCE(396);
+      if (lookahead == 'h') ADVANCE(427);
       END_STATE();
     case 382:
-      if (lookahead == 's') ADVANCE(397);
+      if (lookahead == 'd') ADVANCE(428);
       END_STATE();
     case 383:
-      if (lookahead == 'd') ADVANCE(398);
+      if (lookahead == 'c') ADVANCE(429);
       END_STATE();
     case 384:
-      if (lookahead == 'a') ADVANCE(399);
+      if (lookahead == 'e') ADVANCE(430);
       END_STATE();
     case 385:
-      if (lookahead == 'o') ADVANCE(400);
+      ACCEPT_TOKEN(anon_sym_unique);
       END_STATE();
     case 386:
-      ACCEPT_TOKEN(anon_sym_user_data);
+      ACCEPT_TOKEN(anon_sym_update);
       END_STATE();
     case 387:
-      if (lookahead == 'n') ADVANCE(401);
+      if (lookahead == 'a') ADVANCE(431);
       END_STATE();
     case 388:
-      if (lookahead == 't') ADVANCE(402);
+      if (lookahead == 't') ADVANCE(432);
       END_STATE();
     case 389:
-      if (lookahead == 't') ADVANCE(403);
+      ACCEPT_TOKEN(anon_sym_verify);
       END_STATE();
     case 390:
-      if (lookahead == 'e') ADVANCE(404);
+      if (lookahead == 'a') ADVANCE(433);
       END_STATE();
     case 391:
-      if (lookahead == 'd') ADVANCE(405);
+      if (lookahead == 't') ADVANCE(434);
       END_STATE();
     case 392:
-      if (lookahead == 'a') ADVANCE(406);
+      if (lookahead == 't') ADVANCE(435);
       END_STATE();
     case 393:
-      if (lookahead == 'o') ADVANCE(407);
+      if (lookahead == 'g') ADVANCE(436);
       END_STATE();
     case 394:
-      if (lookahead == 'a') ADVANCE(408);
+      ACCEPT_TOKEN(anon_sym_Service);
       END_STATE();
     case 395:
-      ACCEPT_TOKEN(anon_sym_is_not_set);
+      if (lookahead == '_') ADVANCE(437);
       END_STATE();
     case 396:
-      if (lookahead == 't') ADVANCE(409);
+      if (lookahead == 't') ADVANCE(438);
       END_STATE();
     case 397:
-      ACCEPT_TOKEN(anon_sym_not_equals);
+      ACCEPT_TOKEN(anon_sym_at_most);
       END_STATE();
     case 398:
-      if (lookahead == 'a') ADVANCE(410);
+      if (lookahead == 'i') ADVANCE(439);
       END_STATE();
     case 399:
-      ACCEPT_TOKEN(anon_sym_token_data);
+      if (lookahead == 'z') ADVANCE(440);
       END_STATE();
     case 400:
-      if (lookahead == 'n') ADVANCE(411);
+      ACCEPT_TOKEN(anon_sym_average);
       END_STATE();
     case 401:
-      ACCEPT_TOKEN(anon_sym_Application);
+      ACCEPT_TOKEN(anon_sym_between);
       END_STATE();
     case 402:
-      if (lookahead == 'a') ADVANCE(412);
+      if (lookahead == 't') ADVANCE(441);
       END_STATE();
     case 403:
-      if (lookahead == 'e') ADVANCE(413);
+      if (lookahead == 'c') ADVANCE(442);
       END_STATE();
     case 404:
-      if (lookahead == '_') ADVANCE(414);
+      if (lookahead == 's') ADVANCE(443);
       END_STATE();
     case 405:
-      ACCEPT_TOKEN(anon_sym_data_record);
+      if (lookahead == 'c') ADVANCE(444);
       END_STATE();
     case 406:
-      if (lookahead == 't') ADVANCE(415);
+      ACCEPT_TOKEN(anon_sym_decrypt);
       END_STATE();
     case 407:
-      if (lookahead == 'n') ADVANCE(416);
+      ACCEPT_TOKEN(anon_sym_deposit);
       END_STATE();
     case 408:
-      if (lookahead == 'n') ADVANCE(417);
+      if (lookahead == 't') ADVANCE(445);
       END_STATE();
     case 409:
-      if (lookahead == 'a') ADVANCE(418);
+      if (lookahead == 't') ADVANCE(446);
       END_STATE();
     case 410:
-      if (lookahead == 't') ADVANCE(419);
+      if (lookahead == 't') ADVANCE(447);
       END_STATE();
     case 411:
-      ACCEPT_TOKEN(anon_sym_transaction);
+      ACCEPT_TOKEN(anon_sym_encrypt);
       END_STATE();
     case 412:
-      ACCEPT_TOKEN(anon_sym_account_data);
+      if (lookahead == 't') ADVANCE(448);
       END_STATE();
     case 413:
-      ACCEPT_TOKEN(anon_sym_authenticate);
+      if (lookahead == 'e') ADVANCE(449);
       END_STATE();
     case 414:
-      if (lookahead == 'd') ADVANCE(420);
+      if (lookahead == '_') ADVANCE(450);
       END_STATE();
     case 415:
-      if (lookahead == 'a') ADVANCE(421);
+      if (lookahead == 'e') ADVANCE(451);
       END_STATE();
     case 416:
-      if (lookahead == 't') ADVANCE(422);
+      if (lookahead == 's') ADVANCE(452);
       END_STATE();
     case 417:
-      ACCEPT_TOKEN(anon_sym_greater_than);
+      if (lookahead == 'a') ADVANCE(453);
       END_STATE();
     case 418:
-      ACCEPT_TOKEN(anon_sym_message_data);
+      if (lookahead == '_') ADVANCE(454);
       END_STATE();
     case 419:
-      if (lookahead == 'a') ADVANCE(423);
+      if (lookahead == 'e') ADVANCE(455);
       END_STATE();
     case 420:
-      if (lookahead == 'a') ADVANCE(424);
+      if (lookahead == 'a') ADVANCE(456);
       END_STATE();
     case 421:
-      ACCEPT_TOKEN(anon_sym_document_data);
+      if (lookahead == 'd') ADVANCE(457);
       END_STATE();
     case 422:
-      if (lookahead == 'a') ADVANCE(425);
+      ACCEPT_TOKEN(anon_sym_process);
       END_STATE();
     case 423:
-      ACCEPT_TOKEN(anon_sym_password_data);
+      ACCEPT_TOKEN(anon_sym_receive);
       END_STATE();
     case 424:
-      if (lookahead == 't') ADVANCE(426);
+      if (lookahead == 'r') ADVANCE(458);
       END_STATE();
     case 425:
-      if (lookahead == 'i') ADVANCE(427);
+      if (lookahead == 'e') ADVANCE(459);
       END_STATE();
     case 426:
-      if (lookahead == 'a') ADVANCE(428);
+      if (lookahead == 'y') ADVANCE(460);
       END_STATE();
     case 427:
-      if (lookahead == 'n') ADVANCE(429);
+      ACCEPT_TOKEN(anon_sym_through);
       END_STATE();
     case 428:
-      ACCEPT_TOKEN(anon_sym_certificate_data);
+      if (lookahead == 'a') ADVANCE(461);
       END_STATE();
     case 429:
+      if (lookahead == 't') ADVANCE(462);
+      END_STATE();
+    case 430:
+      if (lookahead == 'r') ADVANCE(463);
+      END_STATE();
+    case 431:
+      if (lookahead == 't') ADVANCE(464);
+      END_STATE();
+    case 432:
+      if (lookahead == 'e') ADVANCE(465);
+      END_STATE();
+    case 433:
+      if (lookahead == 'w') ADVANCE(466);
+      END_STATE();
+    case 434:
+      ACCEPT_TOKEN(anon_sym_without);
+      END_STATE();
+    case 435:
+      if (lookahead == 'i') ADVANCE(467);
+      END_STATE();
+    case 436:
+      ACCEPT_TOKEN(anon_sym_Assuming);
+      END_STATE();
+    case 437:
+      if (lookahead == 'd') ADVANCE(468);
+      END_STATE();
+    case 438:
+      ACCEPT_TOKEN(anon_sym_at_least);
+      END_STATE();
+    case 439:
+      if (lookahead == 'c') ADVANCE(469);
+      END_STATE();
+    case 440:
+      if (lookahead == 'e') ADVANCE(470);
+      END_STATE();
+    case 441:
+      if (lookahead == 'e') ADVANCE(471);
+      END_STATE();
+    case 442:
+      if (lookahead == 'a') ADVANCE(472);
+      END_STATE();
+    case 443:
+      ACCEPT_TOKEN(anon_sym_contains);
+      END_STATE();
+    case 444:
+      if (lookahead == 'o') ADVANCE(473);
+      END_STATE();
+    case 445:
+      ACCEPT_TOKEN(anon_sym_distinct);
+      END_STATE();
+    case 446:
+      if (lookahead == '_') ADVANCE(474);
+      END_STATE();
+    case 447:
+      if (lookahead == '_') ADVANCE(475);
+      END_STATE();
+    case 448:
+      if (lookahead == 'a') ADVANCE(476);
+      END_STATE();
+    case 449:
+      ACCEPT_TOKEN(anon_sym_generate);
+      END_STATE();
+    case 450:
+      if (lookahead == 't') ADVANCE(477);
+      END_STATE();
+    case 451:
+      if (lookahead == 'e') ADVANCE(478);
+      END_STATE();
+    case 452:
+      if (lookahead == 'e') ADVANCE(479);
+      END_STATE();
+    case 453:
+      if (lookahead == 'n') ADVANCE(480);
+      END_STATE();
+    case 454:
+      if (lookahead == 'd') ADVANCE(481);
+      END_STATE();
+    case 455:
+      ACCEPT_TOKEN(anon_sym_multiple);
+      END_STATE();
+    case 456:
+      if (lookahead == 'l') ADVANCE(482);
+      END_STATE();
+    case 457:
+      if (lookahead == '_') ADVANCE(483);
+      END_STATE();
+    case 458:
+      ACCEPT_TOKEN(anon_sym_register);
+      END_STATE();
+    case 459:
+      ACCEPT_TOKEN(anon_sym_retrieve);
+      END_STATE();
+    case 460:
+      ACCEPT_TOKEN(anon_sym_strictly);
+      END_STATE();
+    case 461:
+      if (lookahead == 't') ADVANCE(484);
+      END_STATE();
+    case 462:
+      if (lookahead == 'i') ADVANCE(485);
+      END_STATE();
+    case 463:
+      ACCEPT_TOKEN(anon_sym_transfer);
+      END_STATE();
+    case 464:
+      if (lookahead == 'a') ADVANCE(486);
+      END_STATE();
+    case 465:
+      ACCEPT_TOKEN(anon_sym_validate);
+      END_STATE();
+    case 466:
+      ACCEPT_TOKEN(anon_sym_withdraw);
+      END_STATE();
+    case 467:
+      if (lookahead == 'o') ADVANCE(487);
+      END_STATE();
+    case 468:
+      if (lookahead == 'a') ADVANCE(488);
+      END_STATE();
+    case 469:
+      if (lookahead == 'a') ADVANCE(489);
+      END_STATE();
+    case 470:
+      ACCEPT_TOKEN(anon_sym_authorize);
+      END_STATE();
+    case 471:
+      ACCEPT_TOKEN(anon_sym_calculate);
+      END_STATE();
+    case 472:
+      if (lookahead == 't') ADVANCE(490);
+      END_STATE();
+    case 473:
+      if (lookahead == 'r') ADVANCE(491);
+      END_STATE();
+    case 474:
+      if (lookahead == 'd') ADVANCE(492);
+      END_STATE();
+    case 475:
+      if (lookahead == 'c') ADVANCE(493);
+      END_STATE();
+    case 476:
+      ACCEPT_TOKEN(anon_sym_file_data);
+      END_STATE();
+    case 477:
+      if (lookahead == 'h') ADVANCE(494);
+      END_STATE();
+    case 478:
+      if (lookahead == 'd') ADVANCE(495);
+      END_STATE();
+    case 479:
+      if (lookahead == 't') ADVANCE(496);
+      END_STATE();
+    case 480:
+      ACCEPT_TOKEN(anon_sym_less_than);
+      END_STATE();
+    case 481:
+      if (lookahead == 'a') ADVANCE(497);
+      END_STATE();
+    case 482:
+      if (lookahead == 's') ADVANCE(498);
+      END_STATE();
+    case 483:
+      if (lookahead == 'd') ADVANCE(499);
+      END_STATE();
+    case 484:
+      if (lookahead == 'a') ADVANCE(500);
+      END_STATE();
+    case 485:
+      if (lookahead == 'o') ADVANCE(501);
+      END_STATE();
+    case 486:
+      ACCEPT_TOKEN(anon_sym_user_data);
+      END_STATE();
+    case 487:
+      if (lookahead == 'n') ADVANCE(502);
+      END_STATE();
+    case 488:
+      if (lookahead == 't') ADVANCE(503);
+      END_STATE();
+    case 489:
+      if (lookahead == 't') ADVANCE(504);
+      END_STATE();
+    case 490:
+      if (lookahead == 'e') ADVANCE(505);
+      END_STATE();
+    case 491:
+      if (lookahead == 'd') ADVANCE(506);
+      END_STATE();
+    case 492:
+      if (lookahead == 'a') ADVANCE(507);
+      END_STATE();
+    case 493:
+      if (lookahead == 'o') ADVANCE(508);
+      END_STATE();
+    case 494:
+      if (lookahead == 'a') ADVANCE(509);
+      END_STATE();
+    case 495:
+      ACCEPT_TOKEN(anon_sym_guaranteed);
+      END_STATE();
+    case 496:
+      ACCEPT_TOKEN(anon_sym_is_not_set);
+      END_STATE();
+    case 497:
+      if (lookahead == 't') ADVANCE(510);
+      END_STATE();
+    case 498:
+      ACCEPT_TOKEN(anon_sym_not_equals);
+      END_STATE();
+    case 499:
+      if (lookahead == 'a') ADVANCE(511);
+      END_STATE();
+    case 500:
+      ACCEPT_TOKEN(anon_sym_token_data);
+      END_STATE();
+    case 501:
+      if (lookahead == 'n') ADVANCE(512);
+      END_STATE();
+    case 502:
+      ACCEPT_TOKEN(anon_sym_Application);
+      END_STATE();
+    case 503:
+      if (lookahead == 'a') ADVANCE(513);
+      END_STATE();
+    case 504:
+      if (lookahead == 'e') ADVANCE(514);
+      END_STATE();
+    case 505:
+      if (lookahead == '_') ADVANCE(515);
+      END_STATE();
+    case 506:
+      ACCEPT_TOKEN(anon_sym_data_record);
+      END_STATE();
+    case 507:
+      if (lookahead == 't') ADVANCE(516);
+      END_STATE();
+    case 508:
+      if (lookahead == 'n') ADVANCE(517);
+      END_STATE();
+    case 509:
+      if (lookahead == 'n') ADVANCE(518);
+      END_STATE();
+    case 510:
+      if (lookahead == 'a') ADVANCE(519);
+      END_STATE();
+    case 511:
+      if (lookahead == 't') ADVANCE(520);
+      END_STATE();
+    case 512:
+      ACCEPT_TOKEN(anon_sym_transaction);
+      END_STATE();
+    case 513:
+      ACCEPT_TOKEN(anon_sym_account_data);
+      END_STATE();
+    case 514:
+      ACCEPT_TOKEN(anon_sym_authenticate);
+      END_STATE();
+    case 515:
+      if (lookahead == 'd') ADVANCE(521);
+      END_STATE();
+    case 516:
+      if (lookahead == 'a') ADVANCE(522);
+      END_STATE();
+    case 517:
+      if (lookahead == 't') ADVANCE(523);
+      END_STATE();
+    case 518:
+      ACCEPT_TOKEN(anon_sym_greater_than);
+      END_STATE();
+    case 519:
+      ACCEPT_TOKEN(anon_sym_message_data);
+      END_STATE();
+    case 520:
+      if (lookahead == 'a') ADVANCE(524);
+      END_STATE();
+    case 521:
+      if (lookahead == 'a') ADVANCE(525);
+      END_STATE();
+    case 522:
+      ACCEPT_TOKEN(anon_sym_document_data);
+      END_STATE();
+    case 523:
+      if (lookahead == 'a') ADVANCE(526);
+      END_STATE();
+    case 524:
+      ACCEPT_TOKEN(anon_sym_password_data);
+      END_STATE();
+    case 525:
+      if (lookahead == 't') ADVANCE(527);
+      END_STATE();
+    case 526:
+      if (lookahead == 'i') ADVANCE(528);
+      END_STATE();
+    case 527:
+      if (lookahead == 'a') ADVANCE(529);
+      END_STATE();
+    case 528:
+      if (lookahead == 'n') ADVANCE(530);
+      END_STATE();
+    case 529:
+      ACCEPT_TOKEN(anon_sym_certificate_data);
+      END_STATE();
+    case 530:
       ACCEPT_TOKEN(anon_sym_does_not_contain);
       END_STATE();
     default:
@@ -2588,89 +3812,288 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
 static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0},
   [1] = {.lex_state = 0},
-  [2] = {.lex_state = 4},
-  [3] = {.lex_state = 4},
-  [4] = {.lex_state = 0},
-  [5] = {.lex_state = 0},
-  [6] = {.lex_state = 4},
-  [7] = {.lex_state = 4},
-  [8] = {.lex_state = 4},
-  [9] = {.lex_state = 0},
-  [10] = {.lex_state = 0},
-  [11] = {.lex_state = 0},
-  [12] = {.lex_state = 0},
-  [13] = {.lex_state = 4},
-  [14] = {.lex_state = 4},
-  [15] = {.lex_state = 4},
-  [16] = {.lex_state = 0},
-  [17] = {.lex_state = 0},
-  [18] = {.lex_state = 4},
-  [19] = {.lex_state = 4},
-  [20] = {.lex_state = 0},
-  [21] = {.lex_state = 0},
-  [22] = {.lex_state = 0},
-  [23] = {.lex_state = 0},
-  [24] = {.lex_state = 0},
-  [25] = {.lex_state = 0},
-  [26] = {.lex_state = 4},
-  [27] = {.lex_state = 4},
-  [28] = {.lex_state = 0},
-  [29] = {.lex_state = 4},
-  [30] = {.lex_state = 4},
-  [31] = {.lex_state = 4},
-  [32] = {.lex_state = 4},
-  [33] = {.lex_state = 4},
-  [34] = {.lex_state = 4},
-  [35] = {.lex_state = 1},
-  [36] = {.lex_state = 1},
-  [37] = {.lex_state = 0},
-  [38] = {.lex_state = 1},
-  [39] = {.lex_state = 4},
-  [40] = {.lex_state = 0},
-  [41] = {.lex_state = 0},
-  [42] = {.lex_state = 0},
+  [2] = {.lex_state = 10},
+  [3] = {.lex_state = 10},
+  [4] = {.lex_state = 1},
+  [5] = {.lex_state = 1},
+  [6] = {.lex_state = 1},
+  [7] = {.lex_state = 1},
+  [8] = {.lex_state = 1},
+  [9] = {.lex_state = 10},
+  [10] = {.lex_state = 10},
+  [11] = {.lex_state = 10},
+  [12] = {.lex_state = 10},
+  [13] = {.lex_state = 10},
+  [14] = {.lex_state = 1},
+  [15] = {.lex_state = 10},
+  [16] = {.lex_state = 10},
+  [17] = {.lex_state = 10},
+  [18] = {.lex_state = 10},
+  [19] = {.lex_state = 10},
+  [20] = {.lex_state = 10},
+  [21] = {.lex_state = 10},
+  [22] = {.lex_state = 10},
+  [23] = {.lex_state = 10},
+  [24] = {.lex_state = 10},
+  [25] = {.lex_state = 10},
+  [26] = {.lex_state = 10},
+  [27] = {.lex_state = 10},
+  [28] = {.lex_state = 10},
+  [29] = {.lex_state = 10},
+  [30] = {.lex_state = 10},
+  [31] = {.lex_state = 10},
+  [32] = {.lex_state = 10},
+  [33] = {.lex_state = 10},
+  [34] = {.lex_state = 10},
+  [35] = {.lex_state = 10},
+  [36] = {.lex_state = 10},
+  [37] = {.lex_state = 10},
+  [38] = {.lex_state = 10},
+  [39] = {.lex_state = 10},
+  [40] = {.lex_state = 10},
+  [41] = {.lex_state = 10},
+  [42] = {.lex_state = 10},
   [43] = {.lex_state = 1},
-  [44] = {.lex_state = 0},
-  [45] = {.lex_state = 4},
-  [46] = {.lex_state = 4},
+  [44] = {.lex_state = 1},
+  [45] = {.lex_state = 10},
+  [46] = {.lex_state = 0},
   [47] = {.lex_state = 0},
-  [48] = {.lex_state = 1},
-  [49] = {.lex_state = 0},
-  [50] = {.lex_state = 0},
-  [51] = {.lex_state = 0},
-  [52] = {.lex_state = 0},
-  [53] = {.lex_state = 4},
-  [54] = {.lex_state = 0},
-  [55] = {.lex_state = 0},
-  [56] = {.lex_state = 4},
-  [57] = {.lex_state = 0},
-  [58] = {.lex_state = 0},
-  [59] = {.lex_state = 0},
-  [60] = {.lex_state = 30},
-  [61] = {.lex_state = 4},
-  [62] = {.lex_state = 4},
-  [63] = {.lex_state = 4},
-  [64] = {.lex_state = 0},
-  [65] = {(TSStateId)(-1),},
+  [48] = {.lex_state = 10},
+  [49] = {.lex_state = 1},
+  [50] = {.lex_state = 10},
+  [51] = {.lex_state = 1},
+  [52] = {.lex_state = 10},
+  [53] = {.lex_state = 10},
+  [54] = {.lex_state = 10},
+  [55] = {.lex_state = 10},
+  [56] = {.lex_state = 10},
+  [57] = {.lex_state = 10},
+  [58] = {.lex_state = 10},
+  [59] = {.lex_state = 10},
+  [60] = {.lex_state = 10},
+  [61] = {.lex_state = 0},
+  [62] = {.lex_state = 10},
+  [63] = {.lex_state = 10},
+  [64] = {.lex_state = 10},
+  [65] = {.lex_state = 10},
+  [66] = {.lex_state = 10},
+  [67] = {.lex_state = 10},
+  [68] = {.lex_state = 0},
+  [69] = {.lex_state = 10},
+  [70] = {.lex_state = 10},
+  [71] = {.lex_state = 0},
+  [72] = {.lex_state = 10},
+  [73] = {.lex_state = 0},
+  [74] = {.lex_state = 0},
+  [75] = {.lex_state = 10},
+  [76] = {.lex_state = 0},
+  [77] = {.lex_state = 0},
+  [78] = {.lex_state = 0},
+  [79] = {.lex_state = 0},
+  [80] = {.lex_state = 10},
+  [81] = {.lex_state = 10},
+  [82] = {.lex_state = 10},
+  [83] = {.lex_state = 10},
+  [84] = {.lex_state = 1},
+  [85] = {.lex_state = 1},
+  [86] = {.lex_state = 10},
+  [87] = {.lex_state = 10},
+  [88] = {.lex_state = 10},
+  [89] = {.lex_state = 1},
+  [90] = {.lex_state = 10},
+  [91] = {.lex_state = 10},
+  [92] = {.lex_state = 10},
+  [93] = {.lex_state = 10},
+  [94] = {.lex_state = 10},
+  [95] = {.lex_state = 10},
+  [96] = {.lex_state = 10},
+  [97] = {.lex_state = 1},
+  [98] = {.lex_state = 10},
+  [99] = {.lex_state = 10},
+  [100] = {.lex_state = 10},
+  [101] = {.lex_state = 10},
+  [102] = {.lex_state = 1},
+  [103] = {.lex_state = 1},
+  [104] = {.lex_state = 1},
+  [105] = {.lex_state = 2},
+  [106] = {.lex_state = 1},
+  [107] = {.lex_state = 1},
+  [108] = {.lex_state = 1},
+  [109] = {.lex_state = 10},
+  [110] = {.lex_state = 1},
+  [111] = {.lex_state = 2},
+  [112] = {.lex_state = 2},
+  [113] = {.lex_state = 10},
+  [114] = {.lex_state = 1},
+  [115] = {.lex_state = 1},
+  [116] = {.lex_state = 2},
+  [117] = {.lex_state = 2},
+  [118] = {.lex_state = 10},
+  [119] = {.lex_state = 10},
+  [120] = {.lex_state = 10},
+  [121] = {.lex_state = 1},
+  [122] = {.lex_state = 1},
+  [123] = {.lex_state = 1},
+  [124] = {.lex_state = 1},
+  [125] = {.lex_state = 10},
+  [126] = {.lex_state = 1},
+  [127] = {.lex_state = 1},
+  [128] = {.lex_state = 1},
+  [129] = {.lex_state = 1},
+  [130] = {.lex_state = 1},
+  [131] = {.lex_state = 1},
+  [132] = {.lex_state = 1},
+  [133] = {.lex_state = 1},
+  [134] = {.lex_state = 10},
+  [135] = {.lex_state = 1},
+  [136] = {.lex_state = 10},
+  [137] = {.lex_state = 1},
+  [138] = {.lex_state = 10},
+  [139] = {.lex_state = 10},
+  [140] = {.lex_state = 10},
+  [141] = {.lex_state = 10},
+  [142] = {.lex_state = 10},
+  [143] = {.lex_state = 10},
+  [144] = {.lex_state = 1},
+  [145] = {.lex_state = 1},
+  [146] = {.lex_state = 10},
+  [147] = {.lex_state = 10},
+  [148] = {.lex_state = 10},
+  [149] = {.lex_state = 10},
+  [150] = {.lex_state = 10},
+  [151] = {.lex_state = 10},
+  [152] = {.lex_state = 4},
+  [153] = {.lex_state = 1},
+  [154] = {.lex_state = 10},
+  [155] = {.lex_state = 10},
+  [156] = {.lex_state = 10},
+  [157] = {.lex_state = 10},
+  [158] = {.lex_state = 10},
+  [159] = {.lex_state = 0},
+  [160] = {.lex_state = 10},
+  [161] = {.lex_state = 0},
+  [162] = {.lex_state = 0},
+  [163] = {.lex_state = 0},
+  [164] = {.lex_state = 10},
+  [165] = {.lex_state = 10},
+  [166] = {.lex_state = 1},
+  [167] = {.lex_state = 1},
+  [168] = {.lex_state = 10},
+  [169] = {.lex_state = 1},
+  [170] = {.lex_state = 10},
+  [171] = {.lex_state = 2},
+  [172] = {.lex_state = 0},
+  [173] = {.lex_state = 10},
+  [174] = {.lex_state = 10},
+  [175] = {.lex_state = 10},
+  [176] = {.lex_state = 10},
+  [177] = {.lex_state = 10},
+  [178] = {.lex_state = 1},
+  [179] = {.lex_state = 10},
+  [180] = {.lex_state = 10},
+  [181] = {.lex_state = 2},
+  [182] = {.lex_state = 1},
+  [183] = {.lex_state = 10},
+  [184] = {.lex_state = 10},
+  [185] = {.lex_state = 10},
+  [186] = {.lex_state = 10},
+  [187] = {.lex_state = 10},
+  [188] = {.lex_state = 10},
+  [189] = {.lex_state = 10},
+  [190] = {.lex_state = 1},
+  [191] = {.lex_state = 10},
+  [192] = {.lex_state = 10},
+  [193] = {.lex_state = 10},
+  [194] = {.lex_state = 1},
+  [195] = {.lex_state = 10},
+  [196] = {.lex_state = 10},
+  [197] = {.lex_state = 10},
+  [198] = {.lex_state = 10},
+  [199] = {.lex_state = 10},
+  [200] = {.lex_state = 10},
+  [201] = {.lex_state = 10},
+  [202] = {.lex_state = 10},
+  [203] = {.lex_state = 10},
+  [204] = {.lex_state = 10},
+  [205] = {.lex_state = 10},
+  [206] = {.lex_state = 10},
+  [207] = {.lex_state = 10},
+  [208] = {.lex_state = 1},
+  [209] = {.lex_state = 10},
+  [210] = {.lex_state = 10},
+  [211] = {.lex_state = 0},
+  [212] = {.lex_state = 10},
+  [213] = {.lex_state = 10},
+  [214] = {.lex_state = 10},
+  [215] = {.lex_state = 10},
+  [216] = {.lex_state = 10},
+  [217] = {.lex_state = 10},
+  [218] = {.lex_state = 10},
+  [219] = {.lex_state = 10},
+  [220] = {.lex_state = 0},
+  [221] = {.lex_state = 10},
+  [222] = {.lex_state = 0},
+  [223] = {.lex_state = 0},
+  [224] = {.lex_state = 10},
+  [225] = {.lex_state = 0},
+  [226] = {.lex_state = 10},
+  [227] = {.lex_state = 59},
+  [228] = {.lex_state = 10},
+  [229] = {.lex_state = 10},
+  [230] = {.lex_state = 10},
+  [231] = {.lex_state = 10},
+  [232] = {.lex_state = 10},
+  [233] = {.lex_state = 10},
+  [234] = {.lex_state = 0},
+  [235] = {.lex_state = 10},
+  [236] = {.lex_state = 10},
+  [237] = {.lex_state = 10},
+  [238] = {.lex_state = 10},
+  [239] = {.lex_state = 0},
+  [240] = {.lex_state = 10},
+  [241] = {.lex_state = 10},
+  [242] = {.lex_state = 10},
+  [243] = {.lex_state = 10},
+  [244] = {.lex_state = 10},
+  [245] = {.lex_state = 10},
+  [246] = {.lex_state = 10},
+  [247] = {.lex_state = 10},
+  [248] = {.lex_state = 10},
+  [249] = {.lex_state = 10},
+  [250] = {.lex_state = 10},
+  [251] = {.lex_state = 0},
+  [252] = {(TSStateId)(-1)},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [STATE(0)] = {
+  [0] = {
     [sym_comment] = STATE(0),
     [ts_builtin_sym_end] = ACTIONS(1),
     [sym_identifier] = ACTIONS(1),
+    [anon_sym_COLON_EQ] = ACTIONS(1),
+    [anon_sym_Given] = ACTIONS(1),
+    [anon_sym_When] = ACTIONS(1),
+    [anon_sym_Then] = ACTIONS(1),
+    [anon_sym_And] = ACTIONS(1),
     [anon_sym_User] = ACTIONS(1),
     [anon_sym_System] = ACTIONS(1),
     [anon_sym_Admin] = ACTIONS(1),
     [anon_sym_Application] = ACTIONS(1),
     [anon_sym_Service] = ACTIONS(1),
     [anon_sym_API] = ACTIONS(1),
+    [anon_sym_Assuming] = ACTIONS(1),
+    [anon_sym_Assume] = ACTIONS(1),
+    [anon_sym_Invariant_COLON] = ACTIONS(1),
+    [anon_sym_Always] = ACTIONS(1),
     [anon_sym_can] = ACTIONS(1),
     [anon_sym_must] = ACTIONS(1),
     [anon_sym_should] = ACTIONS(1),
     [anon_sym_shall] = ACTIONS(1),
     [anon_sym_will] = ACTIONS(1),
     [anon_sym_may] = ACTIONS(1),
+    [anon_sym_is] = ACTIONS(1),
+    [anon_sym_guaranteed] = ACTIONS(1),
+    [anon_sym_to] = ACTIONS(1),
     [anon_sym_create] = ACTIONS(1),
     [anon_sym_read] = ACTIONS(1),
     [anon_sym_update] = ACTIONS(1),
@@ -2708,9 +4131,40 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_message_data] = ACTIONS(1),
     [anon_sym_if] = ACTIONS(1),
     [anon_sym_where] = ACTIONS(1),
+    [anon_sym_else] = ACTIONS(1),
+    [anon_sym_strictly] = ACTIONS(1),
+    [anon_sym_between] = ACTIONS(1),
     [anon_sym_and] = ACTIONS(1),
-    [anon_sym_or] = ACTIONS(1),
     [anon_sym_not] = ACTIONS(1),
+    [anon_sym_a] = ACTIONS(1),
+    [anon_sym_multiple] = ACTIONS(1),
+    [anon_sym_of] = ACTIONS(1),
+    [anon_sym_has] = ACTIONS(1),
+    [anon_sym_bit] = ACTIONS(1),
+    [anon_sym_set] = ACTIONS(1),
+    [anon_sym_clear] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_are] = ACTIONS(1),
+    [anon_sym_unique] = ACTIONS(1),
+    [anon_sym_distinct] = ACTIONS(1),
+    [anon_sym_or] = ACTIONS(1),
+    [anon_sym_then] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_min] = ACTIONS(1),
+    [anon_sym_max] = ACTIONS(1),
+    [anon_sym_abs] = ACTIONS(1),
+    [anon_sym_sum] = ACTIONS(1),
+    [anon_sym_count] = ACTIONS(1),
+    [anon_sym_average] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
+    [anon_sym_mod] = ACTIONS(1),
+    [anon_sym_AMP] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
+    [anon_sym_CARET] = ACTIONS(1),
+    [anon_sym_LT_LT] = ACTIONS(1),
+    [anon_sym_GT_GT] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
     [anon_sym_EQ_EQ] = ACTIONS(1),
     [anon_sym_BANG_EQ] = ACTIONS(1),
     [anon_sym_GT] = ACTIONS(1),
@@ -2731,9 +4185,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DASH] = ACTIONS(1),
     [anon_sym_STAR] = ACTIONS(1),
     [anon_sym_SLASH] = ACTIONS(1),
-    [anon_sym_PERCENT] = ACTIONS(1),
     [anon_sym_from] = ACTIONS(1),
-    [anon_sym_to] = ACTIONS(1),
     [anon_sym_in] = ACTIONS(1),
     [anon_sym_on] = ACTIONS(1),
     [anon_sym_at] = ACTIONS(1),
@@ -2753,26 +4205,36 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_n] = ACTIONS(1),
     [anon_sym_t] = ACTIONS(1),
     [sym_number] = ACTIONS(1),
+    [sym_bit_pattern] = ACTIONS(1),
     [sym_whitespace] = ACTIONS(3),
     [sym_newline] = ACTIONS(1),
     [anon_sym_SLASH_SLASH] = ACTIONS(5),
   },
-  [STATE(1)] = {
-    [sym_source_file] = STATE(63),
-    [sym_requirement] = STATE(25),
-    [sym_subject] = STATE(26),
+  [1] = {
+    [sym_source_file] = STATE(216),
+    [sym_definition] = STATE(73),
+    [sym_requirement] = STATE(73),
+    [sym_bdd_requirement] = STATE(73),
+    [sym_given_clause] = STATE(188),
+    [sym_subject] = STATE(91),
+    [sym_kind_marker] = STATE(90),
     [sym_comment] = STATE(1),
-    [aux_sym_source_file_repeat1] = STATE(17),
+    [aux_sym_source_file_repeat1] = STATE(46),
     [ts_builtin_sym_end] = ACTIONS(7),
     [sym_identifier] = ACTIONS(9),
-    [anon_sym_User] = ACTIONS(9),
-    [anon_sym_System] = ACTIONS(9),
-    [anon_sym_Admin] = ACTIONS(9),
-    [anon_sym_Application] = ACTIONS(9),
-    [anon_sym_Service] = ACTIONS(9),
-    [anon_sym_API] = ACTIONS(9),
+    [anon_sym_Given] = ACTIONS(11),
+    [anon_sym_User] = ACTIONS(13),
+    [anon_sym_System] = ACTIONS(13),
+    [anon_sym_Admin] = ACTIONS(13),
+    [anon_sym_Application] = ACTIONS(13),
+    [anon_sym_Service] = ACTIONS(13),
+    [anon_sym_API] = ACTIONS(13),
+    [anon_sym_Assuming] = ACTIONS(15),
+    [anon_sym_Assume] = ACTIONS(15),
+    [anon_sym_Invariant_COLON] = ACTIONS(17),
+    [anon_sym_Always] = ACTIONS(15),
     [sym_whitespace] = ACTIONS(3),
-    [sym_newline] = ACTIONS(11),
+    [sym_newline] = ACTIONS(19),
     [anon_sym_SLASH_SLASH] = ACTIONS(5),
   },
 };
@@ -2781,163 +4243,36 @@ static const uint16_t ts_small_parse_table[] = {
   [0] = 6,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(25), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(27), 1,
       sym_whitespace,
     STATE(2), 1,
       sym_comment,
-    STATE(14), 1,
-      sym_verb,
-    STATE(37), 1,
-      sym_action,
-    ACTIONS(13), 26,
-      anon_sym_create,
-      anon_sym_read,
-      anon_sym_update,
-      anon_sym_delete,
-      anon_sym_validate,
-      anon_sym_verify,
-      anon_sym_authenticate,
-      anon_sym_authorize,
-      anon_sym_encrypt,
-      anon_sym_decrypt,
-      anon_sym_send,
-      anon_sym_receive,
-      anon_sym_store,
-      anon_sym_retrieve,
-      anon_sym_process,
-      anon_sym_calculate,
-      anon_sym_generate,
-      anon_sym_export,
-      anon_sym_import,
-      anon_sym_withdraw,
-      anon_sym_deposit,
-      anon_sym_transfer,
-      anon_sym_login,
-      anon_sym_logout,
-      anon_sym_register,
-      sym_identifier,
-  [44] = 4,
-    ACTIONS(5), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
-      sym_whitespace,
-    STATE(3), 1,
-      sym_comment,
-    ACTIONS(17), 26,
-      anon_sym_create,
-      anon_sym_read,
-      anon_sym_update,
-      anon_sym_delete,
-      anon_sym_validate,
-      anon_sym_verify,
-      anon_sym_authenticate,
-      anon_sym_authorize,
-      anon_sym_encrypt,
-      anon_sym_decrypt,
-      anon_sym_send,
-      anon_sym_receive,
-      anon_sym_store,
-      anon_sym_retrieve,
-      anon_sym_process,
-      anon_sym_calculate,
-      anon_sym_generate,
-      anon_sym_export,
-      anon_sym_import,
-      anon_sym_withdraw,
-      anon_sym_deposit,
-      anon_sym_transfer,
-      anon_sym_login,
-      anon_sym_logout,
-      anon_sym_register,
-      sym_identifier,
-  [82] = 5,
-    ACTIONS(3), 1,
-      sym_whitespace,
-    ACTIONS(5), 1,
-      anon_sym_SLASH_SLASH,
-    STATE(4), 1,
-      sym_comment,
-    ACTIONS(21), 2,
-      anon_sym_DQUOTE,
-      sym_number,
-    ACTIONS(19), 22,
-      anon_sym_if,
-      anon_sym_where,
-      anon_sym_and,
-      anon_sym_or,
-      anon_sym_from,
-      anon_sym_to,
-      anon_sym_in,
-      anon_sym_on,
-      anon_sym_at,
-      anon_sym_by,
-      anon_sym_with,
-      anon_sym_without,
-      anon_sym_for,
-      anon_sym_into,
-      anon_sym_onto,
-      anon_sym_through,
-      anon_sym_during,
-      anon_sym_before,
-      anon_sym_after,
-      anon_sym_the,
-      sym_identifier,
-      sym_newline,
-  [120] = 5,
-    ACTIONS(3), 1,
-      sym_whitespace,
-    ACTIONS(5), 1,
-      anon_sym_SLASH_SLASH,
-    STATE(5), 1,
-      sym_comment,
-    ACTIONS(25), 2,
-      anon_sym_DQUOTE,
-      sym_number,
-    ACTIONS(23), 22,
+    ACTIONS(21), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(23), 35,
       anon_sym_if,
-      anon_sym_where,
+      anon_sym_else,
+      anon_sym_strictly,
+      anon_sym_between,
       anon_sym_and,
+      anon_sym_has,
+      anon_sym_COMMA,
+      anon_sym_are,
       anon_sym_or,
-      anon_sym_from,
-      anon_sym_to,
-      anon_sym_in,
-      anon_sym_on,
-      anon_sym_at,
-      anon_sym_by,
-      anon_sym_with,
-      anon_sym_without,
-      anon_sym_for,
-      anon_sym_into,
-      anon_sym_onto,
-      anon_sym_through,
-      anon_sym_during,
-      anon_sym_before,
-      anon_sym_after,
-      anon_sym_the,
-      sym_identifier,
-      sym_newline,
-  [158] = 9,
-    ACTIONS(5), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
-      sym_whitespace,
-    ACTIONS(33), 1,
-      anon_sym_SLASH,
-    STATE(6), 1,
-      sym_comment,
-    STATE(32), 1,
-      sym_arithmetic_operator,
-    STATE(33), 1,
-      sym_comparison_operator,
-    ACTIONS(29), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(31), 4,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
+      anon_sym_then,
+      anon_sym_RPAREN,
       anon_sym_PERCENT,
-    ACTIONS(27), 14,
+      anon_sym_mod,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_GT_EQ,
@@ -2952,20 +4287,40 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_is_not_set,
       anon_sym_contains,
       anon_sym_does_not_contain,
-  [203] = 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [56] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(7), 1,
+    STATE(3), 1,
       sym_comment,
-    ACTIONS(37), 3,
+    ACTIONS(29), 4,
+      anon_sym_is,
       anon_sym_GT,
       anon_sym_LT,
       anon_sym_SLASH,
-    ACTIONS(35), 20,
+    ACTIONS(31), 35,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_strictly,
+      anon_sym_between,
       anon_sym_and,
+      anon_sym_has,
+      anon_sym_COMMA,
+      anon_sym_are,
       anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_GT_EQ,
@@ -2983,22 +4338,38 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-      anon_sym_PERCENT,
-  [240] = 6,
+  [109] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
-      sym_whitespace,
-    STATE(8), 1,
+    ACTIONS(33), 1,
+      anon_sym_SQUOTE,
+    STATE(4), 1,
       sym_comment,
-    ACTIONS(39), 2,
-      anon_sym_and,
-      anon_sym_or,
-    ACTIONS(43), 3,
+    ACTIONS(21), 5,
+      anon_sym_is,
       anon_sym_GT,
       anon_sym_LT,
       anon_sym_SLASH,
-    ACTIONS(41), 18,
+      sym_newline,
+    ACTIONS(23), 33,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_and,
+      anon_sym_has,
+      anon_sym_COMMA,
+      anon_sym_are,
+      anon_sym_or,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_GT_EQ,
@@ -3016,55 +4387,93 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-      anon_sym_PERCENT,
-  [279] = 9,
+  [164] = 5,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(51), 1,
-      sym_newline,
-    STATE(9), 1,
+    STATE(5), 1,
       sym_comment,
-    STATE(27), 1,
-      sym_preposition,
-    STATE(47), 1,
-      sym_preposition_phrase,
-    ACTIONS(45), 2,
+    ACTIONS(29), 5,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+      sym_newline,
+    ACTIONS(31), 33,
       anon_sym_if,
       anon_sym_where,
-    ACTIONS(49), 3,
-      anon_sym_in,
-      anon_sym_on,
-      anon_sym_with,
-    ACTIONS(47), 12,
-      anon_sym_from,
-      anon_sym_to,
-      anon_sym_at,
-      anon_sym_by,
-      anon_sym_without,
-      anon_sym_for,
-      anon_sym_into,
-      anon_sym_onto,
-      anon_sym_through,
-      anon_sym_during,
-      anon_sym_before,
-      anon_sym_after,
-  [321] = 5,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_and,
+      anon_sym_has,
+      anon_sym_COMMA,
+      anon_sym_are,
+      anon_sym_or,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [216] = 15,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    STATE(10), 1,
-      sym_comment,
-    ACTIONS(37), 3,
-      anon_sym_GT,
-      anon_sym_LT,
-      sym_newline,
-    ACTIONS(35), 17,
+    ACTIONS(35), 1,
+      anon_sym_is,
+    ACTIONS(38), 1,
       anon_sym_where,
+    ACTIONS(44), 1,
+      anon_sym_has,
+    ACTIONS(46), 1,
+      anon_sym_COMMA,
+    ACTIONS(48), 1,
+      anon_sym_are,
+    ACTIONS(57), 1,
+      sym_newline,
+    STATE(6), 1,
+      sym_comment,
+    STATE(154), 1,
+      aux_sym_uniqueness_repeat1,
+    ACTIONS(42), 2,
       anon_sym_and,
       anon_sym_or,
+    ACTIONS(50), 2,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+    ACTIONS(55), 3,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(53), 5,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(40), 19,
+      anon_sym_strictly,
+      anon_sym_between,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_GT_EQ,
@@ -3079,25 +4488,50 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_is_not_set,
       anon_sym_contains,
       anon_sym_does_not_contain,
-  [355] = 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [288] = 11,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    STATE(11), 1,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    STATE(7), 1,
       sym_comment,
-    ACTIONS(55), 4,
-      anon_sym_in,
-      anon_sym_on,
-      anon_sym_with,
-      sym_newline,
-    ACTIONS(53), 14,
+    STATE(49), 1,
+      sym_object,
+    STATE(51), 1,
+      sym_string,
+    STATE(96), 1,
+      sym_preposition,
+    STATE(182), 1,
+      sym_preposition_phrase,
+    ACTIONS(63), 3,
       anon_sym_if,
       anon_sym_where,
-      anon_sym_from,
+      sym_newline,
+    ACTIONS(59), 11,
+      anon_sym_data_record,
+      anon_sym_user_data,
+      anon_sym_transaction,
+      anon_sym_account_data,
+      anon_sym_password_data,
+      anon_sym_token_data,
+      anon_sym_certificate_data,
+      anon_sym_file_data,
+      anon_sym_document_data,
+      anon_sym_message_data,
+      sym_identifier,
+    ACTIONS(61), 15,
       anon_sym_to,
+      anon_sym_from,
+      anon_sym_in,
+      anon_sym_on,
       anon_sym_at,
       anon_sym_by,
+      anon_sym_with,
       anon_sym_without,
       anon_sym_for,
       anon_sym_into,
@@ -3106,21 +4540,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_during,
       anon_sym_before,
       anon_sym_after,
-  [387] = 7,
+  [348] = 14,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(39), 1,
-      anon_sym_where,
-    ACTIONS(57), 1,
+    ACTIONS(35), 1,
+      anon_sym_is,
+    ACTIONS(44), 1,
+      anon_sym_has,
+    ACTIONS(46), 1,
+      anon_sym_COMMA,
+    ACTIONS(48), 1,
+      anon_sym_are,
+    ACTIONS(69), 1,
       sym_newline,
-    STATE(12), 1,
+    STATE(8), 1,
       sym_comment,
-    ACTIONS(43), 2,
+    STATE(154), 1,
+      aux_sym_uniqueness_repeat1,
+    ACTIONS(55), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(41), 14,
+    ACTIONS(67), 2,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+    ACTIONS(42), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+    ACTIONS(53), 5,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(40), 16,
+      anon_sym_strictly,
+      anon_sym_between,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_GT_EQ,
@@ -3135,19 +4592,43 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_is_not_set,
       anon_sym_contains,
       anon_sym_does_not_contain,
-  [423] = 6,
+  [414] = 13,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(13), 1,
+    ACTIONS(46), 1,
+      anon_sym_COMMA,
+    ACTIONS(71), 1,
+      anon_sym_is,
+    ACTIONS(74), 1,
+      anon_sym_has,
+    ACTIONS(76), 1,
+      anon_sym_are,
+    STATE(9), 1,
       sym_comment,
-    STATE(33), 1,
-      sym_comparison_operator,
-    ACTIONS(29), 2,
+    STATE(184), 1,
+      aux_sym_uniqueness_repeat1,
+    ACTIONS(55), 2,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(27), 14,
+    ACTIONS(67), 2,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+    ACTIONS(42), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+    ACTIONS(53), 5,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(40), 16,
+      anon_sym_strictly,
+      anon_sym_between,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_GT_EQ,
@@ -3162,41 +4643,181 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_is_not_set,
       anon_sym_contains,
       anon_sym_does_not_contain,
-  [456] = 7,
+  [478] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(61), 1,
-      anon_sym_DQUOTE,
-    STATE(9), 1,
-      sym_object,
-    STATE(11), 1,
-      sym_string,
-    STATE(14), 1,
+    STATE(10), 1,
       sym_comment,
-    ACTIONS(59), 11,
-      anon_sym_data_record,
-      anon_sym_user_data,
-      anon_sym_transaction,
-      anon_sym_account_data,
-      anon_sym_password_data,
-      anon_sym_token_data,
-      anon_sym_certificate_data,
-      anon_sym_file_data,
-      anon_sym_document_data,
-      anon_sym_message_data,
-      sym_identifier,
-  [488] = 5,
-    ACTIONS(5), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
-      sym_whitespace,
-    ACTIONS(65), 1,
+    ACTIONS(78), 33,
+      anon_sym_can,
+      anon_sym_must,
+      anon_sym_should,
+      anon_sym_shall,
+      anon_sym_will,
+      anon_sym_may,
+      anon_sym_is,
+      anon_sym_create,
+      anon_sym_read,
+      anon_sym_update,
+      anon_sym_delete,
+      anon_sym_validate,
+      anon_sym_verify,
+      anon_sym_authenticate,
+      anon_sym_authorize,
+      anon_sym_encrypt,
+      anon_sym_decrypt,
+      anon_sym_send,
+      anon_sym_receive,
+      anon_sym_store,
+      anon_sym_retrieve,
+      anon_sym_process,
+      anon_sym_calculate,
+      anon_sym_generate,
+      anon_sym_export,
+      anon_sym_import,
+      anon_sym_withdraw,
+      anon_sym_deposit,
+      anon_sym_transfer,
+      anon_sym_login,
+      anon_sym_logout,
+      anon_sym_register,
+      sym_identifier,
+  [523] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(11), 1,
+      sym_comment,
+    ACTIONS(80), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(82), 28,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_and,
+      anon_sym_COMMA,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [569] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(12), 1,
+      sym_comment,
+    ACTIONS(84), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(86), 28,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_and,
+      anon_sym_COMMA,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [615] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(13), 1,
+      sym_comment,
+    ACTIONS(88), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(90), 28,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_and,
+      anon_sym_COMMA,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [661] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(94), 1,
       anon_sym_DQUOTE,
-    STATE(15), 1,
+    STATE(14), 1,
       sym_comment,
-    ACTIONS(63), 11,
+    ACTIONS(92), 29,
+      anon_sym_to,
       anon_sym_data_record,
       anon_sym_user_data,
       anon_sym_transaction,
@@ -3207,913 +4828,5045 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_file_data,
       anon_sym_document_data,
       anon_sym_message_data,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_from,
+      anon_sym_in,
+      anon_sym_on,
+      anon_sym_at,
+      anon_sym_by,
+      anon_sym_with,
+      anon_sym_without,
+      anon_sym_for,
+      anon_sym_into,
+      anon_sym_onto,
+      anon_sym_through,
+      anon_sym_during,
+      anon_sym_before,
+      anon_sym_after,
+      sym_identifier,
+      sym_newline,
+  [705] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(7), 1,
+      sym_verb,
+    STATE(15), 1,
+      sym_comment,
+    STATE(110), 1,
+      sym_action,
+    ACTIONS(96), 26,
+      anon_sym_create,
+      anon_sym_read,
+      anon_sym_update,
+      anon_sym_delete,
+      anon_sym_validate,
+      anon_sym_verify,
+      anon_sym_authenticate,
+      anon_sym_authorize,
+      anon_sym_encrypt,
+      anon_sym_decrypt,
+      anon_sym_send,
+      anon_sym_receive,
+      anon_sym_store,
+      anon_sym_retrieve,
+      anon_sym_process,
+      anon_sym_calculate,
+      anon_sym_generate,
+      anon_sym_export,
+      anon_sym_import,
+      anon_sym_withdraw,
+      anon_sym_deposit,
+      anon_sym_transfer,
+      anon_sym_login,
+      anon_sym_logout,
+      anon_sym_register,
+      sym_identifier,
+  [749] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(7), 1,
+      sym_verb,
+    STATE(16), 1,
+      sym_comment,
+    STATE(115), 1,
+      sym_action,
+    ACTIONS(96), 26,
+      anon_sym_create,
+      anon_sym_read,
+      anon_sym_update,
+      anon_sym_delete,
+      anon_sym_validate,
+      anon_sym_verify,
+      anon_sym_authenticate,
+      anon_sym_authorize,
+      anon_sym_encrypt,
+      anon_sym_decrypt,
+      anon_sym_send,
+      anon_sym_receive,
+      anon_sym_store,
+      anon_sym_retrieve,
+      anon_sym_process,
+      anon_sym_calculate,
+      anon_sym_generate,
+      anon_sym_export,
+      anon_sym_import,
+      anon_sym_withdraw,
+      anon_sym_deposit,
+      anon_sym_transfer,
+      anon_sym_login,
+      anon_sym_logout,
+      anon_sym_register,
+      sym_identifier,
+  [793] = 18,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    STATE(6), 1,
+      sym_variable,
+    STATE(17), 1,
+      sym_comment,
+    STATE(24), 1,
+      sym_left_expression,
+    STATE(120), 1,
+      sym_parenthesized_expression,
+    STATE(189), 1,
+      sym_expression,
+    STATE(194), 1,
+      sym_arithmetic_expression,
+    STATE(222), 1,
+      sym_constraint_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(123), 7,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+  [861] = 18,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    STATE(6), 1,
+      sym_variable,
+    STATE(18), 1,
+      sym_comment,
+    STATE(24), 1,
+      sym_left_expression,
+    STATE(120), 1,
+      sym_parenthesized_expression,
+    STATE(189), 1,
+      sym_expression,
+    STATE(190), 1,
+      sym_constraint_expression,
+    STATE(194), 1,
+      sym_arithmetic_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(123), 7,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+  [929] = 18,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    STATE(6), 1,
+      sym_variable,
+    STATE(19), 1,
+      sym_comment,
+    STATE(24), 1,
+      sym_left_expression,
+    STATE(120), 1,
+      sym_parenthesized_expression,
+    STATE(189), 1,
+      sym_expression,
+    STATE(194), 1,
+      sym_arithmetic_expression,
+    STATE(223), 1,
+      sym_constraint_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(123), 7,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+  [997] = 18,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    STATE(6), 1,
+      sym_variable,
+    STATE(20), 1,
+      sym_comment,
+    STATE(24), 1,
+      sym_left_expression,
+    STATE(120), 1,
+      sym_parenthesized_expression,
+    STATE(172), 1,
+      sym_constraint_expression,
+    STATE(189), 1,
+      sym_expression,
+    STATE(194), 1,
+      sym_arithmetic_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(123), 7,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+  [1065] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(7), 1,
+      sym_verb,
+    STATE(21), 1,
+      sym_comment,
+    STATE(234), 1,
+      sym_action,
+    ACTIONS(96), 26,
+      anon_sym_create,
+      anon_sym_read,
+      anon_sym_update,
+      anon_sym_delete,
+      anon_sym_validate,
+      anon_sym_verify,
+      anon_sym_authenticate,
+      anon_sym_authorize,
+      anon_sym_encrypt,
+      anon_sym_decrypt,
+      anon_sym_send,
+      anon_sym_receive,
+      anon_sym_store,
+      anon_sym_retrieve,
+      anon_sym_process,
+      anon_sym_calculate,
+      anon_sym_generate,
+      anon_sym_export,
+      anon_sym_import,
+      anon_sym_withdraw,
+      anon_sym_deposit,
+      anon_sym_transfer,
+      anon_sym_login,
+      anon_sym_logout,
+      anon_sym_register,
+      sym_identifier,
+  [1109] = 18,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    STATE(6), 1,
+      sym_variable,
+    STATE(22), 1,
+      sym_comment,
+    STATE(24), 1,
+      sym_left_expression,
+    STATE(120), 1,
+      sym_parenthesized_expression,
+    STATE(189), 1,
+      sym_expression,
+    STATE(194), 1,
+      sym_arithmetic_expression,
+    STATE(239), 1,
+      sym_constraint_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(123), 7,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+  [1177] = 18,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    STATE(6), 1,
+      sym_variable,
+    STATE(23), 1,
+      sym_comment,
+    STATE(24), 1,
+      sym_left_expression,
+    STATE(120), 1,
+      sym_parenthesized_expression,
+    STATE(161), 1,
+      sym_constraint_expression,
+    STATE(189), 1,
+      sym_expression,
+    STATE(194), 1,
+      sym_arithmetic_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(123), 7,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+  [1245] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(112), 1,
+      anon_sym_is,
+    ACTIONS(114), 1,
+      anon_sym_strictly,
+    ACTIONS(116), 1,
+      anon_sym_between,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    STATE(24), 1,
+      sym_comment,
+    STATE(55), 1,
+      sym_comparison_operator,
+    STATE(63), 1,
+      sym_arithmetic_operator,
+    ACTIONS(122), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(118), 5,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+    ACTIONS(120), 14,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+  [1300] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(25), 1,
+      sym_comment,
+    ACTIONS(126), 26,
+      anon_sym_create,
+      anon_sym_read,
+      anon_sym_update,
+      anon_sym_delete,
+      anon_sym_validate,
+      anon_sym_verify,
+      anon_sym_authenticate,
+      anon_sym_authorize,
+      anon_sym_encrypt,
+      anon_sym_decrypt,
+      anon_sym_send,
+      anon_sym_receive,
+      anon_sym_store,
+      anon_sym_retrieve,
+      anon_sym_process,
+      anon_sym_calculate,
+      anon_sym_generate,
+      anon_sym_export,
+      anon_sym_import,
+      anon_sym_withdraw,
+      anon_sym_deposit,
+      anon_sym_transfer,
+      anon_sym_login,
+      anon_sym_logout,
+      anon_sym_register,
+      sym_identifier,
+  [1338] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(128), 1,
+      anon_sym_LPAREN,
+    ACTIONS(130), 1,
+      sym_number,
+    STATE(8), 1,
+      sym_variable,
+    STATE(26), 1,
+      sym_comment,
+    STATE(48), 1,
+      sym_left_expression,
+    STATE(124), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(133), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1398] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(128), 1,
+      anon_sym_LPAREN,
+    ACTIONS(130), 1,
+      sym_number,
+    STATE(8), 1,
+      sym_variable,
+    STATE(27), 1,
+      sym_comment,
+    STATE(48), 1,
+      sym_left_expression,
+    STATE(135), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(133), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1458] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(28), 1,
+      sym_comment,
+    ACTIONS(132), 26,
+      anon_sym_create,
+      anon_sym_read,
+      anon_sym_update,
+      anon_sym_delete,
+      anon_sym_validate,
+      anon_sym_verify,
+      anon_sym_authenticate,
+      anon_sym_authorize,
+      anon_sym_encrypt,
+      anon_sym_decrypt,
+      anon_sym_send,
+      anon_sym_receive,
+      anon_sym_store,
+      anon_sym_retrieve,
+      anon_sym_process,
+      anon_sym_calculate,
+      anon_sym_generate,
+      anon_sym_export,
+      anon_sym_import,
+      anon_sym_withdraw,
+      anon_sym_deposit,
+      anon_sym_transfer,
+      anon_sym_login,
+      anon_sym_logout,
+      anon_sym_register,
+      sym_identifier,
+  [1496] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(128), 1,
+      anon_sym_LPAREN,
+    ACTIONS(130), 1,
+      sym_number,
+    STATE(8), 1,
+      sym_variable,
+    STATE(29), 1,
+      sym_comment,
+    STATE(48), 1,
+      sym_left_expression,
+    STATE(144), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(133), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1556] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(30), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(174), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1616] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(31), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(168), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1676] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(32), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(139), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1736] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(33), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(140), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1796] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(34), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(141), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1856] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(35), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(134), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1916] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(100), 1,
+      anon_sym_if,
+    ACTIONS(102), 1,
+      anon_sym_not,
+    ACTIONS(128), 1,
+      anon_sym_LPAREN,
+    ACTIONS(130), 1,
+      sym_number,
+    STATE(8), 1,
+      sym_variable,
+    STATE(36), 1,
+      sym_comment,
+    STATE(48), 1,
+      sym_left_expression,
+    STATE(145), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(133), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [1976] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(37), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(176), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [2036] = 15,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(104), 1,
+      anon_sym_LPAREN,
+    ACTIONS(110), 1,
+      sym_number,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(136), 1,
+      anon_sym_if,
+    ACTIONS(138), 1,
+      anon_sym_not,
+    STATE(9), 1,
+      sym_variable,
+    STATE(38), 1,
+      sym_comment,
+    STATE(50), 1,
+      sym_left_expression,
+    STATE(177), 1,
+      sym_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+    STATE(120), 8,
+      sym_comparison,
+      sym_conditional_comparison,
+      sym_range_comparison,
+      sym_divisibility,
+      sym_bit_test,
+      sym_uniqueness,
+      sym_logical_expression,
+      sym_parenthesized_expression,
+  [2096] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(39), 1,
+      sym_comment,
+    ACTIONS(140), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(142), 21,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [2135] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(40), 1,
+      sym_comment,
+    ACTIONS(144), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(146), 21,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [2174] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(41), 1,
+      sym_comment,
+    ACTIONS(55), 4,
+      anon_sym_is,
+      anon_sym_GT,
+      anon_sym_LT,
+      anon_sym_SLASH,
+    ACTIONS(40), 21,
+      anon_sym_strictly,
+      anon_sym_between,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [2213] = 8,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(148), 1,
+      anon_sym_else,
+    STATE(42), 1,
+      sym_comment,
+    ACTIONS(55), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(67), 2,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+    ACTIONS(53), 5,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(40), 14,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+  [2257] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(43), 1,
+      sym_comment,
+    ACTIONS(152), 2,
+      anon_sym_DQUOTE,
+      sym_number,
+    ACTIONS(150), 22,
+      anon_sym_to,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_from,
+      anon_sym_in,
+      anon_sym_on,
+      anon_sym_at,
+      anon_sym_by,
+      anon_sym_with,
+      anon_sym_without,
+      anon_sym_for,
+      anon_sym_into,
+      anon_sym_onto,
+      anon_sym_through,
+      anon_sym_during,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_the,
+      sym_identifier,
+      sym_newline,
+  [2295] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(44), 1,
+      sym_comment,
+    ACTIONS(156), 2,
+      anon_sym_DQUOTE,
+      sym_number,
+    ACTIONS(154), 22,
+      anon_sym_to,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_from,
+      anon_sym_in,
+      anon_sym_on,
+      anon_sym_at,
+      anon_sym_by,
+      anon_sym_with,
+      anon_sym_without,
+      anon_sym_for,
+      anon_sym_into,
+      anon_sym_onto,
+      anon_sym_through,
+      anon_sym_during,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_the,
+      sym_identifier,
+      sym_newline,
+  [2333] = 8,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(158), 1,
+      anon_sym_else,
+    STATE(45), 1,
+      sym_comment,
+    ACTIONS(55), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(67), 2,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+    ACTIONS(53), 5,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_CARET,
+      anon_sym_LT_LT,
+      anon_sym_GT_GT,
+    ACTIONS(40), 14,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+  [2377] = 15,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(9), 1,
+      sym_identifier,
+    ACTIONS(11), 1,
+      anon_sym_Given,
+    ACTIONS(17), 1,
+      anon_sym_Invariant_COLON,
+    ACTIONS(160), 1,
+      ts_builtin_sym_end,
+    ACTIONS(162), 1,
+      sym_newline,
+    STATE(46), 1,
+      sym_comment,
+    STATE(47), 1,
+      aux_sym_source_file_repeat1,
+    STATE(90), 1,
+      sym_kind_marker,
+    STATE(91), 1,
+      sym_subject,
+    STATE(188), 1,
+      sym_given_clause,
+    ACTIONS(15), 3,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+    STATE(73), 3,
+      sym_definition,
+      sym_requirement,
+      sym_bdd_requirement,
+    ACTIONS(13), 6,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+  [2432] = 14,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(164), 1,
+      ts_builtin_sym_end,
+    ACTIONS(166), 1,
+      sym_identifier,
+    ACTIONS(169), 1,
+      anon_sym_Given,
+    ACTIONS(178), 1,
+      anon_sym_Invariant_COLON,
+    ACTIONS(181), 1,
+      sym_newline,
+    STATE(90), 1,
+      sym_kind_marker,
+    STATE(91), 1,
+      sym_subject,
+    STATE(188), 1,
+      sym_given_clause,
+    STATE(47), 2,
+      sym_comment,
+      aux_sym_source_file_repeat1,
+    ACTIONS(175), 3,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+    STATE(73), 3,
+      sym_definition,
+      sym_requirement,
+      sym_bdd_requirement,
+    ACTIONS(172), 6,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+  [2485] = 9,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(112), 1,
+      anon_sym_is,
+    ACTIONS(114), 1,
+      anon_sym_strictly,
+    ACTIONS(116), 1,
+      anon_sym_between,
+    STATE(48), 1,
+      sym_comment,
+    STATE(55), 1,
+      sym_comparison_operator,
+    ACTIONS(122), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(120), 14,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+  [2527] = 9,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(187), 1,
+      sym_newline,
+    STATE(49), 1,
+      sym_comment,
+    STATE(96), 1,
+      sym_preposition,
+    STATE(166), 1,
+      sym_preposition_phrase,
+    ACTIONS(185), 2,
+      anon_sym_if,
+      anon_sym_where,
+    ACTIONS(61), 3,
+      anon_sym_in,
+      anon_sym_on,
+      anon_sym_with,
+    ACTIONS(183), 12,
+      anon_sym_to,
+      anon_sym_from,
+      anon_sym_at,
+      anon_sym_by,
+      anon_sym_without,
+      anon_sym_for,
+      anon_sym_into,
+      anon_sym_onto,
+      anon_sym_through,
+      anon_sym_during,
+      anon_sym_before,
+      anon_sym_after,
+  [2569] = 9,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(189), 1,
+      anon_sym_is,
+    ACTIONS(191), 1,
+      anon_sym_strictly,
+    ACTIONS(193), 1,
+      anon_sym_between,
+    STATE(50), 1,
+      sym_comment,
+    STATE(53), 1,
+      sym_comparison_operator,
+    ACTIONS(122), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(120), 14,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+  [2611] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(51), 1,
+      sym_comment,
+    ACTIONS(197), 4,
+      anon_sym_in,
+      anon_sym_on,
+      anon_sym_with,
+      sym_newline,
+    ACTIONS(195), 14,
+      anon_sym_to,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_from,
+      anon_sym_at,
+      anon_sym_by,
+      anon_sym_without,
+      anon_sym_for,
+      anon_sym_into,
+      anon_sym_onto,
+      anon_sym_through,
+      anon_sym_during,
+      anon_sym_before,
+      anon_sym_after,
+  [2643] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(52), 1,
+      sym_comment,
+    STATE(64), 1,
+      sym_comparison_operator,
+    ACTIONS(122), 2,
+      anon_sym_GT,
+      anon_sym_LT,
+    ACTIONS(120), 14,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_equals,
+      anon_sym_not_equals,
+      anon_sym_greater_than,
+      anon_sym_less_than,
+      anon_sym_at_least,
+      anon_sym_at_most,
+      anon_sym_is_set,
+      anon_sym_is_not_set,
+      anon_sym_contains,
+      anon_sym_does_not_contain,
+  [2676] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(53), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(109), 1,
+      sym_right_expression,
+    STATE(136), 1,
+      sym_conditional_value,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2720] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(207), 1,
+      sym_number,
+    STATE(54), 1,
+      sym_comment,
+    STATE(84), 1,
+      sym_variable,
+    STATE(114), 1,
+      sym_right_expression,
+    STATE(131), 1,
+      sym_conditional_value,
+    ACTIONS(203), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(205), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(103), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2764] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(207), 1,
+      sym_number,
+    STATE(55), 1,
+      sym_comment,
+    STATE(84), 1,
+      sym_variable,
+    STATE(106), 1,
+      sym_right_expression,
+    STATE(130), 1,
+      sym_conditional_value,
+    ACTIONS(203), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(205), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(103), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2808] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(56), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(130), 1,
+      sym_conditional_value,
+    STATE(213), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2852] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(57), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(118), 1,
+      sym_right_expression,
+    STATE(150), 1,
+      sym_conditional_value,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2896] = 12,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(58), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(136), 1,
+      sym_conditional_value,
+    STATE(249), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2940] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(207), 1,
+      sym_number,
+    STATE(59), 1,
+      sym_comment,
+    STATE(84), 1,
+      sym_variable,
+    STATE(126), 1,
+      sym_right_expression,
+    ACTIONS(203), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(205), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(103), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [2981] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(207), 1,
+      sym_number,
+    STATE(60), 1,
+      sym_comment,
+    STATE(84), 1,
+      sym_variable,
+    STATE(128), 1,
+      sym_right_expression,
+    ACTIONS(203), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(205), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(103), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3022] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(213), 1,
+      anon_sym_And,
+    STATE(61), 1,
+      sym_comment,
+    ACTIONS(209), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(211), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3053] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(62), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(236), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3094] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(98), 1,
+      sym_identifier,
+    ACTIONS(207), 1,
+      sym_number,
+    STATE(63), 1,
+      sym_comment,
+    STATE(84), 1,
+      sym_variable,
+    STATE(208), 1,
+      sym_right_expression,
+    ACTIONS(203), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(205), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(103), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3135] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(64), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(214), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3176] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(65), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(148), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3217] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(66), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(237), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3258] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(67), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(226), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3299] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(213), 1,
+      anon_sym_And,
+    STATE(68), 1,
+      sym_comment,
+    ACTIONS(215), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(217), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3330] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(69), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(146), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3371] = 11,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(199), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(201), 1,
+      sym_number,
+    STATE(70), 1,
+      sym_comment,
+    STATE(80), 1,
+      sym_variable,
+    STATE(230), 1,
+      sym_right_expression,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(101), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+      sym_string,
+  [3412] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(71), 1,
+      sym_comment,
+    ACTIONS(219), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(221), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3440] = 10,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    STATE(42), 1,
+      sym_variable,
+    STATE(52), 1,
+      sym_left_expression,
+    STATE(72), 1,
+      sym_comment,
+    STATE(244), 1,
+      sym_comparison,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+  [3478] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(73), 1,
+      sym_comment,
+    ACTIONS(223), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(225), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3506] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(74), 1,
+      sym_comment,
+    ACTIONS(227), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(229), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3534] = 10,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    STATE(45), 1,
+      sym_variable,
+    STATE(52), 1,
+      sym_left_expression,
+    STATE(75), 1,
+      sym_comment,
+    STATE(233), 1,
+      sym_comparison,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(41), 4,
+      sym_function_call,
+      sym_aggregate,
+      sym_modulo_term,
+      sym_bitwise_term,
+  [3572] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(76), 1,
+      sym_comment,
+    ACTIONS(231), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(233), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3600] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(77), 1,
+      sym_comment,
+    ACTIONS(235), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(237), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3628] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(78), 1,
+      sym_comment,
+    ACTIONS(239), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(241), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3656] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(79), 1,
+      sym_comment,
+    ACTIONS(243), 2,
+      ts_builtin_sym_end,
+      anon_sym_Invariant_COLON,
+    ACTIONS(245), 12,
+      anon_sym_Given,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      anon_sym_Assuming,
+      anon_sym_Assume,
+      anon_sym_Always,
+      sym_identifier,
+      sym_newline,
+  [3684] = 7,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    STATE(80), 1,
+      sym_comment,
+    STATE(155), 1,
+      sym_arithmetic_operator,
+    ACTIONS(118), 5,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+    ACTIONS(247), 6,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [3715] = 10,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(249), 1,
+      sym_number,
+    STATE(81), 1,
+      sym_comment,
+    STATE(86), 1,
+      sym_variable,
+    STATE(196), 1,
+      sym_call_argument,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(193), 3,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+  [3752] = 10,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(249), 1,
+      sym_number,
+    STATE(82), 1,
+      sym_comment,
+    STATE(86), 1,
+      sym_variable,
+    STATE(179), 1,
+      sym_call_argument,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(193), 3,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+  [3789] = 10,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(134), 1,
+      sym_identifier,
+    ACTIONS(249), 1,
+      sym_number,
+    STATE(83), 1,
+      sym_comment,
+    STATE(86), 1,
+      sym_variable,
+    STATE(156), 1,
+      sym_call_argument,
+    ACTIONS(106), 3,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+    ACTIONS(108), 3,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+    STATE(193), 3,
+      sym_function_call,
+      sym_aggregate,
+      sym_arithmetic_term,
+  [3826] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    ACTIONS(251), 1,
+      sym_newline,
+    STATE(84), 1,
+      sym_comment,
+    STATE(164), 1,
+      sym_arithmetic_operator,
+    ACTIONS(247), 4,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+    ACTIONS(118), 5,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [3858] = 9,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(257), 1,
+      sym_number,
+    STATE(85), 1,
+      sym_comment,
+    STATE(89), 1,
+      aux_sym_noun_phrase_repeat1,
+    STATE(97), 1,
+      sym_string,
+    ACTIONS(253), 2,
+      anon_sym_the,
+      sym_identifier,
+    ACTIONS(255), 3,
+      anon_sym_if,
+      anon_sym_where,
+      sym_newline,
+  [3889] = 7,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(124), 1,
+      anon_sym_SLASH,
+    STATE(86), 1,
+      sym_comment,
+    STATE(155), 1,
+      sym_arithmetic_operator,
+    ACTIONS(259), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+    ACTIONS(118), 5,
+      anon_sym_PERCENT,
+      anon_sym_mod,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [3916] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(87), 1,
+      sym_comment,
+    ACTIONS(263), 2,
+      anon_sym_DQUOTE,
+      sym_number,
+    ACTIONS(261), 7,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+      sym_identifier,
+  [3939] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(88), 1,
+      sym_comment,
+    ACTIONS(267), 2,
+      anon_sym_DQUOTE,
+      sym_number,
+    ACTIONS(265), 7,
+      anon_sym_min,
+      anon_sym_max,
+      anon_sym_abs,
+      anon_sym_sum,
+      anon_sym_count,
+      anon_sym_average,
+      sym_identifier,
+  [3962] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(274), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(277), 1,
+      sym_number,
+    STATE(97), 1,
+      sym_string,
+    ACTIONS(269), 2,
+      anon_sym_the,
+      sym_identifier,
+    STATE(89), 2,
+      sym_comment,
+      aux_sym_noun_phrase_repeat1,
+    ACTIONS(272), 3,
+      anon_sym_if,
+      anon_sym_where,
+      sym_newline,
+  [3991] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(90), 1,
+      sym_comment,
+    STATE(92), 1,
+      sym_subject,
+    ACTIONS(13), 7,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      sym_identifier,
+  [4013] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(282), 1,
+      anon_sym_is,
+    STATE(16), 1,
+      sym_modal_verb,
+    STATE(91), 1,
+      sym_comment,
+    ACTIONS(280), 6,
+      anon_sym_can,
+      anon_sym_must,
+      anon_sym_should,
+      anon_sym_shall,
+      anon_sym_will,
+      anon_sym_may,
+  [4037] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(282), 1,
+      anon_sym_is,
+    STATE(15), 1,
+      sym_modal_verb,
+    STATE(92), 1,
+      sym_comment,
+    ACTIONS(280), 6,
+      anon_sym_can,
+      anon_sym_must,
+      anon_sym_should,
+      anon_sym_shall,
+      anon_sym_will,
+      anon_sym_may,
+  [4061] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(284), 1,
+      anon_sym_COLON_EQ,
+    STATE(93), 1,
+      sym_comment,
+    ACTIONS(286), 7,
+      anon_sym_can,
+      anon_sym_must,
+      anon_sym_should,
+      anon_sym_shall,
+      anon_sym_will,
+      anon_sym_may,
+      anon_sym_is,
+  [4083] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(21), 1,
+      sym_subject,
+    STATE(94), 1,
+      sym_comment,
+    ACTIONS(13), 7,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      sym_identifier,
+  [4105] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(95), 1,
+      sym_comment,
+    ACTIONS(288), 7,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_and,
+      anon_sym_COMMA,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4124] = 9,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(65), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(257), 1,
+      sym_number,
+    STATE(85), 1,
+      aux_sym_noun_phrase_repeat1,
+    STATE(96), 1,
+      sym_comment,
+    STATE(97), 1,
+      sym_string,
+    STATE(167), 1,
+      sym_noun_phrase,
+    ACTIONS(253), 2,
+      anon_sym_the,
+      sym_identifier,
+  [4153] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(97), 1,
+      sym_comment,
+    ACTIONS(292), 2,
+      anon_sym_DQUOTE,
+      sym_number,
+    ACTIONS(290), 5,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_the,
+      sym_identifier,
+      sym_newline,
+  [4174] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(98), 1,
+      sym_comment,
+    ACTIONS(294), 7,
+      anon_sym_User,
+      anon_sym_System,
+      anon_sym_Admin,
+      anon_sym_Application,
+      anon_sym_Service,
+      anon_sym_API,
+      sym_identifier,
+  [4193] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(99), 1,
+      sym_comment,
+    ACTIONS(152), 6,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4211] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(100), 1,
+      sym_comment,
+    ACTIONS(156), 6,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4229] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(101), 1,
+      sym_comment,
+    ACTIONS(247), 6,
+      anon_sym_if,
+      anon_sym_else,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4247] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(80), 1,
+      sym_newline,
+    STATE(102), 1,
+      sym_comment,
+    ACTIONS(82), 4,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4266] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(251), 1,
+      sym_newline,
+    STATE(103), 1,
+      sym_comment,
+    ACTIONS(247), 4,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4285] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(88), 1,
+      sym_newline,
+    STATE(104), 1,
+      sym_comment,
+    ACTIONS(90), 4,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4304] = 7,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(296), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(298), 1,
+      aux_sym_string_token1,
+    ACTIONS(301), 1,
+      anon_sym_BSLASH,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    STATE(171), 1,
+      sym_escape_sequence,
+    STATE(105), 2,
+      sym_comment,
+      aux_sym_string_repeat1,
+  [4327] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(306), 1,
+      anon_sym_if,
+    ACTIONS(310), 1,
+      sym_newline,
+    STATE(106), 1,
+      sym_comment,
+    ACTIONS(308), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4348] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(312), 1,
+      sym_newline,
+    STATE(107), 1,
+      sym_comment,
+    ACTIONS(288), 4,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4367] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(84), 1,
+      sym_newline,
+    STATE(108), 1,
+      sym_comment,
+    ACTIONS(86), 4,
+      anon_sym_if,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4386] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(314), 1,
+      anon_sym_if,
+    STATE(109), 1,
+      sym_comment,
+    ACTIONS(308), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4405] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_where,
+    ACTIONS(320), 1,
+      sym_newline,
+    STATE(110), 1,
+      sym_comment,
+    STATE(169), 1,
+      sym_condition,
+    STATE(211), 1,
+      sym_constraint,
+  [4430] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(322), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(324), 1,
+      aux_sym_string_token1,
+    ACTIONS(326), 1,
+      anon_sym_BSLASH,
+    STATE(105), 1,
+      aux_sym_string_repeat1,
+    STATE(111), 1,
+      sym_comment,
+    STATE(171), 1,
+      sym_escape_sequence,
+  [4455] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(324), 1,
+      aux_sym_string_token1,
+    ACTIONS(326), 1,
+      anon_sym_BSLASH,
+    ACTIONS(328), 1,
+      anon_sym_DQUOTE,
+    STATE(105), 1,
+      aux_sym_string_repeat1,
+    STATE(112), 1,
+      sym_comment,
+    STATE(171), 1,
+      sym_escape_sequence,
+  [4480] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(330), 1,
+      anon_sym_SLASH,
+    STATE(113), 1,
+      sym_comment,
+    ACTIONS(332), 4,
+      anon_sym_DQUOTE,
+      anon_sym_BSLASH,
+      anon_sym_n,
+      anon_sym_t,
+  [4499] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(306), 1,
+      anon_sym_if,
+    ACTIONS(336), 1,
+      sym_newline,
+    STATE(114), 1,
+      sym_comment,
+    ACTIONS(334), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4520] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(316), 1,
+      anon_sym_if,
+    ACTIONS(318), 1,
+      anon_sym_where,
+    ACTIONS(338), 1,
+      sym_newline,
+    STATE(115), 1,
+      sym_comment,
+    STATE(178), 1,
+      sym_condition,
+    STATE(225), 1,
+      sym_constraint,
+  [4545] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(324), 1,
+      aux_sym_string_token1,
+    ACTIONS(326), 1,
+      anon_sym_BSLASH,
+    ACTIONS(340), 1,
+      anon_sym_DQUOTE,
+    STATE(111), 1,
+      aux_sym_string_repeat1,
+    STATE(116), 1,
+      sym_comment,
+    STATE(171), 1,
+      sym_escape_sequence,
+  [4570] = 8,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(324), 1,
+      aux_sym_string_token1,
+    ACTIONS(326), 1,
+      anon_sym_BSLASH,
+    ACTIONS(342), 1,
+      anon_sym_DQUOTE,
+    STATE(112), 1,
+      aux_sym_string_repeat1,
+    STATE(117), 1,
+      sym_comment,
+    STATE(171), 1,
+      sym_escape_sequence,
+  [4595] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(314), 1,
+      anon_sym_if,
+    STATE(118), 1,
+      sym_comment,
+    ACTIONS(334), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4614] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(119), 1,
+      sym_comment,
+    ACTIONS(344), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4630] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(120), 1,
+      sym_comment,
+    ACTIONS(42), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4646] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(348), 1,
+      sym_newline,
+    STATE(121), 1,
+      sym_comment,
+    ACTIONS(346), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4664] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(348), 1,
+      sym_newline,
+    STATE(122), 1,
+      sym_comment,
+    ACTIONS(346), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4682] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(38), 1,
+      anon_sym_where,
+    ACTIONS(57), 1,
+      sym_newline,
+    STATE(123), 1,
+      sym_comment,
+    ACTIONS(42), 2,
+      anon_sym_and,
+      anon_sym_or,
+  [4702] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(352), 1,
+      sym_newline,
+    STATE(124), 1,
+      sym_comment,
+    ACTIONS(350), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4720] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(125), 1,
+      sym_comment,
+    ACTIONS(354), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4736] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(358), 1,
+      sym_newline,
+    STATE(126), 1,
+      sym_comment,
+    ACTIONS(356), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4754] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(362), 1,
+      sym_newline,
+    STATE(127), 1,
+      sym_comment,
+    ACTIONS(360), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4772] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(366), 1,
+      sym_newline,
+    STATE(128), 1,
+      sym_comment,
+    ACTIONS(364), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4790] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(370), 1,
+      sym_newline,
+    STATE(129), 1,
+      sym_comment,
+    ACTIONS(368), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4808] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(374), 1,
+      sym_newline,
+    STATE(130), 1,
+      sym_comment,
+    ACTIONS(372), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4826] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(336), 1,
+      sym_newline,
+    STATE(131), 1,
+      sym_comment,
+    ACTIONS(334), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4844] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(376), 1,
+      sym_newline,
+    STATE(132), 1,
+      sym_comment,
+    ACTIONS(344), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4862] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(69), 1,
+      sym_newline,
+    STATE(133), 1,
+      sym_comment,
+    ACTIONS(42), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4880] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(134), 1,
+      sym_comment,
+    ACTIONS(350), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4896] = 7,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(378), 1,
+      anon_sym_where,
+    ACTIONS(380), 1,
+      anon_sym_and,
+    ACTIONS(382), 1,
+      anon_sym_or,
+    ACTIONS(384), 1,
+      sym_newline,
+    STATE(135), 1,
+      sym_comment,
+  [4918] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(136), 1,
+      sym_comment,
+    ACTIONS(372), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4934] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(388), 1,
+      sym_newline,
+    STATE(137), 1,
+      sym_comment,
+    ACTIONS(386), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [4952] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(138), 1,
+      sym_comment,
+    ACTIONS(386), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4968] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(139), 1,
+      sym_comment,
+    ACTIONS(390), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [4984] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(392), 1,
+      anon_sym_and,
+    STATE(140), 1,
+      sym_comment,
+    ACTIONS(390), 3,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5002] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(392), 1,
+      anon_sym_and,
+    ACTIONS(394), 1,
+      anon_sym_or,
+    STATE(141), 1,
+      sym_comment,
+    ACTIONS(378), 2,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5022] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(142), 1,
+      sym_comment,
+    ACTIONS(346), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5038] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(143), 1,
+      sym_comment,
+    ACTIONS(346), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5054] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(396), 1,
+      sym_newline,
+    STATE(144), 1,
+      sym_comment,
+    ACTIONS(390), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [5072] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(380), 1,
+      anon_sym_and,
+    ACTIONS(396), 1,
+      sym_newline,
+    STATE(145), 1,
+      sym_comment,
+    ACTIONS(390), 2,
+      anon_sym_where,
+      anon_sym_or,
+  [5092] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(146), 1,
+      sym_comment,
+    ACTIONS(356), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5108] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(147), 1,
+      sym_comment,
+    ACTIONS(360), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5124] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(148), 1,
+      sym_comment,
+    ACTIONS(364), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5140] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(149), 1,
+      sym_comment,
+    ACTIONS(368), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5156] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(150), 1,
+      sym_comment,
+    ACTIONS(334), 4,
+      anon_sym_and,
+      anon_sym_or,
+      anon_sym_then,
+      anon_sym_RPAREN,
+  [5172] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(151), 1,
+      sym_comment,
+    ACTIONS(398), 2,
+      anon_sym_the,
+      sym_identifier,
+    ACTIONS(400), 2,
+      anon_sym_DQUOTE,
+      sym_number,
+  [5190] = 7,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(402), 1,
+      sym_identifier,
+    ACTIONS(404), 1,
+      sym_number,
+    ACTIONS(406), 1,
+      sym_bit_pattern,
+    STATE(39), 1,
+      sym_variable,
+    STATE(152), 1,
+      sym_comment,
+  [5212] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(408), 1,
+      sym_newline,
+    STATE(153), 1,
+      sym_comment,
+    ACTIONS(354), 3,
+      anon_sym_where,
+      anon_sym_and,
+      anon_sym_or,
+  [5230] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(46), 1,
+      anon_sym_COMMA,
+    ACTIONS(410), 1,
+      anon_sym_are,
+    STATE(154), 1,
+      sym_comment,
+    STATE(165), 1,
+      aux_sym_uniqueness_repeat1,
+  [5249] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(402), 1,
+      sym_identifier,
+    ACTIONS(412), 1,
+      sym_number,
+    STATE(95), 1,
+      sym_variable,
+    STATE(155), 1,
+      sym_comment,
+  [5268] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(414), 1,
+      anon_sym_COMMA,
+    ACTIONS(416), 1,
+      anon_sym_RPAREN,
+    STATE(156), 1,
+      sym_comment,
+    STATE(157), 1,
+      aux_sym_function_call_repeat1,
+  [5287] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(414), 1,
+      anon_sym_COMMA,
+    ACTIONS(418), 1,
+      anon_sym_RPAREN,
+    STATE(157), 1,
+      sym_comment,
+    STATE(175), 1,
+      aux_sym_function_call_repeat1,
+  [5306] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(420), 1,
+      sym_identifier,
+    ACTIONS(422), 1,
+      sym_number,
+    STATE(129), 1,
+      sym_variable,
+    STATE(158), 1,
+      sym_comment,
+  [5325] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(424), 1,
+      sym_newline,
+    STATE(251), 1,
+      sym_and_clause,
+    STATE(159), 2,
+      sym_comment,
+      aux_sym_given_clause_repeat1,
+  [5342] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(420), 1,
+      sym_identifier,
+    ACTIONS(427), 1,
+      sym_number,
+    STATE(132), 1,
+      sym_variable,
+    STATE(160), 1,
+      sym_comment,
+  [5361] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(429), 1,
+      sym_newline,
+    STATE(161), 1,
+      sym_comment,
+    STATE(162), 1,
+      aux_sym_given_clause_repeat1,
+    STATE(251), 1,
+      sym_and_clause,
+  [5380] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(431), 1,
+      sym_newline,
+    STATE(159), 1,
+      aux_sym_given_clause_repeat1,
+    STATE(162), 1,
+      sym_comment,
+    STATE(251), 1,
+      sym_and_clause,
+  [5399] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(433), 1,
+      sym_newline,
+    STATE(159), 1,
+      aux_sym_given_clause_repeat1,
+    STATE(163), 1,
+      sym_comment,
+    STATE(251), 1,
+      sym_and_clause,
+  [5418] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(420), 1,
+      sym_identifier,
+    ACTIONS(435), 1,
+      sym_number,
+    STATE(107), 1,
+      sym_variable,
+    STATE(164), 1,
+      sym_comment,
+  [5437] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(437), 1,
+      anon_sym_COMMA,
+    ACTIONS(440), 1,
+      anon_sym_are,
+    STATE(165), 2,
+      sym_comment,
+      aux_sym_uniqueness_repeat1,
+  [5454] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(444), 1,
+      sym_newline,
+    STATE(166), 1,
+      sym_comment,
+    ACTIONS(442), 2,
+      anon_sym_if,
+      anon_sym_where,
+  [5471] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(448), 1,
+      sym_newline,
+    STATE(167), 1,
+      sym_comment,
+    ACTIONS(446), 2,
+      anon_sym_if,
+      anon_sym_where,
+  [5488] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(392), 1,
+      anon_sym_and,
+    ACTIONS(394), 1,
+      anon_sym_or,
+    ACTIONS(450), 1,
+      anon_sym_then,
+    STATE(168), 1,
+      sym_comment,
+  [5507] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(318), 1,
+      anon_sym_where,
+    ACTIONS(452), 1,
+      sym_newline,
+    STATE(169), 1,
+      sym_comment,
+    STATE(220), 1,
+      sym_constraint,
+  [5526] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(402), 1,
+      sym_identifier,
+    ACTIONS(454), 1,
+      sym_number,
+    STATE(149), 1,
+      sym_variable,
+    STATE(170), 1,
+      sym_comment,
+  [5545] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(458), 1,
+      aux_sym_string_token1,
+    STATE(171), 1,
+      sym_comment,
+    ACTIONS(456), 2,
+      anon_sym_DQUOTE,
+      anon_sym_BSLASH,
+  [5562] = 6,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(460), 1,
+      sym_newline,
+    STATE(163), 1,
+      aux_sym_given_clause_repeat1,
+    STATE(172), 1,
+      sym_comment,
+    STATE(251), 1,
+      sym_and_clause,
+  [5581] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(402), 1,
       sym_identifier,
-  [514] = 8,
+    ACTIONS(462), 1,
+      sym_number,
+    STATE(119), 1,
+      sym_variable,
+    STATE(173), 1,
+      sym_comment,
+  [5600] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(392), 1,
+      anon_sym_and,
+    ACTIONS(394), 1,
+      anon_sym_or,
+    ACTIONS(464), 1,
+      anon_sym_RPAREN,
+    STATE(174), 1,
+      sym_comment,
+  [5619] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(466), 1,
+      anon_sym_COMMA,
+    ACTIONS(469), 1,
+      anon_sym_RPAREN,
+    STATE(175), 2,
+      sym_comment,
+      aux_sym_function_call_repeat1,
+  [5636] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(392), 1,
+      anon_sym_and,
+    ACTIONS(394), 1,
+      anon_sym_or,
+    ACTIONS(471), 1,
+      anon_sym_then,
+    STATE(176), 1,
+      sym_comment,
+  [5655] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(392), 1,
+      anon_sym_and,
+    ACTIONS(394), 1,
+      anon_sym_or,
+    ACTIONS(473), 1,
+      anon_sym_RPAREN,
+    STATE(177), 1,
+      sym_comment,
+  [5674] = 6,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(67), 1,
-      ts_builtin_sym_end,
-    ACTIONS(72), 1,
+    ACTIONS(318), 1,
+      anon_sym_where,
+    ACTIONS(320), 1,
       sym_newline,
-    STATE(25), 1,
-      sym_requirement,
-    STATE(26), 1,
-      sym_subject,
-    STATE(16), 2,
+    STATE(178), 1,
       sym_comment,
-      aux_sym_source_file_repeat1,
-    ACTIONS(69), 7,
-      anon_sym_User,
-      anon_sym_System,
-      anon_sym_Admin,
-      anon_sym_Application,
-      anon_sym_Service,
-      anon_sym_API,
-      sym_identifier,
-  [546] = 9,
+    STATE(211), 1,
+      sym_constraint,
+  [5693] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(414), 1,
+      anon_sym_COMMA,
+    ACTIONS(475), 1,
+      anon_sym_RPAREN,
+    STATE(179), 1,
+      sym_comment,
+    STATE(180), 1,
+      aux_sym_function_call_repeat1,
+  [5712] = 6,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(414), 1,
+      anon_sym_COMMA,
+    ACTIONS(477), 1,
+      anon_sym_RPAREN,
+    STATE(175), 1,
+      aux_sym_function_call_repeat1,
+    STATE(180), 1,
+      sym_comment,
+  [5731] = 5,
+    ACTIONS(3), 1,
+      sym_whitespace,
+    ACTIONS(304), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(481), 1,
+      aux_sym_string_token1,
+    STATE(181), 1,
+      sym_comment,
+    ACTIONS(479), 2,
+      anon_sym_DQUOTE,
+      anon_sym_BSLASH,
+  [5748] = 5,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(74), 1,
-      ts_builtin_sym_end,
-    ACTIONS(76), 1,
+    ACTIONS(187), 1,
       sym_newline,
-    STATE(16), 1,
-      aux_sym_source_file_repeat1,
-    STATE(17), 1,
+    STATE(182), 1,
       sym_comment,
-    STATE(25), 1,
-      sym_requirement,
-    STATE(26), 1,
-      sym_subject,
-    ACTIONS(9), 7,
-      anon_sym_User,
-      anon_sym_System,
-      anon_sym_Admin,
-      anon_sym_Application,
-      anon_sym_Service,
-      anon_sym_API,
-      sym_identifier,
-  [580] = 12,
+    ACTIONS(185), 2,
+      anon_sym_if,
+      anon_sym_where,
+  [5765] = 6,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(78), 1,
+    ACTIONS(402), 1,
       sym_identifier,
-    ACTIONS(80), 1,
-      anon_sym_not,
-    ACTIONS(82), 1,
+    ACTIONS(483), 1,
       sym_number,
-    STATE(6), 1,
-      sym_left_expression,
-    STATE(8), 1,
+    STATE(40), 1,
       sym_variable,
-    STATE(18), 1,
+    STATE(183), 1,
       sym_comment,
-    STATE(42), 1,
-      sym_comparison,
-    STATE(51), 1,
-      sym_constraint_expression,
-    STATE(56), 1,
-      sym_expression,
-    STATE(52), 2,
-      sym_logical_expression,
-      sym_arithmetic_expression,
-  [618] = 12,
+  [5784] = 6,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(78), 1,
+    ACTIONS(46), 1,
+      anon_sym_COMMA,
+    ACTIONS(485), 1,
+      anon_sym_are,
+    STATE(165), 1,
+      aux_sym_uniqueness_repeat1,
+    STATE(184), 1,
+      sym_comment,
+  [5803] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(402), 1,
       sym_identifier,
-    ACTIONS(80), 1,
-      anon_sym_not,
-    ACTIONS(82), 1,
-      sym_number,
-    STATE(6), 1,
-      sym_left_expression,
-    STATE(8), 1,
+    STATE(11), 1,
       sym_variable,
-    STATE(19), 1,
+    STATE(185), 1,
       sym_comment,
-    STATE(42), 1,
-      sym_comparison,
-    STATE(56), 1,
-      sym_expression,
-    STATE(64), 1,
-      sym_constraint_expression,
-    STATE(52), 2,
-      sym_logical_expression,
-      sym_arithmetic_expression,
-  [656] = 5,
-    ACTIONS(3), 1,
+  [5819] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(487), 1,
+      anon_sym_not,
+    ACTIONS(489), 1,
+      anon_sym_a,
+    STATE(186), 1,
+      sym_comment,
+  [5835] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(84), 1,
-      ts_builtin_sym_end,
-    STATE(20), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(187), 1,
       sym_comment,
-    ACTIONS(86), 8,
-      anon_sym_User,
-      anon_sym_System,
-      anon_sym_Admin,
-      anon_sym_Application,
-      anon_sym_Service,
-      anon_sym_API,
-      sym_identifier,
-      sym_newline,
-  [679] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(491), 2,
+      anon_sym_COMMA,
+      anon_sym_are,
+  [5849] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(493), 1,
+      anon_sym_When,
+    STATE(188), 1,
+      sym_comment,
+    STATE(197), 1,
+      sym_when_clause,
+  [5865] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(88), 1,
-      ts_builtin_sym_end,
-    STATE(21), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(380), 1,
+      anon_sym_and,
+    ACTIONS(382), 1,
+      anon_sym_or,
+    STATE(189), 1,
       sym_comment,
-    ACTIONS(90), 8,
-      anon_sym_User,
-      anon_sym_System,
-      anon_sym_Admin,
-      anon_sym_Application,
-      anon_sym_Service,
-      anon_sym_API,
-      sym_identifier,
-      sym_newline,
-  [702] = 5,
+  [5881] = 5,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(92), 1,
-      ts_builtin_sym_end,
-    STATE(22), 1,
-      sym_comment,
-    ACTIONS(94), 8,
-      anon_sym_User,
-      anon_sym_System,
-      anon_sym_Admin,
-      anon_sym_Application,
-      anon_sym_Service,
-      anon_sym_API,
-      sym_identifier,
+    ACTIONS(495), 1,
+      anon_sym_where,
+    ACTIONS(497), 1,
       sym_newline,
-  [725] = 9,
-    ACTIONS(3), 1,
+    STATE(190), 1,
+      sym_comment,
+  [5897] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(499), 1,
+      anon_sym_When,
+    ACTIONS(501), 1,
+      anon_sym_And,
+    STATE(191), 1,
+      sym_comment,
+  [5913] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(61), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(100), 1,
-      sym_number,
-    STATE(23), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(503), 1,
+      anon_sym_unique,
+    ACTIONS(505), 1,
+      anon_sym_distinct,
+    STATE(192), 1,
       sym_comment,
-    STATE(24), 1,
-      aux_sym_noun_phrase_repeat1,
-    STATE(28), 1,
-      sym_string,
-    ACTIONS(96), 2,
-      anon_sym_the,
-      sym_identifier,
-    ACTIONS(98), 3,
-      anon_sym_if,
-      anon_sym_where,
-      sym_newline,
-  [756] = 8,
+  [5929] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(193), 1,
+      sym_comment,
+    ACTIONS(259), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [5943] = 5,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(107), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(110), 1,
-      sym_number,
-    STATE(28), 1,
-      sym_string,
-    ACTIONS(102), 2,
-      anon_sym_the,
-      sym_identifier,
-    STATE(24), 2,
-      sym_comment,
-      aux_sym_noun_phrase_repeat1,
-    ACTIONS(105), 3,
-      anon_sym_if,
+    ACTIONS(38), 1,
       anon_sym_where,
+    ACTIONS(57), 1,
       sym_newline,
-  [785] = 5,
-    ACTIONS(3), 1,
+    STATE(194), 1,
+      sym_comment,
+  [5959] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(501), 1,
+      anon_sym_And,
+    ACTIONS(507), 1,
+      anon_sym_When,
+    STATE(195), 1,
+      sym_comment,
+  [5975] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(113), 1,
-      ts_builtin_sym_end,
-    STATE(25), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(196), 1,
       sym_comment,
-    ACTIONS(115), 8,
-      anon_sym_User,
-      anon_sym_System,
-      anon_sym_Admin,
-      anon_sym_Application,
-      anon_sym_Service,
-      anon_sym_API,
-      sym_identifier,
-      sym_newline,
-  [808] = 5,
+    ACTIONS(469), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [5989] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(2), 1,
-      sym_modal_verb,
-    STATE(26), 1,
+    ACTIONS(509), 1,
+      anon_sym_Then,
+    STATE(79), 1,
+      sym_then_clause,
+    STATE(197), 1,
       sym_comment,
-    ACTIONS(117), 6,
-      anon_sym_can,
-      anon_sym_must,
-      anon_sym_should,
-      anon_sym_shall,
-      anon_sym_will,
-      anon_sym_may,
-  [829] = 9,
+  [6005] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(61), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(100), 1,
-      sym_number,
-    STATE(23), 1,
-      aux_sym_noun_phrase_repeat1,
-    STATE(27), 1,
+    ACTIONS(511), 1,
+      anon_sym_of,
+    ACTIONS(513), 1,
+      anon_sym_LPAREN,
+    STATE(198), 1,
       sym_comment,
-    STATE(28), 1,
-      sym_string,
-    STATE(49), 1,
-      sym_noun_phrase,
-    ACTIONS(96), 2,
-      anon_sym_the,
-      sym_identifier,
-  [858] = 5,
-    ACTIONS(3), 1,
+  [6021] = 5,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(515), 1,
+      anon_sym_not,
+    ACTIONS(517), 1,
+      anon_sym_a,
+    STATE(199), 1,
+      sym_comment,
+  [6037] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    STATE(28), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(519), 1,
+      anon_sym_of,
+    ACTIONS(521), 1,
+      anon_sym_LPAREN,
+    STATE(200), 1,
       sym_comment,
-    ACTIONS(121), 2,
-      anon_sym_DQUOTE,
-      sym_number,
-    ACTIONS(119), 5,
-      anon_sym_if,
-      anon_sym_where,
-      anon_sym_the,
-      sym_identifier,
-      sym_newline,
-  [879] = 9,
+  [6053] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(123), 1,
-      sym_identifier,
-    ACTIONS(125), 1,
-      sym_number,
-    STATE(12), 1,
-      sym_variable,
-    STATE(13), 1,
-      sym_left_expression,
-    STATE(29), 1,
+    STATE(201), 1,
+      sym_comment,
+    ACTIONS(523), 2,
+      anon_sym_set,
+      anon_sym_clear,
+  [6067] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    STATE(202), 1,
       sym_comment,
-    STATE(50), 1,
-      sym_expression,
-    STATE(57), 1,
-      sym_comparison,
-  [907] = 4,
+    ACTIONS(525), 2,
+      anon_sym_unique,
+      anon_sym_distinct,
+  [6081] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(30), 1,
+    ACTIONS(527), 1,
+      anon_sym_unique,
+    ACTIONS(529), 1,
+      anon_sym_distinct,
+    STATE(203), 1,
       sym_comment,
-    ACTIONS(127), 6,
-      anon_sym_can,
-      anon_sym_must,
-      anon_sym_should,
-      anon_sym_shall,
-      anon_sym_will,
-      anon_sym_may,
-  [925] = 9,
+  [6097] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(123), 1,
+    ACTIONS(420), 1,
       sym_identifier,
-    ACTIONS(125), 1,
-      sym_number,
-    STATE(12), 1,
+    STATE(102), 1,
       sym_variable,
-    STATE(13), 1,
-      sym_left_expression,
-    STATE(31), 1,
+    STATE(204), 1,
       sym_comment,
-    STATE(55), 1,
-      sym_expression,
-    STATE(57), 1,
-      sym_comparison,
-  [953] = 8,
+  [6113] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(61), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(123), 1,
-      sym_identifier,
-    ACTIONS(129), 1,
-      sym_number,
-    STATE(32), 1,
+    STATE(205), 1,
       sym_comment,
-    STATE(54), 1,
-      sym_right_expression,
-    STATE(40), 2,
-      sym_variable,
-      sym_string,
-  [979] = 8,
+    ACTIONS(531), 2,
+      anon_sym_set,
+      anon_sym_clear,
+  [6127] = 5,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(61), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(123), 1,
+    ACTIONS(402), 1,
       sym_identifier,
-    ACTIONS(129), 1,
-      sym_number,
-    STATE(33), 1,
-      sym_comment,
-    STATE(41), 1,
-      sym_right_expression,
-    STATE(40), 2,
+    STATE(187), 1,
       sym_variable,
-      sym_string,
-  [1005] = 5,
+    STATE(206), 1,
+      sym_comment,
+  [6143] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(131), 1,
-      anon_sym_SLASH,
-    STATE(34), 1,
+    STATE(207), 1,
       sym_comment,
-    ACTIONS(133), 4,
-      anon_sym_DQUOTE,
-      anon_sym_BSLASH,
-      anon_sym_n,
-      anon_sym_t,
-  [1024] = 8,
+    ACTIONS(533), 2,
+      anon_sym_unique,
+      anon_sym_distinct,
+  [6157] = 5,
     ACTIONS(3), 1,
       sym_whitespace,
-    ACTIONS(135), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(137), 1,
-      aux_sym_string_token1,
-    ACTIONS(139), 1,
-      anon_sym_BSLASH,
-    ACTIONS(141), 1,
+    ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    STATE(35), 1,
+    ACTIONS(535), 1,
+      anon_sym_where,
+    ACTIONS(537), 1,
+      sym_newline,
+    STATE(208), 1,
       sym_comment,
-    STATE(38), 1,
-      aux_sym_string_repeat1,
-    STATE(43), 1,
-      sym_escape_sequence,
-  [1049] = 8,
-    ACTIONS(3), 1,
+  [6173] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(137), 1,
-      aux_sym_string_token1,
-    ACTIONS(139), 1,
-      anon_sym_BSLASH,
-    ACTIONS(141), 1,
+    ACTIONS(539), 1,
+      anon_sym_multiple,
+    STATE(209), 1,
+      sym_comment,
+  [6186] = 4,
+    ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(143), 1,
-      anon_sym_DQUOTE,
-    STATE(35), 1,
-      aux_sym_string_repeat1,
-    STATE(36), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(541), 1,
+      anon_sym_multiple,
+    STATE(210), 1,
       sym_comment,
-    STATE(43), 1,
-      sym_escape_sequence,
-  [1074] = 8,
+  [6199] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(145), 1,
-      anon_sym_if,
-    ACTIONS(147), 1,
-      anon_sym_where,
-    ACTIONS(149), 1,
+    ACTIONS(452), 1,
       sym_newline,
-    STATE(37), 1,
+    STATE(211), 1,
       sym_comment,
-    STATE(44), 1,
-      sym_condition,
-    STATE(58), 1,
-      sym_constraint,
-  [1099] = 7,
-    ACTIONS(3), 1,
+  [6212] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(141), 1,
+    ACTIONS(543), 1,
+      anon_sym_of,
+    STATE(212), 1,
+      sym_comment,
+  [6225] = 4,
+    ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(151), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(153), 1,
-      aux_sym_string_token1,
-    ACTIONS(156), 1,
-      anon_sym_BSLASH,
-    STATE(43), 1,
-      sym_escape_sequence,
-    STATE(38), 2,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(306), 1,
+      anon_sym_if,
+    STATE(213), 1,
       sym_comment,
-      aux_sym_string_repeat1,
-  [1122] = 5,
+  [6238] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(39), 1,
+    ACTIONS(308), 1,
+      anon_sym_else,
+    STATE(214), 1,
       sym_comment,
-    ACTIONS(159), 2,
-      anon_sym_the,
-      sym_identifier,
-    ACTIONS(161), 2,
-      anon_sym_DQUOTE,
+  [6251] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(545), 1,
       sym_number,
-  [1140] = 5,
-    ACTIONS(3), 1,
+    STATE(215), 1,
+      sym_comment,
+  [6264] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(547), 1,
+      ts_builtin_sym_end,
+    STATE(216), 1,
+      sym_comment,
+  [6277] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(165), 1,
-      sym_newline,
-    STATE(40), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(549), 1,
+      anon_sym_Then,
+    STATE(217), 1,
       sym_comment,
-    ACTIONS(163), 3,
-      anon_sym_where,
-      anon_sym_and,
-      anon_sym_or,
-  [1158] = 5,
-    ACTIONS(3), 1,
+  [6290] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(551), 1,
+      anon_sym_to,
+    STATE(218), 1,
+      sym_comment,
+  [6303] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(169), 1,
-      sym_newline,
-    STATE(41), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(553), 1,
+      anon_sym_bit,
+    STATE(219), 1,
       sym_comment,
-    ACTIONS(167), 3,
-      anon_sym_where,
-      anon_sym_and,
-      anon_sym_or,
-  [1176] = 6,
+  [6316] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(171), 1,
-      anon_sym_where,
-    ACTIONS(173), 1,
+    ACTIONS(555), 1,
       sym_newline,
-    STATE(42), 1,
+    STATE(220), 1,
       sym_comment,
-    ACTIONS(39), 2,
-      anon_sym_and,
-      anon_sym_or,
-  [1196] = 5,
-    ACTIONS(3), 1,
-      sym_whitespace,
-    ACTIONS(141), 1,
+  [6329] = 4,
+    ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(177), 1,
-      aux_sym_string_token1,
-    STATE(43), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(557), 1,
+      ts_builtin_sym_end,
+    STATE(221), 1,
       sym_comment,
-    ACTIONS(175), 2,
-      anon_sym_DQUOTE,
-      anon_sym_BSLASH,
-  [1213] = 6,
+  [6342] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(147), 1,
-      anon_sym_where,
-    ACTIONS(179), 1,
+    ACTIONS(559), 1,
       sym_newline,
-    STATE(44), 1,
+    STATE(222), 1,
       sym_comment,
-    STATE(59), 1,
-      sym_constraint,
-  [1232] = 4,
+  [6355] = 4,
+    ACTIONS(3), 1,
+      sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
-      sym_whitespace,
-    STATE(45), 1,
+    ACTIONS(561), 1,
+      sym_newline,
+    STATE(223), 1,
       sym_comment,
-    ACTIONS(181), 3,
-      sym_identifier,
-      anon_sym_DQUOTE,
-      sym_number,
-  [1247] = 4,
+  [6368] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(46), 1,
+    ACTIONS(160), 1,
+      ts_builtin_sym_end,
+    STATE(224), 1,
       sym_comment,
-    ACTIONS(183), 3,
-      sym_identifier,
-      anon_sym_DQUOTE,
-      sym_number,
-  [1262] = 5,
+  [6381] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(187), 1,
+    ACTIONS(320), 1,
       sym_newline,
-    STATE(47), 1,
+    STATE(225), 1,
       sym_comment,
-    ACTIONS(185), 2,
-      anon_sym_if,
-      anon_sym_where,
-  [1279] = 5,
+  [6394] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(563), 1,
+      anon_sym_and,
+    STATE(226), 1,
+      sym_comment,
+  [6407] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
-    ACTIONS(141), 1,
+    ACTIONS(304), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(191), 1,
-      aux_sym_string_token1,
-    STATE(48), 1,
+    ACTIONS(565), 1,
+      aux_sym_comment_token1,
+    STATE(227), 1,
       sym_comment,
-    ACTIONS(189), 2,
-      anon_sym_DQUOTE,
-      anon_sym_BSLASH,
-  [1296] = 5,
-    ACTIONS(3), 1,
+  [6420] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(501), 1,
+      anon_sym_And,
+    STATE(228), 1,
+      sym_comment,
+  [6433] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(195), 1,
-      sym_newline,
-    STATE(49), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(567), 1,
+      anon_sym_multiple,
+    STATE(229), 1,
       sym_comment,
-    ACTIONS(193), 2,
-      anon_sym_if,
-      anon_sym_where,
-  [1313] = 5,
-    ACTIONS(3), 1,
+  [6446] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(569), 1,
+      anon_sym_and,
+    STATE(230), 1,
+      sym_comment,
+  [6459] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(197), 1,
-      anon_sym_where,
-    ACTIONS(199), 1,
-      sym_newline,
-    STATE(50), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(571), 1,
+      anon_sym_of,
+    STATE(231), 1,
       sym_comment,
-  [1329] = 5,
-    ACTIONS(3), 1,
+  [6472] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(513), 1,
+      anon_sym_LPAREN,
+    STATE(232), 1,
+      sym_comment,
+  [6485] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(201), 1,
-      anon_sym_where,
-    ACTIONS(203), 1,
-      sym_newline,
-    STATE(51), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(158), 1,
+      anon_sym_else,
+    STATE(233), 1,
       sym_comment,
-  [1345] = 5,
+  [6498] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(171), 1,
-      anon_sym_where,
-    ACTIONS(173), 1,
+    ACTIONS(573), 1,
       sym_newline,
-    STATE(52), 1,
+    STATE(234), 1,
       sym_comment,
-  [1361] = 4,
+  [6511] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(53), 1,
+    ACTIONS(575), 1,
+      anon_sym_of,
+    STATE(235), 1,
       sym_comment,
-    ACTIONS(39), 2,
+  [6524] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(577), 1,
       anon_sym_and,
-      anon_sym_or,
-  [1375] = 5,
-    ACTIONS(3), 1,
+    STATE(236), 1,
+      sym_comment,
+  [6537] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(579), 1,
+      anon_sym_and,
+    STATE(237), 1,
+      sym_comment,
+  [6550] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(205), 1,
-      anon_sym_where,
-    ACTIONS(207), 1,
-      sym_newline,
-    STATE(54), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(581), 1,
+      anon_sym_between,
+    STATE(238), 1,
       sym_comment,
-  [1391] = 5,
+  [6563] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(209), 1,
-      anon_sym_where,
-    ACTIONS(211), 1,
+    ACTIONS(583), 1,
       sym_newline,
-    STATE(55), 1,
+    STATE(239), 1,
       sym_comment,
-  [1407] = 4,
+  [6576] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    STATE(56), 1,
+    ACTIONS(585), 1,
+      anon_sym_of,
+    STATE(240), 1,
       sym_comment,
-    ACTIONS(213), 2,
-      anon_sym_and,
-      anon_sym_or,
-  [1421] = 5,
-    ACTIONS(3), 1,
-      sym_whitespace,
+  [6589] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(39), 1,
-      anon_sym_where,
-    ACTIONS(57), 1,
-      sym_newline,
-    STATE(57), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(587), 1,
+      anon_sym_multiple,
+    STATE(241), 1,
       sym_comment,
-  [1437] = 4,
-    ACTIONS(3), 1,
+  [6602] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(589), 1,
+      sym_number,
+    STATE(242), 1,
+      sym_comment,
+  [6615] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(179), 1,
-      sym_newline,
-    STATE(58), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(591), 1,
+      anon_sym_a,
+    STATE(243), 1,
       sym_comment,
-  [1450] = 4,
-    ACTIONS(3), 1,
+  [6628] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
+    ACTIONS(148), 1,
+      anon_sym_else,
+    STATE(244), 1,
+      sym_comment,
+  [6641] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(215), 1,
-      sym_newline,
-    STATE(59), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(521), 1,
+      anon_sym_LPAREN,
+    STATE(245), 1,
       sym_comment,
-  [1463] = 4,
-    ACTIONS(3), 1,
+  [6654] = 4,
+    ACTIONS(5), 1,
+      anon_sym_SLASH_SLASH,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(141), 1,
+    ACTIONS(593), 1,
+      anon_sym_between,
+    STATE(246), 1,
+      sym_comment,
+  [6667] = 4,
+    ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(217), 1,
-      aux_sym_comment_token1,
-    STATE(60), 1,
+    ACTIONS(27), 1,
+      sym_whitespace,
+    ACTIONS(595), 1,
+      anon_sym_guaranteed,
+    STATE(247), 1,
       sym_comment,
-  [1476] = 4,
+  [6680] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(74), 1,
-      ts_builtin_sym_end,
-    STATE(61), 1,
+    ACTIONS(597), 1,
+      anon_sym_bit,
+    STATE(248), 1,
       sym_comment,
-  [1489] = 4,
+  [6693] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(219), 1,
-      ts_builtin_sym_end,
-    STATE(62), 1,
+    ACTIONS(314), 1,
+      anon_sym_if,
+    STATE(249), 1,
       sym_comment,
-  [1502] = 4,
+  [6706] = 4,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(15), 1,
+    ACTIONS(27), 1,
       sym_whitespace,
-    ACTIONS(221), 1,
-      ts_builtin_sym_end,
-    STATE(63), 1,
+    ACTIONS(599), 1,
+      anon_sym_a,
+    STATE(250), 1,
       sym_comment,
-  [1515] = 4,
+  [6719] = 4,
     ACTIONS(3), 1,
       sym_whitespace,
     ACTIONS(5), 1,
       anon_sym_SLASH_SLASH,
-    ACTIONS(223), 1,
+    ACTIONS(601), 1,
       sym_newline,
-    STATE(64), 1,
+    STATE(251), 1,
       sym_comment,
-  [1528] = 1,
-    ACTIONS(225), 1,
+  [6732] = 1,
+    ACTIONS(603), 1,
       ts_builtin_sym_end,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 44,
-  [SMALL_STATE(4)] = 82,
-  [SMALL_STATE(5)] = 120,
-  [SMALL_STATE(6)] = 158,
-  [SMALL_STATE(7)] = 203,
-  [SMALL_STATE(8)] = 240,
-  [SMALL_STATE(9)] = 279,
-  [SMALL_STATE(10)] = 321,
-  [SMALL_STATE(11)] = 355,
-  [SMALL_STATE(12)] = 387,
-  [SMALL_STATE(13)] = 423,
-  [SMALL_STATE(14)] = 456,
-  [SMALL_STATE(15)] = 488,
-  [SMALL_STATE(16)] = 514,
-  [SMALL_STATE(17)] = 546,
-  [SMALL_STATE(18)] = 580,
-  [SMALL_STATE(19)] = 618,
-  [SMALL_STATE(20)] = 656,
-  [SMALL_STATE(21)] = 679,
-  [SMALL_STATE(22)] = 702,
-  [SMALL_STATE(23)] = 725,
-  [SMALL_STATE(24)] = 756,
-  [SMALL_STATE(25)] = 785,
-  [SMALL_STATE(26)] = 808,
-  [SMALL_STATE(27)] = 829,
-  [SMALL_STATE(28)] = 858,
-  [SMALL_STATE(29)] = 879,
-  [SMALL_STATE(30)] = 907,
-  [SMALL_STATE(31)] = 925,
-  [SMALL_STATE(32)] = 953,
-  [SMALL_STATE(33)] = 979,
-  [SMALL_STATE(34)] = 1005,
-  [SMALL_STATE(35)] = 1024,
-  [SMALL_STATE(36)] = 1049,
-  [SMALL_STATE(37)] = 1074,
-  [SMALL_STATE(38)] = 1099,
-  [SMALL_STATE(39)] = 1122,
-  [SMALL_STATE(40)] = 1140,
-  [SMALL_STATE(41)] = 1158,
-  [SMALL_STATE(42)] = 1176,
-  [SMALL_STATE(43)] = 1196,
-  [SMALL_STATE(44)] = 1213,
-  [SMALL_STATE(45)] = 1232,
-  [SMALL_STATE(46)] = 1247,
-  [SMALL_STATE(47)] = 1262,
-  [SMALL_STATE(48)] = 1279,
-  [SMALL_STATE(49)] = 1296,
-  [SMALL_STATE(50)] = 1313,
-  [SMALL_STATE(51)] = 1329,
-  [SMALL_STATE(52)] = 1345,
-  [SMALL_STATE(53)] = 1361,
-  [SMALL_STATE(54)] = 1375,
-  [SMALL_STATE(55)] = 1391,
-  [SMALL_STATE(56)] = 1407,
-  [SMALL_STATE(57)] = 1421,
-  [SMALL_STATE(58)] = 1437,
-  [SMALL_STATE(59)] = 1450,
-  [SMALL_STATE(60)] = 1463,
-  [SMALL_STATE(61)] = 1476,
-  [SMALL_STATE(62)] = 1489,
-  [SMALL_STATE(63)] = 1502,
-  [SMALL_STATE(64)] = 1515,
-  [SMALL_STATE(65)] = 1528,
+  [SMALL_STATE(3)] = 56,
+  [SMALL_STATE(4)] = 109,
+  [SMALL_STATE(5)] = 164,
+  [SMALL_STATE(6)] = 216,
+  [SMALL_STATE(7)] = 288,
+  [SMALL_STATE(8)] = 348,
+  [SMALL_STATE(9)] = 414,
+  [SMALL_STATE(10)] = 478,
+  [SMALL_STATE(11)] = 523,
+  [SMALL_STATE(12)] = 569,
+  [SMALL_STATE(13)] = 615,
+  [SMALL_STATE(14)] = 661,
+  [SMALL_STATE(15)] = 705,
+  [SMALL_STATE(16)] = 749,
+  [SMALL_STATE(17)] = 793,
+  [SMALL_STATE(18)] = 861,
+  [SMALL_STATE(19)] = 929,
+  [SMALL_STATE(20)] = 997,
+  [SMALL_STATE(21)] = 1065,
+  [SMALL_STATE(22)] = 1109,
+  [SMALL_STATE(23)] = 1177,
+  [SMALL_STATE(24)] = 1245,
+  [SMALL_STATE(25)] = 1300,
+  [SMALL_STATE(26)] = 1338,
+  [SMALL_STATE(27)] = 1398,
+  [SMALL_STATE(28)] = 1458,
+  [SMALL_STATE(29)] = 1496,
+  [SMALL_STATE(30)] = 1556,
+  [SMALL_STATE(31)] = 1616,
+  [SMALL_STATE(32)] = 1676,
+  [SMALL_STATE(33)] = 1736,
+  [SMALL_STATE(34)] = 1796,
+  [SMALL_STATE(35)] = 1856,
+  [SMALL_STATE(36)] = 1916,
+  [SMALL_STATE(37)] = 1976,
+  [SMALL_STATE(38)] = 2036,
+  [SMALL_STATE(39)] = 2096,
+  [SMALL_STATE(40)] = 2135,
+  [SMALL_STATE(41)] = 2174,
+  [SMALL_STATE(42)] = 2213,
+  [SMALL_STATE(43)] = 2257,
+  [SMALL_STATE(44)] = 2295,
+  [SMALL_STATE(45)] = 2333,
+  [SMALL_STATE(46)] = 2377,
+  [SMALL_STATE(47)] = 2432,
+  [SMALL_STATE(48)] = 2485,
+  [SMALL_STATE(49)] = 2527,
+  [SMALL_STATE(50)] = 2569,
+  [SMALL_STATE(51)] = 2611,
+  [SMALL_STATE(52)] = 2643,
+  [SMALL_STATE(53)] = 2676,
+  [SMALL_STATE(54)] = 2720,
+  [SMALL_STATE(55)] = 2764,
+  [SMALL_STATE(56)] = 2808,
+  [SMALL_STATE(57)] = 2852,
+  [SMALL_STATE(58)] = 2896,
+  [SMALL_STATE(59)] = 2940,
+  [SMALL_STATE(60)] = 2981,
+  [SMALL_STATE(61)] = 3022,
+  [SMALL_STATE(62)] = 3053,
+  [SMALL_STATE(63)] = 3094,
+  [SMALL_STATE(64)] = 3135,
+  [SMALL_STATE(65)] = 3176,
+  [SMALL_STATE(66)] = 3217,
+  [SMALL_STATE(67)] = 3258,
+  [SMALL_STATE(68)] = 3299,
+  [SMALL_STATE(69)] = 3330,
+  [SMALL_STATE(70)] = 3371,
+  [SMALL_STATE(71)] = 3412,
+  [SMALL_STATE(72)] = 3440,
+  [SMALL_STATE(73)] = 3478,
+  [SMALL_STATE(74)] = 3506,
+  [SMALL_STATE(75)] = 3534,
+  [SMALL_STATE(76)] = 3572,
+  [SMALL_STATE(77)] = 3600,
+  [SMALL_STATE(78)] = 3628,
+  [SMALL_STATE(79)] = 3656,
+  [SMALL_STATE(80)] = 3684,
+  [SMALL_STATE(81)] = 3715,
+  [SMALL_STATE(82)] = 3752,
+  [SMALL_STATE(83)] = 3789,
+  [SMALL_STATE(84)] = 3826,
+  [SMALL_STATE(85)] = 3858,
+  [SMALL_STATE(86)] = 3889,
+  [SMALL_STATE(87)] = 3916,
+  [SMALL_STATE(88)] = 3939,
+  [SMALL_STATE(89)] = 3962,
+  [SMALL_STATE(90)] = 3991,
+  [SMALL_STATE(91)] = 4013,
+  [SMALL_STATE(92)] = 4037,
+  [SMALL_STATE(93)] = 4061,
+  [SMALL_STATE(94)] = 4083,
+  [SMALL_STATE(95)] = 4105,
+  [SMALL_STATE(96)] = 4124,
+  [SMALL_STATE(97)] = 4153,
+  [SMALL_STATE(98)] = 4174,
+  [SMALL_STATE(99)] = 4193,
+  [SMALL_STATE(100)] = 4211,
+  [SMALL_STATE(101)] = 4229,
+  [SMALL_STATE(102)] = 4247,
+  [SMALL_STATE(103)] = 4266,
+  [SMALL_STATE(104)] = 4285,
+  [SMALL_STATE(105)] = 4304,
+  [SMALL_STATE(106)] = 4327,
+  [SMALL_STATE(107)] = 4348,
+  [SMALL_STATE(108)] = 4367,
+  [SMALL_STATE(109)] = 4386,
+  [SMALL_STATE(110)] = 4405,
+  [SMALL_STATE(111)] = 4430,
+  [SMALL_STATE(112)] = 4455,
+  [SMALL_STATE(113)] = 4480,
+  [SMALL_STATE(114)] = 4499,
+  [SMALL_STATE(115)] = 4520,
+  [SMALL_STATE(116)] = 4545,
+  [SMALL_STATE(117)] = 4570,
+  [SMALL_STATE(118)] = 4595,
+  [SMALL_STATE(119)] = 4614,
+  [SMALL_STATE(120)] = 4630,
+  [SMALL_STATE(121)] = 4646,
+  [SMALL_STATE(122)] = 4664,
+  [SMALL_STATE(123)] = 4682,
+  [SMALL_STATE(124)] = 4702,
+  [SMALL_STATE(125)] = 4720,
+  [SMALL_STATE(126)] = 4736,
+  [SMALL_STATE(127)] = 4754,
+  [SMALL_STATE(128)] = 4772,
+  [SMALL_STATE(129)] = 4790,
+  [SMALL_STATE(130)] = 4808,
+  [SMALL_STATE(131)] = 4826,
+  [SMALL_STATE(132)] = 4844,
+  [SMALL_STATE(133)] = 4862,
+  [SMALL_STATE(134)] = 4880,
+  [SMALL_STATE(135)] = 4896,
+  [SMALL_STATE(136)] = 4918,
+  [SMALL_STATE(137)] = 4934,
+  [SMALL_STATE(138)] = 4952,
+  [SMALL_STATE(139)] = 4968,
+  [SMALL_STATE(140)] = 4984,
+  [SMALL_STATE(141)] = 5002,
+  [SMALL_STATE(142)] = 5022,
+  [SMALL_STATE(143)] = 5038,
+  [SMALL_STATE(144)] = 5054,
+  [SMALL_STATE(145)] = 5072,
+  [SMALL_STATE(146)] = 5092,
+  [SMALL_STATE(147)] = 5108,
+  [SMALL_STATE(148)] = 5124,
+  [SMALL_STATE(149)] = 5140,
+  [SMALL_STATE(150)] = 5156,
+  [SMALL_STATE(151)] = 5172,
+  [SMALL_STATE(152)] = 5190,
+  [SMALL_STATE(153)] = 5212,
+  [SMALL_STATE(154)] = 5230,
+  [SMALL_STATE(155)] = 5249,
+  [SMALL_STATE(156)] = 5268,
+  [SMALL_STATE(157)] = 5287,
+  [SMALL_STATE(158)] = 5306,
+  [SMALL_STATE(159)] = 5325,
+  [SMALL_STATE(160)] = 5342,
+  [SMALL_STATE(161)] = 5361,
+  [SMALL_STATE(162)] = 5380,
+  [SMALL_STATE(163)] = 5399,
+  [SMALL_STATE(164)] = 5418,
+  [SMALL_STATE(165)] = 5437,
+  [SMALL_STATE(166)] = 5454,
+  [SMALL_STATE(167)] = 5471,
+  [SMALL_STATE(168)] = 5488,
+  [SMALL_STATE(169)] = 5507,
+  [SMALL_STATE(170)] = 5526,
+  [SMALL_STATE(171)] = 5545,
+  [SMALL_STATE(172)] = 5562,
+  [SMALL_STATE(173)] = 5581,
+  [SMALL_STATE(174)] = 5600,
+  [SMALL_STATE(175)] = 5619,
+  [SMALL_STATE(176)] = 5636,
+  [SMALL_STATE(177)] = 5655,
+  [SMALL_STATE(178)] = 5674,
+  [SMALL_STATE(179)] = 5693,
+  [SMALL_STATE(180)] = 5712,
+  [SMALL_STATE(181)] = 5731,
+  [SMALL_STATE(182)] = 5748,
+  [SMALL_STATE(183)] = 5765,
+  [SMALL_STATE(184)] = 5784,
+  [SMALL_STATE(185)] = 5803,
+  [SMALL_STATE(186)] = 5819,
+  [SMALL_STATE(187)] = 5835,
+  [SMALL_STATE(188)] = 5849,
+  [SMALL_STATE(189)] = 5865,
+  [SMALL_STATE(190)] = 5881,
+  [SMALL_STATE(191)] = 5897,
+  [SMALL_STATE(192)] = 5913,
+  [SMALL_STATE(193)] = 5929,
+  [SMALL_STATE(194)] = 5943,
+  [SMALL_STATE(195)] = 5959,
+  [SMALL_STATE(196)] = 5975,
+  [SMALL_STATE(197)] = 5989,
+  [SMALL_STATE(198)] = 6005,
+  [SMALL_STATE(199)] = 6021,
+  [SMALL_STATE(200)] = 6037,
+  [SMALL_STATE(201)] = 6053,
+  [SMALL_STATE(202)] = 6067,
+  [SMALL_STATE(203)] = 6081,
+  [SMALL_STATE(204)] = 6097,
+  [SMALL_STATE(205)] = 6113,
+  [SMALL_STATE(206)] = 6127,
+  [SMALL_STATE(207)] = 6143,
+  [SMALL_STATE(208)] = 6157,
+  [SMALL_STATE(209)] = 6173,
+  [SMALL_STATE(210)] = 6186,
+  [SMALL_STATE(211)] = 6199,
+  [SMALL_STATE(212)] = 6212,
+  [SMALL_STATE(213)] = 6225,
+  [SMALL_STATE(214)] = 6238,
+  [SMALL_STATE(215)] = 6251,
+  [SMALL_STATE(216)] = 6264,
+  [SMALL_STATE(217)] = 6277,
+  [SMALL_STATE(218)] = 6290,
+  [SMALL_STATE(219)] = 6303,
+  [SMALL_STATE(220)] = 6316,
+  [SMALL_STATE(221)] = 6329,
+  [SMALL_STATE(222)] = 6342,
+  [SMALL_STATE(223)] = 6355,
+  [SMALL_STATE(224)] = 6368,
+  [SMALL_STATE(225)] = 6381,
+  [SMALL_STATE(226)] = 6394,
+  [SMALL_STATE(227)] = 6407,
+  [SMALL_STATE(228)] = 6420,
+  [SMALL_STATE(229)] = 6433,
+  [SMALL_STATE(230)] = 6446,
+  [SMALL_STATE(231)] = 6459,
+  [SMALL_STATE(232)] = 6472,
+  [SMALL_STATE(233)] = 6485,
+  [SMALL_STATE(234)] = 6498,
+  [SMALL_STATE(235)] = 6511,
+  [SMALL_STATE(236)] = 6524,
+  [SMALL_STATE(237)] = 6537,
+  [SMALL_STATE(238)] = 6550,
+  [SMALL_STATE(239)] = 6563,
+  [SMALL_STATE(240)] = 6576,
+  [SMALL_STATE(241)] = 6589,
+  [SMALL_STATE(242)] = 6602,
+  [SMALL_STATE(243)] = 6615,
+  [SMALL_STATE(244)] = 6628,
+  [SMALL_STATE(245)] = 6641,
+  [SMALL_STATE(246)] = 6654,
+  [SMALL_STATE(247)] = 6667,
+  [SMALL_STATE(248)] = 6680,
+  [SMALL_STATE(249)] = 6693,
+  [SMALL_STATE(250)] = 6706,
+  [SMALL_STATE(251)] = 6719,
+  [SMALL_STATE(252)] = 6732,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
-  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
   [7] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(30),
-  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
-  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
-  [17] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_modal_verb, 1, 0, 0),
-  [19] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
-  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
-  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
-  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
-  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(46),
-  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(45),
-  [35] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 1, 0, 0),
-  [37] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 1, 0, 0),
-  [39] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1, 0, 0),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_left_expression, 1, 0, 0),
-  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_left_expression, 1, 0, 0),
-  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 2, 0, 0),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
-  [51] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_action, 2, 0, 0),
-  [53] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object, 1, 0, 0),
-  [55] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object, 1, 0, 0),
-  [57] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1, 0, 0),
-  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(11),
-  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [63] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_verb, 1, 0, 0),
-  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_verb, 1, 0, 0),
-  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(30),
-  [72] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [74] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [76] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
-  [78] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
-  [80] = {.entry = {.count = 1, .reusable = false}}, SHIFT(29),
-  [82] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [84] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 4, 0, 0),
-  [86] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 4, 0, 0),
-  [88] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 6, 0, 0),
-  [90] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 6, 0, 0),
-  [92] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 5, 0, 0),
-  [94] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 5, 0, 0),
-  [96] = {.entry = {.count = 1, .reusable = false}}, SHIFT(28),
-  [98] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_noun_phrase, 1, 0, 0),
-  [100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [102] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0), SHIFT_REPEAT(28),
-  [105] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0),
-  [107] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0), SHIFT_REPEAT(36),
-  [110] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0), SHIFT_REPEAT(28),
-  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
-  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
-  [117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [119] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_noun_phrase_repeat1, 1, 0, 0),
-  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_noun_phrase_repeat1, 1, 0, 0),
-  [123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_subject, 1, 0, 0),
-  [129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
-  [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [137] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
-  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
-  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
-  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
-  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
-  [153] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(43),
-  [156] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(34),
-  [159] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_preposition, 1, 0, 0),
-  [161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preposition, 1, 0, 0),
-  [163] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_right_expression, 1, 0, 0),
-  [165] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_right_expression, 1, 0, 0),
-  [167] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison, 3, 0, 0),
-  [169] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comparison, 3, 0, 0),
-  [171] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_constraint_expression, 1, 0, 0),
-  [173] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_constraint_expression, 1, 0, 0),
-  [175] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 1, 0, 0),
-  [177] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 1, 0, 0),
-  [179] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
-  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_operator, 1, 0, 0),
-  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_operator, 1, 0, 0),
-  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 3, 0, 0),
-  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_action, 3, 0, 0),
-  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_escape_sequence, 2, 0, 0),
-  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_escape_sequence, 2, 0, 0),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preposition_phrase, 2, 0, 0),
-  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_preposition_phrase, 2, 0, 0),
-  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logical_expression, 2, 0, 0),
-  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_logical_expression, 2, 0, 0),
-  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 2, 0, 0),
-  [203] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_condition, 2, 0, 0),
-  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
-  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logical_expression, 3, 0, 0),
-  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_logical_expression, 3, 0, 0),
-  [213] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [215] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
-  [217] = {.entry = {.count = 1, .reusable = false}}, SHIFT(65),
-  [219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
-  [221] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [223] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_constraint, 2, 0, 0),
-  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 2, 0, 0),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(224),
+  [21] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 1, 0, 0),
+  [23] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 1, 0, 0),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [29] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_variable, 2, 0, 0),
+  [31] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_variable, 2, 0, 0),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [35] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_left_expression, 1, 0, 0), SHIFT(199),
+  [38] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_constraint_expression, 1, 0, 0),
+  [40] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_left_expression, 1, 0, 0),
+  [42] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1, 0, 0),
+  [44] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [46] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [48] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [50] = {.entry = {.count = 2, .reusable = true}}, REDUCE(sym_left_expression, 1, 0, 0), SHIFT(183),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [55] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_left_expression, 1, 0, 0),
+  [57] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_constraint_expression, 1, 0, 0),
+  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
+  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(151),
+  [63] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_action, 1, 0, 0),
+  [65] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [69] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1, 0, 0),
+  [71] = {.entry = {.count = 2, .reusable = false}}, REDUCE(sym_left_expression, 1, 0, 0), SHIFT(186),
+  [74] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [76] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [78] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_subject, 1, 0, 0),
+  [80] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_aggregate, 3, 0, 1),
+  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_aggregate, 3, 0, 1),
+  [84] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 5, 0, 6),
+  [86] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 5, 0, 6),
+  [88] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 4, 0, 6),
+  [90] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 4, 0, 6),
+  [92] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_verb, 1, 0, 0),
+  [94] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_verb, 1, 0, 0),
+  [96] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
+  [98] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
+  [100] = {.entry = {.count = 1, .reusable = false}}, SHIFT(31),
+  [102] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
+  [104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [106] = {.entry = {.count = 1, .reusable = false}}, SHIFT(232),
+  [108] = {.entry = {.count = 1, .reusable = false}}, SHIFT(198),
+  [110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [112] = {.entry = {.count = 1, .reusable = false}}, SHIFT(56),
+  [114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
+  [116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [122] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [124] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [126] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_modal_verb, 1, 0, 0),
+  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [132] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_modal_verb, 3, 0, 0),
+  [134] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
+  [136] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [138] = {.entry = {.count = 1, .reusable = false}}, SHIFT(35),
+  [140] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bitwise_term, 3, 0, 0),
+  [142] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bitwise_term, 3, 0, 0),
+  [144] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_modulo_term, 3, 0, 0),
+  [146] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_modulo_term, 3, 0, 0),
+  [148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [150] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
+  [152] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
+  [154] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [156] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [160] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [162] = {.entry = {.count = 1, .reusable = false}}, SHIFT(221),
+  [164] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [166] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(93),
+  [169] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(23),
+  [172] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
+  [175] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(98),
+  [178] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(98),
+  [181] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 2, 0, 0),
+  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_action, 2, 0, 0),
+  [189] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
+  [195] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_object, 1, 0, 0),
+  [197] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_object, 1, 0, 0),
+  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
+  [203] = {.entry = {.count = 1, .reusable = false}}, SHIFT(245),
+  [205] = {.entry = {.count = 1, .reusable = false}}, SHIFT(200),
+  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_then_clause, 4, 0, 0),
+  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_then_clause, 4, 0, 0),
+  [213] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [215] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_then_clause, 3, 0, 0),
+  [217] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_then_clause, 3, 0, 0),
+  [219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 7, 0, 0),
+  [221] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 7, 0, 0),
+  [223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
+  [225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
+  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 6, 0, 0),
+  [229] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 6, 0, 0),
+  [231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_definition, 4, 0, 5),
+  [233] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_definition, 4, 0, 5),
+  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 5, 0, 0),
+  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 5, 0, 0),
+  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_requirement, 4, 0, 0),
+  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_requirement, 4, 0, 0),
+  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bdd_requirement, 3, 0, 0),
+  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bdd_requirement, 3, 0, 0),
+  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_right_expression, 1, 0, 0),
+  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_right_expression, 1, 0, 0),
+  [253] = {.entry = {.count = 1, .reusable = false}}, SHIFT(97),
+  [255] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_noun_phrase, 1, 0, 0),
+  [257] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_call_argument, 1, 0, 0),
+  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arithmetic_operator, 1, 0, 0),
+  [263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_operator, 1, 0, 0),
+  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comparison_operator, 1, 0, 0),
+  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison_operator, 1, 0, 0),
+  [269] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0), SHIFT_REPEAT(97),
+  [272] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0),
+  [274] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0), SHIFT_REPEAT(117),
+  [277] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_noun_phrase_repeat1, 2, 0, 0), SHIFT_REPEAT(97),
+  [280] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [282] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [284] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [286] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_subject, 1, 0, 0),
+  [288] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_term, 3, 0, 0),
+  [290] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_noun_phrase_repeat1, 1, 0, 0),
+  [292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_noun_phrase_repeat1, 1, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_kind_marker, 1, 0, 0),
+  [296] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
+  [298] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(171),
+  [301] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(113),
+  [304] = {.entry = {.count = 1, .reusable = false}}, SHIFT(227),
+  [306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [308] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comparison, 3, 0, 0),
+  [310] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_comparison, 3, 0, 0),
+  [312] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arithmetic_term, 3, 0, 0),
+  [314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [316] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [320] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [322] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [324] = {.entry = {.count = 1, .reusable = false}}, SHIFT(171),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [330] = {.entry = {.count = 1, .reusable = false}}, SHIFT(181),
+  [332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional_value, 5, 0, 8),
+  [336] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional_value, 5, 0, 8),
+  [338] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
+  [340] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
+  [342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_divisibility, 7, 0, 0),
+  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uniqueness, 4, 0, 7),
+  [348] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uniqueness, 4, 0, 7),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logical_expression, 2, 0, 0),
+  [352] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_logical_expression, 2, 0, 0),
+  [354] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
+  [356] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range_comparison, 5, 0, 0),
+  [358] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_range_comparison, 5, 0, 0),
+  [360] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bit_test, 5, 0, 0),
+  [362] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bit_test, 5, 0, 0),
+  [364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_range_comparison, 6, 0, 0),
+  [366] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_range_comparison, 6, 0, 0),
+  [368] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_divisibility, 6, 0, 0),
+  [370] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_divisibility, 6, 0, 0),
+  [372] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_conditional_comparison, 3, 0, 0),
+  [374] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_conditional_comparison, 3, 0, 0),
+  [376] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_divisibility, 7, 0, 0),
+  [378] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logical_expression, 4, 0, 0),
+  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
+  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [384] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_logical_expression, 4, 0, 0),
+  [386] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_uniqueness, 3, 0, 3),
+  [388] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_uniqueness, 3, 0, 3),
+  [390] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logical_expression, 3, 0, 0),
+  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [396] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_logical_expression, 3, 0, 0),
+  [398] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_preposition, 1, 0, 0),
+  [400] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preposition, 1, 0, 0),
+  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [404] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [408] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_parenthesized_expression, 3, 0, 0),
+  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [424] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_given_clause_repeat1, 2, 0, 0), SHIFT_REPEAT(228),
+  [427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [429] = {.entry = {.count = 1, .reusable = false}}, SHIFT(195),
+  [431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(191),
+  [433] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [437] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_uniqueness_repeat1, 2, 0, 4), SHIFT_REPEAT(206),
+  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_uniqueness_repeat1, 2, 0, 4),
+  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_action, 3, 0, 0),
+  [444] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_action, 3, 0, 0),
+  [446] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preposition_phrase, 2, 0, 0),
+  [448] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_preposition_phrase, 2, 0, 0),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [452] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [454] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_repeat1, 1, 0, 0),
+  [458] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 1, 0, 0),
+  [460] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
+  [462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [464] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [466] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0), SHIFT_REPEAT(81),
+  [469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_call_repeat1, 2, 0, 0),
+  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [479] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_escape_sequence, 2, 0, 0),
+  [481] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_escape_sequence, 2, 0, 0),
+  [483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [489] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [491] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_uniqueness_repeat1, 2, 0, 2),
+  [493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 2, 0, 0),
+  [497] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_condition, 2, 0, 0),
+  [499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_given_clause, 4, 0, 0),
+  [501] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_given_clause, 3, 0, 0),
+  [509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [511] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [513] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [521] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [523] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [525] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [529] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [531] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [533] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [537] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arithmetic_expression, 3, 0, 0),
+  [539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [547] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_when_clause, 4, 0, 0),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [555] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
+  [557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [559] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_and_clause, 3, 0, 0),
+  [561] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_constraint, 2, 0, 0),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [565] = {.entry = {.count = 1, .reusable = false}}, SHIFT(252),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [573] = {.entry = {.count = 1, .reusable = false}}, SHIFT(217),
+  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [581] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [583] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [585] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
+  [595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [601] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_given_clause_repeat1, 1, 0, 0),
+  [603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 2, 0, 0),
 };
 
 #ifdef __cplusplus
@@ -4129,7 +9882,7 @@ extern "C" {
 
 TS_PUBLIC const TSLanguage *tree_sitter_requirements(void) {
   static const TSLanguage language = {
-    .abi_version = LANGUAGE_VERSION,
+    .version = LANGUAGE_VERSION,
     .symbol_count = SYMBOL_COUNT,
     .alias_count = ALIAS_COUNT,
     .token_count = TOKEN_COUNT,
@@ -4144,11 +9897,14 @@ TS_PUBLIC const TSLanguage *tree_sitter_requirements(void) {
     .small_parse_table_map = ts_small_parse_table_map,
     .parse_actions = ts_parse_actions,
     .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
     .symbol_metadata = ts_symbol_metadata,
     .public_symbol_map = ts_symbol_map,
     .alias_map = ts_non_terminal_alias_map,
     .alias_sequences = &ts_alias_sequences[0][0],
-    .lex_modes = (const void*)ts_lex_modes,
+    .lex_modes = ts_lex_modes,
     .lex_fn = ts_lex,
     .keyword_lex_fn = ts_lex_keywords,
     .keyword_capture_token = sym_identifier,
@@ -0,0 +1,197 @@
+//! State machine extraction from action-oriented requirements
+//!
+//! Requirements are grouped by subject. Within a group the lifecycle comes
+//! from one of two sources:
+//!
+//! - an explicit state variable (`state` or `status`): `state == "x"` in the
+//!   condition names the source state, `state' == "y"` in the postcondition
+//!   the target (see `Requirement::transition`);
+//! - otherwise the action verbs: `register` leads to `registered`, `login`
+//!   to `logged_in`, and so on. Actions without a lifecycle meaning (e.g.
+//!   `withdraw`) loop on every live state.
+//!
+//! The rest of each condition becomes the transition guard.
+
+use crate::{ActionType, IntentAst, LogicalOperator, ParsedConstraint, Requirement};
+use crucible_core::{CompoundConstraint, StateMachine};
+
+/// Variable names treated as the explicit lifecycle state
+const STATE_VARIABLES: &[&str] = &["state", "status"];
+
+const INITIAL: &str = "initial";
+
+/// `(source states, target state)` implied by a lifecycle verb
+fn lifecycle(verb: &ActionType) -> Option<(&'static [&'static str], &'static str)> {
+    match verb {
+        ActionType::Register => Some((&[INITIAL], "registered")),
+        ActionType::Login => Some((&["registered", "logged_out"], "logged_in")),
+        ActionType::Logout => Some((&["logged_in"], "logged_out")),
+        ActionType::Authenticate => Some((&[INITIAL], "authenticated")),
+        ActionType::Create => Some((&[INITIAL], "created")),
+        ActionType::Delete => Some((&["created"], "deleted")),
+        _ => None,
+    }
+}
+
+/// One state machine per subject, in order of first appearance
+pub fn extract_state_machines(ast: &IntentAst) -> Vec<StateMachine> {
+    let mut subjects: Vec<&str> = Vec::new();
+    for requirement in &ast.requirements {
+        if !subjects.contains(&requirement.subject.as_str()) {
+            subjects.push(&requirement.subject);
+        }
+    }
+
+    subjects
+        .into_iter()
+        .map(|subject| {
+            let requirements: Vec<&Requirement> =
+                ast.requirements.iter().filter(|r| r.subject == subject).collect();
+            if requirements.iter().any(|r| explicit_states(r).is_some()) {
+                explicit_machine(subject, &requirements)
+            } else {
+                lifecycle_machine(subject, &requirements)
+            }
+        })
+        .collect()
+}
+
+/// Machine driven by `state == "x"` guards and `state' == "y"` updates
+fn explicit_machine(subject: &str, requirements: &[&Requirement]) -> StateMachine {
+    // Requirements that say nothing about the state are available everywhere
+    let edges: Vec<(Vec<String>, Option<String>, &Requirement)> = requirements
+        .iter()
+        .map(|r| {
+            let (from, to) = explicit_states(r).unwrap_or_default();
+            (from, to, *r)
+        })
+        .collect();
+
+    let initial = edges
+        .iter()
+        .flat_map(|(from, _, _)| from.iter())
+        .find(|state| !edges.iter().any(|(_, to, _)| to.as_deref() == Some(state.as_str())))
+        .or_else(|| edges.iter().flat_map(|(from, _, _)| from.iter()).next())
+        .cloned()
+        .unwrap_or_else(|| INITIAL.to_string());
+
+    let mut machine = StateMachine::new(subject, &initial);
+    let mut all_states = vec![initial.clone()];
+    for state in edges.iter().flat_map(|(from, to, _)| from.iter().chain(to)) {
+        if !all_states.contains(state) {
+            all_states.push(state.clone());
+        }
+    }
+
+    for (from, to, requirement) in &edges {
+        let sources = if from.is_empty() { &all_states } else { from };
+        for source in sources {
+            machine.add_transition(
+                source,
+                to.as_deref().unwrap_or(source),
+                &requirement.action.verb.to_string(),
+                data_guard(requirement),
+            );
+        }
+    }
+    machine
+}
+
+/// Machine driven by lifecycle verbs
+fn lifecycle_machine(subject: &str, requirements: &[&Requirement]) -> StateMachine {
+    let mut machine = StateMachine::new(subject, INITIAL);
+
+    for requirement in requirements {
+        if let Some((sources, target)) = lifecycle(&requirement.action.verb) {
+            for source in sources {
+                machine.add_transition(source, target, &requirement.action.verb.to_string(), data_guard(requirement));
+            }
+        }
+    }
+    if machine.states.iter().any(|s| s == "deleted") {
+        machine.final_states.push("deleted".to_string());
+    }
+
+    // Everything else is available in every live state
+    let live: Vec<String> = machine.states.iter().filter(|s| *s != "deleted").cloned().collect();
+    for requirement in requirements.iter().filter(|r| lifecycle(&r.action.verb).is_none()) {
+        for state in &live {
+            machine.add_transition(state, state, &requirement.action.verb.to_string(), data_guard(requirement));
+        }
+    }
+    machine
+}
+
+/// Source states (`state == "x"`, possibly or-ed) and target state (`state' == "y"`)
+fn explicit_states(requirement: &Requirement) -> Option<(Vec<String>, Option<String>)> {
+    let mut from = Vec::new();
+    if let Some(condition) = &requirement.condition {
+        collect_state_values(condition, false, &mut from);
+    }
+    let mut to = Vec::new();
+    for post in [&requirement.postcondition, &requirement.constraint].into_iter().flatten() {
+        collect_state_values(post, true, &mut to);
+    }
+
+    if from.is_empty() && to.is_empty() {
+        None
+    } else {
+        Some((from, to.into_iter().next()))
+    }
+}
+
+fn collect_state_values(constraint: &ParsedConstraint, primed: bool, out: &mut Vec<String>) {
+    match constraint {
+        ParsedConstraint::Atomic(c) => {
+            let (name, is_primed) = match c.left_variable.strip_suffix('\'') {
+                Some(name) => (name, true),
+                None => (c.left_variable.as_str(), false),
+            };
+            if is_primed == primed && STATE_VARIABLES.contains(&name) {
+                out.push(c.right_value.trim_matches('"').to_string());
+            }
+        }
+        ParsedConstraint::Reference(_) | ParsedConstraint::Distinct(_) => {}
+        ParsedConstraint::Compound { operator, left, right } => {
+            if *operator == LogicalOperator::Not {
+                return;
+            }
+            collect_state_values(left, primed, out);
+            if let Some(right) = right {
+                collect_state_values(right, primed, out);
+            }
+        }
+    }
+}
+
+/// The condition without its state tests
+fn data_guard(requirement: &Requirement) -> Option<CompoundConstraint> {
+    requirement.condition.as_ref().and_then(strip_state_tests).and_then(|c| c.to_compound())
+}
+
+fn strip_state_tests(constraint: &ParsedConstraint) -> Option<ParsedConstraint> {
+    match constraint {
+        ParsedConstraint::Atomic(c) if STATE_VARIABLES.contains(&c.left_variable.as_str()) => None,
+        ParsedConstraint::Atomic(_) | ParsedConstraint::Reference(_) | ParsedConstraint::Distinct(_) => {
+            Some(constraint.clone())
+        }
+        ParsedConstraint::Compound { operator, left, right } => {
+            let left = strip_state_tests(left);
+            let right = right.as_deref().and_then(strip_state_tests);
+            match (left, right) {
+                (Some(left), Some(right)) => Some(ParsedConstraint::Compound {
+                    operator: operator.clone(),
+                    left: Box::new(left),
+                    right: Some(Box::new(right)),
+                }),
+                (Some(only), None) | (None, Some(only)) if *operator != LogicalOperator::Not => Some(only),
+                (Some(inner), None) => Some(ParsedConstraint::Compound {
+                    operator: operator.clone(),
+                    left: Box::new(inner),
+                    right: None,
+                }),
+                _ => None,
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Crucible Provers - External Proof Harness Integration
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! Closes the loop from intent to proof: the code generator writes SPARK
+//! packages, Rust crates with Kani harnesses or C sources, and this crate
+//! runs the matching prover on them (`gnatprove`, `cargo kani`, `cbmc`),
+//! parses the per-obligation results and attaches a `ProofStatus` to every
+//! requirement of the Intent-AST. None of the provers is a build dependency;
+//! a missing binary surfaces as `ProverError::NotInstalled`.
+
+mod parse;
+
+use crucible_core::{IntentAst, ProofStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur while running a prover
+#[derive(Debug, Error)]
+pub enum ProverError {
+    #[error("`{0}` is not installed or not on PATH")]
+    NotInstalled(String),
+
+    #[error("Failed to run prover: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Prover output could not be interpreted: {0}")]
+    UnrecognizedOutput(String),
+}
+
+/// Supported external provers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Prover {
+    /// SPARK/Ada (`gnatprove -P <project>.gpr`)
+    GnatProve,
+    /// Rust model checker (`cargo kani`)
+    Kani,
+    /// C bounded model checker (`cbmc <file>.c`)
+    Cbmc,
+}
+
+impl Prover {
+    /// Executable looked up on PATH
+    pub fn binary(&self) -> &'static str {
+        match self {
+            Prover::GnatProve => "gnatprove",
+            Prover::Kani => "cargo",
+            Prover::Cbmc => "cbmc",
+        }
+    }
+
+    /// Arguments for proving `target` (project file, harness name or C file)
+    fn arguments(&self, target: &str) -> Vec<String> {
+        let mut args: Vec<String> = match self {
+            Prover::GnatProve => vec!["-P", target, "--level=4", "--report=all"],
+            Prover::Kani => vec!["kani"],
+            Prover::Cbmc => vec![target, "--bounds-check", "--pointer-check", "--signed-overflow-check"],
+        }
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        if *self == Prover::Kani && !target.is_empty() {
+            args.extend(["--harness".to_string(), target.to_string()]);
+        }
+        args
+    }
+}
+
+/// One proof obligation reported by a prover
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Obligation {
+    /// Check name (`Can_Withdraw'Result`, `check_validate_intent`, `main.assertion.1`)
+    pub name: String,
+    pub status: ProofStatus,
+    /// Prover message (e.g. "postcondition might fail")
+    pub message: String,
+    /// `file:line` where the prover reports it, if any
+    pub location: Option<String>,
+}
+
+/// Result of one prover invocation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProverRun {
+    pub prover: Prover,
+    pub obligations: Vec<Obligation>,
+    pub exit_code: Option<i32>,
+    /// Combined stdout and stderr, kept for the report
+    pub output: String,
+}
+
+impl ProverRun {
+    /// Parse captured prover output
+    pub fn from_output(prover: Prover, output: &str, exit_code: Option<i32>) -> Self {
+        let obligations = match prover {
+            Prover::GnatProve => parse::gnatprove(output),
+            Prover::Kani => parse::kani(output),
+            Prover::Cbmc => parse::cbmc(output),
+        };
+        Self {
+            prover,
+            obligations,
+            exit_code,
+            output: output.to_string(),
+        }
+    }
+
+    /// Proved only if every obligation is proved and the prover exited cleanly
+    pub fn status(&self) -> ProofStatus {
+        combine(self.obligations.iter().map(|o| o.status)).map_or(ProofStatus::Inconclusive, |status| {
+            if status == ProofStatus::Proved && self.exit_code != Some(0) {
+                ProofStatus::Inconclusive
+            } else {
+                status
+            }
+        })
+    }
+}
+
+/// Failed dominates Inconclusive, which dominates Proved; `None` for no statuses
+fn combine(statuses: impl Iterator<Item = ProofStatus>) -> Option<ProofStatus> {
+    statuses.fold(None, |acc, status| match (acc, status) {
+        (Some(ProofStatus::Failed), _) | (_, ProofStatus::Failed) => Some(ProofStatus::Failed),
+        (Some(ProofStatus::Inconclusive), _) | (_, ProofStatus::Inconclusive) => Some(ProofStatus::Inconclusive),
+        _ => Some(ProofStatus::Proved),
+    })
+}
+
+/// Invokes a prover on a directory of generated artifacts
+#[derive(Debug, Clone)]
+pub struct ProverRunner {
+    prover: Prover,
+    binary: Option<PathBuf>,
+    extra_args: Vec<String>,
+}
+
+impl ProverRunner {
+    pub fn new(prover: Prover) -> Self {
+        Self {
+            prover,
+            binary: None,
+            extra_args: Vec::new(),
+        }
+    }
+
+    /// Use this executable instead of looking the prover up on PATH
+    pub fn binary(mut self, path: impl Into<PathBuf>) -> Self {
+        self.binary = Some(path.into());
+        self
+    }
+
+    /// Extra command-line argument (e.g. `--timeout=60`)
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.extra_args.push(arg.into());
+        self
+    }
+
+    /// Run the prover in `dir` on `target`: the `.gpr` file for GNATprove,
+    /// a harness name (or `""` for all) for Kani, the `.c` file for CBMC
+    pub fn run(&self, dir: &Path, target: &str) -> Result<ProverRun, ProverError> {
+        let binary = self
+            .binary
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(self.prover.binary()));
+
+        let output = Command::new(&binary)
+            .args(self.prover.arguments(target))
+            .args(&self.extra_args)
+            .current_dir(dir)
+            .output()
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::NotFound => ProverError::NotInstalled(binary.display().to_string()),
+                _ => ProverError::Io(e),
+            })?;
+
+        let text = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let run = ProverRun::from_output(self.prover, &text, output.status.code());
+        if run.obligations.is_empty() && !output.status.success() {
+            return Err(ProverError::UnrecognizedOutput(text.lines().last().unwrap_or_default().to_string()));
+        }
+        Ok(run)
+    }
+}
+
+/// Prover runs per requirement: the "intent -> code -> proof" report
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProofReport {
+    runs: BTreeMap<Uuid, Vec<ProverRun>>,
+}
+
+impl ProofReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a run on the code generated for `requirement_id`
+    pub fn record(&mut self, requirement_id: Uuid, run: ProverRun) {
+        self.runs.entry(requirement_id).or_default().push(run);
+    }
+
+    /// Combined verdict of every run recorded for a requirement
+    pub fn status(&self, requirement_id: &Uuid) -> Option<ProofStatus> {
+        combine(self.runs.get(requirement_id)?.iter().map(ProverRun::status))
+    }
+
+    /// Set `proof` on every requirement that has runs
+    pub fn attach(&self, ast: &mut IntentAst) {
+        for requirement in &mut ast.requirements {
+            if let Some(status) = self.status(&requirement.id) {
+                requirement.proof = Some(status);
+            }
+        }
+    }
+
+    /// Markdown table: requirement text, prover, obligations proved, verdict
+    pub fn to_markdown(&self, ast: &IntentAst) -> String {
+        let mut out = String::from("| Requirement | Prover | Obligations | Status |\n|---|---|---|---|\n");
+        for requirement in &ast.requirements {
+            let Some(runs) = self.runs.get(&requirement.id) else {
+                out.push_str(&format!("| {} | - | - | not run |\n", requirement.content));
+                continue;
+            };
+            for run in runs {
+                let proved = run.obligations.iter().filter(|o| o.status == ProofStatus::Proved).count();
+                out.push_str(&format!(
+                    "| {} | {:?} | {}/{} | {:?} |\n",
+                    requirement.content,
+                    run.prover,
+                    proved,
+                    run.obligations.len(),
+                    run.status()
+                ));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_attaches_worst_status() {
+        let mut ast = IntentAst::new();
+        ast.add_requirement("User can withdraw money if balance >= amount".to_string());
+        ast.add_requirement("Admin can export reports".to_string());
+        let id = ast.requirements[0].id;
+
+        let proved = "payment_rules.adb:8:14: info: postcondition proved\n";
+        let failed = "payment_rules.ads:12:17: medium: postcondition might fail\n";
+        let mut report = ProofReport::new();
+        report.record(id, ProverRun::from_output(Prover::GnatProve, proved, Some(0)));
+        assert_eq!(report.status(&id), Some(ProofStatus::Proved));
+        report.record(id, ProverRun::from_output(Prover::GnatProve, failed, Some(1)));
+
+        report.attach(&mut ast);
+        assert_eq!(ast.requirements[0].proof, Some(ProofStatus::Failed));
+        assert_eq!(ast.requirements[1].proof, None);
+
+        let markdown = report.to_markdown(&ast);
+        assert!(markdown.contains("| GnatProve | 1/1 | Proved |"));
+        assert!(markdown.contains("| Admin can export reports | - | - | not run |"));
+    }
+
+    #[test]
+    fn test_missing_binary_is_not_installed() {
+        let runner = ProverRunner::new(Prover::Cbmc).binary("/nonexistent/cbmc");
+        let result = runner.run(Path::new("."), "validator.c");
+        assert!(matches!(result, Err(ProverError::NotInstalled(_))));
+    }
+}
@@ -0,0 +1,165 @@
+//! Output parsers, one per prover
+//!
+//! Each parser is line based and ignores everything it does not recognize,
+//! so banner text and progress lines need no special handling.
+
+use super::Obligation;
+use crucible_core::ProofStatus;
+
+/// `file.adb:12:7: info: postcondition proved` / `file.ads:5:9: medium: overflow check might fail`
+pub(crate) fn gnatprove(output: &str) -> Vec<Obligation> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(5, ':');
+            let (file, row, _column) = (parts.next()?, parts.next()?, parts.next()?);
+            let severity = parts.next()?.trim();
+            let message = parts.next()?.trim();
+            row.parse::<u32>().ok()?;
+
+            let status = match severity {
+                "info" if message.contains("proved") => ProofStatus::Proved,
+                "info" => return None,
+                "low" | "medium" | "high" | "error" => ProofStatus::Failed,
+                "warning" => ProofStatus::Inconclusive,
+                _ => return None,
+            };
+            // "postcondition proved (CVC5: 1 VC)" -> "postcondition"
+            let name = message
+                .split(" proved")
+                .next()
+                .unwrap_or(message)
+                .split(" might fail")
+                .next()
+                .unwrap_or(message)
+                .to_string();
+
+            Some(Obligation {
+                name,
+                status,
+                message: message.to_string(),
+                location: Some(format!("{}:{}", file.trim(), row)),
+            })
+        })
+        .collect()
+}
+
+/// ```text
+/// Checking harness verification::check_validate_intent...
+/// Check 1: verification::check_validate_intent.assertion.1
+///          - Status: SUCCESS
+///          - Description: "assertion failed: result == expected"
+///          - Location: src/lib.rs:42:9 in function check_validate_intent
+/// ```
+pub(crate) fn kani(output: &str) -> Vec<Obligation> {
+    let mut obligations: Vec<Obligation> = Vec::new();
+    for line in output.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("Check ") {
+            if let Some((_, name)) = rest.split_once(": ") {
+                obligations.push(Obligation {
+                    name: name.to_string(),
+                    status: ProofStatus::Inconclusive,
+                    message: String::new(),
+                    location: None,
+                });
+            }
+            continue;
+        }
+
+        let Some(current) = obligations.last_mut() else {
+            continue;
+        };
+        if let Some(status) = line.strip_prefix("- Status: ") {
+            current.status = match status {
+                "SUCCESS" | "UNREACHABLE" => ProofStatus::Proved,
+                "FAILURE" => ProofStatus::Failed,
+                _ => ProofStatus::Inconclusive,
+            };
+        } else if let Some(description) = line.strip_prefix("- Description: ") {
+            current.message = description.trim_matches('"').to_string();
+        } else if let Some(location) = line.strip_prefix("- Location: ") {
+            current.location = location.split_whitespace().next().map(|l| {
+                // Drop the column: src/lib.rs:42:9 -> src/lib.rs:42
+                l.rsplit_once(':').map_or(l, |(head, _)| head).to_string()
+            });
+        }
+    }
+    obligations
+}
+
+/// `[main.assertion.1] line 12 assertion params.amount > 0: SUCCESS`
+pub(crate) fn cbmc(output: &str) -> Vec<Obligation> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix('[')?;
+            let (name, rest) = rest.split_once("] ")?;
+            let (description, status) = rest.rsplit_once(": ")?;
+
+            let status = match status.trim() {
+                "SUCCESS" => ProofStatus::Proved,
+                "FAILURE" => ProofStatus::Failed,
+                _ => ProofStatus::Inconclusive,
+            };
+            let (location, message) = match description.strip_prefix("line ") {
+                Some(rest) => {
+                    let (row, message) = rest.split_once(' ').unwrap_or((rest, ""));
+                    (Some(format!("line {}", row)), message)
+                }
+                None => (None, description),
+            };
+
+            Some(Obligation {
+                name: name.to_string(),
+                status,
+                message: message.to_string(),
+                location,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kani_and_cbmc() {
+        let kani_output = "Checking harness verification::check_validate_intent...\n\
+                           Check 1: verification::check_validate_intent.assertion.1\n\
+                           \t - Status: FAILURE\n\
+                           \t - Description: \"assertion failed: result == expected\"\n\
+                           \t - Location: src/lib.rs:42:9 in function check_validate_intent\n\
+                           Check 2: verification::check_validate_intent.overflow.1\n\
+                           \t - Status: SUCCESS\n\
+                           VERIFICATION:- FAILED\n";
+        let obligations = kani(kani_output);
+        assert_eq!(obligations.len(), 2);
+        assert_eq!(obligations[0].status, ProofStatus::Failed);
+        assert_eq!(obligations[0].message, "assertion failed: result == expected");
+        assert_eq!(obligations[0].location.as_deref(), Some("src/lib.rs:42"));
+        assert_eq!(obligations[1].status, ProofStatus::Proved);
+
+        let cbmc_output = "** Results:\n\
+                           [validate_intent.assertion.1] line 12 assertion params.amount > 0: SUCCESS\n\
+                           [validate_intent.overflow.1] line 14 arithmetic overflow on +: FAILURE\n";
+        let obligations = cbmc(cbmc_output);
+        assert_eq!(obligations[0].name, "validate_intent.assertion.1");
+        assert_eq!(obligations[0].message, "assertion params.amount > 0");
+        assert_eq!(obligations[1].status, ProofStatus::Failed);
+        assert_eq!(obligations[1].location.as_deref(), Some("line 14"));
+    }
+
+    #[test]
+    fn test_parse_gnatprove() {
+        let output = "Phase 1 of 2: generation of Global contracts ...\n\
+                      payment_rules.adb:8:14: info: postcondition proved (CVC5: 1 VC)\n\
+                      payment_rules.ads:12:17: medium: overflow check might fail\n";
+        let obligations = gnatprove(output);
+        assert_eq!(obligations.len(), 2);
+        assert_eq!(obligations[0].name, "postcondition");
+        assert_eq!(obligations[0].status, ProofStatus::Proved);
+        assert_eq!(obligations[1].name, "overflow check");
+        assert_eq!(obligations[1].location.as_deref(), Some("payment_rules.ads:12"));
+    }
+}
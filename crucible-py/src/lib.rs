@@ -0,0 +1,324 @@
+//! Crucible Python - bindings for notebooks and scripts
+//! "Correct by Design, Not by Debugging"
+//!
+//! Licensed under the Crucible Engine License v2.0
+//! See LICENSE file for full terms
+//!
+//! Provisional Patent Application: 63/928,407
+//!
+//! The `crucible` Python module: parse a spec, verify it and generate
+//! validators from it without leaving Python. Results are plain lists of
+//! dicts, one per requirement, so they drop straight into pandas:
+//!
+//! ```text
+//! import crucible, pandas as pd
+//!
+//! schema = crucible.Schema.from_records(pd.read_csv("fields.csv").to_dict("records"))
+//! pd.DataFrame(crucible.parse(spec))              # line, subject, constraint, tree, ...
+//! pd.DataFrame(crucible.verify(spec, schema))     # line, verdict, detail, model
+//! files = crucible.generate(spec, "python", schema)
+//! ```
+//!
+//! A spec the pipeline rejects raises `crucible.CrucibleError`, whose
+//! `stage`, `code`, `line` and `column` attributes say where and why.
+//! `verify` needs the `verification` feature, which the wheel is built with.
+
+use crucible_codegen::{CodeGenerator, CodegenOptions, TargetLanguage};
+use crucible_core::{CompoundConstraint, CrucibleConfig, DataType, Schema};
+use crucible_parser::Requirement;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+create_exception!(
+    crucible,
+    CrucibleError,
+    PyException,
+    "A pipeline stage rejected the input; `stage` and `code` say which"
+);
+
+/// `error` as a `crucible.CrucibleError` carrying its stage, code and span
+fn raise(error: crucible_core::CrucibleError) -> PyErr {
+    let stage = match &error {
+        crucible_core::CrucibleError::Parse(_) => "parse",
+        crucible_core::CrucibleError::Schema(_) => "schema",
+        crucible_core::CrucibleError::Verification(_) => "verification",
+        crucible_core::CrucibleError::Codegen(_) => "codegen",
+    };
+    Python::with_gil(|py| {
+        let exception = CrucibleError::new_err(error.to_string());
+        let value = exception.value(py);
+        let span = error.span();
+        // Setting attributes on a fresh exception instance does not fail
+        let _ = value.setattr("stage", stage);
+        let _ = value.setattr("code", error.code());
+        let _ = value.setattr("line", span.map(|s| s.line));
+        let _ = value.setattr("column", span.map(|s| s.column));
+        exception
+    })
+}
+
+/// A serializable value as the Python objects `json.loads` makes of it
+fn to_python<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<PyObject> {
+    let json = serde_json::to_string(value).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(py.import("json")?.call_method1("loads", (json,))?.unbind())
+}
+
+/// The reverse of `to_python`
+fn from_python<T: DeserializeOwned>(value: &Bound<'_, PyAny>) -> PyResult<T> {
+    let json: String = value.py().import("json")?.call_method1("dumps", (value,))?.extract()?;
+    serde_json::from_str(&json).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Each requirement of a spec with its constraint tree, definitions inlined
+fn requirements(text: &str) -> PyResult<Vec<(Requirement, Option<CompoundConstraint>)>> {
+    let ast = crucible_parser::parse(text).map_err(|e| raise(e.into()))?;
+    let definitions = ast.definition_table().map_err(|e| raise(e.into()))?;
+    ast.requirements
+        .into_iter()
+        .map(|requirement| {
+            let tree = match requirement.compound() {
+                Some(compound) => Some(definitions.inline(&compound).map_err(|e| raise(e.into()))?),
+                None => None,
+            };
+            Ok((requirement, tree))
+        })
+        .collect()
+}
+
+/// Field names, types and documentation for type-aware verification and
+/// code generation. Types are written as in the Schema JSON: `"Uint64"`,
+/// `"Decimal"`, `{"Custom": {"name": ..., "range_min": ..., "range_max": ...}}`
+#[pyclass(name = "Schema", module = "crucible")]
+#[derive(Clone)]
+struct PySchema {
+    inner: Schema,
+}
+
+#[pymethods]
+impl PySchema {
+    #[new]
+    #[pyo3(signature = (traceability_id = String::new()))]
+    fn new(traceability_id: String) -> Self {
+        Self {
+            inner: Schema::new(traceability_id),
+        }
+    }
+
+    #[pyo3(signature = (name, data_type, doc = None))]
+    fn add_field(&mut self, name: String, data_type: &Bound<'_, PyAny>, doc: Option<String>) -> PyResult<()> {
+        self.inner.add_field(name, from_python::<DataType>(data_type)?, doc);
+        Ok(())
+    }
+
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        let inner = serde_json::from_str(json).map_err(|e| raise(e.into()))?;
+        Ok(Self { inner })
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string_pretty(&self.inner).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    /// A schema from dicts with `name`, `type` and optionally `doc`, such as
+    /// `DataFrame.to_dict("records")`
+    #[staticmethod]
+    #[pyo3(signature = (records, traceability_id = String::new()))]
+    fn from_records(records: &Bound<'_, PyAny>, traceability_id: String) -> PyResult<Self> {
+        let mut schema = Self::new(traceability_id);
+        for record in records.try_iter()? {
+            let record = record?;
+            let name: String = record.get_item("name")?.extract()?;
+            // pandas fills an empty `doc` cell with NaN
+            let doc = record.get_item("doc").ok().and_then(|doc| doc.extract::<String>().ok());
+            schema.add_field(name, &record.get_item("type")?, doc)?;
+        }
+        Ok(schema)
+    }
+
+    /// One dict per field, by name, with `name`, `type` and `doc`
+    fn to_records(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let fields: BTreeMap<&String, &DataType> = self.inner.fields.iter().collect();
+        let records: Vec<serde_json::Value> = fields
+            .into_iter()
+            .map(|(name, data_type)| {
+                json!({"name": name, "type": data_type, "doc": self.inner.documentation.get(name)})
+            })
+            .collect();
+        to_python(py, &records)
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.fields.len()
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.inner.fields.contains_key(name)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Schema({} fields)", self.inner.fields.len())
+    }
+}
+
+/// The requirements of a spec, one dict each: `line`, `subject`,
+/// `modal_verb`, `action`, `object`, `severity`, `kind`, `constraint` (as
+/// text) and `tree` (as nested dicts), the last two `None` for requirements
+/// without constraints
+#[pyfunction]
+fn parse(py: Python<'_>, text: &str) -> PyResult<PyObject> {
+    let records: Vec<serde_json::Value> = requirements(text)?
+        .into_iter()
+        .map(|(requirement, tree)| {
+            json!({
+                "line": requirement.line,
+                "subject": requirement.subject,
+                "modal_verb": requirement.modal_verb,
+                "action": requirement.action.verb.to_string(),
+                "object": requirement.action.object,
+                "severity": requirement.severity,
+                "kind": requirement.kind,
+                "constraint": tree.as_ref().map(ToString::to_string),
+                "tree": tree,
+            })
+        })
+        .collect();
+    to_python(py, &records)
+}
+
+/// Check every requirement of a spec with Z3, against `schema` if given:
+/// one dict per requirement with `line`, `subject`, `verdict` (`sat`,
+/// `unsat`, `invalid` when it does not fit the schema, `unknown`, or
+/// `none` without constraints), `detail` and, when satisfiable, an example
+/// `model`
+#[cfg(feature = "verification")]
+#[pyfunction]
+#[pyo3(signature = (text, schema = None))]
+fn verify(py: Python<'_>, text: &str, schema: Option<PySchema>) -> PyResult<PyObject> {
+    use crucible_verification::{VerificationError, Z3Verifier};
+
+    let requirements = requirements(text)?;
+    // Z3 can take a while; other Python threads keep running meanwhile
+    let records: Vec<serde_json::Value> = py.allow_threads(|| {
+        let verifier = Z3Verifier::new();
+        requirements
+            .iter()
+            .map(|(requirement, tree)| {
+                let result = tree.as_ref().map(|tree| match &schema {
+                    Some(schema) => verifier.verify_with_schema(tree, &schema.inner),
+                    None => verifier.verify_compound_constraints(tree),
+                });
+                let (verdict, detail, model) = match result {
+                    None => ("none", None, None),
+                    Some(Ok(output)) => ("sat", None, output.model),
+                    Some(Err(VerificationError::Unsatisfiable(reason))) => ("unsat", Some(reason), None),
+                    Some(Err(e @ VerificationError::InvalidConstraint(_))) => ("invalid", Some(e.to_string()), None),
+                    Some(Err(e)) => ("unknown", Some(e.to_string()), None),
+                };
+                json!({
+                    "line": requirement.line,
+                    "subject": requirement.subject,
+                    "verdict": verdict,
+                    "detail": detail,
+                    "model": model,
+                })
+            })
+            .collect()
+    });
+    to_python(py, &records)
+}
+
+/// Validators for every requirement of a spec in `language` (`rust`, `ts`,
+/// `python`, `sol`, ...), typed by `schema` if given: a dict of file name to
+/// contents, named like `crucible watch` names them
+#[pyfunction]
+#[pyo3(signature = (text, language, schema = None))]
+fn generate(text: &str, language: &str, schema: Option<PySchema>) -> PyResult<BTreeMap<String, String>> {
+    let language = TargetLanguage::from_name(language)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown language {}", language)))?;
+    let config = CrucibleConfig::default();
+    let mut files = BTreeMap::new();
+    for tree in requirements(text)?.into_iter().filter_map(|(_, tree)| tree) {
+        let hash = tree.canonical_hash();
+        let options = CodegenOptions {
+            func_name: format!("{}{}", config.naming.file_prefix, hash),
+            ..CodegenOptions::from_config(&config)
+        };
+        let output = match &schema {
+            Some(schema) => CodeGenerator.generate_with_schema_and_options(&tree, &schema.inner, language, &options),
+            None => CodeGenerator.generate_with_options(&tree, language, &options),
+        }
+        .map_err(|e| raise(e.into()))?;
+        files.insert(format!("{}.{}", options.func_name, language.file_extension()), output.code);
+        files.extend(output.files.into_iter().map(|file| (file.path, file.contents)));
+    }
+    Ok(files)
+}
+
+#[pymodule]
+#[pyo3(name = "crucible")]
+fn crucible_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add("CrucibleError", m.py().get_type::<CrucibleError>())?;
+    m.add_class::<PySchema>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    #[cfg(feature = "verification")]
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    m.add_function(wrap_pyfunction!(generate, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::{PyDict, PyList};
+
+    #[test]
+    fn test_python_module() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let records = PyList::empty(py);
+            let balance = PyDict::new(py);
+            balance.set_item("name", "balance").unwrap();
+            balance.set_item("type", "Uint64").unwrap();
+            balance.set_item("doc", f64::NAN).unwrap();
+            records.append(balance).unwrap();
+            let mut schema = PySchema::from_records(&records, "TR-1".to_string()).unwrap();
+            assert!(schema.__contains__("balance"));
+            let generated = generate("User can withdraw money if balance >= amount\n", "rust", Some(schema.clone()));
+            let error = generated.unwrap_err();
+            assert_eq!(error.value(py).getattr("code").unwrap().extract::<String>().unwrap(), "S001");
+
+            schema.add_field("amount".to_string(), "Uint64".into_pyobject(py).unwrap().as_any(), None).unwrap();
+            assert_eq!(schema.__len__(), 2);
+            assert_eq!(
+                schema.to_records(py).unwrap().bind(py).repr().unwrap().to_string(),
+                concat!(
+                    "[{'doc': None, 'name': 'amount', 'type': 'Uint64'}, ",
+                    "{'doc': None, 'name': 'balance', 'type': 'Uint64'}]"
+                )
+            );
+            assert!(PySchema::from_json("{").is_err());
+
+            let spec = "User can withdraw money if balance >= amount\n";
+            let parsed = parse(py, spec).unwrap();
+            let requirement = parsed.bind(py).get_item(0).unwrap();
+            assert_eq!(requirement.get_item("line").unwrap().extract::<usize>().unwrap(), 1);
+            assert_eq!(requirement.get_item("constraint").unwrap().extract::<String>().unwrap(), "balance >= amount");
+
+            let files = generate(spec, "rust", Some(schema)).unwrap();
+            assert_eq!(files.len(), 1);
+            assert!(files.keys().all(|name| name.starts_with("requirement_") && name.ends_with(".rs")));
+            assert!(generate(spec, "cobol", None).is_err());
+
+            let error = parse(py, "The user may if if.\n").unwrap_err();
+            assert!(error.is_instance_of::<CrucibleError>(py));
+            assert_eq!(error.value(py).getattr("stage").unwrap().extract::<String>().unwrap(), "parse");
+        });
+    }
+}
@@ -0,0 +1,100 @@
+//! Solver and SMT-LIB benchmarks
+//!
+//! `solve/<shape>/<n>` times `verify_compound_constraints` on satisfiable
+//! trees of `n` simple constraints, so a regression can be pinned on the
+//! translation of a particular tree shape; `smt_lib/<n>` measures how fast
+//! constraints are rendered as SMT-LIB. Baselines are in `docs/BENCHMARKS.md`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use crucible_core::{CompoundConstraint, Constraint, ConstraintOperator};
+use crucible_verification::Z3Verifier;
+use std::hint::black_box;
+
+const SIZES: [usize; 4] = [8, 32, 128, 512];
+
+fn constraint(left: String, operator: ConstraintOperator, right: String) -> Constraint {
+    Constraint {
+        left_variable: left,
+        operator,
+        right_value: right,
+    }
+}
+
+/// `x_i >= i` and `x_i <= i + 100`, alternating, over `n / 2` variables
+fn bounds(n: usize) -> Vec<Constraint> {
+    (0..n)
+        .map(|i| {
+            let variable = format!("x_{}", i / 2);
+            if i % 2 == 0 {
+                constraint(variable, ConstraintOperator::GreaterThanOrEqual, (i / 2).to_string())
+            } else {
+                constraint(variable, ConstraintOperator::LessThanOrEqual, (i / 2 + 100).to_string())
+            }
+        })
+        .collect()
+}
+
+/// `x_0 < x_1 < ... < x_n`: every constraint shares a variable with the next
+fn chain(n: usize) -> Vec<Constraint> {
+    (0..n)
+        .map(|i| constraint(format!("x_{}", i), ConstraintOperator::LessThan, format!("x_{}", i + 1)))
+        .collect()
+}
+
+fn simple(constraints: Vec<Constraint>) -> Vec<CompoundConstraint> {
+    constraints.into_iter().map(CompoundConstraint::Simple).collect()
+}
+
+/// Balanced binary tree alternating AND and OR by level
+fn balanced(mut leaves: Vec<CompoundConstraint>, and: bool) -> CompoundConstraint {
+    if leaves.len() == 1 {
+        return leaves.pop().unwrap();
+    }
+    let right = leaves.split_off(leaves.len() / 2);
+    let children = vec![balanced(leaves, !and), balanced(right, !and)];
+    if and {
+        CompoundConstraint::And(children)
+    } else {
+        CompoundConstraint::Or(children)
+    }
+}
+
+/// The tree shapes benchmarked, by name
+fn shapes(n: usize) -> Vec<(&'static str, CompoundConstraint)> {
+    vec![
+        ("flat_and", CompoundConstraint::And(simple(bounds(n)))),
+        ("flat_or", CompoundConstraint::Or(simple(bounds(n)))),
+        ("balanced", balanced(simple(bounds(n)), true)),
+        ("chain", CompoundConstraint::And(simple(chain(n)))),
+    ]
+}
+
+fn bench_solve(c: &mut Criterion) {
+    let verifier = Z3Verifier::new();
+    let mut group = c.benchmark_group("solve");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        for (shape, tree) in shapes(n) {
+            group.bench_with_input(BenchmarkId::new(shape, n), &tree, |b, tree| {
+                b.iter(|| verifier.verify_compound_constraints(black_box(tree)).unwrap())
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_smt_lib(c: &mut Criterion) {
+    let verifier = Z3Verifier::new();
+    let mut group = c.benchmark_group("smt_lib");
+    for n in SIZES {
+        let constraints = bounds(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &constraints, |b, constraints| {
+            b.iter(|| verifier.generate_smt_lib(black_box(constraints)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_solve, bench_smt_lib);
+criterion_main!(benches);
@@ -0,0 +1,316 @@
+//! Floating-point semantics for `Decimal` fields
+//!
+//! By default every number is an unbounded integer to Z3. The Rust, Zig,
+//! TypeScript and WIT validators hold a `Decimal` field in an IEEE 754
+//! double instead, where `0.1 + 0.2 == 0.3` is false and large sums lose
+//! their low digits. Under `DecimalSemantics::Float64`,
+//! `Z3Verifier::verify_with_schema` checks every comparison that reads a
+//! `Decimal` field in Z3's floating-point theory:
+//!
+//! - each operand is a double: `Decimal` fields as they are, other numeric
+//!   fields converted, literals the double nearest to them (as the compiler
+//!   reads them);
+//! - `+`, `-`, `*` and `/` round with the chosen `RoundingMode`, and may
+//!   overflow to infinity;
+//! - comparisons are IEEE comparisons, so `-0.0 == 0.0` and NaN equals
+//!   nothing. Inputs are finite, as a JSON number is.
+//!
+//! Such a comparison is written as an SMT-LIB script defining a Bool named
+//! after it, which the rest of the integer translation refers to.
+
+use crate::{VerificationError, VerificationResult};
+use crucible_core::{Call, Conditional, Constraint, ConstraintOperator, DataType, MathFunction, Schema};
+use std::collections::{BTreeMap, HashSet};
+
+/// The SMT-LIB sort of an IEEE 754 double
+const FLOAT64: &str = "(_ FloatingPoint 11 53)";
+
+/// How `Z3Verifier::verify_with_schema` models the Schema's `Decimal` fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalSemantics {
+    /// Unbounded integers, like every other numeric field
+    #[default]
+    Exact,
+    /// IEEE 754 doubles, as the generated validators compute, with
+    /// arithmetic rounded by the mode
+    Float64(RoundingMode),
+}
+
+/// An IEEE 754 rounding mode for the results of arithmetic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// To the nearest double, ties to an even last digit: `f64` and
+    /// JavaScript numbers round this way
+    #[default]
+    NearestTiesToEven,
+    /// To the nearest double, ties away from zero
+    NearestTiesToAway,
+    TowardPositive,
+    TowardNegative,
+    TowardZero,
+}
+
+impl RoundingMode {
+    /// The SMT-LIB name of the mode, e.g. `RNE`
+    pub fn smt_name(&self) -> &'static str {
+        match self {
+            RoundingMode::NearestTiesToEven => "RNE",
+            RoundingMode::NearestTiesToAway => "RNA",
+            RoundingMode::TowardPositive => "RTP",
+            RoundingMode::TowardNegative => "RTN",
+            RoundingMode::TowardZero => "RTZ",
+        }
+    }
+}
+
+/// The SMT-LIB sort each field of a comparison is declared with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sort {
+    Float,
+    Int,
+    Bool,
+}
+
+/// The `Decimal` fields of a Schema while `verify_with_schema` runs
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FloatModel {
+    decimals: HashSet<String>,
+    rounding: RoundingMode,
+}
+
+impl FloatModel {
+    pub(crate) fn new(schema: &Schema, rounding: RoundingMode) -> Self {
+        let decimals = schema.fields.iter().filter(|(_, dt)| **dt == DataType::Decimal);
+        Self {
+            decimals: decimals.map(|(name, _)| name.clone()).collect(),
+            rounding,
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.decimals.is_empty()
+    }
+
+    /// Whether `constraint` reads a `Decimal` field, and so compares doubles
+    pub(crate) fn covers(&self, constraint: &Constraint) -> bool {
+        constraint.variables().iter().any(|name| self.is_decimal(name))
+    }
+
+    pub(crate) fn is_decimal(&self, name: &str) -> bool {
+        self.decimals.contains(name.trim_end_matches('\''))
+    }
+
+    /// An SMT-LIB script declaring the fields of `constraint` and defining
+    /// the Bool `name` as the comparison over doubles
+    pub(crate) fn define(&self, name: &str, constraint: &Constraint) -> VerificationResult<String> {
+        let mut fields = BTreeMap::new();
+        let term = self.comparison(constraint, &mut fields)?;
+        let mut script = String::new();
+        for (field, sort) in &fields {
+            let sort_name = match sort {
+                Sort::Float => FLOAT64,
+                Sort::Int => "Int",
+                Sort::Bool => "Bool",
+            };
+            script.push_str(&format!("(declare-const {} {})\n", quoted(field)?, sort_name));
+            if *sort == Sort::Float {
+                let finite = format!("(or (fp.isNaN {0}) (fp.isInfinite {0}))", quoted(field)?);
+                script.push_str(&format!("(assert (not {}))\n", finite));
+            }
+        }
+        script.push_str(&format!("(declare-const {} Bool)\n", quoted(name)?));
+        script.push_str(&format!("(assert (= {} {}))\n", quoted(name)?, term));
+        Ok(script)
+    }
+
+    fn comparison(&self, constraint: &Constraint, fields: &mut BTreeMap<String, Sort>) -> VerificationResult<String> {
+        if constraint.operator.is_membership() || constraint.modulo().is_some() || constraint.bitwise().is_some() {
+            return Err(unsupported(constraint));
+        }
+        // A condition on a boolean field, `member == true`
+        if let ("true" | "false", ConstraintOperator::Equal | ConstraintOperator::NotEqual) =
+            (constraint.right_value.as_str(), constraint.operator)
+        {
+            fields.insert(constraint.left_variable.clone(), Sort::Bool);
+            let test = format!("(= {} {})", quoted(&constraint.left_variable)?, constraint.right_value);
+            return Ok(match constraint.operator {
+                ConstraintOperator::NotEqual => format!("(not {})", test),
+                _ => test,
+            });
+        }
+
+        let left = self.term(constraint, &constraint.left_variable, fields)?;
+        let right = self.term(constraint, &constraint.right_value, fields)?;
+        let predicate = match constraint.operator {
+            ConstraintOperator::GreaterThanOrEqual => "fp.geq",
+            ConstraintOperator::LessThanOrEqual => "fp.leq",
+            ConstraintOperator::GreaterThan => "fp.gt",
+            ConstraintOperator::LessThan => "fp.lt",
+            ConstraintOperator::Equal | ConstraintOperator::NotEqual => "fp.eq",
+            ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => unreachable!("rejected above"),
+        };
+        let test = format!("({} {} {})", predicate, left, right);
+        Ok(match constraint.operator {
+            ConstraintOperator::NotEqual => format!("(not {})", test),
+            _ => test,
+        })
+    }
+
+    /// One operand of `constraint` as a double
+    fn term(
+        &self,
+        constraint: &Constraint,
+        text: &str,
+        fields: &mut BTreeMap<String, Sort>,
+    ) -> VerificationResult<String> {
+        let text = text.trim();
+        let rounding = self.rounding.smt_name();
+
+        if let Some(conditional) = Conditional::parse(text) {
+            return Ok(format!(
+                "(ite {} {} {})",
+                self.comparison(&conditional.condition, fields)?,
+                self.term(constraint, &conditional.then_value, fields)?,
+                self.term(constraint, &conditional.else_value, fields)?
+            ));
+        }
+        if let Some(call) = Call::parse(text) {
+            let args = call
+                .args
+                .iter()
+                .map(|arg| self.term(constraint, arg, fields))
+                .collect::<VerificationResult<Vec<_>>>()?;
+            let function = match call.function {
+                MathFunction::Min => "fp.min",
+                MathFunction::Max => "fp.max",
+                MathFunction::Abs => return Ok(format!("(fp.abs {})", args[0])),
+                MathFunction::Sum | MathFunction::Count | MathFunction::Average => return Err(unsupported(constraint)),
+            };
+            return Ok(args[1..]
+                .iter()
+                .fold(args[0].clone(), |acc, arg| format!("({} {} {})", function, acc, arg)));
+        }
+        if let [left, op, right] = text.split_whitespace().collect::<Vec<_>>()[..] {
+            let operation = match op {
+                "+" => "fp.add",
+                "-" => "fp.sub",
+                "*" => "fp.mul",
+                "/" => "fp.div",
+                _ => return Err(unsupported(constraint)),
+            };
+            let (left, right) = (
+                self.term(constraint, left, fields)?,
+                self.term(constraint, right, fields)?,
+            );
+            return Ok(format!("({} {} {} {})", operation, rounding, left, right));
+        }
+        if let Some(value) = text.parse::<f64>().ok().filter(|value| value.is_finite()) {
+            return Ok(double(value));
+        }
+        if self.is_decimal(text) {
+            fields.insert(text.to_string(), Sort::Float);
+            return quoted(text);
+        }
+        fields.insert(text.to_string(), Sort::Int);
+        Ok(format!("((_ to_fp 11 53) {} (to_real {}))", rounding, quoted(text)?))
+    }
+}
+
+/// `value` as an SMT-LIB double literal, bit for bit
+fn double(value: f64) -> String {
+    let bits = value.to_bits();
+    format!(
+        "(fp #b{:01b} #b{:011b} #b{:052b})",
+        bits >> 63,
+        (bits >> 52) & 0x7FF,
+        bits & ((1 << 52) - 1)
+    )
+}
+
+/// `name` as a quoted SMT-LIB symbol, which may hold spaces and primes
+fn quoted(name: &str) -> VerificationResult<String> {
+    match name.contains(['|', '\\']) {
+        true => Err(VerificationError::TranslationError(format!(
+            "`{}` is not a valid SMT-LIB symbol",
+            name
+        ))),
+        false => Ok(format!("|{}|", name)),
+    }
+}
+
+fn unsupported(constraint: &Constraint) -> VerificationError {
+    VerificationError::TranslationError(format!(
+        "`{}` has no floating-point translation; verify it with DecimalSemantics::Exact",
+        constraint
+    ))
+}
+
+/// A double from Z3's model, e.g. `(fp #b0 #b01111111101 #x3333333333333)`,
+/// as the shortest decimal that reads back as it: `0.30000000000000004`
+pub(crate) fn model_value(text: &str) -> Option<String> {
+    let special = |name: &str| text == format!("(_ {} 11 53)", name);
+    if special("+zero") {
+        return Some("0".to_string());
+    } else if special("-zero") {
+        return Some("-0".to_string());
+    } else if special("+oo") {
+        return Some("inf".to_string());
+    } else if special("-oo") {
+        return Some("-inf".to_string());
+    } else if special("NaN") {
+        return Some("NaN".to_string());
+    }
+
+    let parts: Vec<&str> = text
+        .strip_prefix("(fp ")?
+        .strip_suffix(')')?
+        .split_whitespace()
+        .collect();
+    let [sign, exponent, significand] = parts[..] else {
+        return None;
+    };
+    let bits = |literal: &str| match (literal.strip_prefix("#b"), literal.strip_prefix("#x")) {
+        (Some(binary), _) => u64::from_str_radix(binary, 2).ok(),
+        (_, Some(hex)) => u64::from_str_radix(hex, 16).ok(),
+        _ => None,
+    };
+    let value = f64::from_bits(bits(sign)? << 63 | bits(exponent)? << 52 | bits(significand)?);
+    Some(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_model() {
+        let mut schema = Schema::new("TR-FLOAT".to_string());
+        schema.add_field("total".to_string(), DataType::Decimal, None);
+        schema.add_field("items".to_string(), DataType::Int64, None);
+        let model = FloatModel::new(&schema, RoundingMode::TowardZero);
+        let total = Constraint {
+            left_variable: "total".to_string(),
+            operator: ConstraintOperator::NotEqual,
+            right_value: "items * 0.5".to_string(),
+        };
+        assert!(model.covers(&total) && !model.covers(&Constraint::from("items")));
+        assert_eq!(
+            model.define("float total", &total).unwrap(),
+            "(declare-const |items| Int)\n\
+             (declare-const |total| (_ FloatingPoint 11 53))\n\
+             (assert (not (or (fp.isNaN |total|) (fp.isInfinite |total|))))\n\
+             (declare-const |float total| Bool)\n\
+             (assert (= |float total| (not (fp.eq |total| \
+             (fp.mul RTZ ((_ to_fp 11 53) RTZ (to_real |items|)) \
+             (fp #b0 #b01111111110 #b0000000000000000000000000000000000000000000000000000))))))\n"
+        );
+
+        assert_eq!(
+            model_value("(fp #b0 #b01111111101 #x3333333333334)").as_deref(),
+            Some("0.30000000000000004")
+        );
+        assert_eq!(model_value(&double(-2.5)).as_deref(), Some("-2.5"));
+        assert_eq!(model_value("(_ -zero 11 53)").as_deref(), Some("-0"));
+        assert_eq!(model_value("42"), None);
+    }
+}
@@ -9,10 +9,27 @@
 //! This module provides formal verification capabilities using the Z3 SMT solver.
 //! It translates constraint expressions into Z3 formulas and performs satisfiability checking.
 
-use crucible_core::{Constraint, ConstraintOperator, CompoundConstraint};
+pub mod float;
+pub mod passes;
+pub mod pool;
+
+pub use float::{DecimalSemantics, RoundingMode};
+pub use passes::{Finding, Level, PassContext, PassManager, RedundantConjuncts, VerificationPass};
+pub use pool::{PoolError, PooledVerifier, VerifierPool};
+
+use crucible_core::error::codes;
+use crucible_core::{
+    presolve, ArithmeticTerm, BitWidth, Bitwise, BitwiseOperator, BoundaryCase, Call, ComponentContract, Conditional,
+    Constraint, ConstraintArena, ConstraintError, ConstraintOperator, CompoundConstraint, CrucibleError, Definitions,
+    Diagnostic, FaultCase, IntentAst, MathFunction, Node, NodeId, OverflowRisk, Presolved, RequirementKind, Schema,
+    Severity, StateMachine, StateTransition,
+};
+use float::FloatModel;
 use thiserror::Error;
-use z3::{ast::Ast, Config, Context, Solver};
-use std::collections::HashMap;
+use z3::{ast::Ast, Config, Context, Optimize, Solver};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
 
 /// Result type for verification operations
 pub type VerificationResult<T> = std::result::Result<T, VerificationError>;
@@ -31,6 +48,22 @@ pub enum VerificationError {
     
     #[error("Unknown constraint type")]
     UnknownConstraintType,
+
+    #[error("Invalid constraint: {0}")]
+    InvalidConstraint(#[from] ConstraintError),
+}
+
+impl From<VerificationError> for CrucibleError {
+    fn from(error: VerificationError) -> Self {
+        let diagnostic = |code| Diagnostic::new(code, error.to_string());
+        CrucibleError::Verification(match &error {
+            VerificationError::InvalidConstraint(e) => return e.clone().into(),
+            VerificationError::Unsatisfiable(detail) => diagnostic(codes::UNSATISFIABLE).with_arg("detail", detail),
+            VerificationError::SolverError(detail) => diagnostic(codes::SOLVER).with_arg("detail", detail),
+            VerificationError::TranslationError(detail) => diagnostic(codes::TRANSLATION).with_arg("detail", detail),
+            VerificationError::UnknownConstraintType => diagnostic(codes::TRANSLATION),
+        })
+    }
 }
 
 /// Result of a verification check
@@ -42,20 +75,355 @@ pub struct VerificationResultOutput {
     pub constraints_count: usize,
 }
 
+/// A soft constraint the MaxSAT solution had to give up
+#[derive(Debug, Clone)]
+pub struct SoftConflict {
+    /// Index into the constraints passed to `verify_layered`
+    pub index: usize,
+    pub severity: Severity,
+    pub suggestion: String,
+}
+
+/// Result of a layered (hard, then soft) verification
+#[derive(Debug, Clone)]
+pub struct LayeredVerificationOutput {
+    /// Model satisfying every hard and as many soft constraints as possible
+    pub model: Option<HashMap<String, String>>,
+    /// Soft constraints to relax; empty when everything holds together
+    pub relaxed: Vec<SoftConflict>,
+}
+
+impl LayeredVerificationOutput {
+    /// Soft conflicts rendered as warnings
+    pub fn warnings(&self) -> Vec<String> {
+        self.relaxed.iter().map(|c| c.suggestion.clone()).collect()
+    }
+}
+
+/// Which extreme of a variable `optimize` looks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Minimize,
+    Maximize,
+}
+
+impl Objective {
+    fn label(self) -> &'static str {
+        match self {
+            Objective::Minimize => "minimum",
+            Objective::Maximize => "maximum",
+        }
+    }
+}
+
+/// Least or greatest value a variable can take under the spec
+#[derive(Debug, Clone)]
+pub struct OptimizationOutput {
+    pub variable: String,
+    pub objective: Objective,
+    /// `None` when the spec leaves the variable unbounded in that direction
+    /// (or the optimum lies outside the `i64` range)
+    pub value: Option<i64>,
+    /// An assignment attaining `value`
+    pub model: Option<HashMap<String, String>>,
+}
+
+impl std::fmt::Display for OptimizationOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Some(value) => write!(f, "{} permitted `{}` is {}", self.objective.label(), self.variable, value),
+            None => write!(f, "`{}` has no {}", self.variable, self.objective.label()),
+        }
+    }
+}
+
+/// Feasible interval of one integer variable; `None` ends are unbounded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeasibleRange {
+    pub variable: String,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+/// How a new requirement relates to the existing ones
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Consistency {
+    /// Satisfiable together with the existing requirements, and adds to them
+    Consistent,
+    /// Already follows from these existing requirements (indices, a minimal set)
+    Implied(Vec<usize>),
+    /// Cannot hold together with these existing requirements (indices, a
+    /// minimal set); empty when the new requirement contradicts itself
+    Contradicts(Vec<usize>),
+}
+
+/// Whether a constraint holds for a concrete scenario
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioVerdict {
+    Satisfied,
+    Violated,
+    /// Decided by variables the scenario leaves unbound
+    Undetermined,
+}
+
+/// Verdict for one of the constraints passed to `check_scenario`
+#[derive(Debug, Clone)]
+pub struct ScenarioCheck {
+    /// Index into the constraints passed to `check_scenario`
+    pub index: usize,
+    pub verdict: ScenarioVerdict,
+    /// Smallest subexpressions that are false under the scenario, e.g.
+    /// `amount <= 10000` out of a longer conjunction
+    pub violations: Vec<String>,
+}
+
+/// How a guarantee relates to the assumptions it is checked under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuaranteeStatus {
+    /// A ⊢ G: every environment satisfying the assumptions satisfies the guarantee
+    Entailed,
+    /// A ∧ G is satisfiable but not entailed: the implementation must enforce it
+    Enforceable,
+    /// A ∧ G is unsatisfiable: the guarantee contradicts the assumptions
+    Contradicted,
+}
+
+/// Result for one guarantee or invariant
+#[derive(Debug, Clone)]
+pub struct GuaranteeCheck {
+    /// Index into the requirements passed to `verify_contract`
+    pub index: usize,
+    pub kind: RequirementKind,
+    pub status: GuaranteeStatus,
+    /// An assignment satisfying the assumptions but violating the guarantee
+    pub counterexample: Option<HashMap<String, String>>,
+}
+
+/// Result of an assume-guarantee check
+#[derive(Debug, Clone)]
+pub struct ContractVerificationOutput {
+    pub assumptions_count: usize,
+    pub checks: Vec<GuaranteeCheck>,
+}
+
+impl ContractVerificationOutput {
+    /// True when no guarantee or invariant contradicts the assumptions
+    pub fn is_consistent(&self) -> bool {
+        self.checks.iter().all(|c| c.status != GuaranteeStatus::Contradicted)
+    }
+}
+
+/// A consumer assumption the producer's guarantees do not imply
+#[derive(Debug, Clone)]
+pub struct InterfaceGap {
+    /// Index into the consumer's assumptions
+    pub assumption: usize,
+    /// Producer output meeting its guarantees but breaking the assumption
+    pub counterexample: Option<HashMap<String, String>>,
+    /// Fields of the assumption the producer's contract never mentions
+    pub unconstrained: Vec<String>,
+}
+
+/// Result of a producer/consumer compatibility check
+#[derive(Debug, Clone)]
+pub struct CompatibilityOutput {
+    pub assumptions_count: usize,
+    pub gaps: Vec<InterfaceGap>,
+}
+
+impl CompatibilityOutput {
+    /// True when the producer's guarantees imply every consumer assumption
+    pub fn is_compatible(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+/// How a new version of a spec relates to the old one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Refinement {
+    /// Both versions allow exactly the same values
+    Equivalent,
+    /// The new version rejects values the old one allowed, and allows nothing new
+    Stronger,
+    /// The new version allows values the old one rejected, and rejects nothing new
+    Weaker,
+    /// Each version allows values the other rejects
+    Incomparable,
+}
+
+impl std::fmt::Display for Refinement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Refinement::Equivalent => "equivalent",
+            Refinement::Stronger => "strictly stronger",
+            Refinement::Weaker => "strictly weaker",
+            Refinement::Incomparable => "incomparable",
+        })
+    }
+}
+
+/// Result of comparing two versions of a spec
+#[derive(Debug, Clone)]
+pub struct RefinementOutput {
+    pub relation: Refinement,
+    /// Values the old version allowed and the new one rejects
+    pub newly_rejected: Option<HashMap<String, String>>,
+    /// Values the new version allows and the old one rejected
+    pub newly_allowed: Option<HashMap<String, String>>,
+}
+
+/// Result of checking a state transition
+#[derive(Debug, Clone)]
+pub struct TransitionVerificationOutput {
+    /// Every postcondition check holds after the update whenever the precondition held
+    pub postcondition_holds: bool,
+    /// Pre-state (and resulting post-state) that breaks a postcondition check
+    pub counterexample: Option<HashMap<String, String>>,
+    /// State variables left unchanged by the frame condition
+    pub framed_variables: Vec<String>,
+}
+
+/// Whether one action preserves one invariant
+#[derive(Debug, Clone)]
+pub struct PreservationCheck {
+    pub action: String,
+    /// Index into the invariants passed to `verify_invariant_preservation`
+    pub invariant: usize,
+    pub preserved: bool,
+    /// Pre-state (and primed post-state) in which the invariant held before
+    /// the action and fails after it
+    pub counterexample: Option<HashMap<String, String>>,
+}
+
+/// Result of an inductive invariant check
+#[derive(Debug, Clone)]
+pub struct InvariantPreservationOutput {
+    /// One per action and invariant, in action order
+    pub checks: Vec<PreservationCheck>,
+    /// Actions whose precondition never holds in a state satisfying the invariants
+    pub disabled_actions: Vec<String>,
+}
+
+impl InvariantPreservationOutput {
+    /// True when every action preserves every invariant
+    pub fn is_inductive(&self) -> bool {
+        self.checks.iter().all(|c| c.preserved)
+    }
+
+    /// The action/invariant pairs that fail
+    pub fn violations(&self) -> impl Iterator<Item = &PreservationCheck> {
+        self.checks.iter().filter(|c| !c.preserved)
+    }
+}
+
+/// The action taken at step `i` of a `check_trace` unrolling is `action#i`
+const TRACE_ACTION: &str = "action#";
+
+/// `float <comparison>` names the Bool a comparison of doubles defines;
+/// such names are left out of models
+const FLOAT_COMPARISON: &str = "float ";
+
+/// Elements the verifier models per list field; `sum`, `count` and `average`
+/// are proven for lists up to this long
+pub const MAX_LIST_LENGTH: usize = 8;
+
+/// One state of a `check_trace` counterexample
+#[derive(Debug, Clone)]
+pub struct TraceStep {
+    /// The action leading here; `None` for the initial state
+    pub action: Option<String>,
+    /// The action's inputs
+    pub inputs: HashMap<String, String>,
+    pub state: HashMap<String, String>,
+}
+
+/// Result of a bounded model check
+#[derive(Debug, Clone)]
+pub struct TraceCheckOutput {
+    /// Actions unrolled
+    pub bound: usize,
+    /// The initial state and the steps to the first state violating the
+    /// property; `None` when none is reachable within `bound` actions
+    pub violation: Option<Vec<TraceStep>>,
+}
+
+impl TraceCheckOutput {
+    /// True when the property holds in every state reachable within the bound
+    pub fn is_safe(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+/// A reachable state in which no outgoing transition may be enabled
+#[derive(Debug, Clone)]
+pub struct Deadlock {
+    pub state: String,
+    /// Data values for which every outgoing guard is false
+    pub witness: Option<HashMap<String, String>>,
+}
+
+/// Result of checking a state machine
+#[derive(Debug, Clone)]
+pub struct StateMachineVerificationOutput {
+    /// Transitions (by index) whose guard can never hold
+    pub dead_transitions: Vec<usize>,
+    pub unreachable_states: Vec<String>,
+    pub deadlocks: Vec<Deadlock>,
+}
+
+impl StateMachineVerificationOutput {
+    pub fn is_live(&self) -> bool {
+        self.unreachable_states.is_empty() && self.deadlocks.is_empty()
+    }
+}
+
 /// Z3-backed verification engine
 pub struct Z3Verifier {
     ctx: Context,
+    /// Bit widths of the Schema's integer fields while `verify_with_schema`
+    /// runs; bitwise terms over any other field are `BitWidth::DEFAULT` wide
+    widths: RefCell<HashMap<String, BitWidth>>,
+    decimal: DecimalSemantics,
+    /// The Schema's `Decimal` fields while `verify_with_schema` runs under
+    /// `DecimalSemantics::Float64`
+    floats: RefCell<FloatModel>,
 }
 
 impl Z3Verifier {
     /// Create a new Z3 verifier
     pub fn new() -> Self {
-        let cfg = Config::new();
+        Self::with_timeout(None)
+    }
+
+    /// A verifier whose checks give up (answering `unknown`) after `timeout`
+    pub fn with_timeout(timeout: Option<Duration>) -> Self {
+        let mut cfg = Config::new();
+        if let Some(timeout) = timeout {
+            cfg.set_timeout_msec(timeout.as_millis() as u64);
+        }
         let ctx = Context::new(&cfg);
-        Self { ctx }
+        Self {
+            ctx,
+            widths: RefCell::default(),
+            decimal: DecimalSemantics::default(),
+            floats: RefCell::default(),
+        }
+    }
+
+    /// How `verify_with_schema` models `Decimal` fields: as integers, or as
+    /// the doubles the generated validators hold
+    pub fn with_decimal_semantics(mut self, semantics: DecimalSemantics) -> Self {
+        self.decimal = semantics;
+        self
+    }
+
+    /// Version of the linked Z3, e.g. `Z3 4.12.2.0`, for provenance records
+    pub fn solver_version() -> String {
+        z3::full_version().to_string()
     }
 
     /// Verify a list of constraints
+    #[tracing::instrument(skip_all, fields(constraints = constraints.len()))]
     pub fn verify_constraints(
         &self,
         constraints: &[Constraint],
@@ -73,7 +441,7 @@ impl Z3Verifier {
         }
 
         // Check satisfiability
-        match solver.check() {
+        match timed_check(&solver) {
             z3::SatResult::Sat => {
                 let model = solver.get_model();
                 let model_map = model.as_ref().map(|m| {
@@ -111,26 +479,88 @@ impl Z3Verifier {
         }
     }
 
-    /// Verify compound constraints (AND/OR/NOT trees)
+    /// Verify compound constraints (AND/OR/NOT trees).
+    ///
+    /// Conjunctions of plain bounds are decided by the interval pre-solver;
+    /// only the conjuncts it cannot decide are sent to Z3. Empty groups and
+    /// excessive nesting fail with `InvalidConstraint` before either runs.
+    #[tracing::instrument(skip_all, fields(constraints = compound.count_constraints()))]
     pub fn verify_compound_constraints(
         &self,
         compound: &CompoundConstraint,
     ) -> VerificationResult<VerificationResultOutput> {
+        compound.validate_structure()?;
+        self.solve_compound(compound)
+    }
+
+    /// `verify_compound_constraints` after checking the tree against
+    /// `schema`: unknown fields and mismatched comparisons are
+    /// `InvalidConstraint` errors rather than fresh solver variables,
+    /// bitwise terms take the width of their field's type, and `Decimal`
+    /// fields follow the verifier's `DecimalSemantics`
+    pub fn verify_with_schema(
+        &self,
+        compound: &CompoundConstraint,
+        schema: &Schema,
+    ) -> VerificationResult<VerificationResultOutput> {
+        compound.validate(schema)?;
+        let widths = schema.fields.iter().filter_map(|(name, dt)| Some((name.clone(), BitWidth::of(dt)?)));
+        let previous = self.widths.replace(widths.collect());
+        let floats = match self.decimal {
+            DecimalSemantics::Exact => FloatModel::default(),
+            DecimalSemantics::Float64(rounding) => FloatModel::new(schema, rounding),
+        };
+        let previous_floats = self.floats.replace(floats);
+        let result = self.solve_compound(compound);
+        self.widths.replace(previous);
+        self.floats.replace(previous_floats);
+        result
+    }
+
+    fn solve_compound(&self, compound: &CompoundConstraint) -> VerificationResult<VerificationResultOutput> {
+        // The pre-solver's intervals are over integers, not doubles
+        let presolved = match self.floats.borrow().is_empty() {
+            true => presolve(compound),
+            false => Presolved::Residual { core: compound.clone(), decided: BTreeMap::new() },
+        };
+        let (residual, decided) = match presolved {
+            Presolved::Sat(witnesses) => {
+                tracing::debug!(fields = witnesses.len(), "decided by interval pre-solver");
+                return Ok(VerificationResultOutput {
+                    satisfiable: true,
+                    model: Some(z3_model(witnesses)),
+                    proof: Some("Compound constraints are satisfiable (interval pre-solver)".to_string()),
+                    constraints_count: compound.count_constraints(),
+                });
+            }
+            Presolved::Unsat(reason) => {
+                tracing::debug!(%reason, "refuted by interval pre-solver");
+                return Err(VerificationError::Unsatisfiable(format!(
+                    "Compound constraints are unsatisfiable: {}",
+                    reason
+                )));
+            }
+            Presolved::Residual { core, decided } => (core, decided),
+        };
+
         let solver = Solver::new(&self.ctx);
         let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
         
-        let z3_expr = self.translate_compound(compound, &mut var_map, &solver)?;
+        let z3_expr = self.translate_compound(&residual, &mut var_map, &solver)?;
         solver.assert(&z3_expr);
         
-        match solver.check() {
+        match timed_check(&solver) {
             z3::SatResult::Sat => {
                 let model = solver.get_model();
                 let model_map = model.as_ref().map(|m| {
-                    let mut map = HashMap::new();
+                    let mut map = z3_model(decided);
                     for decl in m.get_decls() {
                         let name = decl.name().to_string();
-                        let value = m.eval(&decl).unwrap();
-                        map.insert(name, value.to_string());
+                        if name.starts_with(FLOAT_COMPARISON) {
+                            continue;
+                        }
+                        let value = m.eval(&decl).unwrap().to_string();
+                        map.insert(name, float::model_value(&value).unwrap_or(value));
                     }
                     map
                 });
@@ -155,284 +585,2761 @@ impl Z3Verifier {
         }
     }
 
-    /// Translate a simple constraint to a Z3 expression
-    fn translate_constraint<C: Into<Constraint>>(
+    /// Verify a tree that references named predicates; Z3 sees every
+    /// definition inlined at its point of use
+    pub fn verify_with_definitions(
         &self,
-        constraint: &C,
-        var_map: &mut HashMap<String, z3::ast::Int>,
-        _solver: &Solver,
-    ) -> VerificationResult<z3::ast::Bool> {
-        let constraint = constraint.clone().into();
-        
-        // Get or create the left variable
-        let left_var = var_map
-            .entry(constraint.left_variable.clone())
-            .or_insert_with(|| z3::ast::Int::new_const(&self.ctx, constraint.left_variable))
-            .clone();
-
-        // Parse the right value as an integer or variable
-        let right_expr = self.parse_right_value(&constraint.right_value, var_map)?;
-
-        // Map the operator to Z3 expression
-        match constraint.operator {
-            ConstraintOperator::GreaterThanOrEqual => Ok(left_var.ge(&right_expr)),
-            ConstraintOperator::LessThanOrEqual => Ok(left_var.le(&right_expr)),
-            ConstraintOperator::GreaterThan => Ok(left_var.gt(&right_expr)),
-            ConstraintOperator::LessThan => Ok(left_var.lt(&right_expr)),
-            ConstraintOperator::Equal => Ok(left_var._eq(&right_expr)),
-            ConstraintOperator::NotEqual => Ok(left_var._eq(&right_expr).not()),
-        }
+        compound: &CompoundConstraint,
+        definitions: &Definitions,
+    ) -> VerificationResult<VerificationResultOutput> {
+        let inlined = definitions
+            .inline(compound)
+            .map_err(|e| VerificationError::TranslationError(e.to_string()))?;
+        self.verify_compound_constraints(&inlined)
     }
 
-    /// Translate a compound constraint (AND/OR/NOT tree)
-    fn translate_compound(
+    /// Verify constraints in severity layers.
+    ///
+    /// `MustHold` constraints must be satisfiable together; otherwise this fails
+    /// with `Unsatisfiable`. Soft constraints are then added with their
+    /// severity weight and solved as MaxSAT: any that cannot hold alongside the
+    /// hard layer come back as warnings naming the constraints to relax.
+    #[tracing::instrument(skip_all, fields(layers = constraints.len()))]
+    pub fn verify_layered(
         &self,
-        compound: &CompoundConstraint,
-        var_map: &mut HashMap<String, z3::ast::Int>,
-        solver: &Solver,
-    ) -> VerificationResult<z3::ast::Bool> {
-        match compound {
-            CompoundConstraint::And(constraints) => {
-                let z3_constraints: Vec<z3::ast::Bool> = constraints
-                    .iter()
-                    .map(|c| self.translate_compound(c, var_map, solver))
-                    .collect::<Result<Vec<_>, _>>()?;
-                
-                let mut result = z3_constraints
-                    .first()
-                    .cloned()
-                    .unwrap_or_else(|| z3::ast::Bool::from_bool(&self.ctx, true));
-                
-                for constraint in z3_constraints.into_iter().skip(1) {
-                    result = result.and(&constraint);
+        constraints: &[(Severity, CompoundConstraint)],
+    ) -> VerificationResult<LayeredVerificationOutput> {
+        let hard: Vec<CompoundConstraint> = constraints
+            .iter()
+            .filter(|(severity, _)| severity.is_hard())
+            .map(|(_, c)| c.clone())
+            .collect();
+        for (_, compound) in constraints {
+            compound.validate_structure()?;
+        }
+        // No hard layer is an empty conjunction, which holds
+        self.solve_compound(&CompoundConstraint::And(hard))?;
+
+        let optimizer = Optimize::new(&self.ctx);
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let mut soft = Vec::new();
+
+        for (index, (severity, compound)) in constraints.iter().enumerate() {
+            let z3_expr = self.translate_compound(compound, &mut var_map, &solver)?;
+            match severity.soft_weight() {
+                None => optimizer.assert(&z3_expr),
+                Some(weight) => {
+                    optimizer.assert_soft(&z3_expr, weight as usize, None);
+                    soft.push((index, *severity, z3_expr));
                 }
-                
-                Ok(result)
             }
-            CompoundConstraint::Or(constraints) => {
-                let z3_constraints: Vec<z3::ast::Bool> = constraints
-                    .iter()
-                    .map(|c| self.translate_compound(c, var_map, solver))
-                    .collect::<Result<Vec<_>, _>>()?;
-                
-                let mut result = z3_constraints
-                    .first()
-                    .cloned()
-                    .unwrap_or_else(|| z3::ast::Bool::from_bool(&self.ctx, false));
-                
-                for constraint in z3_constraints.into_iter().skip(1) {
-                    result = result.or(&constraint);
+        }
+
+        match optimizer.check(&[]) {
+            z3::SatResult::Sat => {
+                let model = optimizer.get_model().ok_or_else(|| {
+                    VerificationError::SolverError("Optimizer returned no model".to_string())
+                })?;
+
+                let relaxed: Vec<SoftConflict> = soft
+                    .into_iter()
+                    .filter(|(_, _, expr)| model.eval(expr, true).and_then(|v| v.as_bool()) == Some(false))
+                    .map(|(index, severity, _)| SoftConflict {
+                        index,
+                        severity,
+                        suggestion: format!(
+                            "{:?} constraint #{} conflicts with the MustHold constraints; relax or drop it",
+                            severity, index
+                        ),
+                    })
+                    .collect();
+
+                let mut model_map = HashMap::new();
+                for decl in model.get_decls() {
+                    let name = decl.name().to_string();
+                    let value = model.eval(&decl).unwrap();
+                    model_map.insert(name, value.to_string());
                 }
-                
-                Ok(result)
-            }
-            CompoundConstraint::Not(constraint) => {
-                let inner = self.translate_compound(constraint, var_map, solver)?;
-                Ok(inner.not())
-            }
-            CompoundConstraint::Simple(constraint) => {
-                self.translate_constraint(constraint, var_map, solver)
+
+                Ok(LayeredVerificationOutput {
+                    model: Some(model_map),
+                    relaxed,
+                })
             }
+            z3::SatResult::Unsat => Err(VerificationError::Unsatisfiable(
+                "Hard constraints are unsatisfiable".to_string(),
+            )),
+            z3::SatResult::Unknown => Err(VerificationError::SolverError(
+                "Z3 optimizer returned unknown result".to_string(),
+            )),
         }
     }
 
-    /// Parse the right value (can be integer or variable reference)
-    fn parse_right_value(
+    /// Least or greatest value `variable` can take while `constraints` hold,
+    /// e.g. "maximum permitted `withdrawal` is 10000".
+    ///
+    /// Fails with `Unsatisfiable` when no assignment satisfies the constraints.
+    #[tracing::instrument(skip(self, constraints))]
+    pub fn optimize(
         &self,
-        right_value: &str,
-        var_map: &mut HashMap<String, z3::ast::Int>,
-    ) -> VerificationResult<z3::ast::Int> {
-        // Try to parse as integer
-        if let Ok(int_val) = right_value.parse::<i64>() {
-            return Ok(z3::ast::Int::from_i64(&self.ctx, int_val));
-        }
+        variable: &str,
+        objective: Objective,
+        constraints: &CompoundConstraint,
+    ) -> VerificationResult<OptimizationOutput> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let spec = self.translate_compound(constraints, &mut var_map, &solver)?;
+        let term = self.parse_right_value(variable, &mut var_map)?;
+        let (value, model) = self.extreme(&spec, &term, objective)?;
+        Ok(OptimizationOutput {
+            variable: variable.to_string(),
+            objective,
+            value,
+            model,
+        })
+    }
 
-        // Otherwise, treat as a variable
-        let var = var_map
-            .entry(right_value.to_string())
-            .or_insert_with(|| z3::ast::Int::new_const(&self.ctx, right_value.to_string()))
-            .clone();
+    /// Minimum and maximum of every integer variable in `constraints`, sorted by name
+    pub fn feasible_ranges(&self, constraints: &CompoundConstraint) -> VerificationResult<Vec<FeasibleRange>> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let spec = self.translate_compound(constraints, &mut var_map, &solver)?;
+        let variables: BTreeMap<_, _> = var_map.into_iter().collect();
 
-        Ok(var)
+        variables
+            .into_iter()
+            .map(|(variable, term)| {
+                let (min, _) = self.extreme(&spec, &term, Objective::Minimize)?;
+                let (max, _) = self.extreme(&spec, &term, Objective::Maximize)?;
+                Ok(FeasibleRange { variable, min, max })
+            })
+            .collect()
     }
 
-    /// Generate SMT-LIB format output for constraints
-    pub fn generate_smt_lib(&self, constraints: &[Constraint]) -> String {
-        let mut smt_lib = String::from("(set-logic QF_LIA)\n");
-        smt_lib.push_str("(set-option :produce-models true)\n\n");
-        
-        // Track declared variables
-        let mut declared_vars: std::collections::HashSet<String> = std::collections::HashSet::new();
-        
-        for constraint in constraints {
-            self.append_constraint_smt(constraint, &mut smt_lib, &mut declared_vars);
+    /// Optimum of `term` under `spec`, with the model attaining it
+    fn extreme(
+        &self,
+        spec: &z3::ast::Bool,
+        term: &z3::ast::Int,
+        objective: Objective,
+    ) -> VerificationResult<(Option<i64>, Option<HashMap<String, String>>)> {
+        let optimizer = Optimize::new(&self.ctx);
+        optimizer.assert(spec);
+        match objective {
+            Objective::Minimize => optimizer.minimize(term),
+            Objective::Maximize => optimizer.maximize(term),
+        }
+        match optimizer.check(&[]) {
+            z3::SatResult::Sat => {}
+            z3::SatResult::Unsat => {
+                return Err(VerificationError::Unsatisfiable(
+                    "Constraints are unsatisfiable; nothing to optimize".to_string(),
+                ))
+            }
+            z3::SatResult::Unknown => {
+                return Err(VerificationError::SolverError(
+                    "Z3 optimizer returned unknown result".to_string(),
+                ))
+            }
+        }
+        let model = optimizer.get_model().ok_or_else(|| {
+            VerificationError::SolverError("Optimizer returned no model".to_string())
+        })?;
+        // Optimum beyond the i64 range
+        let Some(value) = model.eval(term, true).and_then(|v| v.as_i64()) else {
+            return Ok((None, None));
+        };
+
+        // An unbounded objective still comes back `sat` with some model, so
+        // the value is the optimum only if nothing lies beyond it
+        let bound = z3::ast::Int::from_i64(&self.ctx, value);
+        let solver = Solver::new(&self.ctx);
+        solver.assert(spec);
+        solver.assert(&match objective {
+            Objective::Minimize => term.lt(&bound),
+            Objective::Maximize => term.gt(&bound),
+        });
+        match timed_check(&solver) {
+            z3::SatResult::Unsat => {
+                let mut model_map = HashMap::new();
+                for decl in model.get_decls() {
+                    let name = decl.name().to_string();
+                    let value = model.eval(&decl).unwrap();
+                    model_map.insert(name, value.to_string());
+                }
+                Ok((Some(value), Some(model_map)))
+            }
+            z3::SatResult::Sat => Ok((None, None)),
+            z3::SatResult::Unknown => Err(VerificationError::SolverError(
+                "Z3 solver returned unknown result".to_string(),
+            )),
         }
-        
-        smt_lib.push_str("\n(check-sat)\n(get-model)\n");
-        smt_lib
     }
 
-    /// Append a constraint to SMT-LIB output
-    fn append_constraint_smt(
+    /// Classify `candidate` against `existing`: contradicting a subset of it,
+    /// implied by a subset of it, or consistent and new. The subsets are
+    /// minimal, so every index named is needed for the conflict or entailment.
+    #[tracing::instrument(skip_all, fields(existing = existing.len()))]
+    pub fn classify(
         &self,
-        constraint: &Constraint,
-        output: &mut String,
-        declared_vars: &mut std::collections::HashSet<String>,
-    ) {
-        // Declare left variable if not already declared
-        if declared_vars.insert(constraint.left_variable.clone()) {
-            output.push_str(&format!(
-                "(declare-const {} Int)\n",
-                constraint.left_variable
-            ));
+        existing: &[CompoundConstraint],
+        candidate: &CompoundConstraint,
+    ) -> VerificationResult<Consistency> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        // Each existing requirement is switched on by its own assumption literal
+        let mut guards = Vec::with_capacity(existing.len());
+        for (index, compound) in existing.iter().enumerate() {
+            let guard = z3::ast::Bool::new_const(&self.ctx, format!("existing_{}", index));
+            solver.assert(&guard.implies(&self.translate_compound(compound, &mut var_map, &solver)?));
+            guards.push(guard);
         }
+        let candidate = self.translate_compound(candidate, &mut var_map, &solver)?;
 
-        // Declare right variable if it's not a number
-        if constraint.right_value.parse::<i64>().is_err() {
-            if declared_vars.insert(constraint.right_value.clone()) {
-                output.push_str(&format!(
-                    "(declare-const {} Int)\n",
-                    constraint.right_value
-                ));
-            }
+        if let Some(conflict) = self.minimal_core(&solver, &candidate, &guards)? {
+            return Ok(Consistency::Contradicts(conflict));
         }
+        if let Some(premises) = self.minimal_core(&solver, &candidate.not(), &guards)? {
+            return Ok(Consistency::Implied(premises));
+        }
+        Ok(Consistency::Consistent)
+    }
+
+    /// Smallest set of `guards` (by index) under which `goal` is unsatisfiable,
+    /// or `None` when `goal` holds alongside all of them
+    fn minimal_core(
+        &self,
+        solver: &Solver,
+        goal: &z3::ast::Bool,
+        guards: &[z3::ast::Bool],
+    ) -> VerificationResult<Option<Vec<usize>>> {
+        let check = |active: &[usize]| {
+            let assumptions: Vec<z3::ast::Bool> = active.iter().map(|&i| guards[i].clone()).collect();
+            solver.check_assumptions(&assumptions)
+        };
+
+        solver.push();
+        solver.assert(goal);
+        let all: Vec<usize> = (0..guards.len()).collect();
+        let result = match check(&all) {
+            z3::SatResult::Sat => Ok(None),
+            z3::SatResult::Unknown => Err(VerificationError::SolverError(
+                "Z3 solver returned unknown result".to_string(),
+            )),
+            z3::SatResult::Unsat => {
+                let named: Vec<String> = solver.get_unsat_core().iter().map(|b| b.to_string()).collect();
+                let mut core: Vec<usize> = all.into_iter().filter(|&i| named.contains(&guards[i].to_string())).collect();
+                // Z3's core need not be minimal; drop each member that is not needed
+                let mut i = 0;
+                while i < core.len() {
+                    let mut without = core.clone();
+                    without.remove(i);
+                    if check(&without) == z3::SatResult::Unsat {
+                        core = without;
+                    } else {
+                        i += 1;
+                    }
+                }
+                Ok(Some(core))
+            }
+        };
+        solver.pop(1);
+        result
+    }
+
+    /// Check concrete values, e.g. an example transaction, against each constraint.
+    ///
+    /// Unquoted integers bind integer variables; anything else (quotes
+    /// optional) binds the string variable of that name. Variables without a
+    /// binding stay free, so a constraint over them may be `Undetermined`.
+    #[tracing::instrument(skip_all, fields(bindings = bindings.len(), constraints = constraints.len()))]
+    pub fn check_scenario(
+        &self,
+        bindings: &BTreeMap<String, String>,
+        constraints: &[CompoundConstraint],
+    ) -> VerificationResult<Vec<ScenarioCheck>> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        for (name, value) in bindings {
+            if let Ok(number) = value.parse::<i64>() {
+                let var = var_map
+                    .entry(name.clone())
+                    .or_insert_with(|| z3::ast::Int::new_const(&self.ctx, name.as_str()));
+                solver.assert(&var._eq(&z3::ast::Int::from_i64(&self.ctx, number)));
+                continue;
+            }
+            let text = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+            let literal = z3::ast::String::from_str(&self.ctx, text).map_err(|e| {
+                VerificationError::TranslationError(format!("Invalid value for {}: {}", name, e))
+            })?;
+            solver.assert(&z3::ast::String::new_const(&self.ctx, name.as_str())._eq(&literal));
+        }
+
+        let mut checks = Vec::with_capacity(constraints.len());
+        for (index, compound) in constraints.iter().enumerate() {
+            let verdict = self.scenario_verdict(compound, &mut var_map, &solver)?;
+            let mut violations = Vec::new();
+            if verdict == ScenarioVerdict::Violated {
+                self.collect_violations(compound, &mut var_map, &solver, &mut violations)?;
+            }
+            checks.push(ScenarioCheck {
+                index,
+                verdict,
+                violations,
+            });
+        }
+        Ok(checks)
+    }
+
+    /// Whether `compound` must, cannot or may hold given the bindings asserted on `solver`
+    fn scenario_verdict(
+        &self,
+        compound: &CompoundConstraint,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+    ) -> VerificationResult<ScenarioVerdict> {
+        let expr = self.translate_compound(compound, var_map, solver)?;
+        let possible = |expr: &z3::ast::Bool| {
+            solver.push();
+            solver.assert(expr);
+            let result = timed_check(solver);
+            solver.pop(1);
+            result
+        };
+        match (possible(&expr), possible(&expr.not())) {
+            (_, z3::SatResult::Unsat) => Ok(ScenarioVerdict::Satisfied),
+            (z3::SatResult::Unsat, _) => Ok(ScenarioVerdict::Violated),
+            (z3::SatResult::Sat, z3::SatResult::Sat) => Ok(ScenarioVerdict::Undetermined),
+            _ => Err(VerificationError::SolverError(
+                "Z3 solver returned unknown result".to_string(),
+            )),
+        }
+    }
+
+    /// Narrow a violated constraint to the parts responsible: the violated
+    /// conjuncts of an AND and the consequent of an implication are explained
+    /// in turn; any other node is reported whole
+    fn collect_violations(
+        &self,
+        compound: &CompoundConstraint,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+        out: &mut Vec<String>,
+    ) -> VerificationResult<()> {
+        let found = out.len();
+        match compound {
+            CompoundConstraint::And(parts) => {
+                for part in parts {
+                    if self.scenario_verdict(part, var_map, solver)? == ScenarioVerdict::Violated {
+                        self.collect_violations(part, var_map, solver, out)?;
+                    }
+                }
+            }
+            CompoundConstraint::Implies(_, consequent) => {
+                self.collect_violations(consequent, var_map, solver, out)?;
+            }
+            _ => {}
+        }
+        // Also when no single conjunct is violated on its own
+        if out.len() == found {
+            out.push(compound.to_string());
+        }
+        Ok(())
+    }
+
+    /// Check each guarantee under the assumptions (A ⊢ G) instead of one flat SAT query.
+    ///
+    /// Assumptions must be consistent on their own. Invariants are checked like
+    /// guarantees and, being true in every state, are also added to the
+    /// hypotheses of the guarantee checks.
+    #[tracing::instrument(skip_all, fields(requirements = requirements.len()))]
+    pub fn verify_contract(
+        &self,
+        requirements: &[(RequirementKind, CompoundConstraint)],
+    ) -> VerificationResult<ContractVerificationOutput> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+
+        let mut translated = Vec::with_capacity(requirements.len());
+        for (kind, compound) in requirements {
+            translated.push((*kind, self.translate_compound(compound, &mut var_map, &solver)?));
+        }
+
+        let assumptions: Vec<&z3::ast::Bool> = translated
+            .iter()
+            .filter(|(kind, _)| *kind == RequirementKind::Assumption)
+            .map(|(_, expr)| expr)
+            .collect();
+        for assumption in &assumptions {
+            solver.assert(*assumption);
+        }
+        if timed_check(&solver) == z3::SatResult::Unsat {
+            return Err(VerificationError::Unsatisfiable(
+                "Assumptions contradict each other".to_string(),
+            ));
+        }
+
+        let mut checks = Vec::new();
+        for kind in [RequirementKind::Invariant, RequirementKind::Guarantee] {
+            for (index, (_, expr)) in translated.iter().enumerate().filter(|(_, (k, _))| *k == kind) {
+                solver.push();
+                solver.assert(&expr.not());
+                let (status, counterexample) = match timed_check(&solver) {
+                    z3::SatResult::Unsat => (GuaranteeStatus::Entailed, None),
+                    z3::SatResult::Sat => {
+                        let counterexample = solver.get_model().map(|m| {
+                            m.get_decls()
+                                .into_iter()
+                                .map(|decl| (decl.name().to_string(), m.eval(&decl).unwrap().to_string()))
+                                .collect()
+                        });
+                        solver.pop(1);
+                        solver.push();
+                        solver.assert(expr);
+                        let status = match timed_check(&solver) {
+                            z3::SatResult::Unsat => GuaranteeStatus::Contradicted,
+                            _ => GuaranteeStatus::Enforceable,
+                        };
+                        (status, counterexample)
+                    }
+                    z3::SatResult::Unknown => {
+                        return Err(VerificationError::SolverError(
+                            "Z3 solver returned unknown result".to_string(),
+                        ))
+                    }
+                };
+                solver.pop(1);
+                checks.push(GuaranteeCheck {
+                    index,
+                    kind,
+                    status,
+                    counterexample,
+                });
+            }
+
+            // Invariants hold in every state, so guarantees may rely on them
+            if kind == RequirementKind::Invariant {
+                for (_, expr) in translated.iter().filter(|(k, _)| *k == RequirementKind::Invariant) {
+                    solver.assert(expr);
+                }
+            }
+        }
+        checks.sort_by_key(|c| c.index);
+
+        Ok(ContractVerificationOutput {
+            assumptions_count: assumptions.len(),
+            checks,
+        })
+    }
+
+    /// Check that `producer` can be plugged into `consumer`: whatever the
+    /// producer may deliver (its guarantees, under its own assumptions)
+    /// must meet every assumption of the consumer.
+    ///
+    /// Each consumer assumption is checked on its own; the ones that do not
+    /// follow are the gaps, each with a producer output that breaks it. A
+    /// producer whose contract contradicts itself promises nothing and is an
+    /// `Unsatisfiable` error.
+    #[tracing::instrument(skip_all, fields(producer = %producer.name, consumer = %consumer.name))]
+    pub fn check_compatibility(
+        &self,
+        producer: &ComponentContract,
+        consumer: &ComponentContract,
+    ) -> VerificationResult<CompatibilityOutput> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        for tree in producer.assumptions.iter().chain(&producer.guarantees) {
+            solver.assert(&self.translate_compound(tree, &mut var_map, &solver)?);
+        }
+        if timed_check(&solver) == z3::SatResult::Unsat {
+            return Err(VerificationError::Unsatisfiable(format!(
+                "The contract of `{}` contradicts itself",
+                producer.name
+            )));
+        }
+
+        let promised = producer.fields();
+        let mut gaps = Vec::new();
+        for (assumption, tree) in consumer.assumptions.iter().enumerate() {
+            solver.push();
+            solver.assert(&self.translate_compound(tree, &mut var_map, &solver)?.not());
+            match timed_check(&solver) {
+                z3::SatResult::Unsat => {}
+                z3::SatResult::Sat => {
+                    let mut unconstrained: Vec<String> = tree
+                        .leaves()
+                        .into_iter()
+                        .flat_map(|constraint| constraint.variables())
+                        .filter(|field| !promised.contains(field))
+                        .collect();
+                    unconstrained.sort();
+                    unconstrained.dedup();
+                    gaps.push(InterfaceGap {
+                        assumption,
+                        counterexample: solver.get_model().map(|m| {
+                            m.get_decls()
+                                .into_iter()
+                                .map(|decl| (decl.name().to_string(), m.eval(&decl).unwrap().to_string()))
+                                .collect()
+                        }),
+                        unconstrained,
+                    });
+                }
+                z3::SatResult::Unknown => {
+                    return Err(VerificationError::SolverError(
+                        "Z3 solver returned unknown result".to_string(),
+                    ))
+                }
+            }
+            solver.pop(1);
+        }
+
+        Ok(CompatibilityOutput {
+            assumptions_count: consumer.assumptions.len(),
+            gaps,
+        })
+    }
+
+    /// Classify `spec_v2` against `spec_v1` by what their binding
+    /// requirements allow, not by how they are worded; see `compare_specs`.
+    #[tracing::instrument(skip_all)]
+    pub fn check_refinement(&self, spec_v1: &IntentAst, spec_v2: &IntentAst) -> VerificationResult<RefinementOutput> {
+        self.compare_specs(&spec_v1.binding_constraints(), &spec_v2.binding_constraints())
+    }
+
+    /// Compare the conjunction of `before` with the conjunction of `after`.
+    ///
+    /// Two queries decide the relation: `before ∧ ¬after` (values the change
+    /// rejects) and `after ∧ ¬before` (values it allows). Each satisfiable
+    /// query comes with a model as its witness.
+    pub fn compare_specs(
+        &self,
+        before: &[CompoundConstraint],
+        after: &[CompoundConstraint],
+    ) -> VerificationResult<RefinementOutput> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let old = self.translate_compound(&CompoundConstraint::And(before.to_vec()), &mut var_map, &solver)?;
+        let new = self.translate_compound(&CompoundConstraint::And(after.to_vec()), &mut var_map, &solver)?;
+
+        let witness = |holds: &z3::ast::Bool, fails: &z3::ast::Bool| {
+            solver.push();
+            solver.assert(holds);
+            solver.assert(&fails.not());
+            let found = match timed_check(&solver) {
+                z3::SatResult::Unsat => Ok(None),
+                z3::SatResult::Sat => Ok(Some(solver.get_model().map_or_else(HashMap::new, |m| {
+                    m.get_decls()
+                        .into_iter()
+                        .map(|decl| (decl.name().to_string(), m.eval(&decl).unwrap().to_string()))
+                        .collect()
+                }))),
+                z3::SatResult::Unknown => Err(VerificationError::SolverError(
+                    "Z3 solver returned unknown result".to_string(),
+                )),
+            };
+            solver.pop(1);
+            found
+        };
+        let newly_rejected = witness(&old, &new)?;
+        let newly_allowed = witness(&new, &old)?;
+
+        let relation = match (&newly_rejected, &newly_allowed) {
+            (None, None) => Refinement::Equivalent,
+            (Some(_), None) => Refinement::Stronger,
+            (None, Some(_)) => Refinement::Weaker,
+            (Some(_), Some(_)) => Refinement::Incomparable,
+        };
+        Ok(RefinementOutput {
+            relation,
+            newly_rejected,
+            newly_allowed,
+        })
+    }
+
+    /// Verify a state transition against its frame and postcondition.
+    ///
+    /// The precondition, the updates (`x' == expr`) and the frame condition
+    /// (`y' == y` for every other state variable) must be satisfiable
+    /// together; then the postcondition checks must follow from them.
+    #[tracing::instrument(skip_all, fields(action = %transition.action))]
+    pub fn verify_transition(
+        &self,
+        transition: &StateTransition,
+        state_variables: &[String],
+    ) -> VerificationResult<TransitionVerificationOutput> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let frame = self.assert_transition(transition, state_variables, &mut var_map, &solver)?;
+
+        if timed_check(&solver) == z3::SatResult::Unsat {
+            return Err(VerificationError::Unsatisfiable(format!(
+                "Precondition, updates and frame of `{}` are inconsistent",
+                transition.action
+            )));
+        }
+
+        let (postcondition_holds, counterexample) = match transition.post_checks() {
+            None => (true, None),
+            Some(checks) => {
+                solver.assert(&self.translate_compound(&checks, &mut var_map, &solver)?.not());
+                match timed_check(&solver) {
+                    z3::SatResult::Unsat => (true, None),
+                    _ => (
+                        false,
+                        solver.get_model().map(|m| {
+                            m.get_decls()
+                                .into_iter()
+                                .map(|decl| (decl.name().to_string(), m.eval(&decl).unwrap().to_string()))
+                                .collect()
+                        }),
+                    ),
+                }
+            }
+        };
+
+        Ok(TransitionVerificationOutput {
+            postcondition_holds,
+            counterexample,
+            framed_variables: frame.into_iter().map(|c| c.right_value).collect(),
+        })
+    }
+
+    /// Check that every action preserves every invariant (an inductive step).
+    ///
+    /// For each transition, the invariants are assumed in the pre-state
+    /// together with the precondition, updates and frame; each invariant
+    /// must then hold over the primed post-state. A failing pair comes with
+    /// the pre- and post-state that breaks it. Actions that can never fire
+    /// from a state satisfying the invariants preserve them vacuously and
+    /// are listed as disabled.
+    #[tracing::instrument(skip_all, fields(invariants = invariants.len(), actions = transitions.len()))]
+    pub fn verify_invariant_preservation(
+        &self,
+        invariants: &[CompoundConstraint],
+        transitions: &[StateTransition],
+        state_variables: &[String],
+    ) -> VerificationResult<InvariantPreservationOutput> {
+        let mut checks = Vec::with_capacity(invariants.len() * transitions.len());
+        let mut disabled_actions = Vec::new();
+        for transition in transitions {
+            let solver = Solver::new(&self.ctx);
+            let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+            for invariant in invariants {
+                solver.assert(&self.translate_compound(invariant, &mut var_map, &solver)?);
+            }
+            self.assert_transition(transition, state_variables, &mut var_map, &solver)?;
+            let enabled = timed_check(&solver) != z3::SatResult::Unsat;
+            if !enabled {
+                disabled_actions.push(transition.action.clone());
+            }
+
+            for (invariant, expr) in invariants.iter().enumerate() {
+                let mut counterexample = None;
+                if enabled {
+                    let post = crucible_core::prime_state(expr, state_variables);
+                    solver.push();
+                    solver.assert(&self.translate_compound(&post, &mut var_map, &solver)?.not());
+                    match timed_check(&solver) {
+                        z3::SatResult::Unsat => {}
+                        z3::SatResult::Sat => {
+                            counterexample = Some(
+                                solver
+                                    .get_model()
+                                    .map(|m| {
+                                        m.get_decls()
+                                            .into_iter()
+                                            .map(|decl| {
+                                                (decl.name().to_string(), m.eval(&decl).unwrap().to_string())
+                                            })
+                                            .collect()
+                                    })
+                                    .unwrap_or_default(),
+                            );
+                        }
+                        z3::SatResult::Unknown => {
+                            return Err(VerificationError::SolverError(format!(
+                                "Z3 solver returned unknown result for `{}` and invariant {}",
+                                transition.action, invariant
+                            )))
+                        }
+                    }
+                    solver.pop(1);
+                }
+                checks.push(PreservationCheck {
+                    action: transition.action.clone(),
+                    invariant,
+                    preserved: counterexample.is_none(),
+                    counterexample,
+                });
+            }
+        }
+
+        Ok(InvariantPreservationOutput {
+            checks,
+            disabled_actions,
+        })
+    }
+
+    /// Bounded model checking: can `property` fail within `k` actions?
+    ///
+    /// The action system is unrolled `k` steps from a state satisfying
+    /// `init`; each step takes one action whose precondition holds, with
+    /// fresh inputs (`amount`) per step. State variables are those some
+    /// action updates or `init` and `property` read; the rest are inputs.
+    /// `property` is checked in every state of the unrolling, so the trace
+    /// returned is a shortest one reaching a bad state.
+    #[tracing::instrument(skip_all, fields(actions = actions.len(), k))]
+    pub fn check_trace(
+        &self,
+        init: &CompoundConstraint,
+        actions: &[StateTransition],
+        k: usize,
+        property: &CompoundConstraint,
+    ) -> VerificationResult<TraceCheckOutput> {
+        let mut state_variables: Vec<String> = Vec::new();
+        let mut leaves = Vec::new();
+        collect_simple(init, &mut leaves);
+        collect_simple(property, &mut leaves);
+        let read = leaves.iter().flat_map(|c| c.variables());
+        let mut updated = Vec::new();
+        let mut action_updates = Vec::with_capacity(actions.len());
+        for action in actions {
+            let updates = action
+                .updates()
+                .map_err(|e| VerificationError::TranslationError(e.to_string()))?;
+            updated.extend(updates.iter().map(|u| u.variable.clone()));
+            action_updates.push(updates);
+        }
+        for variable in updated.into_iter().chain(read) {
+            if !state_variables.contains(&variable) {
+                state_variables.push(variable);
+            }
+        }
+
+        // Action `j` as one step: precondition, updates and frame
+        let mut relations = Vec::with_capacity(actions.len());
+        for (action, updates) in actions.iter().zip(&action_updates) {
+            let mut parts = vec![action.pre.clone()];
+            for update in updates {
+                parts.push(CompoundConstraint::Simple(Constraint {
+                    left_variable: crucible_core::transition::primed(&update.variable),
+                    operator: ConstraintOperator::Equal,
+                    right_value: update.expression.to_string(),
+                }));
+            }
+            let frame = action
+                .frame(&state_variables)
+                .map_err(|e| VerificationError::TranslationError(e.to_string()))?;
+            parts.extend(frame.into_iter().map(CompoundConstraint::Simple));
+            relations.push(CompoundConstraint::And(parts));
+        }
+
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        solver.assert(&self.translate_compound(&at_step(init, 0), &mut var_map, &solver)?);
+        for depth in 0..=k {
+            if depth > 0 {
+                let step = depth - 1;
+                let choices = relations
+                    .iter()
+                    .enumerate()
+                    .map(|(index, relation)| {
+                        let chosen = CompoundConstraint::Simple(Constraint {
+                            left_variable: format!("{}{}", TRACE_ACTION, step),
+                            operator: ConstraintOperator::Equal,
+                            right_value: index.to_string(),
+                        });
+                        CompoundConstraint::And(vec![chosen, at_step(relation, step)])
+                    })
+                    .collect();
+                solver.assert(&self.translate_compound(&CompoundConstraint::Or(choices), &mut var_map, &solver)?);
+            }
+
+            solver.push();
+            let holds = at_step(property, depth);
+            solver.assert(&self.translate_compound(&holds, &mut var_map, &solver)?.not());
+            match timed_check(&solver) {
+                z3::SatResult::Unsat => solver.pop(1),
+                z3::SatResult::Sat => {
+                    let model: HashMap<String, String> = solver
+                        .get_model()
+                        .map(|m| {
+                            m.get_decls()
+                                .into_iter()
+                                .map(|decl| (decl.name().to_string(), m.eval(&decl).unwrap().to_string()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    solver.pop(1);
+                    return Ok(TraceCheckOutput {
+                        bound: k,
+                        violation: Some(trace(&model, actions, &state_variables, depth)),
+                    });
+                }
+                z3::SatResult::Unknown => {
+                    return Err(VerificationError::SolverError(format!(
+                        "Z3 solver returned unknown result at depth {}",
+                        depth
+                    )))
+                }
+            }
+        }
+
+        Ok(TraceCheckOutput { bound: k, violation: None })
+    }
+
+    /// Assert the precondition, the updates (`x' == expr`) and the frame of
+    /// `transition`; returns the frame
+    fn assert_transition(
+        &self,
+        transition: &StateTransition,
+        state_variables: &[String],
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+    ) -> VerificationResult<Vec<Constraint>> {
+        let updates = transition
+            .updates()
+            .map_err(|e| VerificationError::TranslationError(e.to_string()))?;
+        let frame = transition
+            .frame(state_variables)
+            .map_err(|e| VerificationError::TranslationError(e.to_string()))?;
+
+        solver.assert(&self.translate_compound(&transition.pre, var_map, solver)?);
+        for update in &updates {
+            let assignment = Constraint {
+                left_variable: crucible_core::transition::primed(&update.variable),
+                operator: ConstraintOperator::Equal,
+                right_value: update.expression.to_string(),
+            };
+            solver.assert(&self.translate_constraint(&assignment, var_map, solver)?);
+        }
+        for constraint in &frame {
+            solver.assert(&self.translate_constraint(constraint, var_map, solver)?);
+        }
+        Ok(frame)
+    }
+
+    /// Check reachability and deadlock-freedom of a state machine.
+    ///
+    /// Reachability only follows transitions whose guard is satisfiable. A
+    /// reachable, non-final state deadlocks when some data valuation falsifies
+    /// every outgoing guard (`¬(g1 ∨ g2 ∨ ...)` is satisfiable).
+    #[tracing::instrument(skip_all, fields(states = machine.states.len()))]
+    pub fn verify_state_machine(&self, machine: &StateMachine) -> VerificationResult<StateMachineVerificationOutput> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+
+        let mut guards = Vec::with_capacity(machine.transitions.len());
+        for transition in &machine.transitions {
+            guards.push(match &transition.guard {
+                Some(guard) => self.translate_compound(guard, &mut var_map, &solver)?,
+                None => z3::ast::Bool::from_bool(&self.ctx, true),
+            });
+        }
+
+        let mut dead_transitions = Vec::new();
+        for (index, guard) in guards.iter().enumerate() {
+            solver.push();
+            solver.assert(guard);
+            if timed_check(&solver) == z3::SatResult::Unsat {
+                dead_transitions.push(index);
+            }
+            solver.pop(1);
+        }
+
+        let enabled = |t: &crucible_core::MachineTransition| {
+            let index = machine.transitions.iter().position(|other| std::ptr::eq(other, t));
+            index.is_some_and(|i| !dead_transitions.contains(&i))
+        };
+        let unreachable_states = machine.unreachable_states(enabled);
+        let reachable = machine.reachable_states(enabled);
+
+        let mut deadlocks = Vec::new();
+        for state in machine.states.iter().filter(|s| reachable.contains(*s)) {
+            if machine.final_states.contains(state) {
+                continue;
+            }
+            let outgoing: Vec<&z3::ast::Bool> = machine
+                .transitions
+                .iter()
+                .zip(&guards)
+                .filter(|(t, _)| &t.from == state)
+                .map(|(_, g)| g)
+                .collect();
+
+            solver.push();
+            if !outgoing.is_empty() {
+                solver.assert(&z3::ast::Bool::or(&self.ctx, &outgoing).not());
+            }
+            if timed_check(&solver) == z3::SatResult::Sat {
+                let witness = solver.get_model().map(|m| {
+                    m.get_decls()
+                        .into_iter()
+                        .map(|decl| (decl.name().to_string(), m.eval(&decl).unwrap().to_string()))
+                        .collect()
+                });
+                deadlocks.push(Deadlock {
+                    state: state.clone(),
+                    witness,
+                });
+            }
+            solver.pop(1);
+        }
+
+        Ok(StateMachineVerificationOutput {
+            dead_transitions,
+            unreachable_states,
+            deadlocks,
+        })
+    }
+
+    /// Inputs on and next to the edge of every comparison in `compound`.
+    ///
+    /// For each `left op right`, Z3 finds non-negative values (the generated
+    /// Solidity parameters are unsigned) with `left - right` equal to -1, 0
+    /// and 1; the whole constraint is evaluated in that model for the
+    /// expected verdict.
+    pub fn boundary_cases(&self, compound: &CompoundConstraint) -> VerificationResult<Vec<BoundaryCase>> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let whole = self.translate_compound(compound, &mut var_map, &solver)?;
+
+        let zero = z3::ast::Int::from_i64(&self.ctx, 0);
+        for var in var_map.values() {
+            solver.assert(&var.ge(&zero));
+        }
+
+        let mut leaves = Vec::new();
+        collect_simple(compound, &mut leaves);
+
+        let mut cases = Vec::new();
+        // Containment has no numeric edge
+        for leaf in leaves.into_iter().filter(|leaf| !leaf.operator.is_membership()) {
+            let left = self.parse_right_value(&leaf.left_variable, &mut var_map)?;
+            let right = self.parse_right_value(&leaf.right_value, &mut var_map)?;
+            let difference = z3::ast::Int::sub(&self.ctx, &[&left, &right]);
+
+            for delta in [-1, 0, 1] {
+                solver.push();
+                solver.assert(&difference._eq(&z3::ast::Int::from_i64(&self.ctx, delta)));
+                if timed_check(&solver) == z3::SatResult::Sat {
+                    if let Some(model) = solver.get_model() {
+                        let values: BTreeMap<String, i64> = var_map
+                            .iter()
+                            .map(|(name, var)| {
+                                (name.clone(), model.eval(var, true).and_then(|v| v.as_i64()).unwrap_or(0))
+                            })
+                            .collect();
+                        let expected = model.eval(&whole, true).and_then(|v| v.as_bool()).unwrap_or(false);
+                        let case = BoundaryCase { values, expected };
+                        if !cases.contains(&case) {
+                            cases.push(case);
+                        }
+                    }
+                }
+                solver.pop(1);
+            }
+        }
+        Ok(cases)
+    }
+
+    /// For each top-level constraint of `compound`, an input that violates it
+    /// and satisfies the others: a model of `And(others) ∧ ¬this`.
+    ///
+    /// A constraint the others imply has no such input, so it gets no case;
+    /// neither does any constraint of a tree that compares strings, whose
+    /// models are not all integers.
+    pub fn fault_cases(&self, compound: &CompoundConstraint) -> VerificationResult<Vec<FaultCase>> {
+        let mut leaves = Vec::new();
+        collect_simple(compound, &mut leaves);
+        if leaves.iter().any(|leaf| leaf.operator.is_membership()) {
+            return Ok(Vec::new());
+        }
+
+        let conjuncts: Vec<&CompoundConstraint> = match compound {
+            CompoundConstraint::And(parts) => parts.iter().collect(),
+            other => vec![other],
+        };
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let terms = conjuncts
+            .iter()
+            .map(|conjunct| self.translate_compound(conjunct, &mut var_map, &solver))
+            .collect::<VerificationResult<Vec<_>>>()?;
+
+        let mut cases = Vec::new();
+        for index in 0..terms.len() {
+            solver.push();
+            for (other, term) in terms.iter().enumerate() {
+                solver.assert(&if other == index { term.not() } else { term.clone() });
+            }
+            if timed_check(&solver) == z3::SatResult::Sat {
+                if let Some(model) = solver.get_model() {
+                    let values: BTreeMap<String, i64> = var_map
+                        .iter()
+                        .map(|(name, var)| (name.clone(), model.eval(var, true).and_then(|v| v.as_i64()).unwrap_or(0)))
+                        .collect();
+                    cases.push(FaultCase { constraint: index, values });
+                }
+            }
+            solver.pop(1);
+        }
+        Ok(cases)
+    }
+
+    /// Arithmetic terms of `compound` that can leave the type the target
+    /// computes them in, before generation picks an operation for each.
+    ///
+    /// `widths` gives each integer field its type in the target (see
+    /// `TargetLanguage::integer_widths`); inputs range over those types, and a
+    /// term is as wide as its first operand field in `widths`, so a term over
+    /// no such field is unbounded there and never at risk. A term is only
+    /// reached when the validator evaluates it: the earlier conjuncts of an
+    /// `AND` hold, the earlier disjuncts of an `OR` fail and the antecedent of
+    /// an implication holds. Both branches of a conditional value count as
+    /// reached, which can report a risk the condition rules out.
+    pub fn overflow_risks(
+        &self,
+        compound: &CompoundConstraint,
+        widths: &HashMap<String, BitWidth>,
+    ) -> VerificationResult<Vec<OverflowRisk>> {
+        let solver = Solver::new(&self.ctx);
+        let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+        let mut reached = Vec::new();
+        let always = z3::ast::Bool::from_bool(&self.ctx, true);
+        self.reached_terms(compound, always, &mut var_map, &solver, &mut reached)?;
+
+        let bound = |value: i128| {
+            z3::ast::Int::from_str(&self.ctx, &value.to_string())
+                .ok_or_else(|| VerificationError::TranslationError(format!("Invalid bound {}", value)))
+        };
+        let mut terms = Vec::new();
+        for (path, term, constraint) in reached {
+            let Some(width) = term.fields().iter().find_map(|field| widths.get(*field)).copied() else {
+                continue;
+            };
+            terms.push((path, self.parse_right_value(&term.to_string(), &mut var_map)?, term, constraint, width));
+        }
+        for (field, var) in &var_map {
+            if let Some(width) = widths.get(field) {
+                solver.assert(&var.ge(&bound(width.min())?));
+                solver.assert(&var.le(&bound(width.max())?));
+            }
+        }
+
+        let mut risks: Vec<OverflowRisk> = Vec::new();
+        for (path, value, term, constraint, width) in terms {
+            let fields: Vec<String> = term.fields().iter().map(|field| field.to_string()).collect();
+            let (term, constraint) = (term.to_string(), constraint.to_string());
+            if risks.iter().any(|risk| risk.term == term && risk.constraint == constraint) {
+                continue;
+            }
+            solver.push();
+            solver.assert(&path);
+            solver.assert(&z3::ast::Bool::or(
+                &self.ctx,
+                &[&value.lt(&bound(width.min())?), &value.gt(&bound(width.max())?)],
+            ));
+            if timed_check(&solver) == z3::SatResult::Sat {
+                if let Some(model) = solver.get_model() {
+                    let witness = fields
+                        .into_iter()
+                        .filter_map(|field| {
+                            let value = model.eval(var_map.get(&field)?, true)?;
+                            Some((field, value.to_string()))
+                        })
+                        .collect();
+                    risks.push(OverflowRisk { term, constraint, width, witness });
+                }
+            }
+            solver.pop(1);
+        }
+        Ok(risks)
+    }
+
+    /// The arithmetic terms of `tree` with the condition under which the
+    /// validator evaluates each, `path` holding on the way in
+    fn reached_terms(
+        &self,
+        tree: &CompoundConstraint,
+        path: z3::ast::Bool,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+        reached: &mut Vec<(z3::ast::Bool, ArithmeticTerm, Constraint)>,
+    ) -> VerificationResult<()> {
+        match tree {
+            CompoundConstraint::Simple(constraint) => {
+                for term in ArithmeticTerm::in_constraint(constraint) {
+                    reached.push((path.clone(), term, constraint.clone()));
+                }
+            }
+            // Short-circuiting: a later part runs once the earlier ones decided nothing
+            CompoundConstraint::And(parts) | CompoundConstraint::Or(parts) => {
+                let mut path = path;
+                for part in parts {
+                    self.reached_terms(part, path.clone(), var_map, solver, reached)?;
+                    let holds = self.translate_compound(part, var_map, solver)?;
+                    let undecided = match tree {
+                        CompoundConstraint::And(_) => holds,
+                        _ => holds.not(),
+                    };
+                    path = z3::ast::Bool::and(&self.ctx, &[&path, &undecided]);
+                }
+            }
+            CompoundConstraint::Implies(antecedent, consequent) => {
+                self.reached_terms(antecedent, path.clone(), var_map, solver, reached)?;
+                let holds = self.translate_compound(antecedent, var_map, solver)?;
+                let path = z3::ast::Bool::and(&self.ctx, &[&path, &holds]);
+                self.reached_terms(consequent, path, var_map, solver, reached)?;
+            }
+            CompoundConstraint::ExactlyOne(parts) | CompoundConstraint::AtMostOne(parts) => {
+                for part in parts {
+                    self.reached_terms(part, path.clone(), var_map, solver, reached)?;
+                }
+            }
+            CompoundConstraint::Not(inner) => self.reached_terms(inner, path, var_map, solver, reached)?,
+            CompoundConstraint::Distinct(_) | CompoundConstraint::Named(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Translate a simple constraint to a Z3 expression
+    fn translate_constraint<C: Into<Constraint>>(
+        &self,
+        constraint: &C,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+    ) -> VerificationResult<z3::ast::Bool> {
+        let constraint = constraint.clone().into();
+        // A comparison of doubles is defined in SMT-LIB, which has every
+        // rounding mode, and referred to by name
+        let floats = self.floats.borrow();
+        if floats.covers(&constraint) {
+            let name = format!("{}{}", FLOAT_COMPARISON, constraint);
+            solver.from_string(floats.define(&name, &constraint)?);
+            return Ok(z3::ast::Bool::new_const(&self.ctx, name));
+        }
+        self.translate_comparison(&constraint, var_map)
+    }
+
+    /// `left op right` as a Z3 Bool; also the condition of a conditional value
+    fn translate_comparison(
+        &self,
+        constraint: &Constraint,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+    ) -> VerificationResult<z3::ast::Bool> {
+        // Containment is over strings; Z3 identifies constants by name and sort
+        if constraint.operator.is_membership() {
+            let haystack = z3::ast::String::new_const(&self.ctx, constraint.left_variable.as_str());
+            let needle = self.parse_string_value(&constraint.right_value)?;
+            let contains = haystack.contains(&needle);
+            return Ok(match constraint.operator {
+                ConstraintOperator::DoesNotContain => contains.not(),
+                _ => contains,
+            });
+        }
+        
+        // Get or create the left variable; `id % 10` becomes Z3's (Euclidean) `mod`
+        let left_var = match (constraint.modulo(), constraint.bitwise()) {
+            (Some(m), _) => {
+                let dividend = self.parse_right_value(&m.dividend, var_map)?;
+                dividend.modulo(&self.parse_right_value(&m.divisor, var_map)?)
+            }
+            (None, Some(bitwise)) => self.translate_bitwise(&bitwise, var_map)?,
+            (None, None) if Call::parse(&constraint.left_variable).is_some() => {
+                self.parse_right_value(&constraint.left_variable, var_map)?
+            }
+            (None, None) => var_map
+                .entry(constraint.left_variable.clone())
+                .or_insert_with(|| z3::ast::Int::new_const(&self.ctx, constraint.left_variable.as_str()))
+                .clone(),
+        };
+
+        // Parse the right value as an integer or variable
+        let right_expr = self.parse_right_value(&constraint.right_value, var_map)?;
+
+        // Map the operator to Z3 expression
+        match constraint.operator {
+            ConstraintOperator::GreaterThanOrEqual => Ok(left_var.ge(&right_expr)),
+            ConstraintOperator::LessThanOrEqual => Ok(left_var.le(&right_expr)),
+            ConstraintOperator::GreaterThan => Ok(left_var.gt(&right_expr)),
+            ConstraintOperator::LessThan => Ok(left_var.lt(&right_expr)),
+            ConstraintOperator::Equal => Ok(left_var._eq(&right_expr)),
+            ConstraintOperator::NotEqual => Ok(left_var._eq(&right_expr).not()),
+            ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => unreachable!("handled above"),
+        }
+    }
+
+    /// `operand op mask` over bit-vectors as wide as the operand's field,
+    /// read back as a signed or unsigned integer like the field. Integers
+    /// outside the width wrap on the way in, as they would in the field
+    fn translate_bitwise(
+        &self,
+        bitwise: &Bitwise,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+    ) -> VerificationResult<z3::ast::Int> {
+        let width = self.widths.borrow().get(&bitwise.operand).copied().unwrap_or(BitWidth::DEFAULT);
+        let operand = self.parse_right_value(&bitwise.operand, var_map)?;
+        let mask = match bitwise.mask_value() {
+            Some(value) => z3::ast::Int::from_str(&self.ctx, &value.to_string())
+                .ok_or_else(|| VerificationError::TranslationError(format!("Invalid mask in `{}`", bitwise)))?,
+            None => self.parse_right_value(&bitwise.mask, var_map)?,
+        };
+        let (operand, mask) = (z3::ast::BV::from_int(&operand, width.bits), z3::ast::BV::from_int(&mask, width.bits));
+        let bits = match bitwise.operator {
+            BitwiseOperator::And => operand.bvand(&mask),
+            BitwiseOperator::Or => operand.bvor(&mask),
+            BitwiseOperator::Xor => operand.bvxor(&mask),
+            BitwiseOperator::ShiftLeft => operand.bvshl(&mask),
+            BitwiseOperator::ShiftRight if width.signed => operand.bvashr(&mask),
+            BitwiseOperator::ShiftRight => operand.bvlshr(&mask),
+        };
+        Ok(bits.to_int(width.signed))
+    }
+
+    /// A quoted string literal, or a string variable
+    fn parse_string_value(&self, value: &str) -> VerificationResult<z3::ast::String> {
+        match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(literal) => z3::ast::String::from_str(&self.ctx, literal)
+                .map_err(|e| VerificationError::TranslationError(format!("Invalid string literal {}: {}", value, e))),
+            None => Ok(z3::ast::String::new_const(&self.ctx, value)),
+        }
+    }
+
+    /// Translate a compound constraint (AND/OR/NOT tree). The tree is interned
+    /// into a `ConstraintArena` first, so a subtree repeated across the spec
+    /// is translated once and shared in the Z3 term.
+    fn translate_compound(
+        &self,
+        compound: &CompoundConstraint,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+    ) -> VerificationResult<z3::ast::Bool> {
+        let (arena, root) = ConstraintArena::from_tree(compound);
+        let mut translated = vec![None; arena.len()];
+        self.translate_node(&arena, root, var_map, solver, &mut translated)
+    }
+
+    /// Translate one arena node, memoized by `NodeId`
+    fn translate_node(
+        &self,
+        arena: &ConstraintArena,
+        id: NodeId,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+        solver: &Solver,
+        translated: &mut Vec<Option<z3::ast::Bool>>,
+    ) -> VerificationResult<z3::ast::Bool> {
+        if let Some(expr) = &translated[id.index()] {
+            return Ok(expr.clone());
+        }
+        let list = |children, var_map: &mut HashMap<String, z3::ast::Int>, translated: &mut Vec<_>| {
+            arena
+                .children(children)
+                .iter()
+                .map(|&child| self.translate_node(arena, child, var_map, solver, translated))
+                .collect::<VerificationResult<Vec<z3::ast::Bool>>>()
+        };
+        let expr = match arena.node(id) {
+            Node::And(children) => {
+                let z3_constraints = list(children, var_map, translated)?;
+                let mut result = z3_constraints
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| z3::ast::Bool::from_bool(&self.ctx, true));
+                
+                for constraint in z3_constraints.into_iter().skip(1) {
+                    result = result.and(&constraint);
+                }
+                
+                result
+            }
+            Node::Or(children) => {
+                let z3_constraints = list(children, var_map, translated)?;
+                let mut result = z3_constraints
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| z3::ast::Bool::from_bool(&self.ctx, false));
+                
+                for constraint in z3_constraints.into_iter().skip(1) {
+                    result = result.or(&constraint);
+                }
+                
+                result
+            }
+            Node::Not(inner) => self.translate_node(arena, inner, var_map, solver, translated)?.not(),
+            Node::Implies(antecedent, consequent) => {
+                let antecedent = self.translate_node(arena, antecedent, var_map, solver, translated)?;
+                let consequent = self.translate_node(arena, consequent, var_map, solver, translated)?;
+                antecedent.implies(&consequent)
+            }
+            Node::ExactlyOne(children) | Node::AtMostOne(children) => {
+                // Count the conditions that hold: sum of `ite(c, 1, 0)`
+                let one = z3::ast::Int::from_i64(&self.ctx, 1);
+                let zero = z3::ast::Int::from_i64(&self.ctx, 0);
+                let terms: Vec<z3::ast::Int> = list(children, var_map, translated)?
+                    .iter()
+                    .map(|c| c.ite(&one, &zero))
+                    .collect();
+                let count = match terms.is_empty() {
+                    true => zero.clone(),
+                    false => z3::ast::Int::add(&self.ctx, &terms.iter().collect::<Vec<_>>()),
+                };
+                match arena.node(id) {
+                    Node::ExactlyOne(_) => count._eq(&one),
+                    _ => count.le(&one),
+                }
+            }
+            Node::Simple(leaf) => self.translate_constraint(&arena.constraint(leaf), var_map, solver)?,
+            // Doubles differ pairwise, by IEEE comparison
+            Node::Distinct(fields) if arena.fields(fields).iter().any(|f| self.floats.borrow().is_decimal(f)) => {
+                let fields = arena.fields(fields);
+                let mut pairs = Vec::new();
+                for (index, left) in fields.iter().enumerate() {
+                    for right in &fields[index + 1..] {
+                        let differ = Constraint {
+                            left_variable: left.clone(),
+                            operator: ConstraintOperator::NotEqual,
+                            right_value: right.clone(),
+                        };
+                        pairs.push(self.translate_constraint(&differ, var_map, solver)?);
+                    }
+                }
+                z3::ast::Bool::and(&self.ctx, &pairs.iter().collect::<Vec<_>>())
+            }
+            Node::Distinct(fields) => self.translate_distinct(&arena.fields(fields), var_map),
+            Node::Named(name) => {
+                return Err(VerificationError::TranslationError(format!(
+                    "predicate `{}` must be inlined before verification (see `verify_with_definitions`)",
+                    arena.resolve(name)
+                )))
+            }
+        };
+        translated[id.index()] = Some(expr.clone());
+        Ok(expr)
+    }
+
+    /// Parse the right value (integer, variable reference, or `operand op operand`)
+    fn parse_right_value(
+        &self,
+        right_value: &str,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+    ) -> VerificationResult<z3::ast::Int> {
+        // `then if condition else otherwise` as Z3's `ite`
+        if let Some(conditional) = Conditional::parse(right_value) {
+            let condition = self.translate_comparison(&conditional.condition, var_map)?;
+            let then_value = self.parse_right_value(&conditional.then_value, var_map)?;
+            let else_value = self.parse_right_value(&conditional.else_value, var_map)?;
+            return Ok(condition.ite(&then_value, &else_value));
+        }
+
+        // Before the arithmetic split: `abs(x - y)` is three words too
+        if let Some(call) = Call::parse(right_value) {
+            return self.translate_call(&call, var_map);
+        }
+
+        // Masks, also as a boundary case's left side
+        if let Some(bitwise) = Bitwise::parse(right_value) {
+            return self.translate_bitwise(&bitwise, var_map);
+        }
+
+        // Arithmetic right-hand sides, e.g. `balance - amount` in an update
+        if let [left, op, right] = right_value.split_whitespace().collect::<Vec<_>>()[..] {
+            let left = self.parse_right_value(left, var_map)?;
+            let right = self.parse_right_value(right, var_map)?;
+            return match op {
+                "+" => Ok(z3::ast::Int::add(&self.ctx, &[&left, &right])),
+                "-" => Ok(z3::ast::Int::sub(&self.ctx, &[&left, &right])),
+                "*" => Ok(z3::ast::Int::mul(&self.ctx, &[&left, &right])),
+                "/" => Ok(left.div(&right)),
+                "%" | "mod" => Ok(left.modulo(&right)),
+                _ => Err(VerificationError::TranslationError(format!(
+                    "Unsupported arithmetic operator `{}`",
+                    op
+                ))),
+            };
+        }
+
+        // Try to parse as integer
+        if let Ok(int_val) = right_value.parse::<i64>() {
+            return Ok(z3::ast::Int::from_i64(&self.ctx, int_val));
+        }
+
+        // Otherwise, treat as a variable
+        let var = var_map
+            .entry(right_value.to_string())
+            .or_insert_with(|| z3::ast::Int::new_const(&self.ctx, right_value.to_string()))
+            .clone();
+
+        Ok(var)
+    }
+
+    /// `min`/`max` as a chain of `ite` over the arguments, `abs(x)` as
+    /// `ite(x >= 0, x, -x)`; integers are unbounded, so `abs` never overflows here
+    fn translate_call(
+        &self,
+        call: &Call,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+    ) -> VerificationResult<z3::ast::Int> {
+        if call.function.is_aggregate() {
+            return Ok(self.translate_aggregate(call.function, &call.args[0], var_map));
+        }
+        let mut args = call
+            .args
+            .iter()
+            .map(|arg| self.parse_right_value(arg, var_map))
+            .collect::<VerificationResult<Vec<_>>>()?
+            .into_iter();
+        let first = args
+            .next()
+            .ok_or_else(|| VerificationError::TranslationError(format!("`{}` has no arguments", call)))?;
+        Ok(match call.function {
+            MathFunction::Abs => first.ge(&z3::ast::Int::from_i64(&self.ctx, 0)).ite(&first, &first.unary_minus()),
+            MathFunction::Min => args.fold(first, |least, arg| least.le(&arg).ite(&least, &arg)),
+            MathFunction::Max => args.fold(first, |greatest, arg| greatest.ge(&arg).ite(&greatest, &arg)),
+            MathFunction::Sum | MathFunction::Count | MathFunction::Average => unreachable!("handled above"),
+        })
+    }
+
+    /// A bounded list: element constants `items[0]` to `items[7]` and a
+    /// length `items.len`, read clamped to `0..=MAX_LIST_LENGTH` so that every
+    /// model is a list the bound admits
+    fn list_elements(
+        &self,
+        list: &str,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+    ) -> (z3::ast::Int, Vec<z3::ast::Int>) {
+        let mut constant = |name: String| {
+            var_map.entry(name.clone()).or_insert_with(|| z3::ast::Int::new_const(&self.ctx, name)).clone()
+        };
+        let zero = z3::ast::Int::from_i64(&self.ctx, 0);
+        let bound = z3::ast::Int::from_i64(&self.ctx, MAX_LIST_LENGTH as i64);
+        let length = constant(format!("{}.len", list));
+        let length = length.lt(&zero).ite(&zero, &length.gt(&bound).ite(&bound, &length));
+        let elements = (0..MAX_LIST_LENGTH).map(|index| constant(format!("{}[{}]", list, index))).collect();
+        (length, elements)
+    }
+
+    /// `sum` adds the elements below the length; `average` is `div` by the
+    /// length (rounding down), 0 when empty
+    fn translate_aggregate(
+        &self,
+        function: MathFunction,
+        list: &str,
+        var_map: &mut HashMap<String, z3::ast::Int>,
+    ) -> z3::ast::Int {
+        let zero = z3::ast::Int::from_i64(&self.ctx, 0);
+        let (length, elements) = self.list_elements(list, var_map);
+        let elements: Vec<z3::ast::Int> = elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| z3::ast::Int::from_i64(&self.ctx, index as i64).lt(&length).ite(element, &zero))
+            .collect();
+        let sum = z3::ast::Int::add(&self.ctx, &elements.iter().collect::<Vec<_>>());
+        match function {
+            MathFunction::Count => length,
+            MathFunction::Average => length._eq(&zero).ite(&zero, &sum.div(&length)),
+            _ => sum,
+        }
+    }
+
+    /// `distinct(a, b)` as Z3's `distinct`; `distinct(items)` requires every
+    /// two elements below the length to differ
+    fn translate_distinct(&self, fields: &[String], var_map: &mut HashMap<String, z3::ast::Int>) -> z3::ast::Bool {
+        if let [list] = fields {
+            let (length, elements) = self.list_elements(list, var_map);
+            let mut pairs = Vec::new();
+            for (index, element) in elements.iter().enumerate().skip(1) {
+                let present = z3::ast::Int::from_i64(&self.ctx, index as i64).lt(&length);
+                for earlier in &elements[..index] {
+                    pairs.push(present.implies(&earlier._eq(element).not()));
+                }
+            }
+            return z3::ast::Bool::and(&self.ctx, &pairs.iter().collect::<Vec<_>>());
+        }
+        let value = |field: &String| {
+            var_map.entry(field.clone()).or_insert_with(|| z3::ast::Int::new_const(&self.ctx, field.as_str())).clone()
+        };
+        let values: Vec<z3::ast::Int> = fields.iter().map(value).collect();
+        z3::ast::Ast::distinct(&self.ctx, &values.iter().collect::<Vec<_>>())
+    }
+
+    /// Generate SMT-LIB format output for constraints
+    pub fn generate_smt_lib(&self, constraints: &[Constraint]) -> String {
+        // Containment needs the string theory, masks convert between
+        // integers and bit-vectors, and a remainder by a variable is non-linear
+        let logic = if constraints.iter().any(|c| c.operator.is_membership()) {
+            "QF_SLIA"
+        } else if constraints.iter().any(|c| c.bitwise().is_some()) {
+            "ALL"
+        } else if constraints.iter().any(|c| c.modulo().is_some_and(|m| m.divisor.parse::<i64>().is_err())) {
+            "QF_NIA"
+        } else {
+            "QF_LIA"
+        };
+        let mut smt_lib = format!("(set-logic {})\n", logic);
+        smt_lib.push_str("(set-option :produce-models true)\n\n");
+        
+        // Track declared variables
+        let mut declared_vars: std::collections::HashSet<String> = std::collections::HashSet::new();
+        
+        for constraint in constraints {
+            self.append_constraint_smt(constraint, &mut smt_lib, &mut declared_vars);
+        }
+        
+        smt_lib.push_str("\n(check-sat)\n(get-model)\n");
+        smt_lib
+    }
+
+    /// Append a constraint to SMT-LIB output
+    fn append_constraint_smt(
+        &self,
+        constraint: &Constraint,
+        output: &mut String,
+        declared_vars: &mut std::collections::HashSet<String>,
+    ) {
+        if constraint.operator.is_membership() {
+            for name in [&constraint.left_variable, &constraint.right_value] {
+                if !name.starts_with('"') && declared_vars.insert(name.clone()) {
+                    output.push_str(&format!("(declare-const {} String)\n", name));
+                }
+            }
+            let contains = format!("(str.contains {} {})", constraint.left_variable, constraint.right_value);
+            let assertion = match constraint.operator {
+                ConstraintOperator::DoesNotContain => format!("(not {})", contains),
+                _ => contains,
+            };
+            output.push_str(&format!("(assert {})\n", assertion));
+            return;
+        }
+
+        // Declare left variable (or the operands of `id % 10` or `flags & mask`) if not already declared
+        let left = match (constraint.modulo(), constraint.bitwise()) {
+            (Some(m), _) => {
+                for name in [&m.dividend, &m.divisor] {
+                    if name.parse::<i64>().is_err() && declared_vars.insert(name.clone()) {
+                        output.push_str(&format!("(declare-const {} Int)\n", name));
+                    }
+                }
+                format!("(mod {} {})", m.dividend, m.divisor)
+            }
+            (None, Some(bitwise)) => {
+                for name in bitwise.variables() {
+                    if declared_vars.insert(name.clone()) {
+                        output.push_str(&format!("(declare-const {} Int)\n", name));
+                    }
+                }
+                smt_bitwise(&bitwise)
+            }
+            (None, None) => {
+                if declared_vars.insert(constraint.left_variable.clone()) {
+                    output.push_str(&format!(
+                        "(declare-const {} Int)\n",
+                        constraint.left_variable
+                    ));
+                }
+                constraint.left_variable.clone()
+            }
+        };
+
+        // Declare right variable if it's not a number
+        if constraint.right_value.parse::<i64>().is_err() {
+            if declared_vars.insert(constraint.right_value.clone()) {
+                output.push_str(&format!(
+                    "(declare-const {} Int)\n",
+                    constraint.right_value
+                ));
+            }
+        }
+
+        // Add the constraint
+        let op_str = match constraint.operator {
+            ConstraintOperator::GreaterThanOrEqual => ">=",
+            ConstraintOperator::LessThanOrEqual => "<=",
+            ConstraintOperator::GreaterThan => ">",
+            ConstraintOperator::LessThan => "<",
+            ConstraintOperator::Equal => "=",
+            ConstraintOperator::NotEqual => "distinct",
+            ConstraintOperator::Contains | ConstraintOperator::DoesNotContain => unreachable!("handled above"),
+        };
+        
+        output.push_str(&format!(
+            "(assert ({} {} {}))\n",
+            op_str,
+            left,
+            constraint.right_value
+        ));
+    }
+}
+
+/// `bitwise` as an SMT-LIB integer term: both operands as `BitWidth::DEFAULT`
+/// bit-vectors, the result read back as a signed integer
+fn smt_bitwise(bitwise: &Bitwise) -> String {
+    let bits = BitWidth::DEFAULT.bits;
+    let operand = |value: &str| match crucible_core::bitwise::parse_integer(value) {
+        Some(literal) if literal < 0 => format!("((_ int2bv {}) (- {}))", bits, -literal),
+        Some(literal) => format!("((_ int2bv {}) {})", bits, literal),
+        None => format!("((_ int2bv {}) {})", bits, value),
+    };
+    let function = match bitwise.operator {
+        BitwiseOperator::And => "bvand",
+        BitwiseOperator::Or => "bvor",
+        BitwiseOperator::Xor => "bvxor",
+        BitwiseOperator::ShiftLeft => "bvshl",
+        BitwiseOperator::ShiftRight => "bvashr",
+    };
+    format!(
+        "(let ((bits ({} {} {}))) (ite (bvslt bits (_ bv0 {n})) (- (bv2nat bits) {}) (bv2nat bits)))",
+        function,
+        operand(&bitwise.operand),
+        operand(&bitwise.mask),
+        1u128 << bits,
+        n = bits
+    )
+}
+
+impl Default for Z3Verifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Leaves of a constraint tree, in order
+fn collect_simple<'a>(compound: &'a CompoundConstraint, out: &mut Vec<&'a Constraint>) {
+    match compound {
+        CompoundConstraint::Simple(c) => out.push(c),
+        CompoundConstraint::And(parts)
+        | CompoundConstraint::Or(parts)
+        | CompoundConstraint::ExactlyOne(parts)
+        | CompoundConstraint::AtMostOne(parts) => {
+            for part in parts {
+                collect_simple(part, out);
+            }
+        }
+        CompoundConstraint::Not(inner) => collect_simple(inner, out),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            collect_simple(antecedent, out);
+            collect_simple(consequent, out);
+        }
+        CompoundConstraint::Named(_) | CompoundConstraint::Distinct(_) => {}
+    }
+}
+
+/// Step-indexed names for `check_trace`: `x` is `x@i` in state `i` (or an
+/// input of step `i`) and `x'` is `x@{i+1}`; literals are kept
+fn at_step(tree: &CompoundConstraint, step: usize) -> CompoundConstraint {
+    let rename = |text: &str| {
+        text.split_whitespace()
+            .map(|token| match crucible_core::transition::unprimed(token) {
+                Some(variable) => format!("{}@{}", variable, step + 1),
+                None if token == "mod" || !token.starts_with(|c: char| c.is_alphabetic() || c == '_') => {
+                    token.to_string()
+                }
+                None => format!("{}@{}", token, step),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let all = |parts: &[CompoundConstraint]| parts.iter().map(|part| at_step(part, step)).collect();
+    match tree {
+        CompoundConstraint::Simple(constraint) => CompoundConstraint::Simple(Constraint {
+            left_variable: rename(&constraint.left_variable),
+            operator: constraint.operator,
+            right_value: rename(&constraint.right_value),
+        }),
+        CompoundConstraint::And(parts) => CompoundConstraint::And(all(parts)),
+        CompoundConstraint::Or(parts) => CompoundConstraint::Or(all(parts)),
+        CompoundConstraint::ExactlyOne(parts) => CompoundConstraint::ExactlyOne(all(parts)),
+        CompoundConstraint::AtMostOne(parts) => CompoundConstraint::AtMostOne(all(parts)),
+        CompoundConstraint::Not(inner) => CompoundConstraint::Not(Box::new(at_step(inner, step))),
+        CompoundConstraint::Implies(antecedent, consequent) => {
+            CompoundConstraint::Implies(Box::new(at_step(antecedent, step)), Box::new(at_step(consequent, step)))
+        }
+        CompoundConstraint::Distinct(fields) => CompoundConstraint::Distinct(fields.iter().map(|f| rename(f)).collect()),
+        CompoundConstraint::Named(name) => CompoundConstraint::Named(name.clone()),
+    }
+}
+
+/// The first `depth` steps of a `check_trace` model
+fn trace(
+    model: &HashMap<String, String>,
+    actions: &[StateTransition],
+    state_variables: &[String],
+    depth: usize,
+) -> Vec<TraceStep> {
+    let values = |step: usize, wanted: &dyn Fn(&str) -> bool| -> HashMap<String, String> {
+        let suffix = format!("@{}", step);
+        model
+            .iter()
+            .filter_map(|(name, value)| {
+                let variable = name.strip_suffix(&suffix)?;
+                wanted(variable).then(|| (variable.to_string(), value.clone()))
+            })
+            .collect()
+    };
+    let is_state = |variable: &str| state_variables.iter().any(|v| v == variable);
+    (0..=depth)
+        .map(|step| TraceStep {
+            action: step.checked_sub(1).and_then(|taken| {
+                let index: usize = model.get(&format!("{}{}", TRACE_ACTION, taken))?.parse().ok()?;
+                actions.get(index).map(|action| action.action.clone())
+            }),
+            inputs: step.checked_sub(1).map(|taken| values(taken, &|v| !is_state(v))).unwrap_or_default(),
+            state: values(step, &is_state),
+        })
+        .collect()
+}
+
+/// `solver.check()`, with the result and the solver's wall-clock time traced
+fn timed_check(solver: &Solver) -> z3::SatResult {
+    let started = Instant::now();
+    let result = solver.check();
+    tracing::debug!(?result, micros = started.elapsed().as_micros() as u64, "solver check");
+    result
+}
+
+/// Pre-solver witnesses rendered the way Z3 prints integers, e.g. `(- 5)`
+fn z3_model(witnesses: BTreeMap<String, i64>) -> HashMap<String, String> {
+    witnesses
+        .into_iter()
+        .map(|(field, value)| {
+            let value = if value < 0 { format!("(- {})", value.unsigned_abs()) } else { value.to_string() };
+            (field, value)
+        })
+        .collect()
+}
+
+/// Convenience function to verify a single constraint
+pub fn verify_single_constraint(constraint: &Constraint) -> VerificationResult<VerificationResultOutput> {
+    let verifier = Z3Verifier::new();
+    verifier.verify_constraints(&[constraint.clone()])
+}
+
+/// Check if two constraints are equivalent
+pub fn check_equivalence(
+    constraint1: &Constraint,
+    constraint2: &Constraint,
+) -> VerificationResult<bool> {
+    let verifier = Z3Verifier::new();
+    
+    // Create solver with both constraints
+    let solver = Solver::new(&verifier.ctx);
+    let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
+    
+    let z3_c1 = verifier.translate_constraint(constraint1, &mut var_map, &solver)?;
+    let z3_c2 = verifier.translate_constraint(constraint2, &mut var_map, &solver)?;
+    
+    // Check if c1 AND NOT c2 is unsatisfiable (c1 implies c2)
+    solver.assert(&z3_c1);
+    solver.assert(&z3_c2.not());
+    let c1_implies_c2 = timed_check(&solver) == z3::SatResult::Unsat;
+    
+    // Reset and check c2 AND NOT c1 (c2 implies c1)
+    solver.reset();
+    solver.assert(&z3_c2);
+    solver.assert(&z3_c1.not());
+    let c2_implies_c1 = timed_check(&solver) == z3::SatResult::Unsat;
+    
+    Ok(c1_implies_c2 && c2_implies_c1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
+
+    #[test]
+    fn test_simple_satisfiable_constraint() {
+        let verifier = Z3Verifier::new();
+        
+        let constraint = Constraint {
+            left_variable: "x".to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: "0".to_string(),
+        };
+        
+        let result = verifier.verify_constraints(&[constraint]);
+        assert!(result.is_ok());
+        assert!(result.unwrap().satisfiable);
+    }
+
+    #[test]
+    fn test_simple_unsatisfiable_constraint() {
+        let verifier = Z3Verifier::new();
+        
+        let constraint = Constraint {
+            left_variable: "x".to_string(),
+            operator: ConstraintOperator::GreaterThan,
+            right_value: "x".to_string(),
+        };
+        
+        let result = verifier.verify_constraints(&[constraint]);
+        assert!(result.is_err());
+        assert!(matches!(result.unwrap_err(), VerificationError::Unsatisfiable(_)));
+    }
+
+    #[test]
+    fn test_compound_and_constraints() {
+        let verifier = Z3Verifier::new();
+        
+        let compound = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "x".to_string(),
+                operator: ConstraintOperator::GreaterThanOrEqual,
+                right_value: "0".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "x".to_string(),
+                operator: ConstraintOperator::LessThanOrEqual,
+                right_value: "10".to_string(),
+            }),
+        ]);
+        
+        let result = verifier.verify_compound_constraints(&compound);
+        assert!(result.is_ok());
+        assert!(result.unwrap().satisfiable);
+    }
+
+    #[test]
+    fn test_compound_or_constraints() {
+        let verifier = Z3Verifier::new();
+        
+        let compound = CompoundConstraint::Or(vec![
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "x".to_string(),
+                operator: ConstraintOperator::LessThan,
+                right_value: "0".to_string(),
+            }),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "x".to_string(),
+                operator: ConstraintOperator::GreaterThan,
+                right_value: "10".to_string(),
+            }),
+        ]);
+        
+        let result = verifier.verify_compound_constraints(&compound);
+        assert!(result.is_ok());
+        assert!(result.unwrap().satisfiable);
+    }
+
+    #[test]
+    fn test_invalid_constraints_are_rejected_before_solving() {
+        let verifier = Z3Verifier::new();
+        assert!(matches!(
+            verifier.verify_compound_constraints(&CompoundConstraint::And(Vec::new())),
+            Err(VerificationError::InvalidConstraint(ConstraintError::Empty("AND")))
+        ));
+        let error = verifier.verify_compound_constraints(&CompoundConstraint::Or(Vec::new())).unwrap_err();
+        let error = CrucibleError::from(error);
+        assert_eq!((error.code(), error.exit_code()), (codes::EMPTY_GROUP, 4));
+        assert_eq!(CrucibleError::from(VerificationError::SolverError("timeout".into())).http_status(), 500);
+
+        let mut schema = Schema::new("TR-1".to_string());
+        schema.add_field("x".to_string(), crucible_core::DataType::Int64, None);
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "x".to_string(),
+            operator: ConstraintOperator::LessThan,
+            right_value: "y".to_string(),
+        });
+        assert!(matches!(
+            verifier.verify_with_schema(&compound, &schema),
+            Err(VerificationError::InvalidConstraint(ConstraintError::UnknownVariable { name, .. })) if name == "y"
+        ));
+        schema.add_field("y".to_string(), crucible_core::DataType::Int64, None);
+        assert!(verifier.verify_with_schema(&compound, &schema).unwrap().satisfiable);
+    }
+
+    #[test]
+    fn test_presolved_fields_join_the_z3_model() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+
+        // `fee` is decided by its interval alone; `x < y` still needs Z3
+        let compound = CompoundConstraint::And(vec![
+            simple("fee", ConstraintOperator::LessThanOrEqual, "-3"),
+            simple("x", ConstraintOperator::LessThan, "y"),
+        ]);
+        let model = verifier.verify_compound_constraints(&compound).unwrap().model.unwrap();
+        assert_eq!(model["fee"], "(- 3)");
+        assert!(model.contains_key("x") && model.contains_key("y"));
+
+        let empty = CompoundConstraint::And(vec![
+            simple("fee", ConstraintOperator::GreaterThan, "3"),
+            simple("fee", ConstraintOperator::LessThan, "4"),
+        ]);
+        assert!(matches!(
+            verifier.verify_compound_constraints(&empty),
+            Err(VerificationError::Unsatisfiable(reason)) if reason.contains("fee")
+        ));
+    }
+
+    #[test]
+    fn test_optimize_reports_extremes_and_unbounded_directions() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let spec = CompoundConstraint::And(vec![
+            simple("withdrawal", ConstraintOperator::LessThanOrEqual, "balance"),
+            simple("balance", ConstraintOperator::LessThanOrEqual, "10000"),
+            simple("withdrawal", ConstraintOperator::GreaterThan, "0"),
+        ]);
+
+        let max = verifier.optimize("withdrawal", Objective::Maximize, &spec).unwrap();
+        assert_eq!(max.value, Some(10000));
+        assert_eq!(max.model.unwrap()["balance"], "10000");
+        assert_eq!(max.to_string(), "maximum permitted `withdrawal` is 10000");
+
+        let min = verifier.optimize("balance", Objective::Minimize, &spec).unwrap();
+        assert_eq!(min.value, Some(1));
+
+        let range = |variable: &str| FeasibleRange {
+            variable: variable.to_string(),
+            min: Some(1),
+            max: Some(10000),
+        };
+        assert_eq!(verifier.feasible_ranges(&spec).unwrap(), vec![range("balance"), range("withdrawal")]);
+
+        let open = simple("fee", ConstraintOperator::GreaterThanOrEqual, "5");
+        let unbounded = verifier.optimize("fee", Objective::Maximize, &open).unwrap();
+        assert_eq!(unbounded.value, None);
+        assert_eq!(unbounded.to_string(), "`fee` has no maximum");
+    }
+
+    #[test]
+    fn test_scenario_reports_violated_subexpressions() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let constraints = vec![
+            CompoundConstraint::And(vec![
+                simple("amount", ConstraintOperator::GreaterThan, "0"),
+                simple("amount", ConstraintOperator::LessThanOrEqual, "10000"),
+            ]),
+            CompoundConstraint::implies(
+                simple("priority", ConstraintOperator::Equal, "1"),
+                simple("amount", ConstraintOperator::LessThanOrEqual, "limit"),
+            ),
+            simple("memo", ConstraintOperator::Contains, "\"ref\""),
+        ];
+        let bindings = BTreeMap::from([
+            ("amount".to_string(), "12000".to_string()),
+            ("memo".to_string(), "\"ref 42\"".to_string()),
+        ]);
+
+        let checks = verifier.check_scenario(&bindings, &constraints).unwrap();
+        let verdicts: Vec<ScenarioVerdict> = checks.iter().map(|c| c.verdict).collect();
+        assert_eq!(
+            verdicts,
+            vec![ScenarioVerdict::Violated, ScenarioVerdict::Undetermined, ScenarioVerdict::Satisfied]
+        );
+        assert_eq!(checks[0].violations, vec!["amount <= 10000".to_string()]);
+        assert!(checks[1].violations.is_empty());
+    }
+
+    #[test]
+    fn test_classify_names_responsible_requirements() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let existing = vec![
+            simple("age", ConstraintOperator::GreaterThanOrEqual, "18"),
+            simple("limit", ConstraintOperator::LessThanOrEqual, "5000"),
+            simple("withdrawal", ConstraintOperator::LessThanOrEqual, "limit"),
+        ];
+
+        let conflict = simple("withdrawal", ConstraintOperator::GreaterThan, "6000");
+        assert_eq!(verifier.classify(&existing, &conflict).unwrap(), Consistency::Contradicts(vec![1, 2]));
+
+        let implied = simple("withdrawal", ConstraintOperator::LessThan, "5001");
+        assert_eq!(verifier.classify(&existing, &implied).unwrap(), Consistency::Implied(vec![1, 2]));
+
+        let new = simple("age", ConstraintOperator::LessThanOrEqual, "120");
+        assert_eq!(verifier.classify(&existing, &new).unwrap(), Consistency::Consistent);
+    }
+
+    #[test]
+    fn test_layered_soft_conflict_is_warning() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+
+        let constraints = vec![
+            (Severity::MustHold, atom("amount", ConstraintOperator::GreaterThan, "100")),
+            (Severity::ShouldHold, atom("amount", ConstraintOperator::LessThanOrEqual, "50")),
+            (Severity::MayHold, atom("fee", ConstraintOperator::Equal, "0")),
+        ];
+
+        let result = verifier.verify_layered(&constraints).unwrap();
+        assert_eq!(result.relaxed.len(), 1);
+        assert_eq!(result.relaxed[0].index, 1);
+        assert_eq!(result.relaxed[0].severity, Severity::ShouldHold);
+
+        // A conflict between hard constraints is still an error
+        let hard_conflict = vec![
+            (Severity::MustHold, atom("x", ConstraintOperator::GreaterThan, "10")),
+            (Severity::MustHold, atom("x", ConstraintOperator::LessThan, "5")),
+        ];
+        assert!(matches!(
+            verifier.verify_layered(&hard_conflict),
+            Err(VerificationError::Unsatisfiable(_))
+        ));
+    }
+
+    #[test]
+    fn test_guarantees_checked_under_assumptions() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+
+        let requirements = vec![
+            (RequirementKind::Assumption, atom("amount", ConstraintOperator::GreaterThan, "0")),
+            (RequirementKind::Guarantee, atom("amount", ConstraintOperator::GreaterThanOrEqual, "1")),
+            (RequirementKind::Guarantee, atom("fee", ConstraintOperator::LessThan, "amount")),
+            (RequirementKind::Guarantee, atom("amount", ConstraintOperator::LessThan, "0")),
+        ];
+
+        let result = verifier.verify_contract(&requirements).unwrap();
+        let statuses: Vec<GuaranteeStatus> = result.checks.iter().map(|c| c.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                GuaranteeStatus::Entailed,
+                GuaranteeStatus::Enforceable,
+                GuaranteeStatus::Contradicted,
+            ]
+        );
+        assert!(result.checks[1].counterexample.is_some());
+        assert!(!result.is_consistent());
+    }
+
+    #[test]
+    fn test_transition_postcondition_and_frame() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let post = CompoundConstraint::And(vec![
+            atom("balance'", ConstraintOperator::Equal, "balance - amount"),
+            atom("balance'", ConstraintOperator::GreaterThanOrEqual, "0"),
+            atom("limit'", ConstraintOperator::Equal, "limit"),
+        ]);
+        let state = vec!["balance".to_string(), "limit".to_string()];
 
-        // Add the constraint
-        let op_str = match constraint.operator {
-            ConstraintOperator::GreaterThanOrEqual => ">=",
-            ConstraintOperator::LessThanOrEqual => "<=",
-            ConstraintOperator::GreaterThan => ">",
-            ConstraintOperator::LessThan => "<",
-            ConstraintOperator::Equal => "=",
-            ConstraintOperator::NotEqual => "distinct",
+        let guarded = StateTransition::new(
+            "withdraw",
+            atom("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            post.clone(),
+        );
+        let result = verifier.verify_transition(&guarded, &state).unwrap();
+        assert!(result.postcondition_holds);
+
+        // Without the guard the balance can go negative
+        let unguarded = StateTransition::new("withdraw", atom("amount", ConstraintOperator::GreaterThan, "0"), post);
+        let result = verifier.verify_transition(&unguarded, &state).unwrap();
+        assert!(!result.postcondition_holds);
+        assert!(result.counterexample.is_some());
+    }
+
+    #[test]
+    fn test_invariant_preservation() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
         };
-        
-        output.push_str(&format!(
-            "(assert ({} {} {}))\n",
-            op_str,
-            constraint.left_variable,
-            constraint.right_value
+        let state = vec!["balance".to_string(), "limit".to_string()];
+        let invariants = [
+            atom("balance", ConstraintOperator::GreaterThanOrEqual, "0"),
+            atom("balance", ConstraintOperator::LessThanOrEqual, "limit"),
+        ];
+        let withdraw = StateTransition::new(
+            "withdraw",
+            CompoundConstraint::And(vec![
+                atom("amount", ConstraintOperator::GreaterThan, "0"),
+                atom("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            ]),
+            atom("balance'", ConstraintOperator::Equal, "balance - amount"),
+        );
+        // No check against the limit
+        let deposit = StateTransition::new(
+            "deposit",
+            atom("amount", ConstraintOperator::GreaterThan, "0"),
+            atom("balance'", ConstraintOperator::Equal, "balance + amount"),
+        );
+        let frozen = StateTransition::new(
+            "close",
+            atom("balance", ConstraintOperator::LessThan, "0"),
+            atom("balance'", ConstraintOperator::Equal, "0"),
+        );
+
+        let result = verifier
+            .verify_invariant_preservation(&invariants, &[withdraw, deposit, frozen], &state)
+            .unwrap();
+        assert_eq!(result.checks.len(), 6);
+        assert!(!result.is_inductive());
+        let violations: Vec<_> = result.violations().map(|c| (c.action.as_str(), c.invariant)).collect();
+        assert_eq!(violations, [("deposit", 1)]);
+        let counterexample = result.violations().next().unwrap().counterexample.as_ref().unwrap();
+        assert!(counterexample.contains_key("balance'") && counterexample.contains_key("limit"));
+        assert_eq!(result.disabled_actions, ["close"]);
+    }
+
+    #[test]
+    fn test_component_compatibility() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let mut producer = ComponentContract::new("checkout");
+        producer
+            .assume(atom("cart_total", ConstraintOperator::GreaterThan, "0"))
+            .guarantee(atom("amount", ConstraintOperator::Equal, "cart_total"))
+            .guarantee(atom("amount", ConstraintOperator::LessThanOrEqual, "10000"));
+        let mut consumer = ComponentContract::new("payments");
+        consumer
+            .assume(atom("amount", ConstraintOperator::GreaterThanOrEqual, "1"))
+            .assume(atom("amount", ConstraintOperator::LessThanOrEqual, "5000"))
+            .assume(atom("currency", ConstraintOperator::Equal, "978"))
+            .guarantee(atom("status", ConstraintOperator::Equal, "1"));
+
+        let result = verifier.check_compatibility(&producer, &consumer).unwrap();
+        assert!(!result.is_compatible());
+        assert_eq!(result.assumptions_count, 3);
+        let gaps: Vec<usize> = result.gaps.iter().map(|gap| gap.assumption).collect();
+        assert_eq!(gaps, [1, 2]);
+        assert!(result.gaps[0].unconstrained.is_empty());
+        assert_eq!(result.gaps[1].unconstrained, ["currency"]);
+
+        producer.guarantee(atom("amount", ConstraintOperator::LessThan, "0"));
+        assert!(matches!(
+            verifier.check_compatibility(&producer, &consumer),
+            Err(VerificationError::Unsatisfiable(_))
         ));
     }
-}
 
-impl Default for Z3Verifier {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn test_check_refinement() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let spec = |trees: Vec<CompoundConstraint>| {
+            let mut ast = IntentAst::new();
+            for tree in trees {
+                ast.add_constrained_requirement("Amount must be in range".to_string(), Some(tree));
+            }
+            ast
+        };
+        let v1 = spec(vec![atom("amount", ConstraintOperator::GreaterThan, "0")]);
+        let reworded = spec(vec![atom("amount", ConstraintOperator::GreaterThanOrEqual, "1")]);
+        let capped = spec(vec![
+            atom("amount", ConstraintOperator::GreaterThan, "0"),
+            atom("amount", ConstraintOperator::LessThanOrEqual, "100"),
+        ]);
+        let relaxed = spec(vec![atom("amount", ConstraintOperator::GreaterThanOrEqual, "0")]);
+        let bounded = spec(vec![atom("amount", ConstraintOperator::LessThan, "50")]);
+
+        let result = verifier.check_refinement(&v1, &reworded).unwrap();
+        assert_eq!(result.relation, Refinement::Equivalent);
+        assert!(result.newly_rejected.is_none() && result.newly_allowed.is_none());
+
+        let result = verifier.check_refinement(&v1, &capped).unwrap();
+        assert_eq!(result.relation, Refinement::Stronger);
+        let rejected: i64 = result.newly_rejected.unwrap()["amount"].parse().unwrap();
+        assert!(rejected > 100);
+
+        let result = verifier.check_refinement(&v1, &relaxed).unwrap();
+        assert_eq!(result.relation, Refinement::Weaker);
+        assert_eq!(result.newly_allowed.unwrap()["amount"], "0");
+
+        let result = verifier.check_refinement(&v1, &bounded).unwrap();
+        assert_eq!(result.relation, Refinement::Incomparable);
+        assert!(result.newly_rejected.is_some() && result.newly_allowed.is_some());
+        assert_eq!(result.relation.to_string(), "incomparable");
     }
-}
 
-/// Convenience function to verify a single constraint
-pub fn verify_single_constraint(constraint: &Constraint) -> VerificationResult<VerificationResultOutput> {
-    let verifier = Z3Verifier::new();
-    verifier.verify_constraints(&[constraint.clone()])
-}
+    #[test]
+    fn test_check_trace() {
+        let verifier = Z3Verifier::new();
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let init = atom("balance", ConstraintOperator::Equal, "10");
+        let non_negative = atom("balance", ConstraintOperator::GreaterThanOrEqual, "0");
+        let fee = StateTransition::new(
+            "fee",
+            atom("balance", ConstraintOperator::GreaterThan, "0"),
+            atom("balance'", ConstraintOperator::Equal, "balance - 4"),
+        );
+        let withdraw = StateTransition::new(
+            "withdraw",
+            atom("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            atom("balance'", ConstraintOperator::Equal, "balance - amount"),
+        );
 
-/// Check if two constraints are equivalent
-pub fn check_equivalence(
-    constraint1: &Constraint,
-    constraint2: &Constraint,
-) -> VerificationResult<bool> {
-    let verifier = Z3Verifier::new();
-    
-    // Create solver with both constraints
-    let solver = Solver::new(&verifier.ctx);
-    let mut var_map: HashMap<String, z3::ast::Int> = HashMap::new();
-    
-    let z3_c1 = verifier.translate_constraint(constraint1, &mut var_map, &solver)?;
-    let z3_c2 = verifier.translate_constraint(constraint2, &mut var_map, &solver)?;
-    
-    // Check if c1 AND NOT c2 is unsatisfiable (c1 implies c2)
-    solver.assert(&z3_c1);
-    solver.assert(&z3_c2.not());
-    let c1_implies_c2 = solver.check() == z3::SatResult::Unsat;
-    
-    // Reset and check c2 AND NOT c1 (c2 implies c1)
-    solver.reset();
-    solver.assert(&z3_c2);
-    solver.assert(&z3_c1.not());
-    let c2_implies_c1 = solver.check() == z3::SatResult::Unsat;
-    
-    Ok(c1_implies_c2 && c2_implies_c1)
-}
+        // Withdrawals alone never overdraw
+        let result = verifier.check_trace(&init, &[withdraw.clone()], 3, &non_negative).unwrap();
+        assert!(result.is_safe());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crucible_core::{Constraint, ConstraintOperator};
+        // The fee only checks for a positive balance: 10 -> 6 -> 2 -> -2
+        let result = verifier.check_trace(&init, &[fee.clone()], 3, &non_negative).unwrap();
+        let trace = result.violation.unwrap();
+        assert_eq!(trace.len(), 4);
+        assert_eq!(trace[0].action, None);
+        assert_eq!(trace[0].state["balance"], "10");
+        assert!(trace[1..].iter().all(|step| step.action.as_deref() == Some("fee")));
+        assert_eq!(trace[3].state["balance"], "(- 2)");
+        assert!(verifier.check_trace(&init, &[fee.clone()], 2, &non_negative).unwrap().is_safe());
+
+        // A withdrawal down to less than the fee gets there in two
+        let result = verifier.check_trace(&init, &[withdraw, fee], 3, &non_negative).unwrap();
+        let trace = result.violation.unwrap();
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace[1].action.as_deref(), Some("withdraw"));
+        assert!(trace[1].inputs.contains_key("amount"));
+    }
 
     #[test]
-    fn test_simple_satisfiable_constraint() {
+    fn test_state_machine_deadlock_and_reachability() {
         let verifier = Z3Verifier::new();
-        
-        let constraint = Constraint {
-            left_variable: "x".to_string(),
+        let atom = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+
+        let mut machine = StateMachine::new("User", "registered");
+        machine.add_transition(
+            "registered",
+            "logged_in",
+            "login",
+            Some(atom("attempts", ConstraintOperator::LessThan, "3")),
+        );
+        machine.add_transition("logged_in", "registered", "logout", None);
+        machine.add_transition(
+            "logged_in",
+            "locked",
+            "lock",
+            Some(CompoundConstraint::And(vec![
+                atom("attempts", ConstraintOperator::GreaterThan, "5"),
+                atom("attempts", ConstraintOperator::LessThan, "5"),
+            ])),
+        );
+
+        let result = verifier.verify_state_machine(&machine).unwrap();
+        assert_eq!(result.dead_transitions, vec![2]);
+        assert_eq!(result.unreachable_states, vec!["locked".to_string()]);
+        // `registered` is stuck once attempts >= 3
+        assert_eq!(result.deadlocks.len(), 1);
+        assert_eq!(result.deadlocks[0].state, "registered");
+        assert!(!result.is_live());
+    }
+
+    #[test]
+    fn test_boundary_cases_straddle_each_comparison() {
+        let verifier = Z3Verifier::new();
+        let compound = CompoundConstraint::Simple(Constraint {
+            left_variable: "balance".to_string(),
             operator: ConstraintOperator::GreaterThanOrEqual,
-            right_value: "0".to_string(),
+            right_value: "amount".to_string(),
+        });
+
+        let cases = verifier.boundary_cases(&compound).unwrap();
+        assert_eq!(cases.len(), 3);
+        for case in &cases {
+            assert_eq!(case.expected, case.values["balance"] >= case.values["amount"]);
+        }
+        assert!(cases.iter().any(|c| !c.expected));
+    }
+
+    #[test]
+    fn test_fault_cases_violate_one_constraint_each() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
         };
-        
-        let result = verifier.verify_constraints(&[constraint]);
-        assert!(result.is_ok());
-        assert!(result.unwrap().satisfiable);
+        let compound = CompoundConstraint::And(vec![
+            simple("amount", ConstraintOperator::GreaterThan, "0"),
+            simple("balance", ConstraintOperator::GreaterThanOrEqual, "amount"),
+            // Implied by the first: no input violates it alone
+            simple("amount", ConstraintOperator::GreaterThan, "-5"),
+        ]);
+
+        let cases = verifier.fault_cases(&compound).unwrap();
+        assert_eq!(cases.iter().map(|case| case.constraint).collect::<Vec<_>>(), vec![0, 1]);
+        let (amount, balance) = (cases[0].values["amount"], cases[0].values["balance"]);
+        assert!(amount <= 0 && balance >= amount && amount > -5);
+        let (amount, balance) = (cases[1].values["amount"], cases[1].values["balance"]);
+        assert!(amount > 0 && balance < amount);
     }
 
     #[test]
-    fn test_simple_unsatisfiable_constraint() {
+    fn test_min_max_abs() {
         let verifier = Z3Verifier::new();
-        
-        let constraint = Constraint {
-            left_variable: "x".to_string(),
-            operator: ConstraintOperator::GreaterThan,
-            right_value: "x".to_string(),
+        let simple = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
         };
-        
-        let result = verifier.verify_constraints(&[constraint]);
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), VerificationError::Unsatisfiable(_)));
+        let fee = |floor: &str, amount: &str| {
+            CompoundConstraint::And(vec![
+                simple("fee", ConstraintOperator::Equal, "max(min_fee, amount * rate)"),
+                simple("min_fee", ConstraintOperator::Equal, floor),
+                simple("amount", ConstraintOperator::Equal, amount),
+                simple("rate", ConstraintOperator::Equal, "2"),
+                simple("fee", ConstraintOperator::LessThan, "10"),
+            ])
+        };
+        // The floor wins below it, the proportional fee above
+        assert!(verifier.verify_compound_constraints(&fee("5", "2")).unwrap().satisfiable);
+        assert!(!verifier.verify_compound_constraints(&fee("5", "6")).unwrap().satisfiable);
+        assert!(!verifier.verify_compound_constraints(&fee("12", "1")).unwrap().satisfiable);
+
+        let delta = |value: &str| {
+            CompoundConstraint::And(vec![
+                simple("abs(delta - 1)", ConstraintOperator::LessThanOrEqual, "3"),
+                simple("delta", ConstraintOperator::Equal, value),
+                simple("min(delta, 0, limit)", ConstraintOperator::Equal, "-2"),
+            ])
+        };
+        assert!(verifier.verify_compound_constraints(&delta("-2")).unwrap().satisfiable);
+        assert!(!verifier.verify_compound_constraints(&delta("-3")).unwrap().satisfiable);
     }
 
     #[test]
-    fn test_compound_and_constraints() {
+    fn test_conditional_values() {
         let verifier = Z3Verifier::new();
-        
-        let compound = CompoundConstraint::And(vec![
+        let simple = |left: &str, operator, right: &str| {
             CompoundConstraint::Simple(Constraint {
-                left_variable: "x".to_string(),
-                operator: ConstraintOperator::GreaterThanOrEqual,
-                right_value: "0".to_string(),
-            }),
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let fee = |tier: &str, fee: &str| {
+            CompoundConstraint::And(vec![
+                simple("fee", ConstraintOperator::Equal, "0 if tier >= 3 else 2 if tier == 2 else base + 1"),
+                simple("base", ConstraintOperator::Equal, "4"),
+                simple("tier", ConstraintOperator::Equal, tier),
+                simple("fee", ConstraintOperator::Equal, fee),
+            ])
+        };
+        assert!(verifier.verify_compound_constraints(&fee("3", "0")).unwrap().satisfiable);
+        assert!(verifier.verify_compound_constraints(&fee("2", "2")).unwrap().satisfiable);
+        assert!(verifier.verify_compound_constraints(&fee("1", "5")).unwrap().satisfiable);
+        assert!(matches!(
+            verifier.verify_compound_constraints(&fee("1", "0")),
+            Err(VerificationError::Unsatisfiable(_))
+        ));
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let verifier = Z3Verifier::new();
+        let spec = |parts: &[(&str, ConstraintOperator, &str)]| {
+            let parts = parts
+                .iter()
+                .map(|(left, operator, right)| {
+                    CompoundConstraint::Simple(Constraint {
+                        left_variable: left.to_string(),
+                        operator: *operator,
+                        right_value: right.to_string(),
+                    })
+                })
+                .collect();
+            verifier.verify_compound_constraints(&CompoundConstraint::And(parts))
+        };
+        let average = spec(&[
+            ("count(items)", ConstraintOperator::Equal, "2"),
+            ("sum(items)", ConstraintOperator::Equal, "total"),
+            ("total", ConstraintOperator::Equal, "11"),
+            ("average(items)", ConstraintOperator::Equal, "5"),
+        ]);
+        assert!(average.unwrap().satisfiable);
+
+        // An empty list sums to zero, and lists are bounded
+        let empty = spec(&[
+            ("count(items)", ConstraintOperator::Equal, "0"),
+            ("sum(items)", ConstraintOperator::NotEqual, "0"),
+        ]);
+        assert!(matches!(empty, Err(VerificationError::Unsatisfiable(_))));
+        let longest = MAX_LIST_LENGTH.to_string();
+        let long = spec(&[("count(items)", ConstraintOperator::GreaterThan, &longest)]);
+        assert!(matches!(long, Err(VerificationError::Unsatisfiable(_))));
+    }
+
+    #[test]
+    fn test_distinct() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, right: &str| {
             CompoundConstraint::Simple(Constraint {
-                left_variable: "x".to_string(),
-                operator: ConstraintOperator::LessThanOrEqual,
-                right_value: "10".to_string(),
-            }),
+                left_variable: left.to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: right.to_string(),
+            })
+        };
+        let distinct = |fields: &[&str]| CompoundConstraint::Distinct(fields.iter().map(|f| f.to_string()).collect());
+
+        let equal = CompoundConstraint::And(vec![distinct(&["sender", "receiver"]), simple("sender", "receiver")]);
+        let equal = verifier.verify_compound_constraints(&equal);
+        assert!(matches!(equal, Err(VerificationError::Unsatisfiable(_))));
+
+        // The elements below the length differ; the rest are free
+        let ids = CompoundConstraint::And(vec![distinct(&["ids"]), simple("count(ids)", "3")]);
+        let model = verifier.verify_compound_constraints(&ids).unwrap().model.unwrap();
+        let mut elements: Vec<&String> = (0..3).filter_map(|index| model.get(&format!("ids[{}]", index))).collect();
+        elements.sort();
+        elements.dedup();
+        assert_eq!(elements.len(), 3);
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let verifier = Z3Verifier::new();
+        let simple = |left: &str, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: right.to_string(),
+            })
+        };
+        let mode = |value: &str| CompoundConstraint::And(vec![simple("mode & 0x0F", "2"), simple("mode", value)]);
+        assert!(verifier.verify_compound_constraints(&mode("18")).unwrap().satisfiable);
+        assert!(matches!(verifier.verify_compound_constraints(&mode("19")), Err(VerificationError::Unsatisfiable(_))));
+
+        let flags = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint::bit_set("flags", 3)),
+            CompoundConstraint::Simple(Constraint::bit_clear("flags", 3)),
         ]);
-        
-        let result = verifier.verify_compound_constraints(&compound);
-        assert!(result.is_ok());
-        assert!(result.unwrap().satisfiable);
+        assert!(matches!(verifier.verify_compound_constraints(&flags), Err(VerificationError::Unsatisfiable(_))));
+        let negative = CompoundConstraint::And(vec![simple("delta >> 1", "-1"), simple("delta", "-1")]);
+        assert!(verifier.verify_compound_constraints(&negative).unwrap().satisfiable);
+
+        // The top bit of a 32-bit field is shifted out; a 64-bit one keeps it
+        let overflow = CompoundConstraint::And(vec![simple("word << 1", "0"), simple("word", "2147483648")]);
+        let mut schema = Schema::new("TR-BITS".to_string());
+        schema.add_field("word".to_string(), crucible_core::DataType::Uint32, None);
+        assert!(verifier.verify_with_schema(&overflow, &schema).unwrap().satisfiable);
+        assert!(matches!(verifier.verify_compound_constraints(&overflow), Err(VerificationError::Unsatisfiable(_))));
     }
 
     #[test]
-    fn test_compound_or_constraints() {
+    fn test_overflow_risks() {
         let verifier = Z3Verifier::new();
-        
-        let compound = CompoundConstraint::Or(vec![
+        let simple = |left: &str, operator: ConstraintOperator, right: &str| {
             CompoundConstraint::Simple(Constraint {
-                left_variable: "x".to_string(),
-                operator: ConstraintOperator::LessThan,
-                right_value: "0".to_string(),
-            }),
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let word = BitWidth { bits: 32, signed: false };
+        let widths: HashMap<String, BitWidth> =
+            ["amount", "fee", "total"].into_iter().map(|field| (field.to_string(), word)).collect();
+        let total = simple("total", ConstraintOperator::Equal, "amount + fee");
+        let small = |field: &str| simple(field, ConstraintOperator::LessThanOrEqual, "1000");
+
+        let risks = verifier.overflow_risks(&total, &widths).unwrap();
+        assert_eq!((risks.len(), risks[0].term.as_str(), risks[0].width), (1, "amount + fee", word));
+        let sum: i128 = risks[0].witness.values().map(|value| value.parse::<i128>().unwrap()).sum();
+        assert!(sum > word.max());
+
+        // Bounds checked before the sum keep it in range; bounds after it do not
+        let guarded = CompoundConstraint::And(vec![small("amount"), small("fee"), total.clone()]);
+        assert!(verifier.overflow_risks(&guarded, &widths).unwrap().is_empty());
+        let late = CompoundConstraint::And(vec![total.clone(), small("amount"), small("fee")]);
+        assert_eq!(verifier.overflow_risks(&late, &widths).unwrap().len(), 1);
+
+        // An unsigned difference underflows unless the antecedent orders it
+        let change = simple("total", ConstraintOperator::Equal, "amount - fee");
+        assert_eq!(verifier.overflow_risks(&change, &widths).unwrap().len(), 1);
+        let ordered = CompoundConstraint::Implies(
+            Box::new(simple("fee", ConstraintOperator::LessThanOrEqual, "amount")),
+            Box::new(change),
+        );
+        assert!(verifier.overflow_risks(&ordered, &widths).unwrap().is_empty());
+
+        // Unbounded in the target (Python, Elixir): nothing to report
+        assert!(verifier.overflow_risks(&total, &HashMap::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_decimal_float_semantics() {
+        let simple = |left: &str, operator, right: &str| {
             CompoundConstraint::Simple(Constraint {
-                left_variable: "x".to_string(),
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let mut schema = Schema::new("TR-FLOAT".to_string());
+        for field in ["price", "tax", "total"] {
+            schema.add_field(field.to_string(), crucible_core::DataType::Decimal, None);
+        }
+        let float = |rounding| Z3Verifier::new().with_decimal_semantics(DecimalSemantics::Float64(rounding));
+        let nearest = float(RoundingMode::NearestTiesToEven);
+
+        // 0.1 + 0.2 is the double after 0.3
+        let sum = |total: &str| {
+            CompoundConstraint::And(vec![
+                simple("total", ConstraintOperator::Equal, "price + tax"),
+                simple("price", ConstraintOperator::Equal, "0.1"),
+                simple("tax", ConstraintOperator::Equal, "0.2"),
+                simple("total", ConstraintOperator::Equal, total),
+            ])
+        };
+        assert!(matches!(nearest.verify_with_schema(&sum("0.3"), &schema), Err(VerificationError::Unsatisfiable(_))));
+        let output = nearest.verify_with_schema(&sum("0.30000000000000004"), &schema).unwrap();
+        assert_eq!(output.model.unwrap()["total"], "0.30000000000000004");
+
+        // Adding 1 to 10^16 is lost to rounding unless it rounds up
+        let grows = CompoundConstraint::And(vec![
+            simple("price", ConstraintOperator::Equal, "10000000000000000"),
+            simple("tax", ConstraintOperator::Equal, "1"),
+            simple("total", ConstraintOperator::Equal, "price + tax"),
+            simple("total", ConstraintOperator::GreaterThan, "price"),
+        ]);
+        assert!(matches!(nearest.verify_with_schema(&grows, &schema), Err(VerificationError::Unsatisfiable(_))));
+        assert!(float(RoundingMode::TowardPositive).verify_with_schema(&grows, &schema).unwrap().satisfiable);
+        assert!(Z3Verifier::new().verify_with_schema(&grows, &schema).unwrap().satisfiable);
+
+        let distinct = CompoundConstraint::And(vec![
+            CompoundConstraint::Distinct(vec!["price".to_string(), "tax".to_string()]),
+            simple("price", ConstraintOperator::Equal, "-0.0"),
+            simple("tax", ConstraintOperator::Equal, "0.0"),
+        ]);
+        assert!(matches!(nearest.verify_with_schema(&distinct, &schema), Err(VerificationError::Unsatisfiable(_))));
+    }
+
+    #[test]
+    fn test_string_containment() {
+        let verifier = Z3Verifier::new();
+        let email = |operator, needle: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "email".to_string(),
+                operator,
+                right_value: needle.to_string(),
+            })
+        };
+
+        let consistent = CompoundConstraint::And(vec![
+            email(ConstraintOperator::Contains, "\"@\""),
+            email(ConstraintOperator::DoesNotContain, "\"+\""),
+        ]);
+        assert!(verifier.verify_compound_constraints(&consistent).unwrap().satisfiable);
+
+        // "a@b" contains "@", so excluding "@" contradicts requiring "a@b"
+        let conflicting = CompoundConstraint::And(vec![
+            email(ConstraintOperator::Contains, "\"a@b\""),
+            email(ConstraintOperator::DoesNotContain, "\"@\""),
+        ]);
+        assert!(matches!(
+            verifier.verify_compound_constraints(&conflicting),
+            Err(VerificationError::Unsatisfiable(_))
+        ));
+
+        let smt_lib = verifier.generate_smt_lib(&[Constraint {
+            left_variable: "email".to_string(),
+            operator: ConstraintOperator::DoesNotContain,
+            right_value: "\"+\"".to_string(),
+        }]);
+        assert!(smt_lib.starts_with("(set-logic QF_SLIA)"));
+        assert!(smt_lib.contains("(declare-const email String)"));
+        assert!(smt_lib.contains("(assert (not (str.contains email \"+\")))"));
+    }
+
+    #[test]
+    fn test_implication() {
+        let verifier = Z3Verifier::new();
+        let c = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+        let approval = CompoundConstraint::implies(
+            c("amount", ConstraintOperator::GreaterThan, "1000"),
+            c("approvals", ConstraintOperator::GreaterThanOrEqual, "2"),
+        );
+
+        // A small amount needs no approvals
+        let small = CompoundConstraint::And(vec![
+            approval.clone(),
+            c("amount", ConstraintOperator::Equal, "10"),
+            c("approvals", ConstraintOperator::Equal, "0"),
+        ]);
+        assert!(verifier.verify_compound_constraints(&small).unwrap().satisfiable);
+
+        let unapproved = CompoundConstraint::And(vec![
+            approval,
+            c("amount", ConstraintOperator::Equal, "5000"),
+            c("approvals", ConstraintOperator::Equal, "0"),
+        ]);
+        assert!(matches!(
+            verifier.verify_compound_constraints(&unapproved),
+            Err(VerificationError::Unsatisfiable(_))
+        ));
+    }
+
+    #[test]
+    fn test_cardinality() {
+        let verifier = Z3Verifier::new();
+        let flag = |name: &str, value: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: name.to_string(),
+                operator: ConstraintOperator::Equal,
+                right_value: value.to_string(),
+            })
+        };
+        let payment = CompoundConstraint::ExactlyOne(vec![flag("card", "1"), flag("transfer", "1")]);
+
+        let one = CompoundConstraint::And(vec![payment.clone(), flag("card", "1"), flag("transfer", "0")]);
+        assert!(verifier.verify_compound_constraints(&one).unwrap().satisfiable);
+
+        for both_or_neither in ["1", "0"] {
+            let invalid = CompoundConstraint::And(vec![
+                payment.clone(),
+                flag("card", both_or_neither),
+                flag("transfer", both_or_neither),
+            ]);
+            assert!(matches!(
+                verifier.verify_compound_constraints(&invalid),
+                Err(VerificationError::Unsatisfiable(_))
+            ));
+        }
+
+        let neither = CompoundConstraint::And(vec![
+            CompoundConstraint::AtMostOne(vec![flag("card", "1"), flag("transfer", "1")]),
+            flag("card", "0"),
+            flag("transfer", "0"),
+        ]);
+        assert!(verifier.verify_compound_constraints(&neither).unwrap().satisfiable);
+    }
+
+    #[test]
+    fn test_named_predicates_are_inlined() {
+        let verifier = Z3Verifier::new();
+        let mut definitions = Definitions::new();
+        definitions
+            .define(
+                "sufficient_funds",
+                CompoundConstraint::Simple(Constraint {
+                    left_variable: "balance".to_string(),
+                    operator: ConstraintOperator::GreaterThanOrEqual,
+                    right_value: "amount".to_string(),
+                }),
+            )
+            .unwrap();
+        let overdraft = CompoundConstraint::And(vec![
+            CompoundConstraint::Named("sufficient_funds".to_string()),
+            CompoundConstraint::Simple(Constraint {
+                left_variable: "amount".to_string(),
                 operator: ConstraintOperator::GreaterThan,
-                right_value: "10".to_string(),
+                right_value: "balance".to_string(),
             }),
         ]);
-        
-        let result = verifier.verify_compound_constraints(&compound);
-        assert!(result.is_ok());
-        assert!(result.unwrap().satisfiable);
+
+        assert!(matches!(
+            verifier.verify_with_definitions(&overdraft, &definitions),
+            Err(VerificationError::Unsatisfiable(_))
+        ));
+        assert!(matches!(
+            verifier.verify_compound_constraints(&overdraft),
+            Err(VerificationError::TranslationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_modulo_is_euclidean() {
+        let verifier = Z3Verifier::new();
+        let id = |left: &str, operator, right: &str| {
+            CompoundConstraint::Simple(Constraint {
+                left_variable: left.to_string(),
+                operator,
+                right_value: right.to_string(),
+            })
+        };
+
+        // -7 mod 10 is 3 in Z3, not -7 as with a truncating `%`
+        let negative = CompoundConstraint::And(vec![
+            id("id", ConstraintOperator::Equal, "-7"),
+            id("id % 10", ConstraintOperator::Equal, "3"),
+        ]);
+        assert!(verifier.verify_compound_constraints(&negative).unwrap().satisfiable);
+
+        let conflicting = CompoundConstraint::And(vec![
+            CompoundConstraint::Simple(Constraint::multiple_of("amount", "100")),
+            id("amount", ConstraintOperator::Equal, "150"),
+        ]);
+        assert!(matches!(
+            verifier.verify_compound_constraints(&conflicting),
+            Err(VerificationError::Unsatisfiable(_))
+        ));
+
+        let smt_lib = verifier.generate_smt_lib(&[Constraint::multiple_of("amount", "100")]);
+        assert!(smt_lib.starts_with("(set-logic QF_LIA)"));
+        assert!(smt_lib.contains("(declare-const amount Int)"));
+        assert!(smt_lib.contains("(assert (= (mod amount 100) 0))"));
     }
 
     #[test]
@@ -0,0 +1,255 @@
+//! Verification Passes - custom analyses over constraint trees
+//!
+//! A `VerificationPass` looks at one requirement's constraint tree at a time
+//! (with the rest of the spec, the Schema and the solver at hand) and
+//! reports what it finds as `Diagnostic`s. The `PassManager` runs every
+//! registered pass over every requirement and tags each finding with the
+//! pass and requirement it came from, so domain lint rules and company
+//! policy checks land in the same report as the solver's verdicts:
+//!
+//! ```text
+//! struct NoRawCardNumbers;
+//!
+//! impl VerificationPass for NoRawCardNumbers {
+//!     fn name(&self) -> &str {
+//!         "no-raw-card-numbers"
+//!     }
+//!
+//!     fn check(&self, tree: &CompoundConstraint, _: &PassContext) -> VerificationResult<Vec<(Level, Diagnostic)>> {
+//!         let message = "compare a token, not the card number";
+//!         let found = tree.to_string().contains("card_number");
+//!         Ok(found.then(|| (Level::Error, Diagnostic::new("ACME001", message))).into_iter().collect())
+//!     }
+//! }
+//!
+//! let mut passes = PassManager::with_builtin();
+//! passes.register(NoRawCardNumbers);
+//! let findings = passes.run(&verifier, None, &trees);
+//! ```
+//!
+//! A pass that fails (the solver gave up, say) is reported as a warning
+//! from that pass rather than aborting the others. Codes are the pass's
+//! own; the built-in passes use `L`-codes.
+
+use crate::{Consistency, VerificationResult, Z3Verifier};
+use crucible_core::{CompoundConstraint, CrucibleError, Diagnostic, Schema};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A conjunct is implied by the other conjuncts of its requirement
+pub const REDUNDANT_CONJUNCT: &str = "L001";
+
+/// How much a finding matters; `Error` fails the report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Note,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Note => "note",
+            Level::Warning => "warning",
+            Level::Error => "error",
+        })
+    }
+}
+
+/// A diagnostic a pass reported about one requirement
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Name of the pass that reported it
+    pub pass: String,
+    /// Index into the trees given to `PassManager::run`
+    pub requirement: usize,
+    pub level: Level,
+    pub diagnostic: Diagnostic,
+}
+
+/// What a pass can see besides the tree it checks
+pub struct PassContext<'a> {
+    pub verifier: &'a Z3Verifier,
+    pub schema: Option<&'a Schema>,
+    /// Every requirement's tree in spec order, the one being checked included
+    pub requirements: &'a [CompoundConstraint],
+    /// Index of the tree being checked in `requirements`
+    pub index: usize,
+}
+
+/// An analysis run over each requirement's constraint tree
+pub trait VerificationPass: Send + Sync {
+    /// Stable name, e.g. `no-raw-card-numbers`; findings carry it
+    fn name(&self) -> &str;
+
+    /// The findings for `tree`, none when it passes
+    fn check(
+        &self,
+        tree: &CompoundConstraint,
+        context: &PassContext<'_>,
+    ) -> VerificationResult<Vec<(Level, Diagnostic)>>;
+}
+
+/// The passes to run, in registration order
+#[derive(Default)]
+pub struct PassManager {
+    passes: Vec<Box<dyn VerificationPass>>,
+}
+
+impl PassManager {
+    /// No passes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The passes `crucible report` runs
+    pub fn with_builtin() -> Self {
+        let mut manager = Self::new();
+        manager.register(RedundantConjuncts);
+        manager
+    }
+
+    /// Add `pass` after the ones already registered
+    pub fn register(&mut self, pass: impl VerificationPass + 'static) -> &mut Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    /// Names of the registered passes, in order
+    pub fn names(&self) -> Vec<&str> {
+        self.passes.iter().map(|pass| pass.name()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Run every pass over every tree; findings are ordered by requirement, then pass
+    #[tracing::instrument(skip_all, fields(passes = self.passes.len(), requirements = requirements.len()))]
+    pub fn run(
+        &self,
+        verifier: &Z3Verifier,
+        schema: Option<&Schema>,
+        requirements: &[CompoundConstraint],
+    ) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for (index, tree) in requirements.iter().enumerate() {
+            let context = PassContext {
+                verifier,
+                schema,
+                requirements,
+                index,
+            };
+            for pass in &self.passes {
+                let found = pass.check(tree, &context).unwrap_or_else(|e| {
+                    tracing::warn!(pass = pass.name(), requirement = index, error = %e, "verification pass failed");
+                    let diagnostic = CrucibleError::from(e).diagnostic().clone();
+                    vec![(Level::Warning, diagnostic)]
+                });
+                findings.extend(found.into_iter().map(|(level, diagnostic)| Finding {
+                    pass: pass.name().to_string(),
+                    requirement: index,
+                    level,
+                    diagnostic,
+                }));
+            }
+        }
+        findings
+    }
+}
+
+/// Warns about conjuncts the other conjuncts of the same requirement already
+/// imply, e.g. `amount > 0` next to `amount >= 10`: usually a typo in one of
+/// the bounds, and never a constraint of its own
+pub struct RedundantConjuncts;
+
+impl VerificationPass for RedundantConjuncts {
+    fn name(&self) -> &str {
+        "redundant-conjuncts"
+    }
+
+    fn check(
+        &self,
+        tree: &CompoundConstraint,
+        context: &PassContext<'_>,
+    ) -> VerificationResult<Vec<(Level, Diagnostic)>> {
+        let CompoundConstraint::And(parts) = tree else {
+            return Ok(Vec::new());
+        };
+        let mut findings = Vec::new();
+        for (index, part) in parts.iter().enumerate() {
+            let others: Vec<CompoundConstraint> =
+                parts.iter().enumerate().filter(|(other, _)| *other != index).map(|(_, c)| c.clone()).collect();
+            if let Consistency::Implied(premises) = context.verifier.classify(&others, part)? {
+                let premises: Vec<String> = premises.iter().map(|&i| others[i].to_string()).collect();
+                let message = format!("`{}` is implied by {}", part, premises.join(" and "));
+                let diagnostic = Diagnostic::new(REDUNDANT_CONJUNCT, message)
+                    .with_arg("conjunct", part)
+                    .with_arg("premises", premises.join(", "));
+                findings.push((Level::Warning, diagnostic));
+            }
+        }
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::{Constraint, ConstraintOperator};
+
+    fn compare(variable: &str, operator: ConstraintOperator, value: &str) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: variable.to_string(),
+            operator,
+            right_value: value.to_string(),
+        })
+    }
+
+    /// A policy check of the kind downstream users register
+    struct NoField(&'static str);
+
+    impl VerificationPass for NoField {
+        fn name(&self) -> &str {
+            "no-field"
+        }
+
+        fn check(
+            &self,
+            tree: &CompoundConstraint,
+            _: &PassContext<'_>,
+        ) -> VerificationResult<Vec<(Level, Diagnostic)>> {
+            let message = format!("`{}` is off limits", self.0);
+            let found = tree.to_string().contains(self.0);
+            Ok(found.then(|| (Level::Error, Diagnostic::new("ACME001", message))).into_iter().collect())
+        }
+    }
+
+    #[test]
+    fn test_pass_manager() {
+        let verifier = Z3Verifier::new();
+        let requirements = vec![
+            CompoundConstraint::And(vec![
+                compare("amount", ConstraintOperator::GreaterThan, "0"),
+                compare("amount", ConstraintOperator::GreaterThanOrEqual, "10"),
+            ]),
+            compare("card_number", ConstraintOperator::NotEqual, "0"),
+        ];
+
+        let mut passes = PassManager::with_builtin();
+        passes.register(NoField("card_number"));
+        assert_eq!(passes.names(), ["redundant-conjuncts", "no-field"]);
+
+        let findings = passes.run(&verifier, None, &requirements);
+        assert_eq!(findings.len(), 2);
+        assert_eq!((findings[0].requirement, findings[0].level), (0, Level::Warning));
+        assert_eq!(findings[0].diagnostic.code, REDUNDANT_CONJUNCT);
+        assert_eq!(findings[0].diagnostic.args["conjunct"], "amount > 0");
+        assert_eq!((findings[1].pass.as_str(), findings[1].requirement), ("no-field", 1));
+        assert_eq!(findings[1].level, Level::Error);
+
+        assert!(PassManager::new().run(&verifier, None, &requirements).is_empty());
+    }
+}
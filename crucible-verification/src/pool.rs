@@ -0,0 +1,259 @@
+//! Verifier Pool - reusable Z3 contexts for multi-threaded callers
+//!
+//! A Z3 `Context` must stay on the thread that created it, and creating one
+//! costs far more than a typical check. The pool starts a fixed number of
+//! worker threads, each owning one warmed-up `Z3Verifier` for its lifetime.
+//! Callers check a worker out, run closures on it, and return it by dropping
+//! the checkout:
+//!
+//! ```text
+//! handler --checkout(timeout)--> PooledVerifier --run(job)--> worker thread (Z3Verifier)
+//!                                      |                              |
+//!                                      +<------- result over channel -+
+//! ```
+//!
+//! When every worker is busy, `checkout` waits up to its timeout and then
+//! reports `PoolError::Busy`, so callers can shed load instead of queueing
+//! without bound.
+
+use crate::Z3Verifier;
+use crucible_core::{Constraint, ConstraintOperator};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Errors raised by the verifier pool
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PoolError {
+    #[error("all {workers} verifier workers are busy (waited {waited:?})")]
+    Busy { workers: usize, waited: Duration },
+
+    #[error("verifier worker panicked while running a job")]
+    WorkerPanicked,
+
+    #[error("could not start verifier worker: {0}")]
+    Spawn(String),
+}
+
+type Job = Box<dyn FnOnce(&Z3Verifier) + Send>;
+
+/// Handle to one worker thread
+struct Worker {
+    id: usize,
+    jobs: mpsc::Sender<Job>,
+}
+
+struct Shared {
+    idle: Mutex<Vec<Worker>>,
+    returned: Condvar,
+    size: usize,
+}
+
+/// Fixed set of Z3 workers; cloning shares the same workers
+#[derive(Clone)]
+pub struct VerifierPool {
+    shared: Arc<Shared>,
+}
+
+impl VerifierPool {
+    /// Start `size` workers (at least one) and wait until each has a warm context
+    pub fn new(size: usize) -> Result<Self, PoolError> {
+        Self::with_timeout(size, None)
+    }
+
+    /// `new`, with every worker's checks limited to `timeout`
+    pub fn with_timeout(size: usize, timeout: Option<Duration>) -> Result<Self, PoolError> {
+        let size = size.max(1);
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let mut workers = Vec::with_capacity(size);
+        for id in 0..size {
+            let (jobs, queue) = mpsc::channel::<Job>();
+            let ready = ready_tx.clone();
+            thread::Builder::new()
+                .name(format!("z3-worker-{}", id))
+                .spawn(move || run_worker(id, timeout, queue, ready))
+                .map_err(|e| PoolError::Spawn(e.to_string()))?;
+            workers.push(Worker { id, jobs });
+        }
+        drop(ready_tx);
+        for _ in 0..size {
+            ready_rx.recv().map_err(|_| PoolError::Spawn("worker exited during warm-up".to_string()))?;
+        }
+        tracing::info!(workers = size, "verifier pool ready");
+
+        Ok(Self {
+            shared: Arc::new(Shared {
+                idle: Mutex::new(workers),
+                returned: Condvar::new(),
+                size,
+            }),
+        })
+    }
+
+    /// One worker per available CPU
+    pub fn with_available_parallelism() -> Result<Self, PoolError> {
+        Self::new(thread::available_parallelism().map_or(1, |n| n.get()))
+    }
+
+    /// Number of workers
+    pub fn size(&self) -> usize {
+        self.shared.size
+    }
+
+    /// Workers not checked out right now
+    pub fn available(&self) -> usize {
+        self.lock_idle().len()
+    }
+
+    /// Take a worker, waiting up to `timeout` for one to be returned
+    pub fn checkout(&self, timeout: Duration) -> Result<PooledVerifier, PoolError> {
+        let started = Instant::now();
+        let mut idle = self.lock_idle();
+        loop {
+            if let Some(worker) = idle.pop() {
+                return Ok(PooledVerifier {
+                    shared: self.shared.clone(),
+                    worker: Some(worker),
+                });
+            }
+            let waited = started.elapsed();
+            let Some(remaining) = timeout.checked_sub(waited).filter(|r| !r.is_zero()) else {
+                tracing::warn!(workers = self.shared.size, ?waited, "verifier pool exhausted");
+                return Err(PoolError::Busy {
+                    workers: self.shared.size,
+                    waited,
+                });
+            };
+            idle = self
+                .shared
+                .returned
+                .wait_timeout(idle, remaining)
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .0;
+        }
+    }
+
+    /// Take a worker only if one is idle now
+    pub fn try_checkout(&self) -> Result<PooledVerifier, PoolError> {
+        self.checkout(Duration::ZERO)
+    }
+
+    fn lock_idle(&self) -> std::sync::MutexGuard<'_, Vec<Worker>> {
+        // Workers are only pushed and popped under the lock, so a panic
+        // elsewhere cannot leave the list inconsistent
+        self.shared.idle.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Exclusive use of one worker until dropped
+pub struct PooledVerifier {
+    shared: Arc<Shared>,
+    worker: Option<Worker>,
+}
+
+impl PooledVerifier {
+    /// Run `job` on the worker's verifier and wait for its result
+    pub fn run<R, F>(&self, job: F) -> Result<R, PoolError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&Z3Verifier) -> R + Send + 'static,
+    {
+        let worker = self.worker.as_ref().expect("worker is held until drop");
+        let (result_tx, result_rx) = mpsc::channel();
+        let job: Job = Box::new(move |verifier| {
+            let _ = result_tx.send(job(verifier));
+        });
+        worker.jobs.send(job).map_err(|_| PoolError::WorkerPanicked)?;
+        // The sender is dropped unsent when the job panics
+        result_rx.recv().map_err(|_| PoolError::WorkerPanicked)
+    }
+}
+
+impl Drop for PooledVerifier {
+    fn drop(&mut self) {
+        if let Some(worker) = self.worker.take() {
+            tracing::trace!(worker = worker.id, "verifier returned to pool");
+            self.shared.idle.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(worker);
+            self.shared.returned.notify_one();
+        }
+    }
+}
+
+/// Worker thread: build and warm a context, then serve jobs until the pool is dropped
+fn run_worker(id: usize, timeout: Option<Duration>, queue: mpsc::Receiver<Job>, ready: mpsc::Sender<()>) {
+    let verifier = Z3Verifier::with_timeout(timeout);
+    // The first check loads the solver's tactics; pay for it before the
+    // first request does
+    let warm_up = Constraint {
+        left_variable: "warm_up".to_string(),
+        operator: ConstraintOperator::GreaterThanOrEqual,
+        right_value: "0".to_string(),
+    };
+    let _ = verifier.verify_constraints(&[warm_up]);
+    let _ = ready.send(());
+    drop(ready);
+
+    for job in queue {
+        // A panicking job loses its result, not the worker
+        if panic::catch_unwind(AssertUnwindSafe(|| job(&verifier))).is_err() {
+            tracing::error!(worker = id, "verifier job panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crucible_core::CompoundConstraint;
+
+    fn at_least(variable: &str, value: i64) -> CompoundConstraint {
+        CompoundConstraint::Simple(Constraint {
+            left_variable: variable.to_string(),
+            operator: ConstraintOperator::GreaterThanOrEqual,
+            right_value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_checkout_runs_on_worker_and_returns_it() {
+        let pool = VerifierPool::new(2).unwrap();
+        assert_eq!(pool.available(), 2);
+        {
+            let verifier = pool.checkout(Duration::from_secs(1)).unwrap();
+            assert_eq!(pool.available(), 1);
+            let compound = at_least("balance", 0);
+            let satisfiable = verifier
+                .run(move |z3| z3.verify_compound_constraints(&compound).is_ok())
+                .unwrap();
+            assert!(satisfiable);
+        }
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    fn test_busy_when_all_workers_checked_out() {
+        let pool = VerifierPool::new(1).unwrap();
+        let held = pool.try_checkout().unwrap();
+        let error = pool.checkout(Duration::from_millis(20)).err().unwrap();
+        assert!(matches!(error, PoolError::Busy { workers: 1, .. }));
+
+        // A worker returned while waiting is handed over
+        let waiter = {
+            let pool = pool.clone();
+            thread::spawn(move || pool.checkout(Duration::from_secs(5)).is_ok())
+        };
+        thread::sleep(Duration::from_millis(20));
+        drop(held);
+        assert!(waiter.join().unwrap());
+    }
+
+    #[test]
+    fn test_worker_survives_panicking_job() {
+        let pool = VerifierPool::new(1).unwrap();
+        let verifier = pool.try_checkout().unwrap();
+        assert_eq!(verifier.run(|_| -> i32 { panic!("job failed") }), Err(PoolError::WorkerPanicked));
+        assert_eq!(verifier.run(|_| 42), Ok(42));
+    }
+}